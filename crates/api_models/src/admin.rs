@@ -55,6 +55,10 @@ pub struct MerchantAccountCreate {
     #[schema(default = false, example = true)]
     pub redirect_to_merchant_with_http_post: Option<bool>,
 
+    /// A boolean value to indicate if the raw response of the connector needs to be returned in the payment and refund responses
+    #[schema(default = false, example = true)]
+    pub return_raw_connector_response: Option<bool>,
+
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
     pub metadata: Option<pii::SecretSerdeValue>,
@@ -66,6 +70,10 @@ pub struct MerchantAccountCreate {
     /// An identifier for the vault used to store payment method information.
     #[schema(example = "locker_abc123")]
     pub locker_id: Option<String>,
+
+    /// The identifier of the organization which owns the Merchant Account
+    #[schema(max_length = 64, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub organization_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema)]
@@ -112,6 +120,10 @@ pub struct MerchantAccountUpdate {
     #[schema(default = false, example = true)]
     pub redirect_to_merchant_with_http_post: Option<bool>,
 
+    /// A boolean value to indicate if the raw response of the connector needs to be returned in the payment and refund responses
+    #[schema(default = false, example = true)]
+    pub return_raw_connector_response: Option<bool>,
+
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
     pub metadata: Option<pii::SecretSerdeValue>,
@@ -155,6 +167,10 @@ pub struct MerchantAccountResponse {
     #[schema(default = false, example = true)]
     pub redirect_to_merchant_with_http_post: bool,
 
+    /// A boolean value to indicate if the raw response of the connector needs to be returned in the payment and refund responses
+    #[schema(default = false, example = true)]
+    pub return_raw_connector_response: bool,
+
     /// Merchant related details
     #[schema(value_type = Option<MerchantDetails>)]
     pub merchant_details: Option<serde_json::Value>,
@@ -186,6 +202,10 @@ pub struct MerchantAccountResponse {
     /// An identifier for the vault used to store payment method information.
     #[schema(example = "locker_abc123")]
     pub locker_id: Option<String>,
+
+    /// The identifier of the organization which owns the Merchant Account
+    #[schema(max_length = 64, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub organization_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -234,6 +254,30 @@ pub struct MerchantDetails {
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum RoutingAlgorithm {
     Single(api_enums::RoutableConnectors),
+    /// Splits the payments across connectors, as per the percentage of traffic assigned to each
+    VolumeSplit(Vec<ConnectorVolumeSplit>),
+    /// Routes the payments through the connector with the highest recent authorization success
+    /// rate for the card network and country of the payment
+    SuccessRate(SuccessRateRoutingAlgorithm),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorVolumeSplit {
+    pub connector: api_enums::RoutableConnectors,
+    /// Percentage of the payments to be routed through the connector
+    pub split: u8,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SuccessRateRoutingAlgorithm {
+    /// The connectors eligible for routing, in the order of preference while their success rates
+    /// are not known yet
+    pub connectors: Vec<api_enums::RoutableConnectors>,
+    /// Percentage of the payments distributed evenly across all the eligible connectors,
+    /// irrespective of their success rates, so that none of them is starved of traffic
+    pub exploration_percentage: u8,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -428,6 +472,24 @@ pub struct ToggleKVRequest {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ToggleMerchantConnectorRequest {
+    /// Whether the connector is disabled. Payments are not routed through a disabled connector.
+    #[schema(example = true)]
+    pub disabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConnectorCredentialsKeyRotateResponse {
+    /// The identifier of the data key the connector credentials are now encrypted with
+    #[schema(example = "dek_5apGeP94tMts6rg3U3kR")]
+    pub key_id: String,
+    /// The number of merchant connector accounts whose credentials were re-encrypted
+    #[schema(example = 12)]
+    pub accounts_reencrypted: usize,
+}
+
 #[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct MerchantConnectorDetailsWrap {
     /// Creds Identifier is to uniquely identify the credentials. Do not send any sensitive info in this field. And do not send the string "null".
@@ -23,6 +23,11 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The scopes the API Key is restricted to. An API Key created without scopes has access to
+    /// all APIs, while an API Key with scopes can only access the APIs of its scopes.
+    #[schema(example = json!(["payments:read"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
 }
 
 /// The response body for creating an API Key.
@@ -32,9 +37,13 @@ pub struct CreateApiKeyResponse {
     #[schema(max_length = 64, example = "5hEEqkgJUyuxgSKGArHA4mWSnX")]
     pub key_id: String,
 
-    /// The identifier for the Merchant Account.
+    /// The identifier for the Merchant Account. Not set for the API Keys of an organization.
     #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
-    pub merchant_id: String,
+    pub merchant_id: Option<String>,
+
+    /// The identifier for the organization, for the API Keys of an organization.
+    #[schema(max_length = 64, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub organization_id: Option<String>,
 
     /// The unique name for the API Key to help you identify it.
     #[schema(max_length = 64, example = "Sandbox integration key")]
@@ -60,6 +69,10 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The scopes the API Key is restricted to, if any.
+    #[schema(example = json!(["payments:read"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -75,9 +88,13 @@ pub struct RetrieveApiKeyResponse {
     #[schema(max_length = 64, example = "5hEEqkgJUyuxgSKGArHA4mWSnX")]
     pub key_id: String,
 
-    /// The identifier for the Merchant Account.
+    /// The identifier for the Merchant Account. Not set for the API Keys of an organization.
     #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
-    pub merchant_id: String,
+    pub merchant_id: Option<String>,
+
+    /// The identifier for the organization, for the API Keys of an organization.
+    #[schema(max_length = 64, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub organization_id: Option<String>,
 
     /// The unique name for the API Key to help you identify it.
     #[schema(max_length = 64, example = "Sandbox integration key")]
@@ -102,6 +119,10 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The scopes the API Key is restricted to, if any.
+    #[schema(example = json!(["payments:read"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -129,6 +150,10 @@ pub struct UpdateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
+
+    /// The scopes the API Key is restricted to.
+    #[schema(example = json!(["payments:read"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
 }
 
 /// The response body for revoking an API Key.
@@ -154,6 +179,36 @@ pub struct ListApiKeyConstraints {
     pub skip: Option<i64>,
 }
 
+/// The APIs an API Key can be restricted to.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+pub enum ApiKeyScope {
+    /// Retrieve and list payments and refunds.
+    #[serde(rename = "payments:read")]
+    #[strum(serialize = "payments:read")]
+    PaymentsRead,
+
+    /// Create, update, confirm, capture and cancel payments.
+    #[serde(rename = "payments:write")]
+    #[strum(serialize = "payments:write")]
+    PaymentsWrite,
+
+    /// Create and update refunds.
+    #[serde(rename = "refunds:write")]
+    #[strum(serialize = "refunds:write")]
+    RefundsWrite,
+}
+
 /// The expiration date and time for an API Key.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CurrencyBalance {
+    /// The currency of the balance
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The amount captured for payments, net of refunds, lost disputes and payouts, in the lowest
+    /// denomination of the currency
+    #[schema(example = 420000)]
+    pub available: i64,
+
+    /// The amount authorized for payments which is yet to be captured, in the lowest denomination
+    /// of the currency
+    #[schema(example = 6540)]
+    pub pending: i64,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct BalanceResponse {
+    /// The balances of the merchant, one for each currency the merchant has transacted in
+    pub balances: Vec<CurrencyBalance>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BalanceTransactionListConstraints {
+    /// The maximum number of balance transactions to include in the response
+    #[schema(default = 10)]
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+
+    /// The number of balance transactions to skip, the most recent first
+    pub offset: Option<i64>,
+}
+
+fn default_limit() -> i64 {
+    10
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct BalanceTransactionResponse {
+    /// The identifier for the balance transaction
+    #[schema(max_length = 64, example = "btxn_q98uSGAYbjEwqs0mJwnz")]
+    pub transaction_id: String,
+
+    /// The event recorded by the balance transaction
+    #[schema(value_type = BalanceTransactionType, example = "capture")]
+    pub transaction_type: api_enums::BalanceTransactionType,
+
+    /// The identifier for the object which caused the event, such as the payment attempt, refund,
+    /// dispute or payout
+    #[schema(max_length = 255, example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub source_id: String,
+
+    /// The identifier for the payment the event relates to, if any
+    #[schema(max_length = 255, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<String>,
+
+    /// The amount moved, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+
+    /// The currency of the amount
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The account the amount is moved into
+    #[schema(value_type = LedgerAccount, example = "available")]
+    pub debit_account: api_enums::LedgerAccount,
+
+    /// The account the amount is moved out of
+    #[schema(value_type = LedgerAccount, example = "pending")]
+    pub credit_account: api_enums::LedgerAccount,
+
+    /// The time at which the event was recorded
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct BalanceTransactionListResponse {
+    /// The number of balance transactions included in the list
+    pub size: usize,
+
+    /// The balance transactions, the most recent first
+    pub data: Vec<BalanceTransactionResponse>,
+}
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::admin;
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BusinessProfileCreate {
+    /// Name of the business profile, unique among the business profiles of the merchant
+    #[schema(max_length = 64, example = "US Online Store")]
+    pub profile_name: String,
+
+    /// The URL to redirect after the completion of payments made with the business profile
+    #[schema(value_type = Option<String>, max_length = 255, example = "https://www.example.com/success")]
+    pub return_url: Option<url::Url>,
+
+    /// Webhook related details for payments made with the business profile
+    pub webhook_details: Option<admin::WebhookDetails>,
+
+    /// The routing algorithm to be used for routing payments made with the business profile to desired connectors
+    #[schema(value_type = Option<Object>, example = json!({"type": "single", "data": "stripe"}))]
+    pub routing_algorithm: Option<serde_json::Value>,
+
+    /// The default statement descriptor name of payments made with the business profile
+    #[schema(max_length = 255, example = "Juspay Router")]
+    pub statement_descriptor_name: Option<String>,
+
+    /// The default statement descriptor suffix of payments made with the business profile
+    #[schema(max_length = 255, example = "Payment for shoes purchase")]
+    pub statement_descriptor_suffix: Option<String>,
+
+    /// The default time (in seconds) after authorization at which manually captured payments made with the business profile are captured automatically, unless they are captured or cancelled before
+    #[schema(example = 7200)]
+    pub auto_capture_delay: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BusinessProfileUpdate {
+    /// Name of the business profile, unique among the business profiles of the merchant
+    #[schema(max_length = 64, example = "US Online Store")]
+    pub profile_name: Option<String>,
+
+    /// The URL to redirect after the completion of payments made with the business profile
+    #[schema(value_type = Option<String>, max_length = 255, example = "https://www.example.com/success")]
+    pub return_url: Option<url::Url>,
+
+    /// Webhook related details for payments made with the business profile
+    pub webhook_details: Option<admin::WebhookDetails>,
+
+    /// The routing algorithm to be used for routing payments made with the business profile to desired connectors
+    #[schema(value_type = Option<Object>, example = json!({"type": "single", "data": "stripe"}))]
+    pub routing_algorithm: Option<serde_json::Value>,
+
+    /// The default statement descriptor name of payments made with the business profile
+    #[schema(max_length = 255, example = "Juspay Router")]
+    pub statement_descriptor_name: Option<String>,
+
+    /// The default statement descriptor suffix of payments made with the business profile
+    #[schema(max_length = 255, example = "Payment for shoes purchase")]
+    pub statement_descriptor_suffix: Option<String>,
+
+    /// The default time (in seconds) after authorization at which manually captured payments made with the business profile are captured automatically, unless they are captured or cancelled before
+    #[schema(example = 7200)]
+    pub auto_capture_delay: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct BusinessProfileResponse {
+    /// The identifier for the business profile
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: String,
+
+    /// The identifier for the merchant account the business profile belongs to
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// Name of the business profile
+    #[schema(max_length = 64, example = "US Online Store")]
+    pub profile_name: String,
+
+    /// The URL to redirect after the completion of payments made with the business profile
+    #[schema(max_length = 255, example = "https://www.example.com/success")]
+    pub return_url: Option<String>,
+
+    /// Webhook related details for payments made with the business profile
+    #[schema(value_type = Option<WebhookDetails>)]
+    pub webhook_details: Option<serde_json::Value>,
+
+    /// The routing algorithm to be used for routing payments made with the business profile to desired connectors
+    #[schema(value_type = Option<Object>, example = json!({"type": "single", "data": "stripe"}))]
+    pub routing_algorithm: Option<serde_json::Value>,
+
+    /// The default statement descriptor name of payments made with the business profile
+    #[schema(max_length = 255, example = "Juspay Router")]
+    pub statement_descriptor_name: Option<String>,
+
+    /// The default statement descriptor suffix of payments made with the business profile
+    #[schema(max_length = 255, example = "Payment for shoes purchase")]
+    pub statement_descriptor_suffix: Option<String>,
+
+    /// The default time (in seconds) after authorization at which manually captured payments made with the business profile are captured automatically
+    #[schema(example = 7200)]
+    pub auto_capture_delay: Option<u32>,
+
+    /// The time at which the business profile was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the business profile was last modified
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct BusinessProfileDeleteResponse {
+    /// The identifier for the business profile
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: String,
+
+    /// The identifier for the merchant account the business profile belonged to
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// If the business profile is deleted or not
+    #[schema(example = true)]
+    pub deleted: bool,
+}
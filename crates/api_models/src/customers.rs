@@ -112,6 +112,45 @@ pub struct CustomerDeleteResponse {
     /// Whether payment methods deleted or not
     #[schema(example = false)]
     pub payment_methods_deleted: bool,
+    /// Whether the mandates of the customer were revoked or not
+    #[schema(example = false)]
+    pub mandates_revoked: bool,
+}
+
+#[derive(Default, Debug, Deserialize, Serialize, ToSchema)]
+pub struct CustomerRedactResponse {
+    /// The identifier for the customer object
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+    /// Whether the personal data of the customer was redacted or not
+    #[schema(example = true)]
+    pub customer_redacted: bool,
+    /// The number of addresses of the customer which were redacted
+    #[schema(example = 2)]
+    pub addresses_redacted: usize,
+    /// The number of payments of the customer which were redacted
+    #[schema(example = 5)]
+    pub payments_redacted: usize,
+    /// The number of mandates of the customer which were redacted
+    #[schema(example = 1)]
+    pub mandates_redacted: usize,
+    /// The number of saved payment methods of the customer which were deleted, along with their
+    /// cards stored in the locker
+    #[schema(example = 1)]
+    pub payment_methods_deleted: usize,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerListConstraints {
+    /// The customer's email address
+    #[schema(value_type = Option<String>, max_length = 255, example = "JonTest@test.com")]
+    pub email: Option<Secret<String, pii::Email>>,
+    /// The customer's phone number
+    #[schema(value_type = Option<String>, max_length = 255, example = "9999999999")]
+    pub phone: Option<Secret<String>>,
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
 }
 
 pub fn generate_customer_id() -> String {
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DeadLetterTaskListConstraints {
+    /// Only the tasks of this runner are listed
+    #[schema(example = "OUTGOING_WEBHOOK_RETRY_WORKFLOW")]
+    pub runner: Option<String>,
+
+    /// The maximum number of tasks to include in the response
+    #[schema(default = 10)]
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+
+    /// The number of tasks to skip, the most recently dead lettered first
+    pub offset: Option<i64>,
+}
+
+fn default_limit() -> i64 {
+    10
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DeadLetterTaskResponse {
+    /// The identifier for the task
+    #[schema(example = "PAYMENTS_SYNC_WORKFLOW_PAYMENTS_SYNC_pay_mbabizu24mvu3mela5njyhpit4")]
+    pub task_id: String,
+
+    /// The name of the task
+    #[schema(example = "OUTGOING_WEBHOOK_RETRY")]
+    pub name: Option<String>,
+
+    /// The runner of the task
+    #[schema(example = "OUTGOING_WEBHOOK_RETRY_WORKFLOW")]
+    pub runner: Option<String>,
+
+    /// The tags of the task
+    pub tag: Vec<String>,
+
+    /// The number of times the task was retried
+    #[schema(example = 9)]
+    pub retry_count: i32,
+
+    /// The reason the task was dead lettered, such as its retries being exhausted
+    #[schema(example = "RETRIES_EXCEEDED")]
+    pub reason: String,
+
+    /// The data the task runs with
+    #[schema(value_type = Object)]
+    pub tracking_data: serde_json::Value,
+
+    /// The time at which the task was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the task was dead lettered
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub dead_lettered_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DeadLetterTaskListResponse {
+    /// The number of tasks included in the list
+    pub size: usize,
+
+    /// The dead lettered tasks, the most recently dead lettered first
+    pub data: Vec<DeadLetterTaskResponse>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DeadLetterTaskReplayRequest {
+    /// The identifiers of the tasks to replay
+    pub task_ids: Option<Vec<String>>,
+
+    /// Replays the dead lettered tasks of this runner, when the tasks are not identified
+    #[schema(example = "PAYMENTS_SYNC_WORKFLOW")]
+    pub runner: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DeadLetterTaskReplayResponse {
+    /// The number of tasks scheduled to run again
+    #[schema(example = 1)]
+    pub replayed_count: usize,
+}
@@ -0,0 +1,103 @@
+use common_utils::custom_serde;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::enums as api_enums;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DummyConnectorPaymentRequest {
+    /// The amount of the payment, in the lowest denomination of the currency
+    pub amount: i64,
+    pub currency: api_enums::Currency,
+    pub card: DummyConnectorCard,
+    /// The URL to redirect the customer to once they complete the 3DS challenge
+    pub return_url: Option<String>,
+    /// The URL to notify of the outcome of payments which are processed asynchronously, which has
+    /// to be the incoming webhook endpoint of the merchant on the router
+    pub webhook_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DummyConnectorCard {
+    pub number: Secret<String>,
+    pub expiry_month: Secret<String>,
+    pub expiry_year: Secret<String>,
+    pub cvc: Secret<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorStatus {
+    Succeeded,
+    #[default]
+    Processing,
+    RequiresAction,
+    Failed,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DummyConnectorPaymentResponse {
+    pub id: String,
+    pub status: DummyConnectorStatus,
+    pub amount: i64,
+    pub currency: api_enums::Currency,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    /// The reason the payment failed, if it did
+    pub error: Option<DummyConnectorError>,
+    /// The action the customer has to take for the payment to proceed, if any
+    pub next_action: Option<DummyConnectorNextAction>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct DummyConnectorError {
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DummyConnectorNextAction {
+    /// The URL of the 3DS challenge page to redirect the customer to
+    pub redirect_to_url: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DummyConnectorPaymentRetrieveRequest {
+    pub payment_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DummyConnectorAuthenticationRequest {
+    /// The identifier for the payment to authenticate, taken from the path of the request
+    #[serde(skip_deserializing)]
+    pub payment_id: String,
+    /// Whether the customer passed the 3DS challenge
+    pub confirm: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DummyConnectorRefundRequest {
+    /// The identifier for the payment to refund, taken from the path of the request
+    #[serde(skip_deserializing)]
+    pub payment_id: String,
+    /// The amount to refund, in the lowest denomination of the currency
+    pub amount: i64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DummyConnectorRefundResponse {
+    pub id: String,
+    pub payment_id: String,
+    pub status: DummyConnectorStatus,
+    pub amount: i64,
+    pub currency: api_enums::Currency,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DummyConnectorRefundRetrieveRequest {
+    pub refund_id: String,
+}
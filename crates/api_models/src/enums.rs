@@ -13,6 +13,7 @@ use utoipa::ToSchema;
     strum::Display,
     strum::EnumString,
     frunk::LabelledGeneric,
+    ToSchema,
 )]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
@@ -34,6 +35,18 @@ pub enum AttemptStatus {
     VoidFailed,
     AutoRefunded,
     PartialCharged,
+    PartiallyCaptured,
+    /// The bank debit was submitted and is awaiting settlement, which can take several days
+    DebitInitiated,
+    /// The customer paid less than the amount of the payment, which is to be resolved by the
+    /// merchant with the connector
+    Underpaid,
+    /// The customer paid more than the amount of the payment, the excess is to be returned by the
+    /// merchant
+    Overpaid,
+    /// The authorization is held for review, by the fraud rules of the merchant or by the
+    /// connector, and is to be approved or rejected by the merchant
+    ManualReview,
     #[default]
     Pending,
     Failure,
@@ -123,6 +136,8 @@ pub enum ConnectorType {
     BankingEntities,
     /// All types of non-banking financial institutions including Insurance, Credit / Lending etc
     NonBankingFinance,
+    /// 3DS and other cardholder authentication providers
+    AuthenticationProcessor,
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -275,6 +290,12 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    PaymentScheduleCycleSucceeded,
+    PaymentScheduleCycleFailed,
+    PayoutSucceeded,
+    PayoutFailed,
+    PayoutCancelled,
+    PayoutReversed,
 }
 
 #[derive(
@@ -297,12 +318,16 @@ pub enum IntentStatus {
     Succeeded,
     Failed,
     Cancelled,
+    Expired,
     Processing,
     RequiresCustomerAction,
     RequiresPaymentMethod,
     #[default]
     RequiresConfirmation,
     RequiresCapture,
+    /// The payment was held for review, by the fraud rules of the merchant or by the connector,
+    /// and is to be approved or rejected by the merchant
+    RequiresMerchantAction,
 }
 
 #[derive(
@@ -416,6 +441,38 @@ pub enum PaymentMethodType {
     GooglePay,
     ApplePay,
     Paypal,
+    Ach,
+    Sepa,
+    UpiCollect,
+    UpiIntent,
+    CryptoCurrency,
+    Givex,
+    Netbanking,
+    Blik,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BankDebitVerificationMethod {
+    /// The account is verified instantly when possible, and with micro-deposits otherwise
+    #[default]
+    Automatic,
+    /// The account is verified instantly, by the customer logging in to their bank
+    Instant,
+    /// Small amounts are deposited to the account, which the customer has to confirm
+    Microdeposits,
 }
 
 #[derive(
@@ -441,6 +498,10 @@ pub enum PaymentMethod {
     PayLater,
     Wallet,
     BankRedirect,
+    BankDebit,
+    Upi,
+    Crypto,
+    GiftCard,
 }
 
 #[derive(
@@ -535,6 +596,428 @@ pub enum MandateStatus {
     Revoked,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentScheduleInterval {
+    Day,
+    Week,
+    #[default]
+    Month,
+    Year,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentScheduleStatus {
+    #[default]
+    Active,
+    Cancelled,
+    Completed,
+    Failed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutStatus {
+    /// The payout is created and is yet to be submitted to the connector
+    #[default]
+    Pending,
+    /// The payout is submitted to the connector and is awaiting a result
+    Initiated,
+    Success,
+    Failed,
+    Cancelled,
+    /// The payout succeeded but the amount was returned by the bank of the recipient
+    Reversed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutType {
+    Card,
+    #[default]
+    Bank,
+}
+
+/// The role of a dashboard user for a merchant account.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DashboardRole {
+    /// Manages the merchant account, including the roles of other users
+    Admin,
+    /// Manages the API keys, connectors and webhooks of the merchant account
+    Developer,
+    /// Manages the payments and refunds of the merchant account
+    Operations,
+    /// Views the payments, refunds and other resources of the merchant account
+    #[default]
+    ReadOnly,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentLinkStatus {
+    #[default]
+    Active,
+    Used,
+    Expired,
+}
+
+/// The status of a source funding a part of the amount of a split tender payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FundingSourceStatus {
+    /// The funding source is yet to be charged
+    #[default]
+    Started,
+    /// The amount was authorized on the funding source
+    Authorized,
+    /// The amount was charged to (or redeemed from) the funding source
+    Charged,
+    /// The funding source could not be charged
+    Failed,
+    /// The amount charged to the funding source was reversed, since the payment failed
+    RolledBack,
+}
+
+/// The status of the share of a split payment which goes to a sub-merchant of the platform
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SplitStatus {
+    /// The payment is yet to succeed
+    #[default]
+    Pending,
+    /// The payment succeeded, and the share is to be transferred to the sub-merchant
+    Settled,
+    /// The payment failed or was cancelled, so nothing is transferred to the sub-merchant
+    Cancelled,
+}
+
+/// The event which a balance transaction records in the ledger of the merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BalanceTransactionType {
+    /// The amount of a payment was authorized, and is pending until captured
+    Authorization,
+    /// The authorized amount of a payment which was not captured was released
+    AuthorizationRelease,
+    /// The amount of a payment was captured, and is available to the merchant
+    Capture,
+    /// A payment was refunded to the customer
+    Refund,
+    /// A dispute of a payment was lost, and the disputed amount was returned to the customer
+    Dispute,
+    /// Funds were paid out of the account of the merchant
+    Payout,
+}
+
+/// An account of the ledger of the merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum LedgerAccount {
+    /// Funds outside the ledger, such as those of customers and payout recipients
+    External,
+    /// Funds authorized for payments which are yet to be captured
+    Pending,
+    /// Funds captured for payments, available to the merchant
+    Available,
+}
+
+/// What caused a change in the status of a payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentEventSource {
+    /// A request made to the API
+    Api,
+    /// A webhook received from the connector
+    Webhook,
+    /// A task run by the scheduler
+    Scheduler,
+}
+
+/// The result of reconciling a line of a settlement report of a connector against the payments
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconStatus {
+    /// The payment was settled with the amount and currency it was processed with
+    Matched,
+    /// The payment was settled with a different amount than it was processed with
+    AmountMismatch,
+    /// The payment was settled in a different currency than it was processed in
+    CurrencyMismatch,
+    /// No payment was processed with the connector transaction ID of the settled transaction
+    TransactionMissing,
+}
+
+/// The reconciliation status of a payment against the settlement reports of its connector
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentReconStatus {
+    /// The payment has not appeared in any settlement report yet
+    NotSettled,
+    /// Every settlement of the payment matches the payment
+    Matched,
+    /// A settlement of the payment does not match the payment
+    Mismatched,
+}
+
+/// The result of the verification of the billing address of the customer by the issuer
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AvsResult {
+    /// Both the street address and the postal code match
+    FullMatch,
+    /// The street address matches, but the postal code does not
+    AddressMatch,
+    /// The postal code matches, but the street address does not
+    PostalCodeMatch,
+    /// Neither the street address nor the postal code match
+    NoMatch,
+    /// The address could not be verified by the issuer
+    Unavailable,
+}
+
+/// The result of the verification of the card security code (CVC) by the issuer
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CvcResult {
+    /// The card security code matches
+    Match,
+    /// The card security code does not match
+    NoMatch,
+    /// The card security code was not verified by the issuer
+    NotProcessed,
+    /// The issuer does not support the verification of the card security code
+    Unavailable,
+}
+
+/// The verdict of the external fraud check provider on a payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FraudCheckVerdict {
+    /// The payment is not suspected to be fraudulent
+    Accept,
+    /// The payment is to be reviewed by the merchant
+    Review,
+    /// The payment is suspected to be fraudulent
+    Decline,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -561,11 +1044,14 @@ pub enum Connector {
     Bluesnap,
     Braintree,
     Checkout,
+    Coinbase,
     Cybersource,
     #[default]
     Dummy,
     Bambora,
     Dlocal,
+    Dummyconnector,
+    Externalprocessor,
     Fiserv,
     Globalpay,
     Klarna,
@@ -575,6 +1061,7 @@ pub enum Connector {
     Paypal,
     Payu,
     Rapyd,
+    Razorpay,
     Shift4,
     Stripe,
     Worldline,
@@ -618,8 +1105,11 @@ pub enum RoutableConnectors {
     Bluesnap,
     Braintree,
     Checkout,
+    Coinbase,
     Cybersource,
     Dlocal,
+    Dummyconnector,
+    Externalprocessor,
     Fiserv,
     Globalpay,
     Klarna,
@@ -629,6 +1119,7 @@ pub enum RoutableConnectors {
     Paypal,
     Payu,
     Rapyd,
+    Razorpay,
     Shift4,
     Stripe,
     Trustpay,
@@ -719,6 +1210,15 @@ pub enum BankNames {
     VolksbankGruppe,
     VolkskreditbankAg,
     VrBankBraunau,
+    AxisBank,
+    BankOfBaroda,
+    HdfcBank,
+    IciciBank,
+    IndusindBank,
+    KotakMahindraBank,
+    PunjabNationalBank,
+    StateBankOfIndia,
+    YesBank,
 }
 
 #[derive(
@@ -751,13 +1251,17 @@ pub enum CardNetwork {
 impl From<AttemptStatus> for IntentStatus {
     fn from(s: AttemptStatus) -> Self {
         match s {
-            AttemptStatus::Charged | AttemptStatus::AutoRefunded => Self::Succeeded,
+            AttemptStatus::Charged
+            | AttemptStatus::PartiallyCaptured
+            | AttemptStatus::AutoRefunded
+            | AttemptStatus::Overpaid => Self::Succeeded,
 
             AttemptStatus::ConfirmationAwaited => Self::RequiresConfirmation,
             AttemptStatus::PaymentMethodAwaited => Self::RequiresPaymentMethod,
 
             AttemptStatus::Authorized => Self::RequiresCapture,
             AttemptStatus::AuthenticationPending => Self::RequiresCustomerAction,
+            AttemptStatus::ManualReview => Self::RequiresMerchantAction,
 
             AttemptStatus::PartialCharged
             | AttemptStatus::Started
@@ -766,6 +1270,8 @@ impl From<AttemptStatus> for IntentStatus {
             | AttemptStatus::CodInitiated
             | AttemptStatus::VoidInitiated
             | AttemptStatus::CaptureInitiated
+            | AttemptStatus::DebitInitiated
+            | AttemptStatus::Underpaid
             | AttemptStatus::Pending => Self::Processing,
 
             AttemptStatus::AuthenticationFailed
@@ -861,3 +1367,173 @@ pub enum FrmPreferredFlowTypes {
     Pre,
     Post,
 }
+
+/// The outcome of a 3DS 2.0 authentication, as reported in the ARes or the RReq
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+pub enum ThreeDsTransStatus {
+    /// Authentication successful
+    #[serde(rename = "Y")]
+    #[strum(serialize = "Y")]
+    Success,
+    /// Not authenticated, the transaction has been denied
+    #[serde(rename = "N")]
+    #[strum(serialize = "N")]
+    Failure,
+    /// Authentication could not be performed due to a technical or other problem
+    #[serde(rename = "U")]
+    #[strum(serialize = "U")]
+    VerificationNotPerformed,
+    /// Authentication was not performed, but a proof of the authentication attempt was provided
+    #[serde(rename = "A")]
+    #[strum(serialize = "A")]
+    AttemptsProcessingPerformed,
+    /// Additional authentication is required through a challenge
+    #[serde(rename = "C")]
+    #[strum(serialize = "C")]
+    ChallengeRequired,
+    /// Additional authentication is required through a decoupled challenge
+    #[serde(rename = "D")]
+    #[strum(serialize = "D")]
+    ChallengeRequiredDecoupled,
+    /// Authentication rejected by the issuer, the transaction should not be authorized
+    #[serde(rename = "R")]
+    #[strum(serialize = "R")]
+    Rejected,
+    /// Informational only, the requestor's challenge preference has been acknowledged
+    #[serde(rename = "I")]
+    #[strum(serialize = "I")]
+    InformationOnly,
+}
+
+/// The channel through which the device data for a 3DS 2.0 authentication was collected
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ThreeDsDeviceChannel {
+    /// Device data was collected in the browser through the 3DS method
+    Browser,
+    /// Device data was collected in the app through the 3DS SDK
+    App,
+}
+
+/// Error codes shared across connectors, to which the error codes of the connectors are mapped
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum UnifiedCode {
+    /// The account of the customer does not have sufficient funds
+    InsufficientFunds,
+    /// The issuer declined the payment without a specific reason
+    DoNotHonor,
+    /// The card has expired
+    ExpiredCard,
+    /// The security code of the card is incorrect
+    IncorrectCvc,
+    /// The billing address does not match the address on file with the issuer
+    IncorrectAddress,
+    /// The card number is incorrect
+    InvalidCardNumber,
+    /// The card was reported lost or stolen
+    LostOrStolenCard,
+    /// The card or the type of payment is not supported
+    CardNotSupported,
+    /// The customer has exceeded the amount or frequency limits of the card
+    LimitExceeded,
+    /// The payment was declined as suspected fraud
+    FraudSuspected,
+    /// The payment requires the authentication of the customer
+    AuthenticationRequired,
+    /// The issuer could not be reached
+    IssuerUnavailable,
+    /// The connector failed to process the payment
+    ProcessingError,
+    /// The request sent to the connector was invalid
+    InvalidRequest,
+    /// The payment was declined for a reason which is not mapped to a specific code
+    GenericDecline,
+}
+
+impl UnifiedCode {
+    /// The message of the unified code, which is safe to show to the customer
+    pub fn get_unified_message(&self) -> &'static str {
+        match self {
+            Self::InsufficientFunds => "Your card has insufficient funds.",
+            Self::DoNotHonor | Self::GenericDecline => "Your card was declined.",
+            Self::ExpiredCard => "Your card has expired.",
+            Self::IncorrectCvc => "Your card's security code is incorrect.",
+            Self::IncorrectAddress => "Your billing address does not match the card.",
+            Self::InvalidCardNumber => "Your card number is incorrect.",
+            Self::LostOrStolenCard => "Your card was declined, please contact your bank.",
+            Self::CardNotSupported => "Your card does not support this type of purchase.",
+            Self::LimitExceeded => "Your card has exceeded its limit.",
+            Self::FraudSuspected => "Your payment was declined as it was flagged as suspicious.",
+            Self::AuthenticationRequired => "Your card requires authentication for this payment.",
+            Self::IssuerUnavailable => "Your bank could not be reached, please try again later.",
+            Self::ProcessingError => "An error occurred while processing your card.",
+            Self::InvalidRequest => "The payment could not be processed.",
+        }
+    }
+}
+
+/// The kind of input a required field of a payment method takes, so that the field can be rendered
+/// in a form
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FieldType {
+    /// The full name of the customer
+    UserFullName,
+    /// The email address of the customer
+    UserEmailAddress,
+    /// The country of the customer, as an ISO 3166-1 alpha-2 code
+    UserCountry,
+    /// The preferred language of the customer, as an ISO 639-1 code
+    UserLanguagePreference,
+    /// The bank of the customer, out of the banks listed for the payment method type
+    UserBank,
+}
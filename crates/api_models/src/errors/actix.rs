@@ -15,8 +15,10 @@ impl actix_web::ResponseError for ApiErrorResponse {
             Self::NotImplemented(_) => StatusCode::NOT_IMPLEMENTED,
             Self::ConnectorError(_, code) => *code,
             Self::MethodNotAllowed(_) => StatusCode::METHOD_NOT_ALLOWED,
+            Self::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
             Self::NotFound(_) => StatusCode::NOT_FOUND,
             Self::BadRequest(_) => StatusCode::BAD_REQUEST,
+            Self::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 
@@ -82,6 +82,8 @@ pub enum ApiErrorResponse {
     NotFound(ApiError),
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
+    ServiceUnavailable(ApiError),
+    TooManyRequests(ApiError),
 }
 
 impl ::core::fmt::Display for ApiErrorResponse {
@@ -110,6 +112,8 @@ impl ApiErrorResponse {
             | Self::NotFound(i)
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
+            | Self::ServiceUnavailable(i)
+            | Self::TooManyRequests(i)
             | Self::ConnectorError(i, _) => i,
         }
     }
@@ -125,8 +129,9 @@ impl ApiErrorResponse {
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
             | Self::NotFound(_)
-            | Self::BadRequest(_) => "invalid_request",
-            Self::InternalServerError(_) => "api",
+            | Self::BadRequest(_)
+            | Self::TooManyRequests(_) => "invalid_request",
+            Self::InternalServerError(_) | Self::ServiceUnavailable(_) => "api",
             Self::ConnectorError(_, _) => "connector",
         }
     }
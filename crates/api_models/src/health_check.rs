@@ -0,0 +1,29 @@
+/// Whether the router or one of its dependencies is available.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct DependencyHealth {
+    /// Whether the dependency could be reached
+    pub status: HealthStatus,
+    /// Time (in milliseconds) taken to check the dependency
+    pub latency: u64,
+    /// Why the dependency could not be reached
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ReadinessResponse {
+    /// Up only if all dependencies of the router are up
+    pub status: HealthStatus,
+    pub database: DependencyHealth,
+    pub redis: DependencyHealth,
+    /// Checked only if a canary connector is configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canary_connector: Option<DependencyHealth>,
+}
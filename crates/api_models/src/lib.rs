@@ -1,17 +1,29 @@
 #![forbid(unsafe_code)]
 pub mod admin;
 pub mod api_keys;
+pub mod balance;
 pub mod bank_accounts;
+pub mod business_profile;
 pub mod cards_info;
 pub mod customers;
+pub mod dead_letter;
 pub mod disputes;
+pub mod dummy_connector;
 pub mod enums;
 #[cfg(feature = "errors")]
 pub mod errors;
 pub mod files;
+pub mod health_check;
 pub mod mandates;
+pub mod organization;
+pub mod payment_links;
 pub mod payment_methods;
+pub mod payment_schedules;
 pub mod payments;
 pub mod payouts;
+pub mod recon;
 pub mod refunds;
+pub mod routing;
+pub mod user;
+pub mod vault;
 pub mod webhooks;
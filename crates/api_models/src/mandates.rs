@@ -1,5 +1,7 @@
+use common_utils::custom_serde;
 use masking::Secret;
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
 use crate::{enums as api_enums, payments};
@@ -60,3 +62,48 @@ pub struct MandateCardDetails {
     /// A unique identifier alias to identify a particular card
     pub card_fingerprint: Option<Secret<String>>,
 }
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MandateListConstraints {
+    /// The identifier for the customer
+    pub customer_id: Option<String>,
+    /// The status of the mandate
+    #[schema(value_type = Option<MandateStatus>)]
+    pub mandate_status: Option<api_enums::MandateStatus>,
+    /// The connector linked to the mandate
+    pub connector: Option<String>,
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The time at which the mandate was created
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub created_time: Option<PrimitiveDateTime>,
+    /// Time less than the mandate created time
+    #[serde(
+        default,
+        rename = "created_time.lt",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub created_time_lt: Option<PrimitiveDateTime>,
+    /// Time greater than the mandate created time
+    #[serde(
+        default,
+        rename = "created_time.gt",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub created_time_gt: Option<PrimitiveDateTime>,
+    /// Time less than or equals to the mandate created time
+    #[serde(
+        default,
+        rename = "created_time.lte",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub created_time_lte: Option<PrimitiveDateTime>,
+    /// Time greater than or equals to the mandate created time
+    #[serde(
+        default,
+        rename = "created_time.gte",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub created_time_gte: Option<PrimitiveDateTime>,
+}
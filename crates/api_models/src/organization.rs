@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OrganizationCreateRequest {
+    /// Name of the organization
+    #[schema(max_length = 255, example = "NewAge Retail Group")]
+    pub organization_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct OrganizationResponse {
+    /// The identifier for the organization
+    #[schema(max_length = 64, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub organization_id: String,
+
+    /// Name of the organization
+    #[schema(max_length = 255, example = "NewAge Retail Group")]
+    pub organization_name: Option<String>,
+
+    /// The time at which the organization was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the organization was last modified
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OrganizationPaymentsAggregateConstraints {
+    /// Only payments created at or after this time are aggregated
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        rename = "created.gte"
+    )]
+    pub created_gte: Option<PrimitiveDateTime>,
+
+    /// Only payments created before this time are aggregated
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        rename = "created.lt"
+    )]
+    pub created_lt: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct PaymentsAggregate {
+    /// The currency of the payments
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The status of the payments
+    #[schema(value_type = IntentStatus, example = "succeeded")]
+    pub status: api_enums::IntentStatus,
+
+    /// The number of payments
+    #[schema(example = 42)]
+    pub count: i64,
+
+    /// The total amount of the payments, in the lowest denomination of the currency
+    #[schema(example = 420000)]
+    pub total_amount: i64,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct OrganizationPaymentsAggregateResponse {
+    /// The identifier for the organization
+    #[schema(max_length = 64, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub organization_id: String,
+
+    /// The number of merchant accounts of the organization whose payments were aggregated
+    #[schema(example = 3)]
+    pub merchant_count: usize,
+
+    /// The payments of the merchant accounts of the organization, grouped by currency and status
+    pub aggregates: Vec<PaymentsAggregate>,
+}
@@ -0,0 +1,117 @@
+use common_utils::{custom_serde, pii};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::{enums as api_enums, payments};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaymentLinkId {
+    pub link_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkCreateRequest {
+    /// The amount charged for every payment made through the link, in the lowest denomination of
+    /// the currency
+    #[schema(minimum = 1, example = 6540)]
+    pub amount: i64,
+
+    /// The three-letter ISO currency code of the amount
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// A description shown on the checkout page, and passed on to every payment made through the
+    /// link
+    #[schema(example = "Concert ticket")]
+    pub description: Option<String>,
+
+    /// The URL to redirect the customer to after a payment made through the link is completed
+    #[schema(value_type = Option<String>, example = "https://hyperswitch.io")]
+    pub return_url: Option<url::Url>,
+
+    /// Whether the link can be used for more than one successful payment, defaults to false
+    #[schema(default = false, example = false)]
+    pub reusable: Option<bool>,
+
+    /// Time (in seconds) after which the link expires. The link does not expire if not provided
+    #[schema(minimum = 1, example = 86400)]
+    pub expires_in: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct PaymentLinkResponse {
+    /// The identifier for the payment link
+    #[schema(example = "plink_mbabizu24mvu3mela5njyhpit4")]
+    pub link_id: String,
+
+    /// The URL of the checkout page of the link, to be shared with customers
+    #[schema(
+        example = "https://sandbox.hyperswitch.io/payment_links/plink_mbabizu24mvu3mela5njyhpit4/checkout"
+    )]
+    pub url: String,
+
+    /// The amount charged for every payment made through the link
+    pub amount: i64,
+
+    /// The three-letter ISO currency code of the amount
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// A description of the link
+    pub description: Option<String>,
+
+    /// The URL to redirect the customer to after a payment made through the link is completed
+    pub return_url: Option<String>,
+
+    /// Whether the link can be used for more than one successful payment
+    pub reusable: bool,
+
+    /// The status of the payment link
+    #[schema(value_type = PaymentLinkStatus, example = "active")]
+    pub status: api_enums::PaymentLinkStatus,
+
+    /// The number of payments made through the link
+    pub usage_count: i32,
+
+    /// The identifier for the most recent payment made through the link
+    pub last_payment_id: Option<String>,
+
+    /// The time at which the link expires
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+
+    /// The time at which the link was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkPayRequest {
+    /// The identifier for the payment link, taken from the path of the request
+    #[serde(skip_deserializing)]
+    pub link_id: String,
+
+    /// The payment method that is to be used
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: api_enums::PaymentMethod,
+
+    /// Payment Method Type
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// The payment method information provided for making the payment
+    pub payment_method_data: payments::PaymentMethodData,
+
+    /// The customer's email address
+    #[schema(max_length = 255, value_type = Option<String>, example = "johntest@test.com")]
+    pub email: Option<Secret<String, pii::Email>>,
+
+    /// Additional details about the browser of the customer, required for 3DS authentication
+    pub browser_info: Option<serde_json::Value>,
+}
@@ -196,6 +196,24 @@ pub struct ResponsePaymentMethodTypes {
 
     /// The list of banks enabled, if applicable for a payment method type
     pub bank_names: Option<Vec<BankCodeResponse>>,
+
+    /// The fields of the payment method data which the eligible connectors require, if any
+    pub required_fields: Option<Vec<RequiredFieldInfo>>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
+pub struct RequiredFieldInfo {
+    /// The path of the field in the payments request
+    #[schema(example = "payment_method_data.bank_redirect.ideal.bank_name")]
+    pub required_field: String,
+
+    /// The name of the field to be displayed to the customer
+    #[schema(example = "Bank")]
+    pub display_name: String,
+
+    /// The kind of input the field takes
+    #[schema(value_type = FieldType, example = "user_bank")]
+    pub field_type: api_enums::FieldType,
 }
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -468,12 +486,30 @@ pub struct PaymentMethodDeleteResponse {
     pub deleted: bool,
 }
 
+/// The request body for saving a card of a customer, which can be used in future payments of the
+/// customer.
+#[derive(Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerPaymentMethodCreate {
+    /// The card to be saved for the customer
+    pub card: CardDetail,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
 #[derive(Debug, serde::Serialize, ToSchema)]
 pub struct CustomerPaymentMethod {
     /// Token for payment method in temporary card locker which gets refreshed often
     #[schema(example = "7ebf443f-a050-4067-84e5-e6f6d4800aef")]
     pub payment_token: String,
 
+    /// The unique identifier of the saved payment method, which can be passed as the
+    /// `payment_method_id` in payments of the customer
+    #[schema(example = "vault_tok_2Lg2RpL2BTCcZBS4R4xZ")]
+    pub payment_method_id: String,
+
     /// The unique identifier of the customer.
     #[schema(example = "cus_meowerunwiuwiwqw")]
     pub customer_id: String,
@@ -0,0 +1,107 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaymentScheduleId {
+    pub schedule_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentScheduleCreateRequest {
+    /// The identifier for the customer whose mandate is charged
+    #[schema(max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+
+    /// The identifier for the mandate charged on every cycle of the schedule
+    #[schema(example = "mandate_iwer89rnjef349dni3")]
+    pub mandate_id: String,
+
+    /// The amount charged on every cycle, in the lowest denomination of the currency
+    #[schema(minimum = 1, example = 6540)]
+    pub amount: i64,
+
+    /// The three-letter ISO currency code of the amount
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The unit of time between two cycles of the schedule
+    #[schema(value_type = PaymentScheduleInterval, example = "month")]
+    pub interval: api_enums::PaymentScheduleInterval,
+
+    /// The number of intervals between two cycles of the schedule, defaults to 1
+    #[schema(minimum = 1, example = 1)]
+    pub interval_count: Option<i32>,
+
+    /// The time at which the first cycle is charged, defaults to the current time
+    #[schema(example = "2023-04-21T11:00:00Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub start_at: Option<PrimitiveDateTime>,
+
+    /// The number of cycles after which the schedule is completed. The schedule runs until
+    /// cancelled if not provided
+    #[schema(minimum = 1, example = 12)]
+    pub total_cycles: Option<i32>,
+
+    /// A description of the schedule, passed on to every payment created by it
+    #[schema(example = "Monthly subscription")]
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct PaymentScheduleResponse {
+    /// The identifier for the payment schedule
+    #[schema(example = "sch_mbabizu24mvu3mela5njyhpit4")]
+    pub schedule_id: String,
+
+    /// The identifier for the customer whose mandate is charged
+    pub customer_id: String,
+
+    /// The identifier for the mandate charged on every cycle of the schedule
+    pub mandate_id: String,
+
+    /// The amount charged on every cycle, in the lowest denomination of the currency
+    pub amount: i64,
+
+    /// The three-letter ISO currency code of the amount
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The unit of time between two cycles of the schedule
+    #[schema(value_type = PaymentScheduleInterval, example = "month")]
+    pub interval: api_enums::PaymentScheduleInterval,
+
+    /// The number of intervals between two cycles of the schedule
+    pub interval_count: i32,
+
+    /// The status of the payment schedule
+    #[schema(value_type = PaymentScheduleStatus, example = "active")]
+    pub status: api_enums::PaymentScheduleStatus,
+
+    /// The number of cycles after which the schedule is completed
+    pub total_cycles: Option<i32>,
+
+    /// The number of cycles charged successfully
+    pub completed_cycles: i32,
+
+    /// The number of cycles which could not be charged
+    pub failed_cycles: i32,
+
+    /// The time at which the next cycle is charged
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub next_payment_at: Option<PrimitiveDateTime>,
+
+    /// The identifier for the payment created by the most recent cycle
+    pub last_payment_id: Option<String>,
+
+    /// A description of the schedule
+    pub description: Option<String>,
+
+    /// The time at which the payment schedule was created
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -1,6 +1,6 @@
 use std::num::NonZeroI64;
 
-use common_utils::pii;
+use common_utils::{pii, types::MinorUnit};
 use masking::{PeekInterface, Secret};
 use router_derive::Setter;
 use time::PrimitiveDateTime;
@@ -147,6 +147,11 @@ pub struct PaymentsRequest {
     #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
     pub payment_token: Option<String>,
 
+    /// The identifier of a payment method saved for the customer, to be used for the payment in
+    /// place of the payment method data
+    #[schema(example = "vault_tok_2Lg2RpL2BTCcZBS4R4xZ")]
+    pub payment_method_id: Option<String>,
+
     /// This is used when payment is to be confirmed and the card is not saved
     #[schema(value_type = Option<String>)]
     pub card_cvc: Option<Secret<String>>,
@@ -179,7 +184,7 @@ pub struct PaymentsRequest {
     #[schema(max_length = 255, example = "mandate_iwer89rnjef349dni3")]
     pub mandate_id: Option<String>,
 
-    /// Additional details required by 3DS 2.0
+    /// Additional details required by 3DS 2.0. The `ip_address` and `ip_country` of the customer are used by the fraud rules of the merchant as well
     #[schema(value_type = Option<Object>, example = r#"{
         "user_agent": "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/70.0.3538.110 Safari/537.36",
         "accept_header": "text/html,application/xhtml+xml,application/xml;q=0.9,image/webp,image/apng,*/*;q=0.8",
@@ -203,6 +208,63 @@ pub struct PaymentsRequest {
 
     /// Merchant connector details used to make payments.
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
+
+    /// Time (in seconds) after which the payment expires, if it has not reached a terminal state. Uncaptured authorizations are voided on expiry.
+    #[schema(example = 900)]
+    pub session_expiry: Option<u32>,
+
+    /// Time (in seconds) after authorization at which a payment with `capture_method` set to `manual` is captured automatically, unless it is captured or cancelled before. Defaults to the capture delay of the business profile of the payment.
+    #[schema(example = 7200)]
+    pub auto_capture_delay: Option<u32>,
+
+    /// Stores the requests made to connectors for the payment, along with their responses, with personal and card data masked. The stored requests can be viewed through the connector logs of the payment, to debug the integration with a connector.
+    #[schema(example = false)]
+    pub connector_debug_mode: Option<bool>,
+
+    /// The line items of the order, required by Buy Now Pay Later providers (e.g. Klarna). The sum of the amounts of the line items must be equal to the amount of the payment, or to the subtotal of the amount breakdown if it is provided, if the amounts are provided.
+    pub order_details: Option<Vec<OrderDetails>>,
+
+    /// The breakdown of the amount of the payment into the subtotal, tax, shipping and discount of the order. The breakdown must add up to the amount of the payment.
+    pub amount_breakdown: Option<AmountBreakdown>,
+
+    /// Level 2 and level 3 data of card payments made with commercial and purchasing cards, which qualify the payment for lower interchange rates with connectors supporting it.
+    pub l2_l3_data: Option<L2L3Data>,
+
+    /// A gift card to be redeemed towards the payment. The balance of the gift card is redeemed first, and the remaining amount is charged to the payment method provided in `payment_method_data`.
+    pub gift_card_data: Option<GiftCardData>,
+
+    /// The business profile of the merchant to make the payment with. The return URL, statement descriptors, routing algorithm and webhook details of the profile are used when they are not provided for the payment. This can only be set while creating the payment.
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: Option<String>,
+
+    /// The shares of the payment which go to sub-merchants of the platform, when a platform makes the payment on behalf of its sub-merchants. The remaining amount is retained by the platform as its fee. This can only be set while creating the payment, and is supported only by some connectors.
+    pub split_payments: Option<Vec<PaymentSplit>>,
+}
+
+/// The share of a payment which goes to a sub-merchant of the platform
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentSplit {
+    /// The merchant account of the sub-merchant, which must be a sub-merchant of the platform
+    #[schema(max_length = 255, example = "merchant_1668273825")]
+    pub sub_merchant_id: String,
+
+    /// The account of the sub-merchant with the connector, to which the share is transferred
+    #[schema(max_length = 255, example = "acct_1032D82eZvKYlo2C")]
+    pub connector_account_id: String,
+
+    /// The share of the amount of the payment which goes to the sub-merchant
+    pub split: SplitAmount,
+}
+
+/// The share of the amount of a payment, either as a fixed amount or as a percentage of the amount
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SplitAmount {
+    /// A fixed amount, in the lowest denomination of the currency
+    Fixed { amount: i64 },
+    /// A percentage of the amount of the payment, in basis points (1/100th of a percent)
+    Percentage { basis_points: i64 },
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq)]
@@ -227,6 +289,18 @@ impl From<i64> for Amount {
     }
 }
 
+impl From<Amount> for MinorUnit {
+    fn from(amount: Amount) -> Self {
+        Self::new(amount.into())
+    }
+}
+
+impl From<MinorUnit> for Amount {
+    fn from(amount: MinorUnit) -> Self {
+        amount.get_amount_as_i64().into()
+    }
+}
+
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct PaymentsRedirectRequest {
@@ -288,6 +362,9 @@ pub enum MandateTxnType {
 pub struct MandateIds {
     pub mandate_id: String,
     pub connector_mandate_id: Option<String>,
+    /// The network transaction id of the payment which set up the mandate, referenced by the
+    /// merchant initiated payments made on the mandate
+    pub network_transaction_id: Option<String>,
 }
 
 impl MandateIds {
@@ -295,6 +372,7 @@ impl MandateIds {
         Self {
             mandate_id,
             connector_mandate_id: None,
+            network_transaction_id: None,
         }
     }
 }
@@ -399,7 +477,9 @@ pub struct Card {
     #[schema(example = "chase")]
     pub card_issuer: Option<String>,
 
-    /// The card network for the card
+    /// The card network to process the payment with. Co-badged cards, which belong to a local
+    /// network as well as a global one, are processed with the network selected by the customer,
+    /// which must be one of the networks of the card.
     #[schema(value_type = Option<CardNetwork>, example = "Visa")]
     pub card_network: Option<api_enums::CardNetwork>,
 }
@@ -441,6 +521,10 @@ pub enum PaymentMethodData {
     Wallet(WalletData),
     PayLater(PayLaterData),
     BankRedirect(BankRedirectData),
+    BankDebit(BankDebitData),
+    Upi(UpiData),
+    Crypto(CryptoData),
+    GiftCard(Box<GiftCardData>),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -455,6 +539,10 @@ pub enum AdditionalPaymentData {
     },
     Wallet {},
     PayLater {},
+    BankDebit {},
+    Upi {},
+    Crypto {},
+    GiftCard {},
 }
 
 impl From<&PaymentMethodData> for AdditionalPaymentData {
@@ -474,10 +562,17 @@ impl From<&PaymentMethodData> for AdditionalPaymentData {
                 BankRedirectData::Ideal { bank_name, .. } => Self::BankRedirect {
                     bank_name: Some(bank_name.to_owned()),
                 },
+                BankRedirectData::Netbanking { bank_name } => Self::BankRedirect {
+                    bank_name: Some(bank_name.to_owned()),
+                },
                 _ => Self::BankRedirect { bank_name: None },
             },
             PaymentMethodData::Wallet(_) => Self::Wallet {},
             PaymentMethodData::PayLater(_) => Self::PayLater {},
+            PaymentMethodData::BankDebit(_) => Self::BankDebit {},
+            PaymentMethodData::Upi(_) => Self::Upi {},
+            PaymentMethodData::Crypto(_) => Self::Crypto {},
+            PaymentMethodData::GiftCard(_) => Self::GiftCard {},
         }
     }
 }
@@ -514,6 +609,122 @@ pub enum BankRedirectData {
         #[schema(example = "en")]
         preferred_language: String,
     },
+    /// Payment Method data for netbanking, where the customer is redirected to the internet
+    /// banking portal of their bank in India
+    Netbanking {
+        /// The hyperswitch bank code for netbanking
+        #[schema(value_type = BankNames, example = "hdfc_bank")]
+        bank_name: api_enums::BankNames,
+    },
+    /// Payment Method data for BLIK, where the customer authorizes the payment in their banking
+    /// app using a one time code
+    Blik {
+        /// The six digit code generated in the banking app of the customer
+        #[schema(value_type = String, example = "777123")]
+        blik_code: Secret<String>,
+    },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BankDebitData {
+    /// Payment Method data for ACH bank debit
+    AchBankDebit {
+        /// The billing details for bank debit
+        billing_details: BankDebitBilling,
+
+        /// The account number of the customer's bank account
+        #[schema(value_type = String, example = "000123456789")]
+        account_number: Secret<String>,
+
+        /// The routing number of the customer's bank
+        #[schema(value_type = String, example = "110000000")]
+        routing_number: Secret<String>,
+
+        /// How the ownership of the bank account is to be verified
+        #[serde(default)]
+        #[schema(value_type = BankDebitVerificationMethod, example = "microdeposits")]
+        verification_method: api_enums::BankDebitVerificationMethod,
+    },
+    /// Payment Method data for SEPA Direct Debit
+    SepaBankDebit {
+        /// The billing details for bank debit
+        billing_details: BankDebitBilling,
+
+        /// The International Bank Account Number of the customer's bank account
+        #[schema(value_type = String, example = "DE89370400440532013000")]
+        iban: Secret<String>,
+    },
+}
+
+impl BankDebitData {
+    pub fn get_billing_details(&self) -> &BankDebitBilling {
+        match self {
+            Self::AchBankDebit {
+                billing_details, ..
+            }
+            | Self::SepaBankDebit {
+                billing_details, ..
+            } => billing_details,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UpiData {
+    /// Payment Method data for UPI collect, where a payment request is sent to the UPI app of the
+    /// customer using their Virtual Payment Address
+    UpiCollect {
+        /// The Virtual Payment Address of the customer
+        #[schema(value_type = String, example = "successtest@iata")]
+        vpa_id: Secret<String, pii::UpiVpaMaskingStrategy>,
+    },
+    /// Payment Method data for UPI intent, where the customer pays using a UPI app on their
+    /// device, which is opened using the deep link returned in the next action
+    UpiIntent {},
+}
+
+/// Payment Method data for cryptocurrencies, where the customer chooses the cryptocurrency and
+/// pays on the hosted payment page of the connector
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct CryptoData {}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GiftCardData {
+    /// Payment Method data for Givex gift cards
+    Givex(GiftCardDetails),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GiftCardDetails {
+    /// The number of the gift card
+    #[schema(value_type = String, example = "6036280000000000000")]
+    pub number: Secret<String>,
+
+    /// The security code of the gift card
+    #[schema(value_type = String, example = "123")]
+    pub cvc: Secret<String>,
+}
+
+impl GiftCardData {
+    pub fn get_details(&self) -> &GiftCardDetails {
+        match self {
+            Self::Givex(details) => details,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BankDebitBilling {
+    /// The name of the holder of the bank account
+    #[schema(value_type = String, example = "John Doe")]
+    pub name: Secret<String>,
+
+    /// The email of the holder of the bank account, to which the debit notifications are sent
+    #[schema(value_type = String, example = "john.doe@example.com")]
+    pub email: Secret<String, pii::Email>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -584,7 +795,7 @@ pub struct GpayTokenizationData {
 
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct ApplePayWalletData {
-    /// The payment data of Apple pay
+    /// The base64 encoded payment data of the Apple Pay token (PKPaymentToken)
     pub payment_data: String,
     /// The payment method of Apple pay
     pub payment_method: ApplepayPaymentMethod,
@@ -603,6 +814,17 @@ pub struct ApplepayPaymentMethod {
     pub pm_type: String,
 }
 
+/// Configuration for decrypting Apple Pay tokens in the router, read from the
+/// `apple_pay_decryption` key of the metadata of the merchant connector account. Apple Pay tokens
+/// are passed on to connectors which do not have it configured, for the connector to decrypt.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ApplePayDecryptionConfig {
+    /// The merchant identifier registered with Apple
+    pub merchant_identifier: String,
+    /// The private key of the payment processing certificate of the merchant, in PEM format
+    pub payment_processing_certificate_key: Secret<String>,
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, serde::Serialize)]
 pub struct CardResponse {
     last4: String,
@@ -610,6 +832,46 @@ pub struct CardResponse {
     exp_year: String,
 }
 
+/// The bank account of a bank debit, with only the last four digits of the account number
+#[derive(Eq, PartialEq, Clone, Debug, serde::Serialize)]
+pub struct BankDebitResponse {
+    last4: String,
+}
+
+/// The gift card of a payment, with only the last four digits of the gift card number
+#[derive(Eq, PartialEq, Clone, Debug, serde::Serialize)]
+pub struct GiftCardResponse {
+    last4: String,
+}
+
+impl From<GiftCardData> for GiftCardResponse {
+    fn from(gift_card_data: GiftCardData) -> Self {
+        let number = gift_card_data.get_details().number.peek();
+        Self {
+            last4: number
+                .get(number.len().saturating_sub(4)..)
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+}
+
+impl From<BankDebitData> for BankDebitResponse {
+    fn from(bank_debit_data: BankDebitData) -> Self {
+        let account_number = match bank_debit_data {
+            BankDebitData::AchBankDebit { account_number, .. } => account_number,
+            BankDebitData::SepaBankDebit { iban, .. } => iban,
+        };
+        let account_number = account_number.peek();
+        Self {
+            last4: account_number
+                .get(account_number.len().saturating_sub(4)..)
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, serde::Serialize)]
 pub enum PaymentMethodDataResponse {
     #[serde(rename = "card")]
@@ -620,6 +882,10 @@ pub enum PaymentMethodDataResponse {
     PayLater(PayLaterData),
     Paypal,
     BankRedirect(BankRedirectData),
+    BankDebit(BankDebitResponse),
+    Upi(UpiData),
+    Crypto(CryptoData),
+    GiftCard(GiftCardResponse),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -734,7 +1000,7 @@ pub struct PaymentsCaptureRequest {
     pub payment_id: Option<String>,
     /// The unique identifier for the merchant
     pub merchant_id: Option<String>,
-    /// The Amount to be captured/ debited from the user's payment method.
+    /// The Amount to be captured/ debited from the user's payment method. If this is less than the authorized amount, the payment is partially captured.
     pub amount_to_capture: Option<i64>,
     /// Decider to refund the uncaptured amount
     pub refund_uncaptured_amount: Option<bool>,
@@ -762,6 +1028,7 @@ pub enum NextActionType {
     DisplayQrCode,
     InvokeSdkClient,
     TriggerApi,
+    InvokeUpiIntent,
 }
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
 pub struct NextAction {
@@ -771,6 +1038,55 @@ pub struct NextAction {
     /// Contains the url for redirection flow
     #[schema(example = "https://router.juspay.io/redirect/fakushdfjlksdfasklhdfj")]
     pub redirect_to_url: Option<String>,
+    /// Contains the deep link which opens the UPI app of the customer, for the UPI intent flow
+    #[schema(example = "upi://pay?pa=merchant@bank&pn=Merchant&am=10.00&cu=INR")]
+    pub upi_intent_uri: Option<String>,
+    /// Contains the QR code to be displayed to the customer, for payment methods where the
+    /// customer completes the payment by scanning a QR code
+    pub qr_code_information: Option<QrCodeInformation>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct QrCodeInformation {
+    /// The image of the QR code, as a base64 encoded data URL
+    #[schema(example = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAAB")]
+    pub image_data_url: Option<String>,
+    /// The data encoded in the QR code, for rendering the QR code in the client or for being
+    /// copied by the customer
+    #[schema(example = "00020101021226880014br.gov.bcb.pix2566qrcodes-pix.example.com")]
+    pub qr_code_data: Option<String>,
+    /// The time at which the QR code expires, for rendering a countdown to the customer
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+/// The surcharge added to the amount of a payment by the surcharge rules of the merchant
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct SurchargeDetailsResponse {
+    /// The surcharge, in the lowest denomination of the currency
+    #[schema(example = 30)]
+    pub surcharge_amount: i64,
+    /// The amount processed by the connector, which is the amount of the payment and its
+    /// surcharge
+    #[schema(example = 6570)]
+    pub total_amount: i64,
+}
+
+/// The amount of a payment converted to the settlement currency of the merchant
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct SettlementDetailsResponse {
+    /// The amount processed by the connector converted to the settlement currency, in the lowest
+    /// denomination of the settlement currency
+    #[schema(example = 6040)]
+    pub settlement_amount: i64,
+    /// The currency the merchant settles payments in
+    #[schema(value_type = Currency, example = "EUR")]
+    pub settlement_currency: api_enums::Currency,
+    /// The exchange rate applied for converting the amount from the currency of the payment to
+    /// the settlement currency
+    #[schema(example = "0.92")]
+    pub fx_rate: String,
 }
 
 #[derive(Setter, Clone, Default, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
@@ -925,6 +1241,41 @@ pub struct PaymentsResponse {
     #[schema(example = "Failed while verifying the card")]
     pub error_message: Option<String>,
 
+    /// The code of the error received from the connector, unified across connectors
+    #[schema(value_type = Option<UnifiedCode>, example = "insufficient_funds")]
+    pub unified_code: Option<api_enums::UnifiedCode>,
+
+    /// The message of the unified code of the error, which is safe to be shown to the customer
+    #[schema(example = "Your card has insufficient funds.")]
+    pub unified_message: Option<String>,
+
+    /// The raw response of the connector, for merchants who opted in to receive it
+    #[schema(value_type = Option<Object>)]
+    #[auth_based]
+    pub connector_raw_response: Option<serde_json::Value>,
+
+    /// The result of the verification of the billing address by the issuer
+    #[schema(value_type = Option<AvsResult>, example = "full_match")]
+    pub avs_result: Option<api_enums::AvsResult>,
+
+    /// The result of the verification of the card security code by the issuer
+    #[schema(value_type = Option<CvcResult>, example = "match")]
+    pub cvc_result: Option<api_enums::CvcResult>,
+
+    /// The verdict of the external fraud check provider on the payment
+    #[schema(value_type = Option<FraudCheckVerdict>, example = "accept")]
+    pub fraud_check_verdict: Option<api_enums::FraudCheckVerdict>,
+
+    /// The risk score of the payment assigned by the external fraud check provider
+    #[schema(example = 20)]
+    pub fraud_check_score: Option<i32>,
+
+    /// The breakdown of the amount of the payment, if it was surcharged
+    pub surcharge_details: Option<SurchargeDetailsResponse>,
+
+    /// The amount of the payment in the settlement currency of the merchant, if it was converted
+    pub settlement_details: Option<SettlementDetailsResponse>,
+
     /// Payment Experience for the current payment
     #[schema(value_type = Option<PaymentExperience>, example = "redirect_to_url")]
     pub payment_experience: Option<api_enums::PaymentExperience>,
@@ -932,6 +1283,86 @@ pub struct PaymentsResponse {
     /// Payment Method Type
     #[schema(value_type = Option<PaymentMethodType>, example = "gpay")]
     pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// Time after which the payment expires, if it has not reached a terminal state
+    #[schema(example = "2022-09-10T10:26:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub session_expiry: Option<PrimitiveDateTime>,
+
+    /// Time (in seconds) after authorization at which the payment is captured automatically, if it is not captured or cancelled before
+    #[schema(example = 7200)]
+    pub auto_capture_delay: Option<u32>,
+
+    /// The line items of the order
+    pub order_details: Option<Vec<OrderDetails>>,
+
+    /// The breakdown of the amount of the payment
+    pub amount_breakdown: Option<AmountBreakdown>,
+
+    /// The level 2 and level 3 card data of the payment
+    pub l2_l3_data: Option<L2L3Data>,
+
+    /// The sources funding the payment, when the payment is paid partially with a gift card
+    pub funding_sources: Option<Vec<FundingSourceResponse>>,
+
+    /// The business profile the payment was made with
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: Option<String>,
+
+    /// The shares of the payment which go to sub-merchants of the platform, if the payment is split
+    pub split_payments: Option<Vec<PaymentSplitResponse>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct PaymentSplitResponse {
+    /// Unique identifier of the split
+    #[schema(example = "split_0cT2ZcZ9zvlLKtwpXL4W")]
+    pub split_id: String,
+
+    /// The merchant account of the sub-merchant the share goes to
+    #[schema(example = "merchant_1668273825")]
+    pub sub_merchant_id: String,
+
+    /// The account of the sub-merchant with the connector, to which the share is transferred
+    #[schema(example = "acct_1032D82eZvKYlo2C")]
+    pub connector_account_id: String,
+
+    /// The share of the sub-merchant, in the lowest denomination of the currency
+    #[schema(example = 5400)]
+    pub amount: i64,
+
+    /// The status of the split
+    #[schema(value_type = SplitStatus, example = "settled")]
+    pub status: api_enums::SplitStatus,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct FundingSourceResponse {
+    /// Unique identifier of the funding source
+    #[schema(example = "fs_0cT2ZcZ9zvlLKtwpXL4W")]
+    pub funding_source_id: String,
+
+    /// The payment method of the funding source
+    #[schema(value_type = PaymentMethod, example = "gift_card")]
+    pub payment_method: api_enums::PaymentMethod,
+
+    /// The payment method type of the funding source
+    #[schema(value_type = Option<PaymentMethodType>, example = "givex")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// The amount funded by the funding source, in the lowest denomination of the currency
+    #[schema(example = 1000)]
+    pub amount: i64,
+
+    /// The status of the funding source
+    #[schema(value_type = FundingSourceStatus, example = "charged")]
+    pub status: api_enums::FundingSourceStatus,
+
+    /// The error code received from the connector, if the funding source could not be charged
+    pub error_code: Option<String>,
+
+    /// The error message received from the connector, if the funding source could not be charged
+    pub error_message: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema)]
@@ -1001,6 +1432,141 @@ pub struct PaymentListResponse {
     pub data: Vec<PaymentsResponse>,
 }
 
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentAttemptResponse {
+    /// The identifier for the attempt
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub attempt_id: String,
+    /// The status of the attempt
+    #[schema(value_type = AttemptStatus, example = "charged")]
+    pub status: api_enums::AttemptStatus,
+    /// The amount of the attempt, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+    /// The currency of the amount of the attempt
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    /// The connector the attempt was routed through
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+    /// The identifier of the attempt at the connector
+    pub connector_transaction_id: Option<String>,
+    /// The payment method of the attempt
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+    /// The payment method type of the attempt
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    /// The code of the error received from the connector, if the attempt failed
+    #[schema(example = "E0001")]
+    pub error_code: Option<String>,
+    /// The message of the error received from the connector, if the attempt failed
+    #[schema(example = "Failed while verifying the card")]
+    pub error_message: Option<String>,
+    /// The code of the error received from the connector, unified across connectors
+    #[schema(value_type = Option<UnifiedCode>)]
+    pub unified_code: Option<api_enums::UnifiedCode>,
+    /// The reason provided when the attempt was cancelled
+    pub cancellation_reason: Option<String>,
+    /// The time at which the attempt was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time at which the attempt was last modified
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentAttemptListResponse {
+    /// The list of attempts made for the payment, ordered by the time of their creation
+    pub data: Vec<PaymentAttemptResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentEventResponse {
+    /// The identifier of the event
+    #[schema(example = "pevt_mbabizu24mvu3mela5njyhpit4")]
+    pub event_id: String,
+    /// The identifier of the attempt which was active when the status of the payment changed
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub attempt_id: String,
+    /// The operation which changed the status of the payment
+    #[schema(example = "PaymentConfirm")]
+    pub operation: String,
+    /// The status of the payment before the change
+    #[schema(value_type = IntentStatus, example = "processing")]
+    pub previous_status: api_enums::IntentStatus,
+    /// The status of the payment after the change
+    #[schema(value_type = IntentStatus, example = "succeeded")]
+    pub status: api_enums::IntentStatus,
+    /// The status of the attempt before the change
+    #[schema(value_type = AttemptStatus, example = "pending")]
+    pub previous_attempt_status: api_enums::AttemptStatus,
+    /// The status of the attempt after the change
+    #[schema(value_type = AttemptStatus, example = "charged")]
+    pub attempt_status: api_enums::AttemptStatus,
+    /// What caused the change
+    #[schema(value_type = PaymentEventSource, example = "api")]
+    pub source: api_enums::PaymentEventSource,
+    /// The merchant whose request, or the connector whose webhook, caused the change
+    #[schema(example = "stripe")]
+    pub actor: Option<String>,
+    /// The raw response received from the connector for the attempt
+    #[schema(value_type = Option<Object>)]
+    pub connector_response: Option<serde_json::Value>,
+    /// The time at which the change was recorded
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentEventListResponse {
+    /// The changes in the status of the payment, in the order they were made
+    pub data: Vec<PaymentEventResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectorLogResponse {
+    /// The identifier of the log
+    #[schema(example = "clog_mbabizu24mvu3mela5njyhpit4")]
+    pub log_id: String,
+    /// The identifier of the attempt for which the request was made
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub attempt_id: String,
+    /// The connector to which the request was made
+    #[schema(example = "stripe")]
+    pub connector: String,
+    /// The connector flow of the request
+    #[schema(example = "Authorize")]
+    pub flow: String,
+    /// The HTTP method of the request
+    #[schema(example = "POST")]
+    pub method: String,
+    /// The URL to which the request was made
+    #[schema(example = "https://api.stripe.com/v1/payment_intents")]
+    pub url: String,
+    /// The body of the request, with personal and card data masked
+    pub request_body: Option<String>,
+    /// The body of the response of the connector, with personal and card data masked. Not present if no response was received.
+    pub response_body: Option<String>,
+    /// The HTTP status code of the response of the connector. Not present if no response was received.
+    #[schema(example = 200)]
+    pub status_code: Option<u16>,
+    /// The time at which the request was made
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectorLogListResponse {
+    /// The requests made to connectors for the payment, in the order they were made
+    pub data: Vec<ConnectorLogResponse>,
+}
+
 #[derive(Setter, Clone, Default, Debug, Eq, PartialEq, serde::Serialize)]
 pub struct VerifyResponse {
     pub verify_id: Option<String>,
@@ -1125,6 +1691,14 @@ impl From<PaymentMethodData> for PaymentMethodDataResponse {
             PaymentMethodData::BankRedirect(bank_redirect_data) => {
                 Self::BankRedirect(bank_redirect_data)
             }
+            PaymentMethodData::BankDebit(bank_debit_data) => {
+                Self::BankDebit(BankDebitResponse::from(bank_debit_data))
+            }
+            PaymentMethodData::Upi(upi_data) => Self::Upi(upi_data),
+            PaymentMethodData::Crypto(crypto_data) => Self::Crypto(crypto_data),
+            PaymentMethodData::GiftCard(gift_card_data) => {
+                Self::GiftCard(GiftCardResponse::from(*gift_card_data))
+            }
         }
     }
 }
@@ -1179,6 +1753,92 @@ pub struct OrderDetails {
     /// The quantity of the product to be purchased
     #[schema(example = 1)]
     pub quantity: u16,
+    /// The price of a single unit of the product, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: Option<i64>,
+}
+
+impl OrderDetails {
+    /// The total amount of the line item, if the price of the product is provided
+    pub fn get_total_amount(&self) -> Option<i64> {
+        self.amount
+            .map(|amount| amount.saturating_mul(i64::from(self.quantity)))
+    }
+}
+
+/// The breakdown of the amount of a payment. The total amount of the order is the subtotal, with
+/// the tax and the shipping added and the discount subtracted.
+#[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AmountBreakdown {
+    /// The amount of the products of the order, before tax, shipping and discounts, in the lowest denomination of the currency
+    #[schema(example = 6000)]
+    pub subtotal: i64,
+    /// The tax on the order, in the lowest denomination of the currency
+    #[schema(example = 480)]
+    pub tax_amount: Option<i64>,
+    /// The shipping cost of the order, in the lowest denomination of the currency
+    #[schema(example = 500)]
+    pub shipping_amount: Option<i64>,
+    /// The discount on the order, in the lowest denomination of the currency
+    #[schema(example = 440)]
+    pub discount_amount: Option<i64>,
+}
+
+impl AmountBreakdown {
+    /// The total amount of the order, unless it overflows
+    pub fn get_total_amount(&self) -> Option<i64> {
+        self.subtotal
+            .checked_add(self.tax_amount.unwrap_or(0))?
+            .checked_add(self.shipping_amount.unwrap_or(0))?
+            .checked_sub(self.discount_amount.unwrap_or(0))
+    }
+}
+
+/// Level 2 and level 3 data of a card payment. Level 2 data identifies the tax on the payment and
+/// the reference of the customer, and level 3 data the line items of the purchase.
+#[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct L2L3Data {
+    /// The tax on the payment, in the lowest denomination of the currency
+    #[schema(example = 480)]
+    pub tax_amount: Option<i64>,
+    /// The reference of the customer for the purchase, such as a purchase order number
+    #[schema(max_length = 25, example = "PO-12345")]
+    pub customer_reference: Option<String>,
+    /// The line items of the purchase
+    pub line_items: Option<Vec<L3LineItem>>,
+}
+
+/// A line item of the level 3 data of a card payment
+#[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct L3LineItem {
+    /// The merchant's code for the product
+    #[schema(max_length = 12, example = "SKU-1001")]
+    pub product_code: Option<String>,
+    /// Description of the product
+    #[schema(max_length = 35, example = "Printer paper")]
+    pub description: String,
+    /// The commodity code of the product, which classifies it in the purchasing systems of the
+    /// customer
+    #[schema(max_length = 12, example = "44121618")]
+    pub commodity_code: Option<String>,
+    /// The quantity of the product purchased
+    #[schema(example = 4)]
+    pub quantity: u16,
+    /// The unit the quantity of the product is measured in
+    #[schema(max_length = 12, example = "EA")]
+    pub unit_of_measure: Option<String>,
+    /// The price of a single unit of the product, in the lowest denomination of the currency
+    #[schema(example = 1500)]
+    pub unit_amount: i64,
+    /// The tax on the line item, in the lowest denomination of the currency
+    #[schema(example = 120)]
+    pub tax_amount: Option<i64>,
+    /// The discount on the line item, in the lowest denomination of the currency
+    #[schema(example = 0)]
+    pub discount_amount: Option<i64>,
 }
 
 #[derive(Default, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -1197,8 +1857,10 @@ pub struct PaymentsSessionRequest {
     pub payment_id: String,
     /// This is a token which expires after 15 minutes, used from the client to authenticate and create sessions from the SDK
     pub client_secret: String,
-    /// The list of the supported wallets
+    /// The list of the supported wallets. Session tokens are created for all the wallets enabled
+    /// by the merchant if no wallets are provided
     #[schema(value_type = Vec<SupportedWallets>)]
+    #[serde(default)]
     pub wallets: Vec<api_enums::SupportedWallets>,
     /// Merchant connector details used to make payments.
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
@@ -1249,14 +1911,17 @@ pub struct GpayTransactionInfo {
     pub currency_code: String,
     /// The total price status (ex: 'FINAL')
     pub total_price_status: String,
-    /// The total price
-    pub total_price: i64,
+    /// The total price, in the base unit of the currency (ex: '10.50')
+    pub total_price: String,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct GpayMerchantInfo {
     /// The name of the merchant
     pub merchant_name: String,
+    /// The merchant identifier issued by Google, required in the production environment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_id: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -1411,10 +2076,52 @@ pub struct PaymentsCancelRequest {
     pub payment_id: String,
     /// The reason for the payment cancel
     pub cancellation_reason: Option<String>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information about the cancellation.
+    #[schema(value_type = Option<Object>, example = r#"{ "cancelled_by": "support_agent" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
     /// Merchant connector details used to make payments.
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsThreeDsAuthenticateRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// The channel through which the device data was collected
+    #[schema(value_type = ThreeDsDeviceChannel, example = "browser")]
+    pub device_channel: api_enums::ThreeDsDeviceChannel,
+    /// The device data collected through the 3DS method in the browser or the 3DS SDK in the app
+    #[schema(value_type = Option<Object>)]
+    pub device_data: Option<serde_json::Value>,
+    /// The version of the 3DS protocol used for the authentication
+    #[schema(example = "2.2.0")]
+    pub message_version: String,
+    /// The transaction identifier assigned by the 3DS server
+    pub three_ds_server_trans_id: String,
+    /// The transaction identifier assigned by the directory server
+    pub ds_trans_id: Option<String>,
+    /// The transaction identifier assigned by the access control server
+    pub acs_trans_id: Option<String>,
+    /// The outcome of the authentication. This is taken from the RReq when a challenge was performed and from the ARes otherwise
+    #[schema(value_type = ThreeDsTransStatus, example = "Y")]
+    pub trans_status: api_enums::ThreeDsTransStatus,
+    /// The reason provided by the access control server when the authentication was not successful
+    pub trans_status_reason: Option<String>,
+    /// The electronic commerce indicator returned by the access control server
+    #[schema(example = "05")]
+    pub eci: Option<String>,
+    /// The cryptographic authentication value (CAVV / AAV) returned by the access control server
+    #[schema(value_type = Option<String>)]
+    pub authentication_value: Option<Secret<String>>,
+    /// The authentication response (ARes) received from the directory server
+    #[schema(value_type = Option<Object>)]
+    pub ares: Option<serde_json::Value>,
+    /// The results request (RReq) received from the access control server after a challenge
+    #[schema(value_type = Option<Object>)]
+    pub rreq: Option<serde_json::Value>,
+}
+
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentsStartRequest {
     /// Unique identifier for the payment. This ensures idempotency for multiple payments
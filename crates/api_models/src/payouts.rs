@@ -1 +1,224 @@
+use common_utils::{custom_serde, pii};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
+use crate::enums as api_enums;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayoutId {
+    pub payout_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutCreateRequest {
+    /// Unique identifier for the payout, generated if not provided
+    #[schema(max_length = 64, example = "po_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: Option<String>,
+
+    /// The amount paid out, in the lowest denomination of the currency
+    #[schema(minimum = 1, example = 6540)]
+    pub amount: i64,
+
+    /// The three-letter ISO currency code of the amount
+    #[schema(value_type = Currency, example = "EUR")]
+    pub currency: api_enums::Currency,
+
+    /// The identifier for the customer receiving the payout
+    #[schema(max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+
+    /// The connector through which the payout is made. If not provided, the payout is routed
+    /// through the eligible connectors of the payout routing config of the merchant, falling back
+    /// to the next eligible connector if a connector fails to make the payout
+    #[schema(value_type = Option<Connector>, example = "adyen")]
+    pub connector: Option<api_enums::Connector>,
+
+    /// The type of the payout method the amount is paid out to
+    #[schema(value_type = PayoutType, example = "bank")]
+    pub payout_type: api_enums::PayoutType,
+
+    /// The details of the card or bank account the amount is paid out to. These are stored and
+    /// can be reused for later payouts through the `payout_method_id` of the response
+    pub payout_method_data: Option<PayoutMethodData>,
+
+    /// The identifier for a payout method stored by an earlier payout, used when
+    /// `payout_method_data` is not provided
+    #[schema(example = "pom_wwe8rgdsmyfoxwewurrqrsb9ea")]
+    pub payout_method_id: Option<String>,
+
+    /// A description of the payout
+    #[schema(example = "Seller earnings for April")]
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutMethodData {
+    Card(CardPayout),
+    Bank(BankPayout),
+}
+
+impl PayoutMethodData {
+    pub fn get_payout_type(&self) -> api_enums::PayoutType {
+        match self {
+            Self::Card(_) => api_enums::PayoutType::Card,
+            Self::Bank(_) => api_enums::PayoutType::Bank,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CardPayout {
+    /// The number of the card
+    #[schema(value_type = String, example = "4111111145551142")]
+    pub card_number: Secret<String, pii::CardNumber>,
+
+    /// The expiry month of the card
+    #[schema(value_type = String, example = "10")]
+    pub expiry_month: Secret<String>,
+
+    /// The expiry year of the card
+    #[schema(value_type = String, example = "25")]
+    pub expiry_year: Secret<String>,
+
+    /// The name of the card holder
+    #[schema(value_type = String, example = "John Doe")]
+    pub card_holder_name: Secret<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BankPayout {
+    /// Payout to a bank account in the United States through ACH
+    Ach(AchBankTransfer),
+    /// Payout to a bank account in the Single Euro Payments Area
+    Sepa(SepaBankTransfer),
+    /// Payout to a bank account in the United Kingdom through Bacs
+    Bacs(BacsBankTransfer),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AchBankTransfer {
+    /// The account number of the bank account
+    #[schema(value_type = String, example = "000123456789")]
+    pub bank_account_number: Secret<String>,
+
+    /// The routing number of the bank
+    #[schema(value_type = String, example = "110000000")]
+    pub bank_routing_number: Secret<String>,
+
+    /// The name of the owner of the bank account
+    #[schema(value_type = String, example = "John Doe")]
+    pub account_holder_name: Secret<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SepaBankTransfer {
+    /// The International Bank Account Number of the bank account
+    #[schema(value_type = String, example = "DE89370400440532013000")]
+    pub iban: Secret<String>,
+
+    /// The Bank Identifier Code of the bank
+    #[schema(value_type = Option<String>, example = "COBADEFFXXX")]
+    pub bic: Option<Secret<String>>,
+
+    /// The two-letter ISO country code of the bank
+    #[schema(value_type = CountryCode, example = "DE")]
+    pub bank_country_code: api_enums::CountryCode,
+
+    /// The name of the owner of the bank account
+    #[schema(value_type = String, example = "John Doe")]
+    pub account_holder_name: Secret<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BacsBankTransfer {
+    /// The account number of the bank account
+    #[schema(value_type = String, example = "55779911")]
+    pub bank_account_number: Secret<String>,
+
+    /// The sort code of the bank
+    #[schema(value_type = String, example = "200000")]
+    pub bank_sort_code: Secret<String>,
+
+    /// The name of the owner of the bank account
+    #[schema(value_type = String, example = "John Doe")]
+    pub account_holder_name: Secret<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PayoutRetrieveBody {
+    /// Whether the status of the payout is fetched from the connector
+    pub force_sync: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PayoutRetrieveRequest {
+    /// The identifier for the payout
+    pub payout_id: String,
+
+    /// Whether the status of the payout is fetched from the connector
+    pub force_sync: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct PayoutResponse {
+    /// The identifier for the payout
+    #[schema(example = "po_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: String,
+
+    /// The identifier for the merchant account
+    pub merchant_id: String,
+
+    /// The identifier for the customer receiving the payout
+    pub customer_id: Option<String>,
+
+    /// The amount paid out, in the lowest denomination of the currency
+    pub amount: i64,
+
+    /// The three-letter ISO currency code of the amount
+    #[schema(value_type = Currency, example = "EUR")]
+    pub currency: api_enums::Currency,
+
+    /// The connector through which the payout is made
+    pub connector: String,
+
+    /// The type of the payout method the amount is paid out to
+    #[schema(value_type = PayoutType, example = "bank")]
+    pub payout_type: api_enums::PayoutType,
+
+    /// The identifier for the stored payout method the amount is paid out to
+    pub payout_method_id: String,
+
+    /// The status of the payout
+    #[schema(value_type = PayoutStatus, example = "success")]
+    pub status: api_enums::PayoutStatus,
+
+    /// The error code returned by the connector if the payout failed
+    pub error_code: Option<String>,
+
+    /// The error message returned by the connector if the payout failed
+    pub error_message: Option<String>,
+
+    /// A description of the payout
+    pub description: Option<String>,
+
+    /// The metadata attached to the payout
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// The time at which the payout was created
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
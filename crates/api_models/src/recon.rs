@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementReportRequest {
+    /// The connector which settled the transactions of the report
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector: api_enums::Connector,
+
+    /// The settled transactions
+    pub report: SettlementReportData,
+}
+
+/// The settled transactions of a settlement report, either as the CSV file provided by the
+/// connector or as a list of transactions
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(tag = "format", rename_all = "snake_case")]
+pub enum SettlementReportData {
+    /// A CSV file with a header row, containing the `connector_transaction_id`, `amount` and
+    /// `currency` columns. Other columns are ignored.
+    Csv {
+        /// The contents of the CSV file
+        content: String,
+    },
+    /// A list of settled transactions
+    Lines {
+        /// The settled transactions
+        lines: Vec<SettlementReportLine>,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementReportLine {
+    /// The identifier for the transaction at the connector
+    #[schema(example = "pi_3MqSCR2eZvKYlo2C1vfiKk4K")]
+    pub connector_transaction_id: String,
+
+    /// The settled amount, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+
+    /// The currency of the settled amount
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SettlementLineResponse {
+    /// The identifier for the transaction at the connector
+    #[schema(example = "pi_3MqSCR2eZvKYlo2C1vfiKk4K")]
+    pub connector_transaction_id: String,
+
+    /// The identifier for the payment processed with the transaction, if any
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<String>,
+
+    /// The identifier for the payment attempt processed with the transaction, if any
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub attempt_id: Option<String>,
+
+    /// The amount settled by the connector, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub settled_amount: i64,
+
+    /// The currency settled by the connector
+    #[schema(value_type = Currency, example = "USD")]
+    pub settled_currency: api_enums::Currency,
+
+    /// The amount expected to be settled for the payment attempt, in the lowest denomination of
+    /// the currency
+    #[schema(example = 6540)]
+    pub expected_amount: Option<i64>,
+
+    /// The currency expected to be settled for the payment attempt
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub expected_currency: Option<api_enums::Currency>,
+
+    /// The result of reconciling the transaction against the payment attempt
+    #[schema(value_type = ReconStatus, example = "matched")]
+    pub recon_status: api_enums::ReconStatus,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SettlementReportResponse {
+    /// The identifier for the settlement report
+    #[schema(max_length = 64, example = "stlm_q98uSGAYbjEwqs0mJwnz")]
+    pub report_id: String,
+
+    /// The connector which settled the transactions of the report
+    #[schema(example = "stripe")]
+    pub connector: String,
+
+    /// The number of settled transactions in the report
+    #[schema(example = 3)]
+    pub line_count: i32,
+
+    /// The number of settled transactions which match their payments
+    #[schema(example = 1)]
+    pub matched_count: i32,
+
+    /// The number of settled transactions whose amount or currency differ from their payments
+    #[schema(example = 1)]
+    pub mismatched_count: i32,
+
+    /// The number of settled transactions with no payment in the router
+    #[schema(example = 1)]
+    pub missing_count: i32,
+
+    /// The time at which the report was ingested
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The reconciled transactions of the report
+    pub lines: Vec<SettlementLineResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct PaymentReconResponse {
+    /// The identifier for the payment
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+
+    /// The reconciliation status of the payment
+    #[schema(value_type = PaymentReconStatus, example = "matched")]
+    pub recon_status: api_enums::PaymentReconStatus,
+
+    /// The latest settlement of each transaction of the payment
+    pub settlement_lines: Vec<SettlementLineResponse>,
+}
@@ -103,6 +103,9 @@ pub struct RefundResponse {
     pub error_message: Option<String>,
     /// The code for the error
     pub error_code: Option<String>,
+    /// The raw response of the connector, for merchants who opted in to receive it
+    #[schema(value_type = Option<Object>)]
+    pub connector_raw_response: Option<serde_json::Value>,
     /// The timestamp at which refund is created
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub created_at: Option<PrimitiveDateTime>,
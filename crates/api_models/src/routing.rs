@@ -0,0 +1,199 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// The request body for creating a routing configuration.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingConfigRequest {
+    /// A unique name for the routing configuration to help you identify it.
+    #[schema(max_length = 64, example = "High value payments via Adyen")]
+    pub name: String,
+
+    /// A description to provide more context about the routing configuration.
+    #[schema(
+        max_length = 256,
+        example = "Routes payments above 1000 USD through Adyen"
+    )]
+    pub description: Option<String>,
+
+    /// The rules to be evaluated for routing a payment.
+    pub algorithm: RoutingRules,
+}
+
+/// An ordered list of routing rules. A payment is routed through the connector of the first rule
+/// whose conditions are all satisfied, and through the default connector if none of the rules
+/// match.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRules {
+    /// The rules, in the order in which they are evaluated.
+    pub rules: Vec<RoutingRule>,
+
+    /// The connector used when none of the rules match.
+    #[schema(value_type = String, example = "stripe")]
+    pub default_connector: api_enums::RoutableConnectors,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRule {
+    /// A name for the rule, returned when evaluating the routing configuration.
+    #[schema(example = "high_value_payments")]
+    pub name: String,
+
+    /// The conditions that must all be satisfied for the rule to match. A rule without any
+    /// conditions matches every payment.
+    pub conditions: Vec<RoutingCondition>,
+
+    /// The connector the payment is routed through if the rule matches.
+    #[schema(value_type = String, example = "adyen")]
+    pub connector: api_enums::RoutableConnectors,
+
+    /// The network co-badged cards are processed with if the rule matches, unless the customer
+    /// selected the network of the card.
+    #[serde(default)]
+    #[schema(value_type = Option<CardNetwork>, example = "CartesBancaires")]
+    pub card_network: Option<api_enums::CardNetwork>,
+}
+
+/// A condition on an attribute of the payment.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(tag = "field", rename_all = "snake_case")]
+pub enum RoutingCondition {
+    /// The amount of the payment, in the lowest denomination of the currency
+    Amount {
+        operator: ComparisonOperator,
+        value: i64,
+    },
+    /// The currency of the payment
+    Currency {
+        operator: MembershipOperator,
+        values: Vec<api_enums::Currency>,
+    },
+    /// The leading digits of the card number. A value matches if the card number starts with it
+    CardBin {
+        operator: MembershipOperator,
+        values: Vec<String>,
+    },
+    /// The network of the card
+    CardNetwork {
+        operator: MembershipOperator,
+        values: Vec<api_enums::CardNetwork>,
+    },
+    /// The payment method of the payment
+    PaymentMethod {
+        operator: MembershipOperator,
+        values: Vec<api_enums::PaymentMethod>,
+    },
+    /// The country of the billing address
+    Country {
+        operator: MembershipOperator,
+        values: Vec<api_enums::CountryCode>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ComparisonOperator {
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MembershipOperator {
+    In,
+    NotIn,
+}
+
+/// The response body for a routing configuration.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RoutingConfigResponse {
+    /// The identifier for the routing configuration.
+    #[schema(max_length = 64, example = "routing_2Lg2RpL2BTCcZBS4R4xZ")]
+    pub routing_config_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The unique name for the routing configuration.
+    #[schema(max_length = 64, example = "High value payments via Adyen")]
+    pub name: String,
+
+    /// The description to provide more context about the routing configuration.
+    #[schema(
+        max_length = 256,
+        example = "Routes payments above 1000 USD through Adyen"
+    )]
+    pub description: Option<String>,
+
+    /// The rules to be evaluated for routing a payment.
+    pub algorithm: RoutingRules,
+
+    /// Whether payments of the merchant are currently routed using this configuration.
+    pub is_active: bool,
+
+    /// The time at which the routing configuration was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the routing configuration was last modified.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// The attributes of a payment, against which the routing configuration is evaluated.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingEvaluateRequest {
+    /// The amount of the payment, in the lowest denomination of the currency.
+    #[schema(example = 6540)]
+    pub amount: i64,
+
+    /// The currency of the payment.
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The leading digits of the card number.
+    #[schema(example = "424242")]
+    pub card_bin: Option<String>,
+
+    /// The network of the card.
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<api_enums::CardNetwork>,
+
+    /// The payment method of the payment.
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+
+    /// The country of the billing address.
+    #[schema(value_type = Option<CountryCode>, example = "US")]
+    pub country: Option<api_enums::CountryCode>,
+}
+
+/// The result of evaluating a routing configuration against a payment.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct RoutingEvaluateResponse {
+    /// The connector the payment would be routed through.
+    #[schema(value_type = String, example = "adyen")]
+    pub connector: api_enums::RoutableConnectors,
+
+    /// The name of the rule that matched, if the default connector was not chosen.
+    #[schema(example = "high_value_payments")]
+    pub matched_rule: Option<String>,
+
+    /// The network co-badged cards would be processed with, if the matched rule selects one.
+    #[schema(value_type = Option<CardNetwork>, example = "CartesBancaires")]
+    pub card_network: Option<api_enums::CardNetwork>,
+}
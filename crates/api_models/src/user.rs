@@ -0,0 +1,109 @@
+use common_utils::pii;
+use masking::{Secret, StrongSecret};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SignUpRequest {
+    /// The email address of the user, used to sign in to the dashboard
+    #[schema(value_type = String, max_length = 255, example = "JonTest@test.com")]
+    pub email: Secret<String, pii::Email>,
+
+    /// The name of the user
+    #[schema(max_length = 255, example = "Jon Test")]
+    pub name: String,
+
+    /// The password of the user, which must be at least 8 characters long
+    #[schema(value_type = String, min_length = 8)]
+    pub password: Secret<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SignInRequest {
+    /// The email address of the user
+    #[schema(value_type = String, max_length = 255, example = "JonTest@test.com")]
+    pub email: Secret<String, pii::Email>,
+
+    /// The password of the user
+    #[schema(value_type = String)]
+    pub password: Secret<String>,
+
+    /// The merchant account to sign in to. Defaults to the first merchant account the user was
+    /// given a role for
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RefreshTokenRequest {
+    /// The refresh token issued when the user signed in
+    #[schema(value_type = String)]
+    pub refresh_token: StrongSecret<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuthTokenResponse {
+    /// The identifier for the user
+    #[schema(example = "user_vRSpeCLn9Nh4bQ5UTdcv")]
+    pub user_id: String,
+
+    /// The email address of the user
+    #[schema(value_type = String, example = "JonTest@test.com")]
+    pub email: Secret<String, pii::Email>,
+
+    /// The name of the user
+    #[schema(example = "Jon Test")]
+    pub name: String,
+
+    /// The merchant account the tokens were issued for, if the user has a role for any
+    #[schema(example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: Option<String>,
+
+    /// The role of the user for the merchant account
+    #[schema(value_type = Option<DashboardRole>, example = "admin")]
+    pub role: Option<api_enums::DashboardRole>,
+
+    /// The JWT to be passed as a bearer token in the `Authorization` header of dashboard requests
+    #[schema(value_type = String)]
+    pub access_token: StrongSecret<String>,
+
+    /// Time (in seconds) for which the access token is valid
+    #[schema(example = 3600)]
+    pub expires_in: i64,
+
+    /// The token to be used to obtain a new access token once it expires
+    #[schema(value_type = String)]
+    pub refresh_token: StrongSecret<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UserRoleRequest {
+    /// The email address of the user to be given the role
+    #[schema(value_type = String, max_length = 255, example = "JonTest@test.com")]
+    pub email: Secret<String, pii::Email>,
+
+    /// The role to be given to the user for the merchant account
+    #[schema(value_type = DashboardRole, example = "developer")]
+    pub role: api_enums::DashboardRole,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserRoleResponse {
+    /// The identifier for the user
+    #[schema(example = "user_vRSpeCLn9Nh4bQ5UTdcv")]
+    pub user_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The role of the user for the merchant account
+    #[schema(value_type = DashboardRole, example = "developer")]
+    pub role: api_enums::DashboardRole,
+}
@@ -0,0 +1,64 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::payment_methods::CardDetail;
+
+/// The request body for storing a card in the vault.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VaultTokenCreateRequest {
+    /// The card to be stored in the vault.
+    pub card: CardDetail,
+
+    /// The identifier for the customer the card belongs to. If provided, the token can only be
+    /// used in payments made for this customer.
+    #[schema(max_length = 64, example = "cus_meowerunwiuwiwqw")]
+    pub customer_id: Option<String>,
+}
+
+/// The metadata of a card stored in the vault. The card number is never returned.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VaultTokenResponse {
+    /// The token which can be passed as the `payment_token` in payments, in place of the card.
+    #[schema(max_length = 64, example = "vault_tok_2Lg2RpL2BTCcZBS4R4xZ")]
+    pub payment_token: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The identifier for the customer the card belongs to.
+    #[schema(max_length = 64, example = "cus_meowerunwiuwiwqw")]
+    pub customer_id: Option<String>,
+
+    /// The last four digits of the card number.
+    #[schema(example = "4242")]
+    pub card_last_four: String,
+
+    /// The card's expiry month.
+    #[schema(example = "10")]
+    pub card_exp_month: String,
+
+    /// The card's expiry year.
+    #[schema(example = "25")]
+    pub card_exp_year: String,
+
+    /// The time at which the card was stored in the vault.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The response body for deleting a card from the vault.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VaultTokenDeleteResponse {
+    /// The token of the deleted card.
+    #[schema(max_length = 64, example = "vault_tok_2Lg2RpL2BTCcZBS4R4xZ")]
+    pub payment_token: String,
+
+    /// Whether the card was deleted from the vault.
+    #[schema(example = true)]
+    pub deleted: bool,
+}
@@ -2,13 +2,17 @@ use common_utils::custom_serde;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::{disputes, enums as api_enums, payments, refunds};
+use crate::{disputes, enums as api_enums, payment_schedules, payments, payouts, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum IncomingWebhookEvent {
     PaymentIntentFailure,
     PaymentIntentSuccess,
+    // payment which is awaiting confirmation from the connector
+    PaymentIntentProcessing,
+    // bank debit which had succeeded or was awaiting settlement has been returned by the bank
+    PaymentDebitReturned,
     RefundFailure,
     RefundSuccess,
     DisputeOpened,
@@ -20,7 +24,16 @@ pub enum IncomingWebhookEvent {
     DisputeWon,
     // dispute has been unsuccessfully challenged
     DisputeLost,
+    // payout has been credited to the card or bank account of the recipient
+    PayoutSuccess,
+    PayoutFailure,
+    // payout has been declined by the merchant before it was processed
+    PayoutCancelled,
+    // payout which had succeeded has been returned by the bank of the recipient
+    PayoutReversed,
     EndpointVerification,
+    // event received from the connector which is not handled by the router
+    EventNotSupported,
 }
 
 pub enum WebhookFlow {
@@ -28,6 +41,7 @@ pub enum WebhookFlow {
     Refund,
     Dispute,
     Subscription,
+    Payout,
     ReturnResponse,
 }
 
@@ -36,6 +50,8 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
         match evt {
             IncomingWebhookEvent::PaymentIntentFailure => Self::Payment,
             IncomingWebhookEvent::PaymentIntentSuccess => Self::Payment,
+            IncomingWebhookEvent::PaymentIntentProcessing => Self::Payment,
+            IncomingWebhookEvent::PaymentDebitReturned => Self::Payment,
             IncomingWebhookEvent::RefundSuccess => Self::Refund,
             IncomingWebhookEvent::RefundFailure => Self::Refund,
             IncomingWebhookEvent::DisputeOpened => Self::Dispute,
@@ -45,7 +61,12 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
             IncomingWebhookEvent::DisputeChallenged => Self::Dispute,
             IncomingWebhookEvent::DisputeWon => Self::Dispute,
             IncomingWebhookEvent::DisputeLost => Self::Dispute,
+            IncomingWebhookEvent::PayoutSuccess => Self::Payout,
+            IncomingWebhookEvent::PayoutFailure => Self::Payout,
+            IncomingWebhookEvent::PayoutCancelled => Self::Payout,
+            IncomingWebhookEvent::PayoutReversed => Self::Payout,
             IncomingWebhookEvent::EndpointVerification => Self::ReturnResponse,
+            IncomingWebhookEvent::EventNotSupported => Self::ReturnResponse,
         }
     }
 }
@@ -57,9 +78,15 @@ pub enum RefundIdType {
     ConnectorRefundId(String),
 }
 
+pub enum PayoutIdType {
+    PayoutId(String),
+    ConnectorPayoutId(String),
+}
+
 pub enum ObjectReferenceId {
     PaymentId(payments::PaymentIdType),
     RefundId(RefundIdType),
+    PayoutId(PayoutIdType),
 }
 
 pub struct IncomingWebhookDetails {
@@ -83,6 +110,8 @@ pub enum OutgoingWebhookContent {
     PaymentDetails(payments::PaymentsResponse),
     RefundDetails(refunds::RefundResponse),
     DisputeDetails(Box<disputes::DisputeResponse>),
+    PaymentScheduleDetails(Box<payment_schedules::PaymentScheduleResponse>),
+    PayoutDetails(Box<payouts::PayoutResponse>),
 }
 
 pub trait OutgoingWebhookType: Serialize + From<OutgoingWebhook> + Sync + Send {}
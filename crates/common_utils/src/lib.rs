@@ -11,6 +11,7 @@ pub mod fp_utils;
 pub mod pii;
 #[cfg(feature = "signals")]
 pub mod signals;
+pub mod types;
 pub mod validation;
 
 /// Date-time utilities.
@@ -43,6 +44,11 @@ pub mod date_time {
         OffsetDateTime::now_utc().unix_timestamp()
     }
 
+    /// Return the UNIX timestamp of the current date and time in UTC, in milliseconds
+    pub fn now_unix_timestamp_millis() -> i128 {
+        OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000
+    }
+
     /// Calculate execution time for a async block in milliseconds
     #[cfg(feature = "async_ext")]
     pub async fn time_it<T, Fut: futures::Future<Output = T>, F: FnOnce() -> Fut>(
@@ -4,7 +4,7 @@ use std::{convert::AsRef, fmt};
 
 use masking::{Strategy, WithType};
 
-use crate::validation::validate_email;
+use crate::validation::{validate_email, validate_upi_vpa};
 
 /// Type alias for serde_json value which has Secret Information
 pub type SecretSerdeValue = masking::Secret<serde_json::Value>;
@@ -137,11 +137,34 @@ where
     }
 }
 
+/// UPI Virtual Payment Address
+#[derive(Debug)]
+pub struct UpiVpaMaskingStrategy;
+
+impl<T> Strategy<T> for UpiVpaMaskingStrategy
+where
+    T: AsRef<str>,
+{
+    fn fmt(val: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val_str: &str = val.as_ref();
+
+        if validate_upi_vpa(val_str).is_err() {
+            return WithType::fmt(val, f);
+        }
+
+        if let Some((user_identifier, bank_or_psp)) = val_str.split_once('@') {
+            write!(f, "{}@{}", "*".repeat(user_identifier.len()), bank_or_psp)
+        } else {
+            WithType::fmt(val, f)
+        }
+    }
+}
+
 #[cfg(test)]
 mod pii_masking_strategy_tests {
     use masking::Secret;
 
-    use super::{CardNumber, ClientSecret, Email, IpAddress};
+    use super::{CardNumber, ClientSecret, Email, IpAddress, UpiVpaMaskingStrategy};
 
     #[test]
     fn test_valid_card_number_masking() {
@@ -187,6 +210,18 @@ mod pii_masking_strategy_tests {
         assert_eq!("*** alloc::string::String ***", format!("{secret:?}"));
     }
 
+    #[test]
+    fn test_valid_upi_vpa_masking() {
+        let secret: Secret<String, UpiVpaMaskingStrategy> = Secret::new("my_name@upi".to_string());
+        assert_eq!("*******@upi", format!("{secret:?}"));
+    }
+
+    #[test]
+    fn test_invalid_upi_vpa_masking() {
+        let secret: Secret<String, UpiVpaMaskingStrategy> = Secret::new("my_name_upi".to_string());
+        assert_eq!("*** alloc::string::String ***", format!("{secret:?}"));
+    }
+
     #[test]
     fn test_valid_ip_addr_masking() {
         let secret: Secret<String, IpAddress> = Secret::new("123.23.1.78".to_string());
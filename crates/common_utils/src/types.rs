@@ -0,0 +1,137 @@
+//! Types shared across the request, storage and connector layers.
+
+use std::fmt;
+
+/// An amount in the minor unit of its currency, such as cents for USD, yen for JPY or fils for
+/// KWD. The number of minor units in a major unit depends on the currency, and is given as the
+/// exponent of the power of ten it is (2 for USD, 0 for JPY and 3 for KWD).
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    serde::Deserialize,
+    serde::Serialize,
+    diesel::AsExpression,
+)]
+#[diesel(sql_type = diesel::sql_types::BigInt)]
+#[serde(transparent)]
+pub struct MinorUnit(i64);
+
+impl MinorUnit {
+    /// Creates an amount from its value in minor units.
+    pub fn new(value: i64) -> Self {
+        Self(value)
+    }
+
+    /// The value of the amount in minor units.
+    pub fn get_amount_as_i64(self) -> i64 {
+        self.0
+    }
+
+    /// Whether the amount is below zero.
+    pub fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Formats the amount in the major unit of its currency, with as many digits after the decimal
+    /// separator as the currency has, such as `"10.50"` for 1050 USD cents, `"1050"` for 1050 JPY
+    /// and `"1.050"` for 1050 KWD fils. The amount is formatted exactly, without going through a
+    /// floating point number.
+    pub fn to_major_unit_as_string(self, exponent: u8) -> String {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let value = self.0.unsigned_abs();
+        let divisor = 10_u64.saturating_pow(u32::from(exponent));
+
+        if exponent == 0 {
+            format!("{sign}{value}")
+        } else {
+            format!(
+                "{sign}{}.{:0>width$}",
+                value / divisor,
+                value % divisor,
+                width = usize::from(exponent)
+            )
+        }
+    }
+
+    /// The amount in the major unit of its currency, for connectors which expect amounts as
+    /// numbers rather than strings.
+    #[allow(clippy::as_conversions)]
+    pub fn to_major_unit_as_f64(self, exponent: u8) -> f64 {
+        self.0 as f64 / 10_f64.powi(i32::from(exponent))
+    }
+}
+
+impl fmt::Display for MinorUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+mod diesel_impl {
+    use diesel::{
+        backend::Backend,
+        deserialize::FromSql,
+        serialize::{Output, ToSql},
+        sql_types::BigInt,
+        Queryable,
+    };
+
+    impl<DB> ToSql<BigInt, DB> for super::MinorUnit
+    where
+        DB: Backend,
+        i64: ToSql<BigInt, DB>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> diesel::serialize::Result {
+            self.0.to_sql(out)
+        }
+    }
+
+    impl<DB> FromSql<BigInt, DB> for super::MinorUnit
+    where
+        DB: Backend,
+        i64: FromSql<BigInt, DB>,
+    {
+        fn from_sql(bytes: diesel::backend::RawValue<'_, DB>) -> diesel::deserialize::Result<Self> {
+            Ok(Self(i64::from_sql(bytes)?))
+        }
+    }
+
+    impl<DB> Queryable<BigInt, DB> for super::MinorUnit
+    where
+        DB: Backend,
+        Self: FromSql<BigInt, DB>,
+    {
+        type Row = Self;
+
+        fn build(row: Self::Row) -> diesel::deserialize::Result<Self> {
+            Ok(row)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_major_unit_as_string() {
+        assert_eq!(MinorUnit::new(1050).to_major_unit_as_string(2), "10.50");
+        assert_eq!(MinorUnit::new(5).to_major_unit_as_string(2), "0.05");
+        assert_eq!(MinorUnit::new(1050).to_major_unit_as_string(0), "1050");
+        assert_eq!(MinorUnit::new(1050).to_major_unit_as_string(3), "1.050");
+        assert_eq!(MinorUnit::new(-1234).to_major_unit_as_string(3), "-1.234");
+    }
+
+    #[test]
+    fn test_to_major_unit_as_f64() {
+        assert!((MinorUnit::new(1050).to_major_unit_as_f64(2) - 10.5).abs() < f64::EPSILON);
+        assert!((MinorUnit::new(1050).to_major_unit_as_f64(0) - 1050.0).abs() < f64::EPSILON);
+    }
+}
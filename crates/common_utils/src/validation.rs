@@ -46,6 +46,103 @@ pub fn validate_email(email: &str) -> CustomResult<(), ValidationError> {
     Ok(())
 }
 
+/// Performs a simple validation against a provided UPI Virtual Payment Address, which consists of
+/// a username and the handle of the payment service provider, separated by `@`.
+pub fn validate_upi_vpa(vpa: &str) -> CustomResult<(), ValidationError> {
+    #[deny(clippy::invalid_regex)]
+    static UPI_VPA_REGEX: Lazy<Option<Regex>> =
+        Lazy::new(
+            || match Regex::new(r"^[a-zA-Z0-9.\-_]{2,256}@[a-zA-Z]{2,64}$") {
+                Ok(regex) => Some(regex),
+                Err(_error) => {
+                    #[cfg(feature = "logs")]
+                    logger::error!(?_error);
+                    None
+                }
+            },
+        );
+    let upi_vpa_regex = match UPI_VPA_REGEX.as_ref() {
+        Some(regex) => Ok(regex),
+        None => Err(report!(ValidationError::InvalidValue {
+            message: "Invalid regex expression".into()
+        })),
+    }?;
+
+    if !upi_vpa_regex.is_match(vpa) {
+        return Err(report!(ValidationError::InvalidValue {
+            message: "Invalid UPI Virtual Payment Address format".into()
+        }));
+    }
+
+    Ok(())
+}
+
+/// Performs a simple validation against a provided phone number, which consists of up to 15 digits
+/// (the maximum length of an E.164 number), optionally separated by spaces or hyphens and
+/// prefixed with `+`.
+pub fn validate_phone_number(phone_number: &str) -> CustomResult<(), ValidationError> {
+    const PHONE_NUMBER_MIN_DIGITS: usize = 4;
+    const PHONE_NUMBER_MAX_DIGITS: usize = 15;
+
+    let phone_number = phone_number.strip_prefix('+').unwrap_or(phone_number);
+    let digits = phone_number.chars().filter(char::is_ascii_digit).count();
+    let is_valid = phone_number
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == ' ' || c == '-')
+        && (PHONE_NUMBER_MIN_DIGITS..=PHONE_NUMBER_MAX_DIGITS).contains(&digits);
+
+    if !is_valid {
+        return Err(report!(ValidationError::InvalidValue {
+            message: "Invalid phone number format".into()
+        }));
+    }
+
+    Ok(())
+}
+
+/// Performs a simple validation against a provided country calling code, such as `+1` or `+91`.
+pub fn validate_phone_country_code(country_code: &str) -> CustomResult<(), ValidationError> {
+    let digits = country_code.strip_prefix('+').unwrap_or(country_code);
+
+    if digits.is_empty() || digits.len() > 4 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(report!(ValidationError::InvalidValue {
+            message: "Invalid phone country code format".into()
+        }));
+    }
+
+    Ok(())
+}
+
+/// Performs a simple validation against a provided card number, which consists of 12 to 19 digits
+/// whose last digit is the Luhn check digit of the others.
+pub fn validate_card_number(card_number: &str) -> CustomResult<(), ValidationError> {
+    let digits = card_number
+        .chars()
+        .map(|c| c.to_digit(10))
+        .collect::<Option<Vec<u32>>>()
+        .unwrap_or_default();
+
+    // Every second digit from the right, starting with the one left of the check digit, is doubled
+    let checksum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| match (index % 2, digit * 2) {
+            (1, doubled) if doubled > 9 => doubled - 9,
+            (1, doubled) => doubled,
+            _ => *digit,
+        })
+        .sum();
+
+    if !(12..=19).contains(&digits.len()) || checksum % 10 != 0 {
+        return Err(report!(ValidationError::InvalidValue {
+            message: "Invalid card number".into()
+        }));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use fake::{faker::internet::en::SafeEmail, Fake};
@@ -81,6 +178,51 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_validate_upi_vpa() {
+        let result = validate_upi_vpa("successtest@iata");
+        assert!(result.is_ok());
+
+        let result = validate_upi_vpa("john.doe-99@okbank");
+        assert!(result.is_ok());
+
+        let result = validate_upi_vpa("johndoe");
+        assert!(result.is_err());
+
+        let result = validate_upi_vpa("john@doe@okbank");
+        assert!(result.is_err());
+
+        let result = validate_upi_vpa("john@ok1");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_phone_number() {
+        assert!(validate_phone_number("3141592653").is_ok());
+        assert!(validate_phone_number("314 159-2653").is_ok());
+        assert!(validate_phone_number("+13141592653").is_ok());
+        assert!(validate_phone_number("(314) 1592653").is_err());
+        assert!(validate_phone_number("123").is_err());
+        assert!(validate_phone_number("3141592653314159").is_err());
+    }
+
+    #[test]
+    fn test_validate_phone_country_code() {
+        assert!(validate_phone_country_code("+1").is_ok());
+        assert!(validate_phone_country_code("91").is_ok());
+        assert!(validate_phone_country_code("+").is_err());
+        assert!(validate_phone_country_code("+12345").is_err());
+    }
+
+    #[test]
+    fn test_validate_card_number() {
+        assert!(validate_card_number("4242424242424242").is_ok());
+        assert!(validate_card_number("378282246310005").is_ok());
+        assert!(validate_card_number("4242424242424241").is_err());
+        assert!(validate_card_number("4242 4242 4242 4242").is_err());
+        assert!(validate_card_number("42424242").is_err());
+    }
+
     proptest::proptest! {
         /// Example of unit test
         #[test]
@@ -186,14 +186,14 @@ async fn drainer(
                     match updatable {
                         kv::Updateable::PaymentIntentUpdate(a) => {
                             macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
+                                update_payment_intent(&conn, a).await,
                                 update_op,
                                 payment_intent
                             )
                         }
                         kv::Updateable::PaymentAttemptUpdate(a) => {
                             macro_util::handle_resp!(
-                                a.orig.update_with_attempt_id(&conn, a.update_data).await,
+                                update_payment_attempt(&conn, a).await,
                                 update_op,
                                 payment_attempt
                             )
@@ -239,6 +239,71 @@ async fn drainer(
     Ok(())
 }
 
+fn is_version_conflict(error: &error_stack::Report<storage_models::errors::DatabaseError>) -> bool {
+    let is_version_conflict = matches!(
+        error.current_context(),
+        storage_models::errors::DatabaseError::VersionConflict
+    );
+    if is_version_conflict {
+        metrics::VERSION_CONFLICTS_WHILE_QUERY_EXECUTION.add(&metrics::CONTEXT, 1, &[]);
+    }
+    is_version_conflict
+}
+
+/// Updates are made on the version of the row they were read at. Redis holds the latest version of
+/// the rows of merchants on the KV storage scheme, so a row which is at another version in the
+/// database, because it missed an update which failed to drain or was updated outside of the
+/// stream, is reloaded and the update is applied on its current version.
+async fn update_payment_intent(
+    conn: &storage_models::PgPooledConn,
+    update: kv::PaymentIntentUpdateMems,
+) -> storage_models::StorageResult<storage_models::payment_intent::PaymentIntent> {
+    match update
+        .orig
+        .clone()
+        .update(conn, update.update_data.clone())
+        .await
+    {
+        Err(error) if is_version_conflict(&error) => {
+            logger::warn!(payment_id = %update.orig.payment_id, "Payment intent version conflict");
+            storage_models::payment_intent::PaymentIntent::find_by_payment_id_merchant_id(
+                conn,
+                &update.orig.payment_id,
+                &update.orig.merchant_id,
+            )
+            .await?
+            .update(conn, update.update_data)
+            .await
+        }
+        result => result,
+    }
+}
+
+async fn update_payment_attempt(
+    conn: &storage_models::PgPooledConn,
+    update: kv::PaymentAttemptUpdateMems,
+) -> storage_models::StorageResult<storage_models::payment_attempt::PaymentAttempt> {
+    match update
+        .orig
+        .clone()
+        .update_with_attempt_id(conn, update.update_data.clone())
+        .await
+    {
+        Err(error) if is_version_conflict(&error) => {
+            logger::warn!(attempt_id = %update.orig.attempt_id, "Payment attempt version conflict");
+            storage_models::payment_attempt::PaymentAttempt::find_by_merchant_id_attempt_id(
+                conn,
+                &update.orig.merchant_id,
+                &update.orig.attempt_id,
+            )
+            .await?
+            .update_with_attempt_id(conn, update.update_data)
+            .await
+        }
+        result => result,
+    }
+}
+
 mod macro_util {
 
     macro_rules! handle_resp {
@@ -8,6 +8,7 @@ counter_metric!(JOBS_PICKED_PER_STREAM, DRAINER_METER);
 counter_metric!(CYCLES_COMPLETED_SUCCESSFULLY, DRAINER_METER);
 counter_metric!(CYCLES_COMPLETED_UNSUCCESSFULLY, DRAINER_METER);
 counter_metric!(ERRORS_WHILE_QUERY_EXECUTION, DRAINER_METER);
+counter_metric!(VERSION_CONFLICTS_WHILE_QUERY_EXECUTION, DRAINER_METER);
 counter_metric!(SUCCESSFUL_QUERY_EXECUTION, DRAINER_METER);
 counter_metric!(SHUTDOWN_SIGNAL_RECEIVED, DRAINER_METER);
 counter_metric!(SUCCESSFUL_SHUTDOWN, DRAINER_METER);
@@ -15,7 +15,7 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, StreamsInterface},
+    interfaces::{HashesInterface, KeysInterface, LuaInterface, StreamsInterface},
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
         RedisKey, RedisMap, RedisValue, SetOptions, XCap, XReadResponse,
@@ -171,6 +171,49 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn serialize_and_set_key_with_expiry<V>(
+        &self,
+        key: &str,
+        value: V,
+        seconds: i64,
+    ) -> CustomResult<(), errors::RedisError>
+    where
+        V: serde::Serialize + Debug,
+    {
+        let serialized = Encode::<V>::encode_to_vec(&value)
+            .change_context(errors::RedisError::JsonSerializationFailed)?;
+
+        self.set_key_with_expiry(key, serialized.as_slice(), seconds)
+            .await
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn serialize_and_set_key_if_not_exist_with_expiry<V>(
+        &self,
+        key: &str,
+        value: V,
+        seconds: i64,
+    ) -> CustomResult<SetnxReply, errors::RedisError>
+    where
+        V: serde::Serialize + Debug,
+    {
+        let serialized = Encode::<V>::encode_to_vec(&value)
+            .change_context(errors::RedisError::JsonSerializationFailed)?;
+
+        self.pool
+            .set(
+                key,
+                serialized.as_slice(),
+                Some(Expiration::EX(seconds)),
+                Some(SetOptions::NX),
+                false,
+            )
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SetFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expiry(
         &self,
@@ -349,6 +392,32 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::GetHashFieldFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_hash_fields<V>(&self, key: &str) -> CustomResult<V, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        self.pool
+            .hgetall(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::GetHashFieldFailed)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_hash_field(
+        &self,
+        key: &str,
+        field: &str,
+        increment: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .hincrby(key, field, increment)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::IncrementHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_hash_field_and_deserialize<V>(
         &self,
@@ -594,6 +663,25 @@ impl super::RedisConnectionPool {
             .into_report()
             .change_context(errors::RedisError::ConsumerGroupClaimFailed)
     }
+
+    /// Executes a Lua script atomically, with the keys it accesses passed separately from its
+    /// other arguments.
+    #[instrument(level = "DEBUG", skip(self, script))]
+    pub async fn evaluate_script<V>(
+        &self,
+        script: &str,
+        keys: Vec<String>,
+        args: Vec<String>,
+    ) -> CustomResult<V, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        self.pool
+            .eval(script, keys, args)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::ScriptExecutionFailed)
+    }
 }
 
 #[cfg(test)]
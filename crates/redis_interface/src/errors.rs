@@ -48,6 +48,8 @@ pub enum RedisError {
     SetHashFieldFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
+    #[error("Failed to increment hash field in Redis")]
+    IncrementHashFieldFailed,
     #[error("The requested value was not found in Redis")]
     NotFound,
     #[error("Invalid RedisEntryId provided")]
@@ -58,4 +60,6 @@ pub enum RedisError {
     SubscribeError,
     #[error("Failed to publish to a channel")]
     PublishError,
+    #[error("Failed to execute the Lua script in Redis")]
+    ScriptExecutionFailed,
 }
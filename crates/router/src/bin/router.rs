@@ -1,7 +1,12 @@
+use std::time::Duration;
+
 use router::{
-    configs::settings::{CmdLineConf, Settings},
-    core::errors::{ApplicationError, ApplicationResult},
-    logger,
+    configs::settings::{CmdLineConf, Settings, Subcommand},
+    core::{
+        data_migrations,
+        errors::{ApplicationError, ApplicationResult},
+    },
+    logger, routes,
 };
 
 #[actix_web::main]
@@ -11,7 +16,6 @@ async fn main() -> ApplicationResult<()> {
 
     #[cfg(feature = "openapi")]
     {
-        use router::configs::settings::Subcommand;
         if let Some(Subcommand::GenerateOpenapiSpec) = cmd_line.subcommand {
             let file_path = "openapi/generated.json";
             #[allow(clippy::expect_used)]
@@ -36,6 +40,25 @@ async fn main() -> ApplicationResult<()> {
 
     let _guard = logger::setup(&conf.log)?;
 
+    match cmd_line.subcommand {
+        Some(Subcommand::MigrateData {
+            version,
+            batch_size,
+            batch_interval,
+            max_batches,
+        }) => {
+            let options = data_migrations::DataMigrationOptions {
+                version,
+                batch_size,
+                batch_interval: Duration::from_millis(batch_interval),
+                max_batches,
+            };
+            return migrate_data(conf, options).await;
+        }
+        Some(Subcommand::ListDataMigrations) => return list_data_migrations(conf).await,
+        _ => {}
+    }
+
     logger::info!("Application started [{:?}] [{:?}]", conf.server, conf.log);
 
     #[allow(clippy::expect_used)]
@@ -45,6 +68,12 @@ async fn main() -> ApplicationResult<()> {
 
     let _ = server.await;
 
+    // Requests in flight are completed by the server before it stops, while the background tasks
+    // they spawned, such as outgoing webhooks, are drained here
+    state
+        .background_tasks
+        .drain(Duration::from_secs(state.conf.server.drain_timeout))
+        .await;
     state.store.close().await;
 
     Err(ApplicationError::from(std::io::Error::new(
@@ -52,3 +81,52 @@ async fn main() -> ApplicationResult<()> {
         "Server shut down",
     )))
 }
+
+async fn migrate_data(
+    conf: Settings,
+    options: data_migrations::DataMigrationOptions,
+) -> ApplicationResult<()> {
+    let mut state = routes::AppState::new(conf).await;
+    let result = data_migrations::run_data_migrations(&state, &options).await;
+    state.store.close().await;
+
+    result.map_err(|error| {
+        logger::error!(data_migration_error=?error);
+        ApplicationError::from(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "Data migration failed",
+        ))
+    })
+}
+
+async fn list_data_migrations(conf: Settings) -> ApplicationResult<()> {
+    let mut state = routes::AppState::new(conf).await;
+    let result = data_migrations::list_data_migrations(&state).await;
+    state.store.close().await;
+
+    let statuses = result.map_err(|error| {
+        logger::error!(data_migration_error=?error);
+        ApplicationError::from(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "Failed to list the data migrations",
+        ))
+    })?;
+    for status in statuses {
+        let progress = match status.progress {
+            Some(progress) if progress.completed => {
+                format!("completed, {} rows migrated", progress.migrated_rows)
+            }
+            Some(progress) => format!(
+                "in progress after row {}, {} rows migrated",
+                progress.last_id, progress.migrated_rows
+            ),
+            None => "pending".to_string(),
+        };
+        logger::info!(
+            "Data migration {}: {} ({progress})",
+            status.version,
+            status.description
+        );
+    }
+    Ok(())
+}
@@ -1,10 +1,10 @@
 #![recursion_limit = "256"]
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use router::{
     configs::settings::{CmdLineConf, Settings},
     core::errors::{self, CustomResult},
-    logger, routes, scheduler,
+    logger, routes, scheduler, types,
 };
 
 const SCHEDULER_FLOW: &str = "SCHEDULER_FLOW";
@@ -20,6 +20,7 @@ async fn main() -> CustomResult<(), errors::ProcessTrackerError> {
         .expect("Unable to construct application configuration");
 
     let mut state = routes::AppState::new(conf).await;
+    state.event_source = types::storage::enums::PaymentEventSource::Scheduler;
     let _guard =
         logger::setup(&state.conf.log).map_err(|_| errors::ProcessTrackerError::UnexpectedFlow)?;
 
@@ -27,6 +28,10 @@ async fn main() -> CustomResult<(), errors::ProcessTrackerError> {
 
     start_scheduler(&state).await?;
 
+    state
+        .background_tasks
+        .drain(Duration::from_secs(state.conf.server.drain_timeout))
+        .await;
     state.store.close().await;
 
     eprintln!("Scheduler shut down");
@@ -13,6 +13,10 @@ const CACHE_TTI: u64 = 10 * 60;
 /// Config Cache with time_to_live as 30 mins and time_to_idle as 10 mins.
 pub static CONFIG_CACHE: Lazy<Cache> = Lazy::new(|| Cache::new(CACHE_TTL, CACHE_TTI));
 
+/// Accounts Cache, holding the merchant accounts and merchant connector accounts read on every
+/// payment, with time_to_live as 30 mins and time_to_idle as 10 mins.
+pub static ACCOUNTS_CACHE: Lazy<Cache> = Lazy::new(|| Cache::new(CACHE_TTL, CACHE_TTI));
+
 /// Trait which defines the behaviour of types that's gonna be stored in Cache
 pub trait Cacheable: Any + Send + Sync + DynClone {
     fn as_any(&self) -> &dyn Any;
@@ -16,6 +16,9 @@ pub enum StripeErrorCode {
     )]
     Unauthorized,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "permission_denied", message = "The provided API key does not have the {scope} scope.")]
+    PermissionDenied { scope: String },
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "IR_02", message = "Unrecognized request URL.")]
     InvalidRequestUrl,
 
@@ -97,6 +100,21 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such API key")]
     ApiKeyNotFound,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such routing config")]
+    RoutingConfigNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such payment schedule")]
+    PaymentScheduleNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such payment link")]
+    PaymentLinkNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such payout")]
+    PayoutNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such ephemeral key")]
+    EphemeralKeyNotFound,
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "parameter_missing", message = "Return url is not available")]
     ReturnUrlUnavailable,
 
@@ -170,6 +188,24 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payment with the specified payment_id '{payment_id}' already exists in our records.")]
     DuplicatePayment { payment_id: String },
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payout with the specified payout_id '{payout_id}' already exists in our records.")]
+    DuplicatePayout { payout_id: String },
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "A customer with the specified {field_name} already exists in our records.")]
+    DuplicateCustomer { field_name: String },
+
+    #[error(error_type = StripeErrorType::IdempotencyError, code = "", message = "Keys for idempotent requests can only be used with the same parameters they were first used with.")]
+    IdempotencyKeyReused,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_key_in_use", message = "There is currently another in-progress request using this Idempotent Key.")]
+    IdempotencyKeyInUse,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "lock_timeout", message = "This object cannot be accessed right now because another API request is currently accessing it.")]
+    LockTimeout,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly.")]
+    RateLimit,
+
     #[error(error_type = StripeErrorType::ConnectorError, code = "", message = "{code}: {message}")]
     ExternalConnectorError {
         code: String,
@@ -180,6 +216,12 @@ pub enum StripeErrorCode {
 
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "The connector provided in the request is incorrect or not available")]
     IncorrectConnectorNameGiven,
+
+    #[error(error_type = StripeErrorType::ConnectorError, code = "", message = "{connector} is currently unavailable")]
+    ConnectorUnavailable { connector: String },
+
+    #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "The service is temporarily unavailable. Retry after some time")]
+    ServiceUnavailable,
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -250,7 +292,6 @@ pub enum StripeErrorCode {
         InvoicePaymentIntentRequiresAction,
         InvoiceUpcomingNone,
         LivemodeMismatch,
-        LockTimeout,
         Missing,
         NoAccount,
         NotAllowedOnStandardAccount,
@@ -289,7 +330,6 @@ pub enum StripeErrorCode {
         PostalCodeInvalid,
         ProcessingError,
         ProductInactive,
-        RateLimit,
         ReferToCustomer,
         RefundDisputedPayment,
         ResourceAlreadyExists,
@@ -337,6 +377,7 @@ pub enum StripeErrorType {
     InvalidRequestError,
     ConnectorError,
     HyperswitchError,
+    IdempotencyError,
 }
 
 impl From<errors::ApiErrorResponse> for StripeErrorCode {
@@ -345,7 +386,9 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::Unauthorized
             | errors::ApiErrorResponse::InvalidJwtToken
             | errors::ApiErrorResponse::GenericUnauthorized { .. }
-            | errors::ApiErrorResponse::InvalidEphemeralKey => Self::Unauthorized,
+            | errors::ApiErrorResponse::InvalidEphemeralKey
+            | errors::ApiErrorResponse::InvalidCredentials
+            | errors::ApiErrorResponse::InsufficientPermission { .. } => Self::Unauthorized,
             errors::ApiErrorResponse::InvalidRequestUrl
             | errors::ApiErrorResponse::InvalidHttpMethod
             | errors::ApiErrorResponse::InvalidCardIin
@@ -385,6 +428,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::RefundFailed { data } => Self::RefundFailed, // Nothing at stripe to map
 
             errors::ApiErrorResponse::InternalServerError => Self::InternalServerError, // not a stripe code
+            errors::ApiErrorResponse::ServiceUnavailable => Self::ServiceUnavailable, // not a stripe code
             errors::ApiErrorResponse::ExternalConnectorError {
                 code,
                 message,
@@ -400,6 +444,9 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::IncorrectConnectorNameGiven => {
                 Self::IncorrectConnectorNameGiven
             }
+            errors::ApiErrorResponse::ConnectorUnavailable { connector } => {
+                Self::ConnectorUnavailable { connector }
+            }
             errors::ApiErrorResponse::MandateActive => Self::MandateActive, //not a stripe code
             errors::ApiErrorResponse::CustomerRedacted => Self::CustomerRedacted, //not a stripe code
             errors::ApiErrorResponse::ConfigNotFound => Self::ConfigNotFound, // not a stripe code
@@ -417,6 +464,11 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             }
             errors::ApiErrorResponse::MandateNotFound => Self::MandateNotFound,
             errors::ApiErrorResponse::ApiKeyNotFound => Self::ApiKeyNotFound,
+            errors::ApiErrorResponse::RoutingConfigNotFound => Self::RoutingConfigNotFound,
+            errors::ApiErrorResponse::PaymentScheduleNotFound => Self::PaymentScheduleNotFound,
+            errors::ApiErrorResponse::PaymentLinkNotFound => Self::PaymentLinkNotFound,
+            errors::ApiErrorResponse::PayoutNotFound => Self::PayoutNotFound,
+            errors::ApiErrorResponse::EphemeralKeyNotFound => Self::EphemeralKeyNotFound,
             errors::ApiErrorResponse::MandateValidationFailed { reason } => {
                 Self::PaymentIntentMandateInvalid { message: reason }
             }
@@ -446,6 +498,31 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::AddressNotFound => Self::AddressNotFound,
             errors::ApiErrorResponse::NotImplemented { .. } => Self::Unauthorized,
             errors::ApiErrorResponse::FlowNotSupported { .. } => Self::InternalServerError,
+            errors::ApiErrorResponse::PayoutNotEligible { message } => {
+                Self::PreconditionFailed { message }
+            }
+            errors::ApiErrorResponse::InvalidConnectorCredentials { connector, message } => {
+                Self::InvalidRequestData {
+                    message: format!(
+                        "The credentials provided for the {connector} connector are invalid: {message}"
+                    ),
+                }
+            }
+            errors::ApiErrorResponse::InvalidRequestFields { errors } => Self::InvalidRequestData {
+                message: errors
+                    .into_iter()
+                    .map(|error| format!("{} {}", error.field_name, error.message))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            },
+            errors::ApiErrorResponse::MerchantConnectorAccountDisabled { connector } => {
+                Self::PreconditionFailed {
+                    message: format!("The {connector} connector is disabled for the merchant"),
+                }
+            }
+            errors::ApiErrorResponse::InsufficientApiKeyScope { scope } => {
+                Self::PermissionDenied { scope }
+            }
             errors::ApiErrorResponse::PaymentUnexpectedState {
                 current_flow,
                 field_name,
@@ -457,10 +534,36 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 current_value,
                 states,
             },
+            errors::ApiErrorResponse::InvalidPaymentStatusTransition {
+                current_status,
+                target_status,
+            } => Self::PreconditionFailed {
+                message: format!(
+                    "The payment cannot move from the {current_status} status to the {target_status} status"
+                ),
+            },
             errors::ApiErrorResponse::DuplicatePayment { payment_id } => {
                 Self::DuplicatePayment { payment_id }
             }
-            errors::ApiErrorResponse::NotSupported { .. } => Self::InternalServerError,
+            errors::ApiErrorResponse::DuplicatePayout { payout_id } => {
+                Self::DuplicatePayout { payout_id }
+            }
+            errors::ApiErrorResponse::DuplicateCustomer { field_name } => {
+                Self::DuplicateCustomer { field_name }
+            }
+            errors::ApiErrorResponse::NotSupported { .. }
+            | errors::ApiErrorResponse::DuplicateUser
+            | errors::ApiErrorResponse::DuplicateBusinessProfile
+            | errors::ApiErrorResponse::UserNotFound
+            | errors::ApiErrorResponse::OrganizationNotFound
+            | errors::ApiErrorResponse::BusinessProfileNotFound
+            | errors::ApiErrorResponse::SettlementReportNotFound
+            | errors::ApiErrorResponse::DeadLetterTaskNotFound => Self::InternalServerError,
+            errors::ApiErrorResponse::IdempotencyKeyReused => Self::IdempotencyKeyReused,
+            errors::ApiErrorResponse::IdempotentRequestInProgress => Self::IdempotencyKeyInUse,
+            errors::ApiErrorResponse::ConcurrentUpdate
+            | errors::ApiErrorResponse::PaymentProcessingInProgress => Self::LockTimeout,
+            errors::ApiErrorResponse::RateLimitExceeded => Self::RateLimit,
         }
     }
 }
@@ -471,6 +574,7 @@ impl actix_web::ResponseError for StripeErrorCode {
 
         match self {
             Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::PermissionDenied { .. } => StatusCode::FORBIDDEN,
             Self::InvalidRequestUrl => StatusCode::NOT_FOUND,
             Self::ParameterUnknown { .. } => StatusCode::UNPROCESSABLE_ENTITY,
             Self::ParameterMissing { .. }
@@ -490,6 +594,11 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::MerchantConnectorAccountNotFound
             | Self::MandateNotFound
             | Self::ApiKeyNotFound
+            | Self::RoutingConfigNotFound
+            | Self::PaymentScheduleNotFound
+            | Self::PaymentLinkNotFound
+            | Self::PayoutNotFound
+            | Self::EphemeralKeyNotFound
             | Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount
             | Self::DuplicatePaymentMethod
@@ -507,12 +616,19 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::PaymentIntentMandateInvalid { .. }
             | Self::PaymentIntentUnexpectedState { .. }
             | Self::DuplicatePayment { .. }
+            | Self::DuplicatePayout { .. }
+            | Self::DuplicateCustomer { .. }
+            | Self::IdempotencyKeyReused
             | Self::IncorrectConnectorNameGiven => StatusCode::BAD_REQUEST,
+            Self::IdempotencyKeyInUse | Self::LockTimeout => StatusCode::CONFLICT,
+            Self::RateLimit => StatusCode::TOO_MANY_REQUESTS,
             Self::RefundFailed
             | Self::InternalServerError
             | Self::MandateActive
             | Self::CustomerRedacted => StatusCode::INTERNAL_SERVER_ERROR,
-            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            Self::ReturnUrlUnavailable
+            | Self::ConnectorUnavailable { .. }
+            | Self::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
             Self::ExternalConnectorError { status_code, .. } => {
                 StatusCode::from_u16(*status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
             }
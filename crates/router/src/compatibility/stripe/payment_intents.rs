@@ -57,7 +57,7 @@ pub async fn payment_intents_create(
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsWrite),
     )
     .await
 }
@@ -78,10 +78,11 @@ pub async fn payment_intents_retrieve(
         merchant_connector_details: None,
     };
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), api_types::ApiKeyScope::PaymentsRead) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     wrap::compatibility_api_wrap::<
         _,
@@ -136,10 +137,11 @@ pub async fn payment_intents_retrieve_with_gateway_creds(
         merchant_connector_details: json_payload.merchant_connector_details.clone(),
         ..Default::default()
     };
-    let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, _auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), api_types::ApiKeyScope::PaymentsRead) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     wrap::compatibility_api_wrap::<
         _,
@@ -195,9 +197,13 @@ pub async fn payment_intents_update(
 
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(payment_id));
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Some(api_types::ApiKeyScope::PaymentsWrite),
+    ) {
         Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
+        Err(err) => return api::log_and_return_error_response(err),
     };
 
     wrap::compatibility_api_wrap::<
@@ -255,11 +261,14 @@ pub async fn payment_intents_confirm(
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(payment_id));
     payload.confirm = Some(true);
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Some(api_types::ApiKeyScope::PaymentsWrite),
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     wrap::compatibility_api_wrap::<
         _,
@@ -334,7 +343,7 @@ pub async fn payment_intents_capture(
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsWrite),
     )
     .await
 }
@@ -361,10 +370,11 @@ pub async fn payment_intents_cancel(
     let mut payload: payment_types::PaymentsCancelRequest = stripe_payload.into();
     payload.payment_id = payment_id;
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), api_types::ApiKeyScope::PaymentsWrite) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     wrap::compatibility_api_wrap::<
         _,
@@ -422,7 +432,7 @@ pub async fn payment_intent_list(
         |state, merchant_account, req| {
             payments::list_payments(&*state.store, merchant_account, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsRead),
     )
     .await
 }
@@ -219,12 +219,13 @@ impl From<api_enums::IntentStatus> for StripePaymentStatus {
         match item {
             api_enums::IntentStatus::Succeeded => Self::Succeeded,
             api_enums::IntentStatus::Failed => Self::Canceled,
-            api_enums::IntentStatus::Processing => Self::Processing,
+            api_enums::IntentStatus::Processing
+            | api_enums::IntentStatus::RequiresMerchantAction => Self::Processing,
             api_enums::IntentStatus::RequiresCustomerAction => Self::RequiresAction,
             api_enums::IntentStatus::RequiresPaymentMethod => Self::RequiresPaymentMethod,
             api_enums::IntentStatus::RequiresConfirmation => Self::RequiresConfirmation,
             api_enums::IntentStatus::RequiresCapture => Self::RequiresCapture,
-            api_enums::IntentStatus::Cancelled => Self::Canceled,
+            api_enums::IntentStatus::Cancelled | api_enums::IntentStatus::Expired => Self::Canceled,
         }
     }
 }
@@ -9,7 +9,7 @@ use crate::{
     core::refunds,
     routes,
     services::{api, authentication as auth},
-    types::api::refunds as refund_types,
+    types::api::{self as api_types, refunds as refund_types},
 };
 
 #[instrument(skip_all)]
@@ -44,7 +44,7 @@ pub async fn refund_create(
         &req,
         create_refund_req,
         refunds::refund_create_core,
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(api_types::ApiKeyScope::RefundsWrite),
     )
     .await
 }
@@ -81,7 +81,7 @@ pub async fn refund_retrieve(
                 refunds::refund_retrieve_core,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsRead),
     )
     .await
 }
@@ -114,7 +114,7 @@ pub async fn refund_update(
         |state, merchant_account, req| {
             refunds::refund_update_core(&*state.store, merchant_account, &refund_id, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(api_types::ApiKeyScope::RefundsWrite),
     )
     .await
 }
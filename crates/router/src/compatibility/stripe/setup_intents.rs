@@ -54,7 +54,7 @@ pub async fn setup_intents_create(
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsWrite),
     )
     .await
 }
@@ -75,10 +75,11 @@ pub async fn setup_intents_retrieve(
         merchant_connector_details: None,
     };
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), api_types::ApiKeyScope::PaymentsRead) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     wrap::compatibility_api_wrap::<
         _,
@@ -130,11 +131,14 @@ pub async fn setup_intents_update(
     let mut payload: payment_types::PaymentsRequest = stripe_payload.into();
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(setup_id));
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Some(api_types::ApiKeyScope::PaymentsWrite),
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     wrap::compatibility_api_wrap::<
         _,
@@ -187,11 +191,14 @@ pub async fn setup_intents_confirm(
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(setup_id));
     payload.confirm = Some(true);
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Some(api_types::ApiKeyScope::PaymentsWrite),
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     wrap::compatibility_api_wrap::<
         _,
@@ -189,11 +189,12 @@ impl From<api_enums::IntentStatus> for StripeSetupStatus {
             api_enums::IntentStatus::RequiresCustomerAction => Self::RequiresAction,
             api_enums::IntentStatus::RequiresPaymentMethod => Self::RequiresPaymentMethod,
             api_enums::IntentStatus::RequiresConfirmation => Self::RequiresConfirmation,
-            api_enums::IntentStatus::RequiresCapture => {
+            api_enums::IntentStatus::RequiresCapture
+            | api_enums::IntentStatus::RequiresMerchantAction => {
                 logger::error!("Invalid status change");
                 Self::Canceled
             }
-            api_enums::IntentStatus::Cancelled => Self::Canceled,
+            api_enums::IntentStatus::Cancelled | api_enums::IntentStatus::Expired => Self::Canceled,
         }
     }
 }
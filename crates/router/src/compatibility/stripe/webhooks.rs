@@ -1,5 +1,7 @@
 use api_models::{
     enums::DisputeStatus,
+    payment_schedules::PaymentScheduleResponse,
+    payouts::PayoutResponse,
     webhooks::{self as api},
 };
 use serde::Serialize;
@@ -24,6 +26,8 @@ pub enum StripeWebhookObject {
     PaymentIntent(StripePaymentIntentResponse),
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
+    PaymentSchedule(Box<PaymentScheduleResponse>),
+    Payout(Box<PayoutResponse>),
 }
 
 #[derive(Serialize)]
@@ -97,6 +101,10 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::DisputeDetails(dispute) => {
                 Self::Dispute((*dispute).into())
             }
+            api::OutgoingWebhookContent::PaymentScheduleDetails(payment_schedule) => {
+                Self::PaymentSchedule(payment_schedule)
+            }
+            api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout(payout),
         }
     }
 }
@@ -107,6 +115,8 @@ impl StripeWebhookObject {
             Self::PaymentIntent(p) => p.id.to_owned(),
             Self::Refund(r) => Some(r.id.to_owned()),
             Self::Dispute(d) => Some(d.id.to_owned()),
+            Self::PaymentSchedule(s) => Some(s.schedule_id.to_owned()),
+            Self::Payout(p) => Some(p.payout_id.to_owned()),
         }
     }
 }
@@ -71,6 +71,7 @@ where
         Ok(api::ApplicationResponse::Form(form_data)) => api::build_redirection_form(&form_data)
             .respond_to(request)
             .map_into_boxed_body(),
+        Ok(api::ApplicationResponse::Html(html)) => api::http_response_html(html),
         Err(error) => {
             logger::error!(api_response_error=?error);
             api::log_and_return_error_response(error)
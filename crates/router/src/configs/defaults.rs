@@ -7,6 +7,7 @@ impl Default for super::settings::Server {
             request_body_limit: 16 * 1024, // POST request body is limited to 16KiB
             base_url: "http://localhost:8080".into(),
             shutdown_timeout: 30,
+            drain_timeout: 30,
         }
     }
 }
@@ -53,6 +54,9 @@ impl Default for super::settings::SupportedConnectors {
     fn default() -> Self {
         Self {
             wallets: ["klarna", "braintree"].map(Into::into).into(),
+            multiple_capture: ["adyen"].map(Into::into).into(),
+            payouts: ["adyen"].map(Into::into).into(),
+            split_payments: ["stripe"].map(Into::into).into(),
             /* cards: [
                 "adyen",
                 "authorizedotnet",
@@ -84,6 +88,137 @@ impl Default for super::settings::EphemeralConfig {
     }
 }
 
+impl Default for super::settings::IdempotencyConfig {
+    fn default() -> Self {
+        Self { ttl: 86400 }
+    }
+}
+
+impl Default for super::settings::PaymentLockConfig {
+    fn default() -> Self {
+        Self { ttl: 60 }
+    }
+}
+
+impl Default for super::settings::UserAuthConfig {
+    fn default() -> Self {
+        Self {
+            access_token_validity: 3600,
+            refresh_token_validity: 604800,
+        }
+    }
+}
+
+impl Default for super::settings::PaymentExpiryConfig {
+    fn default() -> Self {
+        Self {
+            default_session_expiry: 86400,
+        }
+    }
+}
+
+impl Default for super::settings::ReplicaRoutingConfig {
+    fn default() -> Self {
+        Self {
+            // Deployments serving only the dashboard read from the replica unless configured
+            // otherwise
+            enabled: cfg!(all(feature = "olap", not(feature = "oltp"))),
+            max_lag: 5,
+            lag_check_interval: 10,
+        }
+    }
+}
+
+impl Default for super::settings::PaymentScheduleConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            retry_interval: 86400,
+        }
+    }
+}
+
+impl Default for super::settings::SuccessRateRoutingConfig {
+    fn default() -> Self {
+        Self {
+            bucket_size: 300,
+            bucket_count: 12,
+            min_sample_size: 20,
+        }
+    }
+}
+
+impl Default for super::settings::CardVault {
+    fn default() -> Self {
+        Self {
+            #[cfg(feature = "kms")]
+            kms_encrypted_master_key: String::new(),
+            #[cfg(not(feature = "kms"))]
+            master_key: String::new(),
+            // 30 days
+            data_key_rotation_interval: 2592000,
+        }
+    }
+}
+
+impl Default for super::settings::CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            failure_threshold: 5,
+            cool_down_time: 30,
+            health_check_urls: std::collections::HashMap::new(),
+            health_check_timeout: 2000,
+        }
+    }
+}
+
+impl Default for super::settings::RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            api_key: super::settings::RateLimitBucket {
+                capacity: 100,
+                refill_rate: 50,
+            },
+            ip: super::settings::RateLimitBucket {
+                capacity: 20,
+                refill_rate: 5,
+            },
+            trusted_proxies: Vec::new(),
+        }
+    }
+}
+
+impl Default for super::settings::HealthCheckConfig {
+    fn default() -> Self {
+        Self {
+            canary_connector_url: None,
+            timeout: 2000,
+        }
+    }
+}
+
+impl Default for super::settings::RequestLogConfig {
+    fn default() -> Self {
+        Self {
+            verbosity: super::settings::LogVerbosity::Summary,
+            routes: std::collections::HashMap::new(),
+            connector_verbosity: super::settings::LogVerbosity::Summary,
+        }
+    }
+}
+
+impl Default for super::settings::ForexConfig {
+    fn default() -> Self {
+        Self {
+            provider: String::from("static"),
+            cache_ttl: 3600,
+            rates: std::collections::HashMap::new(),
+        }
+    }
+}
+
 impl Default for super::settings::SchedulerSettings {
     fn default() -> Self {
         Self {
@@ -34,6 +34,23 @@ pub enum Subcommand {
     #[cfg(feature = "openapi")]
     /// Generate the OpenAPI specification file from code.
     GenerateOpenapiSpec,
+    /// Run the pending data migrations, resuming those which were interrupted.
+    MigrateData {
+        /// Run only the data migration of this version.
+        #[arg(long)]
+        version: Option<String>,
+        /// Number of rows migrated in a batch.
+        #[arg(long, default_value_t = 100)]
+        batch_size: i64,
+        /// Milliseconds to wait between batches, to limit the load on the database.
+        #[arg(long, default_value_t = 100)]
+        batch_interval: u64,
+        /// Pause the data migrations after this many batches, to resume them in a later run.
+        #[arg(long)]
+        max_batches: Option<u64>,
+    },
+    /// List the data migrations along with their progress.
+    ListDataMigrations,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -43,8 +60,8 @@ pub struct Settings {
     pub proxy: Proxy,
     pub env: Env,
     pub master_database: Database,
-    #[cfg(feature = "olap")]
     pub replica_database: Database,
+    pub replica_routing: ReplicaRoutingConfig,
     pub redis: RedisSettings,
     pub log: Log,
     pub secrets: Secrets,
@@ -52,6 +69,17 @@ pub struct Settings {
     pub connectors: Connectors,
     pub refund: Refund,
     pub eph_key: EphemeralConfig,
+    pub user_auth: UserAuthConfig,
+    pub idempotency: IdempotencyConfig,
+    pub payment_lock: PaymentLockConfig,
+    pub payment_expiry: PaymentExpiryConfig,
+    pub payment_schedule: PaymentScheduleConfig,
+    pub success_rate_routing: SuccessRateRoutingConfig,
+    pub circuit_breaker: CircuitBreakerConfig,
+    pub request_log: RequestLogConfig,
+    pub health_check: HealthCheckConfig,
+    pub rate_limit: RateLimitConfig,
+    pub forex: ForexConfig,
     pub scheduler: Option<SchedulerSettings>,
     #[cfg(feature = "kv_store")]
     pub drainer: DrainerSettings,
@@ -60,6 +88,10 @@ pub struct Settings {
     pub pm_filters: ConnectorFilters,
     pub bank_config: BankRedirectConfig,
     pub api_keys: ApiKeys,
+    pub card_vault: CardVault,
+    pub network_tokenization: NetworkTokenization,
+    pub payment_links: PaymentLinks,
+    pub apple_pay: ApplePay,
     #[cfg(feature = "kms")]
     pub kms: kms::KmsConfig,
 }
@@ -191,6 +223,167 @@ pub struct EphemeralConfig {
     pub validity: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct UserAuthConfig {
+    /// Time (in seconds) for which the JWT issued to a dashboard user on signing in is valid
+    pub access_token_validity: i64,
+    /// Time (in seconds) for which the refresh token issued to a dashboard user is valid
+    pub refresh_token_validity: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct IdempotencyConfig {
+    /// Time (in seconds) for which the response to an idempotent request is retained
+    pub ttl: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentLockConfig {
+    /// Time (in seconds) after which the lock taken on a payment being confirmed or captured
+    /// expires, if it is not released by then
+    pub ttl: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentExpiryConfig {
+    /// Time (in seconds) after which a payment expires, unless specified in the request
+    pub default_session_expiry: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ReplicaRoutingConfig {
+    /// Whether the queries of list endpoints are directed to the replica database
+    pub enabled: bool,
+    /// Replication lag (in seconds) of the replica beyond which reads fall back to the master
+    /// database
+    pub max_lag: u64,
+    /// Interval (in seconds) at which the replication lag of the replica is checked
+    pub lag_check_interval: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentScheduleConfig {
+    /// Number of times a declined payment of a schedule cycle is retried before the cycle is
+    /// considered failed
+    pub max_retries: i32,
+    /// Time (in seconds) after which a declined payment of a schedule cycle is retried
+    pub retry_interval: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SuccessRateRoutingConfig {
+    /// Duration (in seconds) of each bucket in which authorization outcomes are counted
+    pub bucket_size: i64,
+    /// Number of most recent buckets considered while computing the success rate of a connector
+    pub bucket_count: i64,
+    /// Minimum number of authorizations in the window for the success rate of a connector to be
+    /// considered while routing
+    pub min_sample_size: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CircuitBreakerConfig {
+    /// Whether calls to a connector are short circuited after repeated failures
+    pub enabled: bool,
+    /// Number of consecutive timeouts or server errors from a connector after which the circuit
+    /// is opened
+    pub failure_threshold: i64,
+    /// Time (in seconds) for which the circuit stays open, before a single call probes whether
+    /// the connector has recovered
+    pub cool_down_time: i64,
+    /// URLs of the connectors, by connector name, which are checked before the probe of a half
+    /// open circuit is made. A connector without a health check URL is probed with the call
+    /// itself.
+    pub health_check_urls: HashMap<String, String>,
+    /// Time (in milliseconds) within which the health check of a connector must respond
+    pub health_check_timeout: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RateLimitConfig {
+    pub enabled: bool,
+    /// Limit of the requests made with an API key, unless overridden for the merchant
+    pub api_key: RateLimitBucket,
+    /// Limit of the requests made without an API key, per IP address
+    pub ip: RateLimitBucket,
+    /// IP addresses of the proxies in front of the application, whose `X-Forwarded-For` headers
+    /// are trusted to carry the IP address of the client
+    pub trusted_proxies: Vec<std::net::IpAddr>,
+}
+
+/// A token bucket which holds up to `capacity` requests, and is refilled with `refill_rate`
+/// requests every second.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub struct RateLimitBucket {
+    pub capacity: u64,
+    pub refill_rate: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct HealthCheckConfig {
+    /// URL of a connector which must be reachable for the router to be ready, if any
+    pub canary_connector_url: Option<String>,
+    /// Time (in milliseconds) within which each dependency must respond to be considered up
+    pub timeout: u64,
+}
+
+/// How much of a request and its response is logged.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LogVerbosity {
+    /// The request is not logged
+    Off,
+    /// The method, path, status code and latency of the request are logged
+    Summary,
+    /// The bodies of the request and its response are logged as well, with personal and card data
+    /// masked
+    Full,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RequestLogConfig {
+    /// Verbosity of the logs of requests to the API, unless overridden for the route
+    pub verbosity: LogVerbosity,
+    /// Verbosity of the logs of requests to the API, by the path prefix of the route. The
+    /// verbosity of the longest matching prefix applies.
+    pub routes: HashMap<String, LogVerbosity>,
+    /// Verbosity of the logs of requests made to connectors
+    pub connector_verbosity: LogVerbosity,
+}
+
+impl RequestLogConfig {
+    pub fn get_route_verbosity(&self, path: &str) -> LogVerbosity {
+        self.routes
+            .iter()
+            .filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.verbosity, |(_, verbosity)| *verbosity)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ForexConfig {
+    /// Name of the provider the exchange rates are fetched from
+    pub provider: String,
+    /// Time (in seconds) for which exchange rates fetched from the provider are cached
+    pub cache_ttl: i64,
+    /// Exchange rates of currencies against a common base currency, used by the `static`
+    /// provider
+    pub rates: HashMap<api_models::enums::Currency, f64>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct Jwekey {
@@ -220,6 +413,7 @@ pub struct Server {
     pub request_body_limit: usize,
     pub base_url: String,
     pub shutdown_timeout: u64,
+    pub drain_timeout: u64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -241,13 +435,16 @@ pub struct Database {
 #[serde(default)]
 pub struct SupportedConnectors {
     pub wallets: Vec<String>,
+    pub multiple_capture: Vec<String>,
+    pub payouts: Vec<String>,
+    pub split_payments: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct Connectors {
     pub aci: ConnectorParams,
-    pub adyen: ConnectorParams,
+    pub adyen: ConnectorParamsWithPayoutUrl,
     pub airwallex: ConnectorParams,
     pub applepay: ConnectorParams,
     pub authorizedotnet: ConnectorParams,
@@ -255,8 +452,11 @@ pub struct Connectors {
     pub bluesnap: ConnectorParams,
     pub braintree: ConnectorParams,
     pub checkout: ConnectorParams,
+    pub coinbase: ConnectorParams,
     pub cybersource: ConnectorParams,
     pub dlocal: ConnectorParams,
+    pub dummyconnector: ConnectorParams,
+    pub externalprocessor: ExternalProcessorParams,
     pub fiserv: ConnectorParams,
     pub globalpay: ConnectorParams,
     pub klarna: ConnectorParams,
@@ -264,10 +464,12 @@ pub struct Connectors {
     pub multisafepay: ConnectorParams,
     pub nuvei: ConnectorParams,
     pub paypal: ConnectorParams,
-    pub payu: ConnectorParams,
+    pub payu: ConnectorParamsWithRegionalUrls,
     pub rapyd: ConnectorParams,
+    pub razorpay: ConnectorParams,
     pub shift4: ConnectorParams,
     pub stripe: ConnectorParams,
+    pub threedsecureio: ConnectorParams,
     pub worldline: ConnectorParams,
     pub worldpay: ConnectorParams,
     pub trustpay: ConnectorParamsWithMoreUrls,
@@ -289,6 +491,29 @@ pub struct ConnectorParamsWithMoreUrls {
     pub base_url_bank_redirects: String,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorParamsWithRegionalUrls {
+    pub base_url: String,
+    pub base_url_latam: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorParamsWithPayoutUrl {
+    pub base_url: String,
+    pub payout_base_url: String,
+}
+
+/// The gateways which merchants can integrate through the external processor connector, which
+/// is disabled when no gateway is allowed
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ExternalProcessorParams {
+    /// Base urls of the allowed gateways, which have to use https
+    pub allowed_base_urls: Vec<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct SchedulerSettings {
@@ -348,6 +573,58 @@ pub struct ApiKeys {
     pub hash_key: String,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CardVault {
+    /// Base64-encoded (KMS encrypted) ciphertext of the master key used for encrypting the data
+    /// keys of the card vault
+    #[cfg(feature = "kms")]
+    pub kms_encrypted_master_key: String,
+
+    /// Hex-encoded 32-byte long (64 characters long when hex-encoded) master key used for
+    /// encrypting the data keys of the card vault
+    #[cfg(not(feature = "kms"))]
+    pub master_key: String,
+
+    /// Time (in seconds) after which a new data key is generated for encrypting cards. Cards
+    /// encrypted with older data keys can still be decrypted.
+    pub data_key_rotation_interval: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct NetworkTokenization {
+    /// Whether network tokens are provisioned for the cards stored in the card vault
+    pub enabled: bool,
+    /// Visa Token Service (VTS)
+    pub visa: TokenServiceProvider,
+    /// Mastercard Digital Enablement Service (MDES)
+    pub mastercard: TokenServiceProvider,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct PaymentLinks {
+    /// URL of a checkout page hosted by the SDK, which customers opening a payment link are
+    /// redirected to. A minimal checkout page is served by the router if not configured
+    pub sdk_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ApplePay {
+    /// Apple Root CA - G3 certificate in PEM format, which the signatures of Apple Pay tokens
+    /// decrypted by the router are verified against
+    pub root_certificate: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct TokenServiceProvider {
+    pub base_url: String,
+    pub api_key: String,
+}
+
 impl Settings {
     pub fn new() -> ApplicationResult<Self> {
         Self::with_config_path(None)
@@ -378,7 +655,10 @@ impl Settings {
                     .separator("__")
                     .list_separator(",")
                     .with_list_parse_key("redis.cluster_urls")
-                    .with_list_parse_key("connectors.supported.wallets"),
+                    .with_list_parse_key("connectors.supported.wallets")
+                    .with_list_parse_key("connectors.supported.multiple_capture")
+                    .with_list_parse_key("connectors.supported.payouts")
+                    .with_list_parse_key("connectors.supported.split_payments"),
             )
             .build()?;
 
@@ -392,8 +672,13 @@ impl Settings {
     pub fn validate(&self) -> ApplicationResult<()> {
         self.server.validate()?;
         self.master_database.validate()?;
-        #[cfg(feature = "olap")]
-        self.replica_database.validate()?;
+        if self.replica_routing.enabled {
+            self.replica_database.validate()?;
+        }
+        self.replica_routing.validate()?;
+        self.payment_lock.validate()?;
+        self.rate_limit.api_key.validate()?;
+        self.rate_limit.ip.validate()?;
         self.redis.validate().map_err(|error| {
             println!("{error}");
             ApplicationError::InvalidConfigurationValueError("Redis configuration".into())
@@ -422,6 +707,7 @@ impl Settings {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.api_keys.validate()?;
+        self.card_vault.validate()?;
         #[cfg(feature = "kms")]
         self.kms
             .validate()
@@ -93,6 +93,36 @@ impl super::settings::Database {
     }
 }
 
+impl super::settings::ReplicaRoutingConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.enabled && self.lag_check_interval == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "replica lag check interval must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::PaymentLockConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.ttl <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment lock ttl must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RateLimitBucket {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.capacity == 0 || self.refill_rate == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit capacity and refill rate must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::SupportedConnectors {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         common_utils::fp_utils::when(self.wallets.is_empty(), || {
@@ -112,6 +142,7 @@ impl super::settings::Connectors {
         self.braintree.validate()?;
         self.checkout.validate()?;
         self.cybersource.validate()?;
+        self.externalprocessor.validate()?;
         self.globalpay.validate()?;
         self.klarna.validate()?;
         self.shift4.validate()?;
@@ -134,6 +165,31 @@ impl super::settings::ConnectorParams {
     }
 }
 
+impl super::settings::ExternalProcessorParams {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(
+            self.allowed_base_urls
+                .iter()
+                .any(|base_url| !base_url.starts_with("https://")),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "allowed external processor base URLs must use https".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ConnectorParamsWithPayoutUrl {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector base URL must not be empty".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::SchedulerSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -196,3 +252,29 @@ impl super::settings::ApiKeys {
         })
     }
 }
+
+impl super::settings::CardVault {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.data_key_rotation_interval <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card vault data key rotation interval must be positive".into(),
+            ))
+        })?;
+
+        #[cfg(feature = "kms")]
+        return when(self.kms_encrypted_master_key.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card vault master key must not be empty when KMS feature is enabled".into(),
+            ))
+        });
+
+        #[cfg(not(feature = "kms"))]
+        when(self.master_key.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card vault master key must not be empty".into(),
+            ))
+        })
+    }
+}
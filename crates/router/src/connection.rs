@@ -1,11 +1,18 @@
-use async_bb8_diesel::{AsyncConnection, ConnectionError};
+use std::sync::{atomic, Arc};
+
+use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl, ConnectionError};
 use bb8::{CustomizeConnection, PooledConnection};
-use diesel::PgConnection;
+use diesel::{sql_types, PgConnection, QueryableByName};
 use error_stack::{IntoReport, ResultExt};
 #[cfg(feature = "kms")]
 use external_services::kms;
+use router_env::logger;
 
-use crate::{configs::settings::Database, errors};
+use crate::{
+    configs::settings::{Database, ReplicaRoutingConfig},
+    errors,
+    routes::metrics,
+};
 
 pub type PgPool = bb8::Pool<async_bb8_diesel::ConnectionManager<PgConnection>>;
 
@@ -72,31 +79,44 @@ pub async fn diesel_make_pg_pool(
         .expect("Failed to create PostgreSQL connection pool")
 }
 
+/// Returns a connection for reads which may be followed by writes based on what was read, which
+/// must see the latest state of the master database. Only builds without OLTP read from the
+/// replica database.
 pub async fn pg_connection_read(
     store: &crate::services::Store,
 ) -> errors::CustomResult<
     PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
     errors::StorageError,
 > {
-    // If only OLAP is enabled get replica pool.
-    #[cfg(all(feature = "olap", not(feature = "oltp")))]
-    let pool = &store.replica_pool;
-
-    // If either one of these are true we need to get master pool.
-    //  1. Only OLTP is enabled.
-    //  2. Both OLAP and OLTP is enabled.
-    //  3. Both OLAP and OLTP is disabled.
-    #[cfg(any(
-        all(not(feature = "olap"), feature = "oltp"),
-        all(feature = "olap", feature = "oltp"),
-        all(not(feature = "olap"), not(feature = "oltp"))
-    ))]
-    let pool = &store.master_pool;
-
-    pool.get()
-        .await
-        .into_report()
-        .change_context(errors::StorageError::DatabaseConnectionError)
+    if cfg!(all(feature = "olap", not(feature = "oltp"))) {
+        pg_connection_replica_read(store).await
+    } else {
+        get_pooled_connection(&store.master_pool, "master").await
+    }
+}
+
+/// Returns a connection to the replica database when reads are routed to it, falling back to the
+/// master database while the replica lags behind or cannot be connected to. The replica can lag
+/// behind the master database, and is only used by list endpoints, which tolerate stale results.
+pub async fn pg_connection_replica_read(
+    store: &crate::services::Store,
+) -> errors::CustomResult<
+    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    errors::StorageError,
+> {
+    if let Some(replica_pool) = &store.replica_pool {
+        if store.is_replica_available.load(atomic::Ordering::SeqCst) {
+            match get_pooled_connection(replica_pool, "replica").await {
+                Ok(conn) => return Ok(conn),
+                Err(error) => {
+                    logger::warn!(?error, "Failed to connect to the replica database");
+                }
+            }
+        }
+        metrics::REPLICA_READ_FALLBACK.add(&metrics::CONTEXT, 1, &[]);
+    }
+
+    get_pooled_connection(&store.master_pool, "master").await
 }
 
 pub async fn pg_connection_write(
@@ -106,10 +126,103 @@ pub async fn pg_connection_write(
     errors::StorageError,
 > {
     // Since all writes should happen to master DB only choose master DB.
-    let pool = &store.master_pool;
+    get_pooled_connection(&store.master_pool, "master").await
+}
 
-    pool.get()
-        .await
-        .into_report()
-        .change_context(errors::StorageError::DatabaseConnectionError)
+/// Gets a connection from the pool, recording the time waited for it along with the usage of the
+/// pool. Waiting is bounded by the connection timeout of the pool, after which the pool is
+/// considered exhausted so that the request can be shed instead of piling up behind it.
+async fn get_pooled_connection<'a>(
+    pool: &'a PgPool,
+    pool_name: &'static str,
+) -> errors::CustomResult<
+    PooledConnection<'a, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    errors::StorageError,
+> {
+    let start_instant = std::time::Instant::now();
+    let conn = pool.get().await;
+
+    let attributes = [metrics::request::add_attributes("pool", pool_name)];
+    let state = pool.state();
+    metrics::DB_POOL_WAIT_TIME.record(
+        &metrics::CONTEXT,
+        start_instant.elapsed().as_secs_f64(),
+        &attributes,
+    );
+    metrics::DB_POOL_CONNECTIONS_IN_USE.record(
+        &metrics::CONTEXT,
+        f64::from(state.connections.saturating_sub(state.idle_connections)),
+        &attributes,
+    );
+    metrics::DB_POOL_IDLE_CONNECTIONS.record(
+        &metrics::CONTEXT,
+        f64::from(state.idle_connections),
+        &attributes,
+    );
+
+    match conn {
+        Ok(conn) => Ok(conn),
+        Err(bb8::RunError::TimedOut) => {
+            metrics::DB_POOL_EXHAUSTED.add(&metrics::CONTEXT, 1, &attributes);
+            Err(errors::StorageError::PoolExhausted).into_report()
+        }
+        Err(error) => Err(error)
+            .into_report()
+            .change_context(errors::StorageError::DatabaseConnectionError),
+    }
+}
+
+#[derive(QueryableByName)]
+struct ReplicationLag {
+    #[diesel(sql_type = sql_types::BigInt)]
+    lag: i64,
+}
+
+/// Returns the time (in seconds) since the replica last replayed a transaction of the master
+/// database, which is zero when it has replayed everything it has received.
+async fn get_replication_lag(pool: &PgPool) -> errors::CustomResult<i64, errors::StorageError> {
+    let conn = get_pooled_connection(pool, "replica").await?;
+
+    diesel::sql_query(
+        "SELECT CASE WHEN pg_last_wal_receive_lsn() = pg_last_wal_replay_lsn() THEN 0 ELSE \
+         COALESCE(CAST(EXTRACT(EPOCH FROM now() - pg_last_xact_replay_timestamp()) AS BIGINT), 0) \
+         END AS lag",
+    )
+    .get_result_async::<ReplicationLag>(&*conn)
+    .await
+    .into_report()
+    .change_context(errors::StorageError::DatabaseError(
+        storage_models::errors::DatabaseError::Others.into(),
+    ))
+    .map(|replication_lag| replication_lag.lag)
+}
+
+/// Periodically checks the replication lag of the replica, so that reads are routed to the master
+/// database while the replica lags behind by more than the configured limit or cannot be reached.
+pub async fn monitor_replica_lag(
+    pool: PgPool,
+    config: ReplicaRoutingConfig,
+    is_replica_available: Arc<atomic::AtomicBool>,
+) {
+    let mut interval =
+        tokio::time::interval(std::time::Duration::from_secs(config.lag_check_interval));
+    let max_lag = i64::try_from(config.max_lag).unwrap_or(i64::MAX);
+
+    loop {
+        interval.tick().await;
+        let is_available = match get_replication_lag(&pool).await {
+            Ok(lag) => lag <= max_lag,
+            Err(error) => {
+                logger::error!(?error, "Failed to check the replication lag of the replica");
+                false
+            }
+        };
+
+        if is_replica_available.swap(is_available, atomic::Ordering::SeqCst) != is_available {
+            logger::warn!(
+                is_available,
+                "Availability of the replica database for reads changed"
+            );
+        }
+    }
 }
@@ -7,8 +7,11 @@ pub mod bambora;
 pub mod bluesnap;
 pub mod braintree;
 pub mod checkout;
+pub mod coinbase;
 pub mod cybersource;
 pub mod dlocal;
+pub mod dummyconnector;
+pub mod externalprocessor;
 pub mod fiserv;
 pub mod globalpay;
 pub mod klarna;
@@ -17,6 +20,7 @@ pub mod nuvei;
 pub mod paypal;
 pub mod payu;
 pub mod rapyd;
+pub mod razorpay;
 pub mod shift4;
 pub mod stripe;
 pub mod trustpay;
@@ -29,8 +33,9 @@ pub mod mollie;
 pub use self::{
     aci::Aci, adyen::Adyen, airwallex::Airwallex, applepay::Applepay,
     authorizedotnet::Authorizedotnet, bambora::Bambora, bluesnap::Bluesnap, braintree::Braintree,
-    checkout::Checkout, cybersource::Cybersource, dlocal::Dlocal, fiserv::Fiserv,
+    checkout::Checkout, coinbase::Coinbase, cybersource::Cybersource, dlocal::Dlocal,
+    dummyconnector::DummyConnector, externalprocessor::Externalprocessor, fiserv::Fiserv,
     globalpay::Globalpay, klarna::Klarna, mollie::Mollie, multisafepay::Multisafepay, nuvei::Nuvei,
-    paypal::Paypal, payu::Payu, rapyd::Rapyd, shift4::Shift4, stripe::Stripe, trustpay::Trustpay,
-    worldline::Worldline, worldpay::Worldpay,
+    paypal::Paypal, payu::Payu, rapyd::Rapyd, razorpay::Razorpay, shift4::Shift4, stripe::Stripe,
+    trustpay::Trustpay, worldline::Worldline, worldpay::Worldpay,
 };
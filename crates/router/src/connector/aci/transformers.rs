@@ -57,6 +57,14 @@ pub enum PaymentDetails {
     Klarna,
     #[serde(rename = "bankRedirect")]
     BankRedirect,
+    #[serde(rename = "bankDebit")]
+    BankDebit,
+    #[serde(rename = "upi")]
+    Upi,
+    #[serde(rename = "crypto")]
+    Crypto,
+    #[serde(rename = "giftCard")]
+    GiftCard,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize)]
@@ -111,13 +119,17 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for AciPaymentsRequest {
             api::PaymentMethodData::PayLater(_) => PaymentDetails::Klarna,
             api::PaymentMethodData::Wallet(_) => PaymentDetails::Wallet,
             api::PaymentMethodData::BankRedirect(_) => PaymentDetails::BankRedirect,
+            api::PaymentMethodData::BankDebit(_) => PaymentDetails::BankDebit,
+            api::PaymentMethodData::Upi(_) => PaymentDetails::Upi,
+            api::PaymentMethodData::Crypto(_) => PaymentDetails::Crypto,
+            api::PaymentMethodData::GiftCard(_) => PaymentDetails::GiftCard,
         };
 
         let auth = AciAuthType::try_from(&item.connector_auth_type)?;
         let aci_payment_request = Self {
             payment_method: payment_details,
             entity_id: auth.entity_id,
-            amount: item.request.amount,
+            amount: item.request.amount.get_amount_as_i64(),
             currency: item.request.currency.to_string(),
             payment_type: AciPaymentType::Debit,
         };
@@ -215,6 +227,7 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
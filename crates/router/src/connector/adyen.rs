@@ -43,6 +43,13 @@ impl ConnectorCommon for Adyen {
     fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
         connectors.adyen.base_url.as_ref()
     }
+
+    fn get_required_fields(
+        &self,
+        payment_method_type: api::enums::PaymentMethodType,
+    ) -> &'static [api::RequiredField] {
+        api::RequiredField::find(adyen::REQUIRED_FIELDS, payment_method_type)
+    }
 }
 
 impl api::Payment for Adyen {}
@@ -518,6 +525,296 @@ impl
     }
 }
 
+impl api::ConnectorGiftCardBalance for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::Balance,
+        types::GiftCardBalanceRequestData,
+        types::GiftCardBalanceResponseData,
+    > for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::GiftCardBalanceRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::GiftCardBalanceType::get_content_type(self).to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::GiftCardBalanceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v68/paymentMethods/balance",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::GiftCardBalanceRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenBalanceRequest::try_from(req)?;
+        let adyen_req =
+            utils::Encode::<adyen::AdyenBalanceRequest>::encode_to_string_of_json(&connector_req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::GiftCardBalanceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::GiftCardBalanceType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::GiftCardBalanceType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::GiftCardBalanceType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::GiftCardBalanceRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::GiftCardBalanceRouterData, errors::ConnectorError> {
+        let response: adyen::AdyenBalanceResponse = res
+            .response
+            .parse_struct("AdyenBalanceResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl api::ConnectorPayouts for Adyen {}
+impl api::PayoutFulfill for Adyen {}
+impl api::PayoutSync for Adyen {}
+impl api::PayoutCancel for Adyen {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutFulfillRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::PayoutFulfillType::get_content_type(self).to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutFulfillRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}pal/servlet/Payout/v68/storeDetailAndSubmitThirdParty",
+            connectors.adyen.payout_base_url
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutFulfillRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenPayoutCreateRequest::try_from(req)?;
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutCreateRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutFulfillRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutFulfillType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::PayoutFulfillType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PayoutFulfillType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutFulfillRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutFulfillRouterData, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    // Adyen has no API for retrieving payouts, their status is updated through webhooks
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutCancelRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::PayoutCancelType::get_content_type(self).to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutCancelRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}pal/servlet/Payout/v68/declineThirdParty",
+            connectors.adyen.payout_base_url
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutCancelRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenPayoutCancelRequest::try_from(req)?;
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutCancelRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutCancelRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutCancelType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::PayoutCancelType::get_headers(self, req, connectors)?)
+                .body(types::PayoutCancelType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutCancelRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutCancelRouterData, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
 impl api::Refund for Adyen {}
 impl api::RefundExecute for Adyen {}
 impl api::RefundSync for Adyen {}
@@ -718,6 +1015,17 @@ impl api::IncomingWebhook for Adyen {
                 api_models::webhooks::RefundIdType::ConnectorRefundId(notif.psp_reference),
             ));
         }
+        if adyen::is_payout_event(&notif.event_code) {
+            // Notifications of a declined, expired or reversed payout reference the payout in
+            // their original reference
+            let connector_payout_id = match notif.event_code {
+                adyen::WebhookEventCode::PayoutThirdparty => notif.psp_reference,
+                _ => notif.original_reference.unwrap_or(notif.psp_reference),
+            };
+            return Ok(api_models::webhooks::ObjectReferenceId::PayoutId(
+                api_models::webhooks::PayoutIdType::ConnectorPayoutId(connector_payout_id),
+            ));
+        }
         if adyen::is_chargeback_event(&notif.event_code) {
             return Ok(api_models::webhooks::ObjectReferenceId::PaymentId(
                 api_models::payments::PaymentIdType::ConnectorTransactionId(
@@ -736,8 +1044,10 @@ impl api::IncomingWebhook for Adyen {
     ) -> CustomResult<IncomingWebhookEvent, errors::ConnectorError> {
         let notif = get_webhook_object_from_body(request.body)
             .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        let success = notif.is_success();
         Ok(IncomingWebhookEvent::foreign_from((
             notif.event_code,
+            success,
             notif.additional_data.dispute_status,
         )))
     }
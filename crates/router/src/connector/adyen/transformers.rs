@@ -4,7 +4,7 @@ use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::PaymentsAuthorizeRequestData,
+    connector::utils::{PaymentsAuthorizeRequestData, PayoutsRequestData},
     consts,
     core::errors,
     pii::{self, Email, Secret},
@@ -17,6 +17,19 @@ use crate::{
     },
 };
 
+/// The fields of the payment method data which Adyen requires, such as the bank of the customer
+/// for bank redirects, which is sent as the issuer of the payment method
+pub const REQUIRED_FIELDS: api::RequiredFieldsRegistry = &[
+    (
+        api_enums::PaymentMethodType::Eps,
+        &[api::RequiredField::EPS_BANK_NAME],
+    ),
+    (
+        api_enums::PaymentMethodType::Ideal,
+        &[api::RequiredField::IDEAL_BANK_NAME],
+    ),
+];
+
 // Adyen Types Definition
 // Payments Request and Response Types
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -119,6 +132,8 @@ pub enum AdyenStatus {
     Refused,
     Cancelled,
     RedirectShopper,
+    Pending,
+    Received,
 }
 
 impl From<AdyenStatus> for storage_enums::AttemptStatus {
@@ -128,6 +143,7 @@ impl From<AdyenStatus> for storage_enums::AttemptStatus {
             AdyenStatus::Refused => Self::Failure,
             AdyenStatus::Cancelled => Self::Voided,
             AdyenStatus::RedirectShopper => Self::AuthenticationPending,
+            AdyenStatus::Pending | AdyenStatus::Received => Self::Pending,
         }
     }
 }
@@ -222,6 +238,11 @@ pub enum AdyenPaymentMethod<'a> {
     Ideal(BankRedirectionWithIssuer<'a>),
     Giropay(BankRedirectionPMData),
     Sofort(BankRedirectionPMData),
+    SepaDirectDebit(AdyenSepaDirectDebitData),
+    AchDirectDebit(AdyenAchDirectDebitData),
+    UpiCollect(AdyenUpiCollectData),
+    UpiIntent(AdyenUpiIntentData),
+    GiftCard(AdyenGiftCardData),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -237,6 +258,27 @@ pub struct BankRedirectionWithIssuer<'a> {
     issuer: &'a str,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct AdyenSepaDirectDebitData {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+    #[serde(rename = "sepa.ownerName")]
+    owner_name: Secret<String>,
+    #[serde(rename = "sepa.ibanNumber")]
+    iban_number: Secret<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAchDirectDebitData {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+    bank_account_number: Secret<String>,
+    /// The routing number of the bank
+    bank_location_id: Secret<String>,
+    owner_name: Secret<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdyenCard {
@@ -246,6 +288,43 @@ pub struct AdyenCard {
     expiry_month: Secret<String>,
     expiry_year: Secret<String>,
     cvc: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    brand: Option<AdyenCardBrand>,
+}
+
+/// The brands which Adyen processes co-badged cards with
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AdyenCardBrand {
+    Visa,
+    #[serde(rename = "mc")]
+    Mastercard,
+    Amex,
+    #[serde(rename = "cartebancaire")]
+    CartesBancaires,
+    Diners,
+    Discover,
+    Jcb,
+    #[serde(rename = "cup")]
+    UnionPay,
+    Maestro,
+    Interac,
+}
+
+fn get_adyen_card_brand(card_network: &api_enums::CardNetwork) -> Option<AdyenCardBrand> {
+    match card_network {
+        api_enums::CardNetwork::Visa => Some(AdyenCardBrand::Visa),
+        api_enums::CardNetwork::Mastercard => Some(AdyenCardBrand::Mastercard),
+        api_enums::CardNetwork::AmericanExpress => Some(AdyenCardBrand::Amex),
+        api_enums::CardNetwork::CartesBancaires => Some(AdyenCardBrand::CartesBancaires),
+        api_enums::CardNetwork::DinersClub => Some(AdyenCardBrand::Diners),
+        api_enums::CardNetwork::Discover => Some(AdyenCardBrand::Discover),
+        api_enums::CardNetwork::JCB => Some(AdyenCardBrand::Jcb),
+        api_enums::CardNetwork::UnionPay => Some(AdyenCardBrand::UnionPay),
+        api_enums::CardNetwork::Maestro => Some(AdyenCardBrand::Maestro),
+        api_enums::CardNetwork::Interac => Some(AdyenCardBrand::Interac),
+        api_enums::CardNetwork::RuPay => None,
+    }
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -298,6 +377,78 @@ pub struct AdyenPayLaterData {
     payment_type: PaymentType,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenUpiCollectData {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+    virtual_payment_address: Secret<String, pii::UpiVpaMaskingStrategy>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AdyenUpiIntentData {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AdyenGiftCardData {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+    brand: GiftCardBrand,
+    number: Secret<String>,
+    cvc: Secret<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GiftCardBrand {
+    Givex,
+}
+
+impl From<&api_models::payments::GiftCardData> for AdyenGiftCardData {
+    fn from(gift_card_data: &api_models::payments::GiftCardData) -> Self {
+        match gift_card_data {
+            api_models::payments::GiftCardData::Givex(details) => Self {
+                payment_type: PaymentType::Giftcard,
+                brand: GiftCardBrand::Givex,
+                number: details.number.clone(),
+                cvc: details.cvc.clone(),
+            },
+        }
+    }
+}
+
+// Gift card balance Request and Response
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenBalanceRequest {
+    merchant_account: String,
+    payment_method: AdyenGiftCardData,
+    amount: Amount,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenBalanceResponse {
+    psp_reference: String,
+    result_code: AdyenBalanceResultCode,
+    balance: AdyenBalanceAmount,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AdyenBalanceAmount {
+    currency: storage_enums::Currency,
+    value: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum AdyenBalanceResultCode {
+    Success,
+    NotEnoughBalance,
+    Failed,
+}
+
 // Refunds Request and Response
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -338,6 +489,14 @@ pub enum PaymentType {
     Giropay,
     #[serde(rename = "directEbanking")]
     Sofort,
+    #[serde(rename = "sepadirectdebit")]
+    SepaDirectDebit,
+    Ach,
+    #[serde(rename = "upi_collect")]
+    UpiCollect,
+    #[serde(rename = "upi_intent")]
+    UpiIntent,
+    Giftcard,
 }
 
 pub struct AdyenTestBankNames<'a>(&'a str);
@@ -431,14 +590,25 @@ impl<'a> TryFrom<&types::PaymentsAuthorizeRouterData> for AdyenPaymentRequest<'a
             storage_models::enums::PaymentMethod::BankRedirect => {
                 get_bank_redirect_specific_payment_data(item)
             }
+            storage_models::enums::PaymentMethod::BankDebit => {
+                get_bank_debit_specific_payment_data(item)
+            }
+            storage_models::enums::PaymentMethod::Upi => get_upi_specific_payment_data(item),
+            storage_models::enums::PaymentMethod::Crypto => {
+                Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into())
+            }
+            storage_models::enums::PaymentMethod::GiftCard => {
+                get_gift_card_specific_payment_data(item)
+            }
         }
     }
 }
 
 impl From<&types::PaymentsAuthorizeRouterData> for AdyenShopperInteraction {
     fn from(item: &types::PaymentsAuthorizeRouterData) -> Self {
-        match item.request.off_session {
-            Some(true) => Self::ContinuedAuthentication,
+        match (&item.request.stored_credential, item.request.off_session) {
+            (Some(types::StoredCredentialIndicator::SubsequentMerchantInitiated { .. }), _)
+            | (_, Some(true)) => Self::ContinuedAuthentication,
             _ => Self::Ecommerce,
         }
     }
@@ -447,8 +617,11 @@ impl From<&types::PaymentsAuthorizeRouterData> for AdyenShopperInteraction {
 fn get_recurring_processing_model(
     item: &types::PaymentsAuthorizeRouterData,
 ) -> Option<AdyenRecurringModel> {
-    match item.request.setup_future_usage {
-        Some(storage_enums::FutureUsage::OffSession) => {
+    match (
+        &item.request.stored_credential,
+        item.request.setup_future_usage,
+    ) {
+        (Some(_), _) | (_, Some(storage_enums::FutureUsage::OffSession)) => {
             Some(AdyenRecurringModel::UnscheduledCardOnFile)
         }
         _ => None,
@@ -488,7 +661,7 @@ fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<Addi
 fn get_amount_data(item: &types::PaymentsAuthorizeRouterData) -> Amount {
     Amount {
         currency: item.request.currency.to_string(),
-        value: item.request.amount,
+        value: item.request.amount.get_amount_as_i64(),
     }
 }
 
@@ -506,18 +679,32 @@ fn get_address_info(address: Option<&api_models::payments::Address>) -> Option<A
 }
 
 fn get_line_items(item: &types::PaymentsAuthorizeRouterData) -> Vec<LineItem> {
-    let order_details = item.request.order_details.as_ref();
-    let line_item = LineItem {
-        amount_including_tax: Some(item.request.amount),
-        amount_excluding_tax: None,
-        description: order_details.map(|details| details.product_name.clone()),
-        // We support only one product details in payment request as of now, therefore hard coded the id.
-        // If we begin to support multiple product details in future then this logic should be made to create ID dynamically
-        id: Some(String::from("Items #1")),
-        tax_amount: None,
-        quantity: order_details.map(|details| details.quantity),
-    };
-    vec![line_item]
+    match item.request.order_details.as_deref() {
+        Some(order_details) if !order_details.is_empty() => {
+            order_details
+                .iter()
+                .enumerate()
+                .map(|(index, details)| LineItem {
+                    // The amount of the payment is used if the price of the only product is not provided
+                    amount_including_tax: details.amount.or((order_details.len() == 1)
+                        .then_some(item.request.amount.get_amount_as_i64())),
+                    amount_excluding_tax: None,
+                    description: Some(details.product_name.clone()),
+                    id: Some(format!("Items #{}", index.saturating_add(1))),
+                    tax_amount: None,
+                    quantity: Some(details.quantity),
+                })
+                .collect()
+        }
+        _ => vec![LineItem {
+            amount_including_tax: Some(item.request.amount.get_amount_as_i64()),
+            amount_excluding_tax: None,
+            description: None,
+            id: Some(String::from("Items #1")),
+            tax_amount: None,
+            quantity: None,
+        }],
+    }
 }
 
 fn get_telephone_number(item: &types::PaymentsAuthorizeRouterData) -> Option<Secret<String>> {
@@ -566,6 +753,7 @@ fn get_payment_method_data<'a>(
                 expiry_month: card.card_exp_month.clone(),
                 expiry_year: card.card_exp_year.clone(),
                 cvc: Some(card.card_cvc.clone()),
+                brand: card.card_network.as_ref().and_then(get_adyen_card_brand),
             };
             Ok(AdyenPaymentMethod::AdyenCard(adyen_card))
         }
@@ -643,8 +831,58 @@ fn get_payment_method_data<'a>(
                         payment_type: PaymentType::Sofort,
                     }))
                 }
+                api_models::payments::BankRedirectData::Netbanking { .. }
+                | api_models::payments::BankRedirectData::Blik { .. } => Err(
+                    errors::ConnectorError::NotImplemented("Payment methods".to_string()).into(),
+                ),
+            }
+        }
+        api_models::payments::PaymentMethodData::BankDebit(ref bank_debit_data) => {
+            match bank_debit_data {
+                api_models::payments::BankDebitData::SepaBankDebit {
+                    billing_details,
+                    iban,
+                } => Ok(AdyenPaymentMethod::SepaDirectDebit(
+                    AdyenSepaDirectDebitData {
+                        payment_type: PaymentType::SepaDirectDebit,
+                        owner_name: billing_details.name.clone(),
+                        iban_number: iban.clone(),
+                    },
+                )),
+                api_models::payments::BankDebitData::AchBankDebit {
+                    billing_details,
+                    account_number,
+                    routing_number,
+                    ..
+                } => Ok(AdyenPaymentMethod::AchDirectDebit(
+                    AdyenAchDirectDebitData {
+                        payment_type: PaymentType::Ach,
+                        bank_account_number: account_number.clone(),
+                        bank_location_id: routing_number.clone(),
+                        owner_name: billing_details.name.clone(),
+                    },
+                )),
             }
         }
+        api_models::payments::PaymentMethodData::Upi(ref upi_data) => match upi_data {
+            api_models::payments::UpiData::UpiCollect { vpa_id } => {
+                Ok(AdyenPaymentMethod::UpiCollect(AdyenUpiCollectData {
+                    payment_type: PaymentType::UpiCollect,
+                    virtual_payment_address: vpa_id.clone(),
+                }))
+            }
+            api_models::payments::UpiData::UpiIntent {} => {
+                Ok(AdyenPaymentMethod::UpiIntent(AdyenUpiIntentData {
+                    payment_type: PaymentType::UpiIntent,
+                }))
+            }
+        },
+        api_models::payments::PaymentMethodData::Crypto(_) => {
+            Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into())
+        }
+        api_models::payments::PaymentMethodData::GiftCard(ref gift_card_data) => Ok(
+            AdyenPaymentMethod::GiftCard(AdyenGiftCardData::from(gift_card_data.as_ref())),
+        ),
     }
 }
 
@@ -735,6 +973,106 @@ fn get_bank_redirect_specific_payment_data<'a>(
     })
 }
 
+fn get_upi_specific_payment_data<'a>(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<AdyenPaymentRequest<'a>, error_stack::Report<errors::ConnectorError>> {
+    let amount = get_amount_data(item);
+    let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+    let shopper_interaction = AdyenShopperInteraction::from(item);
+    let recurring_processing_model = get_recurring_processing_model(item);
+    let browser_info = get_browser_info(item);
+    let additional_data = get_additional_data(item);
+    let return_url = item.request.get_return_url()?;
+    let payment_method = get_payment_method_data(item)?;
+    let telephone_number = get_telephone_number(item);
+    let shopper_email = item.request.email.clone();
+
+    Ok(AdyenPaymentRequest {
+        amount,
+        merchant_account: auth_type.merchant_account,
+        payment_method,
+        reference: item.payment_id.to_string(),
+        return_url,
+        shopper_interaction,
+        recurring_processing_model,
+        browser_info,
+        additional_data,
+        telephone_number,
+        shopper_name: None,
+        shopper_email,
+        shopper_locale: None,
+        billing_address: None,
+        delivery_address: None,
+        country_code: Some(api_enums::CountryCode::IN),
+        line_items: None,
+    })
+}
+
+fn get_bank_debit_specific_payment_data<'a>(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<AdyenPaymentRequest<'a>, error_stack::Report<errors::ConnectorError>> {
+    let amount = get_amount_data(item);
+    let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+    let shopper_interaction = AdyenShopperInteraction::from(item);
+    let recurring_processing_model = get_recurring_processing_model(item);
+    let return_url = item.request.get_return_url()?;
+    let payment_method = get_payment_method_data(item)?;
+    let shopper_email = match &item.request.payment_method_data {
+        api_models::payments::PaymentMethodData::BankDebit(bank_debit_data) => {
+            Some(bank_debit_data.get_billing_details().email.clone())
+        }
+        _ => item.request.email.clone(),
+    };
+    Ok(AdyenPaymentRequest {
+        amount,
+        merchant_account: auth_type.merchant_account,
+        payment_method,
+        reference: item.payment_id.to_string(),
+        return_url,
+        shopper_interaction,
+        recurring_processing_model,
+        browser_info: None,
+        additional_data: None,
+        telephone_number: None,
+        shopper_name: None,
+        shopper_email,
+        shopper_locale: None,
+        billing_address: None,
+        delivery_address: None,
+        country_code: None,
+        line_items: None,
+    })
+}
+
+fn get_gift_card_specific_payment_data<'a>(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<AdyenPaymentRequest<'a>, error_stack::Report<errors::ConnectorError>> {
+    let amount = get_amount_data(item);
+    let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+    let shopper_interaction = AdyenShopperInteraction::from(item);
+    let return_url = item.request.get_return_url()?;
+    let payment_method = get_payment_method_data(item)?;
+    Ok(AdyenPaymentRequest {
+        amount,
+        merchant_account: auth_type.merchant_account,
+        payment_method,
+        reference: item.payment_id.to_string(),
+        return_url,
+        shopper_interaction,
+        recurring_processing_model: None,
+        browser_info: None,
+        additional_data: None,
+        telephone_number: None,
+        shopper_name: None,
+        shopper_email: None,
+        shopper_locale: None,
+        billing_address: None,
+        delivery_address: None,
+        country_code: None,
+        line_items: None,
+    })
+}
+
 fn get_wallet_specific_payment_data<'a>(
     item: &types::PaymentsAuthorizeRouterData,
 ) -> Result<AdyenPaymentRequest<'a>, error_stack::Report<errors::ConnectorError>> {
@@ -840,6 +1178,7 @@ impl TryFrom<types::PaymentsCancelResponseRouterData<AdyenCancelResponse>>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
@@ -889,6 +1228,7 @@ pub fn get_adyen_response(
         redirection_data: None,
         mandate_reference: None,
         connector_metadata: None,
+        verification_result: None,
     };
     Ok((status, error, payments_response_data))
 }
@@ -943,6 +1283,7 @@ pub fn get_redirection_response(
         redirection_data: Some(redirection_data),
         mandate_reference: None,
         connector_metadata: None,
+        verification_result: None,
     };
     Ok((status, error, payments_response_data))
 }
@@ -978,6 +1319,236 @@ impl<F, Req>
         })
     }
 }
+impl TryFrom<&types::GiftCardBalanceRouterData> for AdyenBalanceRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::GiftCardBalanceRouterData) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            payment_method: AdyenGiftCardData::from(&item.request.gift_card_data),
+            amount: Amount {
+                currency: item.request.currency.to_string(),
+                value: item.request.amount,
+            },
+        })
+    }
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Balance,
+            AdyenBalanceResponse,
+            types::GiftCardBalanceRequestData,
+            types::GiftCardBalanceResponseData,
+        >,
+    > for types::GiftCardBalanceRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Balance,
+            AdyenBalanceResponse,
+            types::GiftCardBalanceRequestData,
+            types::GiftCardBalanceResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let response = match item.response.result_code {
+            // A balance lower than the amount of the payment is still redeemable
+            AdyenBalanceResultCode::Success | AdyenBalanceResultCode::NotEnoughBalance => {
+                Ok(types::GiftCardBalanceResponseData {
+                    balance: item.response.balance.value,
+                    currency: item.response.balance.currency,
+                })
+            }
+            AdyenBalanceResultCode::Failed => Err(types::ErrorResponse {
+                code: consts::NO_ERROR_CODE.to_string(),
+                message: "Failed to fetch the balance of the gift card".to_string(),
+                reason: Some(item.response.psp_reference),
+                status_code: item.http_code,
+            }),
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+// Payouts Request and Response
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCreateRequest {
+    merchant_account: String,
+    amount: Amount,
+    reference: String,
+    shopper_reference: String,
+    shopper_email: Secret<String, Email>,
+    recurring: AdyenPayoutRecurring,
+    #[serde(flatten)]
+    payout_method: AdyenPayoutMethod,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdyenPayoutRecurring {
+    contract: AdyenPayoutContract,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AdyenPayoutContract {
+    Payout,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AdyenPayoutMethod {
+    Card(AdyenPayoutCard),
+    Bank(AdyenPayoutBank),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCard {
+    number: Secret<String, pii::CardNumber>,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    holder_name: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutBank {
+    iban: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bic: Option<Secret<String>>,
+    owner_name: Secret<String>,
+    country_code: api_enums::CountryCode,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCancelRequest {
+    merchant_account: String,
+    original_reference: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutResponse {
+    psp_reference: String,
+    /// Returned when a payout is submitted, for example `[payout-submit-received]` or `Refused`
+    result_code: Option<String>,
+    /// Returned when a payout is declined, for example `[payout-decline-received]`
+    response: Option<String>,
+    refusal_reason: Option<String>,
+}
+
+impl TryFrom<&api::PayoutMethodData> for AdyenPayoutMethod {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(payout_method_data: &api::PayoutMethodData) -> Result<Self, Self::Error> {
+        match payout_method_data {
+            api::PayoutMethodData::Card(card) => Ok(Self::Card(AdyenPayoutCard {
+                number: card.card_number.clone(),
+                expiry_month: card.expiry_month.clone(),
+                expiry_year: card.expiry_year.clone(),
+                holder_name: card.card_holder_name.clone(),
+            })),
+            api::PayoutMethodData::Bank(api::BankPayout::Sepa(bank)) => {
+                Ok(Self::Bank(AdyenPayoutBank {
+                    iban: bank.iban.clone(),
+                    bic: bank.bic.clone(),
+                    owner_name: bank.account_holder_name.clone(),
+                    country_code: bank.bank_country_code,
+                }))
+            }
+            api::PayoutMethodData::Bank(api::BankPayout::Ach(_) | api::BankPayout::Bacs(_)) => {
+                Err(errors::ConnectorError::NotImplemented("Payout methods".to_string()).into())
+            }
+        }
+    }
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutCreateRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            amount: Amount {
+                currency: item.request.currency.to_string(),
+                value: item.request.amount,
+            },
+            reference: item.request.payout_id.clone(),
+            shopper_reference: item
+                .request
+                .customer_id
+                .clone()
+                .unwrap_or_else(|| item.request.payout_id.clone()),
+            shopper_email: item.request.get_email()?,
+            recurring: AdyenPayoutRecurring {
+                contract: AdyenPayoutContract::Payout,
+            },
+            payout_method: AdyenPayoutMethod::try_from(&item.request.payout_method_data)?,
+        })
+    }
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutCancelRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            original_reference: item.request.get_connector_payout_id()?,
+        })
+    }
+}
+
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, AdyenPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, AdyenPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        let status = match (
+            item.response.result_code.as_deref(),
+            item.response.response.as_deref(),
+        ) {
+            // The outcome of a submitted payout is notified through webhooks once the payout is
+            // reviewed and processed
+            (Some("[payout-submit-received]"), _) => storage_enums::PayoutStatus::Initiated,
+            (_, Some("[payout-decline-received]")) => storage_enums::PayoutStatus::Cancelled,
+            _ => storage_enums::PayoutStatus::Failed,
+        };
+        let response = if status == storage_enums::PayoutStatus::Failed {
+            Err(types::ErrorResponse {
+                code: item
+                    .response
+                    .result_code
+                    .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                message: item
+                    .response
+                    .refusal_reason
+                    .clone()
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: item.response.refusal_reason,
+                status_code: item.http_code,
+            })
+        } else {
+            Ok(types::PayoutsResponseData {
+                status,
+                connector_payout_id: Some(item.response.psp_reference),
+            })
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdyenCaptureRequest {
@@ -1033,6 +1604,7 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<AdyenCaptureResponse>>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             amount_captured,
             ..item.data
@@ -1182,6 +1754,12 @@ pub enum WebhookEventCode {
     SecondChargeback,
     PrearbitrationWon,
     PrearbitrationLost,
+    PayoutThirdparty,
+    PayoutDecline,
+    PayoutExpire,
+    PaidoutReversed,
+    #[serde(other)]
+    Unknown,
 }
 
 pub fn is_transaction_event(event_code: &WebhookEventCode) -> bool {
@@ -1197,6 +1775,16 @@ pub fn is_refund_event(event_code: &WebhookEventCode) -> bool {
     )
 }
 
+pub fn is_payout_event(event_code: &WebhookEventCode) -> bool {
+    matches!(
+        event_code,
+        WebhookEventCode::PayoutThirdparty
+            | WebhookEventCode::PayoutDecline
+            | WebhookEventCode::PayoutExpire
+            | WebhookEventCode::PaidoutReversed
+    )
+}
+
 pub fn is_chargeback_event(event_code: &WebhookEventCode) -> bool {
     matches!(
         event_code,
@@ -1209,12 +1797,17 @@ pub fn is_chargeback_event(event_code: &WebhookEventCode) -> bool {
     )
 }
 
-impl ForeignFrom<(WebhookEventCode, Option<DisputeStatus>)> for IncomingWebhookEvent {
-    fn foreign_from((code, status): (WebhookEventCode, Option<DisputeStatus>)) -> Self {
+impl ForeignFrom<(WebhookEventCode, bool, Option<DisputeStatus>)> for IncomingWebhookEvent {
+    fn foreign_from(
+        (code, success, status): (WebhookEventCode, bool, Option<DisputeStatus>),
+    ) -> Self {
         match (code, status) {
-            (WebhookEventCode::Authorisation, _) => Self::PaymentIntentSuccess,
-            (WebhookEventCode::Refund, _) => Self::RefundSuccess,
-            (WebhookEventCode::CancelOrRefund, _) => Self::RefundSuccess,
+            (WebhookEventCode::Authorisation, _) if success => Self::PaymentIntentSuccess,
+            (WebhookEventCode::Authorisation, _) => Self::PaymentIntentFailure,
+            (WebhookEventCode::Refund | WebhookEventCode::CancelOrRefund, _) if success => {
+                Self::RefundSuccess
+            }
+            (WebhookEventCode::Refund | WebhookEventCode::CancelOrRefund, _) => Self::RefundFailure,
             (WebhookEventCode::RefundFailed, _) => Self::RefundFailure,
             (WebhookEventCode::NotificationOfChargeback, _) => Self::DisputeOpened,
             (WebhookEventCode::Chargeback, None) => Self::DisputeLost,
@@ -1231,6 +1824,12 @@ impl ForeignFrom<(WebhookEventCode, Option<DisputeStatus>)> for IncomingWebhookE
             }
             (WebhookEventCode::PrearbitrationWon, _) => Self::DisputeWon,
             (WebhookEventCode::PrearbitrationLost, _) => Self::DisputeLost,
+            (WebhookEventCode::PayoutThirdparty, _) if success => Self::PayoutSuccess,
+            (WebhookEventCode::PayoutThirdparty, _) => Self::PayoutFailure,
+            (WebhookEventCode::PayoutDecline, _) => Self::PayoutCancelled,
+            (WebhookEventCode::PayoutExpire, _) => Self::PayoutFailure,
+            (WebhookEventCode::PaidoutReversed, _) => Self::PayoutReversed,
+            (WebhookEventCode::Unknown, _) => Self::EventNotSupported,
         }
     }
 }
@@ -1257,6 +1856,7 @@ pub struct AdyenNotificationRequestItemWH {
     pub event_code: WebhookEventCode,
     pub merchant_account_code: String,
     pub merchant_reference: String,
+    /// Whether the event was successful, as `"true"` or `"false"`
     pub success: String,
     pub reason: Option<String>,
     pub event_date: Option<String>,
@@ -1274,14 +1874,21 @@ pub struct AdyenIncomingWebhook {
     pub notification_items: Vec<AdyenItemObjectWH>,
 }
 
+impl AdyenNotificationRequestItemWH {
+    pub fn is_success(&self) -> bool {
+        self.success == "true"
+    }
+}
+
 impl From<AdyenNotificationRequestItemWH> for AdyenResponse {
     fn from(notif: AdyenNotificationRequestItemWH) -> Self {
         Self {
             psp_reference: notif.psp_reference,
             merchant_reference: notif.merchant_reference,
-            result_code: match notif.success.as_str() {
-                "true" => AdyenStatus::Authorised,
-                _ => AdyenStatus::Refused,
+            result_code: if notif.is_success() {
+                AdyenStatus::Authorised
+            } else {
+                AdyenStatus::Refused
             },
             amount: Some(Amount {
                 value: notif.amount.value,
@@ -878,7 +878,7 @@ impl api::IncomingWebhook for Airwallex {
         Ok(match details.name.as_str() {
             "payment_attempt.failed_to_process" => api::IncomingWebhookEvent::PaymentIntentFailure,
             "payment_attempt.authorized" => api::IncomingWebhookEvent::PaymentIntentSuccess,
-            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
+            _ => api::IncomingWebhookEvent::EventNotSupported,
         })
     }
 
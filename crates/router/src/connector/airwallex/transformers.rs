@@ -153,7 +153,7 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for AirwallexPaymentsCaptureRequ
         Ok(Self {
             request_id: Uuid::new_v4().to_string(),
             amount: Some(utils::to_currency_base_unit(
-                item.request.amount_to_capture,
+                types::MinorUnit::new(item.request.amount_to_capture),
                 item.request.currency,
             )?),
         })
@@ -268,6 +268,7 @@ impl<F, T>
                 redirection_data,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
@@ -291,7 +292,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for AirwallexRefundRequest {
         Ok(Self {
             request_id: Uuid::new_v4().to_string(),
             amount: Some(utils::to_currency_base_unit(
-                item.request.refund_amount,
+                types::MinorUnit::new(item.request.refund_amount),
                 item.request.currency,
             )?),
             reason: item.request.reason.clone(),
@@ -3,16 +3,20 @@ use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::RefundsRequestData,
+    connector::utils::{self as connector_utils, RefundsRequestData},
     core::errors,
-    types::{self, api, storage::enums},
+    types::{self, api, storage::enums, transformers::ForeignFrom},
     utils::OptionExt,
 };
 
-#[derive(Debug, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
 pub enum TransactionType {
     #[serde(rename = "authCaptureTransaction")]
     Payment,
+    #[serde(rename = "authOnlyTransaction")]
+    Authorization,
+    #[serde(rename = "priorAuthCaptureTransaction")]
+    Capture,
     #[serde(rename = "refundTransaction")]
     Refund,
     #[serde(rename = "voidTransaction")]
@@ -66,6 +70,12 @@ enum PaymentDetails {
     Paypal,
     #[serde(rename = "bankRedirect")]
     BankRedirect,
+    #[serde(rename = "bankDebit")]
+    BankDebit,
+    Upi,
+    Crypto,
+    #[serde(rename = "giftCard")]
+    GiftCard,
 }
 
 impl From<api_models::payments::PaymentMethodData> for PaymentDetails {
@@ -86,6 +96,10 @@ impl From<api_models::payments::PaymentMethodData> for PaymentDetails {
             api::PaymentMethodData::PayLater(_) => Self::Klarna,
             api::PaymentMethodData::Wallet(_) => Self::Wallet,
             api::PaymentMethodData::BankRedirect(_) => Self::BankRedirect,
+            api::PaymentMethodData::BankDebit(_) => Self::BankDebit,
+            api::PaymentMethodData::Upi(_) => Self::Upi,
+            api::PaymentMethodData::Crypto(_) => Self::Crypto,
+            api::PaymentMethodData::GiftCard(_) => Self::GiftCard,
         }
     }
 }
@@ -96,10 +110,121 @@ struct TransactionRequest {
     transaction_type: TransactionType,
     amount: i64,
     currency_code: String,
-    payment: PaymentDetails,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payment: Option<PaymentDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    profile: Option<ProfileDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<LineItems>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax: Option<ExtendedAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    po_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    customer: Option<CustomerDetails>,
     authorization_indicator_type: Option<AuthorizationIndicator>,
 }
 
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct LineItems {
+    line_item: Vec<LineItem>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct LineItem {
+    item_id: String,
+    name: String,
+    quantity: u16,
+    unit_price: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measure: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commodity_code: Option<String>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct ExtendedAmount {
+    amount: i64,
+}
+
+// Line items are identified by the product code, or by their position in the purchase when the
+// product code is not provided
+impl From<(usize, &api_models::payments::L3LineItem)> for LineItem {
+    fn from((index, line_item): (usize, &api_models::payments::L3LineItem)) -> Self {
+        Self {
+            item_id: line_item
+                .product_code
+                .clone()
+                .unwrap_or_else(|| (index + 1).to_string()),
+            name: line_item.description.clone(),
+            quantity: line_item.quantity,
+            unit_price: line_item.unit_amount,
+            tax_amount: line_item.tax_amount,
+            discount_amount: line_item.discount_amount,
+            unit_of_measure: line_item.unit_of_measure.clone(),
+            commodity_code: line_item.commodity_code.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(untagged)]
+enum ProfileDetails {
+    CreateProfileDetails(CreateProfileDetails),
+    CustomerProfileDetails(CustomerProfileDetails),
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct CreateProfileDetails {
+    create_profile: bool,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct CustomerProfileDetails {
+    customer_profile_id: String,
+    payment_profile: PaymentProfileDetails,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct PaymentProfileDetails {
+    payment_profile_id: String,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct CustomerDetails {
+    email: masking::Secret<String, common_utils::pii::Email>,
+}
+
+// The connector mandate id of a card saved in the customer information manager (CIM) holds both
+// the customer profile id and the payment profile id, separated by a hyphen
+impl TryFrom<String> for CustomerProfileDetails {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(connector_mandate_id: String) -> Result<Self, Self::Error> {
+        let (customer_profile_id, payment_profile_id) = connector_mandate_id
+            .split_once('-')
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "connector_mandate_id",
+            })?;
+        Ok(Self {
+            customer_profile_id: customer_profile_id.to_string(),
+            payment_profile: PaymentProfileDetails {
+                payment_profile_id: payment_profile_id.to_string(),
+            },
+        })
+    }
+}
+
 #[derive(Debug, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 struct AuthorizationIndicator {
@@ -113,6 +238,14 @@ struct TransactionVoidRequest {
     ref_trans_id: String,
 }
 
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct TransactionCaptureRequest {
+    transaction_type: TransactionType,
+    amount: i64,
+    ref_trans_id: String,
+}
+
 #[derive(Debug, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthorizedotnetPaymentsRequest {
@@ -127,6 +260,13 @@ pub struct AuthorizedotnetPaymentCancelRequest {
     transaction_request: TransactionVoidRequest,
 }
 
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizedotnetPaymentCaptureRequest {
+    merchant_authentication: MerchantAuthentication,
+    transaction_request: TransactionCaptureRequest,
+}
+
 #[derive(Debug, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 // The connector enforces field ordering, it expects fields to be in the same order as in their API documentation
@@ -140,6 +280,12 @@ pub struct CancelTransactionRequest {
     create_transaction_request: AuthorizedotnetPaymentCancelRequest,
 }
 
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureTransactionRequest {
+    create_transaction_request: AuthorizedotnetPaymentCaptureRequest,
+}
+
 #[derive(Debug, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum AuthorizationType {
@@ -156,19 +302,81 @@ impl From<enums::CaptureMethod> for AuthorizationType {
     }
 }
 
+/// Payments using the manual capture flow are only authorized, and are captured later using the
+/// id of the authorization transaction
+pub fn get_authorization_transaction_type(
+    capture_method: Option<enums::CaptureMethod>,
+) -> TransactionType {
+    match capture_method {
+        Some(enums::CaptureMethod::Manual) => TransactionType::Authorization,
+        _ => TransactionType::Payment,
+    }
+}
+
 impl TryFrom<&types::PaymentsAuthorizeRouterData> for CreateTransactionRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
-        let payment_details = item.request.payment_method_data.clone().into();
         let authorization_indicator_type =
             item.request.capture_method.map(|c| AuthorizationIndicator {
                 authorization_indicator: c.into(),
             });
+
+        // Payments made with a card saved in the customer information manager reference its
+        // profiles instead of the card, and cards to be saved for off session payments create
+        // the profiles from the transaction
+        let (payment, profile, customer) = match item
+            .request
+            .mandate_id
+            .clone()
+            .and_then(|mandate_ids| mandate_ids.connector_mandate_id)
+        {
+            Some(connector_mandate_id) => (
+                None,
+                Some(ProfileDetails::CustomerProfileDetails(
+                    CustomerProfileDetails::try_from(connector_mandate_id)?,
+                )),
+                None,
+            ),
+            None => {
+                let create_profile = item.request.setup_future_usage
+                    == Some(enums::FutureUsage::OffSession)
+                    && matches!(
+                        item.request.payment_method_data,
+                        api::PaymentMethodData::Card(_)
+                    );
+                (
+                    Some(item.request.payment_method_data.clone().into()),
+                    create_profile.then_some(ProfileDetails::CreateProfileDetails(
+                        CreateProfileDetails {
+                            create_profile: true,
+                        },
+                    )),
+                    item.request
+                        .email
+                        .clone()
+                        .filter(|_| create_profile)
+                        .map(|email| CustomerDetails { email }),
+                )
+            }
+        };
+
+        let l2_l3_data = item.request.l2_l3_data.as_ref();
         let transaction_request = TransactionRequest {
-            transaction_type: TransactionType::Payment,
-            amount: item.request.amount,
-            payment: payment_details,
+            transaction_type: get_authorization_transaction_type(item.request.capture_method),
+            amount: item.request.amount.get_amount_as_i64(),
             currency_code: item.request.currency.to_string(),
+            payment,
+            profile,
+            line_items: l2_l3_data
+                .and_then(|l2_l3_data| l2_l3_data.line_items.as_ref())
+                .map(|line_items| LineItems {
+                    line_item: line_items.iter().enumerate().map(LineItem::from).collect(),
+                }),
+            tax: l2_l3_data
+                .and_then(|l2_l3_data| l2_l3_data.tax_amount)
+                .map(|amount| ExtendedAmount { amount }),
+            po_number: l2_l3_data.and_then(|l2_l3_data| l2_l3_data.customer_reference.clone()),
+            customer,
             authorization_indicator_type,
         };
 
@@ -202,6 +410,26 @@ impl TryFrom<&types::PaymentsCancelRouterData> for CancelTransactionRequest {
     }
 }
 
+impl TryFrom<&types::PaymentsCaptureRouterData> for CaptureTransactionRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        let transaction_request = TransactionCaptureRequest {
+            transaction_type: TransactionType::Capture,
+            amount: item.request.amount_to_capture,
+            ref_trans_id: item.request.connector_transaction_id.to_string(),
+        };
+
+        let merchant_authentication = MerchantAuthentication::try_from(&item.connector_auth_type)?;
+
+        Ok(Self {
+            create_transaction_request: AuthorizedotnetPaymentCaptureRequest {
+                merchant_authentication,
+                transaction_request,
+            },
+        })
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize)]
 pub enum AuthorizedotnetPaymentStatus {
     #[serde(rename = "1")]
@@ -217,14 +445,27 @@ pub enum AuthorizedotnetPaymentStatus {
 
 pub type AuthorizedotnetRefundStatus = AuthorizedotnetPaymentStatus;
 
-impl From<AuthorizedotnetPaymentStatus> for enums::AttemptStatus {
-    fn from(item: AuthorizedotnetPaymentStatus) -> Self {
-        match item {
-            AuthorizedotnetPaymentStatus::Approved => Self::Charged,
-            AuthorizedotnetPaymentStatus::Declined | AuthorizedotnetPaymentStatus::Error => {
+impl ForeignFrom<(AuthorizedotnetPaymentStatus, TransactionType)> for enums::AttemptStatus {
+    fn foreign_from(item: (AuthorizedotnetPaymentStatus, TransactionType)) -> Self {
+        let (status, transaction_type) = item;
+        match (status, transaction_type) {
+            (AuthorizedotnetPaymentStatus::Approved, TransactionType::Authorization) => {
+                Self::Authorized
+            }
+            (AuthorizedotnetPaymentStatus::Approved, TransactionType::Void) => Self::Voided,
+            (AuthorizedotnetPaymentStatus::Approved, _) => Self::Charged,
+            (
+                AuthorizedotnetPaymentStatus::Declined | AuthorizedotnetPaymentStatus::Error,
+                TransactionType::Capture,
+            ) => Self::CaptureFailed,
+            (
+                AuthorizedotnetPaymentStatus::Declined | AuthorizedotnetPaymentStatus::Error,
+                TransactionType::Void,
+            ) => Self::VoidFailed,
+            (AuthorizedotnetPaymentStatus::Declined | AuthorizedotnetPaymentStatus::Error, _) => {
                 Self::Failure
             }
-            AuthorizedotnetPaymentStatus::HeldForReview => Self::Pending,
+            (AuthorizedotnetPaymentStatus::HeldForReview, _) => Self::ManualReview,
         }
     }
 }
@@ -264,35 +505,68 @@ pub struct TransactionResponse {
     transaction_id: String,
     pub(super) account_number: Option<String>,
     pub(super) errors: Option<Vec<ErrorMessage>>,
+    avs_result_code: Option<String>,
+    cvv_result_code: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthorizedotnetPaymentsResponse {
     pub transaction_response: TransactionResponse,
+    pub profile_response: Option<ProfileResponse>,
     pub messages: ResponseMessages,
 }
 
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileResponse {
+    customer_profile_id: Option<String>,
+    customer_payment_profile_id_list: Option<Vec<String>>,
+}
+
+impl ProfileResponse {
+    // Joins the ids of the created profiles into the connector mandate id, which is split back by
+    // `CustomerProfileDetails::try_from`
+    fn get_connector_mandate_id(&self) -> Option<String> {
+        let customer_profile_id = self.customer_profile_id.as_ref()?;
+        let payment_profile_id = self.customer_payment_profile_id_list.as_ref()?.first()?;
+        Some(format!("{customer_profile_id}-{payment_profile_id}"))
+    }
+}
+
 impl<F, T>
-    TryFrom<
+    TryFrom<(
         types::ResponseRouterData<
             F,
             AuthorizedotnetPaymentsResponse,
             T,
             types::PaymentsResponseData,
         >,
-    > for types::RouterData<F, T, types::PaymentsResponseData>
+        TransactionType,
+    )> for types::RouterData<F, T, types::PaymentsResponseData>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: types::ResponseRouterData<
-            F,
-            AuthorizedotnetPaymentsResponse,
-            T,
-            types::PaymentsResponseData,
-        >,
+        data: (
+            types::ResponseRouterData<
+                F,
+                AuthorizedotnetPaymentsResponse,
+                T,
+                types::PaymentsResponseData,
+            >,
+            TransactionType,
+        ),
     ) -> Result<Self, Self::Error> {
-        let status = enums::AttemptStatus::from(item.response.transaction_response.response_code);
+        let (item, transaction_type) = data;
+        let status = enums::AttemptStatus::foreign_from((
+            item.response.transaction_response.response_code,
+            transaction_type,
+        ));
+        let mandate_reference = item
+            .response
+            .profile_response
+            .as_ref()
+            .and_then(ProfileResponse::get_connector_mandate_id);
         let error = item
             .response
             .transaction_response
@@ -319,6 +593,20 @@ impl<F, T>
             .change_context(errors::ConnectorError::MissingRequiredField {
                 field_name: "connector_metadata",
             })?;
+        let verification_result = types::VerificationResult {
+            avs_result: item
+                .response
+                .transaction_response
+                .avs_result_code
+                .as_deref()
+                .map(connector_utils::get_avs_result_from_code),
+            cvc_result: item
+                .response
+                .transaction_response
+                .cvv_result_code
+                .as_deref()
+                .map(connector_utils::get_cvc_result_from_code),
+        };
 
         Ok(Self {
             status,
@@ -329,8 +617,9 @@ impl<F, T>
                         item.response.transaction_response.transaction_id,
                     ),
                     redirection_data: None,
-                    mandate_reference: None,
+                    mandate_reference,
                     connector_metadata: metadata,
+                    verification_result: Some(verification_result),
                 }),
             },
             ..item.data
@@ -512,7 +801,7 @@ impl TryFrom<&types::PaymentsSyncRouterData> for AuthorizedotnetCreateSyncReques
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum SyncStatus {
     RefundSettledSuccessfully,
@@ -524,8 +813,10 @@ pub enum SyncStatus {
     Voided,
     CouldNotVoid,
     GeneralError,
+    #[serde(rename = "FDSPendingReview")]
+    FdsPendingReview,
 }
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SyncTransactionResponse {
     #[serde(rename = "transId")]
@@ -533,7 +824,7 @@ pub struct SyncTransactionResponse {
     transaction_status: SyncStatus,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct AuthorizedotnetSyncResponse {
     transaction: SyncTransactionResponse,
 }
@@ -554,6 +845,7 @@ impl From<SyncStatus> for enums::AttemptStatus {
             SyncStatus::SettledSuccessfully | SyncStatus::CapturedPendingSettlement => {
                 Self::Charged
             }
+            SyncStatus::AuthorizedPendingCapture => Self::Authorized,
             SyncStatus::Declined => Self::AuthenticationFailed,
             SyncStatus::Voided => Self::Voided,
             SyncStatus::CouldNotVoid => Self::VoidFailed,
@@ -607,6 +899,7 @@ impl<F, Req>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             status: payment_status,
             ..item.data
@@ -628,6 +921,95 @@ pub struct AuthorizedotnetErrorResponse {
     pub error: ErrorDetails,
 }
 
+#[derive(Debug, Deserialize)]
+pub enum AuthorizedotnetWebhookEvent {
+    #[serde(rename = "net.authorize.payment.authorization.created")]
+    AuthorizationCreated,
+    #[serde(rename = "net.authorize.payment.authcapture.created")]
+    AuthCaptureCreated,
+    #[serde(rename = "net.authorize.payment.capture.created")]
+    CaptureCreated,
+    #[serde(rename = "net.authorize.payment.priorAuthCapture.created")]
+    PriorAuthCaptureCreated,
+    #[serde(rename = "net.authorize.payment.void.created")]
+    VoidCreated,
+    #[serde(rename = "net.authorize.payment.refund.created")]
+    RefundCreated,
+    #[serde(rename = "net.authorize.payment.fraud.held")]
+    FraudHeld,
+    #[serde(rename = "net.authorize.payment.fraud.approved")]
+    FraudApproved,
+    #[serde(rename = "net.authorize.payment.fraud.declined")]
+    FraudDeclined,
+    #[serde(other)]
+    Unknown,
+}
+
+impl AuthorizedotnetWebhookEvent {
+    pub fn is_refund_event(&self) -> bool {
+        matches!(self, Self::RefundCreated)
+    }
+}
+
+impl From<AuthorizedotnetWebhookEvent> for api::IncomingWebhookEvent {
+    fn from(event_type: AuthorizedotnetWebhookEvent) -> Self {
+        match event_type {
+            AuthorizedotnetWebhookEvent::AuthorizationCreated
+            | AuthorizedotnetWebhookEvent::AuthCaptureCreated
+            | AuthorizedotnetWebhookEvent::CaptureCreated
+            | AuthorizedotnetWebhookEvent::PriorAuthCaptureCreated
+            | AuthorizedotnetWebhookEvent::FraudApproved => Self::PaymentIntentSuccess,
+            AuthorizedotnetWebhookEvent::VoidCreated
+            | AuthorizedotnetWebhookEvent::FraudDeclined => Self::PaymentIntentFailure,
+            AuthorizedotnetWebhookEvent::FraudHeld => Self::PaymentIntentProcessing,
+            AuthorizedotnetWebhookEvent::RefundCreated => Self::RefundSuccess,
+            AuthorizedotnetWebhookEvent::Unknown => Self::EventNotSupported,
+        }
+    }
+}
+
+// Captured transactions are settled by the connector in a daily batch, the events of the
+// transactions are mapped to the settlement status that the transaction details report
+impl From<AuthorizedotnetWebhookEvent> for SyncStatus {
+    fn from(event_type: AuthorizedotnetWebhookEvent) -> Self {
+        match event_type {
+            AuthorizedotnetWebhookEvent::AuthorizationCreated => Self::AuthorizedPendingCapture,
+            AuthorizedotnetWebhookEvent::AuthCaptureCreated
+            | AuthorizedotnetWebhookEvent::CaptureCreated
+            | AuthorizedotnetWebhookEvent::PriorAuthCaptureCreated
+            | AuthorizedotnetWebhookEvent::FraudApproved => Self::CapturedPendingSettlement,
+            AuthorizedotnetWebhookEvent::VoidCreated => Self::Voided,
+            AuthorizedotnetWebhookEvent::RefundCreated => Self::RefundPendingSettlement,
+            AuthorizedotnetWebhookEvent::FraudDeclined => Self::Declined,
+            AuthorizedotnetWebhookEvent::FraudHeld => Self::FdsPendingReview,
+            AuthorizedotnetWebhookEvent::Unknown => Self::GeneralError,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizedotnetWebhookObject {
+    pub event_type: AuthorizedotnetWebhookEvent,
+    pub payload: AuthorizedotnetWebhookPayload,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuthorizedotnetWebhookPayload {
+    pub id: String,
+}
+
+impl From<AuthorizedotnetWebhookObject> for AuthorizedotnetSyncResponse {
+    fn from(item: AuthorizedotnetWebhookObject) -> Self {
+        Self {
+            transaction: SyncTransactionResponse {
+                transaction_id: item.payload.id,
+                transaction_status: SyncStatus::from(item.event_type),
+            },
+        }
+    }
+}
+
 fn construct_refund_payment_details(masked_number: String) -> PaymentDetails {
     PaymentDetails::CreditCard(CreditCardDetails {
         card_number: masked_number.into(),
@@ -67,7 +67,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for BamboraPaymentsRequest {
                     complete: item.request.is_auto_capture(),
                 };
                 Ok(Self {
-                    amount: item.request.amount,
+                    amount: item.request.amount.get_amount_as_i64(),
                     payment_method: PaymentMethod::Card,
                     card: bambora_card,
                 })
@@ -148,6 +148,7 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
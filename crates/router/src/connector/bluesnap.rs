@@ -729,7 +729,7 @@ impl api::IncomingWebhook for Bluesnap {
         Ok(match details.transaction_type.as_str() {
             "DECLINE" | "CC_CHARGE_FAILED" => api::IncomingWebhookEvent::PaymentIntentFailure,
             "CHARGE" => api::IncomingWebhookEvent::PaymentIntentSuccess,
-            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
+            _ => api::IncomingWebhookEvent::EventNotSupported,
         })
     }
 
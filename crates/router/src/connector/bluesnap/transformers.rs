@@ -91,8 +91,10 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for BluesnapCaptureRequest {
     fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
         let card_transaction_type = BluesnapTxnType::Capture;
         let transaction_id = item.request.connector_transaction_id.to_string();
-        let amount =
-            utils::to_currency_base_unit(item.request.amount_to_capture, item.request.currency)?;
+        let amount = utils::to_currency_base_unit(
+            types::MinorUnit::new(item.request.amount_to_capture),
+            item.request.currency,
+        )?;
         Ok(Self {
             card_transaction_type,
             transaction_id,
@@ -225,6 +227,7 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
@@ -243,7 +246,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for BluesnapRefundRequest {
         Ok(Self {
             reason: item.request.reason.clone(),
             amount: Some(utils::to_currency_base_unit(
-                item.request.refund_amount,
+                types::MinorUnit::new(item.request.refund_amount),
                 item.request.currency,
             )?),
         })
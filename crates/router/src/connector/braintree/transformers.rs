@@ -10,135 +10,290 @@ use crate::{
     types::{self, api, storage::enums},
 };
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
-pub struct DeviceData;
+pub const CLIENT_TOKEN_MUTATION: &str = "mutation createClientToken($input: CreateClientTokenInput!) { createClientToken(input: $input) { clientToken } }";
+pub const TOKENIZE_CREDIT_CARD_MUTATION: &str = "mutation tokenizeCreditCard($input: TokenizeCreditCardInput!) { tokenizeCreditCard(input: $input) { paymentMethod { id } } }";
+pub const AUTHORIZE_PAYMENT_METHOD_MUTATION: &str = "mutation authorizePaymentMethod($input: AuthorizePaymentMethodInput!) { authorizePaymentMethod(input: $input) { transaction { id status } } }";
+pub const CHARGE_PAYMENT_METHOD_MUTATION: &str = "mutation chargePaymentMethod($input: ChargePaymentMethodInput!) { chargePaymentMethod(input: $input) { transaction { id status } } }";
+pub const CAPTURE_TRANSACTION_MUTATION: &str = "mutation captureTransaction($input: CaptureTransactionInput!) { captureTransaction(input: $input) { transaction { id status } } }";
+pub const REVERSE_TRANSACTION_MUTATION: &str = "mutation reverseTransaction($input: ReverseTransactionInput!) { reverseTransaction(input: $input) { reversal { ... on Transaction { id status } } } }";
+pub const REFUND_TRANSACTION_MUTATION: &str = "mutation refundTransaction($input: RefundTransactionInput!) { refundTransaction(input: $input) { refund { id status } } }";
+pub const TRANSACTION_QUERY: &str =
+    "query transaction($id: ID!) { node(id: $id) { ... on Transaction { id status } } }";
+pub const REFUND_QUERY: &str =
+    "query refund($id: ID!) { node(id: $id) { ... on Refund { id status } } }";
+
+/// A request to the GraphQL API of Braintree
+#[derive(Debug, Serialize)]
+pub struct BraintreeRequest<V> {
+    query: &'static str,
+    variables: V,
+}
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
-pub struct PaymentOptions {
-    submit_for_settlement: bool,
+/// The variables of a mutation, which are always wrapped in an `input` object
+#[derive(Debug, Serialize)]
+pub struct InputVariables<T> {
+    input: T,
 }
 
-#[derive(Debug, Serialize, Eq, PartialEq)]
-pub struct BraintreePaymentsRequest {
-    transaction: TransactionBody,
+/// The variables of a query for a node (transaction or refund) by its ID
+#[derive(Debug, Serialize)]
+pub struct NodeVariables {
+    id: String,
 }
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
-pub struct BraintreeApiVersion {
-    version: String,
+impl<T> BraintreeRequest<InputVariables<T>> {
+    fn mutation(query: &'static str, input: T) -> Self {
+        Self {
+            query,
+            variables: InputVariables { input },
+        }
+    }
 }
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
-pub struct BraintreeSessionRequest {
-    client_token: BraintreeApiVersion,
+impl BraintreeRequest<NodeVariables> {
+    fn node_query(query: &'static str, id: String) -> Self {
+        Self {
+            query,
+            variables: NodeVariables { id },
+        }
+    }
 }
 
+#[derive(Debug, Default, Serialize)]
+pub struct ClientTokenInput {}
+
+pub type BraintreeSessionRequest = BraintreeRequest<InputVariables<ClientTokenInput>>;
+
 impl TryFrom<&types::PaymentsSessionRouterData> for BraintreeSessionRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(_item: &types::PaymentsSessionRouterData) -> Result<Self, Self::Error> {
-        Ok(Self {
-            client_token: BraintreeApiVersion {
-                version: "2".to_string(),
-            },
-        })
+        Ok(Self::mutation(
+            CLIENT_TOKEN_MUTATION,
+            ClientTokenInput::default(),
+        ))
     }
 }
 
-#[derive(Debug, Serialize, Eq, PartialEq)]
+#[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct TransactionBody {
-    amount: String,
-    device_data: DeviceData,
-    options: PaymentOptions,
-    #[serde(flatten)]
-    payment_method_data_type: PaymentMethodType,
-    #[serde(rename = "type")]
-    kind: String,
+pub struct TokenizeCreditCardInput {
+    credit_card: CreditCardInput,
 }
 
-#[derive(Debug, Serialize, Eq, PartialEq)]
+#[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(tag = "type")]
-pub enum PaymentMethodType {
-    CreditCard(Card),
-    PaymentMethodNonce(Nonce),
+pub struct CreditCardInput {
+    number: Secret<String, common_utils::pii::CardNumber>,
+    expiration_month: Secret<String>,
+    expiration_year: Secret<String>,
+    cvv: Secret<String>,
+    cardholder_name: Secret<String>,
 }
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
-pub struct Nonce {
-    payment_method_nonce: String,
+pub type BraintreeTokenizeRequest = BraintreeRequest<InputVariables<TokenizeCreditCardInput>>;
+
+impl TryFrom<&types::PaymentsInitRouterData> for BraintreeTokenizeRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsInitRouterData) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data.clone() {
+            api::PaymentMethodData::Card(ccard) => Ok(Self::mutation(
+                TOKENIZE_CREDIT_CARD_MUTATION,
+                TokenizeCreditCardInput {
+                    credit_card: CreditCardInput {
+                        number: ccard.card_number,
+                        expiration_month: ccard.card_exp_month,
+                        expiration_year: ccard.card_exp_year,
+                        cvv: ccard.card_cvc,
+                        cardholder_name: ccard.card_holder_name,
+                    },
+                },
+            )),
+            _ => Err(errors::ConnectorError::NotImplemented(
+                "Tokenization of payment methods other than cards".to_string(),
+            ))?,
+        }
+    }
 }
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct Card {
-    credit_card: CardDetails,
+pub struct PaymentInput {
+    payment_method_id: String,
+    transaction: TransactionInput,
 }
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct CardDetails {
-    number: Secret<String, common_utils::pii::CardNumber>,
-    expiration_month: Secret<String>,
-    expiration_year: Secret<String>,
-    cvv: Secret<String>,
+pub struct TransactionInput {
+    amount: String,
+    order_id: String,
+}
+
+pub type BraintreePaymentsRequest = BraintreeRequest<InputVariables<PaymentInput>>;
+
+/// Returns the ID of the payment method to be authorized. Cards are tokenized into a single use
+/// payment method before the authorization, while the client SDKs of Braintree provide a nonce.
+pub fn get_payment_method_id(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<Option<String>, error_stack::Report<errors::ConnectorError>> {
+    match &item.request.payment_method_data {
+        api::PaymentMethodData::Card(_) => Ok(item.reference_id.clone()),
+        api::PaymentMethodData::Wallet(payments::WalletData::PaypalSdk(wallet_data)) => {
+            Ok(Some(wallet_data.token.to_owned()))
+        }
+        api::PaymentMethodData::Wallet(_) => Err(errors::ConnectorError::InvalidWallet)?,
+        _ => Err(errors::ConnectorError::NotImplemented(format!(
+            "Current Payment Method - {:?}",
+            item.request.payment_method_data
+        )))?,
+    }
 }
 
 impl TryFrom<&types::PaymentsAuthorizeRouterData> for BraintreePaymentsRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
-        let submit_for_settlement = matches!(
-            item.request.capture_method,
-            Some(enums::CaptureMethod::Automatic) | None
-        );
-
-        let amount = utils::to_currency_base_unit(item.request.amount, item.request.currency)?;
-        let device_data = DeviceData {};
-        let options = PaymentOptions {
-            submit_for_settlement,
+        let query = match item.request.capture_method {
+            Some(enums::CaptureMethod::Automatic) | None => CHARGE_PAYMENT_METHOD_MUTATION,
+            _ => AUTHORIZE_PAYMENT_METHOD_MUTATION,
         };
-        let kind = "sale".to_string();
-
-        let payment_method_data_type = match item.request.payment_method_data.clone() {
-            api::PaymentMethodData::Card(ccard) => Ok(PaymentMethodType::CreditCard(Card {
-                credit_card: CardDetails {
-                    number: ccard.card_number,
-                    expiration_month: ccard.card_exp_month,
-                    expiration_year: ccard.card_exp_year,
-                    cvv: ccard.card_cvc,
+        let payment_method_id =
+            get_payment_method_id(item)?.ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "payment_method_id",
+            })?;
+        Ok(Self::mutation(
+            query,
+            PaymentInput {
+                payment_method_id,
+                transaction: TransactionInput {
+                    amount: utils::to_currency_base_unit(
+                        item.request.amount,
+                        item.request.currency,
+                    )?,
+                    order_id: item.payment_id.clone(),
                 },
-            })),
-            api::PaymentMethodData::Wallet(ref wallet_data) => {
-                Ok(PaymentMethodType::PaymentMethodNonce(Nonce {
-                    payment_method_nonce: match wallet_data {
-                        api_models::payments::WalletData::PaypalSdk(wallet_data) => {
-                            Ok(wallet_data.token.to_owned())
-                        }
-                        _ => Err(errors::ConnectorError::InvalidWallet),
-                    }?,
-                }))
-            }
-            _ => Err(errors::ConnectorError::NotImplemented(format!(
-                "Current Payment Method - {:?}",
-                item.request.payment_method_data
-            ))),
-        }?;
-        let braintree_transaction_body = TransactionBody {
-            amount,
-            device_data,
-            options,
-            payment_method_data_type,
-            kind,
-        };
-        Ok(Self {
-            transaction: braintree_transaction_body,
-        })
+            },
+        ))
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureInput {
+    transaction_id: String,
+    transaction: CaptureTransactionInput,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CaptureTransactionInput {
+    amount: String,
+}
+
+pub type BraintreeCaptureRequest = BraintreeRequest<InputVariables<CaptureInput>>;
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for BraintreeCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self::mutation(
+            CAPTURE_TRANSACTION_MUTATION,
+            CaptureInput {
+                transaction_id: item.request.connector_transaction_id.clone(),
+                transaction: CaptureTransactionInput {
+                    amount: utils::to_currency_base_unit(
+                        types::MinorUnit::new(item.request.amount_to_capture),
+                        item.request.currency,
+                    )?,
+                },
+            },
+        ))
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReverseInput {
+    transaction_id: String,
+}
+
+pub type BraintreeCancelRequest = BraintreeRequest<InputVariables<ReverseInput>>;
+
+impl TryFrom<&types::PaymentsCancelRouterData> for BraintreeCancelRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        Ok(Self::mutation(
+            REVERSE_TRANSACTION_MUTATION,
+            ReverseInput {
+                transaction_id: item.request.connector_transaction_id.clone(),
+            },
+        ))
+    }
+}
+
+pub type BraintreeSyncRequest = BraintreeRequest<NodeVariables>;
+
+impl TryFrom<&types::PaymentsSyncRouterData> for BraintreeSyncRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        let connector_transaction_id = item
+            .request
+            .connector_transaction_id
+            .get_connector_transaction_id()
+            .map_err(|_| errors::ConnectorError::MissingConnectorTransactionID)?;
+        Ok(Self::node_query(
+            TRANSACTION_QUERY,
+            connector_transaction_id,
+        ))
+    }
+}
+
+impl TryFrom<&types::RefundSyncRouterData> for BraintreeSyncRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundSyncRouterData) -> Result<Self, Self::Error> {
+        let connector_refund_id = item
+            .request
+            .connector_refund_id
+            .clone()
+            .ok_or(errors::ConnectorError::MissingConnectorRefundID)?;
+        Ok(Self::node_query(REFUND_QUERY, connector_refund_id))
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundInput {
+    transaction_id: String,
+    refund: RefundDetailsInput,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundDetailsInput {
+    amount: String,
+    order_id: String,
+}
+
+pub type BraintreeRefundRequest = BraintreeRequest<InputVariables<RefundInput>>;
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for BraintreeRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self::mutation(
+            REFUND_TRANSACTION_MUTATION,
+            RefundInput {
+                transaction_id: item.request.connector_transaction_id.clone(),
+                refund: RefundDetailsInput {
+                    amount: utils::to_currency_base_unit(
+                        types::MinorUnit::new(item.request.refund_amount),
+                        item.request.currency,
+                    )?,
+                    order_id: item.request.refund_id.clone(),
+                },
+            },
+        ))
     }
 }
 
 pub struct BraintreeAuthType {
     pub(super) auth_header: String,
-    pub(super) merchant_id: String,
 }
 
 impl TryFrom<&types::ConnectorAuthType> for BraintreeAuthType {
@@ -146,226 +301,444 @@ impl TryFrom<&types::ConnectorAuthType> for BraintreeAuthType {
     fn try_from(item: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
         if let types::ConnectorAuthType::SignatureKey {
             api_key: public_key,
-            key1: merchant_id,
             api_secret: private_key,
+            ..
         } = item
         {
             let auth_key = format!("{public_key}:{private_key}");
             let auth_header = format!("Basic {}", consts::BASE64_ENGINE.encode(auth_key));
-            Ok(Self {
-                auth_header,
-                merchant_id: merchant_id.to_owned(),
-            })
+            Ok(Self { auth_header })
         } else {
             Err(errors::ConnectorError::FailedToObtainAuthType)?
         }
     }
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum BraintreePaymentStatus {
-    Succeeded,
-    Failed,
+/// The status of a transaction or a refund
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BraintreeTransactionStatus {
     Authorized,
-    AuthorizedExpired,
-    ProcessorDeclined,
+    Authorizing,
+    AuthorizationExpired,
+    Failed,
     GatewayRejected,
-    Voided,
+    ProcessorDeclined,
     SubmittedForSettlement,
-    #[default]
     Settling,
     Settled,
     SettlementPending,
     SettlementDeclined,
     SettlementConfirmed,
+    Voided,
+}
+
+impl From<BraintreeTransactionStatus> for enums::AttemptStatus {
+    fn from(item: BraintreeTransactionStatus) -> Self {
+        match item {
+            BraintreeTransactionStatus::SubmittedForSettlement
+            | BraintreeTransactionStatus::Settling
+            | BraintreeTransactionStatus::Settled
+            | BraintreeTransactionStatus::SettlementConfirmed => Self::Charged,
+            BraintreeTransactionStatus::AuthorizationExpired => Self::AuthorizationFailed,
+            BraintreeTransactionStatus::Failed
+            | BraintreeTransactionStatus::GatewayRejected
+            | BraintreeTransactionStatus::ProcessorDeclined
+            | BraintreeTransactionStatus::SettlementDeclined => Self::Failure,
+            BraintreeTransactionStatus::Authorized => Self::Authorized,
+            BraintreeTransactionStatus::Authorizing => Self::Authorizing,
+            BraintreeTransactionStatus::Voided => Self::Voided,
+            BraintreeTransactionStatus::SettlementPending => Self::Pending,
+        }
+    }
 }
 
-impl From<BraintreePaymentStatus> for enums::AttemptStatus {
-    fn from(item: BraintreePaymentStatus) -> Self {
+impl From<BraintreeTransactionStatus> for enums::RefundStatus {
+    fn from(item: BraintreeTransactionStatus) -> Self {
         match item {
-            BraintreePaymentStatus::Succeeded
-            | BraintreePaymentStatus::SubmittedForSettlement
-            | BraintreePaymentStatus::Settling => Self::Charged,
-            BraintreePaymentStatus::AuthorizedExpired => Self::AuthorizationFailed,
-            BraintreePaymentStatus::Failed
-            | BraintreePaymentStatus::GatewayRejected
-            | BraintreePaymentStatus::ProcessorDeclined
-            | BraintreePaymentStatus::SettlementDeclined => Self::Failure,
-            BraintreePaymentStatus::Authorized => Self::Authorized,
-            BraintreePaymentStatus::Voided => Self::Voided,
-            _ => Self::Pending,
+            BraintreeTransactionStatus::SubmittedForSettlement
+            | BraintreeTransactionStatus::Settling
+            | BraintreeTransactionStatus::Settled
+            | BraintreeTransactionStatus::SettlementConfirmed => Self::Success,
+            BraintreeTransactionStatus::Failed
+            | BraintreeTransactionStatus::GatewayRejected
+            | BraintreeTransactionStatus::ProcessorDeclined
+            | BraintreeTransactionStatus::SettlementDeclined
+            | BraintreeTransactionStatus::Voided
+            | BraintreeTransactionStatus::AuthorizationExpired => Self::Failure,
+            BraintreeTransactionStatus::Authorized
+            | BraintreeTransactionStatus::Authorizing
+            | BraintreeTransactionStatus::SettlementPending => Self::Pending,
+        }
+    }
+}
+
+/// The response of the GraphQL API, which responds with errors instead of the data with a
+/// successful HTTP status code
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum BraintreeResponse<T> {
+    ErrorResponse(ErrorResponse),
+    Response(BraintreeData<T>),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BraintreeData<T> {
+    pub data: T,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientTokenData {
+    pub create_client_token: ClientTokenResponse,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientTokenResponse {
+    pub client_token: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenizeCreditCardData {
+    pub tokenize_credit_card: TokenizeCreditCardResponse,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenizeCreditCardResponse {
+    pub payment_method: PaymentMethodResponse,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PaymentMethodResponse {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PaymentsData {
+    #[serde(rename = "authorizePaymentMethod", alias = "chargePaymentMethod")]
+    pub payment: TransactionPayload,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureData {
+    pub capture_transaction: TransactionPayload,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelData {
+    pub reverse_transaction: ReversalPayload,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransactionPayload {
+    pub transaction: TransactionResponse,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReversalPayload {
+    pub reversal: TransactionResponse,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PaymentsSyncData {
+    pub node: TransactionResponse,
+}
+
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq)]
+pub struct TransactionResponse {
+    pub id: String,
+    pub status: BraintreeTransactionStatus,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundData {
+    pub refund_transaction: RefundPayload,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefundPayload {
+    pub refund: RefundResponse,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefundSyncData {
+    pub node: RefundResponse,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefundResponse {
+    pub id: String,
+    pub status: BraintreeTransactionStatus,
+}
+
+pub type BraintreeSessionResponse = BraintreeResponse<ClientTokenData>;
+pub type BraintreeTokenizeResponse = BraintreeResponse<TokenizeCreditCardData>;
+pub type BraintreePaymentsResponse = BraintreeResponse<PaymentsData>;
+pub type BraintreeCaptureResponse = BraintreeResponse<CaptureData>;
+pub type BraintreeCancelResponse = BraintreeResponse<CancelData>;
+pub type BraintreeSyncResponse = BraintreeResponse<PaymentsSyncData>;
+pub type BraintreeRefundResponse = BraintreeResponse<RefundData>;
+pub type BraintreeRefundSyncResponse = BraintreeResponse<RefundSyncData>;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ErrorResponse {
+    pub errors: Vec<ErrorDetails>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ErrorDetails {
+    pub message: String,
+    pub extensions: Option<ErrorExtensions>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorExtensions {
+    pub legacy_code: Option<String>,
+}
+
+impl ErrorResponse {
+    pub fn get_error_response(self, status_code: u16) -> types::ErrorResponse {
+        let error = self.errors.into_iter().next();
+        types::ErrorResponse {
+            code: error
+                .as_ref()
+                .and_then(|error| error.extensions.as_ref())
+                .and_then(|extensions| extensions.legacy_code.clone())
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: error
+                .map(|error| error.message)
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+            status_code,
         }
     }
 }
 
 impl<F, T>
-    TryFrom<types::ResponseRouterData<F, BraintreePaymentsResponse, T, types::PaymentsResponseData>>
+    TryFrom<types::ResponseRouterData<F, BraintreeSessionResponse, T, types::PaymentsResponseData>>
     for types::RouterData<F, T, types::PaymentsResponseData>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
         item: types::ResponseRouterData<
             F,
-            BraintreePaymentsResponse,
+            BraintreeSessionResponse,
             T,
             types::PaymentsResponseData,
         >,
     ) -> Result<Self, Self::Error> {
+        let response = match item.response {
+            BraintreeResponse::ErrorResponse(error) => {
+                Err(error.get_error_response(item.http_code))
+            }
+            BraintreeResponse::Response(response) => {
+                Ok(types::PaymentsResponseData::SessionResponse {
+                    session_token: types::api::SessionToken::Paypal(Box::new(
+                        payments::PaypalSessionTokenResponse {
+                            session_token: response.data.create_client_token.client_token,
+                        },
+                    )),
+                })
+            }
+        };
         Ok(Self {
-            status: enums::AttemptStatus::from(item.response.transaction.status),
-            response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id: types::ResponseId::ConnectorTransactionId(
-                    item.response.transaction.id,
-                ),
-                redirection_data: None,
-                mandate_reference: None,
-                connector_metadata: None,
-            }),
+            response,
             ..item.data
         })
     }
 }
 
 impl<F, T>
-    TryFrom<
-        types::ResponseRouterData<F, BraintreeSessionTokenResponse, T, types::PaymentsResponseData>,
-    > for types::RouterData<F, T, types::PaymentsResponseData>
+    TryFrom<types::ResponseRouterData<F, BraintreeTokenizeResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
         item: types::ResponseRouterData<
             F,
-            BraintreeSessionTokenResponse,
+            BraintreeTokenizeResponse,
             T,
             types::PaymentsResponseData,
         >,
     ) -> Result<Self, Self::Error> {
-        Ok(Self {
-            response: Ok(types::PaymentsResponseData::SessionResponse {
-                session_token: types::api::SessionToken::Paypal(Box::new(
-                    payments::PaypalSessionTokenResponse {
-                        session_token: item.response.client_token.value,
-                    },
-                )),
+        match item.response {
+            BraintreeResponse::ErrorResponse(error) => Ok(Self {
+                response: Err(error.get_error_response(item.http_code)),
+                ..item.data
             }),
-            ..item.data
-        })
+            BraintreeResponse::Response(response) => Ok(Self {
+                reference_id: Some(response.data.tokenize_credit_card.payment_method.id),
+                ..item.data
+            }),
+        }
     }
 }
 
-#[derive(Default, Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct BraintreePaymentsResponse {
-    transaction: TransactionResponse,
-}
-
-#[derive(Default, Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ClientToken {
-    pub value: String,
-}
-
-#[derive(Default, Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct BraintreeSessionTokenResponse {
-    pub client_token: ClientToken,
-}
-
-#[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
-#[serde(rename_all = "camelCase")]
-pub struct TransactionResponse {
-    id: String,
-    currency_iso_code: String,
-    amount: String,
-    status: BraintreePaymentStatus,
-}
-
-#[derive(Debug, Default, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ErrorResponse {
-    pub api_error_response: ApiErrorResponse,
-}
-
-#[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
-pub struct ApiErrorResponse {
-    pub message: String,
-}
-
-#[derive(Default, Debug, Clone, Serialize)]
-pub struct BraintreeRefundRequest {
-    transaction: Amount,
+fn get_transaction_router_data<F, T>(
+    response: Result<TransactionResponse, ErrorResponse>,
+    data: types::RouterData<F, T, types::PaymentsResponseData>,
+    http_code: u16,
+) -> types::RouterData<F, T, types::PaymentsResponseData> {
+    match response {
+        Ok(transaction) => types::RouterData {
+            status: enums::AttemptStatus::from(transaction.status),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(transaction.id),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                verification_result: None,
+            }),
+            ..data
+        },
+        Err(error) => types::RouterData {
+            response: Err(error.get_error_response(http_code)),
+            ..data
+        },
+    }
 }
 
-#[derive(Default, Debug, Serialize, Clone)]
-pub struct Amount {
-    amount: Option<String>,
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, BraintreePaymentsResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            BraintreePaymentsResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let response = match item.response {
+            BraintreeResponse::ErrorResponse(error) => Err(error),
+            BraintreeResponse::Response(response) => Ok(response.data.payment.transaction),
+        };
+        Ok(get_transaction_router_data(
+            response,
+            item.data,
+            item.http_code,
+        ))
+    }
 }
 
-impl<F> TryFrom<&types::RefundsRouterData<F>> for BraintreeRefundRequest {
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, BraintreeCaptureResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
     type Error = error_stack::Report<errors::ConnectorError>;
-    fn try_from(_item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
-        Ok(Self {
-            transaction: Amount { amount: None },
-        })
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            BraintreeCaptureResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let response = match item.response {
+            BraintreeResponse::ErrorResponse(error) => Err(error),
+            BraintreeResponse::Response(response) => {
+                Ok(response.data.capture_transaction.transaction)
+            }
+        };
+        Ok(get_transaction_router_data(
+            response,
+            item.data,
+            item.http_code,
+        ))
     }
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Default, Deserialize, Clone)]
-pub enum RefundStatus {
-    Succeeded,
-    Failed,
-    #[default]
-    Processing,
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, BraintreeCancelResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, BraintreeCancelResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let response = match item.response {
+            BraintreeResponse::ErrorResponse(error) => Err(error),
+            BraintreeResponse::Response(response) => Ok(response.data.reverse_transaction.reversal),
+        };
+        Ok(get_transaction_router_data(
+            response,
+            item.data,
+            item.http_code,
+        ))
+    }
 }
 
-impl From<RefundStatus> for enums::RefundStatus {
-    fn from(item: RefundStatus) -> Self {
-        match item {
-            RefundStatus::Succeeded => Self::Success,
-            RefundStatus::Failed => Self::Failure,
-            RefundStatus::Processing => Self::Pending,
-        }
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, BraintreeSyncResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, BraintreeSyncResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let response = match item.response {
+            BraintreeResponse::ErrorResponse(error) => Err(error),
+            BraintreeResponse::Response(response) => Ok(response.data.node),
+        };
+        Ok(get_transaction_router_data(
+            response,
+            item.data,
+            item.http_code,
+        ))
     }
 }
 
-#[derive(Default, Debug, Clone, Deserialize)]
-pub struct RefundResponse {
-    pub id: String,
-    pub status: RefundStatus,
+fn get_refund_router_data<F>(
+    response: Result<RefundResponse, ErrorResponse>,
+    data: types::RefundsRouterData<F>,
+    http_code: u16,
+) -> types::RefundsRouterData<F> {
+    types::RouterData {
+        response: response
+            .map(|refund| types::RefundsResponseData {
+                connector_refund_id: refund.id,
+                refund_status: enums::RefundStatus::from(refund.status),
+            })
+            .map_err(|error| error.get_error_response(http_code)),
+        ..data
+    }
 }
 
-impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
+impl TryFrom<types::RefundsResponseRouterData<api::Execute, BraintreeRefundResponse>>
     for types::RefundsRouterData<api::Execute>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: types::RefundsResponseRouterData<api::Execute, RefundResponse>,
+        item: types::RefundsResponseRouterData<api::Execute, BraintreeRefundResponse>,
     ) -> Result<Self, Self::Error> {
-        Ok(Self {
-            response: Ok(types::RefundsResponseData {
-                connector_refund_id: item.response.id,
-                refund_status: enums::RefundStatus::from(item.response.status),
-            }),
-            ..item.data
-        })
+        let response = match item.response {
+            BraintreeResponse::ErrorResponse(error) => Err(error),
+            BraintreeResponse::Response(response) => Ok(response.data.refund_transaction.refund),
+        };
+        Ok(get_refund_router_data(response, item.data, item.http_code))
     }
 }
 
-impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
+impl TryFrom<types::RefundsResponseRouterData<api::RSync, BraintreeRefundSyncResponse>>
     for types::RefundsRouterData<api::RSync>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: types::RefundsResponseRouterData<api::RSync, RefundResponse>,
+        item: types::RefundsResponseRouterData<api::RSync, BraintreeRefundSyncResponse>,
     ) -> Result<Self, Self::Error> {
-        Ok(Self {
-            response: Ok(types::RefundsResponseData {
-                connector_refund_id: item.response.id,
-                refund_status: enums::RefundStatus::from(item.response.status),
-            }),
-            ..item.data
-        })
+        let response = match item.response {
+            BraintreeResponse::ErrorResponse(error) => Err(error),
+            BraintreeResponse::Response(response) => Ok(response.data.node),
+        };
+        Ok(get_refund_router_data(response, item.data, item.http_code))
     }
 }
@@ -2,9 +2,16 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::{
+    connector::utils,
+    consts,
     core::errors,
     pii, services,
-    types::{self, api, storage::enums, transformers::ForeignFrom},
+    types::{
+        self,
+        api::{self, enums as api_enums},
+        storage::enums,
+        transformers::ForeignFrom,
+    },
 };
 
 #[derive(Debug, Serialize)]
@@ -16,10 +23,33 @@ pub struct CardSource {
     pub expiry_year: Option<pii::Secret<String>>,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkTokenType {
+    Vts,
+    Mdes,
+    Applepay,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NetworkTokenSource {
+    #[serde(rename = "type")]
+    pub source_type: String,
+    pub token: pii::Secret<String, pii::CardNumber>,
+    pub expiry_month: pii::Secret<String>,
+    pub expiry_year: pii::Secret<String>,
+    pub token_type: NetworkTokenType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cryptogram: Option<pii::Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eci: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(untagged)]
 pub enum Source {
     Card(CardSource),
+    NetworkToken(NetworkTokenSource),
 }
 
 pub struct CheckoutAuthType {
@@ -44,6 +74,17 @@ pub struct PaymentsRequest {
     #[serde(flatten)]
     pub return_url: ReturnUrl,
     pub capture: bool,
+    pub payment_type: CheckoutPaymentType,
+    pub merchant_initiated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_payment_id: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub enum CheckoutPaymentType {
+    #[default]
+    Regular,
+    Recurring,
 }
 
 #[derive(Debug, Serialize)]
@@ -70,9 +111,25 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
     fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
         let ccard = match item.request.payment_method_data {
             api::PaymentMethodData::Card(ref ccard) => Some(ccard),
+            // Apple Pay tokens have to be decrypted by the router, the decrypted device account
+            // number is sent as a network token
+            api::PaymentMethodData::Wallet(api_models::payments::WalletData::ApplePay(_))
+                if item.request.apple_pay_predecrypt_data.is_none() =>
+            {
+                Err(errors::ConnectorError::NotSupported {
+                    payment_method: "Apple Pay without decryption by the router".to_string(),
+                    connector: "Checkout",
+                    payment_experience: api_models::enums::PaymentExperience::InvokeSdkClient
+                        .to_string(),
+                })?
+            }
             api::PaymentMethodData::Wallet(_)
             | api::PaymentMethodData::PayLater(_)
-            | api::PaymentMethodData::BankRedirect(_) => None,
+            | api::PaymentMethodData::BankRedirect(_)
+            | api::PaymentMethodData::BankDebit(_)
+            | api::PaymentMethodData::Upi(_)
+            | api::PaymentMethodData::Crypto(_)
+            | api::PaymentMethodData::GiftCard(_) => None,
         };
 
         let three_ds = match item.auth_type {
@@ -104,28 +161,92 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             Some(enums::CaptureMethod::Automatic)
         );
 
-        let source_var = Source::Card(CardSource {
-            source_type: Some("card".to_owned()),
-            number: ccard.map(|x| x.card_number.clone()),
-            expiry_month: ccard.map(|x| x.card_exp_month.clone()),
-            expiry_year: ccard.map(|x| x.card_exp_year.clone()),
+        // The network token of the card is preferred over the card number, when available
+        let network_token_source = item
+            .request
+            .network_token
+            .as_ref()
+            .and_then(|network_token| {
+                let token_type = match network_token.token_service_provider {
+                    api_models::enums::CardNetwork::Visa => NetworkTokenType::Vts,
+                    api_models::enums::CardNetwork::Mastercard => NetworkTokenType::Mdes,
+                    _ => return None,
+                };
+                Some(Source::NetworkToken(NetworkTokenSource {
+                    source_type: "network_token".to_owned(),
+                    token: network_token.token_number.clone(),
+                    expiry_month: network_token.token_exp_month.clone(),
+                    expiry_year: network_token.token_exp_year.clone(),
+                    token_type,
+                    cryptogram: network_token.cryptogram.clone(),
+                    eci: None,
+                }))
+            })
+            .or_else(|| {
+                item.request
+                    .apple_pay_predecrypt_data
+                    .as_ref()
+                    .map(|apple_pay_data| {
+                        Source::NetworkToken(NetworkTokenSource {
+                            source_type: "network_token".to_owned(),
+                            token: apple_pay_data.application_primary_account_number.clone(),
+                            expiry_month: apple_pay_data.get_expiry_month(),
+                            expiry_year: apple_pay_data.get_four_digit_expiry_year(),
+                            token_type: NetworkTokenType::Applepay,
+                            cryptogram: Some(
+                                apple_pay_data
+                                    .payment_data
+                                    .online_payment_cryptogram
+                                    .clone(),
+                            ),
+                            eci: apple_pay_data.payment_data.eci_indicator.clone(),
+                        })
+                    })
+            });
+        let source_var = network_token_source.unwrap_or_else(|| {
+            Source::Card(CardSource {
+                source_type: Some("card".to_owned()),
+                number: ccard.map(|x| x.card_number.clone()),
+                expiry_month: ccard.map(|x| x.card_exp_month.clone()),
+                expiry_year: ccard.map(|x| x.card_exp_year.clone()),
+            })
         });
+        // Merchant initiated payments reference the network transaction id of the payment which
+        // stored the card, when it is known
+        let (payment_type, merchant_initiated, previous_payment_id) =
+            match &item.request.stored_credential {
+                Some(types::StoredCredentialIndicator::SubsequentMerchantInitiated {
+                    network_transaction_id,
+                }) => (
+                    CheckoutPaymentType::Recurring,
+                    true,
+                    network_transaction_id.clone(),
+                ),
+                Some(types::StoredCredentialIndicator::InitialCustomerInitiated) => {
+                    (CheckoutPaymentType::Recurring, false, None)
+                }
+                None => (CheckoutPaymentType::Regular, false, None),
+            };
+
         let connector_auth = &item.connector_auth_type;
         let auth_type: CheckoutAuthType = connector_auth.try_into()?;
         let processing_channel_id = auth_type.processing_channel_id;
         Ok(Self {
             source: source_var,
-            amount: item.request.amount,
+            amount: item.request.amount.get_amount_as_i64(),
             currency: item.request.currency.to_string(),
             processing_channel_id,
             three_ds,
             return_url,
             capture,
+            payment_type,
+            merchant_initiated,
+            previous_payment_id,
         })
     }
 }
 
-#[derive(Default, Clone, Debug, Eq, PartialEq, Deserialize)]
+#[derive(Default, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum CheckoutPaymentStatus {
     Authorized,
     #[default]
@@ -134,6 +255,14 @@ pub enum CheckoutPaymentStatus {
     CardVerified,
     Declined,
     Captured,
+    #[serde(rename = "Partially Captured")]
+    PartiallyCaptured,
+    Voided,
+    Canceled,
+    Paid,
+    Refunded,
+    #[serde(rename = "Partially Refunded")]
+    PartiallyRefunded,
 }
 
 impl ForeignFrom<(CheckoutPaymentStatus, Option<enums::CaptureMethod>)> for enums::AttemptStatus {
@@ -149,10 +278,15 @@ impl ForeignFrom<(CheckoutPaymentStatus, Option<enums::CaptureMethod>)> for enum
                     Self::Authorized
                 }
             }
-            CheckoutPaymentStatus::Captured => Self::Charged,
+            CheckoutPaymentStatus::Captured
+            | CheckoutPaymentStatus::Paid
+            | CheckoutPaymentStatus::Refunded
+            | CheckoutPaymentStatus::PartiallyRefunded => Self::Charged,
+            CheckoutPaymentStatus::PartiallyCaptured => Self::PartialCharged,
             CheckoutPaymentStatus::Declined => Self::Failure,
             CheckoutPaymentStatus::Pending => Self::AuthenticationPending,
             CheckoutPaymentStatus::CardVerified => Self::Pending,
+            CheckoutPaymentStatus::Voided | CheckoutPaymentStatus::Canceled => Self::Voided,
         }
     }
 }
@@ -172,25 +306,30 @@ impl ForeignFrom<(CheckoutPaymentStatus, Option<Balances>)> for enums::AttemptSt
                     Self::Authorized
                 }
             }
-            CheckoutPaymentStatus::Captured => Self::Charged,
+            CheckoutPaymentStatus::Captured
+            | CheckoutPaymentStatus::Paid
+            | CheckoutPaymentStatus::Refunded
+            | CheckoutPaymentStatus::PartiallyRefunded => Self::Charged,
+            CheckoutPaymentStatus::PartiallyCaptured => Self::PartialCharged,
             CheckoutPaymentStatus::Declined => Self::Failure,
             CheckoutPaymentStatus::Pending => Self::AuthenticationPending,
             CheckoutPaymentStatus::CardVerified => Self::Pending,
+            CheckoutPaymentStatus::Voided | CheckoutPaymentStatus::Canceled => Self::Voided,
         }
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Href {
     #[serde(rename = "href")]
     redirection_url: Url,
 }
 
-#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Links {
     redirect: Option<Href>,
 }
-#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PaymentsResponse {
     id: String,
     amount: Option<i32>,
@@ -198,13 +337,64 @@ pub struct PaymentsResponse {
     #[serde(rename = "_links")]
     links: Links,
     balances: Option<Balances>,
+    response_code: Option<String>,
+    response_summary: Option<String>,
+    source: Option<ResponseSource>,
 }
 
-#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Balances {
     available_to_capture: i32,
 }
 
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ResponseSource {
+    avs_check: Option<String>,
+    cvv_check: Option<String>,
+}
+
+impl PaymentsResponse {
+    /// Returns the response of the payment, which is an error for payments declined by the issuer
+    /// or by the risk checks of Checkout
+    fn get_payments_response(
+        self,
+        http_code: u16,
+    ) -> Result<types::PaymentsResponseData, types::ErrorResponse> {
+        if self.status == CheckoutPaymentStatus::Declined {
+            return Err(types::ErrorResponse {
+                code: self
+                    .response_code
+                    .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                message: self
+                    .response_summary
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: None,
+                status_code: http_code,
+            });
+        }
+        let redirection_data = self.links.redirect.map(|href| {
+            services::RedirectForm::from((href.redirection_url, services::Method::Get))
+        });
+        let verification_result = self.source.map(|source| types::VerificationResult {
+            avs_result: source
+                .avs_check
+                .as_deref()
+                .map(utils::get_avs_result_from_code),
+            cvc_result: source
+                .cvv_check
+                .as_deref()
+                .map(utils::get_cvc_result_from_code),
+        });
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            resource_id: types::ResponseId::ConnectorTransactionId(self.id),
+            redirection_data,
+            mandate_reference: None,
+            connector_metadata: None,
+            verification_result,
+        })
+    }
+}
+
 impl TryFrom<types::PaymentsResponseRouterData<PaymentsResponse>>
     for types::PaymentsAuthorizeRouterData
 {
@@ -212,21 +402,12 @@ impl TryFrom<types::PaymentsResponseRouterData<PaymentsResponse>>
     fn try_from(
         item: types::PaymentsResponseRouterData<PaymentsResponse>,
     ) -> Result<Self, Self::Error> {
-        let redirection_data = item.response.links.redirect.map(|href| {
-            services::RedirectForm::from((href.redirection_url, services::Method::Get))
-        });
-
         Ok(Self {
             status: enums::AttemptStatus::foreign_from((
-                item.response.status,
+                item.response.status.clone(),
                 item.data.request.capture_method,
             )),
-            response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
-                redirection_data,
-                mandate_reference: None,
-                connector_metadata: None,
-            }),
+            response: item.response.get_payments_response(item.http_code),
             ..item.data
         })
     }
@@ -239,21 +420,12 @@ impl TryFrom<types::PaymentsSyncResponseRouterData<PaymentsResponse>>
     fn try_from(
         item: types::PaymentsSyncResponseRouterData<PaymentsResponse>,
     ) -> Result<Self, Self::Error> {
-        let redirection_data = item.response.links.redirect.map(|href| {
-            services::RedirectForm::from((href.redirection_url, services::Method::Get))
-        });
-
         Ok(Self {
             status: enums::AttemptStatus::foreign_from((
-                item.response.status,
-                item.response.balances,
+                item.response.status.clone(),
+                item.response.balances.clone(),
             )),
-            response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
-                redirection_data,
-                mandate_reference: None,
-                connector_metadata: None,
-            }),
+            response: item.response.get_payments_response(item.http_code),
             ..item.data
         })
     }
@@ -262,6 +434,8 @@ impl TryFrom<types::PaymentsSyncResponseRouterData<PaymentsResponse>>
 #[derive(Clone, Default, Debug, Eq, PartialEq, Serialize)]
 pub struct PaymentVoidRequest {
     reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<common_utils::pii::SecretSerdeValue>,
 }
 #[derive(Clone, Default, Debug, Eq, PartialEq, Deserialize)]
 pub struct PaymentVoidResponse {
@@ -295,6 +469,7 @@ impl TryFrom<types::PaymentsCancelResponseRouterData<PaymentVoidResponse>>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             status: response.into(),
             ..item.data
@@ -307,6 +482,7 @@ impl TryFrom<&types::PaymentsCancelRouterData> for PaymentVoidRequest {
     fn try_from(item: &types::PaymentsCancelRouterData) -> Result<Self, Self::Error> {
         Ok(Self {
             reference: item.request.connector_transaction_id.clone(),
+            metadata: item.request.cancellation_metadata.clone(),
         })
     }
 }
@@ -366,6 +542,7 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             status,
             amount_captured,
@@ -458,6 +635,61 @@ pub struct ErrorResponse {
     pub error_codes: Option<Vec<String>>,
 }
 
+impl ErrorResponse {
+    pub fn get_error_response(self, status_code: u16) -> types::ErrorResponse {
+        let error_codes = self
+            .error_codes
+            .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()]);
+        // The first error code is the most relevant one, and is described to the merchant when
+        // it is known
+        let reason = error_codes
+            .first()
+            .and_then(|error_code| get_error_code_description(error_code))
+            .map(ToString::to_string);
+        types::ErrorResponse {
+            status_code,
+            code: error_codes.join(" & "),
+            message: self
+                .error_type
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason,
+        }
+    }
+}
+
+/// Describes the validation and processing error codes of Checkout which are caused by the
+/// payment details provided by the customer or the merchant
+fn get_error_code_description(error_code: &str) -> Option<&'static str> {
+    match error_code {
+        "amount_invalid" | "amount_required" => Some("The amount of the payment is invalid"),
+        "card_expired" => Some("The card has expired"),
+        "card_expiry_month_invalid" | "card_expiry_month_required" => {
+            Some("The expiry month of the card is invalid")
+        }
+        "card_expiry_year_invalid" | "card_expiry_year_required" => {
+            Some("The expiry year of the card is invalid")
+        }
+        "card_number_invalid" | "card_number_required" => Some("The card number is invalid"),
+        "cvv_invalid" => Some("The CVV of the card is invalid"),
+        "currency_invalid" | "currency_required" => Some("The currency is invalid"),
+        "action_failure_limit_exceeded" | "refund_amount_exceeds_balance" => {
+            Some("The amount exceeds the amount available for the action")
+        }
+        "capture_value_greater_than_authorized" => {
+            Some("The amount to capture is greater than the authorized amount")
+        }
+        "payment_source_required" => Some("The payment method is missing"),
+        "processing_channel_id_invalid" => Some("The processing channel is invalid"),
+        "3ds_not_configured" | "3ds_not_enabled_for_card" | "3ds_not_supported" => {
+            Some("3DS is not available for the card")
+        }
+        "risk_enabled_required_for_fail_if_risk_is_not_available" => {
+            Some("The payment was declined by the risk checks")
+        }
+        _ => None,
+    }
+}
+
 #[derive(Deserialize)]
 pub enum ActionType {
     Authorization,
@@ -550,3 +782,102 @@ impl From<CheckoutRedirectResponseStatus> for enums::AttemptStatus {
         }
     }
 }
+
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutWebhookEventType {
+    PaymentApproved,
+    PaymentPending,
+    PaymentCaptured,
+    PaymentDeclined,
+    PaymentExpired,
+    PaymentRefunded,
+    PaymentRefundDeclined,
+    #[serde(other)]
+    Unknown,
+}
+
+impl CheckoutWebhookEventType {
+    pub fn is_refund_event(&self) -> bool {
+        matches!(self, Self::PaymentRefunded | Self::PaymentRefundDeclined)
+    }
+}
+
+impl From<CheckoutWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(event_type: CheckoutWebhookEventType) -> Self {
+        match event_type {
+            CheckoutWebhookEventType::PaymentApproved
+            | CheckoutWebhookEventType::PaymentCaptured => Self::PaymentIntentSuccess,
+            CheckoutWebhookEventType::PaymentDeclined
+            | CheckoutWebhookEventType::PaymentExpired => Self::PaymentIntentFailure,
+            CheckoutWebhookEventType::PaymentPending => Self::PaymentIntentProcessing,
+            CheckoutWebhookEventType::PaymentRefunded => Self::RefundSuccess,
+            CheckoutWebhookEventType::PaymentRefundDeclined => Self::RefundFailure,
+            CheckoutWebhookEventType::Unknown => Self::EventNotSupported,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutWebhookBody {
+    #[serde(rename = "type")]
+    pub event_type: CheckoutWebhookEventType,
+    pub data: CheckoutWebhookData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutWebhookData {
+    pub id: String,
+    pub action_id: Option<String>,
+    pub amount: Option<i32>,
+    pub response_code: Option<String>,
+    pub response_summary: Option<String>,
+}
+
+impl From<CheckoutWebhookBody> for PaymentsResponse {
+    fn from(item: CheckoutWebhookBody) -> Self {
+        // The payment of the event is parsed as the response of the payment sync flow, whose
+        // status is derived from the type of the event
+        let status = match item.event_type {
+            CheckoutWebhookEventType::PaymentApproved => CheckoutPaymentStatus::Authorized,
+            CheckoutWebhookEventType::PaymentCaptured => CheckoutPaymentStatus::Captured,
+            CheckoutWebhookEventType::PaymentDeclined
+            | CheckoutWebhookEventType::PaymentExpired => CheckoutPaymentStatus::Declined,
+            CheckoutWebhookEventType::PaymentPending
+            | CheckoutWebhookEventType::PaymentRefunded
+            | CheckoutWebhookEventType::PaymentRefundDeclined
+            | CheckoutWebhookEventType::Unknown => CheckoutPaymentStatus::Pending,
+        };
+        Self {
+            id: item.data.id,
+            amount: item.data.amount,
+            status,
+            links: Links::default(),
+            balances: None,
+            response_code: item.data.response_code,
+            response_summary: item.data.response_summary,
+            source: None,
+        }
+    }
+}
+
+/// Maps the response codes of Checkout to the error codes shared across connectors
+pub fn get_unified_code(response_code: &str) -> Option<api_enums::UnifiedCode> {
+    match response_code {
+        "20051" => Some(api_enums::UnifiedCode::InsufficientFunds),
+        "20005" => Some(api_enums::UnifiedCode::DoNotHonor),
+        "20054" | "30033" => Some(api_enums::UnifiedCode::ExpiredCard),
+        "20082" | "20087" => Some(api_enums::UnifiedCode::IncorrectCvc),
+        "20014" => Some(api_enums::UnifiedCode::InvalidCardNumber),
+        "20041" | "20043" | "30041" | "30043" => Some(api_enums::UnifiedCode::LostOrStolenCard),
+        "20057" | "20058" => Some(api_enums::UnifiedCode::CardNotSupported),
+        "20061" | "20065" => Some(api_enums::UnifiedCode::LimitExceeded),
+        "20059" | "20062" | "30004" => Some(api_enums::UnifiedCode::FraudSuspected),
+        "20150" | "20151" | "20152" => Some(api_enums::UnifiedCode::AuthenticationRequired),
+        "20091" => Some(api_enums::UnifiedCode::IssuerUnavailable),
+        "20096" | "20068" => Some(api_enums::UnifiedCode::ProcessingError),
+        "20012" | "20013" => Some(api_enums::UnifiedCode::InvalidRequest),
+        code if code.starts_with("20") => Some(api_enums::UnifiedCode::GenericDecline),
+        _ => None,
+    }
+}
@@ -0,0 +1,251 @@
+use error_stack::IntoReport;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils::{self, PaymentsAuthorizeRequestData},
+    core::errors,
+    services,
+    types::{self, api, storage::enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+#[derive(Debug, Serialize)]
+pub struct CoinbasePaymentsRequest {
+    name: Option<String>,
+    description: Option<String>,
+    pricing_type: CoinbasePricingType,
+    local_price: LocalPrice,
+    redirect_url: String,
+    cancel_url: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoinbasePricingType {
+    FixedPrice,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LocalPrice {
+    amount: String,
+    currency: String,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for CoinbasePaymentsRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data {
+            api::PaymentMethodData::Crypto(_) => {
+                let return_url = item.request.get_return_url()?;
+                Ok(Self {
+                    name: item.description.clone(),
+                    description: item.description.clone(),
+                    pricing_type: CoinbasePricingType::FixedPrice,
+                    local_price: LocalPrice {
+                        amount: utils::to_currency_base_unit(
+                            item.request.amount,
+                            item.request.currency,
+                        )?,
+                        currency: item.request.currency.to_string(),
+                    },
+                    redirect_url: return_url.clone(),
+                    cancel_url: return_url,
+                })
+            }
+            _ => Err(errors::ConnectorError::NotImplemented(
+                "Payment methods".to_string(),
+            ))
+            .into_report(),
+        }
+    }
+}
+
+pub struct CoinbaseAuthType {
+    pub(super) api_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for CoinbaseAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        if let types::ConnectorAuthType::HeaderKey { api_key } = auth_type {
+            Ok(Self {
+                api_key: api_key.to_string(),
+            })
+        } else {
+            Err(errors::ConnectorError::FailedToObtainAuthType.into())
+        }
+    }
+}
+
+/// The status of a charge is the status of the latest event in its timeline
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CoinbasePaymentStatus {
+    New,
+    Pending,
+    Completed,
+    Expired,
+    Unresolved,
+    Resolved,
+    Canceled,
+    #[serde(rename = "REFUND PENDING")]
+    RefundPending,
+    Refunded,
+}
+
+/// The reason for which a charge could not be resolved by Coinbase
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum UnResolvedContext {
+    Underpaid,
+    Overpaid,
+    Delayed,
+    Multiple,
+    Manual,
+    Other,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timeline {
+    status: CoinbasePaymentStatus,
+    context: Option<UnResolvedContext>,
+    time: String,
+}
+
+impl From<&Timeline> for enums::AttemptStatus {
+    fn from(timeline: &Timeline) -> Self {
+        match (&timeline.status, &timeline.context) {
+            (CoinbasePaymentStatus::New, _) => Self::AuthenticationPending,
+            (CoinbasePaymentStatus::Pending, _) => Self::Pending,
+            (CoinbasePaymentStatus::Completed, _)
+            | (CoinbasePaymentStatus::Resolved, _)
+            | (CoinbasePaymentStatus::RefundPending, _)
+            | (CoinbasePaymentStatus::Refunded, _) => Self::Charged,
+            (CoinbasePaymentStatus::Expired, _) => Self::Failure,
+            (CoinbasePaymentStatus::Canceled, _) => Self::Voided,
+            (CoinbasePaymentStatus::Unresolved, Some(UnResolvedContext::Underpaid)) => {
+                Self::Underpaid
+            }
+            (CoinbasePaymentStatus::Unresolved, Some(UnResolvedContext::Overpaid)) => {
+                Self::Overpaid
+            }
+            // Charges which were paid after they expired, or paid more than once, are resolved
+            // by the merchant
+            (CoinbasePaymentStatus::Unresolved, _) => Self::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoinbasePaymentResponseData {
+    pub id: String,
+    pub code: String,
+    pub hosted_url: Url,
+    pub timeline: Vec<Timeline>,
+    pub pricing: Option<serde_json::Value>,
+    pub expires_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoinbasePaymentsResponse {
+    pub data: CoinbasePaymentResponseData,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, CoinbasePaymentsResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            CoinbasePaymentsResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let status = item
+            .response
+            .data
+            .timeline
+            .last()
+            .map(enums::AttemptStatus::from)
+            .ok_or(errors::ConnectorError::ResponseHandlingFailed)
+            .into_report()?;
+        let redirection_data =
+            services::RedirectForm::from((item.response.data.hosted_url, services::Method::Get));
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.data.id),
+                redirection_data: Some(redirection_data),
+                mandate_reference: None,
+                connector_metadata: None,
+                verification_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseErrorResponse {
+    pub error: CoinbaseErrorData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseErrorData {
+    #[serde(rename = "type")]
+    pub error_type: String,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseWebhookDetails {
+    pub event: CoinbaseWebhookEvent,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseWebhookEvent {
+    #[serde(rename = "type")]
+    pub event_type: CoinbaseWebhookEventType,
+    pub data: CoinbasePaymentResponseData,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum CoinbaseWebhookEventType {
+    #[serde(rename = "charge:created")]
+    Created,
+    #[serde(rename = "charge:pending")]
+    Pending,
+    #[serde(rename = "charge:confirmed")]
+    Confirmed,
+    #[serde(rename = "charge:failed")]
+    Failed,
+    #[serde(rename = "charge:delayed")]
+    Delayed,
+    #[serde(rename = "charge:resolved")]
+    Resolved,
+    #[serde(other)]
+    Unknown,
+}
+
+impl From<CoinbaseWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(event_type: CoinbaseWebhookEventType) -> Self {
+        match event_type {
+            CoinbaseWebhookEventType::Confirmed | CoinbaseWebhookEventType::Resolved => {
+                Self::PaymentIntentSuccess
+            }
+            CoinbaseWebhookEventType::Failed => Self::PaymentIntentFailure,
+            // Payments received after the charge expired are to be resolved by the merchant
+            CoinbaseWebhookEventType::Pending | CoinbaseWebhookEventType::Delayed => {
+                Self::PaymentIntentProcessing
+            }
+            CoinbaseWebhookEventType::Created | CoinbaseWebhookEventType::Unknown => {
+                Self::EventNotSupported
+            }
+        }
+    }
+}
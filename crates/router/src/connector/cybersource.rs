@@ -95,7 +95,11 @@ impl ConnectorCommon for Cybersource {
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         Ok(types::ErrorResponse {
             status_code: res.status_code,
-            code: consts::NO_ERROR_CODE.to_string(),
+            code: response
+                .reason
+                .as_deref()
+                .map(|reason| cybersource::get_decline_code(reason).to_string())
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
             message: response
                 .message
                 .map(|m| {
@@ -111,6 +115,11 @@ impl ConnectorCommon for Cybersource {
             reason: response.reason,
         })
     }
+
+    fn get_unified_code(&self, error_code: &str) -> Option<api::enums::UnifiedCode> {
+        // The reason codes of Cybersource are mapped to the unified codes in the error response
+        error_code.parse().ok()
+    }
 }
 
 impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Cybersource
@@ -229,12 +238,11 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
         &self,
         req: &types::PaymentsCaptureRouterData,
     ) -> CustomResult<Option<String>, errors::ConnectorError> {
-        let req_obj = cybersource::CybersourcePaymentsRequest::try_from(req)?;
-        let req =
-            utils::Encode::<cybersource::CybersourcePaymentsRequest>::encode_to_string_of_json(
-                &req_obj,
-            )
-            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        let req_obj = cybersource::CybersourcePaymentsCaptureRequest::try_from(req)?;
+        let req = utils::Encode::<cybersource::CybersourcePaymentsCaptureRequest>::encode_to_string_of_json(
+            &req_obj,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
         Ok(Some(req))
     }
     fn build_request(
@@ -476,7 +484,7 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
     ) -> CustomResult<String, errors::ConnectorError> {
         let connector_payment_id = req.request.connector_transaction_id.clone();
         Ok(format!(
-            "{}pts/v2/payments/{}/voids",
+            "{}pts/v2/payments/{}/reversals",
             self.base_url(connectors),
             connector_payment_id
         ))
@@ -488,9 +496,14 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
 
     fn get_request_body(
         &self,
-        _req: &types::PaymentsCancelRouterData,
+        req: &types::PaymentsCancelRouterData,
     ) -> CustomResult<Option<String>, errors::ConnectorError> {
-        Ok(Some("{}".to_string()))
+        let req_obj = cybersource::CybersourceVoidRequest::try_from(req)?;
+        let req = utils::Encode::<cybersource::CybersourceVoidRequest>::encode_to_string_of_json(
+            &req_obj,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(req))
     }
 
     fn build_request(
@@ -4,8 +4,9 @@ use masking::Secret;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::{self, AddressDetailsData, PhoneDetailsData, RouterData},
-    consts,
+    connector::utils::{
+        self, AddressDetailsData, PaymentsCancelRequestData, PhoneDetailsData, RouterData,
+    },
     core::errors,
     pii::PeekInterface,
     types::{
@@ -21,6 +22,9 @@ pub struct CybersourcePaymentsRequest {
     processing_information: ProcessingInformation,
     payment_information: PaymentInformation,
     order_information: OrderInformationWithBill,
+    client_reference_information: ClientReferenceInformation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    device_information: Option<DeviceInformation>,
 }
 
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
@@ -28,6 +32,32 @@ pub struct CybersourcePaymentsRequest {
 pub struct ProcessingInformation {
     capture: bool,
     capture_options: Option<CaptureOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    action_list: Option<Vec<CybersourceActionsList>>,
+    // The level of the card data sent with the payment, "3" when line items are sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_level: Option<String>,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CybersourceActionsList {
+    // Skips the fraud screening of Decision Manager for the payment
+    DecisionSkip,
+}
+
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+pub struct ClientReferenceInformation {
+    code: String,
+}
+
+// Details of the device of the customer, which are used by Decision Manager to screen the payment
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceInformation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ip_address: Option<std::net::IpAddr>,
+    user_agent: String,
 }
 
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
@@ -54,8 +84,47 @@ pub struct Card {
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderInformationWithBill {
-    amount_details: Amount,
+    amount_details: AmountWithTax,
     bill_to: BillTo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invoice_details: Option<InvoiceDetails>,
+}
+
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AmountWithTax {
+    total_amount: String,
+    currency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+}
+
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    product_name: String,
+    quantity: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commodity_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measure: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+}
+
+// The reference of the customer for the purchase is sent as its purchase order number
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceDetails {
+    purchase_order_number: String,
 }
 
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
@@ -71,6 +140,45 @@ pub struct Amount {
     currency: String,
 }
 
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourcePaymentsCaptureRequest {
+    processing_information: ProcessingInformation,
+    order_information: OrderInformation,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourceVoidRequest {
+    client_reference_information: ClientReferenceInformation,
+    reversal_information: ReversalInformation,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReversalInformation {
+    amount_details: Amount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CybersourceConnectorMetadata {
+    // Skips Decision Manager for the payments of the account, for merchants which screen the
+    // payments on their own
+    #[serde(default)]
+    pub skip_decision_manager: bool,
+}
+
+fn get_connector_metadata(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<CybersourceConnectorMetadata, error_stack::Report<errors::ConnectorError>> {
+    match item.connector_meta_data {
+        Some(_) => item.to_connector_meta(),
+        None => Ok(CybersourceConnectorMetadata::default()),
+    }
+}
+
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct BillTo {
@@ -108,6 +216,25 @@ fn build_bill_to(
     })
 }
 
+impl From<&payments::L3LineItem> for LineItem {
+    fn from(line_item: &payments::L3LineItem) -> Self {
+        Self {
+            product_name: line_item.description.clone(),
+            quantity: line_item.quantity,
+            unit_price: Some(line_item.unit_amount.to_string()),
+            product_code: line_item.product_code.clone(),
+            commodity_code: line_item.commodity_code.clone(),
+            unit_of_measure: line_item.unit_of_measure.clone(),
+            tax_amount: line_item
+                .tax_amount
+                .map(|tax_amount| tax_amount.to_string()),
+            discount_amount: line_item
+                .discount_amount
+                .map(|discount_amount| discount_amount.to_string()),
+        }
+    }
+}
+
 impl TryFrom<&types::PaymentsAuthorizeRouterData> for CybersourcePaymentsRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
@@ -125,12 +252,40 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for CybersourcePaymentsRequest
                     .ok_or_else(utils::missing_field_err("email"))?;
                 let bill_to = build_bill_to(item.get_billing()?, email, number_with_code)?;
 
+                // The level 3 line items of the payment take precedence over the line items of
+                // the order, since they carry the data for interchange optimization
+                let l2_l3_data = item.request.l2_l3_data.as_ref();
+                let l3_line_items =
+                    l2_l3_data.and_then(|l2_l3_data| l2_l3_data.line_items.as_ref());
+                let line_items = match l3_line_items {
+                    Some(l3_line_items) => Some(l3_line_items.iter().map(LineItem::from).collect()),
+                    None => item.request.order_details.as_ref().map(|order_details| {
+                        order_details
+                            .iter()
+                            .map(|order| LineItem {
+                                product_name: order.product_name.clone(),
+                                quantity: order.quantity,
+                                unit_price: order.amount.map(|amount| amount.to_string()),
+                                ..Default::default()
+                            })
+                            .collect()
+                    }),
+                };
                 let order_information = OrderInformationWithBill {
-                    amount_details: Amount {
+                    amount_details: AmountWithTax {
                         total_amount: item.request.amount.to_string(),
                         currency: item.request.currency.to_string().to_uppercase(),
+                        tax_amount: l2_l3_data
+                            .and_then(|l2_l3_data| l2_l3_data.tax_amount)
+                            .map(|tax_amount| tax_amount.to_string()),
                     },
                     bill_to,
+                    line_items,
+                    invoice_details: l2_l3_data
+                        .and_then(|l2_l3_data| l2_l3_data.customer_reference.clone())
+                        .map(|purchase_order_number| InvoiceDetails {
+                            purchase_order_number,
+                        }),
                 };
 
                 let payment_information = PaymentInformation {
@@ -148,12 +303,29 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for CybersourcePaymentsRequest
                         Some(enums::CaptureMethod::Automatic) | None
                     ),
                     capture_options: None,
+                    action_list: get_connector_metadata(item)?
+                        .skip_decision_manager
+                        .then(|| vec![CybersourceActionsList::DecisionSkip]),
+                    purchase_level: l3_line_items.map(|_| "3".to_string()),
                 };
 
+                let device_information =
+                    item.request
+                        .browser_info
+                        .as_ref()
+                        .map(|browser_info| DeviceInformation {
+                            ip_address: browser_info.ip_address,
+                            user_agent: browser_info.user_agent.clone(),
+                        });
+
                 Ok(Self {
                     processing_information,
                     payment_information,
                     order_information,
+                    client_reference_information: ClientReferenceInformation {
+                        code: item.payment_id.clone(),
+                    },
+                    device_information,
                 })
             }
             _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
@@ -161,7 +333,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for CybersourcePaymentsRequest
     }
 }
 
-impl TryFrom<&types::PaymentsCaptureRouterData> for CybersourcePaymentsRequest {
+impl TryFrom<&types::PaymentsCaptureRouterData> for CybersourcePaymentsCaptureRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(value: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
         Ok(Self {
@@ -172,14 +344,31 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for CybersourcePaymentsRequest {
                 }),
                 ..Default::default()
             },
-            order_information: OrderInformationWithBill {
+            order_information: OrderInformation {
                 amount_details: Amount {
                     total_amount: value.request.amount_to_capture.to_string(),
-                    ..Default::default()
+                    currency: value.request.currency.to_string().to_uppercase(),
                 },
-                ..Default::default()
             },
-            ..Default::default()
+        })
+    }
+}
+
+// Voids of authorized payments are authorization reversals in Cybersource
+impl TryFrom<&types::PaymentsCancelRouterData> for CybersourceVoidRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(value: &types::PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_reference_information: ClientReferenceInformation {
+                code: value.payment_id.clone(),
+            },
+            reversal_information: ReversalInformation {
+                amount_details: Amount {
+                    total_amount: value.request.get_amount()?.to_string(),
+                    currency: value.request.get_currency()?.to_string().to_uppercase(),
+                },
+                reason: value.request.cancellation_reason.clone(),
+            },
         })
     }
 }
@@ -236,6 +425,9 @@ pub enum CybersourcePaymentStatus {
     Pending,
     Declined,
     AuthorizedPendingReview,
+    AuthorizedRiskDeclined,
+    PendingReview,
+    InvalidRequest,
     Transmitted,
     #[default]
     Processing,
@@ -244,15 +436,20 @@ pub enum CybersourcePaymentStatus {
 impl From<CybersourcePaymentStatus> for enums::AttemptStatus {
     fn from(item: CybersourcePaymentStatus) -> Self {
         match item {
-            CybersourcePaymentStatus::Authorized
-            | CybersourcePaymentStatus::AuthorizedPendingReview => Self::Authorized,
+            CybersourcePaymentStatus::Authorized => Self::Authorized,
+            CybersourcePaymentStatus::AuthorizedPendingReview => Self::ManualReview,
             CybersourcePaymentStatus::Succeeded | CybersourcePaymentStatus::Transmitted => {
                 Self::Charged
             }
             CybersourcePaymentStatus::Voided | CybersourcePaymentStatus::Reversed => Self::Voided,
-            CybersourcePaymentStatus::Failed | CybersourcePaymentStatus::Declined => Self::Failure,
+            CybersourcePaymentStatus::Failed
+            | CybersourcePaymentStatus::Declined
+            | CybersourcePaymentStatus::AuthorizedRiskDeclined
+            | CybersourcePaymentStatus::InvalidRequest => Self::Failure,
             CybersourcePaymentStatus::Processing => Self::Authorizing,
-            CybersourcePaymentStatus::Pending => Self::Pending,
+            CybersourcePaymentStatus::Pending | CybersourcePaymentStatus::PendingReview => {
+                Self::Pending
+            }
         }
     }
 }
@@ -263,7 +460,9 @@ impl From<CybersourcePaymentStatus> for enums::RefundStatus {
             CybersourcePaymentStatus::Succeeded | CybersourcePaymentStatus::Transmitted => {
                 Self::Success
             }
-            CybersourcePaymentStatus::Failed => Self::Failure,
+            CybersourcePaymentStatus::Failed
+            | CybersourcePaymentStatus::Declined
+            | CybersourcePaymentStatus::InvalidRequest => Self::Failure,
             _ => Self::Pending,
         }
     }
@@ -275,6 +474,7 @@ pub struct CybersourcePaymentsResponse {
     id: String,
     status: CybersourcePaymentStatus,
     error_information: Option<CybersourceErrorInformation>,
+    processor_information: Option<ProcessorInformation>,
 }
 
 #[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
@@ -283,6 +483,39 @@ pub struct CybersourceErrorInformation {
     message: String,
 }
 
+#[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessorInformation {
+    avs: Option<Avs>,
+    card_verification: Option<CardVerification>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
+pub struct Avs {
+    code: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CardVerification {
+    result_code: Option<String>,
+}
+
+impl From<ProcessorInformation> for types::VerificationResult {
+    fn from(processor_information: ProcessorInformation) -> Self {
+        Self {
+            avs_result: processor_information
+                .avs
+                .and_then(|avs| avs.code)
+                .map(|code| utils::get_avs_result_from_code(&code)),
+            cvc_result: processor_information
+                .card_verification
+                .and_then(|card_verification| card_verification.result_code)
+                .map(|code| utils::get_cvc_result_from_code(&code)),
+        }
+    }
+}
+
 impl<F, T>
     TryFrom<(
         types::ResponseRouterData<F, CybersourcePaymentsResponse, T, types::PaymentsResponseData>,
@@ -303,20 +536,29 @@ impl<F, T>
     ) -> Result<Self, Self::Error> {
         let item = data.0;
         let is_capture = data.1;
+        let status = get_payment_status(is_capture, item.response.status.into());
         Ok(Self {
-            status: get_payment_status(is_capture, item.response.status.into()),
+            status,
+            // Payments held for review by Decision Manager carry error information as well, so
+            // only the failed payments are errors
             response: match item.response.error_information {
-                Some(error) => Err(types::ErrorResponse {
-                    code: consts::NO_ERROR_CODE.to_string(),
-                    message: error.message,
-                    reason: Some(error.reason),
-                    status_code: item.http_code,
-                }),
+                Some(error) if status == enums::AttemptStatus::Failure => {
+                    Err(types::ErrorResponse {
+                        code: get_decline_code(&error.reason).to_string(),
+                        message: error.message,
+                        reason: Some(error.reason),
+                        status_code: item.http_code,
+                    })
+                }
                 _ => Ok(types::PaymentsResponseData::TransactionResponse {
                     resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
                     redirection_data: None,
                     mandate_reference: None,
                     connector_metadata: None,
+                    verification_result: item
+                        .response
+                        .processor_information
+                        .map(types::VerificationResult::from),
                 }),
             },
             ..item.data
@@ -380,6 +622,7 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
@@ -458,3 +701,35 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, CybersourceTransaction
         })
     }
 }
+
+/// Maps the reason codes of Cybersource to the error codes shared across connectors
+pub fn get_decline_code(reason: &str) -> api_enums::UnifiedCode {
+    match reason {
+        "INSUFFICIENT_FUND" => api_enums::UnifiedCode::InsufficientFunds,
+        "EXPIRED_CARD" => api_enums::UnifiedCode::ExpiredCard,
+        "STOLEN_LOST_CARD" => api_enums::UnifiedCode::LostOrStolenCard,
+        "INVALID_CVN" | "CV_FAILED" => api_enums::UnifiedCode::IncorrectCvc,
+        "AVS_FAILED" => api_enums::UnifiedCode::IncorrectAddress,
+        "INVALID_ACCOUNT" => api_enums::UnifiedCode::InvalidCardNumber,
+        "CARD_TYPE_NOT_ACCEPTED" | "UNAUTHORIZED_CARD" => api_enums::UnifiedCode::CardNotSupported,
+        "EXCEEDS_CREDIT_LIMIT" | "DEBIT_CARD_USAGE_LIMIT_EXCEEDED" => {
+            api_enums::UnifiedCode::LimitExceeded
+        }
+        "PROCESSOR_DECLINED" | "GENERAL_DECLINE" | "PAYMENT_REFUSED" | "CONTACT_PROCESSOR" => {
+            api_enums::UnifiedCode::DoNotHonor
+        }
+        "DECISION_PROFILE_REJECT" | "BLACKLISTED_CUSTOMER" | "SCORE_EXCEEDS_THRESHOLD" => {
+            api_enums::UnifiedCode::FraudSuspected
+        }
+        "CONSUMER_AUTHENTICATION_REQUIRED" => api_enums::UnifiedCode::AuthenticationRequired,
+        "ISSUER_UNAVAILABLE" => api_enums::UnifiedCode::IssuerUnavailable,
+        "PROCESSOR_ERROR" | "PROCESSOR_TIMEOUT" | "SYSTEM_ERROR" | "SERVER_TIMEOUT"
+        | "SERVICE_TIMEOUT" => api_enums::UnifiedCode::ProcessingError,
+        "MISSING_FIELD"
+        | "INVALID_DATA"
+        | "INVALID_AMOUNT"
+        | "DUPLICATE_REQUEST"
+        | "INVALID_MERCHANT_CONFIGURATION" => api_enums::UnifiedCode::InvalidRequest,
+        _ => api_enums::UnifiedCode::GenericDecline,
+    }
+}
@@ -79,7 +79,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for DlocalPaymentsRequest {
                     Some(enums::CaptureMethod::Automatic)
                 );
                 let payment_request = Self {
-                    amount: item.request.amount,
+                    amount: item.request.amount.get_amount_as_i64(),
                     currency: item.request.currency,
                     payment_method_id: PaymentMethodId::Card,
                     payment_method_flow: PaymentMethodFlow::Direct,
@@ -271,6 +271,7 @@ impl<F, T>
             redirection_data,
             mandate_reference: None,
             connector_metadata: None,
+            verification_result: None,
         };
         Ok(Self {
             status: enums::AttemptStatus::from(item.response.status),
@@ -307,6 +308,7 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
@@ -340,6 +342,7 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
@@ -372,6 +375,7 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
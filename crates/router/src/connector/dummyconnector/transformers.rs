@@ -0,0 +1,167 @@
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use serde::Deserialize;
+use storage_models::enums;
+
+use crate::{
+    core::errors,
+    services,
+    types::{self, api, transformers::ForeignInto},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for api::DummyConnectorPaymentRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data {
+            api::PaymentMethodData::Card(ref card) => Ok(Self {
+                amount: item.request.amount.get_amount_as_i64(),
+                currency: item.request.currency.foreign_into(),
+                card: api::DummyConnectorCard {
+                    number: Secret::new(card.card_number.peek().to_owned()),
+                    expiry_month: card.card_exp_month.clone(),
+                    expiry_year: card.card_exp_year.clone(),
+                    cvc: card.card_cvc.clone(),
+                },
+                return_url: item.request.router_return_url.clone(),
+                webhook_url: item.request.webhook_url.clone(),
+            }),
+            _ => Err(errors::ConnectorError::NotImplemented(
+                "Payment Method".to_string(),
+            ))
+            .into_report(),
+        }
+    }
+}
+
+pub struct DummyConnectorAuthType {
+    pub(super) api_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for DummyConnectorAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        if let types::ConnectorAuthType::HeaderKey { api_key } = auth_type {
+            Ok(Self {
+                api_key: api_key.to_string(),
+            })
+        } else {
+            Err(errors::ConnectorError::FailedToObtainAuthType.into())
+        }
+    }
+}
+
+fn get_attempt_status(status: api::DummyConnectorStatus) -> enums::AttemptStatus {
+    match status {
+        api::DummyConnectorStatus::Succeeded => enums::AttemptStatus::Charged,
+        api::DummyConnectorStatus::Processing => enums::AttemptStatus::Pending,
+        api::DummyConnectorStatus::RequiresAction => enums::AttemptStatus::AuthenticationPending,
+        api::DummyConnectorStatus::Failed => enums::AttemptStatus::Failure,
+    }
+}
+
+fn get_refund_status(status: api::DummyConnectorStatus) -> enums::RefundStatus {
+    match status {
+        api::DummyConnectorStatus::Succeeded => enums::RefundStatus::Success,
+        api::DummyConnectorStatus::Processing | api::DummyConnectorStatus::RequiresAction => {
+            enums::RefundStatus::Pending
+        }
+        api::DummyConnectorStatus::Failed => enums::RefundStatus::Failure,
+    }
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            api::DummyConnectorPaymentResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            api::DummyConnectorPaymentResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let redirection_data = item
+            .response
+            .next_action
+            .map(|next_action| {
+                url::Url::parse(&next_action.redirect_to_url)
+                    .into_report()
+                    .change_context(errors::ConnectorError::ResponseHandlingFailed)
+                    .map(|url| services::RedirectForm::from((url, services::Method::Get)))
+            })
+            .transpose()?;
+
+        let response = match item.response.error {
+            Some(error) => Err(types::ErrorResponse {
+                code: error.code,
+                message: error.message,
+                reason: None,
+                status_code: item.http_code,
+            }),
+            None => Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata: None,
+                verification_result: None,
+            }),
+        };
+
+        Ok(Self {
+            status: get_attempt_status(item.response.status),
+            response,
+            ..item.data
+        })
+    }
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for api::DummyConnectorRefundRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            payment_id: item.request.connector_transaction_id.clone(),
+            amount: item.request.refund_amount,
+        })
+    }
+}
+
+impl<T> TryFrom<types::RefundsResponseRouterData<T, api::DummyConnectorRefundResponse>>
+    for types::RefundsRouterData<T>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<T, api::DummyConnectorRefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: get_refund_status(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+pub fn get_webhook_event_type(status: api::DummyConnectorStatus) -> api::IncomingWebhookEvent {
+    match status {
+        api::DummyConnectorStatus::Succeeded => api::IncomingWebhookEvent::PaymentIntentSuccess,
+        api::DummyConnectorStatus::Processing => api::IncomingWebhookEvent::PaymentIntentProcessing,
+        api::DummyConnectorStatus::Failed => api::IncomingWebhookEvent::PaymentIntentFailure,
+        api::DummyConnectorStatus::RequiresAction => api::IncomingWebhookEvent::EventNotSupported,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DummyConnectorErrorResponse {
+    pub error: api::DummyConnectorError,
+}
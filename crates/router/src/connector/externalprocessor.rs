@@ -0,0 +1,594 @@
+mod transformers;
+
+use std::fmt::Debug;
+
+use error_stack::{IntoReport, ResultExt};
+use transformers as externalprocessor;
+
+use crate::{
+    configs::settings,
+    consts,
+    core::errors::{self, CustomResult},
+    headers,
+    services::{self, ConnectorIntegration},
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+        ErrorResponse, Response,
+    },
+    utils::BytesExt,
+};
+
+/// Connector for gateways which are integrated through the request templates and response
+/// mappings of the merchant connector account, instead of a dedicated integration
+#[derive(Debug, Clone)]
+pub struct Externalprocessor;
+
+impl api::Payment for Externalprocessor {}
+impl api::PaymentSession for Externalprocessor {}
+impl api::ConnectorAccessToken for Externalprocessor {}
+impl api::PreVerify for Externalprocessor {}
+impl api::PaymentAuthorize for Externalprocessor {}
+impl api::PaymentSync for Externalprocessor {}
+impl api::PaymentCapture for Externalprocessor {}
+impl api::PaymentVoid for Externalprocessor {}
+impl api::Refund for Externalprocessor {}
+impl api::RefundExecute for Externalprocessor {}
+impl api::RefundSync for Externalprocessor {}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Externalprocessor
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let metadata = externalprocessor::get_connector_metadata(req)?;
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            self.common_get_content_type().to_string(),
+        )];
+        let mut api_key =
+            externalprocessor::get_auth_headers(&req.connector_auth_type, &metadata.auth)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+}
+
+impl ConnectorCommon for Externalprocessor {
+    fn id(&self) -> &'static str {
+        "externalprocessor"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    // The base url of the gateway is a part of the connector metadata of the merchant
+    fn base_url<'a>(&self, _connectors: &'a settings::Connectors) -> &'a str {
+        ""
+    }
+
+    // Errors are not mapped for the responses of failed requests, the body of the response is
+    // returned as the reason of the error instead
+    fn build_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            code: consts::NO_ERROR_CODE.to_string(),
+            message: consts::NO_ERROR_MESSAGE.to_string(),
+            reason: String::from_utf8(res.response.to_vec()).ok(),
+        })
+    }
+}
+
+impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
+    for Externalprocessor
+{
+}
+
+impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, types::AccessToken>
+    for Externalprocessor
+{
+}
+
+impl ConnectorIntegration<api::Verify, types::VerifyRequestData, types::PaymentsResponseData>
+    for Externalprocessor
+{
+}
+
+impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::PaymentsResponseData>
+    for Externalprocessor
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        externalprocessor::ExternalProcessorRequest::try_from(req)?
+            .get_url(&connectors.externalprocessor)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        Ok(externalprocessor::ExternalProcessorRequest::try_from(req)?.body)
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = externalprocessor::ExternalProcessorRequest::try_from(req)?;
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(request.method)
+                .url(&request.get_url(&connectors.externalprocessor)?)
+                .attach_default_headers()
+                .headers(types::PaymentsAuthorizeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(request.body)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsAuthorizeRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        let response: serde_json::Value = res
+            .response
+            .parse_struct("ExternalProcessorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        let response = externalprocessor::get_payments_response(
+            data,
+            externalprocessor::ExternalProcessorFlow::Authorize,
+            &response,
+        )?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
+    for Externalprocessor
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        externalprocessor::ExternalProcessorRequest::try_from(req)?
+            .get_url(&connectors.externalprocessor)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        Ok(externalprocessor::ExternalProcessorRequest::try_from(req)?.body)
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = externalprocessor::ExternalProcessorRequest::try_from(req)?;
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(request.method)
+                .url(&request.get_url(&connectors.externalprocessor)?)
+                .attach_default_headers()
+                .headers(types::PaymentsSyncType::get_headers(self, req, connectors)?)
+                .body(request.body)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsSyncRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsSyncRouterData, errors::ConnectorError> {
+        let response: serde_json::Value = res
+            .response
+            .parse_struct("ExternalProcessorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        let response = externalprocessor::get_payments_response(
+            data,
+            externalprocessor::ExternalProcessorFlow::Psync,
+            &response,
+        )?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::PaymentsResponseData>
+    for Externalprocessor
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        externalprocessor::ExternalProcessorRequest::try_from(req)?
+            .get_url(&connectors.externalprocessor)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        Ok(externalprocessor::ExternalProcessorRequest::try_from(req)?.body)
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = externalprocessor::ExternalProcessorRequest::try_from(req)?;
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(request.method)
+                .url(&request.get_url(&connectors.externalprocessor)?)
+                .attach_default_headers()
+                .headers(types::PaymentsCaptureType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(request.body)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsCaptureRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsCaptureRouterData, errors::ConnectorError> {
+        let response: serde_json::Value = res
+            .response
+            .parse_struct("ExternalProcessorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        let response = externalprocessor::get_payments_response(
+            data,
+            externalprocessor::ExternalProcessorFlow::Capture,
+            &response,
+        )?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsResponseData>
+    for Externalprocessor
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsCancelRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsCancelRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        externalprocessor::ExternalProcessorRequest::try_from(req)?
+            .get_url(&connectors.externalprocessor)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsCancelRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        Ok(externalprocessor::ExternalProcessorRequest::try_from(req)?.body)
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsCancelRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = externalprocessor::ExternalProcessorRequest::try_from(req)?;
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(request.method)
+                .url(&request.get_url(&connectors.externalprocessor)?)
+                .attach_default_headers()
+                .headers(types::PaymentsVoidType::get_headers(self, req, connectors)?)
+                .body(request.body)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsCancelRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsCancelRouterData, errors::ConnectorError> {
+        let response: serde_json::Value = res
+            .response
+            .parse_struct("ExternalProcessorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        let response = externalprocessor::get_payments_response(
+            data,
+            externalprocessor::ExternalProcessorFlow::Void,
+            &response,
+        )?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
+    for Externalprocessor
+{
+    fn get_headers(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        externalprocessor::ExternalProcessorRequest::try_from(req)?
+            .get_url(&connectors.externalprocessor)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::RefundExecuteRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        Ok(externalprocessor::ExternalProcessorRequest::try_from(req)?.body)
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = externalprocessor::ExternalProcessorRequest::try_from(req)?;
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(request.method)
+                .url(&request.get_url(&connectors.externalprocessor)?)
+                .attach_default_headers()
+                .headers(types::RefundExecuteType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(request.body)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::RefundExecuteRouterData,
+        res: Response,
+    ) -> CustomResult<types::RefundExecuteRouterData, errors::ConnectorError> {
+        let response: serde_json::Value = res
+            .response
+            .parse_struct("ExternalProcessorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        let response = externalprocessor::get_refunds_response(
+            data,
+            externalprocessor::ExternalProcessorFlow::Refund,
+            &response,
+        )?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData>
+    for Externalprocessor
+{
+    fn get_headers(
+        &self,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        externalprocessor::ExternalProcessorRequest::try_from(req)?
+            .get_url(&connectors.externalprocessor)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::RefundSyncRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        Ok(externalprocessor::ExternalProcessorRequest::try_from(req)?.body)
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = externalprocessor::ExternalProcessorRequest::try_from(req)?;
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(request.method)
+                .url(&request.get_url(&connectors.externalprocessor)?)
+                .attach_default_headers()
+                .headers(types::RefundSyncType::get_headers(self, req, connectors)?)
+                .body(request.body)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::RefundSyncRouterData,
+        res: Response,
+    ) -> CustomResult<types::RefundSyncRouterData, errors::ConnectorError> {
+        let response: serde_json::Value = res
+            .response
+            .parse_struct("ExternalProcessorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        let response = externalprocessor::get_refunds_response(
+            data,
+            externalprocessor::ExternalProcessorFlow::Rsync,
+            &response,
+        )?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+#[async_trait::async_trait]
+impl api::IncomingWebhook for Externalprocessor {
+    fn get_webhook_object_reference_id(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+}
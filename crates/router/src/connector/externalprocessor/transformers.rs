@@ -0,0 +1,751 @@
+use std::{collections::HashMap, net::Ipv4Addr};
+
+use base64::Engine;
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use url::Url;
+
+use crate::{
+    configs::settings,
+    connector::utils::{self, PaymentsSyncRequestData, RefundsRequestData, RouterData},
+    consts,
+    core::errors,
+    services,
+    types::{self, api, storage::enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+const CONNECTOR_NAME: &str = "externalprocessor";
+
+/// The gateway integration of a merchant connector account, which is stored in its connector
+/// metadata. Requests are built from the templates of the flows, and the fields of the responses
+/// are read from the paths of their mappings.
+#[derive(Debug, Deserialize)]
+pub struct ExternalProcessorMetadata {
+    pub base_url: String,
+    #[serde(default)]
+    pub auth: AuthConfig,
+    pub flows: FlowsConfig,
+}
+
+/// The header in which the API key of the account is sent, for accounts with a header key
+#[derive(Debug, Deserialize)]
+pub struct AuthConfig {
+    pub header: String,
+    #[serde(default)]
+    pub prefix: String,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            header: "Authorization".to_string(),
+            prefix: "Bearer ".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FlowsConfig {
+    pub authorize: Option<FlowConfig>,
+    pub capture: Option<FlowConfig>,
+    pub void: Option<FlowConfig>,
+    pub psync: Option<FlowConfig>,
+    pub refund: Option<FlowConfig>,
+    pub rsync: Option<FlowConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FlowConfig {
+    #[serde(default = "default_method")]
+    pub method: services::Method,
+    // Path of the endpoint, relative to the base url of the gateway
+    pub path: String,
+    // Template of the JSON body of the request, no body is sent when it is absent
+    pub request: Option<Value>,
+    pub response: ResponseMapping,
+}
+
+fn default_method() -> services::Method {
+    services::Method::Post
+}
+
+/// Paths of the fields in the response of the gateway, in dot notation (e.g. `data.id`)
+#[derive(Debug, Deserialize)]
+pub struct ResponseMapping {
+    pub transaction_id: Option<String>,
+    pub status: String,
+    // Statuses of the gateway mapped to the attempt or refund statuses of the router
+    #[serde(default)]
+    pub status_mapping: HashMap<String, String>,
+    pub redirect_url: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum ExternalProcessorFlow {
+    Authorize,
+    Capture,
+    Void,
+    Psync,
+    Refund,
+    Rsync,
+}
+
+impl ExternalProcessorMetadata {
+    pub fn get_flow(&self, flow: ExternalProcessorFlow) -> Result<&FlowConfig, Error> {
+        let flow_config = match flow {
+            ExternalProcessorFlow::Authorize => self.flows.authorize.as_ref(),
+            ExternalProcessorFlow::Capture => self.flows.capture.as_ref(),
+            ExternalProcessorFlow::Void => self.flows.void.as_ref(),
+            ExternalProcessorFlow::Psync => self.flows.psync.as_ref(),
+            ExternalProcessorFlow::Refund => self.flows.refund.as_ref(),
+            ExternalProcessorFlow::Rsync => self.flows.rsync.as_ref(),
+        };
+        flow_config.ok_or_else(|| {
+            errors::ConnectorError::FlowNotSupported {
+                flow: flow.to_string(),
+                connector: CONNECTOR_NAME.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+pub fn get_connector_metadata<F, Req, Res>(
+    item: &types::RouterData<F, Req, Res>,
+) -> Result<ExternalProcessorMetadata, Error> {
+    item.to_connector_meta()
+}
+
+pub fn get_auth_headers(
+    auth_type: &types::ConnectorAuthType,
+    auth_config: &AuthConfig,
+) -> Result<Vec<(String, String)>, Error> {
+    match auth_type {
+        types::ConnectorAuthType::HeaderKey { api_key } => Ok(vec![(
+            auth_config.header.clone(),
+            format!("{}{api_key}", auth_config.prefix),
+        )]),
+        types::ConnectorAuthType::BodyKey { api_key, key1 } => {
+            let encoded_credentials = consts::BASE64_ENGINE.encode(format!("{api_key}:{key1}"));
+            Ok(vec![(
+                crate::headers::AUTHORIZATION.to_string(),
+                format!("Basic {encoded_credentials}"),
+            )])
+        }
+        _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+    }
+}
+
+/// Looks up the value at a path in dot notation, where numeric segments index into arrays
+fn get_value_at_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |current, segment| match current {
+            Value::Array(items) => segment
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| items.get(index)),
+            _ => current.get(segment),
+        })
+}
+
+fn value_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(value) => Some(value.clone()),
+        _ => Some(value.to_string()),
+    }
+}
+
+/// Replaces the `{{path}}` placeholders of a string with the values of the context, absent
+/// values are replaced with an empty string
+fn render_str(template: &str, context: &Value) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut remaining = template;
+    while let Some((start, end)) = remaining.find("{{").and_then(|start| {
+        remaining[start..]
+            .find("}}")
+            .map(|offset| (start, start + offset))
+    }) {
+        rendered.push_str(&remaining[..start]);
+        let path = remaining[start + 2..end].trim();
+        if let Some(value) = get_value_at_path(context, path).and_then(value_to_string) {
+            rendered.push_str(&value);
+        }
+        remaining = &remaining[end + 2..];
+    }
+    rendered.push_str(remaining);
+    rendered
+}
+
+/// Renders a request template. Strings which are a single placeholder are replaced with the
+/// value of the context as is, so that numbers and booleans keep their JSON type.
+fn render_value(template: &Value, context: &Value) -> Value {
+    match template {
+        Value::String(template) => {
+            let trimmed = template.trim();
+            let placeholder = trimmed
+                .strip_prefix("{{")
+                .and_then(|rest| rest.strip_suffix("}}"))
+                .filter(|path| !path.contains("{{") && !path.contains("}}"));
+            match placeholder {
+                Some(path) => get_value_at_path(context, path.trim())
+                    .cloned()
+                    .unwrap_or(Value::Null),
+                None => Value::String(render_str(template, context)),
+            }
+        }
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| render_value(item, context))
+                .collect(),
+        ),
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, value)| (key.clone(), render_value(value, context)))
+                .collect(),
+        ),
+        _ => template.clone(),
+    }
+}
+
+#[derive(Debug)]
+pub struct ExternalProcessorRequest {
+    pub method: services::Method,
+    pub base_url: String,
+    pub path: String,
+    pub body: Option<String>,
+}
+
+impl ExternalProcessorRequest {
+    fn try_from_context<F, Req, Res>(
+        item: &types::RouterData<F, Req, Res>,
+        flow: ExternalProcessorFlow,
+        context: &Value,
+    ) -> Result<Self, Error> {
+        let metadata = get_connector_metadata(item)?;
+        let flow_config = metadata.get_flow(flow)?;
+        Ok(Self {
+            method: flow_config.method,
+            base_url: metadata.base_url,
+            path: render_str(&flow_config.path, context),
+            body: flow_config
+                .request
+                .as_ref()
+                .map(|template| render_value(template, context).to_string()),
+        })
+    }
+
+    /// The url of the request, if the base url of the gateway is one of the gateways allowed by
+    /// the configuration. Requests carry card details, so they are never sent to a gateway which
+    /// was not allowed, or which is not reached over https at a public address.
+    pub fn get_url(&self, params: &settings::ExternalProcessorParams) -> Result<String, Error> {
+        let base_url = self.base_url.trim_end_matches('/');
+        if !params
+            .allowed_base_urls
+            .iter()
+            .any(|allowed_base_url| allowed_base_url.trim_end_matches('/') == base_url)
+        {
+            return Err(errors::ConnectorError::FailedToObtainIntegrationUrl)
+                .into_report()
+                .attach_printable_lazy(|| format!("The gateway {base_url} is not allowed"));
+        }
+
+        let url = format!("{base_url}/{}", self.path.trim_start_matches('/'));
+        let parsed_base_url = Url::parse(base_url)
+            .into_report()
+            .change_context(errors::ConnectorError::FailedToObtainIntegrationUrl)?;
+        let parsed_url = Url::parse(&url)
+            .into_report()
+            .change_context(errors::ConnectorError::FailedToObtainIntegrationUrl)?;
+        if parsed_url.scheme() != "https"
+            || parsed_url.origin() != parsed_base_url.origin()
+            || is_internal_host(&parsed_url)
+        {
+            return Err(errors::ConnectorError::FailedToObtainIntegrationUrl)
+                .into_report()
+                .attach_printable_lazy(|| format!("The gateway url {url} is not allowed"));
+        }
+        Ok(url)
+    }
+}
+
+fn is_internal_ipv4(ip: Ipv4Addr) -> bool {
+    ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+}
+
+/// Whether the host of the url is not publicly reachable, such as a loopback or private address
+fn is_internal_host(url: &Url) -> bool {
+    match url.host() {
+        Some(url::Host::Ipv4(ip)) => is_internal_ipv4(ip),
+        Some(url::Host::Ipv6(ip)) => {
+            let first_segment = ip.segments()[0];
+            ip.is_loopback()
+                || ip.is_unspecified()
+                // Unique local (fc00::/7) and link local (fe80::/10) addresses
+                || first_segment & 0xfe00 == 0xfc00
+                || first_segment & 0xffc0 == 0xfe80
+                || ip.to_ipv4_mapped().map_or(false, is_internal_ipv4)
+        }
+        Some(url::Host::Domain(domain)) => {
+            let domain = domain.to_ascii_lowercase();
+            domain == "localhost" || domain.ends_with(".localhost")
+        }
+        None => true,
+    }
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for ExternalProcessorRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let card = match &item.request.payment_method_data {
+            api::PaymentMethodData::Card(ccard) => json!({
+                "number": ccard.card_number.peek(),
+                "exp_month": ccard.card_exp_month.peek(),
+                "exp_year": ccard.card_exp_year.peek(),
+                "cvc": ccard.card_cvc.peek(),
+                "holder_name": ccard.card_holder_name.peek(),
+            }),
+            _ => Err(errors::ConnectorError::NotImplemented(
+                "Payment methods".to_string(),
+            ))?,
+        };
+        let context = json!({
+            "payment_id": item.payment_id,
+            "attempt_id": item.attempt_id,
+            "amount": item.request.amount,
+            "amount_decimal": utils::to_currency_base_unit(
+                item.request.amount,
+                item.request.currency
+            )?,
+            "currency": item.request.currency.to_string(),
+            "capture": matches!(
+                item.request.capture_method,
+                Some(enums::CaptureMethod::Automatic) | None
+            ),
+            "description": item.description,
+            "email": item.request.email.as_ref().map(|email| email.peek().clone()),
+            "return_url": item.request.router_return_url,
+            "card": card,
+        });
+        Self::try_from_context(item, ExternalProcessorFlow::Authorize, &context)
+    }
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for ExternalProcessorRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        let context = json!({
+            "payment_id": item.payment_id,
+            "attempt_id": item.attempt_id,
+            "connector_transaction_id": item.request.connector_transaction_id,
+            "amount": item.request.amount_to_capture,
+            "amount_decimal": utils::to_currency_base_unit(
+                types::MinorUnit::new(item.request.amount_to_capture),
+                item.request.currency
+            )?,
+            "currency": item.request.currency.to_string(),
+        });
+        Self::try_from_context(item, ExternalProcessorFlow::Capture, &context)
+    }
+}
+
+impl TryFrom<&types::PaymentsCancelRouterData> for ExternalProcessorRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        let context = json!({
+            "payment_id": item.payment_id,
+            "attempt_id": item.attempt_id,
+            "connector_transaction_id": item.request.connector_transaction_id,
+            "amount": item.request.amount,
+            "currency": item.request.currency.map(|currency| currency.to_string()),
+            "cancellation_reason": item.request.cancellation_reason,
+        });
+        Self::try_from_context(item, ExternalProcessorFlow::Void, &context)
+    }
+}
+
+impl TryFrom<&types::PaymentsSyncRouterData> for ExternalProcessorRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        let connector_transaction_id = item
+            .request
+            .get_connector_transaction_id()
+            .change_context(errors::ConnectorError::MissingConnectorTransactionID)?;
+        let context = json!({
+            "payment_id": item.payment_id,
+            "attempt_id": item.attempt_id,
+            "connector_transaction_id": connector_transaction_id,
+        });
+        Self::try_from_context(item, ExternalProcessorFlow::Psync, &context)
+    }
+}
+
+impl TryFrom<&types::RefundExecuteRouterData> for ExternalProcessorRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundExecuteRouterData) -> Result<Self, Self::Error> {
+        let context = json!({
+            "payment_id": item.payment_id,
+            "refund_id": item.request.refund_id,
+            "connector_transaction_id": item.request.connector_transaction_id,
+            "amount": item.request.refund_amount,
+            "amount_decimal": utils::to_currency_base_unit(
+                types::MinorUnit::new(item.request.refund_amount),
+                item.request.currency
+            )?,
+            "currency": item.request.currency.to_string(),
+            "reason": item.request.reason,
+        });
+        Self::try_from_context(item, ExternalProcessorFlow::Refund, &context)
+    }
+}
+
+impl TryFrom<&types::RefundSyncRouterData> for ExternalProcessorRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundSyncRouterData) -> Result<Self, Self::Error> {
+        let context = json!({
+            "payment_id": item.payment_id,
+            "refund_id": item.request.refund_id,
+            "connector_transaction_id": item.request.connector_transaction_id,
+            "connector_refund_id": item.request.get_connector_refund_id()?,
+        });
+        Self::try_from_context(item, ExternalProcessorFlow::Rsync, &context)
+    }
+}
+
+/// The fields of a gateway response, read with the response mapping of the flow
+#[derive(Debug)]
+pub struct ExternalProcessorResponse<S> {
+    pub status: S,
+    pub transaction_id: Option<String>,
+    pub redirect_url: Option<Url>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+impl ResponseMapping {
+    fn get_field(&self, response: &Value, path: Option<&String>) -> Option<String> {
+        path.and_then(|path| get_value_at_path(response, path))
+            .and_then(value_to_string)
+    }
+
+    /// Reads the fields of the response. Statuses which are not mapped are treated as pending,
+    /// so that they are resolved by a later sync.
+    fn get_response<S>(&self, response: &Value) -> Result<ExternalProcessorResponse<S>, Error>
+    where
+        S: std::str::FromStr + Default,
+    {
+        let status = self
+            .get_field(response, Some(&self.status))
+            .and_then(|status| self.status_mapping.get(&status))
+            .map(|status| status.parse::<S>())
+            .transpose()
+            .map_err(|_| errors::ConnectorError::ResponseHandlingFailed)?
+            .unwrap_or_default();
+        let redirect_url = self
+            .get_field(response, self.redirect_url.as_ref())
+            .map(|redirect_url| Url::parse(&redirect_url))
+            .transpose()
+            .into_report()
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+        Ok(ExternalProcessorResponse {
+            status,
+            transaction_id: self.get_field(response, self.transaction_id.as_ref()),
+            redirect_url,
+            error_code: self.get_field(response, self.error_code.as_ref()),
+            error_message: self.get_field(response, self.error_message.as_ref()),
+        })
+    }
+}
+
+pub fn get_payments_response<F, Req, Res>(
+    item: &types::RouterData<F, Req, Res>,
+    flow: ExternalProcessorFlow,
+    response: &Value,
+) -> Result<ExternalProcessorResponse<PaymentStatus>, Error> {
+    get_connector_metadata(item)?
+        .get_flow(flow)?
+        .response
+        .get_response(response)
+}
+
+pub fn get_refunds_response<F, Req, Res>(
+    item: &types::RouterData<F, Req, Res>,
+    flow: ExternalProcessorFlow,
+    response: &Value,
+) -> Result<ExternalProcessorResponse<RefundStatus>, Error> {
+    get_connector_metadata(item)?
+        .get_flow(flow)?
+        .response
+        .get_response(response)
+}
+
+// Wrappers of the router statuses, which default to pending for the statuses that are not mapped
+#[derive(Debug, Default, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentStatus {
+    Authorized,
+    Charged,
+    AuthenticationPending,
+    Voided,
+    Failure,
+    #[default]
+    Pending,
+}
+
+impl From<PaymentStatus> for enums::AttemptStatus {
+    fn from(item: PaymentStatus) -> Self {
+        match item {
+            PaymentStatus::Authorized => Self::Authorized,
+            PaymentStatus::Charged => Self::Charged,
+            PaymentStatus::AuthenticationPending => Self::AuthenticationPending,
+            PaymentStatus::Voided => Self::Voided,
+            PaymentStatus::Failure => Self::Failure,
+            PaymentStatus::Pending => Self::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Default, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundStatus {
+    Success,
+    Failure,
+    #[default]
+    Pending,
+}
+
+impl From<RefundStatus> for enums::RefundStatus {
+    fn from(item: RefundStatus) -> Self {
+        match item {
+            RefundStatus::Success => Self::Success,
+            RefundStatus::Failure => Self::Failure,
+            RefundStatus::Pending => Self::Pending,
+        }
+    }
+}
+
+fn get_error_response<S>(
+    response: &ExternalProcessorResponse<S>,
+    http_code: u16,
+) -> types::ErrorResponse {
+    types::ErrorResponse {
+        code: response
+            .error_code
+            .clone()
+            .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+        message: response
+            .error_message
+            .clone()
+            .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+        reason: None,
+        status_code: http_code,
+    }
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            ExternalProcessorResponse<PaymentStatus>,
+            T,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            ExternalProcessorResponse<PaymentStatus>,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let status = enums::AttemptStatus::from(item.response.status);
+        let response = if status == enums::AttemptStatus::Failure {
+            Err(get_error_response(&item.response, item.http_code))
+        } else {
+            let redirection_data = item
+                .response
+                .redirect_url
+                .map(|url| services::RedirectForm::from((url, services::Method::Get)));
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: item
+                    .response
+                    .transaction_id
+                    .map_or(types::ResponseId::NoResponseId, |transaction_id| {
+                        types::ResponseId::ConnectorTransactionId(transaction_id)
+                    }),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata: None,
+                verification_result: None,
+            })
+        };
+        Ok(Self {
+            status,
+            response,
+            ..item.data
+        })
+    }
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, ExternalProcessorResponse<RefundStatus>>>
+    for types::RefundsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, ExternalProcessorResponse<RefundStatus>>,
+    ) -> Result<Self, Self::Error> {
+        let refund_status = enums::RefundStatus::from(item.response.status);
+        let response = if refund_status == enums::RefundStatus::Failure {
+            Err(get_error_response(&item.response, item.http_code))
+        } else {
+            let connector_refund_id = item
+                .response
+                .transaction_id
+                .ok_or(errors::ConnectorError::MissingConnectorRefundID)?;
+            Ok(types::RefundsResponseData {
+                connector_refund_id,
+                refund_status,
+            })
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn render_request_template() {
+        let context = json!({
+            "amount": 1000,
+            "currency": "USD",
+            "capture": true,
+            "card": { "number": "4111111111111111" },
+        });
+        let template = json!({
+            "amount": "{{amount}}",
+            "capture": "{{ capture }}",
+            "description": "Payment of {{amount}} {{currency}}",
+            "source": { "number": "{{card.number}}", "cvc": "{{card.cvc}}" },
+            "tags": ["{{currency}}", "fixed"],
+        });
+        assert_eq!(
+            render_value(&template, &context),
+            json!({
+                "amount": 1000,
+                "capture": true,
+                "description": "Payment of 1000 USD",
+                "source": { "number": "4111111111111111", "cvc": null },
+                "tags": ["USD", "fixed"],
+            })
+        );
+    }
+
+    #[test]
+    fn render_path_template() {
+        let context = json!({ "connector_transaction_id": "txn_123" });
+        assert_eq!(
+            render_str("/payments/{{connector_transaction_id}}/capture", &context),
+            "/payments/txn_123/capture"
+        );
+        assert_eq!(
+            render_str("/payments/{{unclosed", &context),
+            "/payments/{{unclosed"
+        );
+    }
+
+    fn get_request(base_url: &str, path: &str) -> ExternalProcessorRequest {
+        ExternalProcessorRequest {
+            method: services::Method::Post,
+            base_url: base_url.to_string(),
+            path: path.to_string(),
+            body: None,
+        }
+    }
+
+    #[test]
+    fn only_allowed_gateways_are_called() {
+        let params = settings::ExternalProcessorParams {
+            allowed_base_urls: vec![
+                "https://api.gateway.com/v1/".to_string(),
+                "http://api.insecure.com".to_string(),
+                "https://127.0.0.1".to_string(),
+                "https://[fd00::1]".to_string(),
+            ],
+        };
+
+        assert_eq!(
+            get_request("https://api.gateway.com/v1", "/payments")
+                .get_url(&params)
+                .unwrap(),
+            "https://api.gateway.com/v1/payments"
+        );
+        assert!(get_request("https://api.other.com/v1", "/payments")
+            .get_url(&params)
+            .is_err());
+        assert!(get_request("https://api.gateway.com/v1", "/payments")
+            .get_url(&settings::ExternalProcessorParams::default())
+            .is_err());
+        assert!(get_request("http://api.insecure.com", "/payments")
+            .get_url(&params)
+            .is_err());
+        assert!(get_request("https://127.0.0.1", "/payments")
+            .get_url(&params)
+            .is_err());
+        assert!(get_request("https://[fd00::1]", "/payments")
+            .get_url(&params)
+            .is_err());
+    }
+
+    #[test]
+    fn read_mapped_response() {
+        let mapping = ResponseMapping {
+            transaction_id: Some("data.id".to_string()),
+            status: "data.state".to_string(),
+            status_mapping: HashMap::from([
+                ("APPROVED".to_string(), "charged".to_string()),
+                ("DECLINED".to_string(), "failure".to_string()),
+            ]),
+            redirect_url: None,
+            error_code: Some("errors.0.code".to_string()),
+            error_message: None,
+        };
+        let approved: ExternalProcessorResponse<PaymentStatus> = mapping
+            .get_response(&json!({ "data": { "id": 42, "state": "APPROVED" } }))
+            .unwrap();
+        assert!(matches!(approved.status, PaymentStatus::Charged));
+        assert_eq!(approved.transaction_id, Some("42".to_string()));
+
+        let declined: ExternalProcessorResponse<PaymentStatus> = mapping
+            .get_response(&json!({ "data": { "state": "DECLINED" }, "errors": [{ "code": "51" }] }))
+            .unwrap();
+        assert!(matches!(declined.status, PaymentStatus::Failure));
+        assert_eq!(declined.error_code, Some("51".to_string()));
+
+        let unknown: ExternalProcessorResponse<PaymentStatus> = mapping
+            .get_response(&json!({ "data": { "state": "IN_REVIEW" } }))
+            .unwrap();
+        assert!(matches!(unknown.status, PaymentStatus::Pending));
+    }
+}
@@ -317,6 +317,7 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
@@ -350,6 +351,7 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, FiservSyncResponse, T, types::Pa
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
@@ -388,8 +390,10 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for FiservCaptureRequest {
         let session: SessionObject = metadata
             .parse_value("SessionObject")
             .change_context(errors::ConnectorError::RequestEncodingFailed)?;
-        let amount =
-            utils::to_currency_base_unit(item.request.amount_to_capture, item.request.currency)?;
+        let amount = utils::to_currency_base_unit(
+            types::MinorUnit::new(item.request.amount_to_capture),
+            item.request.currency,
+        )?;
         Ok(Self {
             amount: Amount {
                 total: amount,
@@ -478,7 +482,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for FiservRefundRequest {
         Ok(Self {
             amount: Amount {
                 total: utils::to_currency_base_unit(
-                    item.request.refund_amount,
+                    types::MinorUnit::new(item.request.refund_amount),
                     item.request.currency,
                 )?,
                 currency: item.request.currency.to_string(),
@@ -245,6 +245,7 @@ fn get_payment_response(
             redirection_data,
             mandate_reference: None,
             connector_metadata: None,
+            verification_result: None,
         }),
     }
 }
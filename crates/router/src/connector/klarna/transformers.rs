@@ -4,15 +4,18 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     core::errors,
-    types::{self, storage::enums},
+    types::{self, api, storage::enums},
 };
 
 #[derive(Default, Debug, Serialize)]
 pub struct KlarnaPaymentsRequest {
     order_lines: Vec<OrderLines>,
     order_amount: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_tax_amount: Option<i64>,
     purchase_country: String,
     purchase_currency: enums::Currency,
+    auto_capture: bool,
 }
 
 #[derive(Default, Debug, Deserialize)]
@@ -28,6 +31,8 @@ pub struct KlarnaSessionRequest {
     purchase_currency: enums::Currency,
     locale: String,
     order_amount: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_tax_amount: Option<i64>,
     order_lines: Vec<OrderLines>,
 }
 
@@ -41,24 +46,19 @@ impl TryFrom<&types::PaymentsSessionRouterData> for KlarnaSessionRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsSessionRouterData) -> Result<Self, Self::Error> {
         let request = &item.request;
-        match request.order_details.clone() {
-            Some(order_details) => Ok(Self {
-                intent: KlarnaSessionIntent::Buy,
-                purchase_country: "US".to_string(),
-                purchase_currency: request.currency,
-                order_amount: request.amount,
-                locale: "en-US".to_string(),
-                order_lines: vec![OrderLines {
-                    name: order_details.product_name,
-                    quantity: order_details.quantity,
-                    unit_price: request.amount,
-                    total_amount: request.amount,
-                }],
-            }),
-            None => Err(report!(errors::ConnectorError::MissingRequiredField {
-                field_name: "product_name",
-            })),
-        }
+        Ok(Self {
+            intent: KlarnaSessionIntent::Buy,
+            purchase_country: "US".to_string(),
+            purchase_currency: request.currency,
+            order_amount: request.amount.get_amount_as_i64(),
+            order_tax_amount: get_order_tax_amount(request.amount_breakdown.as_ref()),
+            locale: "en-US".to_string(),
+            order_lines: get_order_lines(
+                request.order_details.as_ref(),
+                request.amount_breakdown.as_ref(),
+                request.amount.get_amount_as_i64(),
+            )?,
+        })
     }
 }
 
@@ -88,22 +88,18 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for KlarnaPaymentsRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
         let request = &item.request;
-        match request.order_details.clone() {
-            Some(order_details) => Ok(Self {
-                purchase_country: "US".to_string(),
-                purchase_currency: request.currency,
-                order_amount: request.amount,
-                order_lines: vec![OrderLines {
-                    name: order_details.product_name,
-                    quantity: order_details.quantity,
-                    unit_price: request.amount,
-                    total_amount: request.amount,
-                }],
-            }),
-            None => Err(report!(errors::ConnectorError::MissingRequiredField {
-                field_name: "product_name"
-            })),
-        }
+        Ok(Self {
+            purchase_country: "US".to_string(),
+            purchase_currency: request.currency,
+            order_amount: request.amount.get_amount_as_i64(),
+            order_tax_amount: get_order_tax_amount(request.amount_breakdown.as_ref()),
+            order_lines: get_order_lines(
+                request.order_details.as_ref(),
+                request.amount_breakdown.as_ref(),
+                request.amount.get_amount_as_i64(),
+            )?,
+            auto_capture: is_auto_capture(request),
+        })
     }
 }
 
@@ -120,20 +116,34 @@ impl TryFrom<types::PaymentsResponseRouterData<KlarnaPaymentsResponse>>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
-            status: item.response.fraud_status.into(),
+            status: get_attempt_status(
+                item.response.fraud_status,
+                is_auto_capture(&item.data.request),
+            ),
             ..item.data
         })
     }
 }
 #[derive(Debug, Serialize)]
 pub struct OrderLines {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    line_type: Option<OrderLineType>,
     name: String,
     quantity: u16,
     unit_price: i64,
     total_amount: i64,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderLineType {
+    ShippingFee,
+    SalesTax,
+    Discount,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
@@ -168,12 +178,188 @@ pub enum KlarnaFraudStatus {
     Pending,
 }
 
-impl From<KlarnaFraudStatus> for enums::AttemptStatus {
-    fn from(item: KlarnaFraudStatus) -> Self {
-        match item {
-            KlarnaFraudStatus::Accepted => Self::Charged,
-            KlarnaFraudStatus::Pending => Self::Authorizing,
-        }
+/// Klarna orders are captured when they are created only if `auto_capture` is requested, and are
+/// authorized otherwise
+fn get_attempt_status(
+    fraud_status: KlarnaFraudStatus,
+    is_auto_capture: bool,
+) -> enums::AttemptStatus {
+    match fraud_status {
+        KlarnaFraudStatus::Accepted if is_auto_capture => enums::AttemptStatus::Charged,
+        KlarnaFraudStatus::Accepted => enums::AttemptStatus::Authorized,
+        KlarnaFraudStatus::Pending => enums::AttemptStatus::Authorizing,
+    }
+}
+
+fn is_auto_capture(request: &types::PaymentsAuthorizeData) -> bool {
+    request.capture_method.unwrap_or_default() == enums::CaptureMethod::Automatic
+}
+
+fn get_order_tax_amount(amount_breakdown: Option<&payments::AmountBreakdown>) -> Option<i64> {
+    amount_breakdown.and_then(|amount_breakdown| amount_breakdown.tax_amount)
+}
+
+/// Klarna requires the line items of the order, including the shipping, tax and discount of the
+/// order, which must add up to the amount of the payment.
+fn get_order_lines(
+    order_details: Option<&Vec<payments::OrderDetails>>,
+    amount_breakdown: Option<&payments::AmountBreakdown>,
+    amount: i64,
+) -> Result<Vec<OrderLines>, error_stack::Report<errors::ConnectorError>> {
+    let products_amount =
+        amount_breakdown.map_or(amount, |amount_breakdown| amount_breakdown.subtotal);
+    let mut order_lines = get_product_order_lines(order_details, products_amount)?;
+
+    if let Some(amount_breakdown) = amount_breakdown {
+        let breakdown_lines = [
+            (
+                OrderLineType::ShippingFee,
+                "Shipping",
+                amount_breakdown.shipping_amount,
+            ),
+            (OrderLineType::SalesTax, "Tax", amount_breakdown.tax_amount),
+            (
+                OrderLineType::Discount,
+                "Discount",
+                amount_breakdown.discount_amount.map(|discount| -discount),
+            ),
+        ];
+        order_lines.extend(breakdown_lines.into_iter().filter_map(
+            |(line_type, name, line_amount)| {
+                line_amount
+                    .filter(|line_amount| *line_amount != 0)
+                    .map(|line_amount| OrderLines {
+                        line_type: Some(line_type),
+                        name: name.to_string(),
+                        quantity: 1,
+                        unit_price: line_amount,
+                        total_amount: line_amount,
+                    })
+            },
+        ));
+    }
+
+    Ok(order_lines)
+}
+
+/// If the price of the only product is not provided, the amount of the products of the order is
+/// used as its price.
+fn get_product_order_lines(
+    order_details: Option<&Vec<payments::OrderDetails>>,
+    amount: i64,
+) -> Result<Vec<OrderLines>, error_stack::Report<errors::ConnectorError>> {
+    let order_details = order_details
+        .filter(|order_details| !order_details.is_empty())
+        .ok_or_else(|| {
+            report!(errors::ConnectorError::MissingRequiredField {
+                field_name: "order_details",
+            })
+        })?;
+
+    match order_details.as_slice() {
+        [details] if details.amount.is_none() => Ok(vec![OrderLines {
+            line_type: None,
+            name: details.product_name.clone(),
+            quantity: details.quantity,
+            unit_price: amount,
+            total_amount: amount,
+        }]),
+        _ => order_details
+            .iter()
+            .map(|details| {
+                let (unit_price, total_amount) = details
+                    .amount
+                    .zip(details.get_total_amount())
+                    .ok_or_else(|| {
+                        report!(errors::ConnectorError::MissingRequiredField {
+                            field_name: "order_details.amount",
+                        })
+                    })?;
+                Ok(OrderLines {
+                    line_type: None,
+                    name: details.product_name.clone(),
+                    quantity: details.quantity,
+                    unit_price,
+                    total_amount,
+                })
+            })
+            .collect(),
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct KlarnaCaptureRequest {
+    captured_amount: i64,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for KlarnaCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            captured_amount: item.request.amount_to_capture,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct KlarnaRefundRequest {
+    refunded_amount: i64,
+    reference: String,
+    description: Option<String>,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for KlarnaRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            refunded_amount: item.request.refund_amount,
+            reference: item.request.refund_id.clone(),
+            description: item.request.reason.clone(),
+        })
+    }
+}
+
+/// Klarna responds to captures and refunds with an empty body, once they have been processed
+pub struct KlarnaOrderManagementResponse;
+
+impl TryFrom<types::PaymentsCaptureResponseRouterData<KlarnaOrderManagementResponse>>
+    for types::PaymentsCaptureRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsCaptureResponseRouterData<KlarnaOrderManagementResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.data.request.connector_transaction_id.clone(),
+                ),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                verification_result: None,
+            }),
+            status: enums::AttemptStatus::Charged,
+            ..item.data
+        })
+    }
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::Execute, KlarnaOrderManagementResponse>>
+    for types::RefundsRouterData<api::Execute>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::Execute, KlarnaOrderManagementResponse>,
+    ) -> Result<Self, Self::Error> {
+        // The refund is identified using the reference sent in the refund request
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.data.request.refund_id.clone(),
+                refund_status: enums::RefundStatus::Success,
+            }),
+            ..item.data
+        })
     }
 }
 
@@ -149,6 +149,11 @@ fn get_payment_method_for_bank_redirect(
             }))
         }
         api_models::payments::BankRedirectData::Sofort { .. } => PaymentMethodData::Sofort,
+        api_models::payments::BankRedirectData::Netbanking { .. }
+        | api_models::payments::BankRedirectData::Blik { .. } => Err(
+            errors::ConnectorError::NotImplemented("Payment Method".to_string()),
+        )
+        .into_report()?,
     };
     Ok(payment_method_data)
 }
@@ -333,6 +338,7 @@ impl<F, T>
                 redirection_data: url,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
@@ -351,7 +357,10 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for MollieRefundRequest {
     fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
         let amount = Amount {
             currency: item.request.currency,
-            value: utils::to_currency_base_unit(item.request.amount, item.request.currency)?,
+            value: utils::to_currency_base_unit(
+                types::MinorUnit::new(item.request.amount),
+                item.request.currency,
+            )?,
         };
         Ok(Self {
             amount,
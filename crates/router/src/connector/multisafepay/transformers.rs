@@ -324,7 +324,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for MultisafepayPaymentsReques
             gateway,
             order_id: item.payment_id.to_string(),
             currency: item.request.currency.to_string(),
-            amount: item.request.amount,
+            amount: item.request.amount.get_amount_as_i64(),
             description,
             payment_options: Some(payment_options),
             customer: Some(customer),
@@ -469,6 +469,7 @@ impl<F, T>
                     .payment_details
                     .and_then(|payment_details| payment_details.recurring_id),
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
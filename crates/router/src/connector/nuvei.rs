@@ -474,7 +474,9 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
         .await?;
         router_data.session_token = resp.session_token;
         let (enrolled_for_3ds, related_transaction_id) = match router_data.auth_type {
-            storage_models::enums::AuthenticationType::ThreeDs => {
+            storage_models::enums::AuthenticationType::ThreeDs
+                if router_data.request.three_ds_data.is_none() =>
+            {
                 let integ: Box<
                     &(dyn ConnectorIntegration<
                         InitPayment,
@@ -500,7 +502,10 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
                     init_resp.request.related_transaction_id,
                 )
             }
-            storage_models::enums::AuthenticationType::NoThreeDs => (false, None),
+            // Cardholders authenticated by an external 3DS provider are not authenticated again,
+            // the results of the authentication are sent along with the payment instead
+            storage_models::enums::AuthenticationType::ThreeDs
+            | storage_models::enums::AuthenticationType::NoThreeDs => (false, None),
         };
 
         router_data.request.enrolled_for_3ds = enrolled_for_3ds;
@@ -911,7 +916,7 @@ impl api::IncomingWebhook for Nuvei {
             nuvei::NuveiWebhookStatus::Declined => {
                 Ok(api::IncomingWebhookEvent::PaymentIntentFailure)
             }
-            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound.into()),
+            _ => Ok(api::IncomingWebhookEvent::EventNotSupported),
         }
     }
 
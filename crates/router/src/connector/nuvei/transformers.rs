@@ -156,6 +156,17 @@ pub struct Card {
     pub issuer_country: Option<String>,
     pub is_prepaid: Option<String>,
     pub external_token: Option<ExternalToken>,
+    pub external_mpi: Option<ExternalMpi>,
+}
+
+/// Results of a 3DS authentication performed outside of Nuvei
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalMpi {
+    pub eci: Option<String>,
+    pub cavv: Option<Secret<String>>,
+    #[serde(rename = "dsTransID")]
+    pub ds_trans_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -498,6 +509,13 @@ fn get_card_info<F>(
                 expiration_year: Some(card.card_exp_year),
                 three_d,
                 cvv: Some(card.card_cvc),
+                external_mpi: item.request.three_ds_data.as_ref().map(|three_ds_data| {
+                    ExternalMpi {
+                        eci: three_ds_data.eci.clone(),
+                        cavv: three_ds_data.authentication_value.clone(),
+                        ds_trans_id: three_ds_data.ds_trans_id.clone(),
+                    }
+                }),
                 ..Default::default()
             }),
             ..Default::default()
@@ -545,6 +563,13 @@ impl<F>
                         expiration_month: Some(card.card_exp_month),
                         expiration_year: Some(card.card_exp_year),
                         cvv: Some(card.card_cvc),
+                        external_mpi: item.request.three_ds_data.as_ref().map(|three_ds_data| {
+                            ExternalMpi {
+                                eci: three_ds_data.eci.clone(),
+                                cavv: three_ds_data.authentication_value.clone(),
+                                ds_trans_id: three_ds_data.ds_trans_id.clone(),
+                            }
+                        }),
                         ..Default::default()
                     }),
                     ..Default::default()
@@ -895,6 +920,7 @@ impl<F, T>
                         } else {
                             None
                         },
+                        verification_result: None,
                     }),
                 },
             },
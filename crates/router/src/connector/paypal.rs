@@ -95,6 +95,17 @@ impl Paypal {
     }
 }
 
+/// Whether the order is yet to be approved by the shopper, in which case there is no
+/// authorization or capture to be synced yet
+fn is_order_pending(paypal_meta: &PaypalMeta, connector_transaction_id: &str) -> bool {
+    match paypal_meta.psync_flow {
+        transformers::PaypalPaymentIntent::Authorize => paypal_meta.authorize_id.is_none(),
+        transformers::PaypalPaymentIntent::Capture => {
+            paypal_meta.order_id == connector_transaction_id
+        }
+    }
+}
+
 impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Paypal
 where
     Self: ConnectorIntegration<Flow, Request, Response>,
@@ -344,15 +355,32 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
         data: &types::PaymentsAuthorizeRouterData,
         res: Response,
     ) -> CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError> {
-        let response: paypal::PaypalOrdersResponse = res
-            .response
-            .parse_struct("Paypal PaymentsAuthorizeResponse")
-            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-        types::RouterData::try_from(types::ResponseRouterData {
-            response,
-            data: data.clone(),
-            http_code: res.status_code,
-        })
+        match data.request.payment_method_data {
+            api_models::payments::PaymentMethodData::Wallet(
+                api_models::payments::WalletData::PaypalRedirect(_),
+            ) => {
+                let response: paypal::PaypalRedirectResponse = res
+                    .response
+                    .parse_struct("paypal PaymentsRedirectResponse")
+                    .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+                types::RouterData::try_from(types::ResponseRouterData {
+                    response,
+                    data: data.clone(),
+                    http_code: res.status_code,
+                })
+            }
+            _ => {
+                let response: paypal::PaypalOrdersResponse = res
+                    .response
+                    .parse_struct("Paypal PaymentsAuthorizeResponse")
+                    .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+                types::RouterData::try_from(types::ResponseRouterData {
+                    response,
+                    data: data.clone(),
+                    http_code: res.status_code,
+                })
+            }
+        }
     }
 
     fn get_error_response(
@@ -370,6 +398,88 @@ impl
         types::PaymentsResponseData,
     > for Paypal
 {
+    fn get_headers(
+        &self,
+        req: &types::PaymentsCompleteAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsCompleteAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        // The order approved by the shopper is captured or authorized based on the capture
+        // method it was created with
+        let paypal_meta: PaypalMeta = to_connector_meta(req.request.connector_meta.clone())?;
+        let complete_authorize_url = match paypal_meta.psync_flow {
+            transformers::PaypalPaymentIntent::Authorize => "authorize",
+            transformers::PaypalPaymentIntent::Capture => "capture",
+        };
+        Ok(format!(
+            "{}v2/checkout/orders/{}/{}",
+            self.base_url(connectors),
+            paypal_meta.order_id,
+            complete_authorize_url
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        _req: &types::PaymentsCompleteAuthorizeRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        Ok(Some("{}".to_string()))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsCompleteAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsComeplteAuthorizeType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsComeplteAuthorizeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsComeplteAuthorizeType::get_request_body(
+                    self, req,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsCompleteAuthorizeRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsCompleteAuthorizeRouterData, errors::ConnectorError> {
+        let response: paypal::PaypalOrdersResponse = res
+            .response
+            .parse_struct("paypal PaymentsOrderResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.get_order_error_response(res)
+    }
 }
 
 impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
@@ -398,6 +508,13 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
             .get_connector_transaction_id()
             .change_context(errors::ConnectorError::MissingConnectorTransactionID)?;
         let paypal_meta: PaypalMeta = to_connector_meta(req.request.connector_meta.clone())?;
+        if is_order_pending(&paypal_meta, &capture_id) {
+            return Ok(format!(
+                "{}v2/checkout/orders/{}",
+                self.base_url(connectors),
+                paypal_meta.order_id
+            ));
+        }
         let psync_url = match paypal_meta.psync_flow {
             transformers::PaypalPaymentIntent::Authorize => format!(
                 "v2/payments/authorizations/{}",
@@ -429,6 +546,23 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
         data: &types::PaymentsSyncRouterData,
         res: Response,
     ) -> CustomResult<types::PaymentsSyncRouterData, errors::ConnectorError> {
+        let paypal_meta: PaypalMeta = to_connector_meta(data.request.connector_meta.clone())?;
+        let capture_id = data
+            .request
+            .connector_transaction_id
+            .get_connector_transaction_id()
+            .change_context(errors::ConnectorError::MissingConnectorTransactionID)?;
+        if is_order_pending(&paypal_meta, &capture_id) {
+            let response: paypal::PaypalRedirectResponse = res
+                .response
+                .parse_struct("paypal PaymentsRedirectResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+            return types::RouterData::try_from(types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            });
+        }
         let response: paypal::PaypalPaymentsSyncResponse = res
             .response
             .parse_struct("paypal PaymentsSyncResponse")
@@ -8,7 +8,7 @@ use crate::{
         PaymentsAuthorizeRequestData,
     },
     core::errors,
-    pii,
+    pii, services,
     types::{self, api, storage::enums as storage_enums, transformers::ForeignFrom},
 };
 
@@ -47,10 +47,36 @@ pub struct CardRequest {
     security_code: Option<Secret<String>>,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum UserAction {
+    PayNow,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ShippingPreference {
+    NoShipping,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExperienceContext {
+    return_url: Option<String>,
+    cancel_url: Option<String>,
+    user_action: UserAction,
+    shipping_preference: ShippingPreference,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalRedirectionRequest {
+    experience_context: ExperienceContext,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum PaymentSourceItem {
     Card(CardRequest),
+    Paypal(PaypalRedirectionRequest),
 }
 
 #[derive(Debug, Serialize)]
@@ -78,22 +104,22 @@ fn get_address_info(
 impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaypalPaymentsRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let intent = match item.request.is_auto_capture() {
+            true => PaypalPaymentIntent::Capture,
+            false => PaypalPaymentIntent::Authorize,
+        };
+        let amount = OrderAmount {
+            currency_code: item.request.currency,
+            value: item.request.amount.to_string(),
+        };
+        let reference_id = item.attempt_id.clone();
+        let purchase_units = vec![PurchaseUnitRequest {
+            reference_id,
+            amount,
+        }];
+
         match item.request.payment_method_data {
             api_models::payments::PaymentMethodData::Card(ref ccard) => {
-                let intent = match item.request.is_auto_capture() {
-                    true => PaypalPaymentIntent::Capture,
-                    false => PaypalPaymentIntent::Authorize,
-                };
-                let amount = OrderAmount {
-                    currency_code: item.request.currency,
-                    value: item.request.amount.to_string(),
-                };
-                let reference_id = item.attempt_id.clone();
-
-                let purchase_units = vec![PurchaseUnitRequest {
-                    reference_id,
-                    amount,
-                }];
                 let card = item.request.get_card()?;
                 let expiry = Some(card.get_expiry_date_as_yyyymm("-"));
 
@@ -111,6 +137,26 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaypalPaymentsRequest {
                     payment_source,
                 })
             }
+            api_models::payments::PaymentMethodData::Wallet(
+                api_models::payments::WalletData::PaypalRedirect(_),
+            ) => {
+                // The shopper is sent back to the router once the order is approved or cancelled,
+                // and the order is then captured or authorized in the complete authorize flow
+                let payment_source = Some(PaymentSourceItem::Paypal(PaypalRedirectionRequest {
+                    experience_context: ExperienceContext {
+                        return_url: item.request.complete_authorize_url.clone(),
+                        cancel_url: item.request.complete_authorize_url.clone(),
+                        user_action: UserAction::PayNow,
+                        shipping_preference: ShippingPreference::NoShipping,
+                    },
+                }));
+
+                Ok(Self {
+                    intent,
+                    purchase_units,
+                    payment_source,
+                })
+            }
             _ => Err(errors::ConnectorError::NotImplemented("Payment Method".to_string()).into()),
         }
     }
@@ -235,6 +281,20 @@ pub struct PaypalOrdersResponse {
     purchase_units: Vec<PurchaseUnitItem>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaypalLinks {
+    href: Option<url::Url>,
+    rel: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaypalRedirectResponse {
+    id: String,
+    intent: PaypalPaymentIntent,
+    status: PaypalOrderStatus,
+    links: Vec<PaypalLinks>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PaypalPaymentsSyncResponse {
     id: String,
@@ -322,6 +382,62 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: Some(connector_meta),
+                verification_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+fn get_redirect_url(
+    item: &PaypalRedirectResponse,
+) -> CustomResult<Option<url::Url>, errors::ConnectorError> {
+    let link = item
+        .links
+        .iter()
+        .find(|link| link.rel == "payer-action")
+        .ok_or(errors::ConnectorError::MissingRequiredField {
+            field_name: "links.payer-action",
+        })?;
+    Ok(link.href.clone())
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, PaypalRedirectResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, PaypalRedirectResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let (status, redirection_data) = match item.response.status {
+            PaypalOrderStatus::PayerActionRequired => (
+                storage_enums::AttemptStatus::AuthenticationPending,
+                get_redirect_url(&item.response)?
+                    .map(|url| services::RedirectForm::from((url, services::Method::Get))),
+            ),
+            _ => (
+                storage_enums::AttemptStatus::foreign_from((
+                    item.response.status.clone(),
+                    item.response.intent.clone(),
+                )),
+                None,
+            ),
+        };
+        let connector_meta = serde_json::json!(PaypalMeta {
+            authorize_id: None,
+            order_id: item.response.id.clone(),
+            psync_flow: item.response.intent
+        });
+
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata: Some(connector_meta),
+                verification_result: None,
             }),
             ..item.data
         })
@@ -349,6 +465,7 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
@@ -436,6 +553,7 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
                     order_id: item.data.request.connector_transaction_id.clone(),
                     psync_flow: PaypalPaymentIntent::Capture
                 })),
+                verification_result: None,
             }),
             amount_captured: Some(amount_captured),
             ..item.data
@@ -481,6 +599,7 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
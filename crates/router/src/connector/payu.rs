@@ -7,6 +7,8 @@ use transformers as payu;
 
 use crate::{
     configs::settings,
+    connector::utils::PaymentsAuthorizeRequestData,
+    consts,
     core::errors::{self, CustomResult},
     headers,
     services::{self, ConnectorIntegration},
@@ -21,6 +23,30 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct Payu;
 
+impl Payu {
+    fn get_latam_payments_url(&self, connectors: &settings::Connectors) -> String {
+        format!(
+            "{}{}",
+            connectors.payu.base_url_latam, "payments-api/4.0/service.cgi"
+        )
+    }
+
+    fn get_latam_reports_url(&self, connectors: &settings::Connectors) -> String {
+        format!(
+            "{}{}",
+            connectors.payu.base_url_latam, "reports-api/4.0/service.cgi"
+        )
+    }
+}
+
+fn encode_latam_request(
+    req: payu::PayuLatamRequest,
+) -> CustomResult<Option<String>, errors::ConnectorError> {
+    let payu_req = utils::Encode::<payu::PayuLatamRequest>::encode_to_string_of_json(&req)
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+    Ok(Some(payu_req))
+}
+
 impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Payu
 where
     Self: ConnectorIntegration<Flow, Request, Response>,
@@ -34,6 +60,14 @@ where
             headers::CONTENT_TYPE.to_string(),
             self.get_content_type().to_string(),
         )];
+        // The credentials are sent in the body of the requests to PayU LATAM
+        if payu::get_region(req)? == payu::PayuRegion::Latam {
+            headers.push((
+                headers::ACCEPT.to_string(),
+                self.common_get_content_type().to_string(),
+            ));
+            return Ok(headers);
+        }
         let access_token = req
             .access_token
             .clone()
@@ -76,17 +110,27 @@ impl ConnectorCommon for Payu {
         &self,
         res: types::Response,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        let response: payu::PayuErrorResponse = res
+        let response: payu::PayuRegionalErrorResponse = res
             .response
             .parse_struct("Payu ErrorResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
 
-        Ok(ErrorResponse {
-            status_code: res.status_code,
-            code: response.status.status_code,
-            message: response.status.status_desc,
-            reason: response.status.code_literal,
-        })
+        match response {
+            payu::PayuRegionalErrorResponse::Europe(response) => Ok(ErrorResponse {
+                status_code: res.status_code,
+                code: response.status.status_code,
+                message: response.status.status_desc,
+                reason: response.status.code_literal,
+            }),
+            payu::PayuRegionalErrorResponse::Latam(response) => Ok(ErrorResponse {
+                status_code: res.status_code,
+                code: consts::NO_ERROR_CODE.to_string(),
+                message: response
+                    .error
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: None,
+            }),
+        }
     }
 }
 
@@ -120,24 +164,45 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
         req: &types::PaymentsCancelRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        let connector_payment_id = &req.request.connector_transaction_id;
-        Ok(format!(
-            "{}{}{}",
-            self.base_url(connectors),
-            "api/v2_1/orders/",
-            connector_payment_id
-        ))
+        match payu::get_region(req)? {
+            payu::PayuRegion::Europe => {
+                let connector_payment_id = &req.request.connector_transaction_id;
+                Ok(format!(
+                    "{}{}{}",
+                    self.base_url(connectors),
+                    "api/v2_1/orders/",
+                    connector_payment_id
+                ))
+            }
+            payu::PayuRegion::Latam => Ok(self.get_latam_payments_url(connectors)),
+        }
     }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsCancelRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        match payu::get_region(req)? {
+            payu::PayuRegion::Europe => Ok(None),
+            payu::PayuRegion::Latam => encode_latam_request(payu::PayuLatamRequest::try_from(req)?),
+        }
+    }
+
     fn build_request(
         &self,
         req: &types::PaymentsCancelRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let method = match payu::get_region(req)? {
+            payu::PayuRegion::Europe => services::Method::Delete,
+            payu::PayuRegion::Latam => services::Method::Post,
+        };
         let request = services::RequestBuilder::new()
-            .method(services::Method::Delete)
+            .method(method)
             .url(&types::PaymentsVoidType::get_url(self, req, connectors)?)
             .attach_default_headers()
             .headers(types::PaymentsVoidType::get_headers(self, req, connectors)?)
+            .body(types::PaymentsVoidType::get_request_body(self, req)?)
             .build();
         Ok(Some(request))
     }
@@ -146,6 +211,20 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
         data: &types::PaymentsCancelRouterData,
         res: types::Response,
     ) -> CustomResult<types::PaymentsCancelRouterData, errors::ConnectorError> {
+        if payu::get_region(data)? == payu::PayuRegion::Latam {
+            let response: payu::PayuLatamPaymentsResponse = res
+                .response
+                .parse_struct("PayuLatamPaymentsResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+            return types::RouterData::try_from((
+                types::ResponseRouterData {
+                    response,
+                    data: data.clone(),
+                    http_code: res.status_code,
+                },
+                payu::PayuLatamTransactionType::Void,
+            ));
+        }
         let response: payu::PayuPaymentsCancelResponse = res
             .response
             .parse_struct("PaymentCancelResponse")
@@ -172,14 +251,17 @@ impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, t
 {
     fn get_url(
         &self,
-        _req: &types::RefreshTokenRouterData,
+        req: &types::RefreshTokenRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Ok(format!(
-            "{}{}",
-            self.base_url(connectors),
-            "pl/standard/user/oauth/authorize"
-        ))
+        match payu::get_region(req)? {
+            payu::PayuRegion::Europe => Ok(format!(
+                "{}{}",
+                self.base_url(connectors),
+                "pl/standard/user/oauth/authorize"
+            )),
+            payu::PayuRegion::Latam => Ok(self.get_latam_payments_url(connectors)),
+        }
     }
 
     fn get_content_type(&self) -> &'static str {
@@ -188,19 +270,35 @@ impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, t
 
     fn get_headers(
         &self,
-        _req: &types::RefreshTokenRouterData,
+        req: &types::RefreshTokenRouterData,
         _connectors: &settings::Connectors,
     ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
-        Ok(vec![(
-            headers::CONTENT_TYPE.to_string(),
-            types::RefreshTokenType::get_content_type(self).to_string(),
-        )])
+        match payu::get_region(req)? {
+            payu::PayuRegion::Europe => Ok(vec![(
+                headers::CONTENT_TYPE.to_string(),
+                types::RefreshTokenType::get_content_type(self).to_string(),
+            )]),
+            payu::PayuRegion::Latam => Ok(vec![
+                (
+                    headers::CONTENT_TYPE.to_string(),
+                    self.common_get_content_type().to_string(),
+                ),
+                (
+                    headers::ACCEPT.to_string(),
+                    self.common_get_content_type().to_string(),
+                ),
+            ]),
+        }
     }
 
     fn get_request_body(
         &self,
         req: &types::RefreshTokenRouterData,
     ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        // PayU LATAM has no access tokens, a ping validates the credentials instead
+        if payu::get_region(req)? == payu::PayuRegion::Latam {
+            return encode_latam_request(payu::PayuLatamRequest::try_from(req)?);
+        }
         let payu_req = utils::Encode::<payu::PayuAuthUpdateRequest>::convert_and_url_encode(req)
             .change_context(errors::ConnectorError::RequestEncodingFailed)?;
 
@@ -229,6 +327,17 @@ impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, t
         data: &types::RefreshTokenRouterData,
         res: types::Response,
     ) -> CustomResult<types::RefreshTokenRouterData, errors::ConnectorError> {
+        if payu::get_region(data)? == payu::PayuRegion::Latam {
+            let response: payu::PayuLatamPaymentsResponse = res
+                .response
+                .parse_struct("PayuLatamPaymentsResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+            return types::RouterData::try_from(types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            });
+        }
         let response: payu::PayuAuthUpdateResponse = res
             .response
             .parse_struct("payu PayuAuthUpdateResponse")
@@ -282,6 +391,9 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
         req: &types::PaymentsSyncRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
+        if payu::get_region(req)? == payu::PayuRegion::Latam {
+            return Ok(self.get_latam_reports_url(connectors));
+        }
         let connector_payment_id = req
             .request
             .connector_transaction_id
@@ -295,17 +407,32 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
         ))
     }
 
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        match payu::get_region(req)? {
+            payu::PayuRegion::Europe => Ok(None),
+            payu::PayuRegion::Latam => encode_latam_request(payu::PayuLatamRequest::try_from(req)?),
+        }
+    }
+
     fn build_request(
         &self,
         req: &types::PaymentsSyncRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let method = match payu::get_region(req)? {
+            payu::PayuRegion::Europe => services::Method::Get,
+            payu::PayuRegion::Latam => services::Method::Post,
+        };
         Ok(Some(
             services::RequestBuilder::new()
-                .method(services::Method::Get)
+                .method(method)
                 .url(&types::PaymentsSyncType::get_url(self, req, connectors)?)
                 .attach_default_headers()
                 .headers(types::PaymentsSyncType::get_headers(self, req, connectors)?)
+                .body(types::PaymentsSyncType::get_request_body(self, req)?)
                 .build(),
         ))
     }
@@ -315,6 +442,17 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
         data: &types::PaymentsSyncRouterData,
         res: types::Response,
     ) -> CustomResult<types::PaymentsSyncRouterData, errors::ConnectorError> {
+        if payu::get_region(data)? == payu::PayuRegion::Latam {
+            let response: payu::PayuLatamQueryResponse<payu::PayuLatamOrderDetail> = res
+                .response
+                .parse_struct("PayuLatamOrderDetailResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+            return types::RouterData::try_from(types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            });
+        }
         let response: payu::PayuPaymentsSyncResponse = res
             .response
             .parse_struct("payu OrderResponse")
@@ -357,19 +495,25 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
         req: &types::PaymentsCaptureRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Ok(format!(
-            "{}{}{}{}",
-            self.base_url(connectors),
-            "api/v2_1/orders/",
-            req.request.connector_transaction_id,
-            "/status"
-        ))
+        match payu::get_region(req)? {
+            payu::PayuRegion::Europe => Ok(format!(
+                "{}{}{}{}",
+                self.base_url(connectors),
+                "api/v2_1/orders/",
+                req.request.connector_transaction_id,
+                "/status"
+            )),
+            payu::PayuRegion::Latam => Ok(self.get_latam_payments_url(connectors)),
+        }
     }
 
     fn get_request_body(
         &self,
         req: &types::PaymentsCaptureRouterData,
     ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        if payu::get_region(req)? == payu::PayuRegion::Latam {
+            return encode_latam_request(payu::PayuLatamRequest::try_from(req)?);
+        }
         let connector_req = payu::PayuPaymentsCaptureRequest::try_from(req)?;
         let payu_req = utils::Encode::<payu::PayuPaymentsCaptureRequest>::encode_to_string_of_json(
             &connector_req,
@@ -383,9 +527,13 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
         req: &types::PaymentsCaptureRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let method = match payu::get_region(req)? {
+            payu::PayuRegion::Europe => services::Method::Put,
+            payu::PayuRegion::Latam => services::Method::Post,
+        };
         Ok(Some(
             services::RequestBuilder::new()
-                .method(services::Method::Put)
+                .method(method)
                 .url(&types::PaymentsCaptureType::get_url(self, req, connectors)?)
                 .attach_default_headers()
                 .headers(types::PaymentsCaptureType::get_headers(
@@ -401,6 +549,20 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
         data: &types::PaymentsCaptureRouterData,
         res: types::Response,
     ) -> CustomResult<types::PaymentsCaptureRouterData, errors::ConnectorError> {
+        if payu::get_region(data)? == payu::PayuRegion::Latam {
+            let response: payu::PayuLatamPaymentsResponse = res
+                .response
+                .parse_struct("PayuLatamPaymentsResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+            return types::RouterData::try_from((
+                types::ResponseRouterData {
+                    response,
+                    data: data.clone(),
+                    http_code: res.status_code,
+                },
+                payu::PayuLatamTransactionType::Capture,
+            ));
+        }
         let response: payu::PayuPaymentsCaptureResponse = res
             .response
             .parse_struct("payu CaptureResponse")
@@ -449,20 +611,26 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
 
     fn get_url(
         &self,
-        _req: &types::PaymentsAuthorizeRouterData,
+        req: &types::PaymentsAuthorizeRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Ok(format!(
-            "{}{}",
-            self.base_url(connectors),
-            "api/v2_1/orders"
-        ))
+        match payu::get_region(req)? {
+            payu::PayuRegion::Europe => Ok(format!(
+                "{}{}",
+                self.base_url(connectors),
+                "api/v2_1/orders"
+            )),
+            payu::PayuRegion::Latam => Ok(self.get_latam_payments_url(connectors)),
+        }
     }
 
     fn get_request_body(
         &self,
         req: &types::PaymentsAuthorizeRouterData,
     ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        if payu::get_region(req)? == payu::PayuRegion::Latam {
+            return encode_latam_request(payu::PayuLatamRequest::try_from(req)?);
+        }
         let connector_req = payu::PayuPaymentsRequest::try_from(req)?;
         let payu_req =
             utils::Encode::<payu::PayuPaymentsRequest>::encode_to_string_of_json(&connector_req)
@@ -499,6 +667,20 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
         data: &types::PaymentsAuthorizeRouterData,
         res: types::Response,
     ) -> CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        if payu::get_region(data)? == payu::PayuRegion::Latam {
+            let response: payu::PayuLatamPaymentsResponse = res
+                .response
+                .parse_struct("PayuLatamPaymentsResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+            return types::RouterData::try_from((
+                types::ResponseRouterData {
+                    response,
+                    data: data.clone(),
+                    http_code: res.status_code,
+                },
+                payu::PayuLatamTransactionType::for_authorize(data.request.is_auto_capture()),
+            ));
+        }
         let response: payu::PayuPaymentsResponse = res
             .response
             .parse_struct("PayuPaymentsResponse")
@@ -542,19 +724,25 @@ impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsRespon
         req: &types::RefundsRouterData<api::Execute>,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Ok(format!(
-            "{}{}{}{}",
-            self.base_url(connectors),
-            "api/v2_1/orders/",
-            req.request.connector_transaction_id,
-            "/refund"
-        ))
+        match payu::get_region(req)? {
+            payu::PayuRegion::Europe => Ok(format!(
+                "{}{}{}{}",
+                self.base_url(connectors),
+                "api/v2_1/orders/",
+                req.request.connector_transaction_id,
+                "/refund"
+            )),
+            payu::PayuRegion::Latam => Ok(self.get_latam_payments_url(connectors)),
+        }
     }
 
     fn get_request_body(
         &self,
         req: &types::RefundsRouterData<api::Execute>,
     ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        if payu::get_region(req)? == payu::PayuRegion::Latam {
+            return encode_latam_request(payu::PayuLatamRequest::try_from(req)?);
+        }
         let connector_req = payu::PayuRefundRequest::try_from(req)?;
         let payu_req =
             utils::Encode::<payu::PayuRefundRequest>::encode_to_string_of_json(&connector_req)
@@ -584,6 +772,17 @@ impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsRespon
         data: &types::RefundsRouterData<api::Execute>,
         res: types::Response,
     ) -> CustomResult<types::RefundsRouterData<api::Execute>, errors::ConnectorError> {
+        if payu::get_region(data)? == payu::PayuRegion::Latam {
+            let response: payu::PayuLatamPaymentsResponse = res
+                .response
+                .parse_struct("PayuLatamPaymentsResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+            return types::RouterData::try_from(types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            });
+        }
         let response: payu::RefundResponse = res
             .response
             .parse_struct("payu RefundResponse")
@@ -623,13 +822,26 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
         req: &types::RefundSyncRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Ok(format!(
-            "{}{}{}{}",
-            self.base_url(connectors),
-            "api/v2_1/orders/",
-            req.request.connector_transaction_id,
-            "/refunds"
-        ))
+        match payu::get_region(req)? {
+            payu::PayuRegion::Europe => Ok(format!(
+                "{}{}{}{}",
+                self.base_url(connectors),
+                "api/v2_1/orders/",
+                req.request.connector_transaction_id,
+                "/refunds"
+            )),
+            payu::PayuRegion::Latam => Ok(self.get_latam_reports_url(connectors)),
+        }
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::RefundSyncRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        match payu::get_region(req)? {
+            payu::PayuRegion::Europe => Ok(None),
+            payu::PayuRegion::Latam => encode_latam_request(payu::PayuLatamRequest::try_from(req)?),
+        }
     }
 
     fn build_request(
@@ -637,12 +849,17 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
         req: &types::RefundsRouterData<api::RSync>,
         connectors: &settings::Connectors,
     ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let method = match payu::get_region(req)? {
+            payu::PayuRegion::Europe => services::Method::Get,
+            payu::PayuRegion::Latam => services::Method::Post,
+        };
         Ok(Some(
             services::RequestBuilder::new()
-                .method(services::Method::Get)
+                .method(method)
                 .url(&types::RefundSyncType::get_url(self, req, connectors)?)
                 .attach_default_headers()
                 .headers(types::RefundSyncType::get_headers(self, req, connectors)?)
+                .body(types::RefundSyncType::get_request_body(self, req)?)
                 .build(),
         ))
     }
@@ -652,6 +869,17 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
         data: &types::RefundSyncRouterData,
         res: types::Response,
     ) -> CustomResult<types::RefundSyncRouterData, errors::ConnectorError> {
+        if payu::get_region(data)? == payu::PayuRegion::Latam {
+            let response: payu::PayuLatamQueryResponse<payu::PayuLatamTransactionDetail> = res
+                .response
+                .parse_struct("PayuLatamTransactionDetailResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+            return types::RouterData::try_from(types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            });
+        }
         let response: payu::RefundSyncResponse =
             res.response
                 .parse_struct("payu RefundResponse")
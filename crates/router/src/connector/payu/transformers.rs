@@ -1,16 +1,67 @@
 use base64::Engine;
+use common_utils::crypto::{self, GenerateDigest};
 use error_stack::{IntoReport, ResultExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::AccessTokenRequestInfo,
+    connector::utils::{
+        self, AccessTokenRequestInfo, CardData, CardIssuer, PaymentsAuthorizeRequestData,
+        RefundsRequestData, RouterData,
+    },
     consts,
     core::errors,
     pii::{self, Secret},
-    types::{self, api, storage::enums},
+    types::{self, api, storage::enums, transformers::ForeignFrom},
 };
 
+type Error = error_stack::Report<errors::ConnectorError>;
+
 const WALLET_IDENTIFIER: &str = "PBL";
+const BLIK_IDENTIFIER: &str = "BLIK_AUTHORIZATION_CODE";
+const LATAM_LANGUAGE: &str = "en";
+// PayU LATAM does not issue access tokens, the ping only validates the credentials of the merchant
+const LATAM_CREDENTIALS_VALIDITY: i64 = 3600;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PayuRegion {
+    // Orders API of PayU GPO, used in Poland, Czech Republic and the rest of Europe
+    #[default]
+    Europe,
+    // Payments API of PayU Latam
+    Latam,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct PayuConnectorMetadata {
+    #[serde(default)]
+    pub region: PayuRegion,
+    // The account of the merchant in the processing country, required in the LATAM region
+    pub account_id: Option<String>,
+}
+
+impl PayuConnectorMetadata {
+    fn get_account_id(&self) -> Result<String, Error> {
+        self.account_id
+            .clone()
+            .ok_or_else(utils::missing_field_err("connector_meta_data.account_id"))
+    }
+}
+
+fn get_connector_metadata<F, Req, Res>(
+    item: &types::RouterData<F, Req, Res>,
+) -> Result<PayuConnectorMetadata, Error> {
+    match item.connector_meta_data {
+        Some(_) => item.to_connector_meta(),
+        None => Ok(PayuConnectorMetadata::default()),
+    }
+}
+
+/// The region of the merchant connector account, which decides the API used for the payment.
+/// Accounts without connector metadata are processed in Europe.
+pub fn get_region<F, Req, Res>(item: &types::RouterData<F, Req, Res>) -> Result<PayuRegion, Error> {
+    Ok(get_connector_metadata(item)?.region)
+}
 
 #[derive(Debug, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -35,6 +86,7 @@ pub struct PayuPaymentMethod {
 pub enum PayuPaymentMethodData {
     Card(PayuCard),
     Wallet(PayuWallet),
+    Blik(PayuBlik),
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -57,6 +109,15 @@ pub struct PayuWallet {
     pub wallet_type: String,
     pub authorization_code: String,
 }
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuBlik {
+    #[serde(rename = "type")]
+    pub blik_type: String,
+    pub authorization_code: Secret<String>,
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum PayuWalletCode {
@@ -101,6 +162,14 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PayuPaymentsRequest {
                     "Unknown Wallet in Payment Method".to_string(),
                 )),
             },
+            api::PaymentMethodData::BankRedirect(
+                api_models::payments::BankRedirectData::Blik { blik_code },
+            ) => Ok(PayuPaymentMethod {
+                pay_method: PayuPaymentMethodData::Blik(PayuBlik {
+                    blik_type: BLIK_IDENTIFIER.to_string(),
+                    authorization_code: blik_code,
+                }),
+            }),
             _ => Err(errors::ConnectorError::NotImplemented(
                 "Unknown payment method".to_string(),
             )),
@@ -117,7 +186,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PayuPaymentsRequest {
                 },
             )?,
             merchant_pos_id: auth_type.merchant_pos_id,
-            total_amount: item.request.amount,
+            total_amount: item.request.amount.get_amount_as_i64(),
             currency_code: item.request.currency,
             description: item.description.clone().ok_or(
                 errors::ConnectorError::MissingRequiredField {
@@ -176,7 +245,7 @@ impl From<PayuPaymentStatus> for enums::AttemptStatus {
 #[serde(rename_all = "camelCase")]
 pub struct PayuPaymentsResponse {
     pub status: PayuPaymentStatusData,
-    pub redirect_uri: String,
+    pub redirect_uri: Option<String>,
     pub iframe_allowed: Option<bool>,
     pub three_ds_protocol_version: Option<String>,
     pub order_id: String,
@@ -198,6 +267,7 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             amount_captured: None,
             ..item.data
@@ -248,6 +318,7 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             amount_captured: None,
             ..item.data
@@ -326,6 +397,7 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             amount_captured: None,
             ..item.data
@@ -454,6 +526,7 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             amount_captured: Some(
                 order
@@ -597,3 +670,850 @@ pub struct PayuAccessTokenErrorResponse {
     pub error: String,
     pub error_description: String,
 }
+
+// Type definitions for the Payments API of PayU LATAM
+
+pub struct PayuLatamAuthType {
+    pub(super) api_key: String,
+    pub(super) api_login: String,
+    pub(super) merchant_id: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for PayuLatamAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::SignatureKey {
+                api_key,
+                key1,
+                api_secret,
+            } => Ok(Self {
+                api_key: api_key.to_string(),
+                api_login: key1.to_string(),
+                merchant_id: api_secret.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType)?,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuLatamMerchant {
+    api_key: String,
+    api_login: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PayuLatamCommand {
+    Ping,
+    SubmitTransaction,
+    OrderDetail,
+    TransactionResponseDetail,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuLatamRequest {
+    language: String,
+    command: PayuLatamCommand,
+    merchant: PayuLatamMerchant,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transaction: Option<Box<PayuLatamTransaction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<PayuLatamQueryDetails>,
+    test: bool,
+}
+
+impl PayuLatamRequest {
+    fn new(command: PayuLatamCommand, auth_type: &types::ConnectorAuthType) -> Result<Self, Error> {
+        let auth = PayuLatamAuthType::try_from(auth_type)?;
+        Ok(Self {
+            language: LATAM_LANGUAGE.to_string(),
+            command,
+            merchant: PayuLatamMerchant {
+                api_key: auth.api_key,
+                api_login: auth.api_login,
+            },
+            transaction: None,
+            details: None,
+            test: false,
+        })
+    }
+
+    fn with_transaction(mut self, transaction: PayuLatamTransaction) -> Self {
+        self.transaction = Some(Box::new(transaction));
+        self
+    }
+
+    fn with_details(mut self, details: PayuLatamQueryDetails) -> Self {
+        self.details = Some(details);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PayuLatamTransactionType {
+    Authorization,
+    AuthorizationAndCapture,
+    Capture,
+    Void,
+    Refund,
+}
+
+impl PayuLatamTransactionType {
+    pub fn for_authorize(is_auto_capture: bool) -> Self {
+        if is_auto_capture {
+            Self::AuthorizationAndCapture
+        } else {
+            Self::Authorization
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuLatamTransaction {
+    order: PayuLatamOrder,
+    #[serde(rename = "type")]
+    transaction_type: PayuLatamTransactionType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payer: Option<PayuLatamPayer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    credit_card: Option<PayuLatamCard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payment_method: Option<PayuLatamCardNetwork>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payment_country: Option<api_models::enums::CountryCode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_transaction_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    additional_values: Option<PayuLatamAdditionalValues>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ip_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_agent: Option<String>,
+}
+
+impl PayuLatamTransaction {
+    // Transactions on an existing order, which only refer to the order and its authorization
+    fn follow_up(
+        order_id: i64,
+        transaction_type: PayuLatamTransactionType,
+        parent_transaction_id: String,
+    ) -> Self {
+        Self {
+            order: PayuLatamOrder::Existing { id: order_id },
+            transaction_type,
+            payer: None,
+            credit_card: None,
+            payment_method: None,
+            payment_country: None,
+            parent_transaction_id: Some(parent_transaction_id),
+            additional_values: None,
+            reason: None,
+            ip_address: None,
+            user_agent: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum PayuLatamOrder {
+    New(Box<PayuLatamNewOrder>),
+    Existing { id: i64 },
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuLatamNewOrder {
+    account_id: String,
+    reference_code: String,
+    description: String,
+    language: String,
+    signature: String,
+    additional_values: PayuLatamAdditionalValues,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    buyer: Option<PayuLatamBuyer>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PayuLatamAdditionalValues {
+    #[serde(rename = "TX_VALUE")]
+    tx_value: PayuLatamAmount,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PayuLatamAmount {
+    value: String,
+    currency: enums::Currency,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuLatamBuyer {
+    email_address: Secret<String, pii::Email>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuLatamPayer {
+    full_name: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email_address: Option<Secret<String, pii::Email>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuLatamCard {
+    number: Secret<String, pii::CardNumber>,
+    security_code: Secret<String>,
+    expiration_date: Secret<String>,
+    name: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PayuLatamCardNetwork {
+    Visa,
+    Mastercard,
+    Amex,
+    Discover,
+}
+
+impl TryFrom<CardIssuer> for PayuLatamCardNetwork {
+    type Error = Error;
+    fn try_from(issuer: CardIssuer) -> Result<Self, Self::Error> {
+        match issuer {
+            CardIssuer::Visa => Ok(Self::Visa),
+            CardIssuer::Master => Ok(Self::Mastercard),
+            CardIssuer::AmericanExpress => Ok(Self::Amex),
+            CardIssuer::Discover => Ok(Self::Discover),
+            CardIssuer::Maestro => Err(errors::ConnectorError::NotSupported {
+                payment_method: api_models::enums::PaymentMethod::Card.to_string(),
+                connector: "payu",
+                payment_experience: api_models::enums::PaymentExperience::RedirectToUrl.to_string(),
+            }
+            .into()),
+        }
+    }
+}
+
+// The signature of a new order, md5 of `api_key~merchant_id~reference_code~value~currency`
+fn get_latam_signature(
+    auth: &PayuLatamAuthType,
+    reference_code: &str,
+    amount: &PayuLatamAmount,
+) -> Result<String, Error> {
+    let message = format!(
+        "{}~{}~{}~{}~{}",
+        auth.api_key, auth.merchant_id, reference_code, amount.value, amount.currency
+    );
+    let digest = crypto::Md5
+        .generate_digest(message.as_bytes())
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+    Ok(hex::encode(digest))
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuLatamQueryDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transaction_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuLatamConnectorMetadata {
+    pub order_id: i64,
+}
+
+impl TryFrom<&types::RefreshTokenRouterData> for PayuLatamRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefreshTokenRouterData) -> Result<Self, Self::Error> {
+        Self::new(PayuLatamCommand::Ping, &item.connector_auth_type)
+    }
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for PayuLatamRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let card = match item.request.payment_method_data {
+            api::PaymentMethodData::Card(ref card) => Ok(card),
+            _ => Err(errors::ConnectorError::NotImplemented(
+                "Payment methods".to_string(),
+            )),
+        }?;
+        let auth = PayuLatamAuthType::try_from(&item.connector_auth_type)?;
+        let metadata = get_connector_metadata(item)?;
+        let amount = PayuLatamAmount {
+            value: utils::to_currency_base_unit(item.request.amount, item.request.currency)?,
+            currency: item.request.currency,
+        };
+        let reference_code = item.attempt_id.clone();
+        let signature = get_latam_signature(&auth, &reference_code, &amount)?;
+        let email = item.request.email.clone();
+        let browser_info = item.request.browser_info.as_ref();
+        let transaction = PayuLatamTransaction {
+            order: PayuLatamOrder::New(Box::new(PayuLatamNewOrder {
+                account_id: metadata.get_account_id()?,
+                reference_code,
+                description: item.get_description()?,
+                language: LATAM_LANGUAGE.to_string(),
+                signature,
+                additional_values: PayuLatamAdditionalValues { tx_value: amount },
+                buyer: email
+                    .clone()
+                    .map(|email_address| PayuLatamBuyer { email_address }),
+            })),
+            transaction_type: PayuLatamTransactionType::for_authorize(
+                item.request.is_auto_capture(),
+            ),
+            payer: Some(PayuLatamPayer {
+                full_name: card.card_holder_name.clone(),
+                email_address: email,
+            }),
+            credit_card: Some(PayuLatamCard {
+                number: card.card_number.clone(),
+                security_code: card.card_cvc.clone(),
+                expiration_date: card.get_expiry_date_as_yyyymm("/"),
+                name: card.card_holder_name.clone(),
+            }),
+            payment_method: Some(PayuLatamCardNetwork::try_from(card.get_card_issuer()?)?),
+            payment_country: Some(item.get_billing_country()?),
+            parent_transaction_id: None,
+            additional_values: None,
+            reason: None,
+            ip_address: browser_info
+                .and_then(|browser_info| browser_info.ip_address)
+                .map(|ip_address| ip_address.to_string()),
+            user_agent: browser_info.map(|browser_info| browser_info.user_agent.clone()),
+        };
+        Ok(Self::new(
+            PayuLatamCommand::SubmitTransaction,
+            &item.connector_auth_type,
+        )?
+        .with_transaction(transaction))
+    }
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for PayuLatamRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        let metadata: PayuLatamConnectorMetadata =
+            utils::to_connector_meta(item.request.connector_meta.clone())?;
+        let transaction = PayuLatamTransaction::follow_up(
+            metadata.order_id,
+            PayuLatamTransactionType::Capture,
+            item.request.connector_transaction_id.clone(),
+        );
+        Ok(Self::new(
+            PayuLatamCommand::SubmitTransaction,
+            &item.connector_auth_type,
+        )?
+        .with_transaction(transaction))
+    }
+}
+
+impl TryFrom<&types::PaymentsCancelRouterData> for PayuLatamRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        let metadata: PayuLatamConnectorMetadata =
+            utils::to_connector_meta(item.request.connector_meta.clone())?;
+        let transaction = PayuLatamTransaction {
+            reason: item.request.cancellation_reason.clone(),
+            ..PayuLatamTransaction::follow_up(
+                metadata.order_id,
+                PayuLatamTransactionType::Void,
+                item.request.connector_transaction_id.clone(),
+            )
+        };
+        Ok(Self::new(
+            PayuLatamCommand::SubmitTransaction,
+            &item.connector_auth_type,
+        )?
+        .with_transaction(transaction))
+    }
+}
+
+impl TryFrom<&types::PaymentsSyncRouterData> for PayuLatamRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        let metadata: PayuLatamConnectorMetadata =
+            utils::to_connector_meta(item.request.connector_meta.clone())?;
+        Ok(
+            Self::new(PayuLatamCommand::OrderDetail, &item.connector_auth_type)?.with_details(
+                PayuLatamQueryDetails {
+                    order_id: Some(metadata.order_id),
+                    ..Default::default()
+                },
+            ),
+        )
+    }
+}
+
+impl TryFrom<&types::RefundsRouterData<api::Execute>> for PayuLatamRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<api::Execute>) -> Result<Self, Self::Error> {
+        let metadata: PayuLatamConnectorMetadata =
+            utils::to_connector_meta(item.request.connector_metadata.clone())?;
+        // The amount is only sent for partial refunds
+        let additional_values = if item.request.refund_amount < item.request.amount {
+            Some(PayuLatamAdditionalValues {
+                tx_value: PayuLatamAmount {
+                    value: utils::to_currency_base_unit(
+                        types::MinorUnit::new(item.request.refund_amount),
+                        item.request.currency,
+                    )?,
+                    currency: item.request.currency,
+                },
+            })
+        } else {
+            None
+        };
+        let transaction = PayuLatamTransaction {
+            reason: Some(
+                item.request
+                    .reason
+                    .clone()
+                    .unwrap_or_else(|| format!("Refund {}", item.request.refund_id)),
+            ),
+            additional_values,
+            ..PayuLatamTransaction::follow_up(
+                metadata.order_id,
+                PayuLatamTransactionType::Refund,
+                item.request.connector_transaction_id.clone(),
+            )
+        };
+        Ok(Self::new(
+            PayuLatamCommand::SubmitTransaction,
+            &item.connector_auth_type,
+        )?
+        .with_transaction(transaction))
+    }
+}
+
+impl TryFrom<&types::RefundSyncRouterData> for PayuLatamRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundSyncRouterData) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            PayuLatamCommand::TransactionResponseDetail,
+            &item.connector_auth_type,
+        )?
+        .with_details(PayuLatamQueryDetails {
+            transaction_id: Some(item.request.get_connector_refund_id()?),
+            ..Default::default()
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PayuLatamResponseCode {
+    Success,
+    Error,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PayuLatamTransactionState {
+    Approved,
+    Declined,
+    Error,
+    Expired,
+    Pending,
+    Submitted,
+}
+
+impl ForeignFrom<(PayuLatamTransactionState, PayuLatamTransactionType)> for enums::AttemptStatus {
+    fn foreign_from(
+        (state, transaction_type): (PayuLatamTransactionState, PayuLatamTransactionType),
+    ) -> Self {
+        match (state, transaction_type) {
+            (PayuLatamTransactionState::Approved, PayuLatamTransactionType::Authorization) => {
+                Self::Authorized
+            }
+            (PayuLatamTransactionState::Approved, PayuLatamTransactionType::Void) => Self::Voided,
+            (PayuLatamTransactionState::Approved, _) => Self::Charged,
+            (
+                PayuLatamTransactionState::Declined
+                | PayuLatamTransactionState::Error
+                | PayuLatamTransactionState::Expired,
+                PayuLatamTransactionType::Capture,
+            ) => Self::CaptureFailed,
+            (
+                PayuLatamTransactionState::Declined
+                | PayuLatamTransactionState::Error
+                | PayuLatamTransactionState::Expired,
+                PayuLatamTransactionType::Void,
+            ) => Self::VoidFailed,
+            (
+                PayuLatamTransactionState::Declined
+                | PayuLatamTransactionState::Error
+                | PayuLatamTransactionState::Expired,
+                _,
+            ) => Self::Failure,
+            (PayuLatamTransactionState::Pending | PayuLatamTransactionState::Submitted, _) => {
+                Self::Pending
+            }
+        }
+    }
+}
+
+impl From<PayuLatamTransactionState> for enums::RefundStatus {
+    fn from(state: PayuLatamTransactionState) -> Self {
+        match state {
+            PayuLatamTransactionState::Approved => Self::Success,
+            PayuLatamTransactionState::Declined
+            | PayuLatamTransactionState::Error
+            | PayuLatamTransactionState::Expired => Self::Failure,
+            PayuLatamTransactionState::Pending | PayuLatamTransactionState::Submitted => {
+                Self::Pending
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuLatamTransactionResponse {
+    pub order_id: Option<i64>,
+    pub transaction_id: Option<String>,
+    pub state: PayuLatamTransactionState,
+    pub response_code: Option<String>,
+    pub response_message: Option<String>,
+    pub payment_network_response_error_message: Option<String>,
+}
+
+impl PayuLatamTransactionResponse {
+    fn is_failure(&self) -> bool {
+        matches!(
+            self.state,
+            PayuLatamTransactionState::Declined
+                | PayuLatamTransactionState::Error
+                | PayuLatamTransactionState::Expired
+        )
+    }
+
+    fn get_error_response(&self, status_code: u16) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: self
+                .response_code
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: self
+                .response_message
+                .clone()
+                .or_else(|| self.response_code.clone())
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: self.payment_network_response_error_message.clone(),
+            status_code,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuLatamPaymentsResponse {
+    pub code: PayuLatamResponseCode,
+    pub error: Option<String>,
+    pub transaction_response: Option<PayuLatamTransactionResponse>,
+}
+
+impl PayuLatamPaymentsResponse {
+    // Errors in the request are returned with a success status code
+    fn get_transaction_response(
+        self,
+        status_code: u16,
+    ) -> Result<PayuLatamTransactionResponse, types::ErrorResponse> {
+        match (self.code, self.transaction_response) {
+            (PayuLatamResponseCode::Success, Some(transaction_response)) => {
+                Ok(transaction_response)
+            }
+            _ => Err(get_latam_error_response(self.error, status_code)),
+        }
+    }
+}
+
+fn get_latam_error_response(error: Option<String>, status_code: u16) -> types::ErrorResponse {
+    types::ErrorResponse {
+        code: consts::NO_ERROR_CODE.to_string(),
+        message: error.unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+        reason: None,
+        status_code,
+    }
+}
+
+impl<F, T> TryFrom<types::ResponseRouterData<F, PayuLatamPaymentsResponse, T, types::AccessToken>>
+    for types::RouterData<F, T, types::AccessToken>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, PayuLatamPaymentsResponse, T, types::AccessToken>,
+    ) -> Result<Self, Self::Error> {
+        let response = match item.response.code {
+            PayuLatamResponseCode::Success => Ok(types::AccessToken {
+                token: String::new(),
+                expires: LATAM_CREDENTIALS_VALIDITY,
+            }),
+            PayuLatamResponseCode::Error => Err(get_latam_error_response(
+                item.response.error,
+                item.http_code,
+            )),
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+impl<F, T>
+    TryFrom<(
+        types::ResponseRouterData<F, PayuLatamPaymentsResponse, T, types::PaymentsResponseData>,
+        PayuLatamTransactionType,
+    )> for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        (item, transaction_type): (
+            types::ResponseRouterData<F, PayuLatamPaymentsResponse, T, types::PaymentsResponseData>,
+            PayuLatamTransactionType,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let transaction_response = match item.response.get_transaction_response(item.http_code) {
+            Ok(transaction_response) => transaction_response,
+            Err(error_response) => {
+                return Ok(Self {
+                    response: Err(error_response),
+                    ..item.data
+                })
+            }
+        };
+        let status = enums::AttemptStatus::foreign_from((
+            transaction_response.state.clone(),
+            transaction_type,
+        ));
+        let response = if transaction_response.is_failure() {
+            Err(transaction_response.get_error_response(item.http_code))
+        } else if matches!(
+            transaction_type,
+            PayuLatamTransactionType::Authorization
+                | PayuLatamTransactionType::AuthorizationAndCapture
+        ) {
+            let resource_id = match transaction_response.transaction_id {
+                Some(transaction_id) => types::ResponseId::ConnectorTransactionId(transaction_id),
+                None => types::ResponseId::NoResponseId,
+            };
+            let connector_metadata = transaction_response
+                .order_id
+                .map(|order_id| serde_json::to_value(PayuLatamConnectorMetadata { order_id }))
+                .transpose()
+                .into_report()
+                .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata,
+                verification_result: None,
+            })
+        } else {
+            // Captures and voids keep the authorization as the transaction of the payment, as it is
+            // the parent of the later transactions on the order
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::NoResponseId,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                verification_result: None,
+            })
+        };
+        Ok(Self {
+            status,
+            response,
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PayuLatamQueryResponse<T> {
+    pub code: PayuLatamResponseCode,
+    pub error: Option<String>,
+    pub result: Option<PayuLatamQueryResult<T>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PayuLatamQueryResult<T> {
+    pub payload: Option<T>,
+}
+
+impl<T> PayuLatamQueryResponse<T> {
+    fn get_payload(self, status_code: u16) -> Result<T, types::ErrorResponse> {
+        match (self.code, self.result.and_then(|result| result.payload)) {
+            (PayuLatamResponseCode::Success, Some(payload)) => Ok(payload),
+            _ => Err(get_latam_error_response(self.error, status_code)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PayuLatamOrderStatus {
+    New,
+    InProgress,
+    Authorized,
+    Captured,
+    Cancelled,
+    Declined,
+    Refunded,
+}
+
+impl From<PayuLatamOrderStatus> for enums::AttemptStatus {
+    fn from(status: PayuLatamOrderStatus) -> Self {
+        match status {
+            PayuLatamOrderStatus::New | PayuLatamOrderStatus::InProgress => Self::Pending,
+            PayuLatamOrderStatus::Authorized => Self::Authorized,
+            // Refunds are tracked separately, the payment itself stays charged
+            PayuLatamOrderStatus::Captured | PayuLatamOrderStatus::Refunded => Self::Charged,
+            PayuLatamOrderStatus::Cancelled => Self::Voided,
+            PayuLatamOrderStatus::Declined => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuLatamOrderDetail {
+    pub id: i64,
+    pub status: PayuLatamOrderStatus,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            PayuLatamQueryResponse<PayuLatamOrderDetail>,
+            T,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            PayuLatamQueryResponse<PayuLatamOrderDetail>,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let order = match item.response.get_payload(item.http_code) {
+            Ok(order) => order,
+            Err(error_response) => {
+                return Ok(Self {
+                    response: Err(error_response),
+                    ..item.data
+                })
+            }
+        };
+        Ok(Self {
+            status: enums::AttemptStatus::from(order.status),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::NoResponseId,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                verification_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::Execute, PayuLatamPaymentsResponse>>
+    for types::RefundsRouterData<api::Execute>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::Execute, PayuLatamPaymentsResponse>,
+    ) -> Result<Self, Self::Error> {
+        let response = item
+            .response
+            .get_transaction_response(item.http_code)
+            .and_then(|transaction_response| {
+                if transaction_response.is_failure() {
+                    Err(transaction_response.get_error_response(item.http_code))
+                } else {
+                    Ok(types::RefundsResponseData {
+                        connector_refund_id: transaction_response
+                            .transaction_id
+                            .clone()
+                            .unwrap_or_default(),
+                        refund_status: enums::RefundStatus::from(transaction_response.state),
+                    })
+                }
+            });
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuLatamTransactionDetail {
+    pub state: PayuLatamTransactionState,
+}
+
+impl
+    TryFrom<
+        types::RefundsResponseRouterData<
+            api::RSync,
+            PayuLatamQueryResponse<PayuLatamTransactionDetail>,
+        >,
+    > for types::RefundsRouterData<api::RSync>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<
+            api::RSync,
+            PayuLatamQueryResponse<PayuLatamTransactionDetail>,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let connector_refund_id = item.data.request.get_connector_refund_id()?;
+        let response = item
+            .response
+            .get_payload(item.http_code)
+            .map(|transaction| types::RefundsResponseData {
+                connector_refund_id,
+                refund_status: enums::RefundStatus::from(transaction.state),
+            });
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PayuLatamErrorResponse {
+    pub code: PayuLatamResponseCode,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum PayuRegionalErrorResponse {
+    Europe(PayuErrorResponse),
+    Latam(PayuLatamErrorResponse),
+}
@@ -1,4 +1,4 @@
-use error_stack::{IntoReport, ResultExt};
+use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
@@ -125,7 +125,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for RapydPaymentsRequest {
             "payment_method".to_owned(),
         ))?;
         Ok(Self {
-            amount: item.request.amount,
+            amount: item.request.amount.get_amount_as_i64(),
             currency: item.request.currency,
             payment_method,
             capture,
@@ -402,6 +402,7 @@ impl<F, T>
                                 redirection_data,
                                 mandate_reference: None,
                                 connector_metadata: None,
+                                verification_result: None,
                             }),
                         )
                     }
@@ -455,7 +456,7 @@ impl TryFrom<RapydWebhookObjectEventType> for api::IncomingWebhookEvent {
             RapydWebhookObjectEventType::PaymentCompleted => Ok(Self::PaymentIntentSuccess),
             RapydWebhookObjectEventType::PaymentCaptured => Ok(Self::PaymentIntentSuccess),
             RapydWebhookObjectEventType::PaymentFailed => Ok(Self::PaymentIntentFailure),
-            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
+            _ => Ok(Self::EventNotSupported),
         }
     }
 }
@@ -0,0 +1,570 @@
+use common_utils::pii::{self, Email};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils::{PaymentsAuthorizeRequestData, PhoneDetailsData, RouterData},
+    consts,
+    core::errors,
+    services,
+    types::{self, api, storage::enums, transformers::ForeignFrom},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+pub struct RazorpayAuthType {
+    pub(super) key_id: String,
+    pub(super) key_secret: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for RazorpayAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        if let types::ConnectorAuthType::BodyKey { api_key, key1 } = auth_type {
+            Ok(Self {
+                key_id: api_key.to_string(),
+                key_secret: key1.to_string(),
+            })
+        } else {
+            Err(errors::ConnectorError::FailedToObtainAuthType.into())
+        }
+    }
+}
+
+// Razorpay accepts payments only against an order, so an order is created for every payment
+// attempt, referencing the payment intent as its receipt
+#[derive(Debug, Serialize)]
+pub struct RazorpayOrderRequest {
+    amount: i64,
+    currency: String,
+    receipt: String,
+    // Payments made against the order are captured by Razorpay as soon as they are authorized
+    payment_capture: bool,
+}
+
+impl TryFrom<&types::PaymentsInitRouterData> for RazorpayOrderRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsInitRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount.get_amount_as_i64(),
+            currency: item.request.currency.to_string(),
+            receipt: item.payment_id.clone(),
+            payment_capture: item.request.is_auto_capture(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RazorpayOrderStatus {
+    Created,
+    Attempted,
+    Paid,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RazorpayOrderResponse {
+    pub id: String,
+    pub status: RazorpayOrderStatus,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, RazorpayOrderResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, RazorpayOrderResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            reference_id: Some(item.response.id),
+            ..item.data
+        })
+    }
+}
+
+/// The order against which the payment was made, kept with the payment attempt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RazorpayConnectorMetadata {
+    pub order_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RazorpayPaymentsRequest {
+    amount: i64,
+    currency: String,
+    order_id: String,
+    email: Secret<String, Email>,
+    contact: Secret<String>,
+    #[serde(flatten)]
+    payment_method: RazorpayPaymentMethod,
+    callback_url: String,
+    description: Option<String>,
+    ip: Option<String>,
+    user_agent: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "method", rename_all = "lowercase")]
+pub enum RazorpayPaymentMethod {
+    Card { card: RazorpayCard },
+    Upi { upi: RazorpayUpi },
+    Netbanking { bank: String },
+}
+
+#[derive(Debug, Serialize)]
+pub struct RazorpayCard {
+    number: Secret<String, pii::CardNumber>,
+    name: Secret<String>,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    cvv: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RazorpayUpi {
+    flow: RazorpayUpiFlow,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vpa: Option<Secret<String, pii::UpiVpaMaskingStrategy>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RazorpayUpiFlow {
+    Collect,
+    Intent,
+}
+
+pub struct RazorpayBankCode(&'static str);
+
+impl TryFrom<&api_models::enums::BankNames> for RazorpayBankCode {
+    type Error = Error;
+    fn try_from(bank: &api_models::enums::BankNames) -> Result<Self, Self::Error> {
+        Ok(match bank {
+            api_models::enums::BankNames::AxisBank => Self("UTIB"),
+            api_models::enums::BankNames::BankOfBaroda => Self("BARB_R"),
+            api_models::enums::BankNames::HdfcBank => Self("HDFC"),
+            api_models::enums::BankNames::IciciBank => Self("ICIC"),
+            api_models::enums::BankNames::IndusindBank => Self("INDB"),
+            api_models::enums::BankNames::KotakMahindraBank => Self("KKBK"),
+            api_models::enums::BankNames::PunjabNationalBank => Self("PUNB_R"),
+            api_models::enums::BankNames::StateBankOfIndia => Self("SBIN"),
+            api_models::enums::BankNames::YesBank => Self("YESB"),
+            _ => Err(errors::ConnectorError::NotSupported {
+                payment_method: api_models::enums::PaymentMethod::BankRedirect.to_string(),
+                connector: "Razorpay",
+                payment_experience: api_models::enums::PaymentExperience::RedirectToUrl.to_string(),
+            })?,
+        })
+    }
+}
+
+impl TryFrom<&api::PaymentMethodData> for RazorpayPaymentMethod {
+    type Error = Error;
+    fn try_from(payment_method_data: &api::PaymentMethodData) -> Result<Self, Self::Error> {
+        match payment_method_data {
+            api::PaymentMethodData::Card(card) => Ok(Self::Card {
+                card: RazorpayCard {
+                    number: card.card_number.clone(),
+                    name: card.card_holder_name.clone(),
+                    expiry_month: card.card_exp_month.clone(),
+                    expiry_year: card.card_exp_year.clone(),
+                    cvv: card.card_cvc.clone(),
+                },
+            }),
+            api::PaymentMethodData::Upi(api_models::payments::UpiData::UpiCollect { vpa_id }) => {
+                Ok(Self::Upi {
+                    upi: RazorpayUpi {
+                        flow: RazorpayUpiFlow::Collect,
+                        vpa: Some(vpa_id.clone()),
+                    },
+                })
+            }
+            api::PaymentMethodData::Upi(api_models::payments::UpiData::UpiIntent {}) => {
+                Ok(Self::Upi {
+                    upi: RazorpayUpi {
+                        flow: RazorpayUpiFlow::Intent,
+                        vpa: None,
+                    },
+                })
+            }
+            api::PaymentMethodData::BankRedirect(
+                api_models::payments::BankRedirectData::Netbanking { bank_name },
+            ) => Ok(Self::Netbanking {
+                bank: RazorpayBankCode::try_from(bank_name)?.0.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::NotImplemented(
+                "Payment methods".to_string(),
+            ))
+            .into_report(),
+        }
+    }
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for RazorpayPaymentsRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let payment_method = RazorpayPaymentMethod::try_from(&item.request.payment_method_data)?;
+        let order_id = item
+            .reference_id
+            .clone()
+            .ok_or(errors::ConnectorError::MissingConnectorTransactionID)?;
+        let phone = item.get_billing_phone()?;
+        let contact = Secret::new(format!(
+            "{}{}",
+            phone.get_country_code()?,
+            phone.get_number()?.peek()
+        ));
+        let browser_info = item.request.browser_info.as_ref();
+        Ok(Self {
+            amount: item.request.amount.get_amount_as_i64(),
+            currency: item.request.currency.to_string(),
+            order_id,
+            email: item.request.get_email()?,
+            contact,
+            payment_method,
+            callback_url: item.request.get_return_url()?,
+            description: item.description.clone(),
+            ip: browser_info
+                .and_then(|browser_info| browser_info.ip_address)
+                .map(|ip_address| ip_address.to_string()),
+            user_agent: browser_info.map(|browser_info| browser_info.user_agent.clone()),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RazorpayNextActionType {
+    // The customer is redirected to complete the 3DS authentication or the netbanking payment
+    Redirect,
+    // The customer pays using a UPI app, which is opened using the deep link
+    Intent,
+    // The customer approves the collect request in their UPI app
+    Poll,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RazorpayNextAction {
+    pub action: RazorpayNextActionType,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RazorpayPaymentsResponse {
+    pub razorpay_payment_id: String,
+    #[serde(default)]
+    pub next: Vec<RazorpayNextAction>,
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Authorize,
+            RazorpayPaymentsResponse,
+            types::PaymentsAuthorizeData,
+            types::PaymentsResponseData,
+        >,
+    > for types::PaymentsAuthorizeRouterData
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Authorize,
+            RazorpayPaymentsResponse,
+            types::PaymentsAuthorizeData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let next_action = item.response.next.first();
+        let status = match next_action.map(|next_action| &next_action.action) {
+            Some(RazorpayNextActionType::Redirect | RazorpayNextActionType::Intent) => {
+                enums::AttemptStatus::AuthenticationPending
+            }
+            _ => enums::AttemptStatus::Pending,
+        };
+        // The deep link of the UPI intent flow is returned as the endpoint of the redirection
+        let redirection_data = next_action
+            .filter(|next_action| {
+                matches!(
+                    next_action.action,
+                    RazorpayNextActionType::Redirect | RazorpayNextActionType::Intent
+                )
+            })
+            .and_then(|next_action| next_action.url.clone())
+            .map(|url| services::RedirectForm {
+                endpoint: url,
+                method: services::Method::Get,
+                form_fields: std::collections::HashMap::new(),
+            });
+        let connector_metadata = item
+            .data
+            .reference_id
+            .clone()
+            .map(|order_id| serde_json::to_value(RazorpayConnectorMetadata { order_id }))
+            .transpose()
+            .into_report()
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.response.razorpay_payment_id,
+                ),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata,
+                verification_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RazorpayPaymentStatus {
+    Created,
+    Authorized,
+    Captured,
+    Refunded,
+    Failed,
+}
+
+// Payments of orders which are captured automatically stay authorized only until Razorpay
+// captures them
+impl ForeignFrom<(RazorpayPaymentStatus, bool)> for enums::AttemptStatus {
+    fn foreign_from((status, is_auto_capture): (RazorpayPaymentStatus, bool)) -> Self {
+        match status {
+            RazorpayPaymentStatus::Created => Self::AuthenticationPending,
+            RazorpayPaymentStatus::Authorized => {
+                if is_auto_capture {
+                    Self::Pending
+                } else {
+                    Self::Authorized
+                }
+            }
+            RazorpayPaymentStatus::Captured | RazorpayPaymentStatus::Refunded => Self::Charged,
+            RazorpayPaymentStatus::Failed => Self::Failure,
+        }
+    }
+}
+
+/// The payment entity, returned by the payment sync and capture flows and sent in webhooks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RazorpayPaymentResponse {
+    pub id: String,
+    pub order_id: Option<String>,
+    pub status: RazorpayPaymentStatus,
+    pub amount: i64,
+    pub currency: String,
+    pub error_code: Option<String>,
+    pub error_description: Option<String>,
+    pub error_reason: Option<String>,
+}
+
+impl<F, T>
+    TryFrom<(
+        types::ResponseRouterData<F, RazorpayPaymentResponse, T, types::PaymentsResponseData>,
+        bool,
+    )> for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        (item, is_auto_capture): (
+            types::ResponseRouterData<F, RazorpayPaymentResponse, T, types::PaymentsResponseData>,
+            bool,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let status =
+            enums::AttemptStatus::foreign_from((item.response.status.clone(), is_auto_capture));
+        let response = if status == enums::AttemptStatus::Failure {
+            Err(types::ErrorResponse {
+                code: item
+                    .response
+                    .error_code
+                    .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                message: item
+                    .response
+                    .error_description
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: item.response.error_reason,
+                status_code: item.http_code,
+            })
+        } else {
+            let connector_metadata = item
+                .response
+                .order_id
+                .map(|order_id| serde_json::to_value(RazorpayConnectorMetadata { order_id }))
+                .transpose()
+                .into_report()
+                .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata,
+                verification_result: None,
+            })
+        };
+        Ok(Self {
+            status,
+            response,
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RazorpayCaptureRequest {
+    amount: i64,
+    currency: String,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for RazorpayCaptureRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount_to_capture,
+            currency: item.request.currency.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RazorpayRefundRequest {
+    amount: i64,
+    receipt: String,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for RazorpayRefundRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.refund_amount,
+            receipt: item.request.refund_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RazorpayRefundStatus {
+    Pending,
+    Processed,
+    Failed,
+}
+
+impl From<RazorpayRefundStatus> for enums::RefundStatus {
+    fn from(item: RazorpayRefundStatus) -> Self {
+        match item {
+            RazorpayRefundStatus::Processed => Self::Success,
+            RazorpayRefundStatus::Failed => Self::Failure,
+            RazorpayRefundStatus::Pending => Self::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RazorpayRefundResponse {
+    pub id: String,
+    pub payment_id: String,
+    pub status: RazorpayRefundStatus,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, RazorpayRefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, RazorpayRefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: enums::RefundStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayErrorResponse {
+    pub error: RazorpayErrorData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayErrorData {
+    pub code: String,
+    pub description: String,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayWebhookBody {
+    pub event: RazorpayWebhookEventType,
+    pub payload: RazorpayWebhookPayload,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayWebhookPayload {
+    pub payment: Option<RazorpayWebhookEntity<RazorpayPaymentResponse>>,
+    pub refund: Option<RazorpayWebhookEntity<RazorpayRefundResponse>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayWebhookEntity<T> {
+    pub entity: T,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum RazorpayWebhookEventType {
+    #[serde(rename = "payment.authorized")]
+    PaymentAuthorized,
+    #[serde(rename = "payment.captured")]
+    PaymentCaptured,
+    #[serde(rename = "payment.failed")]
+    PaymentFailed,
+    #[serde(rename = "order.paid")]
+    OrderPaid,
+    #[serde(rename = "refund.created")]
+    RefundCreated,
+    #[serde(rename = "refund.processed")]
+    RefundProcessed,
+    #[serde(rename = "refund.failed")]
+    RefundFailed,
+    #[serde(other)]
+    Unknown,
+}
+
+impl RazorpayWebhookEventType {
+    pub fn is_refund_event(&self) -> bool {
+        matches!(
+            self,
+            Self::RefundCreated | Self::RefundProcessed | Self::RefundFailed
+        )
+    }
+}
+
+impl From<RazorpayWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(event_type: RazorpayWebhookEventType) -> Self {
+        match event_type {
+            RazorpayWebhookEventType::PaymentCaptured | RazorpayWebhookEventType::OrderPaid => {
+                Self::PaymentIntentSuccess
+            }
+            RazorpayWebhookEventType::PaymentFailed => Self::PaymentIntentFailure,
+            // Authorized payments of orders which are captured automatically are yet to be
+            // captured by Razorpay
+            RazorpayWebhookEventType::PaymentAuthorized => Self::PaymentIntentProcessing,
+            RazorpayWebhookEventType::RefundProcessed => Self::RefundSuccess,
+            RazorpayWebhookEventType::RefundFailed => Self::RefundFailure,
+            RazorpayWebhookEventType::RefundCreated | RazorpayWebhookEventType::Unknown => {
+                Self::EventNotSupported
+            }
+        }
+    }
+}
@@ -114,7 +114,7 @@ fn get_bank_redirect_request(
     redirect_data: &payments::BankRedirectData,
 ) -> Result<Shift4PaymentsRequest, Error> {
     let submit_for_settlement = submit_for_settlement(item);
-    let method_type = PaymentMethodType::from(redirect_data);
+    let method_type = PaymentMethodType::try_from(redirect_data)?;
     let billing = get_billing(item)?;
     let payment_method = Some(PaymentMethod {
         method_type,
@@ -132,13 +132,18 @@ fn get_bank_redirect_request(
     })
 }
 
-impl From<&payments::BankRedirectData> for PaymentMethodType {
-    fn from(value: &payments::BankRedirectData) -> Self {
+impl TryFrom<&payments::BankRedirectData> for PaymentMethodType {
+    type Error = Error;
+    fn try_from(value: &payments::BankRedirectData) -> Result<Self, Self::Error> {
         match value {
-            payments::BankRedirectData::Eps { .. } => Self::Eps,
-            payments::BankRedirectData::Giropay { .. } => Self::Giropay,
-            payments::BankRedirectData::Ideal { .. } => Self::Ideal,
-            payments::BankRedirectData::Sofort { .. } => Self::Sofort,
+            payments::BankRedirectData::Eps { .. } => Ok(Self::Eps),
+            payments::BankRedirectData::Giropay { .. } => Ok(Self::Giropay),
+            payments::BankRedirectData::Ideal { .. } => Ok(Self::Ideal),
+            payments::BankRedirectData::Sofort { .. } => Ok(Self::Sofort),
+            payments::BankRedirectData::Netbanking { .. }
+            | payments::BankRedirectData::Blik { .. } => {
+                Err(errors::ConnectorError::NotImplemented("Payment Method".to_string()).into())
+            }
         }
     }
 }
@@ -317,6 +322,7 @@ impl<F, T>
                     .map(|url| services::RedirectForm::from((url, services::Method::Get))),
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
@@ -53,6 +53,17 @@ impl ConnectorCommon for Stripe {
             format!("Bearer {}", auth.api_key),
         )])
     }
+
+    fn get_unified_code(&self, error_code: &str) -> Option<api::enums::UnifiedCode> {
+        stripe::get_unified_code(error_code)
+    }
+
+    fn get_required_fields(
+        &self,
+        payment_method_type: api::enums::PaymentMethodType,
+    ) -> &'static [api::RequiredField] {
+        api::RequiredField::find(stripe::REQUIRED_FIELDS, payment_method_type)
+    }
 }
 
 impl api::Payment for Stripe {}
@@ -853,6 +864,200 @@ fn get_signature_elements_from_header(
 }
 
 #[async_trait::async_trait]
+impl api::ConnectorMandateRevoke for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::MandateRevokeType::get_content_type(self).to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
+
+    fn get_url(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        // Stripe does not allow cancelling a mandate directly, detaching the payment method the
+        // mandate was set up on deactivates the mandate.
+        let connector_mandate_id = req.request.connector_mandate_id.as_ref().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "connector_mandate_id",
+            },
+        )?;
+        Ok(format!(
+            "{}v1/payment_methods/{}/detach",
+            self.base_url(connectors),
+            connector_mandate_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::MandateRevokeType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::MandateRevokeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::MandateRevokeRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::MandateRevokeRouterData, errors::ConnectorError> {
+        let response: stripe::PaymentMethodDetachResponse = res
+            .response
+            .parse_struct("Stripe PaymentMethodDetachResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl api::ConnectorVerifyCredentials for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyCredentials,
+        types::VerifyCredentialsRequestData,
+        types::VerifyCredentialsResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::VerifyCredentialsRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::VerifyCredentialsType::get_content_type(self).to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::VerifyCredentialsRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        // Retrieving the balance has no side effects and is allowed for any valid secret key.
+        Ok(format!("{}v1/balance", self.base_url(connectors)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::VerifyCredentialsRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::VerifyCredentialsType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(types::VerifyCredentialsType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::VerifyCredentialsRouterData,
+        _res: types::Response,
+    ) -> CustomResult<types::VerifyCredentialsRouterData, errors::ConnectorError> {
+        Ok(types::RouterData {
+            response: Ok(types::VerifyCredentialsResponseData { verified: true }),
+            ..data.clone()
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
 impl api::IncomingWebhook for Stripe {
     fn get_webhook_source_verification_algorithm(
         &self,
@@ -921,8 +1126,19 @@ impl api::IncomingWebhook for Stripe {
             .parse_struct("StripeWebhookObjectId")
             .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
 
+        // Charges are referenced using the payment intent which created them
+        let connector_transaction_id = match details.data.object.object.as_str() {
+            "charge" => details
+                .data
+                .object
+                .payment_intent
+                .ok_or(errors::ConnectorError::WebhookReferenceIdNotFound)
+                .into_report()?,
+            _ => details.data.object.id,
+        };
+
         Ok(api_models::webhooks::ObjectReferenceId::PaymentId(
-            api_models::payments::PaymentIdType::ConnectorTransactionId(details.data.object.id),
+            api_models::payments::PaymentIdType::ConnectorTransactionId(connector_transaction_id),
         ))
     }
 
@@ -938,7 +1154,19 @@ impl api::IncomingWebhook for Stripe {
         Ok(match details.event_type.as_str() {
             "payment_intent.payment_failed" => api::IncomingWebhookEvent::PaymentIntentFailure,
             "payment_intent.succeeded" => api::IncomingWebhookEvent::PaymentIntentSuccess,
-            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
+            // Bank debits which were returned after the payment succeeded fail the charge
+            "charge.failed" => {
+                let charge: stripe::StripeWebhookObjectCharge = request
+                    .body
+                    .parse_struct("StripeWebhookObjectCharge")
+                    .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+                if charge.is_bank_debit() {
+                    api::IncomingWebhookEvent::PaymentDebitReturned
+                } else {
+                    api::IncomingWebhookEvent::EventNotSupported
+                }
+            }
+            _ => api::IncomingWebhookEvent::EventNotSupported,
         })
     }
 
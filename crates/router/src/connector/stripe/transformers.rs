@@ -78,6 +78,8 @@ pub struct PaymentIntentRequest {
     pub return_url: String,
     pub confirm: bool,
     pub mandate: Option<String>,
+    pub off_session: Option<bool>,
+    pub setup_future_usage: Option<enums::FutureUsage>,
     pub description: Option<String>,
     #[serde(flatten)]
     pub shipping: StripeShippingAddress,
@@ -86,6 +88,10 @@ pub struct PaymentIntentRequest {
     #[serde(flatten)]
     pub payment_data: Option<StripePaymentMethodData>,
     pub capture_method: StripeCaptureMethod,
+    #[serde(flatten)]
+    pub mandate_data: Option<StripeMandateRequest>,
+    #[serde(flatten)]
+    pub transfer_data: Option<StripeTransferData>,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -119,7 +125,59 @@ pub struct StripeCardData {
     pub payment_method_data_card_cvc: Secret<String>,
     #[serde(rename = "payment_method_options[card][request_three_d_secure]")]
     pub payment_method_auth_type: Auth3ds,
+    #[serde(
+        rename = "payment_method_options[card][network]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub payment_method_options_card_network: Option<StripeCardNetwork>,
+}
+
+/// The networks which Stripe processes co-badged cards with
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeCardNetwork {
+    Visa,
+    Mastercard,
+    Amex,
+    CartesBancaires,
+    Diners,
+    Discover,
+    Interac,
+    Jcb,
+    Unionpay,
+}
+
+fn get_stripe_card_network(card_network: &api_enums::CardNetwork) -> Option<StripeCardNetwork> {
+    match card_network {
+        api_enums::CardNetwork::Visa => Some(StripeCardNetwork::Visa),
+        api_enums::CardNetwork::Mastercard => Some(StripeCardNetwork::Mastercard),
+        api_enums::CardNetwork::AmericanExpress => Some(StripeCardNetwork::Amex),
+        api_enums::CardNetwork::CartesBancaires => Some(StripeCardNetwork::CartesBancaires),
+        api_enums::CardNetwork::DinersClub => Some(StripeCardNetwork::Diners),
+        api_enums::CardNetwork::Discover => Some(StripeCardNetwork::Discover),
+        api_enums::CardNetwork::Interac => Some(StripeCardNetwork::Interac),
+        api_enums::CardNetwork::JCB => Some(StripeCardNetwork::Jcb),
+        api_enums::CardNetwork::UnionPay => Some(StripeCardNetwork::Unionpay),
+        api_enums::CardNetwork::RuPay | api_enums::CardNetwork::Maestro => None,
+    }
 }
+/// Google Pay tokens of the Stripe gateway carry a Stripe card token, which is used to create the
+/// card payment method
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeGooglePayData {
+    #[serde(rename = "payment_method_types[]")]
+    pub payment_method_types: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[type]")]
+    pub payment_method_data_type: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[card][token]")]
+    pub token: Secret<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeGooglePayToken {
+    pub id: Secret<String>,
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 pub struct StripePayLaterData {
     #[serde(rename = "payment_method_types[]")]
@@ -186,13 +244,129 @@ pub struct StripeBankRedirectData {
     pub bank_specific_data: Option<BankSpecificData>,
 }
 
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeVerificationMethod {
+    Automatic,
+    Instant,
+    Microdeposits,
+}
+
+impl From<&api_enums::BankDebitVerificationMethod> for StripeVerificationMethod {
+    fn from(verification_method: &api_enums::BankDebitVerificationMethod) -> Self {
+        match verification_method {
+            api_enums::BankDebitVerificationMethod::Automatic => Self::Automatic,
+            api_enums::BankDebitVerificationMethod::Instant => Self::Instant,
+            api_enums::BankDebitVerificationMethod::Microdeposits => Self::Microdeposits,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum BankDebitSpecificData {
+    Ach {
+        #[serde(rename = "payment_method_data[us_bank_account][account_holder_type]")]
+        account_holder_type: String,
+        #[serde(rename = "payment_method_data[us_bank_account][account_number]")]
+        account_number: Secret<String>,
+        #[serde(rename = "payment_method_data[us_bank_account][routing_number]")]
+        routing_number: Secret<String>,
+        #[serde(rename = "payment_method_options[us_bank_account][verification_method]")]
+        verification_method: StripeVerificationMethod,
+    },
+    Sepa {
+        #[serde(rename = "payment_method_data[sepa_debit][iban]")]
+        iban: Secret<String>,
+    },
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeBankDebitData {
+    #[serde(rename = "payment_method_types[]")]
+    pub payment_method_types: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[type]")]
+    pub payment_method_data_type: StripePaymentMethodType,
+    #[serde(flatten)]
+    pub bank_specific_data: BankDebitSpecificData,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeMandateType {
+    Online,
+    Offline,
+}
+
+/// The acceptance of the debit mandate by the customer, which Stripe requires for bank debits
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeMandateRequest {
+    #[serde(rename = "mandate_data[customer_acceptance][type]")]
+    pub mandate_type: StripeMandateType,
+    #[serde(rename = "mandate_data[customer_acceptance][online][ip_address]")]
+    pub ip_address: Option<Secret<String>>,
+    #[serde(rename = "mandate_data[customer_acceptance][online][user_agent]")]
+    pub user_agent: Option<String>,
+}
+
+/// A destination charge of Stripe Connect, which transfers the amount of the payment less the
+/// application fee of the platform to the connected account of the sub-merchant
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeTransferData {
+    #[serde(rename = "transfer_data[destination]")]
+    pub destination: String,
+    pub application_fee_amount: i64,
+}
+
+impl TryFrom<&types::SplitPaymentsData> for StripeTransferData {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(split_payments: &types::SplitPaymentsData) -> Result<Self, Self::Error> {
+        // A destination charge transfers to a single connected account
+        match split_payments.transfers.as_slice() {
+            [transfer] => Ok(Self {
+                destination: transfer.connector_account_id.clone(),
+                application_fee_amount: split_payments.platform_fee,
+            }),
+            _ => Err(errors::ConnectorError::FlowNotSupported {
+                flow: "Split payments to more than one sub-merchant".to_string(),
+                connector: "stripe".to_string(),
+            })?,
+        }
+    }
+}
+
+impl From<Option<&types::BrowserInformation>> for StripeMandateRequest {
+    fn from(browser_info: Option<&types::BrowserInformation>) -> Self {
+        // The mandate is accepted online when the customer is present on the checkout page,
+        // which is known from the browser information
+        match browser_info.and_then(|browser_info| {
+            browser_info
+                .ip_address
+                .map(|ip_address| (ip_address, browser_info.user_agent.clone()))
+        }) {
+            Some((ip_address, user_agent)) => Self {
+                mandate_type: StripeMandateType::Online,
+                ip_address: Some(Secret::new(ip_address.to_string())),
+                user_agent: Some(user_agent),
+            },
+            None => Self {
+                mandate_type: StripeMandateType::Offline,
+                ip_address: None,
+                user_agent: None,
+            },
+        }
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum StripePaymentMethodData {
     Card(StripeCardData),
     PayLater(StripePayLaterData),
+    GooglePay(StripeGooglePayData),
     Wallet,
     BankRedirect(StripeBankRedirectData),
+    BankDebit(StripeBankDebitData),
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize, Clone)]
@@ -206,6 +380,8 @@ pub enum StripePaymentMethodType {
     Giropay,
     Ideal,
     Sofort,
+    UsBankAccount,
+    SepaDebit,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize, Clone)]
@@ -462,6 +638,42 @@ impl TryFrom<&payments::BankRedirectData> for StripeBillingAddress {
     }
 }
 
+impl From<&payments::BankDebitData> for StripeBillingAddress {
+    fn from(bank_debit_data: &payments::BankDebitData) -> Self {
+        let billing_details = bank_debit_data.get_billing_details();
+        Self {
+            name: Some(billing_details.name.clone()),
+            email: Some(billing_details.email.clone()),
+            ..Self::default()
+        }
+    }
+}
+
+fn get_bank_debit_data(
+    bank_debit_data: &payments::BankDebitData,
+) -> (StripePaymentMethodType, BankDebitSpecificData) {
+    match bank_debit_data {
+        payments::BankDebitData::AchBankDebit {
+            account_number,
+            routing_number,
+            verification_method,
+            ..
+        } => (
+            StripePaymentMethodType::UsBankAccount,
+            BankDebitSpecificData::Ach {
+                account_holder_type: "individual".to_string(),
+                account_number: account_number.clone(),
+                routing_number: routing_number.clone(),
+                verification_method: StripeVerificationMethod::from(verification_method),
+            },
+        ),
+        payments::BankDebitData::SepaBankDebit { iban, .. } => (
+            StripePaymentMethodType::SepaDebit,
+            BankDebitSpecificData::Sepa { iban: iban.clone() },
+        ),
+    }
+}
+
 fn get_bank_specific_data(
     bank_redirect_data: &payments::BankRedirectData,
 ) -> Option<BankSpecificData> {
@@ -505,6 +717,10 @@ fn create_stripe_payment_method(
                     payment_method_data_card_exp_year: card_details.card_exp_year.clone(),
                     payment_method_data_card_cvc: card_details.card_cvc.clone(),
                     payment_method_auth_type,
+                    payment_method_options_card_network: card_details
+                        .card_network
+                        .as_ref()
+                        .and_then(get_stripe_card_network),
                 }),
                 StripePaymentMethodType::Card,
                 StripeBillingAddress::default(),
@@ -549,6 +765,32 @@ fn create_stripe_payment_method(
                 billing_address,
             ))
         }
+        payments::PaymentMethodData::BankDebit(bank_debit_data) => {
+            let (pm_type, bank_specific_data) = get_bank_debit_data(bank_debit_data);
+            Ok((
+                StripePaymentMethodData::BankDebit(StripeBankDebitData {
+                    payment_method_types: pm_type.clone(),
+                    payment_method_data_type: pm_type.clone(),
+                    bank_specific_data,
+                }),
+                pm_type,
+                StripeBillingAddress::from(bank_debit_data),
+            ))
+        }
+        payments::PaymentMethodData::Wallet(payments::WalletData::GooglePay(google_pay_data)) => {
+            let google_pay_token: StripeGooglePayToken =
+                serde_json::from_str(&google_pay_data.tokenization_data.token)
+                    .map_err(|_| errors::ConnectorError::InvalidWalletToken)?;
+            Ok((
+                StripePaymentMethodData::GooglePay(StripeGooglePayData {
+                    payment_method_types: StripePaymentMethodType::Card,
+                    payment_method_data_type: StripePaymentMethodType::Card,
+                    token: google_pay_token.id,
+                }),
+                StripePaymentMethodType::Card,
+                StripeBillingAddress::default(),
+            ))
+        }
         _ => Err(errors::ConnectorError::NotImplemented(
             "stripe does not support this payment method".to_string(),
         )),
@@ -619,8 +861,34 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             }
         };
 
+        // Stripe flags the payment as merchant initiated when `off_session` is set, and as the
+        // customer initiated payment storing the card when `setup_future_usage` is set
+        let (off_session, setup_future_usage) = match item.request.stored_credential {
+            Some(types::StoredCredentialIndicator::SubsequentMerchantInitiated { .. }) => {
+                (Some(true), None)
+            }
+            Some(types::StoredCredentialIndicator::InitialCustomerInitiated) => {
+                (None, Some(enums::FutureUsage::OffSession))
+            }
+            None => (None, None),
+        };
+
+        let mandate_data = match item.request.payment_method_data {
+            payments::PaymentMethodData::BankDebit(_) if mandate.is_none() => Some(
+                StripeMandateRequest::from(item.request.browser_info.as_ref()),
+            ),
+            _ => None,
+        };
+
+        let transfer_data = item
+            .request
+            .split_payments
+            .as_ref()
+            .map(StripeTransferData::try_from)
+            .transpose()?;
+
         Ok(Self {
-            amount: item.request.amount, //hopefully we don't loose some cents here
+            amount: item.request.amount.get_amount_as_i64(), //hopefully we don't loose some cents here
             currency: item.request.currency.to_string(), //we need to copy the value and not transfer ownership
             statement_descriptor_suffix: item.request.statement_descriptor_suffix.clone(),
             statement_descriptor: item.request.statement_descriptor.clone(),
@@ -640,6 +908,10 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             capture_method: StripeCaptureMethod::from(item.request.capture_method),
             payment_data,
             mandate,
+            off_session,
+            setup_future_usage,
+            mandate_data,
+            transfer_data,
         })
     }
 }
@@ -791,12 +1063,14 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, PaymentIntentResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
-        let redirection_data =
-            item.response
-                .next_action
-                .map(|StripeNextActionResponse::RedirectToUrl(response)| {
-                    services::RedirectForm::from((response.url, services::Method::Get))
-                });
+        let redirection_data = item.response.next_action.map(|next_action_response| {
+            services::RedirectForm::from((next_action_response.get_url(), services::Method::Get))
+        });
+
+        let status = get_attempt_status(
+            item.response.status,
+            item.response.payment_method_options.as_ref(),
+        );
 
         let mandate_reference =
             item.response
@@ -809,7 +1083,7 @@ impl<F, T>
                 });
 
         Ok(Self {
-            status: enums::AttemptStatus::from(item.response.status),
+            status,
             // client_secret: Some(item.response.client_secret.clone().as_str()),
             // description: item.response.description.map(|x| x.as_str()),
             // statement_descriptor_suffix: item.response.statement_descriptor_suffix.map(|x| x.as_str()),
@@ -819,6 +1093,7 @@ impl<F, T>
                 redirection_data,
                 mandate_reference,
                 connector_metadata: None,
+                verification_result: None,
             }),
             amount_captured: Some(item.response.amount_received),
             ..item.data
@@ -839,11 +1114,16 @@ impl<F, T>
             types::PaymentsResponseData,
         >,
     ) -> Result<Self, Self::Error> {
-        let redirection_data = item.response.next_action.as_ref().map(
-            |StripeNextActionResponse::RedirectToUrl(response)| {
-                services::RedirectForm::from((response.url.clone(), services::Method::Get))
-            },
-        );
+        let redirection_data = item
+            .response
+            .next_action
+            .as_ref()
+            .map(|next_action_response| {
+                services::RedirectForm::from((
+                    next_action_response.get_url(),
+                    services::Method::Get,
+                ))
+            });
 
         let mandate_reference =
             item.response
@@ -859,7 +1139,9 @@ impl<F, T>
                     | StripePaymentMethodOptions::Eps {}
                     | StripePaymentMethodOptions::Giropay {}
                     | StripePaymentMethodOptions::Ideal {}
-                    | StripePaymentMethodOptions::Sofort {} => None,
+                    | StripePaymentMethodOptions::Sofort {}
+                    | StripePaymentMethodOptions::UsBankAccount {}
+                    | StripePaymentMethodOptions::SepaDebit {} => None,
                 });
 
         let error_res =
@@ -879,12 +1161,16 @@ impl<F, T>
                 redirection_data,
                 mandate_reference,
                 connector_metadata: None,
+                verification_result: None,
             }),
             Err,
         );
 
         Ok(Self {
-            status: enums::AttemptStatus::from(item.response.status.to_owned()),
+            status: get_attempt_status(
+                item.response.status.to_owned(),
+                item.response.payment_method_options.as_ref(),
+            ),
             response,
             amount_captured: Some(item.response.amount_received),
             ..item.data
@@ -900,12 +1186,9 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, SetupIntentResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
-        let redirection_data =
-            item.response
-                .next_action
-                .map(|StripeNextActionResponse::RedirectToUrl(response)| {
-                    services::RedirectForm::from((response.url, services::Method::Get))
-                });
+        let redirection_data = item.response.next_action.map(|next_action_response| {
+            services::RedirectForm::from((next_action_response.get_url(), services::Method::Get))
+        });
 
         let mandate_reference =
             item.response
@@ -924,6 +1207,7 @@ impl<F, T>
                 redirection_data,
                 mandate_reference,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
@@ -934,6 +1218,20 @@ impl<F, T>
 #[serde(rename_all = "snake_case", remote = "Self")]
 pub enum StripeNextActionResponse {
     RedirectToUrl(StripeRedirectToUrlResponse),
+    VerifyWithMicrodeposits(StripeVerifyWithMicroDepositsResponse),
+}
+
+impl StripeNextActionResponse {
+    /// The URL the customer is to be redirected to, which for micro-deposits verification is
+    /// the page hosted by Stripe for confirming the deposited amounts
+    fn get_url(&self) -> Url {
+        match self {
+            Self::RedirectToUrl(redirect_to_url) => redirect_to_url.url.to_owned(),
+            Self::VerifyWithMicrodeposits(verify_with_microdeposits) => {
+                verify_with_microdeposits.hosted_verification_url.to_owned()
+            }
+        }
+    }
 }
 
 // This impl is required because Stripe's response is of the below format, which is externally
@@ -962,6 +1260,11 @@ pub struct StripeRedirectToUrlResponse {
     url: Url,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct StripeVerifyWithMicroDepositsResponse {
+    hosted_verification_url: Url,
+}
+
 // REFUND :
 // Type definition for Stripe RefundRequest
 
@@ -1062,6 +1365,44 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct PaymentMethodDetachResponse {
+    pub id: String,
+    pub customer: Option<String>,
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::MandateRevoke,
+            PaymentMethodDetachResponse,
+            types::MandateRevokeRequestData,
+            types::MandateRevokeResponseData,
+        >,
+    > for types::MandateRevokeRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::MandateRevoke,
+            PaymentMethodDetachResponse,
+            types::MandateRevokeRequestData,
+            types::MandateRevokeResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        // A detached payment method is no longer attached to any customer, and every mandate
+        // created on it stops being usable for off session payments.
+        let mandate_status = match item.response.customer {
+            None => enums::MandateStatus::Revoked,
+            Some(_) => enums::MandateStatus::Active,
+        };
+        Ok(Self {
+            response: Ok(types::MandateRevokeResponseData { mandate_status }),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
 pub struct ErrorDetails {
     pub code: Option<String>,
@@ -1162,6 +1503,32 @@ pub enum StripePaymentMethodOptions {
     Giropay {},
     Ideal {},
     Sofort {},
+    UsBankAccount {},
+    SepaDebit {},
+}
+
+impl StripePaymentMethodOptions {
+    fn is_bank_debit(&self) -> bool {
+        matches!(self, Self::UsBankAccount {} | Self::SepaDebit {})
+    }
+}
+
+/// Bank debits stay in processing until the debit is settled, which takes several days, unlike
+/// other payment methods which are processed in a short time
+fn get_attempt_status(
+    status: StripePaymentStatus,
+    payment_method_options: Option<&StripePaymentMethodOptions>,
+) -> enums::AttemptStatus {
+    match status {
+        StripePaymentStatus::Processing
+            if payment_method_options
+                .map(StripePaymentMethodOptions::is_bank_debit)
+                .unwrap_or(false) =>
+        {
+            enums::AttemptStatus::DebitInitiated
+        }
+        _ => enums::AttemptStatus::from(status),
+    }
 }
 // #[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
 // pub struct Card
@@ -1225,6 +1592,9 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for CaptureRequest {
 #[derive(Debug, Deserialize)]
 pub struct StripeWebhookDataObjectId {
     pub id: String,
+    pub object: String,
+    /// The payment intent of the object, if the object is a charge
+    pub payment_intent: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1253,6 +1623,48 @@ pub struct StripeWebhookObjectId {
     pub data: StripeWebhookDataId,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeChargePaymentMethodType {
+    UsBankAccount,
+    SepaDebit,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeChargePaymentMethodDetails {
+    #[serde(rename = "type")]
+    pub payment_method_type: StripeChargePaymentMethodType,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeWebhookDataCharge {
+    pub payment_method_details: Option<StripeChargePaymentMethodDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeWebhookDataObjectCharge {
+    pub object: StripeWebhookDataCharge,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeWebhookObjectCharge {
+    pub data: StripeWebhookDataObjectCharge,
+}
+
+impl StripeWebhookObjectCharge {
+    pub fn is_bank_debit(&self) -> bool {
+        matches!(
+            self.data.object.payment_method_details,
+            Some(StripeChargePaymentMethodDetails {
+                payment_method_type: StripeChargePaymentMethodType::UsBankAccount
+                    | StripeChargePaymentMethodType::SepaDebit,
+            })
+        )
+    }
+}
+
 impl
     TryFrom<(
         api::PaymentMethodData,
@@ -1282,6 +1694,10 @@ impl
                     payment_method_data_card_exp_year: ccard.card_exp_year.clone(),
                     payment_method_data_card_cvc: ccard.card_cvc.clone(),
                     payment_method_auth_type,
+                    payment_method_options_card_network: ccard
+                        .card_network
+                        .as_ref()
+                        .and_then(get_stripe_card_network),
                 }
             })),
             api::PaymentMethodData::PayLater(_) => Ok(Self::PayLater(StripePayLaterData {
@@ -1297,6 +1713,91 @@ impl
                 }))
             }
             api::PaymentMethodData::Wallet(_) => Ok(Self::Wallet),
+            api::PaymentMethodData::BankDebit(_) => Err(errors::ConnectorError::NotImplemented(
+                "Mandates with bank debits".to_string(),
+            )),
+            api::PaymentMethodData::Upi(_) => Err(errors::ConnectorError::NotImplemented(
+                "Mandates with UPI".to_string(),
+            )),
+            api::PaymentMethodData::Crypto(_) => Err(errors::ConnectorError::NotImplemented(
+                "Mandates with crypto".to_string(),
+            )),
+            api::PaymentMethodData::GiftCard(_) => Err(errors::ConnectorError::NotImplemented(
+                "Mandates with gift cards".to_string(),
+            )),
+        }
+    }
+}
+
+/// The fields of the payment method data which Stripe requires, such as the name of the account
+/// holder for bank redirects, which is sent in the billing details of the payment method
+pub const REQUIRED_FIELDS: api::RequiredFieldsRegistry = &[
+    (
+        api_enums::PaymentMethodType::Eps,
+        &[
+            api::RequiredField::EPS_BILLING_NAME,
+            api::RequiredField::EPS_BANK_NAME,
+        ],
+    ),
+    (
+        api_enums::PaymentMethodType::Giropay,
+        &[api::RequiredField::GIROPAY_BILLING_NAME],
+    ),
+    (
+        api_enums::PaymentMethodType::Ideal,
+        &[
+            api::RequiredField::IDEAL_BILLING_NAME,
+            api::RequiredField::IDEAL_BANK_NAME,
+        ],
+    ),
+    (
+        api_enums::PaymentMethodType::Sofort,
+        &[
+            api::RequiredField::SOFORT_COUNTRY,
+            api::RequiredField::SOFORT_PREFERRED_LANGUAGE,
+        ],
+    ),
+    (
+        api_enums::PaymentMethodType::Klarna,
+        &[
+            api::RequiredField::KLARNA_BILLING_EMAIL,
+            api::RequiredField::KLARNA_BILLING_COUNTRY,
+        ],
+    ),
+    (
+        api_enums::PaymentMethodType::AfterpayClearpay,
+        &[
+            api::RequiredField::AFTERPAY_CLEARPAY_BILLING_EMAIL,
+            api::RequiredField::AFTERPAY_CLEARPAY_BILLING_NAME,
+        ],
+    ),
+];
+
+/// Maps the error codes of Stripe to the error codes shared across connectors
+pub fn get_unified_code(error_code: &str) -> Option<api_enums::UnifiedCode> {
+    match error_code {
+        "insufficient_funds" => Some(api_enums::UnifiedCode::InsufficientFunds),
+        "expired_card" => Some(api_enums::UnifiedCode::ExpiredCard),
+        "incorrect_cvc" | "invalid_cvc" => Some(api_enums::UnifiedCode::IncorrectCvc),
+        "incorrect_zip" | "incorrect_address" => Some(api_enums::UnifiedCode::IncorrectAddress),
+        "incorrect_number" | "invalid_number" => Some(api_enums::UnifiedCode::InvalidCardNumber),
+        "lost_card" | "stolen_card" => Some(api_enums::UnifiedCode::LostOrStolenCard),
+        "card_not_supported" | "currency_not_supported" => {
+            Some(api_enums::UnifiedCode::CardNotSupported)
         }
+        "card_velocity_exceeded" | "amount_too_large" => {
+            Some(api_enums::UnifiedCode::LimitExceeded)
+        }
+        "fraudulent" | "merchant_blacklist" => Some(api_enums::UnifiedCode::FraudSuspected),
+        "authentication_required" => Some(api_enums::UnifiedCode::AuthenticationRequired),
+        "issuer_not_available" => Some(api_enums::UnifiedCode::IssuerUnavailable),
+        "processing_error" => Some(api_enums::UnifiedCode::ProcessingError),
+        "do_not_honor" => Some(api_enums::UnifiedCode::DoNotHonor),
+        "parameter_invalid_integer"
+        | "parameter_invalid_empty"
+        | "parameter_missing"
+        | "parameter_unknown" => Some(api_enums::UnifiedCode::InvalidRequest),
+        "card_declined" | "generic_decline" => Some(api_enums::UnifiedCode::GenericDecline),
+        _ => None,
     }
 }
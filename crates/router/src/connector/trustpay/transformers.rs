@@ -181,12 +181,20 @@ pub struct TrustpayMandatoryParams {
     pub billing_postcode: Secret<String>,
 }
 
-fn get_trustpay_payment_method(bank_redirection_data: &BankRedirectData) -> TrustpayPaymentMethod {
+fn get_trustpay_payment_method(
+    bank_redirection_data: &BankRedirectData,
+) -> Result<TrustpayPaymentMethod, errors::ConnectorError> {
     match bank_redirection_data {
-        api_models::payments::BankRedirectData::Giropay { .. } => TrustpayPaymentMethod::Giropay,
-        api_models::payments::BankRedirectData::Eps { .. } => TrustpayPaymentMethod::Eps,
-        api_models::payments::BankRedirectData::Ideal { .. } => TrustpayPaymentMethod::IDeal,
-        api_models::payments::BankRedirectData::Sofort { .. } => TrustpayPaymentMethod::Sofort,
+        api_models::payments::BankRedirectData::Giropay { .. } => {
+            Ok(TrustpayPaymentMethod::Giropay)
+        }
+        api_models::payments::BankRedirectData::Eps { .. } => Ok(TrustpayPaymentMethod::Eps),
+        api_models::payments::BankRedirectData::Ideal { .. } => Ok(TrustpayPaymentMethod::IDeal),
+        api_models::payments::BankRedirectData::Sofort { .. } => Ok(TrustpayPaymentMethod::Sofort),
+        api_models::payments::BankRedirectData::Netbanking { .. }
+        | api_models::payments::BankRedirectData::Blik { .. } => Err(
+            errors::ConnectorError::NotImplemented("Payment Method".to_string()),
+        ),
     }
 }
 
@@ -250,27 +258,29 @@ fn get_bank_redirection_request_data(
     amount: String,
     return_url: String,
     auth: TrustpayAuthType,
-) -> TrustpayPaymentsRequest {
-    TrustpayPaymentsRequest::BankRedirectPaymentRequest(Box::new(PaymentRequestBankRedirect {
-        payment_method: get_trustpay_payment_method(bank_redirection_data),
-        merchant_identification: MerchantIdentification {
-            project_id: auth.project_id,
-        },
-        payment_information: BankPaymentInformation {
-            amount: Amount {
-                amount,
-                currency: item.request.currency.to_string(),
+) -> Result<TrustpayPaymentsRequest, errors::ConnectorError> {
+    Ok(TrustpayPaymentsRequest::BankRedirectPaymentRequest(
+        Box::new(PaymentRequestBankRedirect {
+            payment_method: get_trustpay_payment_method(bank_redirection_data)?,
+            merchant_identification: MerchantIdentification {
+                project_id: auth.project_id,
             },
-            references: References {
-                merchant_reference: item.attempt_id.clone(),
+            payment_information: BankPaymentInformation {
+                amount: Amount {
+                    amount,
+                    currency: item.request.currency.to_string(),
+                },
+                references: References {
+                    merchant_reference: item.attempt_id.clone(),
+                },
             },
-        },
-        callback_urls: CallbackURLs {
-            success: format!("{return_url}?status=SuccessOk"),
-            cancel: return_url.clone(),
-            error: return_url,
-        },
-    }))
+            callback_urls: CallbackURLs {
+                success: format!("{return_url}?status=SuccessOk"),
+                cancel: return_url.clone(),
+                error: return_url,
+            },
+        }),
+    ))
 }
 
 impl TryFrom<&types::PaymentsAuthorizeRouterData> for TrustpayPaymentsRequest {
@@ -313,13 +323,13 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for TrustpayPaymentsRequest {
                 item.request.get_return_url()?,
             )),
             api::PaymentMethodData::BankRedirect(ref bank_redirection_data) => {
-                Ok(get_bank_redirection_request_data(
+                get_bank_redirection_request_data(
                     item,
                     bank_redirection_data,
                     amount,
                     item.request.get_return_url()?,
                     auth,
-                ))
+                )
             }
             _ => Err(errors::ConnectorError::NotImplemented(format!(
                 "Current Payment Method - {:?}",
@@ -579,6 +589,7 @@ fn handle_cards_response(
         redirection_data,
         mandate_reference: None,
         connector_metadata: None,
+        verification_result: None,
     };
     Ok((status, error, payment_response_data))
 }
@@ -605,6 +616,7 @@ fn handle_bank_redirects_response(
         ))),
         mandate_reference: None,
         connector_metadata: None,
+        verification_result: None,
     };
     Ok((status, error, payment_response_data))
 }
@@ -635,6 +647,7 @@ fn handle_bank_redirects_error_response(
         redirection_data: None,
         mandate_reference: None,
         connector_metadata: None,
+        verification_result: None,
     };
     Ok((status, error, payment_response_data))
 }
@@ -675,6 +688,7 @@ fn handle_bank_redirects_sync_response(
         redirection_data: None,
         mandate_reference: None,
         connector_metadata: None,
+        verification_result: None,
     };
     Ok((status, error, payment_response_data))
 }
@@ -695,6 +709,7 @@ pub fn handle_webhook_response(
         redirection_data: None,
         mandate_reference: None,
         connector_metadata: None,
+        verification_result: None,
     };
     Ok((status, None, payment_response_data))
 }
@@ -824,10 +839,13 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for TrustpayRefundRequest {
     fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
         let amount = format!(
             "{:.2}",
-            utils::to_currency_base_unit(item.request.amount, item.request.currency)?
-                .parse::<f64>()
-                .into_report()
-                .change_context(errors::ConnectorError::RequestEncodingFailed)?
+            utils::to_currency_base_unit(
+                types::MinorUnit::new(item.request.amount),
+                item.request.currency
+            )?
+            .parse::<f64>()
+            .into_report()
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?
         );
         match item.payment_method {
             storage_models::enums::PaymentMethod::BankRedirect => {
@@ -4,6 +4,7 @@ use base64::Engine;
 use common_utils::{
     errors::ReportSwitchExt,
     pii::{self, Email},
+    types::MinorUnit,
 };
 use error_stack::{report, IntoReport, ResultExt};
 use masking::Secret;
@@ -220,6 +221,23 @@ impl RefundsRequestData for types::RefundsData {
     }
 }
 
+pub trait PayoutsRequestData {
+    fn get_email(&self) -> Result<Secret<String, Email>, Error>;
+    fn get_connector_payout_id(&self) -> Result<String, Error>;
+}
+
+impl PayoutsRequestData for types::PayoutsData {
+    fn get_email(&self) -> Result<Secret<String, Email>, Error> {
+        self.email.clone().ok_or_else(missing_field_err("email"))
+    }
+    fn get_connector_payout_id(&self) -> Result<String, Error> {
+        self.connector_payout_id
+            .clone()
+            .get_required_value("connector_payout_id")
+            .change_context(errors::ConnectorError::MissingConnectorPayoutID)
+    }
+}
+
 static CARD_REGEX: Lazy<HashMap<CardIssuer, Result<Regex, regex::Error>>> = Lazy::new(|| {
     let mut map = HashMap::new();
     // Reference: https://gist.github.com/michaelkeevildown/9096cd3aac9029c4e6e05588448a8841
@@ -495,7 +513,7 @@ pub fn to_currency_base_unit_from_optional_amount(
     currency: storage_models::enums::Currency,
 ) -> Result<String, error_stack::Report<errors::ConnectorError>> {
     match amount {
-        Some(a) => to_currency_base_unit(a, currency),
+        Some(a) => to_currency_base_unit(MinorUnit::new(a), currency),
         _ => Err(errors::ConnectorError::MissingRequiredField {
             field_name: "amount",
         }
@@ -503,23 +521,19 @@ pub fn to_currency_base_unit_from_optional_amount(
     }
 }
 
+/// Formats the amount, in the minor unit of the currency, in the major unit of the currency with
+/// as many digits after the decimal separator as the currency has, such as `"10.50"` for USD,
+/// `"1050"` for JPY and `"1.050"` for KWD.
 pub fn to_currency_base_unit(
-    amount: i64,
+    amount: MinorUnit,
     currency: storage_models::enums::Currency,
 ) -> Result<String, error_stack::Report<errors::ConnectorError>> {
-    let amount_u32 = u32::try_from(amount)
-        .into_report()
-        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
-    let amount_f64 = f64::from(amount_u32);
-    let amount = match currency {
-        storage_models::enums::Currency::JPY | storage_models::enums::Currency::KRW => amount_f64,
-        storage_models::enums::Currency::BHD
-        | storage_models::enums::Currency::JOD
-        | storage_models::enums::Currency::KWD
-        | storage_models::enums::Currency::OMR => amount_f64 / 1000.00,
-        _ => amount_f64 / 100.00,
-    };
-    Ok(format!("{amount:.2}"))
+    if amount.is_negative() {
+        return Err(errors::ConnectorError::RequestEncodingFailed)
+            .into_report()
+            .attach_printable("Negative amounts cannot be sent to connectors");
+    }
+    Ok(types::Currency::from(currency).to_major_unit_as_string(amount))
 }
 
 pub fn str_to_f32<S>(value: &str, serializer: S) -> Result<S::Ok, S::Error>
@@ -569,3 +583,26 @@ pub fn collect_and_sort_values_by_removing_signature(
     values.sort();
     values
 }
+
+/// Maps the AVS result codes of the card networks, which most connectors return as is, to the
+/// normalized AVS result.
+pub fn get_avs_result_from_code(code: &str) -> storage_models::enums::AvsResult {
+    match code {
+        "Y" | "X" | "D" | "F" | "M" => storage_models::enums::AvsResult::FullMatch,
+        "A" | "B" => storage_models::enums::AvsResult::AddressMatch,
+        "Z" | "W" | "P" => storage_models::enums::AvsResult::PostalCodeMatch,
+        "N" | "C" => storage_models::enums::AvsResult::NoMatch,
+        _ => storage_models::enums::AvsResult::Unavailable,
+    }
+}
+
+/// Maps the CVV2/CVC2 result codes of the card networks, which most connectors return as is, to
+/// the normalized CVC result.
+pub fn get_cvc_result_from_code(code: &str) -> storage_models::enums::CvcResult {
+    match code {
+        "M" | "Y" => storage_models::enums::CvcResult::Match,
+        "N" => storage_models::enums::CvcResult::NoMatch,
+        "P" => storage_models::enums::CvcResult::NotProcessed,
+        _ => storage_models::enums::CvcResult::Unavailable,
+    }
+}
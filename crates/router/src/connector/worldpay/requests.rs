@@ -225,3 +225,12 @@ pub struct WorldpayRefundRequest {
     pub value: PaymentValue,
     pub reference: String,
 }
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldpayVerificationRequest {
+    pub transaction_reference: String,
+    pub merchant: Merchant,
+    pub payment_instrument: PaymentInstrument,
+    pub verification_currency: String,
+}
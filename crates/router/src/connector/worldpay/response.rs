@@ -29,6 +29,24 @@ pub enum Outcome {
     Refused,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldpayVerificationResponse {
+    pub outcome: VerificationOutcome,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheme_reference: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum VerificationOutcome {
+    #[serde(rename = "verified")]
+    Verified,
+    #[serde(rename = "not verified")]
+    NotVerified,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WorldpayEventResponse {
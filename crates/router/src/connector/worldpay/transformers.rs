@@ -64,7 +64,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for WorldpayPaymentsRequest {
         Ok(Self {
             instruction: Instruction {
                 value: PaymentValue {
-                    amount: item.request.amount,
+                    amount: item.request.amount.get_amount_as_i64(),
                     currency: item.request.currency.to_string(),
                 },
                 narrative: InstructionNarrative {
@@ -87,6 +87,21 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for WorldpayPaymentsRequest {
     }
 }
 
+impl TryFrom<&types::VerifyRouterData> for WorldpayVerificationRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::VerifyRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            transaction_reference: item.attempt_id.clone(),
+            merchant: Merchant {
+                entity: item.attempt_id.clone().replace('_', "-"),
+                ..Default::default()
+            },
+            payment_instrument: fetch_payment_instrument(item.request.payment_method_data.clone())?,
+            verification_currency: item.request.currency.to_string(),
+        })
+    }
+}
+
 pub struct WorldpayAuthType {
     pub(super) api_key: String,
 }
@@ -116,6 +131,15 @@ impl From<Outcome> for enums::AttemptStatus {
     }
 }
 
+impl From<VerificationOutcome> for enums::AttemptStatus {
+    fn from(item: VerificationOutcome) -> Self {
+        match item {
+            VerificationOutcome::Verified => Self::Charged,
+            VerificationOutcome::NotVerified => Self::Failure,
+        }
+    }
+}
+
 impl From<EventType> for enums::AttemptStatus {
     fn from(value: EventType) -> Self {
         match value {
@@ -158,12 +182,60 @@ impl TryFrom<types::PaymentsResponseRouterData<WorldpayPaymentsResponse>>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                verification_result: None,
             }),
             ..item.data
         })
     }
 }
 
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Verify,
+            WorldpayVerificationResponse,
+            types::VerifyRequestData,
+            types::PaymentsResponseData,
+        >,
+    > for types::VerifyRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Verify,
+            WorldpayVerificationResponse,
+            types::VerifyRequestData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let response = match item.response.outcome {
+            VerificationOutcome::Verified => Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::NoResponseId,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                verification_result: None,
+            }),
+            VerificationOutcome::NotVerified => Err(types::ErrorResponse {
+                code: consts::NO_ERROR_CODE.to_string(),
+                message: item
+                    .response
+                    .description
+                    .clone()
+                    .unwrap_or_else(|| "The account could not be verified".to_string()),
+                reason: None,
+                status_code: item.http_code,
+            }),
+        };
+        Ok(Self {
+            status: enums::AttemptStatus::from(item.response.outcome),
+            description: item.response.description,
+            response,
+            ..item.data
+        })
+    }
+}
+
 impl<F> TryFrom<&types::RefundsRouterData<F>> for WorldpayRefundRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
@@ -12,9 +12,16 @@ pub(crate) const ALPHABETS: [char; 62] = [
 /// API client request timeout (in seconds)
 pub const REQUEST_TIME_OUT: u64 = 30;
 
+/// Time (in seconds) after which requests shed due to an exhausted database connection pool can
+/// be retried
+pub(crate) const DB_POOL_EXHAUSTED_RETRY_AFTER: u64 = 1;
+
 // String literals
 pub(crate) const NO_ERROR_MESSAGE: &str = "No error message";
 pub(crate) const NO_ERROR_CODE: &str = "No error code";
+pub(crate) const BANK_DEBIT_RETURNED_ERROR_CODE: &str = "debit_returned";
+pub(crate) const BANK_DEBIT_RETURNED_ERROR_MESSAGE: &str =
+    "The debit was returned by the bank of the customer";
 
 // General purpose base64 engines
 pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose =
@@ -24,3 +31,14 @@ pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
 pub(crate) const PUB_SUB_CHANNEL: &str = "hyperswitch_invalidate";
+
+/// Maximum length of the value accepted in the `Idempotency-Key` header
+pub(crate) const IDEMPOTENCY_KEY_MAX_LENGTH: usize = 255;
+
+/// Maximum length of the reference of the customer accepted in level 2 card data, which is the
+/// shortest limit of the card networks
+pub(crate) const MAX_CUSTOMER_REFERENCE_LENGTH: usize = 25;
+
+/// Maximum delay after authorization at which payments are captured automatically, as connectors
+/// hold authorizations for about a week
+pub(crate) const MAX_AUTO_CAPTURE_DELAY_IN_SECONDS: i32 = 7 * 24 * 60 * 60;
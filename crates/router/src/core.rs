@@ -1,13 +1,31 @@
 pub mod admin;
 pub mod api_keys;
+pub mod business_profile;
+pub mod card_vault;
 pub mod cards_info;
 pub mod configs;
 pub mod customers;
+pub mod data_migrations;
+pub mod dead_letter;
+#[cfg(feature = "dummy_connector")]
+pub mod dummy_connector;
 pub mod errors;
+pub mod fraud_check;
+pub mod health_check;
+pub mod ledger;
 pub mod mandate;
 pub mod metrics;
+pub mod organization;
+pub mod payment_links;
 pub mod payment_methods;
+pub mod payment_schedules;
 pub mod payments;
+pub mod payouts;
+pub mod rate_limit;
+pub mod recon;
 pub mod refunds;
+pub mod routing;
+pub mod secrets;
+pub mod user;
 pub mod utils;
 pub mod webhooks;
@@ -1,4 +1,4 @@
-use common_utils::ext_traits::ValueExt;
+use common_utils::{ext_traits::ValueExt, pii::SecretSerdeValue};
 use error_stack::{report, FutureExt, IntoReport, ResultExt};
 use storage_models::{enums, merchant_account};
 use uuid::Uuid;
@@ -8,11 +8,12 @@ use crate::{
     core::{
         api_keys,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments, routing, secrets, utils as core_utils,
     },
     db::StorageInterface,
     pii::Secret,
     routes::AppState,
-    services::api as service_api,
+    services::{self, api as service_api},
     types::{
         self, api,
         storage::{self, MerchantAccount},
@@ -35,6 +36,15 @@ pub async fn create_merchant_account(
     req: api::MerchantAccountCreate,
 ) -> RouterResponse<api::MerchantAccountResponse> {
     let db = &*state.store;
+
+    if let Some(ref organization_id) = req.organization_id {
+        db.find_organization_by_organization_id(organization_id)
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::OrganizationNotFound)
+            })?;
+    }
+
     let publishable_key = Some(create_merchant_publishable_key());
 
     let api_key_request = api::CreateApiKeyRequest {
@@ -78,13 +88,14 @@ pub async fn create_merchant_account(
     );
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
+        let routing_algorithm: api::RoutingAlgorithm = routing_algorithm
             .clone()
             .parse_value("RoutingAlgorithm")
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "routing_algorithm",
             })
             .attach_printable("Invalid routing algorithm given")?;
+        routing::validate_routing_algorithm(&routing_algorithm)?;
     }
 
     let merchant_account = storage::MerchantAccountNew {
@@ -105,9 +116,11 @@ pub async fn create_merchant_account(
         enable_payment_response_hash: req.enable_payment_response_hash,
         payment_response_hash_key: req.payment_response_hash_key,
         redirect_to_merchant_with_http_post: req.redirect_to_merchant_with_http_post,
+        return_raw_connector_response: req.return_raw_connector_response,
         publishable_key,
         locker_id: req.locker_id,
         metadata: req.metadata,
+        organization_id: req.organization_id,
     };
 
     let merchant_account = db
@@ -156,13 +169,14 @@ pub async fn merchant_account_update(
     }
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
+        let routing_algorithm: api::RoutingAlgorithm = routing_algorithm
             .clone()
             .parse_value("RoutingAlgorithm")
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "routing_algorithm",
             })
             .attach_printable("Invalid routing algorithm given")?;
+        routing::validate_routing_algorithm(&routing_algorithm)?;
     }
 
     let updated_merchant_account = storage::MerchantAccountUpdate::Update {
@@ -196,6 +210,7 @@ pub async fn merchant_account_update(
         enable_payment_response_hash: req.enable_payment_response_hash,
         payment_response_hash_key: req.payment_response_hash_key,
         redirect_to_merchant_with_http_post: req.redirect_to_merchant_with_http_post,
+        return_raw_connector_response: req.return_raw_connector_response,
         locker_id: req.locker_id,
         metadata: req.metadata,
         publishable_key: None,
@@ -271,11 +286,110 @@ async fn validate_merchant_id<S: Into<String>>(
 // Merchant Connector API -  Every merchant and connector can have an instance of (merchant <> connector)
 //                          with unique merchant_connector_id for Create Operation
 
+/// Makes a test call to the connector with the credentials, so that invalid credentials are
+/// rejected when they are configured rather than on the first payment.
+///
+/// External 3DS providers are not payment connectors, only their names are validated.
+async fn verify_connector_credentials(
+    state: &AppState,
+    merchant_id: &str,
+    connector_name: &str,
+    connector_type: enums::ConnectorType,
+    auth_type: types::ConnectorAuthType,
+    metadata: Option<SecretSerdeValue>,
+) -> RouterResult<()> {
+    if connector_type == enums::ConnectorType::AuthenticationProcessor {
+        return services::authentication_connector::get_authentication_connector_by_name(
+            connector_name,
+        )
+        .map(|_| ());
+    }
+
+    let connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector_name,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::IncorrectConnectorNameGiven)?;
+    let router_data = core_utils::construct_verify_credentials_router_data(
+        merchant_id,
+        connector_name,
+        auth_type,
+        metadata,
+    );
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::VerifyCredentials,
+        types::VerifyCredentialsRequestData,
+        types::VerifyCredentialsResponseData,
+    > = connector.connector.get_connector_integration();
+    let router_data = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while verifying the credentials with the connector")?;
+
+    match router_data.response {
+        Ok(_) => Ok(()),
+        Err(error)
+            if error.status_code == http::StatusCode::UNAUTHORIZED.as_u16()
+                || error.status_code == http::StatusCode::FORBIDDEN.as_u16() =>
+        {
+            Err(report!(
+                errors::ApiErrorResponse::InvalidConnectorCredentials {
+                    connector: connector_name.to_string(),
+                    message: error.message,
+                }
+            ))
+        }
+        Err(error) => Err(report!(errors::ApiErrorResponse::ExternalConnectorError {
+            code: error.code,
+            message: error.message,
+            connector: connector_name.to_string(),
+            status_code: error.status_code,
+            reason: error.reason,
+        })),
+    }
+}
+
+/// The connector account details are stored encrypted, in place of the plaintext details.
+async fn encrypt_connector_account_details(
+    state: &AppState,
+    connector_account_details: Option<&SecretSerdeValue>,
+) -> RouterResult<(Option<String>, Option<Vec<u8>>)> {
+    match connector_account_details {
+        Some(connector_account_details) => {
+            let encrypted =
+                secrets::encrypt_connector_account_details(state, connector_account_details)
+                    .await?;
+            Ok((
+                Some(encrypted.key_id),
+                Some(encrypted.encrypted_connector_account_details),
+            ))
+        }
+        None => Ok((None, None)),
+    }
+}
+
+async fn get_payment_connector_response(
+    state: &AppState,
+    mut mca: storage::MerchantConnectorAccount,
+) -> RouterResult<api::MerchantConnector> {
+    mca.connector_account_details = secrets::decrypt_connector_account_details(state, &mca).await?;
+    mca.foreign_try_into()
+}
+
 pub async fn create_payment_connector(
-    store: &dyn StorageInterface,
+    state: &AppState,
     req: api::MerchantConnector,
     merchant_id: &String,
 ) -> RouterResponse<api::MerchantConnector> {
+    let store = &*state.store;
     let _merchant_account = store
         .find_merchant_account_by_merchant_id(merchant_id)
         .await
@@ -301,7 +415,7 @@ pub async fn create_payment_connector(
     };
 
     // Validate Merchant api details and return error if not in correct format
-    let _: types::ConnectorAuthType = req
+    let auth_type: types::ConnectorAuthType = req
         .connector_account_details
         .clone()
         .parse_value("ConnectorAuthType")
@@ -309,24 +423,40 @@ pub async fn create_payment_connector(
             field_name: "connector_account_details".to_string(),
             expected_format: "auth_type and api_key".to_string(),
         })?;
-    let configs_for_frm_value = req
+    verify_connector_credentials(
+        state,
+        merchant_id,
+        &req.connector_name,
+        req.connector_type.foreign_into(),
+        auth_type,
+        req.metadata.clone(),
+    )
+    .await?;
+    let frm_value = req
         .frm_configs
-        .ok_or_else(|| errors::ApiErrorResponse::ConfigNotFound)?;
-    let frm_value: serde_json::Value =
-        utils::Encode::<api_models::admin::FrmConfigs>::encode_to_value(&configs_for_frm_value)
-            .change_context(errors::ApiErrorResponse::ConfigNotFound)?;
+        .as_ref()
+        .map(utils::Encode::<api_models::admin::FrmConfigs>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while encoding to serde_json::Value, FrmConfigs")?;
+    let (key_id, encrypted_connector_account_details) =
+        encrypt_connector_account_details(state, req.connector_account_details.as_ref()).await?;
 
     let merchant_connector_account = storage::MerchantConnectorAccountNew {
         merchant_id: Some(merchant_id.to_string()),
         connector_type: Some(req.connector_type.foreign_into()),
         connector_name: Some(req.connector_name),
         merchant_connector_id: utils::generate_id(consts::ID_LENGTH, "mca"),
-        connector_account_details: req.connector_account_details,
+        connector_account_details: encrypted_connector_account_details
+            .as_ref()
+            .map(|_| Secret::new(serde_json::Value::Null)),
         payment_methods_enabled,
         test_mode: req.test_mode,
         disabled: req.disabled,
         metadata: req.metadata,
-        frm_configs: Some(frm_value),
+        frm_configs: frm_value,
+        key_id,
+        encrypted_connector_account_details,
     };
 
     let mca = store
@@ -341,10 +471,11 @@ pub async fn create_payment_connector(
 }
 
 pub async fn retrieve_payment_connector(
-    store: &dyn StorageInterface,
+    state: &AppState,
     merchant_id: String,
     merchant_connector_id: String,
 ) -> RouterResponse<api::MerchantConnector> {
+    let store = &*state.store;
     let _merchant_account = store
         .find_merchant_account_by_merchant_id(&merchant_id)
         .await
@@ -363,14 +494,15 @@ pub async fn retrieve_payment_connector(
         })?;
 
     Ok(service_api::ApplicationResponse::Json(
-        mca.foreign_try_into()?,
+        get_payment_connector_response(state, mca).await?,
     ))
 }
 
 pub async fn list_payment_connectors(
-    store: &dyn StorageInterface,
+    state: &AppState,
     merchant_id: String,
 ) -> RouterResponse<Vec<api::MerchantConnector>> {
+    let store = &*state.store;
     // Validate merchant account
     store
         .find_merchant_account_by_merchant_id(&merchant_id)
@@ -389,18 +521,19 @@ pub async fn list_payment_connectors(
 
     // The can be eliminated once [#79711](https://github.com/rust-lang/rust/issues/79711) is stabilized
     for mca in merchant_connector_accounts.into_iter() {
-        response.push(mca.foreign_try_into()?);
+        response.push(get_payment_connector_response(state, mca).await?);
     }
 
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn update_payment_connector(
-    db: &dyn StorageInterface,
+    state: &AppState,
     merchant_id: &str,
     merchant_connector_id: &str,
     req: api::MerchantConnector,
 ) -> RouterResponse<api::MerchantConnector> {
+    let db = &*state.store;
     let _merchant_account = db
         .find_merchant_account_by_merchant_id(merchant_id)
         .await
@@ -418,32 +551,61 @@ pub async fn update_payment_connector(
             error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
         })?;
 
-    let payment_methods_enabled = req.payment_methods_enabled.map(|pm_enabled| {
-        pm_enabled
-            .iter()
-            .flat_map(|payment_method| {
-                utils::Encode::<api::PaymentMethodsEnabled>::encode_to_value(payment_method)
-            })
-            .collect::<Vec<serde_json::Value>>()
-    });
-    let configs_for_frm_value = req
+    let payment_methods_enabled = req
+        .payment_methods_enabled
+        .map(|pm_enabled| {
+            pm_enabled
+                .iter()
+                .map(utils::Encode::<api::PaymentMethodsEnabled>::encode_to_value)
+                .collect::<Result<Vec<serde_json::Value>, _>>()
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while encoding to serde_json::Value, PaymentMethod")?;
+
+    // Credentials are verified only when they are being changed
+    if let Some(connector_account_details) = req.connector_account_details.clone() {
+        let auth_type: types::ConnectorAuthType = connector_account_details
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "connector_account_details".to_string(),
+                expected_format: "auth_type and api_key".to_string(),
+            })?;
+        verify_connector_credentials(
+            state,
+            merchant_id,
+            &mca.connector_name,
+            mca.connector_type,
+            auth_type,
+            req.metadata.clone().or_else(|| mca.metadata.clone()),
+        )
+        .await?;
+    }
+
+    let frm_value = req
         .frm_configs
         .as_ref()
-        .ok_or_else(|| errors::ApiErrorResponse::ConfigNotFound)?;
-    let frm_value: serde_json::Value =
-        utils::Encode::<api_models::admin::FrmConfigs>::encode_to_value(&configs_for_frm_value)
-            .change_context(errors::ApiErrorResponse::ConfigNotFound)?;
+        .map(utils::Encode::<api_models::admin::FrmConfigs>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while encoding to serde_json::Value, FrmConfigs")?;
+    let (key_id, encrypted_connector_account_details) =
+        encrypt_connector_account_details(state, req.connector_account_details.as_ref()).await?;
     let payment_connector = storage::MerchantConnectorAccountUpdate::Update {
         merchant_id: Some(merchant_id.to_string()),
         connector_type: Some(req.connector_type.foreign_into()),
         connector_name: Some(req.connector_name),
         merchant_connector_id: Some(merchant_connector_id.to_string()),
-        connector_account_details: req.connector_account_details,
+        connector_account_details: encrypted_connector_account_details
+            .as_ref()
+            .map(|_| Secret::new(serde_json::Value::Null)),
         payment_methods_enabled,
         test_mode: req.test_mode,
         disabled: req.disabled,
         metadata: req.metadata,
-        frm_configs: Some(frm_value),
+        frm_configs: frm_value,
+        key_id,
+        encrypted_connector_account_details,
     };
 
     let updated_mca = db
@@ -464,12 +626,14 @@ pub async fn update_payment_connector(
             })
             .collect::<Vec<api_models::admin::PaymentMethodsEnabled>>()
     });
+    let connector_account_details =
+        secrets::decrypt_connector_account_details(state, &updated_mca).await?;
 
     let response = api::MerchantConnector {
         connector_type: updated_mca.connector_type.foreign_into(),
         connector_name: updated_mca.connector_name,
         merchant_connector_id: Some(updated_mca.merchant_connector_id),
-        connector_account_details: Some(Secret::new(updated_mca.connector_account_details)),
+        connector_account_details: Some(Secret::new(connector_account_details)),
         test_mode: updated_mca.test_mode,
         disabled: updated_mca.disabled,
         payment_methods_enabled: updated_pm_enabled,
@@ -508,6 +672,62 @@ pub async fn delete_payment_connector(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+pub async fn toggle_payment_connector(
+    state: &AppState,
+    merchant_id: String,
+    merchant_connector_id: String,
+    req: api::ToggleMerchantConnectorRequest,
+) -> RouterResponse<api::MerchantConnector> {
+    let db = &*state.store;
+    let _merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    let mca = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &merchant_id,
+            &merchant_connector_id,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
+        })?;
+
+    let updated_mca = if mca.disabled == Some(req.disabled) {
+        mca
+    } else {
+        db.update_merchant_connector_account(
+            mca,
+            storage::MerchantConnectorAccountUpdate::Update {
+                merchant_id: None,
+                connector_type: None,
+                connector_name: None,
+                connector_account_details: None,
+                test_mode: None,
+                disabled: Some(req.disabled),
+                merchant_connector_id: None,
+                payment_methods_enabled: None,
+                metadata: None,
+                frm_configs: None,
+                key_id: None,
+                encrypted_connector_account_details: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed while updating MerchantConnectorAccount: id: {merchant_connector_id}")
+        })?
+    };
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_payment_connector_response(state, updated_mca).await?,
+    ))
+}
+
 pub async fn kv_for_merchant(
     db: &dyn StorageInterface,
     merchant_id: String,
@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use common_utils::date_time;
 use error_stack::{report, IntoReport, ResultExt};
 #[cfg(feature = "kms")]
@@ -122,6 +124,49 @@ pub async fn create_api_key(
     #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
     api_key: api::CreateApiKeyRequest,
     merchant_id: String,
+) -> RouterResponse<api::CreateApiKeyResponse> {
+    insert_api_key(
+        store,
+        api_key_config,
+        #[cfg(feature = "kms")]
+        kms_config,
+        api_key,
+        Some(merchant_id),
+        None,
+    )
+    .await
+}
+
+/// Creates an API key of an organization, which can act on any merchant account of the
+/// organization.
+#[instrument(skip_all)]
+pub async fn create_organization_api_key(
+    store: &dyn StorageInterface,
+    api_key_config: &settings::ApiKeys,
+    #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+    api_key: api::CreateApiKeyRequest,
+    organization_id: String,
+) -> RouterResponse<api::CreateApiKeyResponse> {
+    insert_api_key(
+        store,
+        api_key_config,
+        #[cfg(feature = "kms")]
+        kms_config,
+        api_key,
+        None,
+        Some(organization_id),
+    )
+    .await
+}
+
+/// An API key is owned by either a merchant account or an organization.
+async fn insert_api_key(
+    store: &dyn StorageInterface,
+    api_key_config: &settings::ApiKeys,
+    #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+    api_key: api::CreateApiKeyRequest,
+    merchant_id: Option<String>,
+    organization_id: Option<String>,
 ) -> RouterResponse<api::CreateApiKeyResponse> {
     let hash_key = get_hash_key(
         api_key_config,
@@ -140,6 +185,8 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        scopes: api_key.scopes.and_then(get_stored_api_key_scopes),
+        organization_id,
     };
 
     let api_key = store
@@ -155,17 +202,44 @@ pub async fn create_api_key(
     ))
 }
 
-#[instrument(skip_all)]
-pub async fn retrieve_api_key(
+/// Fetches an API key of the merchant. API keys of other merchants are treated as not found.
+async fn find_merchant_api_key(
     store: &dyn StorageInterface,
+    merchant_id: &str,
     key_id: &str,
-) -> RouterResponse<api::RetrieveApiKeyResponse> {
-    let api_key = store
+) -> errors::RouterResult<storage::ApiKey> {
+    store
+        .find_api_key_by_key_id_optional(key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .filter(|api_key| api_key.merchant_id.as_deref() == Some(merchant_id))
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound)) // If retrieve returned `None`
+}
+
+/// Fetches an API key of the organization. API keys of merchant accounts and of other
+/// organizations are treated as not found.
+async fn find_organization_api_key(
+    store: &dyn StorageInterface,
+    organization_id: &str,
+    key_id: &str,
+) -> errors::RouterResult<storage::ApiKey> {
+    store
         .find_api_key_by_key_id_optional(key_id)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
-        .attach_printable("Failed to retrieve new API key")?
-        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?; // If retrieve returned `None`
+        .attach_printable("Failed to retrieve API key")?
+        .filter(|api_key| api_key.organization_id.as_deref() == Some(organization_id))
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound)) // If retrieve returned `None`
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_api_key(
+    store: &dyn StorageInterface,
+    merchant_id: &str,
+    key_id: &str,
+) -> RouterResponse<api::RetrieveApiKeyResponse> {
+    let api_key = find_merchant_api_key(store, merchant_id, key_id).await?;
 
     Ok(ApplicationResponse::Json(api_key.foreign_into()))
 }
@@ -173,9 +247,11 @@ pub async fn retrieve_api_key(
 #[instrument(skip_all)]
 pub async fn update_api_key(
     store: &dyn StorageInterface,
+    merchant_id: &str,
     key_id: &str,
     api_key: api::UpdateApiKeyRequest,
 ) -> RouterResponse<api::RetrieveApiKeyResponse> {
+    find_merchant_api_key(store, merchant_id, key_id).await?;
     let api_key = store
         .update_api_key(key_id.to_owned(), api_key.foreign_into())
         .await
@@ -184,8 +260,62 @@ pub async fn update_api_key(
     Ok(ApplicationResponse::Json(api_key.foreign_into()))
 }
 
+#[instrument(skip_all)]
+pub async fn rotate_api_key(
+    store: &dyn StorageInterface,
+    api_key_config: &settings::ApiKeys,
+    #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+    merchant_id: &str,
+    key_id: &str,
+) -> RouterResponse<api::CreateApiKeyResponse> {
+    find_merchant_api_key(store, merchant_id, key_id).await?;
+    let hash_key = get_hash_key(
+        api_key_config,
+        #[cfg(feature = "kms")]
+        kms_config,
+    )
+    .await?;
+    let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
+    let api_key_update = storage::ApiKeyUpdate::RotateUpdate {
+        hashed_api_key: plaintext_api_key.keyed_hash(hash_key.peek()).into(),
+        prefix: plaintext_api_key.prefix(),
+    };
+
+    // The key ID, name, expiry and scopes of the API key are retained, while the previous
+    // plaintext API key stops authenticating requests right away.
+    let api_key = store
+        .update_api_key(key_id.to_owned(), api_key_update)
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound))?;
+
+    metrics::API_KEY_ROTATED.add(&metrics::CONTEXT, 1, &[]);
+
+    Ok(ApplicationResponse::Json(
+        (api_key, plaintext_api_key).foreign_into(),
+    ))
+}
+
 #[instrument(skip_all)]
 pub async fn revoke_api_key(
+    store: &dyn StorageInterface,
+    merchant_id: &str,
+    key_id: &str,
+) -> RouterResponse<api::RevokeApiKeyResponse> {
+    find_merchant_api_key(store, merchant_id, key_id).await?;
+    revoke_stored_api_key(store, key_id).await
+}
+
+#[instrument(skip_all)]
+pub async fn revoke_organization_api_key(
+    store: &dyn StorageInterface,
+    organization_id: &str,
+    key_id: &str,
+) -> RouterResponse<api::RevokeApiKeyResponse> {
+    find_organization_api_key(store, organization_id, key_id).await?;
+    revoke_stored_api_key(store, key_id).await
+}
+
+async fn revoke_stored_api_key(
     store: &dyn StorageInterface,
     key_id: &str,
 ) -> RouterResponse<api::RevokeApiKeyResponse> {
@@ -222,6 +352,57 @@ pub async fn list_api_keys(
     Ok(ApplicationResponse::Json(api_keys))
 }
 
+#[instrument(skip_all)]
+pub async fn list_organization_api_keys(
+    store: &dyn StorageInterface,
+    organization_id: String,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> RouterResponse<Vec<api::RetrieveApiKeyResponse>> {
+    let api_keys = store
+        .list_api_keys_by_organization_id(&organization_id, limit, offset)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list organization API keys")?;
+    let api_keys = api_keys
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(ApplicationResponse::Json(api_keys))
+}
+
+/// Converts the scopes of an API key request to the form they are stored in. An empty list of
+/// scopes leaves the API key unrestricted.
+pub fn get_stored_api_key_scopes(scopes: Vec<api::ApiKeyScope>) -> Option<Vec<String>> {
+    (!scopes.is_empty()).then(|| scopes.iter().map(ToString::to_string).collect())
+}
+
+pub fn get_api_key_scopes(scopes: Option<Vec<String>>) -> Option<Vec<api::ApiKeyScope>> {
+    scopes.map(|scopes| {
+        scopes
+            .iter()
+            .filter_map(|scope| api::ApiKeyScope::from_str(scope).ok())
+            .collect()
+    })
+}
+
+/// The scope reported when a scoped API key is used on a route which is not restricted to a
+/// scope.
+pub const UNRESTRICTED_SCOPE: &str = "unrestricted";
+
+/// An API key without scopes has access to all APIs.
+pub fn is_scope_allowed(api_key: &storage::ApiKey, scope: api::ApiKeyScope) -> bool {
+    api_key.scopes.as_ref().map_or(true, |scopes| {
+        scopes.iter().any(|allowed| *allowed == scope.to_string())
+    })
+}
+
+/// Routes which are not restricted to a scope can only be accessed by API keys without scopes.
+pub fn is_unscoped(api_key: &storage::ApiKey) -> bool {
+    api_key.scopes.is_none()
+}
+
 impl From<&str> for PlaintextApiKey {
     fn from(s: &str) -> Self {
         Self(s.to_owned().into())
@@ -273,4 +454,46 @@ mod tests {
         let new_hashed_api_key = plaintext_api_key.keyed_hash(hash_key.peek());
         assert_eq!(hashed_api_key, new_hashed_api_key)
     }
+    #[test]
+    fn test_api_key_scopes() {
+        let stored_scopes = get_stored_api_key_scopes(vec![
+            api::ApiKeyScope::PaymentsRead,
+            api::ApiKeyScope::RefundsWrite,
+        ]);
+        assert_eq!(
+            stored_scopes,
+            Some(vec![
+                "payments:read".to_string(),
+                "refunds:write".to_string()
+            ])
+        );
+        assert_eq!(get_stored_api_key_scopes(vec![]), None);
+
+        let api_key = storage::ApiKey {
+            key_id: PlaintextApiKey::new_key_id(),
+            merchant_id: Some("merchant".to_string()),
+            name: "key".to_string(),
+            description: None,
+            hashed_api_key: String::new().into(),
+            prefix: String::new(),
+            created_at: date_time::now(),
+            expires_at: None,
+            last_used: None,
+            scopes: stored_scopes,
+            organization_id: None,
+        };
+        assert!(is_scope_allowed(&api_key, api::ApiKeyScope::PaymentsRead));
+        assert!(!is_scope_allowed(&api_key, api::ApiKeyScope::PaymentsWrite));
+        assert!(!is_unscoped(&api_key));
+
+        let unrestricted_api_key = storage::ApiKey {
+            scopes: None,
+            ..api_key
+        };
+        assert!(is_scope_allowed(
+            &unrestricted_api_key,
+            api::ApiKeyScope::PaymentsWrite
+        ));
+        assert!(is_unscoped(&unrestricted_api_key));
+    }
 }
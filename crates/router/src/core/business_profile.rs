@@ -0,0 +1,178 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::helpers,
+        routing,
+    },
+    db::StorageInterface,
+    services::ApplicationResponse,
+    types::{api, storage, transformers::ForeignInto},
+    utils::{self, Encode},
+};
+
+async fn validate_merchant_id(db: &dyn StorageInterface, merchant_id: &str) -> RouterResult<()> {
+    db.find_merchant_account_by_merchant_id(merchant_id)
+        .await
+        .map(|_| ())
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })
+}
+
+fn validate_routing_algorithm(routing_algorithm: Option<&serde_json::Value>) -> RouterResult<()> {
+    if let Some(routing_algorithm) = routing_algorithm {
+        let routing_algorithm: api::RoutingAlgorithm = routing_algorithm
+            .clone()
+            .parse_value("RoutingAlgorithm")
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "routing_algorithm",
+            })
+            .attach_printable("Invalid routing algorithm given")?;
+        routing::validate_routing_algorithm(&routing_algorithm)?;
+    }
+
+    Ok(())
+}
+
+fn encode_webhook_details(
+    webhook_details: Option<&api::WebhookDetails>,
+) -> RouterResult<Option<serde_json::Value>> {
+    webhook_details
+        .map(Encode::<api::WebhookDetails>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "webhook details",
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn create_business_profile(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    req: api::BusinessProfileCreate,
+) -> RouterResponse<api::BusinessProfileResponse> {
+    validate_merchant_id(db, merchant_id).await?;
+    validate_routing_algorithm(req.routing_algorithm.as_ref())?;
+
+    let business_profile = storage::BusinessProfileNew {
+        profile_id: utils::generate_id(consts::ID_LENGTH, "pro"),
+        merchant_id: merchant_id.to_owned(),
+        profile_name: req.profile_name,
+        return_url: req.return_url.map(|return_url| return_url.to_string()),
+        webhook_details: encode_webhook_details(req.webhook_details.as_ref())?,
+        routing_algorithm: req.routing_algorithm,
+        statement_descriptor_name: req.statement_descriptor_name,
+        statement_descriptor_suffix: req.statement_descriptor_suffix,
+        auto_capture_delay: req
+            .auto_capture_delay
+            .map(helpers::validate_auto_capture_delay)
+            .transpose()?,
+    };
+
+    let business_profile = db
+        .insert_business_profile(business_profile)
+        .await
+        .map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicateBusinessProfile)
+        })?;
+
+    Ok(ApplicationResponse::Json(business_profile.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn list_business_profiles(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResponse<Vec<api::BusinessProfileResponse>> {
+    validate_merchant_id(db, merchant_id).await?;
+    let business_profiles = db
+        .list_business_profiles_by_merchant_id(merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list business profiles of the merchant")?;
+
+    Ok(ApplicationResponse::Json(
+        business_profiles
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_business_profile(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    profile_id: &str,
+) -> RouterResponse<api::BusinessProfileResponse> {
+    let business_profile = helpers::get_business_profile(db, profile_id, merchant_id).await?;
+
+    Ok(ApplicationResponse::Json(business_profile.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn update_business_profile(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    profile_id: &str,
+    req: api::BusinessProfileUpdate,
+) -> RouterResponse<api::BusinessProfileResponse> {
+    validate_routing_algorithm(req.routing_algorithm.as_ref())?;
+
+    let business_profile_update = storage::BusinessProfileUpdate::Update {
+        profile_name: req.profile_name,
+        return_url: req.return_url.map(|return_url| return_url.to_string()),
+        webhook_details: encode_webhook_details(req.webhook_details.as_ref())?,
+        routing_algorithm: req.routing_algorithm,
+        statement_descriptor_name: req.statement_descriptor_name,
+        statement_descriptor_suffix: req.statement_descriptor_suffix,
+        auto_capture_delay: req
+            .auto_capture_delay
+            .map(helpers::validate_auto_capture_delay)
+            .transpose()?,
+    };
+
+    let business_profile = db
+        .update_business_profile_by_profile_id_merchant_id(
+            profile_id,
+            merchant_id,
+            business_profile_update,
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_unique_violation() {
+                error.change_context(errors::ApiErrorResponse::DuplicateBusinessProfile)
+            } else {
+                error.to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound)
+            }
+        })?;
+
+    Ok(ApplicationResponse::Json(business_profile.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_business_profile(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    profile_id: &str,
+) -> RouterResponse<api::BusinessProfileDeleteResponse> {
+    let is_deleted = db
+        .delete_business_profile_by_profile_id_merchant_id(profile_id, merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound)
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        api::BusinessProfileDeleteResponse {
+            profile_id: profile_id.to_owned(),
+            merchant_id: merchant_id.to_owned(),
+            deleted: is_deleted,
+        },
+    ))
+}
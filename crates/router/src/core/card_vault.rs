@@ -0,0 +1,474 @@
+pub mod network_token;
+
+use common_utils::date_time;
+use error_stack::{report, IntoReport, ResultExt};
+#[cfg(feature = "kms")]
+use external_services::kms;
+use masking::{PeekInterface, StrongSecret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings,
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::AppState,
+    services::{encryption, ApplicationResponse},
+    types::{api, storage},
+    utils::{self, Encode, StringExt},
+};
+
+const KEY_LENGTH: usize = 32;
+const VAULT_TOKEN_PREFIX: &str = "vault_tok";
+
+static MASTER_KEY: tokio::sync::OnceCell<StrongSecret<[u8; KEY_LENGTH]>> =
+    tokio::sync::OnceCell::const_new();
+
+async fn get_master_key(
+    card_vault_config: &settings::CardVault,
+    #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+) -> RouterResult<&'static StrongSecret<[u8; KEY_LENGTH]>> {
+    MASTER_KEY
+        .get_or_try_init(|| async {
+            #[cfg(feature = "kms")]
+            let master_key = kms::get_kms_client(kms_config)
+                .await
+                .decrypt(&card_vault_config.kms_encrypted_master_key)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to KMS decrypt card vault master key")?;
+
+            #[cfg(not(feature = "kms"))]
+            let master_key = &card_vault_config.master_key;
+
+            decode_key(master_key)
+                .attach_printable("Invalid card vault master key")
+                .map(StrongSecret::new)
+        })
+        .await
+}
+
+fn decode_key(hex_encoded_key: &str) -> RouterResult<[u8; KEY_LENGTH]> {
+    <[u8; KEY_LENGTH]>::try_from(
+        hex::decode(hex_encoded_key)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Key has invalid hexadecimal data")?
+            .as_slice(),
+    )
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Key has incorrect length")
+}
+
+/// Tokens issued by the card vault are distinguished from the ones issued by the temporary
+/// locker by their prefix.
+pub fn is_vault_token(token: &str) -> bool {
+    token.starts_with(VAULT_TOKEN_PREFIX)
+}
+
+fn decrypt_data_key(
+    master_key: &StrongSecret<[u8; KEY_LENGTH]>,
+    data_key: &storage::VaultDataKey,
+) -> RouterResult<StrongSecret<[u8; KEY_LENGTH]>> {
+    let data_key = encryption::decrypt(data_key.encrypted_key.clone(), master_key.peek())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt card vault data key")?;
+
+    decode_key(&data_key).map(StrongSecret::new)
+}
+
+/// Returns the data key for encrypting cards, generating a new data key if the current one is
+/// due for rotation.
+async fn get_active_data_key(
+    state: &AppState,
+    master_key: &StrongSecret<[u8; KEY_LENGTH]>,
+) -> RouterResult<(String, StrongSecret<[u8; KEY_LENGTH]>)> {
+    let db = &*state.store;
+    let rotation_threshold = date_time::now().saturating_sub(time::Duration::seconds(
+        state.conf.card_vault.data_key_rotation_interval,
+    ));
+
+    if let Some(data_key) = db
+        .find_latest_vault_data_key_created_after(rotation_threshold)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch card vault data key")?
+    {
+        return Ok((
+            data_key.key_id.clone(),
+            decrypt_data_key(master_key, &data_key)?,
+        ));
+    }
+
+    generate_data_key(state, master_key).await
+}
+
+/// Generates a data key and stores it encrypted with the master key. Being the latest data key,
+/// it becomes the active data key.
+async fn generate_data_key(
+    state: &AppState,
+    master_key: &StrongSecret<[u8; KEY_LENGTH]>,
+) -> RouterResult<(String, StrongSecret<[u8; KEY_LENGTH]>)> {
+    let db = &*state.store;
+    let data_key: [u8; KEY_LENGTH] = rand::random();
+    let encrypted_key = encryption::encrypt(&hex::encode(data_key), master_key.peek())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt card vault data key")?;
+
+    let data_key_record = db
+        .insert_vault_data_key(storage::VaultDataKeyNew {
+            key_id: utils::generate_id(consts::ID_LENGTH, "dek"),
+            encrypted_key,
+            created_at: date_time::now(),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert card vault data key")?;
+
+    Ok((data_key_record.key_id, StrongSecret::new(data_key)))
+}
+
+fn validate_card(card: &api::CardDetail) -> RouterResult<()> {
+    let card_number = card.card_number.peek();
+    if !(12..=19).contains(&card_number.len()) || !card_number.chars().all(|c| c.is_ascii_digit()) {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "card_number"
+        }));
+    }
+
+    if card.card_exp_month.peek().is_empty() || card.card_exp_month.peek().len() > 2 {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "card_exp_month"
+        }));
+    }
+
+    if !matches!(card.card_exp_year.peek().len(), 2 | 4) {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "card_exp_year"
+        }));
+    }
+
+    Ok(())
+}
+
+/// Encrypts the card with the active data key and stores it in the vault, against a newly
+/// generated payment token.
+#[instrument(skip_all)]
+pub async fn store_card_in_vault(
+    state: &AppState,
+    merchant_id: &str,
+    customer_id: Option<String>,
+    card: &api::CardDetail,
+) -> RouterResult<storage::VaultedCard> {
+    validate_card(card)?;
+
+    let master_key = get_master_key(
+        &state.conf.card_vault,
+        #[cfg(feature = "kms")]
+        &state.conf.kms,
+    )
+    .await?;
+    let (key_id, data_key) = get_active_data_key(state, master_key).await?;
+
+    let card_data = Encode::<api::CardDetail>::encode_to_string_of_json(card)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode card")?;
+    let encrypted_card = encryption::encrypt(&card_data, data_key.peek())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt card")?;
+
+    let network_token = network_token::provision_network_token(state, card).await;
+    let token_service_provider = network_token
+        .as_ref()
+        .map(|network_token| network_token.token_service_provider.to_string());
+    let encrypted_network_token = network_token
+        .map(|network_token| {
+            Encode::<network_token::NetworkTokenDetails>::encode_to_string_of_json(&network_token)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode network token")
+                .and_then(|network_token_data| {
+                    encryption::encrypt(&network_token_data, data_key.peek())
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to encrypt network token")
+                })
+        })
+        .transpose()?;
+
+    let card_number = card.card_number.peek();
+    let card_last_four = card_number
+        .get(card_number.len().saturating_sub(4)..)
+        .unwrap_or_default()
+        .to_string();
+
+    state
+        .store
+        .insert_vaulted_card(storage::VaultedCardNew {
+            payment_token: utils::generate_id(consts::ID_LENGTH, VAULT_TOKEN_PREFIX),
+            merchant_id: merchant_id.to_string(),
+            customer_id,
+            key_id,
+            encrypted_card,
+            card_last_four,
+            card_exp_month: card.card_exp_month.peek().to_string(),
+            card_exp_year: card.card_exp_year.peek().to_string(),
+            created_at: date_time::now(),
+            token_service_provider,
+            encrypted_network_token,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert vaulted card")
+}
+
+/// A card decrypted from the vault, along with its network token if one was provisioned.
+pub struct DecryptedVaultedCard {
+    pub vaulted_card: storage::VaultedCard,
+    pub card: api::CardDetail,
+    pub network_token: Option<network_token::NetworkTokenDetails>,
+}
+
+/// Decrypts the card stored in the vault against the payment token of the merchant.
+#[instrument(skip_all)]
+pub async fn get_card_from_vault(
+    state: &AppState,
+    merchant_id: &str,
+    payment_token: &str,
+) -> RouterResult<DecryptedVaultedCard> {
+    let db = &*state.store;
+    let vaulted_card = db
+        .find_vaulted_card_by_merchant_id_payment_token(merchant_id, payment_token)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+
+    let master_key = get_master_key(
+        &state.conf.card_vault,
+        #[cfg(feature = "kms")]
+        &state.conf.kms,
+    )
+    .await?;
+    let data_key = db
+        .find_vault_data_key_by_key_id(&vaulted_card.key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the data key of vaulted card")?;
+    let data_key = decrypt_data_key(master_key, &data_key)?;
+
+    let card = encryption::decrypt(vaulted_card.encrypted_card.clone(), data_key.peek())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt vaulted card")?
+        .parse_struct("CardDetail")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse vaulted card")?;
+
+    let network_token = vaulted_card
+        .encrypted_network_token
+        .clone()
+        .map(|encrypted_network_token| {
+            encryption::decrypt(encrypted_network_token, data_key.peek())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to decrypt network token of vaulted card")?
+                .parse_struct("NetworkTokenDetails")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse network token of vaulted card")
+        })
+        .transpose()?;
+
+    Ok(DecryptedVaultedCard {
+        vaulted_card,
+        card,
+        network_token,
+    })
+}
+
+/// Encrypts other sensitive data, such as the bank accounts payouts are made to, with the active
+/// data key of the vault. Returns the identifier of the data key along with the encrypted data.
+#[instrument(skip_all)]
+pub async fn encrypt_with_active_data_key(
+    state: &AppState,
+    data: &str,
+) -> RouterResult<(String, Vec<u8>)> {
+    let master_key = get_master_key(
+        &state.conf.card_vault,
+        #[cfg(feature = "kms")]
+        &state.conf.kms,
+    )
+    .await?;
+    let (key_id, data_key) = get_active_data_key(state, master_key).await?;
+
+    let encrypted_data = encryption::encrypt(&data.to_string(), data_key.peek())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt data")?;
+
+    Ok((key_id, encrypted_data))
+}
+
+/// Rotates the active data key of the vault ahead of its rotation interval. Data encrypted with
+/// the earlier data keys can still be decrypted. Returns the identifier of the new data key.
+#[instrument(skip_all)]
+pub async fn rotate_data_key(state: &AppState) -> RouterResult<String> {
+    let master_key = get_master_key(
+        &state.conf.card_vault,
+        #[cfg(feature = "kms")]
+        &state.conf.kms,
+    )
+    .await?;
+
+    generate_data_key(state, master_key)
+        .await
+        .map(|(key_id, _)| key_id)
+}
+
+async fn get_data_key(
+    state: &AppState,
+    key_id: &str,
+) -> RouterResult<StrongSecret<[u8; KEY_LENGTH]>> {
+    let master_key = get_master_key(
+        &state.conf.card_vault,
+        #[cfg(feature = "kms")]
+        &state.conf.kms,
+    )
+    .await?;
+    let data_key = state
+        .store
+        .find_vault_data_key_by_key_id(key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch card vault data key")?;
+
+    decrypt_data_key(master_key, &data_key)
+}
+
+/// Encrypts data with the data key of the vault identified by `key_id`.
+#[instrument(skip_all)]
+pub async fn encrypt_with_data_key(
+    state: &AppState,
+    key_id: &str,
+    data: &str,
+) -> RouterResult<Vec<u8>> {
+    let data_key = get_data_key(state, key_id).await?;
+
+    encryption::encrypt(&data.to_string(), data_key.peek())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt data")
+}
+
+/// Decrypts data encrypted by [`encrypt_with_active_data_key`] or [`encrypt_with_data_key`].
+#[instrument(skip_all)]
+pub async fn decrypt_with_data_key(
+    state: &AppState,
+    key_id: &str,
+    encrypted_data: Vec<u8>,
+) -> RouterResult<String> {
+    let data_key = get_data_key(state, key_id).await?;
+
+    encryption::decrypt(encrypted_data, data_key.peek())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt data")
+}
+
+fn make_vault_token_response(vaulted_card: storage::VaultedCard) -> api::VaultTokenResponse {
+    api::VaultTokenResponse {
+        payment_token: vaulted_card.payment_token,
+        merchant_id: vaulted_card.merchant_id,
+        customer_id: vaulted_card.customer_id,
+        card_last_four: vaulted_card.card_last_four,
+        card_exp_month: vaulted_card.card_exp_month,
+        card_exp_year: vaulted_card.card_exp_year,
+        created_at: vaulted_card.created_at,
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn create_vault_token(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::VaultTokenCreateRequest,
+) -> RouterResponse<api::VaultTokenResponse> {
+    let vaulted_card = store_card_in_vault(
+        state,
+        &merchant_account.merchant_id,
+        req.customer_id,
+        &req.card,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(make_vault_token_response(
+        vaulted_card,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_vault_token(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payment_token: &str,
+) -> RouterResponse<api::VaultTokenResponse> {
+    let vaulted_card = state
+        .store
+        .find_vaulted_card_by_merchant_id_payment_token(
+            &merchant_account.merchant_id,
+            payment_token,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+
+    Ok(ApplicationResponse::Json(make_vault_token_response(
+        vaulted_card,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_vault_token(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payment_token: &str,
+) -> RouterResponse<api::VaultTokenDeleteResponse> {
+    let deleted = state
+        .store
+        .delete_vaulted_card_by_merchant_id_payment_token(
+            &merchant_account.merchant_id,
+            payment_token,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+
+    // The card can no longer be used as a saved payment method of the customer
+    match state
+        .store
+        .delete_payment_method_by_merchant_id_payment_method_id(
+            &merchant_account.merchant_id,
+            payment_token,
+        )
+        .await
+    {
+        Ok(_) => (),
+        Err(error) if error.current_context().is_db_not_found() => (),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete saved payment method of vaulted card")?,
+    }
+
+    Ok(ApplicationResponse::Json(api::VaultTokenDeleteResponse {
+        payment_token: payment_token.to_string(),
+        deleted,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_token_prefix() {
+        let token = utils::generate_id(consts::ID_LENGTH, VAULT_TOKEN_PREFIX);
+
+        assert!(is_vault_token(&token));
+        assert!(!is_vault_token("token_2Lg2RpL2BTCcZBS4R4xZ"));
+    }
+}
@@ -0,0 +1,462 @@
+use api_models::enums as api_enums;
+use common_utils::pii;
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    configs::settings,
+    core::errors::{self, CustomResult},
+    headers, logger,
+    routes::AppState,
+    services,
+    types::{self, api},
+    utils::{self, BytesExt},
+};
+
+/// A network token provisioned for a card stored in the vault. It is stored encrypted in the
+/// vault alongside the card.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkTokenDetails {
+    pub token_service_provider: api_enums::CardNetwork,
+    pub token_number: Secret<String, pii::CardNumber>,
+    pub token_exp_month: Secret<String>,
+    pub token_exp_year: Secret<String>,
+    /// Reference to the token with the token service, used for fetching cryptograms
+    pub token_reference_id: String,
+    /// Whether a cryptogram has to be sent along with the token when authorizing payments
+    pub cryptogram_required: bool,
+}
+
+/// The token service of a card network, which provisions network tokens in place of the card
+/// number and generates cryptograms for transactions made with the tokens.
+#[async_trait::async_trait]
+pub trait TokenServiceProvider: Send + Sync {
+    /// Name of the token service (in lowercase).
+    fn id(&self) -> &'static str;
+
+    /// Provisions a network token for the card.
+    async fn provision_token(
+        &self,
+        state: &AppState,
+        card: &api::CardDetail,
+    ) -> CustomResult<NetworkTokenDetails, errors::ConnectorError>;
+
+    /// Generates the cryptogram for a transaction made with the network token.
+    async fn get_cryptogram(
+        &self,
+        state: &AppState,
+        token_reference_id: &str,
+    ) -> CustomResult<Secret<String>, errors::ConnectorError>;
+}
+
+pub type BoxedTokenServiceProvider = Box<dyn TokenServiceProvider>;
+
+/// Identifies the card network from the leading digits of the card number, for the networks
+/// which offer a token service.
+pub fn get_card_network(card_number: &str) -> Option<api_enums::CardNetwork> {
+    let prefix = |length: usize| {
+        card_number
+            .get(..length)
+            .and_then(|prefix| prefix.parse::<u32>().ok())
+    };
+
+    if card_number.starts_with('4') {
+        Some(api_enums::CardNetwork::Visa)
+    } else if matches!(prefix(2), Some(51..=55)) || matches!(prefix(4), Some(2221..=2720)) {
+        Some(api_enums::CardNetwork::Mastercard)
+    } else {
+        None
+    }
+}
+
+fn get_token_service_provider(
+    config: &settings::NetworkTokenization,
+    card_network: &api_enums::CardNetwork,
+) -> Option<BoxedTokenServiceProvider> {
+    match card_network {
+        api_enums::CardNetwork::Visa => Some(Box::new(Vts {
+            config: config.visa.clone(),
+        })),
+        api_enums::CardNetwork::Mastercard => Some(Box::new(Mdes {
+            config: config.mastercard.clone(),
+        })),
+        _ => None,
+    }
+}
+
+/// Provisions a network token for the card, if its network offers a token service. The card is
+/// stored in the vault without a network token if provisioning fails, and the card number is
+/// used when authorizing payments instead.
+#[instrument(skip_all)]
+pub async fn provision_network_token(
+    state: &AppState,
+    card: &api::CardDetail,
+) -> Option<NetworkTokenDetails> {
+    let config = &state.conf.network_tokenization;
+    if !config.enabled {
+        return None;
+    }
+
+    let card_network = get_card_network(card.card_number.peek())?;
+    let token_service_provider = get_token_service_provider(config, &card_network)?;
+
+    token_service_provider
+        .provision_token(state, card)
+        .await
+        .map_err(|error| {
+            logger::error!(
+                token_service_provider = token_service_provider.id(),
+                network_token_provisioning_error=?error
+            )
+        })
+        .ok()
+}
+
+/// Returns the network token to be used for authorizing the payment, along with a fresh
+/// cryptogram if the token requires one. Returns `None` if the cryptogram could not be obtained,
+/// so that the payment falls back to the card number.
+#[instrument(skip_all)]
+pub async fn get_network_token_for_payment(
+    state: &AppState,
+    network_token: NetworkTokenDetails,
+) -> Option<types::NetworkTokenData> {
+    let config = &state.conf.network_tokenization;
+    if !config.enabled {
+        return None;
+    }
+
+    let cryptogram = if network_token.cryptogram_required {
+        let token_service_provider =
+            get_token_service_provider(config, &network_token.token_service_provider)?;
+        let cryptogram = token_service_provider
+            .get_cryptogram(state, &network_token.token_reference_id)
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    token_service_provider = token_service_provider.id(),
+                    network_token_cryptogram_error=?error
+                )
+            })
+            .ok()?;
+        Some(cryptogram)
+    } else {
+        None
+    };
+
+    Some(types::NetworkTokenData {
+        token_number: network_token.token_number,
+        token_exp_month: network_token.token_exp_month,
+        token_exp_year: network_token.token_exp_year,
+        cryptogram,
+        token_service_provider: network_token.token_service_provider,
+    })
+}
+
+async fn call_token_service<Req, Res>(
+    state: &AppState,
+    config: &settings::TokenServiceProvider,
+    path: &str,
+    request: &Req,
+    response_type_name: &str,
+) -> CustomResult<Res, errors::ConnectorError>
+where
+    Req: Serialize,
+    Res: serde::de::DeserializeOwned,
+{
+    let body = utils::Encode::<Req>::encode_to_string_of_json(request)
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(&format!("{}{path}", config.base_url))
+        .attach_default_headers()
+        .headers(vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                "application/json".to_string(),
+            ),
+            (headers::X_API_KEY.to_string(), config.api_key.clone()),
+        ])
+        .body(Some(body))
+        .build();
+
+    match services::call_connector_api(state, request)
+        .await
+        .change_context(errors::ConnectorError::ProcessingStepFailed(None))?
+    {
+        Ok(response) => response
+            .response
+            .parse_struct(response_type_name)
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed),
+        Err(error_response) => Err(errors::ConnectorError::ProcessingStepFailed(Some(
+            error_response.response,
+        )))
+        .into_report(),
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct VtsExpirationDate {
+    month: Secret<String>,
+    year: Secret<String>,
+}
+
+/// Visa Token Service
+struct Vts {
+    config: settings::TokenServiceProvider,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VtsPaymentInstrument {
+    account_number: Secret<String, pii::CardNumber>,
+    name_on_card: Option<Secret<String>>,
+    expiration_date: VtsExpirationDate,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VtsProvisionRequest {
+    payment_instrument: VtsPaymentInstrument,
+    presentation_type: Vec<&'static str>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VtsTokenInfo {
+    token: Secret<String, pii::CardNumber>,
+    expiration_date: VtsExpirationDate,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VtsProvisionResponse {
+    #[serde(rename = "vProvisionedTokenID")]
+    provisioned_token_id: String,
+    token_info: VtsTokenInfo,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VtsCryptogramRequest {
+    #[serde(rename = "vProvisionedTokenID")]
+    provisioned_token_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VtsCryptogramInfo {
+    cryptogram: Secret<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VtsCryptogramResponse {
+    cryptogram_info: VtsCryptogramInfo,
+}
+
+#[async_trait::async_trait]
+impl TokenServiceProvider for Vts {
+    fn id(&self) -> &'static str {
+        "vts"
+    }
+
+    async fn provision_token(
+        &self,
+        state: &AppState,
+        card: &api::CardDetail,
+    ) -> CustomResult<NetworkTokenDetails, errors::ConnectorError> {
+        let request = VtsProvisionRequest {
+            payment_instrument: VtsPaymentInstrument {
+                account_number: card.card_number.clone(),
+                name_on_card: card.card_holder_name.clone(),
+                expiration_date: VtsExpirationDate {
+                    month: card.card_exp_month.clone(),
+                    year: card.card_exp_year.clone(),
+                },
+            },
+            presentation_type: vec!["ECOM"],
+        };
+        let response: VtsProvisionResponse = call_token_service(
+            state,
+            &self.config,
+            "/provisionedTokens",
+            &request,
+            "VtsProvisionResponse",
+        )
+        .await?;
+
+        Ok(NetworkTokenDetails {
+            token_service_provider: api_enums::CardNetwork::Visa,
+            token_number: response.token_info.token,
+            token_exp_month: response.token_info.expiration_date.month,
+            token_exp_year: response.token_info.expiration_date.year,
+            token_reference_id: response.provisioned_token_id,
+            // Visa requires a TAVV cryptogram for every transaction made with a token
+            cryptogram_required: true,
+        })
+    }
+
+    async fn get_cryptogram(
+        &self,
+        state: &AppState,
+        token_reference_id: &str,
+    ) -> CustomResult<Secret<String>, errors::ConnectorError> {
+        let request = VtsCryptogramRequest {
+            provisioned_token_id: token_reference_id.to_string(),
+        };
+        let response: VtsCryptogramResponse = call_token_service(
+            state,
+            &self.config,
+            &format!("/provisionedTokens/{token_reference_id}/payment"),
+            &request,
+            "VtsCryptogramResponse",
+        )
+        .await?;
+
+        Ok(response.cryptogram_info.cryptogram)
+    }
+}
+
+/// Mastercard Digital Enablement Service
+struct Mdes {
+    config: settings::TokenServiceProvider,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MdesCardInfo {
+    account_number: Secret<String, pii::CardNumber>,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    cardholder_name: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MdesFundingAccountInfo {
+    card_info: MdesCardInfo,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MdesTokenizeRequest {
+    token_type: &'static str,
+    funding_account_info: MdesFundingAccountInfo,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MdesTokenInfo {
+    token_pan: Secret<String, pii::CardNumber>,
+    token_expiry_month: Secret<String>,
+    token_expiry_year: Secret<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MdesTokenizeResponse {
+    token_unique_reference: String,
+    token_info: MdesTokenInfo,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MdesTransactRequest {
+    token_unique_reference: String,
+    dsrp_type: &'static str,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MdesTransactResponse {
+    cryptogram: Secret<String>,
+}
+
+#[async_trait::async_trait]
+impl TokenServiceProvider for Mdes {
+    fn id(&self) -> &'static str {
+        "mdes"
+    }
+
+    async fn provision_token(
+        &self,
+        state: &AppState,
+        card: &api::CardDetail,
+    ) -> CustomResult<NetworkTokenDetails, errors::ConnectorError> {
+        let request = MdesTokenizeRequest {
+            token_type: "CLOUD",
+            funding_account_info: MdesFundingAccountInfo {
+                card_info: MdesCardInfo {
+                    account_number: card.card_number.clone(),
+                    expiry_month: card.card_exp_month.clone(),
+                    expiry_year: card.card_exp_year.clone(),
+                    cardholder_name: card.card_holder_name.clone(),
+                },
+            },
+        };
+        let response: MdesTokenizeResponse = call_token_service(
+            state,
+            &self.config,
+            "/digitization/static/1/0/tokenize",
+            &request,
+            "MdesTokenizeResponse",
+        )
+        .await?;
+
+        Ok(NetworkTokenDetails {
+            token_service_provider: api_enums::CardNetwork::Mastercard,
+            token_number: response.token_info.token_pan,
+            token_exp_month: response.token_info.token_expiry_month,
+            token_exp_year: response.token_info.token_expiry_year,
+            token_reference_id: response.token_unique_reference,
+            // Mastercard requires a UCAF cryptogram for every transaction made with a token
+            cryptogram_required: true,
+        })
+    }
+
+    async fn get_cryptogram(
+        &self,
+        state: &AppState,
+        token_reference_id: &str,
+    ) -> CustomResult<Secret<String>, errors::ConnectorError> {
+        let request = MdesTransactRequest {
+            token_unique_reference: token_reference_id.to_string(),
+            dsrp_type: "UCAF",
+        };
+        let response: MdesTransactResponse = call_token_service(
+            state,
+            &self.config,
+            "/remotetransaction/static/1/0/transact",
+            &request,
+            "MdesTransactResponse",
+        )
+        .await?;
+
+        Ok(response.cryptogram)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_card_network() {
+        assert_eq!(
+            get_card_network("4242424242424242"),
+            Some(api_enums::CardNetwork::Visa)
+        );
+        assert_eq!(
+            get_card_network("5555555555554444"),
+            Some(api_enums::CardNetwork::Mastercard)
+        );
+        assert_eq!(
+            get_card_network("2223003122003222"),
+            Some(api_enums::CardNetwork::Mastercard)
+        );
+        assert_eq!(get_card_network("378282246310005"), None);
+    }
+}
@@ -1,11 +1,13 @@
-use common_utils::ext_traits::ValueExt;
-use error_stack::ResultExt;
+use common_utils::{ext_traits::ValueExt, pii};
+use error_stack::{report, ResultExt};
+use masking::Secret;
 use router_env::{instrument, tracing};
 use storage_models::errors as storage_errors;
 
 use crate::{
     core::{
-        errors::{self, RouterResponse, StorageErrorExt},
+        card_vault,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payment_methods::cards,
     },
     db::StorageInterface,
@@ -16,10 +18,105 @@ use crate::{
         api::customers::{self, CustomerRequestExt},
         storage::{self, enums},
     },
+    utils::StringExt,
 };
 
 pub const REDACTED: &str = "Redacted";
 
+/// Per merchant configuration of the customer details which must not be shared by two customers.
+///
+/// This is stored in the configs table against the key returned by
+/// [`get_customer_uniqueness_config_key`]. Customer details are not required to be unique when
+/// the merchant has no such config.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct CustomerUniquenessConfig {
+    pub unique_email: bool,
+    pub unique_phone: bool,
+}
+
+pub fn get_customer_uniqueness_config_key(merchant_id: &str) -> String {
+    format!("customer_uniqueness_{merchant_id}")
+}
+
+#[instrument(skip_all)]
+pub async fn get_customer_uniqueness_config(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<CustomerUniquenessConfig> {
+    let config = match db
+        .find_config_by_key_cached(&get_customer_uniqueness_config_key(merchant_id))
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => {
+            return Ok(CustomerUniquenessConfig::default())
+        }
+        Err(error) => {
+            return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Failed to fetch customer uniqueness config")
+        }
+    };
+
+    config
+        .config
+        .parse_struct("CustomerUniquenessConfig")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid customer uniqueness config")
+}
+
+/// Ensures that no other customer of the merchant has the email address or phone number of the
+/// customer, when the merchant requires them to be unique.
+#[instrument(skip_all)]
+async fn validate_customer_uniqueness(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+    email: Option<&Secret<String, pii::Email>>,
+    phone: Option<&Secret<String>>,
+) -> RouterResult<()> {
+    let config = get_customer_uniqueness_config(db, merchant_id).await?;
+
+    let email_constraints =
+        email
+            .filter(|_| config.unique_email)
+            .map(|email| customers::CustomerListConstraints {
+                email: Some(email.clone()),
+                ..Default::default()
+            });
+    let phone_constraints =
+        phone
+            .filter(|_| config.unique_phone)
+            .map(|phone| customers::CustomerListConstraints {
+                phone: Some(phone.clone()),
+                ..Default::default()
+            });
+
+    for (field_name, constraints) in [("email", email_constraints), ("phone", phone_constraints)] {
+        let constraints = match constraints {
+            Some(constraints) => constraints,
+            None => continue,
+        };
+        // Two customers are enough to tell whether a customer other than this one exists
+        let existing_customers = db
+            .list_customers_by_merchant_id(merchant_id, constraints, 2)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while filtering customers")?;
+
+        if existing_customers
+            .iter()
+            .any(|customer| customer.customer_id != customer_id)
+        {
+            Err(report!(errors::ApiErrorResponse::DuplicateCustomer {
+                field_name: field_name.to_string(),
+            }))?
+        }
+    }
+
+    Ok(())
+}
+
 #[instrument(skip(db))]
 pub async fn create_customer(
     db: &dyn StorageInterface,
@@ -31,6 +128,15 @@ pub async fn create_customer(
     let merchant_id = &merchant_account.merchant_id;
     customer_data.merchant_id = merchant_id.to_owned();
 
+    validate_customer_uniqueness(
+        db,
+        merchant_id,
+        customer_id,
+        customer_data.email.as_ref(),
+        customer_data.phone.as_ref(),
+    )
+    .await?;
+
     if let Some(addr) = &customer_data.address {
         let customer_address: api_models::payments::AddressDetails = addr
             .peek()
@@ -108,13 +214,43 @@ pub async fn retrieve_customer(
     Ok(services::ApplicationResponse::Json(response.into()))
 }
 
+#[instrument(skip(db))]
+pub async fn list_customers(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    constraints: customers::CustomerListConstraints,
+) -> RouterResponse<Vec<customers::CustomerResponse>> {
+    let limit = validate_customer_list_limit(constraints.limit)?;
+    let customer_list = db
+        .list_customers_by_merchant_id(&merchant_account.merchant_id, constraints, limit)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while filtering customers")?;
+
+    Ok(services::ApplicationResponse::Json(
+        customer_list.into_iter().map(Into::into).collect(),
+    ))
+}
+
+fn validate_customer_list_limit(limit: Option<i64>) -> RouterResult<i64> {
+    match limit {
+        Some(limit) if !(1..=100).contains(&limit) => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "limit should be in between 1 and 100".to_string(),
+            }))
+        }
+        Some(limit) => Ok(limit),
+        None => Ok(10),
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn delete_customer(
     state: &AppState,
     merchant_account: storage::MerchantAccount,
     req: customers::CustomerId,
 ) -> RouterResponse<customers::CustomerDeleteResponse> {
-    let db = &state.store;
+    let db = &*state.store;
 
     db.find_customer_by_customer_id_merchant_id(&req.customer_id, &merchant_account.merchant_id)
         .await
@@ -125,49 +261,144 @@ pub async fn delete_customer(
         .await
         .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
 
+    // The mandates are revoked before the payment methods they were set up with are deleted
     for mandate in customer_mandates.into_iter() {
-        if mandate.mandate_status == enums::MandateStatus::Active {
-            Err(errors::ApiErrorResponse::MandateActive)?
+        if mandate
+            .mandate_status
+            .can_transition_to(enums::MandateStatus::Revoked)
+        {
+            super::mandate::revoke_mandate_with_connector(state, &merchant_account, mandate)
+                .await?;
         }
     }
 
-    match db
-        .find_payment_method_by_customer_id_merchant_id_list(
-            &req.customer_id,
-            &merchant_account.merchant_id,
-        )
+    delete_customer_payment_methods(state, &merchant_account, &req.customer_id).await?;
+
+    redact_customer_addresses(db, &merchant_account.merchant_id, &req.customer_id).await?;
+    redact_customer_details(db, &merchant_account.merchant_id, &req.customer_id).await?;
+
+    let response = customers::CustomerDeleteResponse {
+        customer_id: req.customer_id,
+        customer_deleted: true,
+        address_deleted: true,
+        payment_methods_deleted: true,
+        mandates_revoked: true,
+    };
+    metrics::CUSTOMER_REDACTED.add(&metrics::CONTEXT, 1, &[]);
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[instrument(skip_all)]
+pub async fn redact_customer(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: customers::CustomerId,
+) -> RouterResponse<customers::CustomerRedactResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let customer_id = &req.customer_id;
+
+    db.find_customer_by_customer_id_merchant_id(customer_id, merchant_id)
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
+
+    let addresses_redacted = redact_customer_addresses(db, merchant_id, customer_id).await?;
+    let payments_redacted = redact_customer_payments(
+        db,
+        merchant_id,
+        customer_id,
+        merchant_account.storage_scheme,
+    )
+    .await?;
+    let mandates_redacted = redact_customer_mandates(db, merchant_id, customer_id).await?;
+    let payment_methods_deleted =
+        delete_customer_payment_methods(state, &merchant_account, customer_id).await?;
+    redact_customer_details(db, merchant_id, customer_id).await?;
+
+    db.insert_redaction_audit(storage::RedactionAuditNew {
+        merchant_id: merchant_id.to_owned(),
+        customer_id: customer_id.to_owned(),
+        addresses_redacted: to_audit_count(addresses_redacted),
+        payments_redacted: to_audit_count(payments_redacted),
+        mandates_redacted: to_audit_count(mandates_redacted),
+        payment_methods_deleted: to_audit_count(payment_methods_deleted),
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while inserting redaction audit")?;
+
+    metrics::CUSTOMER_REDACTED.add(&metrics::CONTEXT, 1, &[]);
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerRedactResponse {
+            customer_id: req.customer_id,
+            customer_redacted: true,
+            addresses_redacted,
+            payments_redacted,
+            mandates_redacted,
+            payment_methods_deleted,
+        },
+    ))
+}
+
+/// Deletes the saved payment methods of the customer along with their cards stored in the locker
+/// or the card vault, and returns the number of payment methods deleted.
+async fn delete_customer_payment_methods(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    customer_id: &str,
+) -> RouterResult<usize> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let customer_payment_methods = match db
+        .find_payment_method_by_customer_id_merchant_id_list(customer_id, merchant_id)
         .await
     {
-        Ok(customer_payment_methods) => {
-            for pm in customer_payment_methods.into_iter() {
-                if pm.payment_method == enums::PaymentMethod::Card {
-                    cards::delete_card_from_locker(
-                        state,
-                        &req.customer_id,
-                        &merchant_account.merchant_id,
-                        &pm.payment_method_id,
-                    )
-                    .await?;
-                }
-                db.delete_payment_method_by_merchant_id_payment_method_id(
-                    &merchant_account.merchant_id,
-                    &pm.payment_method_id,
-                )
-                .await
-                .map_err(|error| {
-                    error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
-                })?;
-            }
-        }
+        Ok(customer_payment_methods) => Ok(customer_payment_methods),
         Err(error) => match error.current_context() {
             errors::StorageError::DatabaseError(err) => match err.current_context() {
-                storage_errors::DatabaseError::NotFound => Ok(()),
+                storage_errors::DatabaseError::NotFound => Ok(Vec::new()),
                 _ => Err(errors::ApiErrorResponse::InternalServerError),
             },
             _ => Err(errors::ApiErrorResponse::InternalServerError),
-        }?,
-    };
+        },
+    }?;
+    let payment_methods_deleted = customer_payment_methods.len();
+
+    for pm in customer_payment_methods {
+        // Cards saved through the card vault are deleted from it along with their payment method
+        if card_vault::is_vault_token(&pm.payment_method_id) {
+            card_vault::delete_vault_token(state, merchant_account.clone(), &pm.payment_method_id)
+                .await?;
+            continue;
+        }
+        if pm.payment_method == enums::PaymentMethod::Card {
+            cards::delete_card_from_locker(state, customer_id, merchant_id, &pm.payment_method_id)
+                .await?;
+        }
+        db.delete_payment_method_by_merchant_id_payment_method_id(
+            merchant_id,
+            &pm.payment_method_id,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+    }
+
+    Ok(payment_methods_deleted)
+}
+
+fn to_audit_count(count: usize) -> i32 {
+    i32::try_from(count).unwrap_or(i32::MAX)
+}
 
+/// Redacts all the addresses of the customer, including the billing and shipping addresses of
+/// their payments, and returns the number of addresses redacted.
+async fn redact_customer_addresses(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+) -> RouterResult<usize> {
     let update_address = storage::AddressUpdate::Update {
         city: Some(REDACTED.to_string()),
         country: None,
@@ -182,24 +413,28 @@ pub async fn delete_customer(
         country_code: Some(REDACTED.to_string()),
     };
 
-    match db
-        .update_address_by_merchant_id_customer_id(
-            &req.customer_id,
-            &merchant_account.merchant_id,
-            update_address,
-        )
+    let addresses = match db
+        .update_address_by_merchant_id_customer_id(customer_id, merchant_id, update_address)
         .await
     {
-        Ok(_) => Ok(()),
+        Ok(addresses) => Ok(addresses),
         Err(error) => match error.current_context() {
             errors::StorageError::DatabaseError(err) => match err.current_context() {
-                storage_errors::DatabaseError::NotFound => Ok(()),
+                storage_errors::DatabaseError::NotFound => Ok(Vec::new()),
                 _ => Err(errors::ApiErrorResponse::InternalServerError),
             },
             _ => Err(errors::ApiErrorResponse::InternalServerError),
         },
     }?;
 
+    Ok(addresses.len())
+}
+
+async fn redact_customer_details(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+) -> RouterResult<()> {
     let updated_customer = storage::CustomerUpdate::Update {
         name: Some(REDACTED.to_string()),
         email: Some(REDACTED.to_string().into()),
@@ -209,21 +444,144 @@ pub async fn delete_customer(
         metadata: None,
     };
     db.update_customer_by_customer_id_merchant_id(
-        req.customer_id.clone(),
-        merchant_account.merchant_id,
+        customer_id.to_owned(),
+        merchant_id.to_owned(),
         updated_customer,
     )
     .await
     .change_context(errors::ApiErrorResponse::CustomerNotFound)?;
 
-    let response = customers::CustomerDeleteResponse {
-        customer_id: req.customer_id,
-        customer_deleted: true,
-        address_deleted: true,
-        payment_methods_deleted: true,
-    };
-    metrics::CUSTOMER_REDACTED.add(&metrics::CONTEXT, 1, &[]);
-    Ok(services::ApplicationResponse::Json(response))
+    Ok(())
+}
+
+/// Redacts the description of the payments of the customer and their IP address and user agent
+/// from the payment attempts, and returns the number of payments redacted. The amounts, statuses
+/// and identifiers of the payments are left untouched.
+async fn redact_customer_payments(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<usize> {
+    let payment_intents = db
+        .find_payment_intents_by_merchant_id_customer_id(merchant_id, customer_id, storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding the payments of the customer")?;
+    let payments_redacted = payment_intents.len();
+
+    for payment_intent in payment_intents {
+        let payment_attempts = db
+            .find_attempts_by_merchant_id_payment_id(
+                merchant_id,
+                &payment_intent.payment_id,
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while finding the payment attempts of the customer")?;
+
+        for payment_attempt in payment_attempts {
+            let browser_info = match payment_attempt
+                .browser_info
+                .as_ref()
+                .and_then(redact_browser_info)
+            {
+                Some(browser_info) => browser_info,
+                None => continue,
+            };
+            db.update_payment_attempt_with_attempt_id(
+                payment_attempt,
+                storage::PaymentAttemptUpdate::RedactionUpdate {
+                    browser_info: Some(browser_info),
+                },
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while redacting the payment attempt")?;
+        }
+
+        if payment_intent.description.is_some() {
+            db.update_payment_intent(
+                payment_intent,
+                storage::PaymentIntentUpdate::RedactionUpdate {
+                    description: Some(REDACTED.to_string()),
+                },
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while redacting the payment")?;
+        }
+    }
+
+    Ok(payments_redacted)
+}
+
+/// Removes the IP address and user agent from the browser information of a payment attempt,
+/// keeping the rest of it so that it can still be parsed. Returns `None` when there is nothing to
+/// redact.
+fn redact_browser_info(browser_info: &serde_json::Value) -> Option<serde_json::Value> {
+    let mut browser_info = browser_info.as_object()?.clone();
+    let has_ip_address = browser_info
+        .get("ip_address")
+        .map_or(false, |ip_address| !ip_address.is_null());
+    let has_user_agent = browser_info
+        .get("user_agent")
+        .map_or(false, |user_agent| user_agent != REDACTED);
+
+    if !has_ip_address && !has_user_agent {
+        return None;
+    }
+    if has_ip_address {
+        browser_info.insert("ip_address".to_string(), serde_json::Value::Null);
+    }
+    if has_user_agent {
+        browser_info.insert(
+            "user_agent".to_string(),
+            serde_json::Value::String(REDACTED.to_string()),
+        );
+    }
+
+    Some(serde_json::Value::Object(browser_info))
+}
+
+/// Redacts the IP address and user agent the customer accepted their mandates with, and returns
+/// the number of mandates redacted.
+async fn redact_customer_mandates(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+) -> RouterResult<usize> {
+    let mandates = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding the mandates of the customer")?;
+
+    let mut mandates_redacted = 0;
+    for mandate in mandates {
+        if mandate.customer_ip_address.is_none() && mandate.customer_user_agent.is_none() {
+            continue;
+        }
+        db.update_mandate_by_merchant_id_mandate_id(
+            merchant_id,
+            &mandate.mandate_id,
+            storage::MandateUpdate::RedactionUpdate {
+                customer_ip_address: mandate
+                    .customer_ip_address
+                    .map(|_| Secret::new(REDACTED.to_string())),
+                customer_user_agent: mandate.customer_user_agent.map(|_| REDACTED.to_string()),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while redacting the mandate")?;
+        mandates_redacted += 1;
+    }
+
+    Ok(mandates_redacted)
 }
 
 #[instrument(skip(db))]
@@ -241,6 +599,15 @@ pub async fn update_customer(
     .await
     .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
 
+    validate_customer_uniqueness(
+        db,
+        &merchant_account.merchant_id,
+        &update_customer.customer_id,
+        update_customer.email.as_ref(),
+        update_customer.phone.as_ref(),
+    )
+    .await?;
+
     if let Some(addr) = &update_customer.address {
         let customer_address: api_models::payments::AddressDetails = addr
             .peek()
@@ -295,3 +662,109 @@ pub async fn update_customer(
         customer_update_response,
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl};
+
+    #[test]
+    fn test_browser_info_is_redacted_and_parseable() {
+        let browser_info = serde_json::json!({
+            "color_depth": 24,
+            "java_enabled": true,
+            "java_script_enabled": true,
+            "language": "en-GB",
+            "screen_height": 1080,
+            "screen_width": 1920,
+            "time_zone": -60,
+            "ip_address": "127.0.0.1",
+            "accept_header": "text/html",
+            "user_agent": "Mozilla/5.0"
+        });
+
+        let redacted = redact_browser_info(&browser_info).unwrap();
+
+        assert!(redacted["ip_address"].is_null());
+        assert_eq!(redacted["user_agent"], REDACTED);
+        assert_eq!(redacted["language"], "en-GB");
+        serde_json::from_value::<crate::types::BrowserInformation>(redacted.clone()).unwrap();
+        assert!(redact_browser_info(&redacted).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_customer_payment_methods_are_deleted() {
+        let conf = Settings::new().expect("invalid settings");
+        let state = AppState::with_storage(conf, StorageImpl::Mock).await;
+        let db = &*state.store;
+        let merchant_account = db
+            .insert_merchant(storage::MerchantAccountNew {
+                merchant_id: "merchant".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        for (customer_id, payment_method_id, payment_method) in [
+            ("cus_redacted", "pm_wallet", enums::PaymentMethod::Wallet),
+            (
+                "cus_redacted",
+                "pm_bank_transfer",
+                enums::PaymentMethod::Wallet,
+            ),
+            ("cus_redacted", "vault_tok_card", enums::PaymentMethod::Card),
+            ("cus_other", "pm_other", enums::PaymentMethod::Wallet),
+        ] {
+            db.insert_payment_method(storage::PaymentMethodNew {
+                customer_id: customer_id.to_string(),
+                merchant_id: "merchant".to_string(),
+                payment_method_id: payment_method_id.to_string(),
+                payment_method,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        }
+        db.insert_vaulted_card(storage::VaultedCardNew {
+            payment_token: "vault_tok_card".to_string(),
+            merchant_id: "merchant".to_string(),
+            customer_id: Some("cus_redacted".to_string()),
+            key_id: "key".to_string(),
+            encrypted_card: Vec::new(),
+            card_last_four: "4242".to_string(),
+            card_exp_month: "12".to_string(),
+            card_exp_year: "30".to_string(),
+            created_at: common_utils::date_time::now(),
+            token_service_provider: None,
+            encrypted_network_token: None,
+        })
+        .await
+        .unwrap();
+
+        let payment_methods_deleted =
+            delete_customer_payment_methods(&state, &merchant_account, "cus_redacted")
+                .await
+                .unwrap();
+
+        assert_eq!(payment_methods_deleted, 3);
+        assert!(db
+            .find_vaulted_card_by_merchant_id_payment_token("merchant", "vault_tok_card")
+            .await
+            .unwrap_err()
+            .current_context()
+            .is_db_not_found());
+        assert!(db
+            .find_payment_method_by_customer_id_merchant_id_list("cus_redacted", "merchant")
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            db.find_payment_method_by_customer_id_merchant_id_list("cus_other", "merchant")
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
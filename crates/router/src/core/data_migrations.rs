@@ -0,0 +1,241 @@
+//! Online migrations of the data stored by the application, such as backfilling a column added to
+//! a table for the rows which predate it.
+//!
+//! Every data migrator has a version, which orders it among the others, and migrates its rows in
+//! batches, in the order of their identifiers. The progress of a migration is stored in the
+//! configs table after every batch, so that an interrupted migration resumes after the last batch
+//! it migrated. The batches are spaced out so that a migration can run alongside the application
+//! without overloading the database.
+
+mod payment_attempt_amount_refunded;
+
+use std::time::Duration;
+
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::AppState,
+    types::storage,
+    utils::{Encode, StringExt},
+};
+
+/// A batch of rows migrated by a data migrator.
+pub struct DataMigrationBatch {
+    /// Number of rows migrated in the batch
+    pub migrated_rows: u64,
+    /// Identifier of the last row of the batch, which the next batch starts after. There are no
+    /// rows left to migrate when it is `None`.
+    pub last_id: Option<i32>,
+}
+
+#[async_trait::async_trait]
+pub trait DataMigrator: Send + Sync {
+    /// The version of the migrator, such as `2023-05-27-000000_backfill_amount_refunded`, by which
+    /// the migrators are ordered.
+    fn version(&self) -> &'static str;
+
+    fn description(&self) -> &'static str;
+
+    /// Migrates up to `batch_size` rows, starting after the row identified by `after_id`.
+    async fn migrate_batch(
+        &self,
+        state: &AppState,
+        after_id: i32,
+        batch_size: i64,
+    ) -> RouterResult<DataMigrationBatch>;
+}
+
+/// The data migrators, in the order of their versions.
+fn get_data_migrators() -> Vec<Box<dyn DataMigrator>> {
+    vec![Box::new(
+        payment_attempt_amount_refunded::BackfillPaymentAttemptAmountRefunded,
+    )]
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DataMigrationProgress {
+    /// Identifier of the last row migrated
+    pub last_id: i32,
+    pub migrated_rows: u64,
+    pub completed: bool,
+}
+
+pub struct DataMigrationStatus {
+    pub version: &'static str,
+    pub description: &'static str,
+    /// The progress of the migration, which is `None` until it has been started
+    pub progress: Option<DataMigrationProgress>,
+}
+
+pub struct DataMigrationOptions {
+    /// Version of the only migrator to run, every pending migrator is run otherwise
+    pub version: Option<String>,
+    /// Number of rows migrated in a batch
+    pub batch_size: i64,
+    /// Time to wait between batches
+    pub batch_interval: Duration,
+    /// Number of batches after which the migrations are paused, until they are run again
+    pub max_batches: Option<u64>,
+}
+
+fn get_progress_key(version: &str) -> String {
+    format!("data_migration_{version}")
+}
+
+async fn find_progress(
+    state: &AppState,
+    version: &str,
+) -> RouterResult<Option<DataMigrationProgress>> {
+    match state
+        .store
+        .find_config_by_key(&get_progress_key(version))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("DataMigrationProgress")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the progress of the data migration")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the progress of the data migration")),
+    }
+}
+
+async fn store_progress(
+    state: &AppState,
+    version: &str,
+    progress: &DataMigrationProgress,
+    is_started: bool,
+) -> RouterResult<()> {
+    let key = get_progress_key(version);
+    let config = Encode::<DataMigrationProgress>::encode_to_string_of_json(progress)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the progress of the data migration")?;
+
+    if is_started {
+        state
+            .store
+            .update_config_by_key(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+    } else {
+        state
+            .store
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the progress of the data migration")?;
+
+    Ok(())
+}
+
+/// Runs the pending data migrations in the order of their versions, resuming those which were
+/// interrupted.
+#[instrument(skip_all)]
+pub async fn run_data_migrations(
+    state: &AppState,
+    options: &DataMigrationOptions,
+) -> RouterResult<()> {
+    let migrators = get_data_migrators()
+        .into_iter()
+        .filter(|migrator| {
+            options
+                .version
+                .as_deref()
+                .map_or(true, |version| version == migrator.version())
+        })
+        .collect::<Vec<_>>();
+    if migrators.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "version",
+        }))
+        .attach_printable("No data migration has the requested version");
+    }
+
+    let mut batches = 0;
+    for migrator in migrators {
+        let stored_progress = find_progress(state, migrator.version()).await?;
+        let mut is_started = stored_progress.is_some();
+        let mut progress = stored_progress.unwrap_or_default();
+        if progress.completed {
+            logger::info!("Data migration {} is already completed", migrator.version());
+            continue;
+        }
+
+        logger::info!(
+            "Running data migration {} after row {}",
+            migrator.version(),
+            progress.last_id
+        );
+        loop {
+            if options
+                .max_batches
+                .map_or(false, |max_batches| batches >= max_batches)
+            {
+                logger::info!(
+                    "Pausing data migration {} after {batches} batches, at row {}",
+                    migrator.version(),
+                    progress.last_id
+                );
+                return Ok(());
+            }
+
+            let batch = migrator
+                .migrate_batch(state, progress.last_id, options.batch_size)
+                .await
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Data migration {} failed after row {}",
+                        migrator.version(),
+                        progress.last_id
+                    )
+                })?;
+            batches += 1;
+            progress.migrated_rows += batch.migrated_rows;
+            match batch.last_id {
+                Some(last_id) => progress.last_id = last_id,
+                None => progress.completed = true,
+            }
+            store_progress(state, migrator.version(), &progress, is_started).await?;
+            is_started = true;
+
+            if progress.completed {
+                logger::info!(
+                    "Completed data migration {}, migrating {} rows",
+                    migrator.version(),
+                    progress.migrated_rows
+                );
+                break;
+            }
+            tokio::time::sleep(options.batch_interval).await;
+        }
+    }
+
+    Ok(())
+}
+
+/// Lists the data migrators in the order of their versions, along with the progress of their
+/// migrations.
+#[instrument(skip_all)]
+pub async fn list_data_migrations(state: &AppState) -> RouterResult<Vec<DataMigrationStatus>> {
+    let mut statuses = Vec::new();
+    for migrator in get_data_migrators() {
+        statuses.push(DataMigrationStatus {
+            version: migrator.version(),
+            description: migrator.description(),
+            progress: find_progress(state, migrator.version()).await?,
+        });
+    }
+    Ok(statuses)
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::{DataMigrationBatch, DataMigrator};
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        refunds,
+    },
+    routes::AppState,
+    types::storage,
+};
+
+/// Stores the amount refunded on the charged payment attempts which were refunded before it was
+/// stored on payment attempts.
+pub struct BackfillPaymentAttemptAmountRefunded;
+
+#[async_trait::async_trait]
+impl DataMigrator for BackfillPaymentAttemptAmountRefunded {
+    fn version(&self) -> &'static str {
+        "2023-05-27-000000_backfill_payment_attempt_amount_refunded"
+    }
+
+    fn description(&self) -> &'static str {
+        "Store the amount refunded on charged payment attempts"
+    }
+
+    async fn migrate_batch(
+        &self,
+        state: &AppState,
+        after_id: i32,
+        batch_size: i64,
+    ) -> RouterResult<DataMigrationBatch> {
+        let db = &*state.store;
+        let payment_attempts = db
+            .find_charged_payment_attempts_without_amount_refunded(after_id, batch_size)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment attempts to backfill")?;
+        let last_id = payment_attempts
+            .last()
+            .map(|payment_attempt| payment_attempt.id);
+
+        let mut storage_schemes = HashMap::new();
+        let mut migrated_rows = 0;
+        for payment_attempt in payment_attempts {
+            let storage_scheme = match storage_schemes.get(&payment_attempt.merchant_id) {
+                Some(storage_scheme) => *storage_scheme,
+                None => match db
+                    .find_merchant_account_by_merchant_id(&payment_attempt.merchant_id)
+                    .await
+                {
+                    Ok(merchant_account) => {
+                        storage_schemes.insert(
+                            payment_attempt.merchant_id.clone(),
+                            merchant_account.storage_scheme,
+                        );
+                        merchant_account.storage_scheme
+                    }
+                    Err(error) if error.current_context().is_db_not_found() => {
+                        logger::warn!(
+                            "Skipping payment attempt {} of the deleted merchant {}",
+                            payment_attempt.attempt_id,
+                            payment_attempt.merchant_id
+                        );
+                        continue;
+                    }
+                    Err(error) => {
+                        return Err(error
+                            .change_context(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable("Failed to fetch the merchant account"))
+                    }
+                },
+            };
+
+            let amount_refunded = match &payment_attempt.connector_transaction_id {
+                Some(connector_transaction_id) => {
+                    refunds::get_amount_refunded(
+                        db,
+                        &payment_attempt.merchant_id,
+                        connector_transaction_id,
+                        storage_scheme,
+                    )
+                    .await?
+                }
+                None => 0,
+            };
+
+            // The payment attempt is read again through the storage scheme of the merchant, as
+            // the copy in the database may be outdated for merchants whose data is kept in redis
+            let payment_attempt = db
+                .find_payment_attempt_by_attempt_id_merchant_id(
+                    &payment_attempt.attempt_id,
+                    &payment_attempt.merchant_id,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payment attempt to backfill")?;
+            if payment_attempt.amount_refunded.is_some() {
+                continue;
+            }
+
+            db.update_payment_attempt_with_attempt_id(
+                payment_attempt,
+                storage::PaymentAttemptUpdate::RefundUpdate { amount_refunded },
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to backfill the amount refunded on the payment attempt")?;
+            migrated_rows += 1;
+        }
+
+        Ok(DataMigrationBatch {
+            migrated_rows,
+            last_id,
+        })
+    }
+}
@@ -0,0 +1,150 @@
+//! Scheduler tasks which exhaust their retries, or fail while being handled, are moved to the dead
+//! letter queue, where they are kept with the reason they were dead lettered until they are
+//! replayed. Replaying a task schedules it to run again immediately with its retries reset.
+
+use common_utils::date_time;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    db::StorageInterface,
+    services::ApplicationResponse,
+    types::{
+        api,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils,
+};
+
+const MAX_DEAD_LETTER_TASKS_LIMIT: i64 = 100;
+const MAX_DEAD_LETTER_TASKS_REPLAY_COUNT: usize = 100;
+
+async fn find_dead_letter_task(
+    db: &dyn StorageInterface,
+    task_id: &str,
+) -> RouterResult<storage::ProcessTracker> {
+    db.find_process_by_id(task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch task")?
+        .filter(|process| process.status == storage_enums::ProcessTrackerStatus::DeadLetter)
+        .ok_or_else(|| report!(errors::ApiErrorResponse::DeadLetterTaskNotFound))
+}
+
+async fn replay_tasks(db: &dyn StorageInterface, task_ids: Vec<String>) -> RouterResult<usize> {
+    db.replay_dead_letter_processes(task_ids, date_time::now())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to replay dead lettered tasks")
+}
+
+#[instrument(skip_all)]
+pub async fn list_dead_letter_tasks(
+    db: &dyn StorageInterface,
+    constraints: api::DeadLetterTaskListConstraints,
+) -> RouterResponse<api::DeadLetterTaskListResponse> {
+    utils::when(
+        constraints.limit < 1 || constraints.limit > MAX_DEAD_LETTER_TASKS_LIMIT,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("limit should be in between 1 and {MAX_DEAD_LETTER_TASKS_LIMIT}"),
+            })
+        },
+    )?;
+
+    let tasks = db
+        .find_processes_by_status(
+            storage_enums::ProcessTrackerStatus::DeadLetter,
+            constraints.runner,
+            Some(constraints.limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list dead lettered tasks")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect::<Vec<api::DeadLetterTaskResponse>>();
+
+    Ok(ApplicationResponse::Json(api::DeadLetterTaskListResponse {
+        size: tasks.len(),
+        data: tasks,
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_dead_letter_task(
+    db: &dyn StorageInterface,
+    task_id: &str,
+) -> RouterResponse<api::DeadLetterTaskResponse> {
+    let task = find_dead_letter_task(db, task_id).await?;
+
+    Ok(ApplicationResponse::Json(task.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn replay_dead_letter_task(
+    db: &dyn StorageInterface,
+    task_id: &str,
+) -> RouterResponse<api::DeadLetterTaskReplayResponse> {
+    let task = find_dead_letter_task(db, task_id).await?;
+    let replayed_count = replay_tasks(db, vec![task.id]).await?;
+
+    Ok(ApplicationResponse::Json(
+        api::DeadLetterTaskReplayResponse { replayed_count },
+    ))
+}
+
+/// Replays the identified tasks, or the most recently dead lettered tasks of a runner. Tasks which
+/// are not dead lettered are left as is.
+#[instrument(skip_all)]
+pub async fn replay_dead_letter_tasks(
+    db: &dyn StorageInterface,
+    req: api::DeadLetterTaskReplayRequest,
+) -> RouterResponse<api::DeadLetterTaskReplayResponse> {
+    let task_ids = match (req.task_ids, req.runner) {
+        (Some(task_ids), None) => {
+            utils::when(
+                task_ids.is_empty() || task_ids.len() > MAX_DEAD_LETTER_TASKS_REPLAY_COUNT,
+                || {
+                    Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "task_ids should contain between 1 and {MAX_DEAD_LETTER_TASKS_REPLAY_COUNT} tasks"
+                        ),
+                    })
+                },
+            )?;
+            task_ids
+        }
+        (None, Some(runner)) => db
+            .find_processes_by_status(
+                storage_enums::ProcessTrackerStatus::DeadLetter,
+                Some(runner),
+                Some(MAX_DEAD_LETTER_TASKS_LIMIT),
+                None,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list dead lettered tasks")?
+            .into_iter()
+            .map(|process| process.id)
+            .collect(),
+        _ => {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Exactly one of task_ids or runner should be provided".to_string(),
+            }))
+        }
+    };
+
+    let replayed_count = if task_ids.is_empty() {
+        0
+    } else {
+        replay_tasks(db, task_ids).await?
+    };
+
+    Ok(ApplicationResponse::Json(
+        api::DeadLetterTaskReplayResponse { replayed_count },
+    ))
+}
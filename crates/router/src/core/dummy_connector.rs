@@ -0,0 +1,577 @@
+//! The dummy connector simulates a connector within the application, so that merchants can test
+//! their integration with every outcome of a payment without the credentials of a real connector.
+//! The outcome of a payment is decided by its card number and amount:
+//!
+//! | Card number        | Outcome                                               |
+//! |--------------------|-------------------------------------------------------|
+//! | `4000000000000002` | Declined with `card_declined`                         |
+//! | `4000000000009995` | Declined with `insufficient_funds`                    |
+//! | `4000000000000069` | Declined with `expired_card`                          |
+//! | `4000000000000127` | Declined with `incorrect_cvc`                         |
+//! | `4000000000003220` | Succeeds once the customer passes a 3DS challenge     |
+//! | any other          | Succeeds, unless the amount is one of the ones below  |
+//!
+//! Payments of an amount from 9001 to 9060 are processed asynchronously, and succeed after as many
+//! seconds as the amount exceeds 9000 by. Those of an amount from 9101 to 9160 fail after as many
+//! seconds as the amount exceeds 9100 by. The outcome of such payments is sent to the incoming
+//! webhook endpoint of the merchant on the router once it is reached.
+//!
+//! Payments and refunds are made with the API key of the merchant, and are only visible to the
+//! merchant who made them.
+
+use std::ops::RangeInclusive;
+
+use common_utils::date_time;
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    connector::utils as connector_utils,
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    logger,
+    routes::AppState,
+    services::{self, ApplicationResponse},
+    types::{self, api, storage, transformers::ForeignInto},
+    utils,
+};
+
+/// Time (in seconds) the payments and refunds of the dummy connector are kept for
+const DUMMY_CONNECTOR_DATA_TTL: i64 = 7 * 24 * 60 * 60;
+
+const DUMMY_CONNECTOR_WEBHOOK_TIMEOUT_MS: u64 = 5000;
+
+/// The card numbers payments are declined for, along with the code and message they are declined
+/// with
+const DECLINED_CARD_NUMBERS: &[(&str, &str, &str)] = &[
+    (
+        "4000000000000002",
+        "card_declined",
+        "Your card was declined",
+    ),
+    (
+        "4000000000009995",
+        "insufficient_funds",
+        "Your card has insufficient funds",
+    ),
+    ("4000000000000069", "expired_card", "Your card has expired"),
+    (
+        "4000000000000127",
+        "incorrect_cvc",
+        "Your card's security code is incorrect",
+    ),
+];
+
+/// The card number payments require the customer to pass a 3DS challenge for
+const THREE_DS_CARD_NUMBER: &str = "4000000000003220";
+
+const ASYNC_SUCCESS_AMOUNTS: RangeInclusive<i64> = 9001..=9060;
+const ASYNC_FAILURE_AMOUNTS: RangeInclusive<i64> = 9101..=9160;
+
+/// The outcome of a payment, as decided by its card number and amount
+#[derive(Debug, Eq, PartialEq)]
+enum PaymentOutcome {
+    Succeeded,
+    Declined(api::DummyConnectorError),
+    RequiresAuthentication,
+    /// The payment is processed asynchronously, and succeeds or fails after a number of seconds
+    Settles {
+        succeeds: bool,
+        after_seconds: i64,
+    },
+}
+
+fn get_payment_outcome(card_number: &str, amount: i64) -> PaymentOutcome {
+    if let Some((_, code, message)) = DECLINED_CARD_NUMBERS
+        .iter()
+        .find(|(declined_card_number, _, _)| *declined_card_number == card_number)
+    {
+        PaymentOutcome::Declined(api::DummyConnectorError {
+            code: code.to_string(),
+            message: message.to_string(),
+        })
+    } else if card_number == THREE_DS_CARD_NUMBER {
+        PaymentOutcome::RequiresAuthentication
+    } else if ASYNC_SUCCESS_AMOUNTS.contains(&amount) {
+        PaymentOutcome::Settles {
+            succeeds: true,
+            after_seconds: amount - ASYNC_SUCCESS_AMOUNTS.start() + 1,
+        }
+    } else if ASYNC_FAILURE_AMOUNTS.contains(&amount) {
+        PaymentOutcome::Settles {
+            succeeds: false,
+            after_seconds: amount - ASYNC_FAILURE_AMOUNTS.start() + 1,
+        }
+    } else {
+        PaymentOutcome::Succeeded
+    }
+}
+
+fn get_authentication_url(state: &AppState, payment_id: &str) -> String {
+    format!(
+        "{}/dummy_connector/authorize/{payment_id}",
+        state.conf.server.base_url
+    )
+}
+
+/// Applies the outcome of a payment processed asynchronously, once the payment reaches it.
+fn settle_payment(
+    mut payment: storage::DummyConnectorPaymentData,
+) -> storage::DummyConnectorPaymentData {
+    if let Some(settlement) = payment
+        .settlement
+        .as_ref()
+        .filter(|settlement| settlement.settles_at <= date_time::now())
+    {
+        if payment.status == api::DummyConnectorStatus::Processing {
+            payment.status = settlement.status;
+            payment.error = settlement.error.clone();
+        }
+    }
+    payment
+}
+
+fn make_payment_response(
+    state: &AppState,
+    payment: storage::DummyConnectorPaymentData,
+) -> api::DummyConnectorPaymentResponse {
+    let next_action = (payment.status == api::DummyConnectorStatus::RequiresAction).then(|| {
+        api::DummyConnectorNextAction {
+            redirect_to_url: get_authentication_url(state, &payment.payment_id),
+        }
+    });
+
+    api::DummyConnectorPaymentResponse {
+        id: payment.payment_id,
+        status: payment.status,
+        amount: payment.amount,
+        currency: payment.currency,
+        created: payment.created,
+        error: payment.error,
+        next_action,
+    }
+}
+
+async fn find_payment(
+    state: &AppState,
+    payment_id: &str,
+) -> RouterResult<storage::DummyConnectorPaymentData> {
+    state
+        .store
+        .find_dummy_connector_payment(payment_id)
+        .await
+        .map_err(|error| match error.current_context() {
+            errors::StorageError::ValueNotFound(_) => {
+                error.change_context(errors::ApiErrorResponse::PaymentNotFound)
+            }
+            _ => error.change_context(errors::ApiErrorResponse::InternalServerError),
+        })
+        .map(settle_payment)
+}
+
+async fn find_merchant_payment(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &str,
+) -> RouterResult<storage::DummyConnectorPaymentData> {
+    let payment = find_payment(state, payment_id).await?;
+    utils::when(payment.merchant_id != merchant_account.merchant_id, || {
+        Err(errors::ApiErrorResponse::PaymentNotFound).into_report()
+    })?;
+    Ok(payment)
+}
+
+/// Settlements are only sent to the incoming webhook endpoint of the merchant on the router, as
+/// the dummy connector must not be made to call arbitrary URLs.
+fn validate_webhook_url(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    webhook_url: Option<&String>,
+) -> RouterResult<()> {
+    let expected_webhook_url = format!(
+        "{}/webhooks/{}/dummyconnector",
+        state.conf.server.base_url, merchant_account.merchant_id
+    );
+    utils::when(
+        webhook_url.map_or(false, |webhook_url| *webhook_url != expected_webhook_url),
+        || {
+            Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "webhook_url",
+            })
+            .into_report()
+        },
+    )
+}
+
+async fn store_payment(
+    state: &AppState,
+    payment: storage::DummyConnectorPaymentData,
+) -> RouterResult<storage::DummyConnectorPaymentData> {
+    state
+        .store
+        .store_dummy_connector_payment(payment, DUMMY_CONNECTOR_DATA_TTL)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store dummy connector payment")
+}
+
+/// Sends the outcome of a payment processed asynchronously to the webhook URL of the payment, once
+/// the payment reaches it.
+fn schedule_settlement_webhook(
+    state: &AppState,
+    payment_id: String,
+    settlement: storage::DummyConnectorSettlement,
+) {
+    let state = state.clone();
+    let background_tasks = state.background_tasks.clone();
+
+    background_tasks.spawn(async move {
+        let delay = settlement.settles_at - date_time::now();
+        tokio::time::sleep(delay.try_into().unwrap_or_default()).await;
+
+        let result = async {
+            let payment = store_payment(&state, find_payment(&state, &payment_id).await?).await?;
+            if let Some(webhook_url) = payment.webhook_url.clone() {
+                reqwest::Client::new()
+                    .post(webhook_url)
+                    .json(&make_payment_response(&state, payment))
+                    .timeout(std::time::Duration::from_millis(
+                        DUMMY_CONNECTOR_WEBHOOK_TIMEOUT_MS,
+                    ))
+                    .send()
+                    .await
+                    .into_report()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to send dummy connector webhook")?;
+            }
+            RouterResult::Ok(())
+        }
+        .await;
+
+        if let Err(error) = result {
+            logger::error!(?error, "Failed to settle dummy connector payment");
+        }
+    });
+}
+
+#[instrument(skip_all)]
+pub async fn payment(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::DummyConnectorPaymentRequest,
+) -> RouterResponse<api::DummyConnectorPaymentResponse> {
+    validate_webhook_url(state, &merchant_account, req.webhook_url.as_ref())?;
+
+    let created = date_time::now();
+    let (status, error, settlement) = match get_payment_outcome(req.card.number.peek(), req.amount)
+    {
+        PaymentOutcome::Succeeded => (api::DummyConnectorStatus::Succeeded, None, None),
+        PaymentOutcome::Declined(error) => (api::DummyConnectorStatus::Failed, Some(error), None),
+        PaymentOutcome::RequiresAuthentication => {
+            (api::DummyConnectorStatus::RequiresAction, None, None)
+        }
+        PaymentOutcome::Settles {
+            succeeds,
+            after_seconds,
+        } => {
+            let settlement = storage::DummyConnectorSettlement {
+                status: if succeeds {
+                    api::DummyConnectorStatus::Succeeded
+                } else {
+                    api::DummyConnectorStatus::Failed
+                },
+                error: (!succeeds).then(|| api::DummyConnectorError {
+                    code: "processing_error".to_string(),
+                    message: "An error occurred while processing your card".to_string(),
+                }),
+                settles_at: created + time::Duration::seconds(after_seconds),
+            };
+            (
+                api::DummyConnectorStatus::Processing,
+                None,
+                Some(settlement),
+            )
+        }
+    };
+
+    let payment = store_payment(
+        state,
+        storage::DummyConnectorPaymentData {
+            payment_id: utils::generate_id(consts::ID_LENGTH, "dummy_pay"),
+            merchant_id: merchant_account.merchant_id,
+            status,
+            amount: req.amount,
+            refunded_amount: 0,
+            currency: req.currency,
+            created,
+            error,
+            return_url: req.return_url,
+            webhook_url: req.webhook_url,
+            settlement,
+        },
+    )
+    .await?;
+
+    if let Some(settlement) = payment.settlement.clone() {
+        schedule_settlement_webhook(state, payment.payment_id.clone(), settlement);
+    }
+
+    Ok(ApplicationResponse::Json(make_payment_response(
+        state, payment,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn payment_retrieve(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::DummyConnectorPaymentRetrieveRequest,
+) -> RouterResponse<api::DummyConnectorPaymentResponse> {
+    let payment = find_merchant_payment(state, &merchant_account, &req.payment_id).await?;
+    Ok(ApplicationResponse::Json(make_payment_response(
+        state, payment,
+    )))
+}
+
+/// Serves the 3DS challenge page of a payment, where the customer chooses whether they pass it.
+#[instrument(skip_all)]
+pub async fn authentication_page(
+    state: &AppState,
+    req: api::DummyConnectorPaymentRetrieveRequest,
+) -> RouterResponse<()> {
+    let payment = find_payment(state, &req.payment_id).await?;
+    validate_requires_action(&payment)?;
+
+    let amount = connector_utils::to_currency_base_unit(
+        types::MinorUnit::new(payment.amount),
+        payment.currency.foreign_into(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to convert amount of dummy connector payment to base unit")?;
+    let authentication_url = get_authentication_url(state, &payment.payment_id);
+
+    Ok(ApplicationResponse::Html(
+        build_authentication_page(&payment, &amount, &authentication_url).into_string(),
+    ))
+}
+
+/// Completes the 3DS challenge of a payment, and redirects the customer to the return URL of the
+/// payment.
+#[instrument(skip_all)]
+pub async fn complete_authentication(
+    state: &AppState,
+    req: api::DummyConnectorAuthenticationRequest,
+) -> RouterResponse<api::DummyConnectorPaymentResponse> {
+    let mut payment = find_payment(state, &req.payment_id).await?;
+    validate_requires_action(&payment)?;
+
+    if req.confirm {
+        payment.status = api::DummyConnectorStatus::Succeeded;
+    } else {
+        payment.status = api::DummyConnectorStatus::Failed;
+        payment.error = Some(api::DummyConnectorError {
+            code: "authentication_failed".to_string(),
+            message: "The customer failed the 3DS challenge".to_string(),
+        });
+    }
+    let payment = store_payment(state, payment).await?;
+
+    match payment.return_url.clone() {
+        Some(return_url) => Ok(ApplicationResponse::JsonForRedirection(
+            api::RedirectionResponse {
+                return_url: return_url.clone(),
+                params: Vec::new(),
+                return_url_with_query_params: return_url,
+                http_method: services::Method::Get.to_string(),
+                headers: Vec::new(),
+            },
+        )),
+        None => Ok(ApplicationResponse::Json(make_payment_response(
+            state, payment,
+        ))),
+    }
+}
+
+fn validate_requires_action(payment: &storage::DummyConnectorPaymentData) -> RouterResult<()> {
+    utils::when(
+        payment.status != api::DummyConnectorStatus::RequiresAction,
+        || {
+            Err(errors::ApiErrorResponse::PaymentUnexpectedState {
+                current_flow: "authenticated".to_string(),
+                field_name: "status".to_string(),
+                current_value: payment.status.to_string(),
+                states: api::DummyConnectorStatus::RequiresAction.to_string(),
+            })
+            .into_report()
+        },
+    )
+}
+
+#[instrument(skip_all)]
+pub async fn refund(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::DummyConnectorRefundRequest,
+) -> RouterResponse<api::DummyConnectorRefundResponse> {
+    let mut payment = find_merchant_payment(state, &merchant_account, &req.payment_id).await?;
+
+    utils::when(
+        payment.status != api::DummyConnectorStatus::Succeeded,
+        || {
+            Err(errors::ApiErrorResponse::PaymentUnexpectedState {
+                current_flow: "refunded".to_string(),
+                field_name: "status".to_string(),
+                current_value: payment.status.to_string(),
+                states: api::DummyConnectorStatus::Succeeded.to_string(),
+            })
+            .into_report()
+        },
+    )?;
+    utils::when(req.amount <= 0, || {
+        Err(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "amount",
+        })
+        .into_report()
+    })?;
+    utils::when(
+        req.amount > payment.amount - payment.refunded_amount,
+        || Err(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount).into_report(),
+    )?;
+
+    payment.refunded_amount += req.amount;
+    let payment = store_payment(state, payment).await?;
+
+    let refund = state
+        .store
+        .store_dummy_connector_refund(
+            storage::DummyConnectorRefundData {
+                refund_id: utils::generate_id(consts::ID_LENGTH, "dummy_ref"),
+                merchant_id: merchant_account.merchant_id,
+                payment_id: payment.payment_id,
+                status: api::DummyConnectorStatus::Succeeded,
+                amount: req.amount,
+                currency: payment.currency,
+                created: date_time::now(),
+            },
+            DUMMY_CONNECTOR_DATA_TTL,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store dummy connector refund")?;
+
+    Ok(ApplicationResponse::Json(make_refund_response(refund)))
+}
+
+#[instrument(skip_all)]
+pub async fn refund_retrieve(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::DummyConnectorRefundRetrieveRequest,
+) -> RouterResponse<api::DummyConnectorRefundResponse> {
+    let refund = state
+        .store
+        .find_dummy_connector_refund(&req.refund_id)
+        .await
+        .map_err(|error| match error.current_context() {
+            errors::StorageError::ValueNotFound(_) => {
+                error.change_context(errors::ApiErrorResponse::RefundNotFound)
+            }
+            _ => error.change_context(errors::ApiErrorResponse::InternalServerError),
+        })?;
+    utils::when(refund.merchant_id != merchant_account.merchant_id, || {
+        Err(errors::ApiErrorResponse::RefundNotFound).into_report()
+    })?;
+
+    Ok(ApplicationResponse::Json(make_refund_response(refund)))
+}
+
+fn make_refund_response(
+    refund: storage::DummyConnectorRefundData,
+) -> api::DummyConnectorRefundResponse {
+    api::DummyConnectorRefundResponse {
+        id: refund.refund_id,
+        payment_id: refund.payment_id,
+        status: refund.status,
+        amount: refund.amount,
+        currency: refund.currency,
+        created: refund.created,
+    }
+}
+
+fn build_authentication_page(
+    payment: &storage::DummyConnectorPaymentData,
+    amount: &str,
+    authentication_url: &str,
+) -> maud::Markup {
+    maud::html! {
+        (maud::DOCTYPE)
+        html {
+            head {
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                title { "3DS Challenge" }
+            }
+            body style="background-color: #ffffff; padding: 20px; font-family: Arial, Helvetica, Sans-Serif;" {
+                div style="max-width: 400px; margin-left: auto; margin-right: auto; text-align: center;" {
+                    h3 { "Dummy Connector 3DS Challenge" }
+                    h2 { (amount) " " (payment.currency.to_string()) }
+                    p { "This page simulates the 3DS challenge of the bank of the customer." }
+                    form action=(format!("{authentication_url}/complete")) method="get" {
+                        input type="hidden" name="confirm" value="true";
+                        p { button type="submit" style="width: 100%;" { "Complete authentication" } }
+                    }
+                    form action=(format!("{authentication_url}/complete")) method="get" {
+                        input type="hidden" name="confirm" value="false";
+                        p { button type="submit" style="width: 100%;" { "Fail authentication" } }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payment_outcome_is_decided_by_card_number() {
+        assert_eq!(
+            get_payment_outcome("4242424242424242", 1000),
+            PaymentOutcome::Succeeded
+        );
+        assert_eq!(
+            get_payment_outcome("4000000000009995", 1000),
+            PaymentOutcome::Declined(api::DummyConnectorError {
+                code: "insufficient_funds".to_string(),
+                message: "Your card has insufficient funds".to_string(),
+            })
+        );
+        assert_eq!(
+            get_payment_outcome("4000000000003220", 9005),
+            PaymentOutcome::RequiresAuthentication
+        );
+    }
+
+    #[test]
+    fn test_payment_outcome_is_decided_by_amount() {
+        assert_eq!(
+            get_payment_outcome("4242424242424242", 9005),
+            PaymentOutcome::Settles {
+                succeeds: true,
+                after_seconds: 5,
+            }
+        );
+        assert_eq!(
+            get_payment_outcome("4242424242424242", 9160),
+            PaymentOutcome::Settles {
+                succeeds: false,
+                after_seconds: 60,
+            }
+        );
+        assert_eq!(
+            get_payment_outcome("4242424242424242", 9061),
+            PaymentOutcome::Succeeded
+        );
+    }
+}
@@ -61,6 +61,8 @@ pub enum StorageError {
     },
     #[error("Timed out while trying to connect to the database")]
     DatabaseConnectionError,
+    #[error("Timed out waiting for a connection from the exhausted database connection pool")]
+    PoolExhausted,
     #[error("KV error")]
     KVError,
     #[error("Serialization failure")]
@@ -87,6 +89,14 @@ impl From<error_stack::Report<storage_errors::DatabaseError>> for StorageError {
     }
 }
 
+impl From<diesel::result::Error> for StorageError {
+    fn from(err: diesel::result::Error) -> Self {
+        Self::DatabaseError(
+            error_stack::Report::new(err).change_context(storage_errors::DatabaseError::Others),
+        )
+    }
+}
+
 impl StorageError {
     pub fn is_db_not_found(&self) -> bool {
         match self {
@@ -98,6 +108,16 @@ impl StorageError {
         }
     }
 
+    pub fn is_db_version_conflict(&self) -> bool {
+        match self {
+            Self::DatabaseError(err) => matches!(
+                err.current_context(),
+                storage_errors::DatabaseError::VersionConflict,
+            ),
+            _ => false,
+        }
+    }
+
     pub fn is_db_unique_violation(&self) -> bool {
         match self {
             Self::DatabaseError(err) => matches!(
@@ -263,6 +283,8 @@ pub enum ConnectorError {
     MissingConnectorTransactionID,
     #[error("Missing connector refund ID")]
     MissingConnectorRefundID,
+    #[error("Missing connector payout ID")]
+    MissingConnectorPayoutID,
     #[error("Webhooks not implemented for this connector")]
     WebhooksNotImplemented,
     #[error("Failed to decode webhook event body")]
@@ -289,6 +311,8 @@ pub enum ConnectorError {
     MismatchedPaymentData,
     #[error("Failed to parse Wallet token")]
     InvalidWalletToken,
+    #[error("{connector} is currently unavailable")]
+    ConnectorUnavailable { connector: String },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -420,12 +444,16 @@ pub enum WebhooksFlowError {
     MerchantWebhookDetailsNotFound,
     #[error("Merchant does not have a webhook URL configured")]
     MerchantWebhookURLNotConfigured,
+    #[error("Failed to look up the business profile of the webhook")]
+    BusinessProfileLookupFailed,
     #[error("Payments core flow failed")]
     PaymentsCoreFailed,
     #[error("Refunds core flow failed")]
     RefundsCoreFailed,
     #[error("Dispuste core flow failed")]
     DisputeCoreFailed,
+    #[error("Payouts core flow failed")]
+    PayoutsCoreFailed,
     #[error("Webhook event creation failed")]
     WebhookEventCreationFailed,
     #[error("Unable to fork webhooks flow for outgoing webhooks")]
@@ -434,6 +462,8 @@ pub enum WebhooksFlowError {
     CallToMerchantFailed,
     #[error("Webhook not received by merchant")]
     NotReceivedByMerchant,
+    #[error("Failed to schedule the retry of the webhook")]
+    RetryTaskCreationFailed,
     #[error("Resource not found")]
     ResourceNotFound,
     #[error("Webhook source verification failed")]
@@ -87,6 +87,29 @@ pub enum ApiErrorResponse {
     NotSupported { message: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_20", message = "{flow} flow not supported by the {connector} connector")]
     FlowNotSupported { flow: String, connector: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_21", message = "{message}")]
+    PayoutNotEligible { message: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_22", message = "The credentials provided for the {connector} connector are invalid: {message}")]
+    InvalidConnectorCredentials { connector: String, message: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_23", message = "The {connector} connector is disabled for the merchant")]
+    MerchantConnectorAccountDisabled { connector: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_24", message = "The API key used does not have the {scope} scope")]
+    InsufficientApiKeyScope { scope: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_25", message = "Incorrect email or password")]
+    InvalidCredentials,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_26", message = "The user does not have the {permission} permission for the merchant")]
+    InsufficientPermission { permission: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_27", message = "The payment cannot move from the {current_status} status to the {target_status} status")]
+    InvalidPaymentStatusTransition {
+        current_status: String,
+        target_status: String,
+    },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_28", message = "Too many requests were made. Retry after some time")]
+    RateLimitExceeded,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_29", message = "One or more fields of the request are invalid")]
+    InvalidRequestFields {
+        errors: Vec<crate::utils::validation::FieldError>,
+    },
     #[error(error_type = ErrorType::ConnectorError, code = "CE_00", message = "{code}: {message}", ignore = "status_code")]
     ExternalConnectorError {
         code: String,
@@ -109,9 +132,13 @@ pub enum ApiErrorResponse {
     RefundFailed { data: Option<serde_json::Value> },
     #[error(error_type = ErrorType::ProcessingError, code = "CE_07", message = "Verification failed while processing with connector. Retry operation")]
     VerificationFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_08", message = "{connector} is currently unavailable. Retry after some time")]
+    ConnectorUnavailable { connector: String },
 
     #[error(error_type = ErrorType::ServerNotAvailable, code = "HE_00", message = "Something went wrong")]
     InternalServerError,
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "HE_00", message = "The service is temporarily unavailable. Retry after some time")]
+    ServiceUnavailable,
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "Duplicate refund request. Refund already attempted with the refund ID")]
     DuplicateRefundRequest,
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "Duplicate mandate request. Mandate already attempted with the Mandate ID")]
@@ -124,6 +151,22 @@ pub enum ApiErrorResponse {
     DuplicatePaymentMethod,
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payment with the specified payment_id '{payment_id}' already exists in our records")]
     DuplicatePayment { payment_id: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payout with the specified payout_id '{payout_id}' already exists in our records")]
+    DuplicatePayout { payout_id: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "A customer with the specified {field_name} already exists in our records")]
+    DuplicateCustomer { field_name: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "A user with the specified email already exists in our records")]
+    DuplicateUser,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "A business profile with the specified name already exists for the merchant")]
+    DuplicateBusinessProfile,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The Idempotency-Key has already been used with a different request payload")]
+    IdempotencyKeyReused,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "A request with the same Idempotency-Key is currently being processed")]
+    IdempotentRequestInProgress,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The resource was updated by another request while this request was being processed")]
+    ConcurrentUpdate,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payment is currently being processed by another request")]
+    PaymentProcessingInProgress,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Refund does not exist in our records")]
     RefundNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Customer does not exist in our records")]
@@ -144,6 +187,26 @@ pub enum ApiErrorResponse {
     MandateNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "API Key does not exist in our records")]
     ApiKeyNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Routing config does not exist in our records")]
+    RoutingConfigNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payment schedule does not exist in our records")]
+    PaymentScheduleNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payment link does not exist in our records")]
+    PaymentLinkNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payout does not exist in our records")]
+    PayoutNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Ephemeral key does not exist in our records")]
+    EphemeralKeyNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "User does not exist in our records")]
+    UserNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Organization does not exist in our records")]
+    OrganizationNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Business profile does not exist in our records")]
+    BusinessProfileNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Settlement report does not exist in our records")]
+    SettlementReportNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Dead lettered task does not exist in our records")]
+    DeadLetterTaskNotFound,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Return URL is not configured and not passed in payments request")]
     ReturnUrlUnavailable,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "This refund is not possible through Hyperswitch. Please raise the refund through {connector} dashboard")]
@@ -200,11 +263,16 @@ impl actix_web::ResponseError for ApiErrorResponse {
             Self::Unauthorized
             | Self::InvalidEphemeralKey
             | Self::InvalidJwtToken
-            | Self::GenericUnauthorized { .. } => StatusCode::UNAUTHORIZED, // 401
+            | Self::GenericUnauthorized { .. }
+            | Self::InvalidCredentials => StatusCode::UNAUTHORIZED, // 401
+            Self::InsufficientApiKeyScope { .. } | Self::InsufficientPermission { .. } => {
+                StatusCode::FORBIDDEN
+            } // 403
             Self::ExternalConnectorError { status_code, .. } => {
                 StatusCode::from_u16(*status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
             }
             Self::InvalidRequestUrl => StatusCode::NOT_FOUND, // 404
+            Self::RateLimitExceeded => StatusCode::TOO_MANY_REQUESTS, // 429
             Self::InvalidHttpMethod => StatusCode::METHOD_NOT_ALLOWED, // 405
             Self::MissingRequiredField { .. }
             | Self::InvalidDataValue { .. }
@@ -226,10 +294,16 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::RefundNotPossible { .. }
             | Self::VerificationFailed { .. }
             | Self::PaymentUnexpectedState { .. }
+            | Self::InvalidPaymentStatusTransition { .. }
+            | Self::InvalidRequestFields { .. }
             | Self::MandateValidationFailed { .. } => StatusCode::BAD_REQUEST, // 400
 
             Self::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR, // 500
             Self::DuplicateRefundRequest | Self::DuplicatePayment { .. } => StatusCode::BAD_REQUEST, // 400
+            Self::IdempotencyKeyReused => StatusCode::BAD_REQUEST, // 400
+            Self::IdempotentRequestInProgress
+            | Self::ConcurrentUpdate
+            | Self::PaymentProcessingInProgress => StatusCode::CONFLICT, // 409
             Self::RefundNotFound
             | Self::CustomerNotFound
             | Self::MandateActive
@@ -249,12 +323,31 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::AddressNotFound
             | Self::NotSupported { .. }
             | Self::FlowNotSupported { .. }
-            | Self::ApiKeyNotFound => StatusCode::BAD_REQUEST, // 400
+            | Self::PayoutNotEligible { .. }
+            | Self::InvalidConnectorCredentials { .. }
+            | Self::MerchantConnectorAccountDisabled { .. }
+            | Self::ApiKeyNotFound
+            | Self::RoutingConfigNotFound
+            | Self::PaymentScheduleNotFound
+            | Self::PaymentLinkNotFound
+            | Self::PayoutNotFound
+            | Self::EphemeralKeyNotFound
+            | Self::UserNotFound
+            | Self::OrganizationNotFound
+            | Self::BusinessProfileNotFound
+            | Self::SettlementReportNotFound
+            | Self::DeadLetterTaskNotFound => StatusCode::BAD_REQUEST, // 400
             Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount
             | Self::DuplicatePaymentMethod
-            | Self::DuplicateMandate => StatusCode::BAD_REQUEST, // 400
-            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE, // 503
+            | Self::DuplicateMandate
+            | Self::DuplicatePayout { .. }
+            | Self::DuplicateCustomer { .. }
+            | Self::DuplicateUser
+            | Self::DuplicateBusinessProfile => StatusCode::BAD_REQUEST, // 400
+            Self::ReturnUrlUnavailable
+            | Self::ConnectorUnavailable { .. }
+            | Self::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE, // 503
             Self::PaymentNotSucceeded => StatusCode::BAD_REQUEST,          // 400
             Self::NotImplemented { .. } => StatusCode::NOT_IMPLEMENTED,    // 501
         }
@@ -376,9 +469,15 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::VerificationFailed { data } => {
                 AER::BadRequest(ApiError::new("CE", 7, "Verification failed while processing with connector. Retry operation", Some(Extra { data: data.clone(), ..Default::default()})))
             }
+            Self::ConnectorUnavailable { connector } => {
+                AER::ConnectorError(ApiError::new("CE", 8, format!("{connector} is currently unavailable. Retry after some time"), Some(Extra { connector: Some(connector.clone()), ..Default::default()})), StatusCode::SERVICE_UNAVAILABLE)
+            }
             Self::InternalServerError => {
                 AER::InternalServerError(ApiError::new("HE", 0, "Something went wrong", None))
             }
+            Self::ServiceUnavailable => {
+                AER::ServiceUnavailable(ApiError::new("HE", 0, "The service is temporarily unavailable. Retry after some time", None))
+            }
             Self::DuplicateRefundRequest => AER::BadRequest(ApiError::new("HE", 1, "Duplicate refund request. Refund already attempted with the refund ID", None)),
             Self::DuplicateMandate => AER::BadRequest(ApiError::new("HE", 1, "Duplicate mandate request. Mandate already attempted with the Mandate ID", None)),
             Self::DuplicateMerchantAccount => AER::BadRequest(ApiError::new("HE", 1, "The merchant account with the specified details already exists in our records", None)),
@@ -389,6 +488,18 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::DuplicatePayment { payment_id } => {
                 AER::BadRequest(ApiError::new("HE", 1, format!("The payment with the specified payment_id '{payment_id}' already exists in our records"), None))
             }
+            Self::DuplicatePayout { payout_id } => {
+                AER::BadRequest(ApiError::new("HE", 1, format!("The payout with the specified payout_id '{payout_id}' already exists in our records"), None))
+            }
+            Self::DuplicateCustomer { field_name } => {
+                AER::BadRequest(ApiError::new("HE", 1, format!("A customer with the specified {field_name} already exists in our records"), None))
+            }
+            Self::DuplicateUser => AER::BadRequest(ApiError::new("HE", 1, "A user with the specified email already exists in our records", None)),
+            Self::DuplicateBusinessProfile => AER::BadRequest(ApiError::new("HE", 1, "A business profile with the specified name already exists for the merchant", None)),
+            Self::IdempotencyKeyReused => AER::BadRequest(ApiError::new("HE", 1, "The Idempotency-Key has already been used with a different request payload", None)),
+            Self::IdempotentRequestInProgress => AER::Conflict(ApiError::new("HE", 1, "A request with the same Idempotency-Key is currently being processed", None)),
+            Self::ConcurrentUpdate => AER::Conflict(ApiError::new("HE", 1, "The resource was updated by another request while this request was being processed", None)),
+            Self::PaymentProcessingInProgress => AER::Conflict(ApiError::new("HE", 1, "The payment is currently being processed by another request", None)),
             Self::RefundNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Refund does not exist in our records.", None))
             }
@@ -436,6 +547,36 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::ApiKeyNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "API Key does not exist in our records", None))
             }
+            Self::RoutingConfigNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Routing config does not exist in our records", None))
+            }
+            Self::PaymentScheduleNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Payment schedule does not exist in our records", None))
+            }
+            Self::PaymentLinkNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Payment link does not exist in our records", None))
+            }
+            Self::PayoutNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Payout does not exist in our records", None))
+            }
+            Self::EphemeralKeyNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Ephemeral key does not exist in our records", None))
+            }
+            Self::UserNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "User does not exist in our records", None))
+            }
+            Self::OrganizationNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Organization does not exist in our records", None))
+            }
+            Self::BusinessProfileNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Business profile does not exist in our records", None))
+            }
+            Self::SettlementReportNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Settlement report does not exist in our records", None))
+            }
+            Self::DeadLetterTaskNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Dead lettered task does not exist in our records", None))
+            }
             Self::NotSupported { message } => {
                 AER::BadRequest(ApiError::new("HE", 3, "Payment method type not supported", Some(Extra {reason: Some(message.to_owned()), ..Default::default()})))
             },
@@ -444,6 +585,30 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::FlowNotSupported { flow, connector } => {
                 AER::BadRequest(ApiError::new("IR", 20, format!("{flow} flow not supported"), Some(Extra {connector: Some(connector.to_owned()), ..Default::default()}))) //FIXME: error message
             }
+            Self::PayoutNotEligible { message } => {
+                AER::BadRequest(ApiError::new("IR", 21, message.to_string(), None))
+            }
+            Self::InvalidConnectorCredentials { connector, message } => {
+                AER::BadRequest(ApiError::new("IR", 22, format!("The credentials provided for the {connector} connector are invalid: {message}"), Some(Extra {connector: Some(connector.to_owned()), ..Default::default()})))
+            }
+            Self::MerchantConnectorAccountDisabled { connector } => {
+                AER::BadRequest(ApiError::new("IR", 23, format!("The {connector} connector is disabled for the merchant"), Some(Extra {connector: Some(connector.to_owned()), ..Default::default()})))
+            }
+            Self::InsufficientApiKeyScope { scope } => {
+                AER::ForbiddenCommonResource(ApiError::new("IR", 24, format!("The API key used does not have the {scope} scope"), None))
+            }
+            Self::InvalidCredentials => AER::Unauthorized(ApiError::new("IR", 25, "Incorrect email or password", None)),
+            Self::InsufficientPermission { permission } => {
+                AER::ForbiddenCommonResource(ApiError::new("IR", 26, format!("The user does not have the {permission} permission for the merchant"), None))
+            }
+            Self::InvalidPaymentStatusTransition {
+                current_status,
+                target_status,
+            } => AER::BadRequest(ApiError::new("IR", 27, format!("The payment cannot move from the {current_status} status to the {target_status} status"), None)),
+            Self::RateLimitExceeded => AER::TooManyRequests(ApiError::new("IR", 28, "Too many requests were made. Retry after some time", None)),
+            Self::InvalidRequestFields { errors } => {
+                AER::BadRequest(ApiError::new("IR", 29, "One or more fields of the request are invalid", Some(Extra { data: serde_json::to_value(errors).ok(), ..Default::default() })))
+            }
         }
     }
 }
@@ -23,6 +23,9 @@ impl StorageErrorExt for error_stack::Report<errors::StorageError> {
         if self.current_context().is_db_not_found() {
             return self.change_context(not_found_response);
         }
+        if self.current_context().is_db_version_conflict() {
+            return self.change_context(errors::ApiErrorResponse::ConcurrentUpdate);
+        }
         match self.current_context() {
             errors::StorageError::CustomerRedacted => {
                 self.change_context(errors::ApiErrorResponse::CustomerRedacted)
@@ -54,6 +57,12 @@ pub trait ConnectorErrorExt {
 
 impl ConnectorErrorExt for error_stack::Report<errors::ConnectorError> {
     fn to_refund_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse> {
+        if let errors::ConnectorError::ConnectorUnavailable { connector } = self.current_context() {
+            let connector = connector.to_owned();
+            return self
+                .change_context(errors::ApiErrorResponse::ConnectorUnavailable { connector });
+        }
+
         let data = match self.current_context() {
             errors::ConnectorError::ProcessingStepFailed(Some(bytes)) => {
                 let response_str = std::str::from_utf8(bytes);
@@ -113,6 +122,9 @@ impl ConnectorErrorExt for error_stack::Report<errors::ConnectorError> {
             errors::ConnectorError::FlowNotSupported{ flow, connector } => {
                 errors::ApiErrorResponse::FlowNotSupported { flow: flow.to_owned(), connector: connector.to_owned() }
             }
+            errors::ConnectorError::ConnectorUnavailable { connector } => {
+                errors::ApiErrorResponse::ConnectorUnavailable { connector: connector.to_owned() }
+            }
             _ => errors::ApiErrorResponse::InternalServerError,
         };
         self.change_context(error)
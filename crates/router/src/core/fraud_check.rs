@@ -0,0 +1,517 @@
+//! Fraud rules of merchants, which are evaluated against a payment before it is sent to the
+//! connector.
+//!
+//! The rules are stored in the configs table against the key returned by
+//! [`get_fraud_rules_key`]. A payment which matches the allow list of the merchant is never acted
+//! upon, and a payment which matches the deny list is always blocked. Otherwise, the strictest
+//! action of all the rules matched by the payment is taken.
+
+use std::str::FromStr;
+
+use common_utils::crypto::{self, GenerateDigest};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+pub mod frm;
+
+use super::payments::{state_machine, PaymentData};
+use crate::{
+    core::errors::{self, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    types::{
+        self, api,
+        api::enums as api_enums,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils::StringExt,
+};
+
+const CARD_BIN_LENGTH: usize = 6;
+
+/// Actions taken on payments matching the fraud rules, ordered from the most lenient to the
+/// strictest.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum FraudAction {
+    /// The customer is authenticated with 3DS, regardless of the authentication type of the
+    /// payment
+    ForceThreeDs,
+    /// The payment is authorized and held for review, to be approved or rejected by the merchant
+    ManualReview,
+    /// The payment is declined without being sent to the connector
+    Block,
+}
+
+/// The attribute of a payment which attempts are counted against by a velocity rule.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityDimension {
+    Card,
+    Ip,
+    Customer,
+}
+
+/// Takes the action on a payment if more than `max_attempts` payments were attempted with the
+/// same card, IP address or customer in a window of `window` seconds.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct VelocityRule {
+    pub dimension: VelocityDimension,
+    pub max_attempts: i64,
+    pub window: i64,
+    pub action: FraudAction,
+}
+
+/// Takes the action on a payment whose amount exceeds `max_amount`, for payments in `currency`,
+/// or in any currency if it is not specified.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct AmountRule {
+    pub currency: Option<api_enums::Currency>,
+    pub max_amount: i64,
+    pub action: FraudAction,
+}
+
+/// Card BINs, IP addresses, customers and emails which a payment is matched against.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct FraudList {
+    pub card_bins: Vec<String>,
+    pub ip_addresses: Vec<String>,
+    pub customer_ids: Vec<String>,
+    pub emails: Vec<String>,
+}
+
+impl FraudList {
+    fn matches(&self, input: &FraudCheckInput) -> bool {
+        let is_listed = |list: &[String], value: &Option<String>| {
+            value.as_ref().map_or(false, |value| list.contains(value))
+        };
+
+        input.card_bin.as_ref().map_or(false, |card_bin| {
+            self.card_bins
+                .iter()
+                .any(|listed_bin| card_bin.starts_with(listed_bin.as_str()))
+        }) || is_listed(&self.ip_addresses, &input.ip_address)
+            || is_listed(&self.customer_ids, &input.customer_id)
+            || is_listed(&self.emails, &input.email)
+    }
+}
+
+/// Per merchant rules for acting upon payments suspected to be fraudulent.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct FraudRules {
+    pub velocity_rules: Vec<VelocityRule>,
+    pub amount_rules: Vec<AmountRule>,
+    /// Action taken when the card was issued in a different country than the country of the IP
+    /// address of the customer
+    pub country_mismatch_action: Option<FraudAction>,
+    pub allow_list: FraudList,
+    pub deny_list: FraudList,
+}
+
+/// The attributes of a payment which the fraud rules are evaluated against.
+#[derive(Clone, Debug, Default)]
+pub struct FraudCheckInput {
+    /// A hash of the card number, so that the card number itself is not stored with the
+    /// velocity counts
+    pub card_fingerprint: Option<String>,
+    pub card_bin: Option<String>,
+    pub card_country: Option<api_enums::CountryCode>,
+    pub ip_address: Option<String>,
+    pub ip_country: Option<api_enums::CountryCode>,
+    pub customer_id: Option<String>,
+    pub email: Option<String>,
+    pub amount: i64,
+    pub currency: api_enums::Currency,
+}
+
+impl FraudCheckInput {
+    fn get_velocity_value(&self, dimension: VelocityDimension) -> Option<&str> {
+        match dimension {
+            VelocityDimension::Card => self.card_fingerprint.as_deref(),
+            VelocityDimension::Ip => self.ip_address.as_deref(),
+            VelocityDimension::Customer => self.customer_id.as_deref(),
+        }
+    }
+}
+
+/// The IP address of the customer and its country, as provided by the merchant in the browser
+/// information of the payment.
+#[derive(Debug, Default, serde::Deserialize)]
+struct CustomerIpInformation {
+    ip_address: Option<String>,
+    ip_country: Option<api_enums::CountryCode>,
+}
+
+pub fn get_fraud_rules_key(merchant_id: &str) -> String {
+    format!("fraud_rules_{merchant_id}")
+}
+
+fn get_velocity_key(merchant_id: &str, rule: &VelocityRule, value: &str) -> String {
+    format!(
+        "fraud_velocity_{merchant_id}_{}_{}_{value}",
+        rule.dimension, rule.window
+    )
+}
+
+#[instrument(skip_all)]
+pub async fn get_fraud_rules(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<FraudRules>> {
+    let config = match db
+        .find_config_by_key_cached(&get_fraud_rules_key(merchant_id))
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Failed to fetch fraud rules")
+        }
+    };
+
+    config
+        .config
+        .parse_struct("FraudRules")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid fraud rules")
+        .map(Some)
+}
+
+/// Returns the strictest action of the fraud rules matched by the payment, if any. The velocity
+/// counts are those of the velocity rules, in order, and are not available for payments without
+/// the attribute counted by the rule.
+pub fn evaluate_fraud_rules(
+    rules: &FraudRules,
+    input: &FraudCheckInput,
+    velocity_counts: &[Option<i64>],
+) -> Option<FraudAction> {
+    if rules.allow_list.matches(input) {
+        return None;
+    }
+    if rules.deny_list.matches(input) {
+        return Some(FraudAction::Block);
+    }
+
+    let amount_actions = rules
+        .amount_rules
+        .iter()
+        .filter(|rule| {
+            rule.currency
+                .map_or(true, |currency| currency == input.currency)
+                && input.amount > rule.max_amount
+        })
+        .map(|rule| rule.action);
+
+    let velocity_actions = rules
+        .velocity_rules
+        .iter()
+        .zip(velocity_counts)
+        .filter(|(rule, count)| count.map_or(false, |count| count > rule.max_attempts))
+        .map(|(rule, _)| rule.action);
+
+    let country_mismatch_action = match (input.card_country, input.ip_country) {
+        (Some(card_country), Some(ip_country)) if card_country != ip_country => {
+            rules.country_mismatch_action
+        }
+        _ => None,
+    };
+
+    amount_actions
+        .chain(velocity_actions)
+        .chain(country_mismatch_action)
+        .max()
+}
+
+async fn get_fraud_check_input<F: Clone>(
+    db: &dyn StorageInterface,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<FraudCheckInput> {
+    let (card_fingerprint, card_bin) = match payment_data.payment_method_data {
+        Some(api::PaymentMethodData::Card(ref card)) => {
+            let card_number = card.card_number.peek();
+            let digest = crypto::Sha256
+                .generate_digest(card_number.as_bytes())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to hash card number for fraud check")?;
+            (
+                Some(hex::encode(digest)),
+                card_number.get(..CARD_BIN_LENGTH).map(ToOwned::to_owned),
+            )
+        }
+        _ => (None, None),
+    };
+
+    // The country of the card is not known for BINs missing from the cards info
+    let card_country = match card_bin {
+        Some(ref card_bin) => db
+            .get_card_info(card_bin)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch card info for fraud check")?
+            .and_then(|card_info| card_info.country_code)
+            .and_then(|country_code| api_enums::CountryCode::from_str(&country_code).ok()),
+        None => None,
+    };
+
+    let ip_information: CustomerIpInformation = payment_data
+        .payment_attempt
+        .browser_info
+        .clone()
+        .and_then(|browser_info| serde_json::from_value(browser_info).ok())
+        .unwrap_or_default();
+
+    Ok(FraudCheckInput {
+        card_fingerprint,
+        card_bin,
+        card_country,
+        ip_address: ip_information.ip_address,
+        ip_country: ip_information.ip_country,
+        customer_id: payment_data.payment_intent.customer_id.clone(),
+        email: payment_data
+            .email
+            .as_ref()
+            .map(|email| email.peek().to_owned()),
+        amount: payment_data.amount.get_amount_as_i64(),
+        currency: payment_data.currency.get_currency().foreign_into(),
+    })
+}
+
+/// Counts the attempt of the payment against each of the velocity rules. Failures to count the
+/// attempt are logged, and the rule is not applied to the payment.
+async fn get_velocity_counts(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    rules: &FraudRules,
+    input: &FraudCheckInput,
+) -> Vec<Option<i64>> {
+    let mut velocity_counts = Vec::with_capacity(rules.velocity_rules.len());
+    for rule in rules.velocity_rules.iter() {
+        let count = match input.get_velocity_value(rule.dimension) {
+            Some(value) => db
+                .increment_velocity_count(&get_velocity_key(merchant_id, rule, value), rule.window)
+                .await
+                .map_err(|error| logger::error!(velocity_count_error=?error))
+                .ok(),
+            None => None,
+        };
+        velocity_counts.push(count);
+    }
+    velocity_counts
+}
+
+/// Evaluates the fraud rules of the merchant against the payment, returning the action taken on
+/// the payment. Payments which are to be authenticated or reviewed are updated accordingly, while
+/// blocked payments are to be declined with [`decline_payment`].
+///
+/// Payments held for review are authorized with a manual capture, so that the approval of the
+/// payment by the merchant captures it.
+#[instrument(skip_all)]
+pub async fn perform_fraud_check<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<Option<FraudAction>> {
+    let db = &*state.store;
+    let rules = match get_fraud_rules(db, &merchant_account.merchant_id).await? {
+        Some(rules) => rules,
+        None => return Ok(None),
+    };
+
+    let input = get_fraud_check_input(db, payment_data).await?;
+    let velocity_counts =
+        get_velocity_counts(db, &merchant_account.merchant_id, &rules, &input).await;
+    let action = evaluate_fraud_rules(&rules, &input, &velocity_counts);
+    prepare_payment_for_action(payment_data, action);
+
+    if let Some(action) = action {
+        logger::info!(
+            payment_id = %payment_data.payment_intent.payment_id,
+            fraud_action = ?action,
+            "Payment matched the fraud rules of the merchant"
+        );
+    }
+
+    Ok(action)
+}
+
+/// Updates a payment which is to be authenticated or reviewed, before it is sent to the
+/// connector.
+fn prepare_payment_for_action<F: Clone>(
+    payment_data: &mut PaymentData<F>,
+    action: Option<FraudAction>,
+) {
+    match action {
+        Some(FraudAction::ForceThreeDs) => {
+            payment_data.payment_attempt.authentication_type =
+                Some(storage_enums::AuthenticationType::ThreeDs);
+        }
+        Some(FraudAction::ManualReview) => {
+            payment_data.payment_attempt.capture_method =
+                Some(storage_enums::CaptureMethod::Manual);
+        }
+        Some(FraudAction::Block) | None => {}
+    }
+}
+
+/// Declines a payment blocked by the fraud rules of the merchant or by its fraud check provider.
+#[instrument(skip_all)]
+pub async fn decline_payment<F: Clone>(
+    db: &dyn StorageInterface,
+    payment_data: &mut PaymentData<F>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<()> {
+    let unified_code = api_enums::UnifiedCode::FraudSuspected;
+    state_machine::validate_intent_status_transition(
+        payment_data.payment_intent.status,
+        storage_enums::IntentStatus::Failed,
+    )?;
+    state_machine::validate_attempt_status_transition(
+        payment_data.payment_attempt.status,
+        storage_enums::AttemptStatus::RouterDeclined,
+    )?;
+    payment_data.payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::ErrorUpdate {
+                connector: None,
+                status: storage_enums::AttemptStatus::RouterDeclined,
+                error_code: Some(unified_code.to_string()),
+                error_message: Some(unified_code.get_unified_message().to_string()),
+                unified_code: Some(unified_code.to_string()),
+                unified_message: Some(unified_code.get_unified_message().to_string()),
+                connector_raw_response: None,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent.clone(),
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: storage_enums::IntentStatus::Failed,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok(())
+}
+
+/// Holds the authorization of a payment for the review of the merchant.
+pub fn hold_for_review<F, Req>(
+    router_data: &mut types::RouterData<F, Req, types::PaymentsResponseData>,
+) {
+    if router_data.status == storage_enums::AttemptStatus::Authorized {
+        router_data.status = storage_enums::AttemptStatus::ManualReview;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> FraudRules {
+        FraudRules {
+            velocity_rules: vec![VelocityRule {
+                dimension: VelocityDimension::Card,
+                max_attempts: 3,
+                window: 3600,
+                action: FraudAction::Block,
+            }],
+            amount_rules: vec![
+                AmountRule {
+                    currency: Some(api_enums::Currency::USD),
+                    max_amount: 100000,
+                    action: FraudAction::ManualReview,
+                },
+                AmountRule {
+                    currency: None,
+                    max_amount: 10000,
+                    action: FraudAction::ForceThreeDs,
+                },
+            ],
+            country_mismatch_action: Some(FraudAction::ManualReview),
+            allow_list: FraudList {
+                customer_ids: vec!["trusted_customer".to_string()],
+                ..Default::default()
+            },
+            deny_list: FraudList {
+                card_bins: vec!["400000".to_string()],
+                emails: vec!["fraudster@example.com".to_string()],
+                ..Default::default()
+            },
+        }
+    }
+
+    fn input() -> FraudCheckInput {
+        FraudCheckInput {
+            card_fingerprint: Some("fingerprint".to_string()),
+            card_bin: Some("424242".to_string()),
+            card_country: Some(api_enums::CountryCode::US),
+            ip_country: Some(api_enums::CountryCode::US),
+            amount: 100,
+            currency: api_enums::Currency::USD,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_payment_matching_no_rule_is_allowed() {
+        assert_eq!(evaluate_fraud_rules(&rules(), &input(), &[Some(1)]), None);
+    }
+
+    #[test]
+    fn test_strictest_action_is_taken() {
+        let input = FraudCheckInput {
+            amount: 200000,
+            ip_country: Some(api_enums::CountryCode::DE),
+            ..input()
+        };
+
+        assert_eq!(
+            evaluate_fraud_rules(&rules(), &input, &[Some(1)]),
+            Some(FraudAction::ManualReview)
+        );
+        assert_eq!(
+            evaluate_fraud_rules(&rules(), &input, &[Some(4)]),
+            Some(FraudAction::Block)
+        );
+    }
+
+    #[test]
+    fn test_unavailable_velocity_count_is_not_applied() {
+        assert_eq!(evaluate_fraud_rules(&rules(), &input(), &[None]), None);
+    }
+
+    #[test]
+    fn test_allow_list_takes_precedence_over_deny_list() {
+        let input = FraudCheckInput {
+            card_bin: Some("40000012".to_string()),
+            ..input()
+        };
+        assert_eq!(
+            evaluate_fraud_rules(&rules(), &input, &[Some(1)]),
+            Some(FraudAction::Block)
+        );
+
+        let input = FraudCheckInput {
+            customer_id: Some("trusted_customer".to_string()),
+            ..input
+        };
+        assert_eq!(evaluate_fraud_rules(&rules(), &input, &[Some(10)]), None);
+    }
+}
@@ -0,0 +1,413 @@
+//! Checks of payments with the external fraud check provider (FRM) of the merchant, configured as
+//! a merchant connector account of type `payment_vas` with FRM configs.
+//!
+//! Depending on the preferred flow type of the FRM configs, a payment is checked either before it
+//! is sent to the connector (`pre`), or right after it is authorized (`post`). The verdict and the
+//! score of the provider are stored against the payment attempt.
+
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use super::{hold_for_review, prepare_payment_for_action, FraudAction};
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::{helpers, PaymentData},
+        refunds, secrets,
+    },
+    logger,
+    routes::AppState,
+    services::fraud_check_connector::{
+        self, BoxedFraudCheckConnector, FraudCheckCheckpoint, FraudCheckRequest, FraudCheckResponse,
+    },
+    types::{
+        self, api,
+        api::enums as api_enums,
+        storage::{self, enums as storage_enums},
+    },
+    utils::ValueExt,
+};
+
+const FRAUD_CHECK_DECLINED_REASON: &str = "fraud_check_declined";
+
+/// The fraud check provider of a merchant, along with its credentials and FRM configs.
+struct FraudCheckProvider {
+    connector: BoxedFraudCheckConnector,
+    auth_type: types::ConnectorAuthType,
+    frm_configs: api_models::admin::FrmConfigs,
+}
+
+impl FraudCheckProvider {
+    /// Payments are checked unless the FRM configs restrict the checks to other payment methods,
+    /// payment method types or connectors.
+    fn is_enabled_for(
+        &self,
+        payment_attempt: &storage::PaymentAttempt,
+        connector_name: &str,
+    ) -> bool {
+        let is_enabled = |enabled: &Option<Vec<String>>, value: Option<String>| {
+            enabled.as_ref().map_or(true, |enabled| {
+                value.map_or(false, |value| enabled.contains(&value))
+            })
+        };
+
+        is_enabled(
+            &self.frm_configs.frm_enabled_pms,
+            payment_attempt.payment_method.map(|pm| pm.to_string()),
+        ) && is_enabled(
+            &self.frm_configs.frm_enabled_pm_types,
+            payment_attempt
+                .payment_method_type
+                .as_ref()
+                .map(ToString::to_string),
+        ) && is_enabled(
+            &self.frm_configs.frm_enabled_gateways,
+            Some(connector_name.to_string()),
+        )
+    }
+
+    fn is_checked_at(&self, checkpoint: FraudCheckCheckpoint) -> bool {
+        match self.frm_configs.frm_preferred_flow_type {
+            api_enums::FrmPreferredFlowTypes::Pre => checkpoint == FraudCheckCheckpoint::PreAuth,
+            api_enums::FrmPreferredFlowTypes::Post => checkpoint == FraudCheckCheckpoint::PostAuth,
+        }
+    }
+}
+
+/// Returns the action taken on a payment checked before authorization, based on the verdict of
+/// the provider and the action configured for declined payments.
+pub fn get_pre_auth_action(
+    verdict: storage_enums::FraudCheckVerdict,
+    frm_action: api_enums::FrmAction,
+) -> Option<FraudAction> {
+    match (verdict, frm_action) {
+        (storage_enums::FraudCheckVerdict::Accept, _) => None,
+        (storage_enums::FraudCheckVerdict::Review, _)
+        | (storage_enums::FraudCheckVerdict::Decline, api_enums::FrmAction::ManualReview) => {
+            Some(FraudAction::ManualReview)
+        }
+        (
+            storage_enums::FraudCheckVerdict::Decline,
+            api_enums::FrmAction::CancelTxn | api_enums::FrmAction::AutoRefund,
+        ) => Some(FraudAction::Block),
+    }
+}
+
+#[instrument(skip_all)]
+async fn get_fraud_check_provider(
+    state: &AppState,
+    merchant_id: &str,
+) -> RouterResult<Option<FraudCheckProvider>> {
+    let frm_mca = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(merchant_id, false)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant connector accounts")?
+        .into_iter()
+        .filter(|mca| mca.connector_type == storage_enums::ConnectorType::PaymentVas)
+        .find_map(|mca| {
+            mca.frm_configs
+                .clone()
+                .map(|frm_configs| (mca, frm_configs))
+        });
+
+    let (frm_mca, frm_configs) = match frm_mca {
+        Some(frm_mca) => frm_mca,
+        None => return Ok(None),
+    };
+
+    let frm_configs: api_models::admin::FrmConfigs = frm_configs
+        .parse_value("FrmConfigs")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while parsing value for FrmConfigs")?;
+
+    let auth_type: types::ConnectorAuthType =
+        secrets::decrypt_connector_account_details(state, &frm_mca)
+            .await?
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while parsing value for ConnectorAuthType")?;
+
+    Ok(Some(FraudCheckProvider {
+        connector: fraud_check_connector::get_fraud_check_connector_by_name(
+            &frm_mca.connector_name,
+        )?,
+        auth_type,
+        frm_configs,
+    }))
+}
+
+/// Checks the payment with the provider and stores the verdict against the payment attempt.
+///
+/// Failures to check the payment with the provider are logged, and the payment is processed as
+/// if it was not checked.
+async fn check_payment<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    provider: &FraudCheckProvider,
+    payment_data: &mut PaymentData<F>,
+    request: FraudCheckRequest,
+) -> RouterResult<Option<FraudCheckResponse>> {
+    let response = match provider
+        .connector
+        .check(state, &provider.auth_type, &request)
+        .await
+    {
+        Ok(response) => response,
+        Err(error) => {
+            logger::error!(
+                fraud_check_error = ?error,
+                provider = provider.connector.id(),
+                "Failed to check the payment with the fraud check provider"
+            );
+            return Ok(None);
+        }
+    };
+
+    logger::info!(
+        payment_id = %request.payment_id,
+        checkpoint = ?request.checkpoint,
+        verdict = %response.verdict,
+        score = ?response.score,
+        reason = ?response.reason,
+        "Payment was checked by the fraud check provider"
+    );
+
+    payment_data.payment_attempt = state
+        .store
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::FraudCheckUpdate {
+                fraud_check_verdict: response.verdict,
+                fraud_check_score: response.score,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok(Some(response))
+}
+
+fn get_fraud_check_request<F: Clone>(
+    checkpoint: FraudCheckCheckpoint,
+    payment_data: &PaymentData<F>,
+    connector_name: String,
+) -> FraudCheckRequest {
+    let payment_attempt = &payment_data.payment_attempt;
+    FraudCheckRequest {
+        checkpoint,
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        amount: payment_data.amount.get_amount_as_i64(),
+        currency: payment_data.currency.get_currency(),
+        payment_method: payment_attempt.payment_method,
+        payment_method_data: payment_data.payment_method_data.clone(),
+        browser_info: payment_attempt.browser_info.clone(),
+        email: payment_data.email.clone(),
+        customer_id: payment_data.payment_intent.customer_id.clone(),
+        connector: connector_name,
+        status: None,
+        connector_transaction_id: None,
+    }
+}
+
+/// Checks the payment with the fraud check provider of the merchant before it is sent to the
+/// connector, if the provider is configured to check payments before authorization. Returns the
+/// action taken on the payment, which is prepared for the action as with the fraud rules.
+#[instrument(skip_all)]
+pub async fn perform_pre_auth_check<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: Option<&api::ConnectorCallType>,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<Option<FraudAction>> {
+    let connector_name = match connector {
+        Some(api::ConnectorCallType::Single(connector)) => connector.connector_name.to_string(),
+        Some(api::ConnectorCallType::Multiple(_)) | None => return Ok(None),
+    };
+
+    let provider = match get_fraud_check_provider(state, &merchant_account.merchant_id).await? {
+        Some(provider)
+            if provider.is_checked_at(FraudCheckCheckpoint::PreAuth)
+                && provider.is_enabled_for(&payment_data.payment_attempt, &connector_name) =>
+        {
+            provider
+        }
+        _ => return Ok(None),
+    };
+
+    let request =
+        get_fraud_check_request(FraudCheckCheckpoint::PreAuth, payment_data, connector_name);
+    let action = check_payment(state, merchant_account, &provider, payment_data, request)
+        .await?
+        .and_then(|response| {
+            get_pre_auth_action(response.verdict, provider.frm_configs.frm_action)
+        });
+    prepare_payment_for_action(payment_data, action);
+
+    Ok(action)
+}
+
+/// Checks the payment with the fraud check provider of the merchant right after it is authorized,
+/// if the provider is configured to check payments after authorization.
+///
+/// Payments to be reviewed are held for review if they are yet to be captured. Declined payments
+/// are either held for review, or voided if they are yet to be captured, depending on the FRM
+/// action of the merchant. Returns `true` if the declined payment was captured, in which case it
+/// is to be refunded with [`refund_declined_payment`] once the trackers are updated.
+#[instrument(skip_all)]
+pub async fn perform_post_auth_check<F: Clone, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+    router_data: &mut types::RouterData<F, Req, types::PaymentsResponseData>,
+) -> RouterResult<bool> {
+    let connector_transaction_id = match &router_data.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            resource_id: types::ResponseId::ConnectorTransactionId(connector_transaction_id),
+            ..
+        }) if matches!(
+            router_data.status,
+            storage_enums::AttemptStatus::Authorized | storage_enums::AttemptStatus::Charged
+        ) =>
+        {
+            connector_transaction_id.clone()
+        }
+        _ => return Ok(false),
+    };
+
+    let provider = match get_fraud_check_provider(state, &merchant_account.merchant_id).await? {
+        Some(provider)
+            if provider.is_checked_at(FraudCheckCheckpoint::PostAuth)
+                && provider
+                    .is_enabled_for(&payment_data.payment_attempt, &router_data.connector) =>
+        {
+            provider
+        }
+        _ => return Ok(false),
+    };
+
+    let request = FraudCheckRequest {
+        status: Some(router_data.status),
+        connector_transaction_id: Some(connector_transaction_id),
+        ..get_fraud_check_request(
+            FraudCheckCheckpoint::PostAuth,
+            payment_data,
+            router_data.connector.clone(),
+        )
+    };
+    let verdict =
+        match check_payment(state, merchant_account, &provider, payment_data, request).await? {
+            Some(response) => response.verdict,
+            None => return Ok(false),
+        };
+
+    let is_captured = router_data.status == storage_enums::AttemptStatus::Charged;
+    match (verdict, provider.frm_configs.frm_action) {
+        (storage_enums::FraudCheckVerdict::Accept, _) => Ok(false),
+        (storage_enums::FraudCheckVerdict::Review, _)
+        | (storage_enums::FraudCheckVerdict::Decline, api_enums::FrmAction::ManualReview) => {
+            if is_captured {
+                logger::warn!(
+                    payment_id = %router_data.payment_id,
+                    "Captured payment cannot be held for review"
+                );
+            } else {
+                hold_for_review(router_data);
+            }
+            Ok(false)
+        }
+        (
+            storage_enums::FraudCheckVerdict::Decline,
+            api_enums::FrmAction::CancelTxn | api_enums::FrmAction::AutoRefund,
+        ) => {
+            if is_captured {
+                return Ok(true);
+            }
+            if !helpers::void_authorization(
+                state,
+                payment_data,
+                router_data,
+                FRAUD_CHECK_DECLINED_REASON,
+            )
+            .await?
+            {
+                logger::error!(
+                    payment_id = %router_data.payment_id,
+                    "Failed to void the authorization declined by the fraud check provider"
+                );
+            }
+            Ok(false)
+        }
+    }
+}
+
+/// Refunds a captured payment declined by the fraud check provider. Failures to refund the
+/// payment are logged, and the payment is left to be refunded by the merchant.
+#[instrument(skip_all)]
+pub async fn refund_declined_payment<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &PaymentData<F>,
+) {
+    let refund_request = api::refunds::RefundRequest {
+        payment_id: payment_data.payment_intent.payment_id.clone(),
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        reason: Some(FRAUD_CHECK_DECLINED_REASON.to_string()),
+        refund_type: Some(api::refunds::RefundType::Instant),
+        ..Default::default()
+    };
+
+    if let Err(error) =
+        refunds::refund_create_core(state, merchant_account.clone(), refund_request).await
+    {
+        logger::error!(
+            ?error,
+            payment_id = %payment_data.payment_intent.payment_id,
+            "Failed to refund the payment declined by the fraud check provider"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pre_auth_action_of_declined_payment_follows_frm_action() {
+        assert_eq!(
+            get_pre_auth_action(
+                storage_enums::FraudCheckVerdict::Decline,
+                api_enums::FrmAction::CancelTxn
+            ),
+            Some(FraudAction::Block)
+        );
+        assert_eq!(
+            get_pre_auth_action(
+                storage_enums::FraudCheckVerdict::Decline,
+                api_enums::FrmAction::ManualReview
+            ),
+            Some(FraudAction::ManualReview)
+        );
+    }
+
+    #[test]
+    fn test_pre_auth_action_of_accepted_and_reviewed_payments() {
+        assert_eq!(
+            get_pre_auth_action(
+                storage_enums::FraudCheckVerdict::Accept,
+                api_enums::FrmAction::AutoRefund
+            ),
+            None
+        );
+        assert_eq!(
+            get_pre_auth_action(
+                storage_enums::FraudCheckVerdict::Review,
+                api_enums::FrmAction::CancelTxn
+            ),
+            Some(FraudAction::ManualReview)
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Readiness of the router to serve requests, which depends on the database, Redis and, if one is
+//! configured, a canary connector being reachable. Every dependency is checked concurrently,
+//! within the configured timeout.
+
+use std::{future::Future, time::Duration};
+
+use api_models::health_check::{DependencyHealth, HealthStatus, ReadinessResponse};
+use router_env::{instrument, tracing};
+
+use crate::routes::{metrics, AppState};
+
+async fn check_dependency<F>(check: F, timeout: Duration) -> DependencyHealth
+where
+    F: Future<Output = Result<(), String>>,
+{
+    let (result, latency) = metrics::utils::time_future(tokio::time::timeout(timeout, check)).await;
+    let result = result.unwrap_or_else(|_| Err("Timed out".to_string()));
+
+    DependencyHealth {
+        status: if result.is_ok() {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        },
+        latency: u64::try_from(latency.as_millis()).unwrap_or(u64::MAX),
+        error: result.err(),
+    }
+}
+
+/// A canary connector is reachable if it responds at all, whatever the status of the response.
+async fn check_canary_connector(url: &str) -> Result<(), String> {
+    reqwest::Client::new()
+        .get(url)
+        .send()
+        .await
+        .map(|_| ())
+        .map_err(|error| error.to_string())
+}
+
+#[instrument(skip_all)]
+pub async fn check_readiness(state: &AppState) -> ReadinessResponse {
+    let config = &state.conf.health_check;
+    let timeout = Duration::from_millis(config.timeout);
+
+    let database = check_dependency(
+        async {
+            state
+                .store
+                .health_check_db()
+                .await
+                .map_err(|error| error.current_context().to_string())
+        },
+        timeout,
+    );
+    let redis = check_dependency(
+        async {
+            state
+                .store
+                .health_check_redis()
+                .await
+                .map_err(|error| error.current_context().to_string())
+        },
+        timeout,
+    );
+    let canary_connector = async {
+        match config.canary_connector_url.as_deref() {
+            Some(url) => Some(check_dependency(check_canary_connector(url), timeout).await),
+            None => None,
+        }
+    };
+    let (database, redis, canary_connector) = futures::join!(database, redis, canary_connector);
+
+    let is_ready = [Some(&database), Some(&redis), canary_connector.as_ref()]
+        .into_iter()
+        .flatten()
+        .all(|dependency| dependency.status == HealthStatus::Up);
+
+    ReadinessResponse {
+        status: if is_ready {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        },
+        database,
+        redis,
+        canary_connector,
+    }
+}
@@ -0,0 +1,447 @@
+//! The ledger of a merchant, which records the events moving the funds of the merchant as balance
+//! transactions.
+//!
+//! The ledger is double-entry: each balance transaction moves an amount out of one account and
+//! into another. Authorizing a payment moves its amount from outside the ledger into the pending
+//! account, capturing it moves the amount from the pending account into the available account, and
+//! refunds, lost disputes and payouts move amounts out of the available account. The balance of an
+//! account is the sum of the amounts moved into it less the sum of the amounts moved out of it.
+//!
+//! Each event is recorded at most once, so recording is safe to repeat as payments, refunds,
+//! disputes and payouts are synced. Failing to record an event is logged rather than failing the
+//! operation which caused it, since the operation has already been processed by the connector.
+
+use std::collections::HashMap;
+
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    db::StorageInterface,
+    services::ApplicationResponse,
+    types::{
+        api,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils,
+};
+
+const MAX_BALANCE_TRANSACTIONS_LIST_LIMIT: i64 = 100;
+
+struct LedgerEvent<'a> {
+    merchant_id: &'a str,
+    transaction_type: storage_enums::BalanceTransactionType,
+    source_id: &'a str,
+    payment_id: Option<&'a str>,
+    amount: i64,
+    currency: storage_enums::Currency,
+}
+
+/// Returns the accounts the amount of an event is moved into and out of, respectively.
+fn get_ledger_accounts(
+    transaction_type: storage_enums::BalanceTransactionType,
+) -> (storage_enums::LedgerAccount, storage_enums::LedgerAccount) {
+    match transaction_type {
+        storage_enums::BalanceTransactionType::Authorization => (
+            storage_enums::LedgerAccount::Pending,
+            storage_enums::LedgerAccount::External,
+        ),
+        storage_enums::BalanceTransactionType::AuthorizationRelease => (
+            storage_enums::LedgerAccount::External,
+            storage_enums::LedgerAccount::Pending,
+        ),
+        storage_enums::BalanceTransactionType::Capture => (
+            storage_enums::LedgerAccount::Available,
+            storage_enums::LedgerAccount::Pending,
+        ),
+        storage_enums::BalanceTransactionType::Refund
+        | storage_enums::BalanceTransactionType::Dispute
+        | storage_enums::BalanceTransactionType::Payout => (
+            storage_enums::LedgerAccount::External,
+            storage_enums::LedgerAccount::Available,
+        ),
+    }
+}
+
+/// Records the event in the ledger, unless it has already been recorded.
+async fn record_event(db: &dyn StorageInterface, event: LedgerEvent<'_>) -> RouterResult<()> {
+    if event.amount <= 0 {
+        return Ok(());
+    }
+
+    let (debit_account, credit_account) = get_ledger_accounts(event.transaction_type);
+    let balance_transaction = storage::BalanceTransactionNew {
+        transaction_id: utils::generate_id(consts::ID_LENGTH, "btxn"),
+        merchant_id: event.merchant_id.to_owned(),
+        transaction_type: event.transaction_type,
+        source_id: event.source_id.to_owned(),
+        payment_id: event.payment_id.map(ToOwned::to_owned),
+        amount: event.amount,
+        currency: event.currency,
+        debit_account,
+        credit_account,
+        created_at: None,
+    };
+
+    match db.insert_balance_transaction(balance_transaction).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert balance transaction"),
+    }
+}
+
+async fn find_event(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    transaction_type: storage_enums::BalanceTransactionType,
+    source_id: &str,
+) -> RouterResult<Option<storage::BalanceTransaction>> {
+    match db
+        .find_balance_transaction_by_merchant_id_transaction_type_source_id(
+            merchant_id,
+            transaction_type,
+            source_id,
+        )
+        .await
+    {
+        Ok(balance_transaction) => Ok(Some(balance_transaction)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch balance transaction"),
+    }
+}
+
+async fn record_payment_attempt(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let currency = match payment_attempt.currency.or(payment_intent.currency) {
+        Some(currency) => currency,
+        None => return Ok(()),
+    };
+    let authorized_amount =
+        payment_attempt.amount.get_amount_as_i64() + payment_attempt.surcharge_amount.unwrap_or(0);
+    let event = |transaction_type, amount| LedgerEvent {
+        merchant_id: &payment_attempt.merchant_id,
+        transaction_type,
+        source_id: &payment_attempt.attempt_id,
+        payment_id: Some(payment_attempt.payment_id.as_str()),
+        amount,
+        currency,
+    };
+
+    match payment_attempt.status {
+        storage_enums::AttemptStatus::Authorized => {
+            record_event(
+                db,
+                event(
+                    storage_enums::BalanceTransactionType::Authorization,
+                    authorized_amount,
+                ),
+            )
+            .await
+        }
+        storage_enums::AttemptStatus::Charged | storage_enums::AttemptStatus::PartialCharged => {
+            // Payments captured along with the authorization are authorized first, so that the
+            // captured amount is moved out of the pending account it was authorized into
+            record_event(
+                db,
+                event(
+                    storage_enums::BalanceTransactionType::Authorization,
+                    authorized_amount,
+                ),
+            )
+            .await?;
+
+            let captured_amount = payment_intent
+                .amount_captured
+                .unwrap_or(authorized_amount)
+                .min(authorized_amount);
+            record_event(
+                db,
+                event(
+                    storage_enums::BalanceTransactionType::Capture,
+                    captured_amount,
+                ),
+            )
+            .await?;
+            record_event(
+                db,
+                event(
+                    storage_enums::BalanceTransactionType::AuthorizationRelease,
+                    authorized_amount - captured_amount,
+                ),
+            )
+            .await
+        }
+        storage_enums::AttemptStatus::Voided => {
+            let authorization = find_event(
+                db,
+                &payment_attempt.merchant_id,
+                storage_enums::BalanceTransactionType::Authorization,
+                &payment_attempt.attempt_id,
+            )
+            .await?;
+            match authorization {
+                Some(authorization) => {
+                    record_event(
+                        db,
+                        event(
+                            storage_enums::BalanceTransactionType::AuthorizationRelease,
+                            authorization.amount,
+                        ),
+                    )
+                    .await
+                }
+                None => Ok(()),
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Records the authorization, capture or release of the amount of the payment attempt, based on
+/// its status.
+#[instrument(skip_all)]
+pub async fn record_payment(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) {
+    if let Err(error) = record_payment_attempt(db, payment_intent, payment_attempt).await {
+        logger::error!(?error, "Failed to record payment in the ledger");
+    }
+}
+
+/// Records a successful refund, which moves the refunded amount out of the available balance.
+#[instrument(skip_all)]
+pub async fn record_refund(db: &dyn StorageInterface, refund: &storage::Refund) {
+    if refund.refund_status != storage_enums::RefundStatus::Success {
+        return;
+    }
+
+    let event = LedgerEvent {
+        merchant_id: &refund.merchant_id,
+        transaction_type: storage_enums::BalanceTransactionType::Refund,
+        source_id: &refund.refund_id,
+        payment_id: Some(refund.payment_id.as_str()),
+        amount: refund.refund_amount,
+        currency: refund.currency,
+    };
+    if let Err(error) = record_event(db, event).await {
+        logger::error!(?error, "Failed to record refund in the ledger");
+    }
+}
+
+/// Records a dispute which was lost, accepted or expired, which moves the disputed amount out of
+/// the available balance.
+#[instrument(skip_all)]
+pub async fn record_dispute(db: &dyn StorageInterface, dispute: &storage::Dispute) {
+    if !matches!(
+        dispute.dispute_status,
+        storage_enums::DisputeStatus::DisputeLost
+            | storage_enums::DisputeStatus::DisputeAccepted
+            | storage_enums::DisputeStatus::DisputeExpired
+    ) {
+        return;
+    }
+
+    // The amount and currency of disputes are stored as received from the connector
+    let amount = dispute.amount.parse::<i64>();
+    let currency = dispute
+        .currency
+        .to_uppercase()
+        .parse::<storage_enums::Currency>();
+    let (amount, currency) = match (amount, currency) {
+        (Ok(amount), Ok(currency)) => (amount, currency),
+        _ => {
+            logger::error!(
+                dispute_id = %dispute.dispute_id,
+                "Failed to parse amount and currency of dispute for the ledger"
+            );
+            return;
+        }
+    };
+
+    let event = LedgerEvent {
+        merchant_id: &dispute.merchant_id,
+        transaction_type: storage_enums::BalanceTransactionType::Dispute,
+        source_id: &dispute.dispute_id,
+        payment_id: Some(dispute.payment_id.as_str()),
+        amount,
+        currency,
+    };
+    if let Err(error) = record_event(db, event).await {
+        logger::error!(?error, "Failed to record dispute in the ledger");
+    }
+}
+
+/// Records a successful payout, which moves the paid out amount out of the available balance.
+#[instrument(skip_all)]
+pub async fn record_payout(db: &dyn StorageInterface, payout: &storage::Payout) {
+    if payout.status != storage_enums::PayoutStatus::Success {
+        return;
+    }
+
+    let event = LedgerEvent {
+        merchant_id: &payout.merchant_id,
+        transaction_type: storage_enums::BalanceTransactionType::Payout,
+        source_id: &payout.payout_id,
+        payment_id: None,
+        amount: payout.amount,
+        currency: payout.currency,
+    };
+    if let Err(error) = record_event(db, event).await {
+        logger::error!(?error, "Failed to record payout in the ledger");
+    }
+}
+
+/// Calculates the available and pending balances of each currency from the total amounts moved
+/// between the accounts of the ledger.
+pub fn calculate_balances(
+    aggregates: Vec<storage::BalanceTransactionAggregate>,
+) -> Vec<api::CurrencyBalance> {
+    let mut balances = HashMap::<storage_enums::Currency, (i64, i64)>::new();
+    for aggregate in aggregates {
+        let amount = aggregate.amount.unwrap_or_default();
+        let (available, pending) = balances.entry(aggregate.currency).or_default();
+        for (account, amount) in [
+            (aggregate.debit_account, amount),
+            (aggregate.credit_account, -amount),
+        ] {
+            match account {
+                storage_enums::LedgerAccount::Available => *available += amount,
+                storage_enums::LedgerAccount::Pending => *pending += amount,
+                storage_enums::LedgerAccount::External => {}
+            }
+        }
+    }
+
+    let mut balances = balances
+        .into_iter()
+        .map(|(currency, (available, pending))| api::CurrencyBalance {
+            currency: currency.foreign_into(),
+            available,
+            pending,
+        })
+        .collect::<Vec<_>>();
+    balances.sort_by_key(|balance| balance.currency.to_string());
+    balances
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_balance(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+) -> RouterResponse<api::BalanceResponse> {
+    let aggregates = db
+        .get_balance_transaction_aggregates_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to aggregate balance transactions of the merchant")?;
+
+    Ok(ApplicationResponse::Json(api::BalanceResponse {
+        balances: calculate_balances(aggregates),
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn list_balance_transactions(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    constraints: api::BalanceTransactionListConstraints,
+) -> RouterResponse<api::BalanceTransactionListResponse> {
+    utils::when(
+        constraints.limit < 1 || constraints.limit > MAX_BALANCE_TRANSACTIONS_LIST_LIMIT,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "limit should be in between 1 and {MAX_BALANCE_TRANSACTIONS_LIST_LIMIT}"
+                ),
+            })
+        },
+    )?;
+
+    let balance_transactions = db
+        .list_balance_transactions_by_merchant_id(
+            &merchant_account.merchant_id,
+            Some(constraints.limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list balance transactions of the merchant")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect::<Vec<api::BalanceTransactionResponse>>();
+
+    Ok(ApplicationResponse::Json(
+        api::BalanceTransactionListResponse {
+            size: balance_transactions.len(),
+            data: balance_transactions,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aggregate(
+        currency: storage_enums::Currency,
+        transaction_type: storage_enums::BalanceTransactionType,
+        amount: i64,
+    ) -> storage::BalanceTransactionAggregate {
+        let (debit_account, credit_account) = get_ledger_accounts(transaction_type);
+        storage::BalanceTransactionAggregate {
+            currency,
+            debit_account,
+            credit_account,
+            amount: Some(amount),
+        }
+    }
+
+    #[test]
+    fn test_balances_are_calculated_per_currency() {
+        let balances = calculate_balances(vec![
+            aggregate(
+                storage_enums::Currency::USD,
+                storage_enums::BalanceTransactionType::Authorization,
+                10000,
+            ),
+            aggregate(
+                storage_enums::Currency::USD,
+                storage_enums::BalanceTransactionType::Capture,
+                8000,
+            ),
+            aggregate(
+                storage_enums::Currency::USD,
+                storage_enums::BalanceTransactionType::AuthorizationRelease,
+                500,
+            ),
+            aggregate(
+                storage_enums::Currency::USD,
+                storage_enums::BalanceTransactionType::Refund,
+                1000,
+            ),
+            aggregate(
+                storage_enums::Currency::EUR,
+                storage_enums::BalanceTransactionType::Authorization,
+                300,
+            ),
+        ]);
+
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances[0].currency, api_models::enums::Currency::EUR);
+        assert_eq!((balances[0].available, balances[0].pending), (0, 300));
+        assert_eq!(balances[1].currency, api_models::enums::Currency::USD);
+        assert_eq!((balances[1].available, balances[1].pending), (7000, 1500));
+    }
+}
@@ -2,21 +2,24 @@ use error_stack::{report, ResultExt};
 use router_env::{instrument, logger, tracing};
 use storage_models::enums as storage_enums;
 
-use super::payments::helpers;
+use super::payments::{self, helpers};
 use crate::{
-    core::errors::{self, RouterResponse, StorageErrorExt},
-    db::StorageInterface,
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        utils as core_utils,
+    },
     routes::{metrics, AppState},
     services,
     types::{
         self,
         api::{
-            customers,
+            self, customers,
             mandates::{self, MandateResponseExt},
         },
         storage,
         transformers::ForeignInto,
     },
+    utils,
 };
 
 #[instrument(skip(state))]
@@ -35,23 +38,34 @@ pub async fn get_mandate(
     ))
 }
 
-#[instrument(skip(db))]
+#[instrument(skip(state))]
 pub async fn revoke_mandate(
-    db: &dyn StorageInterface,
+    state: &AppState,
     merchant_account: storage::MerchantAccount,
     req: mandates::MandateId,
 ) -> RouterResponse<mandates::MandateRevokedResponse> {
+    let db = &*state.store;
     let mandate = db
-        .update_mandate_by_merchant_id_mandate_id(
-            &merchant_account.merchant_id,
-            &req.mandate_id,
-            storage::MandateUpdate::StatusUpdate {
-                mandate_status: storage::enums::MandateStatus::Revoked,
-            },
-        )
+        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &req.mandate_id)
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
 
+    utils::when(
+        !mandate
+            .mandate_status
+            .can_transition_to(storage_enums::MandateStatus::Revoked),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "You cannot revoke this mandate because it has status {}",
+                    mandate.mandate_status
+                ),
+            })
+        },
+    )?;
+
+    let mandate = revoke_mandate_with_connector(state, &merchant_account, mandate).await?;
+
     Ok(services::ApplicationResponse::Json(
         mandates::MandateRevokedResponse {
             mandate_id: mandate.mandate_id,
@@ -60,6 +74,113 @@ pub async fn revoke_mandate(
     ))
 }
 
+/// Revokes the mandate with the connector it was registered with, and updates its status with the
+/// status returned by the connector.
+#[instrument(skip_all)]
+pub async fn revoke_mandate_with_connector(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mandate: storage::Mandate,
+) -> errors::RouterResult<storage::Mandate> {
+    let db = &*state.store;
+    let payment_method = db
+        .find_payment_method(&mandate.payment_method_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+
+    let connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &mandate.connector,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+    let router_data = core_utils::construct_mandate_revoke_router_data(
+        state,
+        merchant_account,
+        &mandate,
+        payment_method.payment_method,
+    )
+    .await?;
+
+    // Mandates which were never registered with the connector only need to be revoked on our end
+    let router_data = if mandate.connector_mandate_id.is_some() {
+        let connector_integration: services::BoxedConnectorIntegration<
+            '_,
+            api::MandateRevoke,
+            types::MandateRevokeRequestData,
+            types::MandateRevokeResponseData,
+        > = connector.connector.get_connector_integration();
+        services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            &router_data,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while revoking the mandate with the connector")?
+    } else {
+        router_data
+    };
+
+    let mandate_status = match router_data.response {
+        Ok(response) => response.mandate_status,
+        Err(error) => Err(errors::ApiErrorResponse::ExternalConnectorError {
+            code: error.code,
+            message: error.message,
+            connector: mandate.connector.clone(),
+            status_code: error.status_code,
+            reason: error.reason,
+        })?,
+    };
+
+    db.update_mandate_by_merchant_id_mandate_id(
+        &merchant_account.merchant_id,
+        &mandate.mandate_id,
+        storage::MandateUpdate::StatusUpdate { mandate_status },
+    )
+    .await
+    .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))
+}
+
+#[instrument(skip(state))]
+pub async fn list_mandates(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    constraints: mandates::MandateListConstraints,
+) -> RouterResponse<Vec<mandates::MandateResponse>> {
+    let limit = validate_mandate_list_limit(constraints.limit)?;
+    let mandates = state
+        .store
+        .find_mandates_by_merchant_id(&merchant_account.merchant_id, constraints, limit)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while filtering mandates")?;
+
+    let mut response_vec = Vec::with_capacity(mandates.len());
+    for mandate in mandates {
+        response_vec.push(
+            mandates::MandateResponse::from_db_mandate(state, mandate, &merchant_account).await?,
+        );
+    }
+    Ok(services::ApplicationResponse::Json(response_vec))
+}
+
+fn validate_mandate_list_limit(limit: Option<i64>) -> errors::RouterResult<i64> {
+    match limit {
+        Some(limit) if !(1..=100).contains(&limit) => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "limit should be in between 1 and 100".to_string(),
+            }))
+        }
+        Some(limit) => Ok(limit),
+        None => Ok(10),
+    }
+}
+
 #[instrument(skip(state))]
 pub async fn get_customer_mandates(
     state: &AppState,
@@ -180,6 +301,7 @@ where
                         .set_mandate_id(api_models::payments::MandateIds {
                             mandate_id: new_mandate_data.mandate_id.clone(),
                             connector_mandate_id: new_mandate_data.connector_mandate_id.clone(),
+                            network_transaction_id: new_mandate_data.network_transaction_id.clone(),
                         });
                     state
                         .store
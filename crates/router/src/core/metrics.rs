@@ -18,3 +18,4 @@ counter_metric!(
     INCOMING_DISPUTE_WEBHOOK_MERCHANT_NOTIFIED_METRIC,
     GLOBAL_METER
 ); // No. of incoming dispute webhooks which are notified to merchant
+counter_metric!(OUTGOING_WEBHOOK_DELIVERY_COUNT, GLOBAL_METER); // No. of webhooks sent to merchants, by the outcome of the delivery
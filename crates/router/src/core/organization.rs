@@ -0,0 +1,132 @@
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        api_keys,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services::ApplicationResponse,
+    types::{api, storage, transformers::ForeignInto},
+    utils,
+};
+
+async fn find_organization(
+    db: &dyn StorageInterface,
+    organization_id: &str,
+) -> RouterResult<storage::Organization> {
+    db.find_organization_by_organization_id(organization_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::OrganizationNotFound)
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn create_organization(
+    db: &dyn StorageInterface,
+    req: api::OrganizationCreateRequest,
+) -> RouterResponse<api::OrganizationResponse> {
+    let organization = storage::OrganizationNew {
+        organization_id: utils::generate_id(consts::ID_LENGTH, "org"),
+        organization_name: req.organization_name,
+    };
+
+    let organization = db
+        .insert_organization(organization)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert organization")?;
+
+    Ok(ApplicationResponse::Json(organization.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_organization(
+    db: &dyn StorageInterface,
+    organization_id: &str,
+) -> RouterResponse<api::OrganizationResponse> {
+    let organization = find_organization(db, organization_id).await?;
+
+    Ok(ApplicationResponse::Json(organization.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn list_merchant_accounts(
+    db: &dyn StorageInterface,
+    organization_id: &str,
+) -> RouterResponse<Vec<api::MerchantAccountResponse>> {
+    find_organization(db, organization_id).await?;
+    let merchant_accounts = db
+        .list_merchant_accounts_by_organization_id(organization_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list merchant accounts of the organization")?;
+
+    Ok(ApplicationResponse::Json(
+        merchant_accounts
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn create_api_key(
+    state: &AppState,
+    organization_id: &str,
+    req: api::CreateApiKeyRequest,
+) -> RouterResponse<api::CreateApiKeyResponse> {
+    find_organization(&*state.store, organization_id).await?;
+    api_keys::create_organization_api_key(
+        &*state.store,
+        &state.conf.api_keys,
+        #[cfg(feature = "kms")]
+        &state.conf.kms,
+        req,
+        organization_id.to_owned(),
+    )
+    .await
+}
+
+/// Aggregates the payments of all merchant accounts of the organization by currency and status.
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn get_payments_aggregate(
+    db: &dyn StorageInterface,
+    organization_id: &str,
+    constraints: api::OrganizationPaymentsAggregateConstraints,
+) -> RouterResponse<api::OrganizationPaymentsAggregateResponse> {
+    find_organization(db, organization_id).await?;
+    let merchant_ids = db
+        .list_merchant_accounts_by_organization_id(organization_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list merchant accounts of the organization")?
+        .into_iter()
+        .map(|merchant_account| merchant_account.merchant_id)
+        .collect::<Vec<_>>();
+
+    let aggregates = if merchant_ids.is_empty() {
+        Vec::new()
+    } else {
+        db.get_payment_intent_aggregates_by_merchant_ids(&merchant_ids, &constraints)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to aggregate payments of the organization")?
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect()
+    };
+
+    Ok(ApplicationResponse::Json(
+        api::OrganizationPaymentsAggregateResponse {
+            organization_id: organization_id.to_owned(),
+            merchant_count: merchant_ids.len(),
+            aggregates,
+        },
+    ))
+}
@@ -0,0 +1,560 @@
+use common_utils::date_time;
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+use time::Duration;
+
+use super::payments;
+use crate::{
+    connector::utils as connector_utils,
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::AppState,
+    services::{self, ApplicationResponse},
+    types::{
+        self,
+        api::{self, enums as api_enums},
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils,
+};
+
+/// The status of a payment link, taking its expiry into account.
+fn get_payment_link_status(
+    payment_link: &storage::PaymentLink,
+) -> storage_enums::PaymentLinkStatus {
+    match payment_link.status {
+        storage_enums::PaymentLinkStatus::Active
+            if payment_link
+                .expires_at
+                .map_or(false, |expires_at| expires_at <= date_time::now()) =>
+        {
+            storage_enums::PaymentLinkStatus::Expired
+        }
+        status => status,
+    }
+}
+
+fn get_payment_link_url(state: &AppState, link_id: &str) -> String {
+    format!(
+        "{}/payment_links/{link_id}/checkout",
+        state.conf.server.base_url
+    )
+}
+
+fn make_payment_link_response(
+    state: &AppState,
+    payment_link: storage::PaymentLink,
+) -> api::PaymentLinkResponse {
+    api::PaymentLinkResponse {
+        url: get_payment_link_url(state, &payment_link.link_id),
+        status: get_payment_link_status(&payment_link).foreign_into(),
+        link_id: payment_link.link_id,
+        amount: payment_link.amount,
+        currency: payment_link.currency.foreign_into(),
+        description: payment_link.description,
+        return_url: payment_link.return_url,
+        reusable: payment_link.reusable,
+        usage_count: payment_link.usage_count,
+        last_payment_id: payment_link.last_payment_id,
+        expires_at: payment_link.expires_at,
+        created_at: payment_link.created_at,
+    }
+}
+
+/// Fetches a payment link which can still be paid through.
+async fn find_usable_payment_link(
+    db: &dyn StorageInterface,
+    link_id: &str,
+) -> RouterResult<storage::PaymentLink> {
+    let payment_link = db
+        .find_payment_link_by_link_id(link_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)
+        })?;
+
+    match get_payment_link_status(&payment_link) {
+        storage_enums::PaymentLinkStatus::Active => Ok(payment_link),
+        storage_enums::PaymentLinkStatus::Used => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "This payment link has already been used".to_string(),
+            }))
+        }
+        storage_enums::PaymentLinkStatus::Expired => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "This payment link has expired".to_string(),
+            }))
+        }
+    }
+}
+
+/// Number of times the usage count of a payment link is read and updated again, when it was changed
+/// by a concurrent payment in the meantime.
+const USAGE_COUNT_UPDATE_ATTEMPTS: usize = 3;
+
+/// Reserves a use of a payment link before the customer is charged, so that concurrent payments
+/// cannot use a single use link more than once.
+async fn reserve_payment_link_use(
+    db: &dyn StorageInterface,
+    link_id: &str,
+) -> RouterResult<storage::PaymentLink> {
+    for _ in 0..USAGE_COUNT_UPDATE_ATTEMPTS {
+        let payment_link = find_usable_payment_link(db, link_id).await?;
+        let update = storage::PaymentLinkUpdate::UsageCountUpdate {
+            status: if payment_link.reusable {
+                storage_enums::PaymentLinkStatus::Active
+            } else {
+                storage_enums::PaymentLinkStatus::Used
+            },
+            usage_count: payment_link.usage_count + 1,
+        };
+
+        match db
+            .update_payment_link_usage_count(payment_link, update)
+            .await
+        {
+            Ok(payment_link) => return Ok(payment_link),
+            Err(error) if error.current_context().is_db_version_conflict() => continue,
+            Err(error) => {
+                return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                    .attach_printable("Failed to reserve a use of the payment link")
+            }
+        }
+    }
+
+    Err(report!(errors::ApiErrorResponse::ConcurrentUpdate))
+        .attach_printable("Payment link is being used by too many concurrent payments")
+}
+
+/// Releases the use of a payment link reserved for a payment which failed, so that the customer can
+/// try again.
+async fn release_payment_link_use(db: &dyn StorageInterface, link_id: &str) -> RouterResult<()> {
+    for _ in 0..USAGE_COUNT_UPDATE_ATTEMPTS {
+        let payment_link = db
+            .find_payment_link_by_link_id(link_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment link to release its use")?;
+        let update = storage::PaymentLinkUpdate::UsageCountUpdate {
+            status: storage_enums::PaymentLinkStatus::Active,
+            usage_count: payment_link.usage_count - 1,
+        };
+
+        match db
+            .update_payment_link_usage_count(payment_link, update)
+            .await
+        {
+            Ok(_) => return Ok(()),
+            Err(error) if error.current_context().is_db_version_conflict() => continue,
+            Err(error) => {
+                return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                    .attach_printable("Failed to release the use of the payment link")
+            }
+        }
+    }
+
+    Err(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Payment link was updated by too many concurrent payments to release")
+}
+
+#[instrument(skip(state))]
+pub async fn create_payment_link(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PaymentLinkCreateRequest,
+) -> RouterResponse<api::PaymentLinkResponse> {
+    utils::when(req.amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than 0".to_string(),
+        }))
+    })?;
+    utils::when(req.expires_in == Some(0), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "expires_in must be greater than 0".to_string(),
+        }))
+    })?;
+
+    let now = date_time::now();
+    let payment_link = state
+        .store
+        .insert_payment_link(storage::PaymentLinkNew {
+            link_id: utils::generate_id(consts::ID_LENGTH, "plink"),
+            merchant_id: merchant_account.merchant_id,
+            amount: req.amount,
+            currency: req.currency.foreign_into(),
+            description: req.description,
+            return_url: req.return_url.map(|url| url.to_string()),
+            reusable: req.reusable.unwrap_or(false),
+            status: storage_enums::PaymentLinkStatus::Active,
+            expires_at: req
+                .expires_in
+                .map(|expires_in| now.saturating_add(Duration::seconds(expires_in.into()))),
+            created_at: Some(now),
+            modified_at: Some(now),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payment link")?;
+
+    Ok(ApplicationResponse::Json(make_payment_link_response(
+        state,
+        payment_link,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_payment_link(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PaymentLinkId,
+) -> RouterResponse<api::PaymentLinkResponse> {
+    let payment_link = state
+        .store
+        .find_payment_link_by_link_id(&req.link_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)
+        })?;
+
+    utils::when(
+        payment_link.merchant_id != merchant_account.merchant_id,
+        || Err(report!(errors::ApiErrorResponse::PaymentLinkNotFound)),
+    )?;
+
+    Ok(ApplicationResponse::Json(make_payment_link_response(
+        state,
+        payment_link,
+    )))
+}
+
+/// Serves the checkout page of a payment link, or redirects the customer to the checkout page
+/// hosted by the SDK if one is configured.
+#[instrument(skip(state))]
+pub async fn initiate_payment_link_checkout(
+    state: &AppState,
+    req: api::PaymentLinkId,
+) -> RouterResponse<()> {
+    let payment_link = find_usable_payment_link(&*state.store, &req.link_id).await?;
+
+    match state
+        .conf
+        .payment_links
+        .sdk_url
+        .as_ref()
+        .filter(|sdk_url| !sdk_url.is_empty())
+    {
+        Some(sdk_url) => {
+            let params = vec![("link_id".to_string(), payment_link.link_id)];
+            let redirect_url = url::Url::parse_with_params(sdk_url, &params)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid SDK URL configured for payment links")?;
+
+            Ok(ApplicationResponse::JsonForRedirection(
+                api::RedirectionResponse {
+                    return_url: sdk_url.to_owned(),
+                    params,
+                    return_url_with_query_params: redirect_url.to_string(),
+                    http_method: services::Method::Get.to_string(),
+                    headers: Vec::new(),
+                },
+            ))
+        }
+        None => {
+            let amount = connector_utils::to_currency_base_unit(
+                types::MinorUnit::new(payment_link.amount),
+                payment_link.currency,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert amount of payment link to base unit")?;
+            let pay_url = format!(
+                "{}/payment_links/{}/pay",
+                state.conf.server.base_url, payment_link.link_id
+            );
+
+            Ok(ApplicationResponse::Html(
+                build_checkout_page(&payment_link, &amount, &pay_url).into_string(),
+            ))
+        }
+    }
+}
+
+/// Creates and confirms a payment for the amount of a payment link. The payment is routed
+/// according to the routing configuration of the merchant who created the link. A use of the link
+/// is reserved before the payment is made, and released if the payment fails.
+#[instrument(skip(state))]
+pub async fn pay_payment_link(
+    state: &AppState,
+    req: api::PaymentLinkPayRequest,
+) -> RouterResponse<api::PaymentsResponse> {
+    let db = &*state.store;
+    let payment_link = reserve_payment_link_use(db, &req.link_id).await?;
+
+    let result = make_payment_link_payment(state, &payment_link, req).await;
+    match &result {
+        // Failed payments do not use up the link, the customer can try again. The outcome of the
+        // payment is returned even if the use could not be released.
+        Err(_) => release_payment_link_use(db, &payment_link.link_id)
+            .await
+            .unwrap_or_else(|error| logger::error!(payment_link_release_error=?error)),
+        Ok(ApplicationResponse::Json(payments_response))
+            if payments_response.status == api_enums::IntentStatus::Failed =>
+        {
+            release_payment_link_use(db, &payment_link.link_id)
+                .await
+                .unwrap_or_else(|error| logger::error!(payment_link_release_error=?error))
+        }
+        Ok(ApplicationResponse::Json(api::PaymentsResponse {
+            payment_id: Some(payment_id),
+            ..
+        })) => {
+            db.update_payment_link(
+                payment_link,
+                storage::PaymentLinkUpdate::LastPaymentUpdate {
+                    last_payment_id: payment_id.clone(),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update last payment of payment link")?;
+        }
+        Ok(_) => {}
+    }
+
+    result
+}
+
+async fn make_payment_link_payment(
+    state: &AppState,
+    payment_link: &storage::PaymentLink,
+    req: api::PaymentLinkPayRequest,
+) -> RouterResponse<api::PaymentsResponse> {
+    let db = &*state.store;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&payment_link.merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    let return_url = payment_link
+        .return_url
+        .as_deref()
+        .map(url::Url::parse)
+        .transpose()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid return URL stored in payment link")?;
+
+    let payment_request = api::PaymentsRequest {
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        amount: Some(payment_link.amount.into()),
+        currency: Some(payment_link.currency.foreign_into()),
+        confirm: Some(true),
+        description: payment_link.description.clone(),
+        return_url,
+        email: req.email,
+        payment_method: Some(req.payment_method),
+        payment_method_type: req.payment_method_type,
+        payment_method_data: Some(req.payment_method_data),
+        browser_info: req.browser_info,
+        ..Default::default()
+    };
+    payments::payments_core::<api::Authorize, api::PaymentsResponse, _, _, _>(
+        state,
+        merchant_account,
+        payments::operations::PaymentCreate,
+        payment_request,
+        services::AuthFlow::Client,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+}
+
+fn build_checkout_page(
+    payment_link: &storage::PaymentLink,
+    amount: &str,
+    pay_url: &str,
+) -> maud::Markup {
+    use maud::PreEscaped;
+
+    maud::html! {
+        (maud::DOCTYPE)
+        html {
+            head {
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                title { "Checkout" }
+            }
+            body style="background-color: #ffffff; padding: 20px; font-family: Arial, Helvetica, Sans-Serif;" {
+                div style="max-width: 400px; margin-left: auto; margin-right: auto;" {
+                    @if let Some(description) = &payment_link.description {
+                        h3 { (description) }
+                    }
+                    h2 { (amount) " " (payment_link.currency.to_string()) }
+                    form #payment_link_form data-pay-url=(pay_url) {
+                        p { input name="card_holder_name" placeholder="Name on card" style="width: 100%;"; }
+                        p { input name="card_number" placeholder="Card number" inputmode="numeric" autocomplete="cc-number" required style="width: 100%;"; }
+                        p {
+                            input name="card_exp_month" placeholder="MM" inputmode="numeric" autocomplete="cc-exp-month" required style="width: 30%;";
+                            " "
+                            input name="card_exp_year" placeholder="YY" inputmode="numeric" autocomplete="cc-exp-year" required style="width: 30%;";
+                            " "
+                            input name="card_cvc" placeholder="CVC" inputmode="numeric" autocomplete="cc-csc" required style="width: 30%;";
+                        }
+                        p { input name="email" type="email" placeholder="Email" style="width: 100%;"; }
+                        button type="submit" style="width: 100%;" { "Pay" }
+                    }
+                    p #payment_link_message {}
+                }
+
+                (PreEscaped(r#"
+                <script type="text/javascript">
+                var form = document.getElementById("payment_link_form");
+                var message = document.getElementById("payment_link_message");
+                form.addEventListener("submit", function (event) {
+                    event.preventDefault();
+                    var fields = form.elements;
+                    var body = {
+                        payment_method: "card",
+                        payment_method_data: {
+                            card: {
+                                card_number: fields.card_number.value,
+                                card_exp_month: fields.card_exp_month.value,
+                                card_exp_year: fields.card_exp_year.value,
+                                card_holder_name: fields.card_holder_name.value,
+                                card_cvc: fields.card_cvc.value
+                            }
+                        },
+                        browser_info: {
+                            user_agent: navigator.userAgent,
+                            accept_header: "text/html",
+                            language: navigator.language,
+                            color_depth: screen.colorDepth,
+                            screen_height: screen.height,
+                            screen_width: screen.width,
+                            time_zone: new Date().getTimezoneOffset(),
+                            java_enabled: false,
+                            java_script_enabled: true
+                        }
+                    };
+                    if (fields.email.value) {
+                        body.email = fields.email.value;
+                    }
+                    message.textContent = "Please wait while we process your payment...";
+                    fetch(form.dataset.payUrl, {
+                        method: "POST",
+                        headers: { "Content-Type": "application/json" },
+                        body: JSON.stringify(body)
+                    })
+                        .then(function (response) { return response.json(); })
+                        .then(function (payment) {
+                            if (payment.error) {
+                                message.textContent = payment.error.message;
+                            } else if (payment.next_action && payment.next_action.redirect_to_url) {
+                                window.location.href = payment.next_action.redirect_to_url;
+                            } else if (payment.status === "failed") {
+                                message.textContent = "Your payment failed, please try again.";
+                            } else if (payment.return_url) {
+                                window.location.href = payment.return_url;
+                            } else {
+                                form.style.display = "none";
+                                message.textContent = "Thank you, your payment was received.";
+                            }
+                        })
+                        .catch(function () {
+                            message.textContent = "Something went wrong, please try again.";
+                        });
+                });
+                </script>
+                "#))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl};
+
+    async fn insert_payment_link(db: &dyn StorageInterface, reusable: bool) -> String {
+        db.insert_payment_link(storage::PaymentLinkNew {
+            link_id: "plink_test".to_string(),
+            merchant_id: "merchant".to_string(),
+            amount: 1000,
+            currency: storage_enums::Currency::USD,
+            description: None,
+            return_url: None,
+            reusable,
+            status: storage_enums::PaymentLinkStatus::Active,
+            expires_at: None,
+            created_at: None,
+            modified_at: None,
+        })
+        .await
+        .expect("failed to insert payment link")
+        .link_id
+    }
+
+    #[tokio::test]
+    async fn test_single_use_link_is_used_once() {
+        let conf = Settings::new().expect("invalid settings");
+        let state = AppState::with_storage(conf, StorageImpl::Mock).await;
+        let db = &*state.store;
+        let link_id = insert_payment_link(db, false).await;
+
+        let payment_link = reserve_payment_link_use(db, &link_id)
+            .await
+            .expect("failed to reserve payment link");
+        assert_eq!(payment_link.usage_count, 1);
+        assert_eq!(payment_link.status, storage_enums::PaymentLinkStatus::Used);
+
+        let error = reserve_payment_link_use(db, &link_id)
+            .await
+            .expect_err("single use link was reserved twice");
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+
+        // A failed payment gives the use back
+        release_payment_link_use(db, &link_id)
+            .await
+            .expect("failed to release payment link");
+        let payment_link = reserve_payment_link_use(db, &link_id)
+            .await
+            .expect("released payment link could not be reserved");
+        assert_eq!(payment_link.usage_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_stale_usage_count_update_conflicts() {
+        let conf = Settings::new().expect("invalid settings");
+        let state = AppState::with_storage(conf, StorageImpl::Mock).await;
+        let db = &*state.store;
+        let link_id = insert_payment_link(db, true).await;
+
+        let stale_payment_link = db
+            .find_payment_link_by_link_id(&link_id)
+            .await
+            .expect("failed to find payment link");
+        reserve_payment_link_use(db, &link_id)
+            .await
+            .expect("failed to reserve payment link");
+
+        let error = db
+            .update_payment_link_usage_count(
+                stale_payment_link,
+                storage::PaymentLinkUpdate::UsageCountUpdate {
+                    status: storage_enums::PaymentLinkStatus::Active,
+                    usage_count: 1,
+                },
+            )
+            .await
+            .expect_err("stale usage count was overwritten");
+        assert!(error.current_context().is_db_version_conflict());
+    }
+}
@@ -4,7 +4,7 @@ use api_models::{
     admin::{self, PaymentMethodsEnabled},
     enums::{self as api_enums},
     payment_methods::{
-        CardNetworkTypes, PaymentExperienceTypes, RequestPaymentMethodTypes,
+        CardNetworkTypes, PaymentExperienceTypes, RequestPaymentMethodTypes, RequiredFieldInfo,
         ResponsePaymentMethodIntermediate, ResponsePaymentMethodTypes,
         ResponsePaymentMethodsEnabled,
     },
@@ -23,6 +23,7 @@ use crate::scheduler::metrics as scheduler_metrics;
 use crate::{
     configs::settings,
     core::{
+        card_vault,
         errors::{self, StorageErrorExt},
         payment_methods::{transformers as payment_methods, vault},
         payments::helpers,
@@ -715,6 +716,29 @@ pub async fn mock_delete_card<'a>(
     })
 }
 //------------------------------------------------------------------------------
+/// The fields which any of the connectors requires for the payment method type, as the connector
+/// the payment is routed to is not known when the payment methods are listed
+fn get_required_fields<'a>(
+    payment_method_type: api_enums::PaymentMethodType,
+    connectors: impl Iterator<Item = &'a String>,
+) -> Option<Vec<RequiredFieldInfo>> {
+    let mut required_fields: Vec<RequiredFieldInfo> = Vec::new();
+    for connector in connectors {
+        let connector_required_fields =
+            api::ConnectorData::get_required_fields(connector, payment_method_type);
+        for required_field in connector_required_fields {
+            if !required_fields
+                .iter()
+                .any(|field| field.required_field == required_field.path)
+            {
+                required_fields.push((*required_field).into());
+            }
+        }
+    }
+
+    (!required_fields.is_empty()).then_some(required_fields)
+}
+
 pub fn get_banks(
     state: &routes::AppState,
     pm_type: api_enums::PaymentMethodType,
@@ -805,10 +829,17 @@ pub async fn list_payment_methods(
     )
     .await?;
 
+    // Payment methods are filtered by the country of the shipping address of the payment, or of
+    // its billing address for payments which are not shipped
     let address = payment_intent
         .as_ref()
         .async_map(|pi| async {
-            helpers::get_address_by_id(db, pi.shipping_address_id.clone()).await
+            let shipping_address =
+                helpers::get_address_by_id(db, pi.shipping_address_id.clone()).await?;
+            match shipping_address {
+                Some(address) if address.country.is_some() => Ok(Some(address)),
+                _ => helpers::get_address_by_id(db, pi.billing_address_id.clone()).await,
+            }
         })
         .await
         .transpose()?
@@ -980,11 +1011,13 @@ pub async fn list_payment_methods(
                 })
             }
 
+            let connectors = payment_method_types_hm.1.values().flatten();
             payment_method_types.push(ResponsePaymentMethodTypes {
                 payment_method_type: *payment_method_types_hm.0,
                 payment_experience: Some(payment_experience_types),
                 card_networks: None,
                 bank_names: None,
+                required_fields: get_required_fields(*payment_method_types_hm.0, connectors),
             })
         }
 
@@ -1005,11 +1038,13 @@ pub async fn list_payment_methods(
                 })
             }
 
+            let connectors = payment_method_types_hm.1.values().flatten();
             payment_method_types.push(ResponsePaymentMethodTypes {
                 payment_method_type: *payment_method_types_hm.0,
                 card_networks: Some(card_network_types),
                 payment_experience: None,
                 bank_names: None,
+                required_fields: get_required_fields(*payment_method_types_hm.0, connectors),
             })
         }
 
@@ -1024,6 +1059,7 @@ pub async fn list_payment_methods(
     for key in banks_consolidated_hm.iter() {
         let payment_method_type = *key.0;
         let connectors = key.1.clone();
+        let required_fields = get_required_fields(payment_method_type, connectors.iter());
         let bank_names = get_banks(state, payment_method_type, connectors)?;
         bank_payment_method_types.push({
             ResponsePaymentMethodTypes {
@@ -1031,6 +1067,7 @@ pub async fn list_payment_methods(
                 bank_names: Some(bank_names),
                 payment_experience: None,
                 card_networks: None,
+                required_fields,
             }
         })
     }
@@ -1418,9 +1455,9 @@ fn filter_payment_amount_based(
     payment_intent: &storage::PaymentIntent,
     pm: &RequestPaymentMethodTypes,
 ) -> bool {
-    let amount = payment_intent.amount;
-    pm.maximum_amount.map_or(true, |amt| amount < amt.into())
-        && pm.minimum_amount.map_or(true, |amt| amount > amt.into())
+    let amount = payment_intent.amount.get_amount_as_i64();
+    pm.maximum_amount.map_or(true, |amt| amount <= amt.into())
+        && pm.minimum_amount.map_or(true, |amt| amount >= amt.into())
 }
 
 async fn filter_payment_mandate_based(
@@ -1459,6 +1496,20 @@ pub async fn list_customer_payment_method(
     }
     let mut customer_pms = Vec::new();
     for pm in resp.into_iter() {
+        // Cards saved in the card vault can be used directly with their vault tokens
+        if card_vault::is_vault_token(&pm.payment_method_id) {
+            let vaulted_card = db
+                .find_vaulted_card_by_merchant_id_payment_token(
+                    &merchant_account.merchant_id,
+                    &pm.payment_method_id,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch saved card from card vault")?;
+            customer_pms.push(make_vaulted_customer_payment_method(&vaulted_card, pm));
+            continue;
+        }
+
         let payment_token = generate_id(consts::ID_LENGTH, "token");
         let card = if pm.payment_method == enums::PaymentMethod::Card {
             let locker_id = merchant_account
@@ -1472,6 +1523,7 @@ pub async fn list_customer_payment_method(
         //Need validation for enabled payment method ,querying MCA
         let pma = api::CustomerPaymentMethod {
             payment_token: payment_token.to_string(),
+            payment_method_id: pm.payment_method_id,
             customer_id: pm.customer_id,
             payment_method: pm.payment_method.foreign_into(),
             payment_method_type: pm.payment_method_type.map(ForeignInto::foreign_into),
@@ -1496,6 +1548,84 @@ pub async fn list_customer_payment_method(
     Ok(services::ApplicationResponse::Json(response))
 }
 
+fn make_vaulted_customer_payment_method(
+    vaulted_card: &storage::VaultedCard,
+    pm: storage::PaymentMethod,
+) -> api::CustomerPaymentMethod {
+    let card = api::CardDetailFromLocker {
+        scheme: pm.scheme,
+        issuer_country: pm.issuer_country,
+        last4_digits: Some(vaulted_card.card_last_four.clone()),
+        card_number: None,
+        expiry_month: Some(vaulted_card.card_exp_month.clone().into()),
+        expiry_year: Some(vaulted_card.card_exp_year.clone().into()),
+        card_token: None,
+        card_holder_name: pm.cardholder_name,
+        card_fingerprint: None,
+    };
+
+    api::CustomerPaymentMethod {
+        payment_token: pm.payment_method_id.clone(),
+        payment_method_id: pm.payment_method_id,
+        customer_id: pm.customer_id,
+        payment_method: pm.payment_method.foreign_into(),
+        payment_method_type: pm.payment_method_type.map(ForeignInto::foreign_into),
+        payment_method_issuer: pm.payment_method_issuer,
+        payment_method_issuer_code: pm.payment_method_issuer_code.map(ForeignInto::foreign_into),
+        recurring_enabled: false,
+        installment_payment_enabled: false,
+        payment_experience: None,
+        card: Some(card),
+        metadata: pm.metadata,
+        created: Some(pm.created_at),
+    }
+}
+
+/// Saves the card of the customer in the card vault, so that the customer can pay with it in
+/// future payments by passing the `payment_method_id`.
+#[instrument(skip_all)]
+pub async fn save_customer_payment_method(
+    state: &routes::AppState,
+    merchant_account: storage::MerchantAccount,
+    customer_id: &str,
+    req: api::CustomerPaymentMethodCreate,
+) -> errors::RouterResponse<api::CustomerPaymentMethod> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    db.find_customer_by_customer_id_merchant_id(customer_id, merchant_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
+
+    let vaulted_card = card_vault::store_card_in_vault(
+        state,
+        merchant_id,
+        Some(customer_id.to_string()),
+        &req.card,
+    )
+    .await?;
+
+    let payment_method = db
+        .insert_payment_method(storage::PaymentMethodNew {
+            customer_id: customer_id.to_string(),
+            merchant_id: merchant_id.to_string(),
+            payment_method_id: vaulted_card.payment_token.clone(),
+            payment_method: enums::PaymentMethod::Card,
+            scheme: card_vault::network_token::get_card_network(req.card.card_number.peek())
+                .map(|card_network| card_network.to_string()),
+            cardholder_name: req.card.card_holder_name,
+            metadata: req.metadata,
+            ..storage::PaymentMethodNew::default()
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert saved payment method")?;
+
+    Ok(services::ApplicationResponse::Json(
+        make_vaulted_customer_payment_method(&vaulted_card, payment_method),
+    ))
+}
+
 pub async fn get_lookup_key_from_locker(
     state: &routes::AppState,
     payment_token: &str,
@@ -1750,6 +1880,18 @@ pub async fn delete_payment_method(
     merchant_account: storage::MerchantAccount,
     pm: api::PaymentMethodId,
 ) -> errors::RouterResponse<api::PaymentMethodDeleteResponse> {
+    // Cards saved through the card vault are identified by their vault token, and are deleted from
+    // the card vault along with their payment method
+    if card_vault::is_vault_token(&pm.payment_method_id) {
+        card_vault::delete_vault_token(state, merchant_account, &pm.payment_method_id).await?;
+        return Ok(services::ApplicationResponse::Json(
+            api::PaymentMethodDeleteResponse {
+                payment_method_id: pm.payment_method_id,
+                deleted: true,
+            },
+        ));
+    }
+
     let (_, supplementary_data) =
         vault::Vault::get_payment_method_data_from_locker(state, &pm.payment_method_id).await?;
     let payment_method_id = supplementary_data
@@ -1771,9 +1913,9 @@ pub async fn delete_payment_method(
             delete_card_from_locker(state, &pm.customer_id, &pm.merchant_id, &payment_method_id)
                 .await?;
         if response.status == "SUCCESS" {
-            print!("Card From locker deleted Successfully")
+            logger::info!("Card From locker deleted Successfully")
         } else {
-            print!("Error: Deleting Card From Locker")
+            logger::error!("Error: Deleting Card From Locker")
         }
     };
 
@@ -762,7 +762,7 @@ pub async fn retry_delete_tokenize(
     match schedule_time {
         Some(s_time) => pt.retry(db, s_time).await,
         None => {
-            pt.finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+            pt.move_to_dead_letter(db, "RETRIES_EXCEEDED".to_string())
                 .await
         }
     }
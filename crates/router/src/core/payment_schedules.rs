@@ -0,0 +1,544 @@
+use common_utils::date_time;
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+use time::{Duration, PrimitiveDateTime};
+
+use super::{payments, webhooks};
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::AppState,
+    scheduler::utils as pt_utils,
+    services::ApplicationResponse,
+    types::{
+        api,
+        storage::{self, enums as storage_enums, ProcessTrackerExt},
+        transformers::{ForeignFrom, ForeignInto},
+    },
+    utils::{self, ValueExt},
+};
+
+const PAYMENT_SCHEDULE_WORKFLOW_RUNNER: &str = "PAYMENT_SCHEDULE_WORKFLOW";
+const PAYMENT_SCHEDULE_TASK: &str = "PAYMENT_SCHEDULE";
+
+/// Outcome of the payment created for a cycle of a payment schedule.
+enum CycleOutcome {
+    /// The payment was authorized by the connector.
+    Succeeded,
+    /// The payment was declined or could not be processed, and may succeed if retried later.
+    Declined,
+    /// The payment cannot succeed without the customer, for example when the mandate is no
+    /// longer valid or the payment requires authentication.
+    Rejected,
+}
+
+fn validate_payment_schedule_request(req: &api::PaymentScheduleCreateRequest) -> RouterResult<()> {
+    utils::when(req.amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than 0".to_string(),
+        }))
+    })?;
+    utils::when(req.interval_count.map_or(false, |count| count < 1), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "interval_count must be greater than 0".to_string(),
+        }))
+    })?;
+    utils::when(req.total_cycles.map_or(false, |cycles| cycles < 1), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "total_cycles must be greater than 0".to_string(),
+        }))
+    })
+}
+
+fn validate_mandate_for_payment_schedule(
+    mandate: &storage::Mandate,
+    req: &api::PaymentScheduleCreateRequest,
+) -> RouterResult<()> {
+    utils::when(mandate.customer_id != req.customer_id, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "customer associated with mandate is not same as the customer_id".to_string(),
+        }))
+    })?;
+    utils::when(
+        mandate.mandate_status != storage_enums::MandateStatus::Active,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "You cannot schedule payments on this mandate because it has status {}",
+                    mandate.mandate_status
+                ),
+            }))
+        },
+    )?;
+    utils::when(
+        mandate.mandate_type != storage_enums::MandateType::MultiUse,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Payments can only be scheduled on multi use mandates".to_string(),
+            }))
+        },
+    )?;
+    let currency: storage_enums::Currency = req.currency.foreign_into();
+    utils::when(
+        mandate
+            .mandate_currency
+            .map_or(false, |mandate_currency| mandate_currency != currency),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "currency must be the same as the currency of the mandate".to_string(),
+            }))
+        },
+    )
+}
+
+#[instrument(skip(state))]
+pub async fn create_payment_schedule(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PaymentScheduleCreateRequest,
+) -> RouterResponse<api::PaymentScheduleResponse> {
+    let db = &*state.store;
+    validate_payment_schedule_request(&req)?;
+
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &req.mandate_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
+    validate_mandate_for_payment_schedule(&mandate, &req)?;
+
+    let now = date_time::now();
+    let start_at = req.start_at.unwrap_or(now);
+    let payment_schedule = db
+        .insert_payment_schedule(storage::PaymentScheduleNew {
+            schedule_id: utils::generate_id(consts::ID_LENGTH, "sch"),
+            merchant_id: merchant_account.merchant_id,
+            customer_id: req.customer_id,
+            mandate_id: req.mandate_id,
+            amount: req.amount,
+            currency: req.currency.foreign_into(),
+            billing_interval: req.interval.foreign_into(),
+            interval_count: req.interval_count.unwrap_or(1),
+            status: storage_enums::PaymentScheduleStatus::Active,
+            total_cycles: req.total_cycles,
+            next_payment_at: Some(start_at),
+            description: req.description,
+            created_at: Some(now),
+            modified_at: Some(now),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payment schedule")?;
+
+    add_payment_schedule_task(db, &payment_schedule, start_at)
+        .await
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while adding payment schedule task to process tracker")?;
+
+    Ok(ApplicationResponse::Json(
+        api::PaymentScheduleResponse::foreign_from(payment_schedule),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_payment_schedule(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PaymentScheduleId,
+) -> RouterResponse<api::PaymentScheduleResponse> {
+    let payment_schedule = state
+        .store
+        .find_payment_schedule_by_merchant_id_schedule_id(
+            &merchant_account.merchant_id,
+            &req.schedule_id,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentScheduleNotFound)
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        api::PaymentScheduleResponse::foreign_from(payment_schedule),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn cancel_payment_schedule(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PaymentScheduleId,
+) -> RouterResponse<api::PaymentScheduleResponse> {
+    let db = &*state.store;
+    let payment_schedule = db
+        .find_payment_schedule_by_merchant_id_schedule_id(
+            &merchant_account.merchant_id,
+            &req.schedule_id,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentScheduleNotFound)
+        })?;
+
+    utils::when(
+        payment_schedule.status != storage_enums::PaymentScheduleStatus::Active,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "You cannot cancel this payment schedule because it has status {}",
+                    payment_schedule.status
+                ),
+            }))
+        },
+    )?;
+
+    // The pending cycle of the schedule is skipped by the workflow once the schedule is cancelled
+    let payment_schedule = db
+        .update_payment_schedule(
+            payment_schedule,
+            storage::PaymentScheduleUpdate::StatusUpdate {
+                status: storage_enums::PaymentScheduleStatus::Cancelled,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cancel payment schedule")?;
+
+    Ok(ApplicationResponse::Json(
+        api::PaymentScheduleResponse::foreign_from(payment_schedule),
+    ))
+}
+
+/// Computes the time at which the cycle following a cycle charged at `current` is due.
+/// Months are added on the calendar, with the day clamped to the last day of the resulting month.
+pub fn get_next_payment_time(
+    current: PrimitiveDateTime,
+    interval: storage_enums::PaymentScheduleInterval,
+    interval_count: i32,
+) -> Option<PrimitiveDateTime> {
+    match interval {
+        storage_enums::PaymentScheduleInterval::Day => {
+            current.checked_add(Duration::days(interval_count.into()))
+        }
+        storage_enums::PaymentScheduleInterval::Week => {
+            current.checked_add(Duration::weeks(interval_count.into()))
+        }
+        storage_enums::PaymentScheduleInterval::Month => add_months(current, interval_count),
+        storage_enums::PaymentScheduleInterval::Year => {
+            add_months(current, interval_count.checked_mul(12)?)
+        }
+    }
+}
+
+fn add_months(current: PrimitiveDateTime, months: i32) -> Option<PrimitiveDateTime> {
+    let date = current.date();
+    let month_index = date
+        .year()
+        .checked_mul(12)?
+        .checked_add(i32::from(u8::from(date.month())))?
+        .checked_sub(1)?
+        .checked_add(months)?;
+    let year = month_index.div_euclid(12);
+    let month = u8::try_from(month_index.rem_euclid(12) + 1)
+        .ok()
+        .and_then(|month| time::Month::try_from(month).ok())?;
+    let day = date.day().min(time::util::days_in_year_month(year, month));
+    let date = time::Date::from_calendar_date(year, month, day).ok()?;
+    Some(PrimitiveDateTime::new(date, current.time()))
+}
+
+pub async fn add_payment_schedule_task(
+    db: &dyn StorageInterface,
+    payment_schedule: &storage::PaymentSchedule,
+    schedule_time: PrimitiveDateTime,
+) -> Result<(), errors::ProcessTrackerError> {
+    let cycle = payment_schedule.completed_cycles + payment_schedule.failed_cycles + 1;
+    let tracking_data = storage::PaymentScheduleTrackingData {
+        merchant_id: payment_schedule.merchant_id.clone(),
+        schedule_id: payment_schedule.schedule_id.clone(),
+        cycle,
+    };
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        PAYMENT_SCHEDULE_WORKFLOW_RUNNER,
+        PAYMENT_SCHEDULE_TASK,
+        &format!("{}_{cycle}", payment_schedule.schedule_id),
+        &payment_schedule.merchant_id,
+    );
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            PAYMENT_SCHEDULE_TASK,
+            PAYMENT_SCHEDULE_WORKFLOW_RUNNER,
+            tracking_data,
+            schedule_time,
+        )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+fn get_cycle_outcome(
+    payment_result: &RouterResult<(
+        payments::PaymentData<api::Authorize>,
+        api::PaymentsRequest,
+        Option<storage::Customer>,
+    )>,
+) -> CycleOutcome {
+    match payment_result {
+        Ok((payment_data, _, _)) => match payment_data.payment_intent.status {
+            storage_enums::IntentStatus::Succeeded
+            | storage_enums::IntentStatus::Processing
+            | storage_enums::IntentStatus::RequiresCapture
+            | storage_enums::IntentStatus::RequiresMerchantAction => CycleOutcome::Succeeded,
+            storage_enums::IntentStatus::Failed => CycleOutcome::Declined,
+            storage_enums::IntentStatus::RequiresCustomerAction
+            | storage_enums::IntentStatus::RequiresPaymentMethod
+            | storage_enums::IntentStatus::RequiresConfirmation
+            | storage_enums::IntentStatus::Cancelled
+            | storage_enums::IntentStatus::Expired => CycleOutcome::Rejected,
+        },
+        Err(error) => match error.current_context() {
+            errors::ApiErrorResponse::MandateNotFound
+            | errors::ApiErrorResponse::MandateValidationFailed { .. }
+            | errors::ApiErrorResponse::CustomerNotFound
+            | errors::ApiErrorResponse::PreconditionFailed { .. }
+            | errors::ApiErrorResponse::InvalidRequestData { .. }
+            | errors::ApiErrorResponse::MissingRequiredField { .. } => CycleOutcome::Rejected,
+            _ => CycleOutcome::Declined,
+        },
+    }
+}
+
+async fn trigger_payment_schedule_webhook(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payment_schedule: storage::PaymentSchedule,
+    event_type: storage_enums::EventType,
+    payment_id: Option<String>,
+) {
+    let schedule_id = payment_schedule.schedule_id.clone();
+    let result = webhooks::create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+        state.clone(),
+        merchant_account,
+        event_type,
+        storage_enums::EventClass::PaymentSchedules,
+        payment_id,
+        schedule_id,
+        storage_enums::EventObjectType::PaymentScheduleDetails,
+        api::OutgoingWebhookContent::PaymentScheduleDetails(Box::new(
+            api::PaymentScheduleResponse::foreign_from(payment_schedule),
+        )),
+    )
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to trigger payment schedule webhook");
+    }
+}
+
+/// Charges a cycle of a payment schedule: a payment is created and confirmed on the mandate of
+/// the schedule, declined payments are retried up to `payment_schedule.max_retries` times, and the
+/// next cycle is scheduled once the cycle succeeds or fails.
+#[instrument(skip_all)]
+pub async fn start_payment_schedule_workflow(
+    state: &AppState,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::PaymentScheduleTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("PaymentScheduleTrackingData")?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+        .await?;
+    let payment_schedule = db
+        .find_payment_schedule_by_merchant_id_schedule_id(
+            &tracking_data.merchant_id,
+            &tracking_data.schedule_id,
+        )
+        .await?;
+
+    let id = process.id.clone();
+    if payment_schedule.status != storage_enums::PaymentScheduleStatus::Active {
+        logger::info!(
+            "Skipping cycle {} of payment schedule {} with status {}",
+            tracking_data.cycle,
+            payment_schedule.schedule_id,
+            payment_schedule.status
+        );
+        return process
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+            .await;
+    }
+
+    let payment_request = api::PaymentsRequest {
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        amount: Some(payment_schedule.amount.into()),
+        currency: Some(payment_schedule.currency.foreign_into()),
+        customer_id: Some(payment_schedule.customer_id.clone()),
+        mandate_id: Some(payment_schedule.mandate_id.clone()),
+        off_session: Some(true),
+        confirm: Some(true),
+        description: payment_schedule.description.clone(),
+        ..Default::default()
+    };
+    let payment_result = payments::payments_operation_core::<api::Authorize, _, _, _>(
+        state,
+        merchant_account.clone(),
+        payments::operations::PaymentCreate,
+        payment_request,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await;
+
+    let cycle_outcome = get_cycle_outcome(&payment_result);
+    let payment_id = match payment_result {
+        Ok((payment_data, _, _)) => Some(payment_data.payment_intent.payment_id),
+        Err(error) => {
+            logger::error!(?error, "Payment schedule cycle failed");
+            None
+        }
+    };
+
+    let (payment_schedule_update, event_type) = match cycle_outcome {
+        CycleOutcome::Declined if process.retry_count < state.conf.payment_schedule.max_retries => {
+            let retry_time = date_time::now().saturating_add(Duration::seconds(
+                state.conf.payment_schedule.retry_interval,
+            ));
+            return process.retry(db, retry_time).await;
+        }
+        CycleOutcome::Succeeded | CycleOutcome::Declined => {
+            let succeeded = matches!(cycle_outcome, CycleOutcome::Succeeded);
+            let completed_cycles = payment_schedule.completed_cycles + i32::from(succeeded);
+            let failed_cycles = payment_schedule.failed_cycles + i32::from(!succeeded);
+            let is_completed = payment_schedule.total_cycles.map_or(false, |total_cycles| {
+                completed_cycles + failed_cycles >= total_cycles
+            });
+
+            let next_payment_at = if is_completed {
+                None
+            } else {
+                let next_payment_at = get_next_payment_time(
+                    payment_schedule
+                        .next_payment_at
+                        .unwrap_or_else(date_time::now),
+                    payment_schedule.billing_interval,
+                    payment_schedule.interval_count,
+                )
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .into_report()
+                .attach_printable("Failed to compute the time of the next cycle")?;
+                Some(next_payment_at)
+            };
+
+            (
+                storage::PaymentScheduleUpdate::CycleUpdate {
+                    status: if is_completed {
+                        storage_enums::PaymentScheduleStatus::Completed
+                    } else {
+                        storage_enums::PaymentScheduleStatus::Active
+                    },
+                    completed_cycles,
+                    failed_cycles,
+                    next_payment_at,
+                    last_payment_id: payment_id.clone(),
+                },
+                if succeeded {
+                    storage_enums::EventType::PaymentScheduleCycleSucceeded
+                } else {
+                    storage_enums::EventType::PaymentScheduleCycleFailed
+                },
+            )
+        }
+        CycleOutcome::Rejected => (
+            storage::PaymentScheduleUpdate::CycleUpdate {
+                status: storage_enums::PaymentScheduleStatus::Failed,
+                completed_cycles: payment_schedule.completed_cycles,
+                failed_cycles: payment_schedule.failed_cycles + 1,
+                next_payment_at: None,
+                last_payment_id: payment_id.clone(),
+            },
+            storage_enums::EventType::PaymentScheduleCycleFailed,
+        ),
+    };
+
+    let payment_schedule = db
+        .update_payment_schedule(payment_schedule, payment_schedule_update)
+        .await?;
+
+    if let Some(next_payment_at) = payment_schedule.next_payment_at {
+        add_payment_schedule_task(db, &payment_schedule, next_payment_at).await?;
+    }
+
+    trigger_payment_schedule_webhook(
+        state,
+        merchant_account,
+        payment_schedule,
+        event_type,
+        payment_id,
+    )
+    .await;
+
+    process
+        .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_next_payment_time_clamps_day_of_month() {
+        let next_payment_time = get_next_payment_time(
+            datetime!(2023-01-31 10:00),
+            storage_enums::PaymentScheduleInterval::Month,
+            1,
+        )
+        .unwrap();
+        assert_eq!(next_payment_time, datetime!(2023-02-28 10:00));
+    }
+
+    #[test]
+    fn test_next_payment_time_across_years() {
+        let next_payment_time = get_next_payment_time(
+            datetime!(2023-11-15 08:30),
+            storage_enums::PaymentScheduleInterval::Month,
+            3,
+        )
+        .unwrap();
+        assert_eq!(next_payment_time, datetime!(2024-02-15 08:30));
+
+        let next_payment_time = get_next_payment_time(
+            datetime!(2024-02-29 00:00),
+            storage_enums::PaymentScheduleInterval::Year,
+            1,
+        )
+        .unwrap();
+        assert_eq!(next_payment_time, datetime!(2025-02-28 00:00));
+    }
+
+    #[test]
+    fn test_next_payment_time_for_days_and_weeks() {
+        let next_payment_time = get_next_payment_time(
+            datetime!(2023-04-21 12:00),
+            storage_enums::PaymentScheduleInterval::Week,
+            2,
+        )
+        .unwrap();
+        assert_eq!(next_payment_time, datetime!(2023-05-05 12:00));
+
+        let next_payment_time = get_next_payment_time(
+            datetime!(2023-04-21 12:00),
+            storage_enums::PaymentScheduleInterval::Day,
+            10,
+        )
+        .unwrap();
+        assert_eq!(next_payment_time, datetime!(2023-05-01 12:00));
+    }
+}
@@ -1,8 +1,24 @@
 pub mod access_token;
+pub mod apple_pay;
+pub mod co_badged_cards;
+pub mod connector_logs;
+pub mod currency_conversion;
 pub mod flows;
+pub mod force_sync;
 pub mod helpers;
+pub mod idempotency;
 pub mod operations;
+pub mod payment_events;
+pub mod payment_lock;
+pub mod refund_on_cancel;
+pub mod retry;
+pub mod split_payments;
+pub mod split_tender;
+pub mod state_machine;
+pub mod surcharge;
+pub mod three_ds;
 pub mod transformers;
+pub mod verification;
 
 use std::{fmt::Debug, marker::PhantomData, time::Instant};
 
@@ -12,8 +28,9 @@ use router_env::{instrument, tracing};
 use time;
 
 pub use self::operations::{
-    PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate, PaymentMethodValidate,
-    PaymentResponse, PaymentSession, PaymentStatus, PaymentUpdate,
+    PaymentApprove, PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate,
+    PaymentMethodValidate, PaymentReject, PaymentResponse, PaymentSession, PaymentStatus,
+    PaymentUpdate,
 };
 use self::{
     flows::{ConstructFlowSpecificData, Feature},
@@ -21,8 +38,11 @@ use self::{
 };
 use crate::{
     core::{
-        errors::{self, CustomResult, RouterResponse, RouterResult},
+        card_vault,
+        errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+        fraud_check,
         payment_methods::vault,
+        routing,
     },
     db::StorageInterface,
     logger, pii,
@@ -30,7 +50,8 @@ use crate::{
     scheduler::utils as pt_utils,
     services,
     types::{
-        self, api,
+        self,
+        api::{self, PaymentIdTypeExt},
         storage::{self, enums as storage_enums},
     },
     utils::{Encode, OptionExt, ValueExt},
@@ -68,6 +89,20 @@ where
 
     tracing::Span::current().record("payment_id", &format!("{:?}", validate_result.payment_id));
 
+    // The lock is released once the payment data is returned, when it goes out of scope
+    let payment_lock = if operation.requires_lock() {
+        let payment_id = validate_result
+            .payment_id
+            .get_payment_intent_id()
+            .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+        Some(
+            payment_lock::PaymentLock::acquire(state, validate_result.merchant_id, &payment_id)
+                .await?,
+        )
+    } else {
+        None
+    };
+
     let (operation, mut payment_data, customer_details) = operation
         .to_get_tracker()?
         .get_trackers(
@@ -78,6 +113,10 @@ where
             &merchant_account,
         )
         .await?;
+    let previous_statuses = payment_events::PaymentStatuses::of(
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+    );
 
     let (operation, customer) = operation
         .to_domain()?
@@ -96,6 +135,7 @@ where
         .make_pm_data(state, &mut payment_data, validate_result.storage_scheme)
         .await?;
 
+    co_badged_cards::validate_card_network(payment_method_data.as_ref())?;
     payment_data.payment_method_data = payment_method_data;
 
     let connector = get_connector_choice(
@@ -107,6 +147,33 @@ where
     )
     .await?;
 
+    if operation.is_surcharged() {
+        surcharge::apply_surcharge(&*state.store, &merchant_account, &mut payment_data).await?;
+    }
+
+    if operation.is_currency_converted() {
+        currency_conversion::apply_currency_conversion(state, &merchant_account, &mut payment_data)
+            .await?;
+    }
+
+    // The fraud rules are evaluated before the trackers are updated, so that a forced 3DS
+    // authentication is stored against the payment attempt. The strictest of the actions of the
+    // fraud rules and the fraud check provider is taken.
+    let fraud_action = if operation.is_fraud_checked() {
+        let rules_action =
+            fraud_check::perform_fraud_check(state, &merchant_account, &mut payment_data).await?;
+        let provider_action = fraud_check::frm::perform_pre_auth_check(
+            state,
+            &merchant_account,
+            connector.as_ref(),
+            &mut payment_data,
+        )
+        .await?;
+        rules_action.max(provider_action)
+    } else {
+        None
+    };
+
     let (operation, mut payment_data) = operation
         .to_update_tracker()?
         .update_trackers(
@@ -124,9 +191,51 @@ where
             .add_task_to_process_tracker(state, &payment_data.payment_attempt)
             .await?;
 
+        let is_blocked = fraud_action == Some(fraud_check::FraudAction::Block);
+        if is_blocked {
+            fraud_check::decline_payment(
+                &*state.store,
+                &mut payment_data,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+        }
+
+        // Authorization is deferred when the cardholder is yet to complete a challenge with an
+        // external 3DS provider, or if the authentication failed
+        let skip_connector_call = is_blocked
+            || (operation.is_authenticatable()
+                && !three_ds::authenticate_with_external_provider(
+                    state,
+                    &merchant_account,
+                    &mut payment_data,
+                )
+                .await?);
+
+        // The gift card of a split tender payment is redeemed before the payment method is
+        // authorized, and may cover the complete amount of the payment
+        let skip_connector_call = skip_connector_call
+            || match &connector_details {
+                api::ConnectorCallType::Single(connector) if operation.is_split_tenderable() => {
+                    !split_tender::redeem_gift_card(
+                        state,
+                        &merchant_account,
+                        connector,
+                        &mut payment_data,
+                    )
+                    .await?
+                }
+                _ => false,
+            };
+
+        if let Some(payment_lock) = &payment_lock {
+            payment_lock.ensure_held().await?;
+        }
+
         payment_data = match connector_details {
+            _ if skip_connector_call => payment_data,
             api::ConnectorCallType::Single(connector) => {
-                let router_data = call_connector_service(
+                let mut router_data = call_connector_service(
                     state,
                     &merchant_account,
                     connector,
@@ -137,9 +246,81 @@ where
                 )
                 .await?;
 
+                if operation.is_retryable() {
+                    if let Some(retry_config) =
+                        retry::get_retry_config(&*state.store, &merchant_account.merchant_id)
+                            .await?
+                    {
+                        router_data = retry::retry_with_alternate_connectors(
+                            state,
+                            &merchant_account,
+                            &operation,
+                            &mut payment_data,
+                            &customer,
+                            router_data,
+                            &retry_config,
+                        )
+                        .await?;
+                    }
+                }
+
+                if operation.is_verifiable() {
+                    if let Some(verification_rules) = verification::get_verification_rules(
+                        &*state.store,
+                        &merchant_account.merchant_id,
+                    )
+                    .await?
+                    {
+                        router_data = verification::apply_verification_rules(
+                            state,
+                            &payment_data,
+                            &verification_rules,
+                            router_data,
+                        )
+                        .await?;
+                    }
+                }
+
+                let is_refund_required = operation.is_fraud_checked()
+                    && fraud_check::frm::perform_post_auth_check(
+                        state,
+                        &merchant_account,
+                        &mut payment_data,
+                        &mut router_data,
+                    )
+                    .await?;
+
+                if operation.is_success_rate_tracked() {
+                    routing::success_rate::update_success_rate(
+                        state,
+                        &merchant_account,
+                        &routing::get_routing_input(&payment_data),
+                        &router_data,
+                    )
+                    .await;
+                }
+
+                if operation.is_split_tenderable() {
+                    split_tender::complete_split_tender(
+                        state,
+                        &merchant_account,
+                        &mut payment_data,
+                        &router_data,
+                    )
+                    .await?;
+                }
+
+                if fraud_action == Some(fraud_check::FraudAction::ManualReview) {
+                    fraud_check::hold_for_review(&mut router_data);
+                }
+
+                if operation.is_force_syncable() && payment_data.force_sync.unwrap_or(false) {
+                    force_sync::record_status_mismatch(state, &payment_data, &router_data).await;
+                }
+
                 let operation = Box::new(PaymentResponse);
                 let db = &*state.store;
-                operation
+                let payment_data = operation
                     .to_post_update_tracker()?
                     .update_tracker(
                         db,
@@ -148,7 +329,19 @@ where
                         router_data,
                         merchant_account.storage_scheme,
                     )
-                    .await?
+                    .await?;
+
+                // A captured payment can only be refunded once it is marked as succeeded
+                if is_refund_required {
+                    fraud_check::frm::refund_declined_payment(
+                        state,
+                        &merchant_account,
+                        &payment_data,
+                    )
+                    .await;
+                }
+
+                payment_data
             }
 
             api::ConnectorCallType::Multiple(connectors) => {
@@ -164,11 +357,25 @@ where
             }
         };
         if payment_data.payment_intent.status != storage_enums::IntentStatus::RequiresCustomerAction
+            && !payment_data
+                .token
+                .as_deref()
+                .map_or(false, card_vault::is_vault_token)
         {
             vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token)
                 .await
         }
     }
+
+    payment_events::record_status_change(
+        state,
+        &format!("{operation:?}"),
+        previous_statuses,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+    )
+    .await;
+
     Ok((payment_data, req, customer))
 }
 
@@ -332,6 +539,52 @@ impl PaymentRedirectFlow for PaymentRedirectCompleteAuthorize {
     }
 }
 
+/// Completes a payment which is awaiting the shopper's action at the connector, such as the
+/// approval of a PayPal order, once the shopper is back on the merchant's website
+#[instrument(skip_all)]
+pub async fn payments_complete_authorize_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PaymentsRequest,
+    auth_flow: services::AuthFlow,
+) -> RouterResponse<api::PaymentsResponse> {
+    let payment_id = req
+        .payment_id
+        .as_ref()
+        .get_required_value("payment_id")?
+        .get_payment_intent_id()
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_intent.status != storage_enums::IntentStatus::RequiresCustomerAction {
+        return Err(errors::ApiErrorResponse::PaymentUnexpectedState {
+            field_name: "payment.status".to_string(),
+            current_flow: "complete_authorize".to_string(),
+            current_value: payment_intent.status.to_string(),
+            states: "requires_customer_action".to_string(),
+        })
+        .into_report();
+    }
+
+    payments_core::<api::CompleteAuthorize, api::PaymentsResponse, _, _, _>(
+        state,
+        merchant_account,
+        payment_complete_authorize::CompleteAuthorize,
+        req,
+        auth_flow,
+        CallConnectorAction::Trigger,
+    )
+    .await
+}
+
 #[derive(Clone, Debug)]
 pub struct PaymentRedirectSync;
 
@@ -426,7 +679,14 @@ where
             .await
     } else {
         Ok(router_data)
-    };
+    }
+    .map(|mut router_data| {
+        // The raw response of the connector is kept only for the merchants who opted in to it
+        if !merchant_account.return_raw_connector_response {
+            router_data.connector_raw_response = None;
+        }
+        router_data
+    });
 
     let etime_connector = Instant::now();
     let duration_connector = etime_connector.saturating_duration_since(stime_connector);
@@ -530,9 +790,9 @@ where
     pub payment_intent: storage::PaymentIntent,
     pub payment_attempt: storage::PaymentAttempt,
     pub connector_response: storage::ConnectorResponse,
-    pub amount: api::Amount,
+    pub amount: types::MinorUnit,
     pub mandate_id: Option<api_models::payments::MandateIds>,
-    pub currency: storage_enums::Currency,
+    pub currency: types::Currency,
     pub setup_mandate: Option<api::MandateData>,
     pub address: PaymentAddress,
     pub token: Option<String>,
@@ -540,10 +800,15 @@ where
     pub force_sync: Option<bool>,
     pub payment_method_data: Option<api::PaymentMethodData>,
     pub refunds: Vec<storage::Refund>,
+    pub captures: Vec<storage::Capture>,
+    pub funding_sources: Vec<storage::FundingSource>,
+    pub payment_splits: Vec<storage::PaymentSplit>,
+    pub gift_card_data: Option<api_models::payments::GiftCardData>,
     pub sessions_token: Vec<api::SessionToken>,
     pub card_cvc: Option<pii::Secret<String>>,
     pub email: Option<masking::Secret<String, pii::Email>>,
     pub creds_identifier: Option<String>,
+    pub network_token: Option<types::NetworkTokenData>,
 }
 
 #[derive(Debug, Default)]
@@ -609,15 +874,7 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
                 .authentication_data
                 .is_none()
         }
-        "PaymentStatus" => {
-            matches!(
-                payment_data.payment_intent.status,
-                storage_enums::IntentStatus::Failed
-                    | storage_enums::IntentStatus::Processing
-                    | storage_enums::IntentStatus::Succeeded
-                    | storage_enums::IntentStatus::RequiresCustomerAction
-            ) && payment_data.force_sync.unwrap_or(false)
-        }
+        "PaymentStatus" => payment_data.force_sync.unwrap_or(false),
         "PaymentCancel" => matches!(
             payment_data.payment_intent.status,
             storage_enums::IntentStatus::RequiresCapture
@@ -628,6 +885,7 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
                 storage_enums::IntentStatus::RequiresCapture
             )
         }
+        "PaymentApprove" | "PaymentReject" => true,
         "CompleteAuthorize" => true,
         "PaymentSession" => true,
         _ => false,
@@ -687,6 +945,125 @@ pub async fn list_payments(
     ))
 }
 
+/// Lists all the attempts made for the payment, across retries and connectors, ordered by the
+/// time of their creation
+#[cfg(feature = "olap")]
+pub async fn list_payment_attempts(
+    db: &dyn StorageInterface,
+    merchant: storage::MerchantAccount,
+    payment_id: String,
+) -> RouterResponse<api::PaymentAttemptListResponse> {
+    use crate::types::transformers::ForeignTryFrom;
+
+    db.find_payment_intent_by_payment_id_merchant_id(
+        &payment_id,
+        &merchant.merchant_id,
+        merchant.storage_scheme,
+    )
+    .await
+    .map_err(|error| {
+        errors::StorageErrorExt::to_not_found_response(
+            error,
+            errors::ApiErrorResponse::PaymentNotFound,
+        )
+    })?;
+
+    let mut payment_attempts = db
+        .find_attempts_by_merchant_id_payment_id(
+            &merchant.merchant_id,
+            &payment_id,
+            // since OLAP doesn't have KV. Force to get the data from PSQL.
+            storage_enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the attempts of the payment")?;
+    payment_attempts.sort_by_key(|payment_attempt| payment_attempt.created_at);
+
+    let data = payment_attempts
+        .into_iter()
+        .map(ForeignTryFrom::foreign_try_from)
+        .collect::<Result<_, _>>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentAttemptListResponse { data },
+    ))
+}
+
+/// Lists the changes in the status of the payment, in the order they were made
+#[cfg(feature = "olap")]
+pub async fn list_payment_events(
+    db: &dyn StorageInterface,
+    merchant: storage::MerchantAccount,
+    payment_id: String,
+) -> RouterResponse<api::PaymentEventListResponse> {
+    use crate::types::transformers::ForeignInto;
+
+    db.find_payment_intent_by_payment_id_merchant_id(
+        &payment_id,
+        &merchant.merchant_id,
+        merchant.storage_scheme,
+    )
+    .await
+    .map_err(|error| {
+        errors::StorageErrorExt::to_not_found_response(
+            error,
+            errors::ApiErrorResponse::PaymentNotFound,
+        )
+    })?;
+
+    let data = db
+        .list_payment_events_by_merchant_id_payment_id(&merchant.merchant_id, &payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the events of the payment")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentEventListResponse { data },
+    ))
+}
+
+/// Lists the requests made to connectors for the payment while it was in debug mode, in the order
+/// they were made
+#[cfg(feature = "olap")]
+pub async fn list_connector_logs(
+    db: &dyn StorageInterface,
+    merchant: storage::MerchantAccount,
+    payment_id: String,
+) -> RouterResponse<api::ConnectorLogListResponse> {
+    use crate::types::transformers::ForeignInto;
+
+    db.find_payment_intent_by_payment_id_merchant_id(
+        &payment_id,
+        &merchant.merchant_id,
+        merchant.storage_scheme,
+    )
+    .await
+    .map_err(|error| {
+        errors::StorageErrorExt::to_not_found_response(
+            error,
+            errors::ApiErrorResponse::PaymentNotFound,
+        )
+    })?;
+
+    let data = db
+        .list_connector_logs_by_merchant_id_payment_id(&merchant.merchant_id, &payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the connector logs of the payment")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        api::ConnectorLogListResponse { data },
+    ))
+}
+
 pub async fn add_process_sync_task(
     db: &dyn StorageInterface,
     payment_attempt: &storage::PaymentAttempt,
@@ -719,6 +1096,74 @@ pub async fn add_process_sync_task(
     Ok(())
 }
 
+pub async fn add_payment_expiry_task(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    schedule_time: time::PrimitiveDateTime,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = api::PaymentsRetrieveRequest {
+        merchant_id: Some(payment_intent.merchant_id.clone()),
+        resource_id: api::PaymentIdType::PaymentIntentId(payment_intent.payment_id.clone()),
+        ..Default::default()
+    };
+    let runner = "PAYMENT_EXPIRY_WORKFLOW";
+    let task = "PAYMENT_EXPIRY";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payment_intent.payment_id,
+        &payment_intent.merchant_id,
+    );
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            task,
+            runner,
+            tracking_data,
+            schedule_time,
+        )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+pub async fn add_auto_capture_task(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    schedule_time: time::PrimitiveDateTime,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = api::PaymentsRetrieveRequest {
+        merchant_id: Some(payment_intent.merchant_id.clone()),
+        resource_id: api::PaymentIdType::PaymentIntentId(payment_intent.payment_id.clone()),
+        ..Default::default()
+    };
+    let runner = "AUTO_CAPTURE_WORKFLOW";
+    let task = "AUTO_CAPTURE";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payment_intent.payment_id,
+        &payment_intent.merchant_id,
+    );
+
+    // Payments synced while awaiting capture already have their capture scheduled
+    if db.find_process_by_id(&process_tracker_id).await?.is_some() {
+        return Ok(());
+    }
+
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            task,
+            runner,
+            tracking_data,
+            schedule_time,
+        )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
 pub fn update_straight_through_routing<F>(
     payment_data: &mut PaymentData<F>,
     request_straight_through: serde_json::Value,
@@ -769,15 +1214,18 @@ where
                 api::ConnectorCallType::Multiple(connectors)
             }
 
-            api::ConnectorChoice::StraightThrough(straight_through) => connector_selection(
-                state,
-                merchant_account,
-                payment_data,
-                Some(straight_through),
-            )?,
+            api::ConnectorChoice::StraightThrough(straight_through) => {
+                connector_selection(
+                    state,
+                    merchant_account,
+                    payment_data,
+                    Some(straight_through),
+                )
+                .await?
+            }
 
             api::ConnectorChoice::Decide => {
-                connector_selection(state, merchant_account, payment_data, None)?
+                connector_selection(state, merchant_account, payment_data, None).await?
             }
         })
     } else if let api::ConnectorChoice::StraightThrough(val) = connector_choice {
@@ -792,7 +1240,7 @@ where
     Ok(connector)
 }
 
-pub fn connector_selection<F>(
+pub async fn connector_selection<F>(
     state: &AppState,
     merchant_account: &storage::MerchantAccount,
     payment_data: &mut PaymentData<F>,
@@ -816,12 +1264,70 @@ where
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Invalid straight through routing rules format")?;
 
-    let decided_connector = decide_connector(
+    let business_profile =
+        helpers::get_payment_business_profile(&*state.store, &payment_data.payment_intent).await?;
+
+    let is_already_routed = routing_data.routed_through.is_some();
+    let routing_input = routing::get_routing_input(payment_data);
+    let mut decided_connector = decide_connector(
         state,
         merchant_account,
+        business_profile.as_ref(),
+        &payment_data.payment_intent.payment_id,
         request_straight_through,
         &mut routing_data,
-    )?;
+        &routing_input,
+    )
+    .await?;
+
+    // A freshly routed payment is routed around a connector which the merchant has disabled or
+    // whose circuit is open, whereas a payment already routed through a connector fails fast when
+    // the connector is called
+    if let api::ConnectorCallType::Single(ref connector) = decided_connector {
+        let connector_name = connector.connector_name.to_string();
+        let is_disabled = !is_already_routed
+            && helpers::is_merchant_connector_account_disabled(
+                &*state.store,
+                &merchant_account.merchant_id,
+                &connector_name,
+            )
+            .await?;
+        if is_disabled
+            || (!is_already_routed
+                && services::api::circuit_breaker::is_circuit_open(
+                    state,
+                    &merchant_account.merchant_id,
+                    &connector_name,
+                )
+                .await)
+        {
+            match retry::get_next_connector(state, merchant_account, &[connector_name.clone()])
+                .await?
+            {
+                Some(alternate_connector) => {
+                    logger::info!(
+                        "Routing around {} through {}",
+                        connector.connector_name,
+                        alternate_connector.connector_name
+                    );
+                    routing_data.routed_through =
+                        Some(alternate_connector.connector_name.to_string());
+                    decided_connector = api::ConnectorCallType::Single(alternate_connector);
+                }
+                None if is_disabled => {
+                    Err(errors::ApiErrorResponse::MerchantConnectorAccountDisabled {
+                        connector: connector_name,
+                    })?
+                }
+                None => {}
+            }
+        }
+    }
+
+    co_badged_cards::select_card_network(
+        payment_data.payment_method_data.as_mut(),
+        routing_data.card_network.as_ref(),
+    );
 
     let encoded_routing_data = Encode::<storage::RoutingData>::encode_to_value(&routing_data)
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -832,11 +1338,18 @@ where
     Ok(decided_connector)
 }
 
-pub fn decide_connector(
+/// Decides the connector of the payment, from the first of these which is available: the connector
+/// it was already routed through, the straight through algorithm of the request or of the payment
+/// attempt, the routing algorithm of the business profile of the payment, the active routing config
+/// of the merchant and the routing algorithm of the merchant.
+pub async fn decide_connector(
     state: &AppState,
     merchant_account: &storage::MerchantAccount,
+    business_profile: Option<&storage::BusinessProfile>,
+    payment_id: &str,
     request_straight_through: Option<api::RoutingAlgorithm>,
     routing_data: &mut storage::RoutingData,
+    routing_input: &api::RoutingEvaluateRequest,
 ) -> RouterResult<api::ConnectorCallType> {
     if let Some(ref connector_name) = routing_data.routed_through {
         let connector_data = api::ConnectorData::get_connector_by_name(
@@ -851,9 +1364,15 @@ pub fn decide_connector(
     }
 
     if let Some(routing_algorithm) = request_straight_through {
-        let connector_name = match &routing_algorithm {
-            api::RoutingAlgorithm::Single(conn) => conn.to_string(),
-        };
+        routing::validate_routing_algorithm(&routing_algorithm)?;
+        let connector_name = routing::get_connector_from_routing_algorithm(
+            state,
+            &merchant_account.merchant_id,
+            payment_id,
+            routing_input,
+            &routing_algorithm,
+        )
+        .await?;
 
         let connector_data = api::ConnectorData::get_connector_by_name(
             &state.conf.connectors,
@@ -869,9 +1388,14 @@ pub fn decide_connector(
     }
 
     if let Some(ref routing_algorithm) = routing_data.algorithm {
-        let connector_name = match routing_algorithm {
-            api::RoutingAlgorithm::Single(conn) => conn.to_string(),
-        };
+        let connector_name = routing::get_connector_from_routing_algorithm(
+            state,
+            &merchant_account.merchant_id,
+            payment_id,
+            routing_input,
+            routing_algorithm,
+        )
+        .await?;
 
         let connector_data = api::ConnectorData::get_connector_by_name(
             &state.conf.connectors,
@@ -885,6 +1409,59 @@ pub fn decide_connector(
         return Ok(api::ConnectorCallType::Single(connector_data));
     }
 
+    if let Some(profile_routing_algorithm) =
+        business_profile.and_then(|business_profile| business_profile.routing_algorithm.clone())
+    {
+        let routing_algorithm: api::RoutingAlgorithm = profile_routing_algorithm
+            .parse_value("RoutingAlgorithm")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to deserialize business profile routing algorithm")?;
+
+        let connector_name = routing::get_connector_from_routing_algorithm(
+            state,
+            &merchant_account.merchant_id,
+            payment_id,
+            routing_input,
+            &routing_algorithm,
+        )
+        .await?;
+
+        let connector_data = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            &connector_name,
+            api::GetToken::Connector,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Business profile routing algorithm gave invalid connector")?;
+
+        routing_data.routed_through = Some(connector_name);
+        return Ok(api::ConnectorCallType::Single(connector_data));
+    }
+
+    if let Some(routing_rules) =
+        routing::get_active_routing_rules(&*state.store, &merchant_account.merchant_id).await?
+    {
+        let evaluation = routing::evaluate_routing_rules(&routing_rules, routing_input);
+        logger::debug!(
+            routed_through = %evaluation.connector,
+            matched_rule = ?evaluation.matched_rule,
+            "Payment routed by the active routing config"
+        );
+        routing_data.card_network = evaluation.card_network;
+
+        let connector_name = evaluation.connector.to_string();
+        let connector_data = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            &connector_name,
+            api::GetToken::Connector,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Routing config gave invalid connector")?;
+
+        routing_data.routed_through = Some(connector_name);
+        return Ok(api::ConnectorCallType::Single(connector_data));
+    }
+
     let routing_algorithm: api::RoutingAlgorithm = merchant_account
         .routing_algorithm
         .clone()
@@ -892,9 +1469,14 @@ pub fn decide_connector(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to deserialize merchant routing algorithm")?;
 
-    let connector_name = match routing_algorithm {
-        api::RoutingAlgorithm::Single(conn) => conn.to_string(),
-    };
+    let connector_name = routing::get_connector_from_routing_algorithm(
+        state,
+        &merchant_account.merchant_id,
+        payment_id,
+        routing_input,
+        &routing_algorithm,
+    )
+    .await?;
 
     let connector_data = api::ConnectorData::get_connector_by_name(
         &state.conf.connectors,
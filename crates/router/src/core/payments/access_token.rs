@@ -40,6 +40,8 @@ pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
         payment_id: router_data.payment_id,
         payment_method: router_data.payment_method,
         payment_method_id: router_data.payment_method_id,
+        connector_raw_response: router_data.connector_raw_response,
+        connector_debug_mode: router_data.connector_debug_mode,
         return_url: router_data.return_url,
         status: router_data.status,
         attempt_id: router_data.attempt_id,
@@ -0,0 +1,261 @@
+//! Decryption of Apple Pay tokens (PKPaymentToken) in the router, for connectors which accept the
+//! decrypted device account number and cryptogram instead of the encrypted token.
+
+use api_models::payments::ApplePayDecryptionConfig;
+use common_utils::pii;
+use error_stack::{report, IntoReport, ResultExt};
+use masking::PeekInterface;
+use openssl::{
+    derive::Deriver,
+    pkcs7::{Pkcs7, Pkcs7Flags},
+    pkey::PKey,
+    stack::Stack,
+    symm::{self, Cipher},
+    x509::{store::X509StoreBuilder, X509},
+};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings,
+    consts,
+    core::errors::{self, RouterResult},
+    types::{self, api},
+};
+
+const APPLE_PAY_DECRYPTION_METADATA_KEY: &str = "apple_pay_decryption";
+const EC_VERSION: &str = "EC_v1";
+/// Algorithm identifier of the key derivation function, prefixed with its length
+const KDF_ALGORITHM: &[u8] = b"\x0did-aes256-GCM";
+const KDF_PARTY_U_INFO: &[u8] = b"Apple";
+const GCM_TAG_LENGTH: usize = 16;
+
+#[derive(Debug, serde::Deserialize)]
+struct ApplePayPaymentData {
+    version: String,
+    data: String,
+    signature: String,
+    header: ApplePayTokenHeader,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApplePayTokenHeader {
+    ephemeral_public_key: String,
+    transaction_id: String,
+    application_data: Option<String>,
+}
+
+/// Reads the Apple Pay decryption configuration from the metadata of the merchant connector
+/// account. Returns `None` if the token is to be decrypted by the connector.
+fn get_decryption_config(
+    connector_meta_data: Option<&pii::SecretSerdeValue>,
+) -> RouterResult<Option<ApplePayDecryptionConfig>> {
+    connector_meta_data
+        .and_then(|metadata| metadata.peek().get(APPLE_PAY_DECRYPTION_METADATA_KEY))
+        .map(|config| {
+            // Not parsed with `ValueExt`, to keep the private key out of the error
+            serde_json::from_value(config.clone())
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "connector_metadata.apple_pay_decryption".to_string(),
+                    expected_format: "apple_pay_decryption_format".to_string(),
+                })
+        })
+        .transpose()
+}
+
+/// Decrypts the Apple Pay token of the payment, if the payment is made using Apple Pay and the
+/// merchant connector account is configured for decrypting Apple Pay tokens in the router.
+#[instrument(skip_all)]
+pub fn get_apple_pay_predecrypt_data(
+    apple_pay_settings: &settings::ApplePay,
+    router_data: &types::PaymentsAuthorizeRouterData,
+) -> RouterResult<Option<types::ApplePayPredecryptData>> {
+    let wallet_data = match &router_data.request.payment_method_data {
+        api::PaymentMethodData::Wallet(api_models::payments::WalletData::ApplePay(wallet_data)) => {
+            wallet_data
+        }
+        _ => return Ok(None),
+    };
+
+    get_decryption_config(router_data.connector_meta_data.as_ref())?
+        .map(|config| decrypt_apple_pay_token(apple_pay_settings, &config, wallet_data))
+        .transpose()
+}
+
+fn decrypt_apple_pay_token(
+    apple_pay_settings: &settings::ApplePay,
+    config: &ApplePayDecryptionConfig,
+    wallet_data: &api_models::payments::ApplePayWalletData,
+) -> RouterResult<types::ApplePayPredecryptData> {
+    let invalid_token_error = || errors::ApiErrorResponse::InvalidDataValue {
+        field_name: "payment_method_data.wallet.apple_pay.payment_data",
+    };
+
+    let payment_data: ApplePayPaymentData = serde_json::from_slice(
+        &decode_base64(&wallet_data.payment_data).change_context(invalid_token_error())?,
+    )
+    .into_report()
+    .change_context(invalid_token_error())?;
+
+    if payment_data.version != EC_VERSION {
+        return Err(report!(invalid_token_error()))
+            .attach_printable(format!("Unsupported version {}", payment_data.version));
+    }
+
+    let ephemeral_public_key = decode_base64(&payment_data.header.ephemeral_public_key)
+        .change_context(invalid_token_error())?;
+    let encrypted_data = decode_base64(&payment_data.data).change_context(invalid_token_error())?;
+
+    verify_signature(
+        apple_pay_settings,
+        &payment_data,
+        &ephemeral_public_key,
+        &encrypted_data,
+    )?;
+
+    let symmetric_key = derive_symmetric_key(config, &ephemeral_public_key)?;
+
+    let tag_start = encrypted_data
+        .len()
+        .checked_sub(GCM_TAG_LENGTH)
+        .ok_or_else(|| report!(invalid_token_error()))
+        .attach_printable("Encrypted data is shorter than the authentication tag")?;
+    let (ciphertext, tag) = encrypted_data.split_at(tag_start);
+
+    // Apple Pay tokens are encrypted with an initialization vector of zeros
+    let decrypted_data = symm::decrypt_aead(
+        Cipher::aes_256_gcm(),
+        &symmetric_key,
+        Some(&[0; 16]),
+        &[],
+        ciphertext,
+        tag,
+    )
+    .into_report()
+    .change_context(invalid_token_error())
+    .attach_printable("Failed to decrypt the Apple Pay token")?;
+
+    // Not parsed with `ByteSliceExt`, to keep the card details out of the error
+    serde_json::from_slice(&decrypted_data)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the decrypted Apple Pay token")
+}
+
+/// Verifies that the token was signed by Apple, using the Apple Root CA - G3 certificate.
+fn verify_signature(
+    apple_pay_settings: &settings::ApplePay,
+    payment_data: &ApplePayPaymentData,
+    ephemeral_public_key: &[u8],
+    encrypted_data: &[u8],
+) -> RouterResult<()> {
+    let invalid_signature_error = || errors::ApiErrorResponse::InvalidDataValue {
+        field_name: "payment_method_data.wallet.apple_pay.payment_data.signature",
+    };
+
+    if apple_pay_settings.root_certificate.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Apple Pay root certificate is not configured");
+    }
+
+    let root_certificate = X509::from_pem(apple_pay_settings.root_certificate.as_bytes())
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid Apple Pay root certificate")?;
+    let mut store_builder = X509StoreBuilder::new()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    store_builder
+        .add_cert(root_certificate)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let store = store_builder.build();
+    let certificates = Stack::<X509>::new()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    // The signature covers the ephemeral public key, the encrypted data, the transaction id and
+    // the application data, in that order
+    let mut signed_data = Vec::new();
+    signed_data.extend_from_slice(ephemeral_public_key);
+    signed_data.extend_from_slice(encrypted_data);
+    signed_data.extend(
+        hex::decode(&payment_data.header.transaction_id)
+            .into_report()
+            .change_context(invalid_signature_error())?,
+    );
+    if let Some(application_data) = &payment_data.header.application_data {
+        signed_data.extend(
+            hex::decode(application_data)
+                .into_report()
+                .change_context(invalid_signature_error())?,
+        );
+    }
+
+    let signature = Pkcs7::from_der(
+        &decode_base64(&payment_data.signature).change_context(invalid_signature_error())?,
+    )
+    .into_report()
+    .change_context(invalid_signature_error())?;
+
+    signature
+        .verify(
+            &certificates,
+            &store,
+            Some(&signed_data),
+            None,
+            Pkcs7Flags::empty(),
+        )
+        .into_report()
+        .change_context(invalid_signature_error())
+        .attach_printable("Apple Pay token signature verification failed")
+}
+
+/// Derives the symmetric key of the token from the shared secret of the ephemeral public key and
+/// the payment processing certificate of the merchant, using the key derivation function of
+/// NIST SP 800-56A.
+fn derive_symmetric_key(
+    config: &ApplePayDecryptionConfig,
+    ephemeral_public_key: &[u8],
+) -> RouterResult<[u8; 32]> {
+    let private_key =
+        PKey::private_key_from_pem(config.payment_processing_certificate_key.peek().as_bytes())
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name:
+                    "connector_metadata.apple_pay_decryption.payment_processing_certificate_key"
+                        .to_string(),
+                expected_format: "PEM encoded private key".to_string(),
+            })?;
+    let public_key = PKey::public_key_from_der(ephemeral_public_key)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_method_data.wallet.apple_pay.payment_data",
+        })
+        .attach_printable("Invalid ephemeral public key")?;
+
+    let shared_secret = Deriver::new(&private_key)
+        .and_then(|mut deriver| {
+            deriver.set_peer(&public_key)?;
+            deriver.derive_to_vec()
+        })
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to derive the shared secret of the Apple Pay token")?;
+
+    let mut kdf_input = Vec::new();
+    kdf_input.extend_from_slice(&1u32.to_be_bytes());
+    kdf_input.extend_from_slice(&shared_secret);
+    kdf_input.extend_from_slice(KDF_ALGORITHM);
+    kdf_input.extend_from_slice(KDF_PARTY_U_INFO);
+    kdf_input.extend_from_slice(&openssl::sha::sha256(config.merchant_identifier.as_bytes()));
+
+    Ok(openssl::sha::sha256(&kdf_input))
+}
+
+fn decode_base64(data: &str) -> error_stack::Result<Vec<u8>, base64::DecodeError> {
+    use base64::Engine;
+
+    consts::BASE64_ENGINE.decode(data).into_report()
+}
@@ -0,0 +1,188 @@
+//! Co-badged cards belong to a local network, such as Cartes Bancaires, as well as to the global
+//! network of their card number. The network such a card is processed with is the one selected by
+//! the customer, or else the one selected by the routing config of the merchant, or else the one
+//! chosen by the connector.
+
+use error_stack::report;
+use masking::PeekInterface;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    logger,
+    types::api::{self, enums as api_enums},
+};
+
+/// The BIN prefixes of co-badged cards, along with the local network the cards are co-badged with
+const CO_BADGED_BIN_PREFIXES: &[(&str, api_enums::CardNetwork)] = &[
+    ("497", api_enums::CardNetwork::CartesBancaires),
+    ("40000025", api_enums::CardNetwork::CartesBancaires),
+    ("55555525", api_enums::CardNetwork::CartesBancaires),
+];
+
+fn is_in_range(prefix: &str, start: u32, end: u32) -> bool {
+    prefix
+        .parse::<u32>()
+        .map_or(false, |prefix| (start..=end).contains(&prefix))
+}
+
+/// The global network of the card number, as given by its issuer identification number
+fn get_global_card_network(card_number: &str) -> Option<api_enums::CardNetwork> {
+    let prefix = |length: usize| card_number.get(..length).unwrap_or_default();
+
+    if prefix(1) == "4" {
+        Some(api_enums::CardNetwork::Visa)
+    } else if is_in_range(prefix(2), 51, 55) || is_in_range(prefix(4), 2221, 2720) {
+        Some(api_enums::CardNetwork::Mastercard)
+    } else if matches!(prefix(2), "34" | "37") {
+        Some(api_enums::CardNetwork::AmericanExpress)
+    } else if is_in_range(prefix(4), 3528, 3589) {
+        Some(api_enums::CardNetwork::JCB)
+    } else if matches!(prefix(2), "36" | "38") || is_in_range(prefix(3), 300, 305) {
+        Some(api_enums::CardNetwork::DinersClub)
+    } else if matches!(prefix(4), "6521" | "6522") || (prefix(2) == "60" && prefix(4) != "6011") {
+        Some(api_enums::CardNetwork::RuPay)
+    } else if prefix(4) == "6011" || prefix(2) == "65" || is_in_range(prefix(3), 644, 649) {
+        Some(api_enums::CardNetwork::Discover)
+    } else if prefix(2) == "62" {
+        Some(api_enums::CardNetwork::UnionPay)
+    } else if matches!(
+        prefix(4),
+        "5018" | "5020" | "5038" | "5893" | "6304" | "6759" | "6761" | "6762" | "6763"
+    ) {
+        Some(api_enums::CardNetwork::Maestro)
+    } else {
+        None
+    }
+}
+
+/// The networks of the card number, its global network first, followed by the local network it
+/// is co-badged with, if any.
+pub fn get_card_networks(card_number: &str) -> Vec<api_enums::CardNetwork> {
+    let local_network = CO_BADGED_BIN_PREFIXES
+        .iter()
+        .find(|(bin_prefix, _)| card_number.starts_with(bin_prefix))
+        .map(|(_, card_network)| card_network.clone());
+
+    get_global_card_network(card_number)
+        .into_iter()
+        .chain(local_network)
+        .collect()
+}
+
+/// Checks that the network selected for the card is one of the networks of the card. Cards whose
+/// networks cannot be told from their number are left to be checked by the connector.
+pub fn validate_card_network(
+    payment_method_data: Option<&api::PaymentMethodData>,
+) -> RouterResult<()> {
+    let card = match payment_method_data {
+        Some(api::PaymentMethodData::Card(card)) => card,
+        _ => return Ok(()),
+    };
+
+    match card.card_network {
+        Some(ref card_network) => {
+            let card_networks = get_card_networks(card.card_number.peek());
+            if card_networks.is_empty() || card_networks.contains(card_network) {
+                Ok(())
+            } else {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "card_network {card_network} is not one of the networks of the card"
+                    ),
+                }))
+            }
+        }
+        None => Ok(()),
+    }
+}
+
+/// Selects the network of a co-badged card which the customer did not select a network for, if
+/// the network is one of the networks of the card.
+pub fn select_card_network(
+    payment_method_data: Option<&mut api::PaymentMethodData>,
+    card_network: Option<&api_enums::CardNetwork>,
+) {
+    if let (Some(api::PaymentMethodData::Card(card)), Some(card_network)) =
+        (payment_method_data, card_network)
+    {
+        let card_networks = get_card_networks(card.card_number.peek());
+        if card.card_network.is_none()
+            && card_networks.len() > 1
+            && card_networks.contains(card_network)
+        {
+            logger::debug!(%card_network, "Selected the network of the co-badged card");
+            card.card_network = Some(card_network.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(
+        card_number: &str,
+        card_network: Option<api_enums::CardNetwork>,
+    ) -> api::PaymentMethodData {
+        api::PaymentMethodData::Card(api::Card {
+            card_number: masking::Secret::new(card_number.to_string()),
+            card_network,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_get_card_networks() {
+        assert_eq!(
+            get_card_networks("4000002500001001"),
+            vec![
+                api_enums::CardNetwork::Visa,
+                api_enums::CardNetwork::CartesBancaires
+            ]
+        );
+        assert_eq!(
+            get_card_networks("2223003122003222"),
+            vec![api_enums::CardNetwork::Mastercard]
+        );
+        assert_eq!(
+            get_card_networks("6011111111111117"),
+            vec![api_enums::CardNetwork::Discover]
+        );
+        assert!(get_card_networks("9999999999999999").is_empty());
+    }
+
+    #[test]
+    fn test_card_network_must_be_a_network_of_the_card() {
+        let co_badged_card = card(
+            "4000002500001001",
+            Some(api_enums::CardNetwork::CartesBancaires),
+        );
+        assert!(validate_card_network(Some(&co_badged_card)).is_ok());
+
+        let visa_card = card(
+            "4242424242424242",
+            Some(api_enums::CardNetwork::CartesBancaires),
+        );
+        assert!(validate_card_network(Some(&visa_card)).is_err());
+    }
+
+    #[test]
+    fn test_routed_network_is_selected_for_co_badged_cards_only() {
+        let mut co_badged_card = card("4000002500001001", None);
+        select_card_network(
+            Some(&mut co_badged_card),
+            Some(&api_enums::CardNetwork::CartesBancaires),
+        );
+        assert_eq!(
+            co_badged_card,
+            card(
+                "4000002500001001",
+                Some(api_enums::CardNetwork::CartesBancaires)
+            )
+        );
+
+        let mut visa_card = card("4242424242424242", None);
+        select_card_network(Some(&mut visa_card), Some(&api_enums::CardNetwork::Visa));
+        assert_eq!(visa_card, card("4242424242424242", None));
+    }
+}
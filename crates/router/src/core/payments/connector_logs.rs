@@ -0,0 +1,57 @@
+//! The requests made to connectors for payments in debug mode are stored along with the responses
+//! of the connectors, so that the integration with a connector can be debugged. A payment is in
+//! debug mode if it was created with `connector_debug_mode` set, or if debug mode is enabled for
+//! its merchant. Personal and card data are masked before the requests are stored.
+
+use router_env::{instrument, tracing};
+
+use crate::{
+    db::StorageInterface,
+    logger,
+    types::{self, storage},
+};
+
+/// Debug mode is enabled for all payments of a merchant by setting the config stored against
+/// this key to `true`.
+pub fn get_connector_debug_mode_key(merchant_id: &str) -> String {
+    format!("connector_debug_mode_{merchant_id}")
+}
+
+/// Returns whether the requests made to the connector for the router data are to be stored.
+#[instrument(skip_all)]
+pub async fn is_connector_debug_mode<F, Req, Resp>(
+    db: &dyn StorageInterface,
+    router_data: &types::RouterData<F, Req, Resp>,
+) -> bool {
+    if router_data.connector_debug_mode {
+        return true;
+    }
+
+    match db
+        .find_config_by_key_cached(&get_connector_debug_mode_key(&router_data.merchant_id))
+        .await
+    {
+        Ok(config) => config.config == "true",
+        Err(error) => {
+            if !error.current_context().is_db_not_found() {
+                logger::error!(
+                    ?error,
+                    "Failed to fetch the connector debug mode of the merchant"
+                );
+            }
+            false
+        }
+    }
+}
+
+/// Stores the request made to the connector. The request has already been made by the time it is
+/// stored, so failures to store it are logged rather than failing the payment.
+#[instrument(skip_all)]
+pub async fn store_connector_log(
+    db: &dyn StorageInterface,
+    connector_log: storage::ConnectorLogNew,
+) {
+    if let Err(error) = db.insert_connector_log(connector_log).await {
+        logger::error!(?error, "Failed to store the request made to the connector");
+    }
+}
@@ -0,0 +1,228 @@
+use common_utils::types::MinorUnit;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use super::{surcharge, PaymentData};
+use crate::{
+    core::errors::{self, RouterResult, StorageErrorExt},
+    routes::AppState,
+    services::forex_provider,
+    types::{
+        api::{self, enums as api_enums},
+        storage::{self, enums as storage_enums},
+        transformers::{ForeignFrom, ForeignInto},
+    },
+    utils::StringExt,
+};
+
+/// The currency a merchant settles payments in, to which the amounts of payments presented in
+/// other currencies are converted.
+///
+/// This is stored in the configs table against the key returned by
+/// [`get_currency_conversion_config_key`].
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct CurrencyConversionConfig {
+    pub settlement_currency: api_enums::Currency,
+}
+
+pub fn get_currency_conversion_config_key(merchant_id: &str) -> String {
+    format!("currency_conversion_{merchant_id}")
+}
+
+/// Converts the amount, in the minor unit of `from_currency`, to the minor unit of `to_currency`
+/// at the exchange rate, rounding half away from zero.
+#[allow(clippy::as_conversions)]
+pub fn convert_amount(
+    amount: MinorUnit,
+    from_currency: storage_enums::Currency,
+    to_currency: storage_enums::Currency,
+    rate: f64,
+) -> MinorUnit {
+    let exponent = i32::from(to_currency.number_of_digits_after_decimal_point())
+        - i32::from(from_currency.number_of_digits_after_decimal_point());
+    MinorUnit::new(
+        (amount.get_amount_as_i64() as f64 * rate * 10_f64.powi(exponent)).round() as i64,
+    )
+}
+
+pub fn get_settlement_details(
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<api::SettlementDetailsResponse> {
+    match (
+        payment_attempt.settlement_amount,
+        payment_attempt.settlement_currency,
+        payment_attempt.fx_rate.as_ref(),
+    ) {
+        (Some(settlement_amount), Some(settlement_currency), Some(fx_rate)) => {
+            Some(api::SettlementDetailsResponse {
+                settlement_amount,
+                settlement_currency: settlement_currency.foreign_into(),
+                fx_rate: fx_rate.to_owned(),
+            })
+        }
+        _ => None,
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn get_currency_conversion_config(
+    state: &AppState,
+    merchant_id: &str,
+) -> RouterResult<Option<CurrencyConversionConfig>> {
+    let config = match state
+        .store
+        .find_config_by_key_cached(&get_currency_conversion_config_key(merchant_id))
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Failed to fetch currency conversion config")
+        }
+    };
+
+    config
+        .config
+        .parse_struct("CurrencyConversionConfig")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid currency conversion config")
+        .map(Some)
+}
+
+/// Returns the exchange rate between the currencies, from the cache if it was fetched from the
+/// forex provider recently. Caching failures are logged, since the rate can always be fetched
+/// from the provider.
+#[instrument(skip_all)]
+pub async fn get_exchange_rate(
+    state: &AppState,
+    from_currency: storage_enums::Currency,
+    to_currency: storage_enums::Currency,
+) -> RouterResult<Option<f64>> {
+    match state
+        .store
+        .find_cached_exchange_rate(from_currency, to_currency)
+        .await
+    {
+        Ok(Some(rate)) => return Ok(Some(rate)),
+        Ok(None) => {}
+        Err(error) => logger::error!(?error, "Failed to fetch cached exchange rate"),
+    }
+
+    let provider = forex_provider::get_forex_provider_by_name(&state.conf.forex.provider)?;
+    let rate = provider
+        .get_exchange_rate(state, from_currency, to_currency)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed to fetch exchange rate from forex provider {}",
+                provider.id()
+            )
+        })?;
+
+    if let Some(rate) = rate {
+        if let Err(error) = state
+            .store
+            .cache_exchange_rate(from_currency, to_currency, rate, state.conf.forex.cache_ttl)
+            .await
+        {
+            logger::error!(?error, "Failed to cache exchange rate");
+        }
+    }
+
+    Ok(rate)
+}
+
+/// Converts the amount processed by the connector for the payment to the settlement currency of
+/// the merchant, and stores the converted amount and the applied exchange rate against the
+/// payment attempt.
+///
+/// Payments are not blocked if no exchange rate is available for the currency of the payment,
+/// they are only left without a settlement amount.
+#[instrument(skip_all)]
+pub async fn apply_currency_conversion<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    let config = match get_currency_conversion_config(state, &merchant_account.merchant_id).await? {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+
+    let settlement_currency = storage_enums::Currency::foreign_from(config.settlement_currency);
+    let presentment_currency = payment_data.currency.get_currency();
+    if presentment_currency == settlement_currency {
+        return Ok(());
+    }
+
+    let rate = match get_exchange_rate(state, presentment_currency, settlement_currency).await? {
+        Some(rate) => rate,
+        None => {
+            logger::warn!(
+                "No exchange rate available from {presentment_currency} to {settlement_currency}"
+            );
+            return Ok(());
+        }
+    };
+
+    let settlement_amount = convert_amount(
+        surcharge::get_connector_amount(payment_data),
+        presentment_currency,
+        settlement_currency,
+        rate,
+    )
+    .get_amount_as_i64();
+    payment_data.payment_attempt = state
+        .store
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::CurrencyConversionUpdate {
+                settlement_amount,
+                settlement_currency,
+                fx_rate: rate.to_string(),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amounts_are_converted_between_minor_units() {
+        assert_eq!(
+            convert_amount(
+                MinorUnit::new(1000),
+                storage_enums::Currency::USD,
+                storage_enums::Currency::EUR,
+                0.92
+            ),
+            MinorUnit::new(920)
+        );
+        assert_eq!(
+            convert_amount(
+                MinorUnit::new(1000),
+                storage_enums::Currency::USD,
+                storage_enums::Currency::JPY,
+                149.5
+            ),
+            MinorUnit::new(1495)
+        );
+        assert_eq!(
+            convert_amount(
+                MinorUnit::new(1495),
+                storage_enums::Currency::JPY,
+                storage_enums::Currency::KWD,
+                0.002
+            ),
+            MinorUnit::new(2990)
+        );
+    }
+}
@@ -81,13 +81,17 @@ default_imp_for_complete_authorize!(
     connector::Bluesnap,
     connector::Braintree,
     connector::Checkout,
+    connector::Coinbase,
     connector::Cybersource,
     connector::Dlocal,
+    connector::DummyConnector,
+    connector::Externalprocessor,
     connector::Fiserv,
     connector::Klarna,
     connector::Multisafepay,
     connector::Payu,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Shift4,
     connector::Stripe,
     connector::Trustpay,
@@ -121,14 +125,17 @@ default_imp_for_connector_redirect_response!(
     connector::Bambora,
     connector::Bluesnap,
     connector::Braintree,
+    connector::Coinbase,
     connector::Cybersource,
     connector::Dlocal,
+    connector::Externalprocessor,
     connector::Fiserv,
     connector::Globalpay,
     connector::Klarna,
     connector::Multisafepay,
     connector::Payu,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Shift4,
     connector::Worldline,
     connector::Worldpay
@@ -152,8 +159,11 @@ default_imp_for_connector_request_id!(
     connector::Bluesnap,
     connector::Braintree,
     connector::Checkout,
+    connector::Coinbase,
     connector::Cybersource,
     connector::Dlocal,
+    connector::DummyConnector,
+    connector::Externalprocessor,
     connector::Fiserv,
     connector::Globalpay,
     connector::Klarna,
@@ -162,6 +172,208 @@ default_imp_for_connector_request_id!(
     connector::Nuvei,
     connector::Payu,
     connector::Rapyd,
+    connector::Razorpay,
+    connector::Shift4,
+    connector::Stripe,
+    connector::Trustpay,
+    connector::Worldline,
+    connector::Worldpay
+);
+
+macro_rules! default_imp_for_mandate_revoke{
+    ($($path:ident::$connector:ident),*)=> {
+        $(
+            impl api::ConnectorMandateRevoke for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::MandateRevoke,
+            types::MandateRevokeRequestData,
+            types::MandateRevokeResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_mandate_revoke!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Airwallex,
+    connector::Applepay,
+    connector::Authorizedotnet,
+    connector::Bambora,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Coinbase,
+    connector::Cybersource,
+    connector::Dlocal,
+    connector::DummyConnector,
+    connector::Externalprocessor,
+    connector::Fiserv,
+    connector::Globalpay,
+    connector::Klarna,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Nuvei,
+    connector::Paypal,
+    connector::Payu,
+    connector::Rapyd,
+    connector::Razorpay,
+    connector::Shift4,
+    connector::Trustpay,
+    connector::Worldline,
+    connector::Worldpay
+);
+
+macro_rules! default_imp_for_verify_credentials{
+    ($($path:ident::$connector:ident),*)=> {
+        $(
+            impl api::ConnectorVerifyCredentials for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::VerifyCredentials,
+            types::VerifyCredentialsRequestData,
+            types::VerifyCredentialsResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_verify_credentials!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Airwallex,
+    connector::Applepay,
+    connector::Authorizedotnet,
+    connector::Bambora,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Coinbase,
+    connector::Cybersource,
+    connector::Dlocal,
+    connector::DummyConnector,
+    connector::Externalprocessor,
+    connector::Fiserv,
+    connector::Globalpay,
+    connector::Klarna,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Nuvei,
+    connector::Paypal,
+    connector::Payu,
+    connector::Rapyd,
+    connector::Razorpay,
+    connector::Shift4,
+    connector::Trustpay,
+    connector::Worldline,
+    connector::Worldpay
+);
+
+macro_rules! default_imp_for_gift_card_balance{
+    ($($path:ident::$connector:ident),*)=> {
+        $(
+            impl api::ConnectorGiftCardBalance for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::Balance,
+            types::GiftCardBalanceRequestData,
+            types::GiftCardBalanceResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_gift_card_balance!(
+    connector::Aci,
+    connector::Airwallex,
+    connector::Applepay,
+    connector::Authorizedotnet,
+    connector::Bambora,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Coinbase,
+    connector::Cybersource,
+    connector::Dlocal,
+    connector::DummyConnector,
+    connector::Externalprocessor,
+    connector::Fiserv,
+    connector::Globalpay,
+    connector::Klarna,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Nuvei,
+    connector::Paypal,
+    connector::Payu,
+    connector::Rapyd,
+    connector::Razorpay,
+    connector::Shift4,
+    connector::Stripe,
+    connector::Trustpay,
+    connector::Worldline,
+    connector::Worldpay
+);
+
+macro_rules! default_imp_for_payouts{
+    ($($path:ident::$connector:ident),*)=> {
+        $(
+            impl api::ConnectorPayouts for $path::$connector {}
+            impl api::PayoutFulfill for $path::$connector {}
+            impl api::PayoutSync for $path::$connector {}
+            impl api::PayoutCancel for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::PoFulfill,
+            types::PayoutsData,
+            types::PayoutsResponseData,
+        > for $path::$connector
+        {}
+            impl
+            services::ConnectorIntegration<
+            api::PoSync,
+            types::PayoutsData,
+            types::PayoutsResponseData,
+        > for $path::$connector
+        {}
+            impl
+            services::ConnectorIntegration<
+            api::PoCancel,
+            types::PayoutsData,
+            types::PayoutsResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_payouts!(
+    connector::Aci,
+    connector::Airwallex,
+    connector::Applepay,
+    connector::Authorizedotnet,
+    connector::Bambora,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Coinbase,
+    connector::Cybersource,
+    connector::Dlocal,
+    connector::DummyConnector,
+    connector::Externalprocessor,
+    connector::Fiserv,
+    connector::Globalpay,
+    connector::Klarna,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Nuvei,
+    connector::Paypal,
+    connector::Payu,
+    connector::Rapyd,
+    connector::Razorpay,
     connector::Shift4,
     connector::Stripe,
     connector::Trustpay,
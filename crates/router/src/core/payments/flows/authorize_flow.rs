@@ -5,7 +5,7 @@ use crate::{
     core::{
         errors::{ConnectorErrorExt, RouterResult},
         mandate,
-        payments::{self, access_token, transformers, PaymentData},
+        payments::{self, access_token, apple_pay, transformers, PaymentData},
     },
     routes::{metrics, AppState},
     services,
@@ -95,6 +95,8 @@ impl types::PaymentsAuthorizeRouterData {
                     types::PaymentsAuthorizeData,
                     types::PaymentsResponseData,
                 > = connector.connector.get_connector_integration();
+                self.request.apple_pay_predecrypt_data =
+                    apple_pay::get_apple_pay_predecrypt_data(&state.conf.apple_pay, self)?;
                 connector_integration
                     .execute_pretasks(self, state)
                     .await
@@ -129,7 +131,7 @@ impl types::PaymentsAuthorizeRouterData {
 
 impl mandate::MandateBehaviour for types::PaymentsAuthorizeData {
     fn get_amount(&self) -> i64 {
-        self.amount
+        self.amount.get_amount_as_i64()
     }
     fn get_mandate_id(&self) -> Option<&api_models::payments::MandateIds> {
         self.mandate_id.as_ref()
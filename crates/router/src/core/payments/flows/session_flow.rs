@@ -4,6 +4,7 @@ use error_stack::ResultExt;
 
 use super::{ConstructFlowSpecificData, Feature};
 use crate::{
+    connector::utils as connector_utils,
     core::{
         errors::{self, ConnectorErrorExt, RouterResult},
         payments::{self, access_token, transformers, PaymentData},
@@ -91,11 +92,16 @@ fn create_gpay_session_token(
         })?;
 
     let session_data = router_data.request.clone();
+    // Google Pay expects the price in the base unit of the currency
+    let total_price =
+        connector_utils::to_currency_base_unit(session_data.amount, session_data.currency)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert amount to base unit for Google Pay")?;
     let transaction_info = payment_types::GpayTransactionInfo {
         country_code: session_data.country.unwrap_or_default(),
         currency_code: router_data.request.currency.to_string(),
-        total_price_status: "Final".to_string(),
-        total_price: router_data.request.amount,
+        total_price_status: "FINAL".to_string(),
+        total_price,
     };
 
     let response_router_data = types::PaymentsSessionRouterData {
@@ -0,0 +1,74 @@
+use router_env::{instrument, tracing};
+
+use super::PaymentData;
+use crate::{
+    logger,
+    routes::AppState,
+    types::{self, storage::enums as storage_enums},
+};
+
+/// Whether the status of the payment attempt is not expected to be updated by the connector, in
+/// which case a different status at the connector is a mismatch rather than a pending update.
+fn is_status_final(status: storage_enums::AttemptStatus) -> bool {
+    matches!(
+        status,
+        storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::Charged
+            | storage_enums::AttemptStatus::PartialCharged
+            | storage_enums::AttemptStatus::PartiallyCaptured
+            | storage_enums::AttemptStatus::Voided
+            | storage_enums::AttemptStatus::AutoRefunded
+            | storage_enums::AttemptStatus::Underpaid
+            | storage_enums::AttemptStatus::Overpaid
+            | storage_enums::AttemptStatus::Failure
+            | storage_enums::AttemptStatus::RouterDeclined
+            | storage_enums::AttemptStatus::AuthenticationFailed
+            | storage_enums::AttemptStatus::AuthorizationFailed
+            | storage_enums::AttemptStatus::CaptureFailed
+            | storage_enums::AttemptStatus::VoidFailed
+    )
+}
+
+/// Records an audit of the payment attempt if the status reported by the connector differs from
+/// the final status stored in the router. The trackers are reconciled with the status of the
+/// connector by the post update tracker of the payment response.
+///
+/// Failures to record the audit are logged, since they should not fail the retrieval of the
+/// payment.
+#[instrument(skip_all)]
+pub async fn record_status_mismatch<F: Clone, Req>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+    router_data: &types::RouterData<F, Req, types::PaymentsResponseData>,
+) {
+    let payment_attempt = &payment_data.payment_attempt;
+    if router_data.response.is_err()
+        || router_data.status == payment_attempt.status
+        || !is_status_final(payment_attempt.status)
+    {
+        return;
+    }
+
+    logger::warn!(
+        payment_id = %payment_attempt.payment_id,
+        router_status = ?payment_attempt.status,
+        connector_status = ?router_data.status,
+        "Status of the payment attempt differs from the status at the connector"
+    );
+
+    let status_mismatch = types::storage::StatusMismatchNew {
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        connector: router_data.connector.clone(),
+        connector_transaction_id: payment_attempt.connector_transaction_id.clone(),
+        router_status: payment_attempt.status,
+        connector_status: router_data.status,
+    };
+    if let Err(error) = state.store.insert_status_mismatch(status_mismatch).await {
+        logger::error!(
+            ?error,
+            "Failed to record the status mismatch of the payment attempt"
+        );
+    }
+}
@@ -17,17 +17,20 @@ use super::{
     CustomerDetails, PaymentData,
 };
 use crate::{
-    configs::settings::Server,
+    configs::settings::{Server, SupportedConnectors},
     consts,
     core::{
+        card_vault,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         payment_methods::{cards, vault},
+        secrets,
     },
     db::StorageInterface,
     routes::{metrics, AppState},
-    scheduler::{metrics as scheduler_metrics, workflows::payment_sync},
+    scheduler::{metrics as scheduler_metrics, process_data, utils as pt_utils},
     services,
     types::{
+        self,
         api::{self, admin, enums as api_enums, CustomerAcceptanceExt, MandateValidationFieldsExt},
         storage::{self, enums as storage_enums, ephemeral_key},
         transformers::ForeignInto,
@@ -35,7 +38,7 @@ use crate::{
     utils::{
         self,
         crypto::{self, SignMessage},
-        OptionExt,
+        OptionExt, ValueExt,
     },
 };
 
@@ -198,6 +201,51 @@ pub async fn get_token_for_recurring_mandate(
     Ok((Some(token), Some(payment_method.payment_method)))
 }
 
+/// Returns the token for the payment method saved for the customer, which is used for the payment
+/// in place of the payment method data.
+#[instrument(skip_all)]
+pub async fn get_token_for_saved_payment_method(
+    state: &AppState,
+    payment_method_id: &str,
+    customer_id: Option<&String>,
+    merchant_account: &storage::MerchantAccount,
+) -> RouterResult<(String, storage_enums::PaymentMethod)> {
+    let payment_method = state
+        .store
+        .find_payment_method(payment_method_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+
+    utils::when(
+        payment_method.merchant_id != merchant_account.merchant_id,
+        || Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound)),
+    )?;
+
+    utils::when(customer_id != Some(&payment_method.customer_id), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "customer associated with payment method and customer passed in payment are not same".into()
+        }))
+    })?;
+
+    // Cards saved in the card vault are referred to by their vault tokens, while the ones saved
+    // in the locker are made available to the payment through the temporary locker
+    let token = if card_vault::is_vault_token(&payment_method.payment_method_id) {
+        payment_method.payment_method_id.clone()
+    } else {
+        let token = Uuid::new_v4().to_string();
+        let locker_id = merchant_account
+            .locker_id
+            .to_owned()
+            .get_required_value("locker_id")?;
+        cards::get_lookup_key_from_locker(state, &token, &payment_method, &locker_id).await?;
+        token
+    };
+
+    Ok((token, payment_method.payment_method))
+}
+
 #[instrument(skip_all)]
 /// Check weather the merchant id in the request
 /// and merchant id in the merchant account are same.
@@ -252,6 +300,62 @@ pub fn validate_request_amount_and_amount_to_capture(
     }
 }
 
+pub async fn get_mandate_ids(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    mandate_id: Option<&String>,
+) -> RouterResult<Option<api_models::payments::MandateIds>> {
+    mandate_id
+        .async_map(|mandate_id| async {
+            db.find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id)
+                .await
+                .change_context(errors::ApiErrorResponse::MandateNotFound)
+                .map(|mandate| api_models::payments::MandateIds {
+                    mandate_id: mandate.mandate_id,
+                    connector_mandate_id: mandate.connector_mandate_id,
+                    network_transaction_id: mandate.network_transaction_id,
+                })
+        })
+        .await
+        .transpose()
+}
+
+/// Merchant initiated payments on a mandate are made without the customer being present, so the
+/// customer cannot be authenticated for them.
+pub fn get_authentication_type_for_mandate(
+    mandate_type: &Option<api::MandateTxnType>,
+    authentication_type: Option<storage_enums::AuthenticationType>,
+) -> Option<storage_enums::AuthenticationType> {
+    match mandate_type {
+        Some(api::MandateTxnType::RecurringMandateTxn) => {
+            Some(storage_enums::AuthenticationType::NoThreeDs)
+        }
+        Some(api::MandateTxnType::NewMandateTxn) | None => authentication_type,
+    }
+}
+
+/// The stored credential indicator to be sent to the connector, as required by card network rules
+/// for payments which store the payment method of the customer or use a stored payment method.
+pub fn get_stored_credential_indicator(
+    mandate_id: Option<&api_models::payments::MandateIds>,
+    setup_mandate: Option<&api::MandateData>,
+    setup_future_usage: Option<storage_enums::FutureUsage>,
+) -> Option<types::StoredCredentialIndicator> {
+    match mandate_id {
+        Some(mandate_ids) => Some(
+            types::StoredCredentialIndicator::SubsequentMerchantInitiated {
+                network_transaction_id: mandate_ids.network_transaction_id.clone(),
+            },
+        ),
+        None if setup_mandate.is_some()
+            || setup_future_usage == Some(storage_enums::FutureUsage::OffSession) =>
+        {
+            Some(types::StoredCredentialIndicator::InitialCustomerInitiated)
+        }
+        None => None,
+    }
+}
+
 pub fn validate_mandate(
     req: impl Into<api::MandateValidationFields>,
 ) -> RouterResult<Option<api::MandateTxnType>> {
@@ -491,16 +595,15 @@ where
             .routed_through
             .ok_or(errors::ApiErrorResponse::InternalServerError)?;
 
-        let schedule_time = payment_sync::get_sync_process_schedule_time(
+        let schedule_time = pt_utils::get_retry_schedule_time(
             &*state.store,
-            &connector_name,
+            process_data::RetryPolicy::PaymentsSync {
+                connector: &connector_name,
+            },
             &payment_attempt.merchant_id,
             0,
         )
-        .await
-        .into_report()
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed while getting process schedule time")?;
+        .await;
 
         match schedule_time {
             Some(stime) => {
@@ -721,6 +824,47 @@ pub async fn make_pm_data<'a, F: Clone, R>(
 
     // TODO: Handle case where payment method and token both are present in request properly.
     let payment_method = match (request, token) {
+        (_, Some(token)) if card_vault::is_vault_token(&token) => {
+            let card_vault::DecryptedVaultedCard {
+                vaulted_card,
+                card,
+                network_token,
+            } = card_vault::get_card_from_vault(
+                state,
+                &payment_data.payment_intent.merchant_id,
+                &token,
+            )
+            .await?;
+
+            utils::when(
+                vaulted_card.customer_id.is_some()
+                    && vaulted_card
+                        .customer_id
+                        .ne(&payment_data.payment_intent.customer_id),
+                || {
+                    Err(errors::ApiErrorResponse::PreconditionFailed { message: "customer associated with payment method and customer passed in payment are not same".into() })
+                },
+            )?;
+
+            payment_data.payment_attempt.payment_method = Some(storage_enums::PaymentMethod::Card);
+            // Cryptograms are fetched only when the payment is about to be authorized
+            if let Some(network_token) =
+                network_token.filter(|_| payment_data.confirm.unwrap_or(false))
+            {
+                payment_data.network_token =
+                    card_vault::network_token::get_network_token_for_payment(state, network_token)
+                        .await;
+            }
+            // The CVC is never stored in the vault, and must be passed in the request if required
+            Ok(Some(api::PaymentMethodData::Card(api::Card {
+                card_number: card.card_number,
+                card_exp_month: card.card_exp_month,
+                card_exp_year: card.card_exp_year,
+                card_holder_name: card.card_holder_name.unwrap_or_default(),
+                card_cvc: card_cvc.unwrap_or_default(),
+                ..api::Card::default()
+            })))
+        }
         (_, Some(token)) => {
             let (pm, supplementary_data) = vault::Vault::get_payment_method_data_from_locker(
                 state, &token,
@@ -791,20 +935,42 @@ pub async fn make_pm_data<'a, F: Clone, R>(
                 None => None,
             })
         }
-        (pm_opt @ Some(pm @ api::PaymentMethodData::Card(_)), _) => {
-            let token = vault::Vault::store_payment_method_data_in_locker(
-                state,
-                None,
-                pm,
-                payment_data.payment_intent.customer_id.to_owned(),
-                enums::PaymentMethod::Card,
-            )
-            .await?;
+        (pm_opt @ Some(pm @ api::PaymentMethodData::Card(card)), _) => {
+            // Cards are stored in the card vault once the payment is confirmed, so that
+            // subsequent payments can be made using the token
+            let token = if payment_data.confirm.unwrap_or(false) {
+                card_vault::store_card_in_vault(
+                    state,
+                    &payment_data.payment_intent.merchant_id,
+                    payment_data.payment_intent.customer_id.to_owned(),
+                    &api::CardDetail {
+                        card_number: card.card_number.clone(),
+                        card_exp_month: card.card_exp_month.clone(),
+                        card_exp_year: card.card_exp_year.clone(),
+                        card_holder_name: Some(card.card_holder_name.clone()),
+                    },
+                )
+                .await?
+                .payment_token
+            } else {
+                vault::Vault::store_payment_method_data_in_locker(
+                    state,
+                    None,
+                    pm,
+                    payment_data.payment_intent.customer_id.to_owned(),
+                    enums::PaymentMethod::Card,
+                )
+                .await?
+            };
             payment_data.token = Some(token);
             Ok(pm_opt.to_owned())
         }
         (pm @ Some(api::PaymentMethodData::PayLater(_)), _) => Ok(pm.to_owned()),
         (pm @ Some(api::PaymentMethodData::BankRedirect(_)), _) => Ok(pm.to_owned()),
+        (pm @ Some(api::PaymentMethodData::BankDebit(_)), _) => Ok(pm.to_owned()),
+        (pm @ Some(api::PaymentMethodData::Upi(_)), _) => Ok(pm.to_owned()),
+        (pm @ Some(api::PaymentMethodData::Crypto(_)), _) => Ok(pm.to_owned()),
+        (pm @ Some(api::PaymentMethodData::GiftCard(_)), _) => Ok(pm.to_owned()),
         (pm_opt @ Some(pm @ api::PaymentMethodData::Wallet(_)), _) => {
             let token = vault::Vault::store_payment_method_data_in_locker(
                 state,
@@ -841,20 +1007,67 @@ pub(crate) fn validate_capture_method(
 }
 
 #[instrument(skip_all)]
-pub(crate) fn validate_status(status: storage_enums::IntentStatus) -> RouterResult<()> {
+pub(crate) fn validate_status_for_capture(
+    intent_status: storage_enums::IntentStatus,
+    attempt_status: storage_enums::AttemptStatus,
+    capture_method: storage_enums::CaptureMethod,
+) -> RouterResult<()> {
+    // Payments with multiple captures can be captured again until the authorized amount is exhausted
+    let is_further_capture_allowed = capture_method == storage_enums::CaptureMethod::ManualMultiple
+        && attempt_status == storage_enums::AttemptStatus::PartiallyCaptured;
+
     utils::when(
-        status != storage_enums::IntentStatus::RequiresCapture,
+        intent_status != storage_enums::IntentStatus::RequiresCapture
+            && !is_further_capture_allowed,
         || {
             Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
                 field_name: "payment.status".to_string(),
                 current_flow: "captured".to_string(),
-                current_value: status.to_string(),
+                current_value: intent_status.to_string(),
                 states: "requires_capture".to_string()
             }))
         },
     )
 }
 
+/// Only payments held for review can be approved or rejected by the merchant.
+#[instrument(skip_all)]
+pub(crate) fn validate_status_for_review(
+    intent_status: storage_enums::IntentStatus,
+    current_flow: &str,
+) -> RouterResult<()> {
+    utils::when(
+        intent_status != storage_enums::IntentStatus::RequiresMerchantAction,
+        || {
+            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+                field_name: "payment.status".to_string(),
+                current_flow: current_flow.to_string(),
+                current_value: intent_status.to_string(),
+                states: "requires_merchant_action".to_string()
+            }))
+        },
+    )
+}
+
+#[instrument(skip_all)]
+pub(crate) fn validate_multiple_capture_support(
+    supported_connectors: &SupportedConnectors,
+    connector: &str,
+) -> RouterResult<()> {
+    utils::when(
+        !supported_connectors
+            .multiple_capture
+            .iter()
+            .any(|supported_connector| supported_connector == connector),
+        || {
+            Err(report!(errors::ApiErrorResponse::FlowNotSupported {
+                flow: "Multiple capture".to_string(),
+                connector: connector.to_string(),
+            }))
+        },
+    )
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_amount_to_capture(
     amount: i64,
@@ -870,6 +1083,20 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+#[instrument(skip_all)]
+pub(crate) fn validate_amount_to_capture_is_positive(
+    amount_to_capture: Option<i64>,
+) -> RouterResult<()> {
+    utils::when(
+        amount_to_capture.map_or(false, |amount| amount <= 0),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "amount_to_capture should be greater than 0".to_string()
+            }))
+        },
+    )
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
     req: &api::PaymentsRequest,
@@ -894,22 +1121,62 @@ pub(crate) fn validate_payment_method_fields_present(
         },
     )?;
 
+    if let Some(api::PaymentMethodData::Wallet(api_models::payments::WalletData::GooglePay(
+        google_pay_data,
+    ))) = &req.payment_method_data
+    {
+        validate_google_pay_wallet_data(google_pay_data)?;
+    }
+
+    if let Some(api::PaymentMethodData::Upi(api_models::payments::UpiData::UpiCollect { vpa_id })) =
+        &req.payment_method_data
+    {
+        common_utils::validation::validate_upi_vpa(vpa_id.peek()).change_context(
+            errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "payment_method_data.upi.upi_collect.vpa_id",
+            },
+        )?;
+    }
+
     Ok(())
 }
 
+/// Only card payments tokenized by the gateway are accepted, tokens encrypted directly for the
+/// merchant would have to be decrypted by the router.
+fn validate_google_pay_wallet_data(
+    google_pay_data: &api_models::payments::GooglePayWalletData,
+) -> RouterResult<()> {
+    utils::when(google_pay_data.pm_type != "CARD", || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_method_data.wallet.google_pay.type",
+        }))
+    })?;
+
+    utils::when(
+        google_pay_data.tokenization_data.token_type != "PAYMENT_GATEWAY",
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Only Google Pay tokens of the `PAYMENT_GATEWAY` tokenization type are \
+                          supported"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    utils::when(google_pay_data.tokenization_data.token.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "payment_method_data.wallet.google_pay.tokenization_data.token",
+        }))
+    })
+}
+
 pub fn check_force_psync_precondition(
     status: &storage_enums::AttemptStatus,
     connector_transaction_id: &Option<String>,
 ) -> bool {
     !matches!(
         status,
-        storage_enums::AttemptStatus::Charged
-            | storage_enums::AttemptStatus::AutoRefunded
-            | storage_enums::AttemptStatus::Voided
-            | storage_enums::AttemptStatus::CodInitiated
-            | storage_enums::AttemptStatus::Authorized
-            | storage_enums::AttemptStatus::Started
-            | storage_enums::AttemptStatus::Failure
+        storage_enums::AttemptStatus::Started | storage_enums::AttemptStatus::CodInitiated
     ) && connector_transaction_id.is_some()
 }
 
@@ -1013,6 +1280,14 @@ pub async fn make_ephemeral_key(
     merchant_id: String,
 ) -> errors::RouterResponse<ephemeral_key::EphemeralKey> {
     let store = &state.store;
+
+    // An ephemeral key grants access to the saved payment methods of the customer, so it can only
+    // be created for an existing customer of the merchant
+    store
+        .find_customer_by_customer_id_merchant_id(&customer_id, &merchant_id)
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
+
     let id = utils::generate_id(consts::ID_LENGTH, "eki");
     let secret = format!("epk_{}", &Uuid::new_v4().simple().to_string());
     let ek = ephemeral_key::EphemeralKeyNew {
@@ -1032,9 +1307,25 @@ pub async fn make_ephemeral_key(
 pub async fn delete_ephemeral_key(
     store: &dyn StorageInterface,
     ek_id: String,
+    merchant_id: &str,
 ) -> errors::RouterResponse<ephemeral_key::EphemeralKey> {
     let ek = store
-        .delete_ephemeral_key(&ek_id)
+        .get_ephemeral_key(&ek_id)
+        .await
+        .map_err(|err| match err.current_context() {
+            errors::StorageError::ValueNotFound(_) => {
+                err.change_context(errors::ApiErrorResponse::EphemeralKeyNotFound)
+            }
+            _ => err.change_context(errors::ApiErrorResponse::InternalServerError),
+        })
+        .attach_printable("Unable to retrieve ephemeral key")?;
+
+    utils::when(ek.merchant_id != merchant_id, || {
+        Err(report!(errors::ApiErrorResponse::EphemeralKeyNotFound))
+    })?;
+
+    let ek = store
+        .delete_ephemeral_key(&ek.id)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to delete ephemeral key")?;
@@ -1198,6 +1489,124 @@ pub(crate) fn authenticate_client_secret(
     }
 }
 
+/// Requests made with a publishable key and the client secret of the payment come from the client
+/// side, so they are restricted to the details of the customer and the payment method. The amount
+/// of the payment and the settings chosen by the merchant can only be changed with an API key.
+pub(crate) fn validate_client_request_fields(req: &api::PaymentsRequest) -> RouterResult<()> {
+    if req.client_secret.is_none() {
+        return Ok(());
+    }
+
+    let restricted_fields = [
+        ("amount", req.amount.is_some()),
+        ("currency", req.currency.is_some()),
+        ("amount_to_capture", req.amount_to_capture.is_some()),
+        ("capture_method", req.capture_method.is_some()),
+        ("capture_on", req.capture_on.is_some()),
+        ("merchant_id", req.merchant_id.is_some()),
+        ("routing", req.routing.is_some()),
+        ("connector", req.connector.is_some()),
+        (
+            "merchant_connector_details",
+            req.merchant_connector_details.is_some(),
+        ),
+        ("customer_id", req.customer_id.is_some()),
+        ("off_session", req.off_session.is_some()),
+        ("authentication_type", req.authentication_type.is_some()),
+        ("payment_method_id", req.payment_method_id.is_some()),
+        ("mandate_id", req.mandate_id.is_some()),
+        ("description", req.description.is_some()),
+        (
+            "statement_descriptor_name",
+            req.statement_descriptor_name.is_some(),
+        ),
+        (
+            "statement_descriptor_suffix",
+            req.statement_descriptor_suffix.is_some(),
+        ),
+        ("metadata", req.metadata.is_some()),
+        ("session_expiry", req.session_expiry.is_some()),
+        ("auto_capture_delay", req.auto_capture_delay.is_some()),
+        ("connector_debug_mode", req.connector_debug_mode.is_some()),
+        ("order_details", req.order_details.is_some()),
+        ("amount_breakdown", req.amount_breakdown.is_some()),
+        ("l2_l3_data", req.l2_l3_data.is_some()),
+        ("profile_id", req.profile_id.is_some()),
+        ("split_payments", req.split_payments.is_some()),
+    ];
+
+    match restricted_fields.iter().find(|(_, is_present)| *is_present) {
+        Some((field_name, _)) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{field_name} cannot be set with a publishable key"),
+        })),
+        None => Ok(()),
+    }
+}
+
+/// Whether the payment was confirmed and is being processed by the connector, after which only
+/// the details of the payment which do not change what the connector processes can be updated.
+pub(crate) fn is_payment_confirmed(intent_status: storage_enums::IntentStatus) -> bool {
+    matches!(
+        intent_status,
+        storage_enums::IntentStatus::Processing | storage_enums::IntentStatus::RequiresCapture
+    )
+}
+
+/// Validates that an update of a confirmed payment leaves the amount, currency, payment method and
+/// other details processed by the connector unchanged. Its addresses, return URL and level 2 /
+/// level 3 data can still be updated.
+pub(crate) fn validate_confirmed_payment_update(
+    req: &api::PaymentsRequest,
+    intent_status: storage_enums::IntentStatus,
+) -> RouterResult<()> {
+    let immutable_fields = [
+        ("amount", req.amount.is_some()),
+        ("currency", req.currency.is_some()),
+        ("capture_method", req.capture_method.is_some()),
+        ("amount_to_capture", req.amount_to_capture.is_some()),
+        ("capture_on", req.capture_on.is_some()),
+        ("confirm", req.confirm.is_some()),
+        ("customer_id", req.customer_id.is_some()),
+        ("off_session", req.off_session.is_some()),
+        ("setup_future_usage", req.setup_future_usage.is_some()),
+        ("authentication_type", req.authentication_type.is_some()),
+        ("payment_method_data", req.payment_method_data.is_some()),
+        ("payment_method", req.payment_method.is_some()),
+        ("payment_token", req.payment_token.is_some()),
+        ("payment_method_id", req.payment_method_id.is_some()),
+        ("card_cvc", req.card_cvc.is_some()),
+        ("mandate_data", req.mandate_data.is_some()),
+        ("mandate_id", req.mandate_id.is_some()),
+        ("browser_info", req.browser_info.is_some()),
+        ("payment_experience", req.payment_experience.is_some()),
+        ("payment_method_type", req.payment_method_type.is_some()),
+        ("routing", req.routing.is_some()),
+        ("connector", req.connector.is_some()),
+        (
+            "merchant_connector_details",
+            req.merchant_connector_details.is_some(),
+        ),
+        ("order_details", req.order_details.is_some()),
+        ("amount_breakdown", req.amount_breakdown.is_some()),
+        ("gift_card_data", req.gift_card_data.is_some()),
+    ];
+
+    let updated_fields = immutable_fields
+        .iter()
+        .filter(|(_, is_present)| *is_present)
+        .map(|(field_name, _)| *field_name)
+        .collect::<Vec<_>>();
+
+    utils::when(!updated_fields.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "{} cannot be updated because the payment was confirmed and has status {intent_status}",
+                updated_fields.join(", ")
+            ),
+        }))
+    })
+}
+
 pub(crate) fn validate_payment_status_against_not_allowed_statuses(
     intent_status: &storage_enums::IntentStatus,
     not_allowed_statuses: &[storage_enums::IntentStatus],
@@ -1212,6 +1621,297 @@ pub(crate) fn validate_payment_status_against_not_allowed_statuses(
     })
 }
 
+/// Validates the delay after authorization at which a manually captured payment is captured
+/// automatically, which cannot exceed the time for which connectors hold authorizations.
+pub(crate) fn validate_auto_capture_delay(auto_capture_delay: u32) -> RouterResult<i32> {
+    i32::try_from(auto_capture_delay)
+        .ok()
+        .filter(|delay| (1..=consts::MAX_AUTO_CAPTURE_DELAY_IN_SECONDS).contains(delay))
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "auto_capture_delay must be between 1 and {} seconds",
+                    consts::MAX_AUTO_CAPTURE_DELAY_IN_SECONDS
+                ),
+            })
+        })
+}
+
+/// Manually captured payments can be captured automatically after authorization, with the delay
+/// given in the request or else the default delay of the business profile of the payment.
+pub(crate) fn get_requested_auto_capture_delay(
+    request: &api::PaymentsRequest,
+    business_profile: Option<&storage::BusinessProfile>,
+) -> RouterResult<Option<i32>> {
+    let is_manual_capture = request.capture_method == Some(api_enums::CaptureMethod::Manual);
+    utils::when(
+        !is_manual_capture && request.auto_capture_delay.is_some(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message:
+                    "auto_capture_delay can only be set for payments with capture_method manual"
+                        .to_string(),
+            }))
+        },
+    )?;
+    if !is_manual_capture {
+        return Ok(None);
+    }
+
+    match request.auto_capture_delay {
+        Some(auto_capture_delay) => validate_auto_capture_delay(auto_capture_delay).map(Some),
+        None => Ok(business_profile.and_then(|profile| profile.auto_capture_delay)),
+    }
+}
+
+pub(crate) fn get_auto_capture_delay(payment_intent: &storage::PaymentIntent) -> Option<u32> {
+    payment_intent
+        .auto_capture_delay
+        .and_then(|auto_capture_delay| u32::try_from(auto_capture_delay).ok())
+}
+
+pub(crate) fn validate_session_expiry(
+    session_expiry: Option<time::PrimitiveDateTime>,
+    action: &'static str,
+) -> Result<(), errors::ApiErrorResponse> {
+    fp_utils::when(
+        session_expiry.map_or(false, |expiry| expiry < common_utils::date_time::now()),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("You cannot {action} this payment because it has expired"),
+            })
+        },
+    )
+}
+
+/// Validates that the line items of the order add up to the amount of the payment, if the prices
+/// of all the line items are provided.
+pub(crate) fn validate_order_details_amount(
+    order_details: Option<&Vec<api_models::payments::OrderDetails>>,
+    amount: i64,
+) -> Result<(), errors::ApiErrorResponse> {
+    let order_amount = order_details.and_then(|order_details| {
+        order_details
+            .iter()
+            .try_fold(0_i64, |order_amount, line_item| {
+                line_item
+                    .get_total_amount()
+                    .and_then(|line_item_amount| order_amount.checked_add(line_item_amount))
+            })
+    });
+
+    fp_utils::when(
+        order_amount.map_or(false, |order_amount| order_amount != amount),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The amounts of the order details add up to {}, which is not equal to the amount {amount}",
+                    order_amount.unwrap_or_default()
+                ),
+            })
+        },
+    )
+}
+
+/// Validates that the amount breakdown adds up to the amount of the payment, and that none of its
+/// amounts are negative.
+pub(crate) fn validate_amount_breakdown(
+    amount_breakdown: Option<&api_models::payments::AmountBreakdown>,
+    amount: i64,
+) -> Result<(), errors::ApiErrorResponse> {
+    let amount_breakdown = match amount_breakdown {
+        Some(amount_breakdown) => amount_breakdown,
+        None => return Ok(()),
+    };
+
+    let is_negative = amount_breakdown.subtotal < 0
+        || [
+            amount_breakdown.tax_amount,
+            amount_breakdown.shipping_amount,
+            amount_breakdown.discount_amount,
+        ]
+        .into_iter()
+        .flatten()
+        .any(|component| component < 0);
+    fp_utils::when(is_negative, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The amounts of the amount breakdown cannot be negative".to_string(),
+        })
+    })?;
+
+    let total_amount = amount_breakdown.get_total_amount();
+    fp_utils::when(total_amount != Some(amount), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The amount breakdown adds up to {}, which is not equal to the amount {amount}",
+                total_amount.unwrap_or_default()
+            ),
+        })
+    })
+}
+
+pub(crate) fn encode_amount_breakdown(
+    amount_breakdown: Option<&api_models::payments::AmountBreakdown>,
+) -> RouterResult<Option<serde_json::Value>> {
+    amount_breakdown
+        .map(utils::Encode::<api_models::payments::AmountBreakdown>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode amount breakdown")
+}
+
+pub(crate) fn get_amount_breakdown(
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<api_models::payments::AmountBreakdown>> {
+    payment_intent
+        .amount_breakdown
+        .clone()
+        .map(|amount_breakdown| amount_breakdown.parse_value("AmountBreakdown"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid amount breakdown in payment intent")
+}
+
+/// Validates that none of the amounts of the level 2 and level 3 data are negative, and that the
+/// reference of the customer fits the limits of the card networks.
+pub(crate) fn validate_l2_l3_data(
+    l2_l3_data: &api_models::payments::L2L3Data,
+) -> Result<(), errors::ApiErrorResponse> {
+    let line_item_amounts = l2_l3_data
+        .line_items
+        .iter()
+        .flatten()
+        .flat_map(|line_item| {
+            [
+                Some(line_item.unit_amount),
+                line_item.tax_amount,
+                line_item.discount_amount,
+            ]
+        });
+    let is_negative = std::iter::once(l2_l3_data.tax_amount)
+        .chain(line_item_amounts)
+        .flatten()
+        .any(|amount| amount < 0);
+    fp_utils::when(is_negative, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The amounts of the level 2 and level 3 data cannot be negative".to_string(),
+        })
+    })?;
+
+    let is_customer_reference_too_long = l2_l3_data
+        .customer_reference
+        .as_ref()
+        .map_or(false, |customer_reference| {
+            customer_reference.len() > consts::MAX_CUSTOMER_REFERENCE_LENGTH
+        });
+    fp_utils::when(is_customer_reference_too_long, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The customer reference cannot be longer than {} characters",
+                consts::MAX_CUSTOMER_REFERENCE_LENGTH
+            ),
+        })
+    })
+}
+
+pub(crate) fn encode_l2_l3_data(
+    l2_l3_data: Option<&api_models::payments::L2L3Data>,
+) -> RouterResult<Option<serde_json::Value>> {
+    l2_l3_data
+        .map(utils::Encode::<api_models::payments::L2L3Data>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode level 2 and level 3 data")
+}
+
+pub(crate) fn get_l2_l3_data(
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<api_models::payments::L2L3Data>> {
+    payment_intent
+        .l2_l3_data
+        .clone()
+        .map(|l2_l3_data| l2_l3_data.parse_value("L2L3Data"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid level 2 and level 3 data in payment intent")
+}
+
+pub async fn get_business_profile(
+    db: &dyn StorageInterface,
+    profile_id: &str,
+    merchant_id: &str,
+) -> RouterResult<storage::BusinessProfile> {
+    db.find_business_profile_by_profile_id_merchant_id(profile_id, merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound)
+        })
+}
+
+pub async fn get_payment_business_profile(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<storage::BusinessProfile>> {
+    payment_intent
+        .profile_id
+        .as_ref()
+        .async_map(|profile_id| get_business_profile(db, profile_id, &payment_intent.merchant_id))
+        .await
+        .transpose()
+}
+
+/// The business profile of a payment decides its routing and webhooks, so it is chosen only while
+/// creating the payment.
+pub(crate) fn validate_profile_id_not_updated(req: &api::PaymentsRequest) -> RouterResult<()> {
+    utils::when(req.profile_id.is_some(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "profile_id can only be set while creating the payment".to_string(),
+        }))
+    })
+}
+
+pub(crate) fn encode_order_details(
+    order_details: Option<&Vec<api_models::payments::OrderDetails>>,
+) -> RouterResult<Option<Vec<serde_json::Value>>> {
+    order_details
+        .map(|order_details| {
+            order_details
+                .iter()
+                .map(utils::Encode::<api_models::payments::OrderDetails>::encode_to_value)
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode order details")
+}
+
+/// Returns the line items of the order of the payment. Payments created before line items were
+/// supported carry a single product in their metadata, which is used instead.
+pub(crate) fn get_order_details(
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<Vec<api_models::payments::OrderDetails>>> {
+    match &payment_intent.order_details {
+        Some(order_details) => order_details
+            .iter()
+            .cloned()
+            .map(|line_item| line_item.parse_value("OrderDetails"))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid order details in payment intent"),
+        None => Ok(payment_intent
+            .metadata
+            .clone()
+            .and_then(|metadata| {
+                metadata
+                    .parse_value::<api_models::payments::Metadata>("metadata")
+                    .ok()
+            })
+            .and_then(|metadata| metadata.order_details)
+            .map(|order_details| vec![order_details])),
+    }
+}
+
 pub(crate) fn validate_pm_or_token_given(
     payment_method: &Option<api_enums::PaymentMethod>,
     payment_method_data: &Option<api::PaymentMethodData>,
@@ -1276,6 +1976,132 @@ mod tests {
         let pi_cs = Some("2".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), pi_cs.as_ref()).is_err())
     }
+
+    #[test]
+    fn test_validate_amount_breakdown() {
+        let amount_breakdown = api_models::payments::AmountBreakdown {
+            subtotal: 6000,
+            tax_amount: Some(480),
+            shipping_amount: Some(500),
+            discount_amount: Some(440),
+        };
+        assert!(validate_amount_breakdown(Some(&amount_breakdown), 6540).is_ok());
+        assert!(validate_amount_breakdown(Some(&amount_breakdown), 6000).is_err());
+
+        let negative_breakdown = api_models::payments::AmountBreakdown {
+            subtotal: 7000,
+            discount_amount: Some(-460),
+            ..amount_breakdown
+        };
+        assert!(validate_amount_breakdown(Some(&negative_breakdown), 6540).is_err());
+    }
+
+    #[test]
+    fn test_validate_l2_l3_data() {
+        let l2_l3_data = api_models::payments::L2L3Data {
+            tax_amount: Some(480),
+            customer_reference: Some("PO-12345".to_string()),
+            line_items: Some(vec![api_models::payments::L3LineItem {
+                description: "Printer paper".to_string(),
+                quantity: 4,
+                unit_amount: 1500,
+                ..Default::default()
+            }]),
+        };
+        assert!(validate_l2_l3_data(&l2_l3_data).is_ok());
+
+        let negative_l2_l3_data = api_models::payments::L2L3Data {
+            line_items: Some(vec![api_models::payments::L3LineItem {
+                discount_amount: Some(-100),
+                ..Default::default()
+            }]),
+            ..l2_l3_data.clone()
+        };
+        assert!(validate_l2_l3_data(&negative_l2_l3_data).is_err());
+
+        let long_reference_l2_l3_data = api_models::payments::L2L3Data {
+            customer_reference: Some("PO-".repeat(10)),
+            ..l2_l3_data
+        };
+        assert!(validate_l2_l3_data(&long_reference_l2_l3_data).is_err());
+    }
+
+    #[test]
+    fn test_validate_client_request_fields() {
+        let client_request = api::PaymentsRequest {
+            client_secret: Some("pay_123_secret_456".to_string()),
+            payment_method: Some(api_enums::PaymentMethod::Card),
+            ..Default::default()
+        };
+        assert!(validate_client_request_fields(&client_request).is_ok());
+
+        let amount_change_request = api::PaymentsRequest {
+            amount: Some(api::Amount::from(100)),
+            ..client_request.clone()
+        };
+        assert!(validate_client_request_fields(&amount_change_request).is_err());
+
+        let merchant_request = api::PaymentsRequest {
+            client_secret: None,
+            ..amount_change_request
+        };
+        assert!(validate_client_request_fields(&merchant_request).is_ok());
+    }
+
+    #[test]
+    fn test_get_requested_auto_capture_delay() {
+        let manual_capture_request = api::PaymentsRequest {
+            capture_method: Some(api_enums::CaptureMethod::Manual),
+            auto_capture_delay: Some(7200),
+            ..Default::default()
+        };
+        assert_eq!(
+            get_requested_auto_capture_delay(&manual_capture_request, None).ok(),
+            Some(Some(7200))
+        );
+
+        let automatic_capture_request = api::PaymentsRequest {
+            capture_method: Some(api_enums::CaptureMethod::Automatic),
+            ..manual_capture_request.clone()
+        };
+        assert!(get_requested_auto_capture_delay(&automatic_capture_request, None).is_err());
+
+        let long_delay_request = api::PaymentsRequest {
+            auto_capture_delay: Some(30 * 24 * 60 * 60),
+            ..manual_capture_request
+        };
+        assert!(get_requested_auto_capture_delay(&long_delay_request, None).is_err());
+        assert!(validate_auto_capture_delay(0).is_err());
+    }
+
+    #[test]
+    fn test_validate_confirmed_payment_update() {
+        let address_update_request = api::PaymentsRequest {
+            return_url: url::Url::parse("https://example.com").ok(),
+            ..Default::default()
+        };
+        assert!(validate_confirmed_payment_update(
+            &address_update_request,
+            storage_enums::IntentStatus::RequiresCapture
+        )
+        .is_ok());
+
+        let amount_update_request = api::PaymentsRequest {
+            amount: Some(api::Amount::from(1000)),
+            ..address_update_request
+        };
+        assert!(validate_confirmed_payment_update(
+            &amount_update_request,
+            storage_enums::IntentStatus::RequiresCapture
+        )
+        .is_err());
+        assert!(is_payment_confirmed(
+            storage_enums::IntentStatus::Processing
+        ));
+        assert!(!is_payment_confirmed(
+            storage_enums::IntentStatus::RequiresCustomerAction
+        ));
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -1332,11 +2158,12 @@ impl MerchantConnectorAccountType {
 }
 
 pub async fn get_merchant_connector_account(
-    db: &dyn StorageInterface,
+    state: &AppState,
     merchant_id: &str,
     connector_id: &str,
     creds_identifier: Option<String>,
 ) -> RouterResult<MerchantConnectorAccountType> {
+    let db = &*state.store;
     match creds_identifier {
         Some(creds_identifier) => {
             let mca_config = db
@@ -1363,10 +2190,96 @@ pub async fn get_merchant_connector_account(
 
             Ok(MerchantConnectorAccountType::CacheVal(cached_mca))
         }
-        None => db
-            .find_merchant_connector_account_by_merchant_id_connector(merchant_id, connector_id)
-            .await
-            .map(MerchantConnectorAccountType::DbVal)
-            .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound),
+        None => {
+            let mut merchant_connector_account = db
+                .find_merchant_connector_account_by_merchant_id_connector(merchant_id, connector_id)
+                .await
+                .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?;
+            merchant_connector_account.connector_account_details =
+                secrets::decrypt_connector_account_details(state, &merchant_connector_account)
+                    .await?;
+
+            Ok(MerchantConnectorAccountType::DbVal(
+                merchant_connector_account,
+            ))
+        }
+    }
+}
+
+/// A connector which the merchant has no account for is not considered disabled, such payments
+/// fail later when the credentials of the connector are fetched.
+pub async fn is_merchant_connector_account_disabled(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    connector_name: &str,
+) -> RouterResult<bool> {
+    match db
+        .find_merchant_connector_account_by_merchant_id_connector(merchant_id, connector_name)
+        .await
+    {
+        Ok(mca) => Ok(mca.disabled.unwrap_or(false)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(false),
+        Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to fetch the merchant connector account"),
+    }
+}
+
+/// Voids the authorization of a payment right after it was authorized by the connector, in which
+/// case the payment takes the status of the void. Returns whether the authorization was voided.
+#[instrument(skip_all)]
+pub async fn void_authorization<F: Clone, Req>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+    router_data: &mut types::RouterData<F, Req, types::PaymentsResponseData>,
+    cancellation_reason: &str,
+) -> RouterResult<bool> {
+    let (connector_transaction_id, connector_meta) = match &router_data.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            resource_id: types::ResponseId::ConnectorTransactionId(connector_transaction_id),
+            connector_metadata,
+            ..
+        }) if router_data.status == storage_enums::AttemptStatus::Authorized => {
+            (connector_transaction_id.clone(), connector_metadata.clone())
+        }
+        _ => return Ok(false),
+    };
+
+    let connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &router_data.connector,
+        api::GetToken::Connector,
+    )?;
+    let void_router_data = types::PaymentsCancelRouterData::from((
+        &router_data,
+        types::PaymentsCancelData {
+            amount: Some(super::surcharge::get_connector_amount(payment_data).get_amount_as_i64()),
+            currency: Some(payment_data.currency.get_currency()),
+            connector_transaction_id,
+            cancellation_reason: Some(cancellation_reason.to_string()),
+            cancellation_metadata: None,
+            connector_meta,
+        },
+    ));
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Void,
+        types::PaymentsCancelData,
+        types::PaymentsResponseData,
+    > = connector.connector.get_connector_integration();
+    let void_router_data = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &void_router_data,
+        super::CallConnectorAction::Trigger,
+    )
+    .await;
+
+    match void_router_data {
+        Ok(void_router_data) if void_router_data.response.is_ok() => {
+            router_data.status = void_router_data.status;
+            Ok(true)
+        }
+        Ok(_) | Err(_) => Ok(false),
     }
 }
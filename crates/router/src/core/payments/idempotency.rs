@@ -0,0 +1,177 @@
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    date_time,
+};
+use error_stack::{report, IntoReport, ResultExt};
+use futures::Future;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    headers, logger,
+    routes::AppState,
+    services,
+    types::storage::idempotency::{IdempotencyEntry, IdempotencyStatus},
+    utils::Encode,
+};
+
+/// Extracts the value of the `Idempotency-Key` header, if one was sent with the request.
+pub fn get_idempotency_key(
+    request_headers: &actix_web::http::header::HeaderMap,
+) -> RouterResult<Option<String>> {
+    request_headers
+        .get(headers::IDEMPOTENCY_KEY)
+        .map(|value| {
+            value
+                .to_str()
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: headers::IDEMPOTENCY_KEY,
+                })
+                .and_then(|key| {
+                    if key.is_empty() || key.len() > consts::IDEMPOTENCY_KEY_MAX_LENGTH {
+                        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                            message: format!(
+                                "{} must be between 1 and {} characters long",
+                                headers::IDEMPOTENCY_KEY,
+                                consts::IDEMPOTENCY_KEY_MAX_LENGTH
+                            )
+                        }))
+                    } else {
+                        Ok(key.to_owned())
+                    }
+                })
+        })
+        .transpose()
+}
+
+fn generate_request_hash<Req: serde::Serialize + std::fmt::Debug>(
+    request: &Req,
+) -> RouterResult<String> {
+    let encoded_request = Encode::<Req>::encode_to_vec(request)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize request for idempotency check")?;
+
+    crypto::Sha256
+        .generate_digest(&encoded_request)
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate hash of the request for idempotency check")
+}
+
+/// Runs `operation` at most once for a given merchant and idempotency key.
+///
+/// The first request carrying a key reserves it and stores the response once the operation
+/// completes. Subsequent requests with the same key and payload get the stored response back,
+/// while a request reusing the key with a different payload is rejected. Failed operations release
+/// the key, so that the request can be retried with the same key.
+#[instrument(skip_all)]
+pub async fn call_with_idempotency<Req, Res, Fut>(
+    state: &AppState,
+    merchant_id: &str,
+    idempotency_key: Option<String>,
+    request: &Req,
+    operation: Fut,
+) -> RouterResponse<serde_json::Value>
+where
+    Req: serde::Serialize + std::fmt::Debug,
+    Res: serde::Serialize + std::fmt::Debug,
+    Fut: Future<Output = RouterResponse<Res>>,
+{
+    let idempotency_key = match idempotency_key {
+        Some(key) => key,
+        None => return encode_response(operation.await?),
+    };
+
+    let db = &*state.store;
+    let ttl = state.conf.idempotency.ttl;
+    let mut entry = IdempotencyEntry {
+        merchant_id: merchant_id.to_owned(),
+        idempotency_key: idempotency_key.clone(),
+        request_hash: generate_request_hash(request)?,
+        status: IdempotencyStatus::InProgress,
+        response: None,
+        created_at: date_time::now().assume_utc().unix_timestamp(),
+    };
+
+    let is_reserved = db
+        .insert_idempotency_entry_if_not_exists(&entry, ttl)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while reserving idempotency key")?;
+
+    if !is_reserved {
+        let existing_entry = db
+            .find_idempotency_entry(merchant_id, &idempotency_key)
+            .await
+            .change_context(errors::ApiErrorResponse::IdempotentRequestInProgress)
+            .attach_printable("Idempotency entry expired or was released while being read")?;
+
+        if existing_entry.request_hash != entry.request_hash {
+            return Err(report!(errors::ApiErrorResponse::IdempotencyKeyReused));
+        }
+
+        return match existing_entry.status {
+            IdempotencyStatus::InProgress => Err(report!(
+                errors::ApiErrorResponse::IdempotentRequestInProgress
+            )),
+            IdempotencyStatus::Completed => {
+                logger::info!("Replaying stored response for idempotency key {idempotency_key}");
+                existing_entry
+                    .response
+                    .map(services::ApplicationResponse::Json)
+                    .ok_or_else(|| report!(errors::ApiErrorResponse::InternalServerError))
+                    .attach_printable("Stored idempotent response is missing")
+            }
+        };
+    }
+
+    let response = match operation.await.and_then(encode_response) {
+        Ok(response) => response,
+        Err(error) => {
+            db.delete_idempotency_entry(merchant_id, &idempotency_key)
+                .await
+                .map_err(|err| logger::error!(release_idempotency_key_error=?err))
+                .ok();
+            return Err(error);
+        }
+    };
+
+    if let services::ApplicationResponse::Json(ref response_body) = response {
+        entry.status = IdempotencyStatus::Completed;
+        entry.response = Some(response_body.clone());
+        db.update_idempotency_entry(&entry, ttl)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while storing idempotent response")?;
+    } else {
+        db.delete_idempotency_entry(merchant_id, &idempotency_key)
+            .await
+            .map_err(|err| logger::error!(release_idempotency_key_error=?err))
+            .ok();
+    }
+
+    Ok(response)
+}
+
+fn encode_response<Res: serde::Serialize + std::fmt::Debug>(
+    response: services::ApplicationResponse<Res>,
+) -> RouterResponse<serde_json::Value> {
+    Ok(match response {
+        services::ApplicationResponse::Json(response) => services::ApplicationResponse::Json(
+            Encode::<Res>::encode_to_value(&response)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize response")?,
+        ),
+        services::ApplicationResponse::StatusOk => services::ApplicationResponse::StatusOk,
+        services::ApplicationResponse::TextPlain(text) => {
+            services::ApplicationResponse::TextPlain(text)
+        }
+        services::ApplicationResponse::JsonForRedirection(redirection) => {
+            services::ApplicationResponse::JsonForRedirection(redirection)
+        }
+        services::ApplicationResponse::Form(form) => services::ApplicationResponse::Form(form),
+        services::ApplicationResponse::Html(html) => services::ApplicationResponse::Html(html),
+    })
+}
@@ -1,9 +1,11 @@
+pub mod payment_approve;
 pub mod payment_cancel;
 pub mod payment_capture;
 pub mod payment_complete_authorize;
 pub mod payment_confirm;
 pub mod payment_create;
 pub mod payment_method_validate;
+pub mod payment_reject;
 pub mod payment_response;
 pub mod payment_session;
 pub mod payment_start;
@@ -15,9 +17,10 @@ use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 
 pub use self::{
-    payment_cancel::PaymentCancel, payment_capture::PaymentCapture,
-    payment_confirm::PaymentConfirm, payment_create::PaymentCreate,
-    payment_method_validate::PaymentMethodValidate, payment_response::PaymentResponse,
+    payment_approve::PaymentApprove, payment_cancel::PaymentCancel,
+    payment_capture::PaymentCapture, payment_confirm::PaymentConfirm,
+    payment_create::PaymentCreate, payment_method_validate::PaymentMethodValidate,
+    payment_reject::PaymentReject, payment_response::PaymentResponse,
     payment_session::PaymentSession, payment_start::PaymentStart, payment_status::PaymentStatus,
     payment_update::PaymentUpdate,
 };
@@ -63,6 +66,55 @@ pub trait Operation<F: Clone, T>: Send + std::fmt::Debug {
             format!("post connector update tracker not found for {self:?}")
         })
     }
+    /// Whether the operation is performed while holding the lock on the payment
+    fn requires_lock(&self) -> bool {
+        false
+    }
+    /// Whether the operation is retried with alternate connectors when the connector declines it.
+    /// The other operations must be performed with the connector that authorized the payment.
+    fn is_retryable(&self) -> bool {
+        false
+    }
+    /// Whether the outcome of the operation counts towards the success rates of connectors, as
+    /// the operations which authorize a payment do
+    fn is_success_rate_tracked(&self) -> bool {
+        false
+    }
+    /// Whether the fraud rules and the fraud check provider are consulted for the payment, before
+    /// it is sent to the connector
+    fn is_fraud_checked(&self) -> bool {
+        false
+    }
+    /// Whether the surcharge rules of the merchant are applied to the payment, as they are once its
+    /// payment method is known
+    fn is_surcharged(&self) -> bool {
+        false
+    }
+    /// Whether the amount of the payment is converted to the currency of the connector, as it is
+    /// once its surcharge is known
+    fn is_currency_converted(&self) -> bool {
+        false
+    }
+    /// Whether the payment can be retrieved with `force_sync`, in which case the status reported
+    /// by the connector is authoritative over the status stored in the router
+    fn is_force_syncable(&self) -> bool {
+        false
+    }
+    /// Whether the gift card of a split tender payment is redeemed, as it is when the payment is
+    /// being authorized
+    fn is_split_tenderable(&self) -> bool {
+        false
+    }
+    /// Whether the verification rules of the merchant are applied to the response of the connector,
+    /// as they are when the issuer verifies the address and the card security code
+    fn is_verifiable(&self) -> bool {
+        false
+    }
+    /// Whether the cardholder is authenticated with the external 3DS provider of the merchant, as
+    /// the cardholder is before the payment is authorized
+    fn is_authenticatable(&self) -> bool {
+        false
+    }
 }
 
 pub struct ValidateResult<'a> {
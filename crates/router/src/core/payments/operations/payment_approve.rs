@@ -0,0 +1,249 @@
+use std::marker::PhantomData;
+
+use async_trait::async_trait;
+use common_utils::ext_traits::AsyncExt;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::{self, helpers, operations, state_machine},
+    },
+    db::StorageInterface,
+    routes::AppState,
+    types::{
+        self,
+        api::{self, PaymentIdTypeExt},
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils::OptionExt,
+};
+
+/// Releases a payment held for review by capturing its authorization.
+#[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
+#[operation(ops = "all", flow = "capture")]
+pub struct PaymentApprove;
+
+#[async_trait]
+impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptureRequest>
+    for PaymentApprove
+{
+    #[instrument(skip_all)]
+    async fn get_trackers<'a>(
+        &'a self,
+        state: &'a AppState,
+        payment_id: &api::PaymentIdType,
+        request: &api::PaymentsCaptureRequest,
+        _mandate_type: Option<api::MandateTxnType>,
+        merchant_account: &storage::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsCaptureRequest>,
+        payments::PaymentData<F>,
+        Option<payments::CustomerDetails>,
+    )> {
+        let db = &*state.store;
+        let merchant_id = &merchant_account.merchant_id;
+        let storage_scheme = merchant_account.storage_scheme;
+
+        let payment_id = payment_id
+            .get_payment_intent_id()
+            .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(&payment_id, merchant_id, storage_scheme)
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        helpers::validate_status_for_review(payment_intent.status, "approved")?;
+
+        let mut payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                payment_intent.payment_id.as_str(),
+                merchant_id,
+                payment_intent.active_attempt_id.as_str(),
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        helpers::validate_amount_to_capture(
+            payment_attempt.amount.get_amount_as_i64(),
+            request.amount_to_capture,
+        )?;
+
+        payment_attempt
+            .amount_to_capture
+            .update_value(request.amount_to_capture);
+
+        let currency = types::Currency::try_from(payment_attempt.currency)?;
+        let amount = payment_attempt.amount;
+
+        let connector_response = db
+            .find_connector_response_by_payment_id_merchant_id_attempt_id(
+                &payment_attempt.payment_id,
+                &payment_attempt.merchant_id,
+                &payment_attempt.attempt_id,
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        let shipping_address = helpers::get_address_for_payment_request(
+            db,
+            None,
+            payment_intent.shipping_address_id.as_deref(),
+            merchant_id,
+            &payment_intent.customer_id,
+        )
+        .await?;
+
+        let billing_address = helpers::get_address_for_payment_request(
+            db,
+            None,
+            payment_intent.billing_address_id.as_deref(),
+            merchant_id,
+            &payment_intent.customer_id,
+        )
+        .await?;
+
+        let creds_identifier = request
+            .merchant_connector_details
+            .as_ref()
+            .map(|mcd| mcd.creds_identifier.to_owned());
+        request
+            .merchant_connector_details
+            .to_owned()
+            .async_map(|mcd| async {
+                helpers::insert_merchant_connector_creds_to_config(
+                    db,
+                    merchant_account.merchant_id.as_str(),
+                    mcd,
+                )
+                .await
+            })
+            .await
+            .transpose()?;
+
+        Ok((
+            Box::new(self),
+            payments::PaymentData {
+                flow: PhantomData,
+                payment_intent,
+                payment_attempt,
+                currency,
+                force_sync: None,
+                amount,
+                email: None,
+                mandate_id: None,
+                setup_mandate: None,
+                token: None,
+                address: payments::PaymentAddress {
+                    shipping: shipping_address.as_ref().map(|a| a.foreign_into()),
+                    billing: billing_address.as_ref().map(|a| a.foreign_into()),
+                },
+                confirm: None,
+                payment_method_data: None,
+                refunds: vec![],
+                captures: vec![],
+                funding_sources: vec![],
+                payment_splits: vec![],
+                gift_card_data: None,
+                connector_response,
+                sessions_token: vec![],
+                card_cvc: None,
+                creds_identifier,
+                network_token: None,
+            },
+            None,
+        ))
+    }
+}
+
+#[async_trait]
+impl<F: Clone> UpdateTracker<F, payments::PaymentData<F>, api::PaymentsCaptureRequest>
+    for PaymentApprove
+{
+    #[instrument(skip_all)]
+    async fn update_trackers<'b>(
+        &'b self,
+        db: &dyn StorageInterface,
+        _payment_id: &api::PaymentIdType,
+        mut payment_data: payments::PaymentData<F>,
+        _customer: Option<storage::Customer>,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsCaptureRequest>,
+        payments::PaymentData<F>,
+    )>
+    where
+        F: 'b + Send,
+    {
+        state_machine::validate_intent_status_transition(
+            payment_data.payment_intent.status,
+            enums::IntentStatus::Processing,
+        )?;
+        state_machine::validate_attempt_status_transition(
+            payment_data.payment_attempt.status,
+            enums::AttemptStatus::CaptureInitiated,
+        )?;
+
+        let attempt_amount = payment_data.payment_attempt.amount.get_amount_as_i64();
+        let partial_capture_amount = payment_data
+            .payment_attempt
+            .amount_to_capture
+            .filter(|amount_to_capture| *amount_to_capture < attempt_amount);
+
+        if let Some(amount_to_capture) = partial_capture_amount {
+            payment_data.payment_attempt = db
+                .update_payment_attempt_with_attempt_id(
+                    payment_data.payment_attempt,
+                    storage::PaymentAttemptUpdate::PartialCaptureUpdate { amount_to_capture },
+                    storage_scheme,
+                )
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+                })?;
+        }
+
+        Ok((Box::new(self), payment_data))
+    }
+}
+
+impl<F: Send + Clone> ValidateRequest<F, api::PaymentsCaptureRequest> for PaymentApprove {
+    #[instrument(skip_all)]
+    fn validate_request<'a, 'b>(
+        &'b self,
+        request: &api::PaymentsCaptureRequest,
+        merchant_account: &'a storage::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsCaptureRequest>,
+        operations::ValidateResult<'a>,
+    )> {
+        let payment_id = request
+            .payment_id
+            .as_ref()
+            .get_required_value("payment_id")?;
+
+        helpers::validate_amount_to_capture_is_positive(request.amount_to_capture)?;
+
+        Ok((
+            Box::new(self),
+            operations::ValidateResult {
+                merchant_id: &merchant_account.merchant_id,
+                payment_id: api::PaymentIdType::PaymentIntentId(payment_id.to_owned()),
+                mandate_type: None,
+                storage_scheme: merchant_account.storage_scheme,
+            },
+        ))
+    }
+}
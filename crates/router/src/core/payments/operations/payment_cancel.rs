@@ -10,16 +10,18 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::{helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            helpers, operations, state_machine, CustomerDetails, PaymentAddress, PaymentData,
+        },
     },
     db::StorageInterface,
     routes::AppState,
     types::{
+        self,
         api::{self, PaymentIdTypeExt},
         storage::{self, enums, Customer},
         transformers::ForeignInto,
     },
-    utils::OptionExt,
 };
 
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
@@ -95,10 +97,11 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             .map_err(|error| {
                 error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
             })?;
-        let currency = payment_attempt.currency.get_required_value("currency")?;
-        let amount = payment_attempt.amount.into();
+        let currency = types::Currency::try_from(payment_attempt.currency)?;
+        let amount = payment_attempt.amount;
 
         payment_attempt.cancellation_reason = request.cancellation_reason.clone();
+        payment_attempt.cancellation_metadata = request.metadata.clone();
 
         let creds_identifier = request
             .merchant_connector_details
@@ -146,10 +149,15 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
                     payment_method_data: None,
                     force_sync: None,
                     refunds: vec![],
+                    captures: vec![],
+                    funding_sources: vec![],
+                    payment_splits: vec![],
+                    gift_card_data: None,
                     connector_response,
                     sessions_token: vec![],
                     card_cvc: None,
                     creds_identifier,
+                    network_token: None,
                 },
                 None,
             )),
@@ -175,12 +183,18 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsCancelRequest> for
         F: 'b + Send,
     {
         let cancellation_reason = payment_data.payment_attempt.cancellation_reason.clone();
+        let cancellation_metadata = payment_data.payment_attempt.cancellation_metadata.clone();
+        state_machine::validate_attempt_status_transition(
+            payment_data.payment_attempt.status,
+            enums::AttemptStatus::VoidInitiated,
+        )?;
         payment_data.payment_attempt = db
             .update_payment_attempt_with_attempt_id(
                 payment_data.payment_attempt,
                 storage::PaymentAttemptUpdate::VoidUpdate {
                     status: enums::AttemptStatus::VoidInitiated,
                     cancellation_reason,
+                    cancellation_metadata,
                 },
                 storage_scheme,
             )
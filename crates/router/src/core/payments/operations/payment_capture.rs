@@ -2,27 +2,29 @@ use std::marker::PhantomData;
 
 use async_trait::async_trait;
 use common_utils::ext_traits::AsyncExt;
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::{self, helpers, operations},
+        payments::{self, helpers, operations, state_machine},
     },
     db::StorageInterface,
     routes::AppState,
     types::{
+        self,
         api::{self, PaymentIdTypeExt},
-        storage::{self, enums},
+        storage::{self, enums, PaymentAttemptExt},
         transformers::ForeignInto,
     },
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
-#[operation(ops = "all", flow = "capture")]
+#[operation(ops = "all", flow = "capture", flags = "requires_lock")]
 pub struct PaymentCapture;
 
 #[async_trait]
@@ -58,10 +60,6 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
             })?;
 
-        helpers::validate_status(payment_intent.status)?;
-
-        helpers::validate_amount_to_capture(payment_intent.amount, request.amount_to_capture)?;
-
         payment_attempt = db
             .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
                 payment_intent.payment_id.as_str(),
@@ -74,19 +72,63 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
             })?;
 
-        payment_attempt
-            .amount_to_capture
-            .update_value(request.amount_to_capture);
-
         let capture_method = payment_attempt
             .capture_method
             .get_required_value("capture_method")?;
 
         helpers::validate_capture_method(capture_method)?;
 
-        currency = payment_attempt.currency.get_required_value("currency")?;
+        helpers::validate_status_for_capture(
+            payment_intent.status,
+            payment_attempt.status,
+            capture_method,
+        )?;
+
+        let captures = if capture_method == enums::CaptureMethod::ManualMultiple {
+            let connector = payment_attempt
+                .get_routed_through_connector()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?
+                .get_required_value("connector")?;
+
+            helpers::validate_multiple_capture_support(
+                &state.conf.connectors.supported,
+                &connector,
+            )?;
+
+            db.find_all_captures_by_merchant_id_payment_id_attempt_id(
+                merchant_id,
+                &payment_attempt.payment_id,
+                &payment_attempt.attempt_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch captures of the payment attempt")?
+        } else {
+            vec![]
+        };
+
+        // Captures which have not failed are considered when computing the amount left to capture
+        let amount_capturable = payment_attempt.amount.get_amount_as_i64()
+            - captures
+                .iter()
+                .filter(|capture| capture.status != enums::CaptureStatus::Failed)
+                .map(|capture| capture.amount)
+                .sum::<i64>();
+
+        helpers::validate_amount_to_capture(amount_capturable, request.amount_to_capture)?;
+
+        if captures.is_empty() {
+            payment_attempt
+                .amount_to_capture
+                .update_value(request.amount_to_capture);
+        } else {
+            payment_attempt.amount_to_capture =
+                Some(request.amount_to_capture.unwrap_or(amount_capturable));
+        }
 
-        amount = payment_attempt.amount.into();
+        currency = types::Currency::try_from(payment_attempt.currency)?;
+
+        amount = payment_attempt.amount;
 
         let connector_response = db
             .find_connector_response_by_payment_id_merchant_id_attempt_id(
@@ -156,10 +198,15 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 confirm: None,
                 payment_method_data: None,
                 refunds: vec![],
+                captures,
+                funding_sources: vec![],
+                payment_splits: vec![],
+                gift_card_data: None,
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: None,
                 creds_identifier,
+                network_token: None,
             },
             None,
         ))
@@ -173,11 +220,11 @@ impl<F: Clone> UpdateTracker<F, payments::PaymentData<F>, api::PaymentsCaptureRe
     #[instrument(skip_all)]
     async fn update_trackers<'b>(
         &'b self,
-        _db: &dyn StorageInterface,
+        db: &dyn StorageInterface,
         _payment_id: &api::PaymentIdType,
-        payment_data: payments::PaymentData<F>,
+        mut payment_data: payments::PaymentData<F>,
         _customer: Option<storage::Customer>,
-        _storage_scheme: enums::MerchantStorageScheme,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> RouterResult<(
         BoxedOperation<'b, F, api::PaymentsCaptureRequest>,
         payments::PaymentData<F>,
@@ -185,6 +232,42 @@ impl<F: Clone> UpdateTracker<F, payments::PaymentData<F>, api::PaymentsCaptureRe
     where
         F: 'b + Send,
     {
+        state_machine::validate_intent_status_transition(
+            payment_data.payment_intent.status,
+            enums::IntentStatus::Processing,
+        )?;
+        state_machine::validate_attempt_status_transition(
+            payment_data.payment_attempt.status,
+            enums::AttemptStatus::CaptureInitiated,
+        )?;
+
+        let is_multiple_capture = payment_data.payment_attempt.capture_method
+            == Some(enums::CaptureMethod::ManualMultiple);
+
+        let attempt_amount = payment_data.payment_attempt.amount.get_amount_as_i64();
+        let partial_capture_amount = payment_data
+            .payment_attempt
+            .amount_to_capture
+            .filter(|amount_to_capture| *amount_to_capture < attempt_amount);
+
+        if let Some(amount_to_capture) = partial_capture_amount {
+            payment_data.payment_attempt = db
+                .update_payment_attempt_with_attempt_id(
+                    payment_data.payment_attempt,
+                    storage::PaymentAttemptUpdate::PartialCaptureUpdate { amount_to_capture },
+                    storage_scheme,
+                )
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+                })?;
+        }
+
+        if is_multiple_capture {
+            let capture = create_capture(db, &payment_data).await?;
+            payment_data.captures.push(capture);
+        }
+
         Ok((Box::new(self), payment_data))
     }
 }
@@ -204,6 +287,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsCaptureRequest> for Paymen
             .as_ref()
             .get_required_value("payment_id")?;
 
+        helpers::validate_amount_to_capture_is_positive(request.amount_to_capture)?;
+
         Ok((
             Box::new(self),
             operations::ValidateResult {
@@ -215,3 +300,41 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsCaptureRequest> for Paymen
         ))
     }
 }
+
+async fn create_capture<F: Clone>(
+    db: &dyn StorageInterface,
+    payment_data: &payments::PaymentData<F>,
+) -> RouterResult<storage::Capture> {
+    let payment_attempt = &payment_data.payment_attempt;
+    let connector = payment_attempt
+        .get_routed_through_connector()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .get_required_value("connector")?;
+    let capture_sequence = i16::try_from(payment_data.captures.len() + 1)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Number of captures exceeds the maximum supported")?;
+    let now = common_utils::date_time::now();
+
+    db.insert_capture(storage::CaptureNew {
+        capture_id: utils::generate_id(consts::ID_LENGTH, "cap"),
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        status: enums::CaptureStatus::Started,
+        amount: payment_attempt
+            .amount_to_capture
+            .unwrap_or(payment_attempt.amount.get_amount_as_i64()),
+        currency: payment_attempt.currency,
+        connector,
+        capture_sequence,
+        connector_transaction_id: None,
+        error_message: None,
+        error_code: None,
+        created_at: Some(now),
+        modified_at: Some(now),
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert capture")
+}
@@ -9,7 +9,9 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        payments::{helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            helpers, operations, state_machine, CustomerDetails, PaymentAddress, PaymentData,
+        },
         utils as core_utils,
     },
     db::StorageInterface,
@@ -20,11 +22,11 @@ use crate::{
         storage::{self, enums as storage_enums},
         transformers::ForeignInto,
     },
-    utils::{self, OptionExt},
+    utils,
 };
 
 #[derive(Debug, Clone, Copy, PaymentOperation)]
-#[operation(ops = "all", flow = "authorize")]
+#[operation(ops = "all", flow = "authorize", flags = "is_success_rate_tracked")]
 pub struct CompleteAuthorize;
 
 #[async_trait]
@@ -67,6 +69,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             &[
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::Expired,
             ],
             "confirm",
         )?;
@@ -107,25 +110,30 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
 
         let token = token.or_else(|| payment_attempt.payment_token.clone());
 
+        // The payment method type of the attempt is considered as well, as redirect based wallets
+        // are completed without the payment method being passed again
+        payment_attempt.payment_method_type = request
+            .payment_method_type
+            .map(|pmt| pmt.foreign_into())
+            .or(payment_attempt.payment_method_type);
+
         helpers::validate_pm_or_token_given(
             &request.payment_method,
             &request.payment_method_data,
-            &request.payment_method_type,
+            &payment_attempt
+                .payment_method_type
+                .map(ForeignInto::foreign_into),
             &mandate_type,
             &token,
         )?;
 
         payment_attempt.payment_method = payment_method.or(payment_attempt.payment_method);
         payment_attempt.browser_info = browser_info;
-        payment_attempt.payment_method_type = request
-            .payment_method_type
-            .map(|pmt| pmt.foreign_into())
-            .or(payment_attempt.payment_method_type);
         payment_attempt.payment_experience = request
             .payment_experience
             .map(|experience| experience.foreign_into());
-        currency = payment_attempt.currency.get_required_value("currency")?;
-        amount = payment_attempt.amount.into();
+        currency = types::Currency::try_from(payment_attempt.currency)?;
+        amount = payment_attempt.amount;
 
         helpers::validate_customer_id_mandatory_cases(
             request.shipping.is_some(),
@@ -191,9 +199,14 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
                 payment_method_data: request.payment_method_data.clone(),
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                funding_sources: vec![],
+                payment_splits: vec![],
+                gift_card_data: None,
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
                 creds_identifier: None,
+                network_token: None,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -287,6 +300,15 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Comple
     where
         F: 'b + Send,
     {
+        state_machine::validate_intent_status_transition(
+            payment_data.payment_intent.status,
+            storage_enums::IntentStatus::Processing,
+        )?;
+        state_machine::validate_attempt_status_transition(
+            payment_data.payment_attempt.status,
+            storage_enums::AttemptStatus::Authorizing,
+        )?;
+
         Ok((Box::new(self), payment_data))
     }
 }
@@ -317,6 +339,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for CompleteAutho
                 expected_format: "merchant_id from merchant account".to_string(),
             })?;
 
+        helpers::validate_client_request_fields(request)?;
+
         helpers::validate_payment_method_fields_present(request)?;
 
         let mandate_type = helpers::validate_mandate(request)?;
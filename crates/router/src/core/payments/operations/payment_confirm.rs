@@ -10,22 +10,31 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        payments::{helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            helpers, operations, split_payments, state_machine, CustomerDetails, PaymentAddress,
+            PaymentData,
+        },
         utils as core_utils,
     },
     db::StorageInterface,
-    routes::AppState,
+    routes::{metrics, AppState},
     types::{
         self,
         api::{self, PaymentIdTypeExt},
         storage::{self, enums as storage_enums},
         transformers::ForeignInto,
     },
-    utils::{self, OptionExt},
+    utils::{self, validation},
 };
 
 #[derive(Debug, Clone, Copy, PaymentOperation)]
-#[operation(ops = "all", flow = "authorize")]
+#[operation(
+    ops = "all",
+    flow = "authorize",
+    flags = "requires_lock, is_retryable, is_success_rate_tracked, is_fraud_checked, \
+             is_surcharged, is_currency_converted, is_split_tenderable, is_verifiable, \
+             is_authenticatable"
+)]
 pub struct PaymentConfirm;
 
 #[async_trait]
@@ -46,7 +55,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         let db = &*state.store;
         let merchant_id = &merchant_account.merchant_id;
         let storage_scheme = merchant_account.storage_scheme;
-        let (mut payment_intent, mut payment_attempt, currency, amount, connector_response);
+        let (mut payment_intent, currency, amount);
 
         let payment_id = payment_id
             .get_payment_intent_id()
@@ -69,23 +78,29 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             &[
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::Expired,
+                storage_enums::IntentStatus::RequiresMerchantAction,
             ],
             "confirm",
         )?;
 
-        let (token, payment_method, setup_mandate) = helpers::get_token_pm_type_mandate_details(
-            state,
-            request,
-            mandate_type.clone(),
-            merchant_account,
-        )
-        .await?;
+        helpers::validate_session_expiry(payment_intent.session_expiry, "confirm")?;
 
         helpers::authenticate_client_secret(
             request.client_secret.as_ref(),
             payment_intent.client_secret.as_ref(),
         )?;
 
+        helpers::validate_customer_id_mandatory_cases(
+            request.shipping.is_some(),
+            request.billing.is_some(),
+            request.setup_future_usage.is_some(),
+            &payment_intent
+                .customer_id
+                .clone()
+                .or_else(|| request.customer_id.clone()),
+        )?;
+
         let browser_info = request
             .browser_info
             .clone()
@@ -95,20 +110,76 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 field_name: "browser_info",
             })?;
 
-        payment_attempt = db
-            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+        // The lookups below depend only on the payment intent, and are made concurrently
+        let payment_attempt_fut = async {
+            db.find_payment_attempt_by_payment_id_merchant_id_attempt_id(
                 payment_intent.payment_id.as_str(),
                 merchant_id,
                 payment_intent.active_attempt_id.as_str(),
                 storage_scheme,
             )
             .await
-            .map_err(|error| {
-                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
-            })?;
+            .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))
+        };
+        let connector_response_fut = async {
+            db.find_connector_response_by_payment_id_merchant_id_attempt_id(
+                payment_intent.payment_id.as_str(),
+                merchant_id,
+                payment_intent.active_attempt_id.as_str(),
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))
+        };
+        let payment_splits_fut = async {
+            db.find_all_payment_splits_by_merchant_id_payment_id(
+                merchant_id,
+                payment_intent.payment_id.as_str(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch splits of the payment")
+        };
+
+        let (
+            (token, payment_method, setup_mandate),
+            mut payment_attempt,
+            connector_response,
+            mandate_id,
+            payment_splits,
+        ) = futures::try_join!(
+            helpers::get_token_pm_type_mandate_details(
+                state,
+                request,
+                mandate_type.clone(),
+                merchant_account,
+            ),
+            payment_attempt_fut,
+            connector_response_fut,
+            helpers::get_mandate_ids(db, merchant_id, request.mandate_id.as_ref()),
+            payment_splits_fut,
+        )?;
 
         let token = token.or_else(|| payment_attempt.payment_token.clone());
 
+        // The payment method saved for the customer takes the place of the token
+        let (token, payment_method) = match request.payment_method_id.as_deref() {
+            Some(payment_method_id) => {
+                let (token, payment_method) = helpers::get_token_for_saved_payment_method(
+                    state,
+                    payment_method_id,
+                    payment_intent
+                        .customer_id
+                        .as_ref()
+                        .or(request.customer_id.as_ref()),
+                    merchant_account,
+                )
+                .await?;
+                (Some(token), Some(payment_method))
+            }
+            None => (token, payment_method),
+        };
+
         helpers::validate_pm_or_token_given(
             &request.payment_method,
             &request.payment_method_data,
@@ -118,6 +189,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         )?;
 
         payment_attempt.payment_method = payment_method.or(payment_attempt.payment_method);
+        payment_attempt.authentication_type = helpers::get_authentication_type_for_mandate(
+            &mandate_type,
+            payment_attempt.authentication_type,
+        );
         payment_attempt.browser_info = browser_info;
         payment_attempt.payment_method_type = request
             .payment_method_type
@@ -128,47 +203,56 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .payment_experience
             .map(|experience| experience.foreign_into());
 
-        currency = payment_attempt.currency.get_required_value("currency")?;
-        amount = payment_attempt.amount.into();
-
-        helpers::validate_customer_id_mandatory_cases(
-            request.shipping.is_some(),
-            request.billing.is_some(),
-            request.setup_future_usage.is_some(),
-            &payment_intent
-                .customer_id
-                .clone()
-                .or_else(|| request.customer_id.clone()),
+        currency = types::Currency::try_from(payment_attempt.currency)?;
+        amount = payment_attempt.amount;
+
+        if let Some(amount_breakdown) = &request.amount_breakdown {
+            payment_intent.amount_breakdown =
+                helpers::encode_amount_breakdown(Some(amount_breakdown))?;
+        }
+        let amount_breakdown = helpers::get_amount_breakdown(&payment_intent)?;
+        helpers::validate_amount_breakdown(
+            amount_breakdown.as_ref(),
+            payment_attempt.amount.get_amount_as_i64(),
         )?;
 
-        let shipping_address = helpers::get_address_for_payment_request(
-            db,
-            request.shipping.as_ref(),
-            payment_intent.shipping_address_id.as_deref(),
-            merchant_id,
-            &payment_intent.customer_id,
-        )
-        .await?;
-        let billing_address = helpers::get_address_for_payment_request(
-            db,
-            request.billing.as_ref(),
-            payment_intent.billing_address_id.as_deref(),
-            merchant_id,
-            &payment_intent.customer_id,
-        )
-        .await?;
+        if let Some(l2_l3_data) = &request.l2_l3_data {
+            helpers::validate_l2_l3_data(l2_l3_data)?;
+            payment_intent.l2_l3_data = helpers::encode_l2_l3_data(Some(l2_l3_data))?;
+        }
+
+        if let Some(order_details) = &request.order_details {
+            helpers::validate_order_details_amount(
+                Some(order_details),
+                amount_breakdown.as_ref().map_or(
+                    payment_attempt.amount.get_amount_as_i64(),
+                    |amount_breakdown| amount_breakdown.subtotal,
+                ),
+            )?;
+            payment_intent.order_details = helpers::encode_order_details(Some(order_details))?;
+        }
+
+        let (shipping_address, billing_address) = futures::try_join!(
+            helpers::get_address_for_payment_request(
+                db,
+                request.shipping.as_ref(),
+                payment_intent.shipping_address_id.as_deref(),
+                merchant_id,
+                &payment_intent.customer_id,
+            ),
+            helpers::get_address_for_payment_request(
+                db,
+                request.billing.as_ref(),
+                payment_intent.billing_address_id.as_deref(),
+                merchant_id,
+                &payment_intent.customer_id,
+            ),
+        )?;
 
-        connector_response = db
-            .find_connector_response_by_payment_id_merchant_id_attempt_id(
-                &payment_attempt.payment_id,
-                &payment_attempt.merchant_id,
-                &payment_attempt.attempt_id,
-                storage_scheme,
-            )
-            .await
-            .map_err(|error| {
-                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
-            })?;
+        split_payments::validate_not_split_tender(
+            &payment_splits,
+            request.gift_card_data.as_ref(),
+        )?;
 
         payment_intent.shipping_address_id = shipping_address.clone().map(|i| i.address_id);
         payment_intent.billing_address_id = billing_address.clone().map(|i| i.address_id);
@@ -202,7 +286,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 connector_response,
                 amount,
                 email: request.email.clone(),
-                mandate_id: None,
+                mandate_id,
                 setup_mandate,
                 token,
                 address: PaymentAddress {
@@ -213,9 +297,14 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 payment_method_data: request.payment_method_data.clone(),
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                funding_sources: vec![],
+                payment_splits,
+                gift_card_data: request.gift_card_data.clone(),
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
                 creds_identifier,
+                network_token: None,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -323,11 +412,20 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                 storage_enums::AttemptStatus::AuthenticationPending,
             ),
         };
+        state_machine::validate_intent_status_transition(
+            payment_data.payment_intent.status,
+            intent_status,
+        )?;
+        state_machine::validate_attempt_status_transition(
+            payment_data.payment_attempt.status,
+            attempt_status,
+        )?;
 
         let connector = payment_data.payment_attempt.connector.clone();
         let payment_token = payment_data.token.clone();
         let payment_method_type = payment_data.payment_attempt.payment_method_type.clone();
         let payment_experience = payment_data.payment_attempt.payment_experience.clone();
+        let authentication_type = payment_data.payment_attempt.authentication_type;
         let additional_pm_data = payment_data
             .payment_method_data
             .as_ref()
@@ -338,29 +436,6 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to encode additional pm data")?;
 
-        payment_data.payment_attempt = db
-            .update_payment_attempt_with_attempt_id(
-                payment_data.payment_attempt,
-                storage::PaymentAttemptUpdate::ConfirmUpdate {
-                    amount: payment_data.amount.into(),
-                    currency: payment_data.currency,
-                    status: attempt_status,
-                    payment_method,
-                    authentication_type: None,
-                    browser_info,
-                    connector,
-                    payment_token,
-                    payment_method_data: additional_pm_data,
-                    payment_method_type,
-                    payment_experience,
-                },
-                storage_scheme,
-            )
-            .await
-            .map_err(|error| {
-                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
-            })?;
-
         let (shipping_address, billing_address) = (
             payment_data.payment_intent.shipping_address_id.clone(),
             payment_data.payment_intent.billing_address_id.clone(),
@@ -369,19 +444,39 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let customer_id = customer.map(|c| c.customer_id);
         let return_url = payment_data.payment_intent.return_url.clone();
         let setup_future_usage = payment_data.payment_intent.setup_future_usage;
+        let order_details = payment_data.payment_intent.order_details.clone();
+        let amount_breakdown = payment_data.payment_intent.amount_breakdown.clone();
+        let l2_l3_data = payment_data.payment_intent.l2_l3_data.clone();
 
-        payment_data.payment_intent = db
-            .update_payment_intent(
+        (payment_data.payment_intent, payment_data.payment_attempt) = db
+            .update_payment_intent_and_attempt(
                 payment_data.payment_intent,
                 storage::PaymentIntentUpdate::Update {
-                    amount: payment_data.amount.into(),
-                    currency: payment_data.currency,
+                    amount: payment_data.amount,
+                    currency: payment_data.currency.get_currency(),
                     setup_future_usage,
                     status: intent_status,
                     customer_id,
                     shipping_address_id: shipping_address,
                     billing_address_id: billing_address,
                     return_url,
+                    order_details,
+                    amount_breakdown,
+                    l2_l3_data,
+                },
+                payment_data.payment_attempt,
+                storage::PaymentAttemptUpdate::ConfirmUpdate {
+                    amount: payment_data.amount,
+                    currency: payment_data.currency.get_currency(),
+                    status: attempt_status,
+                    payment_method,
+                    authentication_type,
+                    browser_info,
+                    connector,
+                    payment_token,
+                    payment_method_data: additional_pm_data,
+                    payment_method_type,
+                    payment_experience,
                 },
                 storage_scheme,
             )
@@ -389,6 +484,18 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             .map_err(|error| {
                 error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
             })?;
+        metrics::PAYMENT_CONFIRMED_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::request::add_attributes(
+                "payment_method",
+                payment_data
+                    .payment_attempt
+                    .payment_method
+                    .map(|payment_method| payment_method.to_string())
+                    .unwrap_or_default(),
+            )],
+        );
 
         Ok((Box::new(self), payment_data))
     }
@@ -420,6 +527,12 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentConfir
                 expected_format: "merchant_id from merchant account".to_string(),
             })?;
 
+        validation::validate_request(request)?;
+
+        helpers::validate_client_request_fields(request)?;
+        helpers::validate_profile_id_not_updated(request)?;
+        split_payments::validate_split_payments_not_updated(request)?;
+
         helpers::validate_payment_method_fields_present(request)?;
 
         let mandate_type = helpers::validate_mandate(request)?;
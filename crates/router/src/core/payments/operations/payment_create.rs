@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use async_trait::async_trait;
 use common_utils::ext_traits::{AsyncExt, Encode};
-use error_stack::{self, ResultExt};
+use error_stack::{self, IntoReport, ResultExt};
 use router_derive::PaymentOperation;
 use router_env::{instrument, tracing};
 use uuid::Uuid;
@@ -12,11 +12,13 @@ use crate::{
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            self, helpers, operations, split_payments, CustomerDetails, PaymentAddress, PaymentData,
+        },
         utils as core_utils,
     },
     db::StorageInterface,
-    routes::AppState,
+    routes::{metrics, AppState},
     types::{
         self,
         api::{self, PaymentIdTypeExt},
@@ -27,10 +29,14 @@ use crate::{
         },
         transformers::ForeignInto,
     },
-    utils::OptionExt,
+    utils::{validation, OptionExt},
 };
 #[derive(Debug, Clone, Copy, PaymentOperation)]
-#[operation(ops = "all", flow = "authorize")]
+#[operation(
+    ops = "all",
+    flow = "authorize",
+    flags = "is_success_rate_tracked,is_split_tenderable,is_authenticatable"
+)]
 pub struct PaymentCreate;
 
 #[async_trait]
@@ -61,11 +67,28 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .get_payment_intent_id()
             .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
 
+        let business_profile = request
+            .profile_id
+            .as_ref()
+            .async_map(|profile_id| helpers::get_business_profile(db, profile_id, merchant_id))
+            .await
+            .transpose()?;
+
+        let payment_splits = split_payments::make_payment_splits(
+            db,
+            merchant_account,
+            &payment_id,
+            amount.into(),
+            currency,
+            request,
+        )
+        .await?;
+
         let (token, payment_method_type, setup_mandate) =
             helpers::get_token_pm_type_mandate_details(
                 state,
                 request,
-                mandate_type,
+                mandate_type.clone(),
                 merchant_account,
             )
             .await?;
@@ -108,6 +131,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     payment_method_type,
                     request,
                     browser_info,
+                    &mandate_type,
                 )?,
                 storage_scheme,
             )
@@ -118,6 +142,12 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 })
             })?;
 
+        let session_expiry_in_seconds = request
+            .session_expiry
+            .unwrap_or(state.conf.payment_expiry.default_session_expiry);
+        let session_expiry = common_utils::date_time::now()
+            .saturating_add(time::Duration::seconds(session_expiry_in_seconds.into()));
+
         payment_intent = db
             .insert_payment_intent(
                 Self::make_payment_intent(
@@ -128,6 +158,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     shipping_address.clone().map(|x| x.address_id),
                     billing_address.clone().map(|x| x.address_id),
                     payment_attempt.attempt_id.to_owned(),
+                    session_expiry,
+                    business_profile.as_ref(),
                 )?,
                 storage_scheme,
             )
@@ -137,6 +169,25 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     payment_id: payment_id.clone(),
                 })
             })?;
+        metrics::PAYMENT_CREATED_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::request::add_attributes(
+                "currency",
+                payment_intent
+                    .currency
+                    .map(|currency| currency.to_string())
+                    .unwrap_or_default(),
+            )],
+        );
+
+        let payment_splits = split_payments::insert_payment_splits(db, payment_splits).await?;
+
+        payments::add_payment_expiry_task(db, &payment_intent, session_expiry)
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while adding payment expiry task to process tracker")?;
         connector_response = db
             .insert_connector_response(
                 Self::make_connector_response(&payment_attempt)
@@ -150,21 +201,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 })
             })?;
 
-        let mandate_id = request
-            .mandate_id
-            .as_ref()
-            .async_and_then(|mandate_id| async {
-                let mandate = db
-                    .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id)
-                    .await
-                    .change_context(errors::ApiErrorResponse::MandateNotFound);
-                Some(mandate.map(|mandate_obj| api_models::payments::MandateIds {
-                    mandate_id: mandate_obj.mandate_id,
-                    connector_mandate_id: mandate_obj.connector_mandate_id,
-                }))
-            })
-            .await
-            .transpose()?;
+        let mandate_id =
+            helpers::get_mandate_ids(db, merchant_id, request.mandate_id.as_ref()).await?;
 
         let operation = payments::if_not_create_change_operation::<_, F>(
             payment_intent.status,
@@ -196,8 +234,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 flow: PhantomData,
                 payment_intent,
                 payment_attempt,
-                currency,
-                amount,
+                currency: currency.into(),
+                amount: amount.into(),
                 email: request.email.clone(),
                 mandate_id,
                 setup_mandate,
@@ -209,11 +247,16 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 confirm: request.confirm,
                 payment_method_data: request.payment_method_data.clone(),
                 refunds: vec![],
+                captures: vec![],
+                funding_sources: vec![],
+                payment_splits,
+                gift_card_data: request.gift_card_data.clone(),
                 force_sync: None,
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
                 creds_identifier,
+                network_token: None,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -380,6 +423,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
         helpers::validate_merchant_id(&merchant_account.merchant_id, request_merchant_id)
             .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
+        validation::validate_request(request)?;
+
         helpers::validate_request_amount_and_amount_to_capture(
             request.amount,
             request.amount_to_capture,
@@ -391,6 +436,21 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
 
         helpers::validate_payment_method_fields_present(request)?;
 
+        if let Some(amount) = request.amount {
+            let amount = amount.into();
+            let amount_breakdown = request.amount_breakdown.as_ref();
+            helpers::validate_amount_breakdown(amount_breakdown, amount)?;
+            // The line items of the order add up to its subtotal when the amount is broken down
+            helpers::validate_order_details_amount(
+                request.order_details.as_ref(),
+                amount_breakdown.map_or(amount, |amount_breakdown| amount_breakdown.subtotal),
+            )?;
+        }
+
+        if let Some(l2_l3_data) = &request.l2_l3_data {
+            helpers::validate_l2_l3_data(l2_l3_data)?;
+        }
+
         let payment_id = core_utils::get_or_generate_id("payment_id", &given_payment_id, "pay")?;
 
         let mandate_type = helpers::validate_mandate(request)?;
@@ -433,6 +493,7 @@ impl PaymentCreate {
         payment_method: Option<enums::PaymentMethod>,
         request: &api::PaymentsRequest,
         browser_info: Option<serde_json::Value>,
+        mandate_type: &Option<api::MandateTxnType>,
     ) -> RouterResult<storage::PaymentAttemptNew> {
         let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
         let status =
@@ -463,7 +524,10 @@ impl PaymentCreate {
             created_at,
             modified_at,
             last_synced,
-            authentication_type: request.authentication_type.map(ForeignInto::foreign_into),
+            authentication_type: helpers::get_authentication_type_for_mandate(
+                mandate_type,
+                request.authentication_type.map(ForeignInto::foreign_into),
+            ),
             browser_info,
             payment_experience: request.payment_experience.map(ForeignInto::foreign_into),
             payment_method_type: request.payment_method_type.map(ForeignInto::foreign_into),
@@ -472,6 +536,7 @@ impl PaymentCreate {
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[instrument(skip_all)]
     fn make_payment_intent(
         payment_id: &str,
@@ -481,6 +546,8 @@ impl PaymentCreate {
         shipping_address_id: Option<String>,
         billing_address_id: Option<String>,
         active_attempt_id: String,
+        session_expiry: time::PrimitiveDateTime,
+        business_profile: Option<&storage::BusinessProfile>,
     ) -> RouterResult<storage::PaymentIntentNew> {
         let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
         let status =
@@ -495,6 +562,24 @@ impl PaymentCreate {
             .transpose()
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Encoding Metadata to value failed")?;
+        let order_details = helpers::encode_order_details(request.order_details.as_ref())?;
+        let amount_breakdown = helpers::encode_amount_breakdown(request.amount_breakdown.as_ref())?;
+        let l2_l3_data = helpers::encode_l2_l3_data(request.l2_l3_data.as_ref())?;
+        let auto_capture_delay =
+            helpers::get_requested_auto_capture_delay(request, business_profile)?;
+        // The business profile of the payment provides the settings not given in the request
+        let return_url = request
+            .return_url
+            .as_ref()
+            .map(|a| a.to_string())
+            .or_else(|| business_profile.and_then(|profile| profile.return_url.clone()));
+        let statement_descriptor_name = request.statement_descriptor_name.clone().or_else(|| {
+            business_profile.and_then(|profile| profile.statement_descriptor_name.clone())
+        });
+        let statement_descriptor_suffix =
+            request.statement_descriptor_suffix.clone().or_else(|| {
+                business_profile.and_then(|profile| profile.statement_descriptor_suffix.clone())
+            });
         Ok(storage::PaymentIntentNew {
             payment_id: payment_id.to_string(),
             merchant_id: merchant_id.to_string(),
@@ -508,13 +593,20 @@ impl PaymentCreate {
             client_secret: Some(client_secret),
             setup_future_usage: request.setup_future_usage.map(ForeignInto::foreign_into),
             off_session: request.off_session,
-            return_url: request.return_url.as_ref().map(|a| a.to_string()),
+            return_url,
             shipping_address_id,
             billing_address_id,
-            statement_descriptor_name: request.statement_descriptor_name.clone(),
-            statement_descriptor_suffix: request.statement_descriptor_suffix.clone(),
+            statement_descriptor_name,
+            statement_descriptor_suffix,
             metadata: metadata.map(masking::Secret::new),
             active_attempt_id,
+            session_expiry: Some(session_expiry),
+            order_details,
+            amount_breakdown,
+            l2_l3_data,
+            profile_id: business_profile.map(|profile| profile.profile_id.clone()),
+            auto_capture_delay,
+            connector_debug_mode: request.connector_debug_mode.unwrap_or(false),
             ..storage::PaymentIntentNew::default()
         })
     }
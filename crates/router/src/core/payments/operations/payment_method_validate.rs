@@ -161,8 +161,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::VerifyRequest> for Paym
                 payment_intent,
                 payment_attempt,
                 /// currency and amount are irrelevant in this scenario
-                currency: storage_enums::Currency::default(),
-                amount: api::Amount::Zero,
+                currency: storage_enums::Currency::default().into(),
+                amount: types::MinorUnit::new(0),
                 email: None,
                 mandate_id: None,
                 setup_mandate: request.mandate_data.clone(),
@@ -173,9 +173,14 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::VerifyRequest> for Paym
                 address: types::PaymentAddress::default(),
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                funding_sources: vec![],
+                payment_splits: vec![],
+                gift_card_data: None,
                 sessions_token: vec![],
                 card_cvc: None,
                 creds_identifier,
+                network_token: None,
             },
             Some(payments::CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -301,7 +306,7 @@ impl PaymentMethodValidate {
             attempt_id: Uuid::new_v4().simple().to_string(),
             status,
             // Amount & Currency will be zero in this case
-            amount: 0,
+            amount: types::MinorUnit::new(0),
             currency: Default::default(),
             connector: None,
             payment_method: payment_method.map(ForeignInto::foreign_into),
@@ -328,7 +333,7 @@ impl PaymentMethodValidate {
             payment_id: payment_id.to_string(),
             merchant_id: merchant_id.to_string(),
             status,
-            amount: 0,
+            amount: types::MinorUnit::new(0),
             currency: Default::default(),
             connector_id: None,
             created_at,
@@ -7,9 +7,11 @@ use super::{Operation, PostUpdateTracker};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::PaymentData,
+        ledger,
+        payments::{self, split_payments, state_machine, PaymentData},
     },
     db::StorageInterface,
+    logger,
     routes::metrics,
     services::RedirectForm,
     types::{
@@ -61,6 +63,17 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthorizeData
             storage_scheme,
         )
         .await?;
+        metrics::PAYMENT_AUTHORIZATION_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[
+                metrics::request::add_attributes("connector", connector.clone()),
+                metrics::request::add_attributes(
+                    "status",
+                    payment_data.payment_attempt.status.to_string(),
+                ),
+            ],
+        );
 
         router_response.map(|_| ()).or_else(|error_response| {
             fp_utils::when(!(200..300).contains(&error_response.status_code), || {
@@ -146,23 +159,82 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCaptureData>
         db: &dyn StorageInterface,
         payment_id: &api::PaymentIdType,
         mut payment_data: PaymentData<F>,
-        router_data: types::RouterData<F, types::PaymentsCaptureData, types::PaymentsResponseData>,
+        mut router_data: types::RouterData<
+            F,
+            types::PaymentsCaptureData,
+            types::PaymentsResponseData,
+        >,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> RouterResult<PaymentData<F>>
     where
         F: 'b + Send,
     {
+        let previously_captured_amount: i64 = payment_data
+            .captures
+            .iter()
+            .filter(|capture| capture.status == enums::CaptureStatus::Charged)
+            .map(|capture| capture.amount)
+            .sum();
+
+        // Connectors report a successful capture as `Charged`, irrespective of the amount captured
+        if router_data.status == enums::AttemptStatus::Charged {
+            let total_captured_amount =
+                previously_captured_amount + router_data.request.amount_to_capture;
+            if total_captured_amount < router_data.request.payment_amount {
+                router_data.status = enums::AttemptStatus::PartiallyCaptured;
+            }
+            router_data.amount_captured = Some(total_captured_amount);
+        }
+
         let router_response = router_data.response.clone();
         let connector = router_data.connector.clone();
 
-        payment_data = payment_response_update_tracker(
-            db,
-            payment_id,
-            payment_data,
-            router_data,
-            storage_scheme,
-        )
-        .await?;
+        let current_capture = payment_data
+            .captures
+            .last()
+            .filter(|capture| capture.status == enums::CaptureStatus::Started)
+            .cloned();
+
+        if let Some(capture) = current_capture {
+            let updated_capture = db
+                .update_capture_with_capture_id(
+                    capture,
+                    get_capture_update(router_data.status, &router_response),
+                )
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+                })?;
+            payment_data.captures.pop();
+            payment_data.captures.push(updated_capture);
+
+            // The payment attempt retains the connector reference of the authorization, which is
+            // required for the subsequent captures, while the capture stores its own reference
+            if let Ok(types::PaymentsResponseData::TransactionResponse {
+                ref mut resource_id,
+                ..
+            }) = router_data.response
+            {
+                *resource_id = payment_data
+                    .payment_attempt
+                    .connector_transaction_id
+                    .clone()
+                    .map(types::ResponseId::ConnectorTransactionId)
+                    .unwrap_or_default();
+            }
+        }
+
+        // A failed capture must not fail the payment when an amount has already been captured
+        if router_response.is_ok() || previously_captured_amount == 0 {
+            payment_data = payment_response_update_tracker(
+                db,
+                payment_id,
+                payment_data,
+                router_data,
+                storage_scheme,
+            )
+            .await?;
+        }
 
         router_response.map_err(|error_response| {
             errors::ApiErrorResponse::ExternalConnectorError {
@@ -178,6 +250,41 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCaptureData>
     }
 }
 
+fn get_capture_update(
+    attempt_status: enums::AttemptStatus,
+    router_response: &Result<types::PaymentsResponseData, types::ErrorResponse>,
+) -> storage::CaptureUpdate {
+    match router_response {
+        Err(error_response) => storage::CaptureUpdate::ErrorUpdate {
+            status: enums::CaptureStatus::Failed,
+            error_code: Some(error_response.code.clone()),
+            error_message: Some(error_response.message.clone()),
+        },
+        Ok(payments_response) => {
+            let connector_transaction_id = match payments_response {
+                types::PaymentsResponseData::TransactionResponse {
+                    resource_id: types::ResponseId::ConnectorTransactionId(id),
+                    ..
+                } => Some(id.clone()),
+                _ => None,
+            };
+            let status = match attempt_status {
+                enums::AttemptStatus::Charged | enums::AttemptStatus::PartiallyCaptured => {
+                    enums::CaptureStatus::Charged
+                }
+                enums::AttemptStatus::CaptureFailed | enums::AttemptStatus::Failure => {
+                    enums::CaptureStatus::Failed
+                }
+                _ => enums::CaptureStatus::Pending,
+            };
+            storage::CaptureUpdate::ResponseUpdate {
+                status,
+                connector_transaction_id,
+            }
+        }
+    }
+}
+
 #[async_trait]
 impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCancelData> for PaymentResponse {
     async fn update_tracker<'b>(
@@ -291,22 +398,31 @@ async fn payment_response_update_tracker<F: Clone, T>(
     storage_scheme: enums::MerchantStorageScheme,
 ) -> RouterResult<PaymentData<F>> {
     let (payment_attempt_update, connector_response_update) = match router_data.response.clone() {
-        Err(err) => (
-            Some(storage::PaymentAttemptUpdate::ErrorUpdate {
-                connector: None,
-                status: storage::enums::AttemptStatus::Failure,
-                error_message: Some(err.message),
-                error_code: Some(err.code),
-            }),
-            Some(storage::ConnectorResponseUpdate::ErrorUpdate {
-                connector_name: Some(router_data.connector.clone()),
-            }),
-        ),
+        Err(err) => {
+            let unified_code =
+                api::ConnectorData::get_unified_code(&router_data.connector, &err.code);
+            (
+                Some(storage::PaymentAttemptUpdate::ErrorUpdate {
+                    connector: None,
+                    status: storage::enums::AttemptStatus::Failure,
+                    error_message: Some(err.message),
+                    error_code: Some(err.code),
+                    unified_code: unified_code.map(|code| code.to_string()),
+                    unified_message: unified_code
+                        .map(|code| code.get_unified_message().to_string()),
+                    connector_raw_response: router_data.connector_raw_response.clone(),
+                }),
+                Some(storage::ConnectorResponseUpdate::ErrorUpdate {
+                    connector_name: Some(router_data.connector.clone()),
+                }),
+            )
+        }
         Ok(payments_response) => match payments_response {
             types::PaymentsResponseData::TransactionResponse {
                 resource_id,
                 redirection_data,
                 connector_metadata,
+                verification_result,
                 ..
             } => {
                 let connector_transaction_id = match resource_id {
@@ -339,6 +455,9 @@ async fn payment_response_update_tracker<F: Clone, T>(
                         .clone()
                         .map(|mandate| mandate.mandate_id),
                     connector_metadata,
+                    connector_raw_response: router_data.connector_raw_response.clone(),
+                    avs_result: verification_result.and_then(|result| result.avs_result),
+                    cvc_result: verification_result.and_then(|result| result.cvc_result),
                 };
 
                 let connector_response_update = storage::ConnectorResponseUpdate::ResponseUpdate {
@@ -359,6 +478,21 @@ async fn payment_response_update_tracker<F: Clone, T>(
         },
     };
 
+    let (attempt_status, intent_status) = match router_data.response {
+        Err(_) => (enums::AttemptStatus::Failure, enums::IntentStatus::Failed),
+        Ok(_) => (router_data.status, router_data.status.foreign_into()),
+    };
+    if payment_attempt_update.is_some() {
+        state_machine::validate_attempt_status_transition(
+            payment_data.payment_attempt.status,
+            attempt_status,
+        )?;
+    }
+    state_machine::validate_intent_status_transition(
+        payment_data.payment_intent.status,
+        intent_status,
+    )?;
+
     payment_data.payment_attempt = match payment_attempt_update {
         Some(payment_attempt_update) => db
             .update_payment_attempt_with_attempt_id(
@@ -389,17 +523,20 @@ async fn payment_response_update_tracker<F: Clone, T>(
 
     let amount_captured = router_data.amount_captured.or_else(|| {
         if router_data.status == enums::AttemptStatus::Charged {
-            Some(payment_data.payment_intent.amount)
+            Some(
+                payment_data.payment_intent.amount.get_amount_as_i64()
+                    + payment_data.payment_attempt.surcharge_amount.unwrap_or(0),
+            )
         } else {
             None
         }
     });
     let payment_intent_update = match router_data.response {
         Err(_) => storage::PaymentIntentUpdate::PGStatusUpdate {
-            status: enums::IntentStatus::Failed,
+            status: intent_status,
         },
         Ok(_) => storage::PaymentIntentUpdate::ResponseUpdate {
-            status: router_data.status.foreign_into(),
+            status: intent_status,
             return_url: router_data.return_url.clone(),
             amount_captured,
         },
@@ -414,5 +551,36 @@ async fn payment_response_update_tracker<F: Clone, T>(
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
 
+    split_payments::update_payment_splits(db, &mut payment_data).await?;
+    ledger::record_payment(
+        db,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+    )
+    .await;
+    schedule_auto_capture(db, &payment_data.payment_intent).await;
+
     Ok(payment_data)
 }
+
+/// Schedules the capture of an authorized payment which is to be captured automatically. The
+/// payment is already authorized, so failing to schedule its capture does not fail the payment.
+async fn schedule_auto_capture(db: &dyn StorageInterface, payment_intent: &storage::PaymentIntent) {
+    let auto_capture_delay = match payment_intent.auto_capture_delay {
+        Some(auto_capture_delay)
+            if payment_intent.status == enums::IntentStatus::RequiresCapture =>
+        {
+            auto_capture_delay
+        }
+        _ => return,
+    };
+    let schedule_time = common_utils::date_time::now()
+        .saturating_add(time::Duration::seconds(auto_capture_delay.into()));
+
+    if let Err(error) = payments::add_auto_capture_task(db, payment_intent, schedule_time).await {
+        logger::error!(
+            ?error,
+            "Failed to schedule the automatic capture of the payment"
+        );
+    }
+}
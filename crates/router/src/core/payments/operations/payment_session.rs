@@ -9,20 +9,22 @@ use router_env::{instrument, tracing};
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
+    configs::settings,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         payments::{self, helpers, operations, PaymentData},
+        routing,
     },
     db::StorageInterface,
     logger, pii,
     pii::Secret,
     routes::AppState,
     types::{
+        self,
         api::{self, enums as api_enums, PaymentIdTypeExt},
         storage::{self, enums as storage_enums},
         transformers::ForeignInto,
     },
-    utils::OptionExt,
 };
 
 #[derive(Debug, Clone, Copy, PaymentOperation)]
@@ -66,6 +68,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
             &[
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::Expired,
             ],
             "create a session token for",
         )?;
@@ -82,11 +85,11 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
                 error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
             })?;
 
-        let currency = payment_intent.currency.get_required_value("currency")?;
+        let currency = types::Currency::try_from(payment_intent.currency)?;
 
         payment_attempt.payment_method = Some(storage_enums::PaymentMethod::Wallet);
 
-        let amount = payment_intent.amount.into();
+        let amount = payment_intent.amount;
 
         helpers::authenticate_client_secret(
             Some(&request.client_secret),
@@ -174,10 +177,15 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
                 payment_method_data: None,
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                funding_sources: vec![],
+                payment_splits: vec![],
+                gift_card_data: None,
                 sessions_token: vec![],
                 connector_response,
                 card_cvc: None,
                 creds_identifier,
+                network_token: None,
             },
             Some(customer_details),
         ))
@@ -343,6 +351,8 @@ where
                     })
             })
             .map(|filtered_connector| filtered_connector.connector_name.clone())
+            // Only connectors which can create a session token for a wallet are called
+            .filter(|connector_name| supported_connectors.contains(connector_name))
             .collect();
 
         // Parse the payment methods enabled to check if the merchant has enabled googlepay ( wallet ) using that connector.
@@ -381,59 +391,125 @@ where
                     })
             })
             .map(|filtered_connector| filtered_connector.connector_name.clone())
-            .collect::<HashSet<String>>();
-
-        let given_wallets = request.wallets.clone();
-
-        let connectors_data = if !given_wallets.is_empty() {
-            // Create connectors for provided wallets
-            let mut connectors_data = Vec::with_capacity(supported_connectors.len());
-            for wallet in given_wallets {
-                let (connector_name, connector_type) = match wallet {
-                    api_enums::SupportedWallets::Gpay => ("adyen", api::GetToken::Metadata),
-                    api_enums::SupportedWallets::ApplePay => ("applepay", api::GetToken::Connector),
-                    api_enums::SupportedWallets::Paypal => ("braintree", api::GetToken::Connector),
-                    api_enums::SupportedWallets::Klarna => ("klarna", api::GetToken::Connector),
-                };
-
-                // Check if merchant has enabled the required merchant connector account
-                if session_token_from_metadata_connectors.contains(connector_name)
-                    || normal_connector_names.contains(connector_name)
-                {
-                    connectors_data.push(api::ConnectorData::get_connector_by_name(
-                        connectors,
-                        connector_name,
-                        connector_type,
-                    )?);
+            .fold(Vec::new(), |mut connector_names, connector_name| {
+                if !connector_names.contains(&connector_name) {
+                    connector_names.push(connector_name);
                 }
-            }
-            connectors_data
-        } else {
-            // Create connectors for all enabled wallets
-            let mut connectors_data = Vec::with_capacity(
-                normal_connector_names.len() + session_token_from_metadata_connectors.len(),
-            );
-
-            for connector_name in normal_connector_names {
-                let connector_data = api::ConnectorData::get_connector_by_name(
-                    connectors,
-                    &connector_name,
-                    api::GetToken::Connector,
-                )?;
-                connectors_data.push(connector_data);
-            }
-
-            for connector_name in session_token_from_metadata_connectors {
-                let connector_data = api::ConnectorData::get_connector_by_name(
-                    connectors,
-                    &connector_name,
-                    api::GetToken::Metadata,
-                )?;
-                connectors_data.push(connector_data);
-            }
-            connectors_data
-        };
+                connector_names
+            });
+
+        let google_pay_connector =
+            get_google_pay_connector(merchant_account, &session_token_from_metadata_connectors)?;
+        let connectors_data = get_session_connectors(
+            connectors,
+            &request.wallets,
+            &normal_connector_names,
+            google_pay_connector,
+        )?;
 
         Ok(api::ConnectorChoice::SessionMultiple(connectors_data))
     }
 }
+
+/// The connectors which create the session tokens of the given wallets, or of all the wallets the
+/// merchant has enabled when none are given. Wallets the merchant has not enabled are skipped.
+fn get_session_connectors(
+    connectors: &settings::Connectors,
+    given_wallets: &[api_enums::SupportedWallets],
+    normal_connector_names: &HashSet<String>,
+    google_pay_connector: Option<&String>,
+) -> RouterResult<Vec<api::ConnectorData>> {
+    let session_connectors: Vec<(&str, api::GetToken)> = if !given_wallets.is_empty() {
+        given_wallets
+            .iter()
+            .filter_map(|wallet| match wallet {
+                api_enums::SupportedWallets::Gpay => google_pay_connector
+                    .map(|connector_name| (connector_name.as_str(), api::GetToken::Metadata)),
+                api_enums::SupportedWallets::ApplePay => Some("applepay")
+                    .filter(|connector_name| normal_connector_names.contains(*connector_name))
+                    .map(|connector_name| (connector_name, api::GetToken::Connector)),
+                api_enums::SupportedWallets::Paypal => Some("braintree")
+                    .filter(|connector_name| normal_connector_names.contains(*connector_name))
+                    .map(|connector_name| (connector_name, api::GetToken::Connector)),
+                api_enums::SupportedWallets::Klarna => Some("klarna")
+                    .filter(|connector_name| normal_connector_names.contains(*connector_name))
+                    .map(|connector_name| (connector_name, api::GetToken::Connector)),
+            })
+            .collect()
+    } else {
+        normal_connector_names
+            .iter()
+            .map(|connector_name| (connector_name.as_str(), api::GetToken::Connector))
+            .chain(
+                google_pay_connector
+                    .map(|connector_name| (connector_name.as_str(), api::GetToken::Metadata)),
+            )
+            .collect()
+    };
+
+    session_connectors
+        .into_iter()
+        .map(|(connector_name, connector_type)| {
+            api::ConnectorData::get_connector_by_name(connectors, connector_name, connector_type)
+        })
+        .collect()
+}
+
+/// Google Pay session tokens are created from the metadata of a single connector the merchant has
+/// enabled Google Pay on. The connector preferred by the routing algorithm of the merchant is
+/// chosen, or else the connector on which Google Pay was enabled first.
+fn get_google_pay_connector<'a>(
+    merchant_account: &storage::MerchantAccount,
+    google_pay_connectors: &'a [String],
+) -> RouterResult<Option<&'a String>> {
+    let connector_preference_order = merchant_account
+        .routing_algorithm
+        .clone()
+        .map(|routing_algorithm| {
+            routing_algorithm
+                .parse_value::<api::RoutingAlgorithm>("RoutingAlgorithm")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to deserialize merchant routing algorithm")
+        })
+        .transpose()?
+        .map(|routing_algorithm| routing::get_connector_preference_order(&routing_algorithm))
+        .unwrap_or_default();
+
+    Ok(connector_preference_order
+        .iter()
+        .find_map(|preferred_connector| {
+            google_pay_connectors
+                .iter()
+                .find(|connector_name| *connector_name == preferred_connector)
+        })
+        .or_else(|| google_pay_connectors.first()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_single_google_pay_session_for_all_wallets() {
+        let conf = settings::Settings::new().expect("invalid settings");
+        let normal_connector_names = HashSet::from(["klarna".to_string()]);
+        let google_pay_connector = "adyen".to_string();
+
+        let connectors_data = get_session_connectors(
+            &conf.connectors,
+            &[],
+            &normal_connector_names,
+            Some(&google_pay_connector),
+        )
+        .expect("failed to get session connectors");
+
+        let google_pay_connectors = connectors_data
+            .iter()
+            .filter(|connector_data| matches!(connector_data.get_token, api::GetToken::Metadata))
+            .map(|connector_data| connector_data.connector_name)
+            .collect::<Vec<_>>();
+        assert_eq!(google_pay_connectors, vec![types::Connector::Adyen]);
+        assert_eq!(connectors_data.len(), 2);
+    }
+}
@@ -9,18 +9,20 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        payments::{helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            helpers, operations, state_machine, CustomerDetails, PaymentAddress, PaymentData,
+        },
     },
     db::StorageInterface,
     pii,
     pii::Secret,
     routes::AppState,
     types::{
+        self,
         api::{self, PaymentIdTypeExt},
         storage::{self, enums as storage_enums},
         transformers::ForeignInto,
     },
-    utils::OptionExt,
 };
 
 #[derive(Debug, Clone, Copy, PaymentOperation)]
@@ -63,6 +65,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
             &[
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::Expired,
             ],
             "update",
         )?;
@@ -79,8 +82,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
                 error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
             })?;
 
-        currency = payment_attempt.currency.get_required_value("currency")?;
-        amount = payment_attempt.amount.into();
+        currency = types::Currency::try_from(payment_attempt.currency)?;
+        amount = payment_attempt.amount;
 
         let shipping_address = helpers::get_address_for_payment_request(
             db,
@@ -142,9 +145,14 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
                 payment_method_data: None,
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                funding_sources: vec![],
+                payment_splits: vec![],
+                gift_card_data: None,
                 sessions_token: vec![],
                 card_cvc: None,
                 creds_identifier: None,
+                network_token: None,
             },
             Some(customer_details),
         ))
@@ -168,6 +176,15 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsStartRequest> for P
     where
         F: 'b + Send,
     {
+        state_machine::validate_intent_status_transition(
+            payment_data.payment_intent.status,
+            storage_enums::IntentStatus::RequiresCustomerAction,
+        )?;
+        state_machine::validate_attempt_status_transition(
+            payment_data.payment_attempt.status,
+            storage_enums::AttemptStatus::AuthenticationPending,
+        )?;
+
         Ok((Box::new(self), payment_data))
     }
 }
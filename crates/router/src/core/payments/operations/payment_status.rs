@@ -15,15 +15,14 @@ use crate::{
     db::StorageInterface,
     routes::AppState,
     types::{
-        api,
+        self, api,
         storage::{self, enums},
         transformers::ForeignInto,
     },
-    utils::OptionExt,
 };
 
 #[derive(Debug, Clone, Copy, PaymentOperation)]
-#[operation(ops = "all", flow = "sync")]
+#[operation(ops = "all", flow = "sync", flags = "is_force_syncable")]
 pub struct PaymentStatus;
 
 impl<F: Send + Clone> Operation<F, api::PaymentsRequest> for PaymentStatus {
@@ -208,8 +207,8 @@ async fn get_tracker_for_sync<
         .attach_printable("Database error when finding connector response")?;
 
     connector_response.encoded_data = request.param.clone();
-    currency = payment_attempt.currency.get_required_value("currency")?;
-    amount = payment_attempt.amount.into();
+    currency = types::Currency::try_from(payment_attempt.currency)?;
+    amount = payment_attempt.amount;
 
     let shipping_address =
         helpers::get_address_by_id(db, payment_intent.shipping_address_id.clone()).await?;
@@ -227,6 +226,22 @@ async fn get_tracker_for_sync<
             )
         })?;
 
+    let funding_sources = db
+        .find_all_funding_sources_by_merchant_id_payment_id_attempt_id(
+            merchant_id,
+            &payment_id_str,
+            &payment_attempt.attempt_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch funding sources of the payment attempt")?;
+
+    let payment_splits = db
+        .find_all_payment_splits_by_merchant_id_payment_id(merchant_id, &payment_id_str)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch splits of the payment")?;
+
     let contains_encoded_data = connector_response.encoded_data.is_some();
 
     let creds_identifier = request
@@ -268,9 +283,14 @@ async fn get_tracker_for_sync<
             ),
             payment_attempt,
             refunds,
+            captures: vec![],
+            funding_sources,
+            payment_splits,
+            gift_card_data: None,
             sessions_token: vec![],
             card_cvc: None,
             creds_identifier,
+            network_token: None,
         },
         None,
     ))
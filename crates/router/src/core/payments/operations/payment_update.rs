@@ -10,17 +10,21 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            self, helpers, operations, split_payments, state_machine, CustomerDetails,
+            PaymentAddress, PaymentData,
+        },
         utils as core_utils,
     },
     db::StorageInterface,
     routes::AppState,
     types::{
+        self,
         api::{self, PaymentIdTypeExt},
         storage::{self, enums as storage_enums},
         transformers::ForeignInto,
     },
-    utils::OptionExt,
+    utils::validation,
 };
 #[derive(Debug, Clone, Copy, PaymentOperation)]
 #[operation(ops = "all", flow = "authorize")]
@@ -41,7 +45,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         PaymentData<F>,
         Option<CustomerDetails>,
     )> {
-        let (mut payment_intent, mut payment_attempt, currency): (_, _, storage_enums::Currency);
+        let (mut payment_intent, mut payment_attempt, currency): (_, _, types::Currency);
 
         let payment_id = payment_id
             .get_payment_intent_id()
@@ -68,11 +72,17 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             &[
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
-                storage_enums::IntentStatus::RequiresCapture,
+                storage_enums::IntentStatus::Expired,
+                storage_enums::IntentStatus::RequiresMerchantAction,
             ],
             "update",
         )?;
 
+        let is_payment_confirmed = helpers::is_payment_confirmed(payment_intent.status);
+        if is_payment_confirmed {
+            helpers::validate_confirmed_payment_update(request, payment_intent.status)?;
+        }
+
         helpers::authenticate_client_secret(
             request.client_secret.as_ref(),
             payment_intent.client_secret.as_ref(),
@@ -106,10 +116,22 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
             })?;
 
-        currency = match request.currency {
-            Some(cur) => cur.foreign_into(),
-            None => payment_attempt.currency.get_required_value("currency")?,
-        };
+        let payment_splits = db
+            .find_all_payment_splits_by_merchant_id_payment_id(
+                &payment_intent.merchant_id,
+                &payment_intent.payment_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch splits of the payment")?;
+        split_payments::validate_split_payment_update(&payment_splits, request, &payment_attempt)?;
+
+        currency = types::Currency::try_from(
+            request
+                .currency
+                .map(ForeignInto::foreign_into)
+                .or(payment_attempt.currency),
+        )?;
 
         payment_attempt.payment_method = payment_method_type.or(payment_attempt.payment_method);
 
@@ -117,6 +139,28 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .amount
             .unwrap_or_else(|| payment_attempt.amount.into());
 
+        if let Some(amount_breakdown) = &request.amount_breakdown {
+            payment_intent.amount_breakdown =
+                helpers::encode_amount_breakdown(Some(amount_breakdown))?;
+        }
+        let amount_breakdown = helpers::get_amount_breakdown(&payment_intent)?;
+        helpers::validate_amount_breakdown(amount_breakdown.as_ref(), amount.into())?;
+
+        if let Some(l2_l3_data) = &request.l2_l3_data {
+            helpers::validate_l2_l3_data(l2_l3_data)?;
+            payment_intent.l2_l3_data = helpers::encode_l2_l3_data(Some(l2_l3_data))?;
+        }
+
+        if let Some(order_details) = &request.order_details {
+            helpers::validate_order_details_amount(
+                Some(order_details),
+                amount_breakdown
+                    .as_ref()
+                    .map_or(amount.into(), |amount_breakdown| amount_breakdown.subtotal),
+            )?;
+            payment_intent.order_details = helpers::encode_order_details(Some(order_details))?;
+        }
+
         if request.confirm.unwrap_or(false) {
             helpers::validate_customer_id_mandatory_cases(
                 request.shipping.is_some(),
@@ -176,21 +220,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     .attach_printable("Database error when finding connector response")
             })?;
 
-        let mandate_id = request
-            .mandate_id
-            .as_ref()
-            .async_and_then(|mandate_id| async {
-                let mandate = db
-                    .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id)
-                    .await
-                    .change_context(errors::ApiErrorResponse::MandateNotFound);
-                Some(mandate.map(|mandate_obj| api_models::payments::MandateIds {
-                    mandate_id: mandate_obj.mandate_id,
-                    connector_mandate_id: mandate_obj.connector_mandate_id,
-                }))
-            })
-            .await
-            .transpose()?;
+        let mandate_id =
+            helpers::get_mandate_ids(db, merchant_id, request.mandate_id.as_ref()).await?;
         let next_operation: BoxedOperation<'a, F, api::PaymentsRequest> =
             if request.confirm.unwrap_or(false) {
                 Box::new(operations::PaymentConfirm)
@@ -198,7 +229,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 Box::new(self)
             };
 
-        payment_intent.status = match request.payment_method_data.as_ref() {
+        let intent_status = match request.payment_method_data.as_ref() {
+            // The status of a confirmed payment is left to the connector to update
+            _ if is_payment_confirmed => payment_intent.status,
             Some(_) => {
                 if request.confirm.unwrap_or(false) {
                     payment_intent.status
@@ -208,6 +241,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             }
             None => storage_enums::IntentStatus::RequiresPaymentMethod,
         };
+        state_machine::validate_intent_status_transition(payment_intent.status, intent_status)?;
+        payment_intent.status = intent_status;
 
         payment_attempt.payment_method_type = request
             .payment_method_type
@@ -243,7 +278,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 payment_intent,
                 payment_attempt,
                 currency,
-                amount,
+                amount: amount.into(),
                 email: request.email.clone(),
                 mandate_id,
                 token,
@@ -256,10 +291,15 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 payment_method_data: request.payment_method_data.clone(),
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                funding_sources: vec![],
+                payment_splits,
+                gift_card_data: None,
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
                 creds_identifier,
+                network_token: None,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -369,34 +409,13 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to encode additional pm data")?;
 
-        let payment_method_type = payment_data.payment_attempt.payment_method_type.clone();
-        let payment_experience = payment_data.payment_attempt.payment_experience.clone();
-        payment_data.payment_attempt = db
-            .update_payment_attempt_with_attempt_id(
-                payment_data.payment_attempt,
-                storage::PaymentAttemptUpdate::Update {
-                    amount: payment_data.amount.into(),
-                    currency: payment_data.currency,
-                    status: get_attempt_status(),
-                    authentication_type: None,
-                    payment_method,
-                    payment_token: payment_data.token.clone(),
-                    payment_method_data: additional_pm_data,
-                    payment_experience,
-                    payment_method_type,
-                },
-                storage_scheme,
-            )
-            .await
-            .map_err(|error| {
-                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
-            })?;
-
-        let customer_id = customer.map(|c| c.customer_id);
-
+        let is_payment_confirmed =
+            helpers::is_payment_confirmed(payment_data.payment_intent.status);
         let intent_status = {
             let current_intent_status = payment_data.payment_intent.status;
-            if is_payment_method_unavailable {
+            if is_payment_confirmed {
+                current_intent_status
+            } else if is_payment_method_unavailable {
                 storage_enums::IntentStatus::RequiresPaymentMethod
             } else if !payment_data.confirm.unwrap_or(true)
                 || current_intent_status == storage_enums::IntentStatus::RequiresCustomerAction
@@ -406,6 +425,44 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                 payment_data.payment_intent.status
             }
         };
+        state_machine::validate_intent_status_transition(
+            payment_data.payment_intent.status,
+            intent_status,
+        )?;
+
+        // The attempt of a confirmed payment is being processed by the connector, and none of the
+        // details it holds can have been updated
+        if !is_payment_confirmed {
+            state_machine::validate_attempt_status_transition(
+                payment_data.payment_attempt.status,
+                get_attempt_status(),
+            )?;
+
+            let payment_method_type = payment_data.payment_attempt.payment_method_type.clone();
+            let payment_experience = payment_data.payment_attempt.payment_experience.clone();
+            payment_data.payment_attempt = db
+                .update_payment_attempt_with_attempt_id(
+                    payment_data.payment_attempt,
+                    storage::PaymentAttemptUpdate::Update {
+                        amount: payment_data.amount,
+                        currency: payment_data.currency.get_currency(),
+                        status: get_attempt_status(),
+                        authentication_type: None,
+                        payment_method,
+                        payment_token: payment_data.token.clone(),
+                        payment_method_data: additional_pm_data,
+                        payment_experience,
+                        payment_method_type,
+                    },
+                    storage_scheme,
+                )
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+                })?;
+        }
+
+        let customer_id = customer.map(|c| c.customer_id);
 
         let (shipping_address, billing_address) = (
             payment_data.payment_intent.shipping_address_id.clone(),
@@ -414,19 +471,25 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
 
         let return_url = payment_data.payment_intent.return_url.clone();
         let setup_future_usage = payment_data.payment_intent.setup_future_usage;
+        let order_details = payment_data.payment_intent.order_details.clone();
+        let amount_breakdown = payment_data.payment_intent.amount_breakdown.clone();
+        let l2_l3_data = payment_data.payment_intent.l2_l3_data.clone();
 
         payment_data.payment_intent = db
             .update_payment_intent(
                 payment_data.payment_intent,
                 storage::PaymentIntentUpdate::Update {
-                    amount: payment_data.amount.into(),
-                    currency: payment_data.currency,
+                    amount: payment_data.amount,
+                    currency: payment_data.currency.get_currency(),
                     setup_future_usage,
                     status: intent_status,
                     customer_id,
                     shipping_address_id: shipping_address,
                     billing_address_id: billing_address,
                     return_url,
+                    order_details,
+                    amount_breakdown,
+                    l2_l3_data,
                 },
                 storage_scheme,
             )
@@ -470,6 +533,12 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentUpdate
                 expected_format: "merchant_id from merchant account".to_string(),
             })?;
 
+        validation::validate_request(request)?;
+
+        helpers::validate_client_request_fields(request)?;
+        helpers::validate_profile_id_not_updated(request)?;
+        split_payments::validate_split_payments_not_updated(request)?;
+
         helpers::validate_request_amount_and_amount_to_capture(
             request.amount,
             request.amount_to_capture,
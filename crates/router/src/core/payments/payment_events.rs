@@ -0,0 +1,97 @@
+//! Every change in the status of a payment is appended to the events of the payment, along with
+//! what caused the change, so that the lifecycle of a payment can be audited and debugged.
+
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts, logger,
+    routes::AppState,
+    types::storage::{self, enums as storage_enums, PaymentAttemptExt},
+    utils,
+};
+
+/// The statuses of a payment before it was acted upon.
+#[derive(Clone, Copy, Debug)]
+pub struct PaymentStatuses {
+    pub intent_status: storage_enums::IntentStatus,
+    pub attempt_status: storage_enums::AttemptStatus,
+}
+
+impl PaymentStatuses {
+    pub fn of(
+        payment_intent: &storage::PaymentIntent,
+        payment_attempt: &storage::PaymentAttempt,
+    ) -> Self {
+        Self {
+            intent_status: payment_intent.status,
+            attempt_status: payment_attempt.status,
+        }
+    }
+}
+
+/// The merchant making the request, or the connector sending the webhook, which changed the
+/// status of the payment. Changes made by the scheduler have no actor.
+fn get_actor(state: &AppState, payment_attempt: &storage::PaymentAttempt) -> Option<String> {
+    match state.event_source {
+        storage_enums::PaymentEventSource::Api => Some(payment_attempt.merchant_id.clone()),
+        storage_enums::PaymentEventSource::Webhook => payment_attempt
+            .get_routed_through_connector()
+            .ok()
+            .flatten(),
+        storage_enums::PaymentEventSource::Scheduler => None,
+    }
+}
+
+/// Records an event if the operation changed the status of the payment intent or of its active
+/// attempt. The change has already been made by the time it is recorded, so failures to record
+/// the event are logged rather than failing the operation.
+#[instrument(skip_all)]
+pub async fn record_status_change(
+    state: &AppState,
+    operation: &str,
+    previous: PaymentStatuses,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) {
+    if previous.intent_status == payment_intent.status
+        && previous.attempt_status == payment_attempt.status
+    {
+        return;
+    }
+
+    record_event(state, operation, previous, payment_intent, payment_attempt).await;
+}
+
+/// Records an event for the operation even if it left the status of the payment unchanged, such
+/// as a refund made in place of a cancellation, so that how the payment was acted upon is audited.
+#[instrument(skip_all)]
+pub async fn record_event(
+    state: &AppState,
+    operation: &str,
+    previous: PaymentStatuses,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) {
+    let payment_event = storage::PaymentEventNew {
+        event_id: utils::generate_id(consts::ID_LENGTH, "pevt"),
+        payment_id: payment_intent.payment_id.clone(),
+        merchant_id: payment_intent.merchant_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        operation: operation.to_string(),
+        previous_intent_status: previous.intent_status,
+        intent_status: payment_intent.status,
+        previous_attempt_status: previous.attempt_status,
+        attempt_status: payment_attempt.status,
+        source: state.event_source,
+        actor: get_actor(state, payment_attempt),
+        connector_response: payment_attempt.connector_raw_response.clone(),
+        created_at: None,
+    };
+    if let Err(error) = state.store.insert_payment_event(payment_event).await {
+        logger::error!(
+            ?error,
+            payment_id = %payment_intent.payment_id,
+            "Failed to record the event of the payment"
+        );
+    }
+}
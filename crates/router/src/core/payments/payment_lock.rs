@@ -0,0 +1,86 @@
+//! Confirming or capturing a payment takes a lock on the payment, so that simultaneous requests
+//! for the same payment cannot both reach the connector. The request that fails to take the lock is
+//! rejected, and the lock expires by itself if the request holding it does not release it.
+
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    services::background_tasks::BackgroundTasks,
+};
+
+/// The lock held on a payment, which is released once dropped.
+pub struct PaymentLock {
+    store: Box<dyn StorageInterface>,
+    merchant_id: String,
+    payment_id: String,
+    fencing_token: i64,
+    background_tasks: BackgroundTasks,
+}
+
+impl PaymentLock {
+    #[instrument(skip_all)]
+    pub async fn acquire(
+        state: &AppState,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> RouterResult<Self> {
+        let fencing_token = state
+            .store
+            .acquire_payment_lock(merchant_id, payment_id, state.conf.payment_lock.ttl)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while taking the lock on the payment")?
+            .ok_or_else(|| report!(errors::ApiErrorResponse::PaymentProcessingInProgress))?;
+
+        Ok(Self {
+            store: state.store.clone(),
+            merchant_id: merchant_id.to_owned(),
+            payment_id: payment_id.to_owned(),
+            fencing_token,
+            background_tasks: state.background_tasks.clone(),
+        })
+    }
+
+    /// Ensures that the lock has not expired and been taken by another request, by comparing the
+    /// fencing token of the current holder of the lock with the one it was taken with.
+    #[instrument(skip_all)]
+    pub async fn ensure_held(&self) -> RouterResult<()> {
+        let holder = self
+            .store
+            .find_payment_lock_holder(&self.merchant_id, &self.payment_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching the holder of the lock on the payment")?;
+
+        if holder == Some(self.fencing_token) {
+            Ok(())
+        } else {
+            Err(report!(
+                errors::ApiErrorResponse::PaymentProcessingInProgress
+            ))
+            .attach_printable("The lock on the payment expired while it was being processed")
+        }
+    }
+}
+
+impl Drop for PaymentLock {
+    fn drop(&mut self) {
+        let store = self.store.clone();
+        let merchant_id = std::mem::take(&mut self.merchant_id);
+        let payment_id = std::mem::take(&mut self.payment_id);
+        let fencing_token = self.fencing_token;
+
+        self.background_tasks.spawn(async move {
+            store
+                .release_payment_lock(&merchant_id, &payment_id, fencing_token)
+                .await
+                .map_err(|error| logger::error!(release_payment_lock_error=?error))
+                .ok();
+        });
+    }
+}
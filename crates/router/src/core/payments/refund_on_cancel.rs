@@ -0,0 +1,136 @@
+//! Payments which were already captured cannot be voided. Merchants who opt in with the
+//! `refund_on_cancel_{merchant_id}` config have the cancellation of a captured payment turned into
+//! a full refund of the payment instead. The refund is recorded in the events of the payment, so
+//! that whether a cancellation voided or refunded the payment can be told apart.
+
+use router_env::{instrument, tracing};
+
+use super::{operations, payment_events, payments_core, CallConnectorAction, PaymentCancel};
+use crate::{
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        refunds,
+    },
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        api,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+/// The operation the refund is recorded with in the events of the payment.
+const REFUND_ON_CANCEL_OPERATION: &str = "PaymentCancelAsRefund";
+
+pub fn get_refund_on_cancel_config_key(merchant_id: &str) -> String {
+    format!("refund_on_cancel_{merchant_id}")
+}
+
+#[instrument(skip_all)]
+async fn is_refund_on_cancel_enabled(db: &dyn StorageInterface, merchant_id: &str) -> bool {
+    match db
+        .find_config_by_key_cached(&get_refund_on_cancel_config_key(merchant_id))
+        .await
+    {
+        Ok(config) => config.config == "true",
+        Err(error) => {
+            if !error.current_context().is_db_not_found() {
+                logger::error!(
+                    ?error,
+                    "Failed to fetch the refund on cancel config of the merchant"
+                );
+            }
+            false
+        }
+    }
+}
+
+/// Cancels the payment, by voiding it, or by refunding it in full if it was already captured and
+/// the merchant opted in to refunds on cancellation.
+#[instrument(skip_all)]
+pub async fn payments_cancel_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PaymentsCancelRequest,
+) -> RouterResponse<api::PaymentsResponse> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &req.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    if payment_intent.status != storage_enums::IntentStatus::Succeeded
+        || !is_refund_on_cancel_enabled(db, &merchant_account.merchant_id).await
+    {
+        return payments_core::<api::Void, api::PaymentsResponse, _, _, _>(
+            state,
+            merchant_account,
+            PaymentCancel,
+            req,
+            services::AuthFlow::Merchant,
+            CallConnectorAction::Trigger,
+        )
+        .await;
+    }
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            &merchant_account.merchant_id,
+            &payment_intent.active_attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    refunds::refund_create_core(
+        state,
+        merchant_account.clone(),
+        api::RefundRequest {
+            payment_id: payment_intent.payment_id.clone(),
+            merchant_id: Some(merchant_account.merchant_id.clone()),
+            reason: req.cancellation_reason,
+            refund_type: Some(api::RefundType::Instant),
+            metadata: req.metadata,
+            merchant_connector_details: req.merchant_connector_details,
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    logger::info!(
+        payment_id = %payment_intent.payment_id,
+        "Refunded the captured payment in place of cancelling it"
+    );
+    payment_events::record_event(
+        state,
+        REFUND_ON_CANCEL_OPERATION,
+        payment_events::PaymentStatuses::of(&payment_intent, &payment_attempt),
+        &payment_intent,
+        &payment_attempt,
+    )
+    .await;
+
+    payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
+        state,
+        merchant_account,
+        operations::PaymentStatus,
+        api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(payment_intent.payment_id),
+            merchant_id: Some(payment_intent.merchant_id),
+            force_sync: false,
+            connector: None,
+            param: None,
+            merchant_connector_details: None,
+        },
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Avoid,
+    )
+    .await
+}
@@ -0,0 +1,351 @@
+use std::{collections::HashSet, fmt::Debug, time::Duration};
+
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use uuid::Uuid;
+
+use super::{
+    call_connector_service,
+    flows::{ConstructFlowSpecificData, Feature},
+    operations::{Operation, PaymentCreate},
+    CallConnectorAction, PaymentData, PaymentResponse,
+};
+use crate::{
+    core::errors::{self, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    logger,
+    routes::{metrics, AppState},
+    services,
+    types::{
+        self, api,
+        storage::{self, enums as storage_enums},
+    },
+    utils::{Encode, StringExt, ValueExt},
+};
+
+/// Per merchant configuration for retrying failed authorizations on an alternate connector.
+///
+/// This is stored in the configs table against the key returned by [`get_retry_config_key`].
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct RetryConfig {
+    /// Maximum number of retries to be performed after the first attempt fails
+    pub max_retries: u8,
+    /// Connector error codes (hard declines) for which a retry should be performed
+    pub retryable_error_codes: HashSet<String>,
+    /// Whether a gateway timeout received from the connector should trigger a retry
+    pub retry_on_gateway_timeout: bool,
+    /// Delay (in milliseconds) before the first retry, doubled for every subsequent retry
+    pub backoff_ms: u64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 1,
+            retryable_error_codes: HashSet::new(),
+            retry_on_gateway_timeout: true,
+            backoff_ms: 0,
+        }
+    }
+}
+
+impl RetryConfig {
+    fn is_retryable<F, Req>(
+        &self,
+        router_data: &types::RouterData<F, Req, types::PaymentsResponseData>,
+    ) -> bool {
+        match router_data.response {
+            Ok(_) => false,
+            Err(ref error_response) => {
+                self.retryable_error_codes.contains(&error_response.code)
+                    || (self.retry_on_gateway_timeout
+                        && error_response.status_code == http::StatusCode::GATEWAY_TIMEOUT.as_u16())
+            }
+        }
+    }
+
+    fn get_backoff_duration(&self, retry_count: u8) -> Duration {
+        Duration::from_millis(
+            self.backoff_ms
+                .saturating_mul(2_u64.saturating_pow(retry_count.into())),
+        )
+    }
+}
+
+pub fn get_retry_config_key(merchant_id: &str) -> String {
+    format!("auto_retry_config_{merchant_id}")
+}
+
+#[instrument(skip_all)]
+pub async fn get_retry_config(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<RetryConfig>> {
+    let config = match db
+        .find_config_by_key_cached(&get_retry_config_key(merchant_id))
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Failed to fetch auto retry config")
+        }
+    };
+
+    config
+        .config
+        .parse_struct("RetryConfig")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid auto retry config")
+        .map(Some)
+}
+
+/// Picks the first enabled payment processor of the merchant which has not been tried yet, and
+/// whose circuit is not open.
+pub async fn get_next_connector(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    tried_connectors: &[String],
+) -> RouterResult<Option<api::ConnectorData>> {
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_account.merchant_id,
+            false,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant connector accounts for retry")?;
+
+    for mca in merchant_connector_accounts {
+        if mca.connector_type != storage_enums::ConnectorType::PaymentProcessor
+            || tried_connectors.contains(&mca.connector_name)
+            || services::api::circuit_breaker::is_circuit_open(
+                state,
+                &merchant_account.merchant_id,
+                &mca.connector_name,
+            )
+            .await
+        {
+            continue;
+        }
+
+        match api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            &mca.connector_name,
+            api::GetToken::Connector,
+        ) {
+            Ok(connector) => return Ok(Some(connector)),
+            Err(error) => logger::warn!(retry_connector_error=?error),
+        }
+    }
+
+    Ok(None)
+}
+
+/// Marks the current attempt as failed and creates a new attempt routed through `connector`,
+/// linking it to the payment intent as the active attempt.
+async fn create_retry_attempt<F: Clone, Req>(
+    db: &dyn StorageInterface,
+    payment_data: &mut PaymentData<F>,
+    router_data: &types::RouterData<F, Req, types::PaymentsResponseData>,
+    connector: &api::ConnectorData,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<()> {
+    let (error_code, error_message) = match router_data.response {
+        Err(ref error_response) => (
+            Some(error_response.code.clone()),
+            Some(error_response.message.clone()),
+        ),
+        Ok(_) => (None, None),
+    };
+    let unified_code = error_code
+        .as_deref()
+        .and_then(|error_code| connector.connector.get_unified_code(error_code));
+
+    let failed_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::ErrorUpdate {
+                connector: None,
+                status: storage_enums::AttemptStatus::Failure,
+                error_code,
+                error_message,
+                unified_code: unified_code.map(|code| code.to_string()),
+                unified_message: unified_code.map(|code| code.get_unified_message().to_string()),
+                connector_raw_response: router_data.connector_raw_response.clone(),
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let mut routing_data: storage::RoutingData = failed_attempt
+        .connector
+        .clone()
+        .unwrap_or_else(|| serde_json::json!({}))
+        .parse_value("RoutingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid routing data format in payment attempt")?;
+    routing_data.routed_through = Some(connector.connector_name.to_string());
+    let encoded_routing_data = Encode::<storage::RoutingData>::encode_to_value(&routing_data)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize routing data to serde value")?;
+
+    let now = common_utils::date_time::now();
+    let new_attempt = db
+        .insert_payment_attempt(
+            storage::PaymentAttemptNew {
+                payment_id: failed_attempt.payment_id.clone(),
+                merchant_id: failed_attempt.merchant_id.clone(),
+                attempt_id: Uuid::new_v4().simple().to_string(),
+                status: storage_enums::AttemptStatus::Pending,
+                amount: failed_attempt.amount,
+                surcharge_amount: failed_attempt.surcharge_amount,
+                settlement_amount: failed_attempt.settlement_amount,
+                settlement_currency: failed_attempt.settlement_currency,
+                fx_rate: failed_attempt.fx_rate.clone(),
+                currency: failed_attempt.currency,
+                save_to_locker: failed_attempt.save_to_locker,
+                connector: Some(encoded_routing_data),
+                payment_method_id: failed_attempt.payment_method_id.clone(),
+                payment_method: failed_attempt.payment_method,
+                capture_method: failed_attempt.capture_method,
+                capture_on: failed_attempt.capture_on,
+                confirm: failed_attempt.confirm,
+                authentication_type: failed_attempt.authentication_type,
+                created_at: Some(now),
+                modified_at: Some(now),
+                last_synced: Some(now),
+                amount_to_capture: failed_attempt.amount_to_capture,
+                mandate_id: failed_attempt.mandate_id.clone(),
+                browser_info: failed_attempt.browser_info.clone(),
+                payment_token: failed_attempt.payment_token.clone(),
+                payment_experience: failed_attempt.payment_experience,
+                payment_method_type: failed_attempt.payment_method_type,
+                payment_method_data: failed_attempt.payment_method_data.clone(),
+                ..storage::PaymentAttemptNew::default()
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+                payment_id: failed_attempt.payment_id.clone(),
+            })
+        })?;
+
+    payment_data.connector_response = db
+        .insert_connector_response(
+            PaymentCreate::make_connector_response(&new_attempt)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+                payment_id: new_attempt.payment_id.clone(),
+            })
+        })?;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent.clone(),
+            storage::PaymentIntentUpdate::PaymentAttemptUpdate {
+                active_attempt_id: new_attempt.attempt_id.clone(),
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    payment_data.payment_attempt = new_attempt;
+
+    Ok(())
+}
+
+/// Retries the payment on the alternate connectors configured for the merchant, as long as the
+/// previous attempt failed with an error eligible for a retry as per the `retry_config`.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn retry_with_alternate_connectors<F, Req, Op>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    operation: &Op,
+    payment_data: &mut PaymentData<F>,
+    customer: &Option<storage::Customer>,
+    mut router_data: types::RouterData<F, Req, types::PaymentsResponseData>,
+    retry_config: &RetryConfig,
+) -> RouterResult<types::RouterData<F, Req, types::PaymentsResponseData>>
+where
+    Op: Debug + Sync,
+    F: Send + Clone,
+
+    // To create connector flow specific interface data
+    PaymentData<F>: ConstructFlowSpecificData<F, Req, types::PaymentsResponseData>,
+    types::RouterData<F, Req, types::PaymentsResponseData>: Feature<F, Req> + Send,
+
+    // To construct connector flow specific api
+    dyn api::Connector: services::api::ConnectorIntegration<F, Req, types::PaymentsResponseData>,
+
+    // To perform router related operation for PaymentResponse
+    PaymentResponse: Operation<F, Req>,
+{
+    let mut tried_connectors = vec![router_data.connector.clone()];
+
+    for retry_count in 0..retry_config.max_retries {
+        if !retry_config.is_retryable(&router_data) {
+            break;
+        }
+
+        let connector = match get_next_connector(state, merchant_account, &tried_connectors).await?
+        {
+            Some(connector) => connector,
+            None => break,
+        };
+
+        logger::info!(
+            "Retrying payment {} on connector {} (retry {})",
+            payment_data.payment_intent.payment_id,
+            connector.connector_name,
+            retry_count + 1
+        );
+
+        metrics::RETRY_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::request::add_attributes(
+                "connector",
+                connector.connector_name.to_string(),
+            )],
+        );
+
+        tokio::time::sleep(retry_config.get_backoff_duration(retry_count)).await;
+
+        create_retry_attempt(
+            &*state.store,
+            payment_data,
+            &router_data,
+            &connector,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+        tried_connectors.push(connector.connector_name.to_string());
+
+        router_data = call_connector_service(
+            state,
+            merchant_account,
+            connector,
+            operation,
+            payment_data,
+            customer,
+            CallConnectorAction::Trigger,
+        )
+        .await?;
+    }
+
+    Ok(router_data)
+}
@@ -0,0 +1,357 @@
+//! Split payments, where a platform makes a payment on behalf of its sub-merchants and a share of
+//! the amount of the payment goes to each of them.
+//!
+//! The shares are validated and recorded as splits of the payment when it is created, and are
+//! sent to the connector as transfers to the connector accounts of the sub-merchants when the
+//! payment is authorized. The platform retains the remaining amount as its fee. The splits are
+//! settled once the payment succeeds and cancelled if it does not, forming the ledger the platform
+//! reconciles its transfers against.
+
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::PaymentData;
+use crate::{
+    configs::settings::SupportedConnectors,
+    consts,
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    types::{
+        self, api,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils,
+};
+
+const BASIS_POINTS_PER_UNIT: i64 = 10_000;
+
+/// Calculates the share of the amount, with percentages rounded down to the minor unit of the
+/// currency, so that the shares never add up to more than the amount.
+pub fn calculate_split_amount(split: &api::SplitAmount, amount: i64) -> i64 {
+    match split {
+        api::SplitAmount::Fixed { amount } => *amount,
+        api::SplitAmount::Percentage { basis_points } => {
+            amount * basis_points / BASIS_POINTS_PER_UNIT
+        }
+    }
+}
+
+/// Validates the shares of the payment, returning the amount of each share. Each share must be
+/// positive, and the shares together cannot exceed the amount of the payment.
+pub fn validate_split_amounts(splits: &[api::PaymentSplit], amount: i64) -> RouterResult<Vec<i64>> {
+    if splits.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "split_payments cannot be empty".to_string(),
+        }));
+    }
+
+    let mut split_amounts = Vec::with_capacity(splits.len());
+    for split in splits {
+        let is_valid = match split.split {
+            api::SplitAmount::Fixed { amount } => amount > 0,
+            api::SplitAmount::Percentage { basis_points } => {
+                basis_points > 0 && basis_points <= BASIS_POINTS_PER_UNIT
+            }
+        };
+        let split_amount = calculate_split_amount(&split.split, amount);
+        if !is_valid || split_amount <= 0 {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The share of sub-merchant {} must be a positive amount",
+                    split.sub_merchant_id
+                ),
+            }));
+        }
+        split_amounts.push(split_amount);
+    }
+
+    utils::when(split_amounts.iter().sum::<i64>() > amount, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The shares of the sub-merchants cannot exceed the amount of the payment"
+                .to_string(),
+        }))
+    })?;
+
+    Ok(split_amounts)
+}
+
+/// Validates the splits of a payment being created, returning the splits to be recorded once the
+/// payment is created. Shares can only go to sub-merchants of the merchant making the payment.
+#[instrument(skip_all)]
+pub async fn make_payment_splits(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &str,
+    amount: i64,
+    currency: storage_enums::Currency,
+    request: &api::PaymentsRequest,
+) -> RouterResult<Vec<storage::PaymentSplitNew>> {
+    let splits = match &request.split_payments {
+        Some(splits) => splits,
+        None => return Ok(vec![]),
+    };
+    validate_not_split_tender(splits, request.gift_card_data.as_ref())?;
+    let split_amounts = validate_split_amounts(splits, amount)?;
+
+    let mut payment_splits = Vec::with_capacity(splits.len());
+    for (split, split_amount) in splits.iter().zip(split_amounts) {
+        let is_sub_merchant = db
+            .find_merchant_account_by_merchant_id(&split.sub_merchant_id)
+            .await
+            .map(|sub_merchant| {
+                sub_merchant.parent_merchant_id.as_ref() == Some(&merchant_account.merchant_id)
+            })
+            .or_else(|error| {
+                if error.current_context().is_db_not_found() {
+                    Ok(false)
+                } else {
+                    Err(error)
+                }
+            })
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch merchant account of the sub-merchant")?;
+        if !is_sub_merchant {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "{} is not a sub-merchant of the merchant",
+                    split.sub_merchant_id
+                ),
+            }));
+        }
+
+        payment_splits.push(storage::PaymentSplitNew {
+            split_id: utils::generate_id(consts::ID_LENGTH, "split"),
+            payment_id: payment_id.to_owned(),
+            merchant_id: merchant_account.merchant_id.to_owned(),
+            sub_merchant_id: split.sub_merchant_id.to_owned(),
+            connector_account_id: split.connector_account_id.to_owned(),
+            amount: split_amount,
+            currency,
+            status: storage_enums::SplitStatus::Pending,
+            created_at: None,
+            modified_at: None,
+        });
+    }
+
+    Ok(payment_splits)
+}
+
+#[instrument(skip_all)]
+pub async fn insert_payment_splits(
+    db: &dyn StorageInterface,
+    payment_splits: Vec<storage::PaymentSplitNew>,
+) -> RouterResult<Vec<storage::PaymentSplit>> {
+    let mut inserted_splits = Vec::with_capacity(payment_splits.len());
+    for payment_split in payment_splits {
+        inserted_splits.push(
+            db.insert_payment_split(payment_split)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert split of the payment")?,
+        );
+    }
+    Ok(inserted_splits)
+}
+
+/// The shares of a payment are decided along with its amount, so they are chosen only while
+/// creating the payment.
+pub fn validate_split_payments_not_updated(req: &api::PaymentsRequest) -> RouterResult<()> {
+    utils::when(req.split_payments.is_some(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "split_payments can only be set while creating the payment".to_string(),
+        }))
+    })
+}
+
+/// The shares of a split payment were calculated from its amount and currency, which therefore
+/// cannot be updated.
+pub fn validate_split_payment_update(
+    payment_splits: &[storage::PaymentSplit],
+    req: &api::PaymentsRequest,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let is_amount_updated = req.amount.map_or(false, |amount| {
+        i64::from(amount) != payment_attempt.amount.get_amount_as_i64()
+    });
+    let is_currency_updated = req.currency.map_or(false, |currency| {
+        let currency: storage_enums::Currency = currency.foreign_into();
+        Some(currency) != payment_attempt.currency
+    });
+    utils::when(
+        !payment_splits.is_empty() && (is_amount_updated || is_currency_updated),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The amount and currency of a split payment cannot be updated".to_string(),
+            }))
+        },
+    )
+}
+
+/// The transfers to the sub-merchants are made from the amount charged by the connector, so a
+/// split payment cannot be paid partially with a gift card.
+pub fn validate_not_split_tender<T>(
+    payment_splits: &[T],
+    gift_card_data: Option<&api_models::payments::GiftCardData>,
+) -> RouterResult<()> {
+    utils::when(
+        !payment_splits.is_empty() && gift_card_data.is_some(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "A split payment cannot be paid with a gift card".to_string(),
+            }))
+        },
+    )
+}
+
+/// Returns the transfers to be made by the connector for the splits of the payment, if any. The
+/// platform fee is the amount processed by the connector which does not go to sub-merchants.
+pub fn get_split_payments_data(
+    supported_connectors: &SupportedConnectors,
+    connector: &str,
+    payment_splits: &[storage::PaymentSplit],
+    amount: i64,
+) -> RouterResult<Option<types::SplitPaymentsData>> {
+    if payment_splits.is_empty() {
+        return Ok(None);
+    }
+
+    utils::when(
+        !supported_connectors
+            .split_payments
+            .iter()
+            .any(|supported_connector| supported_connector == connector),
+        || {
+            Err(report!(errors::ApiErrorResponse::FlowNotSupported {
+                flow: "Split payments".to_string(),
+                connector: connector.to_string(),
+            }))
+        },
+    )?;
+
+    let transfers = payment_splits
+        .iter()
+        .map(|payment_split| types::SplitTransfer {
+            connector_account_id: payment_split.connector_account_id.clone(),
+            amount: payment_split.amount,
+        })
+        .collect::<Vec<_>>();
+    let transferred_amount = transfers
+        .iter()
+        .map(|transfer| transfer.amount)
+        .sum::<i64>();
+
+    Ok(Some(types::SplitPaymentsData {
+        platform_fee: amount - transferred_amount,
+        transfers,
+    }))
+}
+
+/// Settles the splits of the payment once it succeeds, and cancels them once it fails, is
+/// cancelled or expires. Splits which are already settled or cancelled are left as is.
+#[instrument(skip_all)]
+pub async fn update_payment_splits<F: Clone>(
+    db: &dyn StorageInterface,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    let status = match payment_data.payment_intent.status {
+        storage_enums::IntentStatus::Succeeded => storage_enums::SplitStatus::Settled,
+        storage_enums::IntentStatus::Failed
+        | storage_enums::IntentStatus::Cancelled
+        | storage_enums::IntentStatus::Expired => storage_enums::SplitStatus::Cancelled,
+        _ => return Ok(()),
+    };
+
+    // Not every operation loads the splits of the payment
+    let payment_splits = db
+        .find_all_payment_splits_by_merchant_id_payment_id(
+            &payment_data.payment_intent.merchant_id,
+            &payment_data.payment_intent.payment_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch splits of the payment")?;
+
+    let mut updated_splits = Vec::with_capacity(payment_splits.len());
+    for payment_split in payment_splits {
+        let payment_split = if payment_split.status == storage_enums::SplitStatus::Pending {
+            db.update_payment_split_with_split_id(
+                payment_split,
+                storage::PaymentSplitUpdate::StatusUpdate { status },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update split of the payment")?
+        } else {
+            payment_split
+        };
+        updated_splits.push(payment_split);
+    }
+    payment_data.payment_splits = updated_splits;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn split(split: api::SplitAmount) -> api::PaymentSplit {
+        api::PaymentSplit {
+            sub_merchant_id: "merchant_sub".to_string(),
+            connector_account_id: "acct_sub".to_string(),
+            split,
+        }
+    }
+
+    #[test]
+    fn test_percentage_split_is_rounded_down() {
+        let split = api::SplitAmount::Percentage { basis_points: 3333 };
+
+        assert_eq!(calculate_split_amount(&split, 1000), 333);
+    }
+
+    #[test]
+    fn test_split_amounts_are_calculated() {
+        let splits = [
+            split(api::SplitAmount::Fixed { amount: 2500 }),
+            split(api::SplitAmount::Percentage { basis_points: 5000 }),
+        ];
+
+        assert_eq!(
+            validate_split_amounts(&splits, 10000).unwrap(),
+            vec![2500, 5000]
+        );
+    }
+
+    #[test]
+    fn test_splits_cannot_exceed_amount() {
+        let splits = [
+            split(api::SplitAmount::Fixed { amount: 6000 }),
+            split(api::SplitAmount::Percentage { basis_points: 5000 }),
+        ];
+
+        assert!(validate_split_amounts(&splits, 10000).is_err());
+    }
+
+    #[test]
+    fn test_splits_must_be_positive() {
+        assert!(
+            validate_split_amounts(&[split(api::SplitAmount::Fixed { amount: 0 })], 100).is_err()
+        );
+        assert!(validate_split_amounts(
+            &[split(api::SplitAmount::Percentage {
+                basis_points: 10001
+            })],
+            100
+        )
+        .is_err());
+        // A percentage which rounds down to nothing is not a share
+        assert!(validate_split_amounts(
+            &[split(api::SplitAmount::Percentage { basis_points: 1 })],
+            100
+        )
+        .is_err());
+    }
+}
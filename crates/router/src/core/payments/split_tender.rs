@@ -0,0 +1,677 @@
+//! Split tender payments, where a part of the amount of the payment is redeemed from a gift card
+//! and the remainder is authorized on the payment method of the payment.
+//!
+//! The gift card is redeemed before the payment method is authorized, and the redemption is
+//! rolled back if the authorization fails. Each part of the amount is tracked against the payment
+//! attempt as a funding source.
+
+use std::marker::PhantomData;
+
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use super::{transformers, CallConnectorAction, PaymentData};
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::{helpers, state_machine},
+    },
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self, api,
+        storage::{self, enums as storage_enums},
+    },
+    utils::{self, OptionExt, ValueExt},
+};
+
+const GIFT_CARD_FUNDING_SEQUENCE: i16 = 1;
+const PAYMENT_METHOD_FUNDING_SEQUENCE: i16 = 2;
+
+/// The outcome of the redemption of the gift card of a split tender payment.
+enum RedemptionOutcome {
+    /// The gift card covers the complete amount of the payment
+    Complete,
+    /// The remaining amount is to be authorized on the payment method of the payment
+    Partial { remaining_amount: i64 },
+    /// The gift card could not be redeemed, the payment has failed
+    Failed,
+}
+
+/// Redeems the gift card of the payment, if any, before the payment method of the payment is
+/// authorized.
+///
+/// Returns whether the payment method of the payment is to be authorized with the connector. The
+/// amount of the payment data is reduced to the amount that remains to be paid.
+#[instrument(skip_all)]
+pub async fn redeem_gift_card<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: &api::ConnectorData,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<bool> {
+    let gift_card_data = match &payment_data.gift_card_data {
+        Some(gift_card_data) if payment_data.confirm == Some(true) => gift_card_data.clone(),
+        _ => return Ok(true),
+    };
+
+    let outcome = match check_gift_card_balance(
+        state,
+        merchant_account,
+        connector,
+        payment_data,
+        &gift_card_data,
+    )
+    .await?
+    {
+        Ok(redeemable_amount) => {
+            authorize_gift_card(
+                state,
+                merchant_account,
+                connector,
+                payment_data,
+                gift_card_data,
+                redeemable_amount,
+            )
+            .await?
+        }
+        Err(error_response) => {
+            fail_payment(
+                state,
+                merchant_account,
+                connector,
+                payment_data,
+                error_response,
+            )
+            .await?;
+            RedemptionOutcome::Failed
+        }
+    };
+
+    match outcome {
+        RedemptionOutcome::Partial { remaining_amount } => {
+            let funding_source =
+                insert_funding_source(state, payment_data, connector, remaining_amount, None)
+                    .await?;
+            payment_data.funding_sources.push(funding_source);
+            payment_data.amount = types::MinorUnit::new(remaining_amount);
+            Ok(true)
+        }
+        RedemptionOutcome::Complete | RedemptionOutcome::Failed => Ok(false),
+    }
+}
+
+/// Completes a split tender payment with the outcome of the authorization of the payment method
+/// of the payment, rolling back the redemption of the gift card if the authorization failed.
+///
+/// Payment methods awaiting customer action (a redirection, for instance) are left as started,
+/// and the redemption of the gift card is not rolled back here.
+#[instrument(skip_all)]
+pub async fn complete_split_tender<F: Clone, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+    router_data: &types::RouterData<F, Req, types::PaymentsResponseData>,
+) -> RouterResult<()> {
+    let funding_source = match payment_data.funding_sources.iter().position(|source| {
+        source.funding_sequence == PAYMENT_METHOD_FUNDING_SEQUENCE
+            && source.status == storage_enums::FundingSourceStatus::Started
+    }) {
+        Some(index) => payment_data.funding_sources.remove(index),
+        None => return Ok(()),
+    };
+    let db = &*state.store;
+
+    let funding_source_update = match (&router_data.response, router_data.status) {
+        (Err(error_response), _) => Some(storage::FundingSourceUpdate::ErrorUpdate {
+            status: storage_enums::FundingSourceStatus::Failed,
+            error_code: Some(error_response.code.clone()),
+            error_message: Some(error_response.message.clone()),
+        }),
+        (
+            Ok(_),
+            storage_enums::AttemptStatus::Failure
+            | storage_enums::AttemptStatus::AuthorizationFailed,
+        ) => Some(storage::FundingSourceUpdate::StatusUpdate {
+            status: storage_enums::FundingSourceStatus::Failed,
+        }),
+        (Ok(response), storage_enums::AttemptStatus::Authorized) => {
+            payment_data.payment_attempt = db
+                .update_payment_attempt_with_attempt_id(
+                    payment_data.payment_attempt.clone(),
+                    storage::PaymentAttemptUpdate::PartialCaptureUpdate {
+                        amount_to_capture: funding_source.amount,
+                    },
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+            Some(storage::FundingSourceUpdate::ResponseUpdate {
+                status: storage_enums::FundingSourceStatus::Authorized,
+                connector_transaction_id: get_connector_transaction_id(response),
+            })
+        }
+        (Ok(response), storage_enums::AttemptStatus::Charged) => {
+            Some(storage::FundingSourceUpdate::ResponseUpdate {
+                status: storage_enums::FundingSourceStatus::Charged,
+                connector_transaction_id: get_connector_transaction_id(response),
+            })
+        }
+        (Ok(_), _) => None,
+    };
+
+    let payment_method_failed = matches!(
+        funding_source_update,
+        Some(storage::FundingSourceUpdate::ErrorUpdate { .. })
+            | Some(storage::FundingSourceUpdate::StatusUpdate { .. })
+    );
+    let funding_source = match funding_source_update {
+        Some(funding_source_update) => {
+            update_funding_source(state, funding_source, funding_source_update).await?
+        }
+        None => funding_source,
+    };
+    payment_data.funding_sources.push(funding_source);
+
+    if payment_method_failed {
+        if let Some(index) = payment_data.funding_sources.iter().position(|source| {
+            source.funding_sequence == GIFT_CARD_FUNDING_SEQUENCE
+                && source.status == storage_enums::FundingSourceStatus::Charged
+        }) {
+            let gift_card_funding_source = payment_data.funding_sources.remove(index);
+            let gift_card_funding_source = roll_back_gift_card(
+                state,
+                merchant_account,
+                payment_data,
+                gift_card_funding_source,
+            )
+            .await?;
+            payment_data.funding_sources.push(gift_card_funding_source);
+        }
+    }
+
+    payment_data.amount = payment_data.payment_attempt.amount;
+    Ok(())
+}
+
+/// Checks the balance of the gift card, returning the amount that can be redeemed from it, or the
+/// error with which the payment is to be failed.
+async fn check_gift_card_balance<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: &api::ConnectorData,
+    payment_data: &PaymentData<F>,
+    gift_card_data: &api_models::payments::GiftCardData,
+) -> RouterResult<Result<i64, types::ErrorResponse>> {
+    let amount = payment_data.amount.get_amount_as_i64();
+    let router_data: types::GiftCardBalanceRouterData = construct_router_data(
+        state,
+        merchant_account,
+        &connector.connector_name.to_string(),
+        payment_data,
+        storage_enums::PaymentMethod::GiftCard,
+        types::GiftCardBalanceRequestData {
+            gift_card_data: gift_card_data.clone(),
+            amount,
+            currency: payment_data.currency.get_currency(),
+        },
+    )
+    .await?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Balance,
+        types::GiftCardBalanceRequestData,
+        types::GiftCardBalanceResponseData,
+    > = connector.connector.get_connector_integration();
+    let router_data = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        CallConnectorAction::Trigger,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while checking the balance of the gift card")?;
+
+    Ok(router_data.response.and_then(|response| {
+        if response.currency != payment_data.currency.get_currency() {
+            Err(get_error_response(
+                "Gift card currency does not match the payment currency".to_string(),
+            ))
+        } else if response.balance <= 0 {
+            Err(get_error_response("Gift card has no balance".to_string()))
+        } else {
+            Ok(response.balance.min(amount))
+        }
+    }))
+}
+
+/// Redeems the amount from the gift card, by authorizing the amount with automatic capture.
+async fn authorize_gift_card<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: &api::ConnectorData,
+    payment_data: &mut PaymentData<F>,
+    gift_card_data: api_models::payments::GiftCardData,
+    redeemable_amount: i64,
+) -> RouterResult<RedemptionOutcome> {
+    let connector_id = connector.connector_name.to_string();
+    let funding_source = insert_funding_source(
+        state,
+        payment_data,
+        connector,
+        redeemable_amount,
+        Some(&gift_card_data),
+    )
+    .await?;
+
+    let mut gift_card_payment_data = payment_data.clone();
+    gift_card_payment_data.payment_method_data =
+        Some(api::PaymentMethodData::GiftCard(Box::new(gift_card_data)));
+    gift_card_payment_data.amount = types::MinorUnit::new(redeemable_amount);
+    gift_card_payment_data.mandate_id = None;
+    gift_card_payment_data.setup_mandate = None;
+    gift_card_payment_data.payment_intent.setup_future_usage = None;
+
+    let mut request: types::PaymentsAuthorizeData =
+        transformers::construct_flow_request_data(state, &connector_id, gift_card_payment_data)?;
+    request.capture_method = Some(storage_enums::CaptureMethod::Automatic);
+    request.payment_method_type = funding_source.payment_method_type;
+
+    let router_data: types::PaymentsAuthorizeRouterData = construct_router_data(
+        state,
+        merchant_account,
+        &connector_id,
+        payment_data,
+        storage_enums::PaymentMethod::GiftCard,
+        request,
+    )
+    .await?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Authorize,
+        types::PaymentsAuthorizeData,
+        types::PaymentsResponseData,
+    > = connector.connector.get_connector_integration();
+    let router_data = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        CallConnectorAction::Trigger,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while redeeming the gift card")?;
+
+    let connector_transaction_id = match (&router_data.response, router_data.status) {
+        (Ok(response), storage_enums::AttemptStatus::Charged) => {
+            get_connector_transaction_id(response)
+        }
+        (Ok(_), _) => {
+            // Gift cards are expected to be redeemed synchronously
+            let error_response =
+                get_error_response(format!("Gift card redemption is {}", router_data.status));
+            return fail_gift_card(
+                state,
+                merchant_account,
+                connector,
+                payment_data,
+                funding_source,
+                error_response,
+            )
+            .await;
+        }
+        (Err(error_response), _) => {
+            return fail_gift_card(
+                state,
+                merchant_account,
+                connector,
+                payment_data,
+                funding_source,
+                error_response.clone(),
+            )
+            .await;
+        }
+    };
+
+    let funding_source = update_funding_source(
+        state,
+        funding_source,
+        storage::FundingSourceUpdate::ResponseUpdate {
+            status: storage_enums::FundingSourceStatus::Charged,
+            connector_transaction_id: connector_transaction_id.clone(),
+        },
+    )
+    .await?;
+    payment_data.funding_sources.push(funding_source);
+
+    let remaining_amount = payment_data.amount.get_amount_as_i64() - redeemable_amount;
+    if remaining_amount > 0 {
+        return Ok(RedemptionOutcome::Partial { remaining_amount });
+    }
+
+    let db = &*state.store;
+    payment_data.payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::ResponseUpdate {
+                status: storage_enums::AttemptStatus::Charged,
+                connector: None,
+                connector_transaction_id,
+                authentication_type: None,
+                payment_method_id: None,
+                mandate_id: None,
+                connector_metadata: None,
+                connector_raw_response: None,
+                avs_result: None,
+                cvc_result: None,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent.clone(),
+            storage::PaymentIntentUpdate::ResponseUpdate {
+                status: storage_enums::IntentStatus::Succeeded,
+                amount_captured: Some(payment_data.payment_intent.amount.get_amount_as_i64()),
+                return_url: None,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(RedemptionOutcome::Complete)
+}
+
+async fn fail_gift_card<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: &api::ConnectorData,
+    payment_data: &mut PaymentData<F>,
+    funding_source: storage::FundingSource,
+    error_response: types::ErrorResponse,
+) -> RouterResult<RedemptionOutcome> {
+    let funding_source = update_funding_source(
+        state,
+        funding_source,
+        storage::FundingSourceUpdate::ErrorUpdate {
+            status: storage_enums::FundingSourceStatus::Failed,
+            error_code: Some(error_response.code.clone()),
+            error_message: Some(error_response.message.clone()),
+        },
+    )
+    .await?;
+    payment_data.funding_sources.push(funding_source);
+    fail_payment(
+        state,
+        merchant_account,
+        connector,
+        payment_data,
+        error_response,
+    )
+    .await?;
+    Ok(RedemptionOutcome::Failed)
+}
+
+/// Voids the redemption of the gift card with the connector it was redeemed through. Failures to
+/// roll back the redemption are logged and the gift card is left as charged, to be reconciled by
+/// the merchant.
+async fn roll_back_gift_card<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &PaymentData<F>,
+    funding_source: storage::FundingSource,
+) -> RouterResult<storage::FundingSource> {
+    let connector_transaction_id = match &funding_source.connector_transaction_id {
+        Some(connector_transaction_id) => connector_transaction_id.clone(),
+        None => {
+            logger::error!(
+                funding_source_id = %funding_source.funding_source_id,
+                "Gift card redemption without a connector transaction id cannot be rolled back"
+            );
+            return Ok(funding_source);
+        }
+    };
+
+    let connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &funding_source.connector,
+        api::GetToken::Connector,
+    )?;
+    let router_data: types::PaymentsCancelRouterData = construct_router_data(
+        state,
+        merchant_account,
+        &funding_source.connector,
+        payment_data,
+        storage_enums::PaymentMethod::GiftCard,
+        types::PaymentsCancelData {
+            amount: Some(funding_source.amount),
+            currency: Some(funding_source.currency),
+            connector_transaction_id,
+            cancellation_reason: Some("split_tender_authorization_failed".to_string()),
+            cancellation_metadata: None,
+            connector_meta: None,
+        },
+    )
+    .await?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Void,
+        types::PaymentsCancelData,
+        types::PaymentsResponseData,
+    > = connector.connector.get_connector_integration();
+    let rolled_back = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        CallConnectorAction::Trigger,
+    )
+    .await
+    .map(|router_data| router_data.response.is_ok());
+
+    match rolled_back {
+        Ok(true) => {
+            update_funding_source(
+                state,
+                funding_source,
+                storage::FundingSourceUpdate::StatusUpdate {
+                    status: storage_enums::FundingSourceStatus::RolledBack,
+                },
+            )
+            .await
+        }
+        Ok(false) | Err(_) => {
+            logger::error!(
+                funding_source_id = %funding_source.funding_source_id,
+                "Failed to roll back the gift card redemption"
+            );
+            Ok(funding_source)
+        }
+    }
+}
+
+/// Fails the payment attempt and the payment intent, for a gift card that could not be redeemed.
+async fn fail_payment<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: &api::ConnectorData,
+    payment_data: &mut PaymentData<F>,
+    error_response: types::ErrorResponse,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    state_machine::validate_attempt_status_transition(
+        payment_data.payment_attempt.status,
+        storage_enums::AttemptStatus::Failure,
+    )?;
+    state_machine::validate_intent_status_transition(
+        payment_data.payment_intent.status,
+        storage_enums::IntentStatus::Failed,
+    )?;
+    let unified_code = connector.connector.get_unified_code(&error_response.code);
+    payment_data.payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::ErrorUpdate {
+                connector: None,
+                status: storage_enums::AttemptStatus::Failure,
+                error_code: Some(error_response.code),
+                error_message: Some(error_response.message),
+                unified_code: unified_code.map(|code| code.to_string()),
+                unified_message: unified_code.map(|code| code.get_unified_message().to_string()),
+                connector_raw_response: None,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent.clone(),
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: storage_enums::IntentStatus::Failed,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    Ok(())
+}
+
+async fn insert_funding_source<F: Clone>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+    connector: &api::ConnectorData,
+    amount: i64,
+    gift_card_data: Option<&api_models::payments::GiftCardData>,
+) -> RouterResult<storage::FundingSource> {
+    let payment_attempt = &payment_data.payment_attempt;
+    let (payment_method, payment_method_type, funding_sequence) = match gift_card_data {
+        Some(api_models::payments::GiftCardData::Givex(_)) => (
+            storage_enums::PaymentMethod::GiftCard,
+            Some(storage_enums::PaymentMethodType::Givex),
+            GIFT_CARD_FUNDING_SEQUENCE,
+        ),
+        None => (
+            payment_attempt
+                .payment_method
+                .get_required_value("payment_method")?,
+            payment_attempt.payment_method_type,
+            PAYMENT_METHOD_FUNDING_SEQUENCE,
+        ),
+    };
+    let now = common_utils::date_time::now();
+
+    state
+        .store
+        .insert_funding_source(storage::FundingSourceNew {
+            funding_source_id: utils::generate_id(consts::ID_LENGTH, "fs"),
+            payment_id: payment_attempt.payment_id.clone(),
+            merchant_id: payment_attempt.merchant_id.clone(),
+            attempt_id: payment_attempt.attempt_id.clone(),
+            payment_method,
+            payment_method_type,
+            status: storage_enums::FundingSourceStatus::Started,
+            amount,
+            currency: payment_data.currency.get_currency(),
+            connector: connector.connector_name.to_string(),
+            funding_sequence,
+            connector_transaction_id: None,
+            error_message: None,
+            error_code: None,
+            created_at: Some(now),
+            modified_at: Some(now),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert funding source")
+}
+
+async fn update_funding_source(
+    state: &AppState,
+    funding_source: storage::FundingSource,
+    funding_source_update: storage::FundingSourceUpdate,
+) -> RouterResult<storage::FundingSource> {
+    state
+        .store
+        .update_funding_source_with_funding_source_id(funding_source, funding_source_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update funding source")
+}
+
+/// Constructs the router data of a connector flow for a funding source of the payment.
+async fn construct_router_data<F: Clone, Flow, Req, Res>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector_id: &str,
+    payment_data: &PaymentData<F>,
+    payment_method: storage_enums::PaymentMethod,
+    request: Req,
+) -> RouterResult<types::RouterData<Flow, Req, Res>> {
+    let merchant_connector_account = helpers::get_merchant_connector_account(
+        state,
+        merchant_account.merchant_id.as_str(),
+        connector_id,
+        payment_data.creds_identifier.to_owned(),
+    )
+    .await?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while parsing value for ConnectorAuthType")?;
+
+    Ok(types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: connector_id.to_owned(),
+        payment_id: payment_data.payment_attempt.payment_id.clone(),
+        attempt_id: payment_data.payment_attempt.attempt_id.clone(),
+        status: payment_data.payment_attempt.status,
+        payment_method,
+        connector_auth_type: auth_type,
+        description: payment_data.payment_intent.description.clone(),
+        return_url: payment_data.payment_intent.return_url.clone(),
+        payment_method_id: None,
+        connector_raw_response: None,
+        connector_debug_mode: payment_data.payment_intent.connector_debug_mode,
+        address: payment_data.address.clone(),
+        auth_type: storage_enums::AuthenticationType::NoThreeDs,
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        request,
+        response: Err(types::ErrorResponse::default()),
+        amount_captured: None,
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+    })
+}
+
+fn get_connector_transaction_id(response: &types::PaymentsResponseData) -> Option<String> {
+    match response {
+        types::PaymentsResponseData::TransactionResponse {
+            resource_id: types::ResponseId::ConnectorTransactionId(id),
+            ..
+        } => Some(id.clone()),
+        _ => None,
+    }
+}
+
+/// Error for a gift card that the connector responded to successfully, but cannot be redeemed.
+fn get_error_response(message: String) -> types::ErrorResponse {
+    types::ErrorResponse {
+        code: consts::NO_ERROR_CODE.to_string(),
+        message,
+        reason: None,
+        status_code: http::StatusCode::OK.as_u16(),
+    }
+}
@@ -0,0 +1,163 @@
+//! The legal transitions between the statuses of payment intents and payment attempts. Every
+//! operation and webhook which updates the status of a payment checks the transition here, so that
+//! a payment which has reached a final status is not moved out of it by a late connector response
+//! or a conflicting request.
+
+use error_stack::report;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    types::storage::{self, enums as storage_enums},
+    utils,
+};
+
+/// Returns whether a payment intent may move from the `current` status to the `next` status.
+pub fn is_valid_intent_transition(
+    current: storage_enums::IntentStatus,
+    next: storage_enums::IntentStatus,
+) -> bool {
+    use storage_enums::IntentStatus;
+
+    if current == next {
+        return true;
+    }
+
+    match current {
+        IntentStatus::Succeeded | IntentStatus::Cancelled | IntentStatus::Expired => false,
+        // A connector may report the success of a payment after it was marked as failed
+        IntentStatus::Failed => next == IntentStatus::Succeeded,
+        // A payment which was submitted to the connector cannot await its confirmation again
+        IntentStatus::Processing
+        | IntentStatus::RequiresCapture
+        | IntentStatus::RequiresMerchantAction => !matches!(
+            next,
+            IntentStatus::RequiresConfirmation | IntentStatus::RequiresPaymentMethod
+        ),
+        IntentStatus::RequiresCustomerAction
+        | IntentStatus::RequiresPaymentMethod
+        | IntentStatus::RequiresConfirmation => true,
+    }
+}
+
+/// Returns whether a payment attempt may move from the `current` status to the `next` status.
+pub fn is_valid_attempt_transition(
+    current: storage_enums::AttemptStatus,
+    next: storage_enums::AttemptStatus,
+) -> bool {
+    use storage_enums::AttemptStatus;
+
+    if current == next {
+        return true;
+    }
+
+    match current {
+        AttemptStatus::Voided | AttemptStatus::AutoRefunded => false,
+        AttemptStatus::Charged => next == AttemptStatus::AutoRefunded,
+        // A connector may report the success of an attempt after it was marked as failed
+        AttemptStatus::Failure
+        | AttemptStatus::AuthorizationFailed
+        | AttemptStatus::AuthenticationFailed
+        | AttemptStatus::RouterDeclined => {
+            matches!(next, AttemptStatus::Charged | AttemptStatus::Authorized)
+        }
+        _ => true,
+    }
+}
+
+/// Returns whether the bank of the customer may return the debit of the payment attempt. A
+/// returned debit is the only way for a charged attempt to fail, and is not checked against the
+/// transitions above.
+pub fn is_returnable_bank_debit(payment_attempt: &storage::PaymentAttempt) -> bool {
+    payment_attempt.payment_method == Some(storage_enums::PaymentMethod::BankDebit)
+        && matches!(
+            payment_attempt.status,
+            storage_enums::AttemptStatus::Charged | storage_enums::AttemptStatus::DebitInitiated
+        )
+}
+
+pub fn validate_intent_status_transition(
+    current: storage_enums::IntentStatus,
+    next: storage_enums::IntentStatus,
+) -> RouterResult<()> {
+    utils::when(!is_valid_intent_transition(current, next), || {
+        Err(
+            report!(errors::ApiErrorResponse::InvalidPaymentStatusTransition {
+                current_status: current.to_string(),
+                target_status: next.to_string(),
+            })
+            .attach_printable("Illegal payment intent status transition"),
+        )
+    })
+}
+
+pub fn validate_attempt_status_transition(
+    current: storage_enums::AttemptStatus,
+    next: storage_enums::AttemptStatus,
+) -> RouterResult<()> {
+    utils::when(!is_valid_attempt_transition(current, next), || {
+        Err(
+            report!(errors::ApiErrorResponse::InvalidPaymentStatusTransition {
+                current_status: current.to_string(),
+                target_status: next.to_string(),
+            })
+            .attach_printable("Illegal payment attempt status transition"),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_final_intent_statuses_are_kept() {
+        use storage_enums::IntentStatus;
+
+        assert!(!is_valid_intent_transition(
+            IntentStatus::Succeeded,
+            IntentStatus::Failed
+        ));
+        assert!(!is_valid_intent_transition(
+            IntentStatus::Cancelled,
+            IntentStatus::Processing
+        ));
+        assert!(!is_valid_intent_transition(
+            IntentStatus::Cancelled,
+            IntentStatus::Expired
+        ));
+        assert!(!is_valid_intent_transition(
+            IntentStatus::Expired,
+            IntentStatus::Cancelled
+        ));
+        assert!(is_valid_intent_transition(
+            IntentStatus::Succeeded,
+            IntentStatus::Succeeded
+        ));
+        assert!(is_valid_intent_transition(
+            IntentStatus::Failed,
+            IntentStatus::Succeeded
+        ));
+        assert!(!is_valid_intent_transition(
+            IntentStatus::RequiresCapture,
+            IntentStatus::RequiresConfirmation
+        ));
+    }
+
+    #[test]
+    fn test_charged_attempt_cannot_fail() {
+        use storage_enums::AttemptStatus;
+
+        assert!(!is_valid_attempt_transition(
+            AttemptStatus::Charged,
+            AttemptStatus::Failure
+        ));
+        assert!(is_valid_attempt_transition(
+            AttemptStatus::Authorized,
+            AttemptStatus::Charged
+        ));
+        assert!(!is_valid_attempt_transition(
+            AttemptStatus::Voided,
+            AttemptStatus::Charged
+        ));
+    }
+}
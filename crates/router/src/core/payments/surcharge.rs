@@ -0,0 +1,234 @@
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use super::PaymentData;
+use crate::{
+    core::errors::{self, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    types::{
+        self,
+        api::{self, enums as api_enums},
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils::StringExt,
+};
+
+const BASIS_POINTS_PER_UNIT: i64 = 10_000;
+
+/// The surcharge added to the amount of a payment, either a fixed amount or a share of the amount
+/// of the payment in basis points (hundredths of a percent).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Surcharge {
+    Fixed { amount: i64 },
+    Percentage { basis_points: i64 },
+}
+
+impl Surcharge {
+    /// Calculates the surcharge for the amount, with percentages rounded half up to the minor
+    /// unit of the currency.
+    pub fn calculate(&self, amount: i64) -> i64 {
+        match self {
+            Self::Fixed { amount } => *amount,
+            Self::Percentage { basis_points } => {
+                (amount * basis_points + BASIS_POINTS_PER_UNIT / 2) / BASIS_POINTS_PER_UNIT
+            }
+        }
+    }
+}
+
+/// Adds the surcharge to payments made with the payment method, the card network and from the
+/// billing country of the rule. Criteria which are not specified match all payments.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct SurchargeRule {
+    pub payment_method: Option<api_enums::PaymentMethod>,
+    pub card_network: Option<api_enums::CardNetwork>,
+    pub country: Option<api_enums::CountryCode>,
+    pub surcharge: Surcharge,
+}
+
+/// Per merchant rules for surcharging payments, of which the first rule matched by a payment
+/// applies.
+///
+/// This is stored in the configs table against the key returned by [`get_surcharge_rules_key`].
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct SurchargeRules {
+    pub rules: Vec<SurchargeRule>,
+}
+
+/// The attributes of a payment which the surcharge rules are matched against.
+#[derive(Clone, Debug)]
+pub struct SurchargeInput {
+    pub payment_method: Option<api_enums::PaymentMethod>,
+    pub card_network: Option<api_enums::CardNetwork>,
+    pub country: Option<api_enums::CountryCode>,
+    pub amount: i64,
+}
+
+impl SurchargeRule {
+    fn matches(&self, input: &SurchargeInput) -> bool {
+        fn matches_criterion<T: PartialEq>(criterion: Option<T>, value: Option<T>) -> bool {
+            criterion.map_or(true, |criterion| value == Some(criterion))
+        }
+
+        matches_criterion(self.payment_method, input.payment_method)
+            && matches_criterion(self.card_network.as_ref(), input.card_network.as_ref())
+            && matches_criterion(self.country, input.country)
+    }
+}
+
+pub fn get_surcharge_rules_key(merchant_id: &str) -> String {
+    format!("surcharge_rules_{merchant_id}")
+}
+
+/// Returns the surcharge of the first rule matched by the payment, if any.
+pub fn calculate_surcharge(rules: &SurchargeRules, input: &SurchargeInput) -> Option<i64> {
+    rules
+        .rules
+        .iter()
+        .find(|rule| rule.matches(input))
+        .map(|rule| rule.surcharge.calculate(input.amount))
+}
+
+/// The amount the connector is requested to process for the payment, which is the amount of the
+/// payment and its surcharge, if any.
+pub fn get_connector_amount<F: Clone>(payment_data: &PaymentData<F>) -> types::MinorUnit {
+    types::MinorUnit::new(
+        payment_data.amount.get_amount_as_i64()
+            + payment_data.payment_attempt.surcharge_amount.unwrap_or(0),
+    )
+}
+
+#[instrument(skip_all)]
+pub async fn get_surcharge_rules(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<SurchargeRules>> {
+    let config = match db
+        .find_config_by_key_cached(&get_surcharge_rules_key(merchant_id))
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Failed to fetch surcharge rules")
+        }
+    };
+
+    config
+        .config
+        .parse_struct("SurchargeRules")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid surcharge rules")
+        .map(Some)
+}
+
+/// Calculates the surcharge of the payment with the surcharge rules of the merchant, and stores
+/// it against the payment attempt separately from the amount of the payment.
+///
+/// The surcharge is recalculated every time the payment is confirmed, since the payment method
+/// may differ between attempts.
+#[instrument(skip_all)]
+pub async fn apply_surcharge<F: Clone>(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    let rules = match get_surcharge_rules(db, &merchant_account.merchant_id).await? {
+        Some(rules) => rules,
+        None => return Ok(()),
+    };
+
+    let card_network = match payment_data.payment_method_data {
+        Some(api::PaymentMethodData::Card(ref card)) => card.card_network.clone(),
+        _ => None,
+    };
+    let input = SurchargeInput {
+        payment_method: payment_data
+            .payment_attempt
+            .payment_method
+            .map(ForeignInto::foreign_into),
+        card_network,
+        country: payment_data
+            .address
+            .billing
+            .as_ref()
+            .and_then(|billing| billing.address.as_ref())
+            .and_then(|address| address.country),
+        amount: payment_data.amount.get_amount_as_i64(),
+    };
+
+    let surcharge_amount = calculate_surcharge(&rules, &input).unwrap_or(0);
+    payment_data.payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::SurchargeUpdate { surcharge_amount },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> SurchargeRules {
+        SurchargeRules {
+            rules: vec![
+                SurchargeRule {
+                    payment_method: Some(api_enums::PaymentMethod::Card),
+                    card_network: Some(api_enums::CardNetwork::AmericanExpress),
+                    country: None,
+                    surcharge: Surcharge::Percentage { basis_points: 350 },
+                },
+                SurchargeRule {
+                    payment_method: Some(api_enums::PaymentMethod::Card),
+                    card_network: None,
+                    country: Some(api_enums::CountryCode::US),
+                    surcharge: Surcharge::Fixed { amount: 30 },
+                },
+            ],
+        }
+    }
+
+    fn input() -> SurchargeInput {
+        SurchargeInput {
+            payment_method: Some(api_enums::PaymentMethod::Card),
+            card_network: None,
+            country: Some(api_enums::CountryCode::US),
+            amount: 1000,
+        }
+    }
+
+    #[test]
+    fn test_first_matching_rule_applies() {
+        let input = SurchargeInput {
+            card_network: Some(api_enums::CardNetwork::AmericanExpress),
+            ..input()
+        };
+        assert_eq!(calculate_surcharge(&rules(), &input), Some(35));
+        assert_eq!(calculate_surcharge(&rules(), &input()), Some(30));
+    }
+
+    #[test]
+    fn test_unmatched_payment_is_not_surcharged() {
+        let input = SurchargeInput {
+            country: Some(api_enums::CountryCode::DE),
+            ..input()
+        };
+        assert_eq!(calculate_surcharge(&rules(), &input), None);
+    }
+
+    #[test]
+    fn test_percentage_surcharge_is_rounded_half_up() {
+        let surcharge = Surcharge::Percentage { basis_points: 250 };
+        assert_eq!(surcharge.calculate(1020), 26);
+        assert_eq!(surcharge.calculate(1000), 25);
+    }
+}
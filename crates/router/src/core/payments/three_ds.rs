@@ -0,0 +1,389 @@
+use api_models::enums::ThreeDsTransStatus;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::{operations, payments_core, state_machine, CallConnectorAction, PaymentData};
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        secrets,
+    },
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    services::{self, authentication_connector},
+    types::{
+        self, api,
+        storage::{self, enums as storage_enums},
+    },
+    utils::{self, Encode, OptionExt, ValueExt},
+};
+
+/// The next step of the payment, based on the outcome of the 3DS authentication.
+enum ThreeDsAuthenticationOutcome {
+    /// Authenticated (frictionless or after a challenge), the payment can be authorized
+    Authenticated,
+    /// The cardholder has to complete a challenge with the access control server
+    ChallengeRequired,
+    /// Not authenticated, the payment must not be authorized
+    Failed,
+}
+
+impl From<ThreeDsTransStatus> for ThreeDsAuthenticationOutcome {
+    fn from(trans_status: ThreeDsTransStatus) -> Self {
+        match trans_status {
+            ThreeDsTransStatus::Success | ThreeDsTransStatus::AttemptsProcessingPerformed => {
+                Self::Authenticated
+            }
+            ThreeDsTransStatus::ChallengeRequired
+            | ThreeDsTransStatus::ChallengeRequiredDecoupled => Self::ChallengeRequired,
+            ThreeDsTransStatus::Failure
+            | ThreeDsTransStatus::VerificationNotPerformed
+            | ThreeDsTransStatus::Rejected
+            | ThreeDsTransStatus::InformationOnly => Self::Failed,
+        }
+    }
+}
+
+#[instrument(skip_all)]
+fn validate_status_for_three_ds_authentication(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    utils::when(
+        payment_intent.status != storage_enums::IntentStatus::RequiresCustomerAction
+            || payment_attempt.status != storage_enums::AttemptStatus::AuthenticationPending,
+        || {
+            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+                field_name: "payment.status".to_string(),
+                current_flow: "authenticated".to_string(),
+                current_value: payment_intent.status.to_string(),
+                states: "requires_customer_action".to_string()
+            }))
+        },
+    )?;
+
+    utils::when(
+        payment_attempt.authentication_type != Some(storage_enums::AuthenticationType::ThreeDs),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "3DS authentication was not requested for this payment".to_string()
+            }))
+        },
+    )
+}
+
+/// Continues a payment awaiting 3DS 2.0 authentication with the results of the authentication
+/// performed by the 3DS server, irrespective of the connector the payment is routed through.
+/// The results are only accepted from the merchant server, as a client could otherwise claim to
+/// have authenticated the cardholder.
+///
+/// The artifacts of the authentication are stored against the payment attempt. Successful
+/// authentications (frictionless or after a challenge) complete the authorization with the
+/// connector, a challenge keeps the payment awaiting customer action until the results of the
+/// challenge are submitted, and failed authentications fail the payment.
+#[instrument(skip_all)]
+pub async fn payments_three_ds_authenticate_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PaymentsThreeDsAuthenticateRequest,
+) -> RouterResponse<api::PaymentsResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.merchant_id.clone();
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &req.payment_id,
+            &merchant_id,
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            &merchant_id,
+            &payment_intent.active_attempt_id,
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    validate_status_for_three_ds_authentication(&payment_intent, &payment_attempt)?;
+
+    let three_ds_data = types::ThreeDsData {
+        device_channel: req.device_channel,
+        device_data: req.device_data,
+        message_version: req.message_version,
+        three_ds_server_trans_id: req.three_ds_server_trans_id,
+        ds_trans_id: req.ds_trans_id,
+        acs_trans_id: req.acs_trans_id,
+        trans_status: req.trans_status,
+        trans_status_reason: req.trans_status_reason,
+        eci: req.eci,
+        authentication_value: req.authentication_value,
+        ares: req.ares,
+        rreq: req.rreq,
+    };
+    let (outcome, _, payment_intent) = update_trackers_with_authentication_result(
+        db,
+        payment_attempt,
+        payment_intent,
+        &three_ds_data,
+        storage_scheme,
+    )
+    .await?;
+
+    let payment_id = api::PaymentIdType::PaymentIntentId(payment_intent.payment_id);
+
+    match outcome {
+        ThreeDsAuthenticationOutcome::Authenticated => {
+            return payments_core::<api::CompleteAuthorize, api::PaymentsResponse, _, _, _>(
+                state,
+                merchant_account,
+                operations::payment_complete_authorize::CompleteAuthorize,
+                api::PaymentsRequest {
+                    payment_id: Some(payment_id),
+                    merchant_id: Some(merchant_id),
+                    ..Default::default()
+                },
+                services::AuthFlow::Merchant,
+                CallConnectorAction::Trigger,
+            )
+            .await;
+        }
+        ThreeDsAuthenticationOutcome::ChallengeRequired | ThreeDsAuthenticationOutcome::Failed => {}
+    }
+
+    payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
+        state,
+        merchant_account,
+        operations::PaymentStatus,
+        api::PaymentsRetrieveRequest {
+            resource_id: payment_id,
+            merchant_id: Some(merchant_id),
+            force_sync: false,
+            connector: None,
+            param: None,
+            merchant_connector_details: None,
+        },
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Avoid,
+    )
+    .await
+}
+
+/// Stores the results of the 3DS authentication against the payment attempt, and fails the
+/// payment if the cardholder could not be authenticated.
+async fn update_trackers_with_authentication_result(
+    db: &dyn StorageInterface,
+    payment_attempt: storage::PaymentAttempt,
+    payment_intent: storage::PaymentIntent,
+    three_ds_data: &types::ThreeDsData,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<(
+    ThreeDsAuthenticationOutcome,
+    storage::PaymentAttempt,
+    storage::PaymentIntent,
+)> {
+    let encoded_three_ds_data = Encode::<types::ThreeDsData>::encode_to_value(three_ds_data)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encode 3DS data")?;
+
+    let outcome = ThreeDsAuthenticationOutcome::from(three_ds_data.trans_status);
+    let (attempt_status, error_code, error_message) = match outcome {
+        ThreeDsAuthenticationOutcome::Authenticated => (
+            storage_enums::AttemptStatus::AuthenticationSuccessful,
+            None,
+            None,
+        ),
+        ThreeDsAuthenticationOutcome::ChallengeRequired => (
+            storage_enums::AttemptStatus::AuthenticationPending,
+            None,
+            None,
+        ),
+        ThreeDsAuthenticationOutcome::Failed => (
+            storage_enums::AttemptStatus::AuthenticationFailed,
+            Some(
+                three_ds_data
+                    .trans_status_reason
+                    .clone()
+                    .unwrap_or_else(|| three_ds_data.trans_status.to_string()),
+            ),
+            Some("3DS authentication failed".to_string()),
+        ),
+    };
+
+    logger::info!(
+        "3DS authentication of payment {} resulted in trans_status {}",
+        payment_intent.payment_id,
+        three_ds_data.trans_status
+    );
+
+    state_machine::validate_attempt_status_transition(payment_attempt.status, attempt_status)?;
+    let payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_attempt,
+            storage::PaymentAttemptUpdate::ThreeDsAuthenticationUpdate {
+                status: attempt_status,
+                three_ds_data: encoded_three_ds_data,
+                error_code,
+                error_message,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let payment_intent = match outcome {
+        ThreeDsAuthenticationOutcome::Failed => db
+            .update_payment_intent(
+                payment_intent,
+                storage::PaymentIntentUpdate::PGStatusUpdate {
+                    status: storage_enums::IntentStatus::Failed,
+                },
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?,
+        ThreeDsAuthenticationOutcome::Authenticated
+        | ThreeDsAuthenticationOutcome::ChallengeRequired => payment_intent,
+    };
+
+    Ok((outcome, payment_attempt, payment_intent))
+}
+
+/// Authenticates the cardholder with the standalone 3DS provider of the merchant, if the merchant
+/// has a connector account of type `authentication_processor`, before the payment is authorized.
+///
+/// The results of a successful authentication are stored on the payment attempt, from where they
+/// are passed on to the connector in the authorization request. Returns `false` when the payment
+/// must not be authorized yet, either because the authentication failed, or because the customer
+/// has to complete a challenge with the issuer. The customer is redirected to the challenge through
+/// the start pay URL of the payment, and the results of the challenge are submitted through the
+/// 3DS authenticate endpoint.
+#[instrument(skip_all)]
+pub async fn authenticate_with_external_provider<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<bool> {
+    let payment_attempt = &payment_data.payment_attempt;
+    if payment_attempt.authentication_type != Some(storage_enums::AuthenticationType::ThreeDs)
+        || payment_attempt.payment_method != Some(storage_enums::PaymentMethod::Card)
+        || payment_attempt.three_ds_data.is_some()
+        || payment_data.confirm != Some(true)
+    {
+        return Ok(true);
+    }
+
+    let db = &*state.store;
+    let authentication_mca = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_account.merchant_id,
+            false,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant connector accounts")?
+        .into_iter()
+        .find(|mca| mca.connector_type == storage_enums::ConnectorType::AuthenticationProcessor);
+
+    let authentication_mca = match authentication_mca {
+        Some(mca) => mca,
+        None => return Ok(true),
+    };
+
+    let authentication_connector = authentication_connector::get_authentication_connector_by_name(
+        &authentication_mca.connector_name,
+    )?;
+
+    let auth_type: types::ConnectorAuthType =
+        secrets::decrypt_connector_account_details(state, &authentication_mca)
+            .await?
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while parsing value for ConnectorAuthType")?;
+
+    let browser_info: Option<types::BrowserInformation> = payment_attempt
+        .browser_info
+        .clone()
+        .map(|browser_info| browser_info.parse_value("BrowserInformation"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "browser_info",
+        })?;
+
+    let request = authentication_connector::AuthenticationRequest {
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        amount: payment_data.amount,
+        currency: payment_data.currency.get_currency(),
+        payment_method_data: payment_data
+            .payment_method_data
+            .clone()
+            .get_required_value("payment_method_data")?,
+        browser_info,
+        email: payment_data.email.clone(),
+        return_url: payment_data.payment_intent.return_url.clone(),
+        connector_meta_data: authentication_mca.metadata.clone(),
+    };
+
+    let authentication_response = authentication_connector
+        .authenticate(state, &auth_type, &request)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while authenticating with {}",
+                authentication_connector.id()
+            )
+        })?;
+
+    let (outcome, payment_attempt, payment_intent) = update_trackers_with_authentication_result(
+        db,
+        payment_data.payment_attempt.clone(),
+        payment_data.payment_intent.clone(),
+        &authentication_response.three_ds_data,
+        merchant_account.storage_scheme,
+    )
+    .await?;
+
+    payment_data.payment_attempt = payment_attempt;
+    payment_data.payment_intent = payment_intent;
+
+    // The customer is redirected to the access control server through the start pay URL of the
+    // payment, which renders the challenge form stored as the authentication data
+    if let (ThreeDsAuthenticationOutcome::ChallengeRequired, Some(challenge_form)) =
+        (&outcome, authentication_response.challenge_form)
+    {
+        let authentication_data =
+            Encode::<services::RedirectForm>::encode_to_value(&challenge_form)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to encode the 3DS challenge form")?;
+
+        payment_data.connector_response = db
+            .update_connector_response(
+                payment_data.connector_response.clone(),
+                storage::ConnectorResponseUpdate::ResponseUpdate {
+                    connector_transaction_id: None,
+                    authentication_data: Some(authentication_data),
+                    encoded_data: None,
+                    connector_name: None,
+                },
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to store the 3DS challenge form")?;
+    }
+
+    Ok(matches!(
+        outcome,
+        ThreeDsAuthenticationOutcome::Authenticated
+    ))
+}
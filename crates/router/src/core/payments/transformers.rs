@@ -1,6 +1,6 @@
 use std::{fmt::Debug, marker::PhantomData};
 
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 
 use super::{flows::Feature, PaymentAddress, PaymentData};
@@ -9,14 +9,14 @@ use crate::{
     connector::Paypal,
     core::{
         errors::{self, RouterResponse, RouterResult},
-        payments::{self, helpers},
+        payments::{self, currency_conversion, helpers, split_payments, surcharge},
     },
     routes::AppState,
     services::{self, RedirectForm},
     types::{
         self, api,
         storage::{self, enums, PaymentAttemptExt},
-        transformers::{ForeignInto, ForeignTryFrom},
+        transformers::{ForeignFrom, ForeignInto, ForeignTryFrom},
     },
     utils::{OptionExt, ValueExt},
 };
@@ -36,9 +36,8 @@ where
         From<<T as TryFrom<PaymentAdditionalData<'a, F>>>::Error>,
 {
     let (merchant_connector_account, payment_method, router_data);
-    let db = &*state.store;
     merchant_connector_account = helpers::get_merchant_connector_account(
-        db,
+        state,
         merchant_account.merchant_id.as_str(),
         connector_id,
         payment_data.creds_identifier.to_owned(),
@@ -67,6 +66,7 @@ where
             redirection_data: None,
             mandate_reference: None,
             connector_metadata: None,
+            verification_result: None,
         });
 
     let additional_data = PaymentAdditionalData {
@@ -88,6 +88,8 @@ where
         description: payment_data.payment_intent.description.clone(),
         return_url: payment_data.payment_intent.return_url.clone(),
         payment_method_id: payment_data.payment_attempt.payment_method_id.clone(),
+        connector_raw_response: None,
+        connector_debug_mode: payment_data.payment_intent.connector_debug_mode,
         address: payment_data.address.clone(),
         auth_type: payment_data
             .payment_attempt
@@ -105,6 +107,27 @@ where
     Ok(router_data)
 }
 
+/// Constructs the request of a connector flow from the payment data, for flows that are performed
+/// independent of the flow of the payment operation.
+pub fn construct_flow_request_data<'a, F, T>(
+    state: &'a AppState,
+    connector_id: &str,
+    payment_data: PaymentData<F>,
+) -> RouterResult<T>
+where
+    T: TryFrom<PaymentAdditionalData<'a, F>>,
+    F: Clone,
+    error_stack::Report<errors::ApiErrorResponse>:
+        From<<T as TryFrom<PaymentAdditionalData<'a, F>>>::Error>,
+{
+    Ok(T::try_from(PaymentAdditionalData {
+        router_base_url: state.conf.server.base_url.clone(),
+        connector_name: connector_id.to_string(),
+        payment_data,
+        state,
+    })?)
+}
+
 pub trait ToResponse<Req, D, Op>
 where
     Self: Sized,
@@ -138,6 +161,8 @@ where
             payment_data.payment_attempt,
             payment_data.payment_intent,
             payment_data.refunds,
+            payment_data.funding_sources,
+            payment_data.payment_splits,
             payment_data.payment_method_data,
             customer,
             auth_flow,
@@ -218,6 +243,71 @@ where
     }
 }
 
+fn construct_next_action(
+    server: &Server,
+    payment_attempt: &storage::PaymentAttempt,
+    payment_intent: &storage::PaymentIntent,
+    redirection_data: Option<serde_json::Value>,
+) -> RouterResult<api::NextAction> {
+    if payment_attempt.payment_method_type == Some(enums::PaymentMethodType::UpiIntent) {
+        return Ok(api::NextAction {
+            next_action_type: api::NextActionType::InvokeUpiIntent,
+            redirect_to_url: None,
+            upi_intent_uri: get_upi_intent_uri(redirection_data)?,
+            qr_code_information: None,
+        });
+    }
+
+    if let Some(qr_code_information) =
+        get_qr_code_information(payment_attempt.connector_metadata.as_ref())
+    {
+        return Ok(api::NextAction {
+            next_action_type: api::NextActionType::DisplayQrCode,
+            redirect_to_url: None,
+            upi_intent_uri: None,
+            qr_code_information: Some(qr_code_information),
+        });
+    }
+
+    Ok(api::NextAction {
+        next_action_type: api::NextActionType::RedirectToUrl,
+        redirect_to_url: Some(helpers::create_startpay_url(
+            server,
+            payment_attempt,
+            payment_intent,
+        )),
+        upi_intent_uri: None,
+        qr_code_information: None,
+    })
+}
+
+/// Connector metadata which is not a QR code instruction is not an error, since connectors store
+/// other details in it for other payment methods.
+fn get_qr_code_information(
+    connector_metadata: Option<&serde_json::Value>,
+) -> Option<api::QrCodeInformation> {
+    connector_metadata
+        .cloned()
+        .and_then(|metadata| {
+            serde_json::from_value::<types::QrCodeNextStepsInstruction>(metadata).ok()
+        })
+        .map(|instruction| instruction.qr_code_information)
+}
+
+/// The deep link of the UPI intent flow is returned by the connector as the endpoint of the
+/// redirection.
+fn get_upi_intent_uri(redirection_data: Option<serde_json::Value>) -> RouterResult<Option<String>> {
+    redirection_data
+        .map(|redirection_data| {
+            serde_json::from_value::<RedirectForm>(redirection_data)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the redirection data of the UPI intent")
+                .map(|form| form.endpoint)
+        })
+        .transpose()
+}
+
 #[instrument(skip_all)]
 // try to use router data here so that already validated things , we don't want to repeat the validations.
 // Add internal value not found and external value not found so that we can give 500 / Internal server error for internal value not found
@@ -227,6 +317,8 @@ pub fn payments_to_payments_response<R, Op>(
     payment_attempt: storage::PaymentAttempt,
     payment_intent: storage::PaymentIntent,
     refunds: Vec<storage::Refund>,
+    funding_sources: Vec<storage::FundingSource>,
+    payment_splits: Vec<storage::PaymentSplit>,
     payment_method_data: Option<api::PaymentMethodData>,
     customer: Option<storage::Customer>,
     auth_flow: services::AuthFlow,
@@ -249,6 +341,31 @@ where
     } else {
         Some(refunds.into_iter().map(ForeignInto::foreign_into).collect())
     };
+    let funding_sources_response = if funding_sources.is_empty() {
+        None
+    } else {
+        Some(
+            funding_sources
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+        )
+    };
+    let payment_splits_response = if payment_splits.is_empty() {
+        None
+    } else {
+        Some(
+            payment_splits
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+        )
+    };
+    let order_details = helpers::get_order_details(&payment_intent)?;
+    let amount_breakdown = helpers::get_amount_breakdown(&payment_intent)?;
+    let l2_l3_data = helpers::get_l2_l3_data(&payment_intent)?;
+    let auto_capture_delay = helpers::get_auto_capture_delay(&payment_intent);
+    let settlement_details = currency_conversion::get_settlement_details(&payment_attempt);
 
     Ok(match payment_request {
         Some(_request) => {
@@ -258,17 +375,17 @@ where
                     .map_err(|_| errors::ApiErrorResponse::InternalServerError)?;
                 services::ApplicationResponse::Form(form)
             } else {
-                let mut next_action_response = None;
-                if payment_intent.status == enums::IntentStatus::RequiresCustomerAction {
-                    next_action_response = Some(api::NextAction {
-                        next_action_type: api::NextActionType::RedirectToUrl,
-                        redirect_to_url: Some(helpers::create_startpay_url(
+                let next_action_response =
+                    if payment_intent.status == enums::IntentStatus::RequiresCustomerAction {
+                        Some(construct_next_action(
                             server,
                             &payment_attempt,
                             &payment_intent,
-                        )),
-                    })
-                }
+                            redirection_data,
+                        )?)
+                    } else {
+                        None
+                    };
                 let mut response: api::PaymentsResponse = Default::default();
                 let routed_through = payment_attempt
                     .get_routed_through_connector()
@@ -278,7 +395,7 @@ where
                         .set_payment_id(Some(payment_attempt.payment_id))
                         .set_merchant_id(Some(payment_attempt.merchant_id))
                         .set_status(payment_intent.status.foreign_into())
-                        .set_amount(payment_attempt.amount)
+                        .set_amount(payment_attempt.amount.get_amount_as_i64())
                         .set_amount_capturable(None)
                         .set_amount_received(payment_intent.amount_captured)
                         .set_connector(routed_through)
@@ -304,6 +421,8 @@ where
                         .set_mandate_id(mandate_id)
                         .set_description(payment_intent.description)
                         .set_refunds(refunds_response) // refunds.iter().map(refund_to_refund_response),
+                        .set_funding_sources(funding_sources_response)
+                        .set_split_payments(payment_splits_response)
                         .set_payment_method(
                             payment_attempt
                                 .payment_method
@@ -317,6 +436,32 @@ where
                         .set_payment_token(payment_attempt.payment_token)
                         .set_error_message(payment_attempt.error_message)
                         .set_error_code(payment_attempt.error_code)
+                        .set_unified_code(
+                            payment_attempt
+                                .unified_code
+                                .and_then(|code| code.parse().ok()),
+                        )
+                        .set_unified_message(payment_attempt.unified_message)
+                        .set_connector_raw_response(
+                            payment_attempt.connector_raw_response,
+                            auth_flow == services::AuthFlow::Merchant,
+                        )
+                        .set_avs_result(payment_attempt.avs_result.map(ForeignInto::foreign_into))
+                        .set_cvc_result(payment_attempt.cvc_result.map(ForeignInto::foreign_into))
+                        .set_fraud_check_verdict(
+                            payment_attempt
+                                .fraud_check_verdict
+                                .map(ForeignInto::foreign_into),
+                        )
+                        .set_fraud_check_score(payment_attempt.fraud_check_score)
+                        .set_surcharge_details(payment_attempt.surcharge_amount.map(
+                            |surcharge_amount| api::SurchargeDetailsResponse {
+                                surcharge_amount,
+                                total_amount: payment_attempt.amount.get_amount_as_i64()
+                                    + surcharge_amount,
+                            },
+                        ))
+                        .set_settlement_details(settlement_details)
                         .set_shipping(address.shipping)
                         .set_billing(address.billing)
                         .set_next_action(next_action_response)
@@ -350,6 +495,12 @@ where
                                 .map(ForeignInto::foreign_into),
                         )
                         .set_metadata(payment_intent.metadata)
+                        .set_session_expiry(payment_intent.session_expiry)
+                        .set_auto_capture_delay(auto_capture_delay)
+                        .set_order_details(order_details)
+                        .set_amount_breakdown(amount_breakdown)
+                        .set_l2_l3_data(l2_l3_data)
+                        .set_profile_id(payment_intent.profile_id)
                         .to_owned(),
                 )
             }
@@ -358,7 +509,7 @@ where
             payment_id: Some(payment_attempt.payment_id),
             merchant_id: Some(payment_attempt.merchant_id),
             status: payment_intent.status.foreign_into(),
-            amount: payment_attempt.amount,
+            amount: payment_attempt.amount.get_amount_as_i64(),
             amount_capturable: None,
             amount_received: payment_intent.amount_captured,
             client_secret: payment_intent.client_secret.map(masking::Secret::new),
@@ -367,6 +518,8 @@ where
             customer_id: payment_intent.customer_id,
             description: payment_intent.description,
             refunds: refunds_response,
+            funding_sources: funding_sources_response,
+            split_payments: payment_splits_response,
             payment_method: payment_attempt
                 .payment_method
                 .map(ForeignInto::foreign_into),
@@ -375,6 +528,24 @@ where
                 .map(ForeignInto::foreign_into),
             error_message: payment_attempt.error_message,
             error_code: payment_attempt.error_code,
+            unified_code: payment_attempt
+                .unified_code
+                .and_then(|code| code.parse().ok()),
+            unified_message: payment_attempt.unified_message,
+            connector_raw_response: payment_attempt.connector_raw_response,
+            avs_result: payment_attempt.avs_result.map(ForeignInto::foreign_into),
+            cvc_result: payment_attempt.cvc_result.map(ForeignInto::foreign_into),
+            fraud_check_verdict: payment_attempt
+                .fraud_check_verdict
+                .map(ForeignInto::foreign_into),
+            fraud_check_score: payment_attempt.fraud_check_score,
+            surcharge_details: payment_attempt.surcharge_amount.map(|surcharge_amount| {
+                api::SurchargeDetailsResponse {
+                    surcharge_amount,
+                    total_amount: payment_attempt.amount.get_amount_as_i64() + surcharge_amount,
+                }
+            }),
+            settlement_details,
             payment_method_data: payment_method_data.map(api::PaymentMethodDataResponse::from),
             email: customer
                 .as_ref()
@@ -391,6 +562,12 @@ where
             cancellation_reason: payment_attempt.cancellation_reason,
             payment_token: payment_attempt.payment_token,
             metadata: payment_intent.metadata,
+            session_expiry: payment_intent.session_expiry,
+            auto_capture_delay,
+            order_details,
+            amount_breakdown,
+            l2_l3_data,
+            profile_id: payment_intent.profile_id,
             ..Default::default()
         }),
     })
@@ -408,7 +585,7 @@ impl ForeignTryFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::
             payment_id: Some(pi.payment_id),
             merchant_id: Some(pi.merchant_id),
             status: pi.status.foreign_into(),
-            amount: pi.amount,
+            amount: pi.amount.get_amount_as_i64(),
             amount_capturable: pi.amount_captured,
             client_secret: pi.client_secret.map(|s| s.into()),
             created: Some(pi.created_at),
@@ -419,11 +596,89 @@ impl ForeignTryFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::
             connector: pa.get_routed_through_connector()?,
             payment_method: pa.payment_method.map(ForeignInto::foreign_into),
             payment_method_type: pa.payment_method_type.map(ForeignInto::foreign_into),
+            session_expiry: pi.session_expiry,
+            auto_capture_delay: helpers::get_auto_capture_delay(&pi),
+            error_code: pa.error_code,
+            error_message: pa.error_message,
+            unified_code: pa.unified_code.and_then(|code| code.parse().ok()),
+            unified_message: pa.unified_message,
+            connector_raw_response: pa.connector_raw_response,
+            avs_result: pa.avs_result.map(ForeignInto::foreign_into),
+            cvc_result: pa.cvc_result.map(ForeignInto::foreign_into),
+            fraud_check_verdict: pa.fraud_check_verdict.map(ForeignInto::foreign_into),
+            fraud_check_score: pa.fraud_check_score,
+            profile_id: pi.profile_id,
             ..Default::default()
         })
     }
 }
 
+impl ForeignTryFrom<storage::PaymentAttempt> for api::PaymentAttemptResponse {
+    type Error = error_stack::Report<errors::ParsingError>;
+
+    fn foreign_try_from(payment_attempt: storage::PaymentAttempt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            connector: payment_attempt.get_routed_through_connector()?,
+            attempt_id: payment_attempt.attempt_id,
+            status: payment_attempt.status.foreign_into(),
+            amount: payment_attempt.amount.get_amount_as_i64(),
+            currency: payment_attempt.currency.map(ForeignInto::foreign_into),
+            connector_transaction_id: payment_attempt.connector_transaction_id,
+            payment_method: payment_attempt
+                .payment_method
+                .map(ForeignInto::foreign_into),
+            payment_method_type: payment_attempt
+                .payment_method_type
+                .map(ForeignInto::foreign_into),
+            error_code: payment_attempt.error_code,
+            error_message: payment_attempt.error_message,
+            unified_code: payment_attempt
+                .unified_code
+                .and_then(|code| code.parse().ok()),
+            cancellation_reason: payment_attempt.cancellation_reason,
+            created_at: payment_attempt.created_at,
+            modified_at: payment_attempt.modified_at,
+        })
+    }
+}
+
+impl ForeignFrom<storage::PaymentEvent> for api::PaymentEventResponse {
+    fn foreign_from(payment_event: storage::PaymentEvent) -> Self {
+        Self {
+            event_id: payment_event.event_id,
+            attempt_id: payment_event.attempt_id,
+            operation: payment_event.operation,
+            previous_status: payment_event.previous_intent_status.foreign_into(),
+            status: payment_event.intent_status.foreign_into(),
+            previous_attempt_status: payment_event.previous_attempt_status.foreign_into(),
+            attempt_status: payment_event.attempt_status.foreign_into(),
+            source: payment_event.source.foreign_into(),
+            actor: payment_event.actor,
+            connector_response: payment_event.connector_response,
+            created_at: payment_event.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::ConnectorLog> for api::ConnectorLogResponse {
+    fn foreign_from(connector_log: storage::ConnectorLog) -> Self {
+        Self {
+            log_id: connector_log.log_id,
+            attempt_id: connector_log.attempt_id,
+            connector: connector_log.connector,
+            flow: connector_log.flow,
+            method: connector_log.method,
+            url: connector_log.url,
+            request_body: connector_log.request_body,
+            response_body: connector_log.response_body,
+            status_code: connector_log
+                .status_code
+                .and_then(|status_code| u16::try_from(status_code).ok()),
+            created_at: connector_log.created_at,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct PaymentAdditionalData<'a, F>
 where
@@ -450,22 +705,17 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "browser_info",
             })?;
-
-        let parsed_metadata: Option<api_models::payments::Metadata> = payment_data
-            .payment_intent
-            .metadata
-            .map(|metadata_value| {
-                metadata_value
-                    .parse_value("metadata")
-                    .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                        field_name: "metadata",
-                    })
-                    .attach_printable("unable to parse metadata")
-            })
+        let three_ds_data: Option<types::ThreeDsData> = attempt
+            .three_ds_data
+            .clone()
+            .map(|data| data.parse_value("ThreeDsData"))
             .transpose()
-            .unwrap_or_default();
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid 3DS data in payment attempt")?;
 
-        let order_details = parsed_metadata.and_then(|data| data.order_details);
+        let order_details = helpers::get_order_details(&payment_data.payment_intent)?;
+        let amount_breakdown = helpers::get_amount_breakdown(&payment_data.payment_intent)?;
+        let l2_l3_data = helpers::get_l2_l3_data(&payment_data.payment_intent)?;
         let complete_authorize_url = Some(helpers::create_complete_authorize_url(
             router_base_url,
             attempt,
@@ -482,6 +732,13 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             connector_name,
             payment_data.creds_identifier.as_deref(),
         ));
+        let amount = surcharge::get_connector_amount(&payment_data);
+        let split_payments = split_payments::get_split_payments_data(
+            &additional_data.state.conf.connectors.supported,
+            connector_name,
+            &payment_data.payment_splits,
+            amount.get_amount_as_i64(),
+        )?;
 
         Ok(Self {
             payment_method_data: payment_data
@@ -495,12 +752,14 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
             statement_descriptor: payment_data.payment_intent.statement_descriptor_name,
             capture_method: payment_data.payment_attempt.capture_method,
-            amount: payment_data.amount.into(),
-            currency: payment_data.currency,
+            amount,
+            currency: payment_data.currency.get_currency(),
             browser_info,
             email: payment_data.email,
             payment_experience: payment_data.payment_attempt.payment_experience,
             order_details,
+            amount_breakdown,
+            l2_l3_data,
             session_token: None,
             enrolled_for_3ds: true,
             related_transaction_id: None,
@@ -508,6 +767,15 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             router_return_url,
             webhook_url,
             complete_authorize_url,
+            three_ds_data,
+            stored_credential: helpers::get_stored_credential_indicator(
+                payment_data.mandate_id.as_ref(),
+                payment_data.setup_mandate.as_ref(),
+                payment_data.payment_intent.setup_future_usage,
+            ),
+            network_token: payment_data.network_token,
+            apple_pay_predecrypt_data: None,
+            split_payments,
         })
     }
 }
@@ -562,14 +830,14 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
         let amount_to_capture: i64 = payment_data
             .payment_attempt
             .amount_to_capture
-            .map_or(payment_data.amount.into(), |capture_amount| capture_amount);
+            .unwrap_or_else(|| surcharge::get_connector_amount(&payment_data).get_amount_as_i64());
         Ok(Self {
             amount_to_capture,
-            currency: payment_data.currency,
+            currency: payment_data.currency.get_currency(),
             connector_transaction_id: connectors
                 .connector_transaction_id(payment_data.payment_attempt.clone())?
                 .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)?,
-            payment_amount: payment_data.amount.into(),
+            payment_amount: surcharge::get_connector_amount(&payment_data).get_amount_as_i64(),
             connector_meta: payment_data.payment_attempt.connector_metadata,
         })
     }
@@ -590,12 +858,13 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
             _ => Err(errors::ApiErrorResponse::ResourceIdNotFound)?,
         };
         Ok(Self {
-            amount: Some(payment_data.amount.into()),
-            currency: Some(payment_data.currency),
+            amount: Some(surcharge::get_connector_amount(&payment_data).get_amount_as_i64()),
+            currency: Some(payment_data.currency.get_currency()),
             connector_transaction_id: connectors
                 .connector_transaction_id(payment_data.payment_attempt.clone())?
                 .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)?,
             cancellation_reason: payment_data.payment_attempt.cancellation_reason,
+            cancellation_metadata: payment_data.payment_attempt.cancellation_metadata,
             connector_meta: payment_data.payment_attempt.connector_metadata,
         })
     }
@@ -606,29 +875,17 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsSessionD
 
     fn try_from(additional_data: PaymentAdditionalData<'_, F>) -> Result<Self, Self::Error> {
         let payment_data = additional_data.payment_data;
-        let parsed_metadata: Option<api_models::payments::Metadata> = payment_data
-            .payment_intent
-            .metadata
-            .map(|metadata_value| {
-                metadata_value
-                    .parse_value("metadata")
-                    .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                        field_name: "metadata",
-                    })
-                    .attach_printable("unable to parse metadata")
-            })
-            .transpose()
-            .unwrap_or_default();
-
-        let order_details = parsed_metadata.and_then(|data| data.order_details);
+        let order_details = helpers::get_order_details(&payment_data.payment_intent)?;
+        let amount_breakdown = helpers::get_amount_breakdown(&payment_data.payment_intent)?;
 
         Ok(Self {
-            amount: payment_data.amount.into(),
-            currency: payment_data.currency,
+            amount: payment_data.amount,
+            currency: payment_data.currency.get_currency(),
             country: payment_data.address.billing.and_then(|billing_address| {
                 billing_address.address.and_then(|address| address.country)
             }),
             order_details,
+            amount_breakdown,
         })
     }
 }
@@ -639,7 +896,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::VerifyRequestDat
     fn try_from(additional_data: PaymentAdditionalData<'_, F>) -> Result<Self, Self::Error> {
         let payment_data = additional_data.payment_data;
         Ok(Self {
-            currency: payment_data.currency,
+            currency: payment_data.currency.get_currency(),
             confirm: true,
             payment_method_data: payment_data
                 .payment_method_data
@@ -658,6 +915,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::CompleteAuthoriz
 
     fn try_from(additional_data: PaymentAdditionalData<'_, F>) -> Result<Self, Self::Error> {
         let payment_data = additional_data.payment_data;
+        let amount = surcharge::get_connector_amount(&payment_data);
         let browser_info: Option<types::BrowserInformation> = payment_data
             .payment_attempt
             .browser_info
@@ -666,6 +924,13 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::CompleteAuthoriz
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "browser_info",
             })?;
+        let three_ds_data: Option<types::ThreeDsData> = payment_data
+            .payment_attempt
+            .three_ds_data
+            .map(|data| data.parse_value("ThreeDsData"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid 3DS data in payment attempt")?;
         Ok(Self {
             setup_future_usage: payment_data.payment_intent.setup_future_usage,
             mandate_id: payment_data.mandate_id.clone(),
@@ -674,13 +939,14 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::CompleteAuthoriz
             confirm: payment_data.payment_attempt.confirm,
             statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
             capture_method: payment_data.payment_attempt.capture_method,
-            amount: payment_data.amount.into(),
-            currency: payment_data.currency,
+            amount,
+            currency: payment_data.currency.get_currency(),
             browser_info,
             email: payment_data.email,
             payment_method_data: payment_data.payment_method_data,
             connector_transaction_id: payment_data.connector_response.connector_transaction_id,
             connector_meta: payment_data.payment_attempt.connector_metadata,
+            three_ds_data,
         })
     }
 }
@@ -0,0 +1,111 @@
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use super::{helpers, PaymentData};
+use crate::{
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    types::{self, storage::enums as storage_enums},
+    utils::StringExt,
+};
+
+const VERIFICATION_FAILED_CANCELLATION_REASON: &str = "verification_failed";
+
+/// Per merchant rules for voiding authorizations based on the results of the verification of the
+/// billing address (AVS) and the card security code (CVC) by the issuer.
+///
+/// This is stored in the configs table against the key returned by
+/// [`get_verification_rules_key`].
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct VerificationRules {
+    /// AVS results for which the authorization of a payment is voided
+    pub void_on_avs_results: Vec<storage_enums::AvsResult>,
+    /// CVC results for which the authorization of a payment is voided
+    pub void_on_cvc_results: Vec<storage_enums::CvcResult>,
+}
+
+impl VerificationRules {
+    fn should_void(&self, verification_result: &types::VerificationResult) -> bool {
+        verification_result.avs_result.map_or(false, |avs_result| {
+            self.void_on_avs_results.contains(&avs_result)
+        }) || verification_result.cvc_result.map_or(false, |cvc_result| {
+            self.void_on_cvc_results.contains(&cvc_result)
+        })
+    }
+}
+
+pub fn get_verification_rules_key(merchant_id: &str) -> String {
+    format!("verification_rules_{merchant_id}")
+}
+
+#[instrument(skip_all)]
+pub async fn get_verification_rules(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<VerificationRules>> {
+    let config = match db
+        .find_config_by_key_cached(&get_verification_rules_key(merchant_id))
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Failed to fetch verification rules")
+        }
+    };
+
+    config
+        .config
+        .parse_struct("VerificationRules")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid verification rules")
+        .map(Some)
+}
+
+/// Voids the authorization of a payment whose verification results are rejected by the
+/// verification rules of the merchant, in which case the payment takes the status of the void.
+///
+/// Failures to void the authorization are logged and the payment is left as authorized, to be
+/// voided by the merchant.
+#[instrument(skip_all)]
+pub async fn apply_verification_rules<F: Clone, Req>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+    verification_rules: &VerificationRules,
+    mut router_data: types::RouterData<F, Req, types::PaymentsResponseData>,
+) -> RouterResult<types::RouterData<F, Req, types::PaymentsResponseData>> {
+    if router_data.status != storage_enums::AttemptStatus::Authorized {
+        return Ok(router_data);
+    }
+
+    let should_void = matches!(
+        &router_data.response,
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            verification_result: Some(verification_result),
+            ..
+        }) if verification_rules.should_void(verification_result)
+    );
+    if !should_void {
+        return Ok(router_data);
+    }
+
+    if !helpers::void_authorization(
+        state,
+        payment_data,
+        &mut router_data,
+        VERIFICATION_FAILED_CANCELLATION_REASON,
+    )
+    .await?
+    {
+        logger::error!(
+            payment_id = %router_data.payment_id,
+            "Failed to void the authorization rejected by the verification rules"
+        );
+    }
+
+    Ok(router_data)
+}
@@ -0,0 +1,491 @@
+pub mod routing;
+
+use common_utils::{date_time, pii};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use super::{card_vault, ledger, payments, utils as core_utils, webhooks};
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::AppState,
+    services::{self, ApplicationResponse},
+    types::{
+        self, api,
+        storage::{self, enums as storage_enums},
+        transformers::{ForeignFrom, ForeignInto, ForeignTryInto},
+    },
+    utils::{self, Encode, OptionExt, StringExt},
+};
+
+fn validate_payout_request(req: &api::PayoutCreateRequest) -> RouterResult<()> {
+    utils::when(req.amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than 0".to_string(),
+        }))
+    })?;
+    utils::when(
+        req.payout_method_data
+            .as_ref()
+            .map_or(false, |data| data.get_payout_type() != req.payout_type),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "payout_method_data must be of the same type as payout_type".to_string(),
+            }))
+        },
+    )
+}
+
+/// The last four digits of the card number or bank account number, for identifying a stored
+/// payout method without decrypting it.
+fn get_last_four(payout_method_data: &api::PayoutMethodData) -> String {
+    let number = match payout_method_data {
+        api::PayoutMethodData::Card(card) => card.card_number.peek(),
+        api::PayoutMethodData::Bank(api::BankPayout::Ach(bank)) => bank.bank_account_number.peek(),
+        api::PayoutMethodData::Bank(api::BankPayout::Sepa(bank)) => bank.iban.peek(),
+        api::PayoutMethodData::Bank(api::BankPayout::Bacs(bank)) => bank.bank_account_number.peek(),
+    };
+    number
+        .get(number.len().saturating_sub(4)..)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Encrypts the details of the card or bank account with the data key of the card vault and
+/// stores them, so that they can be reused for later payouts.
+async fn store_payout_method(
+    state: &AppState,
+    merchant_id: &str,
+    customer_id: Option<String>,
+    payout_method_data: &api::PayoutMethodData,
+) -> RouterResult<storage::PayoutMethod> {
+    let data = Encode::<api::PayoutMethodData>::encode_to_string_of_json(payout_method_data)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode payout method data")?;
+    let (key_id, encrypted_payout_method_data) =
+        card_vault::encrypt_with_active_data_key(state, &data).await?;
+
+    state
+        .store
+        .insert_payout_method(storage::PayoutMethodNew {
+            payout_method_id: utils::generate_id(consts::ID_LENGTH, "pom"),
+            merchant_id: merchant_id.to_string(),
+            customer_id,
+            payout_type: payout_method_data.get_payout_type().foreign_into(),
+            key_id,
+            encrypted_payout_method_data,
+            last_four: get_last_four(payout_method_data),
+            created_at: date_time::now(),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout method")
+}
+
+async fn get_payout_method_data(
+    state: &AppState,
+    payout_method: storage::PayoutMethod,
+) -> RouterResult<api::PayoutMethodData> {
+    card_vault::decrypt_with_data_key(
+        state,
+        &payout_method.key_id,
+        payout_method.encrypted_payout_method_data,
+    )
+    .await?
+    .parse_struct("PayoutMethodData")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse payout method data")
+}
+
+async fn find_payout_method(
+    state: &AppState,
+    merchant_id: &str,
+    payout_method_id: &str,
+) -> RouterResult<storage::PayoutMethod> {
+    state
+        .store
+        .find_payout_method_by_merchant_id_payout_method_id(merchant_id, payout_method_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })
+}
+
+async fn get_customer_email(
+    state: &AppState,
+    merchant_id: &str,
+    customer_id: Option<&String>,
+) -> RouterResult<Option<Secret<String, pii::Email>>> {
+    let customer = match customer_id {
+        Some(customer_id) => state
+            .store
+            .find_customer_optional_by_customer_id_merchant_id(customer_id, merchant_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the customer of the payout")?,
+        None => None,
+    };
+
+    Ok(customer.and_then(|customer| customer.email))
+}
+
+#[instrument(skip_all)]
+async fn call_connector_payout<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout: &storage::Payout,
+    payout_method_data: api::PayoutMethodData,
+    email: Option<Secret<String, pii::Email>>,
+) -> RouterResult<types::PayoutsRouterData<F>>
+where
+    F: Clone + Send + Sync + 'static,
+    dyn api::Connector:
+        services::ConnectorIntegration<F, types::PayoutsData, types::PayoutsResponseData>,
+{
+    let connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &payout.connector,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+    let router_data = core_utils::construct_payout_router_data::<F>(
+        state,
+        merchant_account,
+        payout,
+        payout_method_data,
+        email,
+    )
+    .await?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        F,
+        types::PayoutsData,
+        types::PayoutsResponseData,
+    > = connector.connector.get_connector_integration();
+    services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while processing the payout with the connector")
+}
+
+fn get_connector_response(
+    connector: &str,
+    response: Result<types::PayoutsResponseData, types::ErrorResponse>,
+) -> RouterResult<types::PayoutsResponseData> {
+    response.map_err(|error| {
+        report!(errors::ApiErrorResponse::ExternalConnectorError {
+            code: error.code,
+            message: error.message,
+            connector: connector.to_string(),
+            status_code: error.status_code,
+            reason: error.reason,
+        })
+    })
+}
+
+/// Notifies the merchant of payouts which have reached a final status. Failures are logged, since
+/// the payout itself has already been updated.
+pub async fn trigger_payout_webhook(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout: storage::Payout,
+) {
+    let event_type: storage_enums::EventType = match payout.status.foreign_try_into() {
+        Ok(event_type) => event_type,
+        Err(_) => return,
+    };
+
+    let payout_id = payout.payout_id.clone();
+    let result = webhooks::create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+        state.clone(),
+        merchant_account,
+        event_type,
+        storage_enums::EventClass::Payouts,
+        None,
+        payout_id,
+        storage_enums::EventObjectType::PayoutDetails,
+        api::OutgoingWebhookContent::PayoutDetails(Box::new(api::PayoutResponse::foreign_from(
+            payout,
+        ))),
+    )
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to trigger payout webhook");
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn create_payout(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PayoutCreateRequest,
+) -> RouterResponse<api::PayoutResponse> {
+    let db = &*state.store;
+    validate_payout_request(&req)?;
+    let payout_id = core_utils::get_or_generate_id("payout_id", &req.payout_id, "po")?;
+
+    let (payout_method_data, stored_payout_method) = match req.payout_method_data {
+        Some(payout_method_data) => (payout_method_data, None),
+        None => {
+            let payout_method_id = req
+                .payout_method_id
+                .get_required_value("payout_method_id")?;
+            let payout_method =
+                find_payout_method(state, &merchant_account.merchant_id, &payout_method_id).await?;
+            utils::when(
+                payout_method.payout_type
+                    != storage_enums::PayoutType::foreign_from(req.payout_type),
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: "payout_method_id must be of the same type as payout_type"
+                            .to_string(),
+                    }))
+                },
+            )?;
+            (
+                get_payout_method_data(state, payout_method.clone()).await?,
+                Some(payout_method),
+            )
+        }
+    };
+
+    let routing_input =
+        routing::get_routing_input(db, req.amount, req.currency, &payout_method_data).await?;
+    let routing_config =
+        routing::get_payout_routing_config(db, &merchant_account.merchant_id).await?;
+    let mut connectors = routing::get_eligible_connectors(
+        state,
+        &merchant_account.merchant_id,
+        routing_config.as_ref(),
+        req.connector,
+        &routing_input,
+    )
+    .await?
+    .into_iter();
+    let routing_config = routing_config.unwrap_or_default();
+    let connector = connectors
+        .next()
+        .get_required_value("connector")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Payout routing did not return any connector")?;
+
+    // The payout method is stored only once the payout is known to be eligible for a connector
+    let payout_method = match stored_payout_method {
+        Some(payout_method) => payout_method,
+        None => {
+            store_payout_method(
+                state,
+                &merchant_account.merchant_id,
+                req.customer_id.clone(),
+                &payout_method_data,
+            )
+            .await?
+        }
+    };
+
+    let email = get_customer_email(
+        state,
+        &merchant_account.merchant_id,
+        req.customer_id.as_ref(),
+    )
+    .await?;
+
+    let now = date_time::now();
+    let mut payout = db
+        .insert_payout(storage::PayoutNew {
+            payout_id: payout_id.clone(),
+            merchant_id: merchant_account.merchant_id.clone(),
+            customer_id: req.customer_id,
+            connector,
+            payout_type: req.payout_type.foreign_into(),
+            payout_method_id: payout_method.payout_method_id,
+            amount: req.amount,
+            currency: req.currency.foreign_into(),
+            status: storage_enums::PayoutStatus::Pending,
+            description: req.description,
+            metadata: req.metadata,
+            created_at: Some(now),
+            modified_at: Some(now),
+        })
+        .await
+        .map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout { payout_id })
+        })?;
+
+    // A payout which fails at a connector is made through the next eligible connector, if the
+    // failure is one the merchant falls back on
+    let response = loop {
+        let router_data = call_connector_payout::<api::PoFulfill>(
+            state,
+            &merchant_account,
+            &payout,
+            payout_method_data.clone(),
+            email.clone(),
+        )
+        .await?;
+
+        let fallback_connector = match router_data.response {
+            Err(ref error) if routing_config.should_fallback(error) => connectors.next(),
+            _ => None,
+        };
+        match fallback_connector {
+            Some(connector) => {
+                logger::info!(
+                    "Falling back from {} to {connector} for payout {}",
+                    payout.connector,
+                    payout.payout_id
+                );
+                payout = db
+                    .update_payout(payout, storage::PayoutUpdate::ConnectorUpdate { connector })
+                    .await
+                    .map_err(|error| {
+                        error.to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)
+                    })?;
+            }
+            None => break router_data.response,
+        }
+    };
+
+    let payout_update = match response {
+        Ok(response) => storage::PayoutUpdate::ConnectorResponseUpdate {
+            status: response.status,
+            connector_payout_id: response.connector_payout_id,
+            error_code: None,
+            error_message: None,
+        },
+        Err(error) => storage::PayoutUpdate::ConnectorResponseUpdate {
+            status: storage_enums::PayoutStatus::Failed,
+            connector_payout_id: None,
+            error_code: Some(error.code),
+            error_message: Some(error.message),
+        },
+    };
+    let payout = db
+        .update_payout(payout, payout_update)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PayoutNotFound))?;
+
+    ledger::record_payout(db, &payout).await;
+    trigger_payout_webhook(state, merchant_account, payout.clone()).await;
+
+    Ok(ApplicationResponse::Json(
+        api::PayoutResponse::foreign_from(payout),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_payout(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PayoutRetrieveRequest,
+) -> RouterResponse<api::PayoutResponse> {
+    let db = &*state.store;
+    let payout = db
+        .find_payout_by_merchant_id_payout_id(&merchant_account.merchant_id, &req.payout_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PayoutNotFound))?;
+
+    // Only payouts which have been submitted to the connector can change status there
+    let payout = if req.force_sync && payout.status == storage_enums::PayoutStatus::Initiated {
+        let payout_method = find_payout_method(
+            state,
+            &merchant_account.merchant_id,
+            &payout.payout_method_id,
+        )
+        .await?;
+        let payout_method_data = get_payout_method_data(state, payout_method).await?;
+        let router_data = call_connector_payout::<api::PoSync>(
+            state,
+            &merchant_account,
+            &payout,
+            payout_method_data,
+            None,
+        )
+        .await?;
+        let response = get_connector_response(&payout.connector, router_data.response)?;
+
+        if response.status == payout.status {
+            payout
+        } else {
+            let payout = db
+                .update_payout(
+                    payout,
+                    storage::PayoutUpdate::StatusUpdate {
+                        status: response.status,
+                    },
+                )
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)
+                })?;
+            ledger::record_payout(db, &payout).await;
+            trigger_payout_webhook(state, merchant_account, payout.clone()).await;
+            payout
+        }
+    } else {
+        payout
+    };
+
+    Ok(ApplicationResponse::Json(
+        api::PayoutResponse::foreign_from(payout),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn cancel_payout(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PayoutId,
+) -> RouterResponse<api::PayoutResponse> {
+    let db = &*state.store;
+    let payout = db
+        .find_payout_by_merchant_id_payout_id(&merchant_account.merchant_id, &req.payout_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PayoutNotFound))?;
+
+    let status = match payout.status {
+        // Payouts which were never submitted to the connector only need to be cancelled on our end
+        storage_enums::PayoutStatus::Pending => storage_enums::PayoutStatus::Cancelled,
+        storage_enums::PayoutStatus::Initiated => {
+            let payout_method = find_payout_method(
+                state,
+                &merchant_account.merchant_id,
+                &payout.payout_method_id,
+            )
+            .await?;
+            let payout_method_data = get_payout_method_data(state, payout_method).await?;
+            let router_data = call_connector_payout::<api::PoCancel>(
+                state,
+                &merchant_account,
+                &payout,
+                payout_method_data,
+                None,
+            )
+            .await?;
+            get_connector_response(&payout.connector, router_data.response)?.status
+        }
+        status => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("You cannot cancel this payout because it has status {status}"),
+        })?,
+    };
+
+    let payout = db
+        .update_payout(payout, storage::PayoutUpdate::StatusUpdate { status })
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PayoutNotFound))?;
+
+    trigger_payout_webhook(state, merchant_account, payout.clone()).await;
+
+    Ok(ApplicationResponse::Json(
+        api::PayoutResponse::foreign_from(payout),
+    ))
+}
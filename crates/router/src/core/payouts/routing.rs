@@ -0,0 +1,324 @@
+use std::{collections::HashSet, str::FromStr};
+
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings::SupportedConnectors,
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{self, api, api::enums as api_enums},
+    utils::StringExt,
+};
+
+const CARD_BIN_LENGTH: usize = 6;
+
+/// Per merchant configuration for routing payouts across payout connectors.
+///
+/// This is stored in the configs table against the key returned by
+/// [`get_payout_routing_config_key`].
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct PayoutRoutingConfig {
+    /// The payout connectors in the order of preference, along with the payouts each of them is
+    /// eligible for
+    pub connectors: Vec<PayoutConnectorEligibility>,
+    /// Connector error codes for which the payout is made through the next eligible connector
+    pub fallback_error_codes: HashSet<String>,
+}
+
+/// The payouts a connector is eligible for. A restriction which is not configured does not
+/// exclude any payouts.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutConnectorEligibility {
+    pub connector: api_enums::Connector,
+    pub payout_types: Option<Vec<api_enums::PayoutType>>,
+    pub currencies: Option<Vec<api_enums::Currency>>,
+    /// Minimum amount of the payout, in the lowest denomination of the currency
+    pub min_amount: Option<i64>,
+    /// Maximum amount of the payout, in the lowest denomination of the currency
+    pub max_amount: Option<i64>,
+    /// The card networks the connector can push funds to, for card payouts
+    pub card_networks: Option<Vec<api_enums::CardNetwork>>,
+}
+
+/// The attributes of a payout which decide the connectors it can be made through.
+#[derive(Clone, Debug)]
+pub struct PayoutRoutingInput {
+    pub amount: i64,
+    pub currency: api_enums::Currency,
+    pub payout_type: api_enums::PayoutType,
+    /// The network of the card, for card payouts whose network could be identified
+    pub card_network: Option<api_enums::CardNetwork>,
+}
+
+pub fn get_payout_routing_config_key(merchant_id: &str) -> String {
+    format!("payout_routing_{merchant_id}")
+}
+
+impl PayoutRoutingConfig {
+    /// A connector error which is returned for a gateway failure or is configured by the merchant
+    /// causes the payout to be made through the next eligible connector.
+    pub fn should_fallback(&self, error_response: &types::ErrorResponse) -> bool {
+        error_response.status_code >= http::StatusCode::INTERNAL_SERVER_ERROR.as_u16()
+            || self.fallback_error_codes.contains(&error_response.code)
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn get_payout_routing_config(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<PayoutRoutingConfig>> {
+    let config = match db
+        .find_config_by_key_cached(&get_payout_routing_config_key(merchant_id))
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Failed to fetch payout routing config")
+        }
+    };
+
+    config
+        .config
+        .parse_struct("PayoutRoutingConfig")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid payout routing config")
+        .map(Some)
+}
+
+/// Identifies the network of the card from its BIN, since only some networks support pushing
+/// funds to cards.
+#[instrument(skip_all)]
+pub async fn get_routing_input(
+    db: &dyn StorageInterface,
+    amount: i64,
+    currency: api_enums::Currency,
+    payout_method_data: &api::PayoutMethodData,
+) -> RouterResult<PayoutRoutingInput> {
+    let card_network = match payout_method_data {
+        api::PayoutMethodData::Card(card) => match card.card_number.peek().get(..CARD_BIN_LENGTH) {
+            Some(card_bin) => db
+                .get_card_info(card_bin)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to retrieve card information")?
+                .and_then(|card_info| card_info.card_network)
+                .and_then(|card_network| api_enums::CardNetwork::from_str(&card_network).ok()),
+            None => None,
+        },
+        api::PayoutMethodData::Bank(_) => None,
+    };
+
+    Ok(PayoutRoutingInput {
+        amount,
+        currency,
+        payout_type: payout_method_data.get_payout_type(),
+        card_network,
+    })
+}
+
+/// A card payout is eligible for a connector restricted to some card networks only if the network
+/// of the card is known and is one of them.
+pub fn is_connector_eligible(
+    eligibility: &PayoutConnectorEligibility,
+    input: &PayoutRoutingInput,
+) -> bool {
+    is_allowed(&eligibility.payout_types, &input.payout_type)
+        && is_allowed(&eligibility.currencies, &input.currency)
+        && eligibility
+            .min_amount
+            .map_or(true, |min_amount| input.amount >= min_amount)
+        && eligibility
+            .max_amount
+            .map_or(true, |max_amount| input.amount <= max_amount)
+        && (input.payout_type != api_enums::PayoutType::Card
+            || eligibility.card_networks.as_ref().map_or(true, |networks| {
+                input
+                    .card_network
+                    .as_ref()
+                    .map_or(false, |network| networks.contains(network))
+            }))
+}
+
+fn is_allowed<T: PartialEq>(values: &Option<Vec<T>>, value: &T) -> bool {
+    values
+        .as_ref()
+        .map_or(true, |values| values.contains(value))
+}
+
+fn is_payouts_supported(supported_connectors: &SupportedConnectors, connector: &str) -> bool {
+    supported_connectors
+        .payouts
+        .iter()
+        .any(|supported_connector| supported_connector == connector)
+}
+
+/// Returns the connectors the payout can be made through, in the order they are to be tried.
+///
+/// A connector chosen in the request is used as is, after checking that it is eligible for the
+/// payout. Otherwise the eligible connectors of the payout routing config of the merchant are
+/// used, skipping the ones whose circuit is open.
+#[instrument(skip_all)]
+pub async fn get_eligible_connectors(
+    state: &AppState,
+    merchant_id: &str,
+    routing_config: Option<&PayoutRoutingConfig>,
+    straight_through: Option<api_enums::Connector>,
+    input: &PayoutRoutingInput,
+) -> RouterResult<Vec<String>> {
+    let supported_connectors = &state.conf.connectors.supported;
+
+    if let Some(connector) = straight_through {
+        let connector_name = connector.to_string();
+        if !is_payouts_supported(supported_connectors, &connector_name) {
+            return Err(report!(errors::ApiErrorResponse::FlowNotSupported {
+                flow: "Payouts".to_string(),
+                connector: connector_name,
+            }));
+        }
+
+        let is_eligible = routing_config
+            .and_then(|config| {
+                config
+                    .connectors
+                    .iter()
+                    .find(|eligibility| eligibility.connector == connector)
+            })
+            .map_or(true, |eligibility| {
+                is_connector_eligible(eligibility, input)
+            });
+        if !is_eligible {
+            return Err(report!(errors::ApiErrorResponse::PayoutNotEligible {
+                message: format!("{connector_name} is not eligible for this payout"),
+            }));
+        }
+
+        return Ok(vec![connector_name]);
+    }
+
+    let routing_config = routing_config.ok_or_else(|| {
+        report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "connector"
+        })
+    })?;
+
+    let mut connectors = Vec::new();
+    for eligibility in routing_config.connectors.iter() {
+        let connector_name = eligibility.connector.to_string();
+        if !is_payouts_supported(supported_connectors, &connector_name)
+            || !is_connector_eligible(eligibility, input)
+            || connectors.contains(&connector_name)
+        {
+            continue;
+        }
+
+        if services::api::circuit_breaker::is_circuit_open(state, merchant_id, &connector_name)
+            .await
+        {
+            logger::info!("Routing payout around {connector_name}");
+            continue;
+        }
+
+        connectors.push(connector_name);
+    }
+
+    if connectors.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::PayoutNotEligible {
+            message: "No payout connector is eligible for this payout".to_string(),
+        }));
+    }
+
+    Ok(connectors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eligibility() -> PayoutConnectorEligibility {
+        PayoutConnectorEligibility {
+            connector: api_enums::Connector::Adyen,
+            payout_types: Some(vec![api_enums::PayoutType::Card]),
+            currencies: Some(vec![api_enums::Currency::EUR, api_enums::Currency::GBP]),
+            min_amount: Some(100),
+            max_amount: Some(500000),
+            card_networks: Some(vec![
+                api_enums::CardNetwork::Visa,
+                api_enums::CardNetwork::Mastercard,
+            ]),
+        }
+    }
+
+    fn input() -> PayoutRoutingInput {
+        PayoutRoutingInput {
+            amount: 1000,
+            currency: api_enums::Currency::EUR,
+            payout_type: api_enums::PayoutType::Card,
+            card_network: Some(api_enums::CardNetwork::Visa),
+        }
+    }
+
+    #[test]
+    fn test_payout_within_restrictions_is_eligible() {
+        assert!(is_connector_eligible(&eligibility(), &input()));
+    }
+
+    #[test]
+    fn test_amount_limits_are_enforced() {
+        let below_limit = PayoutRoutingInput {
+            amount: 99,
+            ..input()
+        };
+        let above_limit = PayoutRoutingInput {
+            amount: 500001,
+            ..input()
+        };
+
+        assert!(!is_connector_eligible(&eligibility(), &below_limit));
+        assert!(!is_connector_eligible(&eligibility(), &above_limit));
+    }
+
+    #[test]
+    fn test_card_network_must_support_push() {
+        let unsupported_network = PayoutRoutingInput {
+            card_network: Some(api_enums::CardNetwork::AmericanExpress),
+            ..input()
+        };
+        let unknown_network = PayoutRoutingInput {
+            card_network: None,
+            ..input()
+        };
+
+        assert!(!is_connector_eligible(&eligibility(), &unsupported_network));
+        assert!(!is_connector_eligible(&eligibility(), &unknown_network));
+    }
+
+    #[test]
+    fn test_unrestricted_connector_is_eligible() {
+        let eligibility = PayoutConnectorEligibility {
+            connector: api_enums::Connector::Adyen,
+            payout_types: None,
+            currencies: None,
+            min_amount: None,
+            max_amount: None,
+            card_networks: None,
+        };
+        let bank_payout = PayoutRoutingInput {
+            currency: api_enums::Currency::USD,
+            payout_type: api_enums::PayoutType::Bank,
+            card_network: None,
+            ..input()
+        };
+
+        assert!(is_connector_eligible(&eligibility, &input()));
+        assert!(is_connector_eligible(&eligibility, &bank_payout));
+    }
+}
@@ -0,0 +1,165 @@
+//! Requests are rate limited with token buckets kept in Redis, so that the limits are shared by all
+//! instances of the application. Requests made with an existing API key take tokens from the bucket
+//! of the API key, and the other requests from the stricter bucket of the IP address they were made
+//! from, so that unknown API keys cannot be used to get fresh buckets.
+//! The limit of the API keys of a merchant can be overridden with the `rate_limit_{merchant_id}`
+//! config. Requests are allowed if their limit cannot be checked.
+
+use common_utils::date_time;
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings::RateLimitBucket, core::api_keys, logger, routes::AppState, types::storage,
+};
+
+/// The state of the bucket of a request, after a token was taken from it for the request.
+#[derive(Clone, Copy, Debug)]
+pub struct RateLimitStatus {
+    pub limit: u64,
+    pub remaining: u64,
+    /// Seconds after which the bucket is full again
+    pub reset_after: u64,
+    /// Seconds after which another request can be made, if the request was rate limited
+    pub retry_after: Option<u64>,
+}
+
+impl RateLimitStatus {
+    fn new(bucket: RateLimitBucket, remaining: Option<u64>) -> Self {
+        let remaining_tokens = remaining.unwrap_or(0);
+        let missing_tokens = bucket.capacity.saturating_sub(remaining_tokens);
+
+        Self {
+            limit: bucket.capacity,
+            remaining: remaining_tokens,
+            reset_after: (missing_tokens + bucket.refill_rate - 1) / bucket.refill_rate,
+            // Buckets are refilled with at least one token every second
+            retry_after: remaining.is_none().then_some(1),
+        }
+    }
+
+    pub fn is_limited(&self) -> bool {
+        self.retry_after.is_some()
+    }
+}
+
+pub fn get_rate_limit_config_key(merchant_id: &str) -> String {
+    format!("rate_limit_{merchant_id}")
+}
+
+async fn hash_api_key(state: &AppState, api_key: &str) -> Option<String> {
+    let hash_key = api_keys::get_hash_key(
+        &state.conf.api_keys,
+        #[cfg(feature = "kms")]
+        &state.conf.kms,
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to fetch the API key hashing key"))
+    .ok()?;
+    let hashed_api_key: storage::HashedApiKey = api_keys::PlaintextApiKey::from(api_key)
+        .keyed_hash(hash_key.peek())
+        .into();
+
+    Some(hashed_api_key.into_inner())
+}
+
+/// The bucket of an API key is the one configured for its merchant, if any. Returns `None` for API
+/// keys which are not found or have expired, whose requests are limited by their IP address.
+async fn get_api_key_bucket(state: &AppState, hashed_api_key: &str) -> Option<RateLimitBucket> {
+    let default_bucket = state.conf.rate_limit.api_key;
+
+    let api_key = state
+        .store
+        .find_api_key_by_hash_optional(hashed_api_key.to_owned().into())
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to fetch the API key of the request"))
+        .ok()??;
+
+    if api_key
+        .expires_at
+        .map(|expires_at| expires_at < date_time::now())
+        .unwrap_or(false)
+    {
+        return None;
+    }
+
+    let merchant_id = match api_key.merchant_id {
+        Some(merchant_id) => merchant_id,
+        None => return Some(default_bucket),
+    };
+
+    let bucket = match state
+        .store
+        .find_config_by_key_cached(&get_rate_limit_config_key(&merchant_id))
+        .await
+    {
+        Ok(config) => serde_json::from_str::<RateLimitBucket>(&config.config)
+            .ok()
+            .filter(|bucket| bucket.validate().is_ok())
+            .unwrap_or_else(|| {
+                logger::error!(%merchant_id, "Invalid rate limit config of the merchant");
+                default_bucket
+            }),
+        Err(error) => {
+            if !error.current_context().is_db_not_found() {
+                logger::error!(?error, "Failed to fetch the rate limit of the merchant");
+            }
+            default_bucket
+        }
+    };
+
+    Some(bucket)
+}
+
+/// Takes a token from the bucket of the request, returning the state of the bucket, or `None` if
+/// the rate limit of the request could not be checked.
+#[instrument(skip_all)]
+pub async fn check_rate_limit(
+    state: &AppState,
+    api_key: Option<&str>,
+    client_ip: &str,
+) -> Option<RateLimitStatus> {
+    let api_key_bucket = match api_key.map(str::trim).filter(|api_key| !api_key.is_empty()) {
+        Some(api_key) => {
+            let hashed_api_key = hash_api_key(state, api_key).await?;
+            get_api_key_bucket(state, &hashed_api_key)
+                .await
+                .map(|bucket| (format!("api_key_{hashed_api_key}"), bucket))
+        }
+        None => None,
+    };
+    let (key, bucket) =
+        api_key_bucket.unwrap_or_else(|| (format!("ip_{client_ip}"), state.conf.rate_limit.ip));
+
+    state
+        .store
+        .consume_rate_limit_token(&key, bucket)
+        .await
+        .map(|remaining| RateLimitStatus::new(bucket, remaining))
+        .map_err(|error| logger::error!(?error, "Failed to check the rate limit of the request"))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit_status() {
+        let bucket = RateLimitBucket {
+            capacity: 100,
+            refill_rate: 30,
+        };
+
+        let status = RateLimitStatus::new(bucket, Some(39));
+        assert!(!status.is_limited());
+        assert_eq!(status.remaining, 39);
+        assert_eq!(status.reset_after, 3);
+
+        let status = RateLimitStatus::new(bucket, None);
+        assert!(status.is_limited());
+        assert_eq!(status.remaining, 0);
+        assert_eq!(status.reset_after, 4);
+        assert_eq!(status.retry_after, Some(1));
+    }
+}
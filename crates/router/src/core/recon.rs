@@ -0,0 +1,450 @@
+//! Reconciliation of the settlement reports of connectors against the payments of the merchant.
+//!
+//! Each transaction settled by a connector is matched to the payment attempt processed with the
+//! same connector transaction ID, and flagged when the attempt cannot be found or when the settled
+//! amount or currency differ from those the attempt is expected to settle with. The reconciled
+//! transactions are stored along with the report, so that the reconciliation status of a payment
+//! can be retrieved once its transactions have been settled.
+
+use std::collections::HashSet;
+
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    services::ApplicationResponse,
+    types::{
+        api::{self, enums as api_enums},
+        storage::{self, enums as storage_enums},
+        transformers::{ForeignFrom, ForeignInto},
+    },
+    utils,
+};
+
+const CONNECTOR_TRANSACTION_ID_COLUMN: &str = "connector_transaction_id";
+const AMOUNT_COLUMN: &str = "amount";
+const CURRENCY_COLUMN: &str = "currency";
+
+/// Splits a record of a CSV file into its fields. Fields may be enclosed in double quotes, in
+/// which case they may contain commas and escaped double quotes.
+fn split_csv_record(record: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut is_quoted = false;
+    let mut chars = record.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if is_quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => is_quoted = !is_quoted,
+            ',' if !is_quoted => fields.push(std::mem::take(&mut field)),
+            char => field.push(char),
+        }
+    }
+    fields.push(field);
+
+    fields
+        .into_iter()
+        .map(|field| field.trim().to_owned())
+        .collect()
+}
+
+fn invalid_report(message: String) -> error_stack::Report<errors::ApiErrorResponse> {
+    report!(errors::ApiErrorResponse::InvalidRequestData { message })
+}
+
+/// Parses the settled transactions of a settlement report in CSV format. The first record is the
+/// header, which must contain the `connector_transaction_id`, `amount` and `currency` columns.
+pub fn parse_settlement_report_csv(content: &str) -> RouterResult<Vec<api::SettlementReportLine>> {
+    let mut records = content
+        .lines()
+        .enumerate()
+        .filter(|(_, record)| !record.trim().is_empty());
+
+    let header = records
+        .next()
+        .map(|(_, header)| split_csv_record(header))
+        .ok_or_else(|| invalid_report("The settlement report is empty".to_string()))?;
+    let find_column = |column: &str| {
+        header
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(column))
+            .ok_or_else(|| {
+                invalid_report(format!(
+                    "The settlement report is missing the {column} column"
+                ))
+            })
+    };
+    let connector_transaction_id_column = find_column(CONNECTOR_TRANSACTION_ID_COLUMN)?;
+    let amount_column = find_column(AMOUNT_COLUMN)?;
+    let currency_column = find_column(CURRENCY_COLUMN)?;
+
+    records
+        .map(|(index, record)| {
+            let line_number = index + 1;
+            let fields = split_csv_record(record);
+            let field = |column: usize| {
+                fields
+                    .get(column)
+                    .filter(|field| !field.is_empty())
+                    .ok_or_else(|| {
+                        invalid_report(format!(
+                            "Line {line_number} of the settlement report has missing fields"
+                        ))
+                    })
+            };
+
+            Ok(api::SettlementReportLine {
+                connector_transaction_id: field(connector_transaction_id_column)?.to_owned(),
+                amount: field(amount_column)?.parse().map_err(|_| {
+                    invalid_report(format!(
+                        "Line {line_number} of the settlement report has an invalid amount"
+                    ))
+                })?,
+                currency: field(currency_column)?
+                    .to_uppercase()
+                    .parse()
+                    .map_err(|_| {
+                        invalid_report(format!(
+                            "Line {line_number} of the settlement report has an invalid currency"
+                        ))
+                    })?,
+            })
+        })
+        .collect()
+}
+
+/// The amount and currency the payment attempt is expected to be settled with. Attempts converted
+/// to the settlement currency of the merchant are settled with the converted amount, and others
+/// with the amount processed by the connector, including any surcharge.
+fn get_expected_settlement(
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<(i64, storage_enums::Currency)> {
+    match (
+        payment_attempt.settlement_amount,
+        payment_attempt.settlement_currency,
+    ) {
+        (Some(amount), Some(currency)) => Some((amount, currency)),
+        _ => payment_attempt.currency.map(|currency| {
+            (
+                payment_attempt
+                    .amount_to_capture
+                    .unwrap_or(payment_attempt.amount.get_amount_as_i64())
+                    + payment_attempt.surcharge_amount.unwrap_or(0),
+                currency,
+            )
+        }),
+    }
+}
+
+/// Reconciles a settled transaction against the amount and currency its payment attempt is
+/// expected to be settled with, if the attempt was found.
+pub fn reconcile(
+    line: &api::SettlementReportLine,
+    expected: Option<(i64, storage_enums::Currency)>,
+) -> storage_enums::ReconStatus {
+    match expected {
+        None => storage_enums::ReconStatus::TransactionMissing,
+        Some((_, currency)) if currency != line.currency.foreign_into() => {
+            storage_enums::ReconStatus::CurrencyMismatch
+        }
+        Some((amount, _)) if amount != line.amount => storage_enums::ReconStatus::AmountMismatch,
+        Some(_) => storage_enums::ReconStatus::Matched,
+    }
+}
+
+/// Returns the latest settlement of each transaction, given the settlements of a payment with the
+/// most recent first, along with the reconciliation status of the payment.
+pub fn get_payment_recon_status(
+    settlement_lines: Vec<storage::SettlementLine>,
+) -> (api_enums::PaymentReconStatus, Vec<storage::SettlementLine>) {
+    let mut connector_transaction_ids = HashSet::new();
+    let latest_lines = settlement_lines
+        .into_iter()
+        .filter(|line| connector_transaction_ids.insert(line.connector_transaction_id.clone()))
+        .collect::<Vec<_>>();
+
+    let recon_status = if latest_lines.is_empty() {
+        api_enums::PaymentReconStatus::NotSettled
+    } else if latest_lines
+        .iter()
+        .all(|line| line.recon_status == storage_enums::ReconStatus::Matched)
+    {
+        api_enums::PaymentReconStatus::Matched
+    } else {
+        api_enums::PaymentReconStatus::Mismatched
+    };
+
+    (recon_status, latest_lines)
+}
+
+async fn find_payment_attempt(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    connector_transaction_id: &str,
+) -> RouterResult<Option<storage::PaymentAttempt>> {
+    match db
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            &merchant_account.merchant_id,
+            connector_transaction_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_attempt) => Ok(Some(payment_attempt)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch payment attempt of settled transaction"),
+    }
+}
+
+fn make_settlement_report_response(
+    settlement_report: storage::SettlementReport,
+    settlement_lines: Vec<storage::SettlementLine>,
+) -> api::SettlementReportResponse {
+    api::SettlementReportResponse {
+        report_id: settlement_report.report_id,
+        connector: settlement_report.connector,
+        line_count: settlement_report.line_count,
+        matched_count: settlement_report.matched_count,
+        mismatched_count: settlement_report.mismatched_count,
+        missing_count: settlement_report.missing_count,
+        created_at: settlement_report.created_at,
+        lines: settlement_lines
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn ingest_settlement_report(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    req: api::SettlementReportRequest,
+) -> RouterResponse<api::SettlementReportResponse> {
+    let lines = match req.report {
+        api::SettlementReportData::Csv { content } => parse_settlement_report_csv(&content)?,
+        api::SettlementReportData::Lines { lines } => lines,
+    };
+    utils::when(lines.is_empty(), || {
+        Err(invalid_report(
+            "The settlement report has no settled transactions".to_string(),
+        ))
+    })?;
+
+    let report_id = utils::generate_id(consts::ID_LENGTH, "stlm");
+    let connector = req.connector.to_string();
+    let mut settlement_lines = Vec::with_capacity(lines.len());
+    for line in lines {
+        let payment_attempt =
+            find_payment_attempt(db, &merchant_account, &line.connector_transaction_id).await?;
+        let expected = payment_attempt.as_ref().and_then(get_expected_settlement);
+        settlement_lines.push(storage::SettlementLineNew {
+            report_id: report_id.clone(),
+            merchant_id: merchant_account.merchant_id.clone(),
+            connector: connector.clone(),
+            recon_status: reconcile(&line, expected),
+            connector_transaction_id: line.connector_transaction_id,
+            payment_id: payment_attempt
+                .as_ref()
+                .map(|payment_attempt| payment_attempt.payment_id.clone()),
+            attempt_id: payment_attempt.map(|payment_attempt| payment_attempt.attempt_id),
+            settled_amount: line.amount,
+            settled_currency: line.currency.foreign_into(),
+            expected_amount: expected.map(|(amount, _)| amount),
+            expected_currency: expected.map(|(_, currency)| currency),
+            created_at: None,
+        });
+    }
+
+    let count = |is_counted: fn(storage_enums::ReconStatus) -> bool| {
+        i32::try_from(
+            settlement_lines
+                .iter()
+                .filter(|line| is_counted(line.recon_status))
+                .count(),
+        )
+        .map_err(|_| invalid_report("The settlement report has too many lines".to_string()))
+    };
+    let settlement_report = storage::SettlementReportNew {
+        report_id,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector,
+        line_count: count(|_| true)?,
+        matched_count: count(|status| status == storage_enums::ReconStatus::Matched)?,
+        mismatched_count: count(|status| {
+            matches!(
+                status,
+                storage_enums::ReconStatus::AmountMismatch
+                    | storage_enums::ReconStatus::CurrencyMismatch
+            )
+        })?,
+        missing_count: count(|status| status == storage_enums::ReconStatus::TransactionMissing)?,
+        created_at: None,
+    };
+    let settlement_report = db
+        .insert_settlement_report(settlement_report)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert settlement report")?;
+
+    let mut inserted_lines = Vec::with_capacity(settlement_lines.len());
+    for settlement_line in settlement_lines {
+        inserted_lines.push(
+            db.insert_settlement_line(settlement_line)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert settlement line")?,
+        );
+    }
+
+    Ok(ApplicationResponse::Json(make_settlement_report_response(
+        settlement_report,
+        inserted_lines,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_settlement_report(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    report_id: &str,
+) -> RouterResponse<api::SettlementReportResponse> {
+    let settlement_report = db
+        .find_settlement_report_by_merchant_id_report_id(&merchant_account.merchant_id, report_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::SettlementReportNotFound)
+        })?;
+    let settlement_lines = db
+        .find_settlement_lines_by_merchant_id_report_id(&merchant_account.merchant_id, report_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch lines of settlement report")?;
+
+    Ok(ApplicationResponse::Json(make_settlement_report_response(
+        settlement_report,
+        settlement_lines,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payment_recon(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    payment_id: &str,
+) -> RouterResponse<api::PaymentReconResponse> {
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+    let settlement_lines = db
+        .find_settlement_lines_by_merchant_id_payment_id(
+            &merchant_account.merchant_id,
+            &payment_intent.payment_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch settlement lines of payment")?;
+
+    let (recon_status, settlement_lines) = get_payment_recon_status(settlement_lines);
+
+    Ok(ApplicationResponse::Json(api::PaymentReconResponse {
+        payment_id: payment_intent.payment_id,
+        recon_status,
+        settlement_lines: settlement_lines
+            .into_iter()
+            .map(api::SettlementLineResponse::foreign_from)
+            .collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn line(amount: i64, currency: api_enums::Currency) -> api::SettlementReportLine {
+        api::SettlementReportLine {
+            connector_transaction_id: "txn_1".to_string(),
+            amount,
+            currency,
+        }
+    }
+
+    #[test]
+    fn test_csv_record_with_quoted_fields_is_split() {
+        assert_eq!(
+            split_csv_record(r#"txn_1, "1,000", "say ""hi""""#),
+            vec!["txn_1", "1,000", r#"say "hi""#]
+        );
+    }
+
+    #[test]
+    fn test_settlement_report_csv_is_parsed() {
+        let content = "fee,Currency,connector_transaction_id,amount\n\
+                       10,usd,txn_1,6540\n\
+                       \n\
+                       20,EUR,txn_2,100\n";
+
+        let lines = parse_settlement_report_csv(content).unwrap();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].connector_transaction_id, "txn_1");
+        assert_eq!(lines[0].amount, 6540);
+        assert_eq!(lines[0].currency, api_enums::Currency::USD);
+        assert_eq!(lines[1].currency, api_enums::Currency::EUR);
+    }
+
+    #[test]
+    fn test_settlement_report_csv_is_validated() {
+        assert!(parse_settlement_report_csv("").is_err());
+        assert!(parse_settlement_report_csv("connector_transaction_id,amount\ntxn_1,10").is_err());
+        assert!(parse_settlement_report_csv(
+            "connector_transaction_id,amount,currency\ntxn_1,ten,USD"
+        )
+        .is_err());
+        assert!(parse_settlement_report_csv(
+            "connector_transaction_id,amount,currency\ntxn_1,10,XYZ"
+        )
+        .is_err());
+        assert!(
+            parse_settlement_report_csv("connector_transaction_id,amount,currency\ntxn_1,10")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_settled_transactions_are_reconciled() {
+        let expected = Some((6540, storage_enums::Currency::USD));
+
+        assert_eq!(
+            reconcile(&line(6540, api_enums::Currency::USD), expected),
+            storage_enums::ReconStatus::Matched
+        );
+        assert_eq!(
+            reconcile(&line(6000, api_enums::Currency::USD), expected),
+            storage_enums::ReconStatus::AmountMismatch
+        );
+        assert_eq!(
+            reconcile(&line(6540, api_enums::Currency::EUR), expected),
+            storage_enums::ReconStatus::CurrencyMismatch
+        );
+        assert_eq!(
+            reconcile(&line(6540, api_enums::Currency::USD), None),
+            storage_enums::ReconStatus::TransactionMissing
+        );
+    }
+}
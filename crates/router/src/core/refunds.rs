@@ -8,20 +8,21 @@ use crate::{
     consts,
     core::{
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        ledger,
         payments::{self, access_token},
-        utils as core_utils,
+        utils as core_utils, webhooks,
     },
     db, logger,
     routes::{metrics, AppState},
-    scheduler::{process_data, utils as process_tracker_utils, workflows::payment_sync},
+    scheduler::{process_data, utils as process_tracker_utils},
     services,
     types::{
         self,
         api::{self, refunds},
         storage::{self, enums, PaymentAttemptExt, ProcessTrackerExt},
-        transformers::{ForeignFrom, ForeignInto},
+        transformers::{ForeignFrom, ForeignInto, ForeignTryInto},
     },
-    utils::{self, OptionExt},
+    utils::{self, validation, OptionExt},
 };
 
 // ********************************************** REFUND EXECUTE **********************************************
@@ -35,6 +36,8 @@ pub async fn refund_create_core(
     let db = &*state.store;
     let (merchant_id, payment_intent, payment_attempt, amount);
 
+    validation::validate_request(&req)?;
+
     merchant_id = &merchant_account.merchant_id;
 
     payment_intent = db
@@ -156,7 +159,7 @@ pub async fn trigger_refund_to_gateway(
         state,
         &routed_through,
         merchant_account,
-        (payment_attempt.amount, currency),
+        (payment_attempt.amount.get_amount_as_i64(), currency),
         payment_intent,
         payment_attempt,
         refund,
@@ -196,11 +199,17 @@ pub async fn trigger_refund_to_gateway(
         router_data
     };
 
+    // The raw response of the connector is kept only for the merchants who opted in to it
+    let connector_raw_response = router_data_res
+        .connector_raw_response
+        .filter(|_| merchant_account.return_raw_connector_response);
+
     let refund_update = match router_data_res.response {
         Err(err) => storage::RefundUpdate::ErrorUpdate {
             refund_status: Some(enums::RefundStatus::Failure),
             refund_error_message: Some(err.message),
             refund_error_code: Some(err.code),
+            connector_raw_response,
         },
         Ok(response) => {
             if response.refund_status == storage_models::enums::RefundStatus::Success {
@@ -219,6 +228,7 @@ pub async fn trigger_refund_to_gateway(
                 sent_to_gateway: true,
                 refund_error_message: None,
                 refund_arn: "".to_string(),
+                connector_raw_response,
             }
         }
     };
@@ -238,6 +248,10 @@ pub async fn trigger_refund_to_gateway(
                 refund.refund_id
             )
         })?;
+
+    update_amount_refunded_on_payment_attempt(state, merchant_account, &response).await?;
+    ledger::record_refund(&*state.store, &response).await;
+
     Ok(response)
 }
 
@@ -355,7 +369,7 @@ pub async fn sync_refund_with_gateway(
         state,
         &connector_id,
         merchant_account,
-        (payment_attempt.amount, currency),
+        (payment_attempt.amount.get_amount_as_i64(), currency),
         payment_intent,
         payment_attempt,
         refund,
@@ -395,11 +409,17 @@ pub async fn sync_refund_with_gateway(
         router_data
     };
 
+    // The raw response of the connector is kept only for the merchants who opted in to it
+    let connector_raw_response = router_data_res
+        .connector_raw_response
+        .filter(|_| merchant_account.return_raw_connector_response);
+
     let refund_update = match router_data_res.response {
         Err(error_message) => storage::RefundUpdate::ErrorUpdate {
             refund_status: None,
             refund_error_message: Some(error_message.message),
             refund_error_code: Some(error_message.code),
+            connector_raw_response,
         },
         Ok(response) => storage::RefundUpdate::Update {
             connector_refund_id: response.connector_refund_id,
@@ -407,6 +427,7 @@ pub async fn sync_refund_with_gateway(
             sent_to_gateway: true,
             refund_error_message: None,
             refund_arn: "".to_string(),
+            connector_raw_response,
         },
     };
 
@@ -425,9 +446,77 @@ pub async fn sync_refund_with_gateway(
                 refund.refund_id
             )
         })?;
+
+    update_amount_refunded_on_payment_attempt(state, merchant_account, &response).await?;
+    ledger::record_refund(&*state.store, &response).await;
+
     Ok(response)
 }
 
+/// Recomputes the cumulative amount refunded against the payment attempt of a successful refund
+/// and stores it on the payment attempt.
+#[instrument(skip_all)]
+pub async fn update_amount_refunded_on_payment_attempt(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    refund: &storage::Refund,
+) -> RouterResult<()> {
+    if refund.refund_status != enums::RefundStatus::Success {
+        return Ok(());
+    }
+
+    let db = &*state.store;
+    let amount_refunded = get_amount_refunded(
+        db,
+        &merchant_account.merchant_id,
+        &refund.connector_transaction_id,
+        merchant_account.storage_scheme,
+    )
+    .await?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &refund.attempt_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        storage::PaymentAttemptUpdate::RefundUpdate { amount_refunded },
+        merchant_account.storage_scheme,
+    )
+    .await
+    .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok(())
+}
+
+/// Sums the amounts of the successful refunds of a payment attempt.
+#[instrument(skip_all)]
+pub async fn get_amount_refunded(
+    db: &dyn db::StorageInterface,
+    merchant_id: &str,
+    connector_transaction_id: &str,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<i64> {
+    Ok(db
+        .find_refund_by_merchant_id_connector_transaction_id(
+            merchant_id,
+            connector_transaction_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refunds of the payment attempt")?
+        .iter()
+        .filter(|refund| refund.refund_status == enums::RefundStatus::Success)
+        .map(|refund| refund.refund_amount)
+        .sum())
+}
+
 // ********************************************** REFUND UPDATE **********************************************
 
 pub async fn refund_update_core(
@@ -542,7 +631,12 @@ pub async fn validate_and_create_refund(
                 ),
             })?;
 
-            validator::validate_refund_amount(payment_attempt.amount, &all_refunds, refund_amount)
+            // Refunds are bounded by the amount captured, which is lower than the amount of the
+            // attempt for partially captured payments
+            let captured_amount = payment_intent
+                .amount_captured
+                .unwrap_or(payment_attempt.amount.get_amount_as_i64());
+            validator::validate_refund_amount(captured_amount, &all_refunds, refund_amount)
                 .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
 
             validator::validate_maximum_refund_against_payment_attempt(
@@ -567,7 +661,7 @@ pub async fn validate_and_create_refund(
                 .set_connector_transaction_id(connecter_transaction_id.to_string())
                 .set_connector(connector)
                 .set_refund_type(req.refund_type.unwrap_or_default().foreign_into())
-                .set_total_amount(payment_attempt.amount)
+                .set_total_amount(payment_attempt.amount.get_amount_as_i64())
                 .set_refund_amount(refund_amount)
                 .set_currency(currency)
                 .set_created_at(Some(common_utils::date_time::now()))
@@ -636,6 +730,42 @@ pub async fn refund_list(
     ))
 }
 
+/// Lists all the refunds created against the payment, ordered by the time of their creation
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn refund_list_by_payment_id(
+    db: &dyn db::StorageInterface,
+    merchant_account: storage::merchant_account::MerchantAccount,
+    payment_id: String,
+) -> RouterResponse<api_models::refunds::RefundListResponse> {
+    db.find_payment_intent_by_payment_id_merchant_id(
+        &payment_id,
+        &merchant_account.merchant_id,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let mut refund_list = db
+        .find_refund_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refunds of the payment")?;
+    refund_list.sort_by_key(|refund| refund.created_at);
+
+    let data: Vec<refunds::RefundResponse> = refund_list
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+    Ok(services::ApplicationResponse::Json(
+        api_models::refunds::RefundListResponse { data },
+    ))
+}
+
 impl ForeignFrom<storage::Refund> for api::RefundResponse {
     fn foreign_from(refund: storage::Refund) -> Self {
         let refund = refund;
@@ -649,6 +779,7 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             metadata: refund.metadata,
             error_message: refund.refund_error_message,
             error_code: refund.refund_error_code,
+            connector_raw_response: refund.connector_raw_response,
             created_at: Some(refund.created_at),
             updated_at: Some(refund.updated_at),
         }
@@ -693,7 +824,7 @@ pub async fn schedule_refund_execution(
                             Ok(refund)
                         }
                         api_models::refunds::RefundType::Instant => {
-                            trigger_refund_to_gateway(
+                            let refund = trigger_refund_to_gateway(
                                 state,
                                 &refund,
                                 merchant_account,
@@ -701,7 +832,18 @@ pub async fn schedule_refund_execution(
                                 payment_intent,
                                 creds_identifier,
                             )
-                            .await
+                            .await?;
+
+                            // Refunds processed asynchronously by the connector are polled until
+                            // they reach a terminal status
+                            if refund.refund_status == enums::RefundStatus::Pending {
+                                add_refund_sync_task(db, &refund, runner)
+                                    .await
+                                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                                    .attach_printable_lazy(|| format!("Failed while pushing refund sync task in scheduler: refund_id: {}", refund.refund_id))?;
+                            }
+
+                            Ok(refund)
                         }
                     }
                 }
@@ -752,11 +894,21 @@ pub async fn sync_refund_with_gateway_workflow(
         .find_merchant_account_by_merchant_id(&refund_core.merchant_id)
         .await?;
 
+    let refund = state
+        .store
+        .find_refund_by_internal_reference_id_merchant_id(
+            &refund_core.refund_internal_reference_id,
+            &refund_core.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::RefundNotFound))?;
+
     let response = refund_retrieve_core(
         state,
-        merchant_account,
+        merchant_account.clone(),
         refunds::RefundsRetrieveRequest {
-            refund_id: refund_core.refund_internal_reference_id,
+            refund_id: refund.refund_id,
             merchant_connector_details: None,
         },
     )
@@ -768,6 +920,10 @@ pub async fn sync_refund_with_gateway_workflow(
     ];
     match response.refund_status {
         status if terminal_status.contains(&status) => {
+            if status != refund.refund_status {
+                trigger_refund_outgoing_webhook(state, merchant_account, response).await;
+            }
+
             let id = refund_tracker.id.clone();
             refund_tracker
                 .clone()
@@ -775,11 +931,13 @@ pub async fn sync_refund_with_gateway_workflow(
                 .await?
         }
         _ => {
-            payment_sync::retry_sync_task(
+            process_tracker_utils::retry_process(
                 &*state.store,
-                response.connector,
-                response.merchant_id,
                 refund_tracker.to_owned(),
+                process_data::RetryPolicy::RefundsSync {
+                    connector: &response.connector,
+                },
+                &response.merchant_id,
             )
             .await?
         }
@@ -788,6 +946,35 @@ pub async fn sync_refund_with_gateway_workflow(
     Ok(())
 }
 
+/// Notifies the merchant of a refund which reached a terminal status while being synced with the
+/// connector. Refunds in a status without a corresponding event are not notified.
+async fn trigger_refund_outgoing_webhook(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    refund: storage::Refund,
+) {
+    let event_type: enums::EventType = match refund.refund_status.foreign_try_into() {
+        Ok(event_type) => event_type,
+        Err(_) => return,
+    };
+    let refund_id = refund.refund_id.clone();
+    let result = webhooks::create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+        state.clone(),
+        merchant_account,
+        event_type,
+        enums::EventClass::Refunds,
+        None,
+        refund_id,
+        enums::EventObjectType::RefundDetails,
+        api::OutgoingWebhookContent::RefundDetails(refund.foreign_into()),
+    )
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to trigger refund webhook");
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn start_refund_workflow(
     state: &AppState,
@@ -985,49 +1172,3 @@ pub async fn add_refund_execute_task(
         })?;
     Ok(response)
 }
-
-pub async fn get_refund_sync_process_schedule_time(
-    db: &dyn db::StorageInterface,
-    connector: &str,
-    merchant_id: &str,
-    retry_count: i32,
-) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
-    let redis_mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
-        db::get_and_deserialize_key(
-            db,
-            &format!("pt_mapping_refund_sync_{connector}"),
-            "ConnectorPTMapping",
-        )
-        .await;
-
-    let mapping = match redis_mapping {
-        Ok(x) => x,
-        Err(err) => {
-            logger::error!("Error: while getting connector mapping: {}", err);
-            process_data::ConnectorPTMapping::default()
-        }
-    };
-
-    let time_delta =
-        process_tracker_utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
-
-    Ok(process_tracker_utils::get_time_from_delta(time_delta))
-}
-
-pub async fn retry_refund_sync_task(
-    db: &dyn db::StorageInterface,
-    connector: String,
-    merchant_id: String,
-    pt: storage::ProcessTracker,
-) -> Result<(), errors::ProcessTrackerError> {
-    let schedule_time =
-        get_refund_sync_process_schedule_time(db, &connector, &merchant_id, pt.retry_count).await?;
-
-    match schedule_time {
-        Some(s_time) => pt.retry(db, s_time).await,
-        None => {
-            pt.finish_with_status(db, "RETRIES_EXCEEDED".to_string())
-                .await
-        }
-    }
-}
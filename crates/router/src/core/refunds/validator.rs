@@ -28,7 +28,10 @@ pub enum RefundValidationError {
 pub fn validate_success_transaction(
     transaction: &storage::PaymentAttempt,
 ) -> CustomResult<(), RefundValidationError> {
-    if transaction.status != enums::AttemptStatus::Charged {
+    if !matches!(
+        transaction.status,
+        enums::AttemptStatus::Charged | enums::AttemptStatus::PartiallyCaptured
+    ) {
         Err(report!(RefundValidationError::UnsuccessfulPaymentAttempt))?
     }
 
@@ -37,7 +40,7 @@ pub fn validate_success_transaction(
 
 #[instrument(skip_all)]
 pub fn validate_refund_amount(
-    payment_attempt_amount: i64, // &storage::PaymentAttempt,
+    captured_amount: i64,
     all_refunds: &[storage::Refund],
     refund_amount: i64,
 ) -> CustomResult<(), RefundValidationError> {
@@ -55,7 +58,7 @@ pub fn validate_refund_amount(
         .sum();
 
     utils::when(
-        refund_amount > (payment_attempt_amount - total_refunded_amount),
+        refund_amount > (captured_amount - total_refunded_amount),
         || {
             Err(report!(
                 RefundValidationError::RefundAmountExceedsPaymentAmount
@@ -161,9 +164,6 @@ pub fn validate_for_valid_refunds(
                     (
                         api_models::enums::Connector::Braintree,
                         storage_models::enums::PaymentMethodType::Paypal,
-                    ) | (
-                        api_models::enums::Connector::Klarna,
-                        storage_models::enums::PaymentMethodType::Klarna
                     )
                 ),
                 || {
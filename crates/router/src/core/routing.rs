@@ -0,0 +1,570 @@
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    date_time,
+};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+pub mod success_rate;
+
+use super::payments::PaymentData;
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::{metrics, AppState},
+    services::ApplicationResponse,
+    types::{api, api::enums as api_enums, storage, transformers::ForeignInto},
+    utils::{self, Encode, ValueExt},
+};
+
+const CARD_BIN_LENGTH: usize = 6;
+const VOLUME_SPLIT_TOTAL: u32 = 100;
+
+fn validate_routing_rules(rules: &api::RoutingRules) -> RouterResult<()> {
+    for rule in rules.rules.iter() {
+        for condition in rule.conditions.iter() {
+            if let api::RoutingCondition::CardBin { values, .. } = condition {
+                if values
+                    .iter()
+                    .any(|bin| bin.is_empty() || !bin.chars().all(|c| c.is_ascii_digit()))
+                {
+                    return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "card_bin values of routing rule `{}` must contain only digits",
+                            rule.name
+                        ),
+                    }));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn make_routing_config_response(
+    routing_config: storage::RoutingConfig,
+) -> RouterResult<api::RoutingConfigResponse> {
+    let algorithm = routing_config
+        .algorithm
+        .parse_value("RoutingRules")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid routing rules stored in routing config")?;
+
+    Ok(api::RoutingConfigResponse {
+        routing_config_id: routing_config.routing_config_id,
+        merchant_id: routing_config.merchant_id,
+        name: routing_config.name,
+        description: routing_config.description,
+        algorithm,
+        is_active: routing_config.is_active,
+        created_at: routing_config.created_at,
+        modified_at: routing_config.modified_at,
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn create_routing_config(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    req: api::RoutingConfigRequest,
+) -> RouterResponse<api::RoutingConfigResponse> {
+    validate_routing_rules(&req.algorithm)?;
+
+    let algorithm = Encode::<api::RoutingRules>::encode_to_value(&req.algorithm)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encode routing rules")?;
+
+    let now = date_time::now();
+    let routing_config = db
+        .insert_routing_config(storage::RoutingConfigNew {
+            routing_config_id: utils::generate_id(consts::ID_LENGTH, "routing"),
+            merchant_id: merchant_account.merchant_id,
+            name: req.name,
+            description: req.description,
+            algorithm,
+            is_active: false,
+            created_at: Some(now),
+            modified_at: Some(now),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert routing config")?;
+
+    make_routing_config_response(routing_config).map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_routing_config(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    routing_config_id: &str,
+) -> RouterResponse<api::RoutingConfigResponse> {
+    let routing_config = db
+        .find_routing_config_by_merchant_id_routing_config_id(
+            &merchant_account.merchant_id,
+            routing_config_id,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::RoutingConfigNotFound)
+        })?;
+
+    make_routing_config_response(routing_config).map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn list_routing_configs(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+) -> RouterResponse<Vec<api::RoutingConfigResponse>> {
+    let routing_configs = db
+        .list_routing_configs_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list routing configs")?;
+
+    routing_configs
+        .into_iter()
+        .map(make_routing_config_response)
+        .collect::<RouterResult<Vec<_>>>()
+        .map(ApplicationResponse::Json)
+}
+
+/// Activates the routing configuration, so that all subsequent payments of the merchant are routed
+/// as per its rules. Only one routing configuration of a merchant can be active at a time.
+#[instrument(skip_all)]
+pub async fn activate_routing_config(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    routing_config_id: &str,
+) -> RouterResponse<api::RoutingConfigResponse> {
+    let routing_config = db
+        .find_routing_config_by_merchant_id_routing_config_id(
+            &merchant_account.merchant_id,
+            routing_config_id,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::RoutingConfigNotFound)
+        })?;
+
+    let routing_config = db
+        .activate_routing_config(routing_config)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to activate routing config")?;
+
+    make_routing_config_response(routing_config).map(ApplicationResponse::Json)
+}
+
+/// Evaluates the routing configuration against the given payment attributes, without creating a
+/// payment. This allows the rules to be verified before the configuration is activated.
+#[instrument(skip_all)]
+pub async fn evaluate_routing_config(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    routing_config_id: &str,
+    req: api::RoutingEvaluateRequest,
+) -> RouterResponse<api::RoutingEvaluateResponse> {
+    let routing_config = db
+        .find_routing_config_by_merchant_id_routing_config_id(
+            &merchant_account.merchant_id,
+            routing_config_id,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::RoutingConfigNotFound)
+        })?;
+
+    let rules = make_routing_config_response(routing_config)?.algorithm;
+
+    Ok(ApplicationResponse::Json(evaluate_routing_rules(
+        &rules, &req,
+    )))
+}
+
+/// Returns the rules of the active routing configuration of the merchant, if any.
+pub async fn get_active_routing_rules(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<api::RoutingRules>> {
+    db.find_active_routing_config_by_merchant_id(merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch active routing config")?
+        .map(|routing_config| make_routing_config_response(routing_config).map(|c| c.algorithm))
+        .transpose()
+}
+
+/// Collects the attributes of the payment which the routing rules are evaluated against.
+pub fn get_routing_input<F: Clone>(payment_data: &PaymentData<F>) -> api::RoutingEvaluateRequest {
+    let (card_bin, card_network) = match payment_data.payment_method_data {
+        Some(api::PaymentMethodData::Card(ref card)) => (
+            card.card_number
+                .peek()
+                .get(..CARD_BIN_LENGTH)
+                .map(ToOwned::to_owned),
+            card.card_network.clone(),
+        ),
+        _ => (None, None),
+    };
+
+    api::RoutingEvaluateRequest {
+        amount: payment_data.amount.get_amount_as_i64(),
+        currency: Some(payment_data.currency.get_currency().foreign_into()),
+        card_bin,
+        card_network,
+        payment_method: payment_data
+            .payment_attempt
+            .payment_method
+            .map(ForeignInto::foreign_into),
+        country: payment_data
+            .address
+            .billing
+            .as_ref()
+            .and_then(|billing| billing.address.as_ref())
+            .and_then(|address| address.country),
+    }
+}
+
+/// Returns the connector of the first rule whose conditions are all satisfied by the payment, along
+/// with the name of the rule, or the default connector if none of the rules match.
+pub fn evaluate_routing_rules(
+    rules: &api::RoutingRules,
+    input: &api::RoutingEvaluateRequest,
+) -> api::RoutingEvaluateResponse {
+    rules
+        .rules
+        .iter()
+        .find(|rule| {
+            rule.conditions
+                .iter()
+                .all(|condition| is_condition_satisfied(condition, input))
+        })
+        .map(|rule| api::RoutingEvaluateResponse {
+            connector: rule.connector,
+            matched_rule: Some(rule.name.clone()),
+            card_network: rule.card_network.clone(),
+        })
+        .unwrap_or(api::RoutingEvaluateResponse {
+            connector: rules.default_connector,
+            matched_rule: None,
+            card_network: None,
+        })
+}
+
+fn is_condition_satisfied(
+    condition: &api::RoutingCondition,
+    input: &api::RoutingEvaluateRequest,
+) -> bool {
+    match condition {
+        api::RoutingCondition::Amount { operator, value } => match operator {
+            api::ComparisonOperator::Equal => input.amount == *value,
+            api::ComparisonOperator::NotEqual => input.amount != *value,
+            api::ComparisonOperator::GreaterThan => input.amount > *value,
+            api::ComparisonOperator::GreaterThanOrEqual => input.amount >= *value,
+            api::ComparisonOperator::LessThan => input.amount < *value,
+            api::ComparisonOperator::LessThanOrEqual => input.amount <= *value,
+        },
+        api::RoutingCondition::Currency { operator, values } => {
+            is_member(*operator, input.currency.map(|c| values.contains(&c)))
+        }
+        api::RoutingCondition::CardBin { operator, values } => is_member(
+            *operator,
+            input
+                .card_bin
+                .as_ref()
+                .map(|bin| values.iter().any(|prefix| bin.starts_with(prefix.as_str()))),
+        ),
+        api::RoutingCondition::CardNetwork { operator, values } => is_member(
+            *operator,
+            input
+                .card_network
+                .as_ref()
+                .map(|network| values.contains(network)),
+        ),
+        api::RoutingCondition::PaymentMethod { operator, values } => is_member(
+            *operator,
+            input.payment_method.map(|pm| values.contains(&pm)),
+        ),
+        api::RoutingCondition::Country { operator, values } => is_member(
+            *operator,
+            input.country.map(|country| values.contains(&country)),
+        ),
+    }
+}
+
+/// An attribute which is not available for the payment is considered to be a member of no list.
+fn is_member(operator: api::MembershipOperator, is_contained: Option<bool>) -> bool {
+    match operator {
+        api::MembershipOperator::In => is_contained.unwrap_or(false),
+        api::MembershipOperator::NotIn => !is_contained.unwrap_or(false),
+    }
+}
+
+pub fn validate_routing_algorithm(routing_algorithm: &api::RoutingAlgorithm) -> RouterResult<()> {
+    match routing_algorithm {
+        api::RoutingAlgorithm::Single(_) => Ok(()),
+        api::RoutingAlgorithm::VolumeSplit(splits) => {
+            let total: u32 = splits.iter().map(|split| u32::from(split.split)).sum();
+            if total == VOLUME_SPLIT_TOTAL {
+                Ok(())
+            } else {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Volume splits of the routing algorithm must add up to {VOLUME_SPLIT_TOTAL}, found {total}"
+                    ),
+                }))
+            }
+        }
+        api::RoutingAlgorithm::SuccessRate(algorithm) => {
+            if algorithm.connectors.is_empty() {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "At least one connector must be provided for success rate routing"
+                        .to_string(),
+                }))
+            } else if u32::from(algorithm.exploration_percentage) > VOLUME_SPLIT_TOTAL {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Exploration percentage of success rate routing cannot exceed {VOLUME_SPLIT_TOTAL}"
+                    ),
+                }))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Returns the connector chosen by the routing algorithm for the payment.
+pub async fn get_connector_from_routing_algorithm(
+    state: &AppState,
+    merchant_id: &str,
+    payment_id: &str,
+    routing_input: &api::RoutingEvaluateRequest,
+    routing_algorithm: &api::RoutingAlgorithm,
+) -> RouterResult<String> {
+    match routing_algorithm {
+        api::RoutingAlgorithm::Single(connector) => Ok(connector.to_string()),
+        api::RoutingAlgorithm::VolumeSplit(splits) => {
+            let connector = perform_volume_split(splits, payment_id)?;
+
+            metrics::VOLUME_SPLIT_ROUTED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[
+                    metrics::request::add_attributes("merchant_id", merchant_id.to_owned()),
+                    metrics::request::add_attributes("connector", connector.to_string()),
+                ],
+            );
+
+            Ok(connector.to_string())
+        }
+        api::RoutingAlgorithm::SuccessRate(algorithm) => {
+            let payment_hash = get_payment_id_hash(payment_id)?;
+            success_rate::perform_success_rate_routing(
+                state,
+                merchant_id,
+                algorithm,
+                routing_input,
+                payment_hash,
+            )
+            .await
+            .map(|connector| connector.to_string())
+        }
+    }
+}
+
+/// The connectors of the routing algorithm, in the order of preference of the merchant. The
+/// connectors of a volume split are preferred by their share of the payments.
+pub fn get_connector_preference_order(routing_algorithm: &api::RoutingAlgorithm) -> Vec<String> {
+    match routing_algorithm {
+        api::RoutingAlgorithm::Single(connector) => vec![connector.to_string()],
+        api::RoutingAlgorithm::VolumeSplit(splits) => {
+            let mut splits = splits.iter().collect::<Vec<_>>();
+            splits.sort_by_key(|split| std::cmp::Reverse(split.split));
+            splits
+                .into_iter()
+                .map(|split| split.connector.to_string())
+                .collect()
+        }
+        api::RoutingAlgorithm::SuccessRate(algorithm) => algorithm
+            .connectors
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+    }
+}
+
+/// A hash of the payment ID, which is used to make the routing decisions involving randomness
+/// deterministic for a payment.
+fn get_payment_id_hash(payment_id: &str) -> RouterResult<u32> {
+    let digest = crypto::Sha256
+        .generate_digest(payment_id.as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash payment ID for routing")?;
+
+    Ok(digest
+        .iter()
+        .take(4)
+        .fold(0_u32, |hash, byte| (hash << 8) | u32::from(*byte)))
+}
+
+/// Picks a connector with a probability proportional to its split. The choice is derived from a
+/// hash of the payment ID, so that the same connector is picked every time the payment is routed.
+fn perform_volume_split(
+    splits: &[api::ConnectorVolumeSplit],
+    payment_id: &str,
+) -> RouterResult<api_enums::RoutableConnectors> {
+    let total: u32 = splits.iter().map(|split| u32::from(split.split)).sum();
+    if total == 0 {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Volume splits of the routing algorithm add up to zero");
+    }
+
+    let mut bucket = get_payment_id_hash(payment_id)? % total;
+    for split in splits {
+        let split_size = u32::from(split.split);
+        if bucket < split_size {
+            return Ok(split.connector);
+        }
+        bucket -= split_size;
+    }
+
+    Err(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Volume split did not pick a connector")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn rules() -> api::RoutingRules {
+        api::RoutingRules {
+            rules: vec![
+                api::RoutingRule {
+                    name: "high_value_usd".to_string(),
+                    conditions: vec![
+                        api::RoutingCondition::Amount {
+                            operator: api::ComparisonOperator::GreaterThan,
+                            value: 100000,
+                        },
+                        api::RoutingCondition::Currency {
+                            operator: api::MembershipOperator::In,
+                            values: vec![api_enums::Currency::USD],
+                        },
+                    ],
+                    connector: api_enums::RoutableConnectors::Adyen,
+                    card_network: None,
+                },
+                api::RoutingRule {
+                    name: "visa_outside_us".to_string(),
+                    conditions: vec![
+                        api::RoutingCondition::CardBin {
+                            operator: api::MembershipOperator::In,
+                            values: vec!["4".to_string()],
+                        },
+                        api::RoutingCondition::Country {
+                            operator: api::MembershipOperator::NotIn,
+                            values: vec![api_enums::CountryCode::US],
+                        },
+                    ],
+                    connector: api_enums::RoutableConnectors::Checkout,
+                    card_network: None,
+                },
+            ],
+            default_connector: api_enums::RoutableConnectors::Stripe,
+        }
+    }
+
+    #[test]
+    fn test_first_matching_rule_is_chosen() {
+        let input = api::RoutingEvaluateRequest {
+            amount: 200000,
+            currency: Some(api_enums::Currency::USD),
+            card_bin: Some("424242".to_string()),
+            country: Some(api_enums::CountryCode::DE),
+            ..Default::default()
+        };
+
+        let result = evaluate_routing_rules(&rules(), &input);
+
+        assert_eq!(result.connector, api_enums::RoutableConnectors::Adyen);
+        assert_eq!(result.matched_rule.as_deref(), Some("high_value_usd"));
+    }
+
+    #[test]
+    fn test_rule_with_unavailable_attribute() {
+        let input = api::RoutingEvaluateRequest {
+            amount: 100,
+            card_bin: Some("424242".to_string()),
+            ..Default::default()
+        };
+
+        let result = evaluate_routing_rules(&rules(), &input);
+
+        assert_eq!(result.connector, api_enums::RoutableConnectors::Checkout);
+    }
+
+    #[test]
+    fn test_volume_split_is_deterministic_and_proportional() {
+        let splits = vec![
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                split: 70,
+            },
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                split: 30,
+            },
+        ];
+
+        let routed = (0..1000)
+            .map(|i| perform_volume_split(&splits, &format!("pay_{i}")).unwrap())
+            .collect::<Vec<_>>();
+        let stripe_count = routed
+            .iter()
+            .filter(|connector| **connector == api_enums::RoutableConnectors::Stripe)
+            .count();
+
+        assert!((600..=800).contains(&stripe_count));
+        assert_eq!(perform_volume_split(&splits, "pay_42").unwrap(), routed[42]);
+    }
+
+    #[test]
+    fn test_volume_split_connectors_are_preferred_by_share() {
+        let routing_algorithm = api::RoutingAlgorithm::VolumeSplit(vec![
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                split: 30,
+            },
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                split: 70,
+            },
+        ]);
+
+        assert_eq!(
+            get_connector_preference_order(&routing_algorithm),
+            vec!["stripe".to_string(), "adyen".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_default_connector_when_no_rule_matches() {
+        let input = api::RoutingEvaluateRequest {
+            amount: 100,
+            currency: Some(api_enums::Currency::EUR),
+            ..Default::default()
+        };
+
+        let result = evaluate_routing_rules(&rules(), &input);
+
+        assert_eq!(result.connector, api_enums::RoutableConnectors::Stripe);
+        assert_eq!(result.matched_rule, None);
+    }
+}
@@ -0,0 +1,188 @@
+use common_utils::date_time;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, CustomResult, RouterResult},
+    logger,
+    routes::AppState,
+    types::{
+        self, api,
+        api::enums as api_enums,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+const UNKNOWN_DIMENSION: &str = "unknown";
+
+fn get_authorization_count_key(
+    merchant_id: &str,
+    connector: &str,
+    routing_input: &api::RoutingEvaluateRequest,
+    bucket: i64,
+) -> String {
+    let card_network = routing_input
+        .card_network
+        .as_ref()
+        .map(ToString::to_string)
+        .unwrap_or_else(|| UNKNOWN_DIMENSION.to_string());
+    let country = routing_input
+        .country
+        .map(|country| country.to_string())
+        .unwrap_or_else(|| UNKNOWN_DIMENSION.to_string());
+
+    format!("success_rate_{merchant_id}_{connector}_{card_network}_{country}_{bucket}")
+}
+
+fn get_current_bucket(bucket_size: i64) -> i64 {
+    date_time::now_unix_timestamp() / bucket_size.max(1)
+}
+
+/// Returns the outcome of the authorization, or `None` if the outcome is not known yet (for
+/// example, the customer is yet to complete 3DS authentication).
+fn get_authorization_outcome<F, Req>(
+    router_data: &types::RouterData<F, Req, types::PaymentsResponseData>,
+) -> Option<bool> {
+    if router_data.response.is_err() {
+        return Some(false);
+    }
+
+    match router_data.status {
+        storage_enums::AttemptStatus::Charged | storage_enums::AttemptStatus::Authorized => {
+            Some(true)
+        }
+        storage_enums::AttemptStatus::Failure
+        | storage_enums::AttemptStatus::AuthorizationFailed
+        | storage_enums::AttemptStatus::AuthenticationFailed => Some(false),
+        _ => None,
+    }
+}
+
+/// Counts the outcome of the authorization towards the success rate of the connector. Failing to
+/// do so does not fail the payment.
+#[instrument(skip_all)]
+pub async fn update_success_rate<F, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    routing_input: &api::RoutingEvaluateRequest,
+    router_data: &types::RouterData<F, Req, types::PaymentsResponseData>,
+) {
+    let is_success = match get_authorization_outcome(router_data) {
+        Some(is_success) => is_success,
+        None => return,
+    };
+
+    let config = &state.conf.success_rate_routing;
+    let key = get_authorization_count_key(
+        &merchant_account.merchant_id,
+        &router_data.connector,
+        routing_input,
+        get_current_bucket(config.bucket_size),
+    );
+
+    state
+        .store
+        .increment_authorization_count(
+            &key,
+            is_success,
+            config.bucket_size.saturating_mul(config.bucket_count),
+        )
+        .await
+        .map_err(|error| logger::error!(success_rate_update_error=?error))
+        .ok();
+}
+
+/// Returns the number of authorizations through the connector over the most recent buckets.
+async fn get_windowed_authorization_count(
+    state: &AppState,
+    merchant_id: &str,
+    connector: &str,
+    routing_input: &api::RoutingEvaluateRequest,
+) -> CustomResult<storage::AuthorizationCount, errors::StorageError> {
+    let config = &state.conf.success_rate_routing;
+    let current_bucket = get_current_bucket(config.bucket_size);
+    let mut count = storage::AuthorizationCount::default();
+
+    for bucket in (current_bucket - config.bucket_count + 1)..=current_bucket {
+        let key = get_authorization_count_key(merchant_id, connector, routing_input, bucket);
+        let bucket_count = state.store.get_authorization_count(&key).await?;
+        count.total += bucket_count.total;
+        count.success += bucket_count.success;
+    }
+
+    Ok(count)
+}
+
+/// Picks the connector with the highest authorization success rate over the recent window, for
+/// the card network and country of the payment.
+///
+/// A share of the payments (`exploration_percentage`) is spread evenly across all the connectors,
+/// so that the success rates of the connectors which are not preferred currently keep getting
+/// updated. Connectors which have processed fewer payments than the configured minimum sample
+/// size in the window are only routed to through exploration, and the first connector is chosen
+/// when the success rates of none of the connectors are known yet.
+#[instrument(skip_all)]
+pub async fn perform_success_rate_routing(
+    state: &AppState,
+    merchant_id: &str,
+    algorithm: &api::SuccessRateRoutingAlgorithm,
+    routing_input: &api::RoutingEvaluateRequest,
+    payment_hash: u32,
+) -> RouterResult<api_enums::RoutableConnectors> {
+    let default_connector = algorithm
+        .connectors
+        .first()
+        .copied()
+        .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("No connectors configured for success rate routing")?;
+
+    if payment_hash % 100 < u32::from(algorithm.exploration_percentage) {
+        let index =
+            usize::try_from(payment_hash / 100).unwrap_or_default() % algorithm.connectors.len();
+        return Ok(algorithm
+            .connectors
+            .get(index)
+            .copied()
+            .unwrap_or(default_connector));
+    }
+
+    let min_sample_size = state.conf.success_rate_routing.min_sample_size.max(1);
+    let mut best_connector: Option<(storage::AuthorizationCount, api_enums::RoutableConnectors)> =
+        None;
+
+    for connector in algorithm.connectors.iter() {
+        let count = match get_windowed_authorization_count(
+            state,
+            merchant_id,
+            &connector.to_string(),
+            routing_input,
+        )
+        .await
+        {
+            Ok(count) => count,
+            Err(error) => {
+                logger::error!(success_rate_fetch_error=?error);
+                continue;
+            }
+        };
+
+        if count.total < min_sample_size {
+            continue;
+        }
+
+        // Compares success / total of both the connectors, without converting them to floats
+        let is_better = best_connector.map_or(true, |(best_count, _)| {
+            i128::from(count.success) * i128::from(best_count.total)
+                > i128::from(best_count.success) * i128::from(count.total)
+        });
+        if is_better {
+            best_connector = Some((count, *connector));
+        }
+    }
+
+    logger::debug!(success_rate_routing=?best_connector);
+
+    Ok(best_connector
+        .map(|(_, connector)| connector)
+        .unwrap_or(default_connector))
+}
@@ -0,0 +1,142 @@
+//! Encryption of the connector credentials of merchants at rest.
+//!
+//! The credentials are encrypted with a data key of the vault, which is in turn encrypted with
+//! the master key. This is the only module which decrypts the credentials, everything else reads
+//! them through [`decrypt_connector_account_details`].
+
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        card_vault,
+        errors::{self, RouterResponse, RouterResult},
+    },
+    routes::AppState,
+    services::ApplicationResponse,
+    types::{api, storage},
+    utils::{Encode, StringExt},
+};
+
+/// Number of merchant connector accounts re-encrypted together during a key rotation
+const KEY_ROTATION_BATCH_SIZE: i64 = 100;
+
+/// Connector account details encrypted with a data key of the vault.
+pub struct EncryptedConnectorAccountDetails {
+    pub key_id: String,
+    pub encrypted_connector_account_details: Vec<u8>,
+}
+
+#[instrument(skip_all)]
+pub async fn encrypt_connector_account_details(
+    state: &AppState,
+    connector_account_details: &Secret<serde_json::Value>,
+) -> RouterResult<EncryptedConnectorAccountDetails> {
+    let connector_account_details =
+        Encode::<serde_json::Value>::encode_to_string_of_json(connector_account_details.peek())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode connector account details")?;
+    let (key_id, encrypted_connector_account_details) =
+        card_vault::encrypt_with_active_data_key(state, &connector_account_details).await?;
+
+    Ok(EncryptedConnectorAccountDetails {
+        key_id,
+        encrypted_connector_account_details,
+    })
+}
+
+/// Returns the connector account details of the merchant connector account. The details stored
+/// before encryption was introduced are returned as is.
+#[instrument(skip_all)]
+pub async fn decrypt_connector_account_details(
+    state: &AppState,
+    merchant_connector_account: &storage::MerchantConnectorAccount,
+) -> RouterResult<serde_json::Value> {
+    match (
+        &merchant_connector_account.key_id,
+        &merchant_connector_account.encrypted_connector_account_details,
+    ) {
+        (Some(key_id), Some(encrypted_connector_account_details)) => {
+            card_vault::decrypt_with_data_key(
+                state,
+                key_id,
+                encrypted_connector_account_details.clone(),
+            )
+            .await
+            .attach_printable("Failed to decrypt connector account details")?
+            .parse_struct("ConnectorAccountDetails")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse connector account details")
+        }
+        _ => Ok(merchant_connector_account.connector_account_details.clone()),
+    }
+}
+
+/// Rotates the data key the connector credentials are encrypted with, and re-encrypts the
+/// credentials of every merchant connector account with the new data key.
+///
+/// The accounts are re-encrypted one at a time, updating the encrypted credentials along with the
+/// data key, so the credentials remain readable while the rotation is in progress. Credentials
+/// which are not encrypted yet are encrypted as well.
+#[instrument(skip_all)]
+pub async fn rotate_connector_credentials_key(
+    state: &AppState,
+) -> RouterResponse<api::ConnectorCredentialsKeyRotateResponse> {
+    let db = &*state.store;
+    let key_id = card_vault::rotate_data_key(state).await?;
+    let mut accounts_reencrypted = 0;
+
+    loop {
+        let merchant_connector_accounts = db
+            .find_merchant_connector_accounts_not_encrypted_with_key(
+                &key_id,
+                KEY_ROTATION_BATCH_SIZE,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch merchant connector accounts to re-encrypt")?;
+
+        if merchant_connector_accounts.is_empty() {
+            break;
+        }
+
+        for merchant_connector_account in merchant_connector_accounts {
+            let connector_account_details =
+                decrypt_connector_account_details(state, &merchant_connector_account).await?;
+            let connector_account_details =
+                Encode::<serde_json::Value>::encode_to_string_of_json(&connector_account_details)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to encode connector account details")?;
+            let encrypted_connector_account_details =
+                card_vault::encrypt_with_data_key(state, &key_id, &connector_account_details)
+                    .await?;
+
+            let merchant_connector_id = merchant_connector_account.merchant_connector_id.clone();
+            db.update_merchant_connector_account(
+                merchant_connector_account,
+                storage::MerchantConnectorAccountUpdate::EncryptionUpdate {
+                    key_id: key_id.clone(),
+                    encrypted_connector_account_details,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Failed to re-encrypt merchant connector account: {merchant_connector_id}")
+            })?;
+            accounts_reencrypted += 1;
+        }
+    }
+
+    logger::info!(
+        "Re-encrypted the credentials of {accounts_reencrypted} merchant connector accounts with {key_id}"
+    );
+
+    Ok(ApplicationResponse::Json(
+        api::ConnectorCredentialsKeyRotateResponse {
+            key_id,
+            accounts_reencrypted,
+        },
+    ))
+}
@@ -0,0 +1,280 @@
+use argon2::{
+    password_hash::{
+        rand_core::OsRng, Error as PasswordHashError, PasswordHash, PasswordHasher,
+        PasswordVerifier, SaltString,
+    },
+    Argon2,
+};
+use common_utils::{date_time, pii};
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{PeekInterface, Secret, StrongSecret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::AppState,
+    services::{
+        authentication::{self as auth, AuthToken, TokenType},
+        authorization::Permission,
+        ApplicationResponse,
+    },
+    types::{
+        api::{self, enums as api_enums},
+        storage,
+        transformers::ForeignInto,
+    },
+    utils,
+};
+
+const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// Emails are compared case insensitively, so they are stored in lowercase.
+fn get_normalized_email(email: &Secret<String, pii::Email>) -> RouterResult<String> {
+    let email = email.peek().trim().to_lowercase();
+    utils::validate_email(&email).change_context(errors::ApiErrorResponse::InvalidDataFormat {
+        field_name: "email".to_string(),
+        expected_format: "valid email address".to_string(),
+    })?;
+    Ok(email)
+}
+
+fn hash_password(password: &Secret<String>) -> RouterResult<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.peek().as_bytes(), &salt)
+        .map(|password_hash| password_hash.to_string())
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash password")
+}
+
+fn verify_password(password: &Secret<String>, password_hash: &str) -> RouterResult<()> {
+    let password_hash = PasswordHash::new(password_hash)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse stored password hash")?;
+
+    match Argon2::default().verify_password(password.peek().as_bytes(), &password_hash) {
+        Ok(()) => Ok(()),
+        Err(PasswordHashError::Password) => {
+            Err(report!(errors::ApiErrorResponse::InvalidCredentials))
+        }
+        Err(error) => Err(error)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to verify password"),
+    }
+}
+
+fn generate_auth_tokens(
+    state: &AppState,
+    user: storage::User,
+    user_role: Option<storage::UserRole>,
+) -> RouterResult<api::AuthTokenResponse> {
+    let user_auth_config = &state.conf.user_auth;
+    let now = date_time::now_unix_timestamp();
+    let merchant_id = user_role
+        .as_ref()
+        .map(|user_role| user_role.merchant_id.clone());
+    let role: Option<api_enums::DashboardRole> =
+        user_role.map(|user_role| user_role.role.foreign_into());
+
+    let access_token = auth::encode_jwt(
+        &AuthToken {
+            user_id: user.user_id.clone(),
+            merchant_id: merchant_id.clone(),
+            role,
+            exp: now + user_auth_config.access_token_validity,
+            token_type: TokenType::Access,
+        },
+        state,
+    )?;
+    let refresh_token = auth::encode_jwt(
+        &AuthToken {
+            user_id: user.user_id.clone(),
+            merchant_id: merchant_id.clone(),
+            role,
+            exp: now + user_auth_config.refresh_token_validity,
+            token_type: TokenType::Refresh,
+        },
+        state,
+    )?;
+
+    Ok(api::AuthTokenResponse {
+        user_id: user.user_id,
+        email: user.email.into(),
+        name: user.name,
+        merchant_id,
+        role,
+        access_token: StrongSecret::new(access_token),
+        expires_in: user_auth_config.access_token_validity,
+        refresh_token: StrongSecret::new(refresh_token),
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn signup(
+    state: &AppState,
+    req: api::SignUpRequest,
+) -> RouterResponse<api::AuthTokenResponse> {
+    let email = get_normalized_email(&req.email)?;
+    if req.password.peek().chars().count() < MIN_PASSWORD_LENGTH {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("password must be at least {MIN_PASSWORD_LENGTH} characters long"),
+        }));
+    }
+
+    let user = storage::UserNew {
+        user_id: utils::generate_id(consts::ID_LENGTH, "user"),
+        email,
+        name: req.name,
+        password: hash_password(&req.password)?,
+    };
+    let user =
+        state.store.insert_user(user).await.map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicateUser)
+        })?;
+
+    generate_auth_tokens(state, user, None).map(ApplicationResponse::Json)
+}
+
+/// Signs the user in to the merchant account requested, or to the first merchant account they
+/// were given a role for.
+#[instrument(skip_all)]
+pub async fn signin(
+    state: &AppState,
+    req: api::SignInRequest,
+) -> RouterResponse<api::AuthTokenResponse> {
+    let email = get_normalized_email(&req.email)?;
+    let db = &*state.store;
+
+    let user = db.find_user_by_email(&email).await.map_err(|error| {
+        error.to_not_found_response(errors::ApiErrorResponse::InvalidCredentials)
+    })?;
+    verify_password(&req.password, &user.password)?;
+
+    let user_role = match req.merchant_id {
+        Some(merchant_id) => Some(
+            db.find_user_role_by_user_id_merchant_id(&user.user_id, &merchant_id)
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::InsufficientPermission {
+                        permission: Permission::MerchantAccountRead.to_string(),
+                    })
+                })?,
+        ),
+        None => db
+            .list_user_roles_by_user_id(&user.user_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list roles of the user")?
+            .into_iter()
+            .next(),
+    };
+
+    generate_auth_tokens(state, user, user_role).map(ApplicationResponse::Json)
+}
+
+/// Issues new tokens for the merchant account the refresh token was issued for. The user and
+/// their role are fetched again, so that a user who was removed from the merchant account cannot
+/// continue to access it.
+#[instrument(skip_all)]
+pub async fn refresh_token(
+    state: &AppState,
+    req: api::RefreshTokenRequest,
+) -> RouterResponse<api::AuthTokenResponse> {
+    let token = auth::decode_jwt::<AuthToken>(req.refresh_token.peek(), state)?;
+    if token.token_type != TokenType::Refresh {
+        return Err(report!(errors::ApiErrorResponse::InvalidJwtToken))
+            .attach_printable("Access token used to refresh tokens");
+    }
+    let db = &*state.store;
+
+    let user = db
+        .find_user_by_user_id(&token.user_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::InvalidJwtToken))?;
+
+    let user_role = match token.merchant_id {
+        Some(merchant_id) => Some(
+            db.find_user_role_by_user_id_merchant_id(&user.user_id, &merchant_id)
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::InvalidJwtToken)
+                })?,
+        ),
+        None => None,
+    };
+
+    generate_auth_tokens(state, user, user_role).map(ApplicationResponse::Json)
+}
+
+/// Gives the user the role for the merchant account, replacing any role they already have for
+/// it.
+#[instrument(skip_all)]
+pub async fn set_user_role(
+    state: &AppState,
+    merchant_id: String,
+    req: api::UserRoleRequest,
+) -> RouterResponse<api::UserRoleResponse> {
+    let email = get_normalized_email(&req.email)?;
+    let db = &*state.store;
+
+    let user = db
+        .find_user_by_email(&email)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::UserNotFound))?;
+
+    let user_role = match db
+        .find_user_role_by_user_id_merchant_id(&user.user_id, &merchant_id)
+        .await
+    {
+        Ok(_) => db
+            .update_user_role_by_user_id_merchant_id(
+                &user.user_id,
+                &merchant_id,
+                storage::UserRoleUpdate::RoleUpdate {
+                    role: req.role.foreign_into(),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update role of the user")?,
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_user_role(storage::UserRoleNew {
+                user_id: user.user_id,
+                merchant_id,
+                role: req.role.foreign_into(),
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert role of the user")?,
+        Err(error) => {
+            return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Failed to fetch role of the user")
+        }
+    };
+
+    Ok(ApplicationResponse::Json(api::UserRoleResponse {
+        user_id: user_role.user_id,
+        merchant_id: user_role.merchant_id,
+        role: user_role.role.foreign_into(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_password_hashing() {
+        let password = Secret::new("correct horse battery staple".to_string());
+        let password_hash = hash_password(&password).unwrap();
+
+        assert_ne!(password_hash, password.peek().as_str());
+        assert!(verify_password(&password, &password_hash).is_ok());
+        assert!(verify_password(&Secret::new("incorrect".to_string()), &password_hash).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use api_models::enums::{DisputeStage, DisputeStatus};
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, pii};
 use error_stack::ResultExt;
 use router_env::{instrument, tracing};
 
@@ -11,7 +11,7 @@ use crate::{
     core::errors::{self, RouterResult},
     routes::AppState,
     types::{
-        self,
+        self, api,
         storage::{self, enums},
     },
     utils::{generate_id, OptionExt, ValueExt},
@@ -29,9 +29,8 @@ pub async fn construct_refund_router_data<'a, F>(
     refund: &'a storage::Refund,
     creds_identifier: Option<String>,
 ) -> RouterResult<types::RefundsRouterData<F>> {
-    let db = &*state.store;
     let merchant_connector_account = helpers::get_merchant_connector_account(
-        db,
+        state,
         merchant_account.merchant_id.as_str(),
         connector_id,
         creds_identifier,
@@ -63,6 +62,8 @@ pub async fn construct_refund_router_data<'a, F>(
         description: None,
         return_url: payment_intent.return_url.clone(),
         payment_method_id: payment_attempt.payment_method_id.clone(),
+        connector_raw_response: None,
+        connector_debug_mode: payment_intent.connector_debug_mode,
         // Does refund need shipping/billing address ?
         address: PaymentAddress::default(),
         auth_type: payment_attempt.authentication_type.unwrap_or_default(),
@@ -91,6 +92,171 @@ pub async fn construct_refund_router_data<'a, F>(
     Ok(router_data)
 }
 
+const IRRELEVANT_PAYMENT_ID_IN_MANDATE_REVOKE_FLOW: &str =
+    "irrelevant_payment_id_in_mandate_revoke_flow";
+const IRRELEVANT_ATTEMPT_ID_IN_MANDATE_REVOKE_FLOW: &str =
+    "irrelevant_attempt_id_in_mandate_revoke_flow";
+
+#[instrument(skip_all)]
+pub async fn construct_mandate_revoke_router_data(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mandate: &storage::Mandate,
+    payment_method: enums::PaymentMethod,
+) -> RouterResult<types::MandateRevokeRouterData> {
+    let merchant_connector_account = helpers::get_merchant_connector_account(
+        state,
+        merchant_account.merchant_id.as_str(),
+        &mandate.connector,
+        None,
+    )
+    .await?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: mandate.connector.clone(),
+        payment_id: IRRELEVANT_PAYMENT_ID_IN_MANDATE_REVOKE_FLOW.to_string(),
+        attempt_id: IRRELEVANT_ATTEMPT_ID_IN_MANDATE_REVOKE_FLOW.to_string(),
+        status: enums::AttemptStatus::default(),
+        payment_method,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        payment_method_id: Some(mandate.payment_method_id.clone()),
+        connector_raw_response: None,
+        connector_debug_mode: false,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        amount_captured: None,
+        request: types::MandateRevokeRequestData {
+            mandate_id: mandate.mandate_id.clone(),
+            connector_mandate_id: mandate.connector_mandate_id.clone(),
+        },
+        // Connectors which do not support revoking a mandate leave this untouched, in which case
+        // the mandate is revoked only on our end.
+        response: Ok(types::MandateRevokeResponseData {
+            mandate_status: enums::MandateStatus::Revoked,
+        }),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+    };
+
+    Ok(router_data)
+}
+
+const IRRELEVANT_PAYMENT_ID_IN_VERIFY_CREDENTIALS_FLOW: &str =
+    "irrelevant_payment_id_in_verify_credentials_flow";
+const IRRELEVANT_ATTEMPT_ID_IN_VERIFY_CREDENTIALS_FLOW: &str =
+    "irrelevant_attempt_id_in_verify_credentials_flow";
+
+pub fn construct_verify_credentials_router_data(
+    merchant_id: &str,
+    connector: &str,
+    auth_type: types::ConnectorAuthType,
+    connector_meta_data: Option<pii::SecretSerdeValue>,
+) -> types::VerifyCredentialsRouterData {
+    types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_id.to_string(),
+        connector: connector.to_string(),
+        payment_id: IRRELEVANT_PAYMENT_ID_IN_VERIFY_CREDENTIALS_FLOW.to_string(),
+        attempt_id: IRRELEVANT_ATTEMPT_ID_IN_VERIFY_CREDENTIALS_FLOW.to_string(),
+        status: enums::AttemptStatus::default(),
+        payment_method: enums::PaymentMethod::default(),
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        payment_method_id: None,
+        connector_raw_response: None,
+        connector_debug_mode: false,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data,
+        amount_captured: None,
+        request: types::VerifyCredentialsRequestData,
+        // Connectors which do not support verifying credentials leave this untouched, in which
+        // case the credentials are only checked to be in the expected format.
+        response: Ok(types::VerifyCredentialsResponseData { verified: false }),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+    }
+}
+
+const IRRELEVANT_ATTEMPT_ID_IN_PAYOUT_FLOW: &str = "irrelevant_attempt_id_in_payout_flow";
+
+#[instrument(skip_all)]
+pub async fn construct_payout_router_data<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout: &storage::Payout,
+    payout_method_data: api::PayoutMethodData,
+    email: Option<masking::Secret<String, pii::Email>>,
+) -> RouterResult<types::PayoutsRouterData<F>> {
+    let merchant_connector_account = helpers::get_merchant_connector_account(
+        state,
+        merchant_account.merchant_id.as_str(),
+        &payout.connector,
+        None,
+    )
+    .await?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: payout.connector.clone(),
+        payment_id: payout.payout_id.clone(),
+        attempt_id: IRRELEVANT_ATTEMPT_ID_IN_PAYOUT_FLOW.to_string(),
+        status: enums::AttemptStatus::default(),
+        payment_method: enums::PaymentMethod::default(),
+        connector_auth_type: auth_type,
+        description: payout.description.clone(),
+        return_url: None,
+        payment_method_id: Some(payout.payout_method_id.clone()),
+        connector_raw_response: None,
+        connector_debug_mode: false,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        amount_captured: None,
+        request: types::PayoutsData {
+            payout_id: payout.payout_id.clone(),
+            amount: payout.amount,
+            currency: payout.currency,
+            payout_type: payout.payout_type,
+            payout_method_data,
+            customer_id: payout.customer_id.clone(),
+            email,
+            connector_payout_id: payout.connector_payout_id.clone(),
+            description: payout.description.clone(),
+        },
+        // Connectors which cannot retrieve a payout leave this untouched, in which case the
+        // status of the payout is only updated through webhooks.
+        response: Ok(types::PayoutsResponseData {
+            status: payout.status,
+            connector_payout_id: payout.connector_payout_id.clone(),
+        }),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+    };
+
+    Ok(router_data)
+}
+
 pub fn get_or_generate_id(
     key: &str,
     provided_id: &Option<String>,
@@ -12,11 +12,12 @@ use crate::{
     consts,
     core::{
         errors::{self, CustomResult, RouterResponse},
-        payments, refunds,
+        ledger, payments, payouts, refunds,
     },
     db::StorageInterface,
     logger,
     routes::AppState,
+    scheduler::{metrics as scheduler_metrics, process_data, utils as pt_utils},
     services,
     types::{
         api,
@@ -34,8 +35,27 @@ async fn payments_incoming_webhook_flow<W: api::OutgoingWebhookType>(
     merchant_account: storage::MerchantAccount,
     webhook_details: api::IncomingWebhookDetails,
     source_verified: bool,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let consume_or_trigger_flow = if source_verified {
+    let state = AppState {
+        event_source: enums::PaymentEventSource::Webhook,
+        ..state
+    };
+
+    // A returned debit is not reflected in the payment object of the connector, so the payment is
+    // failed here and its response is built without calling the connector
+    let debit_returned = source_verified
+        && event_type == api_models::webhooks::IncomingWebhookEvent::PaymentDebitReturned
+        && mark_bank_debit_returned(
+            &state,
+            webhook_details.object_reference_id.clone(),
+            &merchant_account,
+        )
+        .await?;
+
+    let consume_or_trigger_flow = if debit_returned {
+        payments::CallConnectorAction::Avoid
+    } else if source_verified {
         payments::CallConnectorAction::HandleResponse(webhook_details.resource_object)
     } else {
         payments::CallConnectorAction::Trigger
@@ -50,7 +70,7 @@ async fn payments_incoming_webhook_flow<W: api::OutgoingWebhookType>(
                 api::PaymentsRetrieveRequest {
                     resource_id: id,
                     merchant_id: Some(merchant_account.merchant_id.clone()),
-                    force_sync: true,
+                    force_sync: !debit_returned,
                     connector: None,
                     param: None,
                     merchant_connector_details: None,
@@ -97,6 +117,81 @@ async fn payments_incoming_webhook_flow<W: api::OutgoingWebhookType>(
     Ok(())
 }
 
+/// Fails a bank debit payment whose debit was returned by the bank of the customer, after the
+/// payment was initiated or had succeeded. Returns `false` if the payment is not a bank debit
+/// payment which can be returned.
+async fn mark_bank_debit_returned(
+    state: &AppState,
+    object_reference_id: api_models::webhooks::ObjectReferenceId,
+    merchant_account: &storage::MerchantAccount,
+) -> CustomResult<bool, errors::WebhooksFlowError> {
+    let db = &*state.store;
+    let payment_attempt = get_payment_attempt_from_object_reference_id(
+        state.clone(),
+        object_reference_id,
+        merchant_account,
+    )
+    .await?;
+
+    if !payments::state_machine::is_returnable_bank_debit(&payment_attempt) {
+        logger::warn!(
+            payment_attempt_status=?payment_attempt.status,
+            "Ignoring returned debit for a payment which is not an initiated bank debit"
+        );
+        return Ok(false);
+    }
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_attempt.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)?;
+
+    let previous_statuses =
+        payments::payment_events::PaymentStatuses::of(&payment_intent, &payment_attempt);
+    let payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_attempt,
+            storage::PaymentAttemptUpdate::ErrorUpdate {
+                connector: None,
+                status: enums::AttemptStatus::Failure,
+                error_code: Some(consts::BANK_DEBIT_RETURNED_ERROR_CODE.to_string()),
+                error_message: Some(consts::BANK_DEBIT_RETURNED_ERROR_MESSAGE.to_string()),
+                unified_code: None,
+                unified_message: None,
+                connector_raw_response: None,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?;
+
+    let payment_intent = db
+        .update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: enums::IntentStatus::Failed,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?;
+
+    payments::payment_events::record_status_change(
+        state,
+        "PaymentDebitReturned",
+        previous_statuses,
+        &payment_intent,
+        &payment_attempt,
+    )
+    .await;
+
+    Ok(true)
+}
+
 #[instrument(skip_all)]
 async fn refunds_incoming_webhook_flow<W: api::OutgoingWebhookType>(
     state: AppState,
@@ -145,7 +240,7 @@ async fn refunds_incoming_webhook_flow<W: api::OutgoingWebhookType>(
                 .into_report()
                 .change_context(errors::WebhooksFlowError::RefundsCoreFailed)?,
         };
-        state
+        let updated_refund = state
             .store
             .update_refund(
                 refund.to_owned(),
@@ -159,7 +254,16 @@ async fn refunds_incoming_webhook_flow<W: api::OutgoingWebhookType>(
                     "Failed while updating refund: refund_id: {}",
                     refund_id.to_owned()
                 )
-            })?
+            })?;
+        refunds::update_amount_refunded_on_payment_attempt(
+            &state,
+            &merchant_account,
+            &updated_refund,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::RefundsCoreFailed)?;
+        ledger::record_refund(&*state.store, &updated_refund).await;
+        updated_refund
     } else {
         refunds::refund_retrieve_core(
             &state,
@@ -198,6 +302,91 @@ async fn refunds_incoming_webhook_flow<W: api::OutgoingWebhookType>(
     Ok(())
 }
 
+#[instrument(skip_all)]
+async fn payouts_incoming_webhook_flow<W: api::OutgoingWebhookType>(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    connector_name: &str,
+    source_verified: bool,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let db = &*state.store;
+    let payout = match webhook_details.object_reference_id {
+        api_models::webhooks::ObjectReferenceId::PayoutId(
+            api_models::webhooks::PayoutIdType::ConnectorPayoutId(id),
+        ) => db
+            .find_payout_by_merchant_id_connector_payout_id_connector(
+                &merchant_account.merchant_id,
+                &id,
+                connector_name,
+            )
+            .await
+            .change_context(errors::WebhooksFlowError::ResourceNotFound)
+            .attach_printable_lazy(|| "Failed fetching the payout")?,
+        api_models::webhooks::ObjectReferenceId::PayoutId(
+            api_models::webhooks::PayoutIdType::PayoutId(id),
+        ) => db
+            .find_payout_by_merchant_id_payout_id(&merchant_account.merchant_id, &id)
+            .await
+            .change_context(errors::WebhooksFlowError::ResourceNotFound)
+            .attach_printable_lazy(|| "Failed fetching the payout")?,
+        _ => Err(errors::WebhooksFlowError::PayoutsCoreFailed).into_report()?,
+    };
+    let payout_id = payout.payout_id.to_owned();
+    //if source verified then update payout status else trigger payout sync
+    let updated_payout = if source_verified {
+        let payout_update = storage::PayoutUpdate::StatusUpdate {
+            status: event_type
+                .foreign_try_into()
+                .into_report()
+                .change_context(errors::WebhooksFlowError::PayoutsCoreFailed)?,
+        };
+        db.update_payout(payout, payout_update)
+            .await
+            .change_context(errors::WebhooksFlowError::PayoutsCoreFailed)
+            .attach_printable_lazy(|| {
+                format!("Failed while updating payout: payout_id: {payout_id}")
+            })?
+    } else {
+        let response = payouts::retrieve_payout(
+            &state,
+            merchant_account.clone(),
+            api_models::payouts::PayoutRetrieveRequest {
+                payout_id: payout_id.to_owned(),
+                force_sync: true,
+            },
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::PayoutsCoreFailed)
+        .attach_printable_lazy(|| format!("Failed while syncing payout: payout_id: {payout_id}"))?;
+        // Retrieving the payout notifies the merchant of any change in its status
+        return match response {
+            services::ApplicationResponse::Json(_) => Ok(()),
+            _ => Err(errors::WebhooksFlowError::PayoutsCoreFailed).into_report(),
+        };
+    };
+    ledger::record_payout(db, &updated_payout).await;
+    let event_type: enums::EventType = updated_payout
+        .status
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::PayoutsCoreFailed)?;
+    let payout_response: api_models::payouts::PayoutResponse = updated_payout.foreign_into();
+    create_event_and_trigger_outgoing_webhook::<W>(
+        state,
+        merchant_account,
+        event_type,
+        enums::EventClass::Payouts,
+        None,
+        payout_id,
+        enums::EventObjectType::PayoutDetails,
+        api::OutgoingWebhookContent::PayoutDetails(Box::new(payout_response)),
+    )
+    .await?;
+    Ok(())
+}
+
 async fn get_payment_attempt_from_object_reference_id(
     state: AppState,
     object_reference_id: api_models::webhooks::ObjectReferenceId,
@@ -334,6 +523,7 @@ async fn disputes_incoming_webhook_flow<W: api::OutgoingWebhookType>(
             event_type.clone(),
         )
         .await?;
+        ledger::record_dispute(&*state.store, &dispute_object).await;
         let disputes_response = Box::new(
             dispute_object
                 .clone()
@@ -367,7 +557,7 @@ async fn disputes_incoming_webhook_flow<W: api::OutgoingWebhookType>(
 
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
-async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookType>(
+pub(crate) async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookType>(
     state: AppState,
     merchant_account: storage::MerchantAccount,
     event_type: enums::EventType,
@@ -394,10 +584,6 @@ async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookType>(
         .change_context(errors::WebhooksFlowError::WebhookEventCreationFailed)?;
 
     if state.conf.webhooks.outgoing_enabled {
-        let arbiter = actix::Arbiter::try_current()
-            .ok_or(errors::WebhooksFlowError::ForkFlowFailed)
-            .into_report()?;
-
         let outgoing_webhook = api::OutgoingWebhook {
             merchant_id: merchant_account.merchant_id.clone(),
             event_id: event.event_id,
@@ -406,7 +592,8 @@ async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookType>(
             timestamp: event.created_at,
         };
 
-        arbiter.spawn(async move {
+        let background_tasks = state.background_tasks.clone();
+        let webhook_task = async move {
             let result =
                 trigger_webhook_to_merchant::<W>(merchant_account, outgoing_webhook, state.store)
                     .await;
@@ -414,19 +601,80 @@ async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookType>(
             if let Err(e) = result {
                 logger::error!(?e);
             }
-        });
+        };
+
+        background_tasks.spawn(webhook_task);
     }
 
     Ok(())
 }
 
-async fn trigger_webhook_to_merchant<W: api::OutgoingWebhookType>(
-    merchant_account: storage::MerchantAccount,
-    webhook: api::OutgoingWebhook,
-    _db: Box<dyn StorageInterface>,
-) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_details_json = merchant_account
-        .webhook_details
+async fn find_payment_profile_id(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &str,
+) -> CustomResult<Option<String>, errors::WebhooksFlowError> {
+    db.find_payment_intent_by_payment_id_merchant_id(
+        payment_id,
+        &merchant_account.merchant_id,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .map(|payment_intent| payment_intent.profile_id)
+    .change_context(errors::WebhooksFlowError::BusinessProfileLookupFailed)
+}
+
+/// Webhooks of payments made with a business profile, and of their refunds and disputes, are sent
+/// with the webhook details of the business profile when it has them.
+async fn get_webhook_profile_id(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    content: &api::OutgoingWebhookContent,
+) -> CustomResult<Option<String>, errors::WebhooksFlowError> {
+    match content {
+        api::OutgoingWebhookContent::PaymentDetails(payment) => Ok(payment.profile_id.clone()),
+        api::OutgoingWebhookContent::RefundDetails(refund) => {
+            find_payment_profile_id(db, merchant_account, &refund.payment_id).await
+        }
+        api::OutgoingWebhookContent::DisputeDetails(dispute) => {
+            find_payment_profile_id(db, merchant_account, &dispute.payment_id).await
+        }
+        api::OutgoingWebhookContent::PaymentScheduleDetails(_)
+        | api::OutgoingWebhookContent::PayoutDetails(_) => Ok(None),
+    }
+}
+
+/// Returns the URL the webhook is sent to, taken from the webhook details of the business profile
+/// when it has them and from those of the merchant otherwise.
+pub(crate) async fn get_webhook_url(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    profile_id: Option<&str>,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    // The webhooks of payments whose business profile has since been deleted are sent with the
+    // webhook details of the merchant
+    let business_profile = match profile_id {
+        Some(profile_id) => match db
+            .find_business_profile_by_profile_id_merchant_id(
+                profile_id,
+                &merchant_account.merchant_id,
+            )
+            .await
+        {
+            Ok(business_profile) => Some(business_profile),
+            Err(error) if error.current_context().is_db_not_found() => None,
+            Err(error) => {
+                return Err(
+                    error.change_context(errors::WebhooksFlowError::BusinessProfileLookupFailed)
+                )
+            }
+        },
+        None => None,
+    };
+
+    let webhook_details_json = business_profile
+        .and_then(|business_profile| business_profile.webhook_details)
+        .or_else(|| merchant_account.webhook_details.clone())
         .get_required_value("webhook_details")
         .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
 
@@ -435,34 +683,47 @@ async fn trigger_webhook_to_merchant<W: api::OutgoingWebhookType>(
             .parse_value("WebhookDetails")
             .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
 
-    let webhook_url = webhook_details
+    webhook_details
         .webhook_url
         .get_required_value("webhook_url")
         .change_context(errors::WebhooksFlowError::MerchantWebhookURLNotConfigured)
-        .map(ExposeInterface::expose)?;
-
-    let transformed_outgoing_webhook = W::from(webhook);
+        .map(ExposeInterface::expose)
+}
 
+/// Sends the webhook to the merchant, which succeeds only once the merchant acknowledges it.
+pub(crate) async fn send_webhook_to_merchant(
+    webhook_url: &str,
+    request_body: &serde_json::Value,
+) -> CustomResult<(), errors::WebhooksFlowError> {
     let response = reqwest::Client::new()
-        .post(&webhook_url)
+        .post(webhook_url)
         .header(reqwest::header::CONTENT_TYPE, "application/json")
-        .json(&transformed_outgoing_webhook)
+        .json(request_body)
         .timeout(core::time::Duration::from_millis(
             OUTGOING_WEBHOOK_TIMEOUT_MS,
         ))
         .send()
         .await;
 
+    let outcome = match response {
+        Err(_) => "call_failed",
+        Ok(ref res) if !res.status().is_success() => "not_received",
+        Ok(_) => "delivered",
+    };
+    metrics::OUTGOING_WEBHOOK_DELIVERY_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[router_env::opentelemetry::KeyValue::new("outcome", outcome)],
+    );
+
     match response {
         Err(e) => {
-            // [#217]: Schedule webhook for retry.
             Err(e)
                 .into_report()
                 .change_context(errors::WebhooksFlowError::CallToMerchantFailed)?;
         }
         Ok(res) => {
             if !res.status().is_success() {
-                // [#217]: Schedule webhook for retry.
                 Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()?;
             }
         }
@@ -471,6 +732,88 @@ async fn trigger_webhook_to_merchant<W: api::OutgoingWebhookType>(
     Ok(())
 }
 
+async fn trigger_webhook_to_merchant<W: api::OutgoingWebhookType>(
+    merchant_account: storage::MerchantAccount,
+    webhook: api::OutgoingWebhook,
+    db: Box<dyn StorageInterface>,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let profile_id = get_webhook_profile_id(&*db, &merchant_account, &webhook.content).await?;
+    let webhook_url = get_webhook_url(&*db, &merchant_account, profile_id.as_deref()).await?;
+
+    let event_id = webhook.event_id.clone();
+    let request_body = serde_json::to_value(W::from(webhook))
+        .into_report()
+        .change_context(errors::WebhooksFlowError::WebhookEventObjectCreationFailed)?;
+
+    let result = send_webhook_to_merchant(&webhook_url, &request_body).await;
+
+    // Webhooks which the merchant did not receive are retried by the scheduler
+    if result.is_err() {
+        let tracking_data = storage::OutgoingWebhookTrackingData {
+            merchant_id: merchant_account.merchant_id,
+            event_id,
+            profile_id,
+            request_body,
+        };
+        if let Err(error) = add_outgoing_webhook_retry_task(&*db, tracking_data).await {
+            logger::error!(?error, "Failed to schedule retry of the webhook");
+        }
+    }
+
+    result
+}
+
+async fn add_outgoing_webhook_retry_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::OutgoingWebhookTrackingData,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let schedule_time = match pt_utils::get_retry_schedule_time(
+        db,
+        process_data::RetryPolicy::OutgoingWebhook,
+        &tracking_data.merchant_id,
+        0,
+    )
+    .await
+    {
+        Some(schedule_time) => schedule_time,
+        None => return Ok(()),
+    };
+
+    let runner = "OUTGOING_WEBHOOK_RETRY_WORKFLOW";
+    let task = "OUTGOING_WEBHOOK_RETRY";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &tracking_data.event_id,
+        &tracking_data.merchant_id,
+    );
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: process_tracker_id,
+        name: Some(String::from(task)),
+        tag: vec![String::from("WEBHOOK")],
+        runner: Some(String::from(runner)),
+        retry_count: 0,
+        schedule_time: Some(schedule_time),
+        rule: String::new(),
+        tracking_data: serde_json::to_value(tracking_data)
+            .into_report()
+            .change_context(errors::WebhooksFlowError::RetryTaskCreationFailed)?,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::WebhooksFlowError::RetryTaskCreationFailed)?;
+    scheduler_metrics::TASKS_ADDED_COUNT.add(&scheduler_metrics::CONTEXT, 1, &[]);
+
+    Ok(())
+}
+
 #[instrument(skip_all)]
 pub async fn webhooks_core<W: api::OutgoingWebhookType>(
     state: &AppState,
@@ -522,9 +865,20 @@ pub async fn webhooks_core<W: api::OutgoingWebhookType>(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Could not find event type in incoming webhook body")?;
 
+    if matches!(
+        event_type,
+        api_models::webhooks::IncomingWebhookEvent::EventNotSupported
+    ) {
+        logger::info!(
+            "Acknowledging incoming webhook with an unsupported event type from {}",
+            connector_name
+        );
+    }
+
     if !matches!(
         event_type,
         api_models::webhooks::IncomingWebhookEvent::EndpointVerification
+            | api_models::webhooks::IncomingWebhookEvent::EventNotSupported
     ) {
         let source_verified = connector
             .verify_webhook_source(
@@ -571,6 +925,7 @@ pub async fn webhooks_core<W: api::OutgoingWebhookType>(
                     merchant_account,
                     webhook_details,
                     source_verified,
+                    event_type,
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -601,6 +956,18 @@ pub async fn webhooks_core<W: api::OutgoingWebhookType>(
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Incoming webhook flow for disputes failed")?,
 
+                api::WebhookFlow::Payout => payouts_incoming_webhook_flow::<W>(
+                    state.clone(),
+                    merchant_account,
+                    webhook_details,
+                    connector_name,
+                    source_verified,
+                    event_type,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Incoming webhook flow for payouts failed")?,
+
                 api::WebhookFlow::ReturnResponse => {}
 
                 _ => Err(errors::ApiErrorResponse::InternalServerError)
@@ -1,26 +1,55 @@
 pub mod address;
 pub mod api_keys;
+pub mod balance_transaction;
+pub mod business_profile;
 pub mod cache;
+pub mod capture;
 pub mod cards_info;
+pub mod circuit_breaker;
 pub mod configs;
+pub mod connector_log;
 pub mod connector_response;
 pub mod customers;
 pub mod dispute;
+pub mod dummy_connector;
 pub mod ephemeral_key;
 pub mod events;
+pub mod forex;
+pub mod fraud_check;
+pub mod funding_source;
+pub mod health_check;
+pub mod idempotency;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod organization;
 pub mod payment_attempt;
+pub mod payment_event;
 pub mod payment_intent;
+pub mod payment_link;
+pub mod payment_lock;
 pub mod payment_method;
+pub mod payment_schedule;
+pub mod payment_split;
+pub mod payout;
+pub mod payout_method;
 pub mod process_tracker;
 pub mod queue;
+pub mod rate_limit;
+pub mod redaction_audit;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod routing_config;
+pub mod settlement_report;
+pub mod status_mismatch;
+pub mod success_rate;
+pub mod user;
+pub mod user_role;
+pub mod vault_data_key;
+pub mod vaulted_card;
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use futures::lock::Mutex;
 
@@ -40,24 +69,53 @@ pub trait StorageInterface:
     + dyn_clone::DynClone
     + address::AddressInterface
     + api_keys::ApiKeyInterface
+    + balance_transaction::BalanceTransactionInterface
+    + business_profile::BusinessProfileInterface
+    + capture::CaptureInterface
+    + circuit_breaker::CircuitBreakerInterface
     + configs::ConfigInterface
+    + connector_log::ConnectorLogInterface
     + connector_response::ConnectorResponseInterface
     + customers::CustomerInterface
     + dispute::DisputeInterface
+    + dummy_connector::DummyConnectorInterface
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
+    + forex::ForexInterface
+    + fraud_check::FraudCheckInterface
+    + funding_source::FundingSourceInterface
+    + health_check::HealthCheckInterface
+    + idempotency::IdempotencyInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
     + merchant_connector_account::ConnectorAccessToken
     + merchant_connector_account::MerchantConnectorAccountInterface
+    + organization::OrganizationInterface
     + payment_attempt::PaymentAttemptInterface
+    + payment_event::PaymentEventInterface
     + payment_intent::PaymentIntentInterface
+    + payment_link::PaymentLinkInterface
+    + payment_lock::PaymentLockInterface
     + payment_method::PaymentMethodInterface
+    + payment_schedule::PaymentScheduleInterface
+    + payment_split::PaymentSplitInterface
+    + payout::PayoutInterface
+    + payout_method::PayoutMethodInterface
     + process_tracker::ProcessTrackerInterface
     + queue::QueueInterface
+    + rate_limit::RateLimitInterface
+    + redaction_audit::RedactionAuditInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
+    + routing_config::RoutingConfigInterface
+    + settlement_report::SettlementReportInterface
+    + status_mismatch::StatusMismatchInterface
+    + success_rate::SuccessRateInterface
+    + user::UserInterface
+    + user_role::UserRoleInterface
+    + vault_data_key::VaultDataKeyInterface
+    + vaulted_card::VaultedCardInterface
     + cards_info::CardsInfoInterface
     + 'static
 {
@@ -85,6 +143,11 @@ pub struct MockDb {
     refunds: Arc<Mutex<Vec<storage::Refund>>>,
     processes: Arc<Mutex<Vec<storage::ProcessTracker>>>,
     connector_response: Arc<Mutex<Vec<storage::ConnectorResponse>>>,
+    api_keys: Arc<Mutex<Vec<storage::ApiKey>>>,
+    payment_methods: Arc<Mutex<Vec<storage::PaymentMethod>>>,
+    payment_links: Arc<Mutex<Vec<storage::PaymentLink>>>,
+    vaulted_cards: Arc<Mutex<Vec<storage::VaultedCard>>>,
+    circuits: Arc<Mutex<HashMap<String, circuit_breaker::MockCircuit>>>,
     redis: Arc<redis_interface::RedisConnectionPool>,
 }
 
@@ -99,6 +162,11 @@ impl MockDb {
             refunds: Default::default(),
             processes: Default::default(),
             connector_response: Default::default(),
+            api_keys: Default::default(),
+            payment_methods: Default::default(),
+            payment_links: Default::default(),
+            vaulted_cards: Default::default(),
+            circuits: Default::default(),
             redis: Arc::new(crate::connection::redis_connection(redis).await),
         }
     }
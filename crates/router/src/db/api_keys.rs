@@ -38,6 +38,13 @@ pub trait ApiKeyInterface {
         limit: Option<i64>,
         offset: Option<i64>,
     ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError>;
+
+    async fn list_api_keys_by_organization_id(
+        &self,
+        organization_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -102,22 +109,49 @@ impl ApiKeyInterface for Store {
         limit: Option<i64>,
         offset: Option<i64>,
     ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
+        let conn = connection::pg_connection_replica_read(self).await?;
         storage::ApiKey::find_by_merchant_id(&conn, merchant_id, limit, offset)
             .await
             .map_err(Into::into)
             .into_report()
     }
+
+    async fn list_api_keys_by_organization_id(
+        &self,
+        organization_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError> {
+        let conn = connection::pg_connection_replica_read(self).await?;
+        storage::ApiKey::find_by_organization_id(&conn, organization_id, limit, offset)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
 impl ApiKeyInterface for MockDb {
     async fn insert_api_key(
         &self,
-        _api_key: storage::ApiKeyNew,
+        api_key: storage::ApiKeyNew,
     ) -> CustomResult<storage::ApiKey, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut api_keys = self.api_keys.lock().await;
+        let api_key = storage::ApiKey {
+            key_id: api_key.key_id,
+            merchant_id: api_key.merchant_id,
+            name: api_key.name,
+            description: api_key.description,
+            hashed_api_key: api_key.hashed_api_key,
+            prefix: api_key.prefix,
+            created_at: api_key.created_at,
+            expires_at: api_key.expires_at,
+            last_used: api_key.last_used,
+            scopes: api_key.scopes,
+            organization_id: api_key.organization_id,
+        };
+        api_keys.push(api_key.clone());
+        Ok(api_key)
     }
 
     async fn update_api_key(
@@ -136,18 +170,28 @@ impl ApiKeyInterface for MockDb {
 
     async fn find_api_key_by_key_id_optional(
         &self,
-        _key_id: &str,
+        key_id: &str,
     ) -> CustomResult<Option<storage::ApiKey>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        Ok(self
+            .api_keys
+            .lock()
+            .await
+            .iter()
+            .find(|api_key| api_key.key_id == key_id)
+            .cloned())
     }
 
     async fn find_api_key_by_hash_optional(
         &self,
-        _hashed_api_key: storage::HashedApiKey,
+        hashed_api_key: storage::HashedApiKey,
     ) -> CustomResult<Option<storage::ApiKey>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        Ok(self
+            .api_keys
+            .lock()
+            .await
+            .iter()
+            .find(|api_key| api_key.hashed_api_key == hashed_api_key)
+            .cloned())
     }
 
     async fn list_api_keys_by_merchant_id(
@@ -159,4 +203,14 @@ impl ApiKeyInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn list_api_keys_by_organization_id(
+        &self,
+        _organization_id: &str,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
@@ -0,0 +1,131 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums, BalanceTransactionDbExt},
+};
+
+#[async_trait::async_trait]
+pub trait BalanceTransactionInterface {
+    async fn insert_balance_transaction(
+        &self,
+        balance_transaction: storage::BalanceTransactionNew,
+    ) -> CustomResult<storage::BalanceTransaction, errors::StorageError>;
+
+    async fn find_balance_transaction_by_merchant_id_transaction_type_source_id(
+        &self,
+        merchant_id: &str,
+        transaction_type: enums::BalanceTransactionType,
+        source_id: &str,
+    ) -> CustomResult<storage::BalanceTransaction, errors::StorageError>;
+
+    async fn list_balance_transactions_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BalanceTransaction>, errors::StorageError>;
+
+    async fn get_balance_transaction_aggregates_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::BalanceTransactionAggregate>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl BalanceTransactionInterface for Store {
+    async fn insert_balance_transaction(
+        &self,
+        balance_transaction: storage::BalanceTransactionNew,
+    ) -> CustomResult<storage::BalanceTransaction, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        balance_transaction
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_balance_transaction_by_merchant_id_transaction_type_source_id(
+        &self,
+        merchant_id: &str,
+        transaction_type: enums::BalanceTransactionType,
+        source_id: &str,
+    ) -> CustomResult<storage::BalanceTransaction, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BalanceTransaction::find_by_merchant_id_transaction_type_source_id(
+            &conn,
+            merchant_id,
+            transaction_type,
+            source_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn list_balance_transactions_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BalanceTransaction>, errors::StorageError> {
+        let conn = connection::pg_connection_replica_read(self).await?;
+        storage::BalanceTransaction::find_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn get_balance_transaction_aggregates_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::BalanceTransactionAggregate>, errors::StorageError> {
+        let conn = connection::pg_connection_replica_read(self).await?;
+        storage::BalanceTransaction::aggregate_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl BalanceTransactionInterface for MockDb {
+    async fn insert_balance_transaction(
+        &self,
+        _balance_transaction: storage::BalanceTransactionNew,
+    ) -> CustomResult<storage::BalanceTransaction, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_balance_transaction_by_merchant_id_transaction_type_source_id(
+        &self,
+        _merchant_id: &str,
+        _transaction_type: enums::BalanceTransactionType,
+        _source_id: &str,
+    ) -> CustomResult<storage::BalanceTransaction, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_balance_transactions_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BalanceTransaction>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_balance_transaction_aggregates_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Vec<storage::BalanceTransactionAggregate>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -102,3 +102,33 @@ where
         .change_context(errors::StorageError::KVError)?;
     Ok(data)
 }
+
+/// Runs `fun` and then removes the given keys from redis and from the in-memory cache of this
+/// instance, publishing each key so that the other instances drop their in-memory copies as well.
+pub async fn publish_and_redact_multiple<T, F, Fut>(
+    store: &Store,
+    keys: &[String],
+    fun: F,
+    in_memory: &cache::Cache,
+) -> CustomResult<T, errors::StorageError>
+where
+    F: FnOnce() -> Fut + Send,
+    Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
+{
+    let data = fun().await?;
+    let redis = store
+        .redis_conn()
+        .map_err(Into::<errors::StorageError>::into)?;
+    for key in keys {
+        in_memory.invalidate(key).await;
+        redis
+            .delete_key(key)
+            .await
+            .change_context(errors::StorageError::KVError)?;
+        redis
+            .publish(consts::PUB_SUB_CHANNEL, key)
+            .await
+            .change_context(errors::StorageError::KVError)?;
+    }
+    Ok(data)
+}
@@ -0,0 +1,104 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CaptureInterface {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+
+    async fn find_all_captures_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError>;
+
+    async fn update_capture_with_capture_id(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CaptureInterface for Store {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        capture
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_all_captures_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Capture::find_all_by_merchant_id_payment_id_attempt_id(
+            &conn,
+            merchant_id,
+            payment_id,
+            attempt_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_capture_with_capture_id(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, capture)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CaptureInterface for MockDb {
+    async fn insert_capture(
+        &self,
+        _capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_all_captures_by_merchant_id_payment_id_attempt_id(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+        _attempt_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_capture_with_capture_id(
+        &self,
+        _this: storage::Capture,
+        _capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
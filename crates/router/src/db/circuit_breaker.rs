@@ -0,0 +1,304 @@
+use crate::{
+    core::errors::{self, CustomResult},
+    db::MockDb,
+};
+
+/// The state of the circuit of a connector, as seen by a call about to be made to it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CircuitState {
+    /// Calls are made to the connector, which has failed `failure_count` times in a row
+    Closed { failure_count: i64 },
+    /// Calls are short circuited, either during the cool down of the circuit or while another
+    /// call is probing the connector
+    Open,
+    /// The cool down of the circuit is over and the call is the single probe of the connector,
+    /// whose outcome closes or opens the circuit again
+    HalfOpen,
+}
+
+impl CircuitState {
+    const OPEN: i64 = -1;
+    const HALF_OPEN: i64 = -2;
+
+    fn from_script_result(result: i64) -> Self {
+        match result {
+            Self::OPEN => Self::Open,
+            Self::HALF_OPEN => Self::HalfOpen,
+            failure_count => Self::Closed { failure_count },
+        }
+    }
+}
+
+/// The circuit of a connector as held by the `MockDb`.
+#[derive(Clone, Debug, Default)]
+pub struct MockCircuit {
+    failure_count: i64,
+    open_until: Option<time::PrimitiveDateTime>,
+    probe_until: Option<time::PrimitiveDateTime>,
+    is_tripped: bool,
+}
+
+#[async_trait::async_trait]
+pub trait CircuitBreakerInterface {
+    /// Returns the state of the circuit for a call about to be made to the connector. Once the
+    /// circuit has cooled down, the first caller is handed the probe of the connector, which is
+    /// held for `probe_timeout` seconds, and the circuit stays open for the other callers.
+    async fn acquire_connector_circuit(
+        &self,
+        circuit_id: &str,
+        probe_timeout: i64,
+    ) -> CustomResult<CircuitState, errors::StorageError>;
+
+    /// Counts a failed call to the connector, returning the number of consecutive failures.
+    async fn increment_connector_failure_count(
+        &self,
+        circuit_id: &str,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    /// Opens the circuit of the connector for `cool_down_time` seconds, after which it is
+    /// half open.
+    async fn open_connector_circuit(
+        &self,
+        circuit_id: &str,
+        cool_down_time: i64,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    /// Closes the circuit of the connector, resetting its failure count.
+    async fn close_connector_circuit(
+        &self,
+        circuit_id: &str,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    /// Whether the circuit of the connector is cooling down, without claiming its probe.
+    async fn is_connector_circuit_open(
+        &self,
+        circuit_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+fn get_failure_count_key(circuit_id: &str) -> String {
+    format!("circuit_breaker_{circuit_id}_failures")
+}
+
+fn get_open_circuit_key(circuit_id: &str) -> String {
+    format!("circuit_breaker_{circuit_id}_open")
+}
+
+fn get_tripped_circuit_key(circuit_id: &str) -> String {
+    format!("circuit_breaker_{circuit_id}_tripped")
+}
+
+fn get_probe_key(circuit_id: &str) -> String {
+    format!("circuit_breaker_{circuit_id}_probe")
+}
+
+fn get_circuit_keys(circuit_id: &str) -> Vec<String> {
+    vec![
+        get_open_circuit_key(circuit_id),
+        get_tripped_circuit_key(circuit_id),
+        get_probe_key(circuit_id),
+        get_failure_count_key(circuit_id),
+    ]
+}
+
+mod storage {
+    use error_stack::ResultExt;
+
+    use super::{
+        get_circuit_keys, get_failure_count_key, get_open_circuit_key, CircuitBreakerInterface,
+        CircuitState,
+    };
+    use crate::{
+        core::errors::{self, CustomResult},
+        services::Store,
+    };
+
+    const FAILURE_COUNT_FIELD: &str = "count";
+
+    /// Returns -1 while the circuit is open, -2 when the caller claimed the probe of the half open
+    /// circuit, and the number of consecutive failures otherwise. A circuit is half open when it
+    /// was tripped and its cool down is over.
+    const ACQUIRE_CIRCUIT_SCRIPT: &str = r#"
+if redis.call("EXISTS", KEYS[1]) == 1 then
+    return -1
+end
+
+if redis.call("EXISTS", KEYS[2]) == 1 then
+    if redis.call("SET", KEYS[3], "true", "NX", "EX", ARGV[1]) then
+        return -2
+    end
+    return -1
+end
+
+return tonumber(redis.call("HGET", KEYS[4], ARGV[2]) or "0")
+"#;
+
+    const OPEN_CIRCUIT_SCRIPT: &str = r#"
+redis.call("SET", KEYS[1], "true", "EX", ARGV[1])
+redis.call("SET", KEYS[2], "true")
+redis.call("DEL", KEYS[3], KEYS[4])
+return 1
+"#;
+
+    const CLOSE_CIRCUIT_SCRIPT: &str = r#"
+redis.call("DEL", KEYS[1], KEYS[2], KEYS[3], KEYS[4])
+return 1
+"#;
+
+    #[async_trait::async_trait]
+    impl CircuitBreakerInterface for Store {
+        async fn acquire_connector_circuit(
+            &self,
+            circuit_id: &str,
+            probe_timeout: i64,
+        ) -> CustomResult<CircuitState, errors::StorageError> {
+            let result = self
+                .redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .evaluate_script::<i64>(
+                    ACQUIRE_CIRCUIT_SCRIPT,
+                    get_circuit_keys(circuit_id),
+                    vec![probe_timeout.to_string(), FAILURE_COUNT_FIELD.to_string()],
+                )
+                .await
+                .change_context(errors::StorageError::KVError)?;
+
+            Ok(CircuitState::from_script_result(result))
+        }
+
+        async fn increment_connector_failure_count(
+            &self,
+            circuit_id: &str,
+        ) -> CustomResult<i64, errors::StorageError> {
+            self.redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .increment_hash_field(&get_failure_count_key(circuit_id), FAILURE_COUNT_FIELD, 1)
+                .await
+                .change_context(errors::StorageError::KVError)
+        }
+
+        async fn open_connector_circuit(
+            &self,
+            circuit_id: &str,
+            cool_down_time: i64,
+        ) -> CustomResult<(), errors::StorageError> {
+            // The failures are counted afresh once the circuit closes
+            self.redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .evaluate_script::<i64>(
+                    OPEN_CIRCUIT_SCRIPT,
+                    get_circuit_keys(circuit_id),
+                    vec![cool_down_time.to_string()],
+                )
+                .await
+                .change_context(errors::StorageError::KVError)?;
+            Ok(())
+        }
+
+        async fn close_connector_circuit(
+            &self,
+            circuit_id: &str,
+        ) -> CustomResult<(), errors::StorageError> {
+            self.redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .evaluate_script::<i64>(
+                    CLOSE_CIRCUIT_SCRIPT,
+                    get_circuit_keys(circuit_id),
+                    Vec::new(),
+                )
+                .await
+                .change_context(errors::StorageError::KVError)?;
+            Ok(())
+        }
+
+        async fn is_connector_circuit_open(
+            &self,
+            circuit_id: &str,
+        ) -> CustomResult<bool, errors::StorageError> {
+            self.redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .exists::<String>(&get_open_circuit_key(circuit_id))
+                .await
+                .change_context(errors::StorageError::KVError)
+        }
+    }
+}
+
+fn is_in_future(time: Option<time::PrimitiveDateTime>, now: time::PrimitiveDateTime) -> bool {
+    time.map_or(false, |time| time > now)
+}
+
+#[async_trait::async_trait]
+impl CircuitBreakerInterface for MockDb {
+    async fn acquire_connector_circuit(
+        &self,
+        circuit_id: &str,
+        probe_timeout: i64,
+    ) -> CustomResult<CircuitState, errors::StorageError> {
+        let mut circuits = self.circuits.lock().await;
+        let circuit = circuits.entry(circuit_id.to_owned()).or_default();
+        let now = common_utils::date_time::now();
+
+        if is_in_future(circuit.open_until, now) {
+            return Ok(CircuitState::Open);
+        }
+        if circuit.is_tripped {
+            if is_in_future(circuit.probe_until, now) {
+                return Ok(CircuitState::Open);
+            }
+            circuit.probe_until = Some(now + time::Duration::seconds(probe_timeout));
+            return Ok(CircuitState::HalfOpen);
+        }
+        Ok(CircuitState::Closed {
+            failure_count: circuit.failure_count,
+        })
+    }
+
+    async fn increment_connector_failure_count(
+        &self,
+        circuit_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let mut circuits = self.circuits.lock().await;
+        let circuit = circuits.entry(circuit_id.to_owned()).or_default();
+        circuit.failure_count += 1;
+        Ok(circuit.failure_count)
+    }
+
+    async fn open_connector_circuit(
+        &self,
+        circuit_id: &str,
+        cool_down_time: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        let open_until = common_utils::date_time::now() + time::Duration::seconds(cool_down_time);
+        self.circuits.lock().await.insert(
+            circuit_id.to_owned(),
+            MockCircuit {
+                open_until: Some(open_until),
+                is_tripped: true,
+                ..Default::default()
+            },
+        );
+        Ok(())
+    }
+
+    async fn close_connector_circuit(
+        &self,
+        circuit_id: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.circuits.lock().await.remove(circuit_id);
+        Ok(())
+    }
+
+    async fn is_connector_circuit_open(
+        &self,
+        circuit_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let now = common_utils::date_time::now();
+        Ok(self
+            .circuits
+            .lock()
+            .await
+            .get(circuit_id)
+            .map_or(false, |circuit| is_in_future(circuit.open_until, now)))
+    }
+}
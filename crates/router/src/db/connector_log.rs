@@ -0,0 +1,69 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ConnectorLogInterface {
+    async fn insert_connector_log(
+        &self,
+        connector_log: storage::ConnectorLogNew,
+    ) -> CustomResult<storage::ConnectorLog, errors::StorageError>;
+
+    async fn list_connector_logs_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ConnectorLogInterface for Store {
+    async fn insert_connector_log(
+        &self,
+        connector_log: storage::ConnectorLogNew,
+    ) -> CustomResult<storage::ConnectorLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        connector_log
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_connector_logs_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorLog>, errors::StorageError> {
+        let conn = connection::pg_connection_replica_read(self).await?;
+        storage::ConnectorLog::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorLogInterface for MockDb {
+    async fn insert_connector_log(
+        &self,
+        _connector_log: storage::ConnectorLogNew,
+    ) -> CustomResult<storage::ConnectorLog, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_connector_logs_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorLog>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
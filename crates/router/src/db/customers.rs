@@ -7,7 +7,7 @@ use crate::{
         customers::REDACTED,
         errors::{self, CustomResult},
     },
-    types::storage,
+    types::storage::{self, CustomerDbExt},
 };
 
 #[async_trait::async_trait]
@@ -41,6 +41,13 @@ pub trait CustomerInterface {
         &self,
         customer_data: storage::CustomerNew,
     ) -> CustomResult<storage::Customer, errors::StorageError>;
+
+    async fn list_customers_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        customer_list_constraints: api_models::customers::CustomerListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Customer>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -116,6 +123,24 @@ impl CustomerInterface for Store {
             .into_report()
     }
 
+    async fn list_customers_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        customer_list_constraints: api_models::customers::CustomerListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Customer>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Customer::filter_by_constraints(
+            &conn,
+            merchant_id,
+            customer_list_constraints,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn delete_customer_by_customer_id_merchant_id(
         &self,
         customer_id: &str,
@@ -189,6 +214,35 @@ impl CustomerInterface for MockDb {
         Ok(customer)
     }
 
+    async fn list_customers_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        customer_list_constraints: api_models::customers::CustomerListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Customer>, errors::StorageError> {
+        let customers = self.customers.lock().await;
+        let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+
+        Ok(customers
+            .iter()
+            .filter(|customer| {
+                customer.merchant_id == merchant_id
+                    && customer.name.as_deref() != Some(REDACTED)
+                    && customer_list_constraints
+                        .email
+                        .as_ref()
+                        .map_or(true, |email| customer.email.as_ref() == Some(email))
+                    && customer_list_constraints
+                        .phone
+                        .as_ref()
+                        .map_or(true, |phone| customer.phone.as_ref() == Some(phone))
+            })
+            .rev()
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
     async fn delete_customer_by_customer_id_merchant_id(
         &self,
         _customer_id: &str,
@@ -0,0 +1,165 @@
+use crate::{
+    core::errors::{self, CustomResult},
+    db::MockDb,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait DummyConnectorInterface {
+    async fn find_dummy_connector_payment(
+        &self,
+        payment_id: &str,
+    ) -> CustomResult<storage::DummyConnectorPaymentData, errors::StorageError>;
+
+    /// Stores the payment, replacing any previous version of it, for `ttl` seconds.
+    async fn store_dummy_connector_payment(
+        &self,
+        payment: storage::DummyConnectorPaymentData,
+        ttl: i64,
+    ) -> CustomResult<storage::DummyConnectorPaymentData, errors::StorageError>;
+
+    async fn find_dummy_connector_refund(
+        &self,
+        refund_id: &str,
+    ) -> CustomResult<storage::DummyConnectorRefundData, errors::StorageError>;
+
+    /// Stores the refund, replacing any previous version of it, for `ttl` seconds.
+    async fn store_dummy_connector_refund(
+        &self,
+        refund: storage::DummyConnectorRefundData,
+        ttl: i64,
+    ) -> CustomResult<storage::DummyConnectorRefundData, errors::StorageError>;
+}
+
+fn get_dummy_connector_payment_key(payment_id: &str) -> String {
+    format!("dummy_connector_payment_{payment_id}")
+}
+
+fn get_dummy_connector_refund_key(refund_id: &str) -> String {
+    format!("dummy_connector_refund_{refund_id}")
+}
+
+mod storage {
+    use error_stack::ResultExt;
+
+    use super::{
+        get_dummy_connector_payment_key, get_dummy_connector_refund_key, DummyConnectorInterface,
+    };
+    use crate::{
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage,
+    };
+
+    #[async_trait::async_trait]
+    impl DummyConnectorInterface for Store {
+        async fn find_dummy_connector_payment(
+            &self,
+            payment_id: &str,
+        ) -> CustomResult<storage::DummyConnectorPaymentData, errors::StorageError> {
+            self.redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .get_and_deserialize_key(
+                    &get_dummy_connector_payment_key(payment_id),
+                    "DummyConnectorPaymentData",
+                )
+                .await
+                .map_err(|error| match error.current_context() {
+                    errors::RedisError::NotFound => {
+                        error.change_context(errors::StorageError::ValueNotFound(
+                            "Dummy connector payment does not exist".into(),
+                        ))
+                    }
+                    _ => error.change_context(errors::StorageError::KVError),
+                })
+        }
+
+        async fn store_dummy_connector_payment(
+            &self,
+            payment: storage::DummyConnectorPaymentData,
+            ttl: i64,
+        ) -> CustomResult<storage::DummyConnectorPaymentData, errors::StorageError> {
+            self.redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .serialize_and_set_key_with_expiry(
+                    &get_dummy_connector_payment_key(&payment.payment_id),
+                    &payment,
+                    ttl,
+                )
+                .await
+                .change_context(errors::StorageError::KVError)?;
+            Ok(payment)
+        }
+
+        async fn find_dummy_connector_refund(
+            &self,
+            refund_id: &str,
+        ) -> CustomResult<storage::DummyConnectorRefundData, errors::StorageError> {
+            self.redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .get_and_deserialize_key(
+                    &get_dummy_connector_refund_key(refund_id),
+                    "DummyConnectorRefundData",
+                )
+                .await
+                .map_err(|error| match error.current_context() {
+                    errors::RedisError::NotFound => {
+                        error.change_context(errors::StorageError::ValueNotFound(
+                            "Dummy connector refund does not exist".into(),
+                        ))
+                    }
+                    _ => error.change_context(errors::StorageError::KVError),
+                })
+        }
+
+        async fn store_dummy_connector_refund(
+            &self,
+            refund: storage::DummyConnectorRefundData,
+            ttl: i64,
+        ) -> CustomResult<storage::DummyConnectorRefundData, errors::StorageError> {
+            self.redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .serialize_and_set_key_with_expiry(
+                    &get_dummy_connector_refund_key(&refund.refund_id),
+                    &refund,
+                    ttl,
+                )
+                .await
+                .change_context(errors::StorageError::KVError)?;
+            Ok(refund)
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DummyConnectorInterface for MockDb {
+    async fn find_dummy_connector_payment(
+        &self,
+        _payment_id: &str,
+    ) -> CustomResult<storage::DummyConnectorPaymentData, errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+
+    async fn store_dummy_connector_payment(
+        &self,
+        _payment: storage::DummyConnectorPaymentData,
+        _ttl: i64,
+    ) -> CustomResult<storage::DummyConnectorPaymentData, errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+
+    async fn find_dummy_connector_refund(
+        &self,
+        _refund_id: &str,
+    ) -> CustomResult<storage::DummyConnectorRefundData, errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+
+    async fn store_dummy_connector_refund(
+        &self,
+        _refund: storage::DummyConnectorRefundData,
+        _ttl: i64,
+    ) -> CustomResult<storage::DummyConnectorRefundData, errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+}
@@ -97,7 +97,12 @@ mod storage {
                 .map_err(Into::<errors::StorageError>::into)?
                 .get_hash_field_and_deserialize(&key, "ephkey", "EphemeralKey")
                 .await
-                .change_context(errors::StorageError::KVError)
+                .map_err(|error| match error.current_context() {
+                    errors::RedisError::NotFound => error.change_context(
+                        errors::StorageError::ValueNotFound("Ephemeral key does not exist".into()),
+                    ),
+                    _ => error.change_context(errors::StorageError::KVError),
+                })
         }
         async fn delete_ephemeral_key(
             &self,
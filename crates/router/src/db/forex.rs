@@ -0,0 +1,106 @@
+use crate::{
+    core::errors::{self, CustomResult},
+    db::MockDb,
+    types::storage::enums as storage_enums,
+};
+
+#[async_trait::async_trait]
+pub trait ForexInterface {
+    /// Returns the cached exchange rate for converting amounts from one currency to another, if
+    /// it has not expired.
+    async fn find_cached_exchange_rate(
+        &self,
+        from_currency: storage_enums::Currency,
+        to_currency: storage_enums::Currency,
+    ) -> CustomResult<Option<f64>, errors::StorageError>;
+
+    /// Caches the exchange rate for converting amounts from one currency to another for `ttl`
+    /// seconds.
+    async fn cache_exchange_rate(
+        &self,
+        from_currency: storage_enums::Currency,
+        to_currency: storage_enums::Currency,
+        rate: f64,
+        ttl: i64,
+    ) -> CustomResult<(), errors::StorageError>;
+}
+
+fn get_exchange_rate_key(
+    from_currency: storage_enums::Currency,
+    to_currency: storage_enums::Currency,
+) -> String {
+    format!("forex_{from_currency}_{to_currency}")
+}
+
+mod storage {
+    use error_stack::ResultExt;
+
+    use super::{get_exchange_rate_key, ForexInterface};
+    use crate::{
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::enums as storage_enums,
+    };
+
+    #[async_trait::async_trait]
+    impl ForexInterface for Store {
+        async fn find_cached_exchange_rate(
+            &self,
+            from_currency: storage_enums::Currency,
+            to_currency: storage_enums::Currency,
+        ) -> CustomResult<Option<f64>, errors::StorageError> {
+            let result = self
+                .redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .get_and_deserialize_key(&get_exchange_rate_key(from_currency, to_currency), "f64")
+                .await;
+
+            match result {
+                Ok(rate) => Ok(Some(rate)),
+                Err(error) => match error.current_context() {
+                    errors::RedisError::NotFound => Ok(None),
+                    _ => Err(error.change_context(errors::StorageError::KVError)),
+                },
+            }
+        }
+
+        async fn cache_exchange_rate(
+            &self,
+            from_currency: storage_enums::Currency,
+            to_currency: storage_enums::Currency,
+            rate: f64,
+            ttl: i64,
+        ) -> CustomResult<(), errors::StorageError> {
+            self.redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .serialize_and_set_key_with_expiry(
+                    &get_exchange_rate_key(from_currency, to_currency),
+                    rate,
+                    ttl,
+                )
+                .await
+                .change_context(errors::StorageError::KVError)
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ForexInterface for MockDb {
+    async fn find_cached_exchange_rate(
+        &self,
+        _from_currency: storage_enums::Currency,
+        _to_currency: storage_enums::Currency,
+    ) -> CustomResult<Option<f64>, errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+
+    async fn cache_exchange_rate(
+        &self,
+        _from_currency: storage_enums::Currency,
+        _to_currency: storage_enums::Currency,
+        _rate: f64,
+        _ttl: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    core::errors::{self, CustomResult},
+    db::MockDb,
+};
+
+#[async_trait::async_trait]
+pub trait FraudCheckInterface {
+    /// Counts an attempt against the key, returning the number of attempts counted since the
+    /// first attempt of the window. The window closes `window` seconds after its first attempt.
+    async fn increment_velocity_count(
+        &self,
+        key: &str,
+        window: i64,
+    ) -> CustomResult<i64, errors::StorageError>;
+}
+
+mod storage {
+    use error_stack::ResultExt;
+
+    use super::FraudCheckInterface;
+    use crate::{
+        core::errors::{self, CustomResult},
+        services::Store,
+    };
+
+    const COUNT_FIELD: &str = "count";
+
+    #[async_trait::async_trait]
+    impl FraudCheckInterface for Store {
+        async fn increment_velocity_count(
+            &self,
+            key: &str,
+            window: i64,
+        ) -> CustomResult<i64, errors::StorageError> {
+            let redis_conn = self
+                .redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?;
+
+            let count = redis_conn
+                .increment_hash_field(key, COUNT_FIELD, 1)
+                .await
+                .change_context(errors::StorageError::KVError)?;
+
+            // The expiry is only set by the first attempt, so that the window is not extended by
+            // subsequent attempts
+            if count == 1 {
+                redis_conn
+                    .set_expiry(key, window)
+                    .await
+                    .change_context(errors::StorageError::KVError)?;
+            }
+
+            Ok(count)
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl FraudCheckInterface for MockDb {
+    async fn increment_velocity_count(
+        &self,
+        _key: &str,
+        _window: i64,
+    ) -> CustomResult<i64, errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+}
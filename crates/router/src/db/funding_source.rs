@@ -0,0 +1,104 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait FundingSourceInterface {
+    async fn insert_funding_source(
+        &self,
+        funding_source: storage::FundingSourceNew,
+    ) -> CustomResult<storage::FundingSource, errors::StorageError>;
+
+    async fn find_all_funding_sources_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::FundingSource>, errors::StorageError>;
+
+    async fn update_funding_source_with_funding_source_id(
+        &self,
+        this: storage::FundingSource,
+        funding_source: storage::FundingSourceUpdate,
+    ) -> CustomResult<storage::FundingSource, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl FundingSourceInterface for Store {
+    async fn insert_funding_source(
+        &self,
+        funding_source: storage::FundingSourceNew,
+    ) -> CustomResult<storage::FundingSource, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        funding_source
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_all_funding_sources_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::FundingSource>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::FundingSource::find_all_by_merchant_id_payment_id_attempt_id(
+            &conn,
+            merchant_id,
+            payment_id,
+            attempt_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_funding_source_with_funding_source_id(
+        &self,
+        this: storage::FundingSource,
+        funding_source: storage::FundingSourceUpdate,
+    ) -> CustomResult<storage::FundingSource, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, funding_source)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl FundingSourceInterface for MockDb {
+    async fn insert_funding_source(
+        &self,
+        _funding_source: storage::FundingSourceNew,
+    ) -> CustomResult<storage::FundingSource, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_all_funding_sources_by_merchant_id_payment_id_attempt_id(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+        _attempt_id: &str,
+    ) -> CustomResult<Vec<storage::FundingSource>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_funding_source_with_funding_source_id(
+        &self,
+        _this: storage::FundingSource,
+        _funding_source: storage::FundingSourceUpdate,
+    ) -> CustomResult<storage::FundingSource, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
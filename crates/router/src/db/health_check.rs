@@ -0,0 +1,62 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+};
+
+/// Key written to and deleted from Redis to check that it is available.
+const REDIS_HEALTH_CHECK_KEY: &str = "health_check";
+
+#[async_trait::async_trait]
+pub trait HealthCheckInterface {
+    /// Checks that a query can be run on the master database.
+    async fn health_check_db(&self) -> CustomResult<(), errors::StorageError>;
+
+    /// Checks that a key can be written to and deleted from Redis.
+    async fn health_check_redis(&self) -> CustomResult<(), errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl HealthCheckInterface for Store {
+    async fn health_check_db(&self) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+
+        diesel::sql_query("SELECT 1")
+            .execute_async(&*conn)
+            .await
+            .into_report()
+            .change_context(errors::StorageError::DatabaseError(
+                storage_models::errors::DatabaseError::Others.into(),
+            ))
+            .map(|_| ())
+    }
+
+    async fn health_check_redis(&self) -> CustomResult<(), errors::StorageError> {
+        let redis_conn = self
+            .redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+
+        redis_conn
+            .set_key_with_expiry(REDIS_HEALTH_CHECK_KEY, "true", 30)
+            .await
+            .change_context(errors::StorageError::KVError)?;
+        redis_conn
+            .delete_key(REDIS_HEALTH_CHECK_KEY)
+            .await
+            .change_context(errors::StorageError::KVError)
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheckInterface for MockDb {
+    async fn health_check_db(&self) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn health_check_redis(&self) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    core::errors::{self, CustomResult},
+    db::MockDb,
+    types::storage::idempotency::IdempotencyEntry,
+};
+
+#[async_trait::async_trait]
+pub trait IdempotencyInterface {
+    /// Inserts the entry only if no entry exists for the key, returns `false` otherwise.
+    async fn insert_idempotency_entry_if_not_exists(
+        &self,
+        entry: &IdempotencyEntry,
+        ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn find_idempotency_entry(
+        &self,
+        merchant_id: &str,
+        idempotency_key: &str,
+    ) -> CustomResult<IdempotencyEntry, errors::StorageError>;
+
+    async fn update_idempotency_entry(
+        &self,
+        entry: &IdempotencyEntry,
+        ttl: i64,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn delete_idempotency_entry(
+        &self,
+        merchant_id: &str,
+        idempotency_key: &str,
+    ) -> CustomResult<(), errors::StorageError>;
+}
+
+fn get_idempotency_redis_key(merchant_id: &str, idempotency_key: &str) -> String {
+    format!("idempotency_{merchant_id}_{idempotency_key}")
+}
+
+mod storage {
+    use error_stack::ResultExt;
+    use redis_interface::SetnxReply;
+
+    use super::{get_idempotency_redis_key, IdempotencyInterface};
+    use crate::{
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::idempotency::IdempotencyEntry,
+    };
+
+    #[async_trait::async_trait]
+    impl IdempotencyInterface for Store {
+        async fn insert_idempotency_entry_if_not_exists(
+            &self,
+            entry: &IdempotencyEntry,
+            ttl: i64,
+        ) -> CustomResult<bool, errors::StorageError> {
+            let key = get_idempotency_redis_key(&entry.merchant_id, &entry.idempotency_key);
+            self.redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .serialize_and_set_key_if_not_exist_with_expiry(&key, entry, ttl)
+                .await
+                .map(|reply| matches!(reply, SetnxReply::KeySet))
+                .change_context(errors::StorageError::KVError)
+        }
+
+        async fn find_idempotency_entry(
+            &self,
+            merchant_id: &str,
+            idempotency_key: &str,
+        ) -> CustomResult<IdempotencyEntry, errors::StorageError> {
+            let key = get_idempotency_redis_key(merchant_id, idempotency_key);
+            self.redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .get_and_deserialize_key(&key, "IdempotencyEntry")
+                .await
+                .map_err(|error| match error.current_context() {
+                    errors::RedisError::NotFound => {
+                        error.change_context(errors::StorageError::ValueNotFound(format!(
+                            "Idempotency entry does not exist for key {idempotency_key}"
+                        )))
+                    }
+                    _ => error.change_context(errors::StorageError::KVError),
+                })
+        }
+
+        async fn update_idempotency_entry(
+            &self,
+            entry: &IdempotencyEntry,
+            ttl: i64,
+        ) -> CustomResult<(), errors::StorageError> {
+            let key = get_idempotency_redis_key(&entry.merchant_id, &entry.idempotency_key);
+            self.redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .serialize_and_set_key_with_expiry(&key, entry, ttl)
+                .await
+                .change_context(errors::StorageError::KVError)
+        }
+
+        async fn delete_idempotency_entry(
+            &self,
+            merchant_id: &str,
+            idempotency_key: &str,
+        ) -> CustomResult<(), errors::StorageError> {
+            let key = get_idempotency_redis_key(merchant_id, idempotency_key);
+            self.redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .delete_key(&key)
+                .await
+                .change_context(errors::StorageError::KVError)
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyInterface for MockDb {
+    async fn insert_idempotency_entry_if_not_exists(
+        &self,
+        _entry: &IdempotencyEntry,
+        _ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+
+    async fn find_idempotency_entry(
+        &self,
+        _merchant_id: &str,
+        _idempotency_key: &str,
+    ) -> CustomResult<IdempotencyEntry, errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+
+    async fn update_idempotency_entry(
+        &self,
+        _entry: &IdempotencyEntry,
+        _ttl: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+
+    async fn delete_idempotency_entry(
+        &self,
+        _merchant_id: &str,
+        _idempotency_key: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+}
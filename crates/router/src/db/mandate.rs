@@ -4,7 +4,7 @@ use super::{MockDb, Store};
 use crate::{
     connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::storage::{self, MandateDbExt},
 };
 
 #[async_trait::async_trait]
@@ -21,6 +21,13 @@ pub trait MandateInterface {
         customer_id: &str,
     ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError>;
 
+    async fn find_mandates_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        mandate_constraints: api_models::mandates::MandateListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError>;
+
     async fn update_mandate_by_merchant_id_mandate_id(
         &self,
         merchant_id: &str,
@@ -60,6 +67,19 @@ impl MandateInterface for Store {
             .into_report()
     }
 
+    async fn find_mandates_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        mandate_constraints: api_models::mandates::MandateListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+        let conn = connection::pg_connection_replica_read(self).await?;
+        storage::Mandate::filter_by_constraints(&conn, merchant_id, mandate_constraints, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
     async fn update_mandate_by_merchant_id_mandate_id(
         &self,
         merchant_id: &str,
@@ -106,6 +126,16 @@ impl MandateInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_mandates_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+        _mandate_constraints: api_models::mandates::MandateListConstraints,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn update_mandate_by_merchant_id_mandate_id(
         &self,
         _merchant_id: &str,
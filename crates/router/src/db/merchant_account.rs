@@ -1,6 +1,8 @@
 use error_stack::IntoReport;
 
 use super::{MockDb, Store};
+#[cfg(feature = "accounts_cache")]
+use crate::cache::ACCOUNTS_CACHE;
 use crate::{
     connection,
     core::errors::{self, CustomResult},
@@ -40,6 +42,11 @@ pub trait MerchantAccountInterface {
         &self,
         merchant_id: &str,
     ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn list_merchant_accounts_by_organization_id(
+        &self,
+        organization_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantAccount>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -75,7 +82,8 @@ impl MerchantAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::get_or_populate_redis(self, merchant_id, fetch_func).await
+            super::cache::get_or_populate_in_memory(self, merchant_id, fetch_func, &ACCOUNTS_CACHE)
+                .await
         }
     }
 
@@ -100,7 +108,13 @@ impl MerchantAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::redact_cache(self, &_merchant_id, update_func, None).await
+            super::cache::publish_and_redact_multiple(
+                self,
+                &[_merchant_id],
+                update_func,
+                &ACCOUNTS_CACHE,
+            )
+            .await
         }
     }
 
@@ -128,7 +142,13 @@ impl MerchantAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::redact_cache(self, merchant_id, update_func, None).await
+            super::cache::publish_and_redact_multiple(
+                self,
+                &[merchant_id.to_string()],
+                update_func,
+                &ACCOUNTS_CACHE,
+            )
+            .await
         }
     }
 
@@ -162,9 +182,26 @@ impl MerchantAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::redact_cache(self, merchant_id, delete_func, None).await
+            super::cache::publish_and_redact_multiple(
+                self,
+                &[merchant_id.to_string()],
+                delete_func,
+                &ACCOUNTS_CACHE,
+            )
+            .await
         }
     }
+
+    async fn list_merchant_accounts_by_organization_id(
+        &self,
+        organization_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantAccount>, errors::StorageError> {
+        let conn = connection::pg_connection_replica_read(self).await?;
+        storage::MerchantAccount::list_by_organization_id(&conn, organization_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -188,6 +225,9 @@ impl MerchantAccountInterface for MockDb {
             redirect_to_merchant_with_http_post: merchant_account
                 .redirect_to_merchant_with_http_post
                 .unwrap_or_default(),
+            return_raw_connector_response: merchant_account
+                .return_raw_connector_response
+                .unwrap_or_default(),
             merchant_name: merchant_account.merchant_name,
             merchant_details: merchant_account.merchant_details,
             webhook_details: merchant_account.webhook_details,
@@ -198,6 +238,7 @@ impl MerchantAccountInterface for MockDb {
             storage_scheme: enums::MerchantStorageScheme::PostgresOnly,
             locker_id: merchant_account.locker_id,
             metadata: merchant_account.metadata,
+            organization_id: merchant_account.organization_id,
         };
         accounts.push(account.clone());
         Ok(account)
@@ -253,4 +294,16 @@ impl MerchantAccountInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn list_merchant_accounts_by_organization_id(
+        &self,
+        organization_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantAccount>, errors::StorageError> {
+        let accounts = self.merchant_accounts.lock().await;
+        Ok(accounts
+            .iter()
+            .filter(|account| account.organization_id.as_deref() == Some(organization_id))
+            .cloned()
+            .collect())
+    }
 }
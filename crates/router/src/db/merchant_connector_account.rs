@@ -3,6 +3,8 @@ use error_stack::{IntoReport, ResultExt};
 use masking::ExposeInterface;
 
 use super::{MockDb, Store};
+#[cfg(feature = "accounts_cache")]
+use crate::cache::ACCOUNTS_CACHE;
 use crate::{
     connection,
     core::errors::{self, CustomResult},
@@ -96,6 +98,11 @@ impl ConnectorAccessToken for MockDb {
     }
 }
 
+#[cfg(feature = "accounts_cache")]
+fn merchant_connector_cache_key(merchant_id: &str, connector: &str) -> String {
+    format!("mca_{merchant_id}_{connector}")
+}
+
 #[async_trait::async_trait]
 pub trait MerchantConnectorAccountInterface {
     async fn find_merchant_connector_account_by_merchant_id_connector(
@@ -132,6 +139,12 @@ pub trait MerchantConnectorAccountInterface {
         merchant_id: &str,
         merchant_connector_id: &str,
     ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn find_merchant_connector_accounts_not_encrypted_with_key(
+        &self,
+        key_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::MerchantConnectorAccount>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -141,15 +154,33 @@ impl MerchantConnectorAccountInterface for Store {
         merchant_id: &str,
         connector: &str,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::MerchantConnectorAccount::find_by_merchant_id_connector(
-            &conn,
-            merchant_id,
-            connector,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+        let find_call = || async {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::MerchantConnectorAccount::find_by_merchant_id_connector(
+                &conn,
+                merchant_id,
+                connector,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            find_call().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            super::cache::get_or_populate_in_memory(
+                self,
+                &merchant_connector_cache_key(merchant_id, connector),
+                find_call,
+                &ACCOUNTS_CACHE,
+            )
+            .await
+        }
     }
 
     async fn find_by_merchant_connector_account_merchant_id_merchant_connector_id(
@@ -175,7 +206,13 @@ impl MerchantConnectorAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::get_or_populate_redis(self, merchant_connector_id, find_call).await
+            super::cache::get_or_populate_in_memory(
+                self,
+                merchant_connector_id,
+                find_call,
+                &ACCOUNTS_CACHE,
+            )
+            .await
         }
     }
 
@@ -204,7 +241,11 @@ impl MerchantConnectorAccountInterface for Store {
         this: storage::MerchantConnectorAccount,
         merchant_connector_account: storage::MerchantConnectorAccountUpdate,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
-        let _merchant_connector_id = this.merchant_connector_id.clone();
+        #[cfg(feature = "accounts_cache")]
+        let cache_keys = [
+            this.merchant_connector_id.clone(),
+            merchant_connector_cache_key(&this.merchant_id, &this.connector_name),
+        ];
         let update_call = || async {
             let conn = connection::pg_connection_write(self).await?;
             this.update(&conn, merchant_connector_account)
@@ -215,7 +256,13 @@ impl MerchantConnectorAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::redact_cache(self, &_merchant_connector_id, update_call, None).await
+            super::cache::publish_and_redact_multiple(
+                self,
+                &cache_keys,
+                update_call,
+                &ACCOUNTS_CACHE,
+            )
+            .await
         }
 
         #[cfg(not(feature = "accounts_cache"))]
@@ -229,15 +276,63 @@ impl MerchantConnectorAccountInterface for Store {
         merchant_id: &str,
         merchant_connector_id: &str,
     ) -> CustomResult<bool, errors::StorageError> {
+        let delete_call = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::MerchantConnectorAccount::delete_by_merchant_id_merchant_connector_id(
+                &conn,
+                merchant_id,
+                merchant_connector_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            delete_call().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            // The connector name is needed to redact the account cached by merchant and connector,
+            // an account which cannot be found is left for the delete to report
+            let mut cache_keys = vec![merchant_connector_id.to_string()];
+            if let Ok(merchant_connector_account) = self
+                .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                    merchant_id,
+                    merchant_connector_id,
+                )
+                .await
+            {
+                cache_keys.push(merchant_connector_cache_key(
+                    merchant_id,
+                    &merchant_connector_account.connector_name,
+                ));
+            }
+
+            super::cache::publish_and_redact_multiple(
+                self,
+                &cache_keys,
+                delete_call,
+                &ACCOUNTS_CACHE,
+            )
+            .await
+        }
+    }
+
+    async fn find_merchant_connector_accounts_not_encrypted_with_key(
+        &self,
+        key_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::MerchantConnectorAccount>, errors::StorageError> {
+        // Reading from the primary, so that the accounts re-encrypted during a key rotation are
+        // not returned again by a lagging replica
         let conn = connection::pg_connection_write(self).await?;
-        storage::MerchantConnectorAccount::delete_by_merchant_id_merchant_connector_id(
-            &conn,
-            merchant_id,
-            merchant_connector_id,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+        storage::MerchantConnectorAccount::find_not_encrypted_with_key(&conn, key_id, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
     }
 }
 
@@ -288,6 +383,8 @@ impl MerchantConnectorAccountInterface for MockDb {
             payment_methods_enabled: t.payment_methods_enabled,
             metadata: t.metadata,
             frm_configs: t.frm_configs,
+            key_id: t.key_id,
+            encrypted_connector_account_details: t.encrypted_connector_account_details,
             connector_type: t
                 .connector_type
                 .unwrap_or(crate::types::storage::enums::ConnectorType::FinOperations),
@@ -322,4 +419,18 @@ impl MerchantConnectorAccountInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_merchant_connector_accounts_not_encrypted_with_key(
+        &self,
+        key_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::MerchantConnectorAccount>, errors::StorageError> {
+        let accounts = self.merchant_connector_accounts.lock().await;
+        Ok(accounts
+            .iter()
+            .filter(|account| account.key_id.as_deref() != Some(key_id))
+            .take(usize::try_from(limit).unwrap_or_default())
+            .cloned()
+            .collect())
+    }
 }
@@ -0,0 +1,66 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait OrganizationInterface {
+    async fn insert_organization(
+        &self,
+        organization: storage::OrganizationNew,
+    ) -> CustomResult<storage::Organization, errors::StorageError>;
+
+    async fn find_organization_by_organization_id(
+        &self,
+        organization_id: &str,
+    ) -> CustomResult<storage::Organization, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl OrganizationInterface for Store {
+    async fn insert_organization(
+        &self,
+        organization: storage::OrganizationNew,
+    ) -> CustomResult<storage::Organization, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        organization
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_organization_by_organization_id(
+        &self,
+        organization_id: &str,
+    ) -> CustomResult<storage::Organization, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Organization::find_by_organization_id(&conn, organization_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl OrganizationInterface for MockDb {
+    async fn insert_organization(
+        &self,
+        _organization: storage::OrganizationNew,
+    ) -> CustomResult<storage::Organization, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_organization_by_organization_id(
+        &self,
+        _organization_id: &str,
+    ) -> CustomResult<storage::Organization, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
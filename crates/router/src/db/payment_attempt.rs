@@ -55,6 +55,21 @@ pub trait PaymentAttemptInterface {
         merchant_id: &str,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentAttempt, errors::StorageError>;
+
+    async fn find_attempts_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError>;
+
+    /// Charged payment attempts which have no amount refunded stored, in the order of their
+    /// identifiers, starting after `after_id`. The attempts are read from the database only.
+    async fn find_charged_payment_attempts_without_amount_refunded(
+        &self,
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError>;
 }
 
 #[cfg(not(feature = "kv_store"))]
@@ -182,6 +197,31 @@ mod storage {
                 .map_err(Into::into)
                 .into_report()
         }
+
+        async fn find_attempts_by_merchant_id_payment_id(
+            &self,
+            merchant_id: &str,
+            payment_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentAttempt::find_all_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_charged_payment_attempts_without_amount_refunded(
+            &self,
+            after_id: i32,
+            limit: i64,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentAttempt::find_charged_without_amount_refunded(&conn, after_id, limit)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
 }
 
@@ -254,6 +294,7 @@ impl PaymentAttemptInterface for MockDb {
             modified_at: payment_attempt.modified_at.unwrap_or(time),
             last_synced: payment_attempt.last_synced,
             cancellation_reason: payment_attempt.cancellation_reason,
+            cancellation_metadata: payment_attempt.cancellation_metadata,
             amount_to_capture: payment_attempt.amount_to_capture,
             mandate_id: None,
             browser_info: None,
@@ -263,13 +304,24 @@ impl PaymentAttemptInterface for MockDb {
             payment_experience: payment_attempt.payment_experience,
             payment_method_type: payment_attempt.payment_method_type,
             payment_method_data: payment_attempt.payment_method_data,
+            amount_refunded: payment_attempt.amount_refunded,
+            three_ds_data: payment_attempt.three_ds_data,
+            unified_code: payment_attempt.unified_code,
+            unified_message: payment_attempt.unified_message,
+            connector_raw_response: payment_attempt.connector_raw_response,
+            avs_result: payment_attempt.avs_result,
+            cvc_result: payment_attempt.cvc_result,
+            fraud_check_verdict: payment_attempt.fraud_check_verdict,
+            fraud_check_score: payment_attempt.fraud_check_score,
+            settlement_amount: payment_attempt.settlement_amount,
+            settlement_currency: payment_attempt.settlement_currency,
+            fx_rate: payment_attempt.fx_rate,
+            version: 0,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
     }
 
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn update_payment_attempt_with_attempt_id(
         &self,
         this: types::PaymentAttempt,
@@ -281,7 +333,15 @@ impl PaymentAttemptInterface for MockDb {
         let item = payment_attempts
             .iter_mut()
             .find(|item| item.attempt_id == this.attempt_id)
-            .unwrap();
+            .ok_or(errors::StorageError::DatabaseError(error_stack::report!(
+                storage_models::errors::DatabaseError::NotFound
+            )))?;
+        if item.version != this.version {
+            return Err(errors::StorageError::DatabaseError(error_stack::report!(
+                storage_models::errors::DatabaseError::VersionConflict
+            ))
+            .into());
+        }
 
         *item = payment_attempt.apply_changeset(this);
 
@@ -318,6 +378,44 @@ impl PaymentAttemptInterface for MockDb {
             .cloned()
             .unwrap())
     }
+
+    async fn find_attempts_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.payment_id == payment_id
+                    && payment_attempt.merchant_id == merchant_id
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_charged_payment_attempts_without_amount_refunded(
+        &self,
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+        let mut payment_attempts = payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.id > after_id
+                    && payment_attempt.amount_refunded.is_none()
+                    && payment_attempt.status == enums::AttemptStatus::Charged
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_attempts.sort_by_key(|payment_attempt| payment_attempt.id);
+        payment_attempts.truncate(usize::try_from(limit).unwrap_or_default());
+        Ok(payment_attempts)
+    }
 }
 
 #[cfg(feature = "kv_store")]
@@ -325,7 +423,7 @@ mod storage {
     use common_utils::date_time;
     use error_stack::{IntoReport, ResultExt};
     use redis_interface::HsetnxReply;
-    use storage_models::reverse_lookup::ReverseLookup;
+    use storage_models::{errors::DatabaseError, reverse_lookup::ReverseLookup};
 
     use super::PaymentAttemptInterface;
     use crate::{
@@ -386,6 +484,7 @@ mod storage {
                         last_synced: payment_attempt.last_synced,
                         amount_to_capture: payment_attempt.amount_to_capture,
                         cancellation_reason: payment_attempt.cancellation_reason.clone(),
+                        cancellation_metadata: payment_attempt.cancellation_metadata.clone(),
                         mandate_id: payment_attempt.mandate_id.clone(),
                         browser_info: payment_attempt.browser_info.clone(),
                         payment_token: payment_attempt.payment_token.clone(),
@@ -394,6 +493,19 @@ mod storage {
                         payment_experience: payment_attempt.payment_experience.clone(),
                         payment_method_type: payment_attempt.payment_method_type.clone(),
                         payment_method_data: payment_attempt.payment_method_data.clone(),
+                        amount_refunded: payment_attempt.amount_refunded,
+                        three_ds_data: payment_attempt.three_ds_data.clone(),
+                        unified_code: payment_attempt.unified_code.clone(),
+                        unified_message: payment_attempt.unified_message.clone(),
+                        connector_raw_response: payment_attempt.connector_raw_response.clone(),
+                        avs_result: payment_attempt.avs_result,
+                        cvc_result: payment_attempt.cvc_result,
+                        fraud_check_verdict: payment_attempt.fraud_check_verdict,
+                        fraud_check_score: payment_attempt.fraud_check_score,
+                        settlement_amount: payment_attempt.settlement_amount,
+                        settlement_currency: payment_attempt.settlement_currency,
+                        fx_rate: payment_attempt.fx_rate.clone(),
+                        version: 0,
                     };
 
                     let field = format!("pa_{}", created_attempt.attempt_id);
@@ -471,12 +583,8 @@ mod storage {
                         .into_report()
                         .change_context(errors::StorageError::KVError)?;
                     let field = format!("pa_{}", updated_attempt.attempt_id);
-                    let updated_attempt = self
-                        .redis_conn
-                        .set_hash_fields(&key, (&field, &redis_value))
-                        .await
-                        .map(|_| updated_attempt)
-                        .change_context(errors::StorageError::KVError)?;
+                    self.set_versioned_hash_field(&key, &field, this.version, redis_value)
+                        .await?;
 
                     match (
                         old_connector_transaction_id,
@@ -572,17 +680,35 @@ mod storage {
             &self,
             payment_id: &str,
             merchant_id: &str,
-            _storage_scheme: enums::MerchantStorageScheme,
+            storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<PaymentAttempt, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
-            PaymentAttempt::find_last_successful_attempt_by_payment_id_merchant_id(
-                &conn,
-                payment_id,
-                merchant_id,
-            )
-            .await
-            .map_err(Into::into)
-            .into_report()
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_read(self).await?;
+                    PaymentAttempt::find_last_successful_attempt_by_payment_id_merchant_id(
+                        &conn,
+                        payment_id,
+                        merchant_id,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+
+                enums::MerchantStorageScheme::RedisKv => self
+                    .find_attempts_by_merchant_id_payment_id(
+                        merchant_id,
+                        payment_id,
+                        storage_scheme,
+                    )
+                    .await?
+                    .into_iter()
+                    .filter(|attempt| attempt.status == enums::AttemptStatus::Charged)
+                    .max_by_key(|attempt| attempt.modified_at)
+                    .ok_or_else(|| {
+                        errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+                    }),
+            }
         }
 
         async fn find_payment_attempt_by_merchant_id_connector_txn_id(
@@ -688,6 +814,69 @@ mod storage {
                 }
             }
         }
+
+        async fn find_attempts_by_merchant_id_payment_id(
+            &self,
+            merchant_id: &str,
+            payment_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let attempts =
+                PaymentAttempt::find_all_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+                    .await
+                    .map_err(Into::<errors::StorageError>::into)
+                    .into_report()?;
+
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => Ok(attempts),
+
+                enums::MerchantStorageScheme::RedisKv => {
+                    // The attempts in redis may not have been drained to the database yet, and
+                    // are more recent than their copies in the database when they have been
+                    let key = format!("{merchant_id}_{payment_id}");
+                    let kv_attempts = self
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .hscan_and_deserialize::<PaymentAttempt>(&key, "pa_*", None)
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    Ok(merge_attempts(attempts, kv_attempts))
+                }
+            }
+        }
+
+        async fn find_charged_payment_attempts_without_amount_refunded(
+            &self,
+            after_id: i32,
+            limit: i64,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentAttempt::find_charged_without_amount_refunded(&conn, after_id, limit)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+    }
+
+    /// Merges the attempts found in redis into those found in the database, preferring the copy
+    /// in redis of an attempt found in both.
+    fn merge_attempts(
+        attempts: Vec<PaymentAttempt>,
+        kv_attempts: Vec<PaymentAttempt>,
+    ) -> Vec<PaymentAttempt> {
+        let mut merged_attempts = attempts
+            .into_iter()
+            .filter(|attempt| {
+                !kv_attempts
+                    .iter()
+                    .any(|kv_attempt| kv_attempt.attempt_id == attempt.attempt_id)
+            })
+            .collect::<Vec<_>>();
+        merged_attempts.extend(kv_attempts);
+        merged_attempts.sort_by_key(|attempt| attempt.created_at);
+        merged_attempts
     }
 
     #[inline]
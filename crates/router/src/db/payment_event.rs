@@ -0,0 +1,69 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentEventInterface {
+    async fn insert_payment_event(
+        &self,
+        payment_event: storage::PaymentEventNew,
+    ) -> CustomResult<storage::PaymentEvent, errors::StorageError>;
+
+    async fn list_payment_events_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentEvent>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentEventInterface for Store {
+    async fn insert_payment_event(
+        &self,
+        payment_event: storage::PaymentEventNew,
+    ) -> CustomResult<storage::PaymentEvent, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_event
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_payment_events_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentEvent>, errors::StorageError> {
+        let conn = connection::pg_connection_replica_read(self).await?;
+        storage::PaymentEvent::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentEventInterface for MockDb {
+    async fn insert_payment_event(
+        &self,
+        _payment_event: storage::PaymentEventNew,
+    ) -> CustomResult<storage::PaymentEvent, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_payment_events_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentEvent>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -1,8 +1,13 @@
-use super::MockDb;
+use async_bb8_diesel::AsyncConnection;
+use error_stack::IntoReport;
+
+use super::{payment_attempt::PaymentAttemptInterface, MockDb};
 #[cfg(feature = "olap")]
 use crate::types::api;
 use crate::{
+    connection,
     core::errors::{self, CustomResult},
+    services::Store,
     types::storage::{self as types, enums},
 };
 
@@ -15,6 +20,17 @@ pub trait PaymentIntentInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentIntent, errors::StorageError>;
 
+    /// Updates the payment intent along with its active attempt. For merchants on the Postgres
+    /// storage scheme, both the updates are applied in a single transaction.
+    async fn update_payment_intent_and_attempt(
+        &self,
+        this: types::PaymentIntent,
+        payment_intent: types::PaymentIntentUpdate,
+        payment_attempt: types::PaymentAttempt,
+        payment_attempt_update: types::PaymentAttemptUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<(types::PaymentIntent, types::PaymentAttempt), errors::StorageError>;
+
     async fn insert_payment_intent(
         &self,
         new: types::PaymentIntentNew,
@@ -28,6 +44,13 @@ pub trait PaymentIntentInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentIntent, errors::StorageError>;
 
+    async fn find_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError>;
+
     #[cfg(feature = "olap")]
     async fn filter_payment_intent_by_constraints(
         &self,
@@ -35,6 +58,36 @@ pub trait PaymentIntentInterface {
         pc: &api::PaymentListConstraints,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn get_payment_intent_aggregates_by_merchant_ids(
+        &self,
+        merchant_ids: &[String],
+        constraints: &api::OrganizationPaymentsAggregateConstraints,
+    ) -> CustomResult<Vec<types::PaymentIntentAggregate>, errors::StorageError>;
+}
+
+async fn update_payment_intent_and_attempt_in_transaction(
+    store: &Store,
+    this: types::PaymentIntent,
+    payment_intent: types::PaymentIntentUpdate,
+    payment_attempt: types::PaymentAttempt,
+    payment_attempt_update: types::PaymentAttemptUpdate,
+) -> CustomResult<(types::PaymentIntent, types::PaymentAttempt), errors::StorageError> {
+    let conn = connection::pg_connection_write(store).await?;
+    conn.transaction_async(|conn| async move {
+        let payment_attempt = payment_attempt
+            .update_with_attempt_id(&conn, payment_attempt_update)
+            .await
+            .map_err(errors::StorageError::from)?;
+        let payment_intent = this
+            .update(&conn, payment_intent)
+            .await
+            .map_err(errors::StorageError::from)?;
+        Ok((payment_intent, payment_attempt))
+    })
+    .await
+    .into_report()
 }
 
 #[cfg(feature = "kv_store")]
@@ -43,14 +96,18 @@ mod storage {
     use error_stack::{IntoReport, ResultExt};
     use redis_interface::HsetnxReply;
 
-    use super::PaymentIntentInterface;
+    use super::{PaymentAttemptInterface, PaymentIntentInterface};
     #[cfg(feature = "olap")]
     use crate::types::api;
     use crate::{
         connection,
         core::errors::{self, CustomResult},
         services::Store,
-        types::storage::{enums, kv, payment_intent::*},
+        types::storage::{
+            enums, kv,
+            payment_attempt::{PaymentAttempt, PaymentAttemptUpdate},
+            payment_intent::*,
+        },
         utils::{self, db_utils, storage_partitioning},
     };
 
@@ -93,6 +150,14 @@ mod storage {
                         off_session: new.off_session,
                         client_secret: new.client_secret.clone(),
                         active_attempt_id: new.active_attempt_id.to_owned(),
+                        session_expiry: new.session_expiry,
+                        order_details: new.order_details.clone(),
+                        amount_breakdown: new.amount_breakdown.clone(),
+                        l2_l3_data: new.l2_l3_data.clone(),
+                        profile_id: new.profile_id.clone(),
+                        auto_capture_delay: new.auto_capture_delay,
+                        version: 0,
+                        connector_debug_mode: new.connector_debug_mode,
                     };
 
                     match self
@@ -153,13 +218,8 @@ mod storage {
                         utils::Encode::<PaymentIntent>::encode_to_string_of_json(&updated_intent)
                             .change_context(errors::StorageError::SerializationFailed)?;
 
-                    let updated_intent = self
-                        .redis_conn()
-                        .map_err(Into::<errors::StorageError>::into)?
-                        .set_hash_fields(&key, ("pi", &redis_value))
-                        .await
-                        .map(|_| updated_intent)
-                        .change_context(errors::StorageError::KVError)?;
+                    self.set_versioned_hash_field(&key, "pi", this.version, redis_value)
+                        .await?;
 
                     let redis_entry = kv::TypedSql {
                         op: kv::DBOperation::Update {
@@ -185,6 +245,44 @@ mod storage {
             }
         }
 
+        async fn update_payment_intent_and_attempt(
+            &self,
+            this: PaymentIntent,
+            payment_intent: PaymentIntentUpdate,
+            payment_attempt: PaymentAttempt,
+            payment_attempt_update: PaymentAttemptUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<(PaymentIntent, PaymentAttempt), errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    super::update_payment_intent_and_attempt_in_transaction(
+                        self,
+                        this,
+                        payment_intent,
+                        payment_attempt,
+                        payment_attempt_update,
+                    )
+                    .await
+                }
+
+                // The updates are drained to the database separately, and cannot be applied in a
+                // single transaction
+                enums::MerchantStorageScheme::RedisKv => {
+                    let payment_attempt = self
+                        .update_payment_attempt_with_attempt_id(
+                            payment_attempt,
+                            payment_attempt_update,
+                            storage_scheme,
+                        )
+                        .await?;
+                    let payment_intent = self
+                        .update_payment_intent(this, payment_intent, storage_scheme)
+                        .await?;
+                    Ok((payment_intent, payment_attempt))
+                }
+            }
+        }
+
         async fn find_payment_intent_by_payment_id_merchant_id(
             &self,
             payment_id: &str,
@@ -214,24 +312,49 @@ mod storage {
             }
         }
 
+        async fn find_payment_intents_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            // Payment intents can only be looked up by their key in redis, so the ones of the
+            // customer are found in the database irrespective of the storage scheme
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentIntent::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
         #[cfg(feature = "olap")]
         async fn filter_payment_intent_by_constraints(
             &self,
             merchant_id: &str,
             pc: &api::PaymentListConstraints,
-            storage_scheme: enums::MerchantStorageScheme,
+            _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
-            match storage_scheme {
-                enums::MerchantStorageScheme::PostgresOnly => {
-                    let conn = connection::pg_connection_read(self).await?;
-                    PaymentIntent::filter_by_constraints(&conn, merchant_id, pc)
-                        .await
-                        .map_err(Into::into)
-                        .into_report()
-                }
+            // Payment intents cannot be filtered in redis, so they are listed from the database
+            // irrespective of the storage scheme, where they are drained to shortly after being
+            // written
+            let conn = connection::pg_connection_replica_read(self).await?;
+            PaymentIntent::filter_by_constraints(&conn, merchant_id, pc)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
 
-                enums::MerchantStorageScheme::RedisKv => Err(errors::StorageError::KVError.into()),
-            }
+        #[cfg(feature = "olap")]
+        async fn get_payment_intent_aggregates_by_merchant_ids(
+            &self,
+            merchant_ids: &[String],
+            constraints: &api::OrganizationPaymentsAggregateConstraints,
+        ) -> CustomResult<Vec<PaymentIntentAggregate>, errors::StorageError> {
+            let conn = connection::pg_connection_replica_read(self).await?;
+            PaymentIntent::aggregate_by_merchant_ids(&conn, merchant_ids, constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
         }
     }
 }
@@ -247,7 +370,11 @@ mod storage {
         connection,
         core::errors::{self, CustomResult},
         services::Store,
-        types::storage::{enums, payment_intent::*},
+        types::storage::{
+            enums,
+            payment_attempt::{PaymentAttempt, PaymentAttemptUpdate},
+            payment_intent::*,
+        },
     };
 
     #[async_trait::async_trait]
@@ -274,6 +401,24 @@ mod storage {
                 .into_report()
         }
 
+        async fn update_payment_intent_and_attempt(
+            &self,
+            this: PaymentIntent,
+            payment_intent: PaymentIntentUpdate,
+            payment_attempt: PaymentAttempt,
+            payment_attempt_update: PaymentAttemptUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<(PaymentIntent, PaymentAttempt), errors::StorageError> {
+            super::update_payment_intent_and_attempt_in_transaction(
+                self,
+                this,
+                payment_intent,
+                payment_attempt,
+                payment_attempt_update,
+            )
+            .await
+        }
+
         async fn find_payment_intent_by_payment_id_merchant_id(
             &self,
             payment_id: &str,
@@ -287,6 +432,19 @@ mod storage {
                 .into_report()
         }
 
+        async fn find_payment_intents_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentIntent::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
         #[cfg(feature = "olap")]
         async fn filter_payment_intent_by_constraints(
             &self,
@@ -294,12 +452,25 @@ mod storage {
             pc: &api::PaymentListConstraints,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_replica_read(self).await?;
             PaymentIntent::filter_by_constraints(&conn, merchant_id, pc)
                 .await
                 .map_err(Into::into)
                 .into_report()
         }
+
+        #[cfg(feature = "olap")]
+        async fn get_payment_intent_aggregates_by_merchant_ids(
+            &self,
+            merchant_ids: &[String],
+            constraints: &api::OrganizationPaymentsAggregateConstraints,
+        ) -> CustomResult<Vec<PaymentIntentAggregate>, errors::StorageError> {
+            let conn = connection::pg_connection_replica_read(self).await?;
+            PaymentIntent::aggregate_by_merchant_ids(&conn, merchant_ids, constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
 }
 
@@ -316,6 +487,16 @@ impl PaymentIntentInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    #[cfg(feature = "olap")]
+    async fn get_payment_intent_aggregates_by_merchant_ids(
+        &self,
+        _merchant_ids: &[String],
+        _constraints: &api::OrganizationPaymentsAggregateConstraints,
+    ) -> CustomResult<Vec<types::PaymentIntentAggregate>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     #[allow(clippy::panic)]
     async fn insert_payment_intent(
         &self,
@@ -349,13 +530,19 @@ impl PaymentIntentInterface for MockDb {
             off_session: new.off_session,
             client_secret: new.client_secret,
             active_attempt_id: new.active_attempt_id.to_owned(),
+            session_expiry: new.session_expiry,
+            order_details: new.order_details,
+            amount_breakdown: new.amount_breakdown,
+            l2_l3_data: new.l2_l3_data,
+            profile_id: new.profile_id,
+            auto_capture_delay: new.auto_capture_delay,
+            version: 0,
+            connector_debug_mode: new.connector_debug_mode,
         };
         payment_intents.push(payment_intent.clone());
         Ok(payment_intent)
     }
 
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn update_payment_intent(
         &self,
         this: types::PaymentIntent,
@@ -366,11 +553,40 @@ impl PaymentIntentInterface for MockDb {
         let payment_intent = payment_intents
             .iter_mut()
             .find(|item| item.id == this.id)
-            .unwrap();
+            .ok_or(errors::StorageError::DatabaseError(error_stack::report!(
+                storage_models::errors::DatabaseError::NotFound
+            )))?;
+        if payment_intent.version != this.version {
+            return Err(errors::StorageError::DatabaseError(error_stack::report!(
+                storage_models::errors::DatabaseError::VersionConflict
+            ))
+            .into());
+        }
         *payment_intent = update.apply_changeset(this);
         Ok(payment_intent.clone())
     }
 
+    async fn update_payment_intent_and_attempt(
+        &self,
+        this: types::PaymentIntent,
+        payment_intent: types::PaymentIntentUpdate,
+        payment_attempt: types::PaymentAttempt,
+        payment_attempt_update: types::PaymentAttemptUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<(types::PaymentIntent, types::PaymentAttempt), errors::StorageError> {
+        let payment_attempt = self
+            .update_payment_attempt_with_attempt_id(
+                payment_attempt,
+                payment_attempt_update,
+                storage_scheme,
+            )
+            .await?;
+        let payment_intent = self
+            .update_payment_intent(this, payment_intent, storage_scheme)
+            .await?;
+        Ok((payment_intent, payment_attempt))
+    }
+
     // safety: only used for testing
     #[allow(clippy::unwrap_used)]
     async fn find_payment_intent_by_payment_id_merchant_id(
@@ -389,4 +605,74 @@ impl PaymentIntentInterface for MockDb {
             .cloned()
             .unwrap())
     }
+
+    async fn find_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError> {
+        let payment_intents = self.payment_intents.lock().await;
+
+        Ok(payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == merchant_id
+                    && payment_intent.customer_id.as_deref() == Some(customer_id)
+            })
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+    use crate::configs::settings::Settings;
+
+    #[tokio::test]
+    async fn test_payment_intent_updated_concurrently_conflicts() {
+        let settings = Settings::new().expect("invalid settings");
+        let db = MockDb::new(&settings).await;
+        let storage_scheme = enums::MerchantStorageScheme::PostgresOnly;
+
+        let payment_intent = db
+            .insert_payment_intent(
+                types::PaymentIntentNew {
+                    payment_id: "pay_version".to_string(),
+                    merchant_id: "merchant".to_string(),
+                    ..Default::default()
+                },
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        let update = types::PaymentIntentUpdate::PGStatusUpdate {
+            status: enums::IntentStatus::Processing,
+        };
+
+        let updated_intent = db
+            .update_payment_intent(payment_intent.clone(), update.clone(), storage_scheme)
+            .await
+            .unwrap();
+        assert_eq!(updated_intent.version, payment_intent.version + 1);
+
+        // The payment intent was updated since this version of it was read
+        let error = db
+            .update_payment_intent(payment_intent, update.clone(), storage_scheme)
+            .await
+            .unwrap_err();
+        assert!(error.current_context().is_db_version_conflict());
+
+        let missing_intent = types::PaymentIntent {
+            id: updated_intent.id + 1,
+            ..updated_intent
+        };
+        let error = db
+            .update_payment_intent(missing_intent, update, storage_scheme)
+            .await
+            .unwrap_err();
+        assert!(error.current_context().is_db_not_found());
+    }
 }
@@ -0,0 +1,170 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentLinkInterface {
+    async fn insert_payment_link(
+        &self,
+        payment_link: storage::PaymentLinkNew,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+
+    async fn find_payment_link_by_link_id(
+        &self,
+        link_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+
+    async fn update_payment_link(
+        &self,
+        this: storage::PaymentLink,
+        payment_link: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+
+    /// Updates the payment link only if its usage count has not changed since it was read.
+    async fn update_payment_link_usage_count(
+        &self,
+        this: storage::PaymentLink,
+        payment_link: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentLinkInterface for Store {
+    async fn insert_payment_link(
+        &self,
+        payment_link: storage::PaymentLinkNew,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_link
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payment_link_by_link_id(
+        &self,
+        link_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentLink::find_by_link_id(&conn, link_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_payment_link(
+        &self,
+        this: storage::PaymentLink,
+        payment_link: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payment_link)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_payment_link_usage_count(
+        &self,
+        this: storage::PaymentLink,
+        payment_link: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_with_usage_count(&conn, payment_link)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentLinkInterface for MockDb {
+    async fn insert_payment_link(
+        &self,
+        payment_link: storage::PaymentLinkNew,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let mut payment_links = self.payment_links.lock().await;
+        let now = common_utils::date_time::now();
+        let payment_link = storage::PaymentLink {
+            #[allow(clippy::as_conversions)]
+            id: payment_links.len() as i32,
+            link_id: payment_link.link_id,
+            merchant_id: payment_link.merchant_id,
+            amount: payment_link.amount,
+            currency: payment_link.currency,
+            description: payment_link.description,
+            return_url: payment_link.return_url,
+            reusable: payment_link.reusable,
+            status: payment_link.status,
+            usage_count: 0,
+            last_payment_id: None,
+            expires_at: payment_link.expires_at,
+            created_at: payment_link.created_at.unwrap_or(now),
+            modified_at: payment_link.modified_at.unwrap_or(now),
+        };
+        payment_links.push(payment_link.clone());
+        Ok(payment_link)
+    }
+
+    async fn find_payment_link_by_link_id(
+        &self,
+        link_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        self.payment_links
+            .lock()
+            .await
+            .iter()
+            .find(|payment_link| payment_link.link_id == link_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::DatabaseError(error_stack::report!(
+                    storage_models::errors::DatabaseError::NotFound
+                ))
+                .into(),
+            )
+    }
+
+    async fn update_payment_link(
+        &self,
+        this: storage::PaymentLink,
+        payment_link: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let mut payment_links = self.payment_links.lock().await;
+        let stored_payment_link = payment_links
+            .iter_mut()
+            .find(|item| item.link_id == this.link_id)
+            .ok_or(errors::StorageError::DatabaseError(error_stack::report!(
+                storage_models::errors::DatabaseError::NotFound
+            )))?;
+        *stored_payment_link = payment_link.apply_changeset(stored_payment_link.clone());
+        Ok(stored_payment_link.clone())
+    }
+
+    async fn update_payment_link_usage_count(
+        &self,
+        this: storage::PaymentLink,
+        payment_link: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let mut payment_links = self.payment_links.lock().await;
+        let stored_payment_link = payment_links
+            .iter_mut()
+            .find(|item| item.link_id == this.link_id)
+            .ok_or(errors::StorageError::DatabaseError(error_stack::report!(
+                storage_models::errors::DatabaseError::NotFound
+            )))?;
+        if stored_payment_link.usage_count != this.usage_count {
+            return Err(errors::StorageError::DatabaseError(error_stack::report!(
+                storage_models::errors::DatabaseError::VersionConflict
+            ))
+            .into());
+        }
+        *stored_payment_link = payment_link.apply_changeset(this);
+        Ok(stored_payment_link.clone())
+    }
+}
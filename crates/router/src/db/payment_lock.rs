@@ -0,0 +1,158 @@
+use crate::{
+    core::errors::{self, CustomResult},
+    db::MockDb,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentLockInterface {
+    /// Takes the lock on the payment for `ttl` seconds, returning the fencing token identifying
+    /// the holder of the lock, or `None` if the lock is already held.
+    async fn acquire_payment_lock(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        ttl: i64,
+    ) -> CustomResult<Option<i64>, errors::StorageError>;
+
+    /// Returns the fencing token of the current holder of the lock on the payment, if the lock is
+    /// held.
+    async fn find_payment_lock_holder(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Option<i64>, errors::StorageError>;
+
+    /// Releases the lock on the payment, only if it is still held by the holder of the fencing
+    /// token.
+    async fn release_payment_lock(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        fencing_token: i64,
+    ) -> CustomResult<(), errors::StorageError>;
+}
+
+fn get_payment_lock_redis_key(merchant_id: &str, payment_id: &str) -> String {
+    format!("payment_lock_{merchant_id}_{payment_id}")
+}
+
+fn get_fencing_token_redis_key(merchant_id: &str, payment_id: &str) -> String {
+    format!("payment_lock_fencing_token_{merchant_id}_{payment_id}")
+}
+
+mod storage {
+    use error_stack::ResultExt;
+    use redis_interface::SetnxReply;
+
+    use super::{get_fencing_token_redis_key, get_payment_lock_redis_key, PaymentLockInterface};
+    use crate::{
+        core::errors::{self, CustomResult},
+        services::Store,
+    };
+
+    #[async_trait::async_trait]
+    impl PaymentLockInterface for Store {
+        async fn acquire_payment_lock(
+            &self,
+            merchant_id: &str,
+            payment_id: &str,
+            ttl: i64,
+        ) -> CustomResult<Option<i64>, errors::StorageError> {
+            let redis = self
+                .redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?;
+
+            // The fencing tokens of a payment increase with every attempt to take the lock, and
+            // are retained for as long as the lock taken with the latest of them can be held
+            let fencing_token_key = get_fencing_token_redis_key(merchant_id, payment_id);
+            let fencing_token = redis
+                .increment_hash_field(&fencing_token_key, "token", 1)
+                .await
+                .change_context(errors::StorageError::KVError)?;
+            redis
+                .set_expiry(&fencing_token_key, ttl)
+                .await
+                .change_context(errors::StorageError::KVError)?;
+
+            let key = get_payment_lock_redis_key(merchant_id, payment_id);
+            redis
+                .serialize_and_set_key_if_not_exist_with_expiry(&key, fencing_token, ttl)
+                .await
+                .map(|reply| matches!(reply, SetnxReply::KeySet).then_some(fencing_token))
+                .change_context(errors::StorageError::KVError)
+        }
+
+        async fn find_payment_lock_holder(
+            &self,
+            merchant_id: &str,
+            payment_id: &str,
+        ) -> CustomResult<Option<i64>, errors::StorageError> {
+            let key = get_payment_lock_redis_key(merchant_id, payment_id);
+            match self
+                .redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .get_and_deserialize_key::<i64>(&key, "i64")
+                .await
+            {
+                Ok(fencing_token) => Ok(Some(fencing_token)),
+                Err(error) => match error.current_context() {
+                    errors::RedisError::NotFound => Ok(None),
+                    _ => Err(error.change_context(errors::StorageError::KVError)),
+                },
+            }
+        }
+
+        async fn release_payment_lock(
+            &self,
+            merchant_id: &str,
+            payment_id: &str,
+            fencing_token: i64,
+        ) -> CustomResult<(), errors::StorageError> {
+            // The lock may have expired and been taken by another request in the meanwhile, in
+            // which case it is left to its new holder
+            if self
+                .find_payment_lock_holder(merchant_id, payment_id)
+                .await?
+                != Some(fencing_token)
+            {
+                return Ok(());
+            }
+
+            let key = get_payment_lock_redis_key(merchant_id, payment_id);
+            self.redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .delete_key(&key)
+                .await
+                .change_context(errors::StorageError::KVError)
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentLockInterface for MockDb {
+    async fn acquire_payment_lock(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+        _ttl: i64,
+    ) -> CustomResult<Option<i64>, errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+
+    async fn find_payment_lock_holder(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+    ) -> CustomResult<Option<i64>, errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+
+    async fn release_payment_lock(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+        _fencing_token: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+}
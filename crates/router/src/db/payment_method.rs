@@ -1,4 +1,5 @@
 use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
 
 use super::{MockDb, Store};
 use crate::{
@@ -86,35 +87,81 @@ impl PaymentMethodInterface for Store {
 impl PaymentMethodInterface for MockDb {
     async fn find_payment_method(
         &self,
-        _payment_method_id: &str,
+        payment_method_id: &str,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.payment_methods
+            .lock()
+            .await
+            .iter()
+            .find(|pm| pm.payment_method_id == payment_method_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
     }
 
     async fn insert_payment_method(
         &self,
-        _m: storage::PaymentMethodNew,
+        m: storage::PaymentMethodNew,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut payment_methods = self.payment_methods.lock().await;
+        let payment_method = storage::PaymentMethod {
+            #[allow(clippy::as_conversions)]
+            id: payment_methods.len() as i32,
+            customer_id: m.customer_id,
+            merchant_id: m.merchant_id,
+            payment_method_id: m.payment_method_id,
+            accepted_currency: m.accepted_currency,
+            scheme: m.scheme,
+            token: m.token,
+            cardholder_name: m.cardholder_name,
+            issuer_name: m.issuer_name,
+            issuer_country: m.issuer_country,
+            payer_country: m.payer_country,
+            is_stored: m.is_stored,
+            swift_code: m.swift_code,
+            direct_debit_token: m.direct_debit_token,
+            created_at: m.created_at,
+            last_modified: m.last_modified,
+            payment_method: m.payment_method,
+            payment_method_type: m.payment_method_type,
+            payment_method_issuer: m.payment_method_issuer,
+            payment_method_issuer_code: m.payment_method_issuer_code,
+            metadata: m.metadata,
+        };
+        payment_methods.push(payment_method.clone());
+        Ok(payment_method)
     }
 
     async fn find_payment_method_by_customer_id_merchant_id_list(
         &self,
-        _customer_id: &str,
-        _merchant_id: &str,
+        customer_id: &str,
+        merchant_id: &str,
     ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        Ok(self
+            .payment_methods
+            .lock()
+            .await
+            .iter()
+            .filter(|pm| pm.customer_id == customer_id && pm.merchant_id == merchant_id)
+            .cloned()
+            .collect())
     }
 
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
-        _merchant_id: &str,
-        _payment_method_id: &str,
+        merchant_id: &str,
+        payment_method_id: &str,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut payment_methods = self.payment_methods.lock().await;
+        let position = payment_methods
+            .iter()
+            .position(|pm| {
+                pm.merchant_id == merchant_id && pm.payment_method_id == payment_method_id
+            })
+            .ok_or(errors::StorageError::DatabaseError(
+                DatabaseError::NotFound.into(),
+            ))?;
+        Ok(payment_methods.remove(position))
     }
 }
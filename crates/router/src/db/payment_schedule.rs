@@ -0,0 +1,96 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentScheduleInterface {
+    async fn insert_payment_schedule(
+        &self,
+        payment_schedule: storage::PaymentScheduleNew,
+    ) -> CustomResult<storage::PaymentSchedule, errors::StorageError>;
+
+    async fn find_payment_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+    ) -> CustomResult<storage::PaymentSchedule, errors::StorageError>;
+
+    async fn update_payment_schedule(
+        &self,
+        this: storage::PaymentSchedule,
+        payment_schedule: storage::PaymentScheduleUpdate,
+    ) -> CustomResult<storage::PaymentSchedule, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentScheduleInterface for Store {
+    async fn insert_payment_schedule(
+        &self,
+        payment_schedule: storage::PaymentScheduleNew,
+    ) -> CustomResult<storage::PaymentSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_schedule
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payment_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+    ) -> CustomResult<storage::PaymentSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentSchedule::find_by_merchant_id_schedule_id(&conn, merchant_id, schedule_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_payment_schedule(
+        &self,
+        this: storage::PaymentSchedule,
+        payment_schedule: storage::PaymentScheduleUpdate,
+    ) -> CustomResult<storage::PaymentSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payment_schedule)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentScheduleInterface for MockDb {
+    async fn insert_payment_schedule(
+        &self,
+        _payment_schedule: storage::PaymentScheduleNew,
+    ) -> CustomResult<storage::PaymentSchedule, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_schedule_by_merchant_id_schedule_id(
+        &self,
+        _merchant_id: &str,
+        _schedule_id: &str,
+    ) -> CustomResult<storage::PaymentSchedule, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_schedule(
+        &self,
+        _this: storage::PaymentSchedule,
+        _payment_schedule: storage::PaymentScheduleUpdate,
+    ) -> CustomResult<storage::PaymentSchedule, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,96 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentSplitInterface {
+    async fn insert_payment_split(
+        &self,
+        payment_split: storage::PaymentSplitNew,
+    ) -> CustomResult<storage::PaymentSplit, errors::StorageError>;
+
+    async fn find_all_payment_splits_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentSplit>, errors::StorageError>;
+
+    async fn update_payment_split_with_split_id(
+        &self,
+        this: storage::PaymentSplit,
+        payment_split: storage::PaymentSplitUpdate,
+    ) -> CustomResult<storage::PaymentSplit, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentSplitInterface for Store {
+    async fn insert_payment_split(
+        &self,
+        payment_split: storage::PaymentSplitNew,
+    ) -> CustomResult<storage::PaymentSplit, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_split
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_all_payment_splits_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentSplit>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentSplit::find_all_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_payment_split_with_split_id(
+        &self,
+        this: storage::PaymentSplit,
+        payment_split: storage::PaymentSplitUpdate,
+    ) -> CustomResult<storage::PaymentSplit, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payment_split)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentSplitInterface for MockDb {
+    async fn insert_payment_split(
+        &self,
+        _payment_split: storage::PaymentSplitNew,
+    ) -> CustomResult<storage::PaymentSplit, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_all_payment_splits_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentSplit>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_split_with_split_id(
+        &self,
+        _this: storage::PaymentSplit,
+        _payment_split: storage::PaymentSplitUpdate,
+    ) -> CustomResult<storage::PaymentSplit, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,127 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutInterface {
+    async fn insert_payout(
+        &self,
+        payout: storage::PayoutNew,
+    ) -> CustomResult<storage::Payout, errors::StorageError>;
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError>;
+
+    async fn find_payout_by_merchant_id_connector_payout_id_connector(
+        &self,
+        merchant_id: &str,
+        connector_payout_id: &str,
+        connector: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError>;
+
+    async fn update_payout(
+        &self,
+        this: storage::Payout,
+        payout: storage::PayoutUpdate,
+    ) -> CustomResult<storage::Payout, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutInterface for Store {
+    async fn insert_payout(
+        &self,
+        payout: storage::PayoutNew,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payout.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Payout::find_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payout_by_merchant_id_connector_payout_id_connector(
+        &self,
+        merchant_id: &str,
+        connector_payout_id: &str,
+        connector: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Payout::find_by_merchant_id_connector_payout_id_connector(
+            &conn,
+            merchant_id,
+            connector_payout_id,
+            connector,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_payout(
+        &self,
+        this: storage::Payout,
+        payout: storage::PayoutUpdate,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payout)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutInterface for MockDb {
+    async fn insert_payout(
+        &self,
+        _payout: storage::PayoutNew,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        _merchant_id: &str,
+        _payout_id: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_by_merchant_id_connector_payout_id_connector(
+        &self,
+        _merchant_id: &str,
+        _connector_payout_id: &str,
+        _connector: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payout(
+        &self,
+        _this: storage::Payout,
+        _payout: storage::PayoutUpdate,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,73 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutMethodInterface {
+    async fn insert_payout_method(
+        &self,
+        payout_method: storage::PayoutMethodNew,
+    ) -> CustomResult<storage::PayoutMethod, errors::StorageError>;
+
+    async fn find_payout_method_by_merchant_id_payout_method_id(
+        &self,
+        merchant_id: &str,
+        payout_method_id: &str,
+    ) -> CustomResult<storage::PayoutMethod, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutMethodInterface for Store {
+    async fn insert_payout_method(
+        &self,
+        payout_method: storage::PayoutMethodNew,
+    ) -> CustomResult<storage::PayoutMethod, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payout_method
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payout_method_by_merchant_id_payout_method_id(
+        &self,
+        merchant_id: &str,
+        payout_method_id: &str,
+    ) -> CustomResult<storage::PayoutMethod, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutMethod::find_by_merchant_id_payout_method_id(
+            &conn,
+            merchant_id,
+            payout_method_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutMethodInterface for MockDb {
+    async fn insert_payout_method(
+        &self,
+        _payout_method: storage::PayoutMethodNew,
+    ) -> CustomResult<storage::PayoutMethod, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_method_by_merchant_id_payout_method_id(
+        &self,
+        _merchant_id: &str,
+        _payout_method_id: &str,
+    ) -> CustomResult<storage::PayoutMethod, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -50,6 +50,20 @@ pub trait ProcessTrackerInterface {
         status: enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_processes_by_status(
+        &self,
+        status: enums::ProcessTrackerStatus,
+        runner: Option<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn replay_dead_letter_processes(
+        &self,
+        ids: Vec<String>,
+        schedule_time: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -97,6 +111,32 @@ impl ProcessTrackerInterface for Store {
         .into_report()
     }
 
+    async fn find_processes_by_status(
+        &self,
+        status: enums::ProcessTrackerStatus,
+        runner: Option<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_processes_by_status(&conn, status, runner, limit, offset)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn replay_dead_letter_processes(
+        &self,
+        ids: Vec<String>,
+        schedule_time: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ProcessTracker::replay_dead_letter_processes(&conn, ids, schedule_time)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -182,6 +222,44 @@ impl ProcessTrackerInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_processes_by_status(
+        &self,
+        status: enums::ProcessTrackerStatus,
+        runner: Option<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let mut processes = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                process.status == status
+                    && runner
+                        .as_ref()
+                        .map_or(true, |runner| process.runner.as_ref() == Some(runner))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        processes.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+
+        let offset = usize::try_from(offset.unwrap_or(0)).unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+        Ok(processes.into_iter().skip(offset).take(limit).collect())
+    }
+
+    async fn replay_dead_letter_processes(
+        &self,
+        _ids: Vec<String>,
+        _schedule_time: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
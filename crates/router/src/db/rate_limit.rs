@@ -0,0 +1,100 @@
+use crate::{
+    configs::settings::RateLimitBucket,
+    core::errors::{self, CustomResult},
+    db::MockDb,
+};
+
+#[async_trait::async_trait]
+pub trait RateLimitInterface {
+    /// Takes a token from the bucket identified by `key`, after refilling it for the time elapsed
+    /// since it was last refilled. Returns the number of tokens left in the bucket, or `None` if
+    /// the bucket was empty.
+    async fn consume_rate_limit_token(
+        &self,
+        key: &str,
+        bucket: RateLimitBucket,
+    ) -> CustomResult<Option<u64>, errors::StorageError>;
+}
+
+/// Refills the bucket and takes a token from it atomically, so that concurrent requests from all
+/// instances of the application share the bucket. Buckets expire once they would be full again.
+const CONSUME_TOKEN_SCRIPT: &str = r#"
+local capacity = tonumber(ARGV[1])
+local refill_rate = tonumber(ARGV[2])
+local now = tonumber(ARGV[3])
+
+local bucket = redis.call("HMGET", KEYS[1], "tokens", "refilled_at")
+local tokens = tonumber(bucket[1]) or capacity
+local refilled_at = tonumber(bucket[2]) or now
+tokens = math.min(capacity, tokens + math.max(0, now - refilled_at) * refill_rate / 1000)
+
+local allowed = 0
+if tokens >= 1 then
+    tokens = tokens - 1
+    allowed = 1
+end
+
+redis.call("HSET", KEYS[1], "tokens", tostring(tokens), "refilled_at", tostring(now))
+redis.call("PEXPIRE", KEYS[1], math.ceil(capacity * 1000 / refill_rate))
+return { allowed, math.floor(tokens) }
+"#;
+
+fn get_rate_limit_redis_key(key: &str) -> String {
+    format!("rate_limit_{key}")
+}
+
+mod storage {
+    use error_stack::{IntoReport, ResultExt};
+
+    use super::{get_rate_limit_redis_key, RateLimitInterface, CONSUME_TOKEN_SCRIPT};
+    use crate::{
+        configs::settings::RateLimitBucket,
+        core::errors::{self, CustomResult},
+        services::Store,
+    };
+
+    #[async_trait::async_trait]
+    impl RateLimitInterface for Store {
+        async fn consume_rate_limit_token(
+            &self,
+            key: &str,
+            bucket: RateLimitBucket,
+        ) -> CustomResult<Option<u64>, errors::StorageError> {
+            let now = common_utils::date_time::now_unix_timestamp_millis();
+            let reply: Vec<i64> = self
+                .redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .evaluate_script(
+                    CONSUME_TOKEN_SCRIPT,
+                    vec![get_rate_limit_redis_key(key)],
+                    vec![
+                        bucket.capacity.to_string(),
+                        bucket.refill_rate.to_string(),
+                        now.to_string(),
+                    ],
+                )
+                .await
+                .change_context(errors::StorageError::KVError)?;
+
+            match reply.as_slice() {
+                [allowed, tokens] => {
+                    Ok((*allowed == 1).then(|| u64::try_from(*tokens).unwrap_or(0)))
+                }
+                _ => Err(errors::StorageError::KVError)
+                    .into_report()
+                    .attach_printable("Unexpected reply from the rate limit script"),
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RateLimitInterface for MockDb {
+    async fn consume_rate_limit_token(
+        &self,
+        _key: &str,
+        _bucket: RateLimitBucket,
+    ) -> CustomResult<Option<u64>, errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+}
@@ -0,0 +1,42 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait RedactionAuditInterface {
+    async fn insert_redaction_audit(
+        &self,
+        redaction_audit: storage::RedactionAuditNew,
+    ) -> CustomResult<storage::RedactionAudit, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RedactionAuditInterface for Store {
+    async fn insert_redaction_audit(
+        &self,
+        redaction_audit: storage::RedactionAuditNew,
+    ) -> CustomResult<storage::RedactionAudit, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        redaction_audit
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl RedactionAuditInterface for MockDb {
+    async fn insert_redaction_audit(
+        &self,
+        _redaction_audit: storage::RedactionAuditNew,
+    ) -> CustomResult<storage::RedactionAudit, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
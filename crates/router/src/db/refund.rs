@@ -190,7 +190,7 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
             limit: i64,
         ) -> CustomResult<Vec<storage_models::refund::Refund>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_replica_read(self).await?;
             <storage_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(
                 &conn,
                 merchant_id,
@@ -290,6 +290,7 @@ mod storage {
                         sent_to_gateway: new.sent_to_gateway,
                         refund_error_message: None,
                         refund_error_code: None,
+                        connector_raw_response: None,
                         metadata: new.metadata.clone(),
                         refund_arn: new.refund_arn.clone(),
                         created_at: new.created_at.unwrap_or_else(date_time::now),
@@ -587,7 +588,7 @@ mod storage {
         ) -> CustomResult<Vec<storage_models::refund::Refund>, errors::StorageError> {
             match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => {
-                    let conn = connection::pg_connection_read(self).await?;
+                    let conn = connection::pg_connection_replica_read(self).await?;
                     <storage_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(&conn, merchant_id, refund_details, limit)
                         .await
                         .map_err(Into::into)
@@ -640,6 +641,7 @@ impl RefundInterface for MockDb {
             sent_to_gateway: new.sent_to_gateway,
             refund_error_message: None,
             refund_error_code: None,
+            connector_raw_response: None,
             metadata: new.metadata,
             refund_arn: new.refund_arn.clone(),
             created_at: new.created_at.unwrap_or(current_time),
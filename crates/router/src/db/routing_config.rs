@@ -0,0 +1,154 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait RoutingConfigInterface {
+    async fn insert_routing_config(
+        &self,
+        routing_config: storage::RoutingConfigNew,
+    ) -> CustomResult<storage::RoutingConfig, errors::StorageError>;
+
+    async fn find_routing_config_by_merchant_id_routing_config_id(
+        &self,
+        merchant_id: &str,
+        routing_config_id: &str,
+    ) -> CustomResult<storage::RoutingConfig, errors::StorageError>;
+
+    async fn find_active_routing_config_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Option<storage::RoutingConfig>, errors::StorageError>;
+
+    async fn list_routing_configs_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::RoutingConfig>, errors::StorageError>;
+
+    /// Activates the routing configuration, deactivating the configuration of the merchant which
+    /// was active until now.
+    async fn activate_routing_config(
+        &self,
+        this: storage::RoutingConfig,
+    ) -> CustomResult<storage::RoutingConfig, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RoutingConfigInterface for Store {
+    async fn insert_routing_config(
+        &self,
+        routing_config: storage::RoutingConfigNew,
+    ) -> CustomResult<storage::RoutingConfig, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        routing_config
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_routing_config_by_merchant_id_routing_config_id(
+        &self,
+        merchant_id: &str,
+        routing_config_id: &str,
+    ) -> CustomResult<storage::RoutingConfig, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RoutingConfig::find_by_merchant_id_routing_config_id(
+            &conn,
+            merchant_id,
+            routing_config_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_active_routing_config_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Option<storage::RoutingConfig>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RoutingConfig::find_optional_active_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_routing_configs_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::RoutingConfig>, errors::StorageError> {
+        let conn = connection::pg_connection_replica_read(self).await?;
+        storage::RoutingConfig::find_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn activate_routing_config(
+        &self,
+        this: storage::RoutingConfig,
+    ) -> CustomResult<storage::RoutingConfig, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RoutingConfig::deactivate_all_by_merchant_id(&conn, &this.merchant_id)
+            .await
+            .map_err(Into::<errors::StorageError>::into)
+            .into_report()?;
+        this.update(
+            &conn,
+            storage::RoutingConfigUpdate::ActivationUpdate { is_active: true },
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl RoutingConfigInterface for MockDb {
+    async fn insert_routing_config(
+        &self,
+        _routing_config: storage::RoutingConfigNew,
+    ) -> CustomResult<storage::RoutingConfig, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_routing_config_by_merchant_id_routing_config_id(
+        &self,
+        _merchant_id: &str,
+        _routing_config_id: &str,
+    ) -> CustomResult<storage::RoutingConfig, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_active_routing_config_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Option<storage::RoutingConfig>, errors::StorageError> {
+        // No routing configurations are stored in `MockDb`
+        Ok(None)
+    }
+
+    async fn list_routing_configs_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Vec<storage::RoutingConfig>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn activate_routing_config(
+        &self,
+        _this: storage::RoutingConfig,
+    ) -> CustomResult<storage::RoutingConfig, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,148 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait SettlementReportInterface {
+    async fn insert_settlement_report(
+        &self,
+        settlement_report: storage::SettlementReportNew,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError>;
+
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError>;
+
+    async fn insert_settlement_line(
+        &self,
+        settlement_line: storage::SettlementLineNew,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError>;
+
+    async fn find_settlement_lines_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError>;
+
+    async fn find_settlement_lines_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SettlementReportInterface for Store {
+    async fn insert_settlement_report(
+        &self,
+        settlement_report: storage::SettlementReportNew,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        settlement_report
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementReport::find_by_merchant_id_report_id(&conn, merchant_id, report_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn insert_settlement_line(
+        &self,
+        settlement_line: storage::SettlementLineNew,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        settlement_line
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_settlement_lines_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementLine::find_by_merchant_id_report_id(&conn, merchant_id, report_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_settlement_lines_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementLine::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl SettlementReportInterface for MockDb {
+    async fn insert_settlement_report(
+        &self,
+        _settlement_report: storage::SettlementReportNew,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        _merchant_id: &str,
+        _report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_settlement_line(
+        &self,
+        _settlement_line: storage::SettlementLineNew,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_settlement_lines_by_merchant_id_report_id(
+        &self,
+        _merchant_id: &str,
+        _report_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_settlement_lines_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,42 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait StatusMismatchInterface {
+    async fn insert_status_mismatch(
+        &self,
+        status_mismatch: storage::StatusMismatchNew,
+    ) -> CustomResult<storage::StatusMismatch, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl StatusMismatchInterface for Store {
+    async fn insert_status_mismatch(
+        &self,
+        status_mismatch: storage::StatusMismatchNew,
+    ) -> CustomResult<storage::StatusMismatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        status_mismatch
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl StatusMismatchInterface for MockDb {
+    async fn insert_status_mismatch(
+        &self,
+        _status_mismatch: storage::StatusMismatchNew,
+    ) -> CustomResult<storage::StatusMismatch, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
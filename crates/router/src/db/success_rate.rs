@@ -0,0 +1,103 @@
+use crate::{
+    core::errors::{self, CustomResult},
+    db::MockDb,
+    types::storage::AuthorizationCount,
+};
+
+#[async_trait::async_trait]
+pub trait SuccessRateInterface {
+    /// Counts an authorization against the key, which expires after `ttl` seconds.
+    async fn increment_authorization_count(
+        &self,
+        key: &str,
+        is_success: bool,
+        ttl: i64,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn get_authorization_count(
+        &self,
+        key: &str,
+    ) -> CustomResult<AuthorizationCount, errors::StorageError>;
+}
+
+const TOTAL_FIELD: &str = "total";
+const SUCCESS_FIELD: &str = "success";
+
+mod storage {
+    use std::collections::HashMap;
+
+    use error_stack::ResultExt;
+
+    use super::{SuccessRateInterface, SUCCESS_FIELD, TOTAL_FIELD};
+    use crate::{
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::AuthorizationCount,
+    };
+
+    #[async_trait::async_trait]
+    impl SuccessRateInterface for Store {
+        async fn increment_authorization_count(
+            &self,
+            key: &str,
+            is_success: bool,
+            ttl: i64,
+        ) -> CustomResult<(), errors::StorageError> {
+            let redis_conn = self
+                .redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?;
+
+            redis_conn
+                .increment_hash_field(key, TOTAL_FIELD, 1)
+                .await
+                .change_context(errors::StorageError::KVError)?;
+            if is_success {
+                redis_conn
+                    .increment_hash_field(key, SUCCESS_FIELD, 1)
+                    .await
+                    .change_context(errors::StorageError::KVError)?;
+            }
+
+            redis_conn
+                .set_expiry(key, ttl)
+                .await
+                .change_context(errors::StorageError::KVError)
+        }
+
+        async fn get_authorization_count(
+            &self,
+            key: &str,
+        ) -> CustomResult<AuthorizationCount, errors::StorageError> {
+            let fields: HashMap<String, i64> = self
+                .redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .get_hash_fields(key)
+                .await
+                .change_context(errors::StorageError::KVError)?;
+
+            Ok(AuthorizationCount {
+                total: fields.get(TOTAL_FIELD).copied().unwrap_or_default(),
+                success: fields.get(SUCCESS_FIELD).copied().unwrap_or_default(),
+            })
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SuccessRateInterface for MockDb {
+    async fn increment_authorization_count(
+        &self,
+        _key: &str,
+        _is_success: bool,
+        _ttl: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+
+    async fn get_authorization_count(
+        &self,
+        _key: &str,
+    ) -> CustomResult<AuthorizationCount, errors::StorageError> {
+        Err(errors::StorageError::KVError.into())
+    }
+}
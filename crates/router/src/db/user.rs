@@ -0,0 +1,86 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait UserInterface {
+    async fn insert_user(
+        &self,
+        user: storage::UserNew,
+    ) -> CustomResult<storage::User, errors::StorageError>;
+
+    async fn find_user_by_email(
+        &self,
+        email: &str,
+    ) -> CustomResult<storage::User, errors::StorageError>;
+
+    async fn find_user_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<storage::User, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl UserInterface for Store {
+    async fn insert_user(
+        &self,
+        user: storage::UserNew,
+    ) -> CustomResult<storage::User, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        user.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_user_by_email(
+        &self,
+        email: &str,
+    ) -> CustomResult<storage::User, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::User::find_by_email(&conn, email)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_user_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<storage::User, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::User::find_by_user_id(&conn, user_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl UserInterface for MockDb {
+    async fn insert_user(
+        &self,
+        _user: storage::UserNew,
+    ) -> CustomResult<storage::User, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_user_by_email(
+        &self,
+        _email: &str,
+    ) -> CustomResult<storage::User, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_user_by_user_id(
+        &self,
+        _user_id: &str,
+    ) -> CustomResult<storage::User, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
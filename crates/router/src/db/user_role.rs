@@ -0,0 +1,123 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait UserRoleInterface {
+    async fn insert_user_role(
+        &self,
+        user_role: storage::UserRoleNew,
+    ) -> CustomResult<storage::UserRole, errors::StorageError>;
+
+    async fn find_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<storage::UserRole, errors::StorageError>;
+
+    async fn list_user_roles_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError>;
+
+    async fn update_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+        user_role: storage::UserRoleUpdate,
+    ) -> CustomResult<storage::UserRole, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl UserRoleInterface for Store {
+    async fn insert_user_role(
+        &self,
+        user_role: storage::UserRoleNew,
+    ) -> CustomResult<storage::UserRole, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        user_role
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<storage::UserRole, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::UserRole::find_by_user_id_merchant_id(&conn, user_id, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_user_roles_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::UserRole::list_by_user_id(&conn, user_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+        user_role: storage::UserRoleUpdate,
+    ) -> CustomResult<storage::UserRole, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRole::update_by_user_id_merchant_id(&conn, user_id, merchant_id, user_role)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl UserRoleInterface for MockDb {
+    async fn insert_user_role(
+        &self,
+        _user_role: storage::UserRoleNew,
+    ) -> CustomResult<storage::UserRole, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_user_role_by_user_id_merchant_id(
+        &self,
+        _user_id: &str,
+        _merchant_id: &str,
+    ) -> CustomResult<storage::UserRole, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_user_roles_by_user_id(
+        &self,
+        _user_id: &str,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_user_role_by_user_id_merchant_id(
+        &self,
+        _user_id: &str,
+        _merchant_id: &str,
+        _user_role: storage::UserRoleUpdate,
+    ) -> CustomResult<storage::UserRole, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
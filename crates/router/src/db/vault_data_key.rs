@@ -0,0 +1,93 @@
+use error_stack::IntoReport;
+use time::PrimitiveDateTime;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait VaultDataKeyInterface {
+    async fn insert_vault_data_key(
+        &self,
+        data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError>;
+
+    async fn find_vault_data_key_by_key_id(
+        &self,
+        key_id: &str,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError>;
+
+    /// Returns the most recently created data key, if it was created after `created_after`.
+    async fn find_latest_vault_data_key_created_after(
+        &self,
+        created_after: PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::VaultDataKey>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl VaultDataKeyInterface for Store {
+    async fn insert_vault_data_key(
+        &self,
+        data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        data_key
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_vault_data_key_by_key_id(
+        &self,
+        key_id: &str,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultDataKey::find_by_key_id(&conn, key_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_latest_vault_data_key_created_after(
+        &self,
+        created_after: PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::VaultDataKey>, errors::StorageError> {
+        // Read from the master database, so that a data key created just now is not missed
+        let conn = connection::pg_connection_write(self).await?;
+        storage::VaultDataKey::find_latest_created_after(&conn, created_after)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl VaultDataKeyInterface for MockDb {
+    async fn insert_vault_data_key(
+        &self,
+        _data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_vault_data_key_by_key_id(
+        &self,
+        _key_id: &str,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_latest_vault_data_key_created_after(
+        &self,
+        _created_after: PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::VaultDataKey>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,135 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait VaultedCardInterface {
+    async fn insert_vaulted_card(
+        &self,
+        vaulted_card: storage::VaultedCardNew,
+    ) -> CustomResult<storage::VaultedCard, errors::StorageError>;
+
+    async fn find_vaulted_card_by_merchant_id_payment_token(
+        &self,
+        merchant_id: &str,
+        payment_token: &str,
+    ) -> CustomResult<storage::VaultedCard, errors::StorageError>;
+
+    async fn delete_vaulted_card_by_merchant_id_payment_token(
+        &self,
+        merchant_id: &str,
+        payment_token: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl VaultedCardInterface for Store {
+    async fn insert_vaulted_card(
+        &self,
+        vaulted_card: storage::VaultedCardNew,
+    ) -> CustomResult<storage::VaultedCard, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        vaulted_card
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_vaulted_card_by_merchant_id_payment_token(
+        &self,
+        merchant_id: &str,
+        payment_token: &str,
+    ) -> CustomResult<storage::VaultedCard, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultedCard::find_by_merchant_id_payment_token(&conn, merchant_id, payment_token)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_vaulted_card_by_merchant_id_payment_token(
+        &self,
+        merchant_id: &str,
+        payment_token: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::VaultedCard::delete_by_merchant_id_payment_token(&conn, merchant_id, payment_token)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl VaultedCardInterface for MockDb {
+    async fn insert_vaulted_card(
+        &self,
+        vaulted_card: storage::VaultedCardNew,
+    ) -> CustomResult<storage::VaultedCard, errors::StorageError> {
+        let mut vaulted_cards = self.vaulted_cards.lock().await;
+        let vaulted_card = storage::VaultedCard {
+            #[allow(clippy::as_conversions)]
+            id: vaulted_cards.len() as i32,
+            payment_token: vaulted_card.payment_token,
+            merchant_id: vaulted_card.merchant_id,
+            customer_id: vaulted_card.customer_id,
+            key_id: vaulted_card.key_id,
+            encrypted_card: vaulted_card.encrypted_card,
+            card_last_four: vaulted_card.card_last_four,
+            card_exp_month: vaulted_card.card_exp_month,
+            card_exp_year: vaulted_card.card_exp_year,
+            created_at: vaulted_card.created_at,
+            token_service_provider: vaulted_card.token_service_provider,
+            encrypted_network_token: vaulted_card.encrypted_network_token,
+        };
+        vaulted_cards.push(vaulted_card.clone());
+        Ok(vaulted_card)
+    }
+
+    async fn find_vaulted_card_by_merchant_id_payment_token(
+        &self,
+        merchant_id: &str,
+        payment_token: &str,
+    ) -> CustomResult<storage::VaultedCard, errors::StorageError> {
+        self.vaulted_cards
+            .lock()
+            .await
+            .iter()
+            .find(|vaulted_card| {
+                vaulted_card.merchant_id == merchant_id
+                    && vaulted_card.payment_token == payment_token
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::DatabaseError(error_stack::report!(
+                    storage_models::errors::DatabaseError::NotFound
+                ))
+                .into(),
+            )
+    }
+
+    async fn delete_vaulted_card_by_merchant_id_payment_token(
+        &self,
+        merchant_id: &str,
+        payment_token: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut vaulted_cards = self.vaulted_cards.lock().await;
+        let position = vaulted_cards
+            .iter()
+            .position(|vaulted_card| {
+                vaulted_card.merchant_id == merchant_id
+                    && vaulted_card.payment_token == payment_token
+            })
+            .ok_or(errors::StorageError::DatabaseError(error_stack::report!(
+                storage_models::errors::DatabaseError::NotFound
+            )))?;
+        vaulted_cards.remove(position);
+        Ok(true)
+    }
+}
@@ -45,12 +45,17 @@ static ALLOC: mimalloc::MiMalloc = mimalloc::MiMalloc;
 pub mod headers {
     pub const ACCEPT: &str = "Accept";
     pub const API_KEY: &str = "API-KEY";
+    pub const APIKEY: &str = "APIKey";
     pub const AUTHORIZATION: &str = "Authorization";
+    pub const BRAINTREE_VERSION: &str = "Braintree-Version";
     pub const CONTENT_TYPE: &str = "Content-Type";
     pub const DATE: &str = "Date";
+    pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
     pub const TIMESTAMP: &str = "Timestamp";
     pub const X_API_KEY: &str = "X-API-KEY";
     pub const X_API_VERSION: &str = "X-ApiVersion";
+    pub const X_CC_API_KEY: &str = "X-CC-Api-Key";
+    pub const X_CC_VERSION: &str = "X-CC-Version";
     pub const X_MERCHANT_ID: &str = "X-Merchant-Id";
     pub const X_LOGIN: &str = "X-Login";
     pub const X_TRANS_KEY: &str = "X-Trans-Key";
@@ -61,7 +66,7 @@ pub mod headers {
 pub mod pii {
     //! Personal Identifiable Information protection.
 
-    pub(crate) use common_utils::pii::{CardNumber, Email};
+    pub(crate) use common_utils::pii::{CardNumber, Email, UpiVpaMaskingStrategy};
     #[doc(inline)]
     pub use masking::*;
 }
@@ -78,7 +83,7 @@ pub fn mk_app(
         InitError = (),
     >,
 > {
-    let mut server_app = get_application_builder(request_body_limit);
+    let mut server_app = get_application_builder(request_body_limit, state.clone());
 
     #[cfg(feature = "openapi")]
     {
@@ -96,7 +101,6 @@ pub fn mk_app(
             .service(routes::Customers::server(state.clone()))
             .service(routes::Configs::server(state.clone()))
             .service(routes::Refunds::server(state.clone()))
-            .service(routes::Payouts::server(state.clone()))
             .service(routes::MerchantConnectorAccount::server(state.clone()))
             .service(routes::Mandates::server(state.clone()));
     }
@@ -106,6 +110,10 @@ pub fn mk_app(
         server_app = server_app
             .service(routes::PaymentMethods::server(state.clone()))
             .service(routes::EphemeralKey::server(state.clone()))
+            .service(routes::CardVault::server(state.clone()))
+            .service(routes::PaymentSchedules::server(state.clone()))
+            .service(routes::PaymentLinks::server(state.clone()))
+            .service(routes::Payouts::server(state.clone()))
             .service(routes::Webhooks::server(state.clone()));
     }
 
@@ -113,13 +121,25 @@ pub fn mk_app(
     {
         server_app = server_app
             .service(routes::MerchantAccount::server(state.clone()))
-            .service(routes::ApiKeys::server(state.clone()));
+            .service(routes::ApiKeys::server(state.clone()))
+            .service(routes::Organizations::server(state.clone()))
+            .service(routes::Balance::server(state.clone()))
+            .service(routes::BalanceTransactions::server(state.clone()))
+            .service(routes::Recon::server(state.clone()))
+            .service(routes::DeadLetterTasks::server(state.clone()))
+            .service(routes::Routing::server(state.clone()))
+            .service(routes::Secrets::server(state.clone()))
+            .service(routes::Users::server(state.clone()));
     }
 
     #[cfg(feature = "stripe")]
     {
         server_app = server_app.service(routes::StripeApis::server(state.clone()));
     }
+    #[cfg(feature = "dummy_connector")]
+    {
+        server_app = server_app.service(routes::DummyConnector::server(state.clone()));
+    }
     server_app = server_app.service(routes::Cards::server(state.clone()));
     server_app = server_app.service(routes::Health::server(state));
     server_app
@@ -149,6 +169,7 @@ pub async fn start_server(conf: settings::Settings) -> ApplicationResult<(Server
 
 pub fn get_application_builder(
     request_body_limit: usize,
+    state: AppState,
 ) -> actix_web::App<
     impl ServiceFactory<
         ServiceRequest,
@@ -162,6 +183,7 @@ pub fn get_application_builder(
         .limit(request_body_limit)
         .content_type_required(true)
         .error_handler(utils::error_parser::custom_json_error_handler);
+    let request_log = state.conf.request_log.clone();
 
     actix_web::App::new()
         .app_data(json_cfg)
@@ -173,8 +195,10 @@ pub fn get_application_builder(
             StatusCode::METHOD_NOT_ALLOWED,
             errors::error_handlers::custom_error_handlers,
         ))
+        .wrap(middleware::RateLimiter::new(state))
         .wrap(middleware::default_response_headers())
         .wrap(cors::cors())
         .wrap(middleware::RequestId)
+        .wrap(middleware::RequestLogger::new(request_log))
         .wrap(router_env::tracing_actix_web::TracingLogger::default())
 }
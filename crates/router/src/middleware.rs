@@ -70,3 +70,327 @@ pub fn default_response_headers() -> actix_web::middleware::DefaultHeaders {
         .add((header::STRICT_TRANSPORT_SECURITY, "max-age=31536000"))
         .add((header::VIA, "HyperSwitch"))
 }
+
+/// Middleware which logs every request to the API and its response, at the verbosity configured
+/// for the route of the request. Request and response bodies are logged with their personal and
+/// card data masked.
+pub struct RequestLogger {
+    config: std::rc::Rc<crate::configs::settings::RequestLogConfig>,
+}
+
+impl RequestLogger {
+    pub fn new(config: crate::configs::settings::RequestLogConfig) -> Self {
+        Self {
+            config: std::rc::Rc::new(config),
+        }
+    }
+}
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for RequestLogger
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: actix_web::body::MessageBody + 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::BoxBody>;
+    type Error = actix_web::Error;
+    type Transform = RequestLoggerMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(RequestLoggerMiddleware {
+            service: std::rc::Rc::new(service),
+            config: self.config.clone(),
+        }))
+    }
+}
+
+pub struct RequestLoggerMiddleware<S> {
+    service: std::rc::Rc<S>,
+    config: std::rc::Rc<crate::configs::settings::RequestLogConfig>,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for RequestLoggerMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: actix_web::body::MessageBody + 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::BoxBody>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        use futures::StreamExt;
+
+        use crate::{configs::settings::LogVerbosity, services::logger};
+
+        let service = self.service.clone();
+        let verbosity = self.config.get_route_verbosity(req.path());
+
+        Box::pin(async move {
+            if verbosity == LogVerbosity::Off {
+                return Ok(service.call(req).await?.map_into_boxed_body());
+            }
+
+            let mut req = req;
+            let start_instant = std::time::Instant::now();
+            let method = req.method().to_string();
+            let path = req.path().to_string();
+
+            let request_body = if verbosity == LogVerbosity::Full {
+                let mut payload = req.take_payload();
+                let mut body = actix_web::web::BytesMut::new();
+                while let Some(chunk) = payload.next().await {
+                    body.extend_from_slice(&chunk?);
+                }
+                let body = body.freeze();
+                let request_body = logger::mask_body(&body);
+                req.set_payload(actix_web::dev::Payload::from(body));
+                Some(request_body)
+            } else {
+                None
+            };
+
+            let response = service.call(req).await?;
+            let status_code = response.status().as_u16();
+
+            let (response, response_body) = if verbosity == LogVerbosity::Full {
+                let (http_req, http_res) = response.into_parts();
+                let (http_res, body) = http_res.into_parts();
+                let body = actix_web::body::to_bytes(body).await.map_err(|error| {
+                    let error: Box<dyn std::error::Error> = error.into();
+                    actix_web::error::ErrorInternalServerError(error.to_string())
+                })?;
+                let response_body = logger::mask_body(&body);
+                let http_res = http_res.set_body(actix_web::body::BoxBody::new(body));
+                (
+                    actix_web::dev::ServiceResponse::new(http_req, http_res),
+                    Some(response_body),
+                )
+            } else {
+                (response.map_into_boxed_body(), None)
+            };
+
+            logger::info!(
+                tag = ?router_env::Tag::ApiIncomingRequest,
+                method = %method,
+                path = %path,
+                status_code,
+                time_taken_ms = start_instant.elapsed().as_millis(),
+                request_body = request_body.as_deref(),
+                response_body = response_body.as_deref(),
+            );
+
+            Ok(response)
+        })
+    }
+}
+
+/// Middleware which rate limits requests by their API key, or by their IP address if they are not
+/// made with an existing API key. Responses carry the state of the rate limit of the request in the
+/// `X-RateLimit-*` headers, and rate limited requests are rejected with a `429` response.
+pub struct RateLimiter {
+    state: std::rc::Rc<crate::routes::AppState>,
+}
+
+impl RateLimiter {
+    pub fn new(state: crate::routes::AppState) -> Self {
+        Self {
+            state: std::rc::Rc::new(state),
+        }
+    }
+}
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for RateLimiter
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Transform = RateLimiterMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(RateLimiterMiddleware {
+            service: std::rc::Rc::new(service),
+            state: self.state.clone(),
+        }))
+    }
+}
+
+pub struct RateLimiterMiddleware<S> {
+    service: std::rc::Rc<S>,
+    state: std::rc::Rc<crate::routes::AppState>,
+}
+
+/// Health checks and metrics are scraped by the infrastructure, and are not rate limited.
+fn is_rate_limited_path(path: &str) -> bool {
+    !(path.starts_with("/health") || path == "/metrics")
+}
+
+/// The IP address of the client is the address of the connection the request was made from. The
+/// `X-Forwarded-For` header can be set by clients, and is only used for the connections of trusted
+/// proxies.
+fn get_client_ip(
+    req: &actix_web::dev::ServiceRequest,
+    trusted_proxies: &[std::net::IpAddr],
+) -> String {
+    let peer_ip = match req.peer_addr() {
+        Some(peer_addr) => peer_addr.ip(),
+        None => return String::new(),
+    };
+
+    if !trusted_proxies.contains(&peer_ip) {
+        return peer_ip.to_string();
+    }
+
+    let forwarded_for = req
+        .headers()
+        .get_all(http::header::HeaderName::from_static("x-forwarded-for"))
+        .filter_map(|value| value.to_str().ok())
+        .collect::<Vec<_>>();
+    get_forwarded_client_ip(&forwarded_for, trusted_proxies)
+        .unwrap_or(peer_ip)
+        .to_string()
+}
+
+/// Every proxy appends the address it received the request from to the `X-Forwarded-For` header, so
+/// the client is the last address which is not one of the trusted proxies. The addresses before it
+/// can be set by the client.
+fn get_forwarded_client_ip(
+    forwarded_for: &[&str],
+    trusted_proxies: &[std::net::IpAddr],
+) -> Option<std::net::IpAddr> {
+    forwarded_for
+        .iter()
+        .flat_map(|value| value.split(','))
+        .map(|addr| addr.trim().parse::<std::net::IpAddr>().ok())
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .find(|ip| ip.map_or(true, |ip| !trusted_proxies.contains(&ip)))
+        .flatten()
+}
+
+fn insert_rate_limit_headers(
+    headers: &mut actix_web::http::header::HeaderMap,
+    status: &crate::core::rate_limit::RateLimitStatus,
+) {
+    headers.insert(
+        http::header::HeaderName::from_static("x-ratelimit-limit"),
+        http::HeaderValue::from(status.limit),
+    );
+    headers.insert(
+        http::header::HeaderName::from_static("x-ratelimit-remaining"),
+        http::HeaderValue::from(status.remaining),
+    );
+    headers.insert(
+        http::header::HeaderName::from_static("x-ratelimit-reset"),
+        http::HeaderValue::from(status.reset_after),
+    );
+    if let Some(retry_after) = status.retry_after {
+        headers.insert(
+            http::header::RETRY_AFTER,
+            http::HeaderValue::from(retry_after),
+        );
+    }
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for RateLimiterMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        use common_utils::errors::ErrorSwitch;
+
+        use crate::{
+            core::{errors, rate_limit},
+            services::authentication,
+        };
+
+        let service = self.service.clone();
+        let state = self.state.clone();
+
+        Box::pin(async move {
+            if !state.conf.rate_limit.enabled || !is_rate_limited_path(req.path()) {
+                return Ok(service.call(req).await?.map_into_left_body());
+            }
+
+            let api_key = authentication::get_api_key(req.headers())
+                .ok()
+                .map(ToOwned::to_owned);
+            let client_ip = get_client_ip(&req, &state.conf.rate_limit.trusted_proxies);
+            let status = rate_limit::check_rate_limit(&state, api_key.as_deref(), &client_ip).await;
+
+            if let Some(status) = status.filter(rate_limit::RateLimitStatus::is_limited) {
+                let mut response =
+                    req.error_response(errors::ApiErrorResponse::RateLimitExceeded.switch());
+                insert_rate_limit_headers(response.headers_mut(), &status);
+                return Ok(response.map_into_right_body());
+            }
+
+            let mut response = service.call(req).await?;
+            if let Some(status) = status {
+                insert_rate_limit_headers(response.headers_mut(), &status);
+            }
+            Ok(response.map_into_left_body())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_forwarded_client_ip_skips_trusted_proxies_only() {
+        let trusted_proxies: [std::net::IpAddr; 2] =
+            ["10.0.0.1".parse().unwrap(), "10.0.0.2".parse().unwrap()];
+
+        let client_ip = get_forwarded_client_ip(&["1.2.3.4, 5.6.7.8, 10.0.0.2"], &trusted_proxies);
+        assert_eq!(client_ip, Some("5.6.7.8".parse().unwrap()));
+
+        let client_ip =
+            get_forwarded_client_ip(&["1.2.3.4", "5.6.7.8, 10.0.0.1"], &trusted_proxies);
+        assert_eq!(client_ip, Some("5.6.7.8".parse().unwrap()));
+
+        let client_ip = get_forwarded_client_ip(&["10.0.0.1"], &trusted_proxies);
+        assert_eq!(client_ip, None);
+
+        // An invalid address appended by a trusted proxy is not skipped over
+        let client_ip = get_forwarded_client_ip(&["1.2.3.4, unknown, 10.0.0.1"], &trusted_proxies);
+        assert_eq!(client_ip, None);
+    }
+}
@@ -57,6 +57,13 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Mandates", description = "Manage mandates"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
+        (name = "Routing", description = "Create and manage rules for routing payments to connectors"),
+        (name = "Card Vault", description = "Store cards securely and manage their tokens"),
+        (name = "Payment Schedules", description = "Charge mandates of customers at regular intervals"),
+        (name = "Payment Links", description = "Create hosted links to collect payments from customers"),
+        (name = "Payouts", description = "Pay out amounts to the cards and bank accounts of recipients"),
+        (name = "Balance", description = "Track the balances of the merchant and the transactions moving them"),
+        (name = "Recon", description = "Reconcile the settlement reports of connectors against payments"),
         // (name = "API Key", description = "Create and manage API Keys"),
     ),
     paths(
@@ -64,6 +71,7 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::refunds::refunds_retrieve,
         crate::routes::refunds::refunds_update,
         crate::routes::refunds::refunds_list,
+        crate::routes::refunds::refunds_list_by_payment_id,
         crate::routes::admin::merchant_account_create,
         crate::routes::admin::retrieve_merchant_account,
         crate::routes::admin::update_merchant_account,
@@ -73,8 +81,11 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::admin::payment_connector_list,
         crate::routes::admin::payment_connector_update,
         crate::routes::admin::payment_connector_delete,
+        crate::routes::admin::payment_connector_toggle,
+        crate::routes::secrets::connector_credentials_key_rotate,
         crate::routes::mandates::get_mandate,
         crate::routes::mandates::revoke_mandate,
+        crate::routes::mandates::retrieve_mandates_list,
         crate::routes::payments::payments_create,
        // crate::routes::payments::payments_start,
         crate::routes::payments::payments_retrieve,
@@ -84,22 +95,75 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::payments::payments_connector_session,
        // crate::routes::payments::payments_redirect_response,
         crate::routes::payments::payments_cancel,
+        crate::routes::payments::payments_approve,
+        crate::routes::payments::payments_reject,
+        crate::routes::payments::payments_complete_authorize_continue,
+        crate::routes::payments::payments_three_ds_authenticate,
         crate::routes::payments::payments_list,
+        crate::routes::payments::payments_attempts_list,
+        crate::routes::payments::payments_events_list,
+        crate::routes::payments::payments_connector_logs_list,
         crate::routes::payment_methods::create_payment_method_api,
         crate::routes::payment_methods::list_payment_method_api,
         crate::routes::payment_methods::list_customer_payment_method_api,
+        crate::routes::payment_methods::create_customer_payment_method_api,
         crate::routes::payment_methods::payment_method_retrieve_api,
         crate::routes::payment_methods::payment_method_update_api,
         crate::routes::payment_methods::payment_method_delete_api,
         crate::routes::customers::customers_create,
         crate::routes::customers::customers_retrieve,
+        crate::routes::customers::customers_list,
         crate::routes::customers::customers_update,
         crate::routes::customers::customers_delete,
+        crate::routes::customers::customers_redact,
+        crate::routes::routing::routing_config_create,
+        crate::routes::routing::routing_config_retrieve,
+        crate::routes::routing::routing_config_list,
+        crate::routes::routing::routing_config_activate,
+        crate::routes::routing::routing_config_evaluate,
+        crate::routes::card_vault::vault_token_create,
+        crate::routes::card_vault::vault_token_retrieve,
+        crate::routes::card_vault::vault_token_delete,
+        crate::routes::payment_schedules::payment_schedule_create,
+        crate::routes::payment_schedules::payment_schedule_retrieve,
+        crate::routes::payment_schedules::payment_schedule_cancel,
+        crate::routes::payment_links::payment_link_create,
+        crate::routes::payment_links::payment_link_retrieve,
+        crate::routes::payouts::payouts_create,
+        crate::routes::payouts::payouts_retrieve,
+        crate::routes::payouts::payouts_cancel,
+        crate::routes::balance::balance_retrieve,
+        crate::routes::balance::balance_transaction_list,
+        crate::routes::recon::settlement_report_ingest,
+        crate::routes::recon::settlement_report_retrieve,
+        crate::routes::recon::payment_recon_retrieve,
         // crate::routes::api_keys::api_key_create,
         // crate::routes::api_keys::api_key_retrieve,
         // crate::routes::api_keys::api_key_update,
         // crate::routes::api_keys::api_key_revoke,
+        // crate::routes::api_keys::api_key_rotate,
         // crate::routes::api_keys::api_key_list,
+        // crate::routes::user::user_signup,
+        // crate::routes::user::user_signin,
+        // crate::routes::user::user_refresh_token,
+        // crate::routes::user::user_role_set,
+        // crate::routes::user::user_role_set_for_merchant,
+        // crate::routes::organization::organization_create,
+        // crate::routes::organization::organization_retrieve,
+        // crate::routes::organization::organization_merchant_account_list,
+        // crate::routes::organization::organization_api_key_create,
+        // crate::routes::organization::organization_api_key_list,
+        // crate::routes::organization::organization_api_key_revoke,
+        // crate::routes::organization::organization_payments_aggregate,
+        // crate::routes::business_profile::business_profile_create,
+        // crate::routes::business_profile::business_profile_list,
+        // crate::routes::business_profile::business_profile_retrieve,
+        // crate::routes::business_profile::business_profile_update,
+        // crate::routes::business_profile::business_profile_delete,
+        // crate::routes::dead_letter::dead_letter_task_list,
+        // crate::routes::dead_letter::dead_letter_task_retrieve,
+        // crate::routes::dead_letter::dead_letter_task_replay,
+        // crate::routes::dead_letter::dead_letter_task_bulk_replay,
     ),
     components(schemas(
         crate::types::api::refunds::RefundRequest,
@@ -111,18 +175,24 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::admin::MerchantAccountUpdate,
         crate::types::api::admin::MerchantAccountDeleteResponse,
         crate::types::api::admin::MerchantConnectorDeleteResponse,
+        crate::types::api::admin::ToggleMerchantConnectorRequest,
+        crate::types::api::admin::ConnectorCredentialsKeyRotateResponse,
         crate::types::api::customers::CustomerRequest,
         crate::types::api::customers::CustomerDeleteResponse,
+        crate::types::api::customers::CustomerListConstraints,
+        crate::types::api::customers::CustomerRedactResponse,
         crate::types::api::payment_methods::PaymentMethodCreate,
         crate::types::api::payment_methods::PaymentMethodResponse,
         crate::types::api::payment_methods::PaymentMethodList,
         crate::types::api::payment_methods::CustomerPaymentMethod,
+        crate::types::api::payment_methods::CustomerPaymentMethodCreate,
         crate::types::api::payment_methods::PaymentMethodListResponse,
         crate::types::api::payment_methods::CustomerPaymentMethodsListResponse,
         crate::types::api::payment_methods::PaymentMethodDeleteResponse,
         crate::types::api::payment_methods::PaymentMethodUpdate,
         crate::types::api::payment_methods::CardDetailFromLocker,
         crate::types::api::payment_methods::CardDetail,
+        crate::types::api::payment_methods::RequiredFieldInfo,
         api_models::customers::CustomerResponse,
         api_models::admin::AcceptedCountries,
         api_models::admin::AcceptedCurrencies,
@@ -135,12 +205,33 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::CaptureMethod,
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
+        api_models::enums::ThreeDsTransStatus,
+        api_models::enums::ThreeDsDeviceChannel,
         api_models::enums::Connector,
         api_models::enums::PaymentMethod,
         api_models::enums::SupportedWallets,
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::MandateStatus,
+        api_models::enums::PaymentScheduleInterval,
+        api_models::enums::PaymentScheduleStatus,
+        api_models::enums::PaymentLinkStatus,
+        api_models::enums::PayoutStatus,
+        api_models::enums::PayoutType,
+        api_models::enums::FundingSourceStatus,
+        api_models::enums::SplitStatus,
+        api_models::enums::BalanceTransactionType,
+        api_models::enums::PaymentEventSource,
+        api_models::enums::LedgerAccount,
+        api_models::enums::ReconStatus,
+        api_models::enums::PaymentReconStatus,
+        api_models::enums::UnifiedCode,
+        api_models::enums::FieldType,
+        api_models::enums::AvsResult,
+        api_models::enums::CvcResult,
+        api_models::enums::FraudCheckVerdict,
+        api_models::enums::AttemptStatus,
         api_models::enums::PaymentExperience,
+        api_models::enums::BankDebitVerificationMethod,
         api_models::enums::BankNames,
         api_models::enums::CardNetwork,
         api_models::enums::CountryCode,
@@ -150,7 +241,21 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::Address,
         api_models::payments::BankRedirectData,
         api_models::payments::BankRedirectBilling,
+        api_models::payments::BankDebitData,
+        api_models::payments::BankDebitBilling,
+        api_models::payments::UpiData,
+        api_models::payments::QrCodeInformation,
+        api_models::payments::CryptoData,
+        api_models::payments::GiftCardData,
+        api_models::payments::GiftCardDetails,
+        api_models::payments::FundingSourceResponse,
+        api_models::payments::PaymentSplit,
+        api_models::payments::SplitAmount,
+        api_models::payments::PaymentSplitResponse,
         api_models::payments::OrderDetails,
+        api_models::payments::AmountBreakdown,
+        api_models::payments::L2L3Data,
+        api_models::payments::L3LineItem,
         api_models::payments::NextActionType,
         api_models::payments::Metadata,
         api_models::payments::WalletData,
@@ -167,6 +272,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::CustomerAcceptance,
         api_models::payments::PaymentsRequest,
         api_models::payments::PaymentsResponse,
+        api_models::payments::SurchargeDetailsResponse,
+        api_models::payments::SettlementDetailsResponse,
         api_models::payments::PaymentsStartRequest,
         api_models::payments::PaymentRetrieveBody,
         api_models::payments::PaymentsRetrieveRequest,
@@ -196,23 +303,70 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::ApplePayWalletData,
         api_models::payments::ApplepayPaymentMethod,
         api_models::payments::PaymentsCancelRequest,
+        api_models::payments::PaymentsThreeDsAuthenticateRequest,
         api_models::payments::PaymentListConstraints,
         api_models::payments::PaymentListResponse,
+        api_models::payments::PaymentAttemptResponse,
+        api_models::payments::PaymentAttemptListResponse,
+        api_models::payments::PaymentEventResponse,
+        api_models::payments::PaymentEventListResponse,
+        api_models::payments::ConnectorLogResponse,
+        api_models::payments::ConnectorLogListResponse,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
+        api_models::mandates::MandateListConstraints,
         crate::types::api::admin::MerchantAccountResponse,
         crate::types::api::admin::MerchantConnectorId,
         crate::types::api::admin::MerchantDetails,
         crate::types::api::admin::WebhookDetails,
         crate::types::api::api_keys::ApiKeyExpiration,
+        crate::types::api::api_keys::ApiKeyScope,
         crate::types::api::api_keys::CreateApiKeyRequest,
         crate::types::api::api_keys::CreateApiKeyResponse,
         crate::types::api::api_keys::RetrieveApiKeyResponse,
         crate::types::api::api_keys::RevokeApiKeyResponse,
-        crate::types::api::api_keys::UpdateApiKeyRequest
+        crate::types::api::api_keys::UpdateApiKeyRequest,
+        api_models::routing::RoutingConfigRequest,
+        api_models::routing::RoutingConfigResponse,
+        api_models::routing::RoutingRules,
+        api_models::routing::RoutingRule,
+        api_models::routing::RoutingCondition,
+        api_models::routing::ComparisonOperator,
+        api_models::routing::MembershipOperator,
+        api_models::routing::RoutingEvaluateRequest,
+        api_models::routing::RoutingEvaluateResponse,
+        api_models::vault::VaultTokenCreateRequest,
+        api_models::vault::VaultTokenResponse,
+        api_models::vault::VaultTokenDeleteResponse,
+        api_models::payment_schedules::PaymentScheduleCreateRequest,
+        api_models::payment_schedules::PaymentScheduleResponse,
+        api_models::payment_links::PaymentLinkCreateRequest,
+        api_models::payment_links::PaymentLinkResponse,
+        api_models::payouts::PayoutCreateRequest,
+        api_models::payouts::PayoutResponse,
+        api_models::payouts::PayoutMethodData,
+        api_models::payouts::CardPayout,
+        api_models::payouts::BankPayout,
+        api_models::payouts::AchBankTransfer,
+        api_models::payouts::SepaBankTransfer,
+        api_models::payouts::BacsBankTransfer,
+        api_models::balance::BalanceResponse,
+        api_models::balance::CurrencyBalance,
+        api_models::balance::BalanceTransactionResponse,
+        api_models::balance::BalanceTransactionListResponse,
+        api_models::recon::SettlementReportRequest,
+        api_models::recon::SettlementReportData,
+        api_models::recon::SettlementReportLine,
+        api_models::recon::SettlementReportResponse,
+        api_models::recon::SettlementLineResponse,
+        api_models::recon::PaymentReconResponse,
+        api_models::dead_letter::DeadLetterTaskResponse,
+        api_models::dead_letter::DeadLetterTaskListResponse,
+        api_models::dead_letter::DeadLetterTaskReplayRequest,
+        api_models::dead_letter::DeadLetterTaskReplayResponse
     )),
     modifiers(&SecurityAddon)
 )]
@@ -1,22 +1,37 @@
 pub mod admin;
 pub mod api_keys;
 pub mod app;
+pub mod balance;
+pub mod business_profile;
+pub mod card_vault;
 pub mod cards_info;
 pub mod configs;
 pub mod customers;
+pub mod dead_letter;
+#[cfg(feature = "dummy_connector")]
+pub mod dummy_connector;
 pub mod ephemeral_key;
 pub mod health;
 pub mod mandates;
 pub mod metrics;
+pub mod organization;
+pub mod payment_links;
 pub mod payment_methods;
+pub mod payment_schedules;
 pub mod payments;
 pub mod payouts;
+pub mod recon;
 pub mod refunds;
+pub mod routing;
+pub mod secrets;
+pub mod user;
 pub mod webhooks;
 
 pub use self::app::{
-    ApiKeys, AppState, Cards, Configs, Customers, EphemeralKey, Health, Mandates, MerchantAccount,
-    MerchantConnectorAccount, PaymentMethods, Payments, Payouts, Refunds, Webhooks,
+    ApiKeys, AppState, Balance, BalanceTransactions, CardVault, Cards, Configs, Customers,
+    DeadLetterTasks, DummyConnector, EphemeralKey, Health, Mandates, MerchantAccount,
+    MerchantConnectorAccount, Organizations, PaymentLinks, PaymentMethods, PaymentSchedules,
+    Payments, Payouts, Recon, Refunds, Routing, Secrets, Users, Webhooks,
 };
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 use super::app::AppState;
 use crate::{
     core::admin::*,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::Permission},
     types::api::admin,
 };
 
@@ -63,8 +63,9 @@ pub async fn retrieve_merchant_account(
     mid: web::Path<String>,
 ) -> HttpResponse {
     let flow = Flow::MerchantsAccountRetrieve;
+    let merchant_id = mid.into_inner();
     let payload = web::Json(admin::MerchantId {
-        merchant_id: mid.into_inner(),
+        merchant_id: merchant_id.clone(),
     })
     .into_inner();
     api::server_wrap(
@@ -73,7 +74,11 @@ pub async fn retrieve_merchant_account(
         &req,
         payload,
         |state, _, req| get_merchant_account(&*state.store, req),
-        &auth::AdminApiAuth,
+        &*auth::admin_or_dashboard_auth(
+            req.headers(),
+            &merchant_id,
+            Permission::MerchantAccountRead,
+        ),
     )
     .await
 }
@@ -109,7 +114,11 @@ pub async fn update_merchant_account(
         &req,
         json_payload.into_inner(),
         |state, _, req| merchant_account_update(&*state.store, &merchant_id, req),
-        &auth::AdminApiAuth,
+        &*auth::admin_or_dashboard_auth(
+            req.headers(),
+            &merchant_id,
+            Permission::MerchantAccountWrite,
+        ),
     )
     .await
 }
@@ -181,8 +190,12 @@ pub async fn payment_connector_create(
         state.get_ref(),
         &req,
         json_payload.into_inner(),
-        |state, _, req| create_payment_connector(&*state.store, req, &merchant_id),
-        &auth::AdminApiAuth,
+        |state, _, req| create_payment_connector(state, req, &merchant_id),
+        &*auth::admin_or_dashboard_auth(
+            req.headers(),
+            &merchant_id,
+            Permission::MerchantConnectorAccountWrite,
+        ),
     )
     .await
 }
@@ -214,6 +227,11 @@ pub async fn payment_connector_retrieve(
 ) -> HttpResponse {
     let flow = Flow::MerchantConnectorsRetrieve;
     let (merchant_id, merchant_connector_id) = path.into_inner();
+    let auth_type = auth::admin_or_dashboard_auth(
+        req.headers(),
+        &merchant_id,
+        Permission::MerchantConnectorAccountRead,
+    );
     let payload = web::Json(admin::MerchantConnectorId {
         merchant_id,
         merchant_connector_id,
@@ -225,9 +243,9 @@ pub async fn payment_connector_retrieve(
         &req,
         payload,
         |state, _, req| {
-            retrieve_payment_connector(&*state.store, req.merchant_id, req.merchant_connector_id)
+            retrieve_payment_connector(state, req.merchant_id, req.merchant_connector_id)
         },
-        &auth::AdminApiAuth,
+        &*auth_type,
     )
     .await
 }
@@ -258,13 +276,18 @@ pub async fn payment_connector_list(
 ) -> HttpResponse {
     let flow = Flow::MerchantConnectorsList;
     let merchant_id = path.into_inner();
+    let auth_type = auth::admin_or_dashboard_auth(
+        req.headers(),
+        &merchant_id,
+        Permission::MerchantConnectorAccountRead,
+    );
     api::server_wrap(
         flow,
         state.get_ref(),
         &req,
         merchant_id,
-        |state, _, merchant_id| list_payment_connectors(&*state.store, merchant_id),
-        &auth::AdminApiAuth,
+        |state, _, merchant_id| list_payment_connectors(state, merchant_id),
+        &*auth_type,
     )
     .await
 }
@@ -303,10 +326,12 @@ pub async fn payment_connector_update(
         state.get_ref(),
         &req,
         json_payload.into_inner(),
-        |state, _, req| {
-            update_payment_connector(&*state.store, &merchant_id, &merchant_connector_id, req)
-        },
-        &auth::AdminApiAuth,
+        |state, _, req| update_payment_connector(state, &merchant_id, &merchant_connector_id, req),
+        &*auth::admin_or_dashboard_auth(
+            req.headers(),
+            &merchant_id,
+            Permission::MerchantConnectorAccountWrite,
+        ),
     )
     .await
 }
@@ -338,6 +363,11 @@ pub async fn payment_connector_delete(
 ) -> HttpResponse {
     let flow = Flow::MerchantConnectorsDelete;
     let (merchant_id, merchant_connector_id) = path.into_inner();
+    let auth_type = auth::admin_or_dashboard_auth(
+        req.headers(),
+        &merchant_id,
+        Permission::MerchantConnectorAccountWrite,
+    );
     let payload = web::Json(admin::MerchantConnectorId {
         merchant_id,
         merchant_connector_id,
@@ -351,7 +381,58 @@ pub async fn payment_connector_delete(
         |state, _, req| {
             delete_payment_connector(&*state.store, req.merchant_id, req.merchant_connector_id)
         },
-        &auth::AdminApiAuth,
+        &*auth_type,
+    )
+    .await
+}
+
+/// Merchant Connector - Toggle
+///
+/// Enable or disable a Merchant Connector. Payments are not routed through a disabled connector.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/{connector_id}/toggle",
+    request_body = ToggleMerchantConnectorRequest,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = i32, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Merchant Connector Toggled", body = MerchantConnector),
+        (status = 404, description = "Merchant Connector does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Toggle a Merchant Connector",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsToggle))]
+pub async fn payment_connector_toggle(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<admin::ToggleMerchantConnectorRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsToggle;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let auth_type = auth::admin_or_dashboard_auth(
+        req.headers(),
+        &merchant_id,
+        Permission::MerchantConnectorAccountWrite,
+    );
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (
+            merchant_id,
+            merchant_connector_id,
+            json_payload.into_inner(),
+        ),
+        |state, _, (merchant_id, merchant_connector_id, payload)| {
+            toggle_payment_connector(state, merchant_id, merchant_connector_id, payload)
+        },
+        &*auth_type,
     )
     .await
 }
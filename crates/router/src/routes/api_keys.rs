@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 use super::app::AppState;
 use crate::{
     core::api_keys,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::Permission},
     types::api as api_types,
 };
 
@@ -52,7 +52,7 @@ pub async fn api_key_create(
             )
             .await
         },
-        &auth::AdminApiAuth,
+        &*auth::admin_or_dashboard_auth(req.headers(), &merchant_id, Permission::ApiKeyWrite),
     )
     .await
 }
@@ -82,15 +82,17 @@ pub async fn api_key_retrieve(
     path: web::Path<(String, String)>,
 ) -> impl Responder {
     let flow = Flow::ApiKeyRetrieve;
-    let (_merchant_id, key_id) = path.into_inner();
+    let (merchant_id, key_id) = path.into_inner();
 
     api::server_wrap(
         flow,
         state.get_ref(),
         &req,
-        &key_id,
-        |state, _, key_id| api_keys::retrieve_api_key(&*state.store, key_id),
-        &auth::AdminApiAuth,
+        (&merchant_id, &key_id),
+        |state, _, (merchant_id, key_id)| {
+            api_keys::retrieve_api_key(&*state.store, merchant_id, key_id)
+        },
+        &*auth::admin_or_dashboard_auth(req.headers(), &merchant_id, Permission::ApiKeyRead),
     )
     .await
 }
@@ -122,16 +124,18 @@ pub async fn api_key_update(
     json_payload: web::Json<api_types::UpdateApiKeyRequest>,
 ) -> impl Responder {
     let flow = Flow::ApiKeyUpdate;
-    let (_merchant_id, key_id) = path.into_inner();
+    let (merchant_id, key_id) = path.into_inner();
     let payload = json_payload.into_inner();
 
     api::server_wrap(
         flow,
         state.get_ref(),
         &req,
-        (&key_id, payload),
-        |state, _, (key_id, payload)| api_keys::update_api_key(&*state.store, key_id, payload),
-        &auth::AdminApiAuth,
+        (&merchant_id, &key_id, payload),
+        |state, _, (merchant_id, key_id, payload)| {
+            api_keys::update_api_key(&*state.store, merchant_id, key_id, payload)
+        },
+        &*auth::admin_or_dashboard_auth(req.headers(), &merchant_id, Permission::ApiKeyWrite),
     )
     .await
 }
@@ -162,15 +166,67 @@ pub async fn api_key_revoke(
     path: web::Path<(String, String)>,
 ) -> impl Responder {
     let flow = Flow::ApiKeyRevoke;
-    let (_merchant_id, key_id) = path.into_inner();
+    let (merchant_id, key_id) = path.into_inner();
 
     api::server_wrap(
         flow,
         state.get_ref(),
         &req,
-        &key_id,
-        |state, _, key_id| api_keys::revoke_api_key(&*state.store, key_id),
-        &auth::AdminApiAuth,
+        (&merchant_id, &key_id),
+        |state, _, (merchant_id, key_id)| {
+            api_keys::revoke_api_key(&*state.store, merchant_id, key_id)
+        },
+        &*auth::admin_or_dashboard_auth(req.headers(), &merchant_id, Permission::ApiKeyWrite),
+    )
+    .await
+}
+
+/// API Key - Rotate
+///
+/// Rotate the specified API Key. A new plaintext API Key is generated for the same key ID,
+/// retaining its name, expiration and scopes, and the previous plaintext API Key can no longer be
+/// used for authenticating with our APIs. The new plaintext API Key will be displayed only once,
+/// so ensure you store it securely.
+#[utoipa::path(
+    post,
+    path = "/api_keys/{merchant_id}/{key_id}/rotate",
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("key_id" = String, Path, description = "The unique identifier for the API Key")
+    ),
+    responses(
+        (status = 200, description = "API Key rotated", body = CreateApiKeyResponse),
+        (status = 404, description = "API Key not found")
+    ),
+    tag = "API Key",
+    operation_id = "Rotate an API Key",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ApiKeyRotate))]
+pub async fn api_key_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::ApiKeyRotate;
+    let (merchant_id, key_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (&merchant_id, &key_id),
+        |state, _, (merchant_id, key_id)| {
+            api_keys::rotate_api_key(
+                &*state.store,
+                &state.conf.api_keys,
+                #[cfg(feature = "kms")]
+                &state.conf.kms,
+                merchant_id,
+                key_id,
+            )
+        },
+        &*auth::admin_or_dashboard_auth(req.headers(), &merchant_id, Permission::ApiKeyWrite),
     )
     .await
 }
@@ -210,11 +266,11 @@ pub async fn api_key_list(
         flow,
         state.get_ref(),
         &req,
-        (limit, offset, merchant_id),
+        (limit, offset, merchant_id.clone()),
         |state, _, (limit, offset, merchant_id)| async move {
             api_keys::list_api_keys(&*state.store, merchant_id, limit, offset).await
         },
-        &auth::AdminApiAuth,
+        &*auth::admin_or_dashboard_auth(req.headers(), &merchant_id, Permission::ApiKeyRead),
     )
     .await
 }
@@ -1,17 +1,26 @@
 use actix_web::{web, Scope};
 
+#[cfg(feature = "dummy_connector")]
+use super::dummy_connector::*;
 use super::health::*;
 #[cfg(feature = "olap")]
-use super::{admin::*, api_keys::*};
+use super::{
+    admin::*, api_keys::*, balance::*, business_profile::*, dead_letter::*, organization::*,
+    recon::*, routing::*, secrets::*, user::*,
+};
+#[cfg(feature = "oltp")]
+use super::{
+    card_vault::*, ephemeral_key::*, payment_links::*, payment_methods::*, payment_schedules::*,
+    webhooks::*,
+};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, mandates::*, payments::*, payouts::*, refunds::*};
-#[cfg(feature = "oltp")]
-use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
 use crate::{
     configs::settings::Settings,
     db::{MockDb, StorageImpl, StorageInterface},
     routes::cards_info::card_iin_info,
-    services::Store,
+    services::{background_tasks::BackgroundTasks, Store},
+    types::storage::enums as storage_enums,
 };
 
 #[derive(Clone)]
@@ -19,6 +28,9 @@ pub struct AppState {
     pub flow_name: String,
     pub store: Box<dyn StorageInterface>,
     pub conf: Settings,
+    /// What the changes made to payments with this state are recorded as caused by
+    pub event_source: storage_enums::PaymentEventSource,
+    pub background_tasks: BackgroundTasks,
 }
 
 pub trait AppStateInfo {
@@ -53,6 +65,8 @@ impl AppState {
             flow_name: String::from("default"),
             store,
             conf,
+            event_source: storage_enums::PaymentEventSource::Api,
+            background_tasks: BackgroundTasks::new(),
         }
     }
 
@@ -68,6 +82,8 @@ impl Health {
         web::scope("")
             .app_data(web::Data::new(state))
             .service(web::resource("/health").route(web::get().to(health)))
+            .service(web::resource("/health/ready").route(web::get().to(readiness)))
+            .service(web::resource("/metrics").route(web::get().to(prometheus_metrics)))
     }
 }
 
@@ -80,7 +96,24 @@ impl Payments {
 
         #[cfg(feature = "olap")]
         {
-            route = route.service(web::resource("/list").route(web::get().to(payments_list)));
+            route = route
+                .service(web::resource("/list").route(web::get().to(payments_list)))
+                .service(
+                    web::resource("/{payment_id}/refunds")
+                        .route(web::get().to(refunds_list_by_payment_id)),
+                )
+                .service(
+                    web::resource("/{payment_id}/attempts")
+                        .route(web::get().to(payments_attempts_list)),
+                )
+                .service(
+                    web::resource("/{payment_id}/events")
+                        .route(web::get().to(payments_events_list)),
+                )
+                .service(
+                    web::resource("/{payment_id}/connector_logs")
+                        .route(web::get().to(payments_connector_logs_list)),
+                );
         }
         #[cfg(feature = "oltp")]
         {
@@ -108,6 +141,20 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments_capture)),
                 )
+                .service(
+                    web::resource("/{payment_id}/approve").route(web::post().to(payments_approve)),
+                )
+                .service(
+                    web::resource("/{payment_id}/reject").route(web::post().to(payments_reject)),
+                )
+                .service(
+                    web::resource("/{payment_id}/complete_authorize")
+                        .route(web::post().to(payments_complete_authorize_continue)),
+                )
+                .service(
+                    web::resource("/{payment_id}/3ds/authenticate")
+                        .route(web::post().to(payments_three_ds_authenticate)),
+                )
                 .service(
                     web::resource("/start/{payment_id}/{merchant_id}/{attempt_id}")
                         .route(web::get().to(payments_start)),
@@ -150,16 +197,25 @@ impl Customers {
         #[cfg(feature = "oltp")]
         {
             route = route
-                .service(web::resource("").route(web::post().to(customers_create)))
+                .service(
+                    web::resource("")
+                        .route(web::get().to(customers_list))
+                        .route(web::post().to(customers_create)),
+                )
                 .service(
                     web::resource("/{customer_id}")
                         .route(web::get().to(customers_retrieve))
                         .route(web::post().to(customers_update))
+                        .route(web::patch().to(customers_update))
                         .route(web::delete().to(customers_delete)),
                 )
+                .service(
+                    web::resource("/{customer_id}/redact").route(web::post().to(customers_redact)),
+                )
                 .service(
                     web::resource("/{customer_id}/payment_methods")
-                        .route(web::get().to(list_customer_payment_method_api)),
+                        .route(web::get().to(list_customer_payment_method_api))
+                        .route(web::post().to(create_customer_payment_method_api)),
                 );
         }
         route
@@ -194,26 +250,14 @@ impl Refunds {
 
 pub struct Payouts;
 
-#[cfg(any(feature = "olap", feature = "oltp"))]
+#[cfg(feature = "oltp")]
 impl Payouts {
     pub fn server(state: AppState) -> Scope {
-        let mut route = web::scope("/payouts").app_data(web::Data::new(state));
-
-        #[cfg(feature = "olap")]
-        {
-            route =
-                route.service(web::resource("/accounts").route(web::get().to(payouts_accounts)));
-        }
-        #[cfg(feature = "oltp")]
-        {
-            route = route
-                .service(web::resource("/create").route(web::post().to(payouts_create)))
-                .service(web::resource("/retrieve").route(web::get().to(payouts_retrieve)))
-                .service(web::resource("/update").route(web::post().to(payouts_update)))
-                .service(web::resource("/reverse").route(web::post().to(payouts_reverse)))
-                .service(web::resource("/cancel").route(web::post().to(payouts_cancel)));
-        }
-        route
+        web::scope("/payouts")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(payouts_create)))
+            .service(web::resource("/{payout_id}").route(web::get().to(payouts_retrieve)))
+            .service(web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)))
     }
 }
 
@@ -257,6 +301,17 @@ impl MerchantAccount {
                     .route(web::post().to(update_merchant_account))
                     .route(web::delete().to(delete_merchant_account)),
             )
+            .service(
+                web::resource("/{id}/business_profile")
+                    .route(web::post().to(business_profile_create))
+                    .route(web::get().to(business_profile_list)),
+            )
+            .service(
+                web::resource("/{id}/business_profile/{profile_id}")
+                    .route(web::get().to(business_profile_retrieve))
+                    .route(web::post().to(business_profile_update))
+                    .route(web::delete().to(business_profile_delete)),
+            )
     }
 }
 
@@ -282,6 +337,10 @@ impl MerchantConnectorAccount {
                         .route(web::get().to(payment_connector_retrieve))
                         .route(web::post().to(payment_connector_update))
                         .route(web::delete().to(payment_connector_delete)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/connectors/{merchant_connector_id}/toggle")
+                        .route(web::post().to(payment_connector_toggle)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -306,6 +365,56 @@ impl EphemeralKey {
     }
 }
 
+pub struct CardVault;
+
+#[cfg(feature = "oltp")]
+impl CardVault {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/vault/tokens")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(vault_token_create)))
+            .service(
+                web::resource("/{payment_token}")
+                    .route(web::get().to(vault_token_retrieve))
+                    .route(web::delete().to(vault_token_delete)),
+            )
+    }
+}
+
+pub struct PaymentSchedules;
+
+#[cfg(feature = "oltp")]
+impl PaymentSchedules {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/payment_schedules")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(payment_schedule_create)))
+            .service(
+                web::resource("/{schedule_id}").route(web::get().to(payment_schedule_retrieve)),
+            )
+            .service(
+                web::resource("/{schedule_id}/cancel")
+                    .route(web::post().to(payment_schedule_cancel)),
+            )
+    }
+}
+
+pub struct PaymentLinks;
+
+#[cfg(feature = "oltp")]
+impl PaymentLinks {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/payment_links")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(payment_link_create)))
+            .service(web::resource("/{link_id}").route(web::get().to(payment_link_retrieve)))
+            .service(
+                web::resource("/{link_id}/checkout").route(web::get().to(payment_link_checkout)),
+            )
+            .service(web::resource("/{link_id}/pay").route(web::post().to(payment_link_pay)))
+    }
+}
+
 pub struct Mandates;
 
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -315,12 +424,15 @@ impl Mandates {
 
         #[cfg(feature = "olap")]
         {
-            route = route.service(web::resource("/{id}").route(web::get().to(get_mandate)));
+            route = route
+                .service(web::resource("").route(web::get().to(retrieve_mandates_list)))
+                .service(web::resource("/{id}").route(web::get().to(get_mandate)));
         }
         #[cfg(feature = "oltp")]
         {
-            route =
-                route.service(web::resource("/revoke/{id}").route(web::post().to(revoke_mandate)));
+            route = route
+                .service(web::resource("/{id}/revoke").route(web::post().to(revoke_mandate)))
+                .service(web::resource("/revoke/{id}").route(web::post().to(revoke_mandate)));
         }
         route
     }
@@ -377,6 +489,184 @@ impl ApiKeys {
                     .route(web::post().to(api_key_update))
                     .route(web::delete().to(api_key_revoke)),
             )
+            .service(web::resource("/{key_id}/rotate").route(web::post().to(api_key_rotate)))
+    }
+}
+
+pub struct Organizations;
+
+#[cfg(feature = "olap")]
+impl Organizations {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/organizations")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(organization_create)))
+            .service(
+                web::resource("/{organization_id}").route(web::get().to(organization_retrieve)),
+            )
+            .service(
+                web::resource("/{organization_id}/accounts")
+                    .route(web::get().to(organization_merchant_account_list)),
+            )
+            .service(
+                web::resource("/{organization_id}/api_keys")
+                    .route(web::post().to(organization_api_key_create))
+                    .route(web::get().to(organization_api_key_list)),
+            )
+            .service(
+                web::resource("/{organization_id}/api_keys/{key_id}")
+                    .route(web::delete().to(organization_api_key_revoke)),
+            )
+            .service(
+                web::resource("/{organization_id}/payments/aggregate")
+                    .route(web::get().to(organization_payments_aggregate)),
+            )
+    }
+}
+
+pub struct Balance;
+
+#[cfg(feature = "olap")]
+impl Balance {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/balance")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(balance_retrieve)))
+    }
+}
+
+pub struct BalanceTransactions;
+
+#[cfg(feature = "olap")]
+impl BalanceTransactions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/balance_transactions")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(balance_transaction_list)))
+    }
+}
+
+pub struct Recon;
+
+#[cfg(feature = "olap")]
+impl Recon {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/recon")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/settlement_reports")
+                    .route(web::post().to(settlement_report_ingest)),
+            )
+            .service(
+                web::resource("/settlement_reports/{report_id}")
+                    .route(web::get().to(settlement_report_retrieve)),
+            )
+            .service(
+                web::resource("/payments/{payment_id}")
+                    .route(web::get().to(payment_recon_retrieve)),
+            )
+    }
+}
+
+pub struct DeadLetterTasks;
+
+#[cfg(feature = "olap")]
+impl DeadLetterTasks {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/dead_letter_tasks")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(dead_letter_task_list)))
+            .service(web::resource("/replay").route(web::post().to(dead_letter_task_bulk_replay)))
+            .service(web::resource("/{task_id}").route(web::get().to(dead_letter_task_retrieve)))
+            .service(
+                web::resource("/{task_id}/replay").route(web::post().to(dead_letter_task_replay)),
+            )
+    }
+}
+
+pub struct Users;
+
+#[cfg(feature = "olap")]
+impl Users {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/user")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/signup").route(web::post().to(user_signup)))
+            .service(web::resource("/signin").route(web::post().to(user_signin)))
+            .service(web::resource("/refresh_token").route(web::post().to(user_refresh_token)))
+            .service(web::resource("/role").route(web::post().to(user_role_set)))
+            .service(
+                web::resource("/{merchant_id}/role")
+                    .route(web::post().to(user_role_set_for_merchant)),
+            )
+    }
+}
+
+pub struct Routing;
+
+#[cfg(feature = "olap")]
+impl Routing {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/routing")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(routing_config_create)))
+            .service(web::resource("/list").route(web::get().to(routing_config_list)))
+            .service(
+                web::resource("/{routing_config_id}").route(web::get().to(routing_config_retrieve)),
+            )
+            .service(
+                web::resource("/{routing_config_id}/activate")
+                    .route(web::post().to(routing_config_activate)),
+            )
+            .service(
+                web::resource("/{routing_config_id}/evaluate")
+                    .route(web::post().to(routing_config_evaluate)),
+            )
+    }
+}
+
+pub struct Secrets;
+
+#[cfg(feature = "olap")]
+impl Secrets {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/secrets")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/connector_credentials/rotate_key")
+                    .route(web::post().to(connector_credentials_key_rotate)),
+            )
+    }
+}
+
+pub struct DummyConnector;
+
+#[cfg(feature = "dummy_connector")]
+impl DummyConnector {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/dummy_connector")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/payments").route(web::post().to(dummy_connector_payment)))
+            .service(
+                web::resource("/payments/{payment_id}")
+                    .route(web::get().to(dummy_connector_payment_retrieve)),
+            )
+            .service(
+                web::resource("/payments/{payment_id}/refunds")
+                    .route(web::post().to(dummy_connector_refund)),
+            )
+            .service(
+                web::resource("/refunds/{refund_id}")
+                    .route(web::get().to(dummy_connector_refund_retrieve)),
+            )
+            .service(
+                web::resource("/authorize/{payment_id}")
+                    .route(web::get().to(dummy_connector_authentication)),
+            )
+            .service(
+                web::resource("/authorize/{payment_id}/complete")
+                    .route(web::get().to(dummy_connector_complete_authentication)),
+            )
     }
 }
 
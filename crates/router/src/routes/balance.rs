@@ -0,0 +1,88 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::ledger,
+    services::{api, authentication as auth, authorization::Permission},
+    types::api as api_types,
+};
+
+/// Balance - Retrieve
+///
+/// Retrieve the available and pending balances of the merchant in each currency. The available
+/// balance is the amount captured for payments, net of refunds, lost disputes and payouts. The
+/// pending balance is the amount authorized for payments which is yet to be captured.
+#[utoipa::path(
+    get,
+    path = "/balance",
+    responses(
+        (status = 200, description = "Balance retrieved", body = BalanceResponse),
+    ),
+    tag = "Balance",
+    operation_id = "Retrieve the Balance",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BalanceRetrieve))]
+pub async fn balance_retrieve(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::BalanceRetrieve;
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (),
+        |state, merchant_account, _| ledger::retrieve_balance(&*state.store, merchant_account),
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsRead)),
+            req.headers(),
+            Permission::PaymentRead,
+        ),
+    )
+    .await
+}
+
+/// Balance Transactions - List
+///
+/// List the balance transactions of the merchant, the most recent first. Each balance transaction
+/// records an authorization, capture, refund, dispute or payout moving funds between the accounts
+/// of the ledger of the merchant.
+#[utoipa::path(
+    get,
+    path = "/balance_transactions",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of balance transactions to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of balance transactions to skip"),
+    ),
+    responses(
+        (status = 200, description = "Balance transactions retrieved", body = BalanceTransactionListResponse),
+        (status = 400, description = "Invalid limit")
+    ),
+    tag = "Balance",
+    operation_id = "List all Balance Transactions",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BalanceTransactionList))]
+pub async fn balance_transaction_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<api_types::BalanceTransactionListConstraints>,
+) -> impl Responder {
+    let flow = Flow::BalanceTransactionList;
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        query.into_inner(),
+        |state, merchant_account, constraints| {
+            ledger::list_balance_transactions(&*state.store, merchant_account, constraints)
+        },
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsRead)),
+            req.headers(),
+            Permission::PaymentRead,
+        ),
+    )
+    .await
+}
@@ -0,0 +1,229 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::business_profile,
+    services::{api, authentication as auth, authorization::Permission},
+    types::api as api_types,
+};
+
+/// Business Profile - Create
+///
+/// Create a business profile for a merchant account. The return URL, webhook details, routing
+/// algorithm and statement descriptors of the business profile are used for the payments made with
+/// it.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/business_profile",
+    params(("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    request_body = BusinessProfileCreate,
+    responses(
+        (status = 200, description = "Business profile created", body = BusinessProfileResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Business Profile",
+    operation_id = "Create a Business Profile",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileCreate))]
+pub async fn business_profile_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_types::BusinessProfileCreate>,
+) -> HttpResponse {
+    let flow = Flow::BusinessProfileCreate;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| business_profile::create_business_profile(&*state.store, &merchant_id, req),
+        &*auth::admin_or_dashboard_auth(
+            req.headers(),
+            &merchant_id,
+            Permission::MerchantAccountWrite,
+        ),
+    )
+    .await
+}
+
+/// Business Profile - List
+///
+/// List the business profiles of a merchant account.
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/business_profile",
+    params(("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Business profiles retrieved", body = Vec<BusinessProfileResponse>),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "List Business Profiles",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileList))]
+pub async fn business_profile_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::BusinessProfileList;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        &merchant_id,
+        |state, _, merchant_id| {
+            business_profile::list_business_profiles(&*state.store, merchant_id)
+        },
+        &*auth::admin_or_dashboard_auth(
+            req.headers(),
+            &merchant_id,
+            Permission::MerchantAccountRead,
+        ),
+    )
+    .await
+}
+
+/// Business Profile - Retrieve
+///
+/// Retrieve a business profile of a merchant account.
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/business_profile/{profile_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Business profile retrieved", body = BusinessProfileResponse),
+        (status = 404, description = "Business profile not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Retrieve a Business Profile",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileRetrieve))]
+pub async fn business_profile_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::BusinessProfileRetrieve;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        &profile_id,
+        |state, _, profile_id| {
+            business_profile::retrieve_business_profile(&*state.store, &merchant_id, profile_id)
+        },
+        &*auth::admin_or_dashboard_auth(
+            req.headers(),
+            &merchant_id,
+            Permission::MerchantAccountRead,
+        ),
+    )
+    .await
+}
+
+/// Business Profile - Update
+///
+/// Update a business profile of a merchant account. The changes apply to the payments made with
+/// the business profile from then on.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/business_profile/{profile_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    request_body = BusinessProfileUpdate,
+    responses(
+        (status = 200, description = "Business profile updated", body = BusinessProfileResponse),
+        (status = 404, description = "Business profile not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Update a Business Profile",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileUpdate))]
+pub async fn business_profile_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_types::BusinessProfileUpdate>,
+) -> HttpResponse {
+    let flow = Flow::BusinessProfileUpdate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| {
+            business_profile::update_business_profile(&*state.store, &merchant_id, &profile_id, req)
+        },
+        &*auth::admin_or_dashboard_auth(
+            req.headers(),
+            &merchant_id,
+            Permission::MerchantAccountWrite,
+        ),
+    )
+    .await
+}
+
+/// Business Profile - Delete
+///
+/// Delete a business profile of a merchant account. Payments already made with the business
+/// profile send their webhooks with the webhook details of the merchant account from then on.
+#[utoipa::path(
+    delete,
+    path = "/accounts/{account_id}/business_profile/{profile_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Business profile deleted", body = BusinessProfileDeleteResponse),
+        (status = 404, description = "Business profile not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Delete a Business Profile",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileDelete))]
+pub async fn business_profile_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::BusinessProfileDelete;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        &profile_id,
+        |state, _, profile_id| {
+            business_profile::delete_business_profile(&*state.store, &merchant_id, profile_id)
+        },
+        &*auth::admin_or_dashboard_auth(
+            req.headers(),
+            &merchant_id,
+            Permission::MerchantAccountWrite,
+        ),
+    )
+    .await
+}
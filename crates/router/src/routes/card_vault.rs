@@ -0,0 +1,119 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::card_vault,
+    services::{api, authentication as auth},
+    types::api as api_types,
+};
+
+/// Card Vault - Create Token
+///
+/// Store a card in the vault, returning a token which can be used in place of the card in
+/// payments. The card is encrypted before it is stored, and the CVC is never stored.
+#[utoipa::path(
+    post,
+    path = "/vault/tokens",
+    request_body = VaultTokenCreateRequest,
+    responses(
+        (status = 200, description = "Card stored in the vault", body = VaultTokenResponse),
+        (status = 400, description = "Invalid card data")
+    ),
+    tag = "Card Vault",
+    operation_id = "Create a Vault Token",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::VaultTokenCreate))]
+pub async fn vault_token_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::VaultTokenCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::VaultTokenCreate;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, payload| {
+            card_vault::create_vault_token(state, merchant_account, payload)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Card Vault - Retrieve Token
+///
+/// Retrieve the metadata of a card stored in the vault.
+#[utoipa::path(
+    get,
+    path = "/vault/tokens/{payment_token}",
+    params(("payment_token" = String, Path, description = "The token of the card stored in the vault")),
+    responses(
+        (status = 200, description = "Vault token retrieved", body = VaultTokenResponse),
+        (status = 404, description = "Vault token not found")
+    ),
+    tag = "Card Vault",
+    operation_id = "Retrieve a Vault Token",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::VaultTokenRetrieve))]
+pub async fn vault_token_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::VaultTokenRetrieve;
+    let payment_token = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        &payment_token,
+        |state, merchant_account, payment_token| {
+            card_vault::retrieve_vault_token(state, merchant_account, payment_token)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Card Vault - Delete Token
+///
+/// Delete a card from the vault. The token can no longer be used in payments once deleted.
+#[utoipa::path(
+    delete,
+    path = "/vault/tokens/{payment_token}",
+    params(("payment_token" = String, Path, description = "The token of the card stored in the vault")),
+    responses(
+        (status = 200, description = "Vault token deleted", body = VaultTokenDeleteResponse),
+        (status = 404, description = "Vault token not found")
+    ),
+    tag = "Card Vault",
+    operation_id = "Delete a Vault Token",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::VaultTokenDelete))]
+pub async fn vault_token_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::VaultTokenDelete;
+    let payment_token = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        &payment_token,
+        |state, merchant_account, payment_token| {
+            card_vault::delete_vault_token(state, merchant_account, payment_token)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
@@ -85,9 +85,47 @@ pub async fn customers_retrieve(
     .await
 }
 
+/// List Customers
+///
+/// List the customers of the merchant, optionally filtered by email address or phone number.
+#[utoipa::path(
+    get,
+    path = "/customers",
+    params(
+        ("email" = Option<String>, Query, description = "The customer's email address"),
+        ("phone" = Option<String>, Query, description = "The customer's phone number"),
+        ("limit" = Option<i64>, Query, description = "Limit on the number of objects to return")
+    ),
+    responses(
+        (status = 200, description = "Customers retrieved", body = Vec<CustomerResponse>),
+        (status = 400, description = "Invalid list constraints")
+    ),
+    tag = "Customers",
+    operation_id = "List Customers",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersList))]
+pub async fn customers_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<customers::CustomerListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::CustomersList;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        query_payload.into_inner(),
+        |state, merchant_account, req| list_customers(&*state.store, merchant_account, req),
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
 /// Update Customer
 ///
-/// Updates the customer's details in a customer object.
+/// Updates the customer's details in a customer object. Only the details present in the request
+/// are updated.
 #[utoipa::path(
     post,
     path = "/customers/{customer_id}",
@@ -124,7 +162,8 @@ pub async fn customers_update(
 
 /// Delete Customer
 ///
-/// Delete a customer record.
+/// Delete a customer record. The mandates of the customer are revoked and their saved payment
+/// methods are deleted, while the customer's details are redacted.
 #[utoipa::path(
     delete,
     path = "/customers/{customer_id}",
@@ -143,7 +182,7 @@ pub async fn customers_delete(
     req: HttpRequest,
     path: web::Path<String>,
 ) -> impl Responder {
-    let flow = Flow::CustomersCreate;
+    let flow = Flow::CustomersDelete;
     let payload = web::Json(customers::CustomerId {
         customer_id: path.into_inner(),
     })
@@ -159,6 +198,44 @@ pub async fn customers_delete(
     .await
 }
 
+/// Redact Customer
+///
+/// Irreversibly redact the personal data of a customer, from the customer record as well as their
+/// addresses, payments and mandates. The amounts, statuses and identifiers of the payments are
+/// retained.
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/redact",
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "Customer was Redacted", body = CustomerRedactResponse),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Redact a Customer",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersRedact))]
+pub async fn customers_redact(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::CustomersRedact;
+    let payload = customers::CustomerId {
+        customer_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        redact_customer,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::CustomersGetMandates))]
 pub async fn get_customer_mandates(
     state: web::Data<AppState>,
@@ -0,0 +1,155 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::dead_letter,
+    services::{api, authentication as auth},
+    types::api as api_types,
+};
+
+/// Dead Letter Tasks - List
+///
+/// List the scheduler tasks which exhausted their retries or failed while being handled, the most
+/// recently dead lettered first.
+#[utoipa::path(
+    get,
+    path = "/dead_letter_tasks",
+    params(
+        ("runner" = Option<String>, Query, description = "Only the tasks of this runner are listed"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of tasks to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of tasks to skip"),
+    ),
+    responses(
+        (status = 200, description = "Dead lettered tasks retrieved", body = DeadLetterTaskListResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Dead Letter Tasks",
+    operation_id = "List Dead Lettered Tasks",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DeadLetterTaskList))]
+pub async fn dead_letter_task_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<api_types::DeadLetterTaskListConstraints>,
+) -> impl Responder {
+    let flow = Flow::DeadLetterTaskList;
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        query.into_inner(),
+        |state, _, constraints| dead_letter::list_dead_letter_tasks(&*state.store, constraints),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Dead Letter Tasks - Retrieve
+///
+/// Retrieve a dead lettered task, along with the reason it was dead lettered and the data it runs
+/// with.
+#[utoipa::path(
+    get,
+    path = "/dead_letter_tasks/{task_id}",
+    params(("task_id" = String, Path, description = "The identifier for the task")),
+    responses(
+        (status = 200, description = "Dead lettered task retrieved", body = DeadLetterTaskResponse),
+        (status = 404, description = "Dead lettered task not found")
+    ),
+    tag = "Dead Letter Tasks",
+    operation_id = "Retrieve a Dead Lettered Task",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DeadLetterTaskRetrieve))]
+pub async fn dead_letter_task_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::DeadLetterTaskRetrieve;
+    let task_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        &task_id,
+        |state, _, task_id| dead_letter::retrieve_dead_letter_task(&*state.store, task_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Dead Letter Tasks - Replay
+///
+/// Replay a dead lettered task, which schedules it to run again immediately with its retries
+/// reset.
+#[utoipa::path(
+    post,
+    path = "/dead_letter_tasks/{task_id}/replay",
+    params(("task_id" = String, Path, description = "The identifier for the task")),
+    responses(
+        (status = 200, description = "Dead lettered task replayed", body = DeadLetterTaskReplayResponse),
+        (status = 404, description = "Dead lettered task not found")
+    ),
+    tag = "Dead Letter Tasks",
+    operation_id = "Replay a Dead Lettered Task",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DeadLetterTaskReplay))]
+pub async fn dead_letter_task_replay(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::DeadLetterTaskReplay;
+    let task_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        &task_id,
+        |state, _, task_id| dead_letter::replay_dead_letter_task(&*state.store, task_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Dead Letter Tasks - Bulk Replay
+///
+/// Replay the identified dead lettered tasks, or the most recently dead lettered tasks of a
+/// runner. Tasks which are not dead lettered are left as is.
+#[utoipa::path(
+    post,
+    path = "/dead_letter_tasks/replay",
+    request_body = DeadLetterTaskReplayRequest,
+    responses(
+        (status = 200, description = "Dead lettered tasks replayed", body = DeadLetterTaskReplayResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Dead Letter Tasks",
+    operation_id = "Replay Dead Lettered Tasks",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DeadLetterTaskBulkReplay))]
+pub async fn dead_letter_task_bulk_replay(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::DeadLetterTaskReplayRequest>,
+) -> impl Responder {
+    let flow = Flow::DeadLetterTaskBulkReplay;
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| dead_letter::replay_dead_letter_tasks(&*state.store, req),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
@@ -0,0 +1,138 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::dummy_connector,
+    services::{api, authentication as auth},
+    types::api as api_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::DummyConnectorPayment))]
+pub async fn dummy_connector_payment(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::DummyConnectorPaymentRequest>,
+) -> impl Responder {
+    let flow = Flow::DummyConnectorPayment;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| dummy_connector::payment(state, merchant_account, req),
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DummyConnectorPaymentRetrieve))]
+pub async fn dummy_connector_payment_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::DummyConnectorPaymentRetrieve;
+    let payload = api_types::DummyConnectorPaymentRetrieveRequest {
+        payment_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            dummy_connector::payment_retrieve(state, merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// The 3DS challenge is completed in the browser of the customer, without the API key of the
+// merchant
+#[instrument(skip_all, fields(flow = ?Flow::DummyConnectorAuthentication))]
+pub async fn dummy_connector_authentication(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::DummyConnectorAuthentication;
+    let payload = api_types::DummyConnectorPaymentRetrieveRequest {
+        payment_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, _, req| dummy_connector::authentication_page(state, req),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DummyConnectorCompleteAuthentication))]
+pub async fn dummy_connector_complete_authentication(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<api_types::DummyConnectorAuthenticationRequest>,
+) -> impl Responder {
+    let flow = Flow::DummyConnectorCompleteAuthentication;
+    let mut payload = query.into_inner();
+    payload.payment_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, _, req| dummy_connector::complete_authentication(state, req),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DummyConnectorRefund))]
+pub async fn dummy_connector_refund(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_types::DummyConnectorRefundRequest>,
+) -> impl Responder {
+    let flow = Flow::DummyConnectorRefund;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| dummy_connector::refund(state, merchant_account, req),
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DummyConnectorRefundRetrieve))]
+pub async fn dummy_connector_refund_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::DummyConnectorRefundRetrieve;
+    let payload = api_types::DummyConnectorRefundRetrieveRequest {
+        refund_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            dummy_connector::refund_retrieve(state, merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
@@ -1,6 +1,10 @@
+use api_models::health_check::HealthStatus;
 use router_env::{instrument, logger, tracing};
 
-use crate::routes::metrics;
+use crate::{
+    core::health_check,
+    routes::{metrics, AppState},
+};
 
 /// .
 // #[logger::instrument(skip_all, name = "name1", level = "warn", fields( key1 = "val1" ))]
@@ -11,3 +15,35 @@ pub async fn health() -> impl actix_web::Responder {
     logger::info!("Health was called");
     actix_web::HttpResponse::Ok().body("health is good")
 }
+
+/// Metrics of the router in the Prometheus text format, for Prometheus to scrape. Only available
+/// when metrics are configured to be exported to Prometheus.
+#[instrument(skip_all)]
+pub async fn prometheus_metrics() -> impl actix_web::Responder {
+    match router_env::metrics::gather_prometheus_metrics() {
+        Some(Ok(metrics)) => actix_web::HttpResponse::Ok()
+            .content_type("text/plain; version=0.0.4")
+            .body(metrics),
+        Some(Err(error)) => {
+            logger::error!(?error, "Failed to encode metrics");
+            actix_web::HttpResponse::InternalServerError().finish()
+        }
+        None => actix_web::HttpResponse::NotFound().finish(),
+    }
+}
+
+/// Readiness of the router to serve requests, with the status and latency of each of its
+/// dependencies. Responds with `503 Service Unavailable` if any dependency is down.
+#[instrument(skip_all)]
+pub async fn readiness(state: actix_web::web::Data<AppState>) -> impl actix_web::Responder {
+    let response = health_check::check_readiness(state.get_ref()).await;
+    let status_code = match response.status {
+        HealthStatus::Up => actix_web::http::StatusCode::OK,
+        HealthStatus::Down => {
+            logger::warn!(?response, "Router is not ready");
+            actix_web::http::StatusCode::SERVICE_UNAVAILABLE
+        }
+    };
+
+    actix_web::HttpResponse::build(status_code).json(response)
+}
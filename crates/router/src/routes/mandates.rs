@@ -49,23 +49,24 @@ pub async fn get_mandate(
 
 /// Mandates - Revoke Mandate
 ///
-/// Revoke a mandate
+/// Revoke a mandate. The mandate is revoked with the connector as well, if the connector supports
+/// revoking mandates.
 #[utoipa::path(
     post,
-    path = "/mandates/revoke/{mandate_id}",
+    path = "/mandates/{mandate_id}/revoke",
     params(
         ("mandate_id" = String, Path, description = "The identifier for mandate")
     ),
     responses(
         (status = 200, description = "The mandate was revoked successfully", body = MandateRevokedResponse),
-        (status = 400, description = "Mandate does not exist in our records")
+        (status = 400, description = "Mandate cannot be revoked in its current status"),
+        (status = 404, description = "Mandate does not exist in our records")
     ),
     tag = "Mandates",
     operation_id = "Revoke a Mandate",
     security(("api_key" = []))
 )]
 #[instrument(skip_all, fields(flow = ?Flow::MandatesRevoke))]
-// #[post("/revoke/{id}")]
 pub async fn revoke_mandate(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -80,9 +81,51 @@ pub async fn revoke_mandate(
         state.get_ref(),
         &req,
         mandate_id,
-        |state, merchant_account, req| {
-            mandate::revoke_mandate(&*state.store, merchant_account, req)
-        },
+        mandate::revoke_mandate,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Mandates - List Mandates
+///
+/// List the mandates of the merchant, optionally filtered by customer, status, connector and
+/// creation time
+#[utoipa::path(
+    get,
+    path = "/mandates",
+    params(
+        ("customer_id" = Option<String>, Query, description = "The identifier for the customer"),
+        ("mandate_status" = Option<MandateStatus>, Query, description = "The status of the mandate"),
+        ("connector" = Option<String>, Query, description = "The connector linked to the mandate"),
+        ("limit" = Option<i64>, Query, description = "Limit on the number of objects to return"),
+        ("created_time" = Option<PrimitiveDateTime>, Query, description = "The time at which the mandate was created"),
+        ("created_time.lt" = Option<PrimitiveDateTime>, Query, description = "Time less than the mandate created time"),
+        ("created_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the mandate created time"),
+        ("created_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the mandate created time"),
+        ("created_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the mandate created time")
+    ),
+    responses(
+        (status = 200, description = "The mandate list was retrieved successfully", body = Vec<MandateResponse>),
+        (status = 400, description = "Invalid list constraints")
+    ),
+    tag = "Mandates",
+    operation_id = "List Mandates",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MandatesList))]
+pub async fn retrieve_mandates_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<mandates::MandateListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::MandatesList;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload.into_inner(),
+        mandate::list_mandates,
         &auth::ApiKeyAuth,
     )
     .await
@@ -5,6 +5,13 @@ global_meter!(GLOBAL_METER, "ROUTER_API");
 
 counter_metric!(HEALTH_METRIC, GLOBAL_METER); // No. of health API hits
 counter_metric!(KV_MISS, GLOBAL_METER); // No. of KV misses
+counter_metric!(REPLICA_READ_FALLBACK, GLOBAL_METER); // No. of reads sent to the master database instead of the replica
+
+// Database Connection Pool Metrics
+histogram_metric!(DB_POOL_WAIT_TIME, GLOBAL_METER); // Time (in seconds) waited for a connection
+histogram_metric!(DB_POOL_CONNECTIONS_IN_USE, GLOBAL_METER);
+histogram_metric!(DB_POOL_IDLE_CONNECTIONS, GLOBAL_METER);
+counter_metric!(DB_POOL_EXHAUSTED, GLOBAL_METER); // No. of requests for a connection which timed out
 #[cfg(feature = "kms")]
 counter_metric!(AWS_KMS_FAILURES, GLOBAL_METER); // No. of AWS KMS API failures
 
@@ -16,6 +23,9 @@ histogram_metric!(REQUEST_TIME, GLOBAL_METER);
 // Operation Level Metrics
 counter_metric!(PAYMENT_COUNT, GLOBAL_METER);
 counter_metric!(SUCCESSFUL_PAYMENT, GLOBAL_METER);
+counter_metric!(PAYMENT_CREATED_COUNT, GLOBAL_METER); // Attributes: currency
+counter_metric!(PAYMENT_CONFIRMED_COUNT, GLOBAL_METER); // Attributes: payment_method
+counter_metric!(PAYMENT_AUTHORIZATION_COUNT, GLOBAL_METER); // Attributes: connector, status
 
 counter_metric!(REFUND_COUNT, GLOBAL_METER);
 counter_metric!(SUCCESSFUL_REFUND, GLOBAL_METER);
@@ -26,7 +36,8 @@ counter_metric!(SUCCESSFUL_CANCEL, GLOBAL_METER);
 counter_metric!(MANDATE_COUNT, GLOBAL_METER);
 counter_metric!(SUBSEQUENT_MANDATE_PAYMENT, GLOBAL_METER);
 
-counter_metric!(RETRY_COUNT, GLOBAL_METER);
+counter_metric!(RETRY_COUNT, GLOBAL_METER); // Attributes: connector
+counter_metric!(VOLUME_SPLIT_ROUTED_COUNT, GLOBAL_METER); // Attributes needed
 
 counter_metric!(STORED_TO_LOCKER, GLOBAL_METER);
 counter_metric!(GET_FROM_LOCKER, GLOBAL_METER);
@@ -41,11 +52,12 @@ counter_metric!(CUSTOMER_REDACTED, GLOBAL_METER);
 
 counter_metric!(API_KEY_CREATED, GLOBAL_METER);
 counter_metric!(API_KEY_REVOKED, GLOBAL_METER);
+counter_metric!(API_KEY_ROTATED, GLOBAL_METER);
 
 // Flow Specific Metrics
 
 counter_metric!(ACCESS_TOKEN_CREATION, GLOBAL_METER);
-histogram_metric!(CONNECTOR_REQUEST_TIME, GLOBAL_METER);
+histogram_metric!(CONNECTOR_REQUEST_TIME, GLOBAL_METER); // Attributes: connector, flow
 counter_metric!(SESSION_TOKEN_CREATED, GLOBAL_METER);
 
 counter_metric!(CONNECTOR_CALL_COUNT, GLOBAL_METER); // Attributes needed
@@ -56,6 +68,7 @@ counter_metric!(THREE_DS_DOWNGRADE_COUNT, GLOBAL_METER);
 counter_metric!(RESPONSE_DESERIALIZATION_FAILURE, GLOBAL_METER);
 counter_metric!(CONNECTOR_ERROR_RESPONSE_COUNT, GLOBAL_METER);
 counter_metric!(REQUEST_TIMEOUT_COUNT, GLOBAL_METER);
+counter_metric!(CONNECTOR_CIRCUIT_OPENED_COUNT, GLOBAL_METER); // Attributes needed
 
 // Connector Level Metric
 counter_metric!(REQUEST_BUILD_FAILURE, GLOBAL_METER);
@@ -0,0 +1,285 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_keys, organization},
+    services::{api, authentication as auth},
+    types::api as api_types,
+};
+
+/// Organization - Create
+///
+/// Create an organization, which can own many merchant accounts.
+#[utoipa::path(
+    post,
+    path = "/organizations",
+    request_body = OrganizationCreateRequest,
+    responses(
+        (status = 200, description = "Organization created", body = OrganizationResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Organization",
+    operation_id = "Create an Organization",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationCreate))]
+pub async fn organization_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::OrganizationCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::OrganizationCreate;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| organization::create_organization(&*state.store, req),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Organization - Retrieve
+///
+/// Retrieve an organization.
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}",
+    params(("organization_id" = String, Path, description = "The unique identifier for the organization")),
+    responses(
+        (status = 200, description = "Organization retrieved", body = OrganizationResponse),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organization",
+    operation_id = "Retrieve an Organization",
+    security(("admin_api_key" = []), ("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationRetrieve))]
+pub async fn organization_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::OrganizationRetrieve;
+    let organization_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        &organization_id,
+        |state, _, organization_id| {
+            organization::retrieve_organization(&*state.store, organization_id)
+        },
+        &auth::OrganizationAuth(organization_id.clone()),
+    )
+    .await
+}
+
+/// Organization - List Merchant Accounts
+///
+/// List the merchant accounts owned by an organization.
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/accounts",
+    params(("organization_id" = String, Path, description = "The unique identifier for the organization")),
+    responses(
+        (status = 200, description = "Merchant accounts of the organization", body = Vec<MerchantAccountResponse>),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organization",
+    operation_id = "List the Merchant Accounts of an Organization",
+    security(("admin_api_key" = []), ("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationMerchantAccountList))]
+pub async fn organization_merchant_account_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::OrganizationMerchantAccountList;
+    let organization_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        &organization_id,
+        |state, _, organization_id| {
+            organization::list_merchant_accounts(&*state.store, organization_id)
+        },
+        &auth::OrganizationAuth(organization_id.clone()),
+    )
+    .await
+}
+
+/// Organization - Create API Key
+///
+/// Create an API Key for an organization. The API Key can act on any merchant account of the
+/// organization, which is chosen through the `X-Merchant-Id` header of the request. The plaintext
+/// API Key will be displayed only once on creation, so ensure you store it securely.
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/api_keys",
+    params(("organization_id" = String, Path, description = "The unique identifier for the organization")),
+    request_body = CreateApiKeyRequest,
+    responses(
+        (status = 200, description = "API Key created", body = CreateApiKeyResponse),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organization",
+    operation_id = "Create an API Key for an Organization",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationApiKeyCreate))]
+pub async fn organization_api_key_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_types::CreateApiKeyRequest>,
+) -> impl Responder {
+    let flow = Flow::OrganizationApiKeyCreate;
+    let organization_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload| organization::create_api_key(state, &organization_id, payload),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Organization - List API Keys
+///
+/// List the API Keys of an organization.
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/api_keys",
+    params(
+        ("organization_id" = String, Path, description = "The unique identifier for the organization"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of API Keys to include in the response"),
+        ("skip" = Option<i64>, Query, description = "The number of API Keys to skip when retrieving the list of API keys."),
+    ),
+    responses(
+        (status = 200, description = "List of API Keys retrieved successfully", body = Vec<RetrieveApiKeyResponse>),
+    ),
+    tag = "Organization",
+    operation_id = "List all API Keys of an Organization",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationApiKeyList))]
+pub async fn organization_api_key_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<api_types::ListApiKeyConstraints>,
+) -> impl Responder {
+    let flow = Flow::OrganizationApiKeyList;
+    let list_api_key_constraints = query.into_inner();
+    let limit = list_api_key_constraints.limit;
+    let offset = list_api_key_constraints.skip;
+    let organization_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (limit, offset, organization_id),
+        |state, _, (limit, offset, organization_id)| async move {
+            api_keys::list_organization_api_keys(&*state.store, organization_id, limit, offset)
+                .await
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Organization - Revoke API Key
+///
+/// Revoke an API Key of an organization. Once revoked, the API Key can no longer be used for
+/// authenticating with our APIs.
+#[utoipa::path(
+    delete,
+    path = "/organizations/{organization_id}/api_keys/{key_id}",
+    params(
+        ("organization_id" = String, Path, description = "The unique identifier for the organization"),
+        ("key_id" = String, Path, description = "The unique identifier for the API Key")
+    ),
+    responses(
+        (status = 200, description = "API Key revoked", body = RevokeApiKeyResponse),
+        (status = 404, description = "API Key not found")
+    ),
+    tag = "Organization",
+    operation_id = "Revoke an API Key of an Organization",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationApiKeyRevoke))]
+pub async fn organization_api_key_revoke(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::OrganizationApiKeyRevoke;
+    let (organization_id, key_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (&organization_id, &key_id),
+        |state, _, (organization_id, key_id)| {
+            api_keys::revoke_organization_api_key(&*state.store, organization_id, key_id)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Organization - Payments Aggregate
+///
+/// Aggregate the payments of all merchant accounts of an organization, grouped by currency and
+/// status.
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payments/aggregate",
+    params(
+        ("organization_id" = String, Path, description = "The unique identifier for the organization"),
+        ("created.gte" = Option<PrimitiveDateTime>, Query, description = "Only payments created at or after this time are aggregated"),
+        ("created.lt" = Option<PrimitiveDateTime>, Query, description = "Only payments created before this time are aggregated"),
+    ),
+    responses(
+        (status = 200, description = "Payments of the organization aggregated", body = OrganizationPaymentsAggregateResponse),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organization",
+    operation_id = "Aggregate the Payments of an Organization",
+    security(("admin_api_key" = []), ("api_key" = []))
+)]
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationPaymentsAggregate))]
+pub async fn organization_payments_aggregate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<api_types::OrganizationPaymentsAggregateConstraints>,
+) -> impl Responder {
+    let flow = Flow::OrganizationPaymentsAggregate;
+    let organization_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        query.into_inner(),
+        |state, _, constraints| {
+            organization::get_payments_aggregate(&*state.store, &organization_id, constraints)
+        },
+        &auth::OrganizationAuth(organization_id.clone()),
+    )
+    .await
+}
@@ -0,0 +1,121 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::payment_links,
+    services::{api, authentication as auth},
+    types::api as api_types,
+};
+
+/// Payment Links - Create
+///
+/// Create a payment link for a fixed amount. Customers opening the link are shown a checkout page,
+/// and their payments are routed using the routing configuration of the merchant.
+#[utoipa::path(
+    post,
+    path = "/payment_links",
+    request_body = PaymentLinkCreateRequest,
+    responses(
+        (status = 200, description = "Payment link created", body = PaymentLinkResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payment Links",
+    operation_id = "Create a Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkCreate))]
+pub async fn payment_link_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::PaymentLinkCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkCreate;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        payment_links::create_payment_link,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Payment Links - Retrieve
+///
+/// Retrieve a payment link.
+#[utoipa::path(
+    get,
+    path = "/payment_links/{link_id}",
+    params(("link_id" = String, Path, description = "The identifier for the payment link")),
+    responses(
+        (status = 200, description = "Payment link retrieved", body = PaymentLinkResponse),
+        (status = 404, description = "Payment link does not exist in our records")
+    ),
+    tag = "Payment Links",
+    operation_id = "Retrieve a Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkRetrieve))]
+pub async fn payment_link_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkRetrieve;
+    let payload = api_types::PaymentLinkId {
+        link_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        payment_links::retrieve_payment_link,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkCheckout))]
+pub async fn payment_link_checkout(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkCheckout;
+    let payload = api_types::PaymentLinkId {
+        link_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, _, req| payment_links::initiate_payment_link_checkout(state, req),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkPay))]
+pub async fn payment_link_pay(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_types::PaymentLinkPayRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkPay;
+    let mut payload = json_payload.into_inner();
+    payload.link_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, _, req| payment_links::pay_payment_link(state, req),
+        &auth::NoAuth,
+    )
+    .await
+}
@@ -45,18 +45,17 @@ pub async fn create_payment_method_api(
 
 /// List payment methods for a Merchant
 ///
-/// To filter and list the applicable payment methods for a particular Merchant ID
+/// To filter and list the applicable payment methods for a particular Merchant ID. When the client secret of a payment is given, the payment methods enabled across the connectors of the merchant are further filtered by the currency, amount and country (of the shipping address, or else of the billing address) of the payment, for the checkout to render
 #[utoipa::path(
     get,
-    path = "/payment_methods/{account_id}",
+    path = "/account/payment_methods",
     params (
-        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
-        ("accepted_country" = Vec<String>, Query, description = "The two-letter ISO currency code"),
-        ("accepted_currency" = Vec<Currency>, Path, description = "The three-letter ISO currency code"),
-        ("minimum_amount" = i64, Query, description = "The minimum amount accepted for processing by the particular payment method."),
-        ("maximum_amount" = i64, Query, description = "The maximum amount amount accepted for processing by the particular payment method."),
-        ("recurring_payment_enabled" = bool, Query, description = "Indicates whether the payment method is eligible for recurring payments"),
-        ("installment_payment_enabled" = bool, Query, description = "Indicates whether the payment method is eligible for installment payments"),
+        ("client_secret" = Option<String>, Query, description = "The client secret of the payment for which the payment methods are listed"),
+        ("accepted_countries" = Option<Vec<CountryCode>>, Query, description = "The two-letter ISO country codes"),
+        ("accepted_currencies" = Option<Vec<Currency>>, Query, description = "The three-letter ISO currency codes"),
+        ("amount" = Option<i64>, Query, description = "The amount accepted for processing by the payment methods"),
+        ("recurring_enabled" = Option<bool>, Query, description = "Indicates whether the payment method is eligible for recurring payments"),
+        ("installment_payment_enabled" = Option<bool>, Query, description = "Indicates whether the payment method is eligible for installment payments"),
     ),
     responses(
         (status = 200, description = "Payment Methods retrieved", body = PaymentMethodListResponse),
@@ -76,7 +75,7 @@ pub async fn list_payment_method_api(
     let flow = Flow::PaymentMethodsList;
     let payload = json_payload.into_inner();
 
-    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload, None) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return api::log_and_return_error_response(e),
     };
@@ -145,6 +144,55 @@ pub async fn list_customer_payment_method_api(
     .await
 }
 
+/// Payment Method - Save for a Customer
+///
+/// To save a card of a customer in the card vault, so that the customer can pay with it in future
+/// payments by passing the `payment_method_id`
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/payment_methods",
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the customer account"),
+    ),
+    request_body = CustomerPaymentMethodCreate,
+    responses(
+        (status = 200, description = "Payment Method saved", body = CustomerPaymentMethod),
+        (status = 400, description = "Invalid Data"),
+        (status = 404, description = "Customer does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Save a Payment Method for a Customer",
+    security(("api_key" = []), ("ephemeral_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsCreate))]
+pub async fn create_customer_payment_method_api(
+    state: web::Data<AppState>,
+    customer_id: web::Path<(String,)>,
+    req: HttpRequest,
+    json_payload: web::Json<payment_methods::CustomerPaymentMethodCreate>,
+) -> HttpResponse {
+    let flow = Flow::CustomerPaymentMethodsCreate;
+    let customer_id = customer_id.into_inner().0;
+
+    let auth_type = match auth::is_ephemeral_auth(req.headers(), &*state.store, &customer_id).await
+    {
+        Ok(auth_type) => auth_type,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, payload| {
+            cards::save_customer_payment_method(state, merchant_account, &customer_id, payload)
+        },
+        &*auth_type,
+    )
+    .await
+}
+
 /// Payment Method - Retrieve
 ///
 /// To retrieve a payment method
@@ -0,0 +1,118 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::payment_schedules,
+    services::{api, authentication as auth},
+    types::api as api_types,
+};
+
+/// Payment Schedules - Create
+///
+/// Create a payment schedule, which charges the mandate of a customer with a fixed amount at a
+/// regular interval. Declined payments are retried, and the merchant is notified of the outcome of
+/// every cycle through webhooks.
+#[utoipa::path(
+    post,
+    path = "/payment_schedules",
+    request_body = PaymentScheduleCreateRequest,
+    responses(
+        (status = 200, description = "Payment schedule created", body = PaymentScheduleResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Mandate does not exist in our records")
+    ),
+    tag = "Payment Schedules",
+    operation_id = "Create a Payment Schedule",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentScheduleCreate))]
+pub async fn payment_schedule_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::PaymentScheduleCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentScheduleCreate;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        payment_schedules::create_payment_schedule,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Payment Schedules - Retrieve
+///
+/// Retrieve a payment schedule.
+#[utoipa::path(
+    get,
+    path = "/payment_schedules/{schedule_id}",
+    params(("schedule_id" = String, Path, description = "The identifier for the payment schedule")),
+    responses(
+        (status = 200, description = "Payment schedule retrieved", body = PaymentScheduleResponse),
+        (status = 404, description = "Payment schedule does not exist in our records")
+    ),
+    tag = "Payment Schedules",
+    operation_id = "Retrieve a Payment Schedule",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentScheduleRetrieve))]
+pub async fn payment_schedule_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentScheduleRetrieve;
+    let payload = api_types::PaymentScheduleId {
+        schedule_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        payment_schedules::retrieve_payment_schedule,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Payment Schedules - Cancel
+///
+/// Cancel an active payment schedule. No further cycles of the schedule are charged.
+#[utoipa::path(
+    post,
+    path = "/payment_schedules/{schedule_id}/cancel",
+    params(("schedule_id" = String, Path, description = "The identifier for the payment schedule")),
+    responses(
+        (status = 200, description = "Payment schedule cancelled", body = PaymentScheduleResponse),
+        (status = 400, description = "Payment schedule is not active"),
+        (status = 404, description = "Payment schedule does not exist in our records")
+    ),
+    tag = "Payment Schedules",
+    operation_id = "Cancel a Payment Schedule",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentScheduleCancel))]
+pub async fn payment_schedule_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentScheduleCancel;
+    let payload = api_types::PaymentScheduleId {
+        schedule_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        payment_schedules::cancel_payment_schedule,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
@@ -8,7 +8,7 @@ use crate::{
         errors::http_not_implemented,
         payments::{self, PaymentRedirectFlow},
     },
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::Permission},
     types::api::{self as api_types, enums as api_enums, payments as payment_types},
 };
 
@@ -18,6 +18,9 @@ use crate::{
 #[utoipa::path(
     post,
     path = "/payments",
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Unique key to safely retry the request without creating a duplicate payment")
+    ),
     request_body=PaymentsRequest,
     responses(
         (status = 200, description = "Payment created", body = PaymentsResponse),
@@ -41,21 +44,44 @@ pub async fn payments_create(
         return http_not_implemented();
     };
 
+    let idempotency_key = match payments::idempotency::get_idempotency_key(req.headers()) {
+        Ok(key) => key,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
     api::server_wrap(
         flow,
         state.get_ref(),
         &req,
         payload,
         |state, merchant_account, req| {
-            authorize_verify_select(
-                payments::PaymentCreate,
-                state,
-                merchant_account,
-                req,
-                api::AuthFlow::Merchant,
-            )
+            let merchant_id = merchant_account.merchant_id.clone();
+            let idempotency_key = idempotency_key.clone();
+            let request = req.clone();
+            async move {
+                payments::idempotency::call_with_idempotency(
+                    state,
+                    &merchant_id,
+                    idempotency_key,
+                    &request,
+                    authorize_verify_select(
+                        payments::PaymentCreate,
+                        state,
+                        merchant_account,
+                        req,
+                        api::AuthFlow::Merchant,
+                    ),
+                )
+                .await
+            }
         },
-        &auth::ApiKeyAuth,
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(
+                api_types::ApiKeyScope::PaymentsWrite,
+            )),
+            req.headers(),
+            Permission::PaymentWrite,
+        ),
     )
     .await
 }
@@ -143,9 +169,13 @@ pub async fn payments_retrieve(
         force_sync: json_payload.force_sync.unwrap_or(false),
         ..Default::default()
     };
-    let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
+    let auth_type: Box<dyn auth::AuthenticateAndFetch<_, _>> = if auth::is_jwt_auth(req.headers()) {
+        Box::new(auth::DashboardAuth(Permission::PaymentRead))
+    } else {
+        match auth::get_auth_type_and_flow(req.headers(), api_types::ApiKeyScope::PaymentsRead) {
+            Ok((auth_type, _auth_flow)) => auth_type,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        }
     };
 
     api::server_wrap(
@@ -190,9 +220,13 @@ pub async fn payments_retrieve_with_gateway_creds(
     req: actix_web::HttpRequest,
     json_payload: web::Json<payment_types::PaymentRetrieveBodyWithCredentials>,
 ) -> impl Responder {
-    let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
+    let auth_type: Box<dyn auth::AuthenticateAndFetch<_, _>> = if auth::is_jwt_auth(req.headers()) {
+        Box::new(auth::DashboardAuth(Permission::PaymentRead))
+    } else {
+        match auth::get_auth_type_and_flow(req.headers(), api_types::ApiKeyScope::PaymentsRead) {
+            Ok((auth_type, _auth_flow)) => auth_type,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        }
     };
     let payload = payment_types::PaymentsRetrieveRequest {
         resource_id: payment_types::PaymentIdType::PaymentIntentId(
@@ -227,6 +261,8 @@ pub async fn payments_retrieve_with_gateway_creds(
 /// Payments - Update
 ///
 /// To update the properties of a PaymentIntent object. This may include attaching a payment method, or attaching customer object or metadata fields after the Payment is created
+///
+/// The amount, currency and order details can be updated until the payment is confirmed. Once the payment is being processed or awaits capture, only its addresses, return URL and level 2 / level 3 data can be updated
 #[utoipa::path(
     post,
     path = "/payments/{payment_id}",
@@ -236,7 +272,7 @@ pub async fn payments_retrieve_with_gateway_creds(
     request_body=PaymentsRequest,
     responses(
         (status = 200, description = "Payment updated", body = PaymentsResponse),
-        (status = 400, description = "Missing mandatory fields")
+        (status = 400, description = "Missing mandatory fields, or fields which cannot be updated once the payment is confirmed")
     ),
     tag = "Payments",
     operation_id = "Update a Payment",
@@ -261,9 +297,13 @@ pub async fn payments_update(
 
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Some(api_types::ApiKeyScope::PaymentsWrite),
+    ) {
         Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
+        Err(err) => return api::log_and_return_error_response(err),
     };
 
     api::server_wrap(
@@ -292,7 +332,8 @@ pub async fn payments_update(
     post,
     path = "/payments/{payment_id}/confirm",
     params(
-        ("payment_id" = String, Path, description = "The identifier for payment")
+        ("payment_id" = String, Path, description = "The identifier for payment"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Unique key to safely retry the request without confirming the payment twice")
     ),
     request_body=PaymentsRequest,
     responses(
@@ -322,11 +363,19 @@ pub async fn payments_confirm(
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
     payload.confirm = Some(true);
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(e) => return api::log_and_return_error_response(e),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Some(api_types::ApiKeyScope::PaymentsWrite),
+    ) {
+        Ok(auth) => auth,
+        Err(e) => return api::log_and_return_error_response(e),
+    };
+
+    let idempotency_key = match payments::idempotency::get_idempotency_key(req.headers()) {
+        Ok(key) => key,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     api::server_wrap(
         flow,
@@ -334,13 +383,25 @@ pub async fn payments_confirm(
         &req,
         payload,
         |state, merchant_account, req| {
-            authorize_verify_select(
-                payments::PaymentConfirm,
-                state,
-                merchant_account,
-                req,
-                auth_flow,
-            )
+            let merchant_id = merchant_account.merchant_id.clone();
+            let idempotency_key = idempotency_key.clone();
+            let request = req.clone();
+            async move {
+                payments::idempotency::call_with_idempotency(
+                    state,
+                    &merchant_id,
+                    idempotency_key,
+                    &request,
+                    authorize_verify_select(
+                        payments::PaymentConfirm,
+                        state,
+                        merchant_account,
+                        req,
+                        auth_flow,
+                    ),
+                )
+                .await
+            }
         },
         &*auth_type,
     )
@@ -394,14 +455,22 @@ pub async fn payments_capture(
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &auth::ApiKeyAuth,
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(
+                api_types::ApiKeyScope::PaymentsWrite,
+            )),
+            req.headers(),
+            Permission::PaymentWrite,
+        ),
     )
     .await
 }
 
 /// Payments - Session token
 ///
-/// To create the session object or to get session token for wallets
+/// To create the session object or to get session token for wallets. Session tokens are returned
+/// for all the wallets enabled by the merchant, unless specific wallets are requested, so that the
+/// SDK can render the wallet buttons.
 #[utoipa::path(
     post,
     path = "/payments/session_tokens",
@@ -594,9 +663,108 @@ pub async fn payments_complete_authorize(
     .await
 }
 
+/// Payments - Complete Authorize
+///
+/// To complete a payment which requires the customer to approve it on the payment processor's website, such as a PayPal payment, once the customer is redirected back. The payment is captured or authorized based on the capture method of the payment
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/complete_authorize",
+    request_body=PaymentsRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Payment authorized", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Complete Authorize a Payment",
+    security(("api_key" = []), ("publishable_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCompleteAuthorize))]
+// #[post("/{payment_id}/complete_authorize")]
+pub async fn payments_complete_authorize_continue(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsCompleteAuthorize;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+    payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
+
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Some(api_types::ApiKeyScope::PaymentsWrite),
+    ) {
+        Ok(auth) => auth,
+        Err(e) => return api::log_and_return_error_response(e),
+    };
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            payments::payments_complete_authorize_core(state, merchant_account, req, auth_flow)
+        },
+        &*auth_type,
+    )
+    .await
+}
+
+/// Payments - 3DS Authenticate
+///
+/// To continue a payment awaiting 3DS authentication with the results of the 3DS 2.0 authentication performed by the 3DS server. The results are trusted as received, so this has to be called from the server of the merchant with its API key, and not from the client. The payment is authorized when the cardholder was authenticated, stays in requires_customer_action when a challenge is required and fails otherwise
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/3ds/authenticate",
+    request_body=PaymentsThreeDsAuthenticateRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Payment authenticated", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Authenticate a Payment with 3DS",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsThreeDsAuthenticate))]
+// #[post("/{payment_id}/3ds/authenticate")]
+pub async fn payments_three_ds_authenticate(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsThreeDsAuthenticateRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsThreeDsAuthenticate;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+    payload.payment_id = payment_id;
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            payments::three_ds::payments_three_ds_authenticate_core(state, merchant_account, req)
+        },
+        &auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsWrite),
+    )
+    .await
+}
+
 /// Payments - Cancel
 ///
 /// A Payment could can be cancelled when it is in one of these statuses: requires_payment_method, requires_capture, requires_confirmation, requires_customer_action
+///
+/// A Payment which was already captured is refunded in full instead of being cancelled, if the merchant has opted in to refunds on cancellation
 #[utoipa::path(
     post,
     path = "/payments/{payment_id}/cancel",
@@ -625,6 +793,113 @@ pub async fn payments_cancel(
     let payment_id = path.into_inner();
     payload.payment_id = payment_id;
 
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            payments::refund_on_cancel::payments_cancel_core(state, merchant_account, req)
+        },
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(
+                api_types::ApiKeyScope::PaymentsWrite,
+            )),
+            req.headers(),
+            Permission::PaymentWrite,
+        ),
+    )
+    .await
+}
+
+/// Payments - Approve
+///
+/// To release a payment held for review, in status requires_merchant_action, by capturing it
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/approve",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body=PaymentsCaptureRequest,
+    responses(
+        (status = 200, description = "Payment approved", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Approve a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsApprove))]
+// #[post("/{payment_id}/approve")]
+pub async fn payments_approve(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsCaptureRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsApprove;
+    let approve_payload = payment_types::PaymentsCaptureRequest {
+        payment_id: Some(path.into_inner()),
+        ..json_payload.into_inner()
+    };
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        approve_payload,
+        |state, merchant_account, payload| {
+            payments::payments_core::<api_types::Capture, payment_types::PaymentsResponse, _, _, _>(
+                state,
+                merchant_account,
+                payments::PaymentApprove,
+                payload,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+            )
+        },
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(
+                api_types::ApiKeyScope::PaymentsWrite,
+            )),
+            req.headers(),
+            Permission::PaymentWrite,
+        ),
+    )
+    .await
+}
+
+/// Payments - Reject
+///
+/// To cancel a payment held for review, in status requires_merchant_action, by voiding its authorization
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/reject",
+    request_body=PaymentsCancelRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Payment rejected", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Reject a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsReject))]
+// #[post("/{payment_id}/reject")]
+pub async fn payments_reject(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsCancelRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsReject;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = path.into_inner();
+
     api::server_wrap(
         flow,
         state.get_ref(),
@@ -634,13 +909,19 @@ pub async fn payments_cancel(
             payments::payments_core::<api_types::Void, payment_types::PaymentsResponse, _, _, _>(
                 state,
                 merchant_account,
-                payments::PaymentCancel,
+                payments::PaymentReject,
                 req,
                 api::AuthFlow::Merchant,
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &auth::ApiKeyAuth,
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(
+                api_types::ApiKeyScope::PaymentsWrite,
+            )),
+            req.headers(),
+            Permission::PaymentWrite,
+        ),
     )
     .await
 }
@@ -688,7 +969,143 @@ pub async fn payments_list(
         |state, merchant_account, req| {
             payments::list_payments(&*state.store, merchant_account, req)
         },
-        &auth::ApiKeyAuth,
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsRead)),
+            req.headers(),
+            Permission::PaymentRead,
+        ),
+    )
+    .await
+}
+
+/// Payments - List Attempts
+///
+/// To list all the attempts made for a payment, across retries and connectors
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/attempts",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for the payment")
+    ),
+    responses(
+        (status = 200, description = "List of attempts of the payment", body = PaymentAttemptListResponse),
+        (status = 404, description = "Payment does not exist in our records")
+    ),
+    tag = "Payments",
+    operation_id = "List all Attempts of a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentAttemptsList))]
+#[cfg(feature = "olap")]
+// #[get("/{payment_id}/attempts")]
+pub async fn payments_attempts_list(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentAttemptsList;
+    let payment_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payment_id,
+        |state, merchant_account, payment_id| {
+            payments::list_payment_attempts(&*state.store, merchant_account, payment_id)
+        },
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsRead)),
+            req.headers(),
+            Permission::PaymentRead,
+        ),
+    )
+    .await
+}
+
+/// Payments - List Events
+///
+/// To list the changes in the status of a payment, along with what caused each change
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/events",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for the payment")
+    ),
+    responses(
+        (status = 200, description = "List of events of the payment", body = PaymentEventListResponse),
+        (status = 404, description = "Payment does not exist in our records")
+    ),
+    tag = "Payments",
+    operation_id = "List all Events of a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentEventsList))]
+#[cfg(feature = "olap")]
+// #[get("/{payment_id}/events")]
+pub async fn payments_events_list(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentEventsList;
+    let payment_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payment_id,
+        |state, merchant_account, payment_id| {
+            payments::list_payment_events(&*state.store, merchant_account, payment_id)
+        },
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsRead)),
+            req.headers(),
+            Permission::PaymentRead,
+        ),
+    )
+    .await
+}
+
+/// Payments - List Connector Logs
+///
+/// To list the requests made to connectors for a payment in debug mode, along with the responses of the connectors
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/connector_logs",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for the payment")
+    ),
+    responses(
+        (status = 200, description = "List of connector logs of the payment", body = ConnectorLogListResponse),
+        (status = 404, description = "Payment does not exist in our records")
+    ),
+    tag = "Payments",
+    operation_id = "List all Connector Logs of a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorLogsList))]
+#[cfg(feature = "olap")]
+// #[get("/{payment_id}/connector_logs")]
+pub async fn payments_connector_logs_list(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ConnectorLogsList;
+    let payment_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payment_id,
+        |state, merchant_account, payment_id| {
+            payments::list_connector_logs(&*state.store, merchant_account, payment_id)
+        },
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsRead)),
+            req.headers(),
+            Permission::PaymentRead,
+        ),
     )
     .await
 }
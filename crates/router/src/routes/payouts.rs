@@ -1,51 +1,124 @@
-use actix_web::{
-    body::{BoxBody, MessageBody},
-    HttpResponse, Responder,
-};
+use actix_web::{web, HttpRequest, Responder};
 use router_env::{instrument, tracing, Flow};
 
+use super::app::AppState;
+use crate::{
+    core::payouts,
+    services::{api, authentication as auth},
+    types::api as api_types,
+};
+
+/// Payouts - Create
+///
+/// Create a payout, which pays out an amount to the card or bank account of a recipient through
+/// a connector. The details of the card or bank account are stored, and can be reused for later
+/// payouts through the `payout_method_id` of the response. The merchant is notified of the final
+/// status of the payout through webhooks.
+#[utoipa::path(
+    post,
+    path = "/payouts",
+    request_body = PayoutCreateRequest,
+    responses(
+        (status = 200, description = "Payout created", body = PayoutResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsCreate))]
-// #[post("/create")]
-pub async fn payouts_create() -> impl Responder {
-    let _flow = Flow::PayoutsCreate;
-    http_response("create")
+pub async fn payouts_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::PayoutCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::PayoutsCreate;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        payouts::create_payout,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+/// Payouts - Retrieve
+///
+/// Retrieve a payout. Passing `force_sync` fetches the status of a payout which is awaiting a
+/// result from the connector.
+#[utoipa::path(
+    get,
+    path = "/payouts/{payout_id}",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for the payout"),
+        ("force_sync" = Option<bool>, Query, description = "Whether the status of the payout is fetched from the connector")
+    ),
+    responses(
+        (status = 200, description = "Payout retrieved", body = PayoutResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsRetrieve))]
-// #[get("/retrieve")]
-pub async fn payouts_retrieve() -> impl Responder {
-    let _flow = Flow::PayoutsRetrieve;
-    http_response("retrieve")
-}
-
-#[instrument(skip_all, fields(flow = ?Flow::PayoutsUpdate))]
-// #[post("/update")]
-pub async fn payouts_update() -> impl Responder {
-    let _flow = Flow::PayoutsUpdate;
-    http_response("update")
-}
-
-#[instrument(skip_all, fields(flow = ?Flow::PayoutsReverse))]
-// #[post("/reverse")]
-pub async fn payouts_reverse() -> impl Responder {
-    let _flow = Flow::PayoutsReverse;
-    http_response("reverse")
+pub async fn payouts_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query_payload: web::Query<api_types::PayoutRetrieveBody>,
+) -> impl Responder {
+    let flow = Flow::PayoutsRetrieve;
+    let payload = api_types::PayoutRetrieveRequest {
+        payout_id: path.into_inner(),
+        force_sync: query_payload.force_sync.unwrap_or(false),
+    };
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        payouts::retrieve_payout,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+/// Payouts - Cancel
+///
+/// Cancel a payout which has not been paid out yet.
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/cancel",
+    params(("payout_id" = String, Path, description = "The identifier for the payout")),
+    responses(
+        (status = 200, description = "Payout cancelled", body = PayoutResponse),
+        (status = 400, description = "Payout cannot be cancelled"),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Cancel a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsCancel))]
-// #[post("/cancel")]
-pub async fn payouts_cancel() -> impl Responder {
-    let _flow = Flow::PayoutsCancel;
-    http_response("cancel")
-}
-
-#[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
-// #[get("/accounts")]
-pub async fn payouts_accounts() -> impl Responder {
-    let _flow = Flow::PayoutsAccounts;
-    http_response("accounts")
-}
-
-fn http_response<T: MessageBody + 'static>(response: T) -> HttpResponse<BoxBody> {
-    HttpResponse::Ok().body(response)
+pub async fn payouts_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PayoutsCancel;
+    let payload = api_types::PayoutId {
+        payout_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        payouts::cancel_payout,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
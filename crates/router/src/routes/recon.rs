@@ -0,0 +1,138 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::recon,
+    services::{api, authentication as auth, authorization::Permission},
+    types::api as api_types,
+};
+
+/// Recon - Ingest Settlement Report
+///
+/// Ingest a settlement report of a connector, either as the CSV file provided by the connector or
+/// as a list of settled transactions. Each settled transaction is matched to the payment processed
+/// with its connector transaction ID, and flagged if the payment is missing or if the settled
+/// amount or currency differ from the payment.
+#[utoipa::path(
+    post,
+    path = "/recon/settlement_reports",
+    request_body = SettlementReportRequest,
+    responses(
+        (status = 200, description = "Settlement report ingested", body = SettlementReportResponse),
+        (status = 400, description = "Invalid settlement report")
+    ),
+    tag = "Recon",
+    operation_id = "Ingest a Settlement Report",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SettlementReportIngest))]
+pub async fn settlement_report_ingest(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::SettlementReportRequest>,
+) -> impl Responder {
+    let flow = Flow::SettlementReportIngest;
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| {
+            recon::ingest_settlement_report(&*state.store, merchant_account, req)
+        },
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(
+                api_types::ApiKeyScope::PaymentsWrite,
+            )),
+            req.headers(),
+            Permission::PaymentWrite,
+        ),
+    )
+    .await
+}
+
+/// Recon - Retrieve Settlement Report
+///
+/// Retrieve a settlement report, along with the reconciliation of each of its settled
+/// transactions.
+#[utoipa::path(
+    get,
+    path = "/recon/settlement_reports/{report_id}",
+    params(("report_id" = String, Path, description = "The identifier for the settlement report")),
+    responses(
+        (status = 200, description = "Settlement report retrieved", body = SettlementReportResponse),
+        (status = 404, description = "Settlement report not found")
+    ),
+    tag = "Recon",
+    operation_id = "Retrieve a Settlement Report",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SettlementReportRetrieve))]
+pub async fn settlement_report_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::SettlementReportRetrieve;
+    let report_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        &report_id,
+        |state, merchant_account, report_id| {
+            recon::retrieve_settlement_report(&*state.store, merchant_account, report_id)
+        },
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsRead)),
+            req.headers(),
+            Permission::PaymentRead,
+        ),
+    )
+    .await
+}
+
+/// Recon - Retrieve Payment Recon Status
+///
+/// Retrieve the reconciliation status of a payment, along with the latest settlement of each of
+/// its transactions.
+#[utoipa::path(
+    get,
+    path = "/recon/payments/{payment_id}",
+    params(("payment_id" = String, Path, description = "The identifier for the payment")),
+    responses(
+        (status = 200, description = "Recon status of the payment retrieved", body = PaymentReconResponse),
+        (status = 404, description = "Payment not found")
+    ),
+    tag = "Recon",
+    operation_id = "Retrieve the Recon Status of a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentReconRetrieve))]
+pub async fn payment_recon_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentReconRetrieve;
+    let payment_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        &payment_id,
+        |state, merchant_account, payment_id| {
+            recon::retrieve_payment_recon(&*state.store, merchant_account, payment_id)
+        },
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsRead)),
+            req.headers(),
+            Permission::PaymentRead,
+        ),
+    )
+    .await
+}
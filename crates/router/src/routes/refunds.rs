@@ -4,8 +4,8 @@ use router_env::{instrument, tracing, Flow};
 use super::app::AppState;
 use crate::{
     core::refunds::*,
-    services::{api, authentication as auth},
-    types::api::refunds,
+    services::{api, authentication as auth, authorization::Permission},
+    types::api::{self as api_types, refunds},
 };
 
 /// Refunds - Create
@@ -37,7 +37,11 @@ pub async fn refunds_create(
         &req,
         json_payload.into_inner(),
         refund_create_core,
-        &auth::ApiKeyAuth,
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(api_types::ApiKeyScope::RefundsWrite)),
+            req.headers(),
+            Permission::RefundWrite,
+        ),
     )
     .await
 }
@@ -85,7 +89,11 @@ pub async fn refunds_retrieve(
                 refund_retrieve_core,
             )
         },
-        &auth::ApiKeyAuth,
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsRead)),
+            req.headers(),
+            Permission::RefundRead,
+        ),
     )
     .await
 }
@@ -120,7 +128,11 @@ pub async fn refunds_retrieve_with_body(
         |state, merchant_account, req| {
             refund_response_wrapper(state, merchant_account, req, refund_retrieve_core)
         },
-        &auth::ApiKeyAuth,
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsRead)),
+            req.headers(),
+            Permission::RefundRead,
+        ),
     )
     .await
 }
@@ -161,7 +173,11 @@ pub async fn refunds_update(
         |state, merchant_account, req| {
             refund_update_core(&*state.store, merchant_account, &refund_id, req)
         },
-        &auth::ApiKeyAuth,
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(api_types::ApiKeyScope::RefundsWrite)),
+            req.headers(),
+            Permission::RefundWrite,
+        ),
     )
     .await
 }
@@ -204,7 +220,55 @@ pub async fn refunds_list(
         &req,
         payload.into_inner(),
         |state, merchant_account, req| refund_list(&*state.store, merchant_account, req),
-        &auth::ApiKeyAuth,
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsRead)),
+            req.headers(),
+            Permission::RefundRead,
+        ),
+    )
+    .await
+}
+
+/// Refunds - List by Payment
+///
+/// To list all the refunds created against a payment
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/refunds",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for the payment")
+    ),
+    responses(
+        (status = 200, description = "List of refunds of the payment", body = RefundListResponse),
+        (status = 404, description = "Payment does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "List all Refunds of a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsList))]
+#[cfg(feature = "olap")]
+// #[get("/{payment_id}/refunds")]
+pub async fn refunds_list_by_payment_id(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsList;
+    let payment_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payment_id,
+        |state, merchant_account, payment_id| {
+            refund_list_by_payment_id(&*state.store, merchant_account, payment_id)
+        },
+        &*auth::dashboard_auth_or(
+            Box::new(auth::ScopedApiKeyAuth(api_types::ApiKeyScope::PaymentsRead)),
+            req.headers(),
+            Permission::RefundRead,
+        ),
     )
     .await
 }
@@ -0,0 +1,191 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::routing,
+    services::{api, authentication as auth},
+    types::api as api_types,
+};
+
+/// Routing - Create
+///
+/// Create a routing configuration, made of an ordered list of rules which decide the connector a
+/// payment is routed through. The configuration is not used for routing payments until it is
+/// activated.
+#[utoipa::path(
+    post,
+    path = "/routing",
+    request_body = RoutingConfigRequest,
+    responses(
+        (status = 200, description = "Routing config created", body = RoutingConfigResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Routing",
+    operation_id = "Create a Routing Config",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RoutingConfigCreate))]
+pub async fn routing_config_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::RoutingConfigRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingConfigCreate;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, payload| {
+            routing::create_routing_config(&*state.store, merchant_account, payload)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Routing - Retrieve
+///
+/// Retrieve a routing configuration.
+#[utoipa::path(
+    get,
+    path = "/routing/{routing_config_id}",
+    params(("routing_config_id" = String, Path, description = "The identifier for the routing config")),
+    responses(
+        (status = 200, description = "Routing config retrieved", body = RoutingConfigResponse),
+        (status = 404, description = "Routing config not found")
+    ),
+    tag = "Routing",
+    operation_id = "Retrieve a Routing Config",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RoutingConfigRetrieve))]
+pub async fn routing_config_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::RoutingConfigRetrieve;
+    let routing_config_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        &routing_config_id,
+        |state, merchant_account, routing_config_id| {
+            routing::retrieve_routing_config(&*state.store, merchant_account, routing_config_id)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Routing - List
+///
+/// List all the routing configurations of the merchant.
+#[utoipa::path(
+    get,
+    path = "/routing/list",
+    responses(
+        (status = 200, description = "List of routing configs", body = Vec<RoutingConfigResponse>),
+    ),
+    tag = "Routing",
+    operation_id = "List all Routing Configs",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RoutingConfigList))]
+pub async fn routing_config_list(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::RoutingConfigList;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (),
+        |state, merchant_account, _| routing::list_routing_configs(&*state.store, merchant_account),
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Routing - Activate
+///
+/// Activate a routing configuration. All subsequent payments of the merchant are routed as per
+/// its rules, and the routing configuration which was active until now is deactivated.
+#[utoipa::path(
+    post,
+    path = "/routing/{routing_config_id}/activate",
+    params(("routing_config_id" = String, Path, description = "The identifier for the routing config")),
+    responses(
+        (status = 200, description = "Routing config activated", body = RoutingConfigResponse),
+        (status = 404, description = "Routing config not found")
+    ),
+    tag = "Routing",
+    operation_id = "Activate a Routing Config",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RoutingConfigActivate))]
+pub async fn routing_config_activate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::RoutingConfigActivate;
+    let routing_config_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        &routing_config_id,
+        |state, merchant_account, routing_config_id| {
+            routing::activate_routing_config(&*state.store, merchant_account, routing_config_id)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Routing - Evaluate
+///
+/// Evaluate a routing configuration against the attributes of a payment, and return the connector
+/// the payment would be routed through. No payment is created.
+#[utoipa::path(
+    post,
+    path = "/routing/{routing_config_id}/evaluate",
+    params(("routing_config_id" = String, Path, description = "The identifier for the routing config")),
+    request_body = RoutingEvaluateRequest,
+    responses(
+        (status = 200, description = "Routing config evaluated", body = RoutingEvaluateResponse),
+        (status = 404, description = "Routing config not found")
+    ),
+    tag = "Routing",
+    operation_id = "Evaluate a Routing Config",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RoutingConfigEvaluate))]
+pub async fn routing_config_evaluate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_types::RoutingEvaluateRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingConfigEvaluate;
+    let routing_config_id = path.into_inner();
+    let payload = json_payload.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (&routing_config_id, payload),
+        |state, merchant_account, (routing_config_id, payload)| {
+            routing::evaluate_routing_config(
+                &*state.store,
+                merchant_account,
+                routing_config_id,
+                payload,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
@@ -0,0 +1,41 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::secrets,
+    services::{api, authentication as auth},
+};
+
+/// Connector Credentials - Rotate Key
+///
+/// Rotate the data key the connector credentials of merchants are encrypted with. The
+/// credentials of every merchant connector account are re-encrypted with the new data key, while
+/// remaining usable by payments in the meantime.
+#[utoipa::path(
+    post,
+    path = "/secrets/connector_credentials/rotate_key",
+    responses(
+        (status = 200, description = "Data key rotated", body = ConnectorCredentialsKeyRotateResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Rotate the Connector Credentials Key",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorCredentialsKeyRotate))]
+pub async fn connector_credentials_key_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::ConnectorCredentialsKeyRotate;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (),
+        |state, _, _| secrets::rotate_connector_credentials_key(state),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
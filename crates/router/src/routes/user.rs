@@ -0,0 +1,180 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::user,
+    services::{api, authentication as auth, authorization::Permission},
+    types::api as api_types,
+};
+
+/// User - Sign Up
+///
+/// Create a dashboard user. The user can access a merchant account only after they are given a
+/// role for it.
+#[utoipa::path(
+    post,
+    path = "/user/signup",
+    request_body = SignUpRequest,
+    responses(
+        (status = 200, description = "User created", body = AuthTokenResponse),
+        (status = 400, description = "User with the email already exists")
+    ),
+    tag = "User",
+    operation_id = "Sign up a User"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UserSignUp))]
+pub async fn user_signup(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::SignUpRequest>,
+) -> impl Responder {
+    let flow = Flow::UserSignUp;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| user::signup(state, req),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+/// User - Sign In
+///
+/// Sign in a dashboard user, issuing an access token and a refresh token for a merchant account
+/// the user has a role for.
+#[utoipa::path(
+    post,
+    path = "/user/signin",
+    request_body = SignInRequest,
+    responses(
+        (status = 200, description = "User signed in", body = AuthTokenResponse),
+        (status = 401, description = "Incorrect email or password")
+    ),
+    tag = "User",
+    operation_id = "Sign in a User"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UserSignIn))]
+pub async fn user_signin(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::SignInRequest>,
+) -> impl Responder {
+    let flow = Flow::UserSignIn;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| user::signin(state, req),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+/// User - Refresh Token
+///
+/// Obtain new tokens for a signed in dashboard user, using the refresh token issued to them.
+#[utoipa::path(
+    post,
+    path = "/user/refresh_token",
+    request_body = RefreshTokenRequest,
+    responses(
+        (status = 200, description = "Tokens issued", body = AuthTokenResponse),
+        (status = 401, description = "Invalid refresh token")
+    ),
+    tag = "User",
+    operation_id = "Refresh the tokens of a User"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UserRefreshToken))]
+pub async fn user_refresh_token(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::RefreshTokenRequest>,
+) -> impl Responder {
+    let flow = Flow::UserRefreshToken;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| user::refresh_token(state, req),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+/// User - Set Role
+///
+/// Give a dashboard user a role for the merchant account the user making the request is signed in
+/// to, which requires the permission to manage users.
+#[utoipa::path(
+    post,
+    path = "/user/role",
+    request_body = UserRoleRequest,
+    responses(
+        (status = 200, description = "Role set", body = UserRoleResponse),
+        (status = 403, description = "User making the request cannot manage users of the merchant account"),
+        (status = 404, description = "User does not exist in our records")
+    ),
+    tag = "User",
+    operation_id = "Set the role of a User"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UserRoleSet))]
+pub async fn user_role_set(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::UserRoleRequest>,
+) -> impl Responder {
+    let flow = Flow::UserRoleSet;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, user_from_token: auth::UserFromToken, req| {
+            user::set_user_role(state, user_from_token.merchant_id, req)
+        },
+        &auth::DashboardAuth(Permission::UsersWrite),
+    )
+    .await
+}
+
+/// User - Set Role for Merchant
+///
+/// Give a dashboard user a role for the specified merchant account, such as the first admin of a
+/// newly created merchant account.
+#[utoipa::path(
+    post,
+    path = "/user/{merchant_id}/role",
+    params(("merchant_id" = String, Path, description = "The unique identifier for the merchant account")),
+    request_body = UserRoleRequest,
+    responses(
+        (status = 200, description = "Role set", body = UserRoleResponse),
+        (status = 404, description = "User does not exist in our records")
+    ),
+    tag = "User",
+    operation_id = "Set the role of a User for a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UserRoleSet))]
+pub async fn user_role_set_for_merchant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_types::UserRoleRequest>,
+) -> impl Responder {
+    let flow = Flow::UserRoleSet;
+    let merchant_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| user::set_user_role(state, merchant_id.clone(), req),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
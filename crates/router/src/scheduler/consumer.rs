@@ -214,7 +214,7 @@ pub async fn run_executor<'a>(
             Err(error) => {
                 logger::error!(%error, "Failed while handling error");
                 let status = process
-                    .finish_with_status(&*state.store, "GLOBAL_FAILURE".to_string())
+                    .move_to_dead_letter(&*state.store, "GLOBAL_FAILURE".to_string())
                     .await;
                 if let Err(err) = status {
                     logger::error!(%err, "Failed while performing database operation: GLOBAL_FAILURE");
@@ -233,16 +233,9 @@ pub async fn consumer_error_handler<E: fmt::Display + fmt::Debug>(
 ) -> CustomResult<(), errors::ProcessTrackerError> {
     logger::error!(pt.name = ?process.name, pt.id = %process.id, ?error, "ERROR: Failed while executing workflow");
 
-    let db: &dyn StorageInterface = &*state.store;
-    db.process_tracker_update_process_status_by_ids(
-        vec![process.id],
-        storage::ProcessTrackerUpdate::StatusUpdate {
-            status: enums::ProcessTrackerStatus::Finish,
-            business_status: Some("GLOBAL_ERROR".to_string()),
-        },
-    )
-    .await
-    .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+    process
+        .move_to_dead_letter(&*state.store, "GLOBAL_ERROR".to_string())
+        .await?;
     Ok(())
 }
 
@@ -39,6 +39,49 @@ impl Default for ConnectorPTMapping {
     }
 }
 
+/// The retry policies of the tasks run by the scheduler. The schedule of a policy can be
+/// overridden by storing a `ConnectorPTMapping` in redis under its mapping key.
+#[derive(Clone, Copy, Debug)]
+pub enum RetryPolicy<'a> {
+    PaymentsSync { connector: &'a str },
+    RefundsSync { connector: &'a str },
+    OutgoingWebhook,
+    AutoCapture,
+    PaymentExpiry,
+}
+
+impl RetryPolicy<'_> {
+    pub fn mapping_key(&self) -> String {
+        match self {
+            Self::PaymentsSync { connector } => format!("pt_mapping_{connector}"),
+            Self::RefundsSync { connector } => format!("pt_mapping_refund_sync_{connector}"),
+            Self::OutgoingWebhook => "pt_mapping_outgoing_webhooks".to_string(),
+            Self::AutoCapture => "pt_mapping_auto_capture".to_string(),
+            Self::PaymentExpiry => "pt_mapping_payment_expiry".to_string(),
+        }
+    }
+
+    pub fn default_mapping(&self) -> ConnectorPTMapping {
+        match self {
+            Self::PaymentsSync { .. }
+            | Self::RefundsSync { .. }
+            | Self::AutoCapture
+            | Self::PaymentExpiry => ConnectorPTMapping::default(),
+            // Webhooks are retried with growing intervals for about a day, giving merchants time
+            // to recover from an outage of their endpoint
+            Self::OutgoingWebhook => ConnectorPTMapping {
+                custom_merchant_mapping: HashMap::new(),
+                default_mapping: RetryMapping {
+                    start_after: 60,
+                    frequency: vec![300, 1800, 3600, 21600],
+                    count: vec![2, 2, 2, 3],
+                },
+                max_retries_count: 9,
+            },
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentMethodsPTMapping {
@@ -12,12 +12,14 @@ use super::{consumer, metrics, process_data, workflows};
 use crate::{
     configs::settings::SchedulerSettings,
     core::errors::{self, CustomResult},
+    db::{self, StorageInterface},
     logger,
     routes::AppState,
     scheduler::{ProcessTrackerBatch, SchedulerFlow},
     types::storage::{
         self,
         enums::{self, ProcessTrackerStatus},
+        ProcessTrackerExt,
     },
     utils::{OptionExt, StringExt},
 };
@@ -314,6 +316,49 @@ pub fn get_schedule_time(
     }
 }
 
+/// Returns the time at which a task is to be retried under the retry policy, or `None` once the
+/// task has exhausted its retries.
+pub async fn get_retry_schedule_time(
+    db: &dyn StorageInterface,
+    retry_policy: process_data::RetryPolicy<'_>,
+    merchant_id: &str,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let redis_mapping: CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
+        db::get_and_deserialize_key(db, &retry_policy.mapping_key(), "ConnectorPTMapping").await;
+    let mapping = match redis_mapping {
+        Ok(x) => x,
+        Err(err) => {
+            logger::info!("Redis Mapping Error: {}", err);
+            retry_policy.default_mapping()
+        }
+    };
+    let time_delta = get_schedule_time(mapping, merchant_id, retry_count + 1);
+
+    get_time_from_delta(time_delta)
+}
+
+/// Schedules the next retry of the task under the retry policy, moving the task to the dead letter
+/// queue once it has exhausted its retries.
+pub async fn retry_process(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    retry_policy: process_data::RetryPolicy<'_>,
+    merchant_id: &str,
+) -> Result<(), errors::ProcessTrackerError> {
+    let schedule_time =
+        get_retry_schedule_time(db, retry_policy, merchant_id, process.retry_count).await;
+
+    match schedule_time {
+        Some(s_time) => process.retry(db, s_time).await,
+        None => {
+            process
+                .move_to_dead_letter(db, "RETRIES_EXCEEDED".to_string())
+                .await
+        }
+    }
+}
+
 pub fn get_pm_schedule_time(
     mapping: process_data::PaymentMethodsPTMapping,
     pm: &enums::PaymentMethod,
@@ -4,6 +4,10 @@ use serde::{Deserialize, Serialize};
 use strum::EnumString;
 
 use crate::{core::errors, routes::AppState, scheduler::consumer, types::storage};
+pub mod auto_capture;
+pub mod outgoing_webhook_retry;
+pub mod payment_expiry;
+pub mod payment_schedule;
 pub mod payment_sync;
 pub mod refund_router;
 pub mod tokenized_data;
@@ -46,7 +50,11 @@ macro_rules! as_item {
 runners! {
     PaymentsSyncWorkflow,
     RefundWorkflowRouter,
-    DeleteTokenizeDataWorkflow
+    DeleteTokenizeDataWorkflow,
+    PaymentExpiryWorkflow,
+    PaymentScheduleWorkflow,
+    OutgoingWebhookRetryWorkflow,
+    AutoCaptureWorkflow
 }
 
 #[async_trait]
@@ -0,0 +1,103 @@
+use router_env::logger;
+
+use super::{AutoCaptureWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::payments::{self as payment_flows, operations},
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::{consumer, process_data, utils},
+    types::{
+        api::{self, PaymentIdTypeExt},
+        storage::{self, enums, ProcessTrackerExt},
+    },
+    utils::{OptionExt, ValueExt},
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for AutoCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::PaymentsRetrieveRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentsRetrieveRequest")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                tracking_data
+                    .merchant_id
+                    .as_ref()
+                    .get_required_value("merchant_id")?,
+            )
+            .await?;
+
+        let payment_id = tracking_data.resource_id.get_payment_intent_id()?;
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // Payments which were captured or cancelled since being authorized are left as they are
+        let is_captured = if payment_intent.status == enums::IntentStatus::RequiresCapture {
+            let result = payment_flows::payments_operation_core::<api::Capture, _, _, _>(
+                state,
+                merchant_account.clone(),
+                operations::PaymentCapture,
+                api::PaymentsCaptureRequest {
+                    payment_id: Some(payment_id.clone()),
+                    merchant_id: Some(merchant_account.merchant_id.clone()),
+                    ..Default::default()
+                },
+                payment_flows::CallConnectorAction::Trigger,
+            )
+            .await;
+
+            match result {
+                Ok((payment_data, _, _)) => {
+                    payment_data.payment_intent.status != enums::IntentStatus::RequiresCapture
+                }
+                Err(error) => {
+                    logger::error!(?error, "Failed to capture payment {payment_id}");
+                    false
+                }
+            }
+        } else {
+            true
+        };
+
+        if is_captured {
+            let id = process.id.clone();
+            process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                .await?;
+        } else {
+            // The payment remains authorized when the connector could not be reached, so its
+            // capture is retried
+            utils::retry_process(
+                db,
+                process,
+                process_data::RetryPolicy::AutoCapture,
+                &merchant_account.merchant_id,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
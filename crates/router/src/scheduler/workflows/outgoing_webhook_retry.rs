@@ -0,0 +1,73 @@
+use router_env::logger;
+
+use super::{OutgoingWebhookRetryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::webhooks,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::{consumer, process_data, utils},
+    types::storage::{self, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for OutgoingWebhookRetryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::OutgoingWebhookTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("OutgoingWebhookTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        // The webhook URL is looked up again, so that retries reach the merchant at its current
+        // endpoint
+        let result = async {
+            let webhook_url = webhooks::get_webhook_url(
+                db,
+                &merchant_account,
+                tracking_data.profile_id.as_deref(),
+            )
+            .await?;
+            webhooks::send_webhook_to_merchant(&webhook_url, &tracking_data.request_body).await
+        }
+        .await;
+
+        match result {
+            Ok(()) => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await?
+            }
+            Err(error) => {
+                logger::warn!(?error, event_id = %tracking_data.event_id, "Failed to deliver the webhook");
+                utils::retry_process(
+                    db,
+                    process,
+                    process_data::RetryPolicy::OutgoingWebhook,
+                    &tracking_data.merchant_id,
+                )
+                .await?
+            }
+        };
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -0,0 +1,172 @@
+use router_env::logger;
+
+use super::{PaymentExpiryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::payments::{self as payment_flows, operations, payment_events, state_machine},
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::{consumer, process_data, utils},
+    types::{
+        api::{self, PaymentIdTypeExt},
+        storage::{self, enums, ProcessTrackerExt},
+    },
+    utils::{OptionExt, ValueExt},
+};
+
+/// Cancellation reason recorded on authorizations voided due to payment expiry.
+const PAYMENT_EXPIRED_CANCELLATION_REASON: &str = "payment_expired";
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for PaymentExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::PaymentsRetrieveRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentsRetrieveRequest")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                tracking_data
+                    .merchant_id
+                    .as_ref()
+                    .get_required_value("merchant_id")?,
+            )
+            .await?;
+
+        let payment_id = tracking_data.resource_id.get_payment_intent_id()?;
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // A payment whose authorization is voided as it expires is cancelled by the void, and is
+        // expired from the status it had before the void
+        let status_before_expiry = payment_intent.status;
+        let expirable_payment_intent = match payment_intent.status {
+            // Uncaptured authorizations are released at the connector before the payment expires,
+            // and payments held for review are rejected, which releases the authorization as well
+            enums::IntentStatus::RequiresCapture | enums::IntentStatus::RequiresMerchantAction => {
+                let request = api::PaymentsCancelRequest {
+                    payment_id: payment_id.clone(),
+                    cancellation_reason: Some(PAYMENT_EXPIRED_CANCELLATION_REASON.to_string()),
+                    ..Default::default()
+                };
+                let result = if payment_intent.status == enums::IntentStatus::RequiresCapture {
+                    payment_flows::payments_operation_core::<api::Void, _, _, _>(
+                        state,
+                        merchant_account.clone(),
+                        operations::PaymentCancel,
+                        request,
+                        payment_flows::CallConnectorAction::Trigger,
+                    )
+                    .await
+                } else {
+                    payment_flows::payments_operation_core::<api::Void, _, _, _>(
+                        state,
+                        merchant_account.clone(),
+                        operations::PaymentReject,
+                        request,
+                        payment_flows::CallConnectorAction::Trigger,
+                    )
+                    .await
+                };
+
+                match result {
+                    Ok((payment_data, _, _))
+                        if payment_data.payment_attempt.status == enums::AttemptStatus::Voided =>
+                    {
+                        Some(payment_data.payment_intent)
+                    }
+                    outcome => {
+                        match outcome {
+                            Ok((payment_data, _, _)) => logger::warn!(
+                                attempt_status = ?payment_data.payment_attempt.status,
+                                "Authorization of payment {payment_id} was not voided"
+                            ),
+                            Err(error) => logger::error!(
+                                ?error,
+                                "Failed to void authorization of payment {payment_id}"
+                            ),
+                        }
+                        // The authorization is still held by the connector, so the payment is not
+                        // expired and the void is retried
+                        return utils::retry_process(
+                            db,
+                            process,
+                            process_data::RetryPolicy::PaymentExpiry,
+                            &merchant_account.merchant_id,
+                        )
+                        .await;
+                    }
+                }
+            }
+            enums::IntentStatus::RequiresCustomerAction
+            | enums::IntentStatus::RequiresPaymentMethod
+            | enums::IntentStatus::RequiresConfirmation => Some(payment_intent),
+            enums::IntentStatus::Succeeded
+            | enums::IntentStatus::Failed
+            | enums::IntentStatus::Cancelled
+            | enums::IntentStatus::Expired
+            | enums::IntentStatus::Processing => None,
+        };
+
+        if let Some(payment_intent) = expirable_payment_intent {
+            let payment_attempt = db
+                .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                    &payment_id,
+                    &merchant_account.merchant_id,
+                    &payment_intent.active_attempt_id,
+                    merchant_account.storage_scheme,
+                )
+                .await?;
+            state_machine::validate_intent_status_transition(
+                status_before_expiry,
+                enums::IntentStatus::Expired,
+            )?;
+            let previous_statuses =
+                payment_events::PaymentStatuses::of(&payment_intent, &payment_attempt);
+            let payment_intent = db
+                .update_payment_intent(
+                    payment_intent,
+                    storage::PaymentIntentUpdate::PGStatusUpdate {
+                        status: enums::IntentStatus::Expired,
+                    },
+                    merchant_account.storage_scheme,
+                )
+                .await?;
+            payment_events::record_status_change(
+                state,
+                "PaymentExpiry",
+                previous_statuses,
+                &payment_intent,
+                &payment_attempt,
+            )
+            .await;
+            logger::info!("Payment {payment_id} expired");
+        }
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
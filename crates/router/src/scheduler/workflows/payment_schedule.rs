@@ -0,0 +1,24 @@
+use super::{PaymentScheduleWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::payment_schedules, errors, routes::AppState, scheduler::consumer, types::storage,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for PaymentScheduleWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        payment_schedules::start_payment_schedule_workflow(state, process).await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
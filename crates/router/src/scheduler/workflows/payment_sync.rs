@@ -1,9 +1,7 @@
-use router_env::logger;
-
 use super::{PaymentsSyncWorkflow, ProcessTrackerWorkflow};
 use crate::{
     core::payments::{self as payment_flows, operations},
-    db::{get_and_deserialize_key, StorageInterface},
+    db::StorageInterface,
     errors,
     routes::AppState,
     scheduler::{consumer, process_data, utils},
@@ -48,6 +46,7 @@ impl ProcessTrackerWorkflow for PaymentsSyncWorkflow {
         let terminal_status = vec![
             enums::AttemptStatus::RouterDeclined,
             enums::AttemptStatus::Charged,
+            enums::AttemptStatus::PartiallyCaptured,
             enums::AttemptStatus::AutoRefunded,
             enums::AttemptStatus::Voided,
             enums::AttemptStatus::VoidFailed,
@@ -68,11 +67,13 @@ impl ProcessTrackerWorkflow for PaymentsSyncWorkflow {
                     .map_err(errors::ProcessTrackerError::EParsingError)?
                     .ok_or(errors::ProcessTrackerError::MissingRequiredField)?;
 
-                retry_sync_task(
+                utils::retry_process(
                     db,
-                    connector,
-                    payment_data.payment_attempt.merchant_id,
                     process,
+                    process_data::RetryPolicy::PaymentsSync {
+                        connector: &connector,
+                    },
+                    &payment_data.payment_attempt.merchant_id,
                 )
                 .await?
             }
@@ -90,44 +91,6 @@ impl ProcessTrackerWorkflow for PaymentsSyncWorkflow {
     }
 }
 
-pub async fn get_sync_process_schedule_time(
-    db: &dyn StorageInterface,
-    connector: &str,
-    merchant_id: &str,
-    retry_count: i32,
-) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
-    let redis_mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
-        get_and_deserialize_key(db, &format!("pt_mapping_{connector}"), "ConnectorPTMapping").await;
-    let mapping = match redis_mapping {
-        Ok(x) => x,
-        Err(err) => {
-            logger::info!("Redis Mapping Error: {}", err);
-            process_data::ConnectorPTMapping::default()
-        }
-    };
-    let time_delta = utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
-
-    Ok(utils::get_time_from_delta(time_delta))
-}
-
-pub async fn retry_sync_task(
-    db: &dyn StorageInterface,
-    connector: String,
-    merchant_id: String,
-    pt: storage::ProcessTracker,
-) -> Result<(), errors::ProcessTrackerError> {
-    let schedule_time =
-        get_sync_process_schedule_time(db, &connector, &merchant_id, pt.retry_count).await?;
-
-    match schedule_time {
-        Some(s_time) => pt.retry(db, s_time).await,
-        None => {
-            pt.finish_with_status(db, "RETRIES_EXCEEDED".to_string())
-                .await
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used, clippy::unwrap_used)]
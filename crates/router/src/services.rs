@@ -1,6 +1,11 @@
 pub mod api;
 pub mod authentication;
+pub mod authentication_connector;
+pub mod authorization;
+pub mod background_tasks;
 pub mod encryption;
+pub mod forex_provider;
+pub mod fraud_check_connector;
 pub mod logger;
 
 use std::sync::{atomic, Arc};
@@ -12,8 +17,8 @@ use redis_interface::{errors as redis_errors, PubsubInterface};
 pub use self::{api::*, encryption::*};
 use crate::{
     async_spawn,
-    cache::CONFIG_CACHE,
-    connection::{diesel_make_pg_pool, PgPool},
+    cache::{ACCOUNTS_CACHE, CONFIG_CACHE},
+    connection::{diesel_make_pg_pool, monitor_replica_lag, PgPool},
     consts,
     core::errors,
 };
@@ -67,6 +72,7 @@ impl PubSubInterface for redis_interface::RedisConnectionPool {
 
             self.delete_key(&key).await?;
             CONFIG_CACHE.invalidate(&key).await;
+            ACCOUNTS_CACHE.invalidate(&key).await;
         }
         Ok(())
     }
@@ -75,8 +81,8 @@ impl PubSubInterface for redis_interface::RedisConnectionPool {
 #[derive(Clone)]
 pub struct Store {
     pub master_pool: PgPool,
-    #[cfg(feature = "olap")]
-    pub replica_pool: PgPool,
+    pub replica_pool: Option<PgPool>,
+    pub is_replica_available: Arc<atomic::AtomicBool>,
     pub redis_conn: Arc<redis_interface::RedisConnectionPool>,
     #[cfg(feature = "kv_store")]
     pub(crate) config: StoreConfig,
@@ -108,6 +114,29 @@ impl Store {
             redis_clone.on_error().await;
         });
 
+        let replica_pool = if config.replica_routing.enabled {
+            Some(
+                diesel_make_pg_pool(
+                    &config.replica_database,
+                    test_transaction,
+                    #[cfg(feature = "kms")]
+                    &config.kms,
+                )
+                .await,
+            )
+        } else {
+            None
+        };
+        let is_replica_available = Arc::new(atomic::AtomicBool::new(true));
+
+        if let Some(replica_pool) = replica_pool.clone() {
+            let replica_routing = config.replica_routing.clone();
+            let is_replica_available = is_replica_available.clone();
+            async_spawn!({
+                monitor_replica_lag(replica_pool, replica_routing, is_replica_available).await;
+            });
+        }
+
         Self {
             master_pool: diesel_make_pg_pool(
                 &config.master_database,
@@ -116,14 +145,8 @@ impl Store {
                 &config.kms,
             )
             .await,
-            #[cfg(feature = "olap")]
-            replica_pool: diesel_make_pg_pool(
-                &config.replica_database,
-                test_transaction,
-                #[cfg(feature = "kms")]
-                &config.kms,
-            )
-            .await,
+            replica_pool,
+            is_replica_available,
             redis_conn,
             #[cfg(feature = "kv_store")]
             config: StoreConfig {
@@ -176,4 +199,50 @@ impl Store {
             .await
             .change_context(crate::core::errors::StorageError::KVError)
     }
+
+    /// Sets the field of a hash holding a versioned row to its updated value, only if the field
+    /// still holds the row at `version`, failing with a `VersionConflict` error otherwise. Rows
+    /// which are not in Redis yet are set unconditionally, and are checked when drained.
+    #[cfg(feature = "kv_store")]
+    pub(crate) async fn set_versioned_hash_field(
+        &self,
+        key: &str,
+        field: &str,
+        version: i32,
+        value: String,
+    ) -> crate::core::errors::CustomResult<(), crate::core::errors::StorageError> {
+        let updated: i64 = self
+            .redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .evaluate_script(
+                SET_VERSIONED_HASH_FIELD_SCRIPT,
+                vec![key.to_owned()],
+                vec![field.to_owned(), version.to_string(), value],
+            )
+            .await
+            .change_context(errors::StorageError::KVError)?;
+
+        if updated == 1 {
+            Ok(())
+        } else {
+            Err(errors::StorageError::DatabaseError(error_stack::report!(
+                storage_models::errors::DatabaseError::VersionConflict
+            )))
+            .into_report()
+        }
+    }
 }
+
+#[cfg(feature = "kv_store")]
+const SET_VERSIONED_HASH_FIELD_SCRIPT: &str = r#"
+local current = redis.call("HGET", KEYS[1], ARGV[1])
+if current then
+    local version = cjson.decode(current)["version"] or 0
+    if version ~= tonumber(ARGV[2]) then
+        return 0
+    end
+end
+
+redis.call("HSET", KEYS[1], ARGV[1], ARGV[3])
+return 1
+"#;
@@ -1,3 +1,4 @@
+pub(crate) mod circuit_breaker;
 mod client;
 pub(crate) mod request;
 
@@ -9,17 +10,18 @@ use std::{
     time::{Duration, Instant},
 };
 
-use actix_web::{body, HttpRequest, HttpResponse, Responder};
-use common_utils::errors::ReportSwitchExt;
+use actix_web::{body, http::header, HttpRequest, HttpResponse, Responder};
+use common_utils::errors::{ErrorSwitch, ReportSwitchExt};
 use error_stack::{report, IntoReport, Report, ResultExt};
-use masking::ExposeOptionInterface;
+use masking::{ExposeOptionInterface, PeekInterface};
 use router_env::{instrument, tracing, Tag};
 use serde::Serialize;
 
 use self::request::{ContentType, HeaderExt, RequestBuilderExt};
 pub use self::request::{Method, Request, RequestBuilder};
 use crate::{
-    configs::settings::Connectors,
+    configs::settings::{Connectors, LogVerbosity},
+    consts,
     core::{
         errors::{self, CustomResult},
         payments,
@@ -28,6 +30,7 @@ use crate::{
     routes::{app::AppStateInfo, metrics, AppState},
     services::authentication as auth,
     types::{self, api, storage, ErrorResponse},
+    utils,
 };
 
 pub type BoxedConnectorIntegration<'a, T, Req, Resp> =
@@ -151,7 +154,22 @@ pub trait ConnectorIntegration<T, Req, Resp>: ConnectorIntegrationAny<T, Req, Re
     }
 }
 
-#[instrument(skip_all)]
+/// The name of the connector flow, such as `Authorize`, without the path of its module.
+fn get_flow_name<T>() -> &'static str {
+    std::any::type_name::<T>()
+        .split("::")
+        .last()
+        .unwrap_or_default()
+}
+
+#[instrument(
+    skip_all,
+    fields(
+        connector_name = %req.connector,
+        flow = get_flow_name::<T>(),
+        attempt_id = %req.attempt_id,
+    )
+)]
 pub async fn execute_connector_processing_step<
     'b,
     'a,
@@ -177,8 +195,14 @@ where
                 response: res.into(),
                 status_code: 200,
             };
+            let connector_raw_response = get_connector_raw_response(&response);
 
-            connector_integration.handle_response(req, response)
+            connector_integration
+                .handle_response(req, response)
+                .map(|mut router_data| {
+                    router_data.connector_raw_response = connector_raw_response;
+                    router_data
+                })
         }
         payments::CallConnectorAction::Avoid => Ok(router_data),
         payments::CallConnectorAction::StatusUpdate(status) => {
@@ -186,21 +210,11 @@ where
             Ok(router_data)
         }
         payments::CallConnectorAction::Trigger => {
-            metrics::CONNECTOR_CALL_COUNT.add(
-                &metrics::CONTEXT,
-                1,
-                &[
-                    metrics::request::add_attributes("connector", req.connector.to_string()),
-                    metrics::request::add_attributes(
-                        "flow",
-                        std::any::type_name::<T>()
-                            .split("::")
-                            .last()
-                            .unwrap_or_default()
-                            .to_string(),
-                    ),
-                ],
-            );
+            let metric_attributes = [
+                metrics::request::add_attributes("connector", req.connector.to_string()),
+                metrics::request::add_attributes("flow", get_flow_name::<T>().to_string()),
+            ];
+            metrics::CONNECTOR_CALL_COUNT.add(&metrics::CONTEXT, 1, &metric_attributes);
             match connector_integration
                 .build_request(req, &state.conf.connectors)
                 .map_err(|error| {
@@ -220,13 +234,65 @@ where
                     }
                     error
                 })? {
-                Some(request) => {
-                    logger::debug!(connector_request=?request);
-                    let response = call_connector_api(state, request).await;
-                    logger::debug!(connector_response=?response);
+                Some(mut request) => {
+                    // Connectors which support tracing continue the trace of the payment
+                    request
+                        .headers
+                        .extend(router_env::get_trace_context_headers());
+
+                    let circuit_state = match circuit_breaker::acquire_circuit(
+                        state,
+                        &req.merchant_id,
+                        &req.connector,
+                    )
+                    .await
+                    {
+                        Some(circuit_state) => circuit_state,
+                        None => {
+                            logger::warn!("Short circuiting the call to {}", req.connector);
+                            return Err(report!(errors::ConnectorError::ConnectorUnavailable {
+                                connector: req.connector.clone(),
+                            }));
+                        }
+                    };
+
+                    let debug_mode =
+                        payments::connector_logs::is_connector_debug_mode(&*state.store, req).await;
+                    let connector_request_log =
+                        ConnectorRequestLog::new(state, &req.connector, &request, debug_mode);
+                    let (response, request_time) =
+                        metrics::utils::time_future(call_connector_api(state, request)).await;
+                    metrics::CONNECTOR_REQUEST_TIME.record(
+                        &metrics::CONTEXT,
+                        request_time.as_secs_f64(),
+                        &metric_attributes,
+                    );
+                    connector_request_log.log(&response);
+                    if debug_mode {
+                        payments::connector_logs::store_connector_log(
+                            &*state.store,
+                            connector_request_log.into_connector_log(
+                                req,
+                                get_flow_name::<T>(),
+                                &response,
+                            ),
+                        )
+                        .await;
+                    }
+                    circuit_breaker::record_connector_response(
+                        state,
+                        &req.merchant_id,
+                        &req.connector,
+                        circuit_state,
+                        &response,
+                    )
+                    .await;
                     match response {
                         Ok(body) => {
-                            let response = match body {
+                            let connector_raw_response = match body {
+                                Ok(ref body) | Err(ref body) => get_connector_raw_response(body),
+                            };
+                            let mut response = match body {
                                 Ok(body) => connector_integration
                                     .handle_response(req, body)
                                     .map_err(|error| {
@@ -259,6 +325,7 @@ where
                                     router_data
                                 }
                             };
+                            response.connector_raw_response = connector_raw_response;
                             Ok(response)
                         }
                         Err(error) => Err(error
@@ -271,6 +338,114 @@ where
     }
 }
 
+/// Raw response of the connector, which is kept as a string if it is not a JSON document.
+fn get_connector_raw_response(response: &types::Response) -> Option<serde_json::Value> {
+    (!response.response.is_empty()).then(|| {
+        serde_json::from_slice(&response.response).unwrap_or_else(|_| {
+            serde_json::Value::String(String::from_utf8_lossy(&response.response).into_owned())
+        })
+    })
+}
+
+/// A request made to a connector, which is logged along with its response at the verbosity
+/// configured for connector requests, and stored if the payment is in debug mode.
+struct ConnectorRequestLog {
+    verbosity: LogVerbosity,
+    connector: String,
+    method: Method,
+    url: String,
+    request_body: Option<String>,
+    start_instant: Instant,
+}
+
+impl ConnectorRequestLog {
+    fn new(state: &AppState, connector: &str, request: &Request, debug_mode: bool) -> Self {
+        let verbosity = state.conf.request_log.connector_verbosity;
+        let request_body = (verbosity == LogVerbosity::Full || debug_mode)
+            .then(|| get_masked_request_body(request))
+            .flatten();
+
+        Self {
+            verbosity,
+            connector: connector.to_string(),
+            method: request.method,
+            url: request.url.clone(),
+            request_body,
+            start_instant: Instant::now(),
+        }
+    }
+
+    fn log(
+        &self,
+        response: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+    ) {
+        if self.verbosity == LogVerbosity::Off {
+            return;
+        }
+
+        let is_full = self.verbosity == LogVerbosity::Full;
+        let response = get_connector_response(response);
+        let request_body = self.request_body.as_deref().filter(|_| is_full);
+        let response_body = response
+            .filter(|_| is_full)
+            .map(|response| super::logger::mask_body(&response.response));
+
+        logger::info!(
+            tag = ?Tag::ApiOutgoingRequest,
+            connector = %self.connector,
+            method = %self.method,
+            url = %self.url,
+            status_code = response.map(|response| response.status_code),
+            time_taken_ms = self.start_instant.elapsed().as_millis(),
+            request_body,
+            response_body = response_body.as_deref(),
+        );
+    }
+
+    fn into_connector_log<F, Req, Resp>(
+        self,
+        router_data: &types::RouterData<F, Req, Resp>,
+        flow: &str,
+        response: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+    ) -> storage::ConnectorLogNew {
+        let response = get_connector_response(response);
+
+        storage::ConnectorLogNew {
+            log_id: utils::generate_id(consts::ID_LENGTH, "clog"),
+            merchant_id: router_data.merchant_id.clone(),
+            payment_id: router_data.payment_id.clone(),
+            attempt_id: router_data.attempt_id.clone(),
+            connector: self.connector,
+            flow: flow.to_string(),
+            method: self.method.to_string(),
+            url: self.url,
+            request_body: self.request_body,
+            response_body: response.map(|response| super::logger::mask_body(&response.response)),
+            status_code: response.map(|response| i32::from(response.status_code)),
+            created_at: None,
+        }
+    }
+}
+
+/// The body of a request to a connector with the card and personal data masked, as it is logged
+/// and stored in the connector logs.
+pub fn get_masked_request_body(request: &Request) -> Option<String> {
+    request
+        .payload
+        .as_ref()
+        .map(|payload| super::logger::mask_body(payload.peek().as_bytes()))
+}
+
+/// The response of the connector, whether the request succeeded or failed, if one was received.
+fn get_connector_response(
+    response: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+) -> Option<&types::Response> {
+    match response {
+        Ok(Ok(response)) | Ok(Err(response)) => Some(response),
+        Err(_) => None,
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn call_connector_api(
     state: &AppState,
@@ -434,6 +609,7 @@ pub enum ApplicationResponse<R> {
     TextPlain(String),
     JsonForRedirection(api::RedirectionResponse),
     Form(RedirectForm),
+    Html(String),
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -508,12 +684,35 @@ where
     A: AppStateInfo,
     CustomResult<ApplicationResponse<Q>, E>: ReportSwitchExt<ApplicationResponse<Q>, OErr>,
     CustomResult<U, errors::ApiErrorResponse>: ReportSwitchExt<U, OErr>,
+    errors::ApiErrorResponse: ErrorSwitch<OErr>,
+    OErr: error_stack::Context,
 {
-    let auth_out = api_auth
+    let auth_out = match api_auth
         .authenticate_and_fetch(request.headers(), state)
         .await
-        .switch()?;
-    func(state, auth_out, payload).await.switch()
+    {
+        Err(error) if is_db_pool_exhausted(&error) => {
+            Err(error.change_context(errors::ApiErrorResponse::ServiceUnavailable))
+        }
+        result => result,
+    }
+    .switch()?;
+
+    match func(state, auth_out, payload).await {
+        Err(error) if is_db_pool_exhausted(&error) => {
+            Err(error.change_context(errors::ApiErrorResponse::ServiceUnavailable)).switch()
+        }
+        result => result.switch(),
+    }
+}
+
+/// Requests which fail as no database connection could be obtained are shed with a 503 response,
+/// so that they are retried later rather than considered failed.
+fn is_db_pool_exhausted<T>(error: &Report<T>) -> bool {
+    matches!(
+        error.downcast_ref::<errors::StorageError>(),
+        Some(errors::StorageError::PoolExhausted)
+    )
 }
 
 #[instrument(
@@ -577,6 +776,7 @@ where
         Ok(ApplicationResponse::Form(response)) => build_redirection_form(&response)
             .respond_to(request)
             .map_into_boxed_body(),
+        Ok(ApplicationResponse::Html(html)) => http_response_html(html),
 
         Err(error) => log_and_return_error_response(error),
     };
@@ -598,7 +798,14 @@ where
     T: actix_web::ResponseError + error_stack::Context + Clone,
 {
     logger::error!(?error);
-    HttpResponse::from_error(error.current_context().clone())
+    let mut response = HttpResponse::from_error(error.current_context().clone());
+    if is_db_pool_exhausted(&error) {
+        response.headers_mut().insert(
+            header::RETRY_AFTER,
+            header::HeaderValue::from(consts::DB_POOL_EXHAUSTED_RETRY_AFTER),
+        );
+    }
+    response
 }
 
 pub fn http_response_json<T: body::MessageBody + 'static>(response: T) -> HttpResponse {
@@ -611,6 +818,10 @@ pub fn http_response_plaintext<T: body::MessageBody + 'static>(res: T) -> HttpRe
     HttpResponse::Ok().content_type(mime::TEXT_PLAIN).body(res)
 }
 
+pub fn http_response_html<T: body::MessageBody + 'static>(res: T) -> HttpResponse {
+    HttpResponse::Ok().content_type(mime::TEXT_HTML).body(res)
+}
+
 pub fn http_response_ok() -> HttpResponse {
     HttpResponse::Ok().finish()
 }
@@ -724,8 +935,29 @@ pub fn build_redirection_form(form: &RedirectForm) -> maud::Markup {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test_mime_essence() {
         assert_eq!(mime::APPLICATION_JSON.essence_str(), "application/json");
     }
+
+    #[test]
+    fn test_db_pool_exhausted_error_is_shed() {
+        let error = report!(errors::StorageError::PoolExhausted)
+            .change_context(errors::ApiErrorResponse::InternalServerError);
+        let response = log_and_return_error_response(
+            error.change_context(errors::ApiErrorResponse::ServiceUnavailable),
+        );
+
+        assert_eq!(
+            response.status(),
+            actix_web::http::StatusCode::SERVICE_UNAVAILABLE
+        );
+        assert!(response.headers().contains_key(header::RETRY_AFTER));
+        assert!(!is_db_pool_exhausted(
+            &report!(errors::StorageError::DatabaseConnectionError)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        ));
+    }
 }
@@ -0,0 +1,280 @@
+use std::time::Duration;
+
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, CustomResult},
+    db::circuit_breaker::CircuitState,
+    logger,
+    routes::{metrics, AppState},
+    types,
+};
+
+/// Circuits are kept per merchant and connector, so that the failures of the account of one
+/// merchant at a connector do not short circuit the calls of other merchants.
+fn get_circuit_id(merchant_id: &str, connector: &str) -> String {
+    format!("{merchant_id}_{connector}")
+}
+
+/// Whether calls to the connector are currently short circuited for the merchant, after it has
+/// failed repeatedly. A half open circuit is not considered open, so that payments are still
+/// routed to the connector to probe it. The circuit is considered closed if its state could not
+/// be determined.
+#[instrument(skip(state))]
+pub async fn is_circuit_open(state: &AppState, merchant_id: &str, connector: &str) -> bool {
+    if !state.conf.circuit_breaker.enabled {
+        return false;
+    }
+
+    state
+        .store
+        .is_connector_circuit_open(&get_circuit_id(merchant_id, connector))
+        .await
+        .map_err(|error| logger::error!(circuit_breaker_error=?error))
+        .unwrap_or(false)
+}
+
+/// Returns the state of the circuit for a call about to be made to the connector, or `None` if
+/// the call is to be short circuited. A call which finds the circuit half open is the probe of
+/// the connector, and is made only if the health check of the connector, when one is configured,
+/// passes.
+#[instrument(skip(state))]
+pub async fn acquire_circuit(
+    state: &AppState,
+    merchant_id: &str,
+    connector: &str,
+) -> Option<CircuitState> {
+    let config = &state.conf.circuit_breaker;
+    let closed = CircuitState::Closed { failure_count: 0 };
+    if !config.enabled {
+        return Some(closed);
+    }
+
+    let circuit_id = get_circuit_id(merchant_id, connector);
+    match state
+        .store
+        .acquire_connector_circuit(&circuit_id, config.cool_down_time)
+        .await
+    {
+        Ok(CircuitState::Open) => None,
+        Ok(CircuitState::HalfOpen) => match config.health_check_urls.get(connector) {
+            Some(url)
+                if !is_connector_healthy(
+                    url,
+                    Duration::from_millis(config.health_check_timeout),
+                )
+                .await =>
+            {
+                logger::warn!("Health check of {connector} failed, keeping its circuit open");
+                open_circuit(state, &circuit_id, connector).await;
+                None
+            }
+            _ => Some(CircuitState::HalfOpen),
+        },
+        Ok(circuit_state) => Some(circuit_state),
+        Err(error) => {
+            logger::error!(circuit_breaker_error=?error);
+            Some(closed)
+        }
+    }
+}
+
+/// A connector is healthy if its health check URL responds in time without a server error.
+async fn is_connector_healthy(url: &str, timeout: Duration) -> bool {
+    let response = tokio::time::timeout(timeout, reqwest::Client::new().get(url).send()).await;
+    matches!(response, Ok(Ok(response)) if !response.status().is_server_error())
+}
+
+/// Timeouts, failures to reach the connector and server errors count towards opening the
+/// circuit. Any other response indicates that the connector is up.
+fn is_connector_failure(
+    response: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+) -> bool {
+    match response {
+        Ok(Ok(_)) => false,
+        Ok(Err(error_response)) => error_response.status_code >= 500,
+        Err(error) => matches!(
+            error.current_context(),
+            errors::ApiClientError::RequestTimeoutReceived
+                | errors::ApiClientError::RequestNotSent(_)
+        ),
+    }
+}
+
+async fn open_circuit(state: &AppState, circuit_id: &str, connector: &str) {
+    metrics::CONNECTOR_CIRCUIT_OPENED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "connector",
+            connector.to_owned(),
+        )],
+    );
+
+    state
+        .store
+        .open_connector_circuit(circuit_id, state.conf.circuit_breaker.cool_down_time)
+        .await
+        .map_err(|error| logger::error!(circuit_breaker_error=?error))
+        .ok();
+}
+
+/// Records the outcome of a call made with the circuit in `circuit_state`. The circuit is opened
+/// once the number of consecutive failures reaches the configured threshold or when the probe of
+/// a half open circuit fails, and is closed when a call succeeds.
+#[instrument(skip_all)]
+pub async fn record_connector_response(
+    state: &AppState,
+    merchant_id: &str,
+    connector: &str,
+    circuit_state: CircuitState,
+    response: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+) {
+    let config = &state.conf.circuit_breaker;
+    if !config.enabled {
+        return;
+    }
+    let circuit_id = get_circuit_id(merchant_id, connector);
+
+    if !is_connector_failure(response) {
+        // A circuit without failures is left as it is, sparing successful calls a round trip
+        if circuit_state != (CircuitState::Closed { failure_count: 0 }) {
+            state
+                .store
+                .close_connector_circuit(&circuit_id)
+                .await
+                .map_err(|error| logger::error!(circuit_breaker_error=?error))
+                .ok();
+        }
+        return;
+    }
+
+    if circuit_state == CircuitState::HalfOpen {
+        logger::warn!("Probe of {connector} failed, opening its circuit again");
+        open_circuit(state, &circuit_id, connector).await;
+        return;
+    }
+
+    let failure_count = match state
+        .store
+        .increment_connector_failure_count(&circuit_id)
+        .await
+    {
+        Ok(failure_count) => failure_count,
+        Err(error) => {
+            logger::error!(circuit_breaker_error=?error);
+            return;
+        }
+    };
+
+    if failure_count >= config.failure_threshold {
+        logger::warn!(
+            "Opening the circuit for {connector} for {} seconds after {failure_count} consecutive failures",
+            config.cool_down_time
+        );
+        open_circuit(state, &circuit_id, connector).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use error_stack::report;
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl};
+
+    fn response(status_code: u16) -> types::Response {
+        types::Response {
+            response: bytes::Bytes::new(),
+            status_code,
+        }
+    }
+
+    #[test]
+    fn test_connector_failures() {
+        assert!(!is_connector_failure(&Ok(Ok(response(200)))));
+        assert!(!is_connector_failure(&Ok(Err(response(402)))));
+        assert!(is_connector_failure(&Ok(Err(response(503)))));
+        assert!(is_connector_failure(&Err(report!(
+            errors::ApiClientError::RequestTimeoutReceived
+        ))));
+        assert!(!is_connector_failure(&Err(report!(
+            errors::ApiClientError::HeaderMapConstructionFailed
+        ))));
+    }
+
+    #[tokio::test]
+    async fn test_half_open_circuit_is_probed_once() {
+        let mut conf = Settings::new().expect("invalid settings");
+        conf.circuit_breaker.enabled = true;
+        conf.circuit_breaker.failure_threshold = 2;
+        let state = AppState::with_storage(conf, StorageImpl::Mock).await;
+        let circuit_id = get_circuit_id("merchant", "stripe");
+        let failure = Ok(Err(response(503)));
+        let success = Ok(Ok(response(200)));
+
+        for _ in 0..2 {
+            let circuit_state = acquire_circuit(&state, "merchant", "stripe").await;
+            assert!(matches!(circuit_state, Some(CircuitState::Closed { .. })));
+            record_connector_response(
+                &state,
+                "merchant",
+                "stripe",
+                circuit_state.expect("circuit is closed"),
+                &failure,
+            )
+            .await;
+        }
+        assert!(is_circuit_open(&state, "merchant", "stripe").await);
+        assert!(!is_circuit_open(&state, "other_merchant", "stripe").await);
+        assert_eq!(acquire_circuit(&state, "merchant", "stripe").await, None);
+
+        // The cool down is over, and only the first call probes the connector
+        state
+            .store
+            .open_connector_circuit(&circuit_id, 0)
+            .await
+            .expect("failed to open circuit");
+        assert!(!is_circuit_open(&state, "merchant", "stripe").await);
+        assert_eq!(
+            acquire_circuit(&state, "merchant", "stripe").await,
+            Some(CircuitState::HalfOpen)
+        );
+        assert_eq!(acquire_circuit(&state, "merchant", "stripe").await, None);
+
+        // A failed probe opens the circuit again
+        record_connector_response(
+            &state,
+            "merchant",
+            "stripe",
+            CircuitState::HalfOpen,
+            &failure,
+        )
+        .await;
+        assert!(is_circuit_open(&state, "merchant", "stripe").await);
+
+        // A successful probe closes the circuit
+        state
+            .store
+            .open_connector_circuit(&circuit_id, 0)
+            .await
+            .expect("failed to open circuit");
+        assert_eq!(
+            acquire_circuit(&state, "merchant", "stripe").await,
+            Some(CircuitState::HalfOpen)
+        );
+        record_connector_response(
+            &state,
+            "merchant",
+            "stripe",
+            CircuitState::HalfOpen,
+            &success,
+        )
+        .await;
+        assert_eq!(
+            acquire_circuit(&state, "merchant", "stripe").await,
+            Some(CircuitState::Closed { failure_count: 0 })
+        );
+    }
+}
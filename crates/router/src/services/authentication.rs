@@ -3,7 +3,7 @@ use api_models::{payment_methods::PaymentMethodListRequest, payments::PaymentsRe
 use async_trait::async_trait;
 use common_utils::date_time;
 use error_stack::{report, IntoReport, ResultExt};
-use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
 use masking::PeekInterface;
 
 use crate::{
@@ -13,8 +13,11 @@ use crate::{
     },
     db::StorageInterface,
     routes::app::AppStateInfo,
-    services::api,
-    types::storage,
+    services::{
+        api,
+        authorization::{self, Permission},
+    },
+    types::{api as api_types, storage, transformers::ForeignInto},
     utils::OptionExt,
 };
 
@@ -30,6 +33,8 @@ where
     ) -> RouterResult<T>;
 }
 
+/// Authenticates requests made with API keys to routes which are not restricted to a scope. Only
+/// API keys without scopes are allowed on such routes.
 #[derive(Debug)]
 pub struct ApiKeyAuth;
 
@@ -59,59 +64,151 @@ where
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<storage::MerchantAccount> {
-        let api_key = get_api_key(request_headers)
-            .change_context(errors::ApiErrorResponse::Unauthorized)?
-            .trim();
-        if api_key.is_empty() {
-            return Err(errors::ApiErrorResponse::Unauthorized)
-                .into_report()
-                .attach_printable("API key is empty");
+        let stored_api_key = authenticate_api_key(request_headers, state).await?;
+
+        if !api_keys::is_unscoped(&stored_api_key) {
+            return Err(report!(errors::ApiErrorResponse::InsufficientApiKeyScope {
+                scope: api_keys::UNRESTRICTED_SCOPE.to_string(),
+            }))
+            .attach_printable("Scoped API keys are only allowed on the routes of their scopes");
         }
 
-        let api_key = api_keys::PlaintextApiKey::from(api_key);
-        let hash_key = {
-            let config = state.conf();
-            api_keys::get_hash_key(
-                &config.api_keys,
-                #[cfg(feature = "kms")]
-                &config.kms,
-            )
-            .await?
-        };
-        let hashed_api_key = api_key.keyed_hash(hash_key.peek());
-
-        let stored_api_key = state
-            .store()
-            .find_api_key_by_hash_optional(hashed_api_key.into())
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
-            .attach_printable("Failed to retrieve API key")?
-            .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
-            .attach_printable("Merchant not authenticated")?;
-
-        if stored_api_key
-            .expires_at
-            .map(|expires_at| expires_at < date_time::now())
-            .unwrap_or(false)
-        {
-            return Err(report!(errors::ApiErrorResponse::Unauthorized))
-                .attach_printable("API key has expired");
+        find_api_key_merchant_account(request_headers, state, &stored_api_key).await
+    }
+}
+
+/// Authenticates requests made with API keys, allowing them only if the API key has the scope
+/// required by the route.
+#[derive(Debug)]
+pub struct ScopedApiKeyAuth(pub api_types::ApiKeyScope);
+
+#[async_trait]
+impl<A> AuthenticateAndFetch<storage::MerchantAccount, A> for ScopedApiKeyAuth
+where
+    A: AppStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<storage::MerchantAccount> {
+        let stored_api_key = authenticate_api_key(request_headers, state).await?;
+
+        if !api_keys::is_scope_allowed(&stored_api_key, self.0) {
+            return Err(report!(errors::ApiErrorResponse::InsufficientApiKeyScope {
+                scope: self.0.to_string(),
+            }))
+            .attach_printable("API key does not have the scope required by the route");
         }
 
-        state
-            .store()
-            .find_merchant_account_by_merchant_id(&stored_api_key.merchant_id)
-            .await
-            .map_err(|e| {
-                if e.current_context().is_db_not_found() {
-                    e.change_context(errors::ApiErrorResponse::Unauthorized)
-                } else {
-                    e.change_context(errors::ApiErrorResponse::InternalServerError)
-                }
-            })
+        find_api_key_merchant_account(request_headers, state, &stored_api_key).await
     }
 }
 
+async fn authenticate_api_key<A>(
+    request_headers: &HeaderMap,
+    state: &A,
+) -> RouterResult<storage::ApiKey>
+where
+    A: AppStateInfo + Sync,
+{
+    let api_key = get_api_key(request_headers)
+        .change_context(errors::ApiErrorResponse::Unauthorized)?
+        .trim();
+    if api_key.is_empty() {
+        return Err(errors::ApiErrorResponse::Unauthorized)
+            .into_report()
+            .attach_printable("API key is empty");
+    }
+
+    let api_key = api_keys::PlaintextApiKey::from(api_key);
+    let hash_key = {
+        let config = state.conf();
+        api_keys::get_hash_key(
+            &config.api_keys,
+            #[cfg(feature = "kms")]
+            &config.kms,
+        )
+        .await?
+    };
+    let hashed_api_key = api_key.keyed_hash(hash_key.peek());
+
+    let stored_api_key = state
+        .store()
+        .find_api_key_by_hash_optional(hashed_api_key.into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
+        .attach_printable("Merchant not authenticated")?;
+
+    if stored_api_key
+        .expires_at
+        .map(|expires_at| expires_at < date_time::now())
+        .unwrap_or(false)
+    {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("API key has expired");
+    }
+
+    Ok(stored_api_key)
+}
+
+async fn find_merchant_account<A>(
+    state: &A,
+    merchant_id: &str,
+) -> RouterResult<storage::MerchantAccount>
+where
+    A: AppStateInfo + Sync,
+{
+    state
+        .store()
+        .find_merchant_account_by_merchant_id(merchant_id)
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(errors::ApiErrorResponse::Unauthorized)
+            } else {
+                e.change_context(errors::ApiErrorResponse::InternalServerError)
+            }
+        })
+}
+
+/// API keys of an organization act on the merchant account of the organization whose identifier
+/// is passed in the `X-Merchant-Id` header.
+async fn find_api_key_merchant_account<A>(
+    request_headers: &HeaderMap,
+    state: &A,
+    api_key: &storage::ApiKey,
+) -> RouterResult<storage::MerchantAccount>
+where
+    A: AppStateInfo + Sync,
+{
+    if let Some(ref merchant_id) = api_key.merchant_id {
+        return find_merchant_account(state, merchant_id).await;
+    }
+
+    let merchant_id = request_headers
+        .get(crate::headers::X_MERCHANT_ID)
+        .get_required_value(crate::headers::X_MERCHANT_ID)?
+        .to_str()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid {} header", crate::headers::X_MERCHANT_ID),
+        })?;
+    let merchant_account = find_merchant_account(state, merchant_id).await?;
+
+    if api_key.organization_id.is_none()
+        || merchant_account.organization_id != api_key.organization_id
+    {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized)).attach_printable(
+            "Merchant account does not belong to the organization of the API key",
+        );
+    }
+
+    Ok(merchant_account)
+}
+
 #[derive(Debug)]
 pub struct AdminApiAuth;
 
@@ -136,6 +233,37 @@ where
     }
 }
 
+/// Authenticates requests to the routes of an organization made with the admin API key, or with an
+/// API key of the organization.
+#[derive(Debug)]
+pub struct OrganizationAuth(pub String);
+
+#[async_trait]
+impl<A> AuthenticateAndFetch<(), A> for OrganizationAuth
+where
+    A: AppStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<()> {
+        let api_key =
+            get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
+        if api_key == state.conf().secrets.admin_api_key {
+            return Ok(());
+        }
+
+        let stored_api_key = authenticate_api_key(request_headers, state).await?;
+        if stored_api_key.organization_id.as_deref() != Some(self.0.as_str()) {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("API key does not belong to the organization");
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct MerchantIdAuth(pub String);
 
@@ -192,17 +320,96 @@ where
     }
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenType {
+    Access,
+    Refresh,
+}
+
+/// The claims of the tokens issued to dashboard users. Tokens issued before the user was given a
+/// role for any merchant account do not carry a merchant account or role.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct AuthToken {
+    pub user_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<api_types::enums::DashboardRole>,
+    pub exp: i64,
+    pub token_type: TokenType,
+}
+
+/// Refresh tokens can only be used to obtain new access tokens.
+fn check_access_token(token_type: TokenType) -> RouterResult<()> {
+    match token_type {
+        TokenType::Refresh => Err(report!(errors::ApiErrorResponse::InvalidJwtToken))
+            .attach_printable("Refresh token used to access a resource"),
+        TokenType::Access => Ok(()),
+    }
+}
+
+#[derive(Debug)]
+pub struct UserFromToken {
+    pub user_id: String,
+    pub merchant_id: String,
+    pub role: api_types::enums::DashboardRole,
+}
+
+/// Authenticates requests made from the dashboard with the access token of a signed in user,
+/// allowing them only if the role of the user for the merchant account the token was issued for
+/// has the permission required by the route. The role is fetched from the database, so that
+/// changes to it take effect before the token expires.
 #[derive(Debug)]
-pub struct JWTAuth;
+pub struct DashboardAuth(pub Permission);
+
+async fn authenticate_dashboard_user<A>(
+    request_headers: &HeaderMap,
+    state: &A,
+    permission: Permission,
+) -> RouterResult<UserFromToken>
+where
+    A: AppStateInfo + Sync,
+{
+    let mut token = get_jwt(request_headers)?;
+    token = strip_jwt_token(token)?;
+    let payload = decode_jwt::<AuthToken>(token, state)?;
+    check_access_token(payload.token_type)?;
+
+    let merchant_id = payload
+        .merchant_id
+        .ok_or(errors::ApiErrorResponse::InsufficientPermission {
+            permission: permission.to_string(),
+        })
+        .into_report()
+        .attach_printable("Token was not issued for a merchant account")?;
 
-#[derive(serde::Deserialize)]
-struct JwtAuthPayloadFetchUnit {
-    #[serde(rename(deserialize = "exp"))]
-    _exp: u64,
+    let role: api_types::enums::DashboardRole = state
+        .store()
+        .find_user_role_by_user_id_merchant_id(&payload.user_id, &merchant_id)
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::InsufficientPermission {
+                    permission: permission.to_string(),
+                })
+            } else {
+                error.change_context(errors::ApiErrorResponse::InternalServerError)
+            }
+        })?
+        .role
+        .foreign_into();
+    authorization::check_permission(role, permission)?;
+
+    Ok(UserFromToken {
+        user_id: payload.user_id,
+        merchant_id,
+        role,
+    })
 }
 
 #[async_trait]
-impl<A> AuthenticateAndFetch<(), A> for JWTAuth
+impl<A> AuthenticateAndFetch<UserFromToken, A> for DashboardAuth
 where
     A: AppStateInfo + Sync,
 {
@@ -210,20 +417,37 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
-    ) -> RouterResult<()> {
-        let mut token = get_jwt(request_headers)?;
-        token = strip_jwt_token(token)?;
-        decode_jwt::<JwtAuthPayloadFetchUnit>(token, state).map(|_| ())
+    ) -> RouterResult<UserFromToken> {
+        authenticate_dashboard_user(request_headers, state, self.0).await
+    }
+}
+
+#[async_trait]
+impl<A> AuthenticateAndFetch<storage::MerchantAccount, A> for DashboardAuth
+where
+    A: AppStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<storage::MerchantAccount> {
+        let user = authenticate_dashboard_user(request_headers, state, self.0).await?;
+        find_merchant_account(state, &user.merchant_id).await
     }
 }
 
-#[derive(serde::Deserialize)]
-struct JwtAuthPayloadFetchMerchantAccount {
-    merchant_id: String,
+/// Authenticates requests made from the dashboard to the routes of a merchant account which are
+/// otherwise authenticated with the admin API key, allowing them only for the merchant account
+/// the access token of the user was issued for.
+#[derive(Debug)]
+pub struct DashboardMerchantAuth {
+    pub merchant_id: String,
+    pub permission: Permission,
 }
 
 #[async_trait]
-impl<A> AuthenticateAndFetch<storage::MerchantAccount, A> for JWTAuth
+impl<A> AuthenticateAndFetch<(), A> for DashboardMerchantAuth
 where
     A: AppStateInfo + Sync,
 {
@@ -231,15 +455,15 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
-    ) -> RouterResult<storage::MerchantAccount> {
-        let mut token = get_jwt(request_headers)?;
-        token = strip_jwt_token(token)?;
-        let payload = decode_jwt::<JwtAuthPayloadFetchMerchantAccount>(token, state)?;
-        state
-            .store()
-            .find_merchant_account_by_merchant_id(&payload.merchant_id)
-            .await
-            .change_context(errors::ApiErrorResponse::InvalidJwtToken)
+    ) -> RouterResult<()> {
+        let user = authenticate_dashboard_user(request_headers, state, self.permission).await?;
+        if user.merchant_id != self.merchant_id {
+            return Err(report!(errors::ApiErrorResponse::InsufficientPermission {
+                permission: self.permission.to_string(),
+            }))
+            .attach_printable("Token was issued for a different merchant account");
+        }
+        Ok(())
     }
 }
 
@@ -265,21 +489,47 @@ impl ClientSecretFetch for api_models::cards_info::CardsInfoRequest {
     }
 }
 
-pub fn jwt_auth_or<'a, T, A: AppStateInfo>(
-    default_auth: &'a dyn AuthenticateAndFetch<T, A>,
+/// Requests made from the dashboard are authenticated with [`DashboardAuth`], and other requests
+/// with the default authentication of the route.
+pub fn dashboard_auth_or<T, A>(
+    default_auth: Box<dyn AuthenticateAndFetch<T, A>>,
     headers: &HeaderMap,
-) -> Box<&'a dyn AuthenticateAndFetch<T, A>>
+    permission: Permission,
+) -> Box<dyn AuthenticateAndFetch<T, A>>
 where
-    JWTAuth: AuthenticateAndFetch<T, A>,
+    A: AppStateInfo,
+    DashboardAuth: AuthenticateAndFetch<T, A>,
 {
     if is_jwt_auth(headers) {
-        return Box::new(&JWTAuth);
+        return Box::new(DashboardAuth(permission));
     }
-    Box::new(default_auth)
+    default_auth
 }
 
+pub fn admin_or_dashboard_auth<A>(
+    headers: &HeaderMap,
+    merchant_id: &str,
+    permission: Permission,
+) -> Box<dyn AuthenticateAndFetch<(), A>>
+where
+    A: AppStateInfo,
+    AdminApiAuth: AuthenticateAndFetch<(), A>,
+    DashboardMerchantAuth: AuthenticateAndFetch<(), A>,
+{
+    if is_jwt_auth(headers) {
+        return Box::new(DashboardMerchantAuth {
+            merchant_id: merchant_id.to_owned(),
+            permission,
+        });
+    }
+    Box::new(AdminApiAuth)
+}
+
+/// Publishable keys are not restricted by scopes, since they can only be used for the client side
+/// flows of a payment.
 pub fn get_auth_type_and_flow<A: AppStateInfo + Sync>(
     headers: &HeaderMap,
+    scope: api_types::ApiKeyScope,
 ) -> RouterResult<(
     Box<dyn AuthenticateAndFetch<storage::MerchantAccount, A>>,
     api::AuthFlow,
@@ -289,12 +539,13 @@ pub fn get_auth_type_and_flow<A: AppStateInfo + Sync>(
     if api_key.starts_with("pk_") {
         return Ok((Box::new(PublishableKeyAuth), api::AuthFlow::Client));
     }
-    Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
+    Ok((Box::new(ScopedApiKeyAuth(scope)), api::AuthFlow::Merchant))
 }
 
 pub fn check_client_secret_and_get_auth<T>(
     headers: &HeaderMap,
     payload: &impl ClientSecretFetch,
+    scope: Option<api_types::ApiKeyScope>,
 ) -> RouterResult<(
     Box<dyn AuthenticateAndFetch<storage::MerchantAccount, T>>,
     api::AuthFlow,
@@ -302,6 +553,7 @@ pub fn check_client_secret_and_get_auth<T>(
 where
     T: AppStateInfo,
     ApiKeyAuth: AuthenticateAndFetch<storage::MerchantAccount, T>,
+    ScopedApiKeyAuth: AuthenticateAndFetch<storage::MerchantAccount, T>,
     PublishableKeyAuth: AuthenticateAndFetch<storage::MerchantAccount, T>,
 {
     let api_key = get_api_key(headers)?;
@@ -323,7 +575,10 @@ where
         .into());
     }
 
-    Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
+    match scope {
+        Some(scope) => Ok((Box::new(ScopedApiKeyAuth(scope)), api::AuthFlow::Merchant)),
+        None => Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant)),
+    }
 }
 
 pub async fn is_ephemeral_auth<A: AppStateInfo + Sync>(
@@ -366,6 +621,19 @@ where
         .change_context(errors::ApiErrorResponse::InvalidJwtToken)
 }
 
+pub fn encode_jwt<T>(claims: &T, state: &impl AppStateInfo) -> RouterResult<String>
+where
+    T: serde::Serialize,
+{
+    let conf = state.conf();
+    let secret = conf.secrets.jwt_secret.as_bytes();
+    let key = EncodingKey::from_secret(secret);
+    encode(&Header::new(Algorithm::HS256), claims, &key)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode JWT")
+}
+
 pub fn get_api_key(headers: &HeaderMap) -> RouterResult<&str> {
     headers
         .get("api-key")
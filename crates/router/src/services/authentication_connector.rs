@@ -0,0 +1,596 @@
+use std::collections::HashMap;
+
+use base64::Engine;
+use common_utils::{date_time, pii};
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils as connector_utils,
+    core::errors::{self, CustomResult},
+    headers,
+    routes::AppState,
+    services,
+    types::{self, api, storage::enums as storage_enums},
+    utils::{self, BytesExt},
+};
+
+/// Details of the payment sent to an external 3DS provider for authenticating the cardholder.
+#[derive(Debug, Clone)]
+pub struct AuthenticationRequest {
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub amount: types::MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub payment_method_data: api::PaymentMethodData,
+    pub browser_info: Option<types::BrowserInformation>,
+    pub email: Option<Secret<String, pii::Email>>,
+    pub return_url: Option<String>,
+    /// Metadata of the merchant connector account of the provider, holding the details of the
+    /// merchant and its acquirer required for the authentication
+    pub connector_meta_data: Option<pii::SecretSerdeValue>,
+}
+
+/// The outcome of authenticating the cardholder with an external 3DS provider.
+#[derive(Debug, Clone)]
+pub struct AuthenticationResponse {
+    pub three_ds_data: types::ThreeDsData,
+    /// The form redirecting the customer to the access control server of the issuer, when the
+    /// cardholder has to complete a challenge
+    pub challenge_form: Option<services::RedirectForm>,
+}
+
+/// A standalone 3DS provider, which authenticates the cardholder independent of the connector
+/// the payment is authorized with.
+///
+/// The results of the authentication (CAVV, ECI etc.) are passed on to the connector in the
+/// authorization request.
+#[async_trait::async_trait]
+pub trait AuthenticationConnector: Send + Sync {
+    /// Name of the authentication provider (in lowercase).
+    fn id(&self) -> &'static str;
+
+    /// Authenticates the cardholder of the payment with the provider.
+    async fn authenticate(
+        &self,
+        state: &AppState,
+        auth_type: &types::ConnectorAuthType,
+        request: &AuthenticationRequest,
+    ) -> CustomResult<AuthenticationResponse, errors::ConnectorError>;
+}
+
+pub type BoxedAuthenticationConnector = Box<dyn AuthenticationConnector>;
+
+/// Returns the authentication provider configured with the given name in the merchant connector
+/// account of type `authentication_processor`.
+pub fn get_authentication_connector_by_name(
+    connector_name: &str,
+) -> CustomResult<BoxedAuthenticationConnector, errors::ApiErrorResponse> {
+    match connector_name {
+        "threedsecureio" => Ok(Box::new(Threedsecureio)),
+        _ => Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "connector_name"
+        }))
+        .attach_printable_lazy(|| {
+            format!("Authentication connector {connector_name} is not supported")
+        }),
+    }
+}
+
+/// The 3DS server of 3dsecure.io, which authenticates browser based payments through the
+/// directory server of the card network.
+pub struct Threedsecureio;
+
+/// Details of the merchant and its acquirer, configured in the metadata of the merchant connector
+/// account of 3dsecure.io.
+#[derive(Debug, Clone, Deserialize)]
+struct ThreedsecureioMetadata {
+    three_ds_requestor_id: String,
+    three_ds_requestor_name: String,
+    three_ds_requestor_url: String,
+    acquirer_bin: String,
+    acquirer_merchant_id: String,
+    merchant_category_code: String,
+    /// ISO 3166-1 numeric code of the country of the merchant
+    merchant_country_code: String,
+    merchant_name: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ThreedsecureioPreAuthRequest {
+    acct_number: Secret<String, pii::CardNumber>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ThreedsecureioPreAuthResponse {
+    #[serde(rename = "threeDSServerTransID")]
+    three_ds_server_trans_id: String,
+    acs_end_protocol_version: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ThreedsecureioAuthRequest {
+    message_category: &'static str,
+    message_type: &'static str,
+    message_version: String,
+    device_channel: &'static str,
+    #[serde(rename = "threeDSServerTransID")]
+    three_ds_server_trans_id: String,
+    #[serde(rename = "threeDSCompInd")]
+    three_ds_comp_ind: &'static str,
+    #[serde(rename = "threeDSRequestorAuthenticationInd")]
+    three_ds_requestor_authentication_ind: &'static str,
+    #[serde(rename = "threeDSRequestorID")]
+    three_ds_requestor_id: String,
+    #[serde(rename = "threeDSRequestorName")]
+    three_ds_requestor_name: String,
+    #[serde(rename = "threeDSRequestorURL")]
+    three_ds_requestor_url: String,
+    #[serde(rename = "acquirerBIN")]
+    acquirer_bin: String,
+    #[serde(rename = "acquirerMerchantID")]
+    acquirer_merchant_id: String,
+    mcc: String,
+    merchant_country_code: String,
+    merchant_name: String,
+    acct_number: Secret<String, pii::CardNumber>,
+    /// Expiry date of the card in the `YYMM` format
+    card_expiry_date: Secret<String>,
+    purchase_amount: String,
+    /// ISO 4217 numeric code of the currency of the payment
+    purchase_currency: &'static str,
+    purchase_exponent: String,
+    /// Date of the purchase in UTC, in the `YYYYMMDDHHMMSS` format
+    purchase_date: String,
+    trans_type: &'static str,
+    #[serde(rename = "notificationURL")]
+    notification_url: String,
+    browser_accept_header: String,
+    #[serde(rename = "browserIP")]
+    browser_ip: Option<std::net::IpAddr>,
+    browser_java_enabled: bool,
+    browser_javascript_enabled: bool,
+    browser_language: String,
+    browser_color_depth: String,
+    browser_screen_height: String,
+    browser_screen_width: String,
+    #[serde(rename = "browserTZ")]
+    browser_tz: String,
+    browser_user_agent: String,
+    email: Option<Secret<String, pii::Email>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ThreedsecureioAuthResponse {
+    message_version: String,
+    #[serde(rename = "threeDSServerTransID")]
+    three_ds_server_trans_id: String,
+    #[serde(rename = "dsTransID")]
+    ds_trans_id: Option<String>,
+    #[serde(rename = "acsTransID")]
+    acs_trans_id: Option<String>,
+    #[serde(rename = "acsURL")]
+    acs_url: Option<String>,
+    trans_status: api_models::enums::ThreeDsTransStatus,
+    trans_status_reason: Option<String>,
+    eci: Option<String>,
+    authentication_value: Option<Secret<String>>,
+}
+
+/// The challenge request posted to the access control server, to start the challenge in the
+/// browser of the customer
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ThreedsecureioChallengeRequest<'a> {
+    #[serde(rename = "threeDSServerTransID")]
+    three_ds_server_trans_id: &'a str,
+    #[serde(rename = "acsTransID")]
+    acs_trans_id: &'a str,
+    message_version: &'a str,
+    message_type: &'static str,
+    challenge_window_size: &'static str,
+}
+
+impl Threedsecureio {
+    const DEFAULT_MESSAGE_VERSION: &'static str = "2.1.0";
+
+    fn get_auth_request(
+        request: &AuthenticationRequest,
+        pre_auth_response: ThreedsecureioPreAuthResponse,
+    ) -> CustomResult<ThreedsecureioAuthRequest, errors::ConnectorError> {
+        let metadata: ThreedsecureioMetadata =
+            connector_utils::to_connector_meta_from_secret(request.connector_meta_data.clone())
+                .change_context(errors::ConnectorError::NoConnectorMetaData)?;
+
+        let card = match &request.payment_method_data {
+            api::PaymentMethodData::Card(card) => card,
+            _ => Err(errors::ConnectorError::NotSupported {
+                payment_method: "non-card payment method".to_string(),
+                connector: "threedsecureio",
+                payment_experience: "redirect_to_url".to_string(),
+            })
+            .into_report()?,
+        };
+
+        let browser_info =
+            request
+                .browser_info
+                .clone()
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "browser_info",
+                })?;
+
+        let notification_url =
+            request
+                .return_url
+                .clone()
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "return_url",
+                })?;
+
+        let card_exp_year = card.card_exp_year.peek();
+        let card_expiry_date = Secret::new(format!(
+            "{}{:0>2}",
+            card_exp_year
+                .get(card_exp_year.len().saturating_sub(2)..)
+                .unwrap_or_default(),
+            card.card_exp_month.peek()
+        ));
+
+        let purchase_date = date_time::date_as_yyyymmddhhmmss()
+            .into_report()
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+
+        Ok(ThreedsecureioAuthRequest {
+            message_category: "01",
+            message_type: "AReq",
+            message_version: pre_auth_response
+                .acs_end_protocol_version
+                .unwrap_or_else(|| Self::DEFAULT_MESSAGE_VERSION.to_string()),
+            device_channel: "02",
+            three_ds_server_trans_id: pre_auth_response.three_ds_server_trans_id,
+            three_ds_comp_ind: "U",
+            three_ds_requestor_authentication_ind: "01",
+            three_ds_requestor_id: metadata.three_ds_requestor_id,
+            three_ds_requestor_name: metadata.three_ds_requestor_name,
+            three_ds_requestor_url: metadata.three_ds_requestor_url,
+            acquirer_bin: metadata.acquirer_bin,
+            acquirer_merchant_id: metadata.acquirer_merchant_id,
+            mcc: metadata.merchant_category_code,
+            merchant_country_code: metadata.merchant_country_code,
+            merchant_name: metadata.merchant_name,
+            acct_number: card.card_number.clone(),
+            card_expiry_date,
+            purchase_amount: request.amount.to_string(),
+            purchase_currency: request.currency.iso_4217_numeric_code(),
+            purchase_exponent: request
+                .currency
+                .number_of_digits_after_decimal_point()
+                .to_string(),
+            purchase_date,
+            trans_type: "01",
+            notification_url,
+            browser_accept_header: browser_info.accept_header,
+            browser_ip: browser_info.ip_address,
+            browser_java_enabled: browser_info.java_enabled,
+            browser_javascript_enabled: browser_info.java_script_enabled,
+            browser_language: browser_info.language,
+            browser_color_depth: browser_info.color_depth.to_string(),
+            browser_screen_height: browser_info.screen_height.to_string(),
+            browser_screen_width: browser_info.screen_width.to_string(),
+            browser_tz: browser_info.time_zone.to_string(),
+            browser_user_agent: browser_info.user_agent,
+            email: request.email.clone(),
+        })
+    }
+
+    fn get_challenge_form(
+        auth_response: &ThreedsecureioAuthResponse,
+    ) -> CustomResult<Option<services::RedirectForm>, errors::ConnectorError> {
+        let (acs_url, acs_trans_id) = match (&auth_response.acs_url, &auth_response.acs_trans_id) {
+            (Some(acs_url), Some(acs_trans_id)) => (acs_url, acs_trans_id),
+            _ => return Ok(None),
+        };
+
+        let challenge_request = ThreedsecureioChallengeRequest {
+            three_ds_server_trans_id: &auth_response.three_ds_server_trans_id,
+            acs_trans_id,
+            message_version: &auth_response.message_version,
+            message_type: "CReq",
+            challenge_window_size: "05",
+        };
+        let creq = utils::Encode::<ThreedsecureioChallengeRequest<'_>>::encode_to_string_of_json(
+            &challenge_request,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+
+        Ok(Some(services::RedirectForm {
+            endpoint: acs_url.clone(),
+            method: services::Method::Post,
+            form_fields: HashMap::from([(
+                "creq".to_string(),
+                base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(creq),
+            )]),
+        }))
+    }
+
+    fn get_authentication_response(
+        auth_response: ThreedsecureioAuthResponse,
+    ) -> CustomResult<AuthenticationResponse, errors::ConnectorError> {
+        let challenge_form = match auth_response.trans_status {
+            api_models::enums::ThreeDsTransStatus::ChallengeRequired => {
+                Self::get_challenge_form(&auth_response)?
+            }
+            _ => None,
+        };
+
+        let ares = utils::Encode::<ThreedsecureioAuthResponse>::encode_to_value(&auth_response)
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+
+        Ok(AuthenticationResponse {
+            three_ds_data: types::ThreeDsData {
+                device_channel: api_models::enums::ThreeDsDeviceChannel::Browser,
+                device_data: None,
+                message_version: auth_response.message_version,
+                three_ds_server_trans_id: auth_response.three_ds_server_trans_id,
+                ds_trans_id: auth_response.ds_trans_id,
+                acs_trans_id: auth_response.acs_trans_id,
+                trans_status: auth_response.trans_status,
+                trans_status_reason: auth_response.trans_status_reason,
+                eci: auth_response.eci,
+                authentication_value: auth_response.authentication_value,
+                ares: Some(ares),
+                rreq: None,
+            },
+            challenge_form,
+        })
+    }
+
+    async fn call_api<Req, Res>(
+        state: &AppState,
+        api_key: &str,
+        path: &str,
+        request: &Req,
+        response_type_name: &str,
+    ) -> CustomResult<Res, errors::ConnectorError>
+    where
+        Req: Serialize,
+        Res: serde::de::DeserializeOwned,
+    {
+        let body = utils::Encode::<Req>::encode_to_string_of_json(request)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&format!(
+                "{}{path}",
+                state.conf.connectors.threedsecureio.base_url
+            ))
+            .attach_default_headers()
+            .headers(vec![
+                (
+                    headers::CONTENT_TYPE.to_string(),
+                    "application/json".to_string(),
+                ),
+                (headers::APIKEY.to_string(), api_key.to_string()),
+            ])
+            .body(Some(body))
+            .build();
+
+        match services::call_connector_api(state, request)
+            .await
+            .change_context(errors::ConnectorError::ProcessingStepFailed(None))?
+        {
+            Ok(response) => response
+                .response
+                .parse_struct(response_type_name)
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed),
+            Err(error_response) => Err(errors::ConnectorError::ProcessingStepFailed(Some(
+                error_response.response,
+            )))
+            .into_report(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthenticationConnector for Threedsecureio {
+    fn id(&self) -> &'static str {
+        "threedsecureio"
+    }
+
+    async fn authenticate(
+        &self,
+        state: &AppState,
+        auth_type: &types::ConnectorAuthType,
+        request: &AuthenticationRequest,
+    ) -> CustomResult<AuthenticationResponse, errors::ConnectorError> {
+        let api_key = match auth_type {
+            types::ConnectorAuthType::HeaderKey { api_key } => api_key,
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType).into_report()?,
+        };
+
+        let card_number = match &request.payment_method_data {
+            api::PaymentMethodData::Card(card) => card.card_number.clone(),
+            _ => Err(errors::ConnectorError::NotSupported {
+                payment_method: "non-card payment method".to_string(),
+                connector: "threedsecureio",
+                payment_experience: "redirect_to_url".to_string(),
+            })
+            .into_report()?,
+        };
+
+        let pre_auth_response: ThreedsecureioPreAuthResponse = Self::call_api(
+            state,
+            api_key,
+            "preauth",
+            &ThreedsecureioPreAuthRequest {
+                acct_number: card_number,
+            },
+            "ThreedsecureioPreAuthResponse",
+        )
+        .await?;
+
+        let auth_request = Self::get_auth_request(request, pre_auth_response)?;
+        let auth_response: ThreedsecureioAuthResponse = Self::call_api(
+            state,
+            api_key,
+            "auth",
+            &auth_request,
+            "ThreedsecureioAuthResponse",
+        )
+        .await?;
+
+        Self::get_authentication_response(auth_response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::ExposeInterface;
+
+    use super::*;
+
+    fn get_authentication_request() -> AuthenticationRequest {
+        AuthenticationRequest {
+            payment_id: "pay_3ds".to_string(),
+            attempt_id: "pay_3ds_1".to_string(),
+            amount: types::MinorUnit::new(1050),
+            currency: storage_enums::Currency::EUR,
+            payment_method_data: api::PaymentMethodData::Card(api::Card {
+                card_number: Secret::new("4242424242424242".to_string()),
+                card_exp_month: Secret::new("3".to_string()),
+                card_exp_year: Secret::new("2030".to_string()),
+                card_holder_name: Secret::new("John Doe".to_string()),
+                card_cvc: Secret::new("123".to_string()),
+                ..Default::default()
+            }),
+            browser_info: Some(types::BrowserInformation {
+                color_depth: 24,
+                java_enabled: false,
+                java_script_enabled: true,
+                language: "en-US".to_string(),
+                screen_height: 1080,
+                screen_width: 1920,
+                time_zone: -60,
+                ip_address: None,
+                accept_header: "text/html".to_string(),
+                user_agent: "Mozilla/5.0".to_string(),
+            }),
+            email: None,
+            return_url: Some("https://merchant.example.com/3ds".to_string()),
+            connector_meta_data: Some(Secret::new(serde_json::json!({
+                "three_ds_requestor_id": "requestor",
+                "three_ds_requestor_name": "Merchant",
+                "three_ds_requestor_url": "https://merchant.example.com",
+                "acquirer_bin": "400000",
+                "acquirer_merchant_id": "merchant_1",
+                "merchant_category_code": "5411",
+                "merchant_country_code": "276",
+                "merchant_name": "Merchant",
+            }))),
+        }
+    }
+
+    #[test]
+    fn test_auth_request_is_built_from_the_payment() {
+        let auth_request = Threedsecureio::get_auth_request(
+            &get_authentication_request(),
+            ThreedsecureioPreAuthResponse {
+                three_ds_server_trans_id: "server_trans_id".to_string(),
+                acs_end_protocol_version: Some("2.2.0".to_string()),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(auth_request.message_version, "2.2.0");
+        assert_eq!(auth_request.card_expiry_date.peek(), "3003");
+        assert_eq!(auth_request.purchase_amount, "1050");
+        assert_eq!(auth_request.purchase_currency, "978");
+        assert_eq!(auth_request.purchase_exponent, "2");
+        assert_eq!(auth_request.acquirer_bin, "400000");
+        assert_eq!(
+            auth_request.notification_url,
+            "https://merchant.example.com/3ds"
+        );
+    }
+
+    #[test]
+    fn test_auth_request_requires_connector_metadata() {
+        let request = AuthenticationRequest {
+            connector_meta_data: None,
+            ..get_authentication_request()
+        };
+
+        assert!(Threedsecureio::get_auth_request(
+            &request,
+            ThreedsecureioPreAuthResponse {
+                three_ds_server_trans_id: "server_trans_id".to_string(),
+                acs_end_protocol_version: None,
+            },
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_challenge_form_is_built_for_challenges() {
+        let auth_response: ThreedsecureioAuthResponse = serde_json::from_value(serde_json::json!({
+            "messageVersion": "2.2.0",
+            "threeDSServerTransID": "server_trans_id",
+            "dsTransID": "ds_trans_id",
+            "acsTransID": "acs_trans_id",
+            "acsURL": "https://acs.example.com/challenge",
+            "transStatus": "C",
+        }))
+        .unwrap();
+
+        let response = Threedsecureio::get_authentication_response(auth_response).unwrap();
+        let challenge_form = response.challenge_form.unwrap();
+        assert_eq!(challenge_form.endpoint, "https://acs.example.com/challenge");
+
+        let creq: serde_json::Value = serde_json::from_slice(
+            &base64::engine::general_purpose::URL_SAFE_NO_PAD
+                .decode(challenge_form.form_fields.get("creq").unwrap())
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(creq["acsTransID"], "acs_trans_id");
+        assert_eq!(creq["messageType"], "CReq");
+    }
+
+    #[test]
+    fn test_frictionless_authentication_results_are_mapped() {
+        let auth_response: ThreedsecureioAuthResponse = serde_json::from_value(serde_json::json!({
+            "messageVersion": "2.2.0",
+            "threeDSServerTransID": "server_trans_id",
+            "dsTransID": "ds_trans_id",
+            "acsTransID": "acs_trans_id",
+            "transStatus": "Y",
+            "eci": "05",
+            "authenticationValue": "AAABBBCCC",
+        }))
+        .unwrap();
+
+        let response = Threedsecureio::get_authentication_response(auth_response).unwrap();
+        assert!(response.challenge_form.is_none());
+        assert_eq!(
+            response.three_ds_data.trans_status,
+            api_models::enums::ThreeDsTransStatus::Success
+        );
+        assert_eq!(response.three_ds_data.eci.as_deref(), Some("05"));
+        assert_eq!(
+            response
+                .three_ds_data
+                .authentication_value
+                .unwrap()
+                .expose(),
+            "AAABBBCCC"
+        );
+    }
+}
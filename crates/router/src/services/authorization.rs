@@ -0,0 +1,117 @@
+use error_stack::{report, ResultExt};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    types::api::enums as api_enums,
+};
+
+/// The operations on the resources of a merchant account which dashboard users can be permitted
+/// to perform, depending on their role for the merchant account.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum Permission {
+    PaymentRead,
+    PaymentWrite,
+    RefundRead,
+    RefundWrite,
+    ApiKeyRead,
+    ApiKeyWrite,
+    MerchantAccountRead,
+    MerchantAccountWrite,
+    MerchantConnectorAccountRead,
+    MerchantConnectorAccountWrite,
+    UsersWrite,
+}
+
+const ADMIN_PERMISSIONS: &[Permission] = &[
+    Permission::PaymentRead,
+    Permission::PaymentWrite,
+    Permission::RefundRead,
+    Permission::RefundWrite,
+    Permission::ApiKeyRead,
+    Permission::ApiKeyWrite,
+    Permission::MerchantAccountRead,
+    Permission::MerchantAccountWrite,
+    Permission::MerchantConnectorAccountRead,
+    Permission::MerchantConnectorAccountWrite,
+    Permission::UsersWrite,
+];
+
+const DEVELOPER_PERMISSIONS: &[Permission] = &[
+    Permission::PaymentRead,
+    Permission::PaymentWrite,
+    Permission::RefundRead,
+    Permission::RefundWrite,
+    Permission::ApiKeyRead,
+    Permission::ApiKeyWrite,
+    Permission::MerchantAccountRead,
+    Permission::MerchantConnectorAccountRead,
+    Permission::MerchantConnectorAccountWrite,
+];
+
+const OPERATIONS_PERMISSIONS: &[Permission] = &[
+    Permission::PaymentRead,
+    Permission::PaymentWrite,
+    Permission::RefundRead,
+    Permission::RefundWrite,
+    Permission::ApiKeyRead,
+    Permission::MerchantAccountRead,
+    Permission::MerchantConnectorAccountRead,
+];
+
+const READ_ONLY_PERMISSIONS: &[Permission] = &[
+    Permission::PaymentRead,
+    Permission::RefundRead,
+    Permission::ApiKeyRead,
+    Permission::MerchantAccountRead,
+    Permission::MerchantConnectorAccountRead,
+];
+
+pub fn get_permissions(role: api_enums::DashboardRole) -> &'static [Permission] {
+    match role {
+        api_enums::DashboardRole::Admin => ADMIN_PERMISSIONS,
+        api_enums::DashboardRole::Developer => DEVELOPER_PERMISSIONS,
+        api_enums::DashboardRole::Operations => OPERATIONS_PERMISSIONS,
+        api_enums::DashboardRole::ReadOnly => READ_ONLY_PERMISSIONS,
+    }
+}
+
+pub fn check_permission(
+    role: api_enums::DashboardRole,
+    permission: Permission,
+) -> RouterResult<()> {
+    if get_permissions(role).contains(&permission) {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::InsufficientPermission {
+            permission: permission.to_string(),
+        }))
+        .attach_printable_lazy(|| format!("The {role} role does not have the permission"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_permissions() {
+        assert!(check_permission(api_enums::DashboardRole::Admin, Permission::UsersWrite).is_ok());
+        assert!(check_permission(
+            api_enums::DashboardRole::Operations,
+            Permission::RefundWrite
+        )
+        .is_ok());
+        assert!(check_permission(
+            api_enums::DashboardRole::Operations,
+            Permission::MerchantConnectorAccountWrite
+        )
+        .is_err());
+        assert!(
+            check_permission(api_enums::DashboardRole::Developer, Permission::UsersWrite).is_err()
+        );
+        assert!(
+            check_permission(api_enums::DashboardRole::ReadOnly, Permission::PaymentWrite).is_err()
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Work which outlives the request that started it, such as sending outgoing webhooks or releasing
+//! the lock on a payment, is spawned as a background task. The tasks are spawned on the runtime the
+//! application was started on, rather than on that of the actix worker handling the request, so
+//! that they are not dropped when the workers stop, and are drained before the application exits.
+
+use std::{
+    future::Future,
+    sync::{atomic, Arc},
+    time::Duration,
+};
+
+use crate::logger;
+
+const DRAIN_POLL_INTERVAL_MS: u64 = 100;
+
+#[derive(Clone, Debug)]
+pub struct BackgroundTasks {
+    active_tasks: Arc<atomic::AtomicUsize>,
+    runtime: tokio::runtime::Handle,
+}
+
+/// Counts a task as active until it completes, or panics.
+struct ActiveTaskGuard(Arc<atomic::AtomicUsize>);
+
+impl Drop for ActiveTaskGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, atomic::Ordering::AcqRel);
+    }
+}
+
+impl BackgroundTasks {
+    /// Creates the tracker of the background tasks spawned on the current runtime. Must be called
+    /// from within a tokio runtime.
+    pub fn new() -> Self {
+        Self {
+            active_tasks: Arc::new(atomic::AtomicUsize::new(0)),
+            runtime: tokio::runtime::Handle::current(),
+        }
+    }
+
+    pub fn spawn<F>(&self, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.active_tasks.fetch_add(1, atomic::Ordering::AcqRel);
+        let guard = ActiveTaskGuard(self.active_tasks.clone());
+
+        self.runtime.spawn(async move {
+            let _guard = guard;
+            task.await;
+        });
+    }
+
+    pub fn active_tasks(&self) -> usize {
+        self.active_tasks.load(atomic::Ordering::Acquire)
+    }
+
+    /// Waits for the active background tasks to complete, for at most `timeout`. Returns the
+    /// number of tasks which were still active once the timeout elapsed.
+    pub async fn drain(&self, timeout: Duration) -> usize {
+        let wait_for_tasks = async {
+            let mut interval = tokio::time::interval(Duration::from_millis(DRAIN_POLL_INTERVAL_MS));
+            while self.active_tasks() > 0 {
+                interval.tick().await;
+            }
+        };
+
+        logger::info!(
+            active_tasks = self.active_tasks(),
+            "Draining background tasks"
+        );
+        if tokio::time::timeout(timeout, wait_for_tasks).await.is_err() {
+            logger::warn!(
+                active_tasks = self.active_tasks(),
+                "Timed out while draining background tasks"
+            );
+        }
+
+        self.active_tasks()
+    }
+}
+
+impl Default for BackgroundTasks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[actix_rt::test]
+    async fn test_drain_waits_for_active_tasks() {
+        let background_tasks = BackgroundTasks::new();
+        background_tasks.spawn(async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        });
+
+        assert_eq!(background_tasks.active_tasks(), 1);
+        assert_eq!(background_tasks.drain(Duration::from_secs(5)).await, 0);
+    }
+
+    #[actix_rt::test]
+    async fn test_drain_is_bounded_by_timeout() {
+        let background_tasks = BackgroundTasks::new();
+        background_tasks.spawn(async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+        });
+
+        assert_eq!(background_tasks.drain(Duration::from_millis(10)).await, 1);
+    }
+}
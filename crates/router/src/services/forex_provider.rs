@@ -0,0 +1,113 @@
+use error_stack::{report, ResultExt};
+
+use crate::{
+    configs::settings,
+    core::errors::{self, CustomResult},
+    routes::AppState,
+    types::{storage::enums as storage_enums, transformers::ForeignFrom},
+};
+
+/// A source of foreign exchange rates, used for converting the amounts of payments to the
+/// settlement currency of the merchant.
+#[async_trait::async_trait]
+pub trait ForexProvider: Send + Sync {
+    /// Name of the provider (in lowercase).
+    fn id(&self) -> &'static str;
+
+    /// Returns the number of units of `to_currency` a unit of `from_currency` is exchanged for,
+    /// or `None` if the provider has no rate for the pair of currencies.
+    async fn get_exchange_rate(
+        &self,
+        state: &AppState,
+        from_currency: storage_enums::Currency,
+        to_currency: storage_enums::Currency,
+    ) -> CustomResult<Option<f64>, errors::ApiClientError>;
+}
+
+pub type BoxedForexProvider = Box<dyn ForexProvider>;
+
+/// Provides the exchange rates configured in the `forex.rates` section of the application
+/// configuration, which are all against a common base currency.
+pub struct StaticForexProvider;
+
+impl StaticForexProvider {
+    fn get_rate(
+        config: &settings::ForexConfig,
+        from_currency: storage_enums::Currency,
+        to_currency: storage_enums::Currency,
+    ) -> Option<f64> {
+        let from_rate = config
+            .rates
+            .get(&api_models::enums::Currency::foreign_from(from_currency))?;
+        let to_rate = config
+            .rates
+            .get(&api_models::enums::Currency::foreign_from(to_currency))?;
+        (*from_rate > 0.0).then(|| to_rate / from_rate)
+    }
+}
+
+#[async_trait::async_trait]
+impl ForexProvider for StaticForexProvider {
+    fn id(&self) -> &'static str {
+        "static"
+    }
+
+    async fn get_exchange_rate(
+        &self,
+        state: &AppState,
+        from_currency: storage_enums::Currency,
+        to_currency: storage_enums::Currency,
+    ) -> CustomResult<Option<f64>, errors::ApiClientError> {
+        Ok(Self::get_rate(
+            &state.conf.forex,
+            from_currency,
+            to_currency,
+        ))
+    }
+}
+
+/// Returns the exchange rate provider configured with the given name in the `forex` section of
+/// the application configuration.
+pub fn get_forex_provider_by_name(
+    provider_name: &str,
+) -> CustomResult<BoxedForexProvider, errors::ApiErrorResponse> {
+    match provider_name {
+        "static" => Ok(Box::new(StaticForexProvider)),
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable_lazy(|| format!("Forex provider {provider_name} is not supported")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_static_rates_are_converted_through_the_base_currency() {
+        let config = settings::ForexConfig {
+            rates: [
+                (api_models::enums::Currency::USD, 1.0),
+                (api_models::enums::Currency::EUR, 0.8),
+                (api_models::enums::Currency::GBP, 0.5),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let rate = StaticForexProvider::get_rate(
+            &config,
+            storage_enums::Currency::EUR,
+            storage_enums::Currency::GBP,
+        )
+        .unwrap();
+        assert!((rate - 0.625).abs() < f64::EPSILON);
+        assert!(StaticForexProvider::get_rate(
+            &config,
+            storage_enums::Currency::EUR,
+            storage_enums::Currency::JPY,
+        )
+        .is_none());
+    }
+}
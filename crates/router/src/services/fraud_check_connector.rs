@@ -0,0 +1,75 @@
+use error_stack::{report, ResultExt};
+
+use crate::{
+    core::errors::{self, CustomResult},
+    routes::AppState,
+    types::{self, api, storage::enums as storage_enums},
+};
+
+/// The point in the lifecycle of a payment at which it is checked by the fraud check provider.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FraudCheckCheckpoint {
+    /// Before the payment is sent to the connector for authorization
+    PreAuth,
+    /// After the payment is authorized by the connector, while the authorization can still be
+    /// voided or the payment refunded
+    PostAuth,
+}
+
+/// Details of the payment sent to an external fraud check provider.
+#[derive(Debug, Clone)]
+pub struct FraudCheckRequest {
+    pub checkpoint: FraudCheckCheckpoint,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payment_method: Option<storage_enums::PaymentMethod>,
+    pub payment_method_data: Option<api::PaymentMethodData>,
+    pub browser_info: Option<serde_json::Value>,
+    pub email: Option<masking::Secret<String, common_utils::pii::Email>>,
+    pub customer_id: Option<String>,
+    /// The connector the payment is (to be) authorized with
+    pub connector: String,
+    /// The status of the payment attempt at the connector, only known after authorization
+    pub status: Option<storage_enums::AttemptStatus>,
+    pub connector_transaction_id: Option<String>,
+}
+
+/// The verdict of a fraud check provider on a payment.
+#[derive(Debug, Clone)]
+pub struct FraudCheckResponse {
+    pub verdict: storage_enums::FraudCheckVerdict,
+    /// The risk score of the payment, on the scale of the provider
+    pub score: Option<i32>,
+    pub reason: Option<String>,
+}
+
+/// An external fraud and risk management provider (Signifyd, Riskified etc.), which scores
+/// payments before or after they are authorized by the connector.
+#[async_trait::async_trait]
+pub trait FraudCheckConnector: Send + Sync {
+    /// Name of the fraud check provider (in lowercase).
+    fn id(&self) -> &'static str;
+
+    /// Checks the payment with the provider.
+    async fn check(
+        &self,
+        state: &AppState,
+        auth_type: &types::ConnectorAuthType,
+        request: &FraudCheckRequest,
+    ) -> CustomResult<FraudCheckResponse, errors::ConnectorError>;
+}
+
+pub type BoxedFraudCheckConnector = Box<dyn FraudCheckConnector>;
+
+/// Returns the fraud check provider configured with the given name in the merchant connector
+/// account of type `payment_vas`.
+pub fn get_fraud_check_connector_by_name(
+    connector_name: &str,
+) -> CustomResult<BoxedFraudCheckConnector, errors::ApiErrorResponse> {
+    Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+        field_name: "connector_name"
+    }))
+    .attach_printable_lazy(|| format!("Fraud check connector {connector_name} is not supported"))
+}
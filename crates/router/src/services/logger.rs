@@ -2,4 +2,278 @@
 //! Logger of the system.
 //!
 
+use common_utils::pii;
+use masking::{Secret, Strategy, WithType};
+
 pub use crate::logger::*;
+
+/// How the value of a field holding personal or card data is masked in logged bodies.
+enum FieldMasking {
+    CardNumber,
+    Email,
+    ClientSecret,
+    IpAddress,
+    Full,
+}
+
+/// Converts a field name in camelCase, PascalCase or kebab-case, as connectors name their fields,
+/// to snake_case, such as `cardNumber`, `CardNumber` and `card-number` to `card_number`.
+fn to_snake_case(field: &str) -> String {
+    let mut snake_case = String::with_capacity(field.len() + 4);
+    let mut chars = field.chars().peekable();
+    let mut previous: Option<char> = None;
+    while let Some(c) = chars.next() {
+        let is_word_start = c.is_ascii_uppercase()
+            && previous.map_or(false, |previous| {
+                previous.is_ascii_lowercase()
+                    || previous.is_ascii_digit()
+                    || (previous.is_ascii_uppercase()
+                        && chars.peek().map_or(false, char::is_ascii_lowercase))
+            });
+        if is_word_start {
+            snake_case.push('_');
+        }
+        snake_case.push(if c == '-' {
+            '_'
+        } else {
+            c.to_ascii_lowercase()
+        });
+        previous = Some(c);
+    }
+    snake_case
+}
+
+fn get_field_masking(field: &str) -> Option<FieldMasking> {
+    match to_snake_case(field).as_str() {
+        "card_number" | "number" | "pan" => Some(FieldMasking::CardNumber),
+        "email" => Some(FieldMasking::Email),
+        "client_secret" => Some(FieldMasking::ClientSecret),
+        "ip_address" | "ip" => Some(FieldMasking::IpAddress),
+        "card_cvc" | "cvc" | "cvv" | "cvv2" | "cvc2" | "cvn" | "card_code" | "security_code"
+        | "card_exp_month" | "card_exp_year" | "exp_month" | "exp_year" | "expiration_date"
+        | "expiration_month" | "expiration_year" | "expiry_date" | "expiry_month"
+        | "expiry_year" | "card_holder_name" | "holder_name" | "first_name" | "last_name"
+        | "line1" | "line2" | "line3" | "zip" | "phone" | "account_number" | "routing_number"
+        | "iban" | "bic" | "sort_code" | "password" | "api_key" => Some(FieldMasking::Full),
+        _ => None,
+    }
+}
+
+/// Whether the value is a card number, by its length and Luhn checksum, so that card numbers are
+/// masked even in fields which are not known to hold them.
+fn is_card_number(value: &str) -> bool {
+    let digits = value
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-'))
+        .map(|c| c.to_digit(10))
+        .collect::<Option<Vec<_>>>();
+    match digits {
+        Some(digits) if (13..=19).contains(&digits.len()) => {
+            let checksum: u32 = digits
+                .iter()
+                .rev()
+                .enumerate()
+                .map(|(index, digit)| match (index % 2, digit * 2) {
+                    (0, _) => *digit,
+                    (_, doubled) if doubled > 9 => doubled - 9,
+                    (_, doubled) => doubled,
+                })
+                .sum();
+            checksum % 10 == 0
+        }
+        _ => false,
+    }
+}
+
+fn mask_with<S: Strategy<String>>(value: String) -> String {
+    format!("{:?}", Secret::<String, S>::new(value))
+}
+
+fn mask_string(masking: &FieldMasking, value: String) -> String {
+    match masking {
+        FieldMasking::CardNumber => mask_with::<pii::CardNumber>(value),
+        FieldMasking::Email => mask_with::<pii::Email>(value),
+        FieldMasking::ClientSecret => mask_with::<pii::ClientSecret>(value),
+        FieldMasking::IpAddress => mask_with::<pii::IpAddress>(value),
+        FieldMasking::Full => mask_with::<WithType>(value),
+    }
+}
+
+fn mask_json_value(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (field, value) in fields.iter_mut() {
+                match (get_field_masking(field), value.take()) {
+                    (Some(masking), serde_json::Value::String(inner)) => {
+                        *value = serde_json::Value::String(mask_string(&masking, inner));
+                    }
+                    (Some(_), serde_json::Value::Null) => {}
+                    (Some(_), inner) => {
+                        *value = serde_json::Value::String(format!(
+                            "{:?}",
+                            Secret::<serde_json::Value>::new(inner)
+                        ));
+                    }
+                    (None, mut inner) => {
+                        mask_json_value(&mut inner);
+                        *value = inner;
+                    }
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(mask_json_value),
+        serde_json::Value::String(inner) if is_card_number(inner) => {
+            *inner = mask_string(&FieldMasking::CardNumber, std::mem::take(inner));
+        }
+        _ => {}
+    }
+}
+
+/// The name of the innermost field of a form encoded key, such as `number` in `card[number]`.
+fn get_form_field_name(key: &str) -> &str {
+    key.rsplit(|c| c == '[' || c == ']')
+        .find(|segment| !segment.is_empty())
+        .unwrap_or(key)
+}
+
+fn is_form_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '[' | ']'))
+}
+
+/// Masks the personal and card data in a JSON or form encoded body, so that the body can be
+/// logged. Card numbers are masked wherever they appear in the body, and bodies in any other
+/// format are masked entirely.
+pub fn mask_body(body: &[u8]) -> String {
+    if body.is_empty() {
+        return String::new();
+    }
+
+    if let Ok(mut value) = serde_json::from_slice::<serde_json::Value>(body) {
+        mask_json_value(&mut value);
+        return value.to_string();
+    }
+
+    let form_fields = serde_urlencoded::from_bytes::<Vec<(String, String)>>(body)
+        .ok()
+        .filter(|fields| !fields.is_empty() && fields.iter().all(|(key, _)| is_form_key(key)));
+    if let Some(form_fields) = form_fields {
+        let masked_fields = form_fields
+            .into_iter()
+            .map(|(key, value)| {
+                let value = match get_field_masking(get_form_field_name(&key)) {
+                    Some(masking) => mask_string(&masking, value),
+                    None if is_card_number(&value) => mask_string(&FieldMasking::CardNumber, value),
+                    None => value,
+                };
+                (key, value)
+            })
+            .collect::<Vec<_>>();
+        if let Ok(masked_body) = serde_urlencoded::to_string(masked_fields) {
+            return masked_body;
+        }
+    }
+
+    mask_with::<WithType>(String::from_utf8_lossy(body).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_body_is_masked() {
+        let body = serde_json::json!({
+            "amount": 6540,
+            "email": "guest@example.com",
+            "payment_method_data": {
+                "card": {
+                    "card_number": "4242424242424242",
+                    "card_cvc": "123",
+                    "card_exp_month": 10
+                }
+            }
+        });
+        let masked: serde_json::Value =
+            serde_json::from_str(&mask_body(body.to_string().as_bytes())).unwrap_or_default();
+
+        assert_eq!(masked["amount"], 6540);
+        assert_eq!(masked["email"], "*****@example.com");
+        let card = &masked["payment_method_data"]["card"];
+        assert_eq!(card["card_number"], "424242**********");
+        assert_ne!(card["card_cvc"], "123");
+        assert_ne!(card["card_exp_month"], 10);
+    }
+
+    #[test]
+    fn test_json_body_with_camel_case_fields_is_masked() {
+        let body = serde_json::json!({
+            "createTransactionRequest": {
+                "transactionRequest": {
+                    "amount": 6540,
+                    "payment": {
+                        "creditCard": {
+                            "cardNumber": "4242424242424242",
+                            "expirationDate": "2030-10",
+                            "cardCode": "123"
+                        }
+                    }
+                }
+            },
+            "paymentInformation": {
+                "card": {
+                    "securityCode": "456",
+                    "expirationMonth": "10"
+                }
+            },
+            "CVV": "789"
+        });
+        let masked = mask_body(body.to_string().as_bytes());
+
+        assert!(masked.contains("6540"));
+        assert!(masked.contains("424242**********"));
+        for value in ["2030-10", "123", "456", "\"10\"", "789"] {
+            assert!(!masked.contains(value), "{value} was not masked");
+        }
+    }
+
+    #[test]
+    fn test_card_numbers_in_unknown_fields_are_masked() {
+        let body = serde_json::json!({
+            "reference": "4111111111111111",
+            "values": ["5424 0000 0000 0015"],
+            "order_id": "4111111111111112"
+        });
+        let masked = mask_body(body.to_string().as_bytes());
+
+        assert!(!masked.contains("4111111111111111"));
+        assert!(!masked.contains("5424 0000 0000 0015"));
+        assert!(masked.contains("4111111111111112"));
+        assert!(!mask_body(b"amount=6540&reference=4111111111111111").contains("1111111111"));
+    }
+
+    #[test]
+    fn test_field_names_are_converted_to_snake_case() {
+        assert_eq!(to_snake_case("cardNumber"), "card_number");
+        assert_eq!(to_snake_case("CardHolderName"), "card_holder_name");
+        assert_eq!(to_snake_case("IPAddress"), "ip_address");
+        assert_eq!(to_snake_case("CVV"), "cvv");
+        assert_eq!(to_snake_case("card-number"), "card_number");
+        assert_eq!(to_snake_case("line1"), "line1");
+    }
+
+    #[test]
+    fn test_form_body_is_masked() {
+        let masked = mask_body(b"amount=6540&card[number]=4242424242424242&card[cvc]=123");
+
+        assert!(masked.starts_with("amount=6540&card%5Bnumber%5D=424242**********"));
+        assert!(!masked.contains("123"));
+    }
+
+    #[test]
+    fn test_unknown_body_is_masked_entirely() {
+        assert!(!mask_body(b"<card><number>4242424242424242</number></card>").contains("4242"));
+    }
+}
@@ -13,12 +13,13 @@ pub mod transformers;
 use std::marker::PhantomData;
 
 pub use api_models::enums::Connector;
+pub use common_utils::types::MinorUnit;
 use common_utils::{pii, pii::Email};
 use error_stack::{IntoReport, ResultExt};
 
 use self::{api::payments, storage::enums as storage_enums};
 pub use crate::core::payments::PaymentAddress;
-use crate::{core::errors, services};
+use crate::{core::errors, services, utils::OptionExt};
 
 pub type PaymentsAuthorizeRouterData =
     RouterData<api::Authorize, PaymentsAuthorizeData, PaymentsResponseData>;
@@ -41,6 +42,20 @@ pub type RefundSyncRouterData = RouterData<api::RSync, RefundsData, RefundsRespo
 pub type RefreshTokenRouterData =
     RouterData<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
 
+pub type MandateRevokeRouterData =
+    RouterData<api::MandateRevoke, MandateRevokeRequestData, MandateRevokeResponseData>;
+
+pub type VerifyCredentialsRouterData =
+    RouterData<api::VerifyCredentials, VerifyCredentialsRequestData, VerifyCredentialsResponseData>;
+
+pub type GiftCardBalanceRouterData =
+    RouterData<api::Balance, GiftCardBalanceRequestData, GiftCardBalanceResponseData>;
+
+pub type PayoutsRouterData<F> = RouterData<F, PayoutsData, PayoutsResponseData>;
+pub type PayoutFulfillRouterData = RouterData<api::PoFulfill, PayoutsData, PayoutsResponseData>;
+pub type PayoutSyncRouterData = RouterData<api::PoSync, PayoutsData, PayoutsResponseData>;
+pub type PayoutCancelRouterData = RouterData<api::PoCancel, PayoutsData, PayoutsResponseData>;
+
 pub type PaymentsResponseRouterData<R> =
     ResponseRouterData<api::Authorize, R, PaymentsAuthorizeData, PaymentsResponseData>;
 pub type PaymentsCancelResponseRouterData<R> =
@@ -55,6 +70,9 @@ pub type PaymentsCaptureResponseRouterData<R> =
 pub type RefundsResponseRouterData<F, R> =
     ResponseRouterData<F, R, RefundsData, RefundsResponseData>;
 
+pub type PayoutsResponseRouterData<F, R> =
+    ResponseRouterData<F, R, PayoutsData, PayoutsResponseData>;
+
 pub type PaymentsAuthorizeType =
     dyn services::ConnectorIntegration<api::Authorize, PaymentsAuthorizeData, PaymentsResponseData>;
 pub type PaymentsComeplteAuthorizeType = dyn services::ConnectorIntegration<
@@ -80,6 +98,8 @@ pub type PaymentsSessionType =
     dyn services::ConnectorIntegration<api::Session, PaymentsSessionData, PaymentsResponseData>;
 pub type PaymentsVoidType =
     dyn services::ConnectorIntegration<api::Void, PaymentsCancelData, PaymentsResponseData>;
+pub type PaymentsVerifyType =
+    dyn services::ConnectorIntegration<api::Verify, VerifyRequestData, PaymentsResponseData>;
 
 pub type RefundExecuteType =
     dyn services::ConnectorIntegration<api::Execute, RefundsData, RefundsResponseData>;
@@ -89,8 +109,78 @@ pub type RefundSyncType =
 pub type RefreshTokenType =
     dyn services::ConnectorIntegration<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
 
+pub type MandateRevokeType = dyn services::ConnectorIntegration<
+    api::MandateRevoke,
+    MandateRevokeRequestData,
+    MandateRevokeResponseData,
+>;
+
+pub type VerifyCredentialsType = dyn services::ConnectorIntegration<
+    api::VerifyCredentials,
+    VerifyCredentialsRequestData,
+    VerifyCredentialsResponseData,
+>;
+
+pub type GiftCardBalanceType = dyn services::ConnectorIntegration<
+    api::Balance,
+    GiftCardBalanceRequestData,
+    GiftCardBalanceResponseData,
+>;
+
+pub type PayoutFulfillType =
+    dyn services::ConnectorIntegration<api::PoFulfill, PayoutsData, PayoutsResponseData>;
+pub type PayoutSyncType =
+    dyn services::ConnectorIntegration<api::PoSync, PayoutsData, PayoutsResponseData>;
+pub type PayoutCancelType =
+    dyn services::ConnectorIntegration<api::PoCancel, PayoutsData, PayoutsResponseData>;
+
 pub type VerifyRouterData = RouterData<api::Verify, VerifyRequestData, PaymentsResponseData>;
 
+/// The currency of a payment once it is known to be set, which the currency of the payment attempt
+/// is not until the payment is confirmed. Amounts in [`MinorUnit`] are converted to the major unit
+/// through it, so that they always have as many digits after the decimal separator as the
+/// currency they are in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Currency(storage_enums::Currency);
+
+impl Currency {
+    pub fn get_currency(self) -> storage_enums::Currency {
+        self.0
+    }
+
+    /// Formats the amount in the major unit of the currency, such as `"10.50"` for 1050 USD
+    /// cents.
+    pub fn to_major_unit_as_string(self, amount: MinorUnit) -> String {
+        amount.to_major_unit_as_string(self.0.number_of_digits_after_decimal_point())
+    }
+}
+
+impl From<storage_enums::Currency> for Currency {
+    fn from(currency: storage_enums::Currency) -> Self {
+        Self(currency)
+    }
+}
+
+impl From<Currency> for storage_enums::Currency {
+    fn from(currency: Currency) -> Self {
+        currency.0
+    }
+}
+
+impl TryFrom<Option<storage_enums::Currency>> for Currency {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(currency: Option<storage_enums::Currency>) -> Result<Self, Self::Error> {
+        currency.get_required_value("currency").map(Self)
+    }
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RouterData<Flow, Request, Response> {
     pub flow: PhantomData<Flow>,
@@ -119,12 +209,18 @@ pub struct RouterData<Flow, Request, Response> {
 
     /// Contains any error response that the connector returns.
     pub payment_method_id: Option<String>,
+
+    /// Contains the raw response of the connector, as received from the connector.
+    pub connector_raw_response: Option<serde_json::Value>,
+
+    /// Whether the requests made to the connector are stored along with their responses.
+    pub connector_debug_mode: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct PaymentsAuthorizeData {
     pub payment_method_data: payments::PaymentMethodData,
-    pub amount: i64,
+    pub amount: MinorUnit,
     pub email: Option<masking::Secret<String, Email>>,
     pub currency: storage_enums::Currency,
     pub confirm: bool,
@@ -140,12 +236,55 @@ pub struct PaymentsAuthorizeData {
     pub off_session: Option<bool>,
     pub setup_mandate_details: Option<payments::MandateData>,
     pub browser_info: Option<BrowserInformation>,
-    pub order_details: Option<api_models::payments::OrderDetails>,
+    pub order_details: Option<Vec<api_models::payments::OrderDetails>>,
+    pub amount_breakdown: Option<api_models::payments::AmountBreakdown>,
+    pub l2_l3_data: Option<api_models::payments::L2L3Data>,
     pub session_token: Option<String>,
     pub enrolled_for_3ds: bool,
     pub related_transaction_id: Option<String>,
     pub payment_experience: Option<storage_enums::PaymentExperience>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    // Results of the 3DS authentication performed by an external provider
+    pub three_ds_data: Option<ThreeDsData>,
+    // Network token of the card, to be preferred over the card number by connectors which
+    // support network tokens
+    pub network_token: Option<NetworkTokenData>,
+    // Payment data of the Apple Pay token, when it was decrypted by the router instead of the
+    // connector
+    pub apple_pay_predecrypt_data: Option<ApplePayPredecryptData>,
+    // Whether the payment stores the payment method for future payments, or is a merchant
+    // initiated payment using a stored payment method
+    pub stored_credential: Option<StoredCredentialIndicator>,
+    // Transfers of the shares of the payment to sub-merchants of the platform, for connectors
+    // which support split payments
+    pub split_payments: Option<SplitPaymentsData>,
+}
+
+/// The transfers of the shares of a split payment to the connector accounts of the sub-merchants
+/// of the platform. The platform retains the remaining amount of the payment as its fee.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitPaymentsData {
+    pub platform_fee: i64,
+    pub transfers: Vec<SplitTransfer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitTransfer {
+    pub connector_account_id: String,
+    pub amount: i64,
+}
+
+/// Card networks require payments made with stored payment methods to be flagged, so that the
+/// issuer can tell the customer initiated payment which stored the payment method apart from the
+/// merchant initiated payments which follow it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StoredCredentialIndicator {
+    /// Customer initiated payment which stores the payment method for future use
+    InitialCustomerInitiated,
+    /// Merchant initiated payment using a stored payment method, without the customer present
+    SubsequentMerchantInitiated {
+        network_transaction_id: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone, Default)]
@@ -162,13 +301,13 @@ pub struct AuthorizeSessionTokenData {
     pub amount_to_capture: Option<i64>,
     pub currency: storage_enums::Currency,
     pub connector_transaction_id: String,
-    pub amount: i64,
+    pub amount: MinorUnit,
 }
 
 #[derive(Debug, Clone)]
 pub struct CompleteAuthorizeData {
     pub payment_method_data: Option<payments::PaymentMethodData>,
-    pub amount: i64,
+    pub amount: MinorUnit,
     pub email: Option<masking::Secret<String, Email>>,
     pub currency: storage_enums::Currency,
     pub confirm: bool,
@@ -182,6 +321,7 @@ pub struct CompleteAuthorizeData {
     pub browser_info: Option<BrowserInformation>,
     pub connector_transaction_id: Option<String>,
     pub connector_meta: Option<serde_json::Value>,
+    pub three_ds_data: Option<ThreeDsData>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -199,15 +339,17 @@ pub struct PaymentsCancelData {
     pub currency: Option<storage_enums::Currency>,
     pub connector_transaction_id: String,
     pub cancellation_reason: Option<String>,
+    pub cancellation_metadata: Option<pii::SecretSerdeValue>,
     pub connector_meta: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone)]
 pub struct PaymentsSessionData {
-    pub amount: i64,
+    pub amount: MinorUnit,
     pub currency: storage_enums::Currency,
     pub country: Option<api::enums::CountryCode>,
-    pub order_details: Option<api_models::payments::OrderDetails>,
+    pub order_details: Option<Vec<api_models::payments::OrderDetails>>,
+    pub amount_breakdown: Option<api_models::payments::AmountBreakdown>,
 }
 
 #[derive(Debug, Clone)]
@@ -247,6 +389,7 @@ pub enum PaymentsResponseData {
         redirection_data: Option<services::RedirectForm>,
         mandate_reference: Option<String>,
         connector_metadata: Option<serde_json::Value>,
+        verification_result: Option<VerificationResult>,
     },
     SessionResponse {
         session_token: api::SessionToken,
@@ -256,6 +399,14 @@ pub enum PaymentsResponseData {
     },
 }
 
+/// Results of the verification of the billing address and the card security code of the customer
+/// by the issuer, as reported by the connector
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct VerificationResult {
+    pub avs_result: Option<storage_enums::AvsResult>,
+    pub cvc_result: Option<storage_enums::CvcResult>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub enum ResponseId {
     ConnectorTransactionId(String),
@@ -309,6 +460,82 @@ pub struct BrowserInformation {
     pub user_agent: String,
 }
 
+/// The artifacts of a 3DS 2.0 authentication performed for a payment attempt
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ThreeDsData {
+    pub device_channel: api_models::enums::ThreeDsDeviceChannel,
+    pub device_data: Option<serde_json::Value>,
+    pub message_version: String,
+    pub three_ds_server_trans_id: String,
+    pub ds_trans_id: Option<String>,
+    pub acs_trans_id: Option<String>,
+    pub trans_status: api_models::enums::ThreeDsTransStatus,
+    pub trans_status_reason: Option<String>,
+    pub eci: Option<String>,
+    pub authentication_value: Option<masking::Secret<String>>,
+    pub ares: Option<serde_json::Value>,
+    pub rreq: Option<serde_json::Value>,
+}
+
+/// A network token provisioned with the token service of the card network, along with the
+/// cryptogram for the current transaction
+#[derive(Debug, Clone)]
+pub struct NetworkTokenData {
+    pub token_number: masking::Secret<String, pii::CardNumber>,
+    pub token_exp_month: masking::Secret<String>,
+    pub token_exp_year: masking::Secret<String>,
+    pub cryptogram: Option<masking::Secret<String>>,
+    pub token_service_provider: api_models::enums::CardNetwork,
+}
+
+/// The payment data of an Apple Pay token decrypted by the router
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplePayPredecryptData {
+    pub application_primary_account_number: masking::Secret<String, pii::CardNumber>,
+    /// Expiry date of the device account number, in the `YYMMDD` format
+    pub application_expiration_date: String,
+    pub currency_code: String,
+    pub transaction_amount: i64,
+    pub device_manufacturer_identifier: masking::Secret<String>,
+    pub payment_data_type: String,
+    pub payment_data: ApplePayCryptogramData,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplePayCryptogramData {
+    pub online_payment_cryptogram: masking::Secret<String>,
+    pub eci_indicator: Option<String>,
+}
+
+impl ApplePayPredecryptData {
+    pub fn get_expiry_month(&self) -> masking::Secret<String> {
+        masking::Secret::new(
+            self.application_expiration_date
+                .get(2..4)
+                .unwrap_or_default()
+                .to_string(),
+        )
+    }
+
+    pub fn get_four_digit_expiry_year(&self) -> masking::Secret<String> {
+        masking::Secret::new(format!(
+            "20{}",
+            self.application_expiration_date
+                .get(0..2)
+                .unwrap_or_default()
+        ))
+    }
+}
+
+/// Connector metadata of the payment attempt, stored by connectors for payment methods where the
+/// customer completes the payment by scanning a QR code
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct QrCodeNextStepsInstruction {
+    pub qr_code_information: api_models::payments::QrCodeInformation,
+}
+
 #[derive(Debug, Clone)]
 pub struct RefundsResponseData {
     pub connector_refund_id: String,
@@ -316,6 +543,61 @@ pub struct RefundsResponseData {
     // pub amount_received: Option<i32>, // Calculation for amount received not in place yet
 }
 
+#[derive(Debug, Clone)]
+pub struct MandateRevokeRequestData {
+    pub mandate_id: String,
+    pub connector_mandate_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MandateRevokeResponseData {
+    pub mandate_status: storage_enums::MandateStatus,
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifyCredentialsRequestData;
+
+#[derive(Debug, Clone)]
+pub struct VerifyCredentialsResponseData {
+    /// Whether the credentials were verified with the connector, connectors which do not support
+    /// verifying credentials leave this unset
+    pub verified: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct GiftCardBalanceRequestData {
+    pub gift_card_data: api_models::payments::GiftCardData,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+}
+
+#[derive(Debug, Clone)]
+pub struct GiftCardBalanceResponseData {
+    /// The balance available on the gift card, in the lowest denomination of the currency
+    pub balance: i64,
+    pub currency: storage_enums::Currency,
+}
+
+#[derive(Debug, Clone)]
+pub struct PayoutsData {
+    pub payout_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payout_type: storage_enums::PayoutType,
+    pub payout_method_data: api::PayoutMethodData,
+    pub customer_id: Option<String>,
+    pub email: Option<masking::Secret<String, Email>>,
+    /// The identifier of the payout at the connector, known once the payout is submitted
+    pub connector_payout_id: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PayoutsResponseData {
+    pub status: storage_enums::PayoutStatus,
+    pub connector_payout_id: Option<String>,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Redirection {
     Redirect,
@@ -472,6 +754,8 @@ impl<F1, F2, T1, T2> From<(&&mut RouterData<F1, T1, PaymentsResponseData>, T2)>
             access_token: data.access_token.clone(),
             response: data.response.clone(),
             payment_method_id: data.payment_method_id.clone(),
+            connector_raw_response: data.connector_raw_response.clone(),
+            connector_debug_mode: data.connector_debug_mode,
             payment_id: data.payment_id.clone(),
             session_token: data.session_token.clone(),
             reference_id: data.reference_id.clone(),
@@ -1,13 +1,25 @@
 pub mod admin;
 pub mod api_keys;
+pub mod balance;
+pub mod business_profile;
 pub mod configs;
 pub mod customers;
+pub mod dead_letter;
 pub mod disputes;
+pub mod dummy_connector;
 pub mod enums;
 pub mod mandates;
+pub mod organization;
+pub mod payment_links;
 pub mod payment_methods;
+pub mod payment_schedules;
 pub mod payments;
+pub mod payouts;
+pub mod recon;
 pub mod refunds;
+pub mod routing;
+pub mod user;
+pub mod vault;
 pub mod webhooks;
 
 use std::{fmt::Debug, str::FromStr};
@@ -15,8 +27,10 @@ use std::{fmt::Debug, str::FromStr};
 use error_stack::{report, IntoReport, ResultExt};
 
 pub use self::{
-    admin::*, api_keys::*, configs::*, customers::*, payment_methods::*, payments::*, refunds::*,
-    webhooks::*,
+    admin::*, api_keys::*, balance::*, business_profile::*, configs::*, customers::*,
+    dead_letter::*, dummy_connector::*, mandates::*, organization::*, payment_links::*,
+    payment_methods::*, payment_schedules::*, payments::*, payouts::*, recon::*, refunds::*,
+    routing::*, user::*, vault::*, webhooks::*,
 };
 use super::ErrorResponse;
 use crate::{
@@ -80,6 +94,21 @@ pub trait ConnectorCommon {
             reason: None,
         })
     }
+
+    /// Unified code of an error code returned by the connector, for connectors which map their
+    /// error codes to the codes shared across connectors
+    fn get_unified_code(&self, _error_code: &str) -> Option<api_enums::UnifiedCode> {
+        None
+    }
+
+    /// Fields of the payment method data which the connector requires for the payment method
+    /// type, beyond those which every connector requires
+    fn get_required_fields(
+        &self,
+        _payment_method_type: api_enums::PaymentMethodType,
+    ) -> &'static [RequiredField] {
+        &[]
+    }
 }
 
 /// Extended trait for connector common to allow functions with generic type
@@ -107,6 +136,10 @@ pub trait Connector:
     + IncomingWebhook
     + ConnectorAccessToken
     + ConnectorTransactionId
+    + ConnectorMandateRevoke
+    + ConnectorVerifyCredentials
+    + ConnectorGiftCardBalance
+    + ConnectorPayouts
 {
 }
 
@@ -122,7 +155,11 @@ impl<
             + Send
             + IncomingWebhook
             + ConnectorAccessToken
-            + ConnectorTransactionId,
+            + ConnectorTransactionId
+            + ConnectorMandateRevoke
+            + ConnectorVerifyCredentials
+            + ConnectorGiftCardBalance
+            + ConnectorPayouts,
     > Connector for T
 {
 }
@@ -165,7 +202,7 @@ impl ConnectorData {
         name: &str,
         connector_type: GetToken,
     ) -> CustomResult<Self, errors::ApiErrorResponse> {
-        let connector = Self::convert_connector(connectors, name)?;
+        let connector = Self::convert_connector(name)?;
         let connector_name = api_enums::Connector::from_str(name)
             .into_report()
             .change_context(errors::ConnectorError::InvalidConnectorName)
@@ -178,8 +215,27 @@ impl ConnectorData {
         })
     }
 
+    /// Unified code of an error code returned by the connector with the name
+    pub fn get_unified_code(
+        connector_name: &str,
+        error_code: &str,
+    ) -> Option<api_enums::UnifiedCode> {
+        Self::convert_connector(connector_name)
+            .ok()?
+            .get_unified_code(error_code)
+    }
+
+    /// Required fields of the payment method type for the connector with the name
+    pub fn get_required_fields(
+        connector_name: &str,
+        payment_method_type: api_enums::PaymentMethodType,
+    ) -> &'static [RequiredField] {
+        Self::convert_connector(connector_name)
+            .map(|connector| connector.get_required_fields(payment_method_type))
+            .unwrap_or(&[])
+    }
+
     fn convert_connector(
-        _connectors: &Connectors,
         connector_name: &str,
     ) -> CustomResult<BoxedConnector, errors::ApiErrorResponse> {
         match connector_name {
@@ -192,8 +248,11 @@ impl ConnectorData {
             "bluesnap" => Ok(Box::new(&connector::Bluesnap)),
             "braintree" => Ok(Box::new(&connector::Braintree)),
             "checkout" => Ok(Box::new(&connector::Checkout)),
+            "coinbase" => Ok(Box::new(&connector::Coinbase)),
             "cybersource" => Ok(Box::new(&connector::Cybersource)),
             "dlocal" => Ok(Box::new(&connector::Dlocal)),
+            "dummyconnector" => Ok(Box::new(&connector::DummyConnector)),
+            "externalprocessor" => Ok(Box::new(&connector::Externalprocessor)),
             "fiserv" => Ok(Box::new(&connector::Fiserv)),
             "globalpay" => Ok(Box::new(&connector::Globalpay)),
             "klarna" => Ok(Box::new(&connector::Klarna)),
@@ -201,6 +260,7 @@ impl ConnectorData {
             "nuvei" => Ok(Box::new(&connector::Nuvei)),
             "payu" => Ok(Box::new(&connector::Payu)),
             "rapyd" => Ok(Box::new(&connector::Rapyd)),
+            "razorpay" => Ok(Box::new(&connector::Razorpay)),
             "shift4" => Ok(Box::new(&connector::Shift4)),
             "stripe" => Ok(Box::new(&connector::Stripe)),
             "worldline" => Ok(Box::new(&connector::Worldline)),
@@ -1,12 +1,16 @@
 pub use api_models::admin::{
-    MerchantAccountCreate, MerchantAccountDeleteResponse, MerchantAccountResponse,
-    MerchantAccountUpdate, MerchantConnector, MerchantConnectorDeleteResponse,
-    MerchantConnectorDetails, MerchantConnectorDetailsWrap, MerchantConnectorId, MerchantDetails,
-    MerchantId, PaymentMethodsEnabled, RoutingAlgorithm, ToggleKVRequest, ToggleKVResponse,
-    WebhookDetails,
+    ConnectorCredentialsKeyRotateResponse, ConnectorVolumeSplit, MerchantAccountCreate,
+    MerchantAccountDeleteResponse, MerchantAccountResponse, MerchantAccountUpdate,
+    MerchantConnector, MerchantConnectorDeleteResponse, MerchantConnectorDetails,
+    MerchantConnectorDetailsWrap, MerchantConnectorId, MerchantDetails, MerchantId,
+    PaymentMethodsEnabled, RoutingAlgorithm, SuccessRateRoutingAlgorithm, ToggleKVRequest,
+    ToggleKVResponse, ToggleMerchantConnectorRequest, WebhookDetails,
 };
 
-use crate::types::{storage, transformers::ForeignFrom};
+use crate::{
+    services,
+    types::{self, storage, transformers::ForeignFrom},
+};
 
 impl ForeignFrom<storage::MerchantAccount> for MerchantAccountResponse {
     fn foreign_from(value: storage::MerchantAccount) -> Self {
@@ -19,6 +23,7 @@ impl ForeignFrom<storage::MerchantAccount> for MerchantAccountResponse {
             enable_payment_response_hash: item.enable_payment_response_hash,
             payment_response_hash_key: item.payment_response_hash_key,
             redirect_to_merchant_with_http_post: item.redirect_to_merchant_with_http_post,
+            return_raw_connector_response: item.return_raw_connector_response,
             merchant_details: item.merchant_details,
             webhook_details: item.webhook_details,
             routing_algorithm: item.routing_algorithm,
@@ -27,6 +32,19 @@ impl ForeignFrom<storage::MerchantAccount> for MerchantAccountResponse {
             publishable_key: item.publishable_key,
             metadata: item.metadata,
             locker_id: item.locker_id,
+            organization_id: item.organization_id,
         }
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct VerifyCredentials;
+
+pub trait ConnectorVerifyCredentials:
+    services::ConnectorIntegration<
+    VerifyCredentials,
+    types::VerifyCredentialsRequestData,
+    types::VerifyCredentialsResponseData,
+>
+{
+}
@@ -0,0 +1,22 @@
+pub use api_models::balance::{
+    BalanceResponse, BalanceTransactionListConstraints, BalanceTransactionListResponse,
+    BalanceTransactionResponse, CurrencyBalance,
+};
+
+use crate::types::{storage, transformers::ForeignFrom};
+
+impl ForeignFrom<storage::BalanceTransaction> for BalanceTransactionResponse {
+    fn foreign_from(balance_transaction: storage::BalanceTransaction) -> Self {
+        Self {
+            transaction_id: balance_transaction.transaction_id,
+            transaction_type: ForeignFrom::foreign_from(balance_transaction.transaction_type),
+            source_id: balance_transaction.source_id,
+            payment_id: balance_transaction.payment_id,
+            amount: balance_transaction.amount,
+            currency: ForeignFrom::foreign_from(balance_transaction.currency),
+            debit_account: ForeignFrom::foreign_from(balance_transaction.debit_account),
+            credit_account: ForeignFrom::foreign_from(balance_transaction.credit_account),
+            created_at: balance_transaction.created_at,
+        }
+    }
+}
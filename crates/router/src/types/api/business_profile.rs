@@ -0,0 +1,26 @@
+pub use api_models::business_profile::{
+    BusinessProfileCreate, BusinessProfileDeleteResponse, BusinessProfileResponse,
+    BusinessProfileUpdate,
+};
+
+use crate::types::{storage, transformers::ForeignFrom};
+
+impl ForeignFrom<storage::BusinessProfile> for BusinessProfileResponse {
+    fn foreign_from(business_profile: storage::BusinessProfile) -> Self {
+        Self {
+            profile_id: business_profile.profile_id,
+            merchant_id: business_profile.merchant_id,
+            profile_name: business_profile.profile_name,
+            return_url: business_profile.return_url,
+            webhook_details: business_profile.webhook_details,
+            routing_algorithm: business_profile.routing_algorithm,
+            statement_descriptor_name: business_profile.statement_descriptor_name,
+            statement_descriptor_suffix: business_profile.statement_descriptor_suffix,
+            auto_capture_delay: business_profile
+                .auto_capture_delay
+                .and_then(|auto_capture_delay| u32::try_from(auto_capture_delay).ok()),
+            created_at: business_profile.created_at,
+            modified_at: business_profile.modified_at,
+        }
+    }
+}
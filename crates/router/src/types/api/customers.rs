@@ -1,15 +1,11 @@
 use api_models::customers;
-pub use api_models::customers::{CustomerDeleteResponse, CustomerId, CustomerRequest};
-use error_stack::ResultExt;
+pub use api_models::customers::{
+    CustomerDeleteResponse, CustomerId, CustomerListConstraints, CustomerRedactResponse,
+    CustomerRequest,
+};
 use serde::Serialize;
 
-use crate::{
-    core::errors::{self, RouterResult},
-    newtype,
-    pii::PeekInterface,
-    types::storage,
-    utils::{self, ValidateCall},
-};
+use crate::{core::errors::RouterResult, newtype, types::storage, utils::validation};
 
 newtype!(
     pub CustomerResponse = customers::CustomerResponse,
@@ -22,13 +18,7 @@ pub(crate) trait CustomerRequestExt: Sized {
 
 impl CustomerRequestExt for CustomerRequest {
     fn validate(self) -> RouterResult<Self> {
-        self.email
-            .as_ref()
-            .validate_opt(|email| utils::validate_email(email.peek()))
-            .change_context(errors::ApiErrorResponse::InvalidDataFormat {
-                field_name: "email".to_string(),
-                expected_format: "valid email address".to_string(),
-            })?;
+        validation::validate_request(&self)?;
 
         Ok(self)
     }
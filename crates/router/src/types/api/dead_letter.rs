@@ -0,0 +1,22 @@
+pub use api_models::dead_letter::{
+    DeadLetterTaskListConstraints, DeadLetterTaskListResponse, DeadLetterTaskReplayRequest,
+    DeadLetterTaskReplayResponse, DeadLetterTaskResponse,
+};
+
+use crate::types::{storage, transformers::ForeignFrom};
+
+impl ForeignFrom<storage::ProcessTracker> for DeadLetterTaskResponse {
+    fn foreign_from(process: storage::ProcessTracker) -> Self {
+        Self {
+            task_id: process.id,
+            name: process.name,
+            runner: process.runner,
+            tag: process.tag,
+            retry_count: process.retry_count,
+            reason: process.business_status,
+            tracking_data: process.tracking_data,
+            created_at: process.created_at,
+            dead_lettered_at: process.updated_at,
+        }
+    }
+}
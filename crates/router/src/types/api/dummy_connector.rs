@@ -0,0 +1,6 @@
+pub use api_models::dummy_connector::{
+    DummyConnectorAuthenticationRequest, DummyConnectorCard, DummyConnectorError,
+    DummyConnectorNextAction, DummyConnectorPaymentRequest, DummyConnectorPaymentResponse,
+    DummyConnectorPaymentRetrieveRequest, DummyConnectorRefundRequest,
+    DummyConnectorRefundResponse, DummyConnectorRefundRetrieveRequest, DummyConnectorStatus,
+};
@@ -1,5 +1,7 @@
 use api_models::mandates;
-pub use api_models::mandates::{MandateId, MandateResponse, MandateRevokedResponse};
+pub use api_models::mandates::{
+    MandateId, MandateListConstraints, MandateResponse, MandateRevokedResponse,
+};
 use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
 
@@ -10,8 +12,9 @@ use crate::{
     },
     newtype,
     routes::AppState,
+    services,
     types::{
-        api,
+        self, api,
         storage::{self, enums as storage_enums},
         transformers::ForeignInto,
     },
@@ -102,3 +105,15 @@ impl From<api::payment_methods::CardDetailFromLocker> for MandateCardDetails {
         .into()
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct MandateRevoke;
+
+pub trait ConnectorMandateRevoke:
+    services::ConnectorIntegration<
+    MandateRevoke,
+    types::MandateRevokeRequestData,
+    types::MandateRevokeResponseData,
+>
+{
+}
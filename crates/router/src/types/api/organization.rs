@@ -0,0 +1,28 @@
+pub use api_models::organization::{
+    OrganizationCreateRequest, OrganizationPaymentsAggregateConstraints,
+    OrganizationPaymentsAggregateResponse, OrganizationResponse, PaymentsAggregate,
+};
+
+use crate::types::{storage, transformers::ForeignFrom};
+
+impl ForeignFrom<storage::Organization> for OrganizationResponse {
+    fn foreign_from(organization: storage::Organization) -> Self {
+        Self {
+            organization_id: organization.organization_id,
+            organization_name: organization.organization_name,
+            created_at: organization.created_at,
+            modified_at: organization.modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::PaymentIntentAggregate> for PaymentsAggregate {
+    fn foreign_from(aggregate: storage::PaymentIntentAggregate) -> Self {
+        Self {
+            currency: aggregate.currency.map(ForeignFrom::foreign_from),
+            status: ForeignFrom::foreign_from(aggregate.status),
+            count: aggregate.count,
+            total_amount: aggregate.total_amount.unwrap_or_default(),
+        }
+    }
+}
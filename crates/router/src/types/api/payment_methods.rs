@@ -1,12 +1,13 @@
 use std::collections::HashMap;
 
 pub use api_models::payment_methods::{
-    CardDetail, CardDetailFromLocker, CustomerPaymentMethod, CustomerPaymentMethodsListResponse,
-    DeleteTokenizeByDateRequest, DeleteTokenizeByTokenRequest, GetTokenizePayloadRequest,
-    GetTokenizePayloadResponse, PaymentMethodCreate, PaymentMethodDeleteResponse, PaymentMethodId,
-    PaymentMethodList, PaymentMethodListRequest, PaymentMethodListResponse, PaymentMethodResponse,
-    PaymentMethodUpdate, TokenizePayloadEncrypted, TokenizePayloadRequest, TokenizedCardValue1,
-    TokenizedCardValue2, TokenizedWalletValue1, TokenizedWalletValue2,
+    CardDetail, CardDetailFromLocker, CustomerPaymentMethod, CustomerPaymentMethodCreate,
+    CustomerPaymentMethodsListResponse, DeleteTokenizeByDateRequest, DeleteTokenizeByTokenRequest,
+    GetTokenizePayloadRequest, GetTokenizePayloadResponse, PaymentMethodCreate,
+    PaymentMethodDeleteResponse, PaymentMethodId, PaymentMethodList, PaymentMethodListRequest,
+    PaymentMethodListResponse, PaymentMethodResponse, PaymentMethodUpdate, RequiredFieldInfo,
+    TokenizePayloadEncrypted, TokenizePayloadRequest, TokenizedCardValue1, TokenizedCardValue2,
+    TokenizedWalletValue1, TokenizedWalletValue2,
 };
 use error_stack::report;
 use literally::hmap;
@@ -115,3 +116,109 @@ impl PaymentMethodCreateExt for PaymentMethodCreate {
             .unwrap_or(true)
     }
 }
+
+/// A field of the payment method data which a connector requires for a payment method type,
+/// declared by the connector so that the field can be listed with the payment methods of a payment.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RequiredField {
+    /// The path of the field in the payments request
+    pub path: &'static str,
+    pub display_name: &'static str,
+    pub field_type: api_enums::FieldType,
+}
+
+/// The required fields of each payment method type of a connector
+pub type RequiredFieldsRegistry =
+    &'static [(api_enums::PaymentMethodType, &'static [RequiredField])];
+
+impl RequiredField {
+    pub const EPS_BILLING_NAME: Self = Self::new(
+        "payment_method_data.bank_redirect.eps.billing_details.billing_name",
+        "Account Holder Name",
+        api_enums::FieldType::UserFullName,
+    );
+    pub const EPS_BANK_NAME: Self = Self::new(
+        "payment_method_data.bank_redirect.eps.bank_name",
+        "Bank",
+        api_enums::FieldType::UserBank,
+    );
+    pub const GIROPAY_BILLING_NAME: Self = Self::new(
+        "payment_method_data.bank_redirect.giropay.billing_details.billing_name",
+        "Account Holder Name",
+        api_enums::FieldType::UserFullName,
+    );
+    pub const IDEAL_BILLING_NAME: Self = Self::new(
+        "payment_method_data.bank_redirect.ideal.billing_details.billing_name",
+        "Account Holder Name",
+        api_enums::FieldType::UserFullName,
+    );
+    pub const IDEAL_BANK_NAME: Self = Self::new(
+        "payment_method_data.bank_redirect.ideal.bank_name",
+        "Bank",
+        api_enums::FieldType::UserBank,
+    );
+    pub const SOFORT_COUNTRY: Self = Self::new(
+        "payment_method_data.bank_redirect.sofort.country",
+        "Country",
+        api_enums::FieldType::UserCountry,
+    );
+    pub const SOFORT_PREFERRED_LANGUAGE: Self = Self::new(
+        "payment_method_data.bank_redirect.sofort.preferred_language",
+        "Preferred Language",
+        api_enums::FieldType::UserLanguagePreference,
+    );
+    pub const KLARNA_BILLING_EMAIL: Self = Self::new(
+        "payment_method_data.pay_later.klarna_redirect.billing_email",
+        "Email",
+        api_enums::FieldType::UserEmailAddress,
+    );
+    pub const KLARNA_BILLING_COUNTRY: Self = Self::new(
+        "payment_method_data.pay_later.klarna_redirect.billing_country",
+        "Billing Country",
+        api_enums::FieldType::UserCountry,
+    );
+    pub const AFTERPAY_CLEARPAY_BILLING_EMAIL: Self = Self::new(
+        "payment_method_data.pay_later.afterpay_clearpay_redirect.billing_email",
+        "Email",
+        api_enums::FieldType::UserEmailAddress,
+    );
+    pub const AFTERPAY_CLEARPAY_BILLING_NAME: Self = Self::new(
+        "payment_method_data.pay_later.afterpay_clearpay_redirect.billing_name",
+        "Billing Name",
+        api_enums::FieldType::UserFullName,
+    );
+
+    pub const fn new(
+        path: &'static str,
+        display_name: &'static str,
+        field_type: api_enums::FieldType,
+    ) -> Self {
+        Self {
+            path,
+            display_name,
+            field_type,
+        }
+    }
+
+    /// The required fields of the payment method type in the registry of a connector
+    pub fn find(
+        registry: RequiredFieldsRegistry,
+        payment_method_type: api_enums::PaymentMethodType,
+    ) -> &'static [Self] {
+        registry
+            .iter()
+            .find(|(registered_type, _)| *registered_type == payment_method_type)
+            .map(|(_, required_fields)| *required_fields)
+            .unwrap_or(&[])
+    }
+}
+
+impl From<RequiredField> for RequiredFieldInfo {
+    fn from(required_field: RequiredField) -> Self {
+        Self {
+            required_field: required_field.path.to_owned(),
+            display_name: required_field.display_name.to_owned(),
+            field_type: required_field.field_type,
+        }
+    }
+}
@@ -1,13 +1,17 @@
 pub use api_models::payments::{
     AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse, Card,
-    CustomerAcceptance, MandateData, MandateTxnType, MandateType, MandateValidationFields,
-    NextAction, NextActionType, OnlineMandate, PayLaterData, PaymentIdType, PaymentListConstraints,
+    ConnectorLogListResponse, ConnectorLogResponse, CustomerAcceptance, MandateData,
+    MandateTxnType, MandateType, MandateValidationFields, NextAction, NextActionType,
+    OnlineMandate, PayLaterData, PaymentAttemptListResponse, PaymentAttemptResponse,
+    PaymentEventListResponse, PaymentEventResponse, PaymentIdType, PaymentListConstraints,
     PaymentListResponse, PaymentMethodData, PaymentMethodDataResponse, PaymentOp,
-    PaymentRetrieveBody, PaymentRetrieveBodyWithCredentials, PaymentsCancelRequest,
+    PaymentRetrieveBody, PaymentRetrieveBodyWithCredentials, PaymentSplit, PaymentsCancelRequest,
     PaymentsCaptureRequest, PaymentsRedirectRequest, PaymentsRedirectionResponse, PaymentsRequest,
     PaymentsResponse, PaymentsResponseForm, PaymentsRetrieveRequest, PaymentsSessionRequest,
-    PaymentsSessionResponse, PaymentsStartRequest, PgRedirectResponse, PhoneDetails,
-    RedirectionResponse, SessionToken, UrlDetails, VerifyRequest, VerifyResponse, WalletData,
+    PaymentsSessionResponse, PaymentsStartRequest, PaymentsThreeDsAuthenticateRequest,
+    PgRedirectResponse, PhoneDetails, QrCodeInformation, RedirectionResponse, SessionToken,
+    SettlementDetailsResponse, SplitAmount, SurchargeDetailsResponse, UrlDetails, VerifyRequest,
+    VerifyResponse, WalletData,
 };
 use error_stack::{IntoReport, ResultExt};
 use masking::PeekInterface;
@@ -83,6 +87,9 @@ pub struct Session;
 #[derive(Debug, Clone)]
 pub struct Verify;
 
+#[derive(Debug, Clone)]
+pub struct Balance;
+
 pub(crate) trait PaymentIdTypeExt {
     fn get_payment_intent_id(&self) -> errors::CustomResult<String, errors::ValidationError>;
 }
@@ -157,6 +164,15 @@ pub trait PaymentsCompleteAuthorize:
 {
 }
 
+pub trait ConnectorGiftCardBalance:
+    api::ConnectorIntegration<
+    Balance,
+    types::GiftCardBalanceRequestData,
+    types::GiftCardBalanceResponseData,
+>
+{
+}
+
 pub trait Payment:
     api_types::ConnectorCommon
     + PaymentAuthorize
@@ -0,0 +1,31 @@
+pub use api_models::payouts::{
+    AchBankTransfer, BacsBankTransfer, BankPayout, CardPayout, PayoutCreateRequest, PayoutId,
+    PayoutMethodData, PayoutResponse, PayoutRetrieveBody, PayoutRetrieveRequest, SepaBankTransfer,
+};
+
+use super::ConnectorCommon;
+use crate::{services::api, types};
+
+#[derive(Debug, Clone)]
+pub struct PoFulfill;
+#[derive(Debug, Clone)]
+pub struct PoSync;
+#[derive(Debug, Clone)]
+pub struct PoCancel;
+
+pub trait PayoutFulfill:
+    api::ConnectorIntegration<PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutSync:
+    api::ConnectorIntegration<PoSync, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutCancel:
+    api::ConnectorIntegration<PoCancel, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait ConnectorPayouts: ConnectorCommon + PayoutFulfill + PayoutSync + PayoutCancel {}
@@ -0,0 +1,23 @@
+pub use api_models::recon::{
+    PaymentReconResponse, SettlementLineResponse, SettlementReportData, SettlementReportLine,
+    SettlementReportRequest, SettlementReportResponse,
+};
+
+use crate::types::{storage, transformers::ForeignFrom};
+
+impl ForeignFrom<storage::SettlementLine> for SettlementLineResponse {
+    fn foreign_from(settlement_line: storage::SettlementLine) -> Self {
+        Self {
+            connector_transaction_id: settlement_line.connector_transaction_id,
+            payment_id: settlement_line.payment_id,
+            attempt_id: settlement_line.attempt_id,
+            settled_amount: settlement_line.settled_amount,
+            settled_currency: ForeignFrom::foreign_from(settlement_line.settled_currency),
+            expected_amount: settlement_line.expected_amount,
+            expected_currency: settlement_line
+                .expected_currency
+                .map(ForeignFrom::foreign_from),
+            recon_status: ForeignFrom::foreign_from(settlement_line.recon_status),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub use api_models::routing::{
+    ComparisonOperator, MembershipOperator, RoutingCondition, RoutingConfigRequest,
+    RoutingConfigResponse, RoutingEvaluateRequest, RoutingEvaluateResponse, RoutingRule,
+    RoutingRules,
+};
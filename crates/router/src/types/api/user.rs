@@ -0,0 +1,4 @@
+pub use api_models::user::{
+    AuthTokenResponse, RefreshTokenRequest, SignInRequest, SignUpRequest, UserRoleRequest,
+    UserRoleResponse,
+};
@@ -0,0 +1,3 @@
+pub use api_models::vault::{
+    VaultTokenCreateRequest, VaultTokenDeleteResponse, VaultTokenResponse,
+};
@@ -1,22 +1,45 @@
 pub mod address;
 pub mod api_keys;
+pub mod balance_transaction;
+pub mod business_profile;
+pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_log;
 pub mod connector_response;
 pub mod customers;
 pub mod dispute;
+pub mod dummy_connector;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
+pub mod funding_source;
+pub mod idempotency;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod organization;
 pub mod payment_attempt;
+pub mod payment_event;
 pub mod payment_intent;
+pub mod payment_link;
 pub mod payment_method;
+pub mod payment_schedule;
+pub mod payment_split;
+pub mod payout;
+pub mod payout_method;
 pub mod process_tracker;
+pub mod redaction_audit;
 pub mod reverse_lookup;
+pub mod routing_config;
+pub mod settlement_report;
+pub mod status_mismatch;
+pub mod success_rate;
+pub mod user;
+pub mod user_role;
+pub mod vault_data_key;
+pub mod vaulted_card;
 
 mod query;
 pub mod refund;
@@ -25,8 +48,12 @@ pub mod refund;
 pub mod kv;
 
 pub use self::{
-    address::*, api_keys::*, cards_info::*, configs::*, connector_response::*, customers::*,
-    dispute::*, events::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, payment_attempt::*, payment_intent::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*,
+    address::*, api_keys::*, balance_transaction::*, business_profile::*, capture::*,
+    cards_info::*, configs::*, connector_log::*, connector_response::*, customers::*, dispute::*,
+    dummy_connector::*, events::*, funding_source::*, locker_mock_up::*, mandate::*,
+    merchant_account::*, merchant_connector_account::*, organization::*, payment_attempt::*,
+    payment_event::*, payment_intent::*, payment_link::*, payment_method::*, payment_schedule::*,
+    payment_split::*, payout::*, payout_method::*, process_tracker::*, redaction_audit::*,
+    refund::*, reverse_lookup::*, routing_config::*, settlement_report::*, status_mismatch::*,
+    success_rate::*, user::*, user_role::*, vault_data_key::*, vaulted_card::*,
 };
@@ -0,0 +1,59 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl, Queryable};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+pub use storage_models::{
+    balance_transaction::{BalanceTransaction, BalanceTransactionNew},
+    errors,
+    schema::balance_transactions::dsl,
+};
+
+use crate::{connection::PgPooledConn, core::errors::CustomResult, types::storage::enums};
+
+/// The total amount moved from one account of the ledger to another, in a currency.
+#[derive(Debug, Queryable)]
+pub struct BalanceTransactionAggregate {
+    pub currency: enums::Currency,
+    pub debit_account: enums::LedgerAccount,
+    pub credit_account: enums::LedgerAccount,
+    pub amount: Option<i64>,
+}
+
+#[async_trait::async_trait]
+pub trait BalanceTransactionDbExt: Sized {
+    async fn aggregate_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<BalanceTransactionAggregate>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl BalanceTransactionDbExt for BalanceTransaction {
+    #[instrument(skip(conn))]
+    async fn aggregate_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<BalanceTransactionAggregate>, errors::DatabaseError> {
+        // The sum of a BIGINT column is a NUMERIC in Postgres, which is cast back to BIGINT
+        let query = <Self as HasTable>::table()
+            .group_by((dsl::currency, dsl::debit_account, dsl::credit_account))
+            .select((
+                dsl::currency,
+                dsl::debit_account,
+                dsl::credit_account,
+                diesel::dsl::sql::<diesel::sql_types::Nullable<diesel::sql_types::BigInt>>(
+                    "CAST(SUM(amount) AS BIGINT)",
+                ),
+            ))
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()));
+
+        crate::logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error aggregating balance transactions by merchant ID")
+    }
+}
@@ -0,0 +1 @@
+pub use storage_models::connector_log::{ConnectorLog, ConnectorLogNew};
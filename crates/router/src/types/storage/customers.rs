@@ -1,3 +1,54 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
+use error_stack::{IntoReport, ResultExt};
 pub use storage_models::customers::{
     Customer, CustomerNew, CustomerUpdate, CustomerUpdateInternal,
 };
+use storage_models::{errors, schema::customers::dsl};
+
+use crate::{connection::PgPooledConn, core::customers::REDACTED, logger};
+
+#[async_trait::async_trait]
+pub trait CustomerDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_list_constraints: api_models::customers::CustomerListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerDbExt for Customer {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_list_constraints: api_models::customers::CustomerListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        // Redacted customers are excluded, since they are treated as deleted
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::name.is_null().or(dsl::name.ne(REDACTED)))
+            .order(dsl::created_at.desc())
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(email) = customer_list_constraints.email {
+            filter = filter.filter(dsl::email.eq(email));
+        }
+        if let Some(phone) = customer_list_constraints.phone {
+            filter = filter.filter(dsl::phone.eq(phone));
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering customers by constraints")
+    }
+}
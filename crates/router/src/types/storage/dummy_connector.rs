@@ -0,0 +1,45 @@
+//! The payments and refunds simulated by the dummy connector, which are only kept in redis.
+
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::types::api::{self, enums as api_enums};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DummyConnectorPaymentData {
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub status: api::DummyConnectorStatus,
+    pub amount: i64,
+    pub refunded_amount: i64,
+    pub currency: api_enums::Currency,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    pub error: Option<api::DummyConnectorError>,
+    pub return_url: Option<String>,
+    pub webhook_url: Option<String>,
+    /// The outcome of a payment which is processed asynchronously
+    pub settlement: Option<DummyConnectorSettlement>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DummyConnectorSettlement {
+    pub status: api::DummyConnectorStatus,
+    pub error: Option<api::DummyConnectorError>,
+    /// The time the payment reaches its outcome at
+    #[serde(with = "custom_serde::iso8601")]
+    pub settles_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DummyConnectorRefundData {
+    pub refund_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub status: api::DummyConnectorStatus,
+    pub amount: i64,
+    pub currency: api_enums::Currency,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
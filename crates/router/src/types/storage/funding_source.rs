@@ -0,0 +1 @@
+pub use storage_models::funding_source::{FundingSource, FundingSourceNew, FundingSourceUpdate};
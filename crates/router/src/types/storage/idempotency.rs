@@ -0,0 +1 @@
+pub use storage_models::idempotency::{IdempotencyEntry, IdempotencyStatus};
@@ -1,3 +1,72 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use error_stack::{IntoReport, ResultExt};
 pub use storage_models::mandate::{
     Mandate, MandateNew, MandateUpdate, MandateUpdateInternal, SingleUseMandate,
 };
+use storage_models::{errors, schema::mandate::dsl};
+
+use crate::{connection::PgPooledConn, logger, types::transformers::ForeignInto};
+
+#[async_trait::async_trait]
+pub trait MandateDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        mandate_list_constraints: api_models::mandates::MandateListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl MandateDbExt for Mandate {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        mandate_list_constraints: api_models::mandates::MandateListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(customer_id) = mandate_list_constraints.customer_id {
+            filter = filter.filter(dsl::customer_id.eq(customer_id));
+        }
+        if let Some(mandate_status) = mandate_list_constraints.mandate_status {
+            let mandate_status: storage_models::enums::MandateStatus =
+                mandate_status.foreign_into();
+            filter = filter.filter(dsl::mandate_status.eq(mandate_status));
+        }
+        if let Some(connector) = mandate_list_constraints.connector {
+            filter = filter.filter(dsl::connector.eq(connector));
+        }
+        if let Some(created_time) = mandate_list_constraints.created_time {
+            filter = filter.filter(dsl::created_at.eq(created_time));
+        }
+        if let Some(created_time_lt) = mandate_list_constraints.created_time_lt {
+            filter = filter.filter(dsl::created_at.lt(created_time_lt));
+        }
+        if let Some(created_time_gt) = mandate_list_constraints.created_time_gt {
+            filter = filter.filter(dsl::created_at.gt(created_time_gt));
+        }
+        if let Some(created_time_lte) = mandate_list_constraints.created_time_lte {
+            filter = filter.filter(dsl::created_at.le(created_time_lte));
+        }
+        if let Some(created_time_gte) = mandate_list_constraints.created_time_gte {
+            filter = filter.filter(dsl::created_at.ge(created_time_gte));
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering mandates by constraints")
+    }
+}
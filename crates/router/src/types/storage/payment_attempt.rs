@@ -12,6 +12,8 @@ use crate::{
 pub struct RoutingData {
     pub routed_through: Option<String>,
     pub algorithm: Option<api_models::admin::RoutingAlgorithm>,
+    /// The network co-badged cards are processed with, as selected by the routing config
+    pub card_network: Option<api_models::enums::CardNetwork>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -0,0 +1 @@
+pub use storage_models::payment_event::{PaymentEvent, PaymentEventNew};
@@ -1,5 +1,5 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
-use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl, Queryable};
 use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 pub use storage_models::{
@@ -10,11 +10,24 @@ pub use storage_models::{
     schema::payment_intent::dsl,
 };
 
-use crate::{connection::PgPooledConn, core::errors::CustomResult, types::api};
+use crate::{
+    connection::PgPooledConn,
+    core::errors::CustomResult,
+    types::{api, storage::enums},
+};
 
 #[cfg(feature = "kv_store")]
 impl crate::utils::storage_partitioning::KvStorePartition for PaymentIntent {}
 
+/// The number and the total amount of the payments of a currency and status.
+#[derive(Debug, Queryable)]
+pub struct PaymentIntentAggregate {
+    pub currency: Option<enums::Currency>,
+    pub status: enums::IntentStatus,
+    pub count: i64,
+    pub total_amount: Option<i64>,
+}
+
 #[async_trait::async_trait]
 pub trait PaymentIntentDbExt: Sized {
     async fn filter_by_constraints(
@@ -22,6 +35,12 @@ pub trait PaymentIntentDbExt: Sized {
         merchant_id: &str,
         pc: &api::PaymentListConstraints,
     ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+
+    async fn aggregate_by_merchant_ids(
+        conn: &PgPooledConn,
+        merchant_ids: &[String],
+        constraints: &api::OrganizationPaymentsAggregateConstraints,
+    ) -> CustomResult<Vec<PaymentIntentAggregate>, errors::DatabaseError>;
 }
 
 #[async_trait::async_trait]
@@ -85,4 +104,40 @@ impl PaymentIntentDbExt for PaymentIntent {
             .change_context(errors::DatabaseError::NotFound)
             .attach_printable_lazy(|| "Error filtering records by predicate")
     }
+    #[instrument(skip(conn))]
+    async fn aggregate_by_merchant_ids(
+        conn: &PgPooledConn,
+        merchant_ids: &[String],
+        constraints: &api::OrganizationPaymentsAggregateConstraints,
+    ) -> CustomResult<Vec<PaymentIntentAggregate>, errors::DatabaseError> {
+        // The sum of a BIGINT column is a NUMERIC in Postgres, which is cast back to BIGINT
+        let mut query = <Self as HasTable>::table()
+            .group_by((dsl::currency, dsl::status))
+            .select((
+                dsl::currency,
+                dsl::status,
+                diesel::dsl::count_star(),
+                diesel::dsl::sql::<diesel::sql_types::Nullable<diesel::sql_types::BigInt>>(
+                    "CAST(SUM(amount) AS BIGINT)",
+                ),
+            ))
+            .filter(dsl::merchant_id.eq_any(merchant_ids.to_owned()))
+            .into_boxed();
+
+        if let Some(created_gte) = constraints.created_gte {
+            query = query.filter(dsl::created_at.ge(created_gte));
+        }
+        if let Some(created_lt) = constraints.created_lt {
+            query = query.filter(dsl::created_at.lt(created_lt));
+        }
+
+        crate::logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error aggregating payments by merchant IDs")
+    }
 }
@@ -0,0 +1 @@
+pub use storage_models::payment_split::{PaymentSplit, PaymentSplitNew, PaymentSplitUpdate};
@@ -0,0 +1 @@
+pub use storage_models::payout::{Payout, PayoutNew, PayoutUpdate};
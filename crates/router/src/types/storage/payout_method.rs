@@ -0,0 +1 @@
+pub use storage_models::payout_method::{PayoutMethod, PayoutMethodNew};
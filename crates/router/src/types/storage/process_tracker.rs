@@ -35,6 +35,15 @@ pub trait ProcessTrackerExt {
         db: &dyn StorageInterface,
         status: String,
     ) -> Result<(), errors::ProcessTrackerError>;
+
+    /// Tasks which have exhausted their retries or failed while being handled are moved to the
+    /// dead letter queue, where they are no longer picked up by the producer but are kept for
+    /// inspection.
+    async fn move_to_dead_letter(
+        self,
+        db: &dyn StorageInterface,
+        business_status: String,
+    ) -> Result<(), errors::ProcessTrackerError>;
 }
 
 #[async_trait::async_trait]
@@ -107,4 +116,22 @@ impl ProcessTrackerExt for ProcessTracker {
         metrics::TASK_FINISHED.add(&metrics::CONTEXT, 1, &[]);
         Ok(())
     }
+
+    async fn move_to_dead_letter(
+        self,
+        db: &dyn StorageInterface,
+        business_status: String,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        db.update_process(
+            self,
+            ProcessTrackerUpdate::StatusUpdate {
+                status: storage_enums::ProcessTrackerStatus::DeadLetter,
+                business_status: Some(business_status),
+            },
+        )
+        .await
+        .attach_printable("Failed while moving the process to the dead letter queue")?;
+        metrics::TASK_DEAD_LETTERED.add(&metrics::CONTEXT, 1, &[]);
+        Ok(())
+    }
 }
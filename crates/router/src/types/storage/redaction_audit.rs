@@ -0,0 +1 @@
+pub use storage_models::redaction_audit::{RedactionAudit, RedactionAuditNew};
@@ -0,0 +1 @@
+pub use storage_models::routing_config::{RoutingConfig, RoutingConfigNew, RoutingConfigUpdate};
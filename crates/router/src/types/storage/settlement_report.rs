@@ -0,0 +1,3 @@
+pub use storage_models::settlement_report::{
+    SettlementLine, SettlementLineNew, SettlementReport, SettlementReportNew,
+};
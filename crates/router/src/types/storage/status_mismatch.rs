@@ -0,0 +1 @@
+pub use storage_models::status_mismatch::{StatusMismatch, StatusMismatchNew};
@@ -0,0 +1 @@
+pub use storage_models::success_rate::AuthorizationCount;
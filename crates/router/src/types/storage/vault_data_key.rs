@@ -0,0 +1 @@
+pub use storage_models::vault_data_key::{VaultDataKey, VaultDataKeyNew};
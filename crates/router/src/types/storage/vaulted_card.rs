@@ -0,0 +1 @@
+pub use storage_models::vaulted_card::{VaultedCard, VaultedCardNew};
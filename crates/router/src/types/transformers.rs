@@ -75,6 +75,120 @@ impl ForeignFrom<storage_enums::MandateStatus> for api_enums::MandateStatus {
     }
 }
 
+impl ForeignFrom<api_enums::MandateStatus> for storage_enums::MandateStatus {
+    fn foreign_from(status: api_enums::MandateStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<storage_enums::PaymentScheduleInterval> for api_enums::PaymentScheduleInterval {
+    fn foreign_from(interval: storage_enums::PaymentScheduleInterval) -> Self {
+        frunk::labelled_convert_from(interval)
+    }
+}
+
+impl ForeignFrom<api_enums::PaymentScheduleInterval> for storage_enums::PaymentScheduleInterval {
+    fn foreign_from(interval: api_enums::PaymentScheduleInterval) -> Self {
+        frunk::labelled_convert_from(interval)
+    }
+}
+
+impl ForeignFrom<storage_enums::PaymentScheduleStatus> for api_enums::PaymentScheduleStatus {
+    fn foreign_from(status: storage_enums::PaymentScheduleStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<storage_enums::PayoutStatus> for api_enums::PayoutStatus {
+    fn foreign_from(status: storage_enums::PayoutStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<storage_enums::PayoutType> for api_enums::PayoutType {
+    fn foreign_from(payout_type: storage_enums::PayoutType) -> Self {
+        frunk::labelled_convert_from(payout_type)
+    }
+}
+
+impl ForeignFrom<api_enums::PayoutType> for storage_enums::PayoutType {
+    fn foreign_from(payout_type: api_enums::PayoutType) -> Self {
+        frunk::labelled_convert_from(payout_type)
+    }
+}
+
+impl ForeignFrom<storage_enums::DashboardRole> for api_enums::DashboardRole {
+    fn foreign_from(role: storage_enums::DashboardRole) -> Self {
+        frunk::labelled_convert_from(role)
+    }
+}
+
+impl ForeignFrom<api_enums::DashboardRole> for storage_enums::DashboardRole {
+    fn foreign_from(role: api_enums::DashboardRole) -> Self {
+        frunk::labelled_convert_from(role)
+    }
+}
+
+impl ForeignFrom<storage_enums::PaymentLinkStatus> for api_enums::PaymentLinkStatus {
+    fn foreign_from(status: storage_enums::PaymentLinkStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<storage_enums::FundingSourceStatus> for api_enums::FundingSourceStatus {
+    fn foreign_from(status: storage_enums::FundingSourceStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<storage_enums::SplitStatus> for api_enums::SplitStatus {
+    fn foreign_from(status: storage_enums::SplitStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<storage_enums::BalanceTransactionType> for api_enums::BalanceTransactionType {
+    fn foreign_from(transaction_type: storage_enums::BalanceTransactionType) -> Self {
+        frunk::labelled_convert_from(transaction_type)
+    }
+}
+
+impl ForeignFrom<storage_enums::LedgerAccount> for api_enums::LedgerAccount {
+    fn foreign_from(account: storage_enums::LedgerAccount) -> Self {
+        frunk::labelled_convert_from(account)
+    }
+}
+
+impl ForeignFrom<storage_enums::PaymentEventSource> for api_enums::PaymentEventSource {
+    fn foreign_from(source: storage_enums::PaymentEventSource) -> Self {
+        frunk::labelled_convert_from(source)
+    }
+}
+
+impl ForeignFrom<storage_enums::ReconStatus> for api_enums::ReconStatus {
+    fn foreign_from(status: storage_enums::ReconStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<storage_enums::AvsResult> for api_enums::AvsResult {
+    fn foreign_from(avs_result: storage_enums::AvsResult) -> Self {
+        frunk::labelled_convert_from(avs_result)
+    }
+}
+
+impl ForeignFrom<storage_enums::CvcResult> for api_enums::CvcResult {
+    fn foreign_from(cvc_result: storage_enums::CvcResult) -> Self {
+        frunk::labelled_convert_from(cvc_result)
+    }
+}
+
+impl ForeignFrom<storage_enums::FraudCheckVerdict> for api_enums::FraudCheckVerdict {
+    fn foreign_from(verdict: storage_enums::FraudCheckVerdict) -> Self {
+        frunk::labelled_convert_from(verdict)
+    }
+}
+
 impl ForeignFrom<api_enums::PaymentMethod> for storage_enums::PaymentMethod {
     fn foreign_from(pm_type: api_enums::PaymentMethod) -> Self {
         frunk::labelled_convert_from(pm_type)
@@ -120,15 +234,17 @@ impl ForeignFrom<api_enums::IntentStatus> for storage_enums::IntentStatus {
 impl ForeignFrom<storage_enums::AttemptStatus> for storage_enums::IntentStatus {
     fn foreign_from(s: storage_enums::AttemptStatus) -> Self {
         match s {
-            storage_enums::AttemptStatus::Charged | storage_enums::AttemptStatus::AutoRefunded => {
-                Self::Succeeded
-            }
+            storage_enums::AttemptStatus::Charged
+            | storage_enums::AttemptStatus::PartiallyCaptured
+            | storage_enums::AttemptStatus::AutoRefunded
+            | storage_enums::AttemptStatus::Overpaid => Self::Succeeded,
 
             storage_enums::AttemptStatus::ConfirmationAwaited => Self::RequiresConfirmation,
             storage_enums::AttemptStatus::PaymentMethodAwaited => Self::RequiresPaymentMethod,
 
             storage_enums::AttemptStatus::Authorized => Self::RequiresCapture,
             storage_enums::AttemptStatus::AuthenticationPending => Self::RequiresCustomerAction,
+            storage_enums::AttemptStatus::ManualReview => Self::RequiresMerchantAction,
 
             storage_enums::AttemptStatus::PartialCharged
             | storage_enums::AttemptStatus::Started
@@ -137,6 +253,8 @@ impl ForeignFrom<storage_enums::AttemptStatus> for storage_enums::IntentStatus {
             | storage_enums::AttemptStatus::CodInitiated
             | storage_enums::AttemptStatus::VoidInitiated
             | storage_enums::AttemptStatus::CaptureInitiated
+            | storage_enums::AttemptStatus::DebitInitiated
+            | storage_enums::AttemptStatus::Underpaid
             | storage_enums::AttemptStatus::Pending => Self::Processing,
 
             storage_enums::AttemptStatus::AuthenticationFailed
@@ -193,6 +311,40 @@ impl ForeignTryFrom<storage_enums::DisputeStatus> for storage_enums::EventType {
     }
 }
 
+impl ForeignTryFrom<storage_enums::PayoutStatus> for storage_enums::EventType {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(value: storage_enums::PayoutStatus) -> Result<Self, Self::Error> {
+        match value {
+            storage_enums::PayoutStatus::Success => Ok(Self::PayoutSucceeded),
+            storage_enums::PayoutStatus::Failed => Ok(Self::PayoutFailed),
+            storage_enums::PayoutStatus::Cancelled => Ok(Self::PayoutCancelled),
+            storage_enums::PayoutStatus::Reversed => Ok(Self::PayoutReversed),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "payout_status",
+            }),
+        }
+    }
+}
+
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::PayoutStatus {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::PayoutSuccess => Ok(Self::Success),
+            api_models::webhooks::IncomingWebhookEvent::PayoutFailure => Ok(Self::Failed),
+            api_models::webhooks::IncomingWebhookEvent::PayoutCancelled => Ok(Self::Cancelled),
+            api_models::webhooks::IncomingWebhookEvent::PayoutReversed => Ok(Self::Reversed),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
 impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::RefundStatus {
     type Error = errors::ValidationError;
 
@@ -298,6 +450,77 @@ impl ForeignFrom<storage::Config> for api_types::Config {
     }
 }
 
+impl ForeignFrom<storage::PaymentSchedule> for api_types::PaymentScheduleResponse {
+    fn foreign_from(payment_schedule: storage::PaymentSchedule) -> Self {
+        Self {
+            schedule_id: payment_schedule.schedule_id,
+            customer_id: payment_schedule.customer_id,
+            mandate_id: payment_schedule.mandate_id,
+            amount: payment_schedule.amount,
+            currency: payment_schedule.currency.foreign_into(),
+            interval: payment_schedule.billing_interval.foreign_into(),
+            interval_count: payment_schedule.interval_count,
+            status: payment_schedule.status.foreign_into(),
+            total_cycles: payment_schedule.total_cycles,
+            completed_cycles: payment_schedule.completed_cycles,
+            failed_cycles: payment_schedule.failed_cycles,
+            next_payment_at: payment_schedule.next_payment_at,
+            last_payment_id: payment_schedule.last_payment_id,
+            description: payment_schedule.description,
+            created_at: payment_schedule.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Payout> for api_types::PayoutResponse {
+    fn foreign_from(payout: storage::Payout) -> Self {
+        Self {
+            payout_id: payout.payout_id,
+            merchant_id: payout.merchant_id,
+            customer_id: payout.customer_id,
+            amount: payout.amount,
+            currency: payout.currency.foreign_into(),
+            connector: payout.connector,
+            payout_type: payout.payout_type.foreign_into(),
+            payout_method_id: payout.payout_method_id,
+            status: payout.status.foreign_into(),
+            error_code: payout.error_code,
+            error_message: payout.error_message,
+            description: payout.description,
+            metadata: payout.metadata,
+            created_at: payout.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::FundingSource> for api_models::payments::FundingSourceResponse {
+    fn foreign_from(funding_source: storage::FundingSource) -> Self {
+        Self {
+            funding_source_id: funding_source.funding_source_id,
+            payment_method: funding_source.payment_method.foreign_into(),
+            payment_method_type: funding_source
+                .payment_method_type
+                .map(ForeignInto::foreign_into),
+            amount: funding_source.amount,
+            status: funding_source.status.foreign_into(),
+            error_code: funding_source.error_code,
+            error_message: funding_source.error_message,
+        }
+    }
+}
+
+impl ForeignFrom<storage::PaymentSplit> for api_models::payments::PaymentSplitResponse {
+    fn foreign_from(payment_split: storage::PaymentSplit) -> Self {
+        Self {
+            split_id: payment_split.split_id,
+            sub_merchant_id: payment_split.sub_merchant_id,
+            connector_account_id: payment_split.connector_account_id,
+            amount: payment_split.amount,
+            status: payment_split.status.foreign_into(),
+        }
+    }
+}
+
 impl<'a> ForeignFrom<&'a api_types::ConfigUpdate> for storage::ConfigUpdate {
     fn foreign_from(config: &api_types::ConfigUpdate) -> Self {
         let config_update = config;
@@ -341,16 +564,14 @@ impl ForeignTryFrom<storage::MerchantConnectorAccount> for api_models::admin::Me
                 .change_context(errors::ApiErrorResponse::InternalServerError)?,
             None => None,
         };
-        let configs_for_frm_value = merchant_ca
+        let frm_configs: Option<api_models::admin::FrmConfigs> = merchant_ca
             .frm_configs
-            .ok_or_else(|| errors::ApiErrorResponse::ConfigNotFound)?;
-        let configs_for_frm : api_models::admin::FrmConfigs = configs_for_frm_value
-        // .clone()
-        .parse_value("FrmConfigs")
-        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
-            field_name: "frm_configs".to_string(),
-            expected_format: "\"frm_configs\" : { \"frm_enabled_pms\" : [\"card\"], \"frm_enabled_pm_types\" : [\"credit\"], \"frm_enabled_gateways\" : [\"stripe\"], \"frm_action\": \"cancel_txn\", \"frm_preferred_flow_type\" : \"pre\" }".to_string(),
-        })?;
+            .map(|configs_for_frm_value| configs_for_frm_value.parse_value("FrmConfigs"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "frm_configs".to_string(),
+                expected_format: "\"frm_configs\" : { \"frm_enabled_pms\" : [\"card\"], \"frm_enabled_pm_types\" : [\"credit\"], \"frm_enabled_gateways\" : [\"stripe\"], \"frm_action\": \"cancel_txn\", \"frm_preferred_flow_type\" : \"pre\" }".to_string(),
+            })?;
 
         Ok(Self {
             connector_type: merchant_ca.connector_type.foreign_into(),
@@ -363,7 +584,7 @@ impl ForeignTryFrom<storage::MerchantConnectorAccount> for api_models::admin::Me
             disabled: merchant_ca.disabled,
             metadata: merchant_ca.metadata,
             payment_methods_enabled,
-            frm_configs: Some(configs_for_frm),
+            frm_configs,
         })
     }
 }
@@ -420,11 +641,13 @@ impl
         Self {
             key_id: api_key.key_id,
             merchant_id: api_key.merchant_id,
+            organization_id: api_key.organization_id,
             name: api_key.name,
             description: api_key.description,
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: crate::core::api_keys::get_api_key_scopes(api_key.scopes),
         }
     }
 }
@@ -436,11 +659,13 @@ impl ForeignFrom<storage_models::api_keys::ApiKey>
         Self {
             key_id: api_key.key_id,
             merchant_id: api_key.merchant_id,
+            organization_id: api_key.organization_id,
             name: api_key.name,
             description: api_key.description,
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: crate::core::api_keys::get_api_key_scopes(api_key.scopes),
         }
     }
 }
@@ -454,6 +679,9 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            scopes: api_key
+                .scopes
+                .map(crate::core::api_keys::get_stored_api_key_scopes),
         }
     }
 }
@@ -0,0 +1,458 @@
+//! A declarative layer for validating the fields of API requests. A request declares the rules
+//! which its fields follow by implementing [`ValidateFields`], and all the fields which break their
+//! rules are reported together in a single error, rather than one at a time.
+
+use api_models::{customers, enums as api_enums, payments, refunds};
+use common_utils::validation;
+use error_stack::report;
+use masking::PeekInterface;
+
+use crate::{
+    core::{
+        errors::{self, CustomResult, RouterResult},
+        payments::co_badged_cards,
+    },
+    types::{storage::enums as storage_enums, transformers::ForeignFrom},
+};
+
+/// The largest amount accepted in the major unit of any currency, such as dollars or yen
+const MAX_AMOUNT_IN_MAJOR_UNITS: i64 = 99_999_999;
+
+/// The test card numbers published by connectors or simulated by the dummy connector, which their
+/// test environments accept with any expiry date and CVC, and which are therefore not validated in
+/// the sandbox.
+const SANDBOX_TEST_CARD_NUMBERS: &[&str] = &[
+    "4242424242424242",
+    "4111111111111111",
+    "4000000000000002",
+    "4000000000000069",
+    "4000000000000127",
+    "4000000000003220",
+    "4000000000009995",
+    "4000002500001001",
+    "5555555555554444",
+    "5105105105105100",
+    "5555552500001001",
+    "2223003122003222",
+    "378282246310005",
+    "371449635398431",
+    "6011111111111117",
+    "3566002020360505",
+    "30569309025904",
+    "6200000000000005",
+];
+
+/// A field of a request which breaks one of its rules, named by its path in the request.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+pub struct FieldError {
+    pub field_name: String,
+    pub message: String,
+}
+
+/// Collects the errors of the fields of a request, as their rules are checked.
+#[derive(Debug, Default)]
+pub struct FieldValidator {
+    prefix: Option<String>,
+    errors: Vec<FieldError>,
+}
+
+pub trait ValidateFields {
+    fn validate_fields(&self, validator: &mut FieldValidator);
+}
+
+impl FieldValidator {
+    fn get_field_path(&self, field_name: &str) -> String {
+        match &self.prefix {
+            Some(prefix) => format!("{prefix}.{field_name}"),
+            None => field_name.to_owned(),
+        }
+    }
+
+    fn add_error(&mut self, field_name: &str, message: impl Into<String>) {
+        self.errors.push(FieldError {
+            field_name: self.get_field_path(field_name),
+            message: message.into(),
+        });
+    }
+
+    fn format<F>(
+        &mut self,
+        field_name: &str,
+        value: Option<&str>,
+        validate: F,
+        expected_format: &str,
+    ) -> &mut Self
+    where
+        F: Fn(&str) -> CustomResult<(), errors::ValidationError>,
+    {
+        if value.map_or(false, |value| validate(value).is_err()) {
+            self.add_error(field_name, format!("must be {expected_format}"));
+        }
+        self
+    }
+
+    pub fn max_length(
+        &mut self,
+        field_name: &str,
+        value: Option<&str>,
+        max_length: usize,
+    ) -> &mut Self {
+        if value.map_or(false, |value| value.chars().count() > max_length) {
+            self.add_error(
+                field_name,
+                format!("must be at most {max_length} characters long"),
+            );
+        }
+        self
+    }
+
+    pub fn email(&mut self, field_name: &str, value: Option<&str>) -> &mut Self {
+        self.format(
+            field_name,
+            value,
+            validation::validate_email,
+            "a valid email address",
+        )
+    }
+
+    pub fn phone_number(&mut self, field_name: &str, value: Option<&str>) -> &mut Self {
+        self.format(
+            field_name,
+            value,
+            validation::validate_phone_number,
+            "a valid phone number",
+        )
+    }
+
+    pub fn phone_country_code(&mut self, field_name: &str, value: Option<&str>) -> &mut Self {
+        self.format(
+            field_name,
+            value,
+            validation::validate_phone_country_code,
+            "a valid country calling code, such as +1",
+        )
+    }
+
+    /// Amounts are in the minor unit of their currency, so the largest amount accepted depends on
+    /// the number of digits after the decimal separator in amounts of the currency, of which there
+    /// are none in zero-decimal currencies such as JPY. Amounts without a currency are only checked
+    /// not to be negative.
+    pub fn amount(
+        &mut self,
+        field_name: &str,
+        amount: Option<i64>,
+        currency: Option<api_enums::Currency>,
+    ) -> &mut Self {
+        match (amount, currency) {
+            (Some(amount), _) if amount < 0 => self.add_error(field_name, "must not be negative"),
+            (Some(amount), Some(currency)) => {
+                let exponent = storage_enums::Currency::foreign_from(currency)
+                    .number_of_digits_after_decimal_point();
+                let max_amount = MAX_AMOUNT_IN_MAJOR_UNITS
+                    .saturating_mul(10_i64.saturating_pow(u32::from(exponent)));
+                if amount > max_amount {
+                    self.add_error(
+                        field_name,
+                        format!("must be at most {max_amount} for the {currency} currency"),
+                    );
+                }
+            }
+            _ => {}
+        }
+        self
+    }
+
+    pub fn card_number(&mut self, field_name: &str, value: Option<&str>) -> &mut Self {
+        self.format(
+            field_name,
+            value,
+            validation::validate_card_number,
+            "a valid card number",
+        )
+    }
+
+    /// Checks that the expiry month and year of a card are valid, and that the card has not expired
+    /// by the current month. Years are given either in full or by their last two digits.
+    pub fn card_expiry(
+        &mut self,
+        (month_field_name, month): (&str, &str),
+        (year_field_name, year): (&str, &str),
+    ) -> &mut Self {
+        let month = month
+            .parse::<u8>()
+            .ok()
+            .filter(|month| (1..=12).contains(month));
+        let year = match year.len() {
+            2 => year.parse::<i32>().ok().map(|year| year + 2000),
+            4 => year.parse::<i32>().ok(),
+            _ => None,
+        };
+
+        if month.is_none() {
+            self.add_error(month_field_name, "must be a month between 1 and 12");
+        }
+        match (month, year) {
+            (_, None) => self.add_error(year_field_name, "must be a year of 2 or 4 digits"),
+            (Some(month), Some(year)) => {
+                let now = common_utils::date_time::now();
+                if (year, month) < (now.year(), u8::from(now.month())) {
+                    self.add_error(year_field_name, "must not be in the past");
+                }
+            }
+            (None, Some(_)) => {}
+        }
+        self
+    }
+
+    /// CVCs are 4 digits long for American Express cards and 3 digits long for other cards.
+    pub fn card_cvc(&mut self, field_name: &str, cvc: &str, card_number: &str) -> &mut Self {
+        let is_american_express = co_badged_cards::get_card_networks(card_number)
+            .first()
+            .map_or(false, |card_network| {
+                *card_network == api_enums::CardNetwork::AmericanExpress
+            });
+        let cvc_length = if is_american_express { 4 } else { 3 };
+
+        if cvc.len() != cvc_length || !cvc.chars().all(|c| c.is_ascii_digit()) {
+            self.add_error(field_name, format!("must be {cvc_length} digits long"));
+        }
+        self
+    }
+
+    /// Checks the fields of a nested object, whose errors are named by their path in the request.
+    pub fn nested<T: ValidateFields>(&mut self, field_name: &str, value: Option<&T>) -> &mut Self {
+        if let Some(value) = value {
+            let mut nested_validator = Self {
+                prefix: Some(self.get_field_path(field_name)),
+                errors: Vec::new(),
+            };
+            value.validate_fields(&mut nested_validator);
+            self.errors.extend(nested_validator.errors);
+        }
+        self
+    }
+
+    pub fn into_errors(self) -> Vec<FieldError> {
+        self.errors
+    }
+}
+
+/// Checks the fields of the request, failing with the errors of all the fields which break their
+/// rules.
+pub fn validate_request<T: ValidateFields>(request: &T) -> RouterResult<()> {
+    let mut validator = FieldValidator::default();
+    request.validate_fields(&mut validator);
+
+    let errors = validator.into_errors();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestFields {
+            errors
+        }))
+    }
+}
+
+impl ValidateFields for payments::PaymentsRequest {
+    fn validate_fields(&self, validator: &mut FieldValidator) {
+        let email = self.email.as_ref().map(|email| email.peek().as_str());
+        let phone = self.phone.as_ref().map(|phone| phone.peek().as_str());
+
+        validator
+            .amount("amount", self.amount.map(i64::from), self.currency)
+            .amount("amount_to_capture", self.amount_to_capture, self.currency)
+            .max_length("customer_id", self.customer_id.as_deref(), 64)
+            .max_length("description", self.description.as_deref(), 255)
+            .max_length(
+                "statement_descriptor_name",
+                self.statement_descriptor_name.as_deref(),
+                255,
+            )
+            .max_length(
+                "statement_descriptor_suffix",
+                self.statement_descriptor_suffix.as_deref(),
+                255,
+            )
+            .max_length("email", email, 255)
+            .email("email", email)
+            .max_length(
+                "name",
+                self.name.as_ref().map(|name| name.peek().as_str()),
+                255,
+            )
+            .phone_number("phone", phone)
+            .phone_country_code("phone_country_code", self.phone_country_code.as_deref())
+            .nested("shipping", self.shipping.as_ref())
+            .nested("billing", self.billing.as_ref());
+
+        if let Some(payments::PaymentMethodData::Card(ref card)) = self.payment_method_data {
+            validator.nested("payment_method_data.card", Some(card));
+        }
+    }
+}
+
+impl ValidateFields for payments::Card {
+    fn validate_fields(&self, validator: &mut FieldValidator) {
+        let card_number = self.card_number.peek().as_str();
+        if cfg!(feature = "sandbox") && SANDBOX_TEST_CARD_NUMBERS.contains(&card_number) {
+            return;
+        }
+
+        validator
+            .card_number("card_number", Some(card_number))
+            .card_expiry(
+                ("card_exp_month", self.card_exp_month.peek()),
+                ("card_exp_year", self.card_exp_year.peek()),
+            )
+            .card_cvc("card_cvc", self.card_cvc.peek(), card_number);
+    }
+}
+
+impl ValidateFields for payments::Address {
+    fn validate_fields(&self, validator: &mut FieldValidator) {
+        validator
+            .nested("address", self.address.as_ref())
+            .nested("phone", self.phone.as_ref());
+    }
+}
+
+impl ValidateFields for payments::AddressDetails {
+    fn validate_fields(&self, validator: &mut FieldValidator) {
+        let peek = |value: &Option<masking::Secret<String>>| {
+            value.as_ref().map(|value| value.peek().as_str())
+        };
+
+        validator
+            .max_length("city", self.city.as_deref(), 128)
+            .max_length("line1", peek(&self.line1), 255)
+            .max_length("line2", peek(&self.line2), 255)
+            .max_length("line3", peek(&self.line3), 255)
+            .max_length("zip", peek(&self.zip), 16)
+            .max_length("state", peek(&self.state), 128)
+            .max_length("first_name", peek(&self.first_name), 255)
+            .max_length("last_name", peek(&self.last_name), 255);
+    }
+}
+
+impl ValidateFields for payments::PhoneDetails {
+    fn validate_fields(&self, validator: &mut FieldValidator) {
+        validator
+            .phone_number(
+                "number",
+                self.number.as_ref().map(|number| number.peek().as_str()),
+            )
+            .phone_country_code("country_code", self.country_code.as_deref());
+    }
+}
+
+impl ValidateFields for customers::CustomerRequest {
+    fn validate_fields(&self, validator: &mut FieldValidator) {
+        let email = self.email.as_ref().map(|email| email.peek().as_str());
+        let phone = self.phone.as_ref().map(|phone| phone.peek().as_str());
+
+        validator
+            .max_length("customer_id", Some(self.customer_id.as_str()), 64)
+            .max_length("name", self.name.as_deref(), 255)
+            .max_length("email", email, 255)
+            .email("email", email)
+            .phone_number("phone", phone)
+            .phone_country_code("phone_country_code", self.phone_country_code.as_deref())
+            .max_length("description", self.description.as_deref(), 255);
+    }
+}
+
+impl ValidateFields for refunds::RefundRequest {
+    fn validate_fields(&self, validator: &mut FieldValidator) {
+        validator
+            .max_length("refund_id", self.refund_id.as_deref(), 64)
+            .amount("amount", self.amount, None)
+            .max_length("reason", self.reason.as_deref(), 255);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_field_errors<T: ValidateFields>(request: &T) -> Vec<String> {
+        let mut validator = FieldValidator::default();
+        request.validate_fields(&mut validator);
+        validator
+            .into_errors()
+            .into_iter()
+            .map(|error| error.field_name)
+            .collect()
+    }
+
+    #[test]
+    fn test_all_invalid_fields_are_reported() {
+        let request = payments::PaymentsRequest {
+            amount: Some(payments::Amount::from(100_000_000)),
+            currency: Some(api_enums::Currency::JPY),
+            description: Some("a".repeat(256)),
+            email: Some(masking::Secret::new("not an email".to_string())),
+            billing: Some(payments::Address {
+                address: None,
+                phone: Some(payments::PhoneDetails {
+                    number: Some(masking::Secret::new("12".to_string())),
+                    country_code: Some("+1".to_string()),
+                }),
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_field_errors(&request),
+            vec!["amount", "description", "email", "billing.phone.number"]
+        );
+    }
+
+    #[test]
+    fn test_card_fields_are_reported_separately() {
+        let request = payments::PaymentsRequest {
+            payment_method_data: Some(payments::PaymentMethodData::Card(payments::Card {
+                card_number: masking::Secret::new("378282246310004".to_string()),
+                card_exp_month: masking::Secret::new("12".to_string()),
+                card_exp_year: masking::Secret::new("2019".to_string()),
+                card_cvc: masking::Secret::new("123".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_field_errors(&request),
+            vec![
+                "payment_method_data.card.card_number",
+                "payment_method_data.card.card_exp_year",
+                "payment_method_data.card.card_cvc"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_card_expiry() {
+        let mut validator = FieldValidator::default();
+        validator.card_expiry(("card_exp_month", "13"), ("card_exp_year", "20"));
+        assert_eq!(validator.into_errors().len(), 1);
+
+        let mut validator = FieldValidator::default();
+        validator.card_expiry(("card_exp_month", "01"), ("card_exp_year", "20"));
+        assert_eq!(validator.into_errors().len(), 1);
+
+        let mut validator = FieldValidator::default();
+        validator.card_expiry(("card_exp_month", "12"), ("card_exp_year", "2099"));
+        assert!(validator.into_errors().is_empty());
+    }
+
+    #[test]
+    fn test_max_amount_depends_on_currency() {
+        let request = payments::PaymentsRequest {
+            amount: Some(payments::Amount::from(100_000_000)),
+            currency: Some(api_enums::Currency::USD),
+            ..Default::default()
+        };
+
+        assert!(get_field_errors(&request).is_empty());
+    }
+}
@@ -0,0 +1,52 @@
+#![allow(clippy::unwrap_used)]
+
+mod utils;
+
+use actix_http::StatusCode;
+use utils::{mk_service, ApiKey, AppClient, MerchantId};
+
+#[actix_web::test]
+async fn scoped_api_key_is_refused_outside_its_scope() {
+    let server = mk_service().await;
+    let client = AppClient::guest();
+    let admin_client = client.admin("test_admin");
+
+    let hlist_pat![merchant_id]: HList![MerchantId] =
+        admin_client.create_merchant_account(&server, None).await;
+
+    let hlist_pat![payments_read_key]: HList![ApiKey] = admin_client
+        .create_api_key(&server, &merchant_id, Some(vec!["payments:read"]))
+        .await;
+    let hlist_pat![refunds_write_key]: HList![ApiKey] = admin_client
+        .create_api_key(&server, &merchant_id, Some(vec!["refunds:write"]))
+        .await;
+    let hlist_pat![unscoped_key]: HList![ApiKey] = admin_client
+        .create_api_key(&server, &merchant_id, None)
+        .await;
+
+    // Routes which are not restricted to a scope refuse every scoped API key
+    let status = client
+        .user(&payments_read_key)
+        .get_status(&server, "/customers/cus_unknown")
+        .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let status = client
+        .user(&unscoped_key)
+        .get_status(&server, "/customers/cus_unknown")
+        .await;
+    assert_ne!(status, StatusCode::FORBIDDEN);
+
+    // Scoped routes only accept the API keys having their scope
+    let status = client
+        .user(&refunds_write_key)
+        .get_status(&server, "/payments/pay_unknown")
+        .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let status = client
+        .user(&payments_read_key)
+        .get_status(&server, "/payments/pay_unknown")
+        .await;
+    assert_ne!(status, StatusCode::FORBIDDEN);
+}
@@ -30,7 +30,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         description: Some("This is a test".to_string()),
         return_url: None,
         request: types::PaymentsAuthorizeData {
-            amount: 1000,
+            amount: types::MinorUnit::new(1000),
             currency: enums::Currency::USD,
             payment_method_data: types::api::PaymentMethodData::Card(types::api::Card {
                 card_number: Secret::new("4200000000000000".to_string()),
@@ -51,6 +51,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             capture_method: None,
             browser_info: None,
             order_details: None,
+            amount_breakdown: None,
+            l2_l3_data: None,
             email: None,
             session_token: None,
             enrolled_for_3ds: false,
@@ -60,9 +62,16 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             router_return_url: None,
             webhook_url: None,
             complete_authorize_url: None,
+            three_ds_data: None,
+            network_token: None,
+            apple_pay_predecrypt_data: None,
+            stored_credential: None,
+            split_payments: None,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
+        connector_raw_response: None,
+        connector_debug_mode: false,
         address: PaymentAddress::default(),
         connector_meta_data: None,
         amount_captured: None,
@@ -101,6 +110,8 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
             connector_refund_id: None,
         },
         payment_method_id: None,
+        connector_raw_response: None,
+        connector_debug_mode: false,
         response: Err(types::ErrorResponse::default()),
         address: PaymentAddress::default(),
         connector_meta_data: None,
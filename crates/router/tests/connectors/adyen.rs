@@ -58,7 +58,7 @@ impl AdyenTest {
         capture_method: enums::CaptureMethod,
     ) -> Option<types::PaymentsAuthorizeData> {
         Some(types::PaymentsAuthorizeData {
-            amount: 3500,
+            amount: types::MinorUnit::new(3500),
             currency: enums::Currency::USD,
             payment_method_data: types::api::PaymentMethodData::Card(types::api::Card {
                 card_number: Secret::new(card_number.to_string()),
@@ -79,6 +79,8 @@ impl AdyenTest {
             capture_method: Some(capture_method),
             browser_info: None,
             order_details: None,
+            amount_breakdown: None,
+            l2_l3_data: None,
             email: None,
             payment_experience: None,
             payment_method_type: None,
@@ -88,6 +90,11 @@ impl AdyenTest {
             router_return_url: Some(String::from("http://localhost:8080")),
             webhook_url: None,
             complete_authorize_url: None,
+            three_ds_data: None,
+            network_token: None,
+            apple_pay_predecrypt_data: None,
+            stored_credential: None,
+            split_payments: None,
         })
     }
 }
@@ -458,4 +465,30 @@ async fn should_fail_capture_for_invalid_payment() {
 
 // Connector dependent test cases goes here
 
+// Creates a SEPA direct debit payment, which is pending until the debit is settled.
+#[actix_web::test]
+async fn should_make_sepa_direct_debit_payment() {
+    let response = CONNECTOR
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                currency: enums::Currency::EUR,
+                payment_method_data: types::api::PaymentMethodData::BankDebit(
+                    api_models::payments::BankDebitData::SepaBankDebit {
+                        billing_details: api_models::payments::BankDebitBilling {
+                            name: Secret::new("A. Schneider".to_string()),
+                            email: Secret::new("a.schneider@example.com".to_string()),
+                        },
+                        iban: Secret::new("NL13TEST0123456789".to_string()),
+                    },
+                ),
+                router_return_url: Some(String::from("http://localhost:8080")),
+                ..utils::PaymentAuthorizeType::default().0
+            }),
+            AdyenTest::get_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::Pending);
+}
+
 // [#478]: add unit tests for non 3DS, wallets & webhooks in connector tests
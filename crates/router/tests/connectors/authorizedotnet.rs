@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use masking::Secret;
 use router::{
-    configs::settings::Settings,
+    configs::settings::{self, Settings},
     connector::Authorizedotnet,
     core::payments,
     db::StorageImpl,
@@ -17,6 +17,12 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         .authorizedotnet
         .expect("Missing Authorize.net connector authentication configuration");
 
+    construct_payment_router_data_with_auth(auth.into())
+}
+
+fn construct_payment_router_data_with_auth(
+    connector_auth_type: types::ConnectorAuthType,
+) -> types::PaymentsAuthorizeRouterData {
     types::RouterData {
         flow: PhantomData,
         merchant_id: String::from("authorizedotnet"),
@@ -25,12 +31,12 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         attempt_id: uuid::Uuid::new_v4().to_string(),
         status: enums::AttemptStatus::default(),
         payment_method: enums::PaymentMethod::Card,
-        connector_auth_type: auth.into(),
+        connector_auth_type,
         auth_type: enums::AuthenticationType::NoThreeDs,
         description: Some("This is a test".to_string()),
         return_url: None,
         request: types::PaymentsAuthorizeData {
-            amount: 100,
+            amount: types::MinorUnit::new(100),
             currency: enums::Currency::USD,
             payment_method_data: types::api::PaymentMethodData::Card(types::api::Card {
                 card_number: Secret::new("5424000000000015".to_string()),
@@ -51,6 +57,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             capture_method: None,
             browser_info: None,
             order_details: None,
+            amount_breakdown: None,
+            l2_l3_data: None,
             email: None,
             session_token: None,
             enrolled_for_3ds: false,
@@ -60,8 +68,15 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             router_return_url: None,
             webhook_url: None,
             complete_authorize_url: None,
+            three_ds_data: None,
+            network_token: None,
+            apple_pay_predecrypt_data: None,
+            stored_credential: None,
+            split_payments: None,
         },
         payment_method_id: None,
+        connector_raw_response: None,
+        connector_debug_mode: false,
         response: Err(types::ErrorResponse::default()),
         address: PaymentAddress::default(),
         connector_meta_data: None,
@@ -102,6 +117,8 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
+        connector_raw_response: None,
+        connector_debug_mode: false,
         address: PaymentAddress::default(),
         amount_captured: None,
         access_token: None,
@@ -110,6 +127,38 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
     }
 }
 
+#[test]
+fn should_mask_card_data_in_logged_request() {
+    static CV: Authorizedotnet = Authorizedotnet;
+    let connector = types::api::ConnectorData {
+        connector: Box::new(&CV),
+        connector_name: types::Connector::Authorizedotnet,
+        get_token: types::api::GetToken::Connector,
+    };
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        types::api::Authorize,
+        types::PaymentsAuthorizeData,
+        types::PaymentsResponseData,
+    > = connector.connector.get_connector_integration();
+    let request = construct_payment_router_data_with_auth(types::ConnectorAuthType::BodyKey {
+        api_key: "login_id".to_string(),
+        key1: "transaction_key".to_string(),
+    });
+
+    let connector_request = connector_integration
+        .build_request(&request, &settings::Connectors::default())
+        .unwrap()
+        .unwrap();
+    let masked_body = services::api::get_masked_request_body(&connector_request).unwrap();
+
+    assert!(masked_body.contains("cardNumber"));
+    assert!(!masked_body.contains("5424000000000015"));
+    assert!(!masked_body.contains("0000000015"));
+    assert!(!masked_body.contains("\"999\""));
+    assert!(!masked_body.contains("2025-10"));
+}
+
 #[actix_web::test]
 #[ignore]
 async fn payments_create_success() {
@@ -146,6 +195,41 @@ async fn payments_create_success() {
     );
 }
 
+#[actix_web::test]
+#[ignore]
+async fn payments_authorize_success() {
+    let conf = Settings::new().unwrap();
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    static CV: Authorizedotnet = Authorizedotnet;
+    let connector = types::api::ConnectorData {
+        connector: Box::new(&CV),
+        connector_name: types::Connector::Authorizedotnet,
+        get_token: types::api::GetToken::Connector,
+    };
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        types::api::Authorize,
+        types::PaymentsAuthorizeData,
+        types::PaymentsResponseData,
+    > = connector.connector.get_connector_integration();
+    let mut request = construct_payment_router_data();
+    request.request.capture_method = Some(enums::CaptureMethod::Manual);
+
+    let response = services::api::execute_connector_processing_step(
+        &state,
+        connector_integration,
+        &request,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .unwrap();
+
+    assert!(
+        response.status == enums::AttemptStatus::Authorized,
+        "The payment was not authorized"
+    );
+}
+
 #[actix_web::test]
 #[ignore]
 async fn payments_create_failure() {
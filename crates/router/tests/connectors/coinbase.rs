@@ -0,0 +1,91 @@
+use api_models::payments::CryptoData;
+use router::types::{self, api, storage::enums};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+#[derive(Clone, Copy)]
+struct CoinbaseTest;
+impl ConnectorActions for CoinbaseTest {}
+impl utils::Connector for CoinbaseTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Coinbase;
+        types::api::ConnectorData {
+            connector: Box::new(&Coinbase),
+            connector_name: types::Connector::Coinbase,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .coinbase
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "coinbase".to_string()
+    }
+}
+
+static CONNECTOR: CoinbaseTest = CoinbaseTest {};
+
+fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        payment_method_data: api::PaymentMethodData::Crypto(CryptoData {}),
+        router_return_url: Some(String::from("https://hyperswitch.io")),
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+// Creates a charge, which is paid by the customer on the hosted payment page of Coinbase
+#[actix_web::test]
+async fn should_create_charge() {
+    let response = CONNECTOR
+        .authorize_payment(payment_method_details(), None)
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    let redirection_data = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        }) => redirection_data,
+        _ => None,
+    };
+    assert!(redirection_data.is_some());
+}
+
+// Synchronizes a charge which was not paid by the customer yet
+#[actix_web::test]
+async fn should_sync_unpaid_charge() {
+    let authorize_response = CONNECTOR
+        .authorize_payment(payment_method_details(), None)
+        .await
+        .expect("Authorize payment response");
+    let txn_id = utils::get_connector_transaction_id(authorize_response.response);
+    let response = CONNECTOR
+        .psync_retry_till_status_matches(
+            enums::AttemptStatus::AuthenticationPending,
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: router::types::ResponseId::ConnectorTransactionId(
+                    txn_id.unwrap(),
+                ),
+                ..Default::default()
+            }),
+            None,
+        )
+        .await
+        .expect("PSync response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+}
+
+// Creates a charge with a payment method other than crypto
+#[actix_web::test]
+async fn should_fail_payment_for_unsupported_payment_method() {
+    let response = CONNECTOR.authorize_payment(None, None).await;
+    assert!(response.is_err());
+}
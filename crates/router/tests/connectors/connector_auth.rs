@@ -11,9 +11,12 @@ pub(crate) struct ConnectorAuthentication {
     pub authorizedotnet: Option<BodyKey>,
     pub bambora: Option<BodyKey>,
     pub bluesnap: Option<BodyKey>,
+    pub braintree: Option<SignatureKey>,
     pub checkout: Option<BodyKey>,
+    pub coinbase: Option<HeaderKey>,
     pub cybersource: Option<SignatureKey>,
     pub dlocal: Option<SignatureKey>,
+    pub dummyconnector: Option<HeaderKey>,
     pub fiserv: Option<SignatureKey>,
     pub globalpay: Option<HeaderKey>,
     pub mollie: Option<HeaderKey>,
@@ -22,6 +25,7 @@ pub(crate) struct ConnectorAuthentication {
     pub paypal: Option<BodyKey>,
     pub payu: Option<BodyKey>,
     pub rapyd: Option<BodyKey>,
+    pub razorpay: Option<BodyKey>,
     pub shift4: Option<HeaderKey>,
     pub stripe: Option<HeaderKey>,
     pub worldpay: Option<BodyKey>,
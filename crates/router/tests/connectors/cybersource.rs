@@ -62,6 +62,14 @@ fn get_default_payment_authorize_data() -> Option<types::PaymentsAuthorizeData>
         ..PaymentAuthorizeType::default().0
     })
 }
+fn get_default_cancel_data() -> Option<types::PaymentsCancelData> {
+    Some(types::PaymentsCancelData {
+        amount: Some(100),
+        currency: Some(storage::enums::Currency::USD),
+        cancellation_reason: Some("requested_by_customer".to_string()),
+        ..utils::PaymentCancelType::default().0
+    })
+}
 #[actix_web::test]
 async fn should_only_authorize_payment() {
     let response = Cybersource {}
@@ -136,11 +144,7 @@ async fn should_void_already_authorized_payment() {
     let response = connector
         .authorize_and_void_payment(
             get_default_payment_authorize_data(),
-            Some(types::PaymentsCancelData {
-                connector_transaction_id: "".to_string(),
-                cancellation_reason: Some("requested_by_customer".to_string()),
-                ..Default::default()
-            }),
+            get_default_cancel_data(),
             get_default_payment_info(),
         )
         .await;
@@ -163,6 +167,7 @@ async fn should_fail_payment_for_incorrect_card_number() {
         .unwrap();
     let x = response.response.unwrap_err();
     assert_eq!(x.message, "Decline - Invalid account number",);
+    assert_eq!(x.code, "invalid_card_number");
 }
 #[actix_web::test]
 async fn should_fail_payment_for_no_card_number() {
@@ -220,6 +225,7 @@ async fn should_fail_payment_for_invalid_exp_year() {
         .unwrap();
     let x = response.response.unwrap_err();
     assert_eq!(x.message, "Decline - Expired card. You might also receive this if the expiration date you provided does not match the date the issuing bank has on file.",);
+    assert_eq!(x.code, "expired_card");
 }
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_card_cvc() {
@@ -259,7 +265,11 @@ async fn should_fail_void_payment_for_reversed_payment() {
     assert_ne!(txn_id, None, "Empty connector transaction id");
     // Void
     let void_response = connector
-        .void_payment("6736046645576085004953".to_string(), None, None)
+        .void_payment(
+            "6736046645576085004953".to_string(),
+            get_default_cancel_data(),
+            None,
+        )
         .await
         .unwrap();
     let res = void_response.response.unwrap_err();
@@ -280,7 +290,7 @@ async fn should_fail_capture_for_invalid_payment() {
         err.message,
         r#"Declined - One or more fields in the request contains invalid data [{"field":"id","reason":"INVALID_DATA"}]"#
     );
-    assert_eq!(err.code, "No error code".to_string());
+    assert_eq!(err.code, "invalid_request".to_string());
 }
 #[actix_web::test]
 async fn should_refund_succeeded_payment() {
@@ -0,0 +1,134 @@
+use masking::Secret;
+use router::types::{self, api, storage::enums};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+#[derive(Clone, Copy)]
+struct DummyConnectorTest;
+impl ConnectorActions for DummyConnectorTest {}
+impl utils::Connector for DummyConnectorTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::DummyConnector;
+        types::api::ConnectorData {
+            connector: Box::new(&DummyConnector),
+            connector_name: types::Connector::Dummyconnector,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .dummyconnector
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "dummyconnector".to_string()
+    }
+}
+
+static CONNECTOR: DummyConnectorTest = DummyConnectorTest {};
+
+fn get_payment_authorize_data(
+    card_number: &str,
+    amount: i64,
+) -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        payment_method_data: types::api::PaymentMethodData::Card(api::Card {
+            card_number: Secret::new(card_number.to_string()),
+            ..utils::CCardType::default().0
+        }),
+        amount,
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+// Creates a payment which succeeds.
+#[actix_web::test]
+async fn should_make_payment() {
+    let response = CONNECTOR
+        .make_payment(get_payment_authorize_data("4242424242424242", 100), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+}
+
+// Creates a payment with a card which is declined.
+#[actix_web::test]
+async fn should_fail_payment_for_declined_card() {
+    let response = CONNECTOR
+        .make_payment(get_payment_authorize_data("4000000000009995", 100), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Failure);
+    assert_eq!(
+        response.response.unwrap_err().code,
+        "insufficient_funds".to_string(),
+    );
+}
+
+// Creates a payment with a card which requires a 3DS challenge.
+#[actix_web::test]
+async fn should_require_authentication_for_3ds_card() {
+    let response = CONNECTOR
+        .make_payment(get_payment_authorize_data("4000000000003220", 100), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+}
+
+// Creates a payment which is processed asynchronously, and synchronizes it once it succeeds.
+#[actix_web::test]
+async fn should_sync_asynchronously_processed_payment() {
+    let authorize_response = CONNECTOR
+        .make_payment(get_payment_authorize_data("4242424242424242", 9001), None)
+        .await
+        .unwrap();
+    assert_eq!(authorize_response.status, enums::AttemptStatus::Pending);
+    let txn_id = utils::get_connector_transaction_id(authorize_response.response);
+    let response = CONNECTOR
+        .psync_retry_till_status_matches(
+            enums::AttemptStatus::Charged,
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: router::types::ResponseId::ConnectorTransactionId(
+                    txn_id.unwrap(),
+                ),
+                ..Default::default()
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+}
+
+// Refunds a payment which succeeded, and synchronizes the refund.
+#[actix_web::test]
+async fn should_refund_and_sync_succeeded_payment() {
+    let refund_response = CONNECTOR
+        .make_payment_and_refund(
+            get_payment_authorize_data("4242424242424242", 100),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    let response = CONNECTOR
+        .rsync_retry_till_status_matches(
+            enums::RefundStatus::Success,
+            refund_response.response.unwrap().connector_refund_id,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        response.response.unwrap().refund_status,
+        enums::RefundStatus::Success,
+    );
+}
@@ -0,0 +1,268 @@
+//! Recording and replaying of the HTTP interactions of connector tests.
+//!
+//! The mode is selected with the `CONNECTOR_TEST_MODE` environment variable:
+//!
+//! - `live` (default): requests are sent to the connector, as usual.
+//! - `record`: requests are sent to the connector, and the interactions of each test are saved to
+//!   `tests/connectors/fixtures/<connector>/<test>.json`.
+//! - `replay`: requests are built but not sent, and the connector responses are read from the
+//!   fixture of the test instead, so that the transformers and flows of a connector can be tested
+//!   without credentials for it.
+//!
+//! The payment and attempt identifiers of the request and the credentials of the connector are
+//! replaced with placeholders in recorded fixtures, and substituted back when replaying them.
+//! Requests made by `execute_pretasks`, such as fetching access tokens, are not recorded.
+
+use std::{cell::Cell, fmt::Debug, path::PathBuf};
+
+use error_stack::{Report, ResultExt};
+use masking::ExposeOptionInterface;
+use router::{
+    core::{errors::ConnectorError, payments},
+    routes, services,
+    types::{self, RouterData},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TestMode {
+    Live,
+    Record,
+    Replay,
+}
+
+impl TestMode {
+    fn from_env() -> Self {
+        match std::env::var("CONNECTOR_TEST_MODE").as_deref() {
+            Ok("record") => Self::Record,
+            Ok("replay") => Self::Replay,
+            Ok("live") | Err(_) => Self::Live,
+            Ok(mode) => panic!("Unknown connector test mode: {mode}"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct RecordedRequest {
+    method: services::Method,
+    url: String,
+    body: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct RecordedResponse {
+    status_code: u16,
+    body: serde_json::Value,
+}
+
+thread_local! {
+    // Each test runs on its own thread, so this counts the interactions of the current test.
+    static INTERACTION_INDEX: Cell<usize> = Cell::new(0);
+}
+
+/// The name of the running test, such as `stripe::should_make_payment`, which the test harness
+/// gives to the thread running it.
+fn get_test_name() -> String {
+    std::thread::current()
+        .name()
+        .filter(|name| name.contains("::"))
+        .map(ToString::to_string)
+        .expect("Connector fixtures can only be used from a test thread named after the test")
+}
+
+fn get_fixture_path(test_name: &str) -> PathBuf {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("tests/connectors/fixtures");
+    path.extend(test_name.split("::"));
+    path.set_extension("json");
+    path
+}
+
+fn read_fixture(test_name: &str) -> Vec<Interaction> {
+    let path = get_fixture_path(test_name);
+    let fixture = std::fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("Failed to read the connector fixture {}", path.display()));
+    serde_json::from_str(&fixture)
+        .unwrap_or_else(|_| panic!("Failed to parse the connector fixture {}", path.display()))
+}
+
+fn write_fixture(test_name: &str, interactions: &[Interaction]) {
+    let path = get_fixture_path(test_name);
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)
+            .expect("Failed to create the connector fixtures directory");
+    }
+    let fixture =
+        serde_json::to_string_pretty(interactions).expect("Failed to serialize connector fixture");
+    std::fs::write(path, fixture + "\n").expect("Failed to write the connector fixture");
+}
+
+/// Values which vary between runs of a test, or must not be committed, with their placeholders.
+fn get_placeholders<T, Req, Resp>(request: &RouterData<T, Req, Resp>) -> Vec<(String, String)> {
+    let mut placeholders = vec![
+        ("{payment_id}".to_string(), request.payment_id.clone()),
+        ("{attempt_id}".to_string(), request.attempt_id.clone()),
+    ];
+    match &request.connector_auth_type {
+        types::ConnectorAuthType::HeaderKey { api_key } => {
+            placeholders.push(("{api_key}".to_string(), api_key.clone()));
+        }
+        types::ConnectorAuthType::BodyKey { api_key, key1 } => {
+            placeholders.push(("{api_key}".to_string(), api_key.clone()));
+            placeholders.push(("{key1}".to_string(), key1.clone()));
+        }
+        types::ConnectorAuthType::SignatureKey {
+            api_key,
+            key1,
+            api_secret,
+        } => {
+            placeholders.push(("{api_key}".to_string(), api_key.clone()));
+            placeholders.push(("{key1}".to_string(), key1.clone()));
+            placeholders.push(("{api_secret}".to_string(), api_secret.clone()));
+        }
+        types::ConnectorAuthType::NoKey => {}
+    }
+    placeholders.retain(|(_, value)| !value.is_empty());
+    placeholders
+}
+
+fn insert_placeholders(text: &str, placeholders: &[(String, String)]) -> String {
+    placeholders
+        .iter()
+        .fold(text.to_string(), |text, (placeholder, value)| {
+            text.replace(value.as_str(), placeholder)
+        })
+}
+
+fn remove_placeholders(text: &str, placeholders: &[(String, String)]) -> String {
+    placeholders
+        .iter()
+        .fold(text.to_string(), |text, (placeholder, value)| {
+            text.replace(placeholder.as_str(), value)
+        })
+}
+
+/// Bodies are kept as JSON documents in fixtures when they are valid JSON, for readability.
+fn to_fixture_body(body: &str) -> serde_json::Value {
+    serde_json::from_str(body).unwrap_or_else(|_| serde_json::Value::String(body.to_string()))
+}
+
+fn from_fixture_body(body: &serde_json::Value) -> String {
+    match body {
+        serde_json::Value::String(body) => body.clone(),
+        body => body.to_string(),
+    }
+}
+
+/// Calls the connector for the request, or replays its response from the fixture of the test,
+/// depending on the mode the connector tests run in.
+pub async fn call_connector<
+    T: Debug + Clone + 'static,
+    Req: Debug + Clone + 'static,
+    Resp: Debug + Clone + 'static,
+>(
+    state: &routes::AppState,
+    request: RouterData<T, Req, Resp>,
+    integration: services::BoxedConnectorIntegration<'_, T, Req, Resp>,
+) -> Result<RouterData<T, Req, Resp>, Report<ConnectorError>> {
+    let mode = TestMode::from_env();
+    if mode == TestMode::Live {
+        return services::api::execute_connector_processing_step(
+            state,
+            integration,
+            &request,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await;
+    }
+
+    let connector_request = match integration.build_request(&request, &state.conf.connectors)? {
+        Some(connector_request) => connector_request,
+        None => return Ok(request),
+    };
+    let test_name = get_test_name();
+    let placeholders = get_placeholders(&request);
+    let recorded_request = RecordedRequest {
+        method: connector_request.method,
+        url: insert_placeholders(&connector_request.url, &placeholders),
+        body: connector_request
+            .payload
+            .clone()
+            .expose_option()
+            .map(|body| to_fixture_body(&insert_placeholders(&body, &placeholders))),
+    };
+    let index = INTERACTION_INDEX.with(|index| index.replace(index.get() + 1));
+
+    let response = match mode {
+        TestMode::Replay => {
+            let interaction = read_fixture(&test_name)
+                .into_iter()
+                .nth(index)
+                .unwrap_or_else(|| {
+                    panic!("The connector fixture of {test_name} has no interaction {index}")
+                });
+            assert_eq!(
+                (interaction.request.method, interaction.request.url.as_str()),
+                (recorded_request.method, recorded_request.url.as_str()),
+                "Interaction {index} of {test_name} was made to a different endpoint than the one recorded",
+            );
+            let response = types::Response {
+                response: remove_placeholders(
+                    &from_fixture_body(&interaction.response.body),
+                    &placeholders,
+                )
+                .into_bytes()
+                .into(),
+                status_code: interaction.response.status_code,
+            };
+            // The same status codes as `call_connector_api` treats as successful
+            if matches!(response.status_code, 200..=202 | 302) {
+                Ok(response)
+            } else {
+                Err(response)
+            }
+        }
+        TestMode::Live | TestMode::Record => {
+            let response = services::api::call_connector_api(state, connector_request)
+                .await
+                .change_context(ConnectorError::ProcessingStepFailed(None))?;
+            let recorded_response = match response {
+                Ok(ref response) | Err(ref response) => response,
+            };
+            let mut interactions = if index == 0 {
+                Vec::new()
+            } else {
+                read_fixture(&test_name)
+            };
+            interactions.push(Interaction {
+                request: recorded_request,
+                response: RecordedResponse {
+                    status_code: recorded_response.status_code,
+                    body: to_fixture_body(&insert_placeholders(
+                        &String::from_utf8_lossy(&recorded_response.response),
+                        &placeholders,
+                    )),
+                },
+            });
+            write_fixture(&test_name, &interactions);
+            response
+        }
+    };
+
+    match response {
+        Ok(response) => integration.handle_response(&request, response),
+        Err(response) => {
+            let error = integration.get_error_response(response)?;
+            Ok(RouterData {
+                response: Err(error),
+                ..request
+            })
+        }
+    }
+}
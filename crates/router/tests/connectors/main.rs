@@ -6,11 +6,15 @@ mod airwallex;
 mod authorizedotnet;
 mod bambora;
 mod bluesnap;
+mod braintree;
 mod checkout;
+mod coinbase;
 mod connector_auth;
 mod cybersource;
 mod dlocal;
+mod dummyconnector;
 mod fiserv;
+mod fixtures;
 mod globalpay;
 mod mollie;
 mod multisafepay;
@@ -18,6 +22,7 @@ mod nuvei;
 mod paypal;
 mod payu;
 mod rapyd;
+mod razorpay;
 mod shift4;
 mod stripe;
 mod trustpay;
@@ -354,3 +354,47 @@ async fn should_fail_already_refunded_payment() {
     let x = response.response.unwrap_err();
     assert_eq!(x.reason.unwrap(), "PAID".to_string());
 }
+
+#[actix_web::test]
+#[ignore]
+async fn should_authorize_blik_payment() {
+    let authorize_response = Payu {}
+        .authorize_payment(
+            Some(types::PaymentsAuthorizeData {
+                currency: enums::Currency::PLN,
+                payment_method_data: api::PaymentMethodData::BankRedirect(
+                    api_models::payments::BankRedirectData::Blik {
+                        blik_code: masking::Secret::new("777123".to_string()),
+                    },
+                ),
+                ..PaymentAuthorizeType::default().0
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    // The payment stays pending until the customer confirms it in their banking app
+    assert_eq!(authorize_response.status, enums::AttemptStatus::Pending);
+}
+
+#[actix_web::test]
+async fn should_fail_latam_payment_with_europe_credentials() {
+    // The region of the account is taken from the connector metadata, PayU LATAM expects the
+    // api key, api login and merchant id of the account
+    let response = Payu {}
+        .authorize_payment(
+            Some(types::PaymentsAuthorizeData {
+                currency: enums::Currency::COP,
+                ..PaymentAuthorizeType::default().0
+            }),
+            Some(utils::PaymentInfo {
+                connector_meta_data: Some(serde_json::json!({
+                    "region": "latam",
+                    "account_id": "512321",
+                })),
+                ..get_default_payment_info().unwrap_or_default()
+            }),
+        )
+        .await;
+    assert!(response.is_err());
+}
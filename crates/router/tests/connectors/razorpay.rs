@@ -0,0 +1,156 @@
+use api_models::payments::{BankRedirectData, UpiData};
+use masking::Secret;
+use router::types::{self, api, storage::enums};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+#[derive(Clone, Copy)]
+struct RazorpayTest;
+impl ConnectorActions for RazorpayTest {}
+impl utils::Connector for RazorpayTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Razorpay;
+        types::api::ConnectorData {
+            connector: Box::new(&Razorpay),
+            connector_name: types::Connector::Razorpay,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .razorpay
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "razorpay".to_string()
+    }
+}
+
+static CONNECTOR: RazorpayTest = RazorpayTest {};
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        address: Some(types::PaymentAddress {
+            billing: Some(api::Address {
+                phone: Some(api::PhoneDetails {
+                    number: Some(Secret::new("9999999999".to_string())),
+                    country_code: Some("+91".to_string()),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+fn payment_method_details(
+    payment_method_data: api::PaymentMethodData,
+) -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        payment_method_data,
+        currency: enums::Currency::INR,
+        email: Some(Secret::new("customer@example.com".to_string())),
+        router_return_url: Some(String::from("https://hyperswitch.io")),
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+// Creates a netbanking payment, which is completed by the customer on the page of their bank
+#[actix_web::test]
+async fn should_redirect_netbanking_payment() {
+    let response = CONNECTOR
+        .authorize_payment(
+            payment_method_details(api::PaymentMethodData::BankRedirect(
+                BankRedirectData::Netbanking {
+                    bank_name: api_models::enums::BankNames::HdfcBank,
+                },
+            )),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    let redirection_data = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        }) => redirection_data,
+        _ => None,
+    };
+    assert!(redirection_data.is_some());
+}
+
+// Creates a UPI intent payment, which returns the deep link of the UPI apps
+#[actix_web::test]
+async fn should_return_upi_intent_link() {
+    let response = CONNECTOR
+        .authorize_payment(
+            payment_method_details(api::PaymentMethodData::Upi(UpiData::UpiIntent {})),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+}
+
+// Creates a UPI collect payment, which stays pending until the customer approves it
+#[actix_web::test]
+async fn should_sync_pending_upi_collect_payment() {
+    let authorize_response = CONNECTOR
+        .authorize_payment(
+            payment_method_details(api::PaymentMethodData::Upi(UpiData::UpiCollect {
+                vpa_id: Secret::new("success@razorpay".to_string()),
+            })),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(authorize_response.status, enums::AttemptStatus::Pending);
+    let txn_id = utils::get_connector_transaction_id(authorize_response.response);
+    let response = CONNECTOR
+        .psync_retry_till_status_matches(
+            enums::AttemptStatus::Pending,
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: router::types::ResponseId::ConnectorTransactionId(
+                    txn_id.unwrap(),
+                ),
+                ..Default::default()
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("PSync response");
+    assert_eq!(response.status, enums::AttemptStatus::Pending);
+}
+
+// Creates a payment with an unsupported bank
+#[actix_web::test]
+async fn should_fail_netbanking_payment_for_unsupported_bank() {
+    let response = CONNECTOR
+        .authorize_payment(
+            payment_method_details(api::PaymentMethodData::BankRedirect(
+                BankRedirectData::Netbanking {
+                    bank_name: api_models::enums::BankNames::AbnAmro,
+                },
+            )),
+            get_default_payment_info(),
+        )
+        .await;
+    assert!(response.is_err());
+}
+
+// Voids a payment, which is not supported by Razorpay
+#[actix_web::test]
+async fn should_fail_void_payment() {
+    let response = CONNECTOR
+        .void_payment("pay_123".to_string(), None, None)
+        .await;
+    assert!(response.is_err());
+}
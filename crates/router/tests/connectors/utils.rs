@@ -5,13 +5,15 @@ use error_stack::Report;
 use masking::Secret;
 use router::{
     configs::settings::Settings,
-    core::{errors, errors::ConnectorError, payments},
+    core::{errors, errors::ConnectorError},
     db::StorageImpl,
     routes, services,
     types::{self, api, storage::enums, AccessToken, PaymentAddress, RouterData},
 };
 use wiremock::{Mock, MockServer};
 
+use crate::fixtures;
+
 pub trait Connector {
     fn get_data(&self) -> types::api::ConnectorData;
     fn get_auth_token(&self) -> types::ConnectorAuthType;
@@ -77,6 +79,19 @@ pub trait ConnectorActions: Connector {
         call_connector(request, integration).await
     }
 
+    async fn verify_payment(
+        &self,
+        payment_data: Option<types::VerifyRequestData>,
+        payment_info: Option<PaymentInfo>,
+    ) -> Result<types::VerifyRouterData, Report<ConnectorError>> {
+        let integration = self.get_data().connector.get_connector_integration();
+        let request = self.generate_data(
+            payment_data.unwrap_or_else(|| PaymentVerifyType::default().0),
+            payment_info,
+        );
+        call_connector(request, integration).await
+    }
+
     async fn sync_payment(
         &self,
         payment_data: Option<types::PaymentsSyncData>,
@@ -372,6 +387,8 @@ pub trait ConnectorActions: Connector {
             request: req,
             response: Err(types::ErrorResponse::default()),
             payment_method_id: None,
+            connector_raw_response: None,
+            connector_debug_mode: false,
             address: info
                 .clone()
                 .and_then(|a| a.address)
@@ -412,13 +429,7 @@ async fn call_connector<
 ) -> Result<RouterData<T, Req, Resp>, Report<ConnectorError>> {
     let conf = Settings::new().unwrap();
     let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
-    services::api::execute_connector_processing_step(
-        &state,
-        integration,
-        &request,
-        payments::CallConnectorAction::Trigger,
-    )
-    .await
+    fixtures::call_connector(&state, request, integration).await
 }
 
 pub struct MockConfig {
@@ -449,6 +460,7 @@ pub struct PaymentAuthorizeType(pub types::PaymentsAuthorizeData);
 pub struct PaymentCaptureType(pub types::PaymentsCaptureData);
 pub struct PaymentCancelType(pub types::PaymentsCancelData);
 pub struct PaymentSyncType(pub types::PaymentsSyncData);
+pub struct PaymentVerifyType(pub types::VerifyRequestData);
 pub struct PaymentRefundType(pub types::RefundsData);
 pub struct CCardType(pub api::Card);
 pub struct BrowserInfoType(pub types::BrowserInformation);
@@ -471,7 +483,7 @@ impl Default for PaymentAuthorizeType {
     fn default() -> Self {
         let data = types::PaymentsAuthorizeData {
             payment_method_data: types::api::PaymentMethodData::Card(CCardType::default().0),
-            amount: 100,
+            amount: types::MinorUnit::new(100),
             currency: enums::Currency::USD,
             confirm: true,
             statement_descriptor_suffix: None,
@@ -483,6 +495,8 @@ impl Default for PaymentAuthorizeType {
             setup_mandate_details: None,
             browser_info: Some(BrowserInfoType::default().0),
             order_details: None,
+            amount_breakdown: None,
+            l2_l3_data: None,
             email: None,
             session_token: None,
             enrolled_for_3ds: false,
@@ -491,6 +505,11 @@ impl Default for PaymentAuthorizeType {
             payment_method_type: None,
             router_return_url: None,
             complete_authorize_url: None,
+            three_ds_data: None,
+            network_token: None,
+            apple_pay_predecrypt_data: None,
+            stored_credential: None,
+            split_payments: None,
             webhook_url: None,
         };
         Self(data)
@@ -551,6 +570,22 @@ impl Default for PaymentSyncType {
     }
 }
 
+impl Default for PaymentVerifyType {
+    fn default() -> Self {
+        let data = types::VerifyRequestData {
+            currency: enums::Currency::USD,
+            payment_method_data: types::api::PaymentMethodData::Card(CCardType::default().0),
+            confirm: true,
+            statement_descriptor_suffix: None,
+            mandate_id: None,
+            setup_future_usage: None,
+            off_session: None,
+            setup_mandate_details: None,
+        };
+        Self(data)
+    }
+}
+
 impl Default for PaymentRefundType {
     fn default() -> Self {
         let data = types::RefundsData {
@@ -589,6 +624,7 @@ pub fn get_connector_metadata(
             redirection_data: _,
             mandate_reference: _,
             connector_metadata,
+            verification_result: _,
         }) => connector_metadata,
         _ => None,
     }
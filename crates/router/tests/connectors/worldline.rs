@@ -61,7 +61,7 @@ impl WorldlineTest {
         capture_method: enums::CaptureMethod,
     ) -> Option<types::PaymentsAuthorizeData> {
         Some(types::PaymentsAuthorizeData {
-            amount: 3500,
+            amount: types::MinorUnit::new(3500),
             currency: enums::Currency::USD,
             payment_method_data: types::api::PaymentMethodData::Card(types::api::Card {
                 card_number: Secret::new(card_number.to_string()),
@@ -82,6 +82,8 @@ impl WorldlineTest {
             capture_method: Some(capture_method),
             browser_info: None,
             order_details: None,
+            amount_breakdown: None,
+            l2_l3_data: None,
             email: None,
             session_token: None,
             enrolled_for_3ds: false,
@@ -91,6 +93,11 @@ impl WorldlineTest {
             router_return_url: None,
             webhook_url: None,
             complete_authorize_url: None,
+            three_ds_data: None,
+            network_token: None,
+            apple_pay_predecrypt_data: None,
+            stored_credential: None,
+            split_payments: None,
         })
     }
 }
@@ -233,6 +233,16 @@ async fn should_sync_refund() {
     );
 }
 
+#[actix_web::test]
+#[serial]
+async fn should_verify_card() {
+    let connector = Worldpay {};
+    let _mock = connector.start_server(get_mock_config()).await;
+    let response = connector.verify_payment(None, None).await.unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    assert!(response.response.is_ok());
+}
+
 fn get_mock_config() -> MockConfig {
     let authorized = json!({
         "outcome": "authorized",
@@ -332,9 +342,18 @@ fn get_mock_config() -> MockConfig {
         }
     });
 
+    let verified = json!({
+        "outcome": "verified",
+        "transactionReference": "verification-reference",
+        "schemeReference": "MCC0RVO9J0904"
+    });
+
     MockConfig {
         address: Some("127.0.0.1:9090".to_string()),
         mocks: vec![
+            Mock::given(method("POST"))
+                .and(path("/verifications/accounts/intelligent".to_string()))
+                .respond_with(ResponseTemplate::new(201).set_body_json(verified)),
             Mock::given(method("POST"))
                 .and(path("/payments/authorizations".to_string()))
                 .respond_with(ResponseTemplate::new(201).set_body_json(authorized)),
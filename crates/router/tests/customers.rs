@@ -16,10 +16,12 @@ async fn customer_success() {
     let api_key = ("API-KEY", "MySecretApiKey");
     let name = "Doe";
     let new_name = "new Doe";
+    let email = format!("{}@example.com", uuid::Uuid::new_v4().simple());
 
     let request = serde_json::json!({
         "customer_id" : customer_id,
         "name" : name,
+        "email" : email,
     });
 
     let update_request = serde_json::json!({
@@ -63,6 +65,29 @@ async fn customer_success() {
     println!("customer-update: {response:?} =:= {response_body:?}");
     assert_eq!(response.status(), awc::http::StatusCode::OK);
 
+    // partially update customer
+    response = client
+        .patch(format!("http://127.0.0.1:8080/customers/{customer_id}"))
+        .insert_header(api_key)
+        .send_json(&update_request)
+        .await
+        .unwrap();
+    response_body = response.body().await;
+    println!("customer-patch: {response:?} =:= {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+
+    // search customers by email
+    response = client
+        .get(format!("http://127.0.0.1:8080/customers?email={email}"))
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    let customers: serde_json::Value = response.json().await.unwrap();
+    println!("customer-list: {response:?} =:= {customers:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_eq!(customers[0]["customer_id"], customer_id);
+
     // delete customer
     response = client
         .delete(format!("http://127.0.0.1:8080/customers/{customer_id}"))
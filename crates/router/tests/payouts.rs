@@ -3,34 +3,43 @@
 mod utils;
 
 #[actix_web::test]
-async fn payouts_todo() {
+async fn payouts_unauthorized() {
     utils::setup().await;
 
     let client = awc::Client::default();
     let mut response;
     let mut response_body;
-    let get_endpoints = vec!["retrieve", "accounts"];
-    let post_endpoints = vec!["create", "update", "reverse", "cancel"];
+    let payout_id = format!("po_{}", uuid::Uuid::new_v4().simple());
+    let get_endpoints = vec![format!("payouts/{payout_id}")];
+    let post_endpoints = vec!["payouts".to_string(), format!("payouts/{payout_id}/cancel")];
+    let request = serde_json::json!({
+        "payout_id": payout_id,
+        "amount": 1000,
+        "currency": "EUR",
+        "connector": "adyen",
+        "payout_type": "card",
+        "payout_method_id": "pom_test",
+    });
 
     for endpoint in get_endpoints {
         response = client
-            .get(format!("http://127.0.0.1:8080/payouts/{endpoint}"))
+            .get(format!("http://127.0.0.1:8080/{endpoint}"))
             .send()
             .await
             .unwrap();
         response_body = response.body().await;
         println!("{endpoint} =:= {response:?} : {response_body:?}");
-        assert_eq!(response.status(), awc::http::StatusCode::OK);
+        assert_eq!(response.status(), awc::http::StatusCode::UNAUTHORIZED);
     }
 
     for endpoint in post_endpoints {
         response = client
-            .post(format!("http://127.0.0.1:8080/payouts/{endpoint}"))
-            .send()
+            .post(format!("http://127.0.0.1:8080/{endpoint}"))
+            .send_json(&request)
             .await
             .unwrap();
         response_body = response.body().await;
         println!("{endpoint} =:= {response:?} : {response_body:?}");
-        assert_eq!(response.status(), awc::http::StatusCode::OK);
+        assert_eq!(response.status(), awc::http::StatusCode::UNAUTHORIZED);
     }
 }
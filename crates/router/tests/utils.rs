@@ -5,10 +5,10 @@
     clippy::unwrap_used
 )]
 
-use actix_http::{body::MessageBody, Request};
+use actix_http::{body::MessageBody, Request, StatusCode};
 use actix_web::{
     dev::{Service, ServiceResponse},
-    test::{call_and_read_body_json, TestRequest},
+    test::{call_and_read_body_json, call_service, TestRequest},
 };
 use derive_deref::Deref;
 use router::{configs::settings::Settings, routes::AppState};
@@ -111,6 +111,25 @@ impl AppClient<Admin> {
 
         call_and_read_body_json(app, request).await
     }
+
+    pub async fn create_api_key<T: DeserializeOwned, S, B>(
+        &self,
+        app: &S,
+        merchant_id: &str,
+        scopes: Option<Vec<&str>>,
+    ) -> T
+    where
+        S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+        B: MessageBody,
+    {
+        let request = TestRequest::post()
+            .uri(&format!("/api_keys/{merchant_id}"))
+            .append_header(("api-key".to_owned(), self.state.authkey.clone()))
+            .set_json(mk_api_key(scopes))
+            .to_request();
+
+        call_and_read_body_json(app, request).await
+    }
 }
 
 impl AppClient<User> {
@@ -149,6 +168,18 @@ impl AppClient<User> {
             .to_request();
         call_and_read_body_json(app, request).await
     }
+
+    pub async fn get_status<S, B>(&self, app: &S, uri: &str) -> StatusCode
+    where
+        S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+        B: MessageBody,
+    {
+        let request = TestRequest::get()
+            .uri(uri)
+            .append_header(("api-key".to_owned(), self.state.authkey.clone()))
+            .to_request();
+        call_service(app, request).await.status()
+    }
 }
 
 impl<T> AppClient<T> {
@@ -225,6 +256,15 @@ fn mk_merchant_account(merchant_id: Option<String>) -> Value {
     })
 }
 
+fn mk_api_key(scopes: Option<Vec<&str>>) -> Value {
+    json!({
+      "name": "Sandbox integration key",
+      "description": "Key used by our developers to integrate with the sandbox environment",
+      "expiration": "never",
+      "scopes": scopes,
+    })
+}
+
 fn mk_payment(amount: i64, amount_to_capture: i32) -> Value {
     json!({
       "amount": amount,
@@ -377,6 +377,10 @@ pub fn api_error_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 /// - domain
 /// - update_tracker
 ///
+/// The boolean methods of the `Operation` trait which describe how the operation is performed,
+/// such as `requires_lock`, return `false` unless they are listed in the `flags` property of the
+/// attribute, e.g. `#[operation(ops = "all", flow = "authorize", flags = "requires_lock")]`.
+///
 /// ## Example
 /// ```
 /// use router_derive::Operation;
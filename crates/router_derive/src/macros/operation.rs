@@ -298,20 +298,44 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
         Span::call_site(),
     );
 
+    // Each flag overrides the method of the same name on the `Operation` trait to return `true`
+    let flag_fns = prop
+        .get("flags")
+        .map(|flags| {
+            flags
+                .iter()
+                .map(|flag| {
+                    let flag = syn::Ident::new(flag.trim(), op.span());
+                    quote! {
+                        fn #flag(&self) -> bool {
+                            true
+                        }
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
     let trait_derive = flow.iter().map(|derive| {
         let derive: Derives = derive.to_owned().into();
-        let fns = ops.iter().map(|t| {
-            let con: Conversion = t.to_owned().into();
-            con.to_function(derive)
-        });
+        let fns = ops
+            .iter()
+            .map(move |t| {
+                let con: Conversion = t.to_owned().into();
+                con.to_function(derive)
+            })
+            .chain(flag_fns.iter().cloned());
         derive.to_operation(fns, struct_name)
     });
     let ref_trait_derive = flow.iter().map(|derive| {
         let derive: Derives = derive.to_owned().into();
-        let fns = ops.iter().map(|t| {
-            let con: Conversion = t.to_owned().into();
-            con.to_ref_function(derive)
-        });
+        let fns = ops
+            .iter()
+            .map(move |t| {
+                let con: Conversion = t.to_owned().into();
+                con.to_ref_function(derive)
+            })
+            .chain(flag_fns.iter().cloned());
         derive.to_ref_operation(fns, struct_name)
     });
     let trait_derive = quote! {
@@ -90,6 +90,19 @@ pub struct LogTelemetry {
     pub enabled: bool,
     /// Sampling rate for traces
     pub sampling_rate: Option<f64>,
+    /// Exporter through which metrics are published
+    pub metrics_exporter: MetricsExporter,
+}
+
+/// Exporter through which metrics are published.
+#[derive(Default, Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricsExporter {
+    /// Metrics are pushed to an OpenTelemetry collector
+    #[default]
+    Otlp,
+    /// Metrics are scraped by Prometheus from the `/metrics` endpoint
+    Prometheus,
 }
 
 /// Telemetry / tracing.
@@ -16,6 +16,9 @@ pub use types::{Category, Flow, Level, Tag};
 mod setup;
 pub use setup::{setup, TelemetryGuard};
 
+mod propagation;
+pub use propagation::get_trace_context_headers;
+
 pub mod formatter;
 pub use formatter::FormattingLayer;
 
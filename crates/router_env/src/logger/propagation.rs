@@ -0,0 +1,18 @@
+//!
+//! Propagation of the trace context to the services called by the application.
+//!
+
+use std::collections::HashMap;
+
+use opentelemetry::global;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Headers which carry the context of the current span to a service called by the application, in
+/// the W3C `traceparent` format, so that the spans of the service belong to the same trace. No
+/// headers are returned if telemetry is disabled.
+pub fn get_trace_context_headers() -> HashMap<String, String> {
+    let context = tracing::Span::current().context();
+    let mut headers = HashMap::new();
+    global::get_text_map_propagator(|propagator| propagator.inject_context(&context, &mut headers));
+    headers
+}
@@ -8,7 +8,11 @@ use opentelemetry::{
     global, runtime,
     sdk::{
         export::metrics::aggregation::cumulative_temporality_selector,
-        metrics::{controllers::BasicController, selectors::simple},
+        metrics::{
+            controllers::{self, BasicController},
+            processors,
+            selectors::simple,
+        },
         propagation::TraceContextPropagator,
         trace, Resource,
     },
@@ -128,7 +132,7 @@ pub fn setup<Str: AsRef<str>>(
     // Returning the WorkerGuard for logs to be printed until it is dropped
     Ok(TelemetryGuard {
         _log_guards: guards,
-        _metric_controller: setup_metrics(),
+        _metric_controller: setup_metrics(&conf.telemetry),
     })
 }
 
@@ -143,7 +147,14 @@ static HISTOGRAM_BUCKETS: Lazy<[f64; 15]> = Lazy::new(|| {
     buckets
 });
 
-fn setup_metrics() -> Option<BasicController> {
+fn setup_metrics(conf: &config::LogTelemetry) -> Option<BasicController> {
+    match conf.metrics_exporter {
+        config::MetricsExporter::Otlp => setup_otlp_metrics(),
+        config::MetricsExporter::Prometheus => setup_prometheus_metrics(),
+    }
+}
+
+fn setup_otlp_metrics() -> Option<BasicController> {
     opentelemetry_otlp::new_pipeline()
         .metrics(
             simple::histogram(*HISTOGRAM_BUCKETS),
@@ -159,3 +170,22 @@ fn setup_metrics() -> Option<BasicController> {
         .map_err(|err| eprintln!("Failed to Setup Metrics with {err:?}"))
         .ok()
 }
+
+/// Metrics exported to Prometheus are collected when they are scraped, from the registry kept in
+/// [`crate::metrics`].
+fn setup_prometheus_metrics() -> Option<BasicController> {
+    let controller = controllers::basic(processors::factory(
+        simple::histogram(*HISTOGRAM_BUCKETS),
+        cumulative_temporality_selector(),
+    ))
+    .build();
+
+    let exporter = opentelemetry_prometheus::exporter(controller.clone())
+        .try_init()
+        .map_err(|err| eprintln!("Failed to Setup Metrics with {err:?}"))
+        .ok()?;
+    crate::metrics::set_prometheus_registry(exporter.registry().clone());
+    global::set_meter_provider(controller.clone());
+
+    Some(controller)
+}
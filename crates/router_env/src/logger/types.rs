@@ -72,6 +72,10 @@ pub enum Flow {
     MerchantConnectorsDelete,
     /// Merchant Connectors list flow.
     MerchantConnectorsList,
+    /// Merchant Connectors toggle flow.
+    MerchantConnectorsToggle,
+    /// Connector credentials key rotate flow.
+    ConnectorCredentialsKeyRotate,
     /// ConfigKey create flow.
     ConfigKeyCreate,
     /// ConfigKey fetch flow.
@@ -86,6 +90,10 @@ pub enum Flow {
     CustomersUpdate,
     /// Customers delete flow.
     CustomersDelete,
+    /// Customers list flow.
+    CustomersList,
+    /// Customers redact flow.
+    CustomersRedact,
     /// Customers get mandates flow.
     CustomersGetMandates,
     /// Create an Ephemeral Key.
@@ -96,12 +104,16 @@ pub enum Flow {
     MandatesRetrieve,
     /// Mandates revoke flow.
     MandatesRevoke,
+    /// Mandates list flow.
+    MandatesList,
     /// Payment methods create flow.
     PaymentMethodsCreate,
     /// Payment methods list flow.
     PaymentMethodsList,
     /// Customer payment methods list flow.
     CustomerPaymentMethodsList,
+    /// Customer payment methods create flow.
+    CustomerPaymentMethodsCreate,
     /// Payment methods retrieve flow.
     PaymentMethodsRetrieve,
     /// Payment methods update flow.
@@ -120,26 +132,34 @@ pub enum Flow {
     PaymentsCapture,
     /// Payments cancel flow.
     PaymentsCancel,
+    /// Payments approve flow.
+    PaymentsApprove,
+    /// Payments reject flow.
+    PaymentsReject,
     /// Payments Session Token flow
     PaymentsSessionToken,
     /// Payments start flow.
     PaymentsStart,
     /// Payments list flow.
     PaymentsList,
+    /// Payment attempts list flow.
+    PaymentAttemptsList,
+    /// Payment events list flow.
+    PaymentEventsList,
+    /// Connector logs list flow.
+    ConnectorLogsList,
     /// Payouts create flow
     PayoutsCreate,
     /// Payouts retrieve flow.
     PayoutsRetrieve,
-    /// Payouts update flow.
-    PayoutsUpdate,
-    /// Payouts reverse flow.
-    PayoutsReverse,
     /// Payouts cancel flow.
     PayoutsCancel,
-    /// Payouts accounts flow.
-    PayoutsAccounts,
     /// Payments Redirect flow.
     PaymentsRedirect,
+    /// Payments 3DS authenticate flow.
+    PaymentsThreeDsAuthenticate,
+    /// Payments complete authorize flow.
+    PaymentsCompleteAuthorize,
     /// Refunds create flow.
     RefundsCreate,
     /// Refunds retrieve flow.
@@ -160,10 +180,104 @@ pub enum Flow {
     ApiKeyUpdate,
     /// API Key revoke flow
     ApiKeyRevoke,
+    /// API Key rotate flow
+    ApiKeyRotate,
     /// API Key list flow
     ApiKeyList,
     /// Cards Info flow
     CardsInfo,
+    /// Routing config create flow
+    RoutingConfigCreate,
+    /// Routing config retrieve flow
+    RoutingConfigRetrieve,
+    /// Routing config list flow
+    RoutingConfigList,
+    /// Routing config activate flow
+    RoutingConfigActivate,
+    /// Routing config evaluate flow
+    RoutingConfigEvaluate,
+    /// Vault token create flow
+    VaultTokenCreate,
+    /// Vault token retrieve flow
+    VaultTokenRetrieve,
+    /// Vault token delete flow
+    VaultTokenDelete,
+    /// Payment schedule create flow
+    PaymentScheduleCreate,
+    /// Payment schedule retrieve flow
+    PaymentScheduleRetrieve,
+    /// Payment schedule cancel flow
+    PaymentScheduleCancel,
+    /// Payment link create flow
+    PaymentLinkCreate,
+    /// Payment link retrieve flow
+    PaymentLinkRetrieve,
+    /// Payment link checkout flow
+    PaymentLinkCheckout,
+    /// Payment link pay flow
+    PaymentLinkPay,
+    /// User sign up flow
+    UserSignUp,
+    /// User sign in flow
+    UserSignIn,
+    /// User refresh token flow
+    UserRefreshToken,
+    /// User role set flow
+    UserRoleSet,
+    /// Organization create flow
+    OrganizationCreate,
+    /// Organization retrieve flow
+    OrganizationRetrieve,
+    /// Organization merchant account list flow
+    OrganizationMerchantAccountList,
+    /// Organization API Key create flow
+    OrganizationApiKeyCreate,
+    /// Organization API Key list flow
+    OrganizationApiKeyList,
+    /// Organization API Key revoke flow
+    OrganizationApiKeyRevoke,
+    /// Organization payments aggregate flow
+    OrganizationPaymentsAggregate,
+    /// Business profile create flow
+    BusinessProfileCreate,
+    /// Business profile list flow
+    BusinessProfileList,
+    /// Business profile retrieve flow
+    BusinessProfileRetrieve,
+    /// Business profile update flow
+    BusinessProfileUpdate,
+    /// Business profile delete flow
+    BusinessProfileDelete,
+    /// Balance retrieve flow
+    BalanceRetrieve,
+    /// Balance transaction list flow
+    BalanceTransactionList,
+    /// Settlement report ingest flow
+    SettlementReportIngest,
+    /// Settlement report retrieve flow
+    SettlementReportRetrieve,
+    /// Payment recon retrieve flow
+    PaymentReconRetrieve,
+    /// Dead letter task list flow
+    DeadLetterTaskList,
+    /// Dead letter task retrieve flow
+    DeadLetterTaskRetrieve,
+    /// Dead letter task replay flow
+    DeadLetterTaskReplay,
+    /// Dead letter task bulk replay flow
+    DeadLetterTaskBulkReplay,
+    /// Dummy connector payment flow
+    DummyConnectorPayment,
+    /// Dummy connector payment retrieve flow
+    DummyConnectorPaymentRetrieve,
+    /// Dummy connector authentication flow
+    DummyConnectorAuthentication,
+    /// Dummy connector complete authentication flow
+    DummyConnectorCompleteAuthentication,
+    /// Dummy connector refund flow
+    DummyConnectorRefund,
+    /// Dummy connector refund retrieve flow
+    DummyConnectorRefundRetrieve,
 }
 
 ///
@@ -1,5 +1,26 @@
 //! Utilities to easily create opentelemetry contexts, meters and metrics.
 
+/// The registry of metrics scraped by Prometheus, when metrics are exported to Prometheus.
+static PROMETHEUS_REGISTRY: once_cell::sync::OnceCell<prometheus::Registry> =
+    once_cell::sync::OnceCell::new();
+
+pub(crate) fn set_prometheus_registry(registry: prometheus::Registry) {
+    // The registry is only set up once, when the logger is set up
+    let _ = PROMETHEUS_REGISTRY.set(registry);
+}
+
+/// Encodes the current values of all metrics in the Prometheus text format. Returns `None` if
+/// metrics are not exported to Prometheus.
+pub fn gather_prometheus_metrics() -> Option<Result<String, prometheus::Error>> {
+    use prometheus::Encoder;
+
+    PROMETHEUS_REGISTRY.get().map(|registry| {
+        let mut buffer = Vec::new();
+        prometheus::TextEncoder::new().encode(&registry.gather(), &mut buffer)?;
+        String::from_utf8(buffer).map_err(|error| prometheus::Error::Msg(error.to_string()))
+    })
+}
+
 /// Create a metrics [`Context`][Context] with the specified name.
 ///
 /// [Context]: opentelemetry::Context
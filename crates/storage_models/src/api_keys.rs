@@ -3,11 +3,11 @@ use time::PrimitiveDateTime;
 
 use crate::schema::api_keys;
 
-#[derive(Debug, Identifiable, Queryable)]
+#[derive(Clone, Debug, Identifiable, Queryable)]
 #[diesel(table_name = api_keys, primary_key(key_id))]
 pub struct ApiKey {
     pub key_id: String,
-    pub merchant_id: String,
+    pub merchant_id: Option<String>,
     pub name: String,
     pub description: Option<String>,
     pub hashed_api_key: HashedApiKey,
@@ -15,13 +15,15 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Option<Vec<String>>,
+    pub organization_id: Option<String>,
 }
 
 #[derive(Debug, Insertable)]
 #[diesel(table_name = api_keys)]
 pub struct ApiKeyNew {
     pub key_id: String,
-    pub merchant_id: String,
+    pub merchant_id: Option<String>,
     pub name: String,
     pub description: Option<String>,
     pub hashed_api_key: HashedApiKey,
@@ -29,6 +31,8 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Option<Vec<String>>,
+    pub organization_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -38,10 +42,15 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        scopes: Option<Option<Vec<String>>>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
     },
+    RotateUpdate {
+        hashed_api_key: HashedApiKey,
+        prefix: String,
+    },
 }
 
 #[derive(Debug, AsChangeset)]
@@ -51,6 +60,9 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Option<Option<Vec<String>>>,
+    pub hashed_api_key: Option<HashedApiKey>,
+    pub prefix: Option<String>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -61,23 +73,42 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                scopes,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                scopes,
+                hashed_api_key: None,
+                prefix: None,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                scopes: None,
+                hashed_api_key: None,
+                prefix: None,
+            },
+            ApiKeyUpdate::RotateUpdate {
+                hashed_api_key,
+                prefix,
+            } => Self {
+                hashed_api_key: Some(hashed_api_key),
+                prefix: Some(prefix),
+                name: None,
+                description: None,
+                expires_at: None,
+                last_used: None,
+                scopes: None,
             },
         }
     }
 }
 
-#[derive(Debug, AsExpression)]
+#[derive(Clone, Debug, Eq, PartialEq, AsExpression)]
 #[diesel(sql_type = diesel::sql_types::Text)]
 pub struct HashedApiKey(String);
 
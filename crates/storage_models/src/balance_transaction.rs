@@ -0,0 +1,43 @@
+use common_utils::custom_serde;
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::balance_transactions};
+
+/// An event which moves funds of a merchant between two accounts of the ledger of the merchant.
+/// The amount is debited to one account and credited to the other, so the ledger always balances.
+/// Balance transactions are never updated once recorded.
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = balance_transactions)]
+#[serde(deny_unknown_fields)]
+pub struct BalanceTransactionNew {
+    pub transaction_id: String,
+    pub merchant_id: String,
+    pub transaction_type: storage_enums::BalanceTransactionType,
+    pub source_id: String,
+    pub payment_id: Option<String>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub debit_account: storage_enums::LedgerAccount,
+    pub credit_account: storage_enums::LedgerAccount,
+    pub created_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = balance_transactions)]
+pub struct BalanceTransaction {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub transaction_id: String,
+    pub merchant_id: String,
+    pub transaction_type: storage_enums::BalanceTransactionType,
+    pub source_id: String,
+    pub payment_id: Option<String>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub debit_account: storage_enums::LedgerAccount,
+    pub credit_account: storage_enums::LedgerAccount,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
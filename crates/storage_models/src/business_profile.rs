@@ -0,0 +1,86 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::business_profile;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = business_profile)]
+pub struct BusinessProfileNew {
+    pub profile_id: String,
+    pub merchant_id: String,
+    pub profile_name: String,
+    pub return_url: Option<String>,
+    pub webhook_details: Option<serde_json::Value>,
+    pub routing_algorithm: Option<serde_json::Value>,
+    pub statement_descriptor_name: Option<String>,
+    pub statement_descriptor_suffix: Option<String>,
+    pub auto_capture_delay: Option<i32>,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = business_profile)]
+pub struct BusinessProfile {
+    pub id: i32,
+    pub profile_id: String,
+    pub merchant_id: String,
+    pub profile_name: String,
+    pub return_url: Option<String>,
+    pub webhook_details: Option<serde_json::Value>,
+    pub routing_algorithm: Option<serde_json::Value>,
+    pub statement_descriptor_name: Option<String>,
+    pub statement_descriptor_suffix: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub auto_capture_delay: Option<i32>,
+}
+
+#[derive(Debug)]
+pub enum BusinessProfileUpdate {
+    Update {
+        profile_name: Option<String>,
+        return_url: Option<String>,
+        webhook_details: Option<serde_json::Value>,
+        routing_algorithm: Option<serde_json::Value>,
+        statement_descriptor_name: Option<String>,
+        statement_descriptor_suffix: Option<String>,
+        auto_capture_delay: Option<i32>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = business_profile)]
+pub struct BusinessProfileUpdateInternal {
+    profile_name: Option<String>,
+    return_url: Option<String>,
+    webhook_details: Option<serde_json::Value>,
+    routing_algorithm: Option<serde_json::Value>,
+    statement_descriptor_name: Option<String>,
+    statement_descriptor_suffix: Option<String>,
+    auto_capture_delay: Option<i32>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
+    fn from(business_profile_update: BusinessProfileUpdate) -> Self {
+        match business_profile_update {
+            BusinessProfileUpdate::Update {
+                profile_name,
+                return_url,
+                webhook_details,
+                routing_algorithm,
+                statement_descriptor_name,
+                statement_descriptor_suffix,
+                auto_capture_delay,
+            } => Self {
+                profile_name,
+                return_url,
+                webhook_details,
+                routing_algorithm,
+                statement_descriptor_name,
+                statement_descriptor_suffix,
+                auto_capture_delay,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
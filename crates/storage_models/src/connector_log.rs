@@ -0,0 +1,46 @@
+use common_utils::custom_serde;
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::connector_logs;
+
+/// A request made to a connector for a payment in debug mode, along with the response of the
+/// connector. Personal and card data are masked in the bodies of both before they are stored.
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = connector_logs)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorLogNew {
+    pub log_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub connector: String,
+    pub flow: String,
+    pub method: String,
+    pub url: String,
+    pub request_body: Option<String>,
+    pub response_body: Option<String>,
+    pub status_code: Option<i32>,
+    pub created_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = connector_logs)]
+pub struct ConnectorLog {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub log_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub connector: String,
+    pub flow: String,
+    pub method: String,
+    pub url: String,
+    pub request_body: Option<String>,
+    pub response_body: Option<String>,
+    pub status_code: Option<i32>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -2,10 +2,10 @@
 pub mod diesel_exports {
     pub use super::{
         DbAttemptStatus as AttemptStatus, DbAuthenticationType as AuthenticationType,
-        DbCaptureMethod as CaptureMethod, DbConnectorType as ConnectorType,
-        DbCountryCode as CountryCode, DbCurrency as Currency, DbDisputeStage as DisputeStage,
-        DbDisputeStatus as DisputeStatus, DbEventClass as EventClass,
-        DbEventObjectType as EventObjectType, DbEventType as EventType,
+        DbCaptureMethod as CaptureMethod, DbCaptureStatus as CaptureStatus,
+        DbConnectorType as ConnectorType, DbCountryCode as CountryCode, DbCurrency as Currency,
+        DbDisputeStage as DisputeStage, DbDisputeStatus as DisputeStatus,
+        DbEventClass as EventClass, DbEventObjectType as EventObjectType, DbEventType as EventType,
         DbFutureUsage as FutureUsage, DbIntentStatus as IntentStatus,
         DbMandateStatus as MandateStatus, DbMandateType as MandateType,
         DbMerchantStorageScheme as MerchantStorageScheme,
@@ -51,6 +51,18 @@ pub enum AttemptStatus {
     VoidFailed,
     AutoRefunded,
     PartialCharged,
+    PartiallyCaptured,
+    /// The bank debit was submitted and is awaiting settlement, which can take several days
+    DebitInitiated,
+    /// The customer paid less than the amount of the payment, which is to be resolved by the
+    /// merchant with the connector
+    Underpaid,
+    /// The customer paid more than the amount of the payment, the excess is to be returned by the
+    /// merchant
+    Overpaid,
+    /// The authorization is held for review, by the fraud rules of the merchant or by the
+    /// connector, and is to be approved or rejected by the merchant
+    ManualReview,
     #[default]
     Pending,
     Failure,
@@ -104,6 +116,30 @@ pub enum CaptureMethod {
     Scheduled,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "pg_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CaptureStatus {
+    // Capture request has been created but not yet sent to the connector
+    #[default]
+    Started,
+    Charged,
+    Pending,
+    Failed,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -134,6 +170,8 @@ pub enum ConnectorType {
     BankingEntities,
     /// All types of non-banking financial institutions including Insurance, Credit / Lending etc
     NonBankingFinance,
+    /// 3DS and other cardholder authentication providers
+    AuthenticationProcessor,
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -260,6 +298,234 @@ pub enum Currency {
     ZAR,
 }
 
+impl Currency {
+    /// The number of digits after the decimal separator in amounts of the currency, as given by
+    /// ISO 4217. This is the exponent of the power of ten a major unit of the currency is of its
+    /// minor unit.
+    pub fn number_of_digits_after_decimal_point(self) -> u8 {
+        match self {
+            Self::JPY | Self::KRW => 0,
+            Self::AED
+            | Self::ALL
+            | Self::AMD
+            | Self::ANG
+            | Self::ARS
+            | Self::AUD
+            | Self::AWG
+            | Self::AZN
+            | Self::BBD
+            | Self::BDT
+            | Self::BMD
+            | Self::BND
+            | Self::BOB
+            | Self::BRL
+            | Self::BSD
+            | Self::BWP
+            | Self::BZD
+            | Self::CAD
+            | Self::CHF
+            | Self::CNY
+            | Self::COP
+            | Self::CRC
+            | Self::CUP
+            | Self::CZK
+            | Self::DKK
+            | Self::DOP
+            | Self::DZD
+            | Self::EGP
+            | Self::ETB
+            | Self::EUR
+            | Self::FJD
+            | Self::GBP
+            | Self::GHS
+            | Self::GIP
+            | Self::GMD
+            | Self::GTQ
+            | Self::GYD
+            | Self::HKD
+            | Self::HNL
+            | Self::HRK
+            | Self::HTG
+            | Self::HUF
+            | Self::IDR
+            | Self::ILS
+            | Self::INR
+            | Self::JMD
+            | Self::KES
+            | Self::KGS
+            | Self::KHR
+            | Self::KYD
+            | Self::KZT
+            | Self::LAK
+            | Self::LBP
+            | Self::LKR
+            | Self::LRD
+            | Self::LSL
+            | Self::MAD
+            | Self::MDL
+            | Self::MKD
+            | Self::MMK
+            | Self::MNT
+            | Self::MOP
+            | Self::MUR
+            | Self::MVR
+            | Self::MWK
+            | Self::MXN
+            | Self::MYR
+            | Self::NAD
+            | Self::NGN
+            | Self::NIO
+            | Self::NOK
+            | Self::NPR
+            | Self::NZD
+            | Self::PEN
+            | Self::PGK
+            | Self::PHP
+            | Self::PKR
+            | Self::PLN
+            | Self::QAR
+            | Self::RUB
+            | Self::SAR
+            | Self::SCR
+            | Self::SEK
+            | Self::SGD
+            | Self::SLL
+            | Self::SOS
+            | Self::SSP
+            | Self::SVC
+            | Self::SZL
+            | Self::THB
+            | Self::TTD
+            | Self::TWD
+            | Self::TZS
+            | Self::USD
+            | Self::UYU
+            | Self::UZS
+            | Self::YER
+            | Self::ZAR => 2,
+            Self::BHD | Self::JOD | Self::KWD | Self::OMR => 3,
+        }
+    }
+
+    pub fn is_zero_decimal_currency(self) -> bool {
+        self.number_of_digits_after_decimal_point() == 0
+    }
+
+    pub fn is_three_decimal_currency(self) -> bool {
+        self.number_of_digits_after_decimal_point() == 3
+    }
+
+    /// The three digit numeric code of the currency, as given by ISO 4217.
+    pub fn iso_4217_numeric_code(self) -> &'static str {
+        match self {
+            Self::AED => "784",
+            Self::ALL => "008",
+            Self::AMD => "051",
+            Self::ANG => "532",
+            Self::ARS => "032",
+            Self::AUD => "036",
+            Self::AWG => "533",
+            Self::AZN => "944",
+            Self::BBD => "052",
+            Self::BDT => "050",
+            Self::BHD => "048",
+            Self::BMD => "060",
+            Self::BND => "096",
+            Self::BOB => "068",
+            Self::BRL => "986",
+            Self::BSD => "044",
+            Self::BWP => "072",
+            Self::BZD => "084",
+            Self::CAD => "124",
+            Self::CHF => "756",
+            Self::CNY => "156",
+            Self::COP => "170",
+            Self::CRC => "188",
+            Self::CUP => "192",
+            Self::CZK => "203",
+            Self::DKK => "208",
+            Self::DOP => "214",
+            Self::DZD => "012",
+            Self::EGP => "818",
+            Self::ETB => "230",
+            Self::EUR => "978",
+            Self::FJD => "242",
+            Self::GBP => "826",
+            Self::GHS => "936",
+            Self::GIP => "292",
+            Self::GMD => "270",
+            Self::GTQ => "320",
+            Self::GYD => "328",
+            Self::HKD => "344",
+            Self::HNL => "340",
+            Self::HRK => "191",
+            Self::HTG => "332",
+            Self::HUF => "348",
+            Self::IDR => "360",
+            Self::ILS => "376",
+            Self::INR => "356",
+            Self::JMD => "388",
+            Self::JOD => "400",
+            Self::JPY => "392",
+            Self::KES => "404",
+            Self::KGS => "417",
+            Self::KHR => "116",
+            Self::KRW => "410",
+            Self::KWD => "414",
+            Self::KYD => "136",
+            Self::KZT => "398",
+            Self::LAK => "418",
+            Self::LBP => "422",
+            Self::LKR => "144",
+            Self::LRD => "430",
+            Self::LSL => "426",
+            Self::MAD => "504",
+            Self::MDL => "498",
+            Self::MKD => "807",
+            Self::MMK => "104",
+            Self::MNT => "496",
+            Self::MOP => "446",
+            Self::MUR => "480",
+            Self::MVR => "462",
+            Self::MWK => "454",
+            Self::MXN => "484",
+            Self::MYR => "458",
+            Self::NAD => "516",
+            Self::NGN => "566",
+            Self::NIO => "558",
+            Self::NOK => "578",
+            Self::NPR => "524",
+            Self::NZD => "554",
+            Self::OMR => "512",
+            Self::PEN => "604",
+            Self::PGK => "598",
+            Self::PHP => "608",
+            Self::PKR => "586",
+            Self::PLN => "985",
+            Self::QAR => "634",
+            Self::RUB => "643",
+            Self::SAR => "682",
+            Self::SCR => "690",
+            Self::SEK => "752",
+            Self::SGD => "702",
+            Self::SLL => "694",
+            Self::SOS => "706",
+            Self::SSP => "728",
+            Self::SVC => "222",
+            Self::SZL => "748",
+            Self::THB => "764",
+            Self::TTD => "780",
+            Self::TWD => "901",
+            Self::TZS => "834",
+            Self::USD => "840",
+            Self::UYU => "858",
+            Self::UZS => "860",
+            Self::YER => "886",
+            Self::ZAR => "710",
+        }
+    }
+}
+
 #[derive(
     Clone,
     Copy,
@@ -278,6 +544,8 @@ pub enum EventClass {
     Payments,
     Refunds,
     Disputes,
+    PaymentSchedules,
+    Payouts,
 }
 
 #[derive(
@@ -298,6 +566,8 @@ pub enum EventObjectType {
     PaymentDetails,
     RefundDetails,
     DisputeDetails,
+    PaymentScheduleDetails,
+    PayoutDetails,
 }
 
 #[derive(
@@ -326,6 +596,12 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    PaymentScheduleCycleSucceeded,
+    PaymentScheduleCycleFailed,
+    PayoutSucceeded,
+    PayoutFailed,
+    PayoutCancelled,
+    PayoutReversed,
 }
 
 #[derive(
@@ -348,12 +624,16 @@ pub enum IntentStatus {
     Succeeded,
     Failed,
     Cancelled,
+    Expired,
     Processing,
     RequiresCustomerAction,
     RequiresPaymentMethod,
     #[default]
     RequiresConfirmation,
     RequiresCapture,
+    /// The payment was held for review, by the fraud rules of the merchant or by the connector,
+    /// and is to be approved or rejected by the merchant
+    RequiresMerchantAction,
 }
 
 #[derive(
@@ -450,6 +730,10 @@ pub enum PaymentMethod {
     PayLater,
     Wallet,
     BankRedirect,
+    BankDebit,
+    Upi,
+    Crypto,
+    GiftCard,
 }
 
 #[derive(
@@ -497,6 +781,8 @@ pub enum ProcessTrackerStatus {
     ProcessStarted,
     // Finished by consumer
     Finish,
+    // Exhausted its retries or failed while being handled, kept for inspection
+    DeadLetter,
 }
 
 #[derive(
@@ -590,6 +876,367 @@ pub enum MandateStatus {
     Revoked,
 }
 
+impl MandateStatus {
+    /// Whether a mandate in this status is allowed to move to `next`.
+    /// `Revoked` is terminal, every other status can be revoked.
+    pub fn can_transition_to(self, next: Self) -> bool {
+        match (self, next) {
+            (Self::Pending, Self::Active | Self::Inactive | Self::Revoked)
+            | (Self::Active, Self::Inactive | Self::Revoked)
+            | (Self::Inactive, Self::Active | Self::Revoked) => true,
+            (Self::Revoked, _)
+            | (Self::Active, Self::Active | Self::Pending)
+            | (Self::Inactive, Self::Inactive | Self::Pending)
+            | (Self::Pending, Self::Pending) => false,
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentScheduleInterval {
+    Day,
+    Week,
+    #[default]
+    Month,
+    Year,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentScheduleStatus {
+    #[default]
+    Active,
+    Cancelled,
+    Completed,
+    Failed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutStatus {
+    #[default]
+    Pending,
+    Initiated,
+    Success,
+    Failed,
+    Cancelled,
+    Reversed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutType {
+    Card,
+    #[default]
+    Bank,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DashboardRole {
+    Admin,
+    Developer,
+    Operations,
+    #[default]
+    ReadOnly,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentLinkStatus {
+    #[default]
+    Active,
+    Used,
+    Expired,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FundingSourceStatus {
+    #[default]
+    Started,
+    Authorized,
+    Charged,
+    Failed,
+    RolledBack,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SplitStatus {
+    #[default]
+    Pending,
+    Settled,
+    Cancelled,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BalanceTransactionType {
+    Authorization,
+    AuthorizationRelease,
+    Capture,
+    Refund,
+    Dispute,
+    Payout,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum LedgerAccount {
+    External,
+    Pending,
+    Available,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentEventSource {
+    Api,
+    Webhook,
+    Scheduler,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconStatus {
+    Matched,
+    AmountMismatch,
+    CurrencyMismatch,
+    TransactionMissing,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AvsResult {
+    FullMatch,
+    AddressMatch,
+    PostalCodeMatch,
+    NoMatch,
+    Unavailable,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CvcResult {
+    Match,
+    NoMatch,
+    NotProcessed,
+    Unavailable,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FraudCheckVerdict {
+    Accept,
+    Review,
+    Decline,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -618,6 +1265,14 @@ pub enum PaymentMethodType {
     GooglePay,
     ApplePay,
     Paypal,
+    Ach,
+    Sepa,
+    UpiCollect,
+    UpiIntent,
+    CryptoCurrency,
+    Givex,
+    Netbanking,
+    Blik,
 }
 
 #[derive(
@@ -692,6 +1347,15 @@ pub enum BankNames {
     VolksbankGruppe,
     VolkskreditbankAg,
     VrBankBraunau,
+    AxisBank,
+    BankOfBaroda,
+    HdfcBank,
+    IciciBank,
+    IndusindBank,
+    KotakMahindraBank,
+    PunjabNationalBank,
+    StateBankOfIndia,
+    YesBank,
 }
 
 #[derive(
@@ -10,6 +10,8 @@ pub enum DatabaseError {
     NoFieldsToUpdate,
     #[error("An error occurred when generating typed SQL query")]
     QueryGenerationFailed,
+    #[error("The row was updated by another request since it was read")]
+    VersionConflict,
     // InsertFailed,
     #[error("An unknown error occurred")]
     Others,
@@ -33,3 +33,12 @@ pub struct Event {
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
 }
+
+/// The tracking data of the scheduler task retrying the delivery of an outgoing webhook.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct OutgoingWebhookTrackingData {
+    pub merchant_id: String,
+    pub event_id: String,
+    pub profile_id: Option<String>,
+    pub request_body: serde_json::Value,
+}
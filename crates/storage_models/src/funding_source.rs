@@ -0,0 +1,113 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::funding_sources};
+
+/// One of the sources (a gift card, a card, ...) funding a part of the amount of a payment
+/// attempt, when the attempt is paid with more than one payment method (split tender).
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = funding_sources)]
+#[serde(deny_unknown_fields)]
+pub struct FundingSourceNew {
+    pub funding_source_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub payment_method: storage_enums::PaymentMethod,
+    pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub status: storage_enums::FundingSourceStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub connector: String,
+    pub funding_sequence: i16,
+    pub connector_transaction_id: Option<String>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = funding_sources)]
+pub struct FundingSource {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub funding_source_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub payment_method: storage_enums::PaymentMethod,
+    pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub status: storage_enums::FundingSourceStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub connector: String,
+    pub funding_sequence: i16,
+    pub connector_transaction_id: Option<String>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum FundingSourceUpdate {
+    ResponseUpdate {
+        status: storage_enums::FundingSourceStatus,
+        connector_transaction_id: Option<String>,
+    },
+    StatusUpdate {
+        status: storage_enums::FundingSourceStatus,
+    },
+    ErrorUpdate {
+        status: storage_enums::FundingSourceStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = funding_sources)]
+pub struct FundingSourceUpdateInternal {
+    status: Option<storage_enums::FundingSourceStatus>,
+    connector_transaction_id: Option<String>,
+    error_message: Option<String>,
+    error_code: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<FundingSourceUpdate> for FundingSourceUpdateInternal {
+    fn from(funding_source_update: FundingSourceUpdate) -> Self {
+        match funding_source_update {
+            FundingSourceUpdate::ResponseUpdate {
+                status,
+                connector_transaction_id,
+            } => Self {
+                status: Some(status),
+                connector_transaction_id,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            FundingSourceUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            FundingSourceUpdate::ErrorUpdate {
+                status,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(status),
+                error_code,
+                error_message,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
@@ -0,0 +1,16 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdempotencyStatus {
+    InProgress,
+    Completed,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct IdempotencyEntry {
+    pub merchant_id: String,
+    pub idempotency_key: String,
+    pub request_hash: String,
+    pub status: IdempotencyStatus,
+    pub response: Option<serde_json::Value>,
+    pub created_at: i64,
+}
@@ -1,7 +1,11 @@
 pub mod address;
 pub mod api_keys;
+pub mod balance_transaction;
+pub mod business_profile;
+pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_log;
 pub mod connector_response;
 pub mod customers;
 pub mod dispute;
@@ -9,20 +13,38 @@ pub mod enums;
 pub mod ephemeral_key;
 pub mod errors;
 pub mod events;
+pub mod funding_source;
+pub mod idempotency;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod organization;
 pub mod payment_attempt;
+pub mod payment_event;
 pub mod payment_intent;
+pub mod payment_link;
 pub mod payment_method;
+pub mod payment_schedule;
+pub mod payment_split;
+pub mod payout;
+pub mod payout_method;
 pub mod process_tracker;
 pub mod query;
+pub mod redaction_audit;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod routing_config;
 pub mod schema;
+pub mod settlement_report;
+pub mod status_mismatch;
+pub mod success_rate;
+pub mod user;
+pub mod user_role;
+pub mod vault_data_key;
+pub mod vaulted_card;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
@@ -63,6 +63,10 @@ pub enum MandateUpdate {
     ConnectorReferenceUpdate {
         connector_mandate_id: Option<String>,
     },
+    RedactionUpdate {
+        customer_ip_address: Option<Secret<String, pii::IpAddress>>,
+        customer_user_agent: Option<String>,
+    },
 }
 
 #[derive(Clone, Eq, PartialEq, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -77,6 +81,8 @@ pub struct MandateUpdateInternal {
     mandate_status: Option<storage_enums::MandateStatus>,
     amount_captured: Option<i64>,
     connector_mandate_id: Option<String>,
+    customer_ip_address: Option<Secret<String, pii::IpAddress>>,
+    customer_user_agent: Option<String>,
 }
 
 impl From<MandateUpdate> for MandateUpdateInternal {
@@ -84,13 +90,11 @@ impl From<MandateUpdate> for MandateUpdateInternal {
         match mandate_update {
             MandateUpdate::StatusUpdate { mandate_status } => Self {
                 mandate_status: Some(mandate_status),
-                connector_mandate_id: None,
-                amount_captured: None,
+                ..Default::default()
             },
             MandateUpdate::CaptureAmountUpdate { amount_captured } => Self {
-                mandate_status: None,
                 amount_captured,
-                connector_mandate_id: None,
+                ..Default::default()
             },
             MandateUpdate::ConnectorReferenceUpdate {
                 connector_mandate_id,
@@ -98,6 +102,14 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 connector_mandate_id,
                 ..Default::default()
             },
+            MandateUpdate::RedactionUpdate {
+                customer_ip_address,
+                customer_user_agent,
+            } => Self {
+                customer_ip_address,
+                customer_user_agent,
+                ..Default::default()
+            },
         }
     }
 }
@@ -34,6 +34,8 @@ pub struct MerchantAccount {
     pub metadata: Option<pii::SecretSerdeValue>,
     pub routing_algorithm: Option<serde_json::Value>,
     pub api_key: Option<StrongSecret<String>>,
+    pub return_raw_connector_response: bool,
+    pub organization_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay)]
@@ -54,6 +56,8 @@ pub struct MerchantAccountNew {
     pub metadata: Option<pii::SecretSerdeValue>,
     pub routing_algorithm: Option<serde_json::Value>,
     pub api_key: Option<StrongSecret<String>>,
+    pub return_raw_connector_response: Option<bool>,
+    pub organization_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -72,6 +76,7 @@ pub enum MerchantAccountUpdate {
         locker_id: Option<String>,
         metadata: Option<pii::SecretSerdeValue>,
         routing_algorithm: Option<serde_json::Value>,
+        return_raw_connector_response: Option<bool>,
     },
     StorageSchemeUpdate {
         storage_scheme: storage_enums::MerchantStorageScheme,
@@ -95,6 +100,7 @@ pub struct MerchantAccountUpdateInternal {
     locker_id: Option<String>,
     metadata: Option<pii::SecretSerdeValue>,
     routing_algorithm: Option<serde_json::Value>,
+    return_raw_connector_response: Option<bool>,
 }
 
 impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
@@ -114,6 +120,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 publishable_key,
                 locker_id,
                 metadata,
+                return_raw_connector_response,
             } => Self {
                 merchant_name,
                 merchant_details,
@@ -128,6 +135,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 publishable_key,
                 locker_id,
                 metadata,
+                return_raw_connector_response,
                 ..Default::default()
             },
             MerchantAccountUpdate::StorageSchemeUpdate { storage_scheme } => Self {
@@ -29,6 +29,10 @@ pub struct MerchantConnectorAccount {
     pub connector_type: storage_enums::ConnectorType,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub frm_configs: Option<serde_json::Value>, //Option<FrmConfigs>
+    /// The data key the connector account details are encrypted with, unset for the details
+    /// stored before encryption was introduced
+    pub key_id: Option<String>,
+    pub encrypted_connector_account_details: Option<Vec<u8>>,
 }
 
 #[derive(Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay)]
@@ -44,6 +48,8 @@ pub struct MerchantConnectorAccountNew {
     pub payment_methods_enabled: Option<Vec<serde_json::Value>>,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub frm_configs: Option<serde_json::Value>,
+    pub key_id: Option<String>,
+    pub encrypted_connector_account_details: Option<Vec<u8>>,
 }
 
 #[derive(Debug)]
@@ -59,6 +65,12 @@ pub enum MerchantConnectorAccountUpdate {
         payment_methods_enabled: Option<Vec<serde_json::Value>>,
         metadata: Option<pii::SecretSerdeValue>,
         frm_configs: Option<serde_json::Value>,
+        key_id: Option<String>,
+        encrypted_connector_account_details: Option<Vec<u8>>,
+    },
+    EncryptionUpdate {
+        key_id: String,
+        encrypted_connector_account_details: Vec<u8>,
     },
 }
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -74,6 +86,8 @@ pub struct MerchantConnectorAccountUpdateInternal {
     payment_methods_enabled: Option<Vec<serde_json::Value>>,
     metadata: Option<pii::SecretSerdeValue>,
     frm_configs: Option<serde_json::Value>,
+    key_id: Option<String>,
+    encrypted_connector_account_details: Option<Vec<u8>>,
 }
 
 impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInternal {
@@ -90,6 +104,8 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 payment_methods_enabled,
                 metadata,
                 frm_configs,
+                key_id,
+                encrypted_connector_account_details,
             } => Self {
                 merchant_id,
                 connector_type,
@@ -101,6 +117,18 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 payment_methods_enabled,
                 metadata,
                 frm_configs,
+                key_id,
+                encrypted_connector_account_details,
+            },
+            // The plaintext details are cleared once they are stored encrypted
+            MerchantConnectorAccountUpdate::EncryptionUpdate {
+                key_id,
+                encrypted_connector_account_details,
+            } => Self {
+                connector_account_details: Some(Secret::new(serde_json::Value::Null)),
+                key_id: Some(key_id),
+                encrypted_connector_account_details: Some(encrypted_connector_account_details),
+                ..Default::default()
             },
         }
     }
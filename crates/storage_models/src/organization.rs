@@ -0,0 +1,21 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::organization;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = organization)]
+pub struct OrganizationNew {
+    pub organization_id: String,
+    pub organization_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = organization)]
+pub struct Organization {
+    pub id: i32,
+    pub organization_id: String,
+    pub organization_name: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
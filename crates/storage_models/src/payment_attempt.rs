@@ -1,3 +1,4 @@
+use common_utils::{pii, types::MinorUnit};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -12,7 +13,7 @@ pub struct PaymentAttempt {
     pub merchant_id: String,
     pub attempt_id: String,
     pub status: storage_enums::AttemptStatus,
-    pub amount: i64,
+    pub amount: MinorUnit,
     pub currency: Option<storage_enums::Currency>,
     pub save_to_locker: Option<bool>,
     pub connector: Option<serde_json::Value>,
@@ -44,6 +45,21 @@ pub struct PaymentAttempt {
     pub payment_experience: Option<storage_enums::PaymentExperience>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
     pub payment_method_data: Option<serde_json::Value>,
+    pub amount_refunded: Option<i64>,
+    pub three_ds_data: Option<serde_json::Value>,
+    pub unified_code: Option<String>,
+    pub unified_message: Option<String>,
+    pub connector_raw_response: Option<serde_json::Value>,
+    pub avs_result: Option<storage_enums::AvsResult>,
+    pub cvc_result: Option<storage_enums::CvcResult>,
+    pub cancellation_metadata: Option<pii::SecretSerdeValue>,
+    pub fraud_check_verdict: Option<storage_enums::FraudCheckVerdict>,
+    pub fraud_check_score: Option<i32>,
+    pub settlement_amount: Option<i64>,
+    pub settlement_currency: Option<storage_enums::Currency>,
+    pub fx_rate: Option<String>,
+    #[serde(default)]
+    pub version: i32,
 }
 
 #[derive(
@@ -55,7 +71,7 @@ pub struct PaymentAttemptNew {
     pub merchant_id: String,
     pub attempt_id: String,
     pub status: storage_enums::AttemptStatus,
-    pub amount: i64,
+    pub amount: MinorUnit,
     pub currency: Option<storage_enums::Currency>,
     // pub auto_capture: Option<bool>,
     pub save_to_locker: Option<bool>,
@@ -79,6 +95,7 @@ pub struct PaymentAttemptNew {
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub last_synced: Option<PrimitiveDateTime>,
     pub cancellation_reason: Option<String>,
+    pub cancellation_metadata: Option<pii::SecretSerdeValue>,
     pub amount_to_capture: Option<i64>,
     pub mandate_id: Option<String>,
     pub browser_info: Option<serde_json::Value>,
@@ -88,12 +105,24 @@ pub struct PaymentAttemptNew {
     pub payment_experience: Option<storage_enums::PaymentExperience>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
     pub payment_method_data: Option<serde_json::Value>,
+    pub amount_refunded: Option<i64>,
+    pub three_ds_data: Option<serde_json::Value>,
+    pub unified_code: Option<String>,
+    pub unified_message: Option<String>,
+    pub connector_raw_response: Option<serde_json::Value>,
+    pub avs_result: Option<storage_enums::AvsResult>,
+    pub cvc_result: Option<storage_enums::CvcResult>,
+    pub fraud_check_verdict: Option<storage_enums::FraudCheckVerdict>,
+    pub fraud_check_score: Option<i32>,
+    pub settlement_amount: Option<i64>,
+    pub settlement_currency: Option<storage_enums::Currency>,
+    pub fx_rate: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PaymentAttemptUpdate {
     Update {
-        amount: i64,
+        amount: MinorUnit,
         currency: storage_enums::Currency,
         status: storage_enums::AttemptStatus,
         authentication_type: Option<storage_enums::AuthenticationType>,
@@ -111,7 +140,7 @@ pub enum PaymentAttemptUpdate {
         authentication_type: storage_enums::AuthenticationType,
     },
     ConfirmUpdate {
-        amount: i64,
+        amount: MinorUnit,
         currency: storage_enums::Currency,
         status: storage_enums::AttemptStatus,
         authentication_type: Option<storage_enums::AuthenticationType>,
@@ -126,6 +155,7 @@ pub enum PaymentAttemptUpdate {
     VoidUpdate {
         status: storage_enums::AttemptStatus,
         cancellation_reason: Option<String>,
+        cancellation_metadata: Option<pii::SecretSerdeValue>,
     },
     ResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -135,6 +165,9 @@ pub enum PaymentAttemptUpdate {
         payment_method_id: Option<Option<String>>,
         mandate_id: Option<String>,
         connector_metadata: Option<serde_json::Value>,
+        connector_raw_response: Option<serde_json::Value>,
+        avs_result: Option<storage_enums::AvsResult>,
+        cvc_result: Option<storage_enums::CvcResult>,
     },
     StatusUpdate {
         status: storage_enums::AttemptStatus,
@@ -144,13 +177,43 @@ pub enum PaymentAttemptUpdate {
         status: storage_enums::AttemptStatus,
         error_code: Option<String>,
         error_message: Option<String>,
+        unified_code: Option<String>,
+        unified_message: Option<String>,
+        connector_raw_response: Option<serde_json::Value>,
+    },
+    PartialCaptureUpdate {
+        amount_to_capture: i64,
+    },
+    RefundUpdate {
+        amount_refunded: i64,
+    },
+    ThreeDsAuthenticationUpdate {
+        status: storage_enums::AttemptStatus,
+        three_ds_data: serde_json::Value,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+    FraudCheckUpdate {
+        fraud_check_verdict: storage_enums::FraudCheckVerdict,
+        fraud_check_score: Option<i32>,
+    },
+    SurchargeUpdate {
+        surcharge_amount: i64,
+    },
+    CurrencyConversionUpdate {
+        settlement_amount: i64,
+        settlement_currency: storage_enums::Currency,
+        fx_rate: String,
+    },
+    RedactionUpdate {
+        browser_info: Option<serde_json::Value>,
     },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
 #[diesel(table_name = payment_attempt)]
 pub struct PaymentAttemptUpdateInternal {
-    amount: Option<i64>,
+    amount: Option<MinorUnit>,
     currency: Option<storage_enums::Currency>,
     status: Option<storage_enums::AttemptStatus>,
     connector_transaction_id: Option<String>,
@@ -160,6 +223,7 @@ pub struct PaymentAttemptUpdateInternal {
     error_message: Option<String>,
     payment_method_id: Option<Option<String>>,
     cancellation_reason: Option<String>,
+    cancellation_metadata: Option<pii::SecretSerdeValue>,
     modified_at: Option<PrimitiveDateTime>,
     mandate_id: Option<String>,
     browser_info: Option<serde_json::Value>,
@@ -169,6 +233,21 @@ pub struct PaymentAttemptUpdateInternal {
     payment_method_data: Option<serde_json::Value>,
     payment_method_type: Option<storage_enums::PaymentMethodType>,
     payment_experience: Option<storage_enums::PaymentExperience>,
+    amount_to_capture: Option<i64>,
+    amount_refunded: Option<i64>,
+    three_ds_data: Option<serde_json::Value>,
+    unified_code: Option<String>,
+    unified_message: Option<String>,
+    connector_raw_response: Option<serde_json::Value>,
+    avs_result: Option<storage_enums::AvsResult>,
+    cvc_result: Option<storage_enums::CvcResult>,
+    fraud_check_verdict: Option<storage_enums::FraudCheckVerdict>,
+    fraud_check_score: Option<i32>,
+    surcharge_amount: Option<i64>,
+    settlement_amount: Option<i64>,
+    settlement_currency: Option<storage_enums::Currency>,
+    fx_rate: Option<String>,
+    pub(crate) version: Option<i32>,
 }
 
 impl PaymentAttemptUpdate {
@@ -185,12 +264,34 @@ impl PaymentAttemptUpdate {
             authentication_type: pa_update.authentication_type.or(source.authentication_type),
             payment_method: pa_update.payment_method.or(source.payment_method),
             error_message: pa_update.error_message.or(source.error_message),
+            error_code: pa_update.error_code.or(source.error_code),
             payment_method_id: pa_update
                 .payment_method_id
                 .unwrap_or(source.payment_method_id),
             browser_info: pa_update.browser_info.or(source.browser_info),
             modified_at: common_utils::date_time::now(),
             payment_token: pa_update.payment_token.or(source.payment_token),
+            cancellation_reason: pa_update.cancellation_reason.or(source.cancellation_reason),
+            cancellation_metadata: pa_update
+                .cancellation_metadata
+                .or(source.cancellation_metadata),
+            amount_to_capture: pa_update.amount_to_capture.or(source.amount_to_capture),
+            amount_refunded: pa_update.amount_refunded.or(source.amount_refunded),
+            three_ds_data: pa_update.three_ds_data.or(source.three_ds_data),
+            unified_code: pa_update.unified_code.or(source.unified_code),
+            unified_message: pa_update.unified_message.or(source.unified_message),
+            connector_raw_response: pa_update
+                .connector_raw_response
+                .or(source.connector_raw_response),
+            avs_result: pa_update.avs_result.or(source.avs_result),
+            cvc_result: pa_update.cvc_result.or(source.cvc_result),
+            fraud_check_verdict: pa_update.fraud_check_verdict.or(source.fraud_check_verdict),
+            fraud_check_score: pa_update.fraud_check_score.or(source.fraud_check_score),
+            surcharge_amount: pa_update.surcharge_amount.or(source.surcharge_amount),
+            settlement_amount: pa_update.settlement_amount.or(source.settlement_amount),
+            settlement_currency: pa_update.settlement_currency.or(source.settlement_currency),
+            fx_rate: pa_update.fx_rate.or(source.fx_rate),
+            version: source.version + 1,
             ..source
         }
     }
@@ -261,9 +362,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
             PaymentAttemptUpdate::VoidUpdate {
                 status,
                 cancellation_reason,
+                cancellation_metadata,
             } => Self {
                 status: Some(status),
                 cancellation_reason,
+                cancellation_metadata,
                 ..Default::default()
             },
             PaymentAttemptUpdate::ResponseUpdate {
@@ -274,6 +377,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_id,
                 mandate_id,
                 connector_metadata,
+                connector_raw_response,
+                avs_result,
+                cvc_result,
             } => Self {
                 status: Some(status),
                 connector,
@@ -283,6 +389,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 modified_at: Some(common_utils::date_time::now()),
                 mandate_id,
                 connector_metadata,
+                connector_raw_response,
+                avs_result,
+                cvc_result,
                 ..Default::default()
             },
             PaymentAttemptUpdate::ErrorUpdate {
@@ -290,11 +399,17 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 status,
                 error_code,
                 error_message,
+                unified_code,
+                unified_message,
+                connector_raw_response,
             } => Self {
                 connector,
                 status: Some(status),
                 error_message,
                 error_code,
+                unified_code,
+                unified_message,
+                connector_raw_response,
                 modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
@@ -310,6 +425,59 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 connector,
                 ..Default::default()
             },
+            PaymentAttemptUpdate::PartialCaptureUpdate { amount_to_capture } => Self {
+                amount_to_capture: Some(amount_to_capture),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::RefundUpdate { amount_refunded } => Self {
+                amount_refunded: Some(amount_refunded),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::ThreeDsAuthenticationUpdate {
+                status,
+                three_ds_data,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(status),
+                three_ds_data: Some(three_ds_data),
+                error_code,
+                error_message,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::FraudCheckUpdate {
+                fraud_check_verdict,
+                fraud_check_score,
+            } => Self {
+                fraud_check_verdict: Some(fraud_check_verdict),
+                fraud_check_score,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::SurchargeUpdate { surcharge_amount } => Self {
+                surcharge_amount: Some(surcharge_amount),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::CurrencyConversionUpdate {
+                settlement_amount,
+                settlement_currency,
+                fx_rate,
+            } => Self {
+                settlement_amount: Some(settlement_amount),
+                settlement_currency: Some(settlement_currency),
+                fx_rate: Some(fx_rate),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::RedactionUpdate { browser_info } => Self {
+                browser_info,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
@@ -0,0 +1,49 @@
+use common_utils::custom_serde;
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_events};
+
+/// A change in the status of a payment, along with what caused it. Payment events are appended
+/// for every change in the status of the payment intent or its active attempt, and are never
+/// updated once recorded.
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_events)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentEventNew {
+    pub event_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub operation: String,
+    pub previous_intent_status: storage_enums::IntentStatus,
+    pub intent_status: storage_enums::IntentStatus,
+    pub previous_attempt_status: storage_enums::AttemptStatus,
+    pub attempt_status: storage_enums::AttemptStatus,
+    pub source: storage_enums::PaymentEventSource,
+    pub actor: Option<String>,
+    pub connector_response: Option<serde_json::Value>,
+    pub created_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = payment_events)]
+pub struct PaymentEvent {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub event_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub operation: String,
+    pub previous_intent_status: storage_enums::IntentStatus,
+    pub intent_status: storage_enums::IntentStatus,
+    pub previous_attempt_status: storage_enums::AttemptStatus,
+    pub attempt_status: storage_enums::AttemptStatus,
+    pub source: storage_enums::PaymentEventSource,
+    pub actor: Option<String>,
+    pub connector_response: Option<serde_json::Value>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -1,4 +1,4 @@
-use common_utils::pii;
+use common_utils::{pii, types::MinorUnit};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -12,7 +12,7 @@ pub struct PaymentIntent {
     pub payment_id: String,
     pub merchant_id: String,
     pub status: storage_enums::IntentStatus,
-    pub amount: i64,
+    pub amount: MinorUnit,
     pub currency: Option<storage_enums::Currency>,
     pub amount_captured: Option<i64>,
     pub customer_id: Option<String>,
@@ -34,6 +34,17 @@ pub struct PaymentIntent {
     pub off_session: Option<bool>,
     pub client_secret: Option<String>,
     pub active_attempt_id: String,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub session_expiry: Option<PrimitiveDateTime>,
+    pub order_details: Option<Vec<serde_json::Value>>,
+    pub amount_breakdown: Option<serde_json::Value>,
+    pub l2_l3_data: Option<serde_json::Value>,
+    pub profile_id: Option<String>,
+    pub auto_capture_delay: Option<i32>,
+    #[serde(default)]
+    pub version: i32,
+    #[serde(default)]
+    pub connector_debug_mode: bool,
 }
 
 #[derive(
@@ -52,7 +63,7 @@ pub struct PaymentIntentNew {
     pub payment_id: String,
     pub merchant_id: String,
     pub status: storage_enums::IntentStatus,
-    pub amount: i64,
+    pub amount: MinorUnit,
     pub currency: Option<storage_enums::Currency>,
     pub amount_captured: Option<i64>,
     pub customer_id: Option<String>,
@@ -74,6 +85,15 @@ pub struct PaymentIntentNew {
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
     pub active_attempt_id: String,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub session_expiry: Option<PrimitiveDateTime>,
+    pub order_details: Option<Vec<serde_json::Value>>,
+    pub amount_breakdown: Option<serde_json::Value>,
+    pub l2_l3_data: Option<serde_json::Value>,
+    pub profile_id: Option<String>,
+    pub auto_capture_delay: Option<i32>,
+    #[serde(default)]
+    pub connector_debug_mode: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -102,7 +122,7 @@ pub enum PaymentIntentUpdate {
         status: storage_enums::IntentStatus,
     },
     Update {
-        amount: i64,
+        amount: MinorUnit,
         currency: storage_enums::Currency,
         setup_future_usage: Option<storage_enums::FutureUsage>,
         status: storage_enums::IntentStatus,
@@ -110,21 +130,28 @@ pub enum PaymentIntentUpdate {
         shipping_address_id: Option<String>,
         billing_address_id: Option<String>,
         return_url: Option<String>,
+        order_details: Option<Vec<serde_json::Value>>,
+        amount_breakdown: Option<serde_json::Value>,
+        l2_l3_data: Option<serde_json::Value>,
     },
     PaymentAttemptUpdate {
         active_attempt_id: String,
     },
+    RedactionUpdate {
+        description: Option<String>,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
 #[diesel(table_name = payment_intent)]
 
 pub struct PaymentIntentUpdateInternal {
-    pub amount: Option<i64>,
+    pub amount: Option<MinorUnit>,
     pub currency: Option<storage_enums::Currency>,
     pub status: Option<storage_enums::IntentStatus>,
     pub amount_captured: Option<i64>,
     pub customer_id: Option<String>,
+    pub description: Option<String>,
     pub return_url: Option<String>,
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
@@ -134,6 +161,10 @@ pub struct PaymentIntentUpdateInternal {
     pub shipping_address_id: Option<String>,
     pub modified_at: Option<PrimitiveDateTime>,
     pub active_attempt_id: Option<String>,
+    pub order_details: Option<Vec<serde_json::Value>>,
+    pub amount_breakdown: Option<serde_json::Value>,
+    pub l2_l3_data: Option<serde_json::Value>,
+    pub version: Option<i32>,
 }
 
 impl PaymentIntentUpdate {
@@ -145,6 +176,7 @@ impl PaymentIntentUpdate {
             status: internal_update.status.unwrap_or(source.status),
             amount_captured: internal_update.amount_captured.or(source.amount_captured),
             customer_id: internal_update.customer_id.or(source.customer_id),
+            description: internal_update.description.or(source.description),
             return_url: internal_update.return_url.or(source.return_url),
             setup_future_usage: internal_update
                 .setup_future_usage
@@ -160,7 +192,11 @@ impl PaymentIntentUpdate {
             shipping_address_id: internal_update
                 .shipping_address_id
                 .or(source.shipping_address_id),
+            order_details: internal_update.order_details.or(source.order_details),
+            amount_breakdown: internal_update.amount_breakdown.or(source.amount_breakdown),
+            l2_l3_data: internal_update.l2_l3_data.or(source.l2_l3_data),
             modified_at: common_utils::date_time::now(),
+            version: source.version + 1,
             ..source
         }
     }
@@ -178,6 +214,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_address_id,
                 billing_address_id,
                 return_url,
+                order_details,
+                amount_breakdown,
+                l2_l3_data,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -189,6 +228,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 billing_address_id,
                 modified_at: Some(common_utils::date_time::now()),
                 return_url,
+                order_details,
+                amount_breakdown,
+                l2_l3_data,
                 ..Default::default()
             },
             PaymentIntentUpdate::MetadataUpdate { metadata } => Self {
@@ -252,6 +294,11 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 active_attempt_id: Some(active_attempt_id),
                 ..Default::default()
             },
+            PaymentIntentUpdate::RedactionUpdate { description } => Self {
+                description,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
@@ -262,11 +309,13 @@ fn make_client_secret_null_based_on_status(
     match status {
         storage_enums::IntentStatus::Succeeded
         | storage_enums::IntentStatus::Failed
-        | storage_enums::IntentStatus::Cancelled => Some(None),
+        | storage_enums::IntentStatus::Cancelled
+        | storage_enums::IntentStatus::Expired => Some(None),
         storage_enums::IntentStatus::Processing
         | storage_enums::IntentStatus::RequiresCustomerAction
         | storage_enums::IntentStatus::RequiresPaymentMethod
         | storage_enums::IntentStatus::RequiresConfirmation
-        | storage_enums::IntentStatus::RequiresCapture => None,
+        | storage_enums::IntentStatus::RequiresCapture
+        | storage_enums::IntentStatus::RequiresMerchantAction => None,
     }
 }
@@ -0,0 +1,108 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_link};
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_link)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkNew {
+    pub link_id: String,
+    pub merchant_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    pub return_url: Option<String>,
+    pub reusable: bool,
+    pub status: storage_enums::PaymentLinkStatus,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = payment_link)]
+pub struct PaymentLink {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub link_id: String,
+    pub merchant_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    pub return_url: Option<String>,
+    pub reusable: bool,
+    pub status: storage_enums::PaymentLinkStatus,
+    pub usage_count: i32,
+    pub last_payment_id: Option<String>,
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PaymentLinkUpdate {
+    UsageCountUpdate {
+        status: storage_enums::PaymentLinkStatus,
+        usage_count: i32,
+    },
+    LastPaymentUpdate {
+        last_payment_id: String,
+    },
+    StatusUpdate {
+        status: storage_enums::PaymentLinkStatus,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_link)]
+pub struct PaymentLinkUpdateInternal {
+    status: Option<storage_enums::PaymentLinkStatus>,
+    usage_count: Option<i32>,
+    last_payment_id: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl PaymentLinkUpdate {
+    pub fn apply_changeset(self, source: PaymentLink) -> PaymentLink {
+        let internal_update: PaymentLinkUpdateInternal = self.into();
+        PaymentLink {
+            status: internal_update.status.unwrap_or(source.status),
+            usage_count: internal_update.usage_count.unwrap_or(source.usage_count),
+            last_payment_id: internal_update.last_payment_id.or(source.last_payment_id),
+            modified_at: internal_update.modified_at.unwrap_or(source.modified_at),
+            ..source
+        }
+    }
+}
+
+impl From<PaymentLinkUpdate> for PaymentLinkUpdateInternal {
+    fn from(payment_link_update: PaymentLinkUpdate) -> Self {
+        match payment_link_update {
+            PaymentLinkUpdate::UsageCountUpdate {
+                status,
+                usage_count,
+            } => Self {
+                status: Some(status),
+                usage_count: Some(usage_count),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentLinkUpdate::LastPaymentUpdate { last_payment_id } => Self {
+                last_payment_id: Some(last_payment_id),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentLinkUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_schedule};
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_schedule)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentScheduleNew {
+    pub schedule_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub mandate_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::PaymentScheduleInterval,
+    pub interval_count: i32,
+    pub status: storage_enums::PaymentScheduleStatus,
+    pub total_cycles: Option<i32>,
+    pub next_payment_at: Option<PrimitiveDateTime>,
+    pub description: Option<String>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = payment_schedule)]
+pub struct PaymentSchedule {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub schedule_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub mandate_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::PaymentScheduleInterval,
+    pub interval_count: i32,
+    pub status: storage_enums::PaymentScheduleStatus,
+    pub total_cycles: Option<i32>,
+    pub completed_cycles: i32,
+    pub failed_cycles: i32,
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub next_payment_at: Option<PrimitiveDateTime>,
+    pub last_payment_id: Option<String>,
+    pub description: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PaymentScheduleUpdate {
+    CycleUpdate {
+        status: storage_enums::PaymentScheduleStatus,
+        completed_cycles: i32,
+        failed_cycles: i32,
+        next_payment_at: Option<PrimitiveDateTime>,
+        last_payment_id: Option<String>,
+    },
+    StatusUpdate {
+        status: storage_enums::PaymentScheduleStatus,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_schedule)]
+pub struct PaymentScheduleUpdateInternal {
+    status: Option<storage_enums::PaymentScheduleStatus>,
+    completed_cycles: Option<i32>,
+    failed_cycles: Option<i32>,
+    next_payment_at: Option<Option<PrimitiveDateTime>>,
+    last_payment_id: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<PaymentScheduleUpdate> for PaymentScheduleUpdateInternal {
+    fn from(payment_schedule_update: PaymentScheduleUpdate) -> Self {
+        match payment_schedule_update {
+            PaymentScheduleUpdate::CycleUpdate {
+                status,
+                completed_cycles,
+                failed_cycles,
+                next_payment_at,
+                last_payment_id,
+            } => Self {
+                status: Some(status),
+                completed_cycles: Some(completed_cycles),
+                failed_cycles: Some(failed_cycles),
+                next_payment_at: Some(next_payment_at),
+                last_payment_id,
+                modified_at: Some(common_utils::date_time::now()),
+            },
+            PaymentScheduleUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct PaymentScheduleTrackingData {
+    pub merchant_id: String,
+    pub schedule_id: String,
+    pub cycle: i32,
+}
@@ -0,0 +1,66 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_splits};
+
+/// The share of the amount of a payment made to a platform which goes to one of its sub-merchants.
+/// The splits of a payment form the ledger the platform reconciles its transfers against.
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_splits)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentSplitNew {
+    pub split_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub sub_merchant_id: String,
+    pub connector_account_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::SplitStatus,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = payment_splits)]
+pub struct PaymentSplit {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub split_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub sub_merchant_id: String,
+    pub connector_account_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::SplitStatus,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PaymentSplitUpdate {
+    StatusUpdate { status: storage_enums::SplitStatus },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_splits)]
+pub struct PaymentSplitUpdateInternal {
+    status: Option<storage_enums::SplitStatus>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<PaymentSplitUpdate> for PaymentSplitUpdateInternal {
+    fn from(payment_split_update: PaymentSplitUpdate) -> Self {
+        match payment_split_update {
+            PaymentSplitUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at: Some(common_utils::date_time::now()),
+            },
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use common_utils::{custom_serde, pii};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payout};
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutNew {
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: String,
+    pub payout_type: storage_enums::PayoutType,
+    pub payout_method_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::PayoutStatus,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = payout)]
+pub struct Payout {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: String,
+    pub payout_type: storage_enums::PayoutType,
+    pub payout_method_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::PayoutStatus,
+    pub connector_payout_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutUpdate {
+    ConnectorResponseUpdate {
+        status: storage_enums::PayoutStatus,
+        connector_payout_id: Option<String>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+    StatusUpdate {
+        status: storage_enums::PayoutStatus,
+    },
+    ConnectorUpdate {
+        connector: String,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout)]
+pub struct PayoutUpdateInternal {
+    connector: Option<String>,
+    status: Option<storage_enums::PayoutStatus>,
+    connector_payout_id: Option<String>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<PayoutUpdate> for PayoutUpdateInternal {
+    fn from(payout_update: PayoutUpdate) -> Self {
+        match payout_update {
+            PayoutUpdate::ConnectorResponseUpdate {
+                status,
+                connector_payout_id,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(status),
+                connector_payout_id,
+                error_code,
+                error_message,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PayoutUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PayoutUpdate::ConnectorUpdate { connector } => Self {
+                connector: Some(connector),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
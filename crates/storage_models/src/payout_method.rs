@@ -0,0 +1,31 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payout_method};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_method)]
+pub struct PayoutMethodNew {
+    pub payout_method_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub payout_type: storage_enums::PayoutType,
+    pub key_id: String,
+    pub encrypted_payout_method_data: Vec<u8>,
+    pub last_four: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = payout_method)]
+pub struct PayoutMethod {
+    pub id: i32,
+    pub payout_method_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub payout_type: storage_enums::PayoutType,
+    pub key_id: String,
+    pub encrypted_payout_method_data: Vec<u8>,
+    pub last_four: String,
+    pub created_at: PrimitiveDateTime,
+}
@@ -93,4 +93,21 @@ impl ApiKey {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_organization_id(
+        conn: &PgPooledConn,
+        organization_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::organization_id.eq(organization_id.to_owned()),
+            limit,
+            offset,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
 }
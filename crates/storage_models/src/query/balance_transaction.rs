@@ -0,0 +1,54 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    balance_transaction::{BalanceTransaction, BalanceTransactionNew},
+    enums as storage_enums,
+    schema::balance_transactions::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl BalanceTransactionNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<BalanceTransaction> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl BalanceTransaction {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_transaction_type_source_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        transaction_type: storage_enums::BalanceTransactionType,
+        source_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::transaction_type.eq(transaction_type))
+                .and(dsl::source_id.eq(source_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Lists the balance transactions of the merchant, the most recent first.
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            offset,
+            Some(dsl::id.desc()),
+        )
+        .await
+    }
+}
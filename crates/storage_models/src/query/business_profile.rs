@@ -0,0 +1,87 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    business_profile::{
+        BusinessProfile, BusinessProfileNew, BusinessProfileUpdate, BusinessProfileUpdateInternal,
+    },
+    schema::business_profile::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl BusinessProfileNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<BusinessProfile> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl BusinessProfile {
+    #[instrument(skip(conn))]
+    pub async fn find_by_profile_id_merchant_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        merchant_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::merchant_id.eq(merchant_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update_by_profile_id_merchant_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        merchant_id: &str,
+        business_profile: BusinessProfileUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::merchant_id.eq(merchant_id.to_owned())),
+            BusinessProfileUpdateInternal::from(business_profile),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_profile_id_merchant_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        merchant_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::merchant_id.eq(merchant_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -0,0 +1,37 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    connector_log::{ConnectorLog, ConnectorLogNew},
+    schema::connector_logs::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ConnectorLogNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ConnectorLog> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ConnectorLog {
+    /// Lists the requests made to connectors for the payment, in the order they were made.
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+}
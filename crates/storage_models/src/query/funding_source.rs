@@ -0,0 +1,72 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    funding_source::{
+        FundingSource, FundingSourceNew, FundingSourceUpdate, FundingSourceUpdateInternal,
+    },
+    schema::funding_sources::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl FundingSourceNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<FundingSource> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl FundingSource {
+    #[instrument(skip(conn))]
+    pub async fn find_all_by_merchant_id_payment_id_attempt_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned()))
+                .and(dsl::attempt_id.eq(attempt_id.to_owned())),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        funding_source: FundingSourceUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::funding_source_id.eq(self.funding_source_id.to_owned()),
+            FundingSourceUpdateInternal::from(funding_source),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -92,4 +92,19 @@ impl MerchantAccount {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn list_by_organization_id(
+        conn: &PgPooledConn,
+        organization_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::organization_id.eq(organization_id.to_owned()),
+            None,
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
 }
@@ -123,4 +123,21 @@ impl MerchantConnectorAccount {
             .await
         }
     }
+
+    /// Returns the accounts whose details are not encrypted with the data key, oldest first.
+    #[instrument(skip(conn))]
+    pub async fn find_not_encrypted_with_key(
+        conn: &PgPooledConn,
+        key_id: &str,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::key_id.is_null().or(dsl::key_id.ne(key_id.to_owned())),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
 }
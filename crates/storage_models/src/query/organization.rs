@@ -0,0 +1,30 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    organization::{Organization, OrganizationNew},
+    schema::organization::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl OrganizationNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Organization> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Organization {
+    #[instrument(skip(conn))]
+    pub async fn find_by_organization_id(
+        conn: &PgPooledConn,
+        organization_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::organization_id.eq(organization_id.to_owned()),
+        )
+        .await
+    }
+}
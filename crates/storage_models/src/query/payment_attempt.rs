@@ -20,12 +20,16 @@ impl PaymentAttemptNew {
 }
 
 impl PaymentAttempt {
+    /// Updates the payment attempt only if it has not been updated since it was read, failing with
+    /// a `VersionConflict` error otherwise, or a `NotFound` error if it does not exist.
     #[instrument(skip(conn))]
     pub async fn update_with_attempt_id(
         self,
         conn: &PgPooledConn,
         payment_attempt: PaymentAttemptUpdate,
     ) -> StorageResult<Self> {
+        let mut payment_attempt = PaymentAttemptUpdateInternal::from(payment_attempt);
+        payment_attempt.version = Some(self.version + 1);
         match generics::generic_update_with_unique_predicate_get_result::<
             <Self as HasTable>::Table,
             _,
@@ -35,13 +39,29 @@ impl PaymentAttempt {
             conn,
             dsl::attempt_id
                 .eq(self.attempt_id.to_owned())
-                .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            PaymentAttemptUpdateInternal::from(payment_attempt),
+                .and(dsl::merchant_id.eq(self.merchant_id.to_owned()))
+                .and(dsl::version.eq(self.version)),
+            payment_attempt,
         )
         .await
         {
             Err(error) => match error.current_context() {
                 errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                errors::DatabaseError::NotFound => {
+                    match generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+                        conn,
+                        dsl::merchant_id
+                            .eq(self.merchant_id.to_owned())
+                            .and(dsl::attempt_id.eq(self.attempt_id.to_owned())),
+                    )
+                    .await?
+                    {
+                        Some(_) => {
+                            Err(error.change_context(errors::DatabaseError::VersionConflict))
+                        }
+                        None => Err(error),
+                    }
+                }
                 _ => Err(error),
             },
             result => result,
@@ -112,6 +132,29 @@ impl PaymentAttempt {
         )
     }
 
+    #[instrument(skip(conn))]
+    pub async fn find_all_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_by_merchant_id_connector_txn_id(
         conn: &PgPooledConn,
@@ -159,4 +202,25 @@ impl PaymentAttempt {
         )
         .await
     }
+
+    /// Charged payment attempts created before the amount refunded was stored on them, in the
+    /// order of their identifiers, starting after `after_id`.
+    #[instrument(skip(conn))]
+    pub async fn find_charged_without_amount_refunded(
+        conn: &PgPooledConn,
+        after_id: i32,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::id
+                .gt(after_id)
+                .and(dsl::amount_refunded.is_null())
+                .and(dsl::status.eq(enums::AttemptStatus::Charged)),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
 }
@@ -0,0 +1,37 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    payment_event::{PaymentEvent, PaymentEventNew},
+    schema::payment_events::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentEventNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentEvent> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentEvent {
+    /// Lists the events of the payment, in the order they were recorded.
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+}
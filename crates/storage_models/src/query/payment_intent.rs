@@ -1,4 +1,4 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
 use router_env::{instrument, tracing};
 
 use super::generics;
@@ -19,18 +19,25 @@ impl PaymentIntentNew {
 }
 
 impl PaymentIntent {
+    /// Updates the payment intent only if it has not been updated since it was read, failing with
+    /// a `VersionConflict` error otherwise, or a `NotFound` error if it does not exist.
     #[instrument(skip(conn))]
     pub async fn update(
         self,
         conn: &PgPooledConn,
         payment_intent: PaymentIntentUpdate,
     ) -> StorageResult<Self> {
+        let payment_intent = PaymentIntentUpdateInternal {
+            version: Some(self.version + 1),
+            ..PaymentIntentUpdateInternal::from(payment_intent)
+        };
         match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
             conn,
             dsl::payment_id
                 .eq(self.payment_id.to_owned())
-                .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            PaymentIntentUpdateInternal::from(payment_intent),
+                .and(dsl::merchant_id.eq(self.merchant_id.to_owned()))
+                .and(dsl::version.eq(self.version)),
+            payment_intent,
         )
         .await
         {
@@ -38,9 +45,21 @@ impl PaymentIntent {
                 errors::DatabaseError::NoFieldsToUpdate => Ok(self),
                 _ => Err(error),
             },
-            Ok(mut payment_intents) => payment_intents
-                .pop()
-                .ok_or(error_stack::report!(errors::DatabaseError::NotFound)),
+            Ok(mut payment_intents) => match payment_intents.pop() {
+                Some(payment_intent) => Ok(payment_intent),
+                None => Err(error_stack::report!(
+                    match Self::find_optional_by_payment_id_merchant_id(
+                        conn,
+                        &self.payment_id,
+                        &self.merchant_id,
+                    )
+                    .await?
+                    {
+                        Some(_) => errors::DatabaseError::VersionConflict,
+                        None => errors::DatabaseError::NotFound,
+                    }
+                )),
+            },
         }
     }
 
@@ -73,4 +92,27 @@ impl PaymentIntent {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
 }
@@ -0,0 +1,89 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    payment_link::{PaymentLink, PaymentLinkNew, PaymentLinkUpdate, PaymentLinkUpdateInternal},
+    schema::payment_link::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentLinkNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentLink> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentLink {
+    #[instrument(skip(conn))]
+    pub async fn find_by_link_id(conn: &PgPooledConn, link_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::link_id.eq(link_id.to_owned()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payment_link: PaymentLinkUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::link_id.eq(self.link_id.to_owned()),
+            PaymentLinkUpdateInternal::from(payment_link),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    /// Updates the payment link only if its usage count has not changed since it was read, failing
+    /// with a `VersionConflict` error otherwise, so that concurrent payments cannot use the link
+    /// more times than allowed.
+    #[instrument(skip(conn))]
+    pub async fn update_with_usage_count(
+        self,
+        conn: &PgPooledConn,
+        payment_link: PaymentLinkUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::link_id
+                .eq(self.link_id.to_owned())
+                .and(dsl::usage_count.eq(self.usage_count)),
+            PaymentLinkUpdateInternal::from(payment_link),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                errors::DatabaseError::NotFound => {
+                    Self::find_by_link_id(conn, &self.link_id).await?;
+                    Err(error.change_context(errors::DatabaseError::VersionConflict))
+                }
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
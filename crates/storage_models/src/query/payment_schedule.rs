@@ -0,0 +1,64 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    payment_schedule::{
+        PaymentSchedule, PaymentScheduleNew, PaymentScheduleUpdate, PaymentScheduleUpdateInternal,
+    },
+    schema::payment_schedule::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentScheduleNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentSchedule> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentSchedule {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_schedule_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        schedule_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::schedule_id.eq(schedule_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payment_schedule: PaymentScheduleUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::schedule_id.eq(self.schedule_id.to_owned())),
+            PaymentScheduleUpdateInternal::from(payment_schedule),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
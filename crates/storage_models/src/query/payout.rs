@@ -0,0 +1,75 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    payout::{Payout, PayoutNew, PayoutUpdate, PayoutUpdateInternal},
+    schema::payout::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Payout> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Payout {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_payout_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payout_id.eq(payout_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_connector_payout_id_connector(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector_payout_id: &str,
+        connector: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector_payout_id.eq(connector_payout_id.to_owned()))
+                .and(dsl::connector.eq(connector.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(self, conn: &PgPooledConn, payout: PayoutUpdate) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::payout_id.eq(self.payout_id.to_owned())),
+            PayoutUpdateInternal::from(payout),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
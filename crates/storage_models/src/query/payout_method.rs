@@ -0,0 +1,33 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    payout_method::{PayoutMethod, PayoutMethodNew},
+    schema::payout_method::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutMethodNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutMethod> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutMethod {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_payout_method_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payout_method_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payout_method_id.eq(payout_method_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -136,4 +136,58 @@ impl ProcessTracker {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_processes_by_status(
+        conn: &PgPooledConn,
+        status: enums::ProcessTrackerStatus,
+        runner: Option<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        match runner {
+            Some(runner) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::status.eq(status).and(dsl::runner.eq(runner)),
+                    limit,
+                    offset,
+                    Some(dsl::updated_at.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::status.eq(status),
+                    limit,
+                    offset,
+                    Some(dsl::updated_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn replay_dead_letter_processes(
+        conn: &PgPooledConn,
+        ids: Vec<String>,
+        schedule_time: PrimitiveDateTime,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::status
+                .eq(enums::ProcessTrackerStatus::DeadLetter)
+                .and(dsl::id.eq_any(ids)),
+            (
+                dsl::status.eq(enums::ProcessTrackerStatus::New),
+                dsl::business_status.eq(String::from("Pending")),
+                dsl::retry_count.eq(0),
+                dsl::schedule_time.eq(schedule_time),
+                dsl::updated_at.eq(schedule_time),
+            ),
+        )
+        .await
+    }
 }
@@ -0,0 +1,14 @@
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    redaction_audit::{RedactionAudit, RedactionAuditNew},
+    PgPooledConn, StorageResult,
+};
+
+impl RedactionAuditNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RedactionAudit> {
+        generics::generic_insert(conn, self).await
+    }
+}
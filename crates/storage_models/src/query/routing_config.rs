@@ -0,0 +1,110 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    routing_config::{
+        RoutingConfig, RoutingConfigNew, RoutingConfigUpdate, RoutingConfigUpdateInternal,
+    },
+    schema::routing_configs::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl RoutingConfigNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RoutingConfig> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RoutingConfig {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_routing_config_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        routing_config_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::routing_config_id.eq(routing_config_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_optional_active_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::is_active.eq(true)),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn deactivate_all_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::is_active.eq(true)),
+            RoutingConfigUpdateInternal::from(RoutingConfigUpdate::ActivationUpdate {
+                is_active: false,
+            }),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        routing_config: RoutingConfigUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::routing_config_id.eq(self.routing_config_id.to_owned())),
+            RoutingConfigUpdateInternal::from(routing_config),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    schema::{settlement_lines, settlement_reports},
+    settlement_report::{SettlementLine, SettlementLineNew, SettlementReport, SettlementReportNew},
+    PgPooledConn, StorageResult,
+};
+
+impl SettlementReportNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SettlementReport> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SettlementReport {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            settlement_reports::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(settlement_reports::dsl::report_id.eq(report_id.to_owned())),
+        )
+        .await
+    }
+}
+
+impl SettlementLineNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SettlementLine> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SettlementLine {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            settlement_lines::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(settlement_lines::dsl::report_id.eq(report_id.to_owned())),
+            None,
+            None,
+            Some(settlement_lines::dsl::id.asc()),
+        )
+        .await
+    }
+
+    /// Lists the settlement lines of the payment, the most recent first.
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            settlement_lines::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(settlement_lines::dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            Some(settlement_lines::dsl::id.desc()),
+        )
+        .await
+    }
+}
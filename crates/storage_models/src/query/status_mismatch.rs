@@ -0,0 +1,14 @@
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    status_mismatch::{StatusMismatch, StatusMismatchNew},
+    PgPooledConn, StorageResult,
+};
+
+impl StatusMismatchNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<StatusMismatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
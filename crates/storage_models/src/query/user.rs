@@ -0,0 +1,36 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    schema::users::dsl,
+    user::{User, UserNew},
+    PgPooledConn, StorageResult,
+};
+
+impl UserNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<User> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl User {
+    #[instrument(skip(conn))]
+    pub async fn find_by_email(conn: &PgPooledConn, email: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::email.eq(email.to_owned()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_user_id(conn: &PgPooledConn, user_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::user_id.eq(user_id.to_owned()),
+        )
+        .await
+    }
+}
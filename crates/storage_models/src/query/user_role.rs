@@ -0,0 +1,67 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    schema::user_roles::dsl,
+    user_role::{UserRole, UserRoleNew, UserRoleUpdate, UserRoleUpdateInternal},
+    PgPooledConn, StorageResult,
+};
+
+impl UserRoleNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<UserRole> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl UserRole {
+    #[instrument(skip(conn))]
+    pub async fn find_by_user_id_merchant_id(
+        conn: &PgPooledConn,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::user_id
+                .eq(user_id.to_owned())
+                .and(dsl::merchant_id.eq(merchant_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn list_by_user_id(conn: &PgPooledConn, user_id: &str) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::user_id.eq(user_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update_by_user_id_merchant_id(
+        conn: &PgPooledConn,
+        user_id: &str,
+        merchant_id: &str,
+        user_role: UserRoleUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::user_id
+                .eq(user_id.to_owned())
+                .and(dsl::merchant_id.eq(merchant_id.to_owned())),
+            UserRoleUpdateInternal::from(user_role),
+        )
+        .await
+    }
+}
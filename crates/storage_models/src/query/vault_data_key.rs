@@ -0,0 +1,44 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::generics;
+use crate::{
+    schema::vault_data_key::dsl,
+    vault_data_key::{VaultDataKey, VaultDataKeyNew},
+    PgPooledConn, StorageResult,
+};
+
+impl VaultDataKeyNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultDataKey> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl VaultDataKey {
+    #[instrument(skip(conn))]
+    pub async fn find_by_key_id(conn: &PgPooledConn, key_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::key_id.eq(key_id.to_owned()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_latest_created_after(
+        conn: &PgPooledConn,
+        created_after: PrimitiveDateTime,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::created_at.gt(created_after),
+            Some(1),
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+        .map(|data_keys| data_keys.into_iter().next())
+    }
+}
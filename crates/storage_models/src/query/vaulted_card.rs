@@ -0,0 +1,48 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    schema::vaulted_card::dsl,
+    vaulted_card::{VaultedCard, VaultedCardNew},
+    PgPooledConn, StorageResult,
+};
+
+impl VaultedCardNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultedCard> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl VaultedCard {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_payment_token(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_token: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_token.eq(payment_token.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_merchant_id_payment_token(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_token: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_token.eq(payment_token.to_owned())),
+        )
+        .await
+    }
+}
@@ -0,0 +1,35 @@
+use common_utils::custom_serde;
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::redaction_audit;
+
+/// Audit record of the redaction of the personal data of a customer, along with the number of
+/// records which were redacted.
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = redaction_audit)]
+#[serde(deny_unknown_fields)]
+pub struct RedactionAuditNew {
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub addresses_redacted: i32,
+    pub payments_redacted: i32,
+    pub mandates_redacted: i32,
+    pub payment_methods_deleted: i32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = redaction_audit)]
+pub struct RedactionAudit {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub addresses_redacted: i32,
+    pub payments_redacted: i32,
+    pub mandates_redacted: i32,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    pub payment_methods_deleted: i32,
+}
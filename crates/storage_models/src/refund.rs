@@ -36,6 +36,7 @@ pub struct Refund {
     pub attempt_id: String,
     pub refund_reason: Option<String>,
     pub refund_error_code: Option<String>,
+    pub connector_raw_response: Option<serde_json::Value>,
 }
 
 #[derive(
@@ -85,6 +86,7 @@ pub enum RefundUpdate {
         sent_to_gateway: bool,
         refund_error_message: Option<String>,
         refund_arn: String,
+        connector_raw_response: Option<serde_json::Value>,
     },
     MetadataAndReasonUpdate {
         metadata: Option<pii::SecretSerdeValue>,
@@ -99,6 +101,7 @@ pub enum RefundUpdate {
         refund_status: Option<storage_enums::RefundStatus>,
         refund_error_message: Option<String>,
         refund_error_code: Option<String>,
+        connector_raw_response: Option<serde_json::Value>,
     },
 }
 
@@ -113,6 +116,7 @@ pub struct RefundUpdateInternal {
     metadata: Option<pii::SecretSerdeValue>,
     refund_reason: Option<String>,
     refund_error_code: Option<String>,
+    connector_raw_response: Option<serde_json::Value>,
 }
 
 impl From<RefundUpdate> for RefundUpdateInternal {
@@ -124,12 +128,14 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 sent_to_gateway,
                 refund_error_message,
                 refund_arn,
+                connector_raw_response,
             } => Self {
                 connector_refund_id: Some(connector_refund_id),
                 refund_status: Some(refund_status),
                 sent_to_gateway: Some(sent_to_gateway),
                 refund_error_message,
                 refund_arn: Some(refund_arn),
+                connector_raw_response,
                 ..Default::default()
             },
             RefundUpdate::MetadataAndReasonUpdate { metadata, reason } => Self {
@@ -151,10 +157,12 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_status,
                 refund_error_message,
                 refund_error_code,
+                connector_raw_response,
             } => Self {
                 refund_status,
                 refund_error_message,
                 refund_error_code,
+                connector_raw_response,
                 ..Default::default()
             },
         }
@@ -174,6 +182,9 @@ impl RefundUpdate {
             refund_error_code: pa_update.refund_error_code.or(source.refund_error_code),
             refund_arn: pa_update.refund_arn.or(source.refund_arn),
             metadata: pa_update.metadata.or(source.metadata),
+            connector_raw_response: pa_update
+                .connector_raw_response
+                .or(source.connector_raw_response),
             ..source
         }
     }
@@ -0,0 +1,60 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::routing_configs;
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = routing_configs)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingConfigNew {
+    pub routing_config_id: String,
+    pub merchant_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub algorithm: serde_json::Value,
+    pub is_active: bool,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = routing_configs)]
+pub struct RoutingConfig {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub routing_config_id: String,
+    pub merchant_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub algorithm: serde_json::Value,
+    pub is_active: bool,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum RoutingConfigUpdate {
+    ActivationUpdate { is_active: bool },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = routing_configs)]
+pub struct RoutingConfigUpdateInternal {
+    is_active: Option<bool>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<RoutingConfigUpdate> for RoutingConfigUpdateInternal {
+    fn from(routing_config_update: RoutingConfigUpdate) -> Self {
+        match routing_config_update {
+            RoutingConfigUpdate::ActivationUpdate { is_active } => Self {
+                is_active: Some(is_active),
+                modified_at: Some(common_utils::date_time::now()),
+            },
+        }
+    }
+}
@@ -31,7 +31,7 @@ diesel::table! {
 
     api_keys (key_id) {
         key_id -> Varchar,
-        merchant_id -> Varchar,
+        merchant_id -> Nullable<Varchar>,
         name -> Varchar,
         description -> Nullable<Varchar>,
         hashed_api_key -> Varchar,
@@ -39,6 +39,70 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        scopes -> Nullable<Array<Nullable<Text>>>,
+        organization_id -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    balance_transactions (id) {
+        id -> Int4,
+        transaction_id -> Varchar,
+        merchant_id -> Varchar,
+        transaction_type -> Varchar,
+        source_id -> Varchar,
+        payment_id -> Nullable<Varchar>,
+        amount -> Int8,
+        currency -> Currency,
+        debit_account -> Varchar,
+        credit_account -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    business_profile (id) {
+        id -> Int4,
+        profile_id -> Varchar,
+        merchant_id -> Varchar,
+        profile_name -> Varchar,
+        return_url -> Nullable<Text>,
+        webhook_details -> Nullable<Json>,
+        routing_algorithm -> Nullable<Json>,
+        statement_descriptor_name -> Nullable<Varchar>,
+        statement_descriptor_suffix -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        auto_capture_delay -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    captures (id) {
+        id -> Int4,
+        capture_id -> Varchar,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        attempt_id -> Varchar,
+        status -> CaptureStatus,
+        amount -> Int8,
+        currency -> Nullable<Currency>,
+        connector -> Varchar,
+        capture_sequence -> Int2,
+        connector_transaction_id -> Nullable<Varchar>,
+        error_message -> Nullable<Varchar>,
+        error_code -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -73,6 +137,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    connector_logs (id) {
+        id -> Int4,
+        log_id -> Varchar,
+        merchant_id -> Varchar,
+        payment_id -> Varchar,
+        attempt_id -> Varchar,
+        connector -> Varchar,
+        flow -> Varchar,
+        method -> Varchar,
+        url -> Text,
+        request_body -> Nullable<Text>,
+        response_body -> Nullable<Text>,
+        status_code -> Nullable<Int4>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -152,6 +237,31 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    funding_sources (id) {
+        id -> Int4,
+        funding_source_id -> Varchar,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        attempt_id -> Varchar,
+        payment_method -> Varchar,
+        payment_method_type -> Nullable<Varchar>,
+        status -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        connector -> Varchar,
+        funding_sequence -> Int2,
+        connector_transaction_id -> Nullable<Varchar>,
+        error_message -> Nullable<Varchar>,
+        error_code -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -223,6 +333,8 @@ diesel::table! {
         metadata -> Nullable<Jsonb>,
         routing_algorithm -> Nullable<Json>,
         api_key -> Nullable<Varchar>,
+        return_raw_connector_response -> Bool,
+        organization_id -> Nullable<Varchar>,
     }
 }
 
@@ -242,6 +354,21 @@ diesel::table! {
         connector_type -> ConnectorType,
         metadata -> Nullable<Jsonb>,
         frm_configs -> Nullable<Jsonb>,
+        key_id -> Nullable<Varchar>,
+        encrypted_connector_account_details -> Nullable<Bytea>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    organization (id) {
+        id -> Int4,
+        organization_id -> Varchar,
+        organization_name -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -283,6 +410,42 @@ diesel::table! {
         payment_experience -> Nullable<Varchar>,
         payment_method_type -> Nullable<Varchar>,
         payment_method_data -> Nullable<Jsonb>,
+        amount_refunded -> Nullable<Int8>,
+        three_ds_data -> Nullable<Jsonb>,
+        unified_code -> Nullable<Varchar>,
+        unified_message -> Nullable<Text>,
+        connector_raw_response -> Nullable<Jsonb>,
+        avs_result -> Nullable<Varchar>,
+        cvc_result -> Nullable<Varchar>,
+        cancellation_metadata -> Nullable<Jsonb>,
+        fraud_check_verdict -> Nullable<Varchar>,
+        fraud_check_score -> Nullable<Int4>,
+        settlement_amount -> Nullable<Int8>,
+        settlement_currency -> Nullable<Currency>,
+        fx_rate -> Nullable<Varchar>,
+        version -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_events (id) {
+        id -> Int4,
+        event_id -> Varchar,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        attempt_id -> Varchar,
+        operation -> Varchar,
+        previous_intent_status -> IntentStatus,
+        intent_status -> IntentStatus,
+        previous_attempt_status -> AttemptStatus,
+        attempt_status -> AttemptStatus,
+        source -> Varchar,
+        actor -> Nullable<Varchar>,
+        connector_response -> Nullable<Jsonb>,
+        created_at -> Timestamp,
     }
 }
 
@@ -314,6 +477,36 @@ diesel::table! {
         off_session -> Nullable<Bool>,
         client_secret -> Nullable<Varchar>,
         active_attempt_id -> Varchar,
+        session_expiry -> Nullable<Timestamp>,
+        order_details -> Nullable<Array<Nullable<Jsonb>>>,
+        amount_breakdown -> Nullable<Jsonb>,
+        l2_l3_data -> Nullable<Jsonb>,
+        profile_id -> Nullable<Varchar>,
+        auto_capture_delay -> Nullable<Int4>,
+        version -> Int4,
+        connector_debug_mode -> Bool,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_link (id) {
+        id -> Int4,
+        link_id -> Varchar,
+        merchant_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        description -> Nullable<Varchar>,
+        return_url -> Nullable<Varchar>,
+        reusable -> Bool,
+        status -> Varchar,
+        usage_count -> Int4,
+        last_payment_id -> Nullable<Varchar>,
+        expires_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -346,6 +539,93 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_schedule (id) {
+        id -> Int4,
+        schedule_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Varchar,
+        mandate_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        billing_interval -> Varchar,
+        interval_count -> Int4,
+        status -> Varchar,
+        total_cycles -> Nullable<Int4>,
+        completed_cycles -> Int4,
+        failed_cycles -> Int4,
+        next_payment_at -> Nullable<Timestamp>,
+        last_payment_id -> Nullable<Varchar>,
+        description -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_splits (id) {
+        id -> Int4,
+        split_id -> Varchar,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        sub_merchant_id -> Varchar,
+        connector_account_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        status -> Varchar,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout (id) {
+        id -> Int4,
+        payout_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Nullable<Varchar>,
+        connector -> Varchar,
+        payout_type -> Varchar,
+        payout_method_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        status -> Varchar,
+        connector_payout_id -> Nullable<Varchar>,
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_method (id) {
+        id -> Int4,
+        payout_method_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Nullable<Varchar>,
+        payout_type -> Varchar,
+        key_id -> Varchar,
+        encrypted_payout_method_data -> Bytea,
+        last_four -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -367,6 +647,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    redaction_audit (id) {
+        id -> Int4,
+        merchant_id -> Varchar,
+        customer_id -> Varchar,
+        addresses_redacted -> Int4,
+        payments_redacted -> Int4,
+        mandates_redacted -> Int4,
+        created_at -> Timestamp,
+        payment_methods_deleted -> Int4,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -396,6 +692,7 @@ diesel::table! {
         attempt_id -> Varchar,
         refund_reason -> Nullable<Varchar>,
         refund_error_code -> Nullable<Text>,
+        connector_raw_response -> Nullable<Jsonb>,
     }
 }
 
@@ -411,23 +708,177 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    routing_configs (id) {
+        id -> Int4,
+        routing_config_id -> Varchar,
+        merchant_id -> Varchar,
+        name -> Varchar,
+        description -> Nullable<Varchar>,
+        algorithm -> Json,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    settlement_lines (id) {
+        id -> Int4,
+        report_id -> Varchar,
+        merchant_id -> Varchar,
+        connector -> Varchar,
+        connector_transaction_id -> Varchar,
+        payment_id -> Nullable<Varchar>,
+        attempt_id -> Nullable<Varchar>,
+        settled_amount -> Int8,
+        settled_currency -> Currency,
+        expected_amount -> Nullable<Int8>,
+        expected_currency -> Nullable<Currency>,
+        recon_status -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    settlement_reports (id) {
+        id -> Int4,
+        report_id -> Varchar,
+        merchant_id -> Varchar,
+        connector -> Varchar,
+        line_count -> Int4,
+        matched_count -> Int4,
+        mismatched_count -> Int4,
+        missing_count -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    status_mismatch (id) {
+        id -> Int4,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        attempt_id -> Varchar,
+        connector -> Varchar,
+        connector_transaction_id -> Nullable<Varchar>,
+        router_status -> AttemptStatus,
+        connector_status -> AttemptStatus,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    user_roles (id) {
+        id -> Int4,
+        user_id -> Varchar,
+        merchant_id -> Varchar,
+        role -> Varchar,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    users (id) {
+        id -> Int4,
+        user_id -> Varchar,
+        email -> Varchar,
+        name -> Varchar,
+        password -> Varchar,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    vault_data_key (id) {
+        id -> Int4,
+        key_id -> Varchar,
+        encrypted_key -> Bytea,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    vaulted_card (id) {
+        id -> Int4,
+        payment_token -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Nullable<Varchar>,
+        key_id -> Varchar,
+        encrypted_card -> Bytea,
+        card_last_four -> Varchar,
+        card_exp_month -> Varchar,
+        card_exp_year -> Varchar,
+        created_at -> Timestamp,
+        token_service_provider -> Nullable<Varchar>,
+        encrypted_network_token -> Nullable<Bytea>,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    balance_transactions,
+    business_profile,
+    captures,
     cards_info,
     configs,
+    connector_logs,
     connector_response,
     customers,
     dispute,
     events,
+    funding_sources,
     locker_mock_up,
     mandate,
     merchant_account,
     merchant_connector_account,
+    organization,
     payment_attempt,
+    payment_events,
     payment_intent,
+    payment_link,
     payment_methods,
+    payment_schedule,
+    payment_splits,
+    payout,
+    payout_method,
     process_tracker,
+    redaction_audit,
     refund,
     reverse_lookup,
+    routing_configs,
+    settlement_lines,
+    settlement_reports,
+    status_mismatch,
+    user_roles,
+    users,
+    vault_data_key,
+    vaulted_card,
 );
@@ -0,0 +1,81 @@
+use common_utils::custom_serde;
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{settlement_lines, settlement_reports},
+};
+
+/// A settlement report of a connector ingested for reconciliation, along with the number of its
+/// lines which were matched, mismatched or missing in the router.
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = settlement_reports)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementReportNew {
+    pub report_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub line_count: i32,
+    pub matched_count: i32,
+    pub mismatched_count: i32,
+    pub missing_count: i32,
+    pub created_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = settlement_reports)]
+pub struct SettlementReport {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub report_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub line_count: i32,
+    pub matched_count: i32,
+    pub mismatched_count: i32,
+    pub missing_count: i32,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// A transaction settled by a connector, reconciled against the payment attempt processed with
+/// the same connector transaction ID.
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = settlement_lines)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementLineNew {
+    pub report_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub connector_transaction_id: String,
+    pub payment_id: Option<String>,
+    pub attempt_id: Option<String>,
+    pub settled_amount: i64,
+    pub settled_currency: storage_enums::Currency,
+    pub expected_amount: Option<i64>,
+    pub expected_currency: Option<storage_enums::Currency>,
+    pub recon_status: storage_enums::ReconStatus,
+    pub created_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = settlement_lines)]
+pub struct SettlementLine {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub report_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub connector_transaction_id: String,
+    pub payment_id: Option<String>,
+    pub attempt_id: Option<String>,
+    pub settled_amount: i64,
+    pub settled_currency: storage_enums::Currency,
+    pub expected_amount: Option<i64>,
+    pub expected_currency: Option<storage_enums::Currency>,
+    pub recon_status: storage_enums::ReconStatus,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
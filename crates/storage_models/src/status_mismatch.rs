@@ -0,0 +1,37 @@
+use common_utils::custom_serde;
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::status_mismatch};
+
+/// Audit record of a payment attempt whose status at the connector differed from the status
+/// stored in the router, found while force syncing the payment with the connector.
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = status_mismatch)]
+#[serde(deny_unknown_fields)]
+pub struct StatusMismatchNew {
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub connector: String,
+    pub connector_transaction_id: Option<String>,
+    pub router_status: storage_enums::AttemptStatus,
+    pub connector_status: storage_enums::AttemptStatus,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = status_mismatch)]
+pub struct StatusMismatch {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub connector: String,
+    pub connector_transaction_id: Option<String>,
+    pub router_status: storage_enums::AttemptStatus,
+    pub connector_status: storage_enums::AttemptStatus,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
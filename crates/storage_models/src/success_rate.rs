@@ -0,0 +1,7 @@
+/// Number of authorizations performed through a connector in a time bucket, for a given card
+/// network and country.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct AuthorizationCount {
+    pub total: i64,
+    pub success: i64,
+}
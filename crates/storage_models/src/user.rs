@@ -0,0 +1,26 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::users;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = users)]
+pub struct UserNew {
+    pub user_id: String,
+    pub email: String,
+    pub name: String,
+    pub password: String,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = users)]
+pub struct User {
+    pub id: i32,
+    pub user_id: String,
+    pub email: String,
+    pub name: String,
+    /// The argon2 hash of the password of the user
+    pub password: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
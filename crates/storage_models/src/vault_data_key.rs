@@ -0,0 +1,21 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::vault_data_key;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = vault_data_key)]
+pub struct VaultDataKeyNew {
+    pub key_id: String,
+    pub encrypted_key: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = vault_data_key)]
+pub struct VaultDataKey {
+    pub id: i32,
+    pub key_id: String,
+    pub encrypted_key: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+}
@@ -0,0 +1,37 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::vaulted_card;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = vaulted_card)]
+pub struct VaultedCardNew {
+    pub payment_token: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub key_id: String,
+    pub encrypted_card: Vec<u8>,
+    pub card_last_four: String,
+    pub card_exp_month: String,
+    pub card_exp_year: String,
+    pub created_at: PrimitiveDateTime,
+    pub token_service_provider: Option<String>,
+    pub encrypted_network_token: Option<Vec<u8>>,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = vaulted_card)]
+pub struct VaultedCard {
+    pub id: i32,
+    pub payment_token: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub key_id: String,
+    pub encrypted_card: Vec<u8>,
+    pub card_last_four: String,
+    pub card_exp_month: String,
+    pub card_exp_year: String,
+    pub created_at: PrimitiveDateTime,
+    pub token_service_provider: Option<String>,
+    pub encrypted_network_token: Option<Vec<u8>>,
+}
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+-- Postgres does not support removing values from an enum type
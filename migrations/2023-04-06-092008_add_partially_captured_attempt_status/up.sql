@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "AttemptStatus" ADD VALUE 'partially_captured' AFTER 'partial_charged';
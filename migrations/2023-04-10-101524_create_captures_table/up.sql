@@ -0,0 +1,24 @@
+-- Your SQL goes here
+CREATE TYPE "CaptureStatus" AS ENUM ('started', 'charged', 'pending', 'failed');
+
+CREATE TABLE captures (
+    id SERIAL PRIMARY KEY,
+    capture_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(255) NOT NULL,
+    merchant_id VARCHAR(255) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    status "CaptureStatus" NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency",
+    connector VARCHAR(255) NOT NULL,
+    capture_sequence SMALLINT NOT NULL,
+    connector_transaction_id VARCHAR(255),
+    error_message VARCHAR(255),
+    error_code VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX captures_capture_id_index ON captures (capture_id);
+
+CREATE INDEX captures_merchant_id_payment_id_attempt_id_index ON captures (merchant_id, payment_id, attempt_id);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent ADD COLUMN session_expiry TIMESTAMP DEFAULT NULL;
+
+ALTER TYPE "IntentStatus" ADD VALUE 'expired';
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "ConnectorType" ADD VALUE 'authentication_processor';
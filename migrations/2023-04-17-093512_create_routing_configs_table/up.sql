@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE routing_configs (
+    id SERIAL PRIMARY KEY,
+    routing_config_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    description VARCHAR(256),
+    algorithm JSON NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX routing_configs_merchant_id_routing_config_id_index ON routing_configs (merchant_id, routing_config_id);
+
+CREATE UNIQUE INDEX routing_configs_merchant_id_active_index ON routing_configs (merchant_id) WHERE is_active;
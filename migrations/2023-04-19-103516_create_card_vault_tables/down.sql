@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE vaulted_card;
+
+DROP TABLE vault_data_key;
@@ -0,0 +1,24 @@
+-- Your SQL goes here
+CREATE TABLE vault_data_key (
+    id SERIAL PRIMARY KEY,
+    key_id VARCHAR(64) NOT NULL,
+    encrypted_key BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX vault_data_key_key_id_index ON vault_data_key (key_id);
+
+CREATE TABLE vaulted_card (
+    id SERIAL PRIMARY KEY,
+    payment_token VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64),
+    key_id VARCHAR(64) NOT NULL,
+    encrypted_card BYTEA NOT NULL,
+    card_last_four VARCHAR(4) NOT NULL,
+    card_exp_month VARCHAR(2) NOT NULL,
+    card_exp_year VARCHAR(4) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX vaulted_card_merchant_id_payment_token_index ON vaulted_card (merchant_id, payment_token);
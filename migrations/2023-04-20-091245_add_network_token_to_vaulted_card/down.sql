@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE vaulted_card
+DROP COLUMN token_service_provider,
+DROP COLUMN encrypted_network_token;
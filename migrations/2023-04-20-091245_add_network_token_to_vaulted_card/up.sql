@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE vaulted_card
+ADD COLUMN token_service_provider VARCHAR(32),
+ADD COLUMN encrypted_network_token BYTEA;
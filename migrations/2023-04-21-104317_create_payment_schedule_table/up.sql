@@ -0,0 +1,30 @@
+-- Your SQL goes here
+CREATE TABLE payment_schedule (
+    id SERIAL PRIMARY KEY,
+    schedule_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    mandate_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    billing_interval VARCHAR(16) NOT NULL,
+    interval_count INTEGER NOT NULL DEFAULT 1,
+    status VARCHAR(16) NOT NULL,
+    total_cycles INTEGER,
+    completed_cycles INTEGER NOT NULL DEFAULT 0,
+    failed_cycles INTEGER NOT NULL DEFAULT 0,
+    next_payment_at TIMESTAMP,
+    last_payment_id VARCHAR(64),
+    description VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX payment_schedule_merchant_id_schedule_id_index ON payment_schedule (merchant_id, schedule_id);
+
+ALTER TYPE "EventClass" ADD VALUE 'payment_schedules';
+
+ALTER TYPE "EventObjectType" ADD VALUE 'payment_schedule_details';
+
+ALTER TYPE "EventType" ADD VALUE 'payment_schedule_cycle_succeeded';
+ALTER TYPE "EventType" ADD VALUE 'payment_schedule_cycle_failed';
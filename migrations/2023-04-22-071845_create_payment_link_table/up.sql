@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE payment_link (
+    id SERIAL PRIMARY KEY,
+    link_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    description VARCHAR(255),
+    return_url VARCHAR(255),
+    reusable BOOLEAN NOT NULL DEFAULT FALSE,
+    status VARCHAR(16) NOT NULL,
+    usage_count INTEGER NOT NULL DEFAULT 0,
+    last_payment_id VARCHAR(64),
+    expires_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX payment_link_link_id_index ON payment_link (link_id);
+
+CREATE INDEX payment_link_merchant_id_index ON payment_link (merchant_id);
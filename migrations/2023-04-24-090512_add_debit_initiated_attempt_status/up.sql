@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "AttemptStatus" ADD VALUE 'debit_initiated' AFTER 'partially_captured';
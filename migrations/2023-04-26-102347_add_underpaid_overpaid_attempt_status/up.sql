@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TYPE "AttemptStatus" ADD VALUE 'underpaid' AFTER 'debit_initiated';
+ALTER TYPE "AttemptStatus" ADD VALUE 'overpaid' AFTER 'underpaid';
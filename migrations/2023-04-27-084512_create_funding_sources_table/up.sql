@@ -0,0 +1,24 @@
+-- Your SQL goes here
+CREATE TABLE funding_sources (
+    id SERIAL PRIMARY KEY,
+    funding_source_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(255) NOT NULL,
+    merchant_id VARCHAR(255) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    payment_method VARCHAR(64) NOT NULL,
+    payment_method_type VARCHAR(64),
+    status VARCHAR(32) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    connector VARCHAR(255) NOT NULL,
+    funding_sequence SMALLINT NOT NULL,
+    connector_transaction_id VARCHAR(255),
+    error_message VARCHAR(255),
+    error_code VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX funding_sources_funding_source_id_index ON funding_sources (funding_source_id);
+
+CREATE INDEX funding_sources_merchant_id_payment_id_attempt_id_index ON funding_sources (merchant_id, payment_id, attempt_id);
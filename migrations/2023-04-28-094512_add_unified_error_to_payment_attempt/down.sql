@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt DROP COLUMN unified_code;
+ALTER TABLE payment_attempt DROP COLUMN unified_message;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt ADD COLUMN unified_code VARCHAR(255);
+ALTER TABLE payment_attempt ADD COLUMN unified_message TEXT;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_account DROP COLUMN return_raw_connector_response;
+
+ALTER TABLE payment_attempt DROP COLUMN connector_raw_response;
+
+ALTER TABLE refund DROP COLUMN connector_raw_response;
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE merchant_account
+ADD COLUMN return_raw_connector_response BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE payment_attempt ADD COLUMN connector_raw_response JSONB;
+
+ALTER TABLE refund ADD COLUMN connector_raw_response JSONB;
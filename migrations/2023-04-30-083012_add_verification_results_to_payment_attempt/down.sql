@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt DROP COLUMN avs_result;
+
+ALTER TABLE payment_attempt DROP COLUMN cvc_result;
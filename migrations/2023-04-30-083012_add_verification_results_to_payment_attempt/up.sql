@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt ADD COLUMN avs_result VARCHAR(32);
+
+ALTER TABLE payment_attempt ADD COLUMN cvc_result VARCHAR(32);
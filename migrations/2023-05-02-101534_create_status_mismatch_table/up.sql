@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE status_mismatch (
+    id SERIAL PRIMARY KEY,
+    payment_id VARCHAR(255) NOT NULL,
+    merchant_id VARCHAR(255) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(255) NOT NULL,
+    connector_transaction_id VARCHAR(255),
+    router_status "AttemptStatus" NOT NULL,
+    connector_status "AttemptStatus" NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX status_mismatch_merchant_id_payment_id_index ON status_mismatch (merchant_id, payment_id);
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TYPE "IntentStatus" ADD VALUE 'requires_merchant_action';
+ALTER TYPE "AttemptStatus" ADD VALUE 'manual_review' AFTER 'overpaid';
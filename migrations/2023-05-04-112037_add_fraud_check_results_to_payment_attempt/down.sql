@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt DROP COLUMN fraud_check_verdict;
+
+ALTER TABLE payment_attempt DROP COLUMN fraud_check_score;
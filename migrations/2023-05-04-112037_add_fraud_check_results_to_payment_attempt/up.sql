@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt ADD COLUMN fraud_check_verdict VARCHAR(32);
+
+ALTER TABLE payment_attempt ADD COLUMN fraud_check_score INTEGER;
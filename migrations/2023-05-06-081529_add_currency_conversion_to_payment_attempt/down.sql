@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt DROP COLUMN settlement_amount;
+
+ALTER TABLE payment_attempt DROP COLUMN settlement_currency;
+
+ALTER TABLE payment_attempt DROP COLUMN fx_rate;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt ADD COLUMN settlement_amount BIGINT;
+
+ALTER TABLE payment_attempt ADD COLUMN settlement_currency "Currency";
+
+ALTER TABLE payment_attempt ADD COLUMN fx_rate VARCHAR(64);
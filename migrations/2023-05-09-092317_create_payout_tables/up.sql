@@ -0,0 +1,47 @@
+-- Your SQL goes here
+CREATE TABLE payout (
+    id SERIAL PRIMARY KEY,
+    payout_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64),
+    connector VARCHAR(64) NOT NULL,
+    payout_type VARCHAR(16) NOT NULL,
+    payout_method_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    status VARCHAR(16) NOT NULL,
+    connector_payout_id VARCHAR(128),
+    error_code VARCHAR(255),
+    error_message TEXT,
+    description VARCHAR(255),
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX payout_merchant_id_payout_id_index ON payout (merchant_id, payout_id);
+
+CREATE INDEX payout_merchant_id_connector_payout_id_index ON payout (merchant_id, connector_payout_id);
+
+CREATE TABLE payout_method (
+    id SERIAL PRIMARY KEY,
+    payout_method_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64),
+    payout_type VARCHAR(16) NOT NULL,
+    key_id VARCHAR(64) NOT NULL,
+    encrypted_payout_method_data BYTEA NOT NULL,
+    last_four VARCHAR(4) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX payout_method_merchant_id_payout_method_id_index ON payout_method (merchant_id, payout_method_id);
+
+ALTER TYPE "EventClass" ADD VALUE 'payouts';
+
+ALTER TYPE "EventObjectType" ADD VALUE 'payout_details';
+
+ALTER TYPE "EventType" ADD VALUE 'payout_succeeded';
+ALTER TYPE "EventType" ADD VALUE 'payout_failed';
+ALTER TYPE "EventType" ADD VALUE 'payout_cancelled';
+ALTER TYPE "EventType" ADD VALUE 'payout_reversed';
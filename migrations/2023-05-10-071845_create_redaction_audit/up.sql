@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE TABLE redaction_audit (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(255) NOT NULL,
+    customer_id VARCHAR(255) NOT NULL,
+    addresses_redacted INTEGER NOT NULL,
+    payments_redacted INTEGER NOT NULL,
+    mandates_redacted INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX redaction_audit_merchant_id_customer_id_index ON redaction_audit (merchant_id, customer_id);
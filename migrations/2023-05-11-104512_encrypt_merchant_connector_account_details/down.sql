@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_connector_account
+DROP COLUMN key_id,
+DROP COLUMN encrypted_connector_account_details;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE merchant_connector_account
+ADD COLUMN key_id VARCHAR(64),
+ADD COLUMN encrypted_connector_account_details BYTEA;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE user_roles;
+
+DROP TABLE users;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+UPDATE user_roles SET role = 'viewer' WHERE role = 'read_only';
+
+UPDATE user_roles SET role = 'viewer' WHERE role = 'operations';
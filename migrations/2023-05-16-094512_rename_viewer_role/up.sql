@@ -0,0 +1,2 @@
+-- Your SQL goes here
+UPDATE user_roles SET role = 'read_only' WHERE role = 'viewer';
@@ -0,0 +1,14 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM api_keys WHERE merchant_id IS NULL;
+
+ALTER TABLE api_keys DROP CONSTRAINT api_keys_owner_check;
+
+ALTER TABLE api_keys DROP COLUMN organization_id;
+
+ALTER TABLE api_keys ALTER COLUMN merchant_id SET NOT NULL;
+
+DROP INDEX merchant_account_organization_id_index;
+
+ALTER TABLE merchant_account DROP COLUMN organization_id;
+
+DROP TABLE organization;
@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE organization (
+    id SERIAL PRIMARY KEY,
+    organization_id VARCHAR(64) NOT NULL,
+    organization_name VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX organization_organization_id_index ON organization (organization_id);
+
+ALTER TABLE merchant_account ADD COLUMN organization_id VARCHAR(64);
+
+CREATE INDEX merchant_account_organization_id_index ON merchant_account (organization_id);
+
+ALTER TABLE api_keys ALTER COLUMN merchant_id DROP NOT NULL;
+
+ALTER TABLE api_keys ADD COLUMN organization_id VARCHAR(64);
+
+ALTER TABLE api_keys
+ADD CONSTRAINT api_keys_owner_check CHECK ((merchant_id IS NULL) <> (organization_id IS NULL));
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_intent DROP COLUMN profile_id;
+
+DROP TABLE business_profile;
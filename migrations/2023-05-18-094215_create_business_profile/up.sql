@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE business_profile (
+    id SERIAL PRIMARY KEY,
+    profile_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_name VARCHAR(64) NOT NULL,
+    return_url TEXT,
+    webhook_details JSON,
+    routing_algorithm JSON,
+    statement_descriptor_name VARCHAR(255),
+    statement_descriptor_suffix VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX business_profile_profile_id_index ON business_profile (profile_id);
+
+CREATE UNIQUE INDEX business_profile_merchant_id_profile_name_index ON business_profile (merchant_id, profile_name);
+
+ALTER TABLE payment_intent ADD COLUMN profile_id VARCHAR(64);
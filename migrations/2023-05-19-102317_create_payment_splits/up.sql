@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE payment_splits (
+    id SERIAL PRIMARY KEY,
+    split_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(255) NOT NULL,
+    merchant_id VARCHAR(255) NOT NULL,
+    sub_merchant_id VARCHAR(255) NOT NULL,
+    connector_account_id VARCHAR(255) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX payment_splits_split_id_index ON payment_splits (split_id);
+
+CREATE INDEX payment_splits_merchant_id_payment_id_index ON payment_splits (merchant_id, payment_id);
+
+CREATE INDEX payment_splits_sub_merchant_id_index ON payment_splits (sub_merchant_id);
@@ -0,0 +1,19 @@
+-- Your SQL goes here
+CREATE TABLE balance_transactions (
+    id SERIAL PRIMARY KEY,
+    transaction_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(255) NOT NULL,
+    transaction_type VARCHAR(32) NOT NULL,
+    source_id VARCHAR(255) NOT NULL,
+    payment_id VARCHAR(255),
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    debit_account VARCHAR(32) NOT NULL,
+    credit_account VARCHAR(32) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX balance_transactions_transaction_id_index ON balance_transactions (transaction_id);
+
+-- An event is recorded in the ledger at most once
+CREATE UNIQUE INDEX balance_transactions_merchant_id_transaction_type_source_id_index ON balance_transactions (merchant_id, transaction_type, source_id);
@@ -0,0 +1,34 @@
+-- Your SQL goes here
+CREATE TABLE settlement_reports (
+    id SERIAL PRIMARY KEY,
+    report_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(255) NOT NULL,
+    connector VARCHAR(255) NOT NULL,
+    line_count INTEGER NOT NULL,
+    matched_count INTEGER NOT NULL,
+    mismatched_count INTEGER NOT NULL,
+    missing_count INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX settlement_reports_report_id_index ON settlement_reports (report_id);
+
+CREATE TABLE settlement_lines (
+    id SERIAL PRIMARY KEY,
+    report_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(255) NOT NULL,
+    connector VARCHAR(255) NOT NULL,
+    connector_transaction_id VARCHAR(255) NOT NULL,
+    payment_id VARCHAR(255),
+    attempt_id VARCHAR(64),
+    settled_amount BIGINT NOT NULL,
+    settled_currency "Currency" NOT NULL,
+    expected_amount BIGINT,
+    expected_currency "Currency",
+    recon_status VARCHAR(32) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX settlement_lines_report_id_index ON settlement_lines (report_id);
+
+CREATE INDEX settlement_lines_merchant_id_payment_id_index ON settlement_lines (merchant_id, payment_id);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "ProcessTrackerStatus" ADD VALUE 'dead_letter' AFTER 'finish';
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_intent DROP COLUMN auto_capture_delay;
+
+ALTER TABLE business_profile DROP COLUMN auto_capture_delay;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent ADD COLUMN auto_capture_delay INTEGER DEFAULT NULL;
+
+ALTER TABLE business_profile ADD COLUMN auto_capture_delay INTEGER DEFAULT NULL;
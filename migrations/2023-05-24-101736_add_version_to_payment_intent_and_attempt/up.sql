@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent ADD COLUMN version INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE payment_attempt ADD COLUMN version INTEGER NOT NULL DEFAULT 0;
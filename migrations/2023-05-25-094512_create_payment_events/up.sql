@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE payment_events (
+    id SERIAL PRIMARY KEY,
+    event_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(255) NOT NULL,
+    merchant_id VARCHAR(255) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    operation VARCHAR(64) NOT NULL,
+    previous_intent_status "IntentStatus" NOT NULL,
+    intent_status "IntentStatus" NOT NULL,
+    previous_attempt_status "AttemptStatus" NOT NULL,
+    attempt_status "AttemptStatus" NOT NULL,
+    source VARCHAR(32) NOT NULL,
+    actor VARCHAR(255),
+    connector_response JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX payment_events_event_id_index ON payment_events (event_id);
+
+CREATE INDEX payment_events_merchant_id_payment_id_index ON payment_events (merchant_id, payment_id);
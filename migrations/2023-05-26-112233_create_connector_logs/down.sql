@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE connector_logs;
+
+ALTER TABLE payment_intent DROP COLUMN connector_debug_mode;
@@ -0,0 +1,22 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent ADD COLUMN connector_debug_mode BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE connector_logs (
+    id SERIAL PRIMARY KEY,
+    log_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(255) NOT NULL,
+    payment_id VARCHAR(255) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    flow VARCHAR(64) NOT NULL,
+    method VARCHAR(16) NOT NULL,
+    url TEXT NOT NULL,
+    request_body TEXT,
+    response_body TEXT,
+    status_code INTEGER,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX connector_logs_log_id_index ON connector_logs (log_id);
+
+CREATE INDEX connector_logs_merchant_id_payment_id_index ON connector_logs (merchant_id, payment_id);
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE redaction_audit DROP COLUMN payment_methods_deleted;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE redaction_audit ADD COLUMN payment_methods_deleted INTEGER NOT NULL DEFAULT 0;